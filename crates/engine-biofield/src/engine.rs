@@ -5,14 +5,17 @@
 
 use async_trait::async_trait;
 use chrono::Utc;
+use noesis_core::description::EngineDescription;
+use noesis_core::examples::{self, EngineExample};
+use noesis_core::options::BiofieldOptions;
 use noesis_core::{
-    ConsciousnessEngine, EngineError, EngineInput, EngineOutput, ValidationResult,
+    rng, ConsciousnessEngine, EngineError, EngineInput, TypedOptions, EngineOutput, ValidationResult,
     CalculationMetadata,
 };
 use serde_json::{json, Value};
 use std::time::Instant;
 
-use crate::mock::{generate_mock_metrics, generate_metrics_for_user};
+use crate::mock::generate_mock_metrics;
 use crate::models::{BiofieldAnalysis, BiofieldMetrics};
 use crate::wisdom::{get_metric_interpretation, get_chakra_wisdom};
 use crate::witness::generate_witness_prompt;
@@ -108,18 +111,22 @@ impl BiofieldEngine {
         areas
     }
     
+    /// Seed for the mock generator
+    ///
+    /// Derived like any engine's (see [`rng`]), except that `options.user_id`
+    /// stands in for the user making the request.
+    fn resolve_seed(&self, input: &EngineInput) -> Result<u64, EngineError> {
+        let user_id = input.engine_options::<BiofieldOptions>()?.user_id.or_else(rng::current_user_id);
+        let mut question = input.clone();
+        question.options.remove("user_id");
+        Ok(rng::derive_seed(&self.engine_id, user_id.as_deref(), &rng::input_hash(&question), input.seed()?))
+    }
+
     /// Perform the biofield analysis (currently returns mock data)
-    fn analyze(&self, input: &EngineInput) -> Result<BiofieldAnalysis, EngineError> {
-        // Get seed from options if provided for reproducible results
-        let seed = input.options.get("seed")
-            .and_then(|v| v.as_u64());
-        
-        // Or use user_id for consistent personal readings
-        let metrics = if let Some(user_id) = input.options.get("user_id").and_then(|v| v.as_str()) {
-            generate_metrics_for_user(user_id)
-        } else {
-            generate_mock_metrics(seed)
-        };
+    fn analyze(&self, input: &EngineInput, seed: u64) -> Result<BiofieldAnalysis, EngineError> {
+        let mut metrics = generate_mock_metrics(Some(seed));
+        // The reading describes the requested moment, not when it was computed
+        metrics.timestamp = input.current_time;
         
         let interpretation = Self::generate_interpretation(&metrics);
         let areas_of_attention = Self::identify_areas_of_attention(&metrics);
@@ -170,6 +177,35 @@ impl BiofieldEngine {
     }
 }
 
+/// Describe vitality, coherence and the most active chakra in plain language
+fn generate_summary(analysis: &BiofieldAnalysis) -> String {
+    let metrics = &analysis.metrics;
+    let vitality = if metrics.vitality_index > 0.7 {
+        "strong"
+    } else if metrics.vitality_index < 0.4 {
+        "low"
+    } else {
+        "moderate"
+    };
+    let mut summary = format!(
+        "Your biofield vitality reads as {} ({:.0}%) with {:.0}% coherence.",
+        vitality,
+        metrics.vitality_index * 100.0,
+        metrics.coherence * 100.0,
+    );
+    let most_active = metrics
+        .chakra_readings
+        .iter()
+        .max_by(|a, b| a.activity_level.total_cmp(&b.activity_level));
+    if let Some(reading) = most_active {
+        summary.push_str(&format!(" The {} chakra is the most active.", reading.chakra.name()));
+    }
+    if analysis.is_mock_data {
+        summary.push_str(" These readings are simulated until biofield hardware is connected.");
+    }
+    summary
+}
+
 impl Default for BiofieldEngine {
     fn default() -> Self {
         Self::new()
@@ -189,12 +225,43 @@ impl ConsciousnessEngine for BiofieldEngine {
     fn required_phase(&self) -> u8 {
         1  // Requires somatic awareness (phase 1)
     }
+
+    fn option_keys(&self) -> Option<&'static [&'static str]> {
+        Some(BiofieldOptions::keys())
+    }
+
+    fn check_input(&self, input: &EngineInput) -> Result<(), EngineError> {
+        self.resolve_seed(input).map(|_| ())
+    }
+
+    fn examples(&self) -> Vec<EngineExample> {
+        vec![EngineExample::new(
+            "seeded",
+            "Reproducible simulated metrics",
+            examples::sample_input(json!({ "seed": 42 })),
+        )]
+    }
+
+    fn describe(&self) -> EngineDescription {
+        EngineDescription::new(
+            "Biofield metrics from Polycontrast Interference Photography: fractal dimension, entropy, \
+             coherence, symmetry, seven chakra readings and a vitality index. No device is connected \
+             yet: metrics are simulated until PIP hardware is integrated.",
+        )
+        .with_input("options.seed", false, "Seed for reproducible simulated metrics")
+        .with_input("options.user_id", false, "User to read as instead of the authenticated one")
+        .with_outputs("Biofield metrics, chakra readings, vitality index, interpretation and a witness prompt")
+        .with_traditions(&["Biofield imaging (PIP)", "Chakra system"])
+        .with_limitation("Mock data: no measurement is taken and results do not describe the person")
+        .mock()
+    }
     
     async fn calculate(&self, input: EngineInput) -> Result<EngineOutput, EngineError> {
         let start = Instant::now();
         
         // Perform analysis
-        let analysis = self.analyze(&input)?;
+        let seed = self.resolve_seed(&input)?;
+        let analysis = self.analyze(&input, seed)?;
         
         // Generate witness prompt
         let witness_prompt = generate_witness_prompt(&analysis);
@@ -212,10 +279,14 @@ impl ConsciousnessEngine for BiofieldEngine {
             .map(|v| v as u8)
             .unwrap_or(1);
         
+        let summary = generate_summary(&analysis);
         let elapsed = start.elapsed();
         
         // Build result with mock notice
         let mut result = Self::serialize_result(&analysis);
+        if let Some(seed) = input.seed()? {
+            result["seed"] = json!(seed);
+        }
         result["notice"] = json!(
             "This is simulated data. Full biofield analysis requires PIP hardware integration."
         );
@@ -232,6 +303,7 @@ impl ConsciousnessEngine for BiofieldEngine {
             engine_id: self.engine_id.clone(),
             result,
             witness_prompt,
+            summary: Some(summary),
             consciousness_level,
             metadata: CalculationMetadata {
                 calculation_time_ms: elapsed.as_secs_f64() * 1000.0,
@@ -239,6 +311,9 @@ impl ConsciousnessEngine for BiofieldEngine {
                 precision_achieved: "simulated".to_string(),
                 cached: false,
                 timestamp: Utc::now(),
+                engine_version: None,
+                warnings: Vec::new(),
+                usage: None,
             },
         })
     }
@@ -307,15 +382,9 @@ impl ConsciousnessEngine for BiofieldEngine {
     }
     
     fn cache_key(&self, input: &EngineInput) -> String {
-        // Include seed or user_id in cache key for reproducibility
-        if let Some(user_id) = input.options.get("user_id").and_then(|v| v.as_str()) {
-            format!("biofield:user:{}", user_id)
-        } else if let Some(seed) = input.options.get("seed").and_then(|v| v.as_u64()) {
-            format!("biofield:seed:{}", seed)
-        } else {
-            // No caching for unseeded mock data
-            format!("biofield:random:{}", Utc::now().timestamp_nanos_opt().unwrap_or(0))
-        }
+        // The seed covers the input, the user and options.seed; check_input
+        // has already rejected a seed that fails to resolve
+        format!("biofield:seed:{:016x}", self.resolve_seed(input).unwrap_or_default())
     }
 }
 
@@ -331,6 +400,8 @@ mod tests {
         
         EngineInput {
             birth_data: None,
+            secondary_birth_data: None,
+            subjects: Vec::new(),
             current_time: Utc::now(),
             location: None,
             precision: Precision::Standard,
@@ -357,6 +428,7 @@ mod tests {
         let output = result.unwrap();
         assert_eq!(output.engine_id, "biofield");
         assert!(!output.witness_prompt.is_empty());
+        assert!(output.summary.as_deref().unwrap().ends_with("until biofield hardware is connected."));
         
         // Check is_mock_data flag
         let is_mock = output.result.get("is_mock_data")
@@ -380,6 +452,8 @@ mod tests {
         
         let input1 = EngineInput {
             birth_data: None,
+            secondary_birth_data: None,
+            subjects: Vec::new(),
             current_time: Utc::now(),
             location: None,
             precision: Precision::Standard,
@@ -388,6 +462,8 @@ mod tests {
         
         let input2 = EngineInput {
             birth_data: None,
+            secondary_birth_data: None,
+            subjects: Vec::new(),
             current_time: Utc::now(),
             location: None,
             precision: Precision::Standard,
@@ -412,6 +488,8 @@ mod tests {
         
         let input = EngineInput {
             birth_data: None,
+            secondary_birth_data: None,
+            subjects: Vec::new(),
             current_time: Utc::now(),
             location: None,
             precision: Precision::Standard,
@@ -452,6 +530,7 @@ mod tests {
                 "is_mock_data": true,
             }),
             witness_prompt: "".to_string(),  // Empty!
+            summary: None,
             consciousness_level: 1,
             metadata: CalculationMetadata {
                 calculation_time_ms: 1.0,
@@ -459,6 +538,9 @@ mod tests {
                 precision_achieved: "test".to_string(),
                 cached: false,
                 timestamp: Utc::now(),
+                engine_version: None,
+                warnings: Vec::new(),
+                usage: None,
             },
         };
         
@@ -471,30 +553,28 @@ mod tests {
     async fn test_cache_key_with_seed() {
         let engine = BiofieldEngine::new();
         let input = create_test_input();
+        let mut reseeded = create_test_input();
+        reseeded.options.insert("seed".to_string(), json!(43));
         
         let key = engine.cache_key(&input);
         assert!(key.starts_with("biofield:seed:"));
-        assert!(key.contains("42"));
+        assert_eq!(engine.cache_key(&create_test_input()), key);
+        assert_ne!(engine.cache_key(&reseeded), key);
     }
     
     #[tokio::test]
     async fn test_cache_key_with_user_id() {
         let engine = BiofieldEngine::new();
+        let mut input = create_test_input();
+        input.options.insert("user_id".to_string(), json!("user123"));
         
-        let mut options = HashMap::new();
-        options.insert("user_id".to_string(), json!("user123"));
-        
-        let input = EngineInput {
-            birth_data: None,
-            current_time: Utc::now(),
-            location: None,
-            precision: Precision::Standard,
-            options,
-        };
-        
+        // options.user_id stands in for the authenticated user
         let key = engine.cache_key(&input);
-        assert!(key.starts_with("biofield:user:"));
-        assert!(key.contains("user123"));
+        let as_user = rng::scope("user123".to_string(), async { engine.cache_key(&create_test_input()) }).await;
+        assert_eq!(as_user, key);
+        
+        input.options.insert("user_id".to_string(), json!("user456"));
+        assert_ne!(engine.cache_key(&input), key);
     }
     
     #[tokio::test]
@@ -528,6 +608,8 @@ mod tests {
             
             let input = EngineInput {
                 birth_data: None,
+                secondary_birth_data: None,
+                subjects: Vec::new(),
                 current_time: Utc::now(),
                 location: None,
                 precision: Precision::Standard,
@@ -546,4 +628,12 @@ mod tests {
             }
         }
     }
+
+    #[tokio::test]
+    async fn test_result_matches_core_schema() {
+        let engine = BiofieldEngine::new();
+        let output = engine.calculate(create_test_input()).await.unwrap();
+        let typed: noesis_core::results::BiofieldResult = output.parse_as().unwrap();
+        assert_eq!(typed.chakra_readings.len(), 7);
+    }
 }
@@ -4,8 +4,8 @@
 //! Uses seeded random for reproducibility.
 
 use chrono::Utc;
-use rand::{Rng, SeedableRng};
-use rand::rngs::StdRng;
+use noesis_core::rng::{self, EngineRng};
+use rand::Rng;
 
 use crate::models::{BiofieldMetrics, ChakraReading, Chakra};
 
@@ -17,10 +17,7 @@ use crate::models::{BiofieldMetrics, ChakraReading, Chakra};
 /// # Returns
 /// BiofieldMetrics with plausible values
 pub fn generate_mock_metrics(seed: Option<u64>) -> BiofieldMetrics {
-    let mut rng: StdRng = match seed {
-        Some(s) => StdRng::seed_from_u64(s),
-        None => StdRng::from_entropy(),
-    };
+    let mut rng = rng::from_seed(seed);
     
     // Generate base metrics within realistic ranges
     let fractal_dimension = generate_fractal_dimension(&mut rng);
@@ -51,7 +48,7 @@ pub fn generate_mock_metrics(seed: Option<u64>) -> BiofieldMetrics {
 }
 
 /// Generate fractal dimension (1.0-2.0, optimal ~1.5)
-fn generate_fractal_dimension(rng: &mut StdRng) -> f64 {
+fn generate_fractal_dimension(rng: &mut EngineRng) -> f64 {
     // Use normal-ish distribution centered around 1.5
     let base: f64 = 1.5;
     let variation: f64 = rng.gen_range(-0.3..0.3);
@@ -59,7 +56,7 @@ fn generate_fractal_dimension(rng: &mut StdRng) -> f64 {
 }
 
 /// Generate entropy (0.0-1.0, optimal ~0.55)
-fn generate_entropy(rng: &mut StdRng) -> f64 {
+fn generate_entropy(rng: &mut EngineRng) -> f64 {
     // Center around optimal with some variation
     let base: f64 = 0.55;
     let variation: f64 = rng.gen_range(-0.25..0.25);
@@ -67,14 +64,14 @@ fn generate_entropy(rng: &mut StdRng) -> f64 {
 }
 
 /// Generate coherence (0.0-1.0, optimal ~0.65)
-fn generate_coherence(rng: &mut StdRng) -> f64 {
+fn generate_coherence(rng: &mut EngineRng) -> f64 {
     let base: f64 = 0.65;
     let variation: f64 = rng.gen_range(-0.3..0.3);
     (base + variation).clamp(0.0, 1.0)
 }
 
 /// Generate symmetry (0.0-1.0, optimal ~0.75)
-fn generate_symmetry(rng: &mut StdRng) -> f64 {
+fn generate_symmetry(rng: &mut EngineRng) -> f64 {
     let base: f64 = 0.75;
     let variation: f64 = rng.gen_range(-0.2..0.2);
     (base + variation).clamp(0.0, 1.0)
@@ -104,7 +101,7 @@ fn calculate_vitality_index(
 }
 
 /// Generate readings for all 7 chakras
-fn generate_chakra_readings(rng: &mut StdRng) -> Vec<ChakraReading> {
+fn generate_chakra_readings(rng: &mut EngineRng) -> Vec<ChakraReading> {
     Chakra::all()
         .into_iter()
         .map(|chakra| generate_chakra_reading(rng, chakra))
@@ -112,7 +109,7 @@ fn generate_chakra_readings(rng: &mut StdRng) -> Vec<ChakraReading> {
 }
 
 /// Generate a single chakra reading
-fn generate_chakra_reading(rng: &mut StdRng, chakra: Chakra) -> ChakraReading {
+fn generate_chakra_reading(rng: &mut EngineRng, chakra: Chakra) -> ChakraReading {
     let activity_level = rng.gen_range(0.3..0.9);
     let balance = rng.gen_range(-0.5..0.5);
     let color_intensity = generate_color_intensity(rng, chakra, activity_level);
@@ -126,7 +123,7 @@ fn generate_chakra_reading(rng: &mut StdRng, chakra: Chakra) -> ChakraReading {
 }
 
 /// Generate appropriate color intensity based on chakra and activity
-fn generate_color_intensity(rng: &mut StdRng, chakra: Chakra, activity: f64) -> String {
+fn generate_color_intensity(rng: &mut EngineRng, chakra: Chakra, activity: f64) -> String {
     let base_color = match chakra {
         Chakra::Root => "red",
         Chakra::Sacral => "orange",
@@ -163,9 +160,7 @@ fn generate_color_intensity(rng: &mut StdRng, chakra: Chakra, activity: f64) ->
 
 /// Generate mock metrics based on a user ID for consistent personal readings
 pub fn generate_metrics_for_user(user_id: &str) -> BiofieldMetrics {
-    // Create seed from user ID hash
-    let seed = user_id.bytes().fold(0u64, |acc, b| acc.wrapping_mul(31).wrapping_add(b as u64));
-    generate_mock_metrics(Some(seed))
+    generate_mock_metrics(Some(rng::derive_seed("biofield", Some(user_id), "", None)))
 }
 
 #[cfg(test)]
@@ -6,8 +6,11 @@
 
 use async_trait::async_trait;
 use chrono::{NaiveDate, Utc};
+use noesis_core::description::EngineDescription;
+use noesis_core::examples::{self, EngineExample};
+use noesis_core::options::BiorhythmOptions;
 use noesis_core::{
-    CalculationMetadata, ConsciousnessEngine, EngineError, EngineInput, EngineOutput,
+    CacheTtl, CalculationMetadata, ConsciousnessEngine, EngineError, EngineInput, TypedOptions, EngineOutput,
     ValidationResult,
 };
 use serde::{Deserialize, Serialize};
@@ -229,26 +232,76 @@ fn build_forecast(
 ) -> Vec<ForecastDay> {
     let base_days = (target_date - birth_date).num_days();
     (1..=forecast_days)
-        .map(|offset| {
-            let d = base_days + offset;
-            let phys = to_percentage(cycle_value(d, PHYSICAL_PERIOD));
-            let emot = to_percentage(cycle_value(d, EMOTIONAL_PERIOD));
-            let inte = to_percentage(cycle_value(d, INTELLECTUAL_PERIOD));
-            let intu = to_percentage(cycle_value(d, INTUITIVE_PERIOD));
-            let date = target_date + chrono::Duration::days(offset);
-            ForecastDay {
-                date: date.format("%Y-%m-%d").to_string(),
-                days_alive: d,
-                physical: phys,
-                emotional: emot,
-                intellectual: inte,
-                intuitive: intu,
-                overall_energy: (phys + emot + inte) / 3.0,
-            }
-        })
+        .map(|offset| forecast_day(base_days + offset, target_date + chrono::Duration::days(offset)))
         .collect()
 }
 
+/// Cycle percentages of the day `date`, `days_alive` days after birth
+fn forecast_day(days_alive: i64, date: NaiveDate) -> ForecastDay {
+    let phys = to_percentage(cycle_value(days_alive, PHYSICAL_PERIOD));
+    let emot = to_percentage(cycle_value(days_alive, EMOTIONAL_PERIOD));
+    let inte = to_percentage(cycle_value(days_alive, INTELLECTUAL_PERIOD));
+    let intu = to_percentage(cycle_value(days_alive, INTUITIVE_PERIOD));
+    ForecastDay {
+        date: date.format("%Y-%m-%d").to_string(),
+        days_alive,
+        physical: phys,
+        emotional: emot,
+        intellectual: inte,
+        intuitive: intu,
+        overall_energy: (phys + emot + inte) / 3.0,
+    }
+}
+
+/// The cycles on `date` for someone born on `birth_date`, in the shape of
+/// a forecast day; `None` before birth
+pub fn cycles_on(birth_date: NaiveDate, date: NaiveDate) -> Option<ForecastDay> {
+    let days_alive = (date - birth_date).num_days();
+    (days_alive >= 0).then(|| forecast_day(days_alive, date))
+}
+
+/// The primary cycles (physical, emotional, intellectual) at a critical
+/// zero crossing on `date`
+pub fn critical_cycles_on(birth_date: NaiveDate, date: NaiveDate) -> Vec<&'static str> {
+    let days_alive = (date - birth_date).num_days();
+    [
+        ("physical", PHYSICAL_PERIOD),
+        ("emotional", EMOTIONAL_PERIOD),
+        ("intellectual", INTELLECTUAL_PERIOD),
+    ]
+    .into_iter()
+    .filter(|(_, period)| is_critical_day(days_alive, *period))
+    .map(|(name, _)| name)
+    .collect()
+}
+
+/// Describe the current cycle state in plain language.
+fn generate_summary(result: &BiorhythmResult) -> String {
+    let describe = |name: &str, cycle: &CycleResult| {
+        format!("{} {:.0}% ({})", name, cycle.percentage, cycle.phase.to_lowercase())
+    };
+    let level = if result.overall_energy > 70.0 {
+        "high"
+    } else if result.overall_energy < 30.0 {
+        "low"
+    } else {
+        "moderate"
+    };
+    let mut summary = format!(
+        "On {} your overall energy is {} at {:.0}%. Your cycles are {}, {} and {}.",
+        result.target_date,
+        level,
+        result.overall_energy,
+        describe("physical", &result.physical),
+        describe("emotional", &result.emotional),
+        describe("intellectual", &result.intellectual),
+    );
+    if let Some(next) = result.critical_days.first() {
+        summary.push_str(&format!(" The next critical day is {}.", next));
+    }
+    summary
+}
+
 /// Generate a reflective witness prompt from the current cycle state.
 fn generate_witness_prompt(result: &BiorhythmResult) -> String {
     let phys_pct = result.physical.percentage;
@@ -313,6 +366,20 @@ fn parse_date(date_str: &str) -> Result<NaiveDate, EngineError> {
     })
 }
 
+/// The birth date of the input's birth data
+fn birth_date(input: &EngineInput) -> Result<NaiveDate, EngineError> {
+    let birth_data = input.primary_birth_data().ok_or_else(|| {
+        EngineError::CalculationError(
+            "birth_data is required for biorhythm calculations".into(),
+        )
+    })?;
+
+    if let Some(error) = birth_data.date_error() {
+        return Err(EngineError::InvalidInput(vec![error]));
+    }
+    parse_date(&birth_data.date)
+}
+
 // ---------------------------------------------------------------------------
 // ConsciousnessEngine implementation
 // ---------------------------------------------------------------------------
@@ -331,18 +398,47 @@ impl ConsciousnessEngine for BiorhythmEngine {
         0
     }
 
+    fn option_keys(&self) -> Option<&'static [&'static str]> {
+        Some(BiorhythmOptions::keys())
+    }
+
+    fn check_input(&self, input: &EngineInput) -> Result<(), EngineError> {
+        birth_date(input)?;
+        input.engine_options::<BiorhythmOptions>().map(|_| ())
+    }
+
+    fn examples(&self) -> Vec<EngineExample> {
+        vec![
+            examples::birth_data_example(),
+            EngineExample::new(
+                "forecast",
+                "Cycles with a 14-day forecast",
+                examples::sample_input(serde_json::json!({ "forecast_days": 14 })),
+            ),
+        ]
+    }
+
+    fn describe(&self) -> EngineDescription {
+        EngineDescription::new(
+            "Follows the sine cycles that start at birth: physical (23 days), emotional (28), \
+             intellectual (33) and intuitive (38), with the mastery, passion and wisdom composites, \
+             on the reading day and the days after it.",
+        )
+        .with_input("birth_data.date", true, "Birth date, day one of every cycle")
+        .with_input("current_time", false, "Reading day; defaults to now")
+        .with_input("options.forecast_days", false, "Days to forecast after the reading day (default 7)")
+        .with_outputs("Each cycle's value and phase, critical days, the forecast and a witness prompt")
+        .with_traditions(&["Biorhythm theory (Fliess, Swoboda)"])
+        .with_limitation("The cycles are fixed-period models with no empirical support as predictors")
+    }
+
     async fn calculate(&self, input: EngineInput) -> Result<EngineOutput, EngineError> {
         let start = Instant::now();
 
         // --- Extract birth date ---
-        let birth_data = input.birth_data.as_ref().ok_or_else(|| {
-            EngineError::CalculationError(
-                "birth_data is required for biorhythm calculations".into(),
-            )
-        })?;
-
-        let birth_date = parse_date(&birth_data.date)?;
-        let target_date = input.current_time.date_naive();
+        let birth_date = birth_date(&input)?;
+        // Local calendar day of the reading, so it matches panchanga and vedic-clock
+        let target_date = input.local_time().date();
 
         let days_alive = (target_date - birth_date).num_days();
         if days_alive < 0 {
@@ -367,11 +463,8 @@ impl ConsciousnessEngine for BiorhythmEngine {
             (physical.percentage + emotional.percentage + intellectual.percentage) / 3.0;
 
         // --- Forecast days option ---
-        let forecast_days = input
-            .options
-            .get("forecast_days")
-            .and_then(|v| v.as_i64())
-            .unwrap_or(7);
+        let options: BiorhythmOptions = input.engine_options()?;
+        let forecast_days = options.forecast_days.unwrap_or(7);
 
         // --- Critical days in upcoming window ---
         let critical_days = find_critical_days(birth_date, target_date, forecast_days);
@@ -400,6 +493,7 @@ impl ConsciousnessEngine for BiorhythmEngine {
         };
 
         let witness_prompt = generate_witness_prompt(&bio_result);
+        let summary = generate_summary(&bio_result);
 
         let result_value = serde_json::to_value(&bio_result).map_err(|e| {
             EngineError::CalculationError(format!("Failed to serialize result: {}", e))
@@ -411,6 +505,7 @@ impl ConsciousnessEngine for BiorhythmEngine {
             engine_id: self.engine_id().to_string(),
             result: result_value,
             witness_prompt,
+            summary: Some(summary),
             consciousness_level: 0,
             metadata: CalculationMetadata {
                 calculation_time_ms: elapsed_ms,
@@ -418,6 +513,9 @@ impl ConsciousnessEngine for BiorhythmEngine {
                 precision_achieved: format!("{:?}", input.precision),
                 cached: false,
                 timestamp: Utc::now(),
+                engine_version: None,
+                warnings: Vec::new(),
+                usage: None,
             },
         })
     }
@@ -491,11 +589,11 @@ impl ConsciousnessEngine for BiorhythmEngine {
         let mut hasher = Sha256::new();
         hasher.update(self.engine_id().as_bytes());
 
-        if let Some(ref bd) = input.birth_data {
+        if let Some(bd) = input.primary_birth_data() {
             hasher.update(bd.date.as_bytes());
         }
 
-        let target_date = input.current_time.date_naive().format("%Y-%m-%d").to_string();
+        let target_date = input.local_time().date().format("%Y-%m-%d").to_string();
         hasher.update(target_date.as_bytes());
 
         if let Some(forecast) = input.options.get("forecast_days") {
@@ -504,6 +602,11 @@ impl ConsciousnessEngine for BiorhythmEngine {
 
         format!("{:x}", hasher.finalize())
     }
+
+    /// Cycles are read for a calendar date, which is part of the cache key.
+    fn cache_ttl(&self, _input: &EngineInput) -> CacheTtl {
+        CacheTtl::Infinite
+    }
 }
 
 // ---------------------------------------------------------------------------
@@ -521,12 +624,19 @@ mod tests {
         EngineInput {
             birth_data: Some(BirthData {
                 name: Some("Test".to_string()),
+                display_name: None,
                 date: birth_date.to_string(),
                 time: None,
+                time_unknown: false,
                 latitude: 0.0,
                 longitude: 0.0,
+                altitude: None,
                 timezone: "UTC".to_string(),
+                place: None,
+                utc_offset_minutes: None,
             }),
+            secondary_birth_data: None,
+            subjects: Vec::new(),
             current_time: target,
             location: None,
             precision: Precision::Standard,
@@ -581,6 +691,8 @@ mod tests {
 
         assert_eq!(output.engine_id, "biorhythm");
         assert!(!output.witness_prompt.is_empty());
+        let summary = output.summary.clone().unwrap();
+        assert!(summary.starts_with("On 2025-06-15 your overall energy is "), "{}", summary);
 
         // Deserialize and check structure.
         let bio: BiorhythmResult = serde_json::from_value(output.result).unwrap();
@@ -593,6 +705,8 @@ mod tests {
         let engine = BiorhythmEngine::new();
         let input = EngineInput {
             birth_data: None,
+            secondary_birth_data: None,
+            subjects: Vec::new(),
             current_time: Utc::now(),
             location: None,
             precision: Precision::Standard,
@@ -632,6 +746,20 @@ mod tests {
         assert_ne!(engine.cache_key(&input_a), engine.cache_key(&input_b));
     }
 
+    #[test]
+    fn test_cycles_on_matches_forecast() {
+        let birth = NaiveDate::from_ymd_opt(1990, 1, 15).unwrap();
+        let target = NaiveDate::from_ymd_opt(2024, 3, 1).unwrap();
+        let forecast = build_forecast(birth, target, 3);
+        let day = cycles_on(birth, NaiveDate::from_ymd_opt(2024, 3, 3).unwrap()).unwrap();
+        assert_eq!(day.physical, forecast[1].physical);
+        assert_eq!(day.date, forecast[1].date);
+        assert!(cycles_on(birth, NaiveDate::from_ymd_opt(1989, 12, 31).unwrap()).is_none());
+
+        // Every cycle starts at zero on the day of birth
+        assert_eq!(critical_cycles_on(birth, birth), ["physical", "emotional", "intellectual"]);
+    }
+
     #[test]
     fn test_find_critical_days() {
         let birth = NaiveDate::from_ymd_opt(1990, 1, 1).unwrap();
@@ -664,4 +792,15 @@ mod tests {
         assert!(!prompt.is_empty());
         assert!(prompt.contains('%'));
     }
+
+    #[tokio::test]
+    async fn test_result_matches_core_schema() {
+        let engine = BiorhythmEngine::new();
+        let target = Utc.with_ymd_and_hms(2025, 1, 15, 12, 0, 0).unwrap();
+        let mut input = make_input("1990-05-15", target);
+        input.options.insert("forecast_days".to_string(), serde_json::json!(3));
+        let output = engine.calculate(input).await.unwrap();
+        let typed: noesis_core::results::BiorhythmResult = output.parse_as().unwrap();
+        assert_eq!(typed.forecast.map(|f| f.len()), Some(3));
+    }
 }
@@ -5,8 +5,11 @@
 
 use async_trait::async_trait;
 use chrono::Utc;
+use noesis_core::description::EngineDescription;
+use noesis_core::examples::{self, EngineExample};
+use noesis_core::options::FaceReadingOptions;
 use noesis_core::{
-    CalculationMetadata, ConsciousnessEngine, EngineError, EngineInput, EngineOutput,
+    rng, CalculationMetadata, ConsciousnessEngine, EngineError, EngineInput, TypedOptions, EngineOutput,
     ValidationResult,
 };
 use serde_json::{json, Value};
@@ -95,6 +98,21 @@ impl FaceReadingEngine {
     }
 }
 
+/// Describe the constitution and dominant element in plain language
+fn generate_summary(analysis: &FaceAnalysis) -> String {
+    let constitution = &analysis.constitution;
+    let mut summary = format!(
+        "This reading suggests a {} constitution ({}) with {} as the dominant element.",
+        constitution.primary_dosha.display_name(),
+        constitution.primary_dosha.description().to_lowercase(),
+        analysis.elemental_balance.dominant().display_name(),
+    );
+    if analysis.is_mock_data {
+        summary.push_str(" It is simulated, not drawn from an image of your face.");
+    }
+    summary
+}
+
 impl Default for FaceReadingEngine {
     fn default() -> Self {
         Self::new()
@@ -115,21 +133,50 @@ impl ConsciousnessEngine for FaceReadingEngine {
         1 // Requires self-reflection capacity
     }
 
+    fn option_keys(&self) -> Option<&'static [&'static str]> {
+        Some(FaceReadingOptions::keys())
+    }
+
+    fn check_input(&self, input: &EngineInput) -> Result<(), EngineError> {
+        rng::engine_seed(&self.engine_id, input)?;
+        input.engine_options::<FaceReadingOptions>().map(|_| ())
+    }
+
+    fn examples(&self) -> Vec<EngineExample> {
+        vec![EngineExample::new(
+            "seeded",
+            "Reproducible simulated analysis",
+            examples::sample_input(json!({ "seed": 42 })),
+        )]
+    }
+
+    fn describe(&self) -> EngineDescription {
+        EngineDescription::new(
+            "Constitutional face reading across three traditions: Five Elements in Chinese Mian Xiang, \
+             Ayurvedic doshas and Western physiognomy. No face is analyzed yet: results are simulated \
+             until facial landmark detection is integrated.",
+        )
+        .with_input("options.seed", false, "Seed for a reproducible simulated analysis")
+        .with_input("options.image_data", false, "Base64 image, reserved for image analysis")
+        .with_input("options.image_url", false, "Image URL, reserved for image analysis")
+        .with_outputs("Face zones, elemental and dosha constitution, personality indicators and a witness prompt")
+        .with_traditions(&["Mian Xiang", "Ayurveda", "Western physiognomy"])
+        .with_limitation("Mock data: images are not read and results do not describe the person")
+        .mock()
+    }
+
     async fn calculate(&self, input: EngineInput) -> Result<EngineOutput, EngineError> {
         let start = Instant::now();
 
-        // Extract optional seed for reproducibility
-        let seed = input
-            .options
-            .get("seed")
-            .and_then(|v| v.as_u64());
+        // Seed every draw so the same question replays to the same output
+        let seed = rng::engine_seed(&self.engine_id, &input)?;
 
         // For future implementation: check for image data
-        let _has_image = input.options.contains_key("image_data")
-            || input.options.contains_key("image_url");
+        let options: FaceReadingOptions = input.engine_options()?;
+        let _has_image = options.image_data.is_some() || options.image_url.is_some();
 
         // Generate mock analysis (in future, this would process actual image)
-        let analysis = generate_mock_analysis(seed);
+        let analysis = generate_mock_analysis(Some(seed));
 
         // Get consciousness level for prompt generation
         let consciousness_level = input
@@ -140,7 +187,7 @@ impl ConsciousnessEngine for FaceReadingEngine {
             .unwrap_or(2);
 
         // Generate witness prompt
-        let witness_prompt = generate_single_witness_prompt(&analysis, consciousness_level);
+        let witness_prompt = generate_single_witness_prompt(&analysis, consciousness_level, Some(seed));
 
         // Ensure witness prompt is non-empty
         if witness_prompt.is_empty() {
@@ -149,12 +196,19 @@ impl ConsciousnessEngine for FaceReadingEngine {
             ));
         }
 
+        let summary = generate_summary(&analysis);
         let elapsed = start.elapsed();
 
+        let mut result = Self::serialize_analysis(&analysis);
+        if let Some(seed) = input.seed()? {
+            result["seed"] = json!(seed);
+        }
+
         Ok(EngineOutput {
             engine_id: self.engine_id.clone(),
-            result: Self::serialize_analysis(&analysis),
+            result,
             witness_prompt,
+            summary: Some(summary),
             consciousness_level,
             metadata: CalculationMetadata {
                 calculation_time_ms: elapsed.as_secs_f64() * 1000.0,
@@ -162,6 +216,9 @@ impl ConsciousnessEngine for FaceReadingEngine {
                 precision_achieved: "simulated".to_string(),
                 cached: false,
                 timestamp: Utc::now(),
+                engine_version: None,
+                warnings: Vec::new(),
+                usage: None,
             },
         })
     }
@@ -227,13 +284,12 @@ impl ConsciousnessEngine for FaceReadingEngine {
     }
 
     fn cache_key(&self, input: &EngineInput) -> String {
-        // For mock implementation, cache key is based on seed if provided
-        if let Some(seed) = input.options.get("seed").and_then(|v| v.as_u64()) {
-            format!("face-reading:mock:seed:{}", seed)
-        } else {
-            // Without seed, each call is unique (timestamp-based)
-            format!("face-reading:mock:{}", Utc::now().timestamp_nanos_opt().unwrap_or(0))
-        }
+        // The seed covers the input, the user and options.seed; check_input
+        // has already rejected a seed that fails to resolve
+        format!(
+            "face-reading:mock:{:016x}",
+            rng::engine_seed(&self.engine_id, input).unwrap_or_default()
+        )
     }
 }
 
@@ -246,6 +302,8 @@ mod tests {
     fn create_test_input() -> EngineInput {
         EngineInput {
             birth_data: None,
+            secondary_birth_data: None,
+            subjects: Vec::new(),
             current_time: Utc::now(),
             location: None,
             precision: Precision::Standard,
@@ -259,6 +317,8 @@ mod tests {
         
         EngineInput {
             birth_data: None,
+            secondary_birth_data: None,
+            subjects: Vec::new(),
             current_time: Utc::now(),
             location: None,
             precision: Precision::Standard,
@@ -336,6 +396,10 @@ mod tests {
         let dosha1 = output1.result["analysis"]["constitution"]["primary_dosha"].clone();
         let dosha2 = output2.result["analysis"]["constitution"]["primary_dosha"].clone();
         assert_eq!(dosha1, dosha2);
+        assert_eq!(output1.witness_prompt, output2.witness_prompt);
+        assert_eq!(output1.summary, output2.summary);
+        assert!(output1.summary.unwrap().ends_with("not drawn from an image of your face."));
+        assert_eq!(output1.result["seed"], json!(12345));
     }
 
     #[tokio::test]
@@ -367,6 +431,7 @@ mod tests {
                 "traditions": [],
             }),
             witness_prompt: "".to_string(),
+            summary: None,
             consciousness_level: 2,
             metadata: CalculationMetadata {
                 calculation_time_ms: 1.0,
@@ -374,6 +439,9 @@ mod tests {
                 precision_achieved: "test".to_string(),
                 cached: false,
                 timestamp: Utc::now(),
+                engine_version: None,
+                warnings: Vec::new(),
+                usage: None,
             },
         };
 
@@ -385,10 +453,10 @@ mod tests {
     #[tokio::test]
     async fn test_cache_key_with_seed() {
         let engine = FaceReadingEngine::new();
-        let input = create_seeded_input(42);
+        let key = engine.cache_key(&create_seeded_input(42));
 
-        let key = engine.cache_key(&input);
-        assert!(key.contains("seed:42"));
+        assert_eq!(engine.cache_key(&create_seeded_input(42)), key);
+        assert_ne!(engine.cache_key(&create_seeded_input(43)), key);
     }
 
     #[tokio::test]
@@ -396,9 +464,12 @@ mod tests {
         let engine = FaceReadingEngine::new();
         let input = create_test_input();
 
+        // Stable per question and per user
         let key = engine.cache_key(&input);
         assert!(key.starts_with("face-reading:mock:"));
-        assert!(!key.contains("seed"));
+        assert_eq!(engine.cache_key(&create_test_input()), key);
+        let as_user = rng::scope("user-1".to_string(), async { engine.cache_key(&input) }).await;
+        assert_ne!(as_user, key);
     }
 
     #[tokio::test]
@@ -428,4 +499,12 @@ mod tests {
         assert!(tradition_strs.iter().any(|t| t.contains("Ayurvedic")));
         assert!(tradition_strs.iter().any(|t| t.contains("Physiognomy")));
     }
+
+    #[tokio::test]
+    async fn test_result_matches_core_schema() {
+        let engine = FaceReadingEngine::new();
+        let output = engine.calculate(create_seeded_input(42)).await.unwrap();
+        let typed: noesis_core::results::FaceReadingResult = output.parse_as().unwrap();
+        assert!(typed.analysis.is_mock_data);
+    }
 }
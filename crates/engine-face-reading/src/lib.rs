@@ -27,6 +27,8 @@
 //!     
 //!     let input = EngineInput {
 //!         birth_data: None,
+//!         secondary_birth_data: None,
+//!         subjects: Vec::new(),
 //!         current_time: Utc::now(),
 //!         location: None,
 //!         precision: Precision::Standard,
@@ -7,16 +7,12 @@ use crate::models::{
     BodyType, ConstitutionAnalysis, Dosha, Element, ElementalBalance,
     FaceAnalysis, FaceZone, HealthIndicator, PersonalityTrait,
 };
+use noesis_core::rng::{self, EngineRng};
 use rand::prelude::*;
-use rand::SeedableRng;
-use rand_chacha::ChaCha8Rng;
 
 /// Generate a mock face analysis with optional seed for reproducibility
 pub fn generate_mock_analysis(seed: Option<u64>) -> FaceAnalysis {
-    let mut rng = match seed {
-        Some(s) => ChaCha8Rng::seed_from_u64(s),
-        None => ChaCha8Rng::from_entropy(),
-    };
+    let mut rng = rng::from_seed(seed);
 
     FaceAnalysis {
         constitution: generate_constitution(&mut rng),
@@ -28,7 +24,7 @@ pub fn generate_mock_analysis(seed: Option<u64>) -> FaceAnalysis {
 }
 
 /// Generate constitutional analysis
-fn generate_constitution(rng: &mut ChaCha8Rng) -> ConstitutionAnalysis {
+fn generate_constitution(rng: &mut EngineRng) -> ConstitutionAnalysis {
     let doshas = [Dosha::Vata, Dosha::Pitta, Dosha::Kapha];
 
     let primary_idx: usize = rng.gen_range(0..3);
@@ -65,7 +61,7 @@ fn generate_constitution(rng: &mut ChaCha8Rng) -> ConstitutionAnalysis {
 }
 
 /// Generate 3-5 personality traits
-fn generate_personality_traits(rng: &mut ChaCha8Rng) -> Vec<PersonalityTrait> {
+fn generate_personality_traits(rng: &mut EngineRng) -> Vec<PersonalityTrait> {
     let all_traits = [
         ("Analytical Thinker", "high forehead", "Shows capacity for abstract thinking and long-term planning"),
         ("Strong-Willed", "prominent chin", "Indicates determination and follow-through in pursuits"),
@@ -97,7 +93,7 @@ fn generate_personality_traits(rng: &mut ChaCha8Rng) -> Vec<PersonalityTrait> {
 }
 
 /// Generate balanced elemental scores
-fn generate_elemental_balance(rng: &mut ChaCha8Rng) -> ElementalBalance {
+fn generate_elemental_balance(rng: &mut EngineRng) -> ElementalBalance {
     // Start with base values
     let mut wood: f64 = rng.gen_range(0.1..0.4);
     let mut fire: f64 = rng.gen_range(0.1..0.4);
@@ -123,7 +119,7 @@ fn generate_elemental_balance(rng: &mut ChaCha8Rng) -> ElementalBalance {
 }
 
 /// Generate 2-3 health indicators
-fn generate_health_indicators(rng: &mut ChaCha8Rng) -> Vec<HealthIndicator> {
+fn generate_health_indicators(rng: &mut EngineRng) -> Vec<HealthIndicator> {
     let all_indicators = [
         (FaceZone::Forehead, "Bladder/Small Intestine", "Area appears clear, suggesting good fluid balance"),
         (FaceZone::Forehead, "Mental processing", "Subtle lines present, may indicate active mental life"),
@@ -5,9 +5,8 @@
 
 use crate::models::FaceAnalysis;
 use crate::wisdom::get_zone_wisdom;
+use noesis_core::rng::{self, EngineRng};
 use rand::prelude::*;
-use rand::SeedableRng;
-use rand_chacha::ChaCha8Rng;
 
 /// Generate witness prompts based on face analysis
 ///
@@ -17,10 +16,7 @@ pub fn generate_witness_prompts(
     consciousness_level: u8,
     seed: Option<u64>,
 ) -> Vec<String> {
-    let mut rng = match seed {
-        Some(s) => ChaCha8Rng::seed_from_u64(s),
-        None => ChaCha8Rng::from_entropy(),
-    };
+    let mut rng = rng::from_seed(seed);
 
     let mut prompts = Vec::new();
 
@@ -47,15 +43,16 @@ pub fn generate_witness_prompts(
 pub fn generate_single_witness_prompt(
     analysis: &FaceAnalysis,
     consciousness_level: u8,
+    seed: Option<u64>,
 ) -> String {
-    let prompts = generate_witness_prompts(analysis, consciousness_level, None);
+    let prompts = generate_witness_prompts(analysis, consciousness_level, seed);
     prompts.into_iter().next().unwrap_or_else(|| {
         "What do you notice when you look at your reflection with curiosity rather than judgment?".to_string()
     })
 }
 
 /// Foundational prompts (levels 0-2) - basic self-observation
-fn generate_foundational_prompts(analysis: &FaceAnalysis, rng: &mut ChaCha8Rng) -> Vec<String> {
+fn generate_foundational_prompts(analysis: &FaceAnalysis, rng: &mut EngineRng) -> Vec<String> {
     let mut prompts = Vec::new();
 
     // Zone-based observation
@@ -86,7 +83,7 @@ fn generate_foundational_prompts(analysis: &FaceAnalysis, rng: &mut ChaCha8Rng)
 }
 
 /// Awareness prompts (levels 3-4) - deeper inquiry
-fn generate_awareness_prompts(analysis: &FaceAnalysis, rng: &mut ChaCha8Rng) -> Vec<String> {
+fn generate_awareness_prompts(analysis: &FaceAnalysis, rng: &mut EngineRng) -> Vec<String> {
     let mut prompts = Vec::new();
 
     // Constitution-based
@@ -125,7 +122,7 @@ fn generate_awareness_prompts(analysis: &FaceAnalysis, rng: &mut ChaCha8Rng) ->
 }
 
 /// Integration prompts (levels 5-6) - transcendent inquiry
-fn generate_integration_prompts(analysis: &FaceAnalysis, rng: &mut ChaCha8Rng) -> Vec<String> {
+fn generate_integration_prompts(analysis: &FaceAnalysis, rng: &mut EngineRng) -> Vec<String> {
     let mut prompts = Vec::new();
 
     // Life journey reflection
@@ -165,7 +162,7 @@ fn generate_integration_prompts(analysis: &FaceAnalysis, rng: &mut ChaCha8Rng) -
 }
 
 /// Generate a general prompt as fallback
-fn generate_general_prompt(rng: &mut ChaCha8Rng) -> String {
+fn generate_general_prompt(rng: &mut EngineRng) -> String {
     let prompts = [
         "What do you notice when you look at your reflection with curiosity rather than judgment?",
         "How might your face be expressing what words cannot?",
@@ -205,7 +202,7 @@ mod tests {
     #[test]
     fn test_single_witness_prompt() {
         let analysis = generate_mock_analysis(Some(999));
-        let prompt = generate_single_witness_prompt(&analysis, 3);
+        let prompt = generate_single_witness_prompt(&analysis, 3, Some(999));
         
         assert!(!prompt.is_empty());
         assert!(prompt.contains('?'));
@@ -26,6 +26,8 @@ fn create_engine_input(ps: u8, pe: u8, ds: u8, de: u8) -> EngineInput {
 
     EngineInput {
         birth_data: None,
+        secondary_birth_data: None,
+        subjects: Vec::new(),
         current_time: Utc::now(),
         location: None,
         precision: noesis_core::Precision::Standard,
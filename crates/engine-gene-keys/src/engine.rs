@@ -7,9 +7,13 @@
 
 use async_trait::async_trait;
 use chrono::Utc;
+use noesis_core::birth_time::{self, TimeStability, TIME_STABILITY_KEY};
+use noesis_core::description::EngineDescription;
+use noesis_core::examples::{self, EngineExample};
+use noesis_core::options::{GeneKeysOptions, HdGates};
 use noesis_core::{
-    ConsciousnessEngine, EngineError, EngineInput, EngineOutput, ValidationResult,
-    CalculationMetadata,
+    CacheTtl, ConsciousnessEngine, EngineError, EngineInput, TypedOptions, EngineOutput, ValidationResult,
+    CalculationMetadata, TimeSensitivity,
 };
 use serde_json::{json, Value};
 use std::sync::Arc;
@@ -57,32 +61,9 @@ impl GeneKeysEngine {
                 "Missing 'hd_gates' in options".to_string()
             ))?;
 
-        let personality_sun = hd_gates.get("personality_sun")
-            .and_then(|v| v.as_u64())
-            .map(|v| v as u8)
-            .ok_or_else(|| EngineError::ValidationError(
-                "Missing or invalid 'personality_sun' in hd_gates".to_string()
-            ))?;
-
-        let personality_earth = hd_gates.get("personality_earth")
-            .and_then(|v| v.as_u64())
-            .map(|v| v as u8)
-            .ok_or_else(|| EngineError::ValidationError(
-                "Missing or invalid 'personality_earth' in hd_gates".to_string()
-            ))?;
-
-        let design_sun = hd_gates.get("design_sun")
-            .and_then(|v| v.as_u64())
-            .map(|v| v as u8)
-            .ok_or_else(|| EngineError::ValidationError(
-                "Missing or invalid 'design_sun' in hd_gates".to_string()
-            ))?;
-
-        let design_earth = hd_gates.get("design_earth")
-            .and_then(|v| v.as_u64())
-            .map(|v| v as u8)
-            .ok_or_else(|| EngineError::ValidationError(
-                "Missing or invalid 'design_earth' in hd_gates".to_string()
+        let HdGates { personality_sun, personality_earth, design_sun, design_earth } =
+            serde_json::from_value(hd_gates.clone()).map_err(|e| EngineError::ValidationError(
+                format!("Invalid 'hd_gates' in options: {}", e)
             ))?;
 
         // Validate gate ranges (1-64)
@@ -152,6 +133,27 @@ impl GeneKeysEngine {
         })
     }
     
+    /// How the keys read at noon hold across the birth day, sampled hourly
+    fn time_stability(
+        hd_engine: &engine_human_design::HumanDesignEngine,
+        input: &EngineInput,
+    ) -> Result<TimeStability, EngineError> {
+        let confidence = birth_time::stability_across_day(60, |time| {
+            let mut sample = input.clone();
+            for birth_data in sample.birth_data_mut() {
+                birth_data.time = Some(time.format("%H:%M").to_string());
+                birth_data.time_unknown = false;
+            }
+            let keys: Vec<(u8, u8)> = map_hd_to_gene_keys(&hd_engine.chart(&sample)?)
+                .iter()
+                .map(|activation| (activation.key_number, activation.line))
+                .collect();
+            Ok(keys)
+        })?;
+        let varies: &[&str] = if confidence < 1.0 { &["activation_sequence", "active_keys"] } else { &[] };
+        Ok(TimeStability::at_noon(confidence, varies.iter().copied()))
+    }
+
     /// Serialize GeneKeysChart to JSON value
     fn serialize_chart(chart: &GeneKeysChart) -> Value {
         // Enrich active keys with full Gene Key data
@@ -188,6 +190,22 @@ impl GeneKeysEngine {
     }
 }
 
+/// Describe the Life's Work key and its frequency arc in plain language
+fn generate_summary(chart: &GeneKeysChart) -> String {
+    let (lifes_work, evolution) = (chart.activation_sequence.lifes_work.0, chart.activation_sequence.evolution.0);
+    match get_gene_key(lifes_work) {
+        Some(key) => format!(
+            "Your Life's Work is Gene Key {} ({}), which moves from the shadow of {} \
+             through the gift of {} toward the siddhi of {}. Your Evolution begins with Gene Key {}.",
+            key.number, key.name, key.shadow, key.gift, key.siddhi, evolution,
+        ),
+        None => format!(
+            "Your Life's Work is Gene Key {} and your Evolution begins with Gene Key {}.",
+            lifes_work, evolution,
+        ),
+    }
+}
+
 impl Default for GeneKeysEngine {
     fn default() -> Self {
         Self::new()
@@ -203,29 +221,82 @@ impl ConsciousnessEngine for GeneKeysEngine {
     fn engine_name(&self) -> &str {
         &self.engine_name
     }
+
+    fn engine_version(&self) -> &str {
+        "2" // Follows the Human Design engine, whose charts it maps
+    }
     
     fn required_phase(&self) -> u8 {
         2 // Requires deeper consciousness than HD (phase 1)
     }
+
+    fn option_keys(&self) -> Option<&'static [&'static str]> {
+        Some(GeneKeysOptions::keys())
+    }
+
+    fn birth_time_sensitivity(&self) -> TimeSensitivity {
+        TimeSensitivity::Decisive
+    }
+
+    fn check_input(&self, input: &EngineInput) -> Result<(), EngineError> {
+        input.engine_options::<GeneKeysOptions>()?;
+        match (input.primary_birth_data(), &self.hd_engine) {
+            (Some(_), Some(hd_engine)) => hd_engine.check_input(input),
+            (Some(_), None) => Err(EngineError::CalculationError(
+                "HD engine not available for birth_data calculation".to_string()
+            )),
+            (None, _) if input.options.contains_key("hd_gates") => {
+                Self::extract_hd_gates_from_options(&input.options).map(|_| ())
+            }
+            (None, _) => Err(EngineError::ValidationError(
+                "Gene Keys requires either birth_data or hd_gates in options".to_string()
+            )),
+        }
+    }
+
+    fn examples(&self) -> Vec<EngineExample> {
+        vec![
+            examples::birth_data_example(),
+            EngineExample::new(
+                "hd_gates",
+                "Activation sequence from known Human Design gates, without birth data",
+                examples::options_input(json!({
+                    "hd_gates": { "personality_sun": 17, "personality_earth": 18, "design_sun": 45, "design_earth": 26 },
+                    "consciousness_level": 2
+                })),
+            ),
+        ]
+    }
+
+    fn describe(&self) -> EngineDescription {
+        EngineDescription::new(
+            "Reads the Gene Keys of a Human Design chart: the shadow, gift and siddhi of each gate in \
+             the four Core Activation Sequences. The gates come from the birth data or are passed in \
+             options.hd_gates.",
+        )
+        .with_input("birth_data", false, "Birth data, calculated through the Human Design engine")
+        .with_input("options.hd_gates", false, "Personality and design Sun and Earth gates; required without birth_data")
+        .with_outputs("The activation sequences with each key's shadow, gift and siddhi, and a witness prompt")
+        .with_traditions(&["Gene Keys (Richard Rudd)", "Human Design System", "I Ching"])
+        .with_limitation("Read from birth data, it inherits the birth time sensitivity of Human Design")
+    }
     
     async fn calculate(&self, input: EngineInput) -> Result<EngineOutput, EngineError> {
         let start = Instant::now();
         
-        let chart = if input.birth_data.is_some() {
+        let mut time_stability = None;
+        let chart = if input.primary_birth_data().is_some() {
             // Mode 1: Calculate from birth_data (requires HD engine)
             let hd_engine = self.hd_engine.as_ref()
                 .ok_or_else(|| EngineError::CalculationError(
                     "HD engine not available for birth_data calculation".to_string()
                 ))?;
             
-            // Call HD engine to get HD chart
-            let hd_output = hd_engine.calculate(input.clone()).await?;
-            
-            // Parse HD chart from output
-            let hd_chart: engine_human_design::HDChart = serde_json::from_value(hd_output.result)
-                .map_err(|e| EngineError::CalculationError(
-                    format!("Failed to parse HD chart: {}", e)
-                ))?;
+            // Human Design's output is shaped for clients, so take its chart directly
+            let hd_chart = hd_engine.chart(&input)?;
+            if input.primary_birth_data().is_some_and(|birth| birth.time_is_unknown()) {
+                time_stability = Some(Self::time_stability(hd_engine, &input)?);
+            }
             
             // Map HD to Gene Keys
             let mut gene_key_activations = map_hd_to_gene_keys(&hd_chart);
@@ -270,19 +341,28 @@ impl ConsciousnessEngine for GeneKeysEngine {
             ));
         }
         
+        let summary = generate_summary(&chart);
+        let mut result = Self::serialize_chart(&chart);
+        if let Some(time_stability) = time_stability {
+            result[TIME_STABILITY_KEY] = json!(time_stability);
+        }
         let elapsed = start.elapsed();
         
         Ok(EngineOutput {
             engine_id: self.engine_id.clone(),
-            result: Self::serialize_chart(&chart),
+            result,
             witness_prompt,
+            summary: Some(summary),
             consciousness_level,
             metadata: CalculationMetadata {
                 calculation_time_ms: elapsed.as_secs_f64() * 1000.0,
-                backend: if input.birth_data.is_some() { "hd-derived" } else { "hd-gates" }.to_string(),
+                backend: if input.primary_birth_data().is_some() { "hd-derived" } else { "hd-gates" }.to_string(),
                 precision_achieved: format!("{:?}", input.precision),
                 cached: false,
                 timestamp: Utc::now(),
+                engine_version: None,
+                warnings: Vec::new(),
+                usage: None,
             },
         })
     }
@@ -339,13 +419,21 @@ impl ConsciousnessEngine for GeneKeysEngine {
         })
     }
     
+    /// Birth-data mode calculates through Human Design, so its health is shared.
+    async fn health(&self) -> Result<(), EngineError> {
+        match &self.hd_engine {
+            Some(hd_engine) => hd_engine.health().await,
+            None => Ok(()),
+        }
+    }
+
     fn cache_key(&self, input: &EngineInput) -> String {
-        if let Some(birth_data) = &input.birth_data {
+        if let Some(birth_data) = input.primary_birth_data() {
             // Mode 1: birth_data cache key
             format!(
                 "gk:{}:{}:{:.4}:{:.4}",
                 birth_data.date,
-                birth_data.time.as_ref().unwrap_or(&"00:00".to_string()),
+                birth_data.time.as_deref().unwrap_or("unknown"),
                 birth_data.latitude,
                 birth_data.longitude
             )
@@ -360,6 +448,11 @@ impl ConsciousnessEngine for GeneKeysEngine {
             format!("gk:invalid:{}", Utc::now().timestamp())
         }
     }
+
+    /// Gene Keys are derived from the birth moment or the given gates.
+    fn cache_ttl(&self, _input: &EngineInput) -> CacheTtl {
+        CacheTtl::Infinite
+    }
 }
 
 #[cfg(test)]
@@ -379,6 +472,8 @@ mod tests {
         
         EngineInput {
             birth_data: None,
+            secondary_birth_data: None,
+            subjects: Vec::new(),
             current_time: Utc::now(),
             location: None,
             precision: Precision::Standard,
@@ -432,6 +527,7 @@ mod tests {
         let output = result.unwrap();
         assert_eq!(output.engine_id, "gene-keys");
         assert!(!output.witness_prompt.is_empty());
+        assert!(output.summary.as_deref().unwrap().starts_with("Your Life's Work is Gene Key "));
         assert_eq!(output.consciousness_level, 3); // Default
     }
     
@@ -461,6 +557,7 @@ mod tests {
                 "frequency_assessments": []
             }),
             witness_prompt: "".to_string(), // Empty
+            summary: None,
             consciousness_level: 3,
             metadata: CalculationMetadata {
                 calculation_time_ms: 10.0,
@@ -468,6 +565,9 @@ mod tests {
                 precision_achieved: "Standard".to_string(),
                 cached: false,
                 timestamp: Utc::now(),
+                engine_version: None,
+                warnings: Vec::new(),
+                usage: None,
             },
         };
         
@@ -497,6 +597,7 @@ mod tests {
                 // Missing frequency_assessments
             }),
             witness_prompt: "Test?".to_string(),
+            summary: None,
             consciousness_level: 3,
             metadata: CalculationMetadata {
                 calculation_time_ms: 10.0,
@@ -504,6 +605,9 @@ mod tests {
                 precision_achieved: "Standard".to_string(),
                 cached: false,
                 timestamp: Utc::now(),
+                engine_version: None,
+                warnings: Vec::new(),
+                usage: None,
             },
         };
         
@@ -517,6 +621,8 @@ mod tests {
         let engine = GeneKeysEngine::new();
         let input = EngineInput {
             birth_data: None,
+            secondary_birth_data: None,
+            subjects: Vec::new(),
             current_time: Utc::now(),
             location: None,
             precision: Precision::Standard,
@@ -527,4 +633,13 @@ mod tests {
         assert!(result.is_err());
         assert!(result.unwrap_err().to_string().contains("requires either"));
     }
+
+    #[tokio::test]
+    async fn test_result_matches_core_schema() {
+        let engine = GeneKeysEngine::new();
+        let output = engine.calculate(create_test_input_with_gates()).await.unwrap();
+        let typed: noesis_core::results::GeneKeysResult = output.parse_as().unwrap();
+        assert_eq!(typed.activation_sequence.lifes_work[0], 17);
+        assert!(!typed.active_keys.is_empty());
+    }
 }
@@ -172,6 +172,8 @@ mod tests {
             authority: Authority::Sacral,
             profile: Profile { conscious_line: 3, unconscious_line: 5 },
             definition: Definition::Single,
+            design_time: None,
+            warnings: Vec::new(),
         }
     }
     
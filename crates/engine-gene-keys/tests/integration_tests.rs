@@ -5,7 +5,7 @@
 //! - Frequency assessment accuracy (3 tests)
 //! - Witness prompt generation (3 tests)
 //! - Transformation pathways (2 tests)
-//! - HD integration mode (2 tests)
+//! - HD integration mode (3 tests)
 
 use engine_gene_keys::{
     GeneKeysEngine, ConsciousnessEngine, EngineInput,
@@ -34,6 +34,8 @@ fn create_engine_input(ps: u8, pe: u8, ds: u8, de: u8) -> EngineInput {
 
     EngineInput {
         birth_data: None,
+        secondary_birth_data: None,
+        subjects: Vec::new(),
         current_time: Utc::now(),
         location: None,
         precision: noesis_core::Precision::Standard,
@@ -53,6 +55,8 @@ fn create_engine_input_with_level(ps: u8, pe: u8, ds: u8, de: u8, level: u8) ->
 
     EngineInput {
         birth_data: None,
+        secondary_birth_data: None,
+        subjects: Vec::new(),
         current_time: Utc::now(),
         location: None,
         precision: noesis_core::Precision::Standard,
@@ -333,12 +337,19 @@ async fn test_hd_integration_missing_hd_engine() {
     let input = EngineInput {
         birth_data: Some(noesis_core::BirthData {
             name: Some("Test".to_string()),
+            display_name: None,
             date: "1990-06-15".to_string(),
             time: Some("14:30".to_string()),
+            time_unknown: false,
             latitude: 40.7128,
             longitude: -74.0060,
+            altitude: None,
             timezone: "America/New_York".to_string(),
+            place: None,
+            utc_offset_minutes: None,
         }),
+        secondary_birth_data: None,
+        subjects: Vec::new(),
         current_time: Utc::now(),
         location: None,
         precision: noesis_core::Precision::Standard,
@@ -354,3 +365,41 @@ async fn test_hd_integration_missing_hd_engine() {
         err_msg
     );
 }
+
+#[tokio::test]
+async fn test_hd_integration_from_birth_data() {
+    // Mode 1: birth_data goes through the attached HD engine's chart
+    let hd_engine = std::sync::Arc::new(engine_human_design::HumanDesignEngine::new());
+    let engine = GeneKeysEngine::with_hd_engine(hd_engine.clone());
+
+    let input = EngineInput {
+        birth_data: Some(noesis_core::BirthData {
+            name: Some("Test".to_string()),
+            display_name: None,
+            date: "1990-06-15".to_string(),
+            time: Some("14:30".to_string()),
+            time_unknown: false,
+            latitude: 40.7128,
+            longitude: -74.0060,
+            altitude: None,
+            timezone: "America/New_York".to_string(),
+            place: None,
+            utc_offset_minutes: None,
+        }),
+        secondary_birth_data: None,
+        subjects: Vec::new(),
+        current_time: Utc::now(),
+        location: None,
+        precision: noesis_core::Precision::Standard,
+        options: HashMap::new(),
+    };
+
+    let chart = hd_engine.chart(&input).expect("HD chart should calculate");
+    let output = engine.calculate(input).await.expect("Gene Keys should calculate from birth_data");
+    let sun = chart
+        .personality_activations
+        .iter()
+        .find(|a| a.planet == engine_human_design::Planet::Sun)
+        .unwrap();
+    assert_eq!(output.result["activation_sequence"]["lifes_work"][0], json!(sun.gate));
+}
@@ -55,6 +55,8 @@ fn create_input_from_gates(gates: &HdGates) -> EngineInput {
 
     EngineInput {
         birth_data: None,
+        secondary_birth_data: None,
+        subjects: Vec::new(),
         current_time: Utc::now(),
         location: None,
         precision: noesis_core::Precision::Standard,
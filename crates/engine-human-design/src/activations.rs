@@ -10,7 +10,7 @@ use crate::{
     models::{Activation, Planet},
     ephemeris::{EphemerisCalculator, HDPlanet},
     gate_sequence::{longitude_to_gate, longitude_to_line},
-    design_time::calculate_design_time,
+    design_time::{calculate_design_time, solve_design_time_with_method, DesignMethod, DesignTimeSolution},
 };
 
 /// Calculate Personality Sun and Earth activations (at birth time)
@@ -189,11 +189,25 @@ pub fn calculate_design_activations(
     birth_time: &DateTime<Utc>,
     calculator: &EphemerisCalculator,
 ) -> Result<Vec<Activation>, EngineError> {
-    // Calculate Design time (88 days solar arc before birth)
-    let design_time = calculate_design_time(*birth_time, Some(calculator.data_path()))
-        .map_err(|e| EngineError::CalculationError(format!("Design time calculation failed: {}", e)))?;
-    
-    let planet_positions = calculator.get_all_planets(&design_time)?;
+    let solution = design_solution(birth_time, calculator, DesignMethod::default())?;
+    design_activations_at(&solution.design_time, calculator)
+}
+
+/// Solve the Design time, mapping solver failures to a calculation error
+fn design_solution(
+    birth_time: &DateTime<Utc>,
+    calculator: &EphemerisCalculator,
+    method: DesignMethod,
+) -> Result<DesignTimeSolution, EngineError> {
+    solve_design_time_with_method(*birth_time, Some(calculator.data_path()), method)
+        .map_err(|e| EngineError::CalculationError(format!("Design time calculation failed: {}", e)))
+}
+
+fn design_activations_at(
+    design_time: &DateTime<Utc>,
+    calculator: &EphemerisCalculator,
+) -> Result<Vec<Activation>, EngineError> {
+    let planet_positions = calculator.get_all_planets(design_time)?;
     
     let activations = planet_positions
         .into_iter()
@@ -220,15 +234,35 @@ pub fn calculate_all_activations(
     birth_time: DateTime<Utc>,
     ephe_path: &str,
 ) -> Result<(Vec<Activation>, Vec<Activation>), String> {
+    calculate_chart_activations(birth_time, ephe_path)
+        .map(|(personality, design, _)| (personality, design))
+}
+
+/// Like [`calculate_all_activations`], also returning how the Design time was solved
+pub fn calculate_chart_activations(
+    birth_time: DateTime<Utc>,
+    ephe_path: &str,
+) -> Result<(Vec<Activation>, Vec<Activation>, DesignTimeSolution), String> {
+    calculate_chart_activations_with_method(birth_time, ephe_path, DesignMethod::default())
+}
+
+/// Like [`calculate_chart_activations`], placing the Design by `method`
+pub fn calculate_chart_activations_with_method(
+    birth_time: DateTime<Utc>,
+    ephe_path: &str,
+    method: DesignMethod,
+) -> Result<(Vec<Activation>, Vec<Activation>, DesignTimeSolution), String> {
     let calculator = EphemerisCalculator::new(ephe_path);
     
     let personality = calculate_personality_activations(&birth_time, &calculator)
         .map_err(|e| format!("Personality calculation failed: {}", e))?;
     
-    let design = calculate_design_activations(&birth_time, &calculator)
+    let solution = design_solution(&birth_time, &calculator, method)
+        .map_err(|e| format!("Design calculation failed: {}", e))?;
+    let design = design_activations_at(&solution.design_time, &calculator)
         .map_err(|e| format!("Design calculation failed: {}", e))?;
     
-    Ok((personality, design))
+    Ok((personality, design, solution))
 }
 
 #[cfg(test)]
@@ -8,7 +8,8 @@ use std::collections::HashMap;
 
 use crate::{
     models::{HDChart, HDType, Authority, Profile, Definition},
-    activations::calculate_all_activations,
+    activations::calculate_chart_activations_with_method,
+    design_time::DesignMethod,
     analysis::analyze_hd_chart,
 };
 
@@ -33,7 +34,20 @@ pub fn generate_hd_chart(
     birth_time: DateTime<Utc>,
     ephe_path: &str,
 ) -> Result<HDChart, String> {
-    let (personality, design) = calculate_all_activations(birth_time, ephe_path)?;
+    generate_hd_chart_with_method(birth_time, ephe_path, DesignMethod::default())
+}
+
+/// Like [`generate_hd_chart`], placing the Design by `method` to match charts
+/// from software that uses it
+pub fn generate_hd_chart_with_method(
+    birth_time: DateTime<Utc>,
+    ephe_path: &str,
+    method: DesignMethod,
+) -> Result<HDChart, String> {
+    // Refuse to chart rather than return one missing channels and definition
+    crate::wisdom_data::wisdom_integrity().map_err(|e| e.to_string())?;
+
+    let (personality, design, design_time) = calculate_chart_activations_with_method(birth_time, ephe_path, method)?;
     
     // Verify we got all 26 activations
     if personality.len() != 13 {
@@ -62,10 +76,13 @@ pub fn generate_hd_chart(
             unconscious_line: 1 
         },
         definition: Definition::Single,
+        design_time: Some(design_time),
+        warnings: Vec::new(),
     };
     
     // Perform complete chart analysis
     analyze_hd_chart(&mut chart)?;
+    chart.warnings = crate::warnings::chart_warnings(&chart);
     
     Ok(chart)
 }
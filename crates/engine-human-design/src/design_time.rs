@@ -1,30 +1,154 @@
 //! Design Time Calculation for Human Design
 //!
-//! The Design time is calculated using an 88-day solar arc — finding the exact moment
-//! approximately 88 days before birth when the Sun was at the same ecliptic longitude
-//! as at birth.
+//! The Design time is calculated using an 88° solar arc — finding the moment
+//! roughly 88 days before birth when the Sun stood 88° behind its birth
+//! longitude.
 //!
-//! This is NOT a simple 88-day subtraction, but requires iterative calculation using
-//! Swiss Ephemeris to find the precise moment when:
-//! sun_longitude(design_time) ≈ sun_longitude(birth_time)
+//! This is NOT a simple 88-day subtraction: the Sun's speed varies between
+//! ~0.95°/day (July) and ~1.02°/day (January), so the arc takes 86-93 days.
+//! The moment is found numerically:
+//!
+//! 1. A secant iteration on the arc residual from an 88-day estimate, which
+//!    usually converges in 3-5 ephemeris calls.
+//! 2. If that stalls or leaves the plausible window, a bracketing bisection
+//!    over 84-96 days before birth. The Sun is never retrograde, so the
+//!    residual changes sign exactly once in that window.
+//!
+//! Either way the result carries [`DesignTimeSolution`] diagnostics, and a
+//! residual above [`TOLERANCE_ARCSECONDS`] is an error rather than a chart.
+//!
+//! UTC is used as UT. The difference to UT1 (under 0.9 s) is far below the
+//! tolerance; a leap second (23:59:60) is read as 23:59:59.999.
 //!
 //! Accuracy requirement: within 1 hour of professional Human Design software.
+//!
+//! Some charting software instead places the Design exactly 88 days before
+//! birth; [`DesignMethod::Days88`] reproduces those charts, with the arc's
+//! deviation from 88° reported as the residual.
 
 use chrono::{DateTime, Utc, Duration, Timelike, Datelike, TimeZone};
+use serde::{Deserialize, Serialize};
 use swisseph::{Body, Seflg};
 use swisseph::swe;
 
-/// Error type for Design Time calculation
+/// Solar arc between Design and birth, in degrees
+pub const DESIGN_ARC_DEGREES: f64 = 88.0;
+
+/// Days between Design and birth under [`DesignMethod::Days88`]
+pub const DESIGN_DAYS: i64 = 88;
+
+/// Largest accepted residual of a solution, in arcseconds (~0.1 s of time)
+pub const TOLERANCE_ARCSECONDS: f64 = 0.005;
+
+/// Window searched by the bracketing solver, in days before birth
+const BRACKET_DAYS: (f64, f64) = (96.0, 84.0);
+
+const SECANT_MAX_ITERATIONS: usize = 12;
+const BISECTION_MAX_ITERATIONS: usize = 64;
+
+/// Why the Design time could not be found
+///
+/// All variants mean no chart should be produced; none is retried internally
+/// beyond the fallback solver.
 #[derive(Debug, thiserror::Error)]
 pub enum DesignTimeError {
+    /// Swiss Ephemeris could not position the Sun, e.g. a date outside the
+    /// range of the installed data files.
     #[error("Swiss Ephemeris error: {0}")]
     SwissEphemerisError(String),
-    #[error("Convergence error: could not find design time within tolerance after {0} iterations")]
-    ConvergenceError(usize),
+    /// The arc residual did not change sign in the bracketing window, so the
+    /// ephemeris returned inconsistent positions.
+    #[error("Design time not bracketed between {earliest_days} and {latest_days} days before birth")]
+    NotBracketed { earliest_days: f64, latest_days: f64 },
+    /// Both solvers stopped with a residual above [`TOLERANCE_ARCSECONDS`].
+    #[error("Convergence error: residual {residual_arcseconds:.4}\" after {iterations} iterations")]
+    ConvergenceError { iterations: usize, residual_arcseconds: f64 },
+    /// The birth time cannot be converted to a Julian Day, or the ephemeris
+    /// returned a non-finite longitude for it.
     #[error("Invalid input: {0}")]
     InvalidInput(String),
 }
 
+/// How the Design moment is placed before birth
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum DesignMethod {
+    /// When the Sun stood [`DESIGN_ARC_DEGREES`] behind its birth longitude
+    #[default]
+    #[serde(rename = "arc_88deg")]
+    Arc88Deg,
+    /// Exactly [`DESIGN_DAYS`] days before birth
+    #[serde(rename = "days_88")]
+    Days88,
+}
+
+impl DesignMethod {
+    /// Parse an option value ("arc_88deg" or "days_88")
+    pub fn parse(name: &str) -> Option<Self> {
+        match name.trim().to_ascii_lowercase().replace('-', "_").as_str() {
+            "arc_88deg" => Some(DesignMethod::Arc88Deg),
+            "days_88" => Some(DesignMethod::Days88),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            DesignMethod::Arc88Deg => "arc_88deg",
+            DesignMethod::Days88 => "days_88",
+        }
+    }
+}
+
+/// Which solver produced a [`DesignTimeSolution`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DesignTimeSolver {
+    Secant,
+    /// Fallback after the secant iteration failed
+    Bisection,
+    /// Nothing to solve: [`DesignMethod::Days88`] subtracts a fixed interval
+    Fixed,
+}
+
+/// A Design time with its convergence diagnostics
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DesignTimeSolution {
+    pub design_time: DateTime<Utc>,
+    /// Method the Design time was placed by
+    #[serde(default)]
+    pub method: DesignMethod,
+    pub solver: DesignTimeSolver,
+    /// Ephemeris evaluations across both solvers
+    pub iterations: usize,
+    /// Remaining difference from the exact 88° arc, in arcseconds. Under
+    /// [`DesignMethod::Days88`] this is how far the 88 days are off the arc.
+    pub residual_arcseconds: f64,
+    /// How the solvers got there; only reported in debug output
+    #[serde(skip)]
+    pub trace: DesignTimeTrace,
+}
+
+/// The ephemeris evaluations behind a [`DesignTimeSolution`]
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct DesignTimeTrace {
+    pub birth_julian_day: f64,
+    /// Sun's longitude at birth, in degrees
+    pub birth_sun_longitude: f64,
+    /// Longitude the Sun stands at at the Design moment, in degrees
+    pub target_longitude: f64,
+    /// Every evaluation of both solvers, in order
+    pub steps: Vec<DesignTimeStep>,
+}
+
+/// One evaluation of the arc residual
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct DesignTimeStep {
+    pub julian_day: f64,
+    pub sun_longitude: f64,
+    /// Degrees the Sun is past the target longitude
+    pub residual_degrees: f64,
+}
+
 /// Initialize Swiss Ephemeris with the given data path
 ///
 /// # Arguments
@@ -34,11 +158,13 @@ pub fn initialize_ephemeris(ephe_path: &str) {
 }
 
 /// Calculate the Julian Day for a given DateTime<Utc>
-fn datetime_to_julian_day(dt: &DateTime<Utc>) -> f64 {
+pub(crate) fn datetime_to_julian_day(dt: &DateTime<Utc>) -> f64 {
     let year = dt.year();
     let month = dt.month() as i32;
     let day = dt.day() as i32;
-    let hour = dt.hour() as f64 + (dt.minute() as f64 / 60.0) + (dt.second() as f64 / 3600.0);
+    // chrono encodes a leap second as nanoseconds >= 1e9; clamp it into the minute
+    let nanos = dt.nanosecond().min(999_999_999) as f64 / 1e9;
+    let hour = dt.hour() as f64 + (dt.minute() as f64 / 60.0) + ((dt.second() as f64 + nanos) / 3600.0);
     
     // gregorian flag = 1 for Gregorian calendar
     swe::julday(year, month, day, hour, 1)
@@ -46,13 +172,11 @@ fn datetime_to_julian_day(dt: &DateTime<Utc>) -> f64 {
 
 /// Convert Julian Day back to DateTime<Utc>
 fn julian_day_to_datetime(jd: f64) -> DateTime<Utc> {
-    // Simplified conversion (could be more precise with swe::revjul)
-    // For now, use a baseline and add days
     let j2000 = 2451545.0; // JD for 2000-01-01 12:00:00
     let days_since_j2000 = jd - j2000;
     
     let base = Utc.with_ymd_and_hms(2000, 1, 1, 12, 0, 0).unwrap();
-    let duration = Duration::milliseconds((days_since_j2000 * 86400.0 * 1000.0) as i64);
+    let duration = Duration::milliseconds((days_since_j2000 * 86400.0 * 1000.0).round() as i64);
     
     base + duration
 }
@@ -70,10 +194,11 @@ fn calculate_sun_longitude(jd: f64) -> Result<f64, DesignTimeError> {
     // calc_ut returns Result<Out<[f64; 6], i32>, String>
     // where Out has fields: out (the array) and code (status code)
     match swe::calc_ut(jd, Body::Sun as u32, flags.into()) {
-        Ok(result) => {
-            // result.out[0] contains the ecliptic longitude
-            Ok(result.out[0])
-        }
+        Ok(result) if result.out[0].is_finite() => Ok(result.out[0]),
+        Ok(_) => Err(DesignTimeError::InvalidInput(format!(
+            "Non-finite Sun longitude at JD {}",
+            jd
+        ))),
         Err(e) => {
             Err(DesignTimeError::SwissEphemerisError(e))
         }
@@ -82,8 +207,7 @@ fn calculate_sun_longitude(jd: f64) -> Result<f64, DesignTimeError> {
 
 /// Calculate the Design Time for a given birth time
 ///
-/// Uses iterative refinement to find the moment approximately 88 days before birth
-/// when the Sun was at the same longitude as at birth.
+/// See [`solve_design_time`] for the algorithm and diagnostics.
 ///
 /// # Arguments
 /// * `birth_time` - Birth time in UTC
@@ -91,94 +215,163 @@ fn calculate_sun_longitude(jd: f64) -> Result<f64, DesignTimeError> {
 ///
 /// # Returns
 /// Design time (approximately 88 days before birth), or error
-///
-/// # Algorithm
-/// 1. Calculate Sun's longitude at birth
-/// 2. Start with initial estimate: birth_time - 88 days
-/// 3. Iteratively refine until Sun's longitude matches birth longitude
-/// 4. Use binary search for convergence
 pub fn calculate_design_time(
     birth_time: DateTime<Utc>,
     ephe_path: Option<&str>,
 ) -> Result<DateTime<Utc>, DesignTimeError> {
+    solve_design_time(birth_time, ephe_path).map(|solution| solution.design_time)
+}
+
+/// Find the Design time for `birth_time`, reporting how it was found
+pub fn solve_design_time(
+    birth_time: DateTime<Utc>,
+    ephe_path: Option<&str>,
+) -> Result<DesignTimeSolution, DesignTimeError> {
+    solve_design_time_with_method(birth_time, ephe_path, DesignMethod::default())
+}
+
+/// Like [`solve_design_time`], placing the Design by `method`
+pub fn solve_design_time_with_method(
+    birth_time: DateTime<Utc>,
+    ephe_path: Option<&str>,
+    method: DesignMethod,
+) -> Result<DesignTimeSolution, DesignTimeError> {
     // Initialize ephemeris if path provided
     if let Some(path) = ephe_path {
         initialize_ephemeris(path);
     }
-    
-    // Calculate Sun's longitude at birth
+
     let birth_jd = datetime_to_julian_day(&birth_time);
+    if !birth_jd.is_finite() {
+        return Err(DesignTimeError::InvalidInput(format!("Birth time {} has no Julian Day", birth_time)));
+    }
     let birth_longitude = calculate_sun_longitude(birth_jd)?;
-    
-    // Design time is when the Sun was 88 DEGREES behind the birth position
-    // This is NOT the same as 88 days - it's based on solar arc
-    // Target longitude = birth_longitude - 88° (going backwards in the zodiac)
-    let target_longitude = (birth_longitude - 88.0).rem_euclid(360.0);
-    
-    // Initial estimate: ~88-92 days before birth (Sun moves ~1° per day)
-    let initial_estimate = birth_time - Duration::days(88);
-    
-    // Perform iterative refinement using binary search
-    let design_time = refine_design_time(initial_estimate, target_longitude)?;
-    
-    Ok(design_time)
+
+    // Target longitude = birth longitude - 88° (going backwards in the zodiac)
+    let target_longitude = (birth_longitude - DESIGN_ARC_DEGREES).rem_euclid(360.0);
+    let mut residual = ArcResidual {
+        trace: DesignTimeTrace {
+            birth_julian_day: birth_jd,
+            birth_sun_longitude: birth_longitude,
+            target_longitude,
+            steps: Vec::new(),
+        },
+    };
+
+    if method == DesignMethod::Days88 {
+        let design_time = birth_time - Duration::days(DESIGN_DAYS);
+        let residual_arcseconds = residual.at(datetime_to_julian_day(&design_time))?.abs() * 3600.0;
+        return Ok(DesignTimeSolution {
+            design_time,
+            method,
+            solver: DesignTimeSolver::Fixed,
+            iterations: residual.evaluations(),
+            residual_arcseconds,
+            trace: residual.trace,
+        });
+    }
+
+    let (jd, solver) = match secant(&mut residual, birth_jd)? {
+        Some(jd) => (jd, DesignTimeSolver::Secant),
+        None => (bisect(&mut residual, birth_jd)?, DesignTimeSolver::Bisection),
+    };
+
+    let residual_arcseconds = residual.at(jd)?.abs() * 3600.0;
+    if residual_arcseconds > TOLERANCE_ARCSECONDS {
+        return Err(DesignTimeError::ConvergenceError {
+            iterations: residual.evaluations(),
+            residual_arcseconds,
+        });
+    }
+
+    Ok(DesignTimeSolution {
+        design_time: julian_day_to_datetime(jd),
+        method,
+        solver,
+        iterations: residual.evaluations(),
+        residual_arcseconds,
+        trace: residual.trace,
+    })
 }
 
-/// Refine the design time estimate using iterative binary search
-///
-/// # Arguments
-/// * `initial_estimate` - Initial estimate of design time (birth - 88 days)
-/// * `target_longitude` - Target Sun longitude (from birth time)
+/// Signed distance of the Sun from the target longitude, recording evaluations
+struct ArcResidual {
+    trace: DesignTimeTrace,
+}
+
+impl ArcResidual {
+    /// Degrees the Sun is past the target at `jd`; increases with time
+    fn at(&mut self, jd: f64) -> Result<f64, DesignTimeError> {
+        let sun_longitude = calculate_sun_longitude(jd)?;
+        let residual_degrees = longitude_difference(sun_longitude, self.trace.target_longitude);
+        self.trace.steps.push(DesignTimeStep { julian_day: jd, sun_longitude, residual_degrees });
+        Ok(residual_degrees)
+    }
+
+    fn evaluations(&self) -> usize {
+        self.trace.steps.len()
+    }
+}
+
+/// Secant iteration from the 88-day estimate
 ///
-/// # Returns
-/// Refined design time, or error if convergence fails
-fn refine_design_time(
-    initial_estimate: DateTime<Utc>,
-    target_longitude: f64,
-) -> Result<DateTime<Utc>, DesignTimeError> {
-    const MAX_ITERATIONS: usize = 50;
-    const TOLERANCE_DEGREES: f64 = 0.001; // ~3.6 arcseconds (~0.24 seconds of time)
-    const TOLERANCE_SECONDS: i64 = 3600; // 1 hour in seconds
-    
-    // Search bounds: ±3 days from initial estimate
-    let mut lower_bound = initial_estimate - Duration::days(3);
-    let mut upper_bound = initial_estimate + Duration::days(3);
-    
-    for _iteration in 0..MAX_ITERATIONS {
-        // Try the midpoint
-        let midpoint = lower_bound + (upper_bound - lower_bound) / 2;
-        let midpoint_jd = datetime_to_julian_day(&midpoint);
-        let midpoint_longitude = calculate_sun_longitude(midpoint_jd)?;
-        
-        // Calculate longitude difference (accounting for 360° wrap)
-        let diff = longitude_difference(midpoint_longitude, target_longitude);
-        
-        // Check convergence
-        if diff.abs() < TOLERANCE_DEGREES {
-            // Additional check: ensure we're within 1 hour
-            let time_diff = (midpoint - initial_estimate).num_seconds().abs();
-            if time_diff < TOLERANCE_SECONDS * 24 * 3 { // Within ±3 days is reasonable
-                return Ok(midpoint);
-            }
+/// Returns `None` when it stalls or leaves the bracketing window, so the
+/// caller falls back to bisection.
+fn secant(residual: &mut ArcResidual, birth_jd: f64) -> Result<Option<f64>, DesignTimeError> {
+    let tolerance_degrees = TOLERANCE_ARCSECONDS / 3600.0 / 2.0;
+    let (earliest, latest) = (birth_jd - BRACKET_DAYS.0, birth_jd - BRACKET_DAYS.1);
+
+    let mut x0 = birth_jd - DESIGN_ARC_DEGREES;
+    let mut f0 = residual.at(x0)?;
+    // Mean solar speed is ~0.9856°/day
+    let mut x1 = x0 - f0 / 0.9856;
+    for _ in 0..SECANT_MAX_ITERATIONS {
+        if !(earliest..=latest).contains(&x1) {
+            return Ok(None);
+        }
+        let f1 = residual.at(x1)?;
+        if f1.abs() < tolerance_degrees {
+            return Ok(Some(x1));
+        }
+        let slope = (f1 - f0) / (x1 - x0);
+        // The Sun never moves backwards; any other slope means a bad step
+        if !(0.5..=1.5).contains(&slope) {
+            return Ok(None);
+        }
+        (x0, f0) = (x1, f1);
+        x1 -= f1 / slope;
+    }
+    Ok(None)
+}
+
+/// Bisection over the bracketing window
+fn bisect(residual: &mut ArcResidual, birth_jd: f64) -> Result<f64, DesignTimeError> {
+    let tolerance_degrees = TOLERANCE_ARCSECONDS / 3600.0 / 2.0;
+    let mut lower = birth_jd - BRACKET_DAYS.0;
+    let mut upper = birth_jd - BRACKET_DAYS.1;
+
+    if residual.at(lower)? >= 0.0 || residual.at(upper)? <= 0.0 {
+        return Err(DesignTimeError::NotBracketed {
+            earliest_days: BRACKET_DAYS.0,
+            latest_days: BRACKET_DAYS.1,
+        });
+    }
+
+    let mut midpoint = lower + (upper - lower) / 2.0;
+    for _ in 0..BISECTION_MAX_ITERATIONS {
+        midpoint = lower + (upper - lower) / 2.0;
+        let diff = residual.at(midpoint)?;
+        if diff.abs() < tolerance_degrees {
+            break;
         }
-        
-        // Adjust search bounds
-        // Sun moves ~1° per day, so if we're ahead in longitude, we need to go earlier in time
         if diff > 0.0 {
-            // Current longitude is ahead of target, need to go earlier
-            upper_bound = midpoint;
+            // Sun is past the target, so the Design time is earlier
+            upper = midpoint;
         } else {
-            // Current longitude is behind target, need to go later
-            lower_bound = midpoint;
-        }
-        
-        // Check if bounds are too close (converged)
-        if (upper_bound - lower_bound).num_seconds() < 60 {
-            return Ok(midpoint);
+            lower = midpoint;
         }
     }
-    
-    Err(DesignTimeError::ConvergenceError(MAX_ITERATIONS))
+    Ok(midpoint)
 }
 
 /// Calculate the shortest angular distance between two longitudes
@@ -277,4 +470,67 @@ mod tests {
         let diff = (original - converted).num_seconds().abs();
         assert!(diff <= 1, "Roundtrip conversion failed: {} seconds difference", diff);
     }
+
+    #[test]
+    fn test_leap_second_julian_day() {
+        let before = Utc.with_ymd_and_hms(2016, 12, 31, 23, 59, 59).unwrap();
+        let leap = before.with_nanosecond(1_500_000_000).unwrap();
+        let jd_before = datetime_to_julian_day(&before);
+        let jd_leap = datetime_to_julian_day(&leap);
+
+        // Held at the end of 23:59:59 rather than spilling into the next minute
+        let seconds = (jd_leap - jd_before) * 86400.0;
+        assert!((seconds - 1.0).abs() < 0.001, "leap second moved JD by {}s", seconds);
+    }
+
+    #[test]
+    fn test_solve_design_time_across_the_year() {
+        // September births need more than 91 days of arc, outside the old fixed window
+        for month in 1..=12 {
+            let birth_time = Utc.with_ymd_and_hms(1990, month, 20, 6, 0, 0).unwrap();
+            let solution = solve_design_time(birth_time, None).unwrap();
+
+            assert!(solution.residual_arcseconds <= TOLERANCE_ARCSECONDS, "{:?}", solution);
+            assert_eq!(solution.trace.steps.len(), solution.iterations);
+            let last = solution.trace.steps.last().unwrap();
+            assert!(last.residual_degrees.abs() * 3600.0 <= TOLERANCE_ARCSECONDS);
+            let days = (birth_time - solution.design_time).num_hours() as f64 / 24.0;
+            assert!((85.0..=93.0).contains(&days), "{} days for month {}", days, month);
+        }
+    }
+
+    #[test]
+    fn test_days_88_subtracts_exactly() {
+        let birth_time = Utc.with_ymd_and_hms(1985, 9, 10, 3, 15, 0).unwrap();
+        let solution = solve_design_time_with_method(birth_time, None, DesignMethod::Days88).unwrap();
+
+        assert_eq!(solution.design_time, Utc.with_ymd_and_hms(1985, 6, 14, 3, 15, 0).unwrap());
+        assert_eq!(solution.method, DesignMethod::Days88);
+        assert_eq!(solution.solver, DesignTimeSolver::Fixed);
+        // A September arc takes ~92 days, so 88 days falls well short of it
+        assert!(solution.residual_arcseconds > 3600.0, "{:?}", solution);
+    }
+
+    #[test]
+    fn test_design_method_names() {
+        for method in [DesignMethod::Arc88Deg, DesignMethod::Days88] {
+            assert_eq!(DesignMethod::parse(method.as_str()), Some(method));
+            assert_eq!(serde_json::to_value(method).unwrap(), method.as_str());
+        }
+        assert_eq!(DesignMethod::parse("88_days"), None);
+    }
+
+    #[test]
+    fn test_bisection_matches_secant() {
+        let birth_time = Utc.with_ymd_and_hms(1985, 9, 10, 3, 15, 0).unwrap();
+        let birth_jd = datetime_to_julian_day(&birth_time);
+        let target = (calculate_sun_longitude(birth_jd).unwrap() - DESIGN_ARC_DEGREES).rem_euclid(360.0);
+        let mut residual = ArcResidual {
+            trace: DesignTimeTrace { target_longitude: target, ..DesignTimeTrace::default() },
+        };
+
+        let secant_jd = secant(&mut residual, birth_jd).unwrap().unwrap();
+        let bisect_jd = bisect(&mut residual, birth_jd).unwrap();
+        assert!((secant_jd - bisect_jd).abs() * 86400.0 < 1.0);
+    }
 }
@@ -4,15 +4,24 @@
 
 use async_trait::async_trait;
 use chrono::{NaiveDate, NaiveTime, TimeZone, Utc};
+use noesis_core::birth_time::{TimeStability, TIME_STABILITY_KEY};
+use noesis_core::description::EngineDescription;
+use noesis_core::examples::{self, EngineExample};
+use noesis_core::options::HumanDesignOptions;
 use noesis_core::{
-    ConsciousnessEngine, EngineError, EngineInput, EngineOutput, ValidationResult,
-    CalculationMetadata,
+    CacheTtl, ConsciousnessEngine, EngineError, EngineInput, TypedOptions, EngineOutput, ValidationResult,
+    CalculationMetadata, TimeSensitivity, ValidationCode, DEBUG_KEY,
 };
+use noesis_core::context;
+use noesis_core::timezone::{self, ResolvedOffset, RESOLUTION_KEY};
 use serde_json::json;
 use std::time::Instant;
 
+use crate::design_time::datetime_to_julian_day;
+use crate::rectification::{rectify, Rectification, MAX_WINDOW_MINUTES};
 use crate::{
-    generate_hd_chart, initialize_ephemeris, witness::generate_witness_prompt, HDChart,
+    generate_hd_chart_with_method, initialize_ephemeris, witness::generate_witness_prompt, Authority, Center,
+    DesignMethod, HDChart, HDType,
 };
 
 /// Human Design consciousness engine implementing the universal trait
@@ -31,40 +40,57 @@ impl HumanDesignEngine {
     }
 
     /// Convert EngineInput to HD calculation parameters
-    fn extract_birth_params(input: &EngineInput) -> Result<(NaiveDate, NaiveTime, String, f64, f64), EngineError> {
+    fn extract_birth_params(input: &EngineInput) -> Result<(NaiveDate, NaiveTime, ResolvedOffset, f64, f64), EngineError> {
         let birth_data = input
-            .birth_data
-            .as_ref()
+            .primary_birth_data()
             .ok_or_else(|| EngineError::ValidationError("birth_data required for Human Design".to_string()))?;
+        birth_data.validate()?;
 
         // Parse date
         let date = NaiveDate::parse_from_str(&birth_data.date, "%Y-%m-%d")
             .map_err(|e| EngineError::ValidationError(format!("Invalid date format: {}", e)))?;
 
-        // Parse time
-        let time_str = birth_data
-            .time
-            .as_ref()
-            .ok_or_else(|| EngineError::ValidationError("birth_time required for Human Design".to_string()))?;
-        
+        // Parse time; an unknown one has to be declared, since the chart
+        // turns on it
+        if birth_data.time.is_none() && !birth_data.time_unknown {
+            return Err(EngineError::invalid_field(
+                "birth_data.time",
+                ValidationCode::Required,
+                "birth_time required for Human Design; set birth_data.time_unknown for a noon chart with its stability",
+            ));
+        }
+        let time_str = birth_data.time_or_stand_in();
+
         let time = NaiveTime::parse_from_str(time_str, "%H:%M")
             .or_else(|_| NaiveTime::parse_from_str(time_str, "%H:%M:%S"))
             .map_err(|e| EngineError::ValidationError(format!("Invalid time format: {}", e)))?;
 
-        let timezone = birth_data.timezone.clone();
+        // Historical offset in force at the birth moment (war time, LMT, DST)
+        let offset = timezone::resolve_offset(
+            &birth_data.timezone,
+            birth_data.utc_offset_minutes,
+            date,
+            Some(time),
+        )?;
         let latitude = birth_data.latitude;
         let longitude = birth_data.longitude;
 
-        Ok((date, time, timezone, latitude, longitude))
+        Ok((date, time, offset, latitude, longitude))
     }
 
     /// Serialize HDChart to JSON value
     fn serialize_chart(chart: &HDChart) -> serde_json::Value {
-        // Extract defined centers as an array
-        let defined_centers: Vec<String> = chart.centers
+        // Extract defined centers as an array, top of the bodygraph first
+        // (HashMap order would make identical charts serialize differently)
+        let mut centers: Vec<Center> = chart.centers
             .iter()
             .filter(|(_, state)| state.defined)
-            .map(|(center, _)| format!("{:?}", center))
+            .map(|(center, _)| *center)
+            .collect();
+        centers.sort_by_key(|center| *center as u8);
+        let defined_centers: Vec<String> = centers
+            .iter()
+            .map(|center| format!("{:?}", center))
             .collect();
         
         // Format channels as "gate1-gate2" strings
@@ -100,7 +126,7 @@ impl HumanDesignEngine {
             })
             .collect();
         
-        json!({
+        let mut result = json!({
             "hd_type": format!("{:?}", chart.hd_type),
             "authority": format!("{:?}", chart.authority),
             "profile": format!("{}/{}", chart.profile.conscious_line, chart.profile.unconscious_line),
@@ -109,16 +135,156 @@ impl HumanDesignEngine {
             "active_channels": active_channels,
             "personality_activations": personality_activations,
             "design_activations": design_activations,
+        });
+        // Solver diagnostics, so a Design time is never trusted blindly
+        if let Some(solution) = &chart.design_time {
+            result["design_time"] = json!(solution);
+        }
+        // Always present, so clients can check it without a key lookup
+        result["warnings"] = json!(chart.warnings);
+        result
+    }
+
+    /// The Design time solver's ephemeris evaluations, for `options.debug`.
+    /// The activations' raw longitudes are in the result already.
+    fn debug_values(chart: &HDChart) -> serde_json::Value {
+        let Some(solution) = &chart.design_time else {
+            return json!({});
+        };
+        json!({
+            "birth_julian_day": solution.trace.birth_julian_day,
+            "birth_sun_longitude": solution.trace.birth_sun_longitude,
+            "target_longitude": solution.trace.target_longitude,
+            "design_julian_day": datetime_to_julian_day(&solution.design_time),
+            "design_time_steps": solution.trace.steps,
         })
     }
 }
 
+/// Read the `design_method` option; absent means the 88° arc
+fn design_method(options: &HumanDesignOptions) -> Result<DesignMethod, EngineError> {
+    let Some(name) = options.design_method.as_deref() else {
+        return Ok(DesignMethod::default());
+    };
+    DesignMethod::parse(name).ok_or_else(|| {
+        EngineError::invalid_field(
+            "options.design_method",
+            ValidationCode::Unknown,
+            format!("Unknown design_method '{}'. Expected arc_88deg or days_88.", name),
+        )
+    })
+}
+
+/// Read the `rectify_window_minutes` option; absent means no rectification
+fn rectify_window(options: &HumanDesignOptions) -> Result<Option<u32>, EngineError> {
+    match options.rectify_window_minutes {
+        Some(minutes) if minutes == 0 || minutes > MAX_WINDOW_MINUTES => Err(EngineError::invalid_field(
+            "options.rectify_window_minutes",
+            ValidationCode::OutOfRange,
+            format!("rectify_window_minutes must be between 1 and {}", MAX_WINDOW_MINUTES),
+        )),
+        window => Ok(window),
+    }
+}
+
+/// Describe type, strategy, authority and profile in plain language
+fn generate_summary(chart: &HDChart) -> String {
+    let (hd_type, strategy) = match chart.hd_type {
+        HDType::Generator => ("Generator", "wait to respond"),
+        HDType::ManifestingGenerator => ("Manifesting Generator", "respond, then inform"),
+        HDType::Projector => ("Projector", "wait for the invitation"),
+        HDType::Manifestor => ("Manifestor", "inform before acting"),
+        HDType::Reflector => ("Reflector", "wait a lunar cycle"),
+    };
+    let authority = match chart.authority {
+        Authority::Sacral => "your gut response in the moment",
+        Authority::Emotional => "riding your emotional wave to clarity",
+        Authority::Splenic => "your spontaneous intuition",
+        Authority::Heart => "what your will truly wants",
+        Authority::GCenter => "your sense of identity and direction",
+        Authority::Mental => "talking decisions through with others",
+        Authority::Lunar => "a full lunar cycle of reflection",
+    };
+    format!(
+        "You are a {} with a {}/{} profile, and your strategy is to {}. \
+         Decisions go best when you rely on {}.",
+        hd_type, chart.profile.conscious_line, chart.profile.unconscious_line, strategy, authority,
+    )
+}
+
 impl Default for HumanDesignEngine {
     fn default() -> Self {
         Self::new()
     }
 }
 
+impl HumanDesignEngine {
+    /// The chart [`calculate`](ConsciousnessEngine::calculate) reports, for
+    /// engines that build on Human Design
+    pub fn chart(&self, input: &EngineInput) -> Result<HDChart, EngineError> {
+        Self::chart_with_offset(input).map(|(chart, _)| chart)
+    }
+
+    /// Chart for the input's birth data, with the UTC offset its local time
+    /// was resolved with
+    fn chart_with_offset(input: &EngineInput) -> Result<(HDChart, ResolvedOffset), EngineError> {
+        // Extract birth parameters from input
+        let (date, time, offset, latitude, longitude) = Self::extract_birth_params(input)?;
+        let options = input.engine_options::<HumanDesignOptions>()?;
+        let method = design_method(&options)?;
+
+        // Initialize ephemeris (idempotent operation)
+        initialize_ephemeris("");
+
+        // Create naive datetime and convert to UTC
+        let naive_dt = date.and_time(time);
+        let utc_dt = Utc.from_utc_datetime(&offset.to_utc(naive_dt));
+
+        // Generate HD chart, or reuse the one Gene Keys or another engine of
+        // the workflow already generated
+        let key = format!("human-design:chart:{}:{}", utc_dt.to_rfc3339(), method.as_str());
+        let mut chart = context::shared(&key, || generate_hd_chart_with_method(utc_dt, "", method))
+            .map_err(|e| EngineError::CalculationError(format!("Chart generation failed: {}", e)))?;
+        // A declared unknown time is reported as such, not as a suspicious 12:00
+        let time_unknown = input.primary_birth_data().is_some_and(|birth| birth.time_is_unknown());
+        let mut warnings = if time_unknown {
+            Vec::new()
+        } else {
+            crate::warnings::birth_time_warnings(time, offset.warning())
+        };
+        warnings.append(&mut chart.warnings);
+        chart.warnings = warnings;
+        Ok((chart, offset))
+    }
+
+    /// How a noon chart holds across the birth day, from a rectification
+    /// of the whole day. The Moon changes gate several times a day, so the
+    /// activations always vary.
+    fn time_stability(input: &EngineInput, method: DesignMethod) -> Result<TimeStability, EngineError> {
+        let day = Self::rectification(input, MAX_WINDOW_MINUTES, method)?;
+        let mut varies: Vec<String> = Vec::new();
+        for breakpoint in &day.breakpoints {
+            for field in &breakpoint.changed {
+                if !varies.contains(field) {
+                    varies.push(field.clone());
+                }
+            }
+        }
+        varies.extend(["personality_activations".to_string(), "design_activations".to_string()]);
+        Ok(TimeStability::at_noon(day.stability, varies))
+    }
+
+    /// How the chart varies across `window_minutes` either side of the
+    /// input's birth time
+    fn rectification(input: &EngineInput, window_minutes: u32, method: DesignMethod) -> Result<Rectification, EngineError> {
+        let (date, time, offset, _, _) = Self::extract_birth_params(input)?;
+        let local = date.and_time(time);
+        let utc = Utc.from_utc_datetime(&offset.to_utc(local));
+        rectify(utc, local, window_minutes, method)
+            .map_err(|e| EngineError::CalculationError(format!("Rectification failed: {}", e)))
+    }
+}
+
 #[async_trait]
 impl ConsciousnessEngine for HumanDesignEngine {
     fn engine_id(&self) -> &str {
@@ -129,43 +295,66 @@ impl ConsciousnessEngine for HumanDesignEngine {
         &self.engine_name
     }
 
+    fn engine_version(&self) -> &str {
+        "2" // Completed channel and gate wisdom data
+    }
+
     fn required_phase(&self) -> u8 {
         1 // Basic consciousness required for HD
     }
 
-    async fn calculate(&self, input: EngineInput) -> Result<EngineOutput, EngineError> {
-        let start = Instant::now();
+    fn option_keys(&self) -> Option<&'static [&'static str]> {
+        Some(HumanDesignOptions::keys())
+    }
 
-        // Extract birth parameters from input
-        let (date, time, timezone_str, latitude, longitude) = Self::extract_birth_params(&input)?;
+    fn birth_time_sensitivity(&self) -> TimeSensitivity {
+        TimeSensitivity::Decisive
+    }
 
-        // Initialize ephemeris (idempotent operation)
-        initialize_ephemeris("");
+    fn check_input(&self, input: &EngineInput) -> Result<(), EngineError> {
+        Self::extract_birth_params(input)?;
+        let options = input.engine_options::<HumanDesignOptions>()?;
+        design_method(&options)?;
+        rectify_window(&options).map(|_| ())
+    }
+
+    fn examples(&self) -> Vec<EngineExample> {
+        vec![
+            examples::birth_data_example(),
+            EngineExample::new(
+                "rectification",
+                "Chart with the changes across a birth time uncertain by two hours",
+                examples::sample_input(json!({ "rectify_window_minutes": 120 })),
+            ),
+        ]
+    }
 
-        // Parse timezone
-        let tz: chrono_tz::Tz = timezone_str
-            .parse()
-            .map_err(|e| EngineError::ValidationError(format!("Invalid timezone: {}", e)))?;
+    fn describe(&self) -> EngineDescription {
+        EngineDescription::new(
+            "Builds a Human Design chart from the planets at birth and at the Design moment, about 88 \
+             days before: type, strategy, authority, profile, definition, defined centers, channels and \
+             the gate of every activation.",
+        )
+        .with_input("birth_data.date", true, "Birth date")
+        .with_input("birth_data.time", true, "Birth time; a chart read without it is flagged unstable")
+        .with_input("birth_data.latitude", true, "Latitude of the birth place")
+        .with_input("birth_data.longitude", true, "Longitude of the birth place")
+        .with_input("birth_data.timezone", true, "IANA timezone of the birth place")
+        .with_input("options.design_method", false, "88° solar arc (default) or 88 days")
+        .with_input("options.rectify_window_minutes", false, "Birth time uncertainty to scan for chart changes")
+        .with_outputs("Type, authority, profile, definition, centers, channels, personality and design activations")
+        .with_traditions(&["Human Design System", "I Ching", "Western astrology", "Kabbalah", "Chakra system"])
+        .with_limitation("Type, authority and profile can change within minutes of birth time")
+        .with_limitation("Needs the Swiss Ephemeris data files on the server")
+    }
 
-        // Create naive datetime and convert to UTC
-        let naive_dt = date.and_time(time);
-        let local_dt = tz
-            .from_local_datetime(&naive_dt)
-            .single()
-            .ok_or_else(|| EngineError::ValidationError("Ambiguous local time".to_string()))?;
-        let utc_dt = local_dt.with_timezone(&Utc);
-
-        // Generate HD chart
-        let chart = generate_hd_chart(utc_dt, "")
-            .map_err(|e| EngineError::CalculationError(format!("Chart generation failed: {}", e)))?;
+    async fn calculate(&self, input: EngineInput) -> Result<EngineOutput, EngineError> {
+        let start = Instant::now();
 
-        // Get consciousness level from input options or default to 1
-        let consciousness_level = input
-            .options
-            .get("consciousness_level")
-            .and_then(|v| v.as_u64())
-            .map(|v| v as u8)
-            .unwrap_or(1);
+        let (chart, offset) = Self::chart_with_offset(&input)?;
+        let options = input.engine_options::<HumanDesignOptions>()?;
+        let consciousness_level = options.consciousness_level.unwrap_or(1);
+        let window = rectify_window(&options)?;
 
         // Generate witness prompt
         let witness_prompt = generate_witness_prompt(&chart, consciousness_level);
@@ -177,12 +366,27 @@ impl ConsciousnessEngine for HumanDesignEngine {
             ));
         }
 
+        let summary = generate_summary(&chart);
+        let mut result = Self::serialize_chart(&chart);
+        result[RESOLUTION_KEY] = json!(offset);
+        if let Some(window_minutes) = window {
+            let rectification = Self::rectification(&input, window_minutes, design_method(&options)?)?;
+            result["rectification"] = json!(rectification);
+        }
+        if input.primary_birth_data().is_some_and(|birth| birth.time_is_unknown()) {
+            result[TIME_STABILITY_KEY] = json!(Self::time_stability(&input, design_method(&options)?)?);
+        }
+        if input.debug() {
+            result[DEBUG_KEY] = Self::debug_values(&chart);
+        }
+
         let elapsed = start.elapsed();
 
         Ok(EngineOutput {
             engine_id: self.engine_id.clone(),
-            result: Self::serialize_chart(&chart),
+            result,
             witness_prompt,
+            summary: Some(summary),
             consciousness_level,
             metadata: CalculationMetadata {
                 calculation_time_ms: elapsed.as_secs_f64() * 1000.0,
@@ -190,6 +394,9 @@ impl ConsciousnessEngine for HumanDesignEngine {
                 precision_achieved: format!("{:?}", input.precision),
                 cached: false,
                 timestamp: Utc::now(),
+                engine_version: None,
+                warnings: Vec::new(),
+                usage: None,
             },
         })
     }
@@ -226,6 +433,9 @@ impl ConsciousnessEngine for HumanDesignEngine {
             valid = false;
         }
 
+        // An ambiguous birth offset is a warning, not a failure
+        messages.extend(timezone::resolution_warnings(&output.result));
+
         let confidence = if valid { 1.0 } else { 0.0 };
 
         Ok(ValidationResult {
@@ -235,20 +445,46 @@ impl ConsciousnessEngine for HumanDesignEngine {
         })
     }
 
+    /// Fails when the Swiss Ephemeris data files cannot be found or the
+    /// wisdom data charts are built from is incomplete.
+    async fn health(&self) -> Result<(), EngineError> {
+        crate::ephemeris::check_data_files()?;
+        crate::wisdom_data::wisdom_integrity()
+    }
+
     fn cache_key(&self, input: &EngineInput) -> String {
         // Generate deterministic cache key from birth data
-        if let Some(birth_data) = &input.birth_data {
-            format!(
-                "hd:{}:{}:{:.4}:{:.4}",
+        if let Some(birth_data) = input.primary_birth_data() {
+            let mut key = format!(
+                "hd:{}:{}:{:.4}:{:.4}:{}:{}",
                 birth_data.date,
-                birth_data.time.as_ref().unwrap_or(&"00:00".to_string()),
+                birth_data.time.as_deref().unwrap_or("unknown"),
                 birth_data.latitude,
-                birth_data.longitude
-            )
+                birth_data.longitude,
+                birth_data.timezone,
+                birth_data.utc_offset_minutes.map(|m| m.to_string()).unwrap_or_default()
+            );
+            // Arc keys keep their old form so existing cache entries stay valid
+            let options = input.engine_options::<HumanDesignOptions>().ok();
+            let method = options.as_ref()
+                .and_then(|options| design_method(options).ok())
+                .unwrap_or_default();
+            if method != DesignMethod::default() {
+                key.push_str(&format!(":{}", method.as_str()));
+            }
+            if let Some(window) = options.and_then(|options| options.rectify_window_minutes) {
+                key.push_str(&format!(":rectify{}", window));
+            }
+            key
         } else {
             format!("hd:invalid:{}", chrono::Utc::now().timestamp())
         }
     }
+
+    /// A bodygraph depends only on the birth moment.
+    fn cache_ttl(&self, _input: &EngineInput) -> CacheTtl {
+        CacheTtl::Infinite
+    }
 }
 
 #[cfg(test)]
@@ -262,12 +498,19 @@ mod tests {
         EngineInput {
             birth_data: Some(BirthData {
                 name: Some("Test".to_string()),
+                display_name: None,
                 date: "1987-01-01".to_string(),
                 time: Some("12:00".to_string()),
+                time_unknown: false,
                 latitude: 51.5074,
                 longitude: -0.1278,
+                altitude: None,
                 timezone: "Europe/London".to_string(),
+                place: None,
+                utc_offset_minutes: None,
             }),
+            secondary_birth_data: None,
+            subjects: Vec::new(),
             current_time: Utc::now(),
             location: None,
             precision: Precision::Standard,
@@ -300,14 +543,98 @@ mod tests {
         let result = HumanDesignEngine::extract_birth_params(&input);
         assert!(result.is_ok());
         
-        let (date, time, tz, lat, lon) = result.unwrap();
+        let (date, time, offset, lat, lon) = result.unwrap();
         assert_eq!(date.to_string(), "1987-01-01");
         assert_eq!(time.format("%H:%M").to_string(), "12:00");
-        assert_eq!(tz, "Europe/London");
+        assert_eq!(offset.timezone, "Europe/London");
+        assert_eq!(offset.offset_minutes(), 0);
         assert_eq!(lat, 51.5074);
         assert_eq!(lon, -0.1278);
     }
 
+    #[tokio::test]
+    async fn test_result_reports_design_time_diagnostics() {
+        let engine = HumanDesignEngine::new();
+        let output = engine.calculate(create_test_input()).await.unwrap();
+
+        let diagnostics = &output.result["design_time"];
+        assert!(diagnostics["iterations"].as_u64().unwrap() > 0);
+        assert!(diagnostics["residual_arcseconds"].as_f64().unwrap() <= crate::design_time::TOLERANCE_ARCSECONDS);
+        assert!(diagnostics["solver"].is_string());
+        assert!(diagnostics["design_time"].as_str().unwrap().starts_with("1986-10"));
+    }
+
+    #[tokio::test]
+    async fn test_noon_birth_time_is_flagged() {
+        let engine = HumanDesignEngine::new();
+        let output = engine.calculate(create_test_input()).await.unwrap();
+
+        let warnings = output.result["warnings"].as_array().unwrap();
+        assert_eq!(warnings[0]["code"], "defaulted_birth_time");
+        assert_eq!(warnings[0]["verify_birth_time"], true);
+
+        let mut input = create_test_input();
+        input.birth_data.as_mut().unwrap().time = Some("12:07".to_string());
+        let output = engine.calculate(input).await.unwrap();
+        let codes: Vec<&str> = output.result["warnings"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .filter_map(|w| w["code"].as_str())
+            .collect();
+        assert!(!codes.contains(&"defaulted_birth_time"));
+    }
+
+    #[tokio::test]
+    async fn test_rectification_option() {
+        let engine = HumanDesignEngine::new();
+        let mut input = create_test_input();
+        input.options.insert("rectify_window_minutes".to_string(), json!(60));
+
+        let output = engine.calculate(input.clone()).await.unwrap();
+        let rectification = &output.result["rectification"];
+        assert_eq!(rectification["window_minutes"], 60);
+        let segments = rectification["segments"].as_array().unwrap();
+        assert_eq!(segments[0]["from_minutes"], -60);
+        assert_eq!(segments[segments.len() - 1]["to_minutes"], 60);
+        assert_eq!(rectification["breakpoints"].as_array().unwrap().len(), segments.len() - 1);
+        assert_ne!(engine.cache_key(&input), engine.cache_key(&create_test_input()));
+        assert!(engine.calculate(create_test_input()).await.unwrap().result.get("rectification").is_none());
+
+        input.options.insert("rectify_window_minutes".to_string(), json!(0));
+        let err = engine.calculate(input).await.unwrap_err();
+        assert!(matches!(err, EngineError::InvalidInput(ref e) if e[0].field == "options.rectify_window_minutes"));
+    }
+
+    #[tokio::test]
+    async fn test_design_method_option() {
+        let engine = HumanDesignEngine::new();
+        let mut input = create_test_input();
+        input.options.insert("design_method".to_string(), json!("days_88"));
+
+        let output = engine.calculate(input.clone()).await.unwrap();
+        let diagnostics = &output.result["design_time"];
+        assert_eq!(diagnostics["method"], "days_88");
+        assert_eq!(diagnostics["solver"], "fixed");
+        assert_eq!(diagnostics["design_time"], "1986-10-05T12:00:00Z");
+        assert_ne!(engine.cache_key(&input), engine.cache_key(&create_test_input()));
+
+        input.options.insert("design_method".to_string(), json!("88_days"));
+        let err = engine.calculate(input).await.unwrap_err();
+        assert!(matches!(err, EngineError::InvalidInput(ref e) if e[0].field == "options.design_method"));
+    }
+
+    #[tokio::test]
+    async fn test_summary_names_type_and_profile() {
+        let engine = HumanDesignEngine::new();
+        let output = engine.calculate(create_test_input()).await.unwrap();
+
+        let summary = output.summary.expect("native engines always summarize");
+        let profile = output.result["profile"].as_str().unwrap();
+        assert!(summary.starts_with("You are a"), "{}", summary);
+        assert!(summary.contains(&format!("{} profile", profile)), "{}", summary);
+    }
+
     #[tokio::test]
     async fn test_missing_birth_data() {
         let engine = HumanDesignEngine::new();
@@ -325,6 +652,7 @@ mod tests {
             engine_id: "human-design".to_string(),
             result: json!({"hd_type": "Generator", "authority": "Sacral", "profile": "1/3"}),
             witness_prompt: "".to_string(), // Empty
+            summary: None,
             consciousness_level: 1,
             metadata: CalculationMetadata {
                 calculation_time_ms: 10.0,
@@ -332,6 +660,9 @@ mod tests {
                 precision_achieved: "Standard".to_string(),
                 cached: false,
                 timestamp: Utc::now(),
+                engine_version: None,
+                warnings: Vec::new(),
+                usage: None,
             },
         };
         
@@ -344,4 +675,51 @@ mod tests {
         let result = engine.validate(&output).await.unwrap();
         assert!(result.valid);
     }
+    #[tokio::test]
+    async fn test_validation_warns_on_ambiguous_offset() {
+        let engine = HumanDesignEngine::new();
+        // 01:30 occurred twice in London when BST ended
+        let offset = timezone::resolve_offset(
+            "Europe/London",
+            None,
+            NaiveDate::from_ymd_opt(1990, 10, 28).unwrap(),
+            NaiveTime::from_hms_opt(1, 30, 0),
+        )
+        .unwrap();
+        let output = EngineOutput {
+            engine_id: "human-design".to_string(),
+            result: json!({
+                "hd_type": "Generator",
+                "authority": "Sacral",
+                "profile": "1/3",
+                RESOLUTION_KEY: offset,
+            }),
+            witness_prompt: "Test question?".to_string(),
+            summary: None,
+            consciousness_level: 1,
+            metadata: CalculationMetadata {
+                calculation_time_ms: 10.0,
+                backend: "test".to_string(),
+                precision_achieved: "Standard".to_string(),
+                cached: false,
+                timestamp: Utc::now(),
+                engine_version: None,
+                warnings: Vec::new(),
+                usage: None,
+            },
+        };
+
+        let result = engine.validate(&output).await.unwrap();
+        assert!(result.valid);
+        assert!(result.messages.iter().any(|m| m.contains("occurs twice")));
+    }
+
+    #[tokio::test]
+    async fn test_result_matches_core_schema() {
+        let engine = HumanDesignEngine::new();
+        let output = engine.calculate(create_test_input()).await.unwrap();
+        let typed: noesis_core::results::HDChartSummary = output.parse_as().unwrap();
+        assert_eq!(typed.personality_activations.len(), 13);
+        assert!(typed.personality_activations.contains_key("sun"));
+    }
 }
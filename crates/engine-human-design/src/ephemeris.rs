@@ -3,6 +3,7 @@
 //! Provides clean API for calculating all 13 planetary positions needed for HD charts.
 
 use chrono::{DateTime, Utc, Timelike, Datelike};
+use noesis_core::geodesy::{topocentric_ecliptic, Observer};
 use noesis_core::EngineError;
 
 /// Planet identifiers for Swiss Ephemeris
@@ -32,6 +33,63 @@ pub struct PlanetPosition {
     pub speed: f64,      // degrees per day
 }
 
+/// Data files HD charts read: planets and the Moon for 1800-2399 CE
+pub const REQUIRED_DATA_FILES: [&str; 2] = ["sepl_18.se1", "semo_18.se1"];
+
+/// Locate the ephemeris directory used when no explicit path is given.
+///
+/// `SWISS_EPHE_PATH` wins if it exists; otherwise the common relative
+/// locations are searched for a directory holding `sepl_18.se1`.
+pub fn find_data_path() -> Option<String> {
+    if let Ok(env_path) = std::env::var("SWISS_EPHE_PATH") {
+        if std::path::Path::new(&env_path).exists() {
+            return Some(env_path);
+        }
+    }
+
+    let candidates = [
+        "data/ephemeris",
+        "./data/ephemeris",
+        "../data/ephemeris",
+        "../../data/ephemeris",
+        "../../../data/ephemeris",
+        "/Volumes/madara/2026/witnessos/Selemene-engine/data/ephemeris",
+    ];
+    candidates
+        .into_iter()
+        .find(|candidate| {
+            let path = std::path::Path::new(candidate);
+            path.exists() && path.join("sepl_18.se1").exists()
+        })
+        .map(str::to_string)
+}
+
+/// Check that the ephemeris directory and [`REQUIRED_DATA_FILES`] are present.
+///
+/// Without them Swiss Ephemeris silently falls back to the less precise
+/// Moshier model, so charts near gate boundaries can come out wrong.
+pub fn check_data_files() -> Result<String, EngineError> {
+    let data_path = find_data_path().ok_or_else(|| {
+        EngineError::SwissEphemerisError(
+            "No ephemeris data directory found; set SWISS_EPHE_PATH".to_string(),
+        )
+    })?;
+
+    let missing: Vec<&str> = REQUIRED_DATA_FILES
+        .into_iter()
+        .filter(|file| !std::path::Path::new(&data_path).join(file).exists())
+        .collect();
+    if missing.is_empty() {
+        Ok(data_path)
+    } else {
+        Err(EngineError::SwissEphemerisError(format!(
+            "Ephemeris files missing from {}: {}",
+            data_path,
+            missing.join(", ")
+        )))
+    }
+}
+
 /// Swiss Ephemeris calculator for Human Design
 pub struct EphemerisCalculator {
     data_path: String,
@@ -49,43 +107,18 @@ impl EphemerisCalculator {
     /// - /Volumes/madara/2026/witnessos/Selemene-engine/data/ephemeris (absolute fallback)
     pub fn new(data_path: impl Into<String>) -> Self {
         let mut data_path = data_path.into();
-        
+
         // If empty, try to find ephemeris data
         if data_path.is_empty() {
-            // Check environment variable first
-            if let Ok(env_path) = std::env::var("SWISS_EPHE_PATH") {
-                if std::path::Path::new(&env_path).exists() {
-                    data_path = env_path;
-                }
-            }
-            
-            // Try common relative paths
-            if data_path.is_empty() {
-                let candidates = [
-                    "data/ephemeris",
-                    "./data/ephemeris",
-                    "../data/ephemeris",
-                    "../../data/ephemeris",
-                    "../../../data/ephemeris",
-                    "/Volumes/madara/2026/witnessos/Selemene-engine/data/ephemeris",
-                ];
-                
-                for candidate in candidates {
-                    let path = std::path::Path::new(candidate);
-                    if path.exists() && path.join("sepl_18.se1").exists() {
-                        data_path = candidate.to_string();
-                        break;
-                    }
-                }
-            }
+            data_path = find_data_path().unwrap_or_default();
         }
-        
+
         swisseph::swe::set_ephe_path(&data_path);
         Self { data_path }
     }
 
-    /// Convert DateTime to Julian Day using Swiss Ephemeris
-    fn datetime_to_jd(dt: &DateTime<Utc>) -> f64 {
+    /// Convert DateTime to Julian Day (UT) using Swiss Ephemeris
+    pub fn julian_day(dt: &DateTime<Utc>) -> f64 {
         let year = dt.year();
         let month = dt.month() as i32;
         let day = dt.day() as i32;
@@ -103,7 +136,7 @@ impl EphemerisCalculator {
         planet: HDPlanet,
         datetime: &DateTime<Utc>,
     ) -> Result<PlanetPosition, EngineError> {
-        let jd = Self::datetime_to_jd(datetime);
+        let jd = Self::julian_day(datetime);
         let planet_id = planet as i32;
         let flags = 258; // SEFLG_SPEED | SEFLG_SWIEPH
 
@@ -169,12 +202,44 @@ impl EphemerisCalculator {
         planets
             .iter()
             .map(|&planet| {
-                let pos = self.get_planet_position(planet, datetime)?;
+                let pos = self.shared_planet_position(planet, datetime)?;
                 Ok((planet, pos))
             })
             .collect()
     }
 
+    /// [`get_planet_position`](Self::get_planet_position), reusing a
+    /// position another engine of the same workflow already calculated (see
+    /// [`noesis_core::context`])
+    pub fn shared_planet_position(
+        &self,
+        planet: HDPlanet,
+        datetime: &DateTime<Utc>,
+    ) -> Result<PlanetPosition, EngineError> {
+        let key = format!("ephemeris:{:?}:{}:{}", planet, datetime.to_rfc3339(), self.data_path);
+        noesis_core::context::shared(&key, || self.get_planet_position(planet, datetime))
+    }
+
+    /// Position of `planet` as seen from `observer` on the Earth's surface
+    /// rather than from its centre (see [`noesis_core::geodesy`]). Speed is
+    /// left geocentric.
+    pub fn topocentric_position(
+        &self,
+        planet: HDPlanet,
+        datetime: &DateTime<Utc>,
+        observer: &Observer,
+    ) -> Result<PlanetPosition, EngineError> {
+        let geocentric = self.shared_planet_position(planet, datetime)?;
+        let (longitude, latitude) = topocentric_ecliptic(
+            geocentric.longitude,
+            geocentric.latitude,
+            geocentric.distance,
+            *datetime,
+            observer,
+        );
+        Ok(PlanetPosition { longitude, latitude, ..geocentric })
+    }
+
     /// Get data path
     pub fn data_path(&self) -> &str {
         &self.data_path
@@ -185,6 +250,12 @@ impl EphemerisCalculator {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_bundled_data_files_pass_check() {
+        let data_path = check_data_files().unwrap();
+        assert!(std::path::Path::new(&data_path).join("semo_18.se1").exists());
+    }
+
     #[test]
     fn test_sun_position_j2000() {
         let calc = EphemerisCalculator::new("");
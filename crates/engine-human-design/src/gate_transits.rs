@@ -0,0 +1,177 @@
+//! Gate ingresses of transiting planets
+//!
+//! An ingress is the moment a planet's tropical longitude crosses into
+//! another gate of the Rave Mandala, e.g. the Sun entering Gate 17 at the
+//! March equinox. Ingresses are found by sampling each planet's gate across
+//! a window and bisecting every change down to a minute. The step is short
+//! enough that no planet crosses a whole 5.625° gate between two samples: the
+//! Moon moves up to about 15° a day and is sampled every two hours, everything
+//! else moves at most 2.2° a day and is sampled twice a day.
+
+use chrono::{DateTime, Duration, Utc};
+use noesis_core::EngineError;
+use serde::{Deserialize, Serialize};
+
+use crate::ephemeris::{EphemerisCalculator, HDPlanet};
+use crate::gate_sequence::longitude_to_gate;
+use crate::stations::planet_name;
+
+/// Planets forecast when none are asked for
+pub const DEFAULT_TRANSIT_PLANETS: [HDPlanet; 2] = [HDPlanet::Sun, HDPlanet::Earth];
+
+/// The 13 Human Design bodies, in activation order
+pub const TRANSIT_PLANETS: [HDPlanet; 13] = [
+    HDPlanet::Sun,
+    HDPlanet::Earth,
+    HDPlanet::Moon,
+    HDPlanet::NorthNode,
+    HDPlanet::SouthNode,
+    HDPlanet::Mercury,
+    HDPlanet::Venus,
+    HDPlanet::Mars,
+    HDPlanet::Jupiter,
+    HDPlanet::Saturn,
+    HDPlanet::Uranus,
+    HDPlanet::Neptune,
+    HDPlanet::Pluto,
+];
+
+/// Precision an ingress time is bisected to
+const INGRESS_PRECISION_SECS: i64 = 60;
+
+/// A planet entering a gate
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GateIngress {
+    /// Lowercase planet name, as in Human Design activation keys
+    pub planet: String,
+    pub gate: u8,
+    /// Gate the planet leaves
+    pub previous_gate: u8,
+    /// Accurate to a minute
+    pub time: DateTime<Utc>,
+    /// Entered while moving backwards through the wheel
+    pub retrograde: bool,
+}
+
+/// Planet with a lowercase name as returned by [`planet_name`]
+pub fn parse_planet(name: &str) -> Option<HDPlanet> {
+    TRANSIT_PLANETS
+        .iter()
+        .copied()
+        .find(|&planet| planet_name(planet) == name.trim().to_lowercase())
+}
+
+/// Sampling step for a planet; see the module docs
+fn sample_step(planet: HDPlanet) -> Duration {
+    match planet {
+        HDPlanet::Moon => Duration::hours(2),
+        _ => Duration::hours(12),
+    }
+}
+
+/// All gate ingresses of `planets` between `from` and `to`, in time order
+pub fn find_gate_ingresses(
+    calculator: &EphemerisCalculator,
+    planets: &[HDPlanet],
+    from: &DateTime<Utc>,
+    to: &DateTime<Utc>,
+) -> Result<Vec<GateIngress>, EngineError> {
+    let mut ingresses = Vec::new();
+    for &planet in planets {
+        let gate_at = |time: &DateTime<Utc>| -> Result<u8, EngineError> {
+            Ok(longitude_to_gate(calculator.get_planet_position(planet, time)?.longitude))
+        };
+
+        let mut previous = (*from, gate_at(from)?);
+        while previous.0 < *to {
+            let time = (previous.0 + sample_step(planet)).min(*to);
+            let gate = gate_at(&time)?;
+            if gate == previous.1 {
+                previous = (time, gate);
+                continue;
+            }
+
+            // Resume from the ingress, so a second crossing within the step
+            // (a planet stationing on a boundary) is found too
+            let (ingress_time, entered) = bisect_ingress(previous, time, &gate_at)?;
+            ingresses.push(GateIngress {
+                planet: planet_name(planet),
+                gate: entered,
+                previous_gate: previous.1,
+                time: ingress_time,
+                retrograde: calculator.get_planet_position(planet, &ingress_time)?.speed < 0.0,
+            });
+            previous = (ingress_time, entered);
+        }
+    }
+    ingresses.sort_by_key(|ingress| ingress.time);
+    Ok(ingresses)
+}
+
+/// Narrow the first gate change after `before` down to a minute; returns the
+/// first sampled time in the new gate, and that gate
+fn bisect_ingress(
+    (mut before, gate): (DateTime<Utc>, u8),
+    mut after: DateTime<Utc>,
+    gate_at: &impl Fn(&DateTime<Utc>) -> Result<u8, EngineError>,
+) -> Result<(DateTime<Utc>, u8), EngineError> {
+    let mut entered = gate_at(&after)?;
+    while (after - before).num_seconds() > INGRESS_PRECISION_SECS {
+        let midpoint = before + (after - before) / 2;
+        let midpoint_gate = gate_at(&midpoint)?;
+        if midpoint_gate == gate {
+            before = midpoint;
+        } else {
+            after = midpoint;
+            entered = midpoint_gate;
+        }
+    }
+    Ok((after, entered))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_sun_enters_gate_17_at_the_equinox() {
+        let calculator = EphemerisCalculator::new("");
+        let from = Utc.with_ymd_and_hms(2024, 3, 15, 0, 0, 0).unwrap();
+        let to = Utc.with_ymd_and_hms(2024, 3, 25, 0, 0, 0).unwrap();
+
+        let ingresses = find_gate_ingresses(&calculator, &[HDPlanet::Sun], &from, &to).unwrap();
+        let gate_17 = ingresses.iter().find(|i| i.gate == 17).expect("no ingress into gate 17");
+
+        // The March 2024 equinox, 0° Aries, was at 2024-03-20 03:06 UTC
+        assert_eq!(gate_17.previous_gate, 25);
+        let expected = Utc.with_ymd_and_hms(2024, 3, 20, 3, 6, 0).unwrap();
+        assert!((gate_17.time - expected).num_minutes().abs() <= 10, "{}", gate_17.time);
+        assert!(!gate_17.retrograde);
+    }
+
+    #[test]
+    fn test_ingresses_are_ordered_and_land_in_their_gate() {
+        let calculator = EphemerisCalculator::new("");
+        let from = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        let to = Utc.with_ymd_and_hms(2024, 2, 1, 0, 0, 0).unwrap();
+
+        let ingresses = find_gate_ingresses(&calculator, &[HDPlanet::Earth, HDPlanet::Moon], &from, &to).unwrap();
+        assert!(!ingresses.is_empty());
+        assert!(ingresses.windows(2).all(|pair| pair[0].time <= pair[1].time));
+
+        for ingress in &ingresses {
+            assert_ne!(ingress.gate, ingress.previous_gate);
+            let planet = parse_planet(&ingress.planet).unwrap();
+            let longitude = calculator.get_planet_position(planet, &ingress.time).unwrap().longitude;
+            assert_eq!(longitude_to_gate(longitude), ingress.gate, "{:?}", ingress);
+        }
+    }
+
+    #[test]
+    fn test_parse_planet() {
+        assert_eq!(parse_planet("Sun").map(planet_name).as_deref(), Some("sun"));
+        assert_eq!(parse_planet("northnode").map(planet_name).as_deref(), Some("northnode"));
+        assert!(parse_planet("vulcan").is_none());
+    }
+}
@@ -6,20 +6,29 @@
 pub use noesis_core::{ConsciousnessEngine, EngineError, EngineInput, EngineOutput};
 
 pub mod ephemeris;
+pub mod stations;
+pub mod gate_transits;
 pub mod gate_sequence;
 pub mod design_time;
 pub mod activations;
 pub mod chart;
 pub mod analysis;
 pub mod witness;
+pub mod warnings;
+pub mod rectification;
 pub mod engine;
 
 // Re-export ephemeris calculator for convenience
 pub use ephemeris::{EphemerisCalculator, HDPlanet, PlanetPosition};
+pub use stations::{find_stations, retrograde_status, RetrogradeStatus, Station, StationKind};
+pub use gate_transits::{find_gate_ingresses, GateIngress};
 
 // Re-export key functions for convenience
 pub use gate_sequence::{longitude_to_gate, longitude_to_line, longitude_to_gate_and_line};
-pub use design_time::{calculate_design_time, initialize_ephemeris, DesignTimeError};
+pub use design_time::{
+    calculate_design_time, initialize_ephemeris, solve_design_time, solve_design_time_with_method, DesignMethod,
+    DesignTimeError, DesignTimeSolution, DesignTimeSolver,
+};
 pub use activations::{
     calculate_personality_sun_earth,
     calculate_design_sun_earth,
@@ -27,8 +36,10 @@ pub use activations::{
     calculate_personality_activations,
     calculate_design_activations,
     calculate_all_activations,
+    calculate_chart_activations,
+    calculate_chart_activations_with_method,
 };
-pub use chart::generate_hd_chart;
+pub use chart::{generate_hd_chart, generate_hd_chart_with_method};
 pub use analysis::{
     analyze_centers,
     analyze_channels,
@@ -47,7 +58,9 @@ pub use models::*;
 pub use wisdom_data::{
     GATES, CENTERS, CHANNELS, TYPES, AUTHORITIES, PROFILES, LINES,
     DEFINITIONS, CIRCUITRY, INCARNATION_CROSSES, VARIABLES, PLANETARY_ACTIVATIONS,
-    init_wisdom,
+    init_wisdom, validate_wisdom, wisdom_integrity,
 };
 pub use witness::generate_witness_prompt;
+pub use warnings::{ChartWarning, ChartWarningCode};
+pub use rectification::{rectify, Breakpoint, ChartReading, Rectification, RectificationSegment};
 pub use engine::HumanDesignEngine;
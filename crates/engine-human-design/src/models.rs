@@ -1,6 +1,9 @@
 //! Human Design data structures
 
 use serde::{Deserialize, Serialize};
+
+use crate::design_time::DesignTimeSolution;
+use crate::warnings::ChartWarning;
 use std::collections::HashMap;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -13,6 +16,13 @@ pub struct HDChart {
     pub authority: Authority,
     pub profile: Profile,
     pub definition: Definition,
+    /// How the Design time was solved; absent for charts built from activations alone
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub design_time: Option<DesignTimeSolution>,
+    /// Defaulted inputs, low-confidence Design times and rare configurations
+    /// worth confirming the birth time for
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub warnings: Vec<ChartWarning>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
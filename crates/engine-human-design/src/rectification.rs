@@ -0,0 +1,284 @@
+//! Birth time rectification
+//!
+//! Many people know their birth time only roughly. Given a window around the
+//! recorded time, this recalculates the chart across it and reports where
+//! type, authority, profile or definition change, refined to the minute, so
+//! a user can tell whether their chart holds across the uncertainty or hinges
+//! on a few minutes.
+
+use chrono::{DateTime, Duration, NaiveDateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::design_time::DesignMethod;
+use crate::models::{Authority, Definition, HDChart, HDType};
+use crate::generate_hd_chart_with_method;
+
+/// Largest accepted window, either side of the recorded time
+pub const MAX_WINDOW_MINUTES: u32 = 720;
+
+/// Minutes between the charts sampled across the window. The Moon, the
+/// fastest body, needs hours to change gate, so no change starts and ends
+/// unseen between two samples; each change found is then bisected to the
+/// minute.
+pub const SAMPLE_STEP_MINUTES: u32 = 5;
+
+/// The parts of a chart a birth time decides
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ChartReading {
+    pub hd_type: HDType,
+    pub authority: Authority,
+    /// Conscious/unconscious lines, e.g. "1/3"
+    pub profile: String,
+    pub definition: Definition,
+}
+
+impl ChartReading {
+    pub fn of(chart: &HDChart) -> Self {
+        Self {
+            hd_type: chart.hd_type,
+            authority: chart.authority,
+            profile: format!("{}/{}", chart.profile.conscious_line, chart.profile.unconscious_line),
+            definition: chart.definition,
+        }
+    }
+
+    /// Names of the fields that differ from `other`
+    fn changes_from(&self, other: &Self) -> Vec<&'static str> {
+        let mut changed = Vec::new();
+        if self.hd_type != other.hd_type {
+            changed.push("hd_type");
+        }
+        if self.authority != other.authority {
+            changed.push("authority");
+        }
+        if self.profile != other.profile {
+            changed.push("profile");
+        }
+        if self.definition != other.definition {
+            changed.push("definition");
+        }
+        changed
+    }
+}
+
+/// A stretch of the window over which the reading holds
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RectificationSegment {
+    /// First minute, relative to the recorded birth time
+    pub from_minutes: i64,
+    /// Last minute, relative to the recorded birth time
+    pub to_minutes: i64,
+    pub reading: ChartReading,
+}
+
+/// The first minute at which the chart reads differently
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Breakpoint {
+    /// Minutes from the recorded birth time
+    pub offset_minutes: i64,
+    pub utc: DateTime<Utc>,
+    /// Local civil time, `YYYY-MM-DDTHH:MM`
+    pub local_time: String,
+    /// Which of hd_type, authority, profile and definition change
+    pub changed: Vec<String>,
+    pub before: ChartReading,
+    pub after: ChartReading,
+}
+
+/// How a chart varies across a birth time window
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Rectification {
+    /// Minutes either side of the recorded birth time
+    pub window_minutes: u32,
+    pub step_minutes: u32,
+    /// Whether the reading holds across the whole window
+    pub stable: bool,
+    /// Share of the window over which the reading matches the recorded time's
+    pub stability: f64,
+    pub segments: Vec<RectificationSegment>,
+    pub breakpoints: Vec<Breakpoint>,
+}
+
+/// Rectify the birth moment `utc`, recorded as local time `local`, across
+/// `window_minutes` either side.
+pub fn rectify(
+    utc: DateTime<Utc>,
+    local: NaiveDateTime,
+    window_minutes: u32,
+    method: DesignMethod,
+) -> Result<Rectification, String> {
+    rectify_with(utc, local, window_minutes, |moment| {
+        generate_hd_chart_with_method(moment, "", method).map(|chart| ChartReading::of(&chart))
+    })
+}
+
+/// [`rectify`] with the chart reading supplied by `read`
+fn rectify_with<F>(
+    utc: DateTime<Utc>,
+    local: NaiveDateTime,
+    window_minutes: u32,
+    read: F,
+) -> Result<Rectification, String>
+where
+    F: Fn(DateTime<Utc>) -> Result<ChartReading, String>,
+{
+    let window = i64::from(window_minutes.min(MAX_WINDOW_MINUTES));
+    let reading_at = |offset: i64| read(utc + Duration::minutes(offset));
+
+    // Sample the window, always including both ends and the recorded time
+    let mut offsets: Vec<i64> = (-window..=window).step_by(SAMPLE_STEP_MINUTES as usize).collect();
+    offsets.extend([0, window]);
+    offsets.sort_unstable();
+    offsets.dedup();
+
+    let mut samples = Vec::with_capacity(offsets.len());
+    for offset in offsets {
+        samples.push((offset, reading_at(offset)?));
+    }
+
+    let mut changes = Vec::new();
+    for pair in samples.windows(2) {
+        let (lo, ref lo_reading) = pair[0];
+        let (hi, ref hi_reading) = pair[1];
+        if lo_reading != hi_reading {
+            refine(&reading_at, lo, lo_reading.clone(), hi, hi_reading.clone(), &mut changes)?;
+        }
+    }
+
+    let mut segments = Vec::new();
+    let mut from = -window;
+    let mut reading = samples[0].1.clone();
+    for (offset, after) in &changes {
+        segments.push(RectificationSegment { from_minutes: from, to_minutes: offset - 1, reading: reading.clone() });
+        from = *offset;
+        reading = after.clone();
+    }
+    segments.push(RectificationSegment { from_minutes: from, to_minutes: window, reading });
+
+    let breakpoints = segments
+        .windows(2)
+        .map(|pair| {
+            let offset = pair[1].from_minutes;
+            Breakpoint {
+                offset_minutes: offset,
+                utc: utc + Duration::minutes(offset),
+                local_time: (local + Duration::minutes(offset)).format("%Y-%m-%dT%H:%M").to_string(),
+                changed: pair[1].reading.changes_from(&pair[0].reading).into_iter().map(String::from).collect(),
+                before: pair[0].reading.clone(),
+                after: pair[1].reading.clone(),
+            }
+        })
+        .collect();
+
+    let recorded = samples.iter().find(|(offset, _)| *offset == 0).map(|(_, r)| r);
+    let matching: i64 = segments
+        .iter()
+        .filter(|s| Some(&s.reading) == recorded)
+        .map(|s| s.to_minutes - s.from_minutes + 1)
+        .sum();
+
+    Ok(Rectification {
+        window_minutes: window as u32,
+        step_minutes: SAMPLE_STEP_MINUTES,
+        stable: segments.len() == 1,
+        stability: matching as f64 / (2 * window + 1) as f64,
+        segments,
+        breakpoints,
+    })
+}
+
+/// Bisect between two minutes whose readings differ, collecting each
+/// `(first minute, new reading)` change between them in order
+fn refine<F>(
+    reading_at: &F,
+    lo: i64,
+    lo_reading: ChartReading,
+    hi: i64,
+    hi_reading: ChartReading,
+    changes: &mut Vec<(i64, ChartReading)>,
+) -> Result<(), String>
+where
+    F: Fn(i64) -> Result<ChartReading, String>,
+{
+    if hi - lo <= 1 {
+        changes.push((hi, hi_reading));
+        return Ok(());
+    }
+    let mid = lo + (hi - lo) / 2;
+    let mid_reading = reading_at(mid)?;
+    if mid_reading == lo_reading {
+        refine(reading_at, mid, mid_reading, hi, hi_reading, changes)
+    } else if mid_reading == hi_reading {
+        refine(reading_at, lo, lo_reading, mid, mid_reading, changes)
+    } else {
+        refine(reading_at, lo, lo_reading, mid, mid_reading.clone(), changes)?;
+        refine(reading_at, mid, mid_reading, hi, hi_reading, changes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn reading(profile: &str, hd_type: HDType) -> ChartReading {
+        ChartReading {
+            hd_type,
+            authority: Authority::Sacral,
+            profile: profile.to_string(),
+            definition: Definition::Single,
+        }
+    }
+
+    #[test]
+    fn test_breakpoints_are_refined_to_the_minute() {
+        let birth = Utc.with_ymd_and_hms(1990, 3, 15, 19, 30, 0).unwrap();
+        let local = birth.naive_utc() - Duration::hours(5);
+        // Profile turns at -37 minutes, type at +83
+        let read = |moment: DateTime<Utc>| {
+            let offset = (moment - birth).num_minutes();
+            let profile = if offset < -37 { "6/2" } else { "1/3" };
+            let hd_type = if offset < 83 { HDType::Generator } else { HDType::Projector };
+            Ok(reading(profile, hd_type))
+        };
+
+        let rectification = rectify_with(birth, local, 120, read).unwrap();
+        assert!(!rectification.stable);
+        assert_eq!(rectification.segments.len(), 3);
+        let offsets: Vec<i64> = rectification.breakpoints.iter().map(|b| b.offset_minutes).collect();
+        assert_eq!(offsets, [-37, 83]);
+        assert_eq!(rectification.breakpoints[0].changed, ["profile"]);
+        assert_eq!(rectification.breakpoints[0].local_time, "1990-03-15T13:53");
+        assert_eq!(rectification.breakpoints[1].changed, ["hd_type"]);
+        assert_eq!(rectification.segments[1].from_minutes, -37);
+        assert_eq!(rectification.segments[1].to_minutes, 82);
+        assert!((rectification.stability - 120.0 / 241.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_two_changes_between_samples_are_both_found() {
+        let birth = Utc.with_ymd_and_hms(2000, 1, 1, 12, 0, 0).unwrap();
+        let read = |moment: DateTime<Utc>| {
+            let offset = (moment - birth).num_minutes();
+            Ok(match offset {
+                ..=1 => reading("1/3", HDType::Generator),
+                2 => reading("1/4", HDType::Generator),
+                _ => reading("2/4", HDType::Generator),
+            })
+        };
+
+        let rectification = rectify_with(birth, birth.naive_utc(), 10, read).unwrap();
+        let offsets: Vec<i64> = rectification.breakpoints.iter().map(|b| b.offset_minutes).collect();
+        assert_eq!(offsets, [2, 3]);
+    }
+
+    #[test]
+    fn test_constant_chart_is_stable() {
+        let birth = Utc.with_ymd_and_hms(2000, 1, 1, 12, 0, 0).unwrap();
+        let read = |_| Ok(reading("3/5", HDType::Manifestor));
+        let rectification = rectify_with(birth, birth.naive_utc(), 60, read).unwrap();
+        assert!(rectification.stable);
+        assert!(rectification.breakpoints.is_empty());
+        assert_eq!(rectification.stability, 1.0);
+    }
+}
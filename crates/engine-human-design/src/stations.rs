@@ -0,0 +1,182 @@
+//! Retrograde status and planetary stations
+//!
+//! A station is the moment a planet's geocentric longitude speed changes
+//! sign: it turns retrograde when the speed goes negative and direct when it
+//! turns positive again. Stations are found by sampling the speed daily
+//! across a window and bisecting each sign change down to a minute; inner
+//! planets stay retrograde for three weeks or more, so a daily step cannot
+//! skip a retrograde period.
+
+use chrono::{DateTime, Duration, Utc};
+use noesis_core::EngineError;
+use serde::{Deserialize, Serialize};
+
+use crate::ephemeris::{EphemerisCalculator, HDPlanet};
+
+/// Planets that station; the Sun and Moon never do and the nodes are
+/// almost always retrograde
+pub const STATION_PLANETS: [HDPlanet; 8] = [
+    HDPlanet::Mercury,
+    HDPlanet::Venus,
+    HDPlanet::Mars,
+    HDPlanet::Jupiter,
+    HDPlanet::Saturn,
+    HDPlanet::Uranus,
+    HDPlanet::Neptune,
+    HDPlanet::Pluto,
+];
+
+/// Sampling step across the window
+const SAMPLE_STEP_HOURS: i64 = 24;
+
+/// Precision a station time is bisected to
+const STATION_PRECISION_SECS: i64 = 60;
+
+/// Direction a planet turns at a station
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StationKind {
+    /// Speed turns negative; the retrograde period begins
+    Retrograde,
+    /// Speed turns positive; the retrograde period ends
+    Direct,
+}
+
+/// A planet changing direction
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Station {
+    /// Lowercase planet name, as in Human Design activation keys
+    pub planet: String,
+    pub kind: StationKind,
+    /// Accurate to a minute
+    pub time: DateTime<Utc>,
+    /// Tropical longitude at the station, in degrees
+    pub longitude: f64,
+}
+
+/// Whether a planet is retrograde at an instant
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RetrogradeStatus {
+    pub planet: String,
+    pub retrograde: bool,
+    /// Longitude speed in degrees per day
+    pub speed: f64,
+}
+
+/// Lowercase name for a planet
+pub fn planet_name(planet: HDPlanet) -> String {
+    format!("{:?}", planet).to_lowercase()
+}
+
+/// Retrograde status of each of [`STATION_PLANETS`] at `at`
+pub fn retrograde_status(
+    calculator: &EphemerisCalculator,
+    at: &DateTime<Utc>,
+) -> Result<Vec<RetrogradeStatus>, EngineError> {
+    STATION_PLANETS
+        .iter()
+        .map(|&planet| {
+            let speed = calculator.get_planet_position(planet, at)?.speed;
+            Ok(RetrogradeStatus {
+                planet: planet_name(planet),
+                retrograde: speed < 0.0,
+                speed,
+            })
+        })
+        .collect()
+}
+
+/// All stations of [`STATION_PLANETS`] between `from` and `to`, in time order
+pub fn find_stations(
+    calculator: &EphemerisCalculator,
+    from: &DateTime<Utc>,
+    to: &DateTime<Utc>,
+) -> Result<Vec<Station>, EngineError> {
+    let mut stations = Vec::new();
+    for &planet in &STATION_PLANETS {
+        let speed_at = |time: &DateTime<Utc>| -> Result<f64, EngineError> {
+            Ok(calculator.get_planet_position(planet, time)?.speed)
+        };
+
+        let mut previous = (*from, speed_at(from)?);
+        while previous.0 < *to {
+            let time = (previous.0 + Duration::hours(SAMPLE_STEP_HOURS)).min(*to);
+            let speed = speed_at(&time)?;
+            if (previous.1 < 0.0) != (speed < 0.0) {
+                let station_time = bisect_station(previous, (time, speed), &speed_at)?;
+                stations.push(Station {
+                    planet: planet_name(planet),
+                    kind: if speed < 0.0 { StationKind::Retrograde } else { StationKind::Direct },
+                    time: station_time,
+                    longitude: calculator.get_planet_position(planet, &station_time)?.longitude,
+                });
+            }
+            previous = (time, speed);
+        }
+    }
+    stations.sort_by_key(|station| station.time);
+    Ok(stations)
+}
+
+/// Narrow a speed sign change between two samples down to a minute
+fn bisect_station(
+    (mut before, before_speed): (DateTime<Utc>, f64),
+    (mut after, _): (DateTime<Utc>, f64),
+    speed_at: &impl Fn(&DateTime<Utc>) -> Result<f64, EngineError>,
+) -> Result<DateTime<Utc>, EngineError> {
+    let turning_retrograde = before_speed >= 0.0;
+    while (after - before).num_seconds() > STATION_PRECISION_SECS {
+        let midpoint = before + (after - before) / 2;
+        if (speed_at(&midpoint)? >= 0.0) == turning_retrograde {
+            before = midpoint;
+        } else {
+            after = midpoint;
+        }
+    }
+    Ok(before + (after - before) / 2)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_mercury_stations_april_2024() {
+        let calculator = EphemerisCalculator::new("");
+        let from = Utc.with_ymd_and_hms(2024, 3, 20, 0, 0, 0).unwrap();
+        let to = Utc.with_ymd_and_hms(2024, 5, 1, 0, 0, 0).unwrap();
+
+        let mercury: Vec<Station> = find_stations(&calculator, &from, &to)
+            .unwrap()
+            .into_iter()
+            .filter(|s| s.planet == "mercury")
+            .collect();
+
+        // Mercury stationed retrograde 2024-04-01 22:14 UTC and direct 2024-04-25 12:54 UTC
+        assert_eq!(mercury.len(), 2, "{:?}", mercury);
+        assert_eq!(mercury[0].kind, StationKind::Retrograde);
+        let expected = Utc.with_ymd_and_hms(2024, 4, 1, 22, 14, 0).unwrap();
+        assert!((mercury[0].time - expected).num_minutes().abs() <= 10, "{}", mercury[0].time);
+        assert_eq!(mercury[1].kind, StationKind::Direct);
+        let expected = Utc.with_ymd_and_hms(2024, 4, 25, 12, 54, 0).unwrap();
+        assert!((mercury[1].time - expected).num_minutes().abs() <= 10, "{}", mercury[1].time);
+    }
+
+    #[test]
+    fn test_stations_are_ordered_and_match_status() {
+        let calculator = EphemerisCalculator::new("");
+        let from = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        let to = Utc.with_ymd_and_hms(2024, 12, 31, 0, 0, 0).unwrap();
+
+        let stations = find_stations(&calculator, &from, &to).unwrap();
+        assert!(stations.windows(2).all(|pair| pair[0].time <= pair[1].time));
+
+        for station in &stations {
+            let after = station.time + Duration::hours(12);
+            let status = retrograde_status(&calculator, &after).unwrap();
+            let planet = status.iter().find(|s| s.planet == station.planet).unwrap();
+            assert_eq!(planet.retrograde, station.kind == StationKind::Retrograde, "{:?}", station);
+        }
+    }
+}
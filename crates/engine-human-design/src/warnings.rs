@@ -0,0 +1,182 @@
+//! Chart warnings
+//!
+//! A chart is only as good as the birth time behind it. These warnings flag
+//! what a user should double-check: a birth time that looks like a
+//! placeholder, a local time that occurred twice, a Design time the solver
+//! could not pin down, and a profile that a few minutes' error would change.
+//! Rare but legitimate configurations (no defined centers, all nine defined,
+//! a quadruple split) are flagged too, so a UI can confirm the time before
+//! presenting them.
+
+use chrono::{NaiveTime, Timelike};
+use serde::{Deserialize, Serialize};
+
+use crate::design_time::DesignTimeSolver;
+use crate::models::{Activation, Definition, HDChart, Planet};
+
+/// Degrees of one line (a gate is six lines of 0.9375°)
+const DEGREES_PER_LINE: f64 = 360.0 / 64.0 / 6.0;
+
+/// Birth time error the profile is checked against
+pub const BIRTH_TIME_MARGIN_MINUTES: f64 = 15.0;
+
+/// The Sun's mean motion in degrees per minute
+const SUN_DEGREES_PER_MINUTE: f64 = 0.9856 / 1440.0;
+
+/// What a [`ChartWarning`] is about
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ChartWarningCode {
+    /// Birth time of exactly 12:00 or 00:00, the usual stand-ins for an unknown time
+    DefaultedBirthTime,
+    /// The local birth time occurred twice, or never, around a clock change
+    AmbiguousBirthTime,
+    /// The Design time needed the fallback solver, or its residual could move
+    /// the Design Sun across a line
+    LowConfidenceDesignTime,
+    /// A Sun sits within [`BIRTH_TIME_MARGIN_MINUTES`] of motion of a line
+    /// change, so the profile depends on the exact minute
+    ProfileNearLineChange,
+    /// No center is defined (Reflector)
+    NoDefinedCenters,
+    /// All nine centers are defined
+    AllCentersDefined,
+    /// Four separate areas of definition
+    QuadrupleSplit,
+}
+
+/// Something about a chart a user may want to verify
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ChartWarning {
+    pub code: ChartWarningCode,
+    pub message: String,
+    /// Whether confirming the birth time would settle it
+    pub verify_birth_time: bool,
+}
+
+impl ChartWarning {
+    fn new(code: ChartWarningCode, message: impl Into<String>) -> Self {
+        Self {
+            code,
+            message: message.into(),
+            // A solver shortfall is not the user's to fix
+            verify_birth_time: code != ChartWarningCode::LowConfidenceDesignTime,
+        }
+    }
+}
+
+/// Warnings about the birth time as entered. `offset_warning` is the
+/// timezone resolution's warning, when the local time was ambiguous.
+pub fn birth_time_warnings(time: NaiveTime, offset_warning: Option<String>) -> Vec<ChartWarning> {
+    let mut warnings = Vec::new();
+    if time.second() == 0 && time.minute() == 0 && (time.hour() == 12 || time.hour() == 0) {
+        warnings.push(ChartWarning::new(
+            ChartWarningCode::DefaultedBirthTime,
+            format!(
+                "Birth time {} is often entered when the real time is unknown; type, authority and profile may differ",
+                time.format("%H:%M")
+            ),
+        ));
+    }
+    if let Some(message) = offset_warning {
+        warnings.push(ChartWarning::new(ChartWarningCode::AmbiguousBirthTime, message));
+    }
+    warnings
+}
+
+/// Degrees from `longitude` to the nearest line boundary
+fn distance_to_line_change(longitude: f64) -> f64 {
+    let within = longitude.rem_euclid(DEGREES_PER_LINE);
+    within.min(DEGREES_PER_LINE - within)
+}
+
+fn sun(activations: &[Activation]) -> Option<&Activation> {
+    activations.iter().find(|a| a.planet == Planet::Sun)
+}
+
+/// Warnings read from the calculated chart
+pub fn chart_warnings(chart: &HDChart) -> Vec<ChartWarning> {
+    let mut warnings = Vec::new();
+    let design_sun = sun(&chart.design_activations);
+
+    if let Some(solution) = &chart.design_time {
+        let residual_degrees = solution.residual_arcseconds.abs() / 3600.0;
+        let near_line = design_sun.is_some_and(|s| distance_to_line_change(s.longitude) < residual_degrees);
+        if solution.solver == DesignTimeSolver::Bisection || near_line {
+            warnings.push(ChartWarning::new(
+                ChartWarningCode::LowConfidenceDesignTime,
+                format!(
+                    "The Design time was solved by {:?} to within {:.4}\"; the Design activations may be off by a line",
+                    solution.solver, solution.residual_arcseconds
+                ),
+            ));
+        }
+    }
+
+    let margin = BIRTH_TIME_MARGIN_MINUTES * SUN_DEGREES_PER_MINUTE;
+    let suns = [("Personality", sun(&chart.personality_activations)), ("Design", design_sun)];
+    let near: Vec<&str> = suns
+        .iter()
+        .filter(|(_, sun)| sun.is_some_and(|s| distance_to_line_change(s.longitude) < margin))
+        .map(|(side, _)| *side)
+        .collect();
+    if !near.is_empty() {
+        warnings.push(ChartWarning::new(
+            ChartWarningCode::ProfileNearLineChange,
+            format!(
+                "The {} Sun is within {} minutes of a line change; the {}/{} profile depends on the exact birth time",
+                near.join(" and "),
+                BIRTH_TIME_MARGIN_MINUTES,
+                chart.profile.conscious_line,
+                chart.profile.unconscious_line
+            ),
+        ));
+    }
+
+    let defined = chart.centers.values().filter(|c| c.defined).count();
+    if !chart.centers.is_empty() && defined == 0 {
+        warnings.push(ChartWarning::new(
+            ChartWarningCode::NoDefinedCenters,
+            "No center is defined, making this a Reflector chart (about 1% of people)",
+        ));
+    } else if defined == 9 {
+        warnings.push(ChartWarning::new(
+            ChartWarningCode::AllCentersDefined,
+            "All nine centers are defined, which is very rare",
+        ));
+    }
+    if chart.definition == Definition::QuadrupleSplit {
+        warnings.push(ChartWarning::new(
+            ChartWarningCode::QuadrupleSplit,
+            "The definition is split into four separate areas, which is very rare",
+        ));
+    }
+
+    warnings
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_placeholder_times_are_flagged() {
+        let noon = NaiveTime::from_hms_opt(12, 0, 0).unwrap();
+        let warnings = birth_time_warnings(noon, None);
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].code, ChartWarningCode::DefaultedBirthTime);
+        assert!(warnings[0].verify_birth_time);
+
+        let precise = NaiveTime::from_hms_opt(12, 7, 0).unwrap();
+        assert!(birth_time_warnings(precise, None).is_empty());
+        let ambiguous = birth_time_warnings(precise, Some("Birth time occurs twice".to_string()));
+        assert_eq!(ambiguous[0].code, ChartWarningCode::AmbiguousBirthTime);
+    }
+
+    #[test]
+    fn test_distance_to_line_change() {
+        assert!(distance_to_line_change(0.9375 * 4.0 + 0.001) < 0.0011);
+        assert!(distance_to_line_change(0.9375 * 4.0 - 0.001) < 0.0011);
+        assert!((distance_to_line_change(0.9375 * 4.5) - 0.46875).abs() < 1e-9);
+    }
+}
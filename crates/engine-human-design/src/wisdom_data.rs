@@ -2,7 +2,9 @@
 
 use crate::wisdom::*;
 use lazy_static::lazy_static;
+use noesis_core::EngineError;
 use std::collections::HashMap;
+use std::sync::OnceLock;
 
 lazy_static! {
     /// 64 Human Design gates with complete wisdom data
@@ -57,3 +59,117 @@ pub fn init_wisdom() {
     lazy_static::initialize(&VARIABLES);
     lazy_static::initialize(&PLANETARY_ACTIVATIONS);
 }
+
+/// The nine centers, by the names the wisdom data uses
+pub const CENTER_NAMES: [&str; 9] = [
+    "Head", "Ajna", "Throat", "G", "Heart", "Spleen", "Sacral", "Solar Plexus", "Root",
+];
+
+/// Channels in the bodygraph
+pub const CHANNEL_COUNT: usize = 36;
+
+/// Profile keys, conscious line then unconscious line
+pub const PROFILE_KEYS: [&str; 12] = [
+    "1_3", "1_4", "2_4", "2_5", "3_5", "3_6", "4_6", "4_1", "5_1", "5_2", "6_2", "6_3",
+];
+
+/// Check the tables charts are built from: all 64 gates on known centers,
+/// the centers' gate lists agreeing with them, all 36 channels joining
+/// existing gates on the right centers with a defined circuit, and all 12
+/// profiles with the 6 lines they are made of.
+///
+/// The loaders log and return an empty table when a file is unreadable, so
+/// without this check a broken data file yields charts missing channels and
+/// definition. Returns every problem found, empty when the data is complete.
+pub fn validate_wisdom() -> Vec<String> {
+    let mut problems = Vec::new();
+
+    for number in 1..=64u8 {
+        match GATES.get(&number.to_string()) {
+            None => problems.push(format!("gate {} is missing", number)),
+            Some(gate) if gate.number != number => {
+                problems.push(format!("gate {} is numbered {}", number, gate.number))
+            }
+            Some(gate) if !CENTER_NAMES.contains(&gate.center.as_str()) => {
+                problems.push(format!("gate {} is on unknown center '{}'", number, gate.center))
+            }
+            Some(_) => {}
+        }
+    }
+
+    for name in CENTER_NAMES {
+        let Some(center) = CENTERS.get(name) else {
+            problems.push(format!("center {} is missing", name));
+            continue;
+        };
+        for gate in &center.gates {
+            if GATES.get(&gate.to_string()).is_some_and(|wisdom| wisdom.center != name) {
+                problems.push(format!("center {} lists gate {} of another center", name, gate));
+            }
+        }
+    }
+
+    if CHANNELS.len() != CHANNEL_COUNT {
+        problems.push(format!("{} of {} channels defined", CHANNELS.len(), CHANNEL_COUNT));
+    }
+    let mut keys: Vec<&String> = CHANNELS.keys().collect();
+    keys.sort();
+    for key in keys {
+        let channel = &CHANNELS[key];
+        let [first, second] = channel.gates[..] else {
+            problems.push(format!("channel {} does not join two gates", key));
+            continue;
+        };
+        let gate_centers: Option<Vec<&str>> = [first, second]
+            .iter()
+            .map(|gate| GATES.get(&gate.to_string()).map(|wisdom| wisdom.center.as_str()))
+            .collect();
+        match gate_centers {
+            None => problems.push(format!("channel {} references a missing gate", key)),
+            Some(gate_centers) => {
+                let mut expected = gate_centers.clone();
+                let mut declared: Vec<&str> = channel.centers.iter().map(String::as_str).collect();
+                expected.sort();
+                declared.sort();
+                if expected != declared {
+                    problems.push(format!(
+                        "channel {} joins {} but its gates are on {}",
+                        key,
+                        channel.centers.join("-"),
+                        gate_centers.join("-")
+                    ));
+                }
+            }
+        }
+        if !CIRCUITRY.contains_key(&channel.circuitry) {
+            problems.push(format!("channel {} has undefined circuitry '{}'", key, channel.circuitry));
+        }
+    }
+
+    for key in PROFILE_KEYS {
+        if !PROFILES.contains_key(key) {
+            problems.push(format!("profile {} is missing", key.replace('_', "/")));
+        }
+    }
+    for line in 1..=6 {
+        if !LINES.contains_key(&line.to_string()) {
+            problems.push(format!("line {} is missing", line));
+        }
+    }
+
+    problems
+}
+
+/// [`validate_wisdom`], run once, as an error listing every problem
+pub fn wisdom_integrity() -> Result<(), EngineError> {
+    static PROBLEMS: OnceLock<Vec<String>> = OnceLock::new();
+    let problems = PROBLEMS.get_or_init(validate_wisdom);
+    if problems.is_empty() {
+        Ok(())
+    } else {
+        Err(EngineError::ConfigError(format!(
+            "Human Design wisdom data is incomplete: {}",
+            problems.join("; ")
+        )))
+    }
+}
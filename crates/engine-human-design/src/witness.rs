@@ -125,6 +125,8 @@ mod tests {
             authority,
             profile,
             definition,
+            design_time: None,
+            warnings: Vec::new(),
         }
     }
     
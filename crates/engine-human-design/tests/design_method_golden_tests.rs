@@ -0,0 +1,121 @@
+//! Golden-file tests for the two Design placement methods
+//!
+//! `tests/golden/design_methods.json` holds, for births across the year, the
+//! Design time and Design Sun/Earth under `arc_88deg` and `days_88`. The
+//! arc's Design times carry the 1 hour accuracy requirement as tolerance;
+//! 88 days is an exact subtraction.
+
+use chrono::{DateTime, Utc};
+use engine_human_design::{generate_hd_chart_with_method, DesignMethod, Planet};
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::fs;
+
+#[derive(Debug, Deserialize)]
+struct GoldenFile {
+    metadata: Metadata,
+    cases: Vec<GoldenCase>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Metadata {
+    design_time_tolerance_minutes: HashMap<String, i64>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GoldenCase {
+    name: String,
+    birth_time: DateTime<Utc>,
+    arc_88deg: Expected,
+    days_88: Expected,
+}
+
+#[derive(Debug, Deserialize)]
+struct Expected {
+    design_time: DateTime<Utc>,
+    design_sun: GateLine,
+    design_earth: GateLine,
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+struct GateLine {
+    gate: u8,
+    line: u8,
+}
+
+fn load_golden() -> GoldenFile {
+    let json_str = fs::read_to_string("tests/golden/design_methods.json")
+        .expect("Failed to read golden/design_methods.json");
+    serde_json::from_str(&json_str).expect("Failed to parse golden/design_methods.json")
+}
+
+fn check_method(golden: &GoldenFile, method: DesignMethod) {
+    let tolerance = golden.metadata.design_time_tolerance_minutes[method.as_str()];
+
+    for case in &golden.cases {
+        let expected = match method {
+            DesignMethod::Arc88Deg => &case.arc_88deg,
+            DesignMethod::Days88 => &case.days_88,
+        };
+        let chart = generate_hd_chart_with_method(case.birth_time, "", method)
+            .unwrap_or_else(|e| panic!("{}: {}", case.name, e));
+        let solution = chart.design_time.as_ref().expect("charts report their Design time");
+        assert_eq!(solution.method, method, "{}", case.name);
+
+        let off_minutes = (solution.design_time - expected.design_time).num_minutes().abs();
+        assert!(
+            off_minutes <= tolerance,
+            "{} ({}): Design time {} is {} minutes from {}",
+            case.name,
+            method.as_str(),
+            solution.design_time,
+            off_minutes,
+            expected.design_time
+        );
+
+        for (planet, expected) in [(Planet::Sun, &expected.design_sun), (Planet::Earth, &expected.design_earth)] {
+            let activation = chart
+                .design_activations
+                .iter()
+                .find(|a| a.planet == planet)
+                .expect("13 design activations");
+            assert_eq!(
+                GateLine { gate: activation.gate, line: activation.line },
+                *expected,
+                "{} ({}): Design {:?}",
+                case.name,
+                method.as_str(),
+                planet
+            );
+        }
+    }
+}
+
+#[test]
+fn test_arc_88deg_matches_golden() {
+    check_method(&load_golden(), DesignMethod::Arc88Deg);
+}
+
+#[test]
+fn test_days_88_matches_golden() {
+    check_method(&load_golden(), DesignMethod::Days88);
+}
+
+#[test]
+fn test_default_method_is_arc() {
+    let golden = load_golden();
+    let case = &golden.cases[0];
+    let default = engine_human_design::generate_hd_chart(case.birth_time, "").unwrap();
+    let arc = generate_hd_chart_with_method(case.birth_time, "", DesignMethod::Arc88Deg).unwrap();
+
+    assert_eq!(default.design_time, arc.design_time);
+}
+
+#[test]
+fn test_golden_methods_disagree() {
+    // Every case places the Design Sun on a different line, so a method
+    // silently ignored would fail one of the tests above
+    for case in load_golden().cases {
+        assert_ne!(case.arc_88deg.design_sun, case.days_88.design_sun, "{}", case.name);
+    }
+}
@@ -1,5 +1,5 @@
 use engine_human_design::{
-    init_wisdom, GATES, CENTERS, CHANNELS, TYPES, AUTHORITIES, PROFILES, 
+    init_wisdom, validate_wisdom, wisdom_integrity, GATES, CENTERS, CHANNELS, TYPES, AUTHORITIES, PROFILES, 
     LINES, DEFINITIONS, CIRCUITRY, INCARNATION_CROSSES, VARIABLES, PLANETARY_ACTIVATIONS
 };
 
@@ -140,3 +140,23 @@ fn test_channel_wisdom_sample() {
         println!("  Circuitry: {}", channel.circuitry);
     }
 }
+
+#[test]
+fn test_wisdom_data_is_complete() {
+    let problems = validate_wisdom();
+    assert!(problems.is_empty(), "Wisdom data problems: {:#?}", problems);
+    assert!(wisdom_integrity().is_ok());
+
+    assert_eq!(GATES.len(), 64);
+    assert_eq!(CENTERS.len(), 9);
+    assert_eq!(CHANNELS.len(), 36);
+    assert_eq!(PROFILES.len(), 12);
+
+    // The centers' gate lists agree with the gates
+    let head = CENTERS.get("Head").unwrap();
+    for gate in &head.gates {
+        assert_eq!(GATES[&gate.to_string()].center, "Head");
+    }
+    let logic = CHANNELS.get("4-63").expect("Channel of Logic");
+    assert_eq!(logic.centers, ["Ajna", "Head"]);
+}
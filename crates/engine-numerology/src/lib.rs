@@ -1,13 +1,25 @@
 //! Numerology Consciousness Engine
 //!
-//! Implements Pythagorean and Chaldean numerology systems.
+//! Implements Pythagorean and Chaldean numerology systems, with Kabbalistic
+//! and Vedic readings on request (see [`systems`]). With `options.subject` it
+//! reads a business name, address or phone number instead (see [`subject`]).
 //! Pure math -- no external dependencies beyond noesis-core.
 
+pub mod subject;
+pub mod systems;
+
 pub use noesis_core::{ConsciousnessEngine, EngineError, EngineInput, EngineOutput};
+pub use subject::SubjectReading;
+pub use systems::{NumerologySystem, SystemReadings};
 
 use async_trait::async_trait;
 use chrono::Utc;
-use noesis_core::{CalculationMetadata, ValidationResult};
+use noesis_core::description::EngineDescription;
+use noesis_core::examples::{self, EngineExample};
+use noesis_core::options::{NumerologyOptions, NumerologySubject, NumerologySubjectKind};
+use noesis_core::{
+    CacheTtl, CalculationMetadata, TypedOptions, ValidationCode, ValidationError, ValidationResult, DEBUG_KEY,
+};
 use serde::{Deserialize, Serialize};
 use sha2::{Digest, Sha256};
 use std::time::Instant;
@@ -151,13 +163,20 @@ pub struct NumerologyResult {
     pub personality: NumerologyNumber,
     pub birthday: NumerologyNumber,
     pub chaldean_name: NumerologyNumber,
+    /// Readings in the systems named by `options.systems`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub systems: Option<SystemReadings>,
 }
 
 // ---------------------------------------------------------------------------
 // Meaning lookup
 // ---------------------------------------------------------------------------
 
-fn meaning_for(n: u32) -> String {
+/// Numbers with a meaning of their own: 1-9 and the master numbers
+pub const MEANINGFUL_NUMBERS: [u32; 12] = [1, 2, 3, 4, 5, 6, 7, 8, 9, 11, 22, 33];
+
+/// Keywords for a reduced number; other numbers get a generic description
+pub fn meaning_for(n: u32) -> String {
     match n {
         1 => "Leadership, independence, pioneering".into(),
         2 => "Partnership, diplomacy, sensitivity".into(),
@@ -254,6 +273,36 @@ fn calculate_chaldean_name(name: &str) -> NumerologyNumber {
     NumerologyNumber::from_raw(raw_sum)
 }
 
+/// The intermediate values behind a reading, for `options.debug`: each
+/// letter's value in both mappings, and the reduction chains of the date
+/// parts the Life Path sums
+fn debug_values(name: &str, date: &str) -> serde_json::Value {
+    let parts: Vec<Option<u32>> = date.split('-').map(|part| part.parse().ok()).collect();
+    let chain = |index: usize| parts.get(index).copied().flatten().map(|n| reduce_to_core(n).1);
+    serde_json::json!({
+        "letters": debug_letters(name),
+        "life_path_parts": {
+            "year": chain(0),
+            "month": chain(1),
+            "day": chain(2),
+        },
+    })
+}
+
+fn debug_letters(name: &str) -> Vec<serde_json::Value> {
+    name.chars()
+        .filter(|c| c.is_ascii_alphabetic())
+        .map(|c| {
+            serde_json::json!({
+                "letter": c.to_string(),
+                "pythagorean": pythagorean_value(c),
+                "chaldean": chaldean_value(c),
+                "vowel": is_vowel(c),
+            })
+        })
+        .collect()
+}
+
 // ---------------------------------------------------------------------------
 // Witness prompt generation
 // ---------------------------------------------------------------------------
@@ -278,6 +327,43 @@ fn generate_witness_prompt(result: &NumerologyResult) -> String {
     base.to_string()
 }
 
+fn subject_witness_prompt(reading: &SubjectReading) -> String {
+    format!(
+        "'{}' carries the vibration of {}. Notice: does what this name promises match what you want it to hold?",
+        reading.value,
+        reading.key_number().value,
+    )
+}
+
+// ---------------------------------------------------------------------------
+// Plain-language summary
+// ---------------------------------------------------------------------------
+
+fn generate_summary(result: &NumerologyResult) -> String {
+    let themes = |n: &NumerologyNumber| n.meaning.to_lowercase();
+    format!(
+        "Your Life Path number is {}, which points to {}. \
+         Your Expression number is {} ({}) and your Soul Urge number is {} ({}).",
+        result.life_path.value,
+        themes(&result.life_path),
+        result.expression.value,
+        themes(&result.expression),
+        result.soul_urge.value,
+        themes(&result.soul_urge),
+    )
+}
+
+/// Names the kind of subject but not its value, which may identify someone
+fn subject_summary(reading: &SubjectReading) -> String {
+    let subject = match reading.kind {
+        NumerologySubjectKind::Business => "The business name",
+        NumerologySubjectKind::Address => "The address",
+        NumerologySubjectKind::Phone => "The phone number",
+    };
+    let number = reading.key_number();
+    format!("{} reduces to {}, which points to {}.", subject, number.value, number.meaning.to_lowercase())
+}
+
 // ---------------------------------------------------------------------------
 // NumerologyEngine
 // ---------------------------------------------------------------------------
@@ -290,19 +376,8 @@ impl NumerologyEngine {
     }
 
     fn compute(&self, input: &EngineInput) -> Result<NumerologyResult, EngineError> {
-        let birth = input
-            .birth_data
-            .as_ref()
-            .ok_or_else(|| EngineError::CalculationError("birth_data is required for numerology".into()))?;
-
-        let name = birth
-            .name
-            .as_deref()
-            .ok_or_else(|| EngineError::CalculationError("name is required for numerology calculations".into()))?;
-
-        if name.trim().is_empty() {
-            return Err(EngineError::CalculationError("name must not be empty".into()));
-        }
+        let birth = named_birth_data(input)?;
+        let name = birth.name.as_deref().unwrap_or_default();
 
         let date = &birth.date;
 
@@ -312,6 +387,7 @@ impl NumerologyEngine {
         let personality = calculate_personality(name);
         let birthday = calculate_birthday(date)?;
         let chaldean_name = calculate_chaldean_name(name);
+        let systems = requested_systems(input)?.map(|systems| systems::read_systems(&systems, name, date));
 
         Ok(NumerologyResult {
             life_path,
@@ -320,10 +396,85 @@ impl NumerologyEngine {
             personality,
             birthday,
             chaldean_name,
+            systems,
         })
     }
 }
 
+/// The input's birth data, with the name and date numerology reads
+fn named_birth_data(input: &EngineInput) -> Result<&noesis_core::BirthData, EngineError> {
+    let birth = input
+        .primary_birth_data()
+        .ok_or_else(|| EngineError::CalculationError("birth_data is required for numerology".into()))?;
+
+    // Numerology only needs the name and date; location and timezone are not checked
+    let mut errors: Vec<ValidationError> = birth.date_error().into_iter().collect();
+    match birth.name.as_deref() {
+        None => errors.push(ValidationError::new(
+            "birth_data.name",
+            ValidationCode::Required,
+            "name is required for numerology calculations",
+        )),
+        Some(name) if name.trim().is_empty() => errors.push(ValidationError::new(
+            "birth_data.name",
+            ValidationCode::Empty,
+            "name must not be empty",
+        )),
+        Some(_) => {}
+    }
+    if !errors.is_empty() {
+        return Err(EngineError::InvalidInput(errors));
+    }
+    Ok(birth)
+}
+
+/// The validated `options.subject`; `systems` read a person and can't be
+/// asked for with it
+fn requested_subject(input: &EngineInput) -> Result<Option<NumerologySubject>, EngineError> {
+    let options: NumerologyOptions = input.engine_options()?;
+    let Some(subject) = options.subject else {
+        return Ok(None);
+    };
+    if options.systems.is_some() {
+        return Err(EngineError::invalid_field(
+            "options.systems",
+            ValidationCode::Conflict,
+            "systems read a person's name and birth date and cannot be combined with subject",
+        ));
+    }
+    subject::validate(&subject)?;
+    Ok(Some(subject))
+}
+
+/// Systems named in `options.systems`, in order and without repeats
+fn requested_systems(input: &EngineInput) -> Result<Option<Vec<NumerologySystem>>, EngineError> {
+    let options: NumerologyOptions = input.engine_options()?;
+    let Some(names) = options.systems else {
+        return Ok(None);
+    };
+    if names.is_empty() {
+        return Err(EngineError::invalid_field(
+            "options.systems",
+            ValidationCode::Empty,
+            format!("systems must name at least one of: {}", systems::SYSTEMS.join(", ")),
+        ));
+    }
+    let mut selected = Vec::new();
+    for name in &names {
+        let system = NumerologySystem::parse(name).ok_or_else(|| {
+            EngineError::invalid_field(
+                "options.systems",
+                ValidationCode::Unknown,
+                format!("Unknown numerology system '{}'. Expected one of: {}", name, systems::SYSTEMS.join(", ")),
+            )
+        })?;
+        if !selected.contains(&system) {
+            selected.push(system);
+        }
+    }
+    Ok(Some(selected))
+}
+
 impl Default for NumerologyEngine {
     fn default() -> Self {
         Self::new()
@@ -344,15 +495,77 @@ impl ConsciousnessEngine for NumerologyEngine {
         0
     }
 
+    fn option_keys(&self) -> Option<&'static [&'static str]> {
+        Some(NumerologyOptions::keys())
+    }
+
+    fn check_input(&self, input: &EngineInput) -> Result<(), EngineError> {
+        if requested_subject(input)?.is_some() {
+            return Ok(());
+        }
+        named_birth_data(input)?;
+        requested_systems(input).map(|_| ())
+    }
+
+    fn examples(&self) -> Vec<EngineExample> {
+        vec![
+            examples::birth_data_example(),
+            EngineExample::new(
+                "systems",
+                "Kabbalistic and Vedic readings beside the Pythagorean and Chaldean ones",
+                examples::sample_input(serde_json::json!({ "systems": ["kabbalistic", "vedic"] })),
+            ),
+            EngineExample::new(
+                "subject",
+                "A business name read without birth data",
+                examples::options_input(serde_json::json!({
+                    "subject": { "kind": "business", "value": "Blue Lotus Studio" }
+                })),
+            ),
+        ]
+    }
+
+    fn describe(&self) -> EngineDescription {
+        EngineDescription::new(
+            "Reads the numbers in a name and birth date: Life Path, Expression, Soul Urge, \
+             Personality and Birthday. Pythagorean and Chaldean letter values are read side by side, \
+             Kabbalistic and Vedic readings on request. A business name, address or phone number can \
+             be read in place of a person.",
+        )
+        .with_input("birth_data.name", true, "Full birth name; not needed with options.subject")
+        .with_input("birth_data.date", true, "Birth date; not needed with options.subject")
+        .with_input("options.systems", false, "Systems to read side by side")
+        .with_input("options.subject", false, "A business name, address or phone number to read instead")
+        .with_outputs("Core numbers with master numbers kept, their meanings per system, and a witness prompt")
+        .with_traditions(&["Pythagorean numerology", "Chaldean numerology", "Kabbalistic gematria", "Vedic numerology"])
+        .with_limitation("Only Latin letters are counted; names in other scripts need a transliteration")
+    }
+
     async fn calculate(&self, input: EngineInput) -> Result<EngineOutput, EngineError> {
         let start = Instant::now();
 
-        let result = self.compute(&input)?;
-        let witness_prompt = generate_witness_prompt(&result);
-
-        let result_json = serde_json::to_value(&result).map_err(|e| {
-            EngineError::InternalError(format!("Failed to serialize NumerologyResult: {}", e))
-        })?;
+        let (mut result_json, witness_prompt, summary) = if let Some(subject) = requested_subject(&input)? {
+            let reading = subject::read_subject(&subject);
+            let result_json = serde_json::to_value(&reading).map_err(|e| {
+                EngineError::InternalError(format!("Failed to serialize SubjectReading: {}", e))
+            })?;
+            (result_json, subject_witness_prompt(&reading), subject_summary(&reading))
+        } else {
+            let result = self.compute(&input)?;
+            let result_json = serde_json::to_value(&result).map_err(|e| {
+                EngineError::InternalError(format!("Failed to serialize NumerologyResult: {}", e))
+            })?;
+            (result_json, generate_witness_prompt(&result), generate_summary(&result))
+        };
+        if input.debug() {
+            result_json[DEBUG_KEY] = match requested_subject(&input)? {
+                Some(subject) => serde_json::json!({ "letters": debug_letters(&subject.value) }),
+                None => {
+                    let birth = named_birth_data(&input)?;
+                    debug_values(birth.name.as_deref().unwrap_or_default(), &birth.date)
+                }
+            };
+        }
 
         let elapsed = start.elapsed().as_secs_f64() * 1000.0;
 
@@ -360,6 +573,7 @@ impl ConsciousnessEngine for NumerologyEngine {
             engine_id: self.engine_id().to_string(),
             result: result_json,
             witness_prompt,
+            summary: Some(summary),
             consciousness_level: 0,
             metadata: CalculationMetadata {
                 calculation_time_ms: elapsed,
@@ -367,6 +581,9 @@ impl ConsciousnessEngine for NumerologyEngine {
                 precision_achieved: "exact".into(),
                 cached: false,
                 timestamp: Utc::now(),
+                engine_version: None,
+                warnings: Vec::new(),
+                usage: None,
             },
         })
     }
@@ -384,19 +601,30 @@ impl ConsciousnessEngine for NumerologyEngine {
             valid = false;
         }
 
-        // Attempt to deserialize the result back into NumerologyResult
-        let parsed: Result<NumerologyResult, _> = serde_json::from_value(output.result.clone());
+        // Attempt to deserialize the result back into NumerologyResult, or
+        // a SubjectReading for `options.subject`
+        let parsed: Result<Vec<(&str, NumerologyNumber)>, _> = if output.result.get("kind").is_some() {
+            serde_json::from_value::<SubjectReading>(output.result.clone()).map(|reading| {
+                [("expression", Some(reading.expression)), ("chaldean", reading.chaldean), ("house_number", reading.house_number)]
+                    .into_iter()
+                    .filter_map(|(label, number)| Some((label, number?)))
+                    .collect()
+            })
+        } else {
+            serde_json::from_value::<NumerologyResult>(output.result.clone()).map(|nr| {
+                vec![
+                    ("life_path", nr.life_path),
+                    ("expression", nr.expression),
+                    ("soul_urge", nr.soul_urge),
+                    ("personality", nr.personality),
+                    ("birthday", nr.birthday),
+                    ("chaldean_name", nr.chaldean_name),
+                ]
+            })
+        };
         match parsed {
-            Ok(nr) => {
+            Ok(numbers) => {
                 // Validate each core number is in the valid range (1-9 or master)
-                let numbers = [
-                    ("life_path", &nr.life_path),
-                    ("expression", &nr.expression),
-                    ("soul_urge", &nr.soul_urge),
-                    ("personality", &nr.personality),
-                    ("birthday", &nr.birthday),
-                    ("chaldean_name", &nr.chaldean_name),
-                ];
 
                 for (label, num) in &numbers {
                     let v = num.value;
@@ -440,16 +668,30 @@ impl ConsciousnessEngine for NumerologyEngine {
     fn cache_key(&self, input: &EngineInput) -> String {
         let mut hasher = Sha256::new();
         hasher.update(b"numerology:");
-        if let Some(ref birth) = input.birth_data {
+        // A subject reads the same whoever asks, so birth data is left out
+        if let Ok(Some(subject)) = requested_subject(input) {
+            hasher.update(format!("subject:{:?}:{}", subject.kind, subject.value.trim()).as_bytes());
+            return format!("numerology:{:x}", hasher.finalize());
+        }
+        if let Some(birth) = input.primary_birth_data() {
             if let Some(ref name) = birth.name {
                 hasher.update(name.as_bytes());
             }
             hasher.update(b"|");
             hasher.update(birth.date.as_bytes());
         }
+        // Old keys stay valid for requests without systems
+        if let Ok(Some(systems)) = requested_systems(input) {
+            hasher.update(format!("|systems:{:?}", systems).as_bytes());
+        }
         let hash = hasher.finalize();
         format!("numerology:{:x}", hash)
     }
+
+    /// Numbers depend only on the name and birth date.
+    fn cache_ttl(&self, _input: &EngineInput) -> CacheTtl {
+        CacheTtl::Infinite
+    }
 }
 
 // ---------------------------------------------------------------------------
@@ -467,12 +709,19 @@ mod tests {
         EngineInput {
             birth_data: Some(noesis_core::BirthData {
                 name: Some(name.to_string()),
+                display_name: None,
                 date: date.to_string(),
                 time: None,
+                time_unknown: false,
                 latitude: 0.0,
                 longitude: 0.0,
+                altitude: None,
                 timezone: "UTC".into(),
+                place: None,
+                utc_offset_minutes: None,
             }),
+            secondary_birth_data: None,
+            subjects: Vec::new(),
             current_time: Utc::now(),
             location: None,
             precision: Precision::Standard,
@@ -610,6 +859,8 @@ mod tests {
         let output = engine.calculate(input).await.unwrap();
         assert_eq!(output.engine_id, "numerology");
         assert!(!output.witness_prompt.is_empty());
+        let summary = output.summary.as_deref().unwrap();
+        assert!(summary.starts_with("Your Life Path number is "), "{}", summary);
 
         let result: NumerologyResult = serde_json::from_value(output.result).unwrap();
         // Verify all numbers are in valid range
@@ -629,6 +880,57 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn test_engine_systems_option() {
+        let engine = NumerologyEngine::new();
+        let plain = make_input("John Doe", "1990-05-15");
+        let output = engine.calculate(plain.clone()).await.unwrap();
+        assert!(output.result.get("systems").is_none());
+
+        let mut input = plain.clone();
+        input.options.insert("systems".into(), serde_json::json!(["vedic", "kabbalistic", "vedic"]));
+        assert_ne!(engine.cache_key(&input), engine.cache_key(&plain));
+        let output = engine.calculate(input).await.unwrap();
+        let systems = &output.result["systems"];
+        assert_eq!(systems["vedic"]["psychic"]["value"], 6);
+        assert!(systems["kabbalistic"]["path"].as_u64().is_some());
+        assert!(systems.get("pythagorean").is_none());
+
+        let mut unknown = plain;
+        unknown.options.insert("systems".into(), serde_json::json!(["tarot"]));
+        let err = engine.calculate(unknown).await.unwrap_err();
+        assert!(err.to_string().contains("tarot"), "{}", err);
+    }
+
+    #[tokio::test]
+    async fn test_engine_subject_needs_no_birth_data() {
+        let engine = NumerologyEngine::new();
+        let mut input = make_input("John Doe", "1990-05-15");
+        input.birth_data = None;
+        input.options.insert("subject".into(), serde_json::json!({ "kind": "business", "value": "Apple" }));
+        engine.check_input(&input).unwrap();
+
+        let output = engine.calculate(input.clone()).await.unwrap();
+        assert_eq!(output.result["kind"], "business");
+        assert_eq!(output.result["expression"]["value"], 5);
+        assert_eq!(output.result["compound"]["value"], 25);
+        assert!(engine.validate(&output).await.unwrap().valid);
+        let summary = output.summary.as_deref().unwrap();
+        assert!(summary.starts_with("The business name reduces to 5"), "{}", summary);
+        assert!(!summary.contains("Apple"), "{}", summary);
+
+        // The same subject shares a cache entry whoever asks
+        let mut other = make_input("Jane Smith", "1985-11-22");
+        other.options = input.options.clone();
+        assert_eq!(engine.cache_key(&other), engine.cache_key(&input));
+
+        input.options.insert("systems".into(), serde_json::json!(["vedic"]));
+        let Err(EngineError::InvalidInput(errors)) = engine.check_input(&input) else {
+            panic!("systems and subject together should be rejected");
+        };
+        assert_eq!(errors[0].code, ValidationCode::Conflict);
+    }
+
     #[tokio::test]
     async fn test_engine_validate_valid() {
         let engine = NumerologyEngine::new();
@@ -644,6 +946,8 @@ mod tests {
         let engine = NumerologyEngine::new();
         let input = EngineInput {
             birth_data: None,
+            secondary_birth_data: None,
+            subjects: Vec::new(),
             current_time: Utc::now(),
             location: None,
             precision: Precision::Standard,
@@ -653,18 +957,41 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[tokio::test]
+    async fn test_engine_reads_primary_subject() {
+        let engine = NumerologyEngine::new();
+        let legacy = make_input("John Doe", "1990-05-15");
+        let mut input = make_input("Jane Roe", "1985-03-02");
+        input.subjects = vec![noesis_core::Subject {
+            role: noesis_core::SubjectRole::Primary,
+            birth_data: legacy.birth_data.clone().unwrap(),
+        }];
+
+        assert_eq!(engine.cache_key(&input), engine.cache_key(&legacy));
+        let from_subject = engine.calculate(input).await.unwrap();
+        let from_legacy = engine.calculate(legacy).await.unwrap();
+        assert_eq!(from_subject.result, from_legacy.result);
+    }
+
     #[tokio::test]
     async fn test_engine_missing_name() {
         let engine = NumerologyEngine::new();
         let input = EngineInput {
             birth_data: Some(noesis_core::BirthData {
                 name: None,
+                display_name: None,
                 date: "1990-01-01".into(),
                 time: None,
+                time_unknown: false,
                 latitude: 0.0,
                 longitude: 0.0,
+                altitude: None,
                 timezone: "UTC".into(),
+                place: None,
+                utc_offset_minutes: None,
             }),
+            secondary_birth_data: None,
+            subjects: Vec::new(),
             current_time: Utc::now(),
             location: None,
             precision: Precision::Standard,
@@ -699,4 +1026,12 @@ mod tests {
         assert_eq!(engine.engine_name(), "Numerology");
         assert_eq!(engine.required_phase(), 0);
     }
+
+    #[tokio::test]
+    async fn test_result_matches_core_schema() {
+        let engine = NumerologyEngine::new();
+        let output = engine.calculate(make_input("John Smith", "1990-05-15")).await.unwrap();
+        let typed: noesis_core::results::NumerologyResult = output.parse_as().unwrap();
+        assert_eq!(typed.life_path.value as u64, output.result["life_path"]["value"].as_u64().unwrap());
+    }
 }
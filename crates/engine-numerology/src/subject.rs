@@ -0,0 +1,272 @@
+//! Readings of business names, addresses and phone numbers
+//!
+//! `options.subject` reads a name or number on its own, without birth data.
+//! Letters count at their Pythagorean and Chaldean values and digits at face
+//! value. A business reads its expression, an address its house number (the
+//! first part with a digit, e.g. `221B`) and a phone number the sum of its
+//! digits. The unreduced Chaldean total, or a phone's digit sum, is also read
+//! as one of Cheiro's compound numbers (10-52), which Chaldean practice
+//! weighs when choosing a name.
+
+use noesis_core::options::{NumerologySubject, NumerologySubjectKind};
+use noesis_core::{EngineError, ValidationCode};
+use serde::{Deserialize, Serialize};
+
+use crate::{chaldean_value, pythagorean_value, NumerologyNumber};
+
+/// Most characters a subject may have
+pub const MAX_SUBJECT_CHARS: usize = 200;
+
+/// Characters a phone number may have besides digits
+const PHONE_PUNCTUATION: [char; 7] = ['+', '-', ' ', '(', ')', '.', '/'];
+
+/// A compound number and its Chaldean reading
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CompoundNumber {
+    /// 10-52
+    pub value: u32,
+    pub meaning: String,
+    /// Whether Chaldean practice counts it in a name's favour
+    pub favourable: bool,
+}
+
+/// Numbers of a business name, address or phone number
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SubjectReading {
+    pub kind: NumerologySubjectKind,
+    pub value: String,
+    /// Pythagorean letters plus digits; for a phone number, its digits
+    pub expression: NumerologyNumber,
+    /// Chaldean letters plus digits; absent for a phone number
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub chaldean: Option<NumerologyNumber>,
+    /// Absent when the unreduced total is outside 10-52
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub compound: Option<CompoundNumber>,
+    /// First part of an address with a digit, reduced
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub house_number: Option<NumerologyNumber>,
+    pub interpretation: String,
+}
+
+impl SubjectReading {
+    /// The number the interpretation is about
+    pub fn key_number(&self) -> &NumerologyNumber {
+        self.house_number.as_ref().unwrap_or(&self.expression)
+    }
+}
+
+/// Reject subjects with nothing to read, too long, or a phone number with
+/// letters
+pub fn validate(subject: &NumerologySubject) -> Result<(), EngineError> {
+    let field = "options.subject.value";
+    let value = subject.value.trim();
+    if value.chars().count() > MAX_SUBJECT_CHARS {
+        return Err(EngineError::invalid_field(
+            field,
+            ValidationCode::OutOfRange,
+            format!("value must be at most {} characters", MAX_SUBJECT_CHARS),
+        ));
+    }
+    if !value.chars().any(|c| c.is_ascii_alphabetic() || matches!(c, '1'..='9')) {
+        return Err(EngineError::invalid_field(field, ValidationCode::Empty, "value has no letters or non-zero digits to read"));
+    }
+    if subject.kind == NumerologySubjectKind::Phone
+        && !value.chars().all(|c| c.is_ascii_digit() || PHONE_PUNCTUATION.contains(&c))
+    {
+        return Err(EngineError::invalid_field(
+            field,
+            ValidationCode::InvalidFormat,
+            "a phone number may only hold digits, spaces and + - ( ) . /",
+        ));
+    }
+    Ok(())
+}
+
+/// Read a validated subject
+pub fn read_subject(subject: &NumerologySubject) -> SubjectReading {
+    let value = subject.value.trim();
+    let (expression, chaldean, compound_total, house_number) = match subject.kind {
+        NumerologySubjectKind::Phone => {
+            let digits = total(value, |_| None);
+            (NumerologyNumber::from_raw(digits), None, digits, None)
+        }
+        NumerologySubjectKind::Business | NumerologySubjectKind::Address => {
+            let chaldean_total = total(value, chaldean_value);
+            let house_number = (subject.kind == NumerologySubjectKind::Address)
+                .then(|| value.split_whitespace().find(|part| part.chars().any(|c| c.is_ascii_digit())))
+                .flatten()
+                .map(|part| NumerologyNumber::from_raw(total(part, pythagorean_value)));
+            (
+                NumerologyNumber::from_raw(total(value, pythagorean_value)),
+                Some(NumerologyNumber::from_raw(chaldean_total)),
+                chaldean_total,
+                house_number,
+            )
+        }
+    };
+
+    let mut reading = SubjectReading {
+        kind: subject.kind,
+        value: value.to_string(),
+        expression,
+        chaldean,
+        compound: compound_number(compound_total),
+        house_number,
+        interpretation: String::new(),
+    };
+    reading.interpretation = interpretation(&reading);
+    reading
+}
+
+/// Sum of the letters at `letter_value` and the digits at face value
+fn total(value: &str, letter_value: impl Fn(char) -> Option<u32>) -> u32 {
+    value
+        .chars()
+        .filter_map(|c| c.to_digit(10).or_else(|| letter_value(c)))
+        .sum()
+}
+
+fn interpretation(reading: &SubjectReading) -> String {
+    let number = reading.key_number().value;
+    let mut text = match reading.kind {
+        NumerologySubjectKind::Business => format!("As a business name, {}: {}.", number, business_meaning(number)),
+        NumerologySubjectKind::Phone => format!("As a business line, {}: {}.", number, business_meaning(number)),
+        NumerologySubjectKind::Address => format!("As a home, {}: {}.", number, home_meaning(number)),
+    };
+    if let Some(compound) = &reading.compound {
+        text.push_str(&format!(
+            " Its compound number {} is {}: {}.",
+            compound.value,
+            if compound.favourable { "favourable" } else { "a caution" },
+            compound.meaning,
+        ));
+    }
+    text
+}
+
+/// What a name's number says about the business carrying it
+fn business_meaning(n: u32) -> &'static str {
+    match n {
+        1 => "suits founders, pioneers and brands that lead their market",
+        2 => "suits partnerships, consulting, mediation and care",
+        3 => "suits media, design, entertainment and anything that communicates",
+        4 => "suits trades, construction, finance and long-lived institutions",
+        5 => "suits travel, sales, marketing and fast-changing markets",
+        6 => "suits hospitality, health, education and family businesses",
+        7 => "suits research, technology, analysis and specialist expertise",
+        8 => "suits commerce, banking, real estate and growth at scale",
+        9 => "suits non-profits, the arts and businesses with a global reach",
+        11 => "suits visionary, inspirational and spiritual ventures (master)",
+        22 => "suits large-scale building and enterprises meant to last (master)",
+        33 => "suits teaching, healing and service to a community (master)",
+        _ => "a compound vibration without a business reading",
+    }
+}
+
+/// What a house number says about living there
+fn home_meaning(n: u32) -> &'static str {
+    match n {
+        1 => "a home for independence and fresh starts",
+        2 => "a home for couples, peace and cooperation",
+        3 => "a home for gatherings, creativity and play",
+        4 => "a home for stability, routine and putting down roots",
+        5 => "a home for change, visitors and an active life",
+        6 => "a home for family, comfort and caring for others",
+        7 => "a home for study, retreat and reflection",
+        8 => "a home for ambition, work and building wealth",
+        9 => "a home for generosity, openness and completion",
+        11 => "a home for intuition and inspiration (master)",
+        22 => "a home for building something lasting (master)",
+        33 => "a home for nurturing and service (master)",
+        _ => "a compound vibration without a home reading",
+    }
+}
+
+/// Cheiro's reading of a compound number, or None outside 10-52
+pub fn compound_number(value: u32) -> Option<CompoundNumber> {
+    let (meaning, favourable) = match value {
+        10 => ("The Wheel of Fortune: honour, faith and rising plans", true),
+        11 => ("The Clenched Hand: hidden dangers and trials", false),
+        12 => ("The Sacrifice: anxiety and plans undone by others", false),
+        13 => ("Change of plans: upheaval that clears the way", false),
+        14 => ("Movement: dealings with the public, with risks to weigh", true),
+        15 => ("The Magician: eloquence, gifts and good fortune", true),
+        16 => ("The Shattered Citadel: sudden reversal of plans", false),
+        17 => ("The Star of the Magi: peace, love and a lasting name", true),
+        18 => ("Conflict: materialism and quarrels", false),
+        19 => ("The Prince of Heaven: success, esteem and honour", true),
+        20 => ("The Awakening: new purpose, plans and ambitions", true),
+        21 => ("The Crown of the Magi: advancement and victory", true),
+        22 => ("Submission: illusion and judgement clouded by others", false),
+        23 => ("The Royal Star of the Lion: help from superiors and success", true),
+        24 | 33 | 42 => ("Love, money and creativity favoured through allies", true),
+        25 | 34 => ("Strength gained through trials and observation", true),
+        26 | 35 | 44 => ("Ruin through partnerships and speculation", false),
+        27 | 36 | 45 => ("The Sceptre: authority and reward for creative work", true),
+        28 => ("Contradictions: promise lost through trust in others", false),
+        29 | 38 | 47 => ("Grace under pressure: treachery and uncertain allies", false),
+        30 | 39 | 48 => ("Thoughtful deduction: mental superiority over material gain", true),
+        31 | 40 | 49 => ("The Recluse: self-contained and isolated", false),
+        32 | 41 | 50 => ("Communication: magnetism and success with the public", true),
+        37 | 46 => ("Good partnerships, friendships and love", true),
+        43 | 52 => ("Revolution: upheaval, strife and failure", false),
+        51 => ("The Warrior: sudden advancement, with enemies", true),
+        _ => return None,
+    };
+    Some(CompoundNumber { value, meaning: meaning.to_string(), favourable })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn subject(kind: NumerologySubjectKind, value: &str) -> NumerologySubject {
+        NumerologySubject { kind, value: value.to_string() }
+    }
+
+    #[test]
+    fn test_business_name() {
+        // A=1 P=7 P=7 L=3 E=5 is 23 Pythagorean; Chaldean 1+8+8+3+5 = 25
+        let reading = read_subject(&subject(NumerologySubjectKind::Business, "Apple"));
+        assert_eq!(reading.expression.value, 5);
+        assert_eq!(reading.expression.reduction_chain, [23, 5]);
+        assert_eq!(reading.chaldean.as_ref().map(|n| n.value), Some(7));
+        let compound = reading.compound.as_ref().unwrap();
+        assert_eq!(compound.value, 25);
+        assert!(compound.favourable);
+        assert!(reading.interpretation.starts_with("As a business name, 5:"), "{}", reading.interpretation);
+    }
+
+    #[test]
+    fn test_address_reads_the_house_number() {
+        // 2+2+1 and B=2
+        let reading = read_subject(&subject(NumerologySubjectKind::Address, "221B Baker Street"));
+        assert_eq!(reading.house_number.as_ref().map(|n| n.value), Some(7));
+        assert_eq!(reading.key_number().value, 7);
+        assert!(reading.interpretation.starts_with("As a home, 7:"));
+    }
+
+    #[test]
+    fn test_phone_sums_its_digits() {
+        let reading = read_subject(&subject(NumerologySubjectKind::Phone, "+1 (555) 010-9999"));
+        assert_eq!(reading.expression.reduction_chain, [53, 8]);
+        assert!(reading.chaldean.is_none());
+        assert!(reading.compound.is_none());
+    }
+
+    #[test]
+    fn test_validate() {
+        assert!(validate(&subject(NumerologySubjectKind::Business, "Blue Lotus Studio")).is_ok());
+        assert!(validate(&subject(NumerologySubjectKind::Business, " -- ")).is_err());
+        assert!(validate(&subject(NumerologySubjectKind::Phone, "000")).is_err());
+        assert!(validate(&subject(NumerologySubjectKind::Phone, "555-CALL-NOW")).is_err());
+        assert!(validate(&subject(NumerologySubjectKind::Address, &"9".repeat(MAX_SUBJECT_CHARS + 1))).is_err());
+    }
+
+    #[test]
+    fn test_compound_numbers_cover_10_to_52() {
+        assert!((10..=52).all(|n| compound_number(n).is_some()));
+        assert!(compound_number(9).is_none() && compound_number(53).is_none());
+    }
+}
@@ -0,0 +1,323 @@
+//! Alternate numerology systems
+//!
+//! The core numbers are Pythagorean, with a Chaldean name number alongside.
+//! `options.systems` asks for readings in named systems, side by side in the
+//! result's `systems`, so a practitioner can compare methodologies on one
+//! name and date: the Pythagorean and Chaldean name numbers, the Kabbalistic
+//! 22-letter reading and the Vedic psychic/destiny pairing.
+
+use serde::{Deserialize, Serialize};
+
+use crate::{chaldean_value, digit_sum, is_vowel, pythagorean_value, NumerologyNumber};
+
+/// Names accepted in `options.systems`
+pub const SYSTEMS: [&str; 4] = ["pythagorean", "chaldean", "kabbalistic", "vedic"];
+
+/// A numerology system selectable in `options.systems`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum NumerologySystem {
+    Pythagorean,
+    Chaldean,
+    Kabbalistic,
+    Vedic,
+}
+
+impl NumerologySystem {
+    pub fn parse(name: &str) -> Option<Self> {
+        match name.trim().to_ascii_lowercase().as_str() {
+            "pythagorean" => Some(Self::Pythagorean),
+            "chaldean" => Some(Self::Chaldean),
+            "kabbalistic" => Some(Self::Kabbalistic),
+            "vedic" => Some(Self::Vedic),
+            _ => None,
+        }
+    }
+}
+
+/// Name numbers read with one letter table
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NameNumbers {
+    /// Every letter of the name
+    pub expression: NumerologyNumber,
+    /// Vowels only
+    pub soul_urge: NumerologyNumber,
+    /// Consonants only
+    pub personality: NumerologyNumber,
+}
+
+/// The name read through the 22 letters of the Hebrew alphabet
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct KabbalisticReading {
+    /// Sum of the name's letters at the values of their Hebrew counterparts
+    pub gematria: u32,
+    /// The gematria reduced to one of the 22 paths (1-22); 0 for a name without letters
+    pub path: u32,
+    pub reduction_chain: Vec<u32>,
+    /// Hebrew letter of the path
+    pub letter: Option<String>,
+    pub meaning: Option<String>,
+}
+
+/// A number reduced to 1-9 with its ruling graha
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VedicNumber {
+    pub value: u32,
+    pub ruler: String,
+    pub reduction_chain: Vec<u32>,
+}
+
+/// How one number's ruler regards another's
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Relation {
+    Same,
+    Friendly,
+    Neutral,
+    Hostile,
+}
+
+/// Vedic (Sankhya) numerology: the psychic number of the birth day, the
+/// destiny number of the whole date and how their rulers pair
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VedicReading {
+    /// Moolank: the day of birth
+    pub psychic: VedicNumber,
+    /// Bhagyank: every digit of the birth date
+    pub destiny: VedicNumber,
+    /// The name at Chaldean letter values
+    pub name: VedicNumber,
+    /// How the psychic number's ruler regards the destiny number's
+    pub psychic_destiny: Relation,
+    /// How the name number's ruler regards the destiny number's
+    pub name_destiny: Relation,
+}
+
+/// Readings of the systems asked for in `options.systems`
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SystemReadings {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pythagorean: Option<NameNumbers>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub chaldean: Option<NameNumbers>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub kabbalistic: Option<KabbalisticReading>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub vedic: Option<VedicReading>,
+}
+
+/// Read `name` and `date` (YYYY-MM-DD) in each of `systems`
+pub fn read_systems(systems: &[NumerologySystem], name: &str, date: &str) -> SystemReadings {
+    let mut readings = SystemReadings::default();
+    for system in systems {
+        match system {
+            NumerologySystem::Pythagorean => readings.pythagorean = Some(name_numbers(name, pythagorean_value)),
+            NumerologySystem::Chaldean => readings.chaldean = Some(name_numbers(name, chaldean_value)),
+            NumerologySystem::Kabbalistic => readings.kabbalistic = Some(kabbalistic(name)),
+            NumerologySystem::Vedic => readings.vedic = Some(vedic(name, date)),
+        }
+    }
+    readings
+}
+
+fn name_numbers(name: &str, value: fn(char) -> Option<u32>) -> NameNumbers {
+    let letters = || name.chars().filter(|c| c.is_ascii_alphabetic());
+    NameNumbers {
+        expression: NumerologyNumber::from_raw(letters().filter_map(value).sum()),
+        soul_urge: NumerologyNumber::from_raw(letters().filter(|c| is_vowel(*c)).filter_map(value).sum()),
+        personality: NumerologyNumber::from_raw(letters().filter(|c| !is_vowel(*c)).filter_map(value).sum()),
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Kabbalistic
+// ---------------------------------------------------------------------------
+
+/// Value of the Hebrew letter a Latin letter transliterates
+fn gematria_value(ch: char) -> Option<u32> {
+    match ch.to_ascii_uppercase() {
+        'A' => Some(1), // Aleph
+        'B' => Some(2), // Bet
+        'G' => Some(3), // Gimel
+        'D' => Some(4), // Dalet
+        'E' | 'H' => Some(5), // Heh
+        'U' | 'V' | 'W' => Some(6), // Vav
+        'Z' => Some(7), // Zayin
+        'I' | 'J' | 'Y' => Some(10), // Yod
+        'C' | 'K' => Some(20), // Kaf
+        'L' => Some(30), // Lamed
+        'M' => Some(40), // Mem
+        'N' => Some(50), // Nun
+        'X' => Some(60), // Samekh
+        'O' => Some(70), // Ayin
+        'F' | 'P' => Some(80), // Peh
+        'Q' => Some(100), // Kuf
+        'R' => Some(200), // Resh
+        'S' => Some(300), // Shin
+        'T' => Some(400), // Tav
+        _ => None,
+    }
+}
+
+/// The 22 paths: Hebrew letter and keywords
+const PATHS: [(&str, &str); 22] = [
+    ("Aleph", "Breath, the undivided beginning"),
+    ("Bet", "House, containment, making a home"),
+    ("Gimel", "Giving, the bridge between need and plenty"),
+    ("Dalet", "Door, humility at the threshold"),
+    ("Heh", "Window, revelation"),
+    ("Vav", "Hook, connection"),
+    ("Zayin", "Sword, discernment"),
+    ("Chet", "Enclosure, life force held"),
+    ("Tet", "Serpent, the good that is hidden"),
+    ("Yod", "Hand, the point of potential"),
+    ("Kaf", "Palm, the capacity to receive"),
+    ("Lamed", "Goad, learning and aspiration"),
+    ("Mem", "Water, the depths"),
+    ("Nun", "Fish, faithfulness"),
+    ("Samekh", "Support, trust"),
+    ("Ayin", "Eye, perception"),
+    ("Peh", "Mouth, speech"),
+    ("Tzadi", "Fishhook, righteousness"),
+    ("Kuf", "The sacred within the mundane"),
+    ("Resh", "Head, beginnings and leadership"),
+    ("Shin", "Fire, transformation"),
+    ("Tav", "Mark, completion and truth"),
+];
+
+fn kabbalistic(name: &str) -> KabbalisticReading {
+    let gematria: u32 = name.chars().filter_map(gematria_value).sum();
+    let mut reduction_chain = vec![gematria];
+    let mut path = gematria;
+    while path > 22 {
+        path = digit_sum(path);
+        reduction_chain.push(path);
+    }
+    let entry = path.checked_sub(1).and_then(|i| PATHS.get(i as usize));
+    KabbalisticReading {
+        gematria,
+        path,
+        reduction_chain,
+        letter: entry.map(|(letter, _)| letter.to_string()),
+        meaning: entry.map(|(_, meaning)| meaning.to_string()),
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Vedic
+// ---------------------------------------------------------------------------
+
+/// Graha ruling each number 1-9
+const RULERS: [&str; 9] = ["Sun", "Moon", "Jupiter", "Rahu", "Mercury", "Venus", "Ketu", "Saturn", "Mars"];
+
+fn vedic_number(raw: u32) -> VedicNumber {
+    let mut reduction_chain = vec![raw];
+    let mut value = raw;
+    while value > 9 {
+        value = digit_sum(value);
+        reduction_chain.push(value);
+    }
+    VedicNumber {
+        value,
+        ruler: value.checked_sub(1).and_then(|i| RULERS.get(i as usize)).unwrap_or(&"None").to_string(),
+        reduction_chain,
+    }
+}
+
+/// Natural friendship of the graha ruling `from` towards the one ruling
+/// `to`. Rahu (4) and Ketu (7) behave as Saturn and Mars, as in Parashari
+/// tradition.
+fn relation(from: u32, to: u32) -> Relation {
+    if from == to {
+        return Relation::Same;
+    }
+    let proxy = |n: u32| match n {
+        4 => 8,
+        7 => 9,
+        n => n,
+    };
+    let (from, to) = (proxy(from), proxy(to));
+    // Friends and enemies of Sun, Moon, Jupiter, Mercury, Venus, Saturn and Mars
+    let (friends, enemies): (&[u32], &[u32]) = match from {
+        1 => (&[2, 3, 9], &[6, 8]),
+        2 => (&[1, 5], &[]),
+        3 => (&[1, 2, 9], &[5, 6]),
+        5 => (&[1, 6], &[2]),
+        6 => (&[5, 8], &[1, 2]),
+        8 => (&[5, 6], &[1, 2, 9]),
+        9 => (&[1, 2, 3], &[5]),
+        _ => (&[], &[]),
+    };
+    if from == to || friends.contains(&to) {
+        Relation::Friendly
+    } else if enemies.contains(&to) {
+        Relation::Hostile
+    } else {
+        Relation::Neutral
+    }
+}
+
+fn vedic(name: &str, date: &str) -> VedicReading {
+    let day: u32 = date.rsplit('-').next().and_then(|day| day.parse().ok()).unwrap_or_default();
+    let psychic = vedic_number(day);
+    let destiny = vedic_number(date.chars().filter_map(|c| c.to_digit(10)).sum());
+    let name = vedic_number(name.chars().filter_map(chaldean_value).sum());
+    VedicReading {
+        psychic_destiny: relation(psychic.value, destiny.value),
+        name_destiny: relation(name.value, destiny.value),
+        psychic,
+        destiny,
+        name,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_kabbalistic_reduces_to_a_path() {
+        // J(10) + O(70) + H(5) + N(50) = 135 -> 9, Tet
+        let reading = kabbalistic("John");
+        assert_eq!(reading.gematria, 135);
+        assert_eq!(reading.reduction_chain, [135, 9]);
+        assert_eq!(reading.letter.as_deref(), Some("Tet"));
+
+        // A(1) + D(4) + A(1) + M(40) = 46 -> 10, Yod; paths up to 22 are kept
+        assert_eq!(kabbalistic("Adam").path, 10);
+        assert_eq!(kabbalistic("123").letter, None);
+    }
+
+    #[test]
+    fn test_vedic_psychic_and_destiny() {
+        // Day 15 -> 6 (Venus); 1+9+9+0+0+5+1+5 = 30 -> 3 (Jupiter)
+        let reading = vedic("John", "1990-05-15");
+        assert_eq!(reading.psychic.value, 6);
+        assert_eq!(reading.psychic.ruler, "Venus");
+        assert_eq!(reading.destiny.value, 3);
+        assert_eq!(reading.destiny.reduction_chain, [30, 3]);
+        // Venus holds Jupiter neutral
+        assert_eq!(reading.psychic_destiny, Relation::Neutral);
+        // Chaldean John = 18 -> 9 (Mars), a friend of Jupiter
+        assert_eq!(reading.name.ruler, "Mars");
+        assert_eq!(reading.name_destiny, Relation::Friendly);
+    }
+
+    #[test]
+    fn test_relations() {
+        assert_eq!(relation(1, 1), Relation::Same);
+        assert_eq!(relation(1, 8), Relation::Hostile);
+        assert_eq!(relation(8, 1), Relation::Hostile);
+        assert_eq!(relation(2, 8), Relation::Neutral);
+        // Rahu is read as Saturn
+        assert_eq!(relation(4, 6), Relation::Friendly);
+        assert_eq!(relation(4, 8), Relation::Friendly);
+    }
+
+    #[test]
+    fn test_only_requested_systems_are_read() {
+        let readings = read_systems(&[NumerologySystem::Chaldean], "John", "1990-05-15");
+        assert_eq!(readings.chaldean.unwrap().expression.value, 9);
+        assert!(readings.pythagorean.is_none() && readings.kabbalistic.is_none() && readings.vedic.is_none());
+    }
+}
@@ -5,11 +5,26 @@
 
 pub use noesis_core::{ConsciousnessEngine, EngineError, EngineInput, EngineOutput};
 
+pub mod muhurta;
+pub mod strength;
+
 use async_trait::async_trait;
-use chrono::Utc;
-use noesis_core::{CalculationMetadata, ValidationResult};
+use chrono::{DateTime, Duration, NaiveDate, NaiveDateTime, NaiveTime, Utc};
+use noesis_core::birth_time::{self, TimeStability};
+use noesis_core::description::EngineDescription;
+use noesis_core::examples::{self, EngineExample};
+use noesis_core::hora::{self, Hora};
+use noesis_core::timezone::{self, ResolvedOffset};
+use noesis_core::options::{PanchangaOptions, RELOCATE_TO_OPTION};
+use noesis_core::results::{DailyStrength, Relocation};
+use noesis_core::{
+    BirthData, CacheTtl, CalculationMetadata, TimeSensitivity, TypedOptions, ValidationCode, ValidationResult,
+    DEBUG_KEY,
+};
+use noesis_solar::SolarDay;
 use serde::{Deserialize, Serialize};
 use sha2::{Digest, Sha256};
+use std::collections::BTreeMap;
 use std::time::Instant;
 
 // ---------------------------------------------------------------------------
@@ -182,6 +197,117 @@ pub struct PanchangaResult {
     pub lunar_longitude: f64,
     /// Julian Day Number used for the calculation
     pub julian_day: f64,
+
+    /// Hora (planetary hour) active at the calculation moment
+    #[serde(default)]
+    pub hora: Option<Hora>,
+
+    /// Local sunrise ("HH:MM") used for the hora calculation
+    #[serde(default)]
+    pub sunrise: Option<String>,
+    /// Local sunset ("HH:MM") used for the hora calculation
+    #[serde(default)]
+    pub sunset: Option<String>,
+
+    /// UTC offset applied to the birth time, resolved from the tz database
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timezone_resolution: Option<ResolvedOffset>,
+
+    /// Sidereal reference applied to the longitudes (absent for tropical)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ayanamsa: Option<Ayanamsa>,
+    /// Ayanamsa in degrees subtracted from the tropical longitudes
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ayanamsa_degrees: Option<f64>,
+
+    /// Home reading, when this one was read for `options.relocate_to`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub relocation: Option<Relocation>,
+
+    /// Tarabala and Chandrabala of the day of `as_of`, else of `current_time`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub daily_strength: Option<DailyStrength>,
+
+    /// How the birth reading holds across the birth day, when the birth
+    /// time is unknown
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub time_stability: Option<TimeStability>,
+}
+
+// ---------------------------------------------------------------------------
+// Ayanamsa
+// ---------------------------------------------------------------------------
+
+/// Degrees of general precession per Julian century (50.290966"/year)
+const PRECESSION_DEG_PER_CENTURY: f64 = 5029.0966 / 3600.0;
+
+/// Zodiac reference for longitudes. Tropical (no correction) is the default;
+/// the others give sidereal longitudes as used by the original Selemene API.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Ayanamsa {
+    #[default]
+    Tropical,
+    /// Chitrapaksha, the Indian national standard
+    Lahiri,
+    Raman,
+    /// KP (Krishnamurti Paddhati)
+    Krishnamurti,
+    FaganBradley,
+}
+
+impl Ayanamsa {
+    /// Parse a legacy option value ("lahiri", "kp", "fagan-bradley", ...)
+    pub fn parse(name: &str) -> Option<Self> {
+        match name.trim().to_ascii_lowercase().replace('-', "_").as_str() {
+            "tropical" | "none" => Some(Ayanamsa::Tropical),
+            "lahiri" | "chitrapaksha" => Some(Ayanamsa::Lahiri),
+            "raman" => Some(Ayanamsa::Raman),
+            "krishnamurti" | "kp" => Some(Ayanamsa::Krishnamurti),
+            "fagan_bradley" | "fagan" => Some(Ayanamsa::FaganBradley),
+            _ => None,
+        }
+    }
+
+    /// Read the `ayanamsa` option; absent means tropical
+    pub fn from_options(
+        options: &std::collections::HashMap<String, serde_json::Value>,
+    ) -> Result<Self, EngineError> {
+        let Some(value) = options.get("ayanamsa") else {
+            return Ok(Ayanamsa::Tropical);
+        };
+        value.as_str().and_then(Self::parse).ok_or_else(|| {
+            EngineError::invalid_field(
+                "options.ayanamsa",
+                ValidationCode::Unknown,
+                format!(
+                    "Unknown ayanamsa {}. Expected tropical, lahiri, raman, krishnamurti or fagan_bradley.",
+                    value
+                ),
+            )
+        })
+    }
+
+    /// Value at J2000.0 in degrees
+    fn j2000_degrees(&self) -> f64 {
+        match self {
+            Ayanamsa::Tropical => 0.0,
+            Ayanamsa::Lahiri => 23.853056,
+            Ayanamsa::Raman => 22.410833,
+            Ayanamsa::Krishnamurti => 23.760278,
+            Ayanamsa::FaganBradley => 24.740300,
+        }
+    }
+
+    /// Ayanamsa in degrees at a Julian Day, extrapolated from J2000.0 by the
+    /// general precession rate
+    pub fn degrees_at(&self, jd: f64) -> f64 {
+        if *self == Ayanamsa::Tropical {
+            return 0.0;
+        }
+        let t = (jd - 2451545.0) / 36525.0;
+        self.j2000_degrees() + PRECESSION_DEG_PER_CENTURY * t
+    }
 }
 
 // ---------------------------------------------------------------------------
@@ -250,6 +376,14 @@ pub fn calculate_lunar_position(jd: f64) -> f64 {
     }
 }
 
+/// Calculate the mean longitude of the Moon's ascending node (degrees,
+/// 0..360) for a given JD. Eclipses fall at new and full moons near a node.
+pub fn calculate_lunar_node(jd: f64) -> f64 {
+    let t = (jd - 2451545.0) / 36525.0;
+    let node = 125.0445479 - 1934.1362891 * t + 0.0020754 * t * t + t * t * t / 467441.0;
+    node.rem_euclid(360.0)
+}
+
 /// Calculate Tithi (lunar day, 0..30 continuous).
 pub fn calculate_tithi(solar_longitude: f64, lunar_longitude: f64) -> f64 {
     let mut tithi = (lunar_longitude - solar_longitude) / 12.0;
@@ -292,9 +426,23 @@ pub fn calculate_vara(jd: f64) -> i32 {
 
 /// Compute a full `PanchangaResult` from date, time, and timezone offset.
 pub fn compute_panchanga(date: &str, time: &str, tz_offset_hours: f64) -> PanchangaResult {
+    compute_panchanga_with_ayanamsa(date, time, tz_offset_hours, Ayanamsa::Tropical)
+}
+
+/// Compute a `PanchangaResult` from longitudes in the given zodiac reference.
+///
+/// Tithi only depends on the Moon-Sun difference and is the same in every
+/// reference; Nakshatra and Yoga shift with the ayanamsa.
+pub fn compute_panchanga_with_ayanamsa(
+    date: &str,
+    time: &str,
+    tz_offset_hours: f64,
+    ayanamsa: Ayanamsa,
+) -> PanchangaResult {
     let jd = calculate_julian_day(date, time, tz_offset_hours);
-    let solar_lng = calculate_solar_position(jd);
-    let lunar_lng = calculate_lunar_position(jd);
+    let correction = ayanamsa.degrees_at(jd);
+    let solar_lng = (calculate_solar_position(jd) - correction).rem_euclid(360.0);
+    let lunar_lng = (calculate_lunar_position(jd) - correction).rem_euclid(360.0);
     let tithi_val = calculate_tithi(solar_lng, lunar_lng);
     let nakshatra_val = calculate_nakshatra(lunar_lng);
     let yoga_val = calculate_yoga(solar_lng, lunar_lng);
@@ -330,7 +478,302 @@ pub fn compute_panchanga(date: &str, time: &str, tz_offset_hours: f64) -> Pancha
         solar_longitude: solar_lng,
         lunar_longitude: lunar_lng,
         julian_day: jd,
+        hora: None,
+        sunrise: None,
+        sunset: None,
+        timezone_resolution: None,
+        ayanamsa: (ayanamsa != Ayanamsa::Tropical).then_some(ayanamsa),
+        ayanamsa_degrees: (ayanamsa != Ayanamsa::Tropical).then_some(correction),
+        relocation: None,
+        daily_strength: None,
+        time_stability: None,
+    }
+}
+
+/// How the birth panchanga read at noon holds across the birth day,
+/// sampled hourly: the limbs that change during it, and the hora, which
+/// always does
+fn birth_time_stability(birth: &BirthData, ayanamsa: Ayanamsa, with_hora: bool) -> Result<TimeStability, EngineError> {
+    let date = NaiveDate::parse_from_str(&birth.date, "%Y-%m-%d").map_err(|e| {
+        EngineError::CalculationError(format!("Invalid birth date '{}': {}", birth.date, e))
+    })?;
+    const LIMBS: [&str; 4] = ["tithi_name", "nakshatra_name", "yoga_name", "karana_name"];
+    let mut reference = None;
+    let mut varies = [false; LIMBS.len()];
+    let confidence = birth_time::stability_across_day(60, |time| {
+        let offset = timezone::resolve_offset(&birth.timezone, birth.utc_offset_minutes, date, Some(time))?;
+        let reading = compute_panchanga_with_ayanamsa(
+            &birth.date,
+            &time.format("%H:%M").to_string(),
+            offset.offset_hours(),
+            ayanamsa,
+        );
+        let limbs = [reading.tithi_index, reading.nakshatra_index, reading.yoga_index, reading.karana_index];
+        // The first reading is the stand-in time's
+        let reference = *reference.get_or_insert(limbs);
+        for (varied, (limb, noon)) in varies.iter_mut().zip(limbs.iter().zip(reference)) {
+            *varied |= *limb != noon;
+        }
+        Ok(limbs)
+    })?;
+    let varied = LIMBS.iter().zip(varies).filter(|(_, varied)| *varied).map(|(limb, _)| *limb);
+    let hora = with_hora.then_some("hora");
+    Ok(TimeStability::at_noon(confidence, varied.chain(hora)))
+}
+
+/// The intermediate values behind a reading, for `options.debug`: the
+/// tropical longitudes at its Julian Day, the ayanamsa taken off them, and
+/// the angles the limbs are counted from
+fn debug_values(result: &PanchangaResult) -> serde_json::Value {
+    let jd = result.julian_day;
+    let ayanamsa = result.ayanamsa.unwrap_or(Ayanamsa::Tropical);
+    serde_json::json!({
+        "julian_day": jd,
+        "tropical_solar_longitude": calculate_solar_position(jd),
+        "tropical_lunar_longitude": calculate_lunar_position(jd),
+        "ayanamsa": ayanamsa,
+        "ayanamsa_degrees": ayanamsa.degrees_at(jd),
+        "solar_longitude": result.solar_longitude,
+        "lunar_longitude": result.lunar_longitude,
+        "moon_sun_elongation": (result.lunar_longitude - result.solar_longitude).rem_euclid(360.0),
+        "sun_moon_sum": (result.solar_longitude + result.lunar_longitude).rem_euclid(360.0),
+        "tithi_value": result.tithi_value,
+        "nakshatra_value": result.nakshatra_value,
+        "yoga_value": result.yoga_value,
+        "karana_value": result.karana_value,
+    })
+}
+
+/// Tarabala and Chandrabala of a local `date` at a place, for the person
+/// born at `birth`. `None` for days before the birth date.
+pub fn daily_strength_on(
+    birth: &BirthData,
+    date: NaiveDate,
+    latitude: f64,
+    longitude: f64,
+    tz_offset_hours: f64,
+    ayanamsa: Ayanamsa,
+) -> Result<Option<DailyStrength>, EngineError> {
+    let birth_date = NaiveDate::parse_from_str(&birth.date, "%Y-%m-%d").map_err(|e| {
+        EngineError::CalculationError(format!("Invalid birth date '{}': {}", birth.date, e))
+    })?;
+    if date < birth_date {
+        return Ok(None);
     }
+    let natal = compute_panchanga_with_ayanamsa(
+        &birth.date,
+        birth.time_or_stand_in(),
+        birth.resolve_offset()?.offset_hours(),
+        ayanamsa,
+    );
+    let (sunrise, _) = local_sun_times(date, latitude, longitude, tz_offset_hours)?;
+    let day = compute_panchanga_with_ayanamsa(
+        &date.format("%Y-%m-%d").to_string(),
+        &sunrise.format("%H:%M").to_string(),
+        tz_offset_hours,
+        ayanamsa,
+    );
+    Ok(Some(strength::daily_strength(date, natal.lunar_longitude, day.lunar_longitude)))
+}
+
+/// Determine the hora active at a local date and time.
+///
+/// `sunrise` and `sunset` are local "HH:MM" times, applied to every day
+/// involved (the previous day is used when `time` falls before sunrise).
+/// Returns `None` if any of the inputs cannot be parsed.
+pub fn compute_hora(date: &str, time: &str, sunrise: &str, sunset: &str) -> Option<Hora> {
+    let date = NaiveDate::parse_from_str(date, "%Y-%m-%d").ok()?;
+    let time = NaiveTime::parse_from_str(time, "%H:%M").ok()?;
+    let sunrise = NaiveTime::parse_from_str(sunrise, "%H:%M").ok()?;
+    let sunset = NaiveTime::parse_from_str(sunset, "%H:%M").ok()?;
+
+    let local = NaiveDateTime::new(date, time);
+    let horas = hora::horas_for_moment(local, |d| {
+        Ok((NaiveDateTime::new(d, sunrise), NaiveDateTime::new(d, sunset)))
+    })
+    .ok()?;
+
+    hora::hora_at(&horas, local).cloned()
+}
+
+/// Local sunrise and sunset for a date and location, computed with the NOAA
+/// solar algorithm.
+///
+/// On polar days and nights, where the sun does not cross the horizon, the
+/// conventional 06:00 / 18:00 local times are used instead.
+pub fn local_sun_times(
+    date: NaiveDate,
+    latitude: f64,
+    longitude: f64,
+    tz_offset_hours: f64,
+) -> Result<(NaiveDateTime, NaiveDateTime), EngineError> {
+    let day = SolarDay::calculate(date, latitude, longitude)
+        .map_err(|e| EngineError::CalculationError(e.to_string()))?;
+    let offset_minutes = (tz_offset_hours * 60.0).round() as i32;
+
+    Ok(day.local_sunrise_sunset(offset_minutes).unwrap_or_else(|| {
+        let fallback = |t: &str| {
+            NaiveDateTime::new(date, NaiveTime::parse_from_str(t, "%H:%M").unwrap_or_default())
+        };
+        (fallback(DEFAULT_SUNRISE), fallback(DEFAULT_SUNSET))
+    }))
+}
+
+/// Determine the hora active at a local date and time, deriving sunrise and
+/// sunset for each day involved from the location.
+pub fn compute_hora_at_location(
+    date: &str,
+    time: &str,
+    latitude: f64,
+    longitude: f64,
+    tz_offset_hours: f64,
+) -> Option<Hora> {
+    let date = NaiveDate::parse_from_str(date, "%Y-%m-%d").ok()?;
+    let time = NaiveTime::parse_from_str(time, "%H:%M").ok()?;
+
+    let local = NaiveDateTime::new(date, time);
+    let horas = hora::horas_for_moment(local, |d| {
+        local_sun_times(d, latitude, longitude, tz_offset_hours)
+    })
+    .ok()?;
+
+    hora::hora_at(&horas, local).cloned()
+}
+
+// ---------------------------------------------------------------------------
+// Ghati (Vedic time of day)
+// ---------------------------------------------------------------------------
+
+/// Ghatis from one sunrise to the next
+pub const GHATIS_PER_DAY: u32 = 60;
+/// Vipalas from one sunrise to the next (60 ghatis × 60 palas × 60 vipalas)
+const VIPALAS_PER_DAY: f64 = 216_000.0;
+
+/// Vedic time elapsed since local sunrise.
+///
+/// The span from sunrise to the next sunrise is divided into 60 ghatis,
+/// each ghati into 60 palas and each pala into 60 vipalas, so a ghati lasts
+/// about 24 minutes and a pala about 24 seconds depending on the season.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct GhatiTime {
+    pub ghati: u8,
+    pub pala: u8,
+    pub vipala: u8,
+    /// Sunrise that opened the current Vedic day
+    pub sunrise: DateTime<Utc>,
+    /// Sunrise that closes it
+    pub next_sunrise: DateTime<Utc>,
+}
+
+impl GhatiTime {
+    /// Length of one pala for this Vedic day
+    pub fn pala_duration(&self) -> Duration {
+        (self.next_sunrise - self.sunrise) / (GHATIS_PER_DAY * 60) as i32
+    }
+
+    /// Start of the pala following the current one
+    pub fn next_pala_at(&self) -> DateTime<Utc> {
+        let elapsed = self.ghati as i32 * 60 + self.pala as i32 + 1;
+        self.sunrise + (self.next_sunrise - self.sunrise) * elapsed / (GHATIS_PER_DAY * 60) as i32
+    }
+}
+
+/// Panchanga for a local date and time at a place, with the hora and solar
+/// times there; explicit sunrise/sunset options override the computed ones
+fn read_at(
+    date: &str,
+    time: &str,
+    tz_offset: f64,
+    latitude: f64,
+    longitude: f64,
+    ayanamsa: Ayanamsa,
+    options: &PanchangaOptions,
+) -> Result<PanchangaResult, EngineError> {
+    let mut result = compute_panchanga_with_ayanamsa(date, time, tz_offset, ayanamsa);
+    match (options.sunrise.as_deref(), options.sunset.as_deref()) {
+        (Some(sunrise), Some(sunset)) => {
+            result.hora = compute_hora(date, time, sunrise, sunset);
+            result.sunrise = Some(sunrise.to_string());
+            result.sunset = Some(sunset.to_string());
+        }
+        _ => {
+            result.hora = compute_hora_at_location(date, time, latitude, longitude, tz_offset);
+            if let Ok(d) = NaiveDate::parse_from_str(date, "%Y-%m-%d") {
+                let (rise, set) = local_sun_times(d, latitude, longitude, tz_offset)?;
+                result.sunrise = Some(rise.format("%H:%M").to_string());
+                result.sunset = Some(set.format("%H:%M").to_string());
+            }
+        }
+    }
+    Ok(result)
+}
+
+/// The values of a reading that depend on where it is read, for a relocation
+/// comparison. Tithi, nakshatra, yoga, karana and vara follow the moment alone.
+fn location_sensitive(result: &PanchangaResult, date: &str, time: &str) -> BTreeMap<String, serde_json::Value> {
+    BTreeMap::from([
+        ("local_time".to_string(), serde_json::json!(format!("{} {}", date, time))),
+        ("sunrise".to_string(), serde_json::json!(result.sunrise)),
+        ("sunset".to_string(), serde_json::json!(result.sunset)),
+        ("hora".to_string(), serde_json::json!(result.hora.as_ref().map(|h| h.ruler))),
+    ])
+}
+
+/// Ghati, pala and vipala at `at` for the given location.
+///
+/// Before today's sunrise the moment belongs to the Vedic day that began at
+/// the previous sunrise. Where the sun does not rise, 06:00 local mean solar
+/// time is used as sunrise.
+pub fn compute_ghati(at: DateTime<Utc>, latitude: f64, longitude: f64) -> Result<GhatiTime, EngineError> {
+    if !(-90.0..=90.0).contains(&latitude) {
+        return Err(EngineError::invalid_field(
+            "latitude",
+            ValidationCode::OutOfRange,
+            format!("Invalid latitude: {}. Must be between -90 and 90.", latitude),
+        ));
+    }
+    if !(-180.0..=180.0).contains(&longitude) {
+        return Err(EngineError::invalid_field(
+            "longitude",
+            ValidationCode::OutOfRange,
+            format!("Invalid longitude: {}. Must be between -180 and 180.", longitude),
+        ));
+    }
+
+    // Local mean solar date, so the sunrise searched for is the one nearest `at`
+    let solar_date = (at + Duration::seconds((longitude * 240.0).round() as i64)).date_naive();
+    let mut sunrise = sunrise_utc(solar_date, latitude, longitude)?;
+    let mut next_sunrise = sunrise_utc(solar_date.succ_opt().unwrap_or(solar_date), latitude, longitude)?;
+    if at < sunrise {
+        next_sunrise = sunrise;
+        sunrise = sunrise_utc(solar_date.pred_opt().unwrap_or(solar_date), latitude, longitude)?;
+    } else if at >= next_sunrise {
+        sunrise = next_sunrise;
+        next_sunrise = sunrise_utc(solar_date + Duration::days(2), latitude, longitude)?;
+    }
+
+    let span = (next_sunrise - sunrise).num_milliseconds() as f64;
+    let elapsed = (at - sunrise).num_milliseconds() as f64;
+    let vipalas = ((elapsed / span) * VIPALAS_PER_DAY).floor().clamp(0.0, VIPALAS_PER_DAY - 1.0) as u32;
+
+    Ok(GhatiTime {
+        ghati: (vipalas / 3600) as u8,
+        pala: (vipalas / 60 % 60) as u8,
+        vipala: (vipalas % 60) as u8,
+        sunrise,
+        next_sunrise,
+    })
+}
+
+/// Sunrise in UTC for a local date, falling back to 06:00 local mean solar time
+fn sunrise_utc(date: NaiveDate, latitude: f64, longitude: f64) -> Result<DateTime<Utc>, EngineError> {
+    let day = SolarDay::calculate(date, latitude, longitude)
+        .map_err(|e| EngineError::CalculationError(e.to_string()))?;
+
+    Ok(day.sunrise.time().unwrap_or_else(|| {
+        let six_am = date.and_hms_opt(6, 0, 0).unwrap_or_default().and_utc();
+        six_am - Duration::seconds((longitude * 240.0).round() as i64)
+    }))
 }
 
 // ---------------------------------------------------------------------------
@@ -348,47 +791,53 @@ fn generate_witness_prompt(result: &PanchangaResult) -> String {
 }
 
 // ---------------------------------------------------------------------------
-// Timezone offset helper
+// Plain-language summary
 // ---------------------------------------------------------------------------
 
-/// Derive a numeric UTC offset from a timezone string.
-///
-/// Supports IANA names for a handful of common zones and explicit
-/// "+HH:MM" / "-HH:MM" offsets. Defaults to 0.0 (UTC) if unknown.
-fn tz_offset_from_string(tz: &str) -> f64 {
-    // Try explicit numeric offset first: "+05:30", "-08:00", etc.
-    if tz.starts_with('+') || tz.starts_with('-') {
-        let parts: Vec<&str> = tz[1..].split(':').collect();
-        let sign: f64 = if tz.starts_with('-') { -1.0 } else { 1.0 };
-        let hours: f64 = parts.first().and_then(|s| s.parse().ok()).unwrap_or(0.0);
-        let minutes: f64 = parts.get(1).and_then(|s| s.parse().ok()).unwrap_or(0.0);
-        return sign * (hours + minutes / 60.0);
-    }
-
-    // Common IANA zone names (enough for MVP)
-    match tz {
-        "Asia/Kolkata" | "Asia/Calcutta" => 5.5,
-        "Asia/Tokyo" => 9.0,
-        "Asia/Shanghai" | "Asia/Hong_Kong" => 8.0,
-        "Asia/Dubai" => 4.0,
-        "Asia/Kathmandu" => 5.75,
-        "Europe/London" | "GMT" | "UTC" => 0.0,
-        "Europe/Paris" | "Europe/Berlin" | "CET" => 1.0,
-        "Europe/Moscow" => 3.0,
-        "America/New_York" | "US/Eastern" | "EST" => -5.0,
-        "America/Chicago" | "US/Central" | "CST" => -6.0,
-        "America/Denver" | "US/Mountain" | "MST" => -7.0,
-        "America/Los_Angeles" | "US/Pacific" | "PST" => -8.0,
-        "Pacific/Honolulu" | "HST" => -10.0,
-        "Australia/Sydney" | "AEST" => 10.0,
-        _ => 0.0,
+fn generate_summary(result: &PanchangaResult) -> String {
+    let mut summary = format!(
+        "This moment falls on {} under Tithi {} and Nakshatra {}. \
+         The Yoga is {} and the Karana is {}.",
+        result.vara_name,
+        result.tithi_name,
+        result.nakshatra_name,
+        result.yoga_name,
+        result.karana_name,
+    );
+    if let Some(hora) = &result.hora {
+        summary.push_str(&format!(" The active hora is ruled by {}.", hora.ruler.as_str()));
     }
+    if let Some(strength) = &result.daily_strength {
+        summary.push_str(&format!(
+            " On {} your Tarabala is {} and the Moon is in the {} house from your natal Moon.",
+            strength.date,
+            strength.tarabala.name,
+            ordinal(strength.chandrabala.house),
+        ));
+    }
+    summary
+}
+
+fn ordinal(n: u8) -> String {
+    let suffix = match (n % 10, n % 100) {
+        (_, 11..=13) => "th",
+        (1, _) => "st",
+        (2, _) => "nd",
+        (3, _) => "rd",
+        _ => "th",
+    };
+    format!("{}{}", n, suffix)
 }
 
 // ---------------------------------------------------------------------------
 // PanchangaEngine — ConsciousnessEngine implementation
 // ---------------------------------------------------------------------------
 
+/// Local sunrise used for hora calculation when the sun does not rise or set
+const DEFAULT_SUNRISE: &str = "06:00";
+/// Local sunset used for hora calculation when the sun does not rise or set
+const DEFAULT_SUNSET: &str = "18:00";
+
 /// The Panchanga consciousness engine.
 ///
 /// Stateless — all configuration comes through `EngineInput`.
@@ -420,25 +869,168 @@ impl ConsciousnessEngine for PanchangaEngine {
         0 // Available at the earliest consciousness phase
     }
 
+    fn option_keys(&self) -> Option<&'static [&'static str]> {
+        Some(PanchangaOptions::keys())
+    }
+
+    fn birth_time_sensitivity(&self) -> TimeSensitivity {
+        TimeSensitivity::Partial
+    }
+
+    fn check_input(&self, input: &EngineInput) -> Result<(), EngineError> {
+        let birth = input.primary_birth_data().ok_or_else(|| {
+            EngineError::CalculationError("birth_data is required for Panchanga calculations".into())
+        })?;
+        birth.validate()?;
+        Ayanamsa::from_options(&input.options)?;
+        let options: PanchangaOptions = input.engine_options()?;
+        if let Some(relocate_to) = &options.relocate_to {
+            relocate_to.validate()?;
+        }
+        input.as_of().map(|_| ())
+    }
+
+    fn examples(&self) -> Vec<EngineExample> {
+        vec![
+            examples::birth_data_example(),
+            EngineExample::new(
+                "sidereal",
+                "Panchanga with the Lahiri ayanamsa",
+                examples::sample_input(serde_json::json!({ "ayanamsa": "lahiri" })),
+            ),
+            EngineExample::new(
+                "relocated",
+                "Sunrise, sunset and hora read in Lisbon instead of the birth place",
+                examples::sample_input(serde_json::json!({
+                    "relocate_to": { "lat": 38.7223, "lon": -9.1393, "tz": "Europe/Lisbon" }
+                })),
+            ),
+        ]
+    }
+
+    fn describe(&self) -> EngineDescription {
+        EngineDescription::new(
+            "Calculates the five limbs of Vedic time for a moment and place: tithi, nakshatra, yoga, \
+             karana and vara, with sunrise, sunset and the planetary hour. Can read the birth moment, \
+             another day with options.as_of, or the same moment at another place.",
+        )
+        .with_input("birth_data.date", true, "Date of the reading")
+        .with_input("birth_data.time", false, "Time of the reading; read at noon when unknown")
+        .with_input("birth_data.latitude", true, "Latitude of the place")
+        .with_input("birth_data.longitude", true, "Longitude of the place")
+        .with_input("birth_data.timezone", true, "IANA timezone of the place")
+        .with_input("options.ayanamsa", false, "Sidereal reference; tropical by default")
+        .with_input("options.relocate_to", false, "Another place to read the same moment at")
+        .with_outputs("The five limbs with their indices and qualities, solar times, the hora and a daily strength")
+        .with_traditions(&["Vedic Jyotish", "Panchanga"])
+        .with_limitation("Tropical unless options.ayanamsa is given, unlike most printed panchangas")
+        .with_limitation("Tithi and nakshatra change during the day; the reading is for the given moment")
+    }
+
     async fn calculate(&self, input: EngineInput) -> Result<EngineOutput, EngineError> {
         let start = Instant::now();
 
-        let birth = input.birth_data.as_ref().ok_or_else(|| {
+        let birth = input.primary_birth_data().ok_or_else(|| {
             EngineError::CalculationError(
                 "birth_data is required for Panchanga calculations".into(),
             )
         })?;
+        birth.validate()?;
+
+        let resolved = birth.resolve_offset()?;
+        let ayanamsa = Ayanamsa::from_options(&input.options)?;
+        let options: PanchangaOptions = input.engine_options()?;
+
+        // A retrospective reading is for the `as_of` moment where the person
+        // is (`location`, else the birth place), otherwise for the birth
+        // moment at the birth place
+        let as_of = input.as_of()?;
+        let (latitude, longitude) = match (&as_of, &input.location) {
+            (Some(_), Some(location)) => (location.latitude, location.longitude),
+            _ => (birth.latitude, birth.longitude),
+        };
+        let (date, time, tz_offset) = match as_of {
+            Some(as_of) => {
+                let offset = input.local_offset_at(as_of)?.unwrap_or_default();
+                let local = as_of.naive_utc() + chrono::Duration::seconds(offset as i64);
+                (
+                    local.format("%Y-%m-%d").to_string(),
+                    local.format("%H:%M").to_string(),
+                    offset as f64 / 3600.0,
+                )
+            }
+            None => (
+                birth.date.clone(),
+                birth.time_or_stand_in().to_string(),
+                resolved.offset_hours(),
+            ),
+        };
+        let (date, time) = (date.as_str(), time.as_str());
+
+        let mut result = read_at(date, time, tz_offset, latitude, longitude, ayanamsa, &options)?;
+        result.timezone_resolution = Some(resolved);
+
+        // A relocated reading keeps the moment and moves the place
+        if let Some(relocate_to) = options.relocate_to.clone() {
+            relocate_to.validate()?;
+            let instant = match as_of {
+                Some(as_of) => as_of,
+                None => birth.birth_instant()?,
+            };
+            let offset = relocate_to.offset_at(instant)?;
+            let local = instant.naive_utc() + chrono::Duration::seconds(offset as i64);
+            let (r_date, r_time) = (local.format("%Y-%m-%d").to_string(), local.format("%H:%M").to_string());
+            let relocated = read_at(
+                &r_date,
+                &r_time,
+                offset as f64 / 3600.0,
+                relocate_to.lat,
+                relocate_to.lon,
+                ayanamsa,
+                &options,
+            )?;
+            let relocation = Relocation::compare(
+                relocate_to,
+                location_sensitive(&result, date, time),
+                location_sensitive(&relocated, &r_date, &r_time),
+            );
+            result = PanchangaResult {
+                timezone_resolution: result.timezone_resolution.take(),
+                relocation: Some(relocation),
+                ..relocated
+            };
+        }
 
-        let date = &birth.date;
-        let time = birth.time.as_deref().unwrap_or("12:00");
-        let tz_offset = tz_offset_from_string(&birth.timezone);
+        if as_of.is_none() && birth.time_is_unknown() {
+            result.time_stability = Some(birth_time_stability(birth, ayanamsa, result.hora.is_some())?);
+        }
+
+        // The person's strength for the day being lived: `as_of`'s, else today's
+        let day_moment = as_of.unwrap_or(input.current_time);
+        let day_offset = input.local_offset_at(day_moment)?.unwrap_or_default();
+        let day = (day_moment.naive_utc() + chrono::Duration::seconds(day_offset as i64)).date();
+        let (day_latitude, day_longitude) = match &input.location {
+            Some(location) => (location.latitude, location.longitude),
+            None => (birth.latitude, birth.longitude),
+        };
+        result.daily_strength = daily_strength_on(
+            birth,
+            day,
+            day_latitude,
+            day_longitude,
+            day_offset as f64 / 3600.0,
+            ayanamsa,
+        )?;
 
-        let result = compute_panchanga(date, time, tz_offset);
         let witness_prompt = generate_witness_prompt(&result);
+        let summary = generate_summary(&result);
 
-        let result_json = serde_json::to_value(&result).map_err(|e| {
+        let mut result_json = serde_json::to_value(&result).map_err(|e| {
             EngineError::CalculationError(format!("failed to serialize PanchangaResult: {e}"))
         })?;
+        if input.debug() {
+            result_json[DEBUG_KEY] = debug_values(&result);
+        }
 
         let elapsed_ms = start.elapsed().as_secs_f64() * 1000.0;
 
@@ -446,6 +1038,7 @@ impl ConsciousnessEngine for PanchangaEngine {
             engine_id: self.engine_id().to_string(),
             result: result_json,
             witness_prompt,
+            summary: Some(summary),
             consciousness_level: 0,
             metadata: CalculationMetadata {
                 calculation_time_ms: elapsed_ms,
@@ -453,6 +1046,9 @@ impl ConsciousnessEngine for PanchangaEngine {
                 precision_achieved: format!("{:?}", input.precision),
                 cached: false,
                 timestamp: Utc::now(),
+                engine_version: None,
+                warnings: Vec::new(),
+                usage: None,
             },
         })
     }
@@ -518,6 +1114,9 @@ impl ConsciousnessEngine for PanchangaEngine {
             messages.push("all Panchanga values within expected ranges".to_string());
         }
 
+        // An ambiguous birth offset is a warning, not a failure
+        messages.extend(timezone::resolution_warnings(&output.result));
+
         let confidence = if valid { 1.0 } else { 0.0 };
 
         Ok(ValidationResult {
@@ -528,18 +1127,71 @@ impl ConsciousnessEngine for PanchangaEngine {
     }
 
     fn cache_key(&self, input: &EngineInput) -> String {
-        let birth = input.birth_data.as_ref();
+        let birth = input.primary_birth_data();
         let date = birth.map(|b| b.date.as_str()).unwrap_or("");
         let time = birth
             .and_then(|b| b.time.as_deref())
-            .unwrap_or("12:00");
+            .unwrap_or("unknown");
         let lat = birth.map(|b| b.latitude).unwrap_or(0.0);
         let lon = birth.map(|b| b.longitude).unwrap_or(0.0);
-
-        let raw = format!("panchanga:{}:{}:{:.6}:{:.6}", date, time, lat, lon);
+        let sunrise = input
+            .options
+            .get("sunrise")
+            .and_then(|v| v.as_str())
+            .unwrap_or("solar");
+        let sunset = input
+            .options
+            .get("sunset")
+            .and_then(|v| v.as_str())
+            .unwrap_or("solar");
+        let tz = birth.map(|b| b.timezone.as_str()).unwrap_or("");
+        let utc_offset = birth
+            .and_then(|b| b.utc_offset_minutes)
+            .map(|m| m.to_string())
+            .unwrap_or_default();
+
+        let ayanamsa = input
+            .options
+            .get("ayanamsa")
+            .and_then(|v| v.as_str())
+            .and_then(Ayanamsa::parse)
+            .unwrap_or_default();
+
+        let mut raw = format!(
+            "panchanga:{}:{}:{}:{}:{:.6}:{:.6}:{}:{}:{:?}",
+            date, time, tz, utc_offset, lat, lon, sunrise, sunset, ayanamsa
+        );
+        if let Some(relocate_to) = input.options.get(RELOCATE_TO_OPTION) {
+            raw.push_str(&format!(":relocate={}", relocate_to));
+        }
+        let as_of = input.as_of().ok().flatten();
+        match as_of {
+            Some(as_of) => raw.push_str(&format!(":as_of={}", as_of.timestamp())),
+            None => {
+                // The daily strength is read for today's local date
+                let offset = input.local_offset_at(input.current_time).ok().flatten().unwrap_or(0);
+                let today = (input.current_time.naive_utc() + chrono::Duration::seconds(offset as i64)).date();
+                raw.push_str(&format!(":day={}", today));
+            }
+        }
+        if let Some(location) = &input.location {
+            raw.push_str(&format!(
+                ":at={:.6},{:.6},{}",
+                location.latitude,
+                location.longitude,
+                location.timezone.as_deref().unwrap_or("")
+            ));
+        }
         let hash = Sha256::digest(raw.as_bytes());
         format!("panchanga:{:x}", hash)
     }
+
+    /// The reading is for the birth moment or the `as_of` moment and place
+    /// (or `relocate_to`), and the daily strength for the day of `as_of` or
+    /// today, all part of the cache key, so it never changes.
+    fn cache_ttl(&self, _input: &EngineInput) -> CacheTtl {
+        CacheTtl::Infinite
+    }
 }
 
 // ---------------------------------------------------------------------------
@@ -555,17 +1207,24 @@ mod tests {
     fn test_birth_data() -> BirthData {
         BirthData {
             name: Some("Test".to_string()),
+            display_name: None,
             date: "1991-08-13".to_string(),
             time: Some("13:31".to_string()),
+            time_unknown: false,
             latitude: 12.9716,
             longitude: 77.5946,
+            altitude: None,
             timezone: "Asia/Kolkata".to_string(),
+            place: None,
+            utc_offset_minutes: None,
         }
     }
 
     fn test_input() -> EngineInput {
         EngineInput {
             birth_data: Some(test_birth_data()),
+            secondary_birth_data: None,
+            subjects: Vec::new(),
             current_time: Utc::now(),
             location: None,
             precision: Precision::Standard,
@@ -594,6 +1253,14 @@ mod tests {
         assert!(lunar >= 0.0 && lunar < 360.0, "lunar = {lunar}");
     }
 
+    #[test]
+    fn test_lunar_node_regresses() {
+        // 125.04 degrees at J2000, moving back about 19.3 degrees a year
+        assert!((calculate_lunar_node(2451545.0) - 125.0445).abs() < 1e-3);
+        let year_later = calculate_lunar_node(2451545.0 + 365.25);
+        assert!((year_later - (125.0445 - 19.3413)).abs() < 0.01, "node = {year_later}");
+    }
+
     #[test]
     fn test_tithi_range() {
         let jd = calculate_julian_day("1991-08-13", "13:31", 5.5);
@@ -637,6 +1304,34 @@ mod tests {
         assert!(!p.vara_name.is_empty());
     }
 
+    #[test]
+    fn test_compute_hora_uses_weekday_lord() {
+        // 1991-08-13 was a Tuesday; the first hora after sunrise is ruled by Mars
+        let h = compute_hora("1991-08-13", "06:30", "06:00", "18:30").unwrap();
+        assert_eq!(h.number, 1);
+        assert_eq!(h.ruler, noesis_core::hora::HoraPlanet::Mars);
+
+        // Before sunrise we are still in Monday's night horas
+        let h = compute_hora("1991-08-13", "05:00", "06:00", "18:30").unwrap();
+        assert!(!h.is_day);
+        assert!(h.number > 12);
+    }
+
+    #[test]
+    fn test_hora_from_location_sun_times() {
+        // Bangalore sunrise on 1991-08-13 is ~06:08 IST, so 06:05 is still night
+        let h = compute_hora_at_location("1991-08-13", "06:05", 12.9716, 77.5946, 5.5).unwrap();
+        assert!(!h.is_day);
+        let h = compute_hora_at_location("1991-08-13", "06:15", 12.9716, 77.5946, 5.5).unwrap();
+        assert_eq!(h.number, 1);
+
+        // Polar day in Tromsø falls back to 06:00 / 18:00
+        let d = NaiveDate::from_ymd_opt(2024, 6, 21).unwrap();
+        let (rise, set) = local_sun_times(d, 69.6492, 18.9553, 2.0).unwrap();
+        assert_eq!(rise.format("%H:%M").to_string(), DEFAULT_SUNRISE);
+        assert_eq!(set.format("%H:%M").to_string(), DEFAULT_SUNSET);
+    }
+
     #[test]
     fn test_cache_key_deterministic() {
         let engine = PanchangaEngine::new();
@@ -662,12 +1357,153 @@ mod tests {
         let output = engine.calculate(input).await.expect("calculate failed");
         assert_eq!(output.engine_id, "panchanga");
         assert!(!output.witness_prompt.is_empty());
+        assert!(output.summary.as_deref().unwrap().contains("Nakshatra"));
         assert_eq!(output.metadata.backend, "native-rust");
 
         // The result should deserialize back into PanchangaResult
         let pr: PanchangaResult =
             serde_json::from_value(output.result).expect("bad PanchangaResult JSON");
         assert!(pr.solar_longitude >= 0.0 && pr.solar_longitude < 360.0);
+        assert!(pr.hora.is_some());
+    }
+
+    #[test]
+    fn test_ayanamsa_shifts_nakshatra_not_tithi() {
+        assert!((Ayanamsa::Lahiri.degrees_at(2451545.0) - 23.853).abs() < 1e-3);
+        assert_eq!(Ayanamsa::Tropical.degrees_at(2460000.0), 0.0);
+
+        let tropical = compute_panchanga("1991-08-13", "13:31", 5.5);
+        let sidereal = compute_panchanga_with_ayanamsa("1991-08-13", "13:31", 5.5, Ayanamsa::Lahiri);
+        let diff = (tropical.lunar_longitude - sidereal.lunar_longitude).rem_euclid(360.0);
+        assert!((diff - sidereal.ayanamsa_degrees.unwrap()).abs() < 1e-9);
+        assert!((tropical.tithi_value - sidereal.tithi_value).abs() < 1e-9);
+        assert_ne!(tropical.nakshatra_index, sidereal.nakshatra_index);
+        assert_eq!(tropical.ayanamsa, None);
+    }
+
+    #[tokio::test]
+    async fn test_calculate_rejects_unknown_ayanamsa() {
+        let engine = PanchangaEngine::new();
+        let mut input = test_input();
+        input.options.insert("ayanamsa".to_string(), serde_json::json!("KP"));
+        let output = engine.calculate(input.clone()).await.unwrap();
+        assert_eq!(output.result["ayanamsa"], "krishnamurti");
+
+        input.options.insert("ayanamsa".to_string(), serde_json::json!("galactic"));
+        let err = engine.calculate(input).await.unwrap_err();
+        assert!(matches!(err, EngineError::InvalidInput(ref e) if e[0].field == "options.ayanamsa"));
+    }
+
+    #[tokio::test]
+    async fn test_calculate_as_of_reads_that_moment() {
+        let engine = PanchangaEngine::new();
+        let mut input = test_input();
+        // 06:30 UTC is 12:00 in Bengaluru
+        input.options.insert("as_of".to_string(), serde_json::json!("2001-09-09T06:30:00Z"));
+        let output = engine.calculate(input.clone()).await.unwrap();
+        let expected = compute_panchanga("2001-09-09", "12:00", 5.5);
+        assert!((output.result["julian_day"].as_f64().unwrap() - expected.julian_day).abs() < 1e-9);
+
+        assert_ne!(engine.cache_key(&input), engine.cache_key(&test_input()));
+    }
+
+    #[tokio::test]
+    async fn test_unknown_birth_time_reads_noon_with_stability() {
+        let engine = PanchangaEngine::new();
+        let mut input = test_input();
+        let birth = input.birth_data.as_mut().unwrap();
+        birth.time = None;
+        birth.time_unknown = true;
+        let output = engine.calculate(input.clone()).await.unwrap();
+        let expected = compute_panchanga("1991-08-13", "12:00", 5.5);
+        assert!((output.result["julian_day"].as_f64().unwrap() - expected.julian_day).abs() < 1e-9);
+
+        // The Moon crosses a tithi or nakshatra boundary on most days
+        let typed: noesis_core::results::PanchangaResult = output.parse_as().unwrap();
+        let stability = typed.time_stability.unwrap();
+        assert_eq!(stability.assumed_time, "12:00");
+        assert!(stability.confidence > 0.0 && stability.confidence < 1.0);
+        assert!(stability.varies.iter().any(|field| field == "hora"));
+
+        let known = engine.calculate(test_input()).await.unwrap();
+        assert!(known.result.get("time_stability").is_none());
+        assert_ne!(engine.cache_key(&input), engine.cache_key(&test_input()));
+    }
+
+    #[tokio::test]
+    async fn test_debug_reports_intermediate_values() {
+        let engine = PanchangaEngine::new();
+        let mut input = test_input();
+        input.options.insert("ayanamsa".to_string(), serde_json::json!("lahiri"));
+        input.options.insert(noesis_core::DEBUG_OPTION.to_string(), serde_json::json!(true));
+        let output = engine.calculate(input).await.unwrap();
+
+        let debug = &output.result[DEBUG_KEY];
+        let ayanamsa = debug["ayanamsa_degrees"].as_f64().unwrap();
+        assert!(ayanamsa > 23.0 && ayanamsa < 25.0);
+        let tropical = debug["tropical_lunar_longitude"].as_f64().unwrap();
+        let sidereal = output.result["lunar_longitude"].as_f64().unwrap();
+        assert!(((tropical - ayanamsa).rem_euclid(360.0) - sidereal).abs() < 1e-9);
+        let elongation = debug["moon_sun_elongation"].as_f64().unwrap();
+        assert!((elongation / 12.0 - output.result["tithi_value"].as_f64().unwrap()).abs() < 1e-9);
+
+        let plain = engine.calculate(test_input()).await.unwrap();
+        assert!(plain.result.get(DEBUG_KEY).is_none());
+    }
+
+    #[tokio::test]
+    async fn test_calculate_as_of_reads_current_location() {
+        let engine = PanchangaEngine::new();
+        let mut input = test_input();
+        input.options.insert("as_of".to_string(), serde_json::json!("2001-09-09T11:00:00Z"));
+        let at_birth_place = engine.calculate(input.clone()).await.unwrap();
+
+        // 11:00 UTC is 12:00 in Lisbon, whose sun rises hours after Bengaluru's
+        input.location = Some(noesis_core::Coordinates {
+            latitude: 38.72,
+            longitude: -9.14,
+            altitude: None,
+            timezone: Some("Europe/Lisbon".to_string()),
+        });
+        let output = engine.calculate(input.clone()).await.unwrap();
+        let expected = compute_panchanga("2001-09-09", "12:00", 1.0);
+        assert!((output.result["julian_day"].as_f64().unwrap() - expected.julian_day).abs() < 1e-9);
+        assert_ne!(output.result["sunrise"], at_birth_place.result["sunrise"]);
+        let mut without_location = input.clone();
+        without_location.location = None;
+        assert_ne!(engine.cache_key(&input), engine.cache_key(&without_location));
+
+        // A natal reading stays at the birth place
+        input.options.remove("as_of");
+        let natal = engine.calculate(input).await.unwrap();
+        assert_eq!(natal.result["sunrise"], engine.calculate(test_input()).await.unwrap().result["sunrise"]);
+    }
+
+    #[tokio::test]
+    async fn test_relocation_keeps_the_moment_and_moves_the_place() {
+        let engine = PanchangaEngine::new();
+        let home = engine.calculate(test_input()).await.unwrap();
+        let mut input = test_input();
+        input.options.insert(
+            "relocate_to".to_string(),
+            serde_json::json!({"lat": 40.7128, "lon": -74.006, "tz": "America/New_York"}),
+        );
+        let output = engine.calculate(input.clone()).await.unwrap();
+        let typed: noesis_core::results::PanchangaResult = output.parse_as().unwrap();
+        let relocation = typed.relocation.unwrap();
+
+        // Same instant, so the same limbs; the place-bound values move
+        assert_eq!(output.result["julian_day"], home.result["julian_day"]);
+        assert_eq!(output.result["tithi_index"], home.result["tithi_index"]);
+        assert_eq!(relocation.home["sunrise"], home.result["sunrise"]);
+        assert_eq!(relocation.relocated["sunrise"], output.result["sunrise"]);
+        assert!(relocation.changed.iter().any(|key| key == "sunrise"));
+        assert!(relocation.changed.iter().any(|key| key == "local_time"));
+        assert_ne!(engine.cache_key(&input), engine.cache_key(&test_input()));
+
+        input.options.insert("relocate_to".to_string(), serde_json::json!({"lat": 91.0, "lon": 0.0}));
+        let err = engine.calculate(input).await.unwrap_err();
+        assert!(matches!(err, EngineError::InvalidInput(ref e) if e[0].field == "options.relocate_to.lat"));
     }
 
     #[tokio::test]
@@ -675,6 +1511,8 @@ mod tests {
         let engine = PanchangaEngine::new();
         let input = EngineInput {
             birth_data: None,
+            secondary_birth_data: None,
+            subjects: Vec::new(),
             current_time: Utc::now(),
             location: None,
             precision: Precision::Standard,
@@ -693,4 +1531,104 @@ mod tests {
         assert!(vr.valid);
         assert_eq!(vr.confidence, 1.0);
     }
+
+    #[tokio::test]
+    async fn test_calculate_uses_historical_offset() {
+        let engine = PanchangaEngine::new();
+        let mut input = test_input();
+        if let Some(birth) = input.birth_data.as_mut() {
+            // War time: India was on UTC+06:30 in 1943
+            birth.date = "1943-06-01".to_string();
+        }
+        let output = engine.calculate(input).await.unwrap();
+        let pr: PanchangaResult = serde_json::from_value(output.result).unwrap();
+        assert_eq!(pr.timezone_resolution.unwrap().offset_minutes(), 390);
+    }
+
+    #[tokio::test]
+    async fn test_validate_warns_on_ambiguous_offset() {
+        let engine = PanchangaEngine::new();
+        let mut input = test_input();
+        if let Some(birth) = input.birth_data.as_mut() {
+            // 01:30 occurred twice in London when BST ended
+            birth.date = "1990-10-28".to_string();
+            birth.time = Some("01:30".to_string());
+            birth.timezone = "Europe/London".to_string();
+        }
+        let output = engine.calculate(input).await.unwrap();
+        let vr = engine.validate(&output).await.unwrap();
+        assert!(vr.valid);
+        assert!(vr.messages.iter().any(|m| m.contains("occurs twice")));
+    }
+
+    #[tokio::test]
+    async fn test_result_matches_core_schema() {
+        let engine = PanchangaEngine::new();
+        let output = engine.calculate(test_input()).await.unwrap();
+        let typed: noesis_core::results::PanchangaResult = output.parse_as().unwrap();
+        assert_eq!(typed.tithi_name, output.result["tithi_name"]);
+        assert!(typed.hora.is_some());
+        assert!(typed.timezone_resolution.is_some());
+        assert!(typed.daily_strength.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_daily_strength_reads_the_day_of_as_of() {
+        let engine = PanchangaEngine::new();
+        let mut input = test_input();
+        input.options.insert("as_of".to_string(), serde_json::json!("2001-09-09T06:30:00Z"));
+        let output = engine.calculate(input).await.unwrap();
+        let strength = &output.result["daily_strength"];
+        assert_eq!(strength["date"], "2001-09-09");
+
+        // The Moon at Bengaluru's sunrise that day against the natal Moon
+        let date = NaiveDate::from_ymd_opt(2001, 9, 9).unwrap();
+        let expected = daily_strength_on(&test_birth_data(), date, 12.9716, 77.5946, 5.5, Ayanamsa::Tropical)
+            .unwrap()
+            .unwrap();
+        assert_eq!(strength["tarabala"]["name"], expected.tarabala.name.as_str());
+        assert_eq!(strength["chandrabala"]["house"], expected.chandrabala.house);
+        assert_eq!(strength["birth_nakshatra"], expected.birth_nakshatra.as_str());
+        assert!(output.summary.unwrap().contains("Tarabala"));
+    }
+
+    #[test]
+    fn test_no_daily_strength_before_birth() {
+        let date = NaiveDate::from_ymd_opt(1990, 1, 1).unwrap();
+        let strength = daily_strength_on(&test_birth_data(), date, 12.9716, 77.5946, 5.5, Ayanamsa::Tropical).unwrap();
+        assert!(strength.is_none());
+    }
+
+    #[test]
+    fn test_cache_key_follows_the_day() {
+        let engine = PanchangaEngine::new();
+        let mut input = test_input();
+        input.current_time = "2026-10-15T10:00:00Z".parse().unwrap();
+        let morning = engine.cache_key(&input);
+        input.current_time = "2026-10-15T12:00:00Z".parse().unwrap();
+        assert_eq!(engine.cache_key(&input), morning);
+        // 19:00 UTC is already the 16th in Bengaluru
+        input.current_time = "2026-10-15T19:00:00Z".parse().unwrap();
+        assert_ne!(engine.cache_key(&input), morning);
+    }
+
+    #[test]
+    fn test_ghati_counts_from_local_sunrise() {
+        let at = |s: &str| s.parse::<DateTime<Utc>>().unwrap();
+
+        // Bengaluru sunrise on 2024-01-15 is about 06:43 IST (01:13 UTC)
+        let g = compute_ghati(at("2024-01-15T06:00:00Z"), 12.9716, 77.5946).unwrap();
+        assert_eq!(g.sunrise.date_naive().to_string(), "2024-01-15");
+        assert!((11..=12).contains(&g.ghati), "ghati was {}", g.ghati);
+        assert!(g.next_sunrise - g.sunrise > Duration::hours(23));
+        assert!(g.next_pala_at() > at("2024-01-15T06:00:00Z"));
+        assert!(g.next_pala_at() - at("2024-01-15T06:00:00Z") <= g.pala_duration());
+
+        // 06:00 IST is before sunrise, so it closes the previous Vedic day
+        let g = compute_ghati(at("2024-01-15T00:30:00Z"), 12.9716, 77.5946).unwrap();
+        assert_eq!(g.sunrise.date_naive().to_string(), "2024-01-14");
+        assert_eq!(g.ghati, 58);
+
+        assert!(compute_ghati(at("2024-01-15T00:30:00Z"), 95.0, 0.0).is_err());
+    }
 }
@@ -0,0 +1,145 @@
+//! Muhurtas: the auspicious and inauspicious periods of a day
+//!
+//! Rahu Kalam, Yamaganda and Gulika Kaal each take one eighth of the span
+//! from sunrise to sunset, which eighth depending on the weekday. Abhijit is
+//! the eighth of the fifteen day muhurtas, around local noon, and is not
+//! observed on Wednesdays. Brahma Muhurta is the second-to-last of the
+//! night's fifteen muhurtas, ending one muhurta (48 minutes at the equinox)
+//! before sunrise.
+
+use chrono::{Duration, NaiveDateTime};
+use serde::{Deserialize, Serialize};
+
+/// Eighth of the day (0-based) holding Rahu Kalam, by vara (0 = Sunday)
+const RAHU_KALAM_PART: [i32; 7] = [7, 1, 6, 4, 5, 3, 2];
+/// Eighth of the day holding Yamaganda, by vara
+const YAMAGANDA_PART: [i32; 7] = [4, 3, 2, 1, 0, 6, 5];
+/// Eighth of the day holding Gulika Kaal, by vara
+const GULIKA_KAAL_PART: [i32; 7] = [6, 5, 4, 3, 2, 1, 0];
+
+/// Vara of Wednesday, when Abhijit is not observed
+const WEDNESDAY: u8 = 3;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MuhurtaKind {
+    RahuKalam,
+    Yamaganda,
+    GulikaKaal,
+    Abhijit,
+    BrahmaMuhurta,
+}
+
+impl MuhurtaKind {
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            MuhurtaKind::RahuKalam => "Rahu Kalam",
+            MuhurtaKind::Yamaganda => "Yamaganda",
+            MuhurtaKind::GulikaKaal => "Gulika Kaal",
+            MuhurtaKind::Abhijit => "Abhijit Muhurta",
+            MuhurtaKind::BrahmaMuhurta => "Brahma Muhurta",
+        }
+    }
+
+    /// Abhijit and Brahma Muhurta are auspicious, the other three periods
+    /// are avoided for anything new or important
+    pub fn is_auspicious(&self) -> bool {
+        matches!(self, MuhurtaKind::Abhijit | MuhurtaKind::BrahmaMuhurta)
+    }
+}
+
+/// One muhurta, in local time
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Muhurta {
+    pub kind: MuhurtaKind,
+    pub start: NaiveDateTime,
+    pub end: NaiveDateTime,
+}
+
+impl Muhurta {
+    /// Minutes of `[start, end)` this muhurta covers
+    pub fn overlap_minutes(&self, start: NaiveDateTime, end: NaiveDateTime) -> i64 {
+        (self.end.min(end) - self.start.max(start)).num_minutes().max(0)
+    }
+}
+
+/// The muhurtas of the day from `sunrise` to `sunset`, weekday `vara`
+/// (0 = Sunday), in time order
+pub fn day_muhurtas(sunrise: NaiveDateTime, sunset: NaiveDateTime, vara: u8) -> Vec<Muhurta> {
+    let day = sunset - sunrise;
+    let vara = (vara % 7) as usize;
+    let eighth = |part: i32| Muhurta {
+        kind: MuhurtaKind::RahuKalam,
+        start: sunrise + day * part / 8,
+        end: sunrise + day * (part + 1) / 8,
+    };
+
+    // The night before this sunrise, approximated by the daylight's complement
+    let night_muhurta = (Duration::hours(24) - day) / 15;
+    let mut muhurtas = vec![
+        Muhurta {
+            kind: MuhurtaKind::BrahmaMuhurta,
+            start: sunrise - night_muhurta * 2,
+            end: sunrise - night_muhurta,
+        },
+        Muhurta { kind: MuhurtaKind::RahuKalam, ..eighth(RAHU_KALAM_PART[vara]) },
+        Muhurta { kind: MuhurtaKind::Yamaganda, ..eighth(YAMAGANDA_PART[vara]) },
+        Muhurta { kind: MuhurtaKind::GulikaKaal, ..eighth(GULIKA_KAAL_PART[vara]) },
+    ];
+    if vara as u8 != WEDNESDAY {
+        muhurtas.push(Muhurta {
+            kind: MuhurtaKind::Abhijit,
+            start: sunrise + day * 7 / 15,
+            end: sunrise + day * 8 / 15,
+        });
+    }
+    muhurtas.sort_by_key(|m| m.start);
+    muhurtas
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::NaiveDate;
+
+    fn at(hour: u32, minute: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(2024, 3, 20).unwrap().and_hms_opt(hour, minute, 0).unwrap()
+    }
+
+    fn find(muhurtas: &[Muhurta], kind: MuhurtaKind) -> Option<&Muhurta> {
+        muhurtas.iter().find(|m| m.kind == kind)
+    }
+
+    #[test]
+    fn test_equinox_monday() {
+        // 06:00-18:00 daylight: eighths of 90 minutes, muhurtas of 48
+        let muhurtas = day_muhurtas(at(6, 0), at(18, 0), 1);
+        let rahu = find(&muhurtas, MuhurtaKind::RahuKalam).unwrap();
+        assert_eq!((rahu.start, rahu.end), (at(7, 30), at(9, 0)));
+        let yamaganda = find(&muhurtas, MuhurtaKind::Yamaganda).unwrap();
+        assert_eq!((yamaganda.start, yamaganda.end), (at(10, 30), at(12, 0)));
+        let abhijit = find(&muhurtas, MuhurtaKind::Abhijit).unwrap();
+        assert_eq!((abhijit.start, abhijit.end), (at(11, 36), at(12, 24)));
+        let brahma = find(&muhurtas, MuhurtaKind::BrahmaMuhurta).unwrap();
+        assert_eq!((brahma.start, brahma.end), (at(4, 24), at(5, 12)));
+
+        assert!(muhurtas.windows(2).all(|pair| pair[0].start <= pair[1].start));
+    }
+
+    #[test]
+    fn test_no_abhijit_on_wednesday() {
+        let muhurtas = day_muhurtas(at(6, 0), at(18, 0), 3);
+        assert!(find(&muhurtas, MuhurtaKind::Abhijit).is_none());
+        let rahu = find(&muhurtas, MuhurtaKind::RahuKalam).unwrap();
+        assert_eq!(rahu.start, at(12, 0));
+    }
+
+    #[test]
+    fn test_overlap_minutes() {
+        let muhurtas = day_muhurtas(at(6, 0), at(18, 0), 0);
+        // Sunday's Rahu Kalam is the last eighth, 16:30-18:00
+        let rahu = find(&muhurtas, MuhurtaKind::RahuKalam).unwrap();
+        assert_eq!(rahu.overlap_minutes(at(15, 0), at(17, 0)), 30);
+        assert_eq!(rahu.overlap_minutes(at(9, 0), at(11, 0)), 0);
+    }
+}
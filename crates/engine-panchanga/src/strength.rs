@@ -0,0 +1,143 @@
+//! Tarabala and Chandrabala: a day's strength for one person
+//!
+//! Tarabala counts the day's nakshatra from the birth nakshatra and reads
+//! the count in cycles of nine taras, from Janma to Parama Mitra. Vipat,
+//! Pratyari and Naidhana are the unfavourable taras. Chandrabala counts the
+//! day's Moon sign from the natal Moon sign. The 1st, 3rd, 6th, 7th, 10th
+//! and 11th are favourable. The 2nd, 5th and 9th are mixed. The 4th and 12th
+//! are weak, and the 8th (Chandrashtama) is the weakest.
+//!
+//! The day's Moon is read at local sunrise, the moment the day's panchanga
+//! is named for. Both Moons must be in the same zodiac reference.
+
+use chrono::NaiveDate;
+use noesis_core::results::{Chandrabala, DailyStrength, Tarabala};
+
+use crate::NAKSHATRA_NAMES;
+
+/// Names and scores of the nine taras, in counting order
+const TARAS: [(&str, f64); 9] = [
+    ("Janma", 0.4),
+    ("Sampat", 0.8),
+    ("Vipat", 0.2),
+    ("Kshema", 0.8),
+    ("Pratyari", 0.2),
+    ("Sadhana", 0.9),
+    ("Naidhana", 0.0),
+    ("Mitra", 0.8),
+    ("Parama Mitra", 1.0),
+];
+
+/// Chandrabala score by house from the natal Moon, 1st first
+const CHANDRABALA_SCORES: [f64; 12] = [1.0, 0.5, 1.0, 0.2, 0.5, 1.0, 1.0, 0.0, 0.5, 1.0, 1.0, 0.2];
+
+/// Lowest score read as favourable
+const FAVOURABLE_SCORE: f64 = 0.5;
+
+/// House of the natal Moon's sign counted from itself
+const CHANDRASHTAMA: u8 = 8;
+
+fn nakshatra_of(longitude: f64) -> usize {
+    ((longitude.rem_euclid(360.0) * 27.0 / 360.0) as usize).min(26)
+}
+
+fn sign_of(longitude: f64) -> usize {
+    ((longitude.rem_euclid(360.0) / 30.0) as usize).min(11)
+}
+
+/// Tarabala for a day Moon at `moon` against a natal Moon at `birth_moon`
+pub fn tarabala(birth_moon: f64, moon: f64) -> Tarabala {
+    let count = (nakshatra_of(moon) + 27 - nakshatra_of(birth_moon)) % 27;
+    let tara = count % 9;
+    let (name, score) = TARAS[tara];
+    Tarabala {
+        count: count as u8 + 1,
+        tara: tara as u8 + 1,
+        name: name.to_string(),
+        score,
+        favourable: score >= FAVOURABLE_SCORE,
+    }
+}
+
+/// Chandrabala for a day Moon at `moon` against a natal Moon at `birth_moon`
+pub fn chandrabala(birth_moon: f64, moon: f64) -> Chandrabala {
+    let house = (sign_of(moon) + 12 - sign_of(birth_moon)) % 12;
+    let score = CHANDRABALA_SCORES[house];
+    Chandrabala {
+        house: house as u8 + 1,
+        score,
+        favourable: score >= FAVOURABLE_SCORE,
+        chandrashtama: house as u8 + 1 == CHANDRASHTAMA,
+    }
+}
+
+/// Strength of `date` for someone whose natal Moon is at `birth_moon`, with
+/// `moon` the Moon at that day's sunrise
+pub fn daily_strength(date: NaiveDate, birth_moon: f64, moon: f64) -> DailyStrength {
+    let tarabala = tarabala(birth_moon, moon);
+    let chandrabala = chandrabala(birth_moon, moon);
+    DailyStrength {
+        date: date.format("%Y-%m-%d").to_string(),
+        birth_nakshatra: NAKSHATRA_NAMES[nakshatra_of(birth_moon)].to_string(),
+        nakshatra: NAKSHATRA_NAMES[nakshatra_of(moon)].to_string(),
+        score: (tarabala.score + chandrabala.score) / 2.0,
+        tarabala,
+        chandrabala,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Middle of the 0-based nakshatra `index`
+    fn in_nakshatra(index: usize) -> f64 {
+        (index as f64 + 0.5) * 360.0 / 27.0
+    }
+
+    #[test]
+    fn test_tarabala_counts_in_cycles_of_nine() {
+        // Birth in Rohini (3); Rohini itself is Janma, the 1st
+        let janma = tarabala(in_nakshatra(3), in_nakshatra(3));
+        assert_eq!((janma.count, janma.tara, janma.name.as_str()), (1, 1, "Janma"));
+
+        // Hasta (12) is the 10th from Rohini, Janma again in the second cycle
+        let second_cycle = tarabala(in_nakshatra(3), in_nakshatra(12));
+        assert_eq!((second_cycle.count, second_cycle.tara), (10, 1));
+
+        // Counting wraps past Revati: Ashwini (0) is the 25th from Rohini
+        let wrapped = tarabala(in_nakshatra(3), in_nakshatra(0));
+        assert_eq!((wrapped.count, wrapped.name.as_str()), (25, "Naidhana"));
+        assert!(!wrapped.favourable);
+        assert_eq!(tarabala(in_nakshatra(3), in_nakshatra(11)).name, "Parama Mitra");
+    }
+
+    #[test]
+    fn test_chandrabala_by_house_from_natal_moon() {
+        // Natal Moon in Cancer (90-120)
+        let natal = 100.0;
+        let same_sign = chandrabala(natal, 115.0);
+        assert_eq!(same_sign.house, 1);
+        assert!(same_sign.favourable);
+
+        let eighth = chandrabala(natal, 310.0);
+        assert_eq!(eighth.house, 8);
+        assert!(eighth.chandrashtama && !eighth.favourable);
+
+        let twelfth = chandrabala(natal, 65.0);
+        assert_eq!((twelfth.house, twelfth.score), (12, 0.2));
+    }
+
+    #[test]
+    fn test_daily_strength_averages_both() {
+        let date = NaiveDate::from_ymd_opt(2026, 10, 15).unwrap();
+        let strength = daily_strength(date, in_nakshatra(3), in_nakshatra(8));
+        assert_eq!(strength.date, "2026-10-15");
+        assert_eq!(strength.birth_nakshatra, "Rohini");
+        assert_eq!(strength.nakshatra, "Ashlesha");
+        // Ashlesha is the 6th from Rohini (Sadhana) and in the 3rd sign from Taurus
+        assert_eq!(strength.tarabala.name, "Sadhana");
+        assert_eq!(strength.chandrabala.house, 3);
+        assert!((strength.score - 0.95).abs() < 1e-9);
+    }
+}
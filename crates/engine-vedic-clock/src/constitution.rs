@@ -0,0 +1,183 @@
+//! Constitution-weighted timing
+//!
+//! Ayurveda holds that like increases like: a dosha period amplifies that
+//! dosha in everyone, and most in people whose birth constitution (prakriti)
+//! already leans towards it. An activity that stokes the period's dosha is
+//! less advisable for them, one that calms it more so, while a person light
+//! on the dosha can take the boost. The midday Pitta period thus favours a
+//! long lunch and a cool walk for a Pitta-dominant person, and demanding work
+//! or training for a Kapha-dominant one.
+
+use noesis_core::options::Constitution;
+use serde::Serialize;
+
+use crate::models::{Activity, Dosha};
+
+/// How far a constitution can move a window's quality
+const CONSTITUTION_WEIGHT: f64 = 0.45;
+
+/// Adjustments below this are not mentioned in reasons
+pub const NOTABLE_ADJUSTMENT: f64 = 0.05;
+
+/// Share of a balanced (tridoshic) constitution
+const BALANCED_SHARE: f64 = 1.0 / 3.0;
+
+/// The activities a constitution favours and should ease off in one period
+#[derive(Debug, Clone, Serialize)]
+pub struct ConstitutionReading {
+    pub vata: f64,
+    pub pitta: f64,
+    pub kapha: f64,
+    pub dominant: Dosha,
+    pub current_period: Dosha,
+    /// Activities the period suits this constitution for, best first
+    pub favored_activities: Vec<&'static str>,
+    /// Activities that would aggravate this constitution now, worst first
+    pub activities_to_ease: Vec<&'static str>,
+}
+
+const ACTIVITIES: [Activity; 7] = [
+    Activity::Meditation,
+    Activity::Exercise,
+    Activity::Work,
+    Activity::Eating,
+    Activity::Sleep,
+    Activity::Creative,
+    Activity::Social,
+];
+
+/// Share (0-1) of `dosha` in the constitution
+pub fn dosha_share(constitution: &Constitution, dosha: Dosha) -> f64 {
+    let (vata, pitta, kapha) = constitution.shares();
+    match dosha {
+        Dosha::Vata => vata,
+        Dosha::Pitta => pitta,
+        Dosha::Kapha => kapha,
+    }
+}
+
+/// The dosha with the largest share; ties go to Vata, then Pitta
+pub fn dominant_dosha(constitution: &Constitution) -> Dosha {
+    [Dosha::Vata, Dosha::Pitta, Dosha::Kapha]
+        .into_iter()
+        .fold(Dosha::Vata, |best, dosha| {
+            if dosha_share(constitution, dosha) > dosha_share(constitution, best) {
+                dosha
+            } else {
+                best
+            }
+        })
+}
+
+/// Whether an activity increases (positive) or calms (negative) a dosha
+fn activity_effect(activity: Activity, dosha: Dosha) -> f64 {
+    match (dosha, activity) {
+        (Dosha::Vata, Activity::Meditation | Activity::Eating | Activity::Sleep) => -1.0,
+        (Dosha::Vata, Activity::Exercise) => 1.0,
+        (Dosha::Vata, Activity::Work | Activity::Creative) => 0.5,
+        (Dosha::Vata, Activity::Social) => 0.0,
+
+        (Dosha::Pitta, Activity::Meditation) => -1.0,
+        (Dosha::Pitta, Activity::Eating | Activity::Social) => -0.5,
+        (Dosha::Pitta, Activity::Exercise | Activity::Work) => 1.0,
+        (Dosha::Pitta, Activity::Sleep | Activity::Creative) => 0.0,
+
+        (Dosha::Kapha, Activity::Exercise) => -1.0,
+        (Dosha::Kapha, Activity::Work | Activity::Creative | Activity::Social) => -0.5,
+        (Dosha::Kapha, Activity::Meditation) => 0.5,
+        (Dosha::Kapha, Activity::Eating | Activity::Sleep) => 1.0,
+    }
+}
+
+/// Quality adjustment for `activity` during a `period` dosha window
+///
+/// Negative when the activity stokes a dosha the person carries in excess or
+/// calms one they are light on, positive the other way round, zero for a
+/// balanced constitution.
+pub fn constitution_adjustment(activity: Activity, period: Dosha, constitution: &Constitution) -> f64 {
+    let excess = dosha_share(constitution, period) - BALANCED_SHARE;
+    -CONSTITUTION_WEIGHT * activity_effect(activity, period) * excess
+}
+
+/// Why a constitution makes `activity` better or worse in a `period` window,
+/// or `None` when the adjustment is too small to mention
+pub fn constitution_reason(activity: Activity, period: Dosha, constitution: &Constitution) -> Option<String> {
+    if constitution_adjustment(activity, period, constitution).abs() < NOTABLE_ADJUSTMENT {
+        return None;
+    }
+    let stokes = activity_effect(activity, period) > 0.0;
+    let in_excess = dosha_share(constitution, period) > BALANCED_SHARE;
+    let dosha = period.display_name();
+    Some(match (stokes, in_excess) {
+        (true, true) => format!("stokes the {} you already carry in excess", dosha),
+        (false, true) => format!("calms the {} you carry in excess", dosha),
+        (true, false) => format!("builds up the {} your constitution is light on", dosha),
+        (false, false) => format!("further lowers the {} your constitution is light on", dosha),
+    })
+}
+
+/// Rank every activity for a constitution during a `period` window
+pub fn read_constitution(constitution: &Constitution, period: Dosha) -> ConstitutionReading {
+    let mut ranked: Vec<(Activity, f64)> = ACTIVITIES
+        .iter()
+        .map(|&activity| (activity, constitution_adjustment(activity, period, constitution)))
+        .collect();
+    ranked.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal));
+
+    let (vata, pitta, kapha) = constitution.shares();
+    ConstitutionReading {
+        vata,
+        pitta,
+        kapha,
+        dominant: dominant_dosha(constitution),
+        current_period: period,
+        favored_activities: ranked
+            .iter()
+            .filter(|(_, adjustment)| *adjustment >= NOTABLE_ADJUSTMENT)
+            .map(|(activity, _)| activity.display_name())
+            .collect(),
+        activities_to_ease: ranked
+            .iter()
+            .rev()
+            .filter(|(_, adjustment)| *adjustment <= -NOTABLE_ADJUSTMENT)
+            .map(|(activity, _)| activity.display_name())
+            .collect(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PITTA: Constitution = Constitution { vata: 0.2, pitta: 0.6, kapha: 0.2 };
+    const KAPHA: Constitution = Constitution { vata: 0.2, pitta: 0.2, kapha: 0.6 };
+
+    #[test]
+    fn test_dominant_dosha() {
+        assert_eq!(dominant_dosha(&PITTA), Dosha::Pitta);
+        assert_eq!(dominant_dosha(&KAPHA), Dosha::Kapha);
+        assert_eq!(dominant_dosha(&Constitution { vata: 1.0, pitta: 1.0, kapha: 1.0 }), Dosha::Vata);
+    }
+
+    #[test]
+    fn test_like_increases_like() {
+        // Work at midday stokes Pitta: worse for Pitta, better for Kapha
+        assert!(constitution_adjustment(Activity::Work, Dosha::Pitta, &PITTA) < 0.0);
+        assert!(constitution_adjustment(Activity::Work, Dosha::Pitta, &KAPHA) > 0.0);
+        // A balanced constitution is left alone
+        let balanced = Constitution { vata: 1.0, pitta: 1.0, kapha: 1.0 };
+        assert!(constitution_adjustment(Activity::Work, Dosha::Pitta, &balanced).abs() < 1e-9);
+        assert!(constitution_reason(Activity::Work, Dosha::Pitta, &balanced).is_none());
+    }
+
+    #[test]
+    fn test_midday_reading_differs_by_constitution() {
+        let pitta = read_constitution(&PITTA, Dosha::Pitta);
+        let kapha = read_constitution(&KAPHA, Dosha::Pitta);
+
+        assert_eq!(pitta.favored_activities.first(), Some(&"Meditation"));
+        assert!(pitta.activities_to_ease.contains(&"Work & Focus"));
+        assert!(kapha.favored_activities.contains(&"Work & Focus"));
+        assert!(!kapha.activities_to_ease.contains(&"Work & Focus"));
+    }
+}
@@ -4,20 +4,29 @@
 //! based on TCM organ clock and Vedic time cycles.
 
 use async_trait::async_trait;
-use chrono::Utc;
+use chrono::{NaiveTime, Timelike, Utc};
+use noesis_core::description::EngineDescription;
+use noesis_core::examples::{self, EngineExample};
+use noesis_core::hora::Hora;
+use noesis_core::options::{Constitution, TimeBasis, VedicClockOptions};
+use noesis_core::results::Relocation;
 use noesis_core::{
-    ConsciousnessEngine, EngineError, EngineInput, EngineOutput, ValidationResult,
-    CalculationMetadata,
+    rng, CacheTtl, ConsciousnessEngine, EngineError, EngineInput, TypedOptions, EngineOutput, ValidationResult,
+    CalculationMetadata, ValidationCode,
 };
 use serde_json::{json, Value};
+use std::collections::BTreeMap;
 use std::time::Instant;
 
 use crate::calculator::{get_current_organ, get_local_hour};
+use crate::constitution::read_constitution;
 use crate::dosha::get_dosha_for_hour;
+use crate::hora_integration::{current_hora, recommendations_from_native_hora, SunTimes};
 use crate::integration::{get_temporal_recommendation, synthesize_organ_dosha};
 use crate::models::{Activity, VedicClockResult, UpcomingTransition};
-use crate::recommendations::{get_optimal_timing, is_favorable_now};
-use crate::witness::generate_witness_prompt;
+use crate::recommendations::{get_optimal_timing_for, is_favorable_now_for};
+use crate::solar_time::SolarTime;
+use crate::witness::generate_seeded_witness_prompt;
 
 /// VedicClock-TCM consciousness engine
 pub struct VedicClockEngine {
@@ -34,19 +43,99 @@ impl VedicClockEngine {
         }
     }
 
+    /// Options for the cache key and TTL, which can't fail; `calculate` has
+    /// already rejected options of the wrong type
+    fn lenient_options(input: &EngineInput) -> VedicClockOptions {
+        input.engine_options().unwrap_or_default()
+    }
+
     /// Extract timezone offset from input options
     /// Defaults to 0 (UTC) if not provided
-    fn get_timezone_offset(options: &std::collections::HashMap<String, Value>) -> i32 {
-        options.get("timezone_offset")
-            .and_then(|v| v.as_i64())
-            .map(|v| v as i32)
+    fn get_timezone_offset(options: &VedicClockOptions) -> i32 {
+        options.timezone_offset.unwrap_or(0)
+    }
+
+    /// Offset for reading `current_time`: at `relocate_to` when set, else at home
+    fn timezone_offset(input: &EngineInput, options: &VedicClockOptions) -> i32 {
+        match &options.relocate_to {
+            Some(relocate_to) => relocate_to
+                .offset_at(input.current_time)
+                .map(|seconds| seconds / 60)
+                .unwrap_or(0),
+            None => Self::home_timezone_offset(input, options),
+        }
+    }
+
+    /// Offset at home: the `timezone_offset` option, else the offset at
+    /// `current_time` of the current location's timezone or the birth
+    /// timezone, else UTC
+    fn home_timezone_offset(input: &EngineInput, options: &VedicClockOptions) -> i32 {
+        if options.timezone_offset.is_some() {
+            return Self::get_timezone_offset(options);
+        }
+        input
+            .local_offset_at(input.current_time)
+            .ok()
+            .flatten()
+            .map(|seconds| seconds / 60)
             .unwrap_or(0)
     }
 
+    /// Solar time at the reading's place when `time_basis` is solar: at
+    /// `relocate_to`, else at the current location, else at the birth place
+    fn solar_time(
+        input: &EngineInput,
+        options: &VedicClockOptions,
+        clock_offset: i32,
+    ) -> Result<Option<SolarTime>, EngineError> {
+        let relocated = options.relocate_to.as_ref().map(|r| r.lon);
+        Self::solar_time_at(input, options, relocated.or(Self::home_longitude(input)), clock_offset)
+    }
+
+    /// Solar time at home, ignoring `relocate_to`
+    fn home_solar_time(
+        input: &EngineInput,
+        options: &VedicClockOptions,
+        clock_offset: i32,
+    ) -> Result<Option<SolarTime>, EngineError> {
+        Self::solar_time_at(input, options, Self::home_longitude(input), clock_offset)
+    }
+
+    fn home_longitude(input: &EngineInput) -> Option<f64> {
+        Self::home_place(input)
+            .map(|(_, longitude)| longitude)
+            .or(input.primary_birth_data().map(|b| b.longitude))
+    }
+
+    fn solar_time_at(
+        input: &EngineInput,
+        options: &VedicClockOptions,
+        longitude: Option<f64>,
+        clock_offset: i32,
+    ) -> Result<Option<SolarTime>, EngineError> {
+        if options.time_basis == TimeBasis::Clock {
+            return Ok(None);
+        }
+        let longitude = longitude.ok_or_else(|| {
+            EngineError::invalid_field(
+                "location",
+                ValidationCode::Required,
+                "Solar time needs a location, relocate_to or birth data",
+            )
+        })?;
+        SolarTime::at(input.current_time, longitude, clock_offset).map(Some)
+    }
+
+    /// Offset the organ and dosha windows are read with: the clock's, or
+    /// apparent solar time's
+    fn window_offset(clock_offset: i32, solar: Option<&SolarTime>) -> i32 {
+        solar.map_or(clock_offset, SolarTime::offset)
+    }
+
     /// Extract optional activity from input options
-    fn get_activity(options: &std::collections::HashMap<String, Value>) -> Option<Activity> {
-        options.get("activity")
-            .and_then(|v| v.as_str())
+    fn get_activity(options: &VedicClockOptions) -> Option<Activity> {
+        options.activity
+            .as_deref()
             .and_then(|s| match s.to_lowercase().as_str() {
                 "meditation" => Some(Activity::Meditation),
                 "exercise" => Some(Activity::Exercise),
@@ -60,14 +149,58 @@ impl VedicClockEngine {
     }
 
     /// Extract optional Panchanga indices from options
-    fn get_panchanga_indices(options: &std::collections::HashMap<String, Value>) -> (Option<u8>, Option<u8>) {
-        let tithi = options.get("tithi_index")
-            .and_then(|v| v.as_u64())
-            .map(|v| v as u8);
-        let nakshatra = options.get("nakshatra_index")
-            .and_then(|v| v.as_u64())
-            .map(|v| v as u8);
-        (tithi, nakshatra)
+    fn get_panchanga_indices(options: &VedicClockOptions) -> (Option<u8>, Option<u8>) {
+        (options.tithi_index, options.nakshatra_index)
+    }
+
+    /// Determine where sunrise/sunset come from.
+    ///
+    /// Explicit "sunrise"/"sunset" ("HH:MM") options win, then `relocate_to`,
+    /// then the input location, falling back to 06:00/18:00.
+    fn get_sun_times(input: &EngineInput, options: &VedicClockOptions) -> SunTimes {
+        let relocated = options.relocate_to.as_ref().map(|r| (r.lat, r.lon));
+        Self::sun_times_at(options, relocated.or(Self::home_place(input)))
+    }
+
+    /// Sun times at home, ignoring `relocate_to`
+    fn home_sun_times(input: &EngineInput, options: &VedicClockOptions) -> SunTimes {
+        Self::sun_times_at(options, Self::home_place(input))
+    }
+
+    fn home_place(input: &EngineInput) -> Option<(f64, f64)> {
+        input.location.as_ref().map(|loc| (loc.latitude, loc.longitude))
+    }
+
+    fn sun_times_at(options: &VedicClockOptions, place: Option<(f64, f64)>) -> SunTimes {
+        let parse = |time: &Option<String>| {
+            time.as_deref()
+                .and_then(|s| NaiveTime::parse_from_str(s, "%H:%M").ok())
+        };
+        match (parse(&options.sunrise), parse(&options.sunset), place) {
+            (Some(sunrise), Some(sunset), _) => SunTimes::Fixed { sunrise, sunset },
+            (_, _, Some((latitude, longitude))) => SunTimes::Location { latitude, longitude },
+            _ => SunTimes::default_fixed(),
+        }
+    }
+
+    /// The values of a reading that depend on where it is read, for a
+    /// relocation comparison
+    fn location_sensitive(
+        datetime: chrono::DateTime<Utc>,
+        timezone_offset: i32,
+        window_offset: i32,
+        sun: SunTimes,
+    ) -> BTreeMap<String, Value> {
+        let local = datetime.naive_utc() + chrono::Duration::minutes(timezone_offset as i64);
+        let organ = get_current_organ(datetime, window_offset);
+        let dosha = get_dosha_for_hour(get_local_hour(datetime, window_offset));
+        let hora = current_hora(datetime, timezone_offset, sun);
+        BTreeMap::from([
+            ("local_time".to_string(), json!(local.format("%Y-%m-%d %H:%M").to_string())),
+            ("organ".to_string(), json!(organ.organ)),
+            ("dosha".to_string(), json!(dosha.dosha)),
+            ("hora".to_string(), json!(hora.map(|h| h.ruler))),
+        ])
     }
 
     /// Build the result JSON
@@ -75,6 +208,8 @@ impl VedicClockEngine {
         &self,
         result: &VedicClockResult,
         activity: Option<Activity>,
+        constitution: Option<&Constitution>,
+        hora: Option<&Hora>,
         datetime: chrono::DateTime<Utc>,
         timezone_offset: i32,
     ) -> Value {
@@ -104,8 +239,8 @@ impl VedicClockEngine {
 
         // Add activity-specific timing if requested
         if let Some(activity) = activity {
-            let optimal_times = get_optimal_timing(activity, datetime, timezone_offset);
-            let (is_favorable, reason) = is_favorable_now(activity, datetime, timezone_offset);
+            let optimal_times = get_optimal_timing_for(activity, datetime, timezone_offset, constitution);
+            let (is_favorable, reason) = is_favorable_now_for(activity, datetime, timezone_offset, constitution);
             
             output["activity_timing"] = json!({
                 "activity": activity.display_name(),
@@ -121,6 +256,23 @@ impl VedicClockEngine {
             });
         }
 
+        // What the current dosha period means for this constitution
+        if let Some(constitution) = constitution {
+            output["constitution"] = json!(read_constitution(constitution, result.current_dosha.dosha));
+        }
+
+        // Add current planetary hour
+        if let Some(hora) = hora {
+            output["current_hora"] = json!({
+                "number": hora.number,
+                "ruler": hora.ruler,
+                "is_day": hora.is_day,
+                "start": hora.start.format("%H:%M").to_string(),
+                "end": hora.end.format("%H:%M").to_string(),
+                "recommendations": recommendations_from_native_hora(hora),
+            });
+        }
+
         // Add upcoming transitions if available
         if let Some(upcoming) = &result.upcoming {
             output["upcoming_transitions"] = json!(upcoming);
@@ -168,6 +320,23 @@ impl VedicClockEngine {
     }
 }
 
+/// Describe the active organ window and dosha period in plain language
+fn generate_summary(result: &VedicClockResult) -> String {
+    let organ = &result.current_organ;
+    let dosha = &result.current_dosha;
+    let mut summary = format!(
+        "It is the {} window ({}), during the {} period. {}.",
+        organ.organ.display_name(),
+        organ.time_range_display(),
+        dosha.dosha.display_name(),
+        organ.peak_energy,
+    );
+    if let Some(activity) = organ.recommended_activities.first() {
+        summary.push_str(&format!(" A good fit for this window: {}.", activity.to_lowercase()));
+    }
+    summary
+}
+
 impl Default for VedicClockEngine {
     fn default() -> Self {
         Self::new()
@@ -188,32 +357,89 @@ impl ConsciousnessEngine for VedicClockEngine {
         0 // Available at all consciousness phases
     }
 
+    fn option_keys(&self) -> Option<&'static [&'static str]> {
+        Some(VedicClockOptions::keys())
+    }
+
+    fn check_input(&self, input: &EngineInput) -> Result<(), EngineError> {
+        let options: VedicClockOptions = input.engine_options()?;
+        if let Some(relocate_to) = &options.relocate_to {
+            relocate_to.validate()?;
+        }
+        if let Some(constitution) = &options.constitution {
+            constitution.validate()?;
+        }
+        Self::solar_time(input, &options, 0)?;
+        Ok(())
+    }
+
+    fn examples(&self) -> Vec<EngineExample> {
+        vec![
+            examples::birth_data_example(),
+            EngineExample::new(
+                "activity",
+                "Best windows for meditation, with a reproducible witness prompt",
+                examples::sample_input(json!({ "activity": "meditation", "seed": 42 })),
+            ),
+        ]
+    }
+
+    fn describe(&self) -> EngineDescription {
+        EngineDescription::new(
+            "Reads the present moment on two clocks: the TCM organ clock of twelve two-hour windows and \
+             the Ayurvedic dosha periods, with the planetary hour and optional panchanga qualities, and \
+             suggests when to time an activity.",
+        )
+        .with_input("current_time", false, "The moment to read; defaults to now")
+        .with_input("location", false, "Place of the reading; sets sunrise, sunset and solar time")
+        .with_input("birth_data", false, "Sets the default timezone and place")
+        .with_input("options.activity", false, "Activity to find the best windows for")
+        .with_input("options.constitution", false, "Birth dosha constitution weighting the timing")
+        .with_input("options.time_basis", false, "Read the windows on clock time (default) or solar time")
+        .with_outputs("The current organ and dosha windows, the hora, recommendations, upcoming transitions and a witness prompt")
+        .with_traditions(&["Traditional Chinese Medicine", "Ayurveda", "Vedic Jyotish"])
+        .with_limitation("Windows are read on clock time unless options.time_basis is solar")
+        .with_limitation("Timing suggestions are traditional correspondences, not medical advice")
+    }
+
     async fn calculate(&self, input: EngineInput) -> Result<EngineOutput, EngineError> {
         let start = Instant::now();
 
         // Get parameters
-        let timezone_offset = Self::get_timezone_offset(&input.options);
-        let activity = Self::get_activity(&input.options);
-        let (tithi, nakshatra) = Self::get_panchanga_indices(&input.options);
+        let options: VedicClockOptions = input.engine_options()?;
+        if let Some(relocate_to) = &options.relocate_to {
+            relocate_to.validate()?;
+        }
+        if let Some(constitution) = &options.constitution {
+            constitution.validate()?;
+        }
+        let timezone_offset = Self::timezone_offset(&input, &options);
+        let solar = Self::solar_time(&input, &options, timezone_offset)?;
+        let window_offset = Self::window_offset(timezone_offset, solar.as_ref());
+        let activity = Self::get_activity(&options);
+        let (tithi, nakshatra) = Self::get_panchanga_indices(&options);
 
         // Use current_time from input
         let datetime = input.current_time;
 
         // Calculate current organ and dosha
-        let current_organ = get_current_organ(datetime, timezone_offset);
-        let local_hour = get_local_hour(datetime, timezone_offset);
+        let current_organ = get_current_organ(datetime, window_offset);
+        let local_hour = get_local_hour(datetime, window_offset);
         let current_dosha = get_dosha_for_hour(local_hour);
 
         // Get temporal recommendation
         let recommendation = get_temporal_recommendation(
             datetime,
-            timezone_offset,
+            window_offset,
             tithi,
             nakshatra,
         );
 
         // Get upcoming transitions
-        let upcoming = Some(Self::get_upcoming_transitions(datetime, timezone_offset));
+        let upcoming = Some(Self::get_upcoming_transitions(datetime, window_offset));
+
+        // Planetary hour from local sunrise/sunset
+        let hora = current_hora(datetime, timezone_offset, Self::get_sun_times(&input, &options));
 
         // Build the result
         let result = VedicClockResult {
@@ -230,10 +456,12 @@ impl ConsciousnessEngine for VedicClockEngine {
             .map(|v| v as u8)
             .unwrap_or(2);
 
-        let witness_prompt = generate_witness_prompt(
+        let seed = rng::engine_seed(&self.engine_id, &input)?;
+        let witness_prompt = generate_seeded_witness_prompt(
             &result.current_organ.organ,
             &result.current_dosha.dosha,
             consciousness_level,
+            seed,
         );
 
         // Ensure witness prompt is non-empty
@@ -243,12 +471,49 @@ impl ConsciousnessEngine for VedicClockEngine {
             ));
         }
 
+        let summary = generate_summary(&result);
         let elapsed = start.elapsed();
 
+        let mut output = self.build_result(
+            &result,
+            activity,
+            options.constitution.as_ref(),
+            hora.as_ref(),
+            datetime,
+            window_offset,
+        );
+        if let Some(seed) = input.seed()? {
+            output["seed"] = json!(seed);
+        }
+        if let Some(solar) = &solar {
+            output["time_basis"] = json!(solar.shift(datetime));
+        }
+        if let Some(relocate_to) = options.relocate_to.clone() {
+            let home_offset = Self::home_timezone_offset(&input, &options);
+            let home_solar = Self::home_solar_time(&input, &options, home_offset)?;
+            let relocation = Relocation::compare(
+                relocate_to,
+                Self::location_sensitive(
+                    datetime,
+                    home_offset,
+                    Self::window_offset(home_offset, home_solar.as_ref()),
+                    Self::home_sun_times(&input, &options),
+                ),
+                Self::location_sensitive(
+                    datetime,
+                    timezone_offset,
+                    window_offset,
+                    Self::get_sun_times(&input, &options),
+                ),
+            );
+            output["relocation"] = json!(relocation);
+        }
+
         Ok(EngineOutput {
             engine_id: self.engine_id.clone(),
-            result: self.build_result(&result, activity, datetime, timezone_offset),
+            result: output,
             witness_prompt,
+            summary: Some(summary),
             consciousness_level,
             metadata: CalculationMetadata {
                 calculation_time_ms: elapsed.as_secs_f64() * 1000.0,
@@ -256,6 +521,9 @@ impl ConsciousnessEngine for VedicClockEngine {
                 precision_achieved: format!("{:?}", input.precision),
                 cached: false,
                 timestamp: Utc::now(),
+                engine_version: None,
+                warnings: Vec::new(),
+                usage: None,
             },
         })
     }
@@ -306,23 +574,83 @@ impl ConsciousnessEngine for VedicClockEngine {
     }
 
     fn cache_key(&self, input: &EngineInput) -> String {
-        let timezone_offset = Self::get_timezone_offset(&input.options);
-        let activity = Self::get_activity(&input.options);
-        let (tithi, nakshatra) = Self::get_panchanga_indices(&input.options);
+        let options = Self::lenient_options(input);
+        let timezone_offset = Self::timezone_offset(input, &options);
+        let solar = Self::solar_time(input, &options, timezone_offset).ok().flatten();
+        let window_offset = Self::window_offset(timezone_offset, solar.as_ref());
+        let activity = Self::get_activity(&options);
+        let (tithi, nakshatra) = Self::get_panchanga_indices(&options);
 
         // Cache key based on hour (organ windows are 2-hour), timezone, and optional parameters
-        let local_hour = get_local_hour(input.current_time, timezone_offset);
+        let local_hour = get_local_hour(input.current_time, window_offset);
         let hour_bucket = local_hour / 2; // Group by 2-hour windows
 
+        // Horas do not align with organ windows, so the active hora is part of the key
+        let hora_number = current_hora(input.current_time, timezone_offset, Self::get_sun_times(input, &options))
+            .map(|h| h.number);
+
+        // A relocated reading also carries the home reading
+        let relocated = options
+            .relocate_to
+            .as_ref()
+            .map(|r| format!(":r{:.4},{:.4},{}", r.lat, r.lon, r.tz.as_deref().unwrap_or("")))
+            .unwrap_or_default();
+
+        // Only the proportions of a constitution matter
+        let constitution = options
+            .constitution
+            .filter(|c| c.validate().is_ok())
+            .map(|c| {
+                let (vata, pitta, kapha) = c.shares();
+                format!(":c{:.4},{:.4},{:.4}", vata, pitta, kapha)
+            })
+            .unwrap_or_default();
+
+        // Solar windows shift with the longitude and the date
+        let solar = solar
+            .map(|s| format!(":sol{}", s.offset()))
+            .unwrap_or_default();
+
+        // The witness prompt is drawn per user and question
+        let seed = rng::engine_seed(&self.engine_id, input).unwrap_or_default();
+
         format!(
-            "vedic-clock:h{}:tz{}:a{:?}:t{:?}:n{:?}",
+            "vedic-clock:h{}:tz{}{}:a{:?}:t{:?}:n{:?}:hora{:?}{}{}:s{:016x}",
             hour_bucket,
             timezone_offset,
+            solar,
             activity,
             tithi,
-            nakshatra
+            nakshatra,
+            hora_number,
+            relocated,
+            constitution,
+            seed
         )
     }
+
+    /// Organ windows, dosha periods and the upcoming transitions all move on
+    /// the local hour (solar or clock), so a reading lasts until the next full
+    /// hour or the end of the current hora, whichever comes first.
+    fn cache_ttl(&self, input: &EngineInput) -> CacheTtl {
+        let options = Self::lenient_options(input);
+        let timezone_offset = Self::timezone_offset(input, &options);
+        let solar = Self::solar_time(input, &options, timezone_offset).ok().flatten();
+        let window = chrono::Duration::minutes(Self::window_offset(timezone_offset, solar.as_ref()) as i64);
+        let local = input.current_time.naive_utc() + window;
+        let next_hour = local
+            .date()
+            .and_hms_opt(local.hour(), 0, 0)
+            .unwrap_or(local)
+            + chrono::Duration::hours(1);
+        let mut next = (next_hour - window).and_utc();
+        // Hora times are clock times
+        if let Some(hora) = current_hora(input.current_time, timezone_offset, Self::get_sun_times(input, &options)) {
+            let offset = chrono::Duration::minutes(timezone_offset as i64);
+            next = next.min((hora.end - offset).and_utc());
+        }
+        CacheTtl::UntilNextTransition(next)
+    }
 }
 
 #[cfg(test)]
@@ -337,6 +665,8 @@ mod tests {
 
         EngineInput {
             birth_data: None,
+            secondary_birth_data: None,
+            subjects: Vec::new(),
             current_time: Utc::now(),
             location: None,
             precision: Precision::Standard,
@@ -363,6 +693,8 @@ mod tests {
         let output = result.unwrap();
         assert_eq!(output.engine_id, "vedic-clock");
         assert!(!output.witness_prompt.is_empty());
+        assert!(output.result.get("current_hora").is_some());
+        assert!(output.summary.unwrap().starts_with("It is the "));
     }
 
     #[tokio::test]
@@ -374,6 +706,8 @@ mod tests {
 
         let input = EngineInput {
             birth_data: None,
+            secondary_birth_data: None,
+            subjects: Vec::new(),
             current_time: Utc::now(),
             location: None,
             precision: Precision::Standard,
@@ -397,6 +731,8 @@ mod tests {
 
         let input = EngineInput {
             birth_data: None,
+            secondary_birth_data: None,
+            subjects: Vec::new(),
             current_time: Utc::now(),
             location: None,
             precision: Precision::Standard,
@@ -431,6 +767,7 @@ mod tests {
             engine_id: "vedic-clock".to_string(),
             result: json!({}), // Missing fields
             witness_prompt: "".to_string(), // Empty
+            summary: None,
             consciousness_level: 2,
             metadata: CalculationMetadata {
                 calculation_time_ms: 1.0,
@@ -438,6 +775,9 @@ mod tests {
                 precision_achieved: "Standard".to_string(),
                 cached: false,
                 timestamp: Utc::now(),
+                engine_version: None,
+                warnings: Vec::new(),
+                usage: None,
             },
         };
 
@@ -457,24 +797,175 @@ mod tests {
         assert_eq!(key1, key2, "Cache key should be deterministic");
     }
 
+    #[test]
+    fn test_cache_ttl_ends_at_next_local_hour() {
+        use chrono::TimeZone;
+        let engine = VedicClockEngine::new();
+        let mut input = create_test_input();
+        input.current_time = Utc.with_ymd_and_hms(2024, 1, 15, 10, 20, 0).unwrap();
+        assert_eq!(
+            engine.cache_ttl(&input),
+            CacheTtl::UntilNextTransition(Utc.with_ymd_and_hms(2024, 1, 15, 11, 0, 0).unwrap())
+        );
+
+        // 15:50 at +05:30 turns over at 16:00 local
+        input.options.insert("timezone_offset".to_string(), json!(330));
+        assert_eq!(
+            engine.cache_ttl(&input),
+            CacheTtl::UntilNextTransition(Utc.with_ymd_and_hms(2024, 1, 15, 10, 30, 0).unwrap())
+        );
+    }
+
     #[test]
     fn test_get_timezone_offset() {
-        let mut options = HashMap::new();
+        use chrono::TimeZone;
+        let mut options = VedicClockOptions::default();
         assert_eq!(VedicClockEngine::get_timezone_offset(&options), 0);
 
-        options.insert("timezone_offset".to_string(), json!(330));
+        options.timezone_offset = Some(330);
         assert_eq!(VedicClockEngine::get_timezone_offset(&options), 330);
+
+        // Without the option, the current location's timezone applies
+        let mut input = create_test_input();
+        input.current_time = Utc.with_ymd_and_hms(2024, 7, 1, 12, 0, 0).unwrap();
+        input.location = Some(noesis_core::Coordinates {
+            latitude: 38.72,
+            longitude: -9.14,
+            altitude: None,
+            timezone: Some("Europe/Lisbon".to_string()),
+        });
+        assert_eq!(VedicClockEngine::timezone_offset(&input, &VedicClockOptions::default()), 60);
+        assert_eq!(VedicClockEngine::timezone_offset(&input, &options), 330);
     }
 
     #[test]
     fn test_get_activity() {
-        let mut options = HashMap::new();
+        let mut options = VedicClockOptions::default();
         assert!(VedicClockEngine::get_activity(&options).is_none());
 
-        options.insert("activity".to_string(), json!("meditation"));
+        options.activity = Some("meditation".to_string());
         assert_eq!(VedicClockEngine::get_activity(&options), Some(Activity::Meditation));
 
-        options.insert("activity".to_string(), json!("EXERCISE"));
+        options.activity = Some("EXERCISE".to_string());
         assert_eq!(VedicClockEngine::get_activity(&options), Some(Activity::Exercise));
     }
+
+    #[tokio::test]
+    async fn test_wrongly_typed_option_is_rejected() {
+        let engine = VedicClockEngine::new();
+        let mut input = create_test_input();
+        input.options.insert("timezone_offset".to_string(), json!("+05:30"));
+        let err = engine.calculate(input).await.unwrap_err();
+        assert!(matches!(err, EngineError::InvalidInput(ref errors) if errors[0].field == "options.timezone_offset"));
+    }
+
+    #[tokio::test]
+    async fn test_result_matches_core_schema() {
+        let engine = VedicClockEngine::new();
+        let mut input = create_test_input();
+        input.options.insert("activity".to_string(), json!("meditation"));
+        let output = engine.calculate(input).await.unwrap();
+        let typed: noesis_core::results::VedicClockResult = output.parse_as().unwrap();
+        assert!(typed.activity_timing.is_some());
+        assert_eq!(typed.current_organ.organ, output.result["current_organ"]["organ"]);
+    }
+
+    #[tokio::test]
+    async fn test_relocation_compares_against_home() {
+        use chrono::TimeZone;
+        let engine = VedicClockEngine::new();
+        let mut input = create_test_input();
+        // 10:20 UTC is 10:20 at home and 06:20 in New York
+        input.current_time = Utc.with_ymd_and_hms(2024, 7, 1, 10, 20, 0).unwrap();
+        input.options.insert(
+            "relocate_to".to_string(),
+            json!({"lat": 40.7128, "lon": -74.0060, "tz": "America/New_York"}),
+        );
+        let output = engine.calculate(input.clone()).await.unwrap();
+        let typed: noesis_core::results::VedicClockResult = output.parse_as().unwrap();
+        let relocation = typed.relocation.expect("relocation");
+        assert_eq!(relocation.home["local_time"], json!("2024-07-01 10:20"));
+        assert_eq!(relocation.relocated["local_time"], json!("2024-07-01 06:20"));
+        assert!(relocation.changed.contains(&"organ".to_string()));
+        assert_eq!(typed.current_organ.organ, relocation.relocated["organ"]);
+
+        input.options.insert("relocate_to".to_string(), json!({"lat": 91.0, "lon": 0.0}));
+        let err = engine.calculate(input).await.unwrap_err();
+        assert!(matches!(err, EngineError::InvalidInput(ref errors) if errors[0].field == "options.relocate_to.lat"));
+    }
+
+    #[tokio::test]
+    async fn test_constitution_weights_midday_timing() {
+        use chrono::TimeZone;
+        let engine = VedicClockEngine::new();
+        let reading = |constitution: Value| {
+            let mut input = create_test_input();
+            input.current_time = Utc.with_ymd_and_hms(2024, 7, 1, 12, 0, 0).unwrap();
+            input.options.insert("activity".to_string(), json!("work"));
+            input.options.insert("constitution".to_string(), constitution);
+            input
+        };
+
+        let pitta_input = reading(json!({"vata": 0.2, "pitta": 0.6, "kapha": 0.2}));
+        let kapha_input = reading(json!({"vata": 0.2, "pitta": 0.2, "kapha": 0.6}));
+        assert_ne!(engine.cache_key(&pitta_input), engine.cache_key(&kapha_input));
+
+        let pitta: noesis_core::results::VedicClockResult =
+            engine.calculate(pitta_input).await.unwrap().parse_as().unwrap();
+        let kapha: noesis_core::results::VedicClockResult =
+            engine.calculate(kapha_input).await.unwrap().parse_as().unwrap();
+
+        let (pitta_timing, kapha_timing) = (pitta.activity_timing.unwrap(), kapha.activity_timing.unwrap());
+        assert!(!pitta_timing.is_favorable_now);
+        assert!(kapha_timing.is_favorable_now);
+        assert_ne!(pitta_timing.optimal_windows, kapha_timing.optimal_windows);
+
+        let pitta_reading = pitta.constitution.unwrap();
+        assert_eq!(pitta_reading.dominant, "Pitta");
+        assert_eq!(pitta_reading.current_period, "Pitta");
+        assert!(pitta_reading.activities_to_ease.contains(&"Work & Focus".to_string()));
+        assert!(kapha.constitution.unwrap().favored_activities.contains(&"Work & Focus".to_string()));
+
+        let err = engine.calculate(reading(json!({"vata": -1, "pitta": 1, "kapha": 1}))).await.unwrap_err();
+        assert!(matches!(err, EngineError::InvalidInput(ref errors) if errors[0].field == "options.constitution.vata"));
+    }
+
+    #[tokio::test]
+    async fn test_solar_time_basis_shifts_windows() {
+        use chrono::TimeZone;
+        let engine = VedicClockEngine::new();
+        let mut input = create_test_input();
+        // 14:00 on Beijing time in Kashgar, where the sun has only just passed 11:20
+        input.current_time = Utc.with_ymd_and_hms(2024, 11, 3, 6, 0, 0).unwrap();
+        input.options.insert("timezone_offset".to_string(), json!(480));
+        input.location = Some(noesis_core::Coordinates {
+            latitude: 39.47,
+            longitude: 75.99,
+            altitude: None,
+            timezone: None,
+        });
+
+        let clock: noesis_core::results::VedicClockResult =
+            engine.calculate(input.clone()).await.unwrap().parse_as().unwrap();
+        assert_eq!(clock.current_organ.organ, "SmallIntestine");
+        assert!(clock.time_basis.is_none());
+
+        input.options.insert("time_basis".to_string(), json!("solar"));
+        let solar: noesis_core::results::VedicClockResult =
+            engine.calculate(input.clone()).await.unwrap().parse_as().unwrap();
+        assert_eq!(solar.current_organ.organ, "Heart");
+        let shift = solar.time_basis.expect("time_basis");
+        assert_eq!((shift.clock_time.as_str(), shift.solar_time.as_str()), ("14:00", "11:20"));
+        assert_eq!(shift.difference_minutes, -160);
+        // The planetary hour stays on the clock
+        assert_eq!(solar.current_hora, clock.current_hora);
+
+        let mut clock_input = input.clone();
+        clock_input.options.remove("time_basis");
+        assert_ne!(engine.cache_key(&input), engine.cache_key(&clock_input));
+
+        input.location = None;
+        let err = engine.calculate(input).await.unwrap_err();
+        assert!(matches!(err, EngineError::InvalidInput(ref errors) if errors[0].field == "location"));
+    }
 }
@@ -1,6 +1,10 @@
 //! Hora-based recommendations for Vedic clock
 
-use noesis_vedic_api::panchang::HoraTimings;
+use chrono::{DateTime, Duration, NaiveDate, NaiveDateTime, NaiveTime, Utc};
+use noesis_core::hora::{self, Hora, HoraPlanet};
+use noesis_core::EngineError;
+use noesis_solar::SolarDay;
+use noesis_vedic_api::panchang::{HoraTimings, Planet};
 
 use crate::models::ActivityRecommendation;
 
@@ -29,3 +33,145 @@ pub fn recommendations_from_hora(horas: &HoraTimings, current_time: &str) -> Vec
 
     recommendations
 }
+
+/// Source of local sunrise/sunset times for hora calculation
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SunTimes {
+    /// Fixed local wall-clock times, applied to every day
+    Fixed { sunrise: NaiveTime, sunset: NaiveTime },
+    /// Computed per day for a location with the NOAA solar algorithm
+    Location { latitude: f64, longitude: f64 },
+}
+
+impl SunTimes {
+    /// Conventional 06:00 / 18:00, also used on polar days and nights
+    pub fn default_fixed() -> Self {
+        SunTimes::Fixed {
+            sunrise: NaiveTime::from_hms_opt(6, 0, 0).unwrap_or_default(),
+            sunset: NaiveTime::from_hms_opt(18, 0, 0).unwrap_or_default(),
+        }
+    }
+
+    /// Local sunrise and sunset on `date` for a UTC offset in minutes
+    pub fn for_date(
+        &self,
+        date: NaiveDate,
+        timezone_offset: i32,
+    ) -> Result<(NaiveDateTime, NaiveDateTime), EngineError> {
+        match *self {
+            SunTimes::Fixed { sunrise, sunset } => {
+                Ok((NaiveDateTime::new(date, sunrise), NaiveDateTime::new(date, sunset)))
+            }
+            SunTimes::Location { latitude, longitude } => {
+                let day = SolarDay::calculate(date, latitude, longitude)
+                    .map_err(|e| EngineError::CalculationError(e.to_string()))?;
+                match day.local_sunrise_sunset(timezone_offset) {
+                    Some(times) => Ok(times),
+                    None => Self::default_fixed().for_date(date, timezone_offset),
+                }
+            }
+        }
+    }
+}
+
+/// Compute the hora active at `datetime` using the shared noesis-core hora math.
+///
+/// The previous or following day's sun times are used when the Vedic day
+/// spans midnight.
+pub fn current_hora(datetime: DateTime<Utc>, timezone_offset: i32, sun: SunTimes) -> Option<Hora> {
+    let local: NaiveDateTime =
+        datetime.naive_utc() + Duration::minutes(timezone_offset as i64);
+
+    let horas = hora::horas_for_moment(local, |d| sun.for_date(d, timezone_offset)).ok()?;
+
+    hora::hora_at(&horas, local).cloned()
+}
+
+/// The 24 horas of the Vedic day beginning at sunrise on `date`
+pub fn day_horas(date: NaiveDate, timezone_offset: i32, sun: SunTimes) -> Result<Vec<Hora>, EngineError> {
+    let (sunrise, sunset) = sun.for_date(date, timezone_offset)?;
+    let (next_sunrise, _) = sun.for_date(date + Duration::days(1), timezone_offset)?;
+    hora::calculate_horas(sunrise, sunset, next_sunrise)
+}
+
+/// Activity recommendations for a natively computed hora
+pub fn recommendations_from_native_hora(current: &Hora) -> Vec<ActivityRecommendation> {
+    let planet = api_planet(current.ruler);
+    let quality = if current.ruler.is_benefic() { "favorable" } else { "neutral" };
+
+    let mut recommendations: Vec<ActivityRecommendation> = planet
+        .suitable_activities()
+        .into_iter()
+        .map(|activity| ActivityRecommendation {
+            activity: activity.to_string(),
+            quality: quality.to_string(),
+            reason: format!("{} Hora (#{})", planet.as_str(), current.number),
+        })
+        .collect();
+
+    recommendations.extend(planet.activities_to_avoid().into_iter().map(|avoid| {
+        ActivityRecommendation {
+            activity: avoid.to_string(),
+            quality: "avoid".to_string(),
+            reason: format!("Avoid during {} Hora", planet.as_str()),
+        }
+    }));
+
+    recommendations
+}
+
+fn api_planet(planet: HoraPlanet) -> Planet {
+    match planet {
+        HoraPlanet::Sun => Planet::Sun,
+        HoraPlanet::Moon => Planet::Moon,
+        HoraPlanet::Mars => Planet::Mars,
+        HoraPlanet::Mercury => Planet::Mercury,
+        HoraPlanet::Jupiter => Planet::Jupiter,
+        HoraPlanet::Venus => Planet::Venus,
+        HoraPlanet::Saturn => Planet::Saturn,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_current_hora_applies_timezone_offset() {
+        // 2024-01-14 (Sunday) 01:00 UTC is 06:30 IST: first hora, ruled by the Sun
+        let dt = Utc.with_ymd_and_hms(2024, 1, 14, 1, 0, 0).unwrap();
+
+        let h = current_hora(dt, 330, SunTimes::default_fixed()).unwrap();
+        assert_eq!(h.number, 1);
+        assert_eq!(h.ruler, HoraPlanet::Sun);
+
+        let recs = recommendations_from_native_hora(&h);
+        assert!(recs.iter().any(|r| r.quality == "avoid"));
+    }
+
+    #[test]
+    fn test_current_hora_from_location() {
+        // Bangalore sunrise on 2024-01-14 is ~06:44 IST, so 06:30 IST is still
+        // Saturday's night horas
+        let dt = Utc.with_ymd_and_hms(2024, 1, 14, 1, 0, 0).unwrap();
+        let sun = SunTimes::Location { latitude: 12.9716, longitude: 77.5946 };
+
+        let h = current_hora(dt, 330, sun).unwrap();
+        assert!(!h.is_day);
+        assert_eq!(h.number, 24);
+    }
+
+    #[test]
+    fn test_day_horas_run_sunrise_to_sunrise() {
+        let sun = SunTimes::Location { latitude: 12.9716, longitude: 77.5946 };
+        let date = NaiveDate::from_ymd_opt(2024, 1, 14).unwrap();
+
+        let horas = day_horas(date, 330, sun).unwrap();
+        assert_eq!(horas.len(), 24);
+        // Sunday: the sunrise hora belongs to the Sun
+        assert_eq!(horas[0].ruler, HoraPlanet::Sun);
+        assert_eq!(horas[0].start.date(), date);
+        assert_eq!(horas[23].end.date(), date + Duration::days(1));
+    }
+}
@@ -10,7 +10,7 @@
 //! - TCM 12-organ clock with associated elements and emotions
 //! - Ayurvedic dosha time periods (Vata, Pitta, Kapha)
 //! - Dosha-organ correspondence mapping
-//! - Activity-based timing recommendations
+//! - Activity-based timing recommendations, optionally weighted by birth constitution
 //! - Optional Panchanga quality overlays
 //! - Non-prescriptive witness prompts for self-observation
 //!
@@ -35,6 +35,8 @@ pub mod hora_integration;
 pub mod choghadiya_integration;
 pub mod panchang_integration;
 pub mod recommendations;
+pub mod constitution;
+pub mod solar_time;
 pub mod witness;
 pub mod engine;
 
@@ -49,11 +51,17 @@ pub use dosha::{dosha_times, get_dosha_for_hour, get_organ_dosha_affinity, calcu
 pub use panchanga_qualities::{get_combined_quality, get_tithi_quality, PanchangaQuality, QualityRating};
 pub use integration::{get_temporal_recommendation, get_activity_favorability, synthesize_organ_dosha};
 pub use organ_clock::get_temporal_recommendation_with_api;
-pub use hora_integration::recommendations_from_hora;
+pub use hora_integration::{
+    current_hora, day_horas, recommendations_from_hora, recommendations_from_native_hora, SunTimes,
+};
 pub use choghadiya_integration::recommendations_from_choghadiya;
 pub use panchang_integration::recommendation_from_complete_panchang;
-pub use recommendations::{get_optimal_timing, get_best_time, is_favorable_now};
-pub use witness::generate_witness_prompt;
+pub use recommendations::{
+    get_best_time, get_best_time_for, get_optimal_timing, get_optimal_timing_for, is_favorable_now,
+    is_favorable_now_for, window_for_hour,
+};
+pub use constitution::{constitution_adjustment, read_constitution, ConstitutionReading};
+pub use witness::{generate_seeded_witness_prompt, generate_witness_prompt};
 pub use engine::VedicClockEngine;
 
 // Re-export core traits
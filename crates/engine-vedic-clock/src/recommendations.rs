@@ -5,10 +5,15 @@
 //! and optional Panchanga qualities.
 
 use chrono::{DateTime, Utc};
+use noesis_core::options::Constitution;
+use crate::constitution::{constitution_adjustment, constitution_reason};
 use crate::models::{Activity, TimeWindow, Organ, Dosha};
 use crate::wisdom::get_organ_for_hour;
 use crate::dosha::{get_dosha_for_hour, get_organ_dosha_affinity};
 
+/// Constitution adjustment that decides [`is_favorable_now_for`] on its own
+const OVERTURNING_ADJUSTMENT: f64 = 0.1;
+
 /// Get optimal time windows for a specific activity
 ///
 /// # Arguments
@@ -22,11 +27,21 @@ pub fn get_optimal_timing(
     activity: Activity,
     datetime: DateTime<Utc>,
     timezone_offset: i32,
+) -> Vec<TimeWindow> {
+    get_optimal_timing_for(activity, datetime, timezone_offset, None)
+}
+
+/// [`get_optimal_timing`] for a person of the given birth constitution
+pub fn get_optimal_timing_for(
+    activity: Activity,
+    datetime: DateTime<Utc>,
+    timezone_offset: i32,
+    constitution: Option<&Constitution>,
 ) -> Vec<TimeWindow> {
     let current_hour = crate::calculator::get_local_hour(datetime, timezone_offset);
     
     // Get all 24-hour windows scored for this activity
-    let mut windows = get_all_windows_for_activity(activity);
+    let mut windows = get_all_windows_for_activity(activity, constitution);
     
     // Boost upcoming windows (within next 6 hours)
     for window in &mut windows {
@@ -45,36 +60,42 @@ pub fn get_optimal_timing(
 }
 
 /// Get all time windows scored for an activity
-fn get_all_windows_for_activity(activity: Activity) -> Vec<TimeWindow> {
-    let favorable_organs = get_favorable_organs(activity);
-    let favorable_doshas = get_favorable_doshas(activity);
-    
-    let mut windows = Vec::new();
-    
-    // Check each 2-hour organ window
-    for hour in (1..24).step_by(2) {
-        let organ = get_organ_for_hour(hour as u8);
-        let dosha = get_dosha_for_hour(hour as u8);
-        
-        // Calculate quality score
-        let organ_score = if favorable_organs.contains(&organ.organ) { 0.4 } else { 0.1 };
-        let dosha_score = if favorable_doshas.contains(&dosha.dosha) { 0.3 } else { 0.1 };
-        let affinity_score = if get_organ_dosha_affinity(&organ.organ) == dosha.dosha { 0.2 } else { 0.0 };
-        
-        let quality = organ_score + dosha_score + affinity_score;
-        
-        // Generate reason
-        let reason = generate_reason(activity, &organ.organ, &dosha.dosha, quality);
-        
-        windows.push(TimeWindow {
-            start_hour: organ.start_hour,
-            end_hour: organ.end_hour,
-            quality,
-            reason,
-        });
+fn get_all_windows_for_activity(activity: Activity, constitution: Option<&Constitution>) -> Vec<TimeWindow> {
+    // Each 2-hour organ window
+    (1..24)
+        .step_by(2)
+        .map(|hour| window_for_hour(activity, hour, constitution))
+        .collect()
+}
+
+/// The organ window containing local `hour` (0-23), scored for an activity
+/// and, when given, a birth constitution
+pub fn window_for_hour(activity: Activity, hour: u8, constitution: Option<&Constitution>) -> TimeWindow {
+    let organ = get_organ_for_hour(hour);
+    let dosha = get_dosha_for_hour(hour);
+
+    // Calculate quality score
+    let organ_score = if get_favorable_organs(activity).contains(&organ.organ) { 0.4 } else { 0.1 };
+    let dosha_score = if get_favorable_doshas(activity).contains(&dosha.dosha) { 0.3 } else { 0.1 };
+    let affinity_score = if get_organ_dosha_affinity(&organ.organ) == dosha.dosha { 0.2 } else { 0.0 };
+
+    let mut quality = organ_score + dosha_score + affinity_score;
+    if let Some(constitution) = constitution {
+        quality = (quality + constitution_adjustment(activity, dosha.dosha, constitution)).clamp(0.0, 1.0);
+    }
+
+    // Generate reason
+    let mut reason = generate_reason(activity, &organ.organ, &dosha.dosha, quality);
+    if let Some(why) = constitution.and_then(|c| constitution_reason(activity, dosha.dosha, c)) {
+        reason = format!("{}; {}", reason, why);
+    }
+
+    TimeWindow {
+        start_hour: organ.start_hour,
+        end_hour: organ.end_hour,
+        quality,
+        reason,
     }
-    
-    windows
 }
 
 /// Get organs favorable for a specific activity
@@ -176,7 +197,12 @@ fn capitalize(s: &str) -> String {
 
 /// Get the best single time window for an activity
 pub fn get_best_time(activity: Activity) -> TimeWindow {
-    let windows = get_all_windows_for_activity(activity);
+    get_best_time_for(activity, None)
+}
+
+/// [`get_best_time`] for a person of the given birth constitution
+pub fn get_best_time_for(activity: Activity, constitution: Option<&Constitution>) -> TimeWindow {
+    let windows = get_all_windows_for_activity(activity, constitution);
     windows.into_iter()
         .max_by(|a, b| a.quality.partial_cmp(&b.quality).unwrap_or(std::cmp::Ordering::Equal))
         .unwrap_or(TimeWindow {
@@ -192,6 +218,20 @@ pub fn is_favorable_now(
     activity: Activity,
     datetime: DateTime<Utc>,
     timezone_offset: i32,
+) -> (bool, String) {
+    is_favorable_now_for(activity, datetime, timezone_offset, None)
+}
+
+/// [`is_favorable_now`] for a person of the given birth constitution
+///
+/// A constitution can overturn the verdict: the current period is
+/// unfavourable if the activity would markedly aggravate it, and favourable
+/// if it would markedly balance it.
+pub fn is_favorable_now_for(
+    activity: Activity,
+    datetime: DateTime<Utc>,
+    timezone_offset: i32,
+    constitution: Option<&Constitution>,
 ) -> (bool, String) {
     let current_hour = crate::calculator::get_local_hour(datetime, timezone_offset);
     let organ = get_organ_for_hour(current_hour);
@@ -200,9 +240,13 @@ pub fn is_favorable_now(
     let favorable_organs = get_favorable_organs(activity);
     let favorable_doshas = get_favorable_doshas(activity);
     
-    let is_favorable = favorable_organs.contains(&organ.organ) || favorable_doshas.contains(&dosha.dosha);
+    let mut is_favorable = favorable_organs.contains(&organ.organ) || favorable_doshas.contains(&dosha.dosha);
+    let adjustment = constitution.map_or(0.0, |c| constitution_adjustment(activity, dosha.dosha, c));
+    if adjustment.abs() >= OVERTURNING_ADJUSTMENT {
+        is_favorable = adjustment > 0.0;
+    }
     
-    let reason = if is_favorable {
+    let mut reason = if is_favorable {
         format!(
             "Current {} time ({}) supports {}",
             organ.organ.display_name(),
@@ -210,13 +254,16 @@ pub fn is_favorable_now(
             activity.display_name().to_lowercase()
         )
     } else {
-        let best = get_best_time(activity);
+        let best = get_best_time_for(activity, constitution);
         format!(
             "Consider waiting until {} for better {} conditions",
             best.time_range_display(),
             activity.display_name().to_lowercase()
         )
     };
+    if let Some(why) = constitution.and_then(|c| constitution_reason(activity, dosha.dosha, c)) {
+        reason = format!("{}; now {}", reason, why);
+    }
     
     (is_favorable, reason)
 }
@@ -282,6 +329,23 @@ mod tests {
         assert_eq!(hours_until_window(5, 5), 0);
     }
 
+    #[test]
+    fn test_constitution_changes_midday_verdict() {
+        // 11 AM: Heart time in the Pitta period
+        let dt = Utc.with_ymd_and_hms(2024, 1, 1, 11, 0, 0).unwrap();
+        let pitta = Constitution { vata: 0.2, pitta: 0.6, kapha: 0.2 };
+        let kapha = Constitution { vata: 0.2, pitta: 0.2, kapha: 0.6 };
+
+        let (for_pitta, reason) = is_favorable_now_for(Activity::Work, dt, 0, Some(&pitta));
+        assert!(!for_pitta);
+        assert!(reason.contains("stokes the Pitta"), "{}", reason);
+        let (for_kapha, _) = is_favorable_now_for(Activity::Work, dt, 0, Some(&kapha));
+        assert!(for_kapha);
+
+        // Without a constitution the verdict is unchanged
+        assert_eq!(is_favorable_now_for(Activity::Work, dt, 0, None), is_favorable_now(Activity::Work, dt, 0));
+    }
+
     #[test]
     fn test_optimal_timing_limits_results() {
         let dt = Utc.with_ymd_and_hms(2024, 1, 1, 10, 0, 0).unwrap();
@@ -0,0 +1,98 @@
+//! Apparent solar time for the organ and dosha windows
+//!
+//! The organ clock and the dosha periods follow the sun: the Heart window
+//! (11:00-13:00) is centred on the sun's meridian transit, not on noon by the
+//! clock. Clock time runs apart from solar time by the place's distance from
+//! its timezone meridian, 4 minutes per degree of longitude, and by the
+//! equation of time; in western China or Spain the two differ by over an hour.
+//!
+//! With `time_basis = "solar"` the engine reads the windows with the UTC
+//! offset of apparent solar time at the place instead of the clock's.
+
+use chrono::{DateTime, Duration, Utc};
+use noesis_core::results::SolarTimeShift;
+use noesis_core::{EngineError, ValidationCode};
+
+/// Clock and solar offsets for one moment and longitude
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SolarTime {
+    pub longitude: f64,
+    /// UTC offset of the clock in minutes
+    pub clock_offset: i32,
+    /// Minutes the meridian at `longitude` is ahead of UTC
+    pub meridian_minutes: f64,
+    pub equation_of_time_minutes: f64,
+}
+
+impl SolarTime {
+    pub fn at(datetime: DateTime<Utc>, longitude: f64, clock_offset: i32) -> Result<Self, EngineError> {
+        if !(-180.0..=180.0).contains(&longitude) {
+            return Err(EngineError::invalid_field(
+                "location.longitude",
+                ValidationCode::OutOfRange,
+                "Longitude must be between -180 and 180",
+            ));
+        }
+        Ok(Self {
+            longitude,
+            clock_offset,
+            meridian_minutes: 4.0 * longitude,
+            equation_of_time_minutes: noesis_solar::equation_of_time(datetime),
+        })
+    }
+
+    /// UTC offset that reads a moment as apparent solar time, to the minute
+    pub fn offset(&self) -> i32 {
+        (self.meridian_minutes + self.equation_of_time_minutes).round() as i32
+    }
+
+    /// Solar minus clock time in minutes
+    pub fn difference_minutes(&self) -> i32 {
+        self.offset() - self.clock_offset
+    }
+
+    /// The shift as reported in the result
+    pub fn shift(&self, datetime: DateTime<Utc>) -> SolarTimeShift {
+        let local = |offset: i32| {
+            (datetime.naive_utc() + Duration::minutes(offset as i64))
+                .format("%H:%M")
+                .to_string()
+        };
+        let round = |minutes: f64| (minutes * 10.0).round() / 10.0;
+        SolarTimeShift {
+            longitude: self.longitude,
+            clock_time: local(self.clock_offset),
+            solar_time: local(self.offset()),
+            difference_minutes: self.difference_minutes(),
+            longitude_minutes: round(self.meridian_minutes - self.clock_offset as f64),
+            equation_of_time_minutes: round(self.equation_of_time_minutes),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_kashgar_runs_behind_beijing_time() {
+        // Kashgar lies at 76°E but keeps Beijing time, UTC+8 on the 120°E meridian
+        let at = Utc.with_ymd_and_hms(2024, 11, 3, 6, 0, 0).unwrap();
+        let solar = SolarTime::at(at, 75.99, 480).unwrap();
+        let shift = solar.shift(at);
+        assert_eq!(shift.clock_time, "14:00");
+        // 4 × 76° = 304 minutes ahead of UTC, 176 behind the clock, plus ~16 of equation of time
+        assert!((shift.longitude_minutes + 176.0).abs() < 0.1);
+        assert!((shift.equation_of_time_minutes - 16.4).abs() < 0.5);
+        assert_eq!(shift.difference_minutes, solar.offset() - 480);
+        assert!((-161..=-159).contains(&shift.difference_minutes));
+        assert_eq!(shift.solar_time, "11:20");
+    }
+
+    #[test]
+    fn test_invalid_longitude() {
+        let err = SolarTime::at(Utc::now(), 181.0, 0).unwrap_err();
+        assert!(matches!(err, EngineError::InvalidInput(ref e) if e[0].field == "location.longitude"));
+    }
+}
@@ -4,7 +4,9 @@
 //! of energy patterns throughout the day.
 
 use crate::models::{Organ, Dosha};
-use rand::prelude::IndexedRandom;
+use noesis_core::rng;
+use rand::seq::SliceRandom;
+use rand::Rng;
 
 /// Generate a witness prompt based on current organ and dosha
 ///
@@ -16,26 +18,45 @@ use rand::prelude::IndexedRandom;
 /// # Returns
 /// A non-prescriptive inquiry prompt
 pub fn generate_witness_prompt(organ: &Organ, dosha: &Dosha, consciousness_level: u8) -> String {
+    select_witness_prompt(organ, dosha, consciousness_level, &mut rng::from_seed(None))
+}
+
+/// Generate a witness prompt reproducibly from `seed`
+pub fn generate_seeded_witness_prompt(
+    organ: &Organ,
+    dosha: &Dosha,
+    consciousness_level: u8,
+    seed: u64,
+) -> String {
+    select_witness_prompt(organ, dosha, consciousness_level, &mut rng::seeded(seed))
+}
+
+fn select_witness_prompt<R: Rng + ?Sized>(
+    organ: &Organ,
+    dosha: &Dosha,
+    consciousness_level: u8,
+    rng: &mut R,
+) -> String {
     match consciousness_level {
-        0..=2 => generate_awareness_prompt(organ, dosha),
-        3..=4 => generate_observation_prompt(organ, dosha),
-        5..=6 => generate_integration_prompt(organ, dosha),
-        _ => generate_observation_prompt(organ, dosha),
+        0..=2 => generate_awareness_prompt(organ, dosha, rng),
+        3..=4 => generate_observation_prompt(organ, dosha, rng),
+        5..=6 => generate_integration_prompt(organ, dosha, rng),
+        _ => generate_observation_prompt(organ, dosha, rng),
     }
 }
 
 /// Level 0-2: Basic awareness prompts
 /// Focus on noticing bodily sensations and energy patterns
-fn generate_awareness_prompt(organ: &Organ, dosha: &Dosha) -> String {
+fn generate_awareness_prompt<R: Rng + ?Sized>(organ: &Organ, dosha: &Dosha, rng: &mut R) -> String {
     let prompts = get_awareness_prompts(organ);
     let dosha_prompts = get_dosha_awareness_prompts(dosha);
     
     // Select one from each category
-    let organ_prompt = prompts.choose(&mut rand::rng())
+    let organ_prompt = prompts.choose(rng)
         .copied()
         .unwrap_or("What do you notice about your energy right now?");
     
-    let dosha_prompt = dosha_prompts.choose(&mut rand::rng())
+    let dosha_prompt = dosha_prompts.choose(rng)
         .copied()
         .unwrap_or("");
     
@@ -48,15 +69,15 @@ fn generate_awareness_prompt(organ: &Organ, dosha: &Dosha) -> String {
 
 /// Level 3-4: Deeper observation prompts
 /// Explore the relationship between time, body, and activity
-fn generate_observation_prompt(organ: &Organ, dosha: &Dosha) -> String {
+fn generate_observation_prompt<R: Rng + ?Sized>(organ: &Organ, dosha: &Dosha, rng: &mut R) -> String {
     let prompts = get_observation_prompts(organ);
     let dosha_prompts = get_dosha_observation_prompts(dosha);
     
-    let organ_prompt = prompts.choose(&mut rand::rng())
+    let organ_prompt = prompts.choose(rng)
         .copied()
         .unwrap_or("What patterns do you notice in your energy at this time of day?");
     
-    let dosha_prompt = dosha_prompts.choose(&mut rand::rng())
+    let dosha_prompt = dosha_prompts.choose(rng)
         .copied()
         .unwrap_or("");
     
@@ -69,15 +90,15 @@ fn generate_observation_prompt(organ: &Organ, dosha: &Dosha) -> String {
 
 /// Level 5-6: Integration prompts
 /// Transcendent awareness of the interconnection of all cycles
-fn generate_integration_prompt(organ: &Organ, dosha: &Dosha) -> String {
+fn generate_integration_prompt<R: Rng + ?Sized>(organ: &Organ, dosha: &Dosha, rng: &mut R) -> String {
     let prompts = get_integration_prompts(organ);
     let dosha_prompts = get_dosha_integration_prompts(dosha);
     
-    let organ_prompt = prompts.choose(&mut rand::rng())
+    let organ_prompt = prompts.choose(rng)
         .copied()
         .unwrap_or("What remains constant as the cycles of time move through you?");
     
-    let dosha_prompt = dosha_prompts.choose(&mut rand::rng())
+    let dosha_prompt = dosha_prompts.choose(rng)
         .copied()
         .unwrap_or("");
     
@@ -374,6 +395,15 @@ mod tests {
         assert!(prompt.contains("?"), "Prompt should be a question");
     }
 
+    #[test]
+    fn test_seeded_witness_prompt_is_reproducible() {
+        for level in [1, 3, 5] {
+            let first = generate_seeded_witness_prompt(&Organ::Heart, &Dosha::Pitta, level, 42);
+            let second = generate_seeded_witness_prompt(&Organ::Heart, &Dosha::Pitta, level, 42);
+            assert_eq!(first, second);
+        }
+    }
+
     #[test]
     fn test_all_organs_have_prompts() {
         for organ in Organ::all_in_cycle_order() {
@@ -334,6 +334,8 @@ async fn test_engine_with_timezone() {
     
     let input = EngineInput {
         birth_data: None,
+        secondary_birth_data: None,
+        subjects: Vec::new(),
         current_time: Utc.with_ymd_and_hms(2024, 1, 1, 4, 0, 0).unwrap(), // 4 AM UTC = 9:30 AM IST
         location: None,
         precision: Precision::Standard,
@@ -360,6 +362,8 @@ async fn test_engine_with_activity() {
     
     let input = EngineInput {
         birth_data: None,
+        secondary_birth_data: None,
+        subjects: Vec::new(),
         current_time: Utc::now(),
         location: None,
         precision: Precision::Standard,
@@ -388,6 +392,8 @@ async fn test_engine_with_panchanga() {
     
     let input = EngineInput {
         birth_data: None,
+        secondary_birth_data: None,
+        subjects: Vec::new(),
         current_time: Utc::now(),
         location: None,
         precision: Precision::Standard,
@@ -475,6 +481,8 @@ fn create_test_input(timezone_offset: i32) -> EngineInput {
     
     EngineInput {
         birth_data: None,
+        secondary_birth_data: None,
+        subjects: Vec::new(),
         current_time: Utc::now(),
         location: None,
         precision: Precision::Standard,
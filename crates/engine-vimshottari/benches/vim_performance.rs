@@ -30,6 +30,8 @@ fn create_moon_input(longitude: f64) -> EngineInput {
 
     EngineInput {
         birth_data: None,
+        secondary_birth_data: None,
+        subjects: Vec::new(),
         current_time: Utc::now(),
         location: None,
         precision: Precision::Standard,
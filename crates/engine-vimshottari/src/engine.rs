@@ -4,19 +4,26 @@
 //! Supports two input modes:
 //! 1. birth_data -> calculate Moon nakshatra -> generate full dasha timeline
 //! 2. moon_longitude provided in options -> derive nakshatra directly
+//!
+//! With `include_transits` the running dasha lords are also placed in the
+//! sky at `current_time` (see [`crate::transits`]).
 
 use async_trait::async_trait;
-use chrono::{NaiveDate, NaiveTime, NaiveDateTime, TimeZone, Utc};
+use chrono::{DateTime, NaiveDate, NaiveTime, NaiveDateTime, TimeZone, Utc};
+use noesis_core::birth_time::{self, TimeStability, TIME_STABILITY_KEY};
+use noesis_core::description::EngineDescription;
+use noesis_core::examples::{self, EngineExample};
+use noesis_core::options::VimshottariOptions;
 use noesis_core::{
-    ConsciousnessEngine, EngineError, EngineInput, EngineOutput, ValidationResult,
-    CalculationMetadata,
+    BirthData, ConsciousnessEngine, EngineError, EngineInput, TypedOptions, EngineOutput, ValidationResult,
+    CalculationMetadata, Precision, TimeSensitivity, DEBUG_KEY,
 };
+use noesis_core::timezone::{self, RESOLUTION_KEY};
 use serde_json::{json, Value};
 use std::sync::Arc;
 use std::time::Instant;
 
 use crate::calculator::{
-    calculate_birth_nakshatra,
     calculate_dasha_balance,
     calculate_mahadashas,
     calculate_complete_timeline,
@@ -25,7 +32,23 @@ use crate::calculator::{
     enrich_period_with_qualities,
     get_nakshatra_from_longitude,
 };
+use crate::models::{CurrentPeriod, Nakshatra, VedicPlanet};
+use crate::transits::{self, DASHA_TRANSITS_KEY};
 use crate::witness::generate_witness_prompt;
+use engine_human_design::ephemeris::{EphemerisCalculator, HDPlanet};
+
+/// The nine grahas, in dasha order, compared against transits
+const NATAL_PLANETS: [VedicPlanet; 9] = [
+    VedicPlanet::Ketu,
+    VedicPlanet::Venus,
+    VedicPlanet::Sun,
+    VedicPlanet::Moon,
+    VedicPlanet::Mars,
+    VedicPlanet::Rahu,
+    VedicPlanet::Jupiter,
+    VedicPlanet::Saturn,
+    VedicPlanet::Mercury,
+];
 
 /// Vimshottari Dasha consciousness engine implementing the universal trait
 pub struct VimshottariEngine {
@@ -54,11 +77,11 @@ impl VimshottariEngine {
         }
     }
 
-    /// Parse birth_data into a UTC DateTime
+    /// Parse a birth date and optional time (default noon) into a local wall-clock time
     fn parse_birth_datetime(
         date_str: &str,
         time_str: Option<&str>,
-    ) -> Result<chrono::DateTime<Utc>, EngineError> {
+    ) -> Result<NaiveDateTime, EngineError> {
         let date = NaiveDate::parse_from_str(date_str, "%Y-%m-%d")
             .map_err(|e| EngineError::CalculationError(
                 format!("Invalid date format '{}': {}", date_str, e)
@@ -74,8 +97,49 @@ impl VimshottariEngine {
             NaiveTime::from_hms_opt(12, 0, 0).unwrap() // Default to noon
         };
 
-        let naive_dt = NaiveDateTime::new(date, time);
-        Ok(Utc.from_utc_datetime(&naive_dt))
+        Ok(NaiveDateTime::new(date, time))
+    }
+
+    /// The intermediate values behind the first Mahadasha, for
+    /// `options.debug`: where the Moon stands in its nakshatra and the
+    /// balance of the ruling planet's period that leaves
+    fn debug_values(birth_time: DateTime<Utc>, moon_longitude: f64, nakshatra: &Nakshatra, balance_years: f64) -> Value {
+        let longitude = moon_longitude.rem_euclid(360.0);
+        json!({
+            "birth_utc": birth_time.to_rfc3339(),
+            "moon_longitude": longitude,
+            "nakshatra_start_degree": nakshatra.start_degree,
+            "nakshatra_end_degree": nakshatra.end_degree,
+            "degrees_into_nakshatra": longitude - nakshatra.start_degree,
+            "ruling_planet_period_years": nakshatra.ruling_planet.period_years(),
+            "balance_years": balance_years,
+        })
+    }
+
+    /// How the birth nakshatra and the running maha- and antardasha read at
+    /// noon hold across the birth day, sampling the Moon every half hour.
+    /// Period dates shift with the birth moment, so the timeline always varies.
+    fn time_stability(birth_data: &BirthData, current_time: DateTime<Utc>) -> Result<TimeStability, EngineError> {
+        let date = Self::parse_birth_datetime(&birth_data.date, None)?.date();
+        let ephe = EphemerisCalculator::new("");
+        let confidence = birth_time::stability_across_day(30, |time| {
+            let offset = timezone::resolve_offset(&birth_data.timezone, birth_data.utc_offset_minutes, date, Some(time))?;
+            let utc_dt = Utc.from_utc_datetime(&offset.to_utc(date.and_time(time)));
+            let moon = ephe.shared_planet_position(HDPlanet::Moon, &utc_dt).map_err(|e| {
+                EngineError::CalculationError(format!("Failed to calculate birth nakshatra: {}", e))
+            })?;
+            let nakshatra = get_nakshatra_from_longitude(moon.longitude);
+            let balance = calculate_dasha_balance(moon.longitude, nakshatra);
+            let timeline = calculate_complete_timeline(calculate_mahadashas(utc_dt, nakshatra.ruling_planet, balance));
+            let running = find_current_period(&timeline, current_time)
+                .map(|cp| (cp.mahadasha.planet, cp.antardasha.planet));
+            Ok((nakshatra.number, running))
+        })?;
+        let mut varies = vec!["timeline", "upcoming_transitions"];
+        if confidence < 1.0 {
+            varies.extend(["birth_nakshatra", "current_period"]);
+        }
+        Ok(TimeStability::at_noon(confidence, varies))
     }
 
     /// Extract Moon longitude from options (Mode 2: direct longitude)
@@ -98,6 +162,77 @@ impl VimshottariEngine {
         Ok(longitude)
     }
 
+    /// Whether the natal Moon is topocentric, at `Precision::High` and above.
+    /// Its parallax moves it by up to a degree, about a year of dasha balance.
+    fn topocentric(input: &EngineInput) -> bool {
+        input.precision >= Precision::High
+    }
+
+    /// Whether `options.include_transits` is set
+    fn include_transits(options: &std::collections::HashMap<String, Value>) -> bool {
+        options
+            .get("include_transits")
+            .and_then(|v| v.as_bool())
+            .unwrap_or(false)
+    }
+
+    /// Longitudes of `planets` at `at` from the Swiss Ephemeris
+    fn positions(
+        planets: &[VedicPlanet],
+        at: &chrono::DateTime<Utc>,
+    ) -> Result<Vec<(VedicPlanet, f64)>, EngineError> {
+        let ephe = EphemerisCalculator::new("");
+        planets
+            .iter()
+            .map(|planet| {
+                let hd_planet = match planet {
+                    VedicPlanet::Sun => HDPlanet::Sun,
+                    VedicPlanet::Moon => HDPlanet::Moon,
+                    VedicPlanet::Mars => HDPlanet::Mars,
+                    VedicPlanet::Mercury => HDPlanet::Mercury,
+                    VedicPlanet::Jupiter => HDPlanet::Jupiter,
+                    VedicPlanet::Venus => HDPlanet::Venus,
+                    VedicPlanet::Saturn => HDPlanet::Saturn,
+                    VedicPlanet::Rahu => HDPlanet::NorthNode,
+                    VedicPlanet::Ketu => HDPlanet::SouthNode,
+                };
+                Ok((*planet, ephe.get_planet_position(hd_planet, at)?.longitude))
+            })
+            .collect()
+    }
+
+    /// Compare the running dasha lords' transits with the natal chart.
+    ///
+    /// With birth data both charts come from the ephemeris. From a
+    /// `moon_longitude` only the natal Moon is known, and the transits are
+    /// moved into its sidereal zodiac.
+    fn dasha_transits(
+        period: &CurrentPeriod,
+        natal_time: Option<chrono::DateTime<Utc>>,
+        moon_longitude: f64,
+    ) -> Result<Value, EngineError> {
+        let mut lords: Vec<VedicPlanet> = Vec::new();
+        for (_, planet) in transits::dasha_lords(period) {
+            if !lords.contains(&planet) {
+                lords.push(planet);
+            }
+        }
+        let mut transit_positions = Self::positions(&lords, &period.current_time)?;
+        let natal = match natal_time {
+            Some(at) => Self::positions(&NATAL_PLANETS, &at)?,
+            None => {
+                let ayanamsa = transits::lahiri_ayanamsa(period.current_time);
+                for (_, longitude) in transit_positions.iter_mut() {
+                    *longitude = (*longitude - ayanamsa).rem_euclid(360.0);
+                }
+                vec![(VedicPlanet::Moon, moon_longitude)]
+            }
+        };
+        let analysis = transits::analyze(period, &natal, &transit_positions);
+        serde_json::to_value(&analysis)
+            .map_err(|e| EngineError::CalculationError(format!("Failed to serialize transits: {}", e)))
+    }
+
     /// Serialize the complete timeline into a JSON Value for the EngineOutput
     fn serialize_timeline(
         birth_time: chrono::DateTime<Utc>,
@@ -183,6 +318,37 @@ impl VimshottariEngine {
     }
 }
 
+/// Describe the running dasha periods and the next transition in plain language
+fn generate_summary(
+    nakshatra: &crate::models::Nakshatra,
+    current_period: Option<&crate::models::CurrentPeriod>,
+    upcoming: &[crate::models::UpcomingTransition],
+) -> String {
+    let Some(cp) = current_period else {
+        return format!(
+            "Your Vimshottari timeline begins in {} nakshatra, ruled by {}.",
+            nakshatra.name,
+            nakshatra.ruling_planet.as_str(),
+        );
+    };
+    let mut summary = format!(
+        "You are in the {} Mahadasha until {}, with a {} Antardasha running until {}.",
+        cp.mahadasha.planet.as_str(),
+        cp.mahadasha.end.format("%Y-%m-%d"),
+        cp.antardasha.planet.as_str(),
+        cp.antardasha.end.format("%Y-%m-%d"),
+    );
+    if let Some(next) = upcoming.first() {
+        summary.push_str(&format!(
+            " The next shift, from {} to {}, comes in {} days.",
+            next.from_planet.as_str(),
+            next.to_planet.as_str(),
+            next.days_until,
+        ));
+    }
+    summary
+}
+
 impl Default for VimshottariEngine {
     fn default() -> Self {
         Self::new()
@@ -203,44 +369,104 @@ impl ConsciousnessEngine for VimshottariEngine {
         2 // Requires deeper consciousness (same as Gene Keys)
     }
 
+    fn option_keys(&self) -> Option<&'static [&'static str]> {
+        Some(VimshottariOptions::keys())
+    }
+
+    fn birth_time_sensitivity(&self) -> TimeSensitivity {
+        TimeSensitivity::Decisive
+    }
+
+    fn check_input(&self, input: &EngineInput) -> Result<(), EngineError> {
+        let options: VimshottariOptions = input.engine_options()?;
+        if let Some(birth_data) = input.primary_birth_data() {
+            birth_data.validate()?;
+            Self::parse_birth_datetime(&birth_data.date, birth_data.time.as_deref()).map(|_| ())
+        } else if input.options.contains_key("moon_longitude") {
+            Self::extract_moon_longitude(&input.options)?;
+            let date_str = options.birth_date.as_deref().unwrap_or("2000-01-01");
+            Self::parse_birth_datetime(date_str, options.birth_time.as_deref()).map(|_| ())
+        } else {
+            Err(EngineError::CalculationError(
+                "Vimshottari requires either birth_data or moon_longitude in options".to_string()
+            ))
+        }
+    }
+
+    fn examples(&self) -> Vec<EngineExample> {
+        vec![
+            examples::birth_data_example(),
+            EngineExample::new(
+                "moon_longitude",
+                "Dashas from a known sidereal Moon longitude, without birth data",
+                examples::options_input(json!({
+                    "moon_longitude": 125.5,
+                    "birth_date": "1985-06-15",
+                    "birth_time": "14:30"
+                })),
+            ),
+        ]
+    }
+
+    fn describe(&self) -> EngineDescription {
+        EngineDescription::new(
+            "Lays out the 120-year Vimshottari dasha timeline from the Moon's nakshatra at birth, \
+             down to pratyantardashas, and the periods running at the reading time.",
+        )
+        .with_input("birth_data", false, "Birth data to place the Moon from")
+        .with_input("options.moon_longitude", false, "Sidereal Moon longitude; required without birth_data")
+        .with_input("options.birth_date", false, "Birth date, required with options.moon_longitude")
+        .with_input("current_time", false, "Reading time for the running periods; defaults to now")
+        .with_input("options.include_transits", false, "Add the running dasha lords' transits")
+        .with_outputs("Mahadashas with their antardashas and pratyantardashas, the running periods and upcoming changes")
+        .with_traditions(&["Vedic Jyotish", "Parashari dasha system"])
+        .with_limitation(
+            "From birth_data the Moon is placed by its tropical longitude; pass a sidereal \
+             options.moon_longitude for a sidereal timeline",
+        )
+        .with_limitation("A Moon near a nakshatra boundary makes every date sensitive to birth time")
+    }
+
     async fn calculate(&self, input: EngineInput) -> Result<EngineOutput, EngineError> {
         let start = Instant::now();
 
         // Determine Moon longitude and birth time
-        let (moon_longitude, birth_time, backend) = if let Some(ref birth_data) = input.birth_data {
+        let (moon_longitude, birth_time, backend, offset) = if let Some(birth_data) = input.primary_birth_data() {
             // Mode 1: Calculate from birth_data using Swiss Ephemeris
-            let utc_dt = Self::parse_birth_datetime(
+            birth_data.validate()?;
+            let local_dt = Self::parse_birth_datetime(
                 &birth_data.date,
                 birth_data.time.as_deref(),
             )?;
+            // Historical offset in force at the birth moment (war time, LMT, DST)
+            let offset = birth_data.resolve_offset()?;
+            let utc_dt = Utc.from_utc_datetime(&offset.to_utc(local_dt));
 
-            let _nakshatra = calculate_birth_nakshatra(utc_dt, "")
-                .map_err(|e| EngineError::CalculationError(
-                    format!("Failed to calculate birth nakshatra: {}", e)
-                ))?;
-
-            // Get precise Moon longitude from Swiss Ephemeris
+            // Get precise Moon longitude from Swiss Ephemeris, reusing Human
+            // Design's natal Moon within a workflow. At high precision the
+            // Moon is placed as seen from the birth place instead.
             let ephe = engine_human_design::ephemeris::EphemerisCalculator::new("");
-            let moon_pos = ephe.get_planet_position(
-                engine_human_design::ephemeris::HDPlanet::Moon,
-                &utc_dt,
-            )?;
+            let moon_pos = if Self::topocentric(&input) {
+                ephe.topocentric_position(HDPlanet::Moon, &utc_dt, &birth_data.observer())
+            } else {
+                ephe.shared_planet_position(HDPlanet::Moon, &utc_dt)
+            }.map_err(|e| EngineError::CalculationError(
+                format!("Failed to calculate birth nakshatra: {}", e)
+            ))?;
 
-            (moon_pos.longitude, utc_dt, "swiss-ephemeris")
+            (moon_pos.longitude, utc_dt, "swiss-ephemeris", Some(offset))
         } else if input.options.contains_key("moon_longitude") {
             // Mode 2: Moon longitude provided directly
+            let options: VimshottariOptions = input.engine_options()?;
             let longitude = Self::extract_moon_longitude(&input.options)?;
 
             // Extract birth date from options or use a default
-            let date_str = input.options.get("birth_date")
-                .and_then(|v| v.as_str())
-                .unwrap_or("2000-01-01");
-            let time_str = input.options.get("birth_time")
-                .and_then(|v| v.as_str());
+            let date_str = options.birth_date.as_deref().unwrap_or("2000-01-01");
+            let time_str = options.birth_time.as_deref();
 
-            let birth_time = Self::parse_birth_datetime(date_str, time_str)?;
+            let birth_time = Utc.from_utc_datetime(&Self::parse_birth_datetime(date_str, time_str)?);
 
-            (longitude, birth_time, "moon-longitude")
+            (longitude, birth_time, "moon-longitude", None)
         } else {
             return Err(EngineError::CalculationError(
                 "Vimshottari requires either birth_data or moon_longitude in options".to_string()
@@ -305,8 +531,10 @@ impl ConsciousnessEngine for VimshottariEngine {
             ));
         }
 
+        let summary = generate_summary(nakshatra, current_period.as_ref(), &upcoming);
+
         // Step 10: Serialize result
-        let result = Self::serialize_timeline(
+        let mut result = Self::serialize_timeline(
             birth_time,
             &nakshatra.name,
             nakshatra.number,
@@ -316,6 +544,21 @@ impl ConsciousnessEngine for VimshottariEngine {
             &upcoming,
             enrichment.as_ref(),
         );
+        if let Some(offset) = offset {
+            result[RESOLUTION_KEY] = json!(offset);
+        }
+        if let Some(birth_data) = input.primary_birth_data().filter(|birth| birth.time_is_unknown()) {
+            result[TIME_STABILITY_KEY] = json!(Self::time_stability(birth_data, current_time)?);
+        }
+        if input.debug() {
+            result[DEBUG_KEY] = Self::debug_values(birth_time, moon_longitude, nakshatra, balance);
+        }
+        if Self::include_transits(&input.options) {
+            if let Some(ref cp) = current_period {
+                let natal_time = input.primary_birth_data().map(|_| birth_time);
+                result[DASHA_TRANSITS_KEY] = Self::dasha_transits(cp, natal_time, moon_longitude)?;
+            }
+        }
 
         let elapsed = start.elapsed();
 
@@ -323,6 +566,7 @@ impl ConsciousnessEngine for VimshottariEngine {
             engine_id: self.engine_id.clone(),
             result,
             witness_prompt,
+            summary: Some(summary),
             consciousness_level,
             metadata: CalculationMetadata {
                 calculation_time_ms: elapsed.as_secs_f64() * 1000.0,
@@ -330,6 +574,9 @@ impl ConsciousnessEngine for VimshottariEngine {
                 precision_achieved: format!("{:?}", input.precision),
                 cached: false,
                 timestamp: Utc::now(),
+                engine_version: None,
+                warnings: Vec::new(),
+                usage: None,
             },
         })
     }
@@ -398,6 +645,9 @@ impl ConsciousnessEngine for VimshottariEngine {
             );
         }
 
+        // An ambiguous birth offset is a warning, not a failure
+        messages.extend(timezone::resolution_warnings(&output.result));
+
         let confidence = if valid { 1.0 } else { 0.0 };
 
         Ok(ValidationResult {
@@ -407,14 +657,36 @@ impl ConsciousnessEngine for VimshottariEngine {
         })
     }
 
+    /// Birth-data mode calculates through Human Design, so its health is shared.
+    async fn health(&self) -> Result<(), EngineError> {
+        match &self.hd_engine {
+            Some(hd_engine) => hd_engine.health().await,
+            None => Ok(()),
+        }
+    }
+
     fn cache_key(&self, input: &EngineInput) -> String {
-        if let Some(ref birth_data) = input.birth_data {
+        // Transits move daily, so they are keyed by the day they were taken on
+        let transits = if Self::include_transits(&input.options) {
+            format!(":transits:{}", input.current_time.format("%Y-%m-%d"))
+        } else {
+            String::new()
+        };
+        let key = if let Some(birth_data) = input.primary_birth_data() {
+            let topocentric = if Self::topocentric(input) {
+                format!(":topo:{:.0}", birth_data.altitude.unwrap_or(0.0))
+            } else {
+                String::new()
+            };
             format!(
-                "vim:{}:{}:{:.4}:{:.4}",
+                "vim:{}:{}:{:.4}:{:.4}:{}:{}{}",
                 birth_data.date,
-                birth_data.time.as_ref().unwrap_or(&"12:00".to_string()),
+                birth_data.time.as_deref().unwrap_or("unknown"),
                 birth_data.latitude,
-                birth_data.longitude
+                birth_data.longitude,
+                birth_data.timezone,
+                birth_data.utc_offset_minutes.map(|m| m.to_string()).unwrap_or_default(),
+                topocentric
             )
         } else if let Ok(lng) = Self::extract_moon_longitude(&input.options) {
             let date = input.options.get("birth_date")
@@ -423,7 +695,8 @@ impl ConsciousnessEngine for VimshottariEngine {
             format!("vim:moon:{:.6}:{}", lng, date)
         } else {
             format!("vim:invalid:{}", Utc::now().timestamp())
-        }
+        };
+        key + &transits
     }
 }
 
@@ -451,6 +724,8 @@ mod tests {
 
         EngineInput {
             birth_data: None,
+            secondary_birth_data: None,
+            subjects: Vec::new(),
             current_time: Utc::now(),
             location: None,
             precision: Precision::Standard,
@@ -463,12 +738,19 @@ mod tests {
         EngineInput {
             birth_data: Some(BirthData {
                 name: Some("Test User".to_string()),
+                display_name: None,
                 date: "1985-06-15".to_string(),
                 time: Some("14:30".to_string()),
+                time_unknown: false,
                 latitude: 12.9716,
                 longitude: 77.5946,
+                altitude: None,
                 timezone: "Asia/Kolkata".to_string(),
+                place: None,
+                utc_offset_minutes: None,
             }),
+            secondary_birth_data: None,
+            subjects: Vec::new(),
             current_time: Utc::now(),
             location: None,
             precision: Precision::Standard,
@@ -501,6 +783,7 @@ mod tests {
         let output = result.unwrap();
         assert_eq!(output.engine_id, "vimshottari");
         assert!(!output.witness_prompt.is_empty(), "Witness prompt should not be empty");
+        assert!(output.summary.as_deref().unwrap().contains("Mahadasha"));
         assert_eq!(output.consciousness_level, 3); // Default
 
         // Check timeline structure
@@ -532,6 +815,24 @@ mod tests {
         assert_eq!(mahadashas.len(), 9);
     }
 
+    #[tokio::test]
+    async fn test_high_precision_uses_topocentric_moon() {
+        let engine = VimshottariEngine::new();
+        let geocentric = engine.calculate(create_test_input_with_birth_data()).await.unwrap();
+
+        let mut input = create_test_input_with_birth_data();
+        input.precision = Precision::High;
+        if let Some(birth) = input.birth_data.as_mut() {
+            birth.altitude = Some(920.0);
+        }
+        assert_ne!(engine.cache_key(&input), engine.cache_key(&create_test_input_with_birth_data()));
+        let topocentric = engine.calculate(input).await.unwrap();
+
+        let moon = |output: &EngineOutput| output.result["birth_nakshatra"]["moon_longitude"].as_f64().unwrap();
+        let shift = (moon(&topocentric) - moon(&geocentric)).abs();
+        assert!(shift > 0.0 && shift < 1.0, "topocentric shift {}", shift);
+    }
+
     #[tokio::test]
     async fn test_cache_key_with_birth_data() {
         let engine = VimshottariEngine::new();
@@ -587,6 +888,7 @@ mod tests {
                 "birth_nakshatra": { "name": "Magha", "number": 10 },
             }),
             witness_prompt: "".to_string(),
+            summary: None,
             consciousness_level: 3,
             metadata: CalculationMetadata {
                 calculation_time_ms: 1.0,
@@ -594,6 +896,9 @@ mod tests {
                 precision_achieved: "Standard".to_string(),
                 cached: false,
                 timestamp: Utc::now(),
+                engine_version: None,
+                warnings: Vec::new(),
+                usage: None,
             },
         };
 
@@ -611,6 +916,7 @@ mod tests {
                 "birth_nakshatra": { "name": "Magha" },
             }),
             witness_prompt: "A witness prompt".to_string(),
+            summary: None,
             consciousness_level: 3,
             metadata: CalculationMetadata {
                 calculation_time_ms: 1.0,
@@ -618,6 +924,9 @@ mod tests {
                 precision_achieved: "Standard".to_string(),
                 cached: false,
                 timestamp: Utc::now(),
+                engine_version: None,
+                warnings: Vec::new(),
+                usage: None,
             },
         };
 
@@ -631,6 +940,8 @@ mod tests {
         let engine = VimshottariEngine::new();
         let input = EngineInput {
             birth_data: None,
+            secondary_birth_data: None,
+            subjects: Vec::new(),
             current_time: Utc::now(),
             location: None,
             precision: Precision::Standard,
@@ -656,6 +967,8 @@ mod tests {
 
         let input = EngineInput {
             birth_data: None,
+            secondary_birth_data: None,
+            subjects: Vec::new(),
             current_time: Utc::now(),
             location: None,
             precision: Precision::Standard,
@@ -700,4 +1013,52 @@ mod tests {
         let planet2 = output2.result["timeline"]["mahadashas"][0]["planet"].as_str().unwrap();
         assert_ne!(planet1, planet2, "Different nakshatras should have different starting planets");
     }
+
+    #[tokio::test]
+    async fn test_result_matches_core_schema() {
+        let engine = VimshottariEngine::new();
+        let output = engine.calculate(create_test_input_with_birth_data()).await.unwrap();
+        let typed: noesis_core::results::VimshottariResult = output.parse_as().unwrap();
+        assert_eq!(typed.timeline.mahadashas.len(), 9);
+        assert!(typed.timezone_resolution.is_some());
+        assert!(typed.dasha_transits.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_include_transits_adds_dasha_transits() {
+        let engine = VimshottariEngine::new();
+        let mut input = create_test_input_with_birth_data();
+        input.options.insert("include_transits".to_string(), json!(true));
+        let output = engine.calculate(input).await.unwrap();
+        let typed: noesis_core::results::VimshottariResult = output.parse_as().unwrap();
+
+        let transits = typed.dasha_transits.expect("dasha_transits requested");
+        assert_eq!(transits.natal_planets.len(), 9);
+        assert!(!transits.lords.is_empty() && transits.lords.len() <= 3);
+        let maha = typed.current_period.unwrap().mahadasha.planet;
+        assert_eq!(transits.lords[0].planet, maha);
+        for lord in &transits.lords {
+            assert!((1..=12).contains(&lord.house_from_moon));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_include_transits_from_moon_longitude_uses_natal_moon() {
+        let engine = VimshottariEngine::new();
+        let mut input = create_test_input_with_moon_longitude(125.5);
+        input.options.insert("include_transits".to_string(), json!(true));
+        let output = engine.calculate(input).await.unwrap();
+        assert_eq!(output.result[DASHA_TRANSITS_KEY]["natal_planets"], json!(["Moon"]));
+    }
+
+    #[test]
+    fn test_cache_key_includes_transit_day() {
+        let engine = VimshottariEngine::new();
+        let mut input = create_test_input_with_moon_longitude(125.5);
+        let without = engine.cache_key(&input);
+        input.options.insert("include_transits".to_string(), json!(true));
+        let with = engine.cache_key(&input);
+        assert!(with.starts_with(&without));
+        assert!(with.ends_with(&input.current_time.format("%Y-%m-%d").to_string()));
+    }
 }
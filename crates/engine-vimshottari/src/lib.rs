@@ -11,6 +11,7 @@ pub mod calculator;
 pub mod wisdom;
 pub mod wisdom_data;
 pub mod witness;
+pub mod transits;
 pub mod engine;
 
 pub use engine::VimshottariEngine;
@@ -0,0 +1,481 @@
+//! Dasha-transit combinations
+//!
+//! A dasha lord is felt most strongly when it also transits a sensitive point
+//! of the natal chart. With `options.include_transits = true` the engine
+//! places each running lord (maha, antar and pratyantar) in the sky at
+//! `current_time`, lists its drishti (graha aspects) onto the natal planets
+//! and flags the classical combinations among them, each with a severity:
+//!
+//! - a lord transiting over or aspecting the natal Moon or Sun
+//! - a lord returning to its own natal position
+//! - Sade Sati (Saturn in the 12th, 1st or 2nd sign from the natal Moon) and
+//!   Ashtama Shani (8th sign) while Saturn is a dasha lord
+//! - Jupiter in a favourable sign from the Moon (2, 5, 7, 9, 11) while
+//!   Jupiter is a dasha lord
+//!
+//! Aspects use whole-degree angles with an orb of [`ASPECT_ORB`]: every
+//! planet aspects the 7th, Mars also the 4th and 8th, Jupiter the 5th and
+//! 9th, Saturn the 3rd and 10th. Positions must share one zodiac; the
+//! engine keeps transits in the frame of the natal Moon.
+
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+
+use crate::models::{CurrentPeriod, TransitionLevel, VedicPlanet};
+
+/// Largest distance in degrees from an exact aspect that still counts
+pub const ASPECT_ORB: f64 = 6.0;
+
+/// Result key of the analysis in the engine output
+pub const DASHA_TRANSITS_KEY: &str = "dasha_transits";
+
+/// How strongly a combination is expected to be felt
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Severity {
+    Low,
+    Moderate,
+    High,
+    Critical,
+}
+
+impl Severity {
+    fn from_score(score: u8) -> Self {
+        match score {
+            0 => Severity::Low,
+            1 => Severity::Moderate,
+            2 => Severity::High,
+            _ => Severity::Critical,
+        }
+    }
+}
+
+/// Whether a combination is classically helpful or testing
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Nature {
+    Supportive,
+    Challenging,
+}
+
+/// Graha drishti, named by the house counted from the transiting planet
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Drishti {
+    Conjunction,
+    Third,
+    Fourth,
+    Fifth,
+    Seventh,
+    Eighth,
+    Ninth,
+    Tenth,
+}
+
+impl Drishti {
+    /// Degrees from the transiting planet forward to the aspected point
+    fn angle(&self) -> f64 {
+        match self {
+            Drishti::Conjunction => 0.0,
+            Drishti::Third => 60.0,
+            Drishti::Fourth => 90.0,
+            Drishti::Fifth => 120.0,
+            Drishti::Seventh => 180.0,
+            Drishti::Eighth => 210.0,
+            Drishti::Ninth => 240.0,
+            Drishti::Tenth => 270.0,
+        }
+    }
+
+    fn as_str(&self) -> &'static str {
+        match self {
+            Drishti::Conjunction => "conjunction",
+            Drishti::Third => "3rd-house aspect",
+            Drishti::Fourth => "4th-house aspect",
+            Drishti::Fifth => "5th-house aspect",
+            Drishti::Seventh => "7th-house aspect",
+            Drishti::Eighth => "8th-house aspect",
+            Drishti::Ninth => "9th-house aspect",
+            Drishti::Tenth => "10th-house aspect",
+        }
+    }
+
+    /// Aspects `planet` casts besides conjunction
+    fn cast_by(planet: VedicPlanet) -> &'static [Drishti] {
+        match planet {
+            VedicPlanet::Mars => &[Drishti::Seventh, Drishti::Fourth, Drishti::Eighth],
+            VedicPlanet::Jupiter => &[Drishti::Seventh, Drishti::Fifth, Drishti::Ninth],
+            VedicPlanet::Saturn => &[Drishti::Seventh, Drishti::Third, Drishti::Tenth],
+            _ => &[Drishti::Seventh],
+        }
+    }
+}
+
+/// Classical natural malefics
+fn is_malefic(planet: VedicPlanet) -> bool {
+    matches!(
+        planet,
+        VedicPlanet::Saturn | VedicPlanet::Mars | VedicPlanet::Rahu | VedicPlanet::Ketu | VedicPlanet::Sun
+    )
+}
+
+/// Sign 1-12 of a longitude
+fn sign_of(longitude: f64) -> u8 {
+    (longitude.rem_euclid(360.0) / 30.0) as u8 + 1
+}
+
+/// Sign of `longitude` counted from `from_sign`, which is 1
+fn house_from(from_sign: u8, longitude: f64) -> u8 {
+    ((sign_of(longitude) as i16 - from_sign as i16).rem_euclid(12) + 1) as u8
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct TransitAspect {
+    pub natal: VedicPlanet,
+    pub aspect: Drishti,
+    /// Degrees from exact
+    pub orb: f64,
+}
+
+/// Where a running dasha lord is transiting
+#[derive(Debug, Clone, Serialize)]
+pub struct DashaLordTransit {
+    pub level: TransitionLevel,
+    pub planet: VedicPlanet,
+    pub longitude: f64,
+    pub sign: u8,
+    /// Sign counted from the natal Moon's sign, which is 1
+    pub house_from_moon: u8,
+    pub aspects: Vec<TransitAspect>,
+}
+
+/// A flagged dasha-transit combination
+#[derive(Debug, Clone, Serialize)]
+pub struct DashaTransitCombination {
+    /// Stable identifier, e.g. `sade_sati` or `saturn_conjunction_natal_moon`
+    pub id: String,
+    pub planet: VedicPlanet,
+    /// Highest dasha level the planet is running at
+    pub level: TransitionLevel,
+    pub severity: Severity,
+    pub nature: Nature,
+    pub description: String,
+}
+
+/// The `dasha_transits` section of the output
+#[derive(Debug, Clone, Serialize)]
+pub struct DashaTransitAnalysis {
+    pub calculated_at: DateTime<Utc>,
+    /// Natal planets the transits were compared with; only the Moon when the
+    /// chart came from `moon_longitude`
+    pub natal_planets: Vec<VedicPlanet>,
+    pub lords: Vec<DashaLordTransit>,
+    /// Most severe first
+    pub combinations: Vec<DashaTransitCombination>,
+}
+
+/// Lahiri ayanamsa in degrees at `at`, from its J2000 value and the mean
+/// rate of precession; close enough to move transits into the zodiac of a
+/// sidereal `moon_longitude`
+pub fn lahiri_ayanamsa(at: DateTime<Utc>) -> f64 {
+    const J2000_VALUE: f64 = 23.853;
+    const ARCSEC_PER_YEAR: f64 = 50.29;
+    let j2000 = DateTime::parse_from_rfc3339("2000-01-01T12:00:00Z")
+        .expect("valid epoch")
+        .with_timezone(&Utc);
+    let years = (at - j2000).num_seconds() as f64 / (365.25 * 86_400.0);
+    J2000_VALUE + years * ARCSEC_PER_YEAR / 3600.0
+}
+
+/// The running lords, mahadasha first
+pub fn dasha_lords(period: &CurrentPeriod) -> [(TransitionLevel, VedicPlanet); 3] {
+    [
+        (TransitionLevel::Mahadasha, period.mahadasha.planet),
+        (TransitionLevel::Antardasha, period.antardasha.planet),
+        (TransitionLevel::Pratyantardasha, period.pratyantardasha.planet),
+    ]
+}
+
+fn level_weight(level: TransitionLevel) -> u8 {
+    match level {
+        TransitionLevel::Mahadasha => 2,
+        TransitionLevel::Antardasha => 1,
+        TransitionLevel::Pratyantardasha => 0,
+    }
+}
+
+fn level_name(level: TransitionLevel) -> &'static str {
+    match level {
+        TransitionLevel::Mahadasha => "Mahadasha",
+        TransitionLevel::Antardasha => "Antardasha",
+        TransitionLevel::Pratyantardasha => "Pratyantardasha",
+    }
+}
+
+/// Aspects from a planet at `longitude` onto the natal positions
+fn aspects_onto(planet: VedicPlanet, longitude: f64, natal: &[(VedicPlanet, f64)]) -> Vec<TransitAspect> {
+    let mut aspects = Vec::new();
+    for &(natal_planet, natal_longitude) in natal {
+        let forward = (natal_longitude - longitude).rem_euclid(360.0);
+        for aspect in std::iter::once(&Drishti::Conjunction).chain(Drishti::cast_by(planet)) {
+            let distance = (forward - aspect.angle()).abs();
+            let orb = distance.min(360.0 - distance);
+            if orb <= ASPECT_ORB {
+                aspects.push(TransitAspect {
+                    natal: natal_planet,
+                    aspect: *aspect,
+                    orb: (orb * 100.0).round() / 100.0,
+                });
+            }
+        }
+    }
+    aspects
+}
+
+/// Compare the running dasha lords' transits with the natal chart.
+///
+/// `natal` and `transits` are sidereal longitudes in the same zodiac and
+/// must include the Moon and every lord respectively.
+pub fn analyze(
+    period: &CurrentPeriod,
+    natal: &[(VedicPlanet, f64)],
+    transits: &[(VedicPlanet, f64)],
+) -> DashaTransitAnalysis {
+    let natal_moon = natal
+        .iter()
+        .find(|(planet, _)| *planet == VedicPlanet::Moon)
+        .map(|(_, longitude)| *longitude)
+        .unwrap_or(0.0);
+    let moon_sign = sign_of(natal_moon);
+    let transit_of = |planet: VedicPlanet| {
+        transits
+            .iter()
+            .find(|(p, _)| *p == planet)
+            .map(|(_, longitude)| *longitude)
+    };
+
+    let mut lords = Vec::new();
+    let mut combinations: Vec<DashaTransitCombination> = Vec::new();
+    for (level, planet) in dasha_lords(period) {
+        let Some(longitude) = transit_of(planet) else {
+            continue;
+        };
+        let aspects = aspects_onto(planet, longitude, natal);
+        let house_from_moon = house_from(moon_sign, longitude);
+
+        let mut flag = |id: String, severity: u8, nature: Nature, description: String| {
+            // A lord running at several levels is flagged once, at the highest
+            if !combinations.iter().any(|c| c.id == id) {
+                combinations.push(DashaTransitCombination {
+                    id,
+                    planet,
+                    level,
+                    severity: Severity::from_score(severity),
+                    nature,
+                    description,
+                });
+            }
+        };
+        let malefic = is_malefic(planet);
+        let nature = if malefic { Nature::Challenging } else { Nature::Supportive };
+
+        for aspect in &aspects {
+            let luminary = matches!(aspect.natal, VedicPlanet::Moon | VedicPlanet::Sun);
+            let is_return = aspect.natal == planet && aspect.aspect == Drishti::Conjunction;
+            if !luminary && !is_return {
+                continue;
+            }
+            let score = level_weight(level)
+                + u8::from(malefic && luminary)
+                + u8::from(aspect.aspect == Drishti::Conjunction);
+            let (id, description) = if is_return {
+                (
+                    format!("{}_return", planet.as_str().to_lowercase()),
+                    format!(
+                        "{} returns to its natal position during its {}",
+                        planet.as_str(),
+                        level_name(level)
+                    ),
+                )
+            } else {
+                (
+                    format!(
+                        "{}_{}_natal_{}",
+                        planet.as_str().to_lowercase(),
+                        match aspect.aspect {
+                            Drishti::Conjunction => "conjunction",
+                            _ => "aspect",
+                        },
+                        aspect.natal.as_str().to_lowercase()
+                    ),
+                    format!(
+                        "{} transit makes a {} to natal {} during {} {}",
+                        planet.as_str(),
+                        aspect.aspect.as_str(),
+                        aspect.natal.as_str(),
+                        planet.as_str(),
+                        level_name(level)
+                    ),
+                )
+            };
+            flag(id, score, nature, description);
+        }
+
+        if planet == VedicPlanet::Saturn {
+            let phase = match house_from_moon {
+                12 => Some(("rising", 0)),
+                1 => Some(("peak", 1)),
+                2 => Some(("setting", 0)),
+                _ => None,
+            };
+            if let Some((phase, peak)) = phase {
+                flag(
+                    "sade_sati".to_string(),
+                    level_weight(level) + 1 + peak,
+                    Nature::Challenging,
+                    format!(
+                        "Sade Sati ({} phase): Saturn transits the {} sign from the natal Moon during Saturn {}",
+                        phase,
+                        ordinal(house_from_moon),
+                        level_name(level)
+                    ),
+                );
+            }
+            if house_from_moon == 8 {
+                flag(
+                    "ashtama_shani".to_string(),
+                    level_weight(level) + 1,
+                    Nature::Challenging,
+                    format!(
+                        "Ashtama Shani: Saturn transits the 8th sign from the natal Moon during Saturn {}",
+                        level_name(level)
+                    ),
+                );
+            }
+        }
+        if planet == VedicPlanet::Jupiter && matches!(house_from_moon, 2 | 5 | 7 | 9 | 11) {
+            flag(
+                "jupiter_favourable_from_moon".to_string(),
+                level_weight(level),
+                Nature::Supportive,
+                format!(
+                    "Jupiter transits the {} sign from the natal Moon during Jupiter {}",
+                    ordinal(house_from_moon),
+                    level_name(level)
+                ),
+            );
+        }
+
+        lords.push(DashaLordTransit {
+            level,
+            planet,
+            longitude,
+            sign: sign_of(longitude),
+            house_from_moon,
+            aspects,
+        });
+    }
+    combinations.sort_by_key(|c| std::cmp::Reverse(c.severity));
+
+    DashaTransitAnalysis {
+        calculated_at: period.current_time,
+        natal_planets: natal.iter().map(|(planet, _)| *planet).collect(),
+        lords,
+        combinations,
+    }
+}
+
+fn ordinal(n: u8) -> String {
+    let suffix = match (n % 10, n % 100) {
+        (1, 11) | (2, 12) | (3, 13) => "th",
+        (1, _) => "st",
+        (2, _) => "nd",
+        (3, _) => "rd",
+        _ => "th",
+    };
+    format!("{}{}", n, suffix)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{CurrentAntardasha, CurrentMahadasha, CurrentPratyantardasha};
+
+    fn period(maha: VedicPlanet, antar: VedicPlanet, pratyantar: VedicPlanet) -> CurrentPeriod {
+        let now = Utc::now();
+        CurrentPeriod {
+            mahadasha: CurrentMahadasha { planet: maha, start: now, end: now, years: 19.0 },
+            antardasha: CurrentAntardasha { planet: antar, start: now, end: now, years: 3.0 },
+            pratyantardasha: CurrentPratyantardasha { planet: pratyantar, start: now, end: now, days: 30.0 },
+            current_time: now,
+        }
+    }
+
+    #[test]
+    fn test_saturn_over_natal_moon_in_saturn_dasha_is_critical() {
+        let natal = [(VedicPlanet::Moon, 95.0), (VedicPlanet::Sun, 300.0), (VedicPlanet::Saturn, 200.0)];
+        let transits = [(VedicPlanet::Saturn, 97.5), (VedicPlanet::Venus, 10.0), (VedicPlanet::Ketu, 50.0)];
+        let analysis = analyze(
+            &period(VedicPlanet::Saturn, VedicPlanet::Venus, VedicPlanet::Ketu),
+            &natal,
+            &transits,
+        );
+
+        assert_eq!(analysis.lords.len(), 3);
+        let saturn = &analysis.lords[0];
+        assert_eq!((saturn.sign, saturn.house_from_moon), (4, 1));
+        assert_eq!(saturn.aspects[0].aspect, Drishti::Conjunction);
+        assert_eq!(saturn.aspects[0].orb, 2.5);
+
+        let conjunction = analysis
+            .combinations
+            .iter()
+            .find(|c| c.id == "saturn_conjunction_natal_moon")
+            .expect("Saturn over the Moon is flagged");
+        assert_eq!(conjunction.severity, Severity::Critical);
+        assert_eq!(conjunction.nature, Nature::Challenging);
+        let sade_sati = analysis.combinations.iter().find(|c| c.id == "sade_sati").unwrap();
+        assert_eq!(sade_sati.severity, Severity::Critical);
+        assert!(sade_sati.description.contains("peak"));
+        assert_eq!(analysis.combinations[0].severity, Severity::Critical);
+    }
+
+    #[test]
+    fn test_severity_follows_dasha_level_and_nature() {
+        let natal = [(VedicPlanet::Moon, 10.0)];
+        // Jupiter casts its 9th-house aspect from 130° onto 10°
+        let transits = [(VedicPlanet::Jupiter, 130.0), (VedicPlanet::Saturn, 250.0), (VedicPlanet::Mars, 5.0)];
+        let analysis = analyze(
+            &period(VedicPlanet::Jupiter, VedicPlanet::Saturn, VedicPlanet::Mars),
+            &natal,
+            &transits,
+        );
+        let find = |id: &str| analysis.combinations.iter().find(|c| c.id == id).cloned();
+
+        let jupiter = find("jupiter_aspect_natal_moon").unwrap();
+        assert_eq!((jupiter.severity, jupiter.nature), (Severity::High, Nature::Supportive));
+        assert_eq!(find("jupiter_favourable_from_moon").unwrap().severity, Severity::High);
+        // Saturn in the 9th sign: no Sade Sati, no Ashtama Shani
+        assert!(find("sade_sati").is_none() && find("ashtama_shani").is_none());
+        // Pratyantar Mars conjunct the Moon: weight 0, malefic on a luminary, conjunction
+        assert_eq!(find("mars_conjunction_natal_moon").unwrap().severity, Severity::High);
+    }
+
+    #[test]
+    fn test_lord_at_several_levels_is_flagged_once() {
+        let natal = [(VedicPlanet::Moon, 40.0), (VedicPlanet::Saturn, 300.0)];
+        let transits = [(VedicPlanet::Saturn, 302.0)];
+        let analysis = analyze(
+            &period(VedicPlanet::Saturn, VedicPlanet::Saturn, VedicPlanet::Saturn),
+            &natal,
+            &transits,
+        );
+        let returns: Vec<_> = analysis.combinations.iter().filter(|c| c.id == "saturn_return").collect();
+        assert_eq!(returns.len(), 1);
+        assert_eq!(returns[0].level, TransitionLevel::Mahadasha);
+        // 302° is the 11th sign, the 10th from a Moon in the 2nd: Ashtama doesn't apply
+        assert!(analysis.combinations.iter().all(|c| c.id != "ashtama_shani"));
+        assert_eq!(ordinal(12), "12th");
+        assert_eq!(ordinal(2), "2nd");
+    }
+}
@@ -16,12 +16,19 @@ fn create_hd_input() -> EngineInput {
     EngineInput {
         birth_data: Some(BirthData {
             name: Some("Benchmark".to_string()),
+            display_name: None,
             date: "1990-01-15".to_string(),
             time: Some("14:30".to_string()),
+            time_unknown: false,
             latitude: 40.7128,
             longitude: -74.0060,
+            altitude: None,
             timezone: "America/New_York".to_string(),
+            place: None,
+            utc_offset_minutes: None,
         }),
+        secondary_birth_data: None,
+        subjects: Vec::new(),
         current_time: Utc::now(),
         location: None,
         precision: Precision::Standard,
@@ -40,6 +47,8 @@ fn create_gk_input() -> EngineInput {
 
     EngineInput {
         birth_data: None,
+        secondary_birth_data: None,
+        subjects: Vec::new(),
         current_time: Utc::now(),
         location: None,
         precision: Precision::Standard,
@@ -55,6 +64,8 @@ fn create_vim_input() -> EngineInput {
 
     EngineInput {
         birth_data: None,
+        secondary_birth_data: None,
+        subjects: Vec::new(),
         current_time: Utc::now(),
         location: None,
         precision: Precision::Standard,
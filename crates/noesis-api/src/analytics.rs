@@ -0,0 +1,187 @@
+//! Anonymized Human Design distributions for product analytics
+//!
+//! Counts are taken over each user's latest saved chart and reported per
+//! type, authority, profile, definition, defined center, channel and
+//! activated gate. No bucket counting fewer than `ANALYTICS_MIN_GROUP_SIZE`
+//! users is revealed: it is suppressed, and so is the whole report while
+//! fewer users than that have a chart. Type, authority, profile and
+//! definition each add up to the number of charts, so a single suppressed
+//! bucket there could be recovered by subtraction; the next smallest is
+//! suppressed with it.
+//!
+//! The report is cached for `ANALYTICS_CACHE_TTL_SECS`, so dashboards polling
+//! it don't put the aggregate query on the database on every request.
+
+use std::collections::BTreeMap;
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use noesis_data::models::workflow_result::ChartDistributionCount;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// Cache key of the Human Design report
+pub const HUMAN_DESIGN_CACHE_KEY: &str = "analytics:human-design";
+
+/// Dimensions every chart has exactly one value of
+const SINGLE_VALUED: [&str; 4] = ["type", "authority", "profile", "definition"];
+
+/// k-anonymity threshold and caching of analytics reports
+#[derive(Debug, Clone, Copy)]
+pub struct AnalyticsSettings {
+    /// Fewest users a reported count may cover
+    pub min_group_size: u64,
+    pub cache_ttl: Duration,
+}
+
+/// Users with one value of a dimension
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct DistributionBucket {
+    #[schema(example = "Generator")]
+    pub value: String,
+    pub users: u64,
+    /// `users` over the number of charts
+    #[schema(example = 0.37)]
+    pub share: f64,
+}
+
+/// The reported buckets of one dimension, most users first
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct Distribution {
+    pub buckets: Vec<DistributionBucket>,
+    /// Buckets left out for counting too few users
+    pub suppressed: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct HumanDesignDistributionResponse {
+    /// Users with a saved chart; absent, with every distribution empty,
+    /// while fewer than `min_group_size`
+    pub charts: Option<u64>,
+    /// Fewest users any reported count covers
+    #[schema(example = 10)]
+    pub min_group_size: u64,
+    pub types: Distribution,
+    pub authorities: Distribution,
+    pub profiles: Distribution,
+    pub definitions: Distribution,
+    pub centers: Distribution,
+    /// Channels as "gate-gate", e.g. "34-20"
+    pub channels: Distribution,
+    /// Gates activated by a Personality or Design planet
+    pub gates: Distribution,
+    pub computed_at: DateTime<Utc>,
+}
+
+/// Build the report from the database counts, suppressing every bucket that
+/// would reveal fewer than `min_group_size` users
+pub fn human_design_distribution(counts: &[ChartDistributionCount], min_group_size: u64) -> HumanDesignDistributionResponse {
+    let charts = counts
+        .iter()
+        .find(|count| count.dimension == "charts")
+        .map_or(0, |count| count.users.max(0) as u64);
+    let mut dimensions: BTreeMap<&str, Vec<(&str, u64)>> = BTreeMap::new();
+    for count in counts.iter().filter(|count| count.dimension != "charts") {
+        dimensions
+            .entry(count.dimension.as_str())
+            .or_default()
+            .push((count.value.as_str(), count.users.max(0) as u64));
+    }
+
+    let visible = charts >= min_group_size;
+    let mut distribution = |dimension: &str| {
+        let buckets = dimensions.remove(dimension).unwrap_or_default();
+        if visible {
+            suppress(buckets, charts, min_group_size, SINGLE_VALUED.contains(&dimension))
+        } else {
+            Distribution::default()
+        }
+    };
+
+    HumanDesignDistributionResponse {
+        types: distribution("type"),
+        authorities: distribution("authority"),
+        profiles: distribution("profile"),
+        definitions: distribution("definition"),
+        centers: distribution("center"),
+        channels: distribution("channel"),
+        gates: distribution("gate"),
+        charts: visible.then_some(charts),
+        min_group_size,
+        computed_at: Utc::now(),
+    }
+}
+
+fn suppress(mut buckets: Vec<(&str, u64)>, charts: u64, min_group_size: u64, single_valued: bool) -> Distribution {
+    buckets.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(b.0)));
+    let mut shown = buckets.iter().take_while(|(_, users)| *users >= min_group_size).count();
+    // The one hidden bucket of a dimension adding up to `charts` would be
+    // `charts` minus the rest
+    if single_valued && shown > 0 && buckets.len() - shown == 1 {
+        shown -= 1;
+    }
+
+    Distribution {
+        buckets: buckets[..shown]
+            .iter()
+            .map(|(value, users)| DistributionBucket {
+                value: value.to_string(),
+                users: *users,
+                share: *users as f64 / charts as f64,
+            })
+            .collect(),
+        suppressed: buckets.len() - shown,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn count(dimension: &str, value: &str, users: i64) -> ChartDistributionCount {
+        ChartDistributionCount { dimension: dimension.to_string(), value: value.to_string(), users }
+    }
+
+    #[test]
+    fn test_small_buckets_are_suppressed() {
+        let counts = [
+            count("charts", "", 40),
+            count("gate", "34", 25),
+            count("gate", "20", 10),
+            count("gate", "64", 3),
+            count("channel", "34-20", 2),
+        ];
+        let report = human_design_distribution(&counts, 10);
+
+        assert_eq!(report.charts, Some(40));
+        let gates: Vec<(&str, u64)> = report.gates.buckets.iter().map(|b| (b.value.as_str(), b.users)).collect();
+        assert_eq!(gates, [("34", 25), ("20", 10)]);
+        assert_eq!(report.gates.suppressed, 1);
+        assert_eq!(report.gates.buckets[0].share, 0.625);
+        assert!(report.channels.buckets.is_empty());
+        assert_eq!(report.channels.suppressed, 1);
+    }
+
+    #[test]
+    fn test_lone_suppressed_bucket_takes_the_next_with_it() {
+        // Reflector is 40 - 22 - 12, so Projector must go too
+        let counts = [
+            count("charts", "", 40),
+            count("type", "Generator", 22),
+            count("type", "Projector", 12),
+            count("type", "Reflector", 6),
+        ];
+        let report = human_design_distribution(&counts, 10);
+        let types: Vec<&str> = report.types.buckets.iter().map(|b| b.value.as_str()).collect();
+        assert_eq!(types, ["Generator"]);
+        assert_eq!(report.types.suppressed, 2);
+    }
+
+    #[test]
+    fn test_too_few_charts_reveals_nothing() {
+        let counts = [count("charts", "", 9), count("type", "Generator", 9)];
+        let report = human_design_distribution(&counts, 10);
+        assert_eq!(report.charts, None);
+        assert_eq!(report.types, Distribution::default());
+    }
+}
@@ -0,0 +1,264 @@
+//! Practitioner annotations on saved results
+//!
+//! An annotation attaches a note to a saved workflow result, corrects one
+//! computed value, or both: a practitioner who reads a borderline chart's
+//! authority differently records the value they stand by next to the one
+//! the engine computed. The stored result is never rewritten. Fetches apply
+//! the corrections to a copy (see [`apply_corrections`]) and list each one
+//! with the computed value it replaces, so a reader can always tell the
+//! calculation from the practitioner's reading of it.
+
+use std::collections::HashMap;
+
+use noesis_core::{EngineError, ValidationCode, ValidationError, WorkflowResult};
+use noesis_data::models::workflow_result::{NewAnnotation, ResultAnnotation};
+use serde::Serialize;
+use serde_json::Value;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+/// Longest note accepted, in characters
+pub const MAX_NOTE_CHARS: usize = 2000;
+
+/// A corrected value, as listed with its annotation
+#[derive(Debug, Clone, PartialEq, Serialize, ToSchema)]
+pub struct Correction {
+    /// The practitioner's value
+    #[schema(value_type = Object, example = "Emotional")]
+    pub value: Value,
+    /// What the engine computed
+    #[schema(value_type = Object, example = "Sacral")]
+    pub original: Value,
+    /// Whether `value` is in the returned result: false when a newer
+    /// correction of the same value supersedes it, or the original was
+    /// requested
+    pub applied: bool,
+}
+
+/// Check a new annotation against the result it annotates: a correction
+/// must name an engine output of `result` and a value that exists in it
+pub fn validate(annotation: &NewAnnotation, result: &WorkflowResult) -> Result<(), EngineError> {
+    let mut errors = Vec::new();
+    let note = annotation.note.as_deref();
+    if note.is_none() && annotation.value.is_none() {
+        errors.push(ValidationError::new("note", ValidationCode::Required, "An annotation needs a note, a value or both"));
+    }
+    if note.is_some_and(|note| note.trim().is_empty()) {
+        errors.push(ValidationError::new("note", ValidationCode::Empty, "Note cannot be empty"));
+    }
+    if note.is_some_and(|note| note.chars().count() > MAX_NOTE_CHARS) {
+        errors.push(ValidationError::new("note", ValidationCode::OutOfRange, format!("Note is limited to {} characters", MAX_NOTE_CHARS)));
+    }
+
+    let output = match &annotation.engine_id {
+        Some(engine_id) => match result.engine_outputs.get(engine_id) {
+            Some(output) => Some(output),
+            None => {
+                errors.push(ValidationError::new("engine_id", ValidationCode::NotFound, format!("The result has no output of engine '{}'", engine_id)));
+                None
+            }
+        },
+        None => {
+            if annotation.path.is_some() || annotation.value.is_some() {
+                errors.push(ValidationError::new("engine_id", ValidationCode::Required, "A path or value needs the engine_id it belongs to"));
+            }
+            None
+        }
+    };
+    match &annotation.path {
+        Some(path) if !path.starts_with('/') => {
+            errors.push(ValidationError::new("path", ValidationCode::InvalidFormat, "Path must be a JSON pointer such as /authority"));
+        }
+        Some(path) if output.is_some_and(|output| output.result.pointer(path).is_none()) => {
+            errors.push(ValidationError::new("path", ValidationCode::NotFound, format!("The engine's result has no value at '{}'", path)));
+        }
+        Some(_) => {}
+        None if annotation.value.is_some() => {
+            errors.push(ValidationError::new("path", ValidationCode::Required, "A value needs the path of the value it corrects"));
+        }
+        None => {}
+    }
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(EngineError::InvalidInput(errors))
+    }
+}
+
+/// Apply the corrections among `annotations`, oldest first, to `result`,
+/// so the newest correction of a value wins. With `apply` false `result`
+/// is left as computed. Returns each correction by annotation id; ones
+/// whose value no longer exists in `result` are left out.
+pub fn apply_corrections(
+    result: &mut WorkflowResult,
+    annotations: &[ResultAnnotation],
+    apply: bool,
+) -> HashMap<Uuid, Correction> {
+    let mut originals: HashMap<(&str, &str), Value> = HashMap::new();
+    let mut newest: HashMap<(&str, &str), Uuid> = HashMap::new();
+    for annotation in annotations {
+        let (Some(engine_id), Some(path), Some(_)) = (&annotation.engine_id, &annotation.path, &annotation.value) else {
+            continue;
+        };
+        let Some(original) = result.engine_outputs.get(engine_id).and_then(|output| output.result.pointer(path)) else {
+            continue;
+        };
+        originals.entry((engine_id, path)).or_insert_with(|| original.clone());
+        newest.insert((engine_id, path), annotation.id);
+    }
+
+    let mut corrections = HashMap::new();
+    for annotation in annotations {
+        let (Some(engine_id), Some(path), Some(value)) = (&annotation.engine_id, &annotation.path, &annotation.value) else {
+            continue;
+        };
+        let key = (engine_id.as_str(), path.as_str());
+        let Some(original) = originals.get(&key) else {
+            continue;
+        };
+        let applied = apply && newest.get(&key) == Some(&annotation.id);
+        if applied {
+            if let Some(target) = result
+                .engine_outputs
+                .get_mut(engine_id)
+                .and_then(|output| output.result.pointer_mut(path))
+            {
+                *target = value.clone();
+            }
+        }
+        corrections.insert(
+            annotation.id,
+            Correction {
+                value: value.clone(),
+                original: original.clone(),
+                applied,
+            },
+        );
+    }
+    corrections
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+    use noesis_core::{CalculationMetadata, EngineOutput};
+    use serde_json::json;
+
+    fn result() -> WorkflowResult {
+        let output = EngineOutput {
+            engine_id: "human-design".to_string(),
+            result: json!({ "hd_type": "Generator", "authority": "Sacral", "channels": [{ "name": "34-20" }] }),
+            witness_prompt: String::new(),
+            summary: None,
+            consciousness_level: 3,
+            metadata: CalculationMetadata {
+                calculation_time_ms: 1.0,
+                backend: "native".to_string(),
+                precision_achieved: "standard".to_string(),
+                cached: false,
+                timestamp: Utc::now(),
+                engine_version: None,
+                warnings: Vec::new(),
+                usage: None,
+            },
+        };
+        WorkflowResult {
+            workflow_id: "birth-blueprint".to_string(),
+            engine_outputs: HashMap::from([("human-design".to_string(), output)]),
+            synthesis: None,
+            total_time_ms: 1.0,
+            timestamp: Utc::now(),
+            usage: None,
+            time_confidence: None,
+        }
+    }
+
+    fn correction(path: &str, value: Value) -> NewAnnotation {
+        NewAnnotation {
+            engine_id: Some("human-design".to_string()),
+            path: Some(path.to_string()),
+            note: None,
+            value: Some(value),
+        }
+    }
+
+    fn saved(annotation: NewAnnotation) -> ResultAnnotation {
+        ResultAnnotation {
+            id: Uuid::new_v4(),
+            result_id: Uuid::new_v4(),
+            author_id: Uuid::new_v4(),
+            engine_id: annotation.engine_id,
+            path: annotation.path,
+            note: annotation.note,
+            value: annotation.value,
+            created_at: Utc::now(),
+        }
+    }
+
+    fn error_fields(annotation: &NewAnnotation) -> Vec<(String, ValidationCode)> {
+        match validate(annotation, &result()) {
+            Err(EngineError::InvalidInput(errors)) => errors.into_iter().map(|e| (e.field, e.code)).collect(),
+            other => panic!("expected InvalidInput, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_validate() {
+        let note = NewAnnotation {
+            note: Some("Emotional authority reads truer in session".to_string()),
+            ..NewAnnotation::default()
+        };
+        assert!(validate(&note, &result()).is_ok());
+        assert!(validate(&correction("/authority", json!("Emotional")), &result()).is_ok());
+        assert!(validate(&correction("/channels/0/name", json!("34-57")), &result()).is_ok());
+
+        assert_eq!(error_fields(&NewAnnotation::default()), vec![("note".to_string(), ValidationCode::Required)]);
+        assert_eq!(
+            error_fields(&correction("/strategy", json!("To respond"))),
+            vec![("path".to_string(), ValidationCode::NotFound)]
+        );
+        assert_eq!(
+            error_fields(&correction("authority", json!("Emotional"))),
+            vec![("path".to_string(), ValidationCode::InvalidFormat)]
+        );
+        let other_engine = NewAnnotation {
+            engine_id: Some("numerology".to_string()),
+            ..correction("/authority", json!("Emotional"))
+        };
+        assert_eq!(error_fields(&other_engine), vec![("engine_id".to_string(), ValidationCode::NotFound)]);
+        let no_engine = NewAnnotation {
+            engine_id: None,
+            ..correction("/authority", json!("Emotional"))
+        };
+        assert_eq!(error_fields(&no_engine), vec![("engine_id".to_string(), ValidationCode::Required)]);
+    }
+
+    #[test]
+    fn test_newest_correction_applies() {
+        let first = saved(correction("/authority", json!("Splenic")));
+        let second = saved(correction("/authority", json!("Emotional")));
+        let note = saved(NewAnnotation {
+            note: Some("Borderline chart".to_string()),
+            ..NewAnnotation::default()
+        });
+        let annotations = vec![first.clone(), note.clone(), second.clone()];
+
+        let mut corrected = result();
+        let corrections = apply_corrections(&mut corrected, &annotations, true);
+        assert_eq!(corrected.engine_outputs["human-design"].result["authority"], "Emotional");
+        assert_eq!(
+            corrections[&second.id],
+            Correction { value: json!("Emotional"), original: json!("Sacral"), applied: true }
+        );
+        assert!(!corrections[&first.id].applied);
+        assert_eq!(corrections[&first.id].original, "Sacral");
+        assert!(!corrections.contains_key(&note.id));
+
+        let mut original = result();
+        let corrections = apply_corrections(&mut original, &annotations, false);
+        assert_eq!(original.engine_outputs["human-design"].result["authority"], "Sacral");
+        assert!(corrections.values().all(|c| !c.applied));
+    }
+}
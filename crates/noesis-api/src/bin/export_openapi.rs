@@ -0,0 +1,29 @@
+//! Write the OpenAPI spec for client SDK generation
+//!
+//! Usage:
+//!   cargo run -p noesis-api --bin export_openapi [OUTPUT]
+//!
+//! OUTPUT defaults to `docs/api/openapi.json` in the workspace root; pass `-`
+//! to print to stdout.
+
+use std::path::PathBuf;
+
+fn main() {
+    let spec = noesis_api::openapi_spec()
+        .to_pretty_json()
+        .expect("Failed to serialize OpenAPI spec");
+
+    match std::env::args().nth(1).as_deref() {
+        Some("-") => println!("{}", spec),
+        arg => {
+            let path = arg.map(PathBuf::from).unwrap_or_else(|| {
+                PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+                    .join("../..")
+                    .join(noesis_api::openapi::SPEC_PATH)
+            });
+            std::fs::write(&path, format!("{}\n", spec))
+                .unwrap_or_else(|e| panic!("Failed to write {}: {}", path.display(), e));
+            eprintln!("Wrote {}", path.display());
+        }
+    }
+}
@@ -65,6 +65,8 @@ async fn main() {
         last_used: None,
         rate_limit: 1000,
         consciousness_level: 0,
+        allowed_engines: None,
+        allowed_workflows: None,
     };
     
     println!("{:#?}", api_key);
@@ -3,8 +3,17 @@
 //! Loads configuration from environment variables with sensible defaults
 //! for development and production environments.
 
+use std::collections::HashMap;
 use std::env;
 
+use noesis_cache::l1_cache::DEFAULT_L1_SHARES;
+use noesis_core::redaction::{RedactionMode, RedactionPolicies};
+use noesis_core::NameNormalization;
+use noesis_data::repositories::retention_repository::{DataClass, RetentionPolicies};
+
+use crate::middleware::MetricsAccess;
+use crate::versioning::ApiVersion;
+
 /// API server configuration loaded from environment variables
 #[derive(Debug, Clone)]
 pub struct ApiConfig {
@@ -40,6 +49,99 @@ pub struct ApiConfig {
     
     /// Log format: "pretty" or "json" (default: "pretty" for dev, "json" for prod)
     pub log_format: String,
+
+    /// Engines whose failing health check makes `/ready` report not ready
+    /// (default: "panchanga,human-design")
+    pub critical_engines: Vec<String>,
+
+    /// Default and maximum lifetime of result share links in seconds
+    /// (default: 604800, one week)
+    pub share_link_ttl_secs: u64,
+
+    /// Public origin prepended to share link URLs, e.g. "https://api.example.com"
+    /// (optional, None returns relative URLs)
+    pub public_base_url: Option<String>,
+
+    /// Largest accepted request body in bytes; larger bodies get 413
+    /// (default: 1048576, 1 MiB)
+    pub max_body_bytes: usize,
+
+    /// Reject unknown top-level fields in engine and workflow inputs with 422
+    /// instead of ignoring them (default: false)
+    pub strict_json: bool,
+
+    /// What is stripped from names in engine and workflow inputs before
+    /// calculation (default: diacritics and punctuation)
+    pub name_normalization: NameNormalization,
+
+    /// Honour `options.debug` for every caller, not only those holding the
+    /// `admin:debug` permission; for development (default: false)
+    pub open_debug: bool,
+
+    /// How often soft-deleted accounts past their restore window are purged,
+    /// in seconds (default: 3600)
+    pub purge_interval_secs: u64,
+
+    /// Days each data class is kept before the retention job deletes it, and
+    /// whether it only counts what it would delete (default: keep everything)
+    pub retention: RetentionPolicies,
+
+    /// How often the retention job runs, in seconds (default: 86400)
+    pub retention_interval_secs: u64,
+
+    /// How often notification rules are evaluated, in seconds (default: 3600)
+    pub notification_interval_secs: u64,
+
+    /// Fewest users an analytics bucket may count before it is suppressed
+    /// (default: 10)
+    pub analytics_min_group_size: u64,
+
+    /// How long analytics aggregates are served from cache, in seconds
+    /// (default: 3600)
+    pub analytics_cache_ttl_secs: u64,
+
+    /// Apply pending database migrations at startup (default: true)
+    pub db_auto_migrate: bool,
+
+    /// Database connection attempts at startup before falling back to
+    /// degraded mode (default: 5)
+    pub db_connect_attempts: u32,
+
+    /// Read replica for reads that tolerate replication lag, such as saved
+    /// result lookups (optional, None reads from `database_url`)
+    pub database_replica_url: Option<String>,
+
+    /// Postgres `statement_timeout` for every pooled connection in
+    /// milliseconds, 0 disables it (default: 5000)
+    pub db_statement_timeout_ms: u64,
+
+    /// Requests in flight at which free-tier calculations are shed; premium
+    /// calculations are shed at twice this, 0 disables the signal (default: 512)
+    pub load_shed_max_in_flight: usize,
+
+    /// Runtime scheduling lag in milliseconds at which free-tier calculations
+    /// are shed, premium at twice this, 0 disables the signal (default: 100)
+    pub load_shed_max_lag_ms: u64,
+
+    /// API versions whose routes answer 410 Gone, e.g. v1 after its sunset
+    /// (default: none)
+    pub disabled_api_versions: Vec<ApiVersion>,
+
+    /// Fraction of the L1 cache reserved for each listed engine; the others
+    /// share the rest (default: "human-design=0.25,gene-keys=0.15,vimshottari=0.15")
+    pub l1_cache_shares: HashMap<String, f64>,
+
+    /// How personal data in engine outputs is redacted in logs, shared
+    /// results and exports (default: hash in logs, strip elsewhere)
+    pub redaction: RedactionPolicies,
+
+    /// Bearer token and source networks required to scrape `/metrics`
+    /// (default: open to anyone who reaches it)
+    pub metrics_access: MetricsAccess,
+
+    /// Serve `/metrics` on its own listener at this port instead of the
+    /// public one (optional, None serves it next to the API)
+    pub metrics_port: Option<u16>,
 }
 
 impl ApiConfig {
@@ -56,6 +158,41 @@ impl ApiConfig {
     /// - `REQUEST_TIMEOUT_SECS`: Request timeout in seconds (default: 30)
     /// - `RUST_LOG`: Log level (default: "info,noesis_api=debug")
     /// - `LOG_FORMAT`: Log format "pretty" or "json" (default: "pretty")
+    /// - `CRITICAL_ENGINES`: Comma-separated engines that gate readiness (default: panchanga,human-design)
+    /// - `SHARE_LINK_TTL_SECS`: Default and maximum share link lifetime (default: 604800)
+    /// - `PUBLIC_BASE_URL`: Origin used in share link URLs (optional)
+    /// - `MAX_BODY_BYTES`: Largest accepted request body in bytes (default: 1048576)
+    /// - `STRICT_JSON`: "true" rejects unknown input fields (default: false)
+    /// - `NAME_STRIP_DIACRITICS`, `NAME_STRIP_PUNCTUATION`: "false" keeps accents or punctuation
+    ///   in names engines calculate with (default: true)
+    /// - `OPEN_DEBUG`: "true" lets any caller set `options.debug`; refused in production (default: false)
+    /// - `PURGE_INTERVAL_SECS`: Interval of the deleted account purge job (default: 3600)
+    /// - `RETENTION_CALCULATION_HISTORY_DAYS`, `RETENTION_JOURNAL_DAYS`, `RETENTION_AUDIT_LOG_DAYS`,
+    ///   `RETENTION_GUEST_DATA_DAYS`: Days saved results, practice logs, usage logs and client
+    ///   profiles are kept (default: unset, kept indefinitely)
+    /// - `RETENTION_DRY_RUN`: "true" counts expired rows without deleting them (default: false)
+    /// - `RETENTION_INTERVAL_SECS`: Interval of the retention job (default: 86400)
+    /// - `NOTIFICATION_INTERVAL_SECS`: Interval of the notification rule job (default: 3600)
+    /// - `ANALYTICS_MIN_GROUP_SIZE`: Smallest group of users an analytics count may reveal (default: 10)
+    /// - `ANALYTICS_CACHE_TTL_SECS`: How long analytics aggregates are cached (default: 3600)
+    /// - `DB_AUTO_MIGRATE`: "false" skips applying migrations at startup (default: true)
+    /// - `DB_CONNECT_ATTEMPTS`: Connection attempts before degraded mode (default: 5)
+    /// - `DATABASE_REPLICA_URL`: Read replica for lag-tolerant reads (optional)
+    /// - `DB_STATEMENT_TIMEOUT_MS`: Statement timeout in milliseconds, 0 disables (default: 5000)
+    /// - `LOAD_SHED_MAX_IN_FLIGHT`: In-flight requests at which free-tier calculations are shed, 0 disables (default: 512)
+    /// - `LOAD_SHED_MAX_LAG_MS`: Runtime lag at which free-tier calculations are shed, 0 disables (default: 100)
+    /// - `API_DISABLED_VERSIONS`: Comma-separated API versions to stop serving, e.g. "v1" (default: none)
+    /// - `L1_CACHE_SHARES`: Comma-separated `engine=fraction` L1 reservations, empty shares all of L1
+    ///   (default: human-design=0.25,gene-keys=0.15,vimshottari=0.15)
+    /// - `REDACT_LOGGING`, `REDACT_SHARING`, `REDACT_EXPORT`: "off", "strip" or "hash" for
+    ///   logged outputs, share links, and exports including LLM synthesis (default: hash, strip, strip)
+    /// - `REDACT_FIELDS`: Comma-separated result keys to redact (default:
+    ///   birth_data,secondary_birth_data,subjects,birth_date,birth_time,birth_location)
+    /// - `REDACT_HASH_SALT`: Salt mixed into redaction hashes (default: empty)
+    /// - `METRICS_BEARER_TOKEN`: Token scrapers must send to read `/metrics` (optional)
+    /// - `METRICS_ALLOWED_IPS`: Comma-separated addresses or CIDR networks allowed to
+    ///   scrape `/metrics` (default: any)
+    /// - `METRICS_PORT`: Port of an internal listener serving only `/metrics` (optional)
     ///
     /// # Returns
     /// Configured `ApiConfig` instance
@@ -127,6 +264,200 @@ impl ApiConfig {
         
         let log_format = env::var("LOG_FORMAT")
             .unwrap_or_else(|_| "pretty".to_string());
+
+        let critical_engines = env::var("CRITICAL_ENGINES")
+            .unwrap_or_else(|_| "panchanga,human-design".to_string())
+            .split(',')
+            .map(|s| s.trim().to_string())
+            .filter(|s| !s.is_empty())
+            .collect();
+
+        let share_link_ttl_secs = env::var("SHARE_LINK_TTL_SECS")
+            .ok()
+            .and_then(|t| t.parse().ok())
+            .unwrap_or(604_800);
+
+        let public_base_url = env::var("PUBLIC_BASE_URL")
+            .ok()
+            .filter(|url| !url.trim().is_empty());
+
+        let max_body_bytes = env::var("MAX_BODY_BYTES")
+            .ok()
+            .and_then(|b| b.parse().ok())
+            .unwrap_or(1_048_576);
+
+        let strict_json = env::var("STRICT_JSON")
+            .map(|s| s.eq_ignore_ascii_case("true") || s == "1")
+            .unwrap_or(false);
+
+        let name_normalization = NameNormalization {
+            strip_diacritics: env::var("NAME_STRIP_DIACRITICS")
+                .map(|s| s.eq_ignore_ascii_case("true") || s == "1")
+                .unwrap_or(true),
+            strip_punctuation: env::var("NAME_STRIP_PUNCTUATION")
+                .map(|s| s.eq_ignore_ascii_case("true") || s == "1")
+                .unwrap_or(true),
+        };
+
+        let open_debug = env::var("OPEN_DEBUG")
+            .map(|s| s.eq_ignore_ascii_case("true") || s == "1")
+            .unwrap_or(false);
+
+        let purge_interval_secs = env::var("PURGE_INTERVAL_SECS")
+            .ok()
+            .and_then(|p| p.parse().ok())
+            .unwrap_or(3600);
+
+        let retention = RetentionPolicies {
+            ttl_days: DataClass::ALL
+                .into_iter()
+                .filter_map(|class| {
+                    let var = format!("RETENTION_{}_DAYS", class.as_str().to_uppercase());
+                    let days = env::var(&var).ok().filter(|d| !d.trim().is_empty())?;
+                    match days.trim().parse::<u32>() {
+                        Ok(days) => Some((class, days)),
+                        Err(e) => {
+                            tracing::warn!("Ignoring {}: {}", var, e);
+                            None
+                        }
+                    }
+                })
+                .collect(),
+            dry_run: env::var("RETENTION_DRY_RUN")
+                .map(|s| s.eq_ignore_ascii_case("true") || s == "1")
+                .unwrap_or(false),
+        };
+
+        let retention_interval_secs = env::var("RETENTION_INTERVAL_SECS")
+            .ok()
+            .and_then(|r| r.parse().ok())
+            .unwrap_or(86_400);
+
+        let notification_interval_secs = env::var("NOTIFICATION_INTERVAL_SECS")
+            .ok()
+            .and_then(|n| n.parse().ok())
+            .unwrap_or(3600);
+
+        let analytics_min_group_size = env::var("ANALYTICS_MIN_GROUP_SIZE")
+            .ok()
+            .and_then(|k| k.parse().ok())
+            .unwrap_or(10);
+
+        let analytics_cache_ttl_secs = env::var("ANALYTICS_CACHE_TTL_SECS")
+            .ok()
+            .and_then(|t| t.parse().ok())
+            .unwrap_or(3600);
+
+        let db_auto_migrate = env::var("DB_AUTO_MIGRATE")
+            .map(|s| !(s.eq_ignore_ascii_case("false") || s == "0"))
+            .unwrap_or(true);
+
+        let db_connect_attempts = env::var("DB_CONNECT_ATTEMPTS")
+            .ok()
+            .and_then(|a| a.parse().ok())
+            .unwrap_or(5);
+
+        let database_replica_url = env::var("DATABASE_REPLICA_URL")
+            .ok()
+            .filter(|url| !url.trim().is_empty());
+
+        let db_statement_timeout_ms = env::var("DB_STATEMENT_TIMEOUT_MS")
+            .ok()
+            .and_then(|t| t.parse().ok())
+            .unwrap_or(5000);
+
+        let load_shed_max_in_flight = env::var("LOAD_SHED_MAX_IN_FLIGHT")
+            .ok()
+            .and_then(|n| n.parse().ok())
+            .unwrap_or(512);
+
+        let load_shed_max_lag_ms = env::var("LOAD_SHED_MAX_LAG_MS")
+            .ok()
+            .and_then(|ms| ms.parse().ok())
+            .unwrap_or(100);
+
+        let disabled_api_versions = env::var("API_DISABLED_VERSIONS")
+            .unwrap_or_default()
+            .split(',')
+            .map(str::trim)
+            .filter(|s| !s.is_empty())
+            .filter_map(|s| match s.parse::<ApiVersion>() {
+                Ok(version) => Some(version),
+                Err(e) => {
+                    tracing::warn!("Ignoring API_DISABLED_VERSIONS entry: {}", e);
+                    None
+                }
+            })
+            .collect();
+
+        let l1_cache_shares = match env::var("L1_CACHE_SHARES") {
+            Ok(shares) => shares
+                .split(',')
+                .map(str::trim)
+                .filter(|s| !s.is_empty())
+                .filter_map(|s| {
+                    let entry = s
+                        .split_once('=')
+                        .and_then(|(engine, share)| Some((engine.trim(), share.trim().parse::<f64>().ok()?)))
+                        .filter(|(engine, share)| !engine.is_empty() && *share > 0.0 && *share < 1.0);
+                    if entry.is_none() {
+                        tracing::warn!("Ignoring L1_CACHE_SHARES entry '{}', expected engine=fraction", s);
+                    }
+                    entry.map(|(engine, share)| (engine.to_string(), share))
+                })
+                .collect(),
+            Err(_) => DEFAULT_L1_SHARES
+                .iter()
+                .map(|(engine, share)| (engine.to_string(), *share))
+                .collect(),
+        };
+
+        let defaults = RedactionPolicies::default();
+        let redaction_mode = |var: &str, default: RedactionMode| match env::var(var) {
+            Ok(mode) => mode.parse().unwrap_or_else(|e| {
+                tracing::warn!("Ignoring {}: {}", var, e);
+                default
+            }),
+            Err(_) => default,
+        };
+        let redaction = RedactionPolicies {
+            logging: redaction_mode("REDACT_LOGGING", defaults.logging),
+            sharing: redaction_mode("REDACT_SHARING", defaults.sharing),
+            export: redaction_mode("REDACT_EXPORT", defaults.export),
+            fields: match env::var("REDACT_FIELDS") {
+                Ok(fields) => fields
+                    .split(',')
+                    .map(str::trim)
+                    .filter(|s| !s.is_empty())
+                    .map(str::to_string)
+                    .collect(),
+                Err(_) => defaults.fields,
+            },
+            hash_salt: env::var("REDACT_HASH_SALT").unwrap_or_default(),
+        };
+
+        let metrics_access = MetricsAccess {
+            bearer_token: env::var("METRICS_BEARER_TOKEN")
+                .ok()
+                .filter(|token| !token.trim().is_empty()),
+            allowed_ips: env::var("METRICS_ALLOWED_IPS")
+                .unwrap_or_default()
+                .split(',')
+                .map(str::trim)
+                .filter(|s| !s.is_empty())
+                .filter_map(|s| {
+                    let net = s
+                        .parse::<ipnet::IpNet>()
+                        .or_else(|_| s.parse::<std::net::IpAddr>().map(ipnet::IpNet::from));
+                    if net.is_err() {
+                        tracing::warn!("Ignoring METRICS_ALLOWED_IPS entry '{}', expected an address or CIDR", s);
+                    }
+                    net.ok()
+                })
+                .collect(),
+        };
+
+        let metrics_port = env::var("METRICS_PORT").ok().and_then(|p| p.parse().ok());
         
         Self {
             host,
@@ -140,6 +471,30 @@ impl ApiConfig {
             request_timeout_secs,
             log_level,
             log_format,
+            critical_engines,
+            share_link_ttl_secs,
+            public_base_url,
+            max_body_bytes,
+            strict_json,
+            name_normalization,
+            open_debug,
+            purge_interval_secs,
+            retention,
+            retention_interval_secs,
+            notification_interval_secs,
+            analytics_min_group_size,
+            analytics_cache_ttl_secs,
+            db_auto_migrate,
+            db_connect_attempts,
+            database_replica_url,
+            db_statement_timeout_ms,
+            load_shed_max_in_flight,
+            load_shed_max_lag_ms,
+            disabled_api_versions,
+            l1_cache_shares,
+            redaction,
+            metrics_access,
+            metrics_port,
         }
     }
     
@@ -178,6 +533,14 @@ impl ApiConfig {
             ));
         }
 
+        if let Some(url) = &self.database_replica_url {
+            if !url.starts_with("postgresql://") && !url.starts_with("postgres://") {
+                return Err(
+                    "DATABASE_REPLICA_URL must start with 'postgresql://' or 'postgres://'".to_string(),
+                );
+            }
+        }
+
         // Validate port range
         if self.port < 1024 {
             tracing::warn!(
@@ -200,6 +563,73 @@ impl ApiConfig {
             return Err("Request timeout cannot be 0 seconds".to_string());
         }
         
+        if self.share_link_ttl_secs == 0 {
+            return Err("Share link TTL cannot be 0 seconds".to_string());
+        }
+
+        if self.max_body_bytes == 0 {
+            return Err("Max body size cannot be 0 bytes".to_string());
+        }
+
+        if self.purge_interval_secs == 0 {
+            return Err("Purge interval cannot be 0 seconds".to_string());
+        }
+
+        if self.retention_interval_secs == 0 {
+            return Err("Retention interval cannot be 0 seconds".to_string());
+        }
+
+        if self.notification_interval_secs == 0 {
+            return Err("Notification interval cannot be 0 seconds".to_string());
+        }
+
+        if self.analytics_min_group_size < 2 {
+            return Err("Analytics minimum group size must be at least 2".to_string());
+        }
+
+        if self.analytics_cache_ttl_secs == 0 {
+            return Err("Analytics cache TTL cannot be 0 seconds".to_string());
+        }
+
+        if let Some((class, _)) = self.retention.ttl_days.iter().find(|(_, days)| **days == 0) {
+            return Err(format!(
+                "RETENTION_{}_DAYS cannot be 0; unset it to keep the data indefinitely",
+                class.as_str().to_uppercase()
+            ));
+        }
+
+        if self.db_connect_attempts == 0 {
+            return Err("Database connection attempts cannot be 0".to_string());
+        }
+
+        if self.l1_cache_shares.values().sum::<f64>() >= 1.0 {
+            return Err("L1_CACHE_SHARES must leave part of the L1 cache to other engines".to_string());
+        }
+
+        if ApiVersion::ALL.iter().all(|v| self.disabled_api_versions.contains(v)) {
+            return Err("API_DISABLED_VERSIONS cannot disable every API version".to_string());
+        }
+        
+        if self.metrics_port == Some(self.port) {
+            return Err("METRICS_PORT must differ from PORT".to_string());
+        }
+
+        let is_production = env::var("RUST_ENV").map(|e| e == "production").unwrap_or(false);
+        if is_production && self.open_debug {
+            return Err("OPEN_DEBUG must not be set in production".to_string());
+        }
+        if is_production && self.metrics_access.is_open() && self.metrics_port.is_none() {
+            tracing::warn!(
+                "/metrics is public; set METRICS_PORT, METRICS_BEARER_TOKEN or METRICS_ALLOWED_IPS to restrict it"
+            );
+        }
+
+        let policies = &self.redaction;
+        let hashes = [policies.logging, policies.sharing, policies.export].contains(&RedactionMode::Hash);
+        if hashes && policies.hash_salt.is_empty() {
+            tracing::warn!("REDACT_HASH_SALT is not set; hashed birth dates can be reversed by trying every date");
+        }
+        
         // Validate log format
         if self.log_format != "pretty" && self.log_format != "json" {
             tracing::warn!(
@@ -215,6 +645,11 @@ impl ApiConfig {
     pub fn bind_address(&self) -> String {
         format!("{}:{}", self.host, self.port)
     }
+
+    /// Bind address of the internal metrics listener, when `METRICS_PORT` is set
+    pub fn metrics_bind_address(&self) -> Option<String> {
+        self.metrics_port.map(|port| format!("{}:{}", self.host, port))
+    }
 }
 
 #[cfg(test)]
@@ -235,6 +670,30 @@ mod tests {
             request_timeout_secs: 30,
             log_level: "info".to_string(),
             log_format: "pretty".to_string(),
+            critical_engines: vec![],
+            share_link_ttl_secs: 604_800,
+            public_base_url: None,
+            max_body_bytes: 1_048_576,
+            strict_json: false,
+            name_normalization: NameNormalization::default(),
+            open_debug: false,
+            purge_interval_secs: 3600,
+            retention: RetentionPolicies::default(),
+            retention_interval_secs: 86_400,
+            notification_interval_secs: 3600,
+            analytics_min_group_size: 10,
+            analytics_cache_ttl_secs: 3600,
+            db_auto_migrate: true,
+            db_connect_attempts: 5,
+            database_replica_url: None,
+            db_statement_timeout_ms: 5000,
+            load_shed_max_in_flight: 512,
+            load_shed_max_lag_ms: 100,
+            disabled_api_versions: vec![],
+            l1_cache_shares: HashMap::new(),
+            redaction: RedactionPolicies::default(),
+            metrics_access: MetricsAccess::default(),
+            metrics_port: None,
         };
         
         assert_eq!(config.bind_address(), "127.0.0.1:3000");
@@ -254,6 +713,30 @@ mod tests {
             request_timeout_secs: 30,
             log_level: "info".to_string(),
             log_format: "pretty".to_string(),
+            critical_engines: vec![],
+            share_link_ttl_secs: 604_800,
+            public_base_url: None,
+            max_body_bytes: 1_048_576,
+            strict_json: false,
+            name_normalization: NameNormalization::default(),
+            open_debug: false,
+            purge_interval_secs: 3600,
+            retention: RetentionPolicies::default(),
+            retention_interval_secs: 86_400,
+            notification_interval_secs: 3600,
+            analytics_min_group_size: 10,
+            analytics_cache_ttl_secs: 3600,
+            db_auto_migrate: true,
+            db_connect_attempts: 5,
+            database_replica_url: None,
+            db_statement_timeout_ms: 5000,
+            load_shed_max_in_flight: 512,
+            load_shed_max_lag_ms: 100,
+            disabled_api_versions: vec![],
+            l1_cache_shares: HashMap::new(),
+            redaction: RedactionPolicies::default(),
+            metrics_access: MetricsAccess::default(),
+            metrics_port: None,
         };
         
         assert!(config.validate().is_err());
@@ -273,9 +756,40 @@ mod tests {
             request_timeout_secs: 30,
             log_level: "info".to_string(),
             log_format: "pretty".to_string(),
+            critical_engines: vec![],
+            share_link_ttl_secs: 604_800,
+            public_base_url: None,
+            max_body_bytes: 1_048_576,
+            strict_json: false,
+            name_normalization: NameNormalization::default(),
+            open_debug: false,
+            purge_interval_secs: 3600,
+            retention: RetentionPolicies::default(),
+            retention_interval_secs: 86_400,
+            notification_interval_secs: 3600,
+            analytics_min_group_size: 10,
+            analytics_cache_ttl_secs: 3600,
+            db_auto_migrate: true,
+            db_connect_attempts: 5,
+            database_replica_url: None,
+            db_statement_timeout_ms: 5000,
+            load_shed_max_in_flight: 512,
+            load_shed_max_lag_ms: 100,
+            disabled_api_versions: vec![],
+            l1_cache_shares: HashMap::new(),
+            redaction: RedactionPolicies::default(),
+            metrics_access: MetricsAccess::default(),
+            metrics_port: None,
         };
 
         assert!(config.validate().is_err());
+
+        let replica = ApiConfig {
+            database_url: "postgres://localhost/test".to_string(),
+            database_replica_url: Some("mysql://replica/test".to_string()),
+            ..config
+        };
+        assert!(replica.validate().is_err());
     }
 
     #[test]
@@ -293,9 +807,66 @@ mod tests {
                 request_timeout_secs: 30,
                 log_level: "info".to_string(),
                 log_format: "pretty".to_string(),
+                critical_engines: vec![],
+                share_link_ttl_secs: 604_800,
+                public_base_url: None,
+                max_body_bytes: 1_048_576,
+                strict_json: false,
+                name_normalization: NameNormalization::default(),
+                open_debug: false,
+                purge_interval_secs: 3600,
+                retention: RetentionPolicies::default(),
+                retention_interval_secs: 86_400,
+                notification_interval_secs: 3600,
+                analytics_min_group_size: 10,
+                analytics_cache_ttl_secs: 3600,
+                db_auto_migrate: true,
+                db_connect_attempts: 5,
+                database_replica_url: None,
+                db_statement_timeout_ms: 5000,
+                load_shed_max_in_flight: 512,
+                load_shed_max_lag_ms: 100,
+                disabled_api_versions: vec![],
+                l1_cache_shares: HashMap::new(),
+                redaction: RedactionPolicies::default(),
+                metrics_access: MetricsAccess::default(),
+                metrics_port: None,
             };
 
             assert!(config.validate().is_ok(), "should accept DATABASE_URL: {}", url);
+
+            let v1_off = ApiConfig { disabled_api_versions: vec![ApiVersion::V1], ..config };
+            assert!(v1_off.validate().is_ok());
+            let greedy = ApiConfig {
+                l1_cache_shares: HashMap::from([("human-design".to_string(), 0.6), ("gene-keys".to_string(), 0.4)]),
+                redaction: RedactionPolicies::default(),
+                ..v1_off.clone()
+            };
+            assert!(greedy.validate().is_err());
+            let all_off = ApiConfig { disabled_api_versions: ApiVersion::ALL.to_vec(), ..v1_off.clone() };
+            assert!(all_off.validate().is_err());
+            let metrics_on_public_port = ApiConfig { metrics_port: Some(8080), ..v1_off.clone() };
+            assert!(metrics_on_public_port.validate().is_err());
+            let internal_metrics = ApiConfig { metrics_port: Some(9090), ..v1_off.clone() };
+            assert!(internal_metrics.validate().is_ok());
+            assert_eq!(internal_metrics.metrics_bind_address().as_deref(), Some("0.0.0.0:9090"));
+            let journal_kept_a_year = ApiConfig {
+                retention: RetentionPolicies {
+                    ttl_days: [(DataClass::Journal, 365)].into(),
+                    dry_run: true,
+                },
+                ..v1_off.clone()
+            };
+            assert!(journal_kept_a_year.validate().is_ok());
+            let audit_log_kept_no_time = ApiConfig {
+                retention: RetentionPolicies {
+                    ttl_days: [(DataClass::AuditLog, 0)].into(),
+                    dry_run: false,
+                },
+                ..v1_off
+            };
+            let error = audit_log_kept_no_time.validate().unwrap_err();
+            assert!(error.contains("RETENTION_AUDIT_LOG_DAYS"), "{}", error);
         }
     }
 
@@ -313,6 +884,30 @@ mod tests {
             request_timeout_secs: 0, // Invalid!
             log_level: "info".to_string(),
             log_format: "pretty".to_string(),
+            critical_engines: vec![],
+            share_link_ttl_secs: 604_800,
+            public_base_url: None,
+            max_body_bytes: 1_048_576,
+            strict_json: false,
+            name_normalization: NameNormalization::default(),
+            open_debug: false,
+            purge_interval_secs: 3600,
+            retention: RetentionPolicies::default(),
+            retention_interval_secs: 86_400,
+            notification_interval_secs: 3600,
+            analytics_min_group_size: 10,
+            analytics_cache_ttl_secs: 3600,
+            db_auto_migrate: true,
+            db_connect_attempts: 5,
+            database_replica_url: None,
+            db_statement_timeout_ms: 5000,
+            load_shed_max_in_flight: 512,
+            load_shed_max_lag_ms: 100,
+            disabled_api_versions: vec![],
+            l1_cache_shares: HashMap::new(),
+            redaction: RedactionPolicies::default(),
+            metrics_access: MetricsAccess::default(),
+            metrics_port: None,
         };
         
         assert!(config.validate().is_err());
@@ -0,0 +1,197 @@
+//! Database startup and degraded mode
+//!
+//! At boot [`connect`] retries the Postgres connection with backoff and, when
+//! `DB_AUTO_MIGRATE` is on, applies pending migrations. If the database stays
+//! unreachable (or migrating fails) the server still starts, in degraded mode:
+//! engine calculation and workflow execution keep working with JWT auth, while
+//! routes behind [`require_database`] answer 503 `DATABASE_UNAVAILABLE`. A
+//! background task keeps reconnecting and leaves degraded mode once the
+//! database is back and migrated.
+//!
+//! With `DATABASE_REPLICA_URL` set, a second pool on the replica serves the
+//! reads repositories mark as lag-tolerant. Every pooled session runs with
+//! `DB_STATEMENT_TIMEOUT_MS` as its `statement_timeout`, so one slow query
+//! cannot hold a connection indefinitely.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+use axum::{
+    extract::{Request, State},
+    http::{header::RETRY_AFTER, HeaderValue, StatusCode},
+    middleware::Next,
+    response::{IntoResponse, Response},
+    Json,
+};
+use noesis_data::{DbPool, DbPools};
+use noesis_metrics::NoesisMetrics;
+use sqlx::postgres::{PgConnectOptions, PgPoolOptions};
+
+use crate::{ApiConfig, ErrorResponse};
+
+/// Wait before the second connection attempt; doubles per attempt
+const INITIAL_RETRY_DELAY: Duration = Duration::from_millis(500);
+
+/// How often a degraded server tries to reach the database again
+const RECONNECT_INTERVAL: Duration = Duration::from_secs(30);
+
+/// Whether Postgres-backed routes can be served
+#[derive(Debug)]
+pub struct DatabaseStatus {
+    available: AtomicBool,
+}
+
+impl DatabaseStatus {
+    pub fn new(available: bool) -> Self {
+        Self {
+            available: AtomicBool::new(available),
+        }
+    }
+
+    pub fn is_available(&self) -> bool {
+        self.available.load(Ordering::Relaxed)
+    }
+
+    fn set_available(&self) {
+        self.available.store(true, Ordering::Relaxed);
+    }
+
+    /// 503 `DATABASE_UNAVAILABLE` while degraded
+    pub fn ensure_available(&self) -> Result<(), (StatusCode, Json<ErrorResponse>)> {
+        if self.is_available() {
+            Ok(())
+        } else {
+            Err(unavailable())
+        }
+    }
+}
+
+fn unavailable() -> (StatusCode, Json<ErrorResponse>) {
+    (
+        StatusCode::SERVICE_UNAVAILABLE,
+        Json(ErrorResponse {
+            error: "The database is unavailable; only engine calculations and workflows are served"
+                .to_string(),
+            error_code: "DATABASE_UNAVAILABLE".to_string(),
+            details: Some(serde_json::json!({ "retry_after": RECONNECT_INTERVAL.as_secs() })),
+            request_id: None,
+        }),
+    )
+}
+
+fn pool_options() -> PgPoolOptions {
+    // Fail fast rather than holding requests for sqlx's 30 second default
+    PgPoolOptions::new()
+        .max_connections(5)
+        .acquire_timeout(Duration::from_secs(3))
+}
+
+fn connect_options(url: &str, config: &ApiConfig) -> PgConnectOptions {
+    let timeout = (config.db_statement_timeout_ms > 0)
+        .then(|| Duration::from_millis(config.db_statement_timeout_ms));
+    noesis_data::connect_options(url, timeout).expect("Invalid database URL")
+}
+
+/// Connect to Postgres, migrate, and fall back to degraded mode on failure.
+///
+/// The pools are always returned: when the database is unreachable the
+/// primary is a lazy pool that connects once the database comes back. The
+/// replica pool is always lazy and never degrades the server by itself.
+pub async fn connect(config: &ApiConfig) -> (DbPools, Arc<DatabaseStatus>) {
+    let (primary, status) = connect_primary(config).await;
+    let mut pools = DbPools::new(primary);
+    if let Some(url) = &config.database_replica_url {
+        tracing::info!("Routing lag-tolerant reads to the read replica");
+        pools = pools.with_replica(pool_options().connect_lazy_with(connect_options(url, config)));
+    }
+    (pools, status)
+}
+
+async fn connect_primary(config: &ApiConfig) -> (DbPool, Arc<DatabaseStatus>) {
+    let options = connect_options(&config.database_url, config);
+    let connected = noesis_data::connect_with_retry(
+        pool_options(),
+        options.clone(),
+        config.db_connect_attempts,
+        INITIAL_RETRY_DELAY,
+    )
+    .await;
+
+    let pool = match connected {
+        Ok(pool) => match migrate(&pool, config.db_auto_migrate).await {
+            Ok(()) => return (pool, Arc::new(DatabaseStatus::new(true))),
+            Err(e) => {
+                tracing::error!(error = %e, "Database migrations failed; starting in degraded mode");
+                pool
+            }
+        },
+        Err(e) => {
+            tracing::error!(
+                attempts = config.db_connect_attempts,
+                error = %e,
+                "Database unreachable; starting in degraded mode"
+            );
+            pool_options().connect_lazy_with(options)
+        }
+    };
+
+    let status = Arc::new(DatabaseStatus::new(false));
+    spawn_reconnect(pool.clone(), status.clone(), config.db_auto_migrate);
+    (pool, status)
+}
+
+async fn migrate(pool: &DbPool, auto_migrate: bool) -> Result<(), sqlx::migrate::MigrateError> {
+    if !auto_migrate {
+        return Ok(());
+    }
+    noesis_data::run_migrations(pool).await?;
+    tracing::info!("Database migrations applied");
+    Ok(())
+}
+
+/// Export connection counts of both pools to the `noesis_db_pool_*` gauges
+pub fn record_pool_metrics(metrics: &NoesisMetrics, pools: &DbPools) {
+    let pools = std::iter::once(("primary", &pools.primary))
+        .chain(pools.replica.as_ref().map(|replica| ("replica", replica)));
+    for (name, pool) in pools {
+        metrics.update_db_pool(name, pool.size(), pool.num_idle(), pool.options().get_max_connections());
+    }
+}
+
+/// Retry the database every [`RECONNECT_INTERVAL`] until it can be used
+fn spawn_reconnect(pool: DbPool, status: Arc<DatabaseStatus>, auto_migrate: bool) {
+    tokio::spawn(async move {
+        loop {
+            tokio::time::sleep(RECONNECT_INTERVAL).await;
+            if let Err(e) = sqlx::query("SELECT 1").execute(&pool).await {
+                tracing::warn!(error = %e, "Database still unavailable");
+                continue;
+            }
+            match migrate(&pool, auto_migrate).await {
+                Ok(()) => {
+                    status.set_available();
+                    tracing::info!("Database available; leaving degraded mode");
+                    return;
+                }
+                Err(e) => tracing::error!(error = %e, "Database migrations failed"),
+            }
+        }
+    });
+}
+
+/// Route layer answering 503 `DATABASE_UNAVAILABLE` while the server is degraded
+pub async fn require_database(
+    State(status): State<Arc<DatabaseStatus>>,
+    req: Request,
+    next: Next,
+) -> Response {
+    if status.is_available() {
+        return next.run(req).await;
+    }
+    let mut response = unavailable().into_response();
+    response
+        .headers_mut()
+        .insert(RETRY_AFTER, HeaderValue::from(RECONNECT_INTERVAL.as_secs()));
+    response
+}
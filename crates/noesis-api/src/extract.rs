@@ -0,0 +1,212 @@
+//! Request body extractors
+//!
+//! [`InputJson`] replaces `Json<EngineInput>` on the calculate and workflow
+//! routes. Malformed JSON keeps axum's 400 rejection, while a body that parses
+//! but does not fit `EngineInput` becomes a 422 `VALIDATION_ERROR` naming the
+//! offending field (e.g. `birth_data.latitude`). With `STRICT_JSON` on, unknown
+//! top-level fields are rejected the same way instead of being ignored.
+//! Until [`CAMEL_CASE_SUNSET`](noesis_core::casing::CAMEL_CASE_SUNSET),
+//! camelCase keys (`birthData`, `options.forecastDays`) are read as their
+//! snake_case spelling.
+//!
+//! [`WorkflowBody`] does the same for workflow execution, which also takes a
+//! [`PresetRequest`] in place of the input.
+//!
+//! Every name in a parsed input is normalized for calculation with the
+//! policy's [`NameNormalization`], keeping the name as given in
+//! `display_name` (see [`noesis_core::names`]).
+
+use axum::{
+    async_trait,
+    extract::{FromRequest, Json, Request},
+    response::{IntoResponse, Response},
+};
+use chrono::Utc;
+use noesis_core::casing::{accepts_camel_case, snake_case_keys};
+use noesis_core::{EngineError, EngineInput, NameNormalization, ValidationCode, ValidationError};
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+
+use crate::engine_error_to_response;
+use crate::middleware::JsonBodyPolicy;
+use crate::presets::PresetRequest;
+
+/// `EngineInput` body that reports field-level errors
+#[derive(Debug)]
+pub struct InputJson(pub EngineInput);
+
+#[async_trait]
+impl<S> FromRequest<S> for InputJson
+where
+    S: Send + Sync,
+{
+    type Rejection = Response;
+
+    async fn from_request(req: Request, state: &S) -> Result<Self, Self::Rejection> {
+        let policy = req.extensions().get::<JsonBodyPolicy>().copied();
+        let strict = policy.is_some_and(|policy| policy.strict);
+        let names = policy.map(|policy| policy.names).unwrap_or_default();
+
+        let Json(value) = Json::<Value>::from_request(req, state)
+            .await
+            .map_err(IntoResponse::into_response)?;
+
+        let mut input = parse_input(value, strict).map_err(|err| engine_error_to_response(err).into_response())?;
+        normalize_names(&mut input, &names);
+        Ok(InputJson(input))
+    }
+}
+
+/// Workflow body: a full input, or a preset naming a saved profile
+#[derive(Debug)]
+pub enum WorkflowBody {
+    Input(Box<EngineInput>),
+    Preset(PresetRequest),
+}
+
+#[async_trait]
+impl<S> FromRequest<S> for WorkflowBody
+where
+    S: Send + Sync,
+{
+    type Rejection = Response;
+
+    async fn from_request(req: Request, state: &S) -> Result<Self, Self::Rejection> {
+        let policy = req.extensions().get::<JsonBodyPolicy>().copied();
+        let strict = policy.is_some_and(|policy| policy.strict);
+        let names = policy.map(|policy| policy.names).unwrap_or_default();
+
+        let Json(value) = Json::<Value>::from_request(req, state)
+            .await
+            .map_err(IntoResponse::into_response)?;
+
+        let mut body = parse_workflow_body(value, strict).map_err(|err| engine_error_to_response(err).into_response())?;
+        if let WorkflowBody::Input(input) = &mut body {
+            normalize_names(input, &names);
+        }
+        Ok(body)
+    }
+}
+
+/// Normalize the name of every birth data in `input`
+pub fn normalize_names(input: &mut EngineInput, rules: &NameNormalization) {
+    for birth in input.birth_data_mut() {
+        birth.normalize_name(rules);
+    }
+}
+
+/// A body with a `preset` field is a [`PresetRequest`], any other an input
+pub fn parse_workflow_body(mut value: Value, strict: bool) -> Result<WorkflowBody, EngineError> {
+    if !value.as_object().is_some_and(|object| object.contains_key("preset")) {
+        return parse_input(value, strict).map(|input| WorkflowBody::Input(Box::new(input)));
+    }
+    normalize_keys(&mut value);
+    deserialize(value).map(WorkflowBody::Preset)
+}
+
+/// Deserialize an input, rejecting unknown top-level fields when `strict`
+pub fn parse_input(mut value: Value, strict: bool) -> Result<EngineInput, EngineError> {
+    normalize_keys(&mut value);
+
+    if strict {
+        if let Some(object) = value.as_object() {
+            let unknown: Vec<ValidationError> = object
+                .keys()
+                .filter(|key| !EngineInput::FIELDS.iter().chain(EngineInput::FIELD_ALIASES).any(|field| field == key))
+                .map(|key| {
+                    ValidationError::new(
+                        key.clone(),
+                        ValidationCode::Unknown,
+                        format!("Unknown field '{}'", key),
+                    )
+                })
+                .collect();
+            if !unknown.is_empty() {
+                return Err(EngineError::InvalidInput(unknown));
+            }
+        }
+    }
+
+    deserialize(value)
+}
+
+fn normalize_keys(value: &mut Value) {
+    if accepts_camel_case(Utc::now()) && snake_case_keys(value) > 0 {
+        noesis_metrics::record_camel_case_input();
+    }
+}
+
+/// Deserialize `value`, naming the offending field on error
+fn deserialize<T: DeserializeOwned>(value: Value) -> Result<T, EngineError> {
+    serde_path_to_error::deserialize(value).map_err(|err| {
+        let path = err.path().to_string();
+        let field = if path == "." { "body".to_string() } else { path };
+        EngineError::invalid_field(field, ValidationCode::InvalidFormat, err.into_inner().to_string())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_strict_mode_rejects_unknown_fields() {
+        let body = json!({ "birth_data": null, "brith_data": {}, "extra": 1 });
+        assert!(parse_input(body.clone(), false).is_ok());
+
+        let Err(EngineError::InvalidInput(errors)) = parse_input(body, true) else {
+            panic!("strict mode should reject unknown fields");
+        };
+        let fields: Vec<&str> = errors.iter().map(|e| e.field.as_str()).collect();
+        assert_eq!(fields, ["brith_data", "extra"]);
+        assert!(errors.iter().all(|e| e.code == ValidationCode::Unknown));
+
+        let aliased = json!({ "current_location": { "latitude": 38.72, "longitude": -9.14, "altitude": null } });
+        assert!(parse_input(aliased, true).unwrap().location.is_some());
+    }
+
+    #[test]
+    fn test_camel_case_keys_are_accepted() {
+        let camel = json!({
+            "birthData": { "date": "1990-01-15", "latitude": 12.97, "longitude": 77.59, "timezone": "Asia/Kolkata" },
+            "currentTime": "2025-01-01T00:00:00Z",
+            "options": { "forecastDays": 7 }
+        });
+        let input = parse_input(camel, true).unwrap();
+        assert_eq!(input.birth_data.unwrap().date, "1990-01-15");
+        assert_eq!(input.options["forecast_days"], 7);
+    }
+
+    #[test]
+    fn test_type_errors_name_the_field() {
+        let body = json!({ "birth_data": { "date": "1990-01-15", "latitude": "north" } });
+        let Err(EngineError::InvalidInput(errors)) = parse_input(body, false) else {
+            panic!("a string latitude should not deserialize");
+        };
+        assert_eq!(errors[0].field, "birth_data.latitude");
+        assert_eq!(errors[0].code, ValidationCode::InvalidFormat);
+
+        let Err(EngineError::InvalidInput(errors)) = parse_input(json!([]), false) else {
+            panic!("an array is not an input");
+        };
+        assert_eq!(errors[0].field, "body");
+    }
+
+    #[test]
+    fn test_preset_bodies_are_told_apart() {
+        let preset = json!({ "profileId": "me", "preset": "today" });
+        let Ok(WorkflowBody::Preset(request)) = parse_workflow_body(preset, false) else {
+            panic!("a body with a preset is a preset request");
+        };
+        assert_eq!(request.profile_id, "me");
+
+        let input = json!({ "birth_data": null });
+        assert!(matches!(parse_workflow_body(input, true), Ok(WorkflowBody::Input(_))));
+
+        let Err(EngineError::InvalidInput(errors)) = parse_workflow_body(json!({ "preset": "today" }), false) else {
+            panic!("a preset needs a profile");
+        };
+        assert_eq!(errors[0].code, ValidationCode::InvalidFormat);
+    }
+}
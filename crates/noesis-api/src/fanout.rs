@@ -0,0 +1,374 @@
+//! Fanout of stream events to many connections
+//!
+//! A streaming endpoint subscribes each connection to a topic, such as the
+//! ghati ticks for one location, instead of running its own calculation
+//! loop. The first subscriber starts the topic's producer; every event it
+//! publishes is serialized once and queued to each subscriber, and the
+//! producer is stopped when the last one leaves. A connection joining a
+//! running topic is sent the latest event straight away.
+//!
+//! Each subscriber has a buffer of [`SUBSCRIBER_BUFFER`] events. A client
+//! that falls that far behind is disconnected rather than buffered without
+//! bound or allowed to hold up the others; SSE clients reconnect on their
+//! own. Subscriptions are plain streams, so SSE and WebSocket handlers can
+//! share a topic. An authenticated user may hold at most
+//! [`MAX_SUBSCRIPTIONS_PER_USER`] at once, across all topics.
+
+use std::collections::HashMap;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll};
+
+use axum::response::sse::Event;
+use futures::Stream;
+use serde::Serialize;
+use tokio::sync::mpsc::{self, error::TrySendError};
+use tokio::task::AbortHandle;
+
+/// Events queued per connection before it is dropped as too slow
+pub const SUBSCRIBER_BUFFER: usize = 16;
+
+/// Streams one user may hold open at once, e.g. one per dashboard
+pub const MAX_SUBSCRIPTIONS_PER_USER: usize = 8;
+
+/// An event as sent to every subscriber of a topic
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StreamEvent {
+    pub name: String,
+    pub data: String,
+}
+
+impl StreamEvent {
+    pub fn new(name: impl Into<String>, data: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            data: data.into(),
+        }
+    }
+
+    /// Event whose data is `value` as JSON
+    pub fn json(name: impl Into<String>, value: &impl Serialize) -> Self {
+        Self::new(name, serde_json::to_string(value).unwrap_or_default())
+    }
+
+    pub fn to_sse(&self) -> Event {
+        Event::default().event(&self.name).data(&self.data)
+    }
+}
+
+/// Why a subscription was refused
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FanoutError {
+    /// The user already holds `limit` subscriptions
+    TooManySubscriptions { limit: usize },
+}
+
+impl std::fmt::Display for FanoutError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FanoutError::TooManySubscriptions { limit } => {
+                write!(f, "At most {} streams can be open at once", limit)
+            }
+        }
+    }
+}
+
+struct Subscriber {
+    id: u64,
+    user: Option<String>,
+    sender: mpsc::Sender<StreamEvent>,
+}
+
+struct Topic {
+    subscribers: Vec<Subscriber>,
+    /// Latest event, sent to connections joining the running topic
+    last: Option<StreamEvent>,
+    producer: Option<AbortHandle>,
+}
+
+struct HubInner {
+    topics: Mutex<HashMap<String, Topic>>,
+    buffer: usize,
+    max_per_user: usize,
+    next_id: AtomicU64,
+}
+
+/// Metric label of a topic: the part of its name before the first `:`
+fn kind(topic: &str) -> &str {
+    topic.split(':').next().unwrap_or(topic)
+}
+
+impl HubInner {
+    /// Remove a subscriber, and the topic with its producer once it was the last
+    fn unsubscribe(&self, topic: &str, id: u64) {
+        let mut topics = self.topics.lock().unwrap();
+        let Some(entry) = topics.get_mut(topic) else {
+            return;
+        };
+        let before = entry.subscribers.len();
+        entry.subscribers.retain(|s| s.id != id);
+        if entry.subscribers.len() < before {
+            noesis_metrics::record_fanout_subscribers(kind(topic), -1);
+        }
+        if entry.subscribers.is_empty() {
+            Self::remove_topic(&mut topics, topic);
+        }
+    }
+
+    fn remove_topic(topics: &mut HashMap<String, Topic>, topic: &str) {
+        if let Some(removed) = topics.remove(topic) {
+            if let Some(producer) = removed.producer {
+                producer.abort();
+            }
+            noesis_metrics::record_fanout_topics(kind(topic), -1);
+        }
+    }
+}
+
+/// Topics, their producers and subscribers
+#[derive(Clone)]
+pub struct FanoutHub {
+    inner: Arc<HubInner>,
+}
+
+impl Default for FanoutHub {
+    fn default() -> Self {
+        Self::with_limits(SUBSCRIBER_BUFFER, MAX_SUBSCRIPTIONS_PER_USER)
+    }
+}
+
+impl FanoutHub {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Hub with `buffer` events per connection and `max_per_user` subscriptions per user
+    pub fn with_limits(buffer: usize, max_per_user: usize) -> Self {
+        Self {
+            inner: Arc::new(HubInner {
+                topics: Mutex::new(HashMap::new()),
+                buffer: buffer.max(1),
+                max_per_user,
+                next_id: AtomicU64::new(0),
+            }),
+        }
+    }
+
+    /// Subscribe to `topic`, spawning `producer` when it is not running yet.
+    ///
+    /// `user` counts the subscription against that user's limit; anonymous
+    /// connections are not limited here.
+    pub fn subscribe<F, Fut>(&self, topic: &str, user: Option<&str>, producer: F) -> Result<Subscription, FanoutError>
+    where
+        F: FnOnce(Publisher) -> Fut,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        let mut topics = self.inner.topics.lock().unwrap();
+        if let Some(user) = user {
+            let held = topics
+                .values()
+                .flat_map(|t| &t.subscribers)
+                .filter(|s| s.user.as_deref() == Some(user))
+                .count();
+            if held >= self.inner.max_per_user {
+                noesis_metrics::record_fanout_disconnect(kind(topic), "user_limit");
+                return Err(FanoutError::TooManySubscriptions {
+                    limit: self.inner.max_per_user,
+                });
+            }
+        }
+
+        let (sender, receiver) = mpsc::channel(self.inner.buffer);
+        let id = self.inner.next_id.fetch_add(1, Ordering::Relaxed);
+        let entry = topics.entry(topic.to_string()).or_insert_with(|| Topic {
+            subscribers: Vec::new(),
+            last: None,
+            producer: None,
+        });
+        if let Some(last) = &entry.last {
+            let _ = sender.try_send(last.clone());
+        }
+        entry.subscribers.push(Subscriber {
+            id,
+            user: user.map(str::to_string),
+            sender,
+        });
+        noesis_metrics::record_fanout_subscribers(kind(topic), 1);
+
+        if entry.producer.is_none() {
+            let publisher = Publisher {
+                hub: self.inner.clone(),
+                topic: topic.to_string(),
+            };
+            entry.producer = Some(tokio::spawn(producer(publisher)).abort_handle());
+            noesis_metrics::record_fanout_topics(kind(topic), 1);
+        }
+
+        Ok(Subscription {
+            receiver,
+            hub: self.inner.clone(),
+            topic: topic.to_string(),
+            id,
+        })
+    }
+
+    /// Connections subscribed to `topic`
+    pub fn subscriber_count(&self, topic: &str) -> usize {
+        self.inner
+            .topics
+            .lock()
+            .unwrap()
+            .get(topic)
+            .map_or(0, |t| t.subscribers.len())
+    }
+
+    /// Topics with a running producer
+    pub fn topic_count(&self) -> usize {
+        self.inner.topics.lock().unwrap().len()
+    }
+}
+
+/// Handed to a topic's producer to publish its events
+pub struct Publisher {
+    hub: Arc<HubInner>,
+    topic: String,
+}
+
+impl Publisher {
+    /// Queue `event` to every subscriber, dropping those whose buffer is
+    /// full. Returns false once the topic has no subscribers left, when the
+    /// producer should return.
+    pub fn publish(&self, event: StreamEvent) -> bool {
+        let mut topics = self.hub.topics.lock().unwrap();
+        let Some(entry) = topics.get_mut(&self.topic) else {
+            return false;
+        };
+
+        let kind = kind(&self.topic);
+        let mut delivered = 0;
+        entry.subscribers.retain(|subscriber| match subscriber.sender.try_send(event.clone()) {
+            Ok(()) => {
+                delivered += 1;
+                true
+            }
+            Err(TrySendError::Full(_)) => {
+                tracing::debug!(topic = %self.topic, "Dropping stream subscriber that fell behind");
+                noesis_metrics::record_fanout_disconnect(kind, "slow");
+                noesis_metrics::record_fanout_subscribers(kind, -1);
+                false
+            }
+            Err(TrySendError::Closed(_)) => {
+                noesis_metrics::record_fanout_subscribers(kind, -1);
+                false
+            }
+        });
+        noesis_metrics::record_fanout_events(kind, delivered);
+        entry.last = Some(event);
+
+        if entry.subscribers.is_empty() {
+            HubInner::remove_topic(&mut topics, &self.topic);
+            return false;
+        }
+        true
+    }
+}
+
+/// One connection's events; leaves the topic when dropped
+pub struct Subscription {
+    receiver: mpsc::Receiver<StreamEvent>,
+    hub: Arc<HubInner>,
+    topic: String,
+    id: u64,
+}
+
+impl Stream for Subscription {
+    type Item = StreamEvent;
+
+    /// Ends when the subscriber was dropped for falling behind
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<StreamEvent>> {
+        self.receiver.poll_recv(cx)
+    }
+}
+
+impl Drop for Subscription {
+    fn drop(&mut self) {
+        self.hub.unsubscribe(&self.topic, self.id);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::StreamExt;
+    use std::sync::atomic::AtomicUsize;
+
+    /// Producer publishing `tick 0`, `tick 1`, ... until told to stop,
+    /// counting how often it was started
+    fn ticker(starts: Arc<AtomicUsize>) -> impl FnOnce(Publisher) -> Pin<Box<dyn Future<Output = ()> + Send>> {
+        move |publisher| {
+            starts.fetch_add(1, Ordering::SeqCst);
+            Box::pin(async move {
+                for n in 0.. {
+                    if !publisher.publish(StreamEvent::new("tick", n.to_string())) {
+                        return;
+                    }
+                    tokio::time::sleep(std::time::Duration::from_millis(5)).await;
+                }
+            })
+        }
+    }
+
+    #[tokio::test]
+    async fn test_subscribers_share_one_producer() {
+        let hub = FanoutHub::new();
+        let starts = Arc::new(AtomicUsize::new(0));
+
+        let mut first = hub.subscribe("tick:a", Some("ada"), ticker(starts.clone())).unwrap();
+        assert_eq!(first.next().await.unwrap().data, "0");
+        let mut second = hub.subscribe("tick:a", Some("ada"), ticker(starts.clone())).unwrap();
+
+        // The latest event comes first, then both see the same ticks
+        assert_eq!(second.next().await.unwrap().data, "0");
+        assert_eq!(first.next().await.unwrap(), second.next().await.unwrap());
+        assert_eq!(starts.load(Ordering::SeqCst), 1);
+        assert_eq!(hub.subscriber_count("tick:a"), 2);
+
+        drop(first);
+        drop(second);
+        assert_eq!(hub.topic_count(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_slow_subscriber_is_dropped() {
+        let hub = FanoutHub::with_limits(2, MAX_SUBSCRIPTIONS_PER_USER);
+        let starts = Arc::new(AtomicUsize::new(0));
+        let mut reader = hub.subscribe("tick:b", None, ticker(starts.clone())).unwrap();
+        let mut idle = hub.subscribe("tick:b", None, ticker(starts)).unwrap();
+
+        for _ in 0..5 {
+            reader.next().await.unwrap();
+        }
+        assert_eq!(hub.subscriber_count("tick:b"), 1);
+        // The idle one gets what was buffered before it was dropped, then its stream ends
+        assert!(idle.next().await.is_some());
+        assert!(idle.next().await.is_some());
+        assert!(idle.next().await.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_subscriptions_are_limited_per_user() {
+        let hub = FanoutHub::with_limits(SUBSCRIBER_BUFFER, 2);
+        let starts = Arc::new(AtomicUsize::new(0));
+        let _a = hub.subscribe("tick:a", Some("ada"), ticker(starts.clone())).unwrap();
+        let b = hub.subscribe("tick:b", Some("ada"), ticker(starts.clone())).unwrap();
+
+        let refused = hub.subscribe("tick:c", Some("ada"), ticker(starts.clone()));
+        assert_eq!(refused.err(), Some(FanoutError::TooManySubscriptions { limit: 2 }));
+        assert!(hub.subscribe("tick:c", Some("grace"), ticker(starts.clone())).is_ok());
+
+        drop(b);
+        assert!(hub.subscribe("tick:c", Some("ada"), ticker(starts)).is_ok());
+    }
+}
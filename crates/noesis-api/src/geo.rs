@@ -0,0 +1,468 @@
+//! Place-name geocoding and coordinate → timezone resolution
+//!
+//! Users usually know where they were born, not the coordinates. This module
+//! resolves place names against an embedded offline gazetteer
+//! (`data/geo/cities.json`) and maps coordinates to IANA timezones using the
+//! bundled timezone boundary polygons from `tzf-rs`.
+//!
+//! Providers implement [`GeocodingProvider`], so an external geocoding service
+//! can be plugged into [`AppState`](crate::AppState) in place of the embedded one.
+
+use async_trait::async_trait;
+use noesis_core::options::RELOCATE_TO_OPTION;
+use noesis_core::{BirthData, EngineError, EngineInput, ValidationCode};
+use serde::{Deserialize, Serialize};
+use std::sync::OnceLock;
+use tzf_rs::DefaultFinder;
+use utoipa::ToSchema;
+
+/// Maximum number of results a search may return
+pub const MAX_SEARCH_LIMIT: usize = 50;
+
+/// A resolved place
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct GeoLocation {
+    /// Primary place name
+    #[schema(example = "Bengaluru")]
+    pub name: String,
+    /// ISO 3166-1 alpha-2 country code
+    #[schema(example = "IN")]
+    pub country_code: String,
+    /// First-level administrative region (state, province)
+    #[schema(example = "Karnataka")]
+    pub admin1: String,
+    /// Latitude in decimal degrees
+    #[schema(example = 12.97194)]
+    pub latitude: f64,
+    /// Longitude in decimal degrees
+    #[schema(example = 77.59369)]
+    pub longitude: f64,
+    /// IANA timezone identifier
+    #[schema(example = "Asia/Kolkata")]
+    pub timezone: String,
+    /// Population, used for ranking ambiguous names
+    pub population: u64,
+}
+
+/// Source of place-name lookups
+#[async_trait]
+pub trait GeocodingProvider: Send + Sync {
+    /// Short identifier reported in logs and responses
+    fn provider_name(&self) -> &str;
+
+    /// Search for places matching `query`, best match first
+    async fn search(&self, query: &str, limit: usize) -> Result<Vec<GeoLocation>, EngineError>;
+}
+
+// ---------------------------------------------------------------------------
+// Embedded gazetteer
+// ---------------------------------------------------------------------------
+
+#[derive(Deserialize)]
+struct CitiesData {
+    cities: Vec<CityEntry>,
+}
+
+#[derive(Deserialize)]
+struct CityEntry {
+    name: String,
+    #[serde(default)]
+    alternate_names: Vec<String>,
+    country_code: String,
+    admin1: String,
+    latitude: f64,
+    longitude: f64,
+    timezone: String,
+    population: u64,
+}
+
+struct IndexedCity {
+    location: GeoLocation,
+    /// Normalized primary and alternate names
+    keys: Vec<String>,
+}
+
+/// Offline geocoder backed by the embedded city dataset
+pub struct EmbeddedGeocoder {
+    cities: Vec<IndexedCity>,
+}
+
+impl EmbeddedGeocoder {
+    pub fn new() -> Self {
+        let json_str = include_str!("../../../data/geo/cities.json");
+        let data: CitiesData =
+            serde_json::from_str(json_str).expect("Failed to parse cities.json");
+
+        let cities = data
+            .cities
+            .into_iter()
+            .map(|c| {
+                let keys = std::iter::once(&c.name)
+                    .chain(c.alternate_names.iter())
+                    .map(|n| normalize(n))
+                    .collect();
+                IndexedCity {
+                    location: GeoLocation {
+                        name: c.name,
+                        country_code: c.country_code,
+                        admin1: c.admin1,
+                        latitude: c.latitude,
+                        longitude: c.longitude,
+                        timezone: c.timezone,
+                        population: c.population,
+                    },
+                    keys,
+                }
+            })
+            .collect();
+
+        Self { cities }
+    }
+
+    /// Synchronous search used by the async trait method
+    pub fn search_sync(&self, query: &str, limit: usize) -> Vec<GeoLocation> {
+        // "Bengaluru, IN" / "Portland, Oregon" narrow by country or region
+        let mut parts = query.splitn(2, ',');
+        let place = normalize(parts.next().unwrap_or(""));
+        let qualifier = parts.next().map(normalize).filter(|q| !q.is_empty());
+        if place.is_empty() {
+            return Vec::new();
+        }
+
+        let mut matches: Vec<(u8, &GeoLocation)> = self
+            .cities
+            .iter()
+            .filter(|c| match &qualifier {
+                Some(q) => {
+                    normalize(&c.location.country_code) == *q || normalize(&c.location.admin1) == *q
+                }
+                None => true,
+            })
+            .filter_map(|c| {
+                let rank = c
+                    .keys
+                    .iter()
+                    .filter_map(|k| match_rank(k, &place))
+                    .min()?;
+                Some((rank, &c.location))
+            })
+            .collect();
+
+        matches.sort_by(|(ra, a), (rb, b)| ra.cmp(rb).then(b.population.cmp(&a.population)));
+        matches
+            .into_iter()
+            .take(limit.min(MAX_SEARCH_LIMIT))
+            .map(|(_, loc)| loc.clone())
+            .collect()
+    }
+}
+
+impl Default for EmbeddedGeocoder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl GeocodingProvider for EmbeddedGeocoder {
+    fn provider_name(&self) -> &str {
+        "embedded"
+    }
+
+    async fn search(&self, query: &str, limit: usize) -> Result<Vec<GeoLocation>, EngineError> {
+        Ok(self.search_sync(query, limit))
+    }
+}
+
+/// 0 = exact name, 1 = name prefix, 2 = word prefix inside the name
+fn match_rank(key: &str, query: &str) -> Option<u8> {
+    if key == query {
+        Some(0)
+    } else if key.starts_with(query) {
+        Some(1)
+    } else if key.split(' ').any(|w| w.starts_with(query)) {
+        Some(2)
+    } else {
+        None
+    }
+}
+
+/// Lowercase, fold common Latin diacritics and collapse punctuation to spaces
+fn normalize(s: &str) -> String {
+    let folded: String = s
+        .chars()
+        .flat_map(|c| c.to_lowercase())
+        .map(|c| match c {
+            'á' | 'à' | 'â' | 'ä' | 'ã' | 'å' => 'a',
+            'é' | 'è' | 'ê' | 'ë' => 'e',
+            'í' | 'ì' | 'î' | 'ï' => 'i',
+            'ó' | 'ò' | 'ô' | 'ö' | 'õ' | 'ø' => 'o',
+            'ú' | 'ù' | 'û' | 'ü' => 'u',
+            'ç' => 'c',
+            'ñ' => 'n',
+            c if c.is_alphanumeric() => c,
+            _ => ' ',
+        })
+        .collect();
+    folded.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+// ---------------------------------------------------------------------------
+// Timezone lookup
+// ---------------------------------------------------------------------------
+
+fn tz_finder() -> &'static DefaultFinder {
+    static FINDER: OnceLock<DefaultFinder> = OnceLock::new();
+    FINDER.get_or_init(DefaultFinder::new)
+}
+
+/// IANA timezone for a coordinate.
+///
+/// Points outside every timezone polygon (open ocean) get the nautical
+/// `Etc/GMT±N` zone for their longitude.
+pub fn timezone_for(latitude: f64, longitude: f64) -> String {
+    let name = tz_finder().get_tz_name(longitude, latitude);
+    if !name.is_empty() {
+        return name.to_string();
+    }
+
+    // Etc/ zones use POSIX sign convention: Etc/GMT-5 is UTC+5
+    let hours = (longitude / 15.0).round() as i32;
+    match hours {
+        0 => "Etc/GMT".to_string(),
+        h if h > 0 => format!("Etc/GMT-{}", h),
+        h => format!("Etc/GMT+{}", -h),
+    }
+}
+
+// ---------------------------------------------------------------------------
+// BirthData enrichment
+// ---------------------------------------------------------------------------
+
+/// Fill in missing coordinates and timezone on every birth data of `input`:
+/// `birth_data`, `secondary_birth_data` and each of `subjects`.
+///
+/// - Longitudes given from 0 to 360 are wrapped into [-180, 180].
+/// - When `place` is set and no coordinates were given (both 0.0), the place
+///   is geocoded and its coordinates (and timezone, if empty) are used.
+/// - When the timezone is empty, it is resolved from the coordinates.
+///
+/// A `location` without a timezone gets the one of its coordinates.
+///
+/// Returns a validation error if a place cannot be found or `subjects`
+/// repeats a role.
+pub async fn enrich_input(
+    provider: &dyn GeocodingProvider,
+    input: &mut EngineInput,
+) -> Result<(), EngineError> {
+    input.validate_subjects()?;
+    if let Some(birth) = input.birth_data.as_mut() {
+        enrich_birth_data(provider, birth).await?;
+    }
+    if let Some(birth) = input.secondary_birth_data.as_mut() {
+        enrich_birth_data(provider, birth)
+            .await
+            .map_err(|err| prefix_fields(err, "secondary_birth_data."))?;
+    }
+    for (i, subject) in input.subjects.iter_mut().enumerate() {
+        enrich_birth_data(provider, &mut subject.birth_data)
+            .await
+            .map_err(|err| prefix_fields(err, &format!("subjects[{}].birth_data.", i)))?;
+    }
+    if let Some(location) = input.location.as_mut() {
+        if location.timezone.as_deref().is_none_or(|tz| tz.trim().is_empty()) {
+            location.timezone = Some(timezone_for(location.latitude, location.longitude));
+        }
+    }
+    // A relocation without a timezone reads the clock of the place it moves to;
+    // malformed coordinates are left for the engine to reject
+    if let Some(serde_json::Value::Object(relocate_to)) = input.options.get_mut(RELOCATE_TO_OPTION) {
+        let missing_tz = relocate_to.get("tz").and_then(|tz| tz.as_str()).is_none_or(|tz| tz.trim().is_empty());
+        let lat = relocate_to.get("lat").and_then(|lat| lat.as_f64());
+        let lon = relocate_to.get("lon").and_then(|lon| lon.as_f64());
+        if let (true, Some(lat), Some(lon)) = (missing_tz, lat, lon) {
+            if (-90.0..=90.0).contains(&lat) && (-180.0..=180.0).contains(&lon) {
+                relocate_to.insert("tz".to_string(), serde_json::Value::String(timezone_for(lat, lon)));
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Report `birth_data.*` validation errors under another birth data's path
+fn prefix_fields(err: EngineError, prefix: &str) -> EngineError {
+    match err {
+        EngineError::InvalidInput(mut errors) => {
+            for error in &mut errors {
+                error.field = error.field.replacen("birth_data.", prefix, 1);
+            }
+            EngineError::InvalidInput(errors)
+        }
+        other => other,
+    }
+}
+
+/// Fill in missing coordinates and timezone on a single [`BirthData`]
+pub async fn enrich_birth_data(
+    provider: &dyn GeocodingProvider,
+    birth: &mut BirthData,
+) -> Result<(), EngineError> {
+    birth.normalize_coordinates();
+    let has_coordinates = birth.latitude != 0.0 || birth.longitude != 0.0;
+
+    if let (Some(place), false) = (birth.place.as_deref(), has_coordinates) {
+        let found = provider
+            .search(place, 1)
+            .await?
+            .into_iter()
+            .next()
+            .ok_or_else(|| {
+                EngineError::invalid_field(
+                    "birth_data.place",
+                    ValidationCode::NotFound,
+                    format!("Place '{}' could not be geocoded", place),
+                )
+            })?;
+
+        tracing::debug!(
+            place,
+            provider = provider.provider_name(),
+            resolved = %found.name,
+            "Geocoded birth place"
+        );
+
+        birth.latitude = found.latitude;
+        birth.longitude = found.longitude;
+        if birth.timezone.trim().is_empty() {
+            birth.timezone = found.timezone;
+        }
+    }
+
+    if birth.timezone.trim().is_empty() {
+        birth.timezone = timezone_for(birth.latitude, birth.longitude);
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use noesis_core::{Subject, SubjectRole};
+
+    #[test]
+    fn test_search_alternate_name() {
+        let geo = EmbeddedGeocoder::new();
+        let results = geo.search_sync("Bangalore", 5);
+        assert_eq!(results[0].name, "Bengaluru");
+        assert_eq!(results[0].timezone, "Asia/Kolkata");
+    }
+
+    #[test]
+    fn test_search_prefix_ranked_by_population() {
+        let geo = EmbeddedGeocoder::new();
+        let results = geo.search_sync("san", 10);
+        assert!(results.len() > 2);
+        // Exact matches are absent, so the most populous prefix match wins
+        assert_eq!(results[0].name, "Santiago");
+    }
+
+    #[test]
+    fn test_search_country_qualifier_and_diacritics() {
+        let geo = EmbeddedGeocoder::new();
+        let results = geo.search_sync("São Paulo, BR", 3);
+        assert_eq!(results[0].name, "Sao Paulo");
+        assert!(geo.search_sync("Portland, IN", 3).is_empty());
+    }
+
+    #[test]
+    fn test_timezone_for_coordinates() {
+        assert_eq!(timezone_for(12.9716, 77.5946), "Asia/Kolkata");
+        assert_eq!(timezone_for(40.7128, -74.0060), "America/New_York");
+    }
+
+    #[tokio::test]
+    async fn test_enrich_birth_data_from_place() {
+        let geo = EmbeddedGeocoder::new();
+        let mut birth = BirthData {
+            name: None,
+            display_name: None,
+            date: "1990-01-15".to_string(),
+            time: Some("14:30".to_string()),
+            time_unknown: false,
+            latitude: 0.0,
+            longitude: 0.0,
+            altitude: None,
+            timezone: String::new(),
+            place: Some("Mumbai".to_string()),
+            utc_offset_minutes: None,
+        };
+        enrich_birth_data(&geo, &mut birth).await.unwrap();
+        assert!((birth.latitude - 19.07283).abs() < 1e-6);
+        assert_eq!(birth.timezone, "Asia/Kolkata");
+
+        birth.place = Some("Atlantis".to_string());
+        birth.latitude = 0.0;
+        birth.longitude = 0.0;
+        assert!(matches!(
+            enrich_birth_data(&geo, &mut birth).await,
+            Err(EngineError::InvalidInput(ref e)) if e[0].field == "birth_data.place"
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_enrich_input_reports_secondary_field() {
+        let geo = EmbeddedGeocoder::new();
+        let birth = |place: &str| BirthData {
+            name: None,
+            display_name: None,
+            date: "1990-01-15".to_string(),
+            time: None,
+            time_unknown: false,
+            latitude: 0.0,
+            longitude: 0.0,
+            altitude: None,
+            timezone: String::new(),
+            place: Some(place.to_string()),
+            utc_offset_minutes: None,
+        };
+        let mut input = EngineInput {
+            birth_data: Some(birth("Mumbai")),
+            secondary_birth_data: Some(birth("Atlantis")),
+            subjects: Vec::new(),
+            current_time: chrono::Utc::now(),
+            location: None,
+            precision: noesis_core::Precision::Standard,
+            options: Default::default(),
+        };
+        assert!(matches!(
+            enrich_input(&geo, &mut input).await,
+            Err(EngineError::InvalidInput(ref e)) if e[0].field == "secondary_birth_data.place"
+        ));
+
+        input.secondary_birth_data = Some(birth("Delhi"));
+        enrich_input(&geo, &mut input).await.unwrap();
+        assert_eq!(input.secondary_birth_data.unwrap().timezone, "Asia/Kolkata");
+
+        input.secondary_birth_data = None;
+        input.subjects = vec![Subject { role: SubjectRole::Secondary, birth_data: birth("Atlantis") }];
+        assert!(matches!(
+            enrich_input(&geo, &mut input).await,
+            Err(EngineError::InvalidInput(ref e)) if e[0].field == "subjects[0].birth_data.place"
+        ));
+
+        input.subjects.clear();
+        input.location = Some(noesis_core::Coordinates {
+            latitude: 38.72,
+            longitude: -9.14,
+            altitude: None,
+            timezone: None,
+        });
+        enrich_input(&geo, &mut input).await.unwrap();
+        assert_eq!(input.location.as_ref().unwrap().timezone.as_deref(), Some("Europe/Lisbon"));
+
+        input.options.insert(RELOCATE_TO_OPTION.to_string(), serde_json::json!({"lat": 40.71, "lon": -74.01}));
+        enrich_input(&geo, &mut input).await.unwrap();
+        assert_eq!(input.options[RELOCATE_TO_OPTION]["tz"], "America/New_York");
+    }
+}
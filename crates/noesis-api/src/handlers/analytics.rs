@@ -0,0 +1,69 @@
+use axum::extract::{Extension, Json, State};
+use noesis_auth::AuthUser;
+use noesis_cache::CacheKey;
+use noesis_core::CacheTtl;
+
+use super::{database_error, HandlerError};
+use crate::analytics::{self, HumanDesignDistributionResponse, HUMAN_DESIGN_CACHE_KEY};
+use crate::{require_permission, AppState};
+
+/// Permission required for `/api/v1/admin/analytics`
+const ANALYTICS_PERMISSION: &str = "admin:analytics";
+
+/// GET /api/v1/admin/analytics/human-design -- anonymized chart distributions
+///
+/// Type, authority, profile, definition, defined center, channel and gate
+/// counts over each user's latest saved Human Design chart. Buckets covering
+/// fewer than `min_group_size` users are suppressed. The report is cached,
+/// so it can lag behind new results by up to `ANALYTICS_CACHE_TTL_SECS`;
+/// `computed_at` says when it was taken.
+#[utoipa::path(
+    get,
+    path = "/api/v1/admin/analytics/human-design",
+    tag = "admin",
+    responses(
+        (status = 200, description = "Chart distributions", body = HumanDesignDistributionResponse),
+        (status = 401, description = "Unauthorized", content(("application/problem+json" = ProblemDetails), ("application/json" = ErrorResponse))),
+        (status = 403, description = "Missing admin:analytics permission", content(("application/problem+json" = ProblemDetails), ("application/json" = ErrorResponse))),
+        (status = 503, description = "Database unavailable", content(("application/problem+json" = ProblemDetails), ("application/json" = ErrorResponse))),
+    ),
+    security(
+        ("bearer_auth" = []),
+        ("api_key" = [])
+    )
+)]
+pub async fn human_design_distribution(
+    State(state): State<AppState>,
+    Extension(auth_user): Extension<AuthUser>,
+) -> Result<Json<HumanDesignDistributionResponse>, HandlerError> {
+    require_permission(&auth_user, ANALYTICS_PERMISSION)?;
+
+    let key = CacheKey::new(HUMAN_DESIGN_CACHE_KEY);
+    match state.core.cache.get(&key).await {
+        Ok(Some(cached)) => match serde_json::from_value(cached) {
+            Ok(report) => return Ok(Json(report)),
+            Err(e) => tracing::warn!(error = %e, "Discarding unreadable cached analytics report"),
+        },
+        Ok(None) => {}
+        Err(e) => tracing::warn!(error = %e, "Analytics cache lookup failed"),
+    }
+
+    let counts = state
+        .result_repository
+        .human_design_distribution()
+        .await
+        .map_err(database_error)?;
+    let report = analytics::human_design_distribution(&counts, state.analytics.min_group_size);
+
+    if let Ok(value) = serde_json::to_value(&report) {
+        if let Err(e) = state
+            .core
+            .cache
+            .store_with_ttl(&key, &value, CacheTtl::Duration(state.analytics.cache_ttl))
+            .await
+        {
+            tracing::warn!(error = %e, "Failed to cache analytics report");
+        }
+    }
+    Ok(Json(report))
+}
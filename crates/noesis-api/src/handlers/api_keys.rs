@@ -0,0 +1,318 @@
+use axum::{
+    extract::{Extension, Json, Path, State},
+    http::StatusCode,
+    response::{IntoResponse, Response},
+};
+use chrono::{DateTime, Utc};
+use noesis_auth::{generate_api_key, sha256_hex, AuthUser};
+use noesis_core::{EngineError, ValidationCode, ValidationError};
+use noesis_data::models::api_key::{NewApiKey, StoredApiKey};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use super::{database_error, not_found, user_uuid, HandlerError};
+use crate::{engine_error_to_response, AppState, ErrorResponse};
+
+#[derive(Deserialize, ToSchema)]
+pub struct CreateApiKeyRequest {
+    /// Label to recognise the key by
+    #[schema(example = "Booking site")]
+    pub name: Option<String>,
+    /// Engines the key may calculate; any engine when omitted
+    #[schema(example = json!(["panchanga", "biorhythm"]))]
+    pub allowed_engines: Option<Vec<String>>,
+    /// Workflows the key may execute; any workflow when omitted
+    #[schema(example = json!([]))]
+    pub allowed_workflows: Option<Vec<String>>,
+    /// When the key stops working; never when omitted
+    pub expires_at: Option<DateTime<Utc>>,
+}
+
+/// New scopes of a key, replacing the old ones
+#[derive(Deserialize, ToSchema)]
+pub struct UpdateApiKeyScopesRequest {
+    /// Engines the key may calculate; any engine when omitted or null
+    #[schema(example = json!(["panchanga"]))]
+    pub allowed_engines: Option<Vec<String>>,
+    /// Workflows the key may execute; any workflow when omitted or null
+    pub allowed_workflows: Option<Vec<String>>,
+}
+
+#[derive(Serialize, ToSchema)]
+pub struct ApiKeyResponse {
+    pub id: String,
+    /// The key itself, only in the response that created it; it is stored
+    /// hashed and cannot be shown again
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(example = "nk_4f8Qm2LzT9aX0cVbN7eR1sYkWj3HdP6u")]
+    pub key: Option<String>,
+    pub name: Option<String>,
+    pub tier: String,
+    pub permissions: Vec<String>,
+    /// Engines the key may calculate; any engine when null
+    pub allowed_engines: Option<Vec<String>>,
+    /// Workflows the key may execute; any workflow when null
+    pub allowed_workflows: Option<Vec<String>>,
+    pub created_at: DateTime<Utc>,
+    pub expires_at: Option<DateTime<Utc>>,
+    pub last_used: Option<DateTime<Utc>>,
+}
+
+#[derive(Serialize, ToSchema)]
+pub struct ApiKeyListResponse {
+    pub api_keys: Vec<ApiKeyResponse>,
+}
+
+impl From<StoredApiKey> for ApiKeyResponse {
+    fn from(key: StoredApiKey) -> Self {
+        Self {
+            id: key.id.to_string(),
+            key: None,
+            name: key.name,
+            tier: key.tier,
+            permissions: key.permissions.0,
+            allowed_engines: key.allowed_engines.map(|ids| ids.0),
+            allowed_workflows: key.allowed_workflows.map(|ids| ids.0),
+            created_at: key.created_at,
+            expires_at: key.expires_at,
+            last_used: key.last_used,
+        }
+    }
+}
+
+/// Errors for scope entries that name no registered engine or workflow
+fn unknown_ids(field: &str, ids: Option<&[String]>, known: &[String], kind: &str) -> Vec<ValidationError> {
+    ids.unwrap_or_default()
+        .iter()
+        .enumerate()
+        .filter(|(_, id)| !known.contains(id))
+        .map(|(i, id)| {
+            ValidationError::new(format!("{}[{}]", field, i), ValidationCode::Unknown, format!("Unknown {} '{}'", kind, id))
+        })
+        .collect()
+}
+
+fn validate_scopes(
+    state: &AppState,
+    allowed_engines: Option<&[String]>,
+    allowed_workflows: Option<&[String]>,
+) -> Vec<ValidationError> {
+    let workflows: Vec<String> = state.core.orchestrator.list_workflows().into_iter().map(|w| w.id).collect();
+    let mut errors = unknown_ids("allowed_engines", allowed_engines, &state.core.orchestrator.list_engines(), "engine");
+    errors.extend(unknown_ids("allowed_workflows", allowed_workflows, &workflows, "workflow"));
+    errors
+}
+
+fn invalid(errors: Vec<ValidationError>) -> Result<(), HandlerError> {
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(engine_error_to_response(EngineError::InvalidInput(errors)))
+    }
+}
+
+/// A scoped key could otherwise mint itself an unscoped one
+fn require_unscoped(auth_user: &AuthUser) -> Result<(), HandlerError> {
+    if !auth_user.is_scoped() {
+        return Ok(());
+    }
+    Err((
+        StatusCode::FORBIDDEN,
+        Json(ErrorResponse {
+            error: "API keys scoped to specific engines or workflows cannot manage API keys".to_string(),
+            error_code: "FORBIDDEN".to_string(),
+            details: Some(serde_json::json!({ "required_scope": "unscoped" })),
+            request_id: None,
+        }),
+    ))
+}
+
+fn api_key_not_found(id: &str) -> HandlerError {
+    not_found("API_KEY_NOT_FOUND", format!("API key '{}' not found", id))
+}
+
+/// GET /api/v1/users/me/api-keys -- the authenticated user's active API keys
+#[utoipa::path(
+    get,
+    path = "/api/v1/users/me/api-keys",
+    tag = "users",
+    responses(
+        (status = 200, description = "Active API keys, oldest first; the keys themselves are not shown", body = ApiKeyListResponse),
+        (status = 401, description = "Unauthorized", content(("application/problem+json" = ProblemDetails), ("application/json" = ErrorResponse))),
+        (status = 403, description = "Called with a scoped API key", content(("application/problem+json" = ProblemDetails), ("application/json" = ErrorResponse))),
+    ),
+    security(
+        ("bearer_auth" = []),
+        ("api_key" = [])
+    )
+)]
+pub async fn list_api_keys(
+    State(state): State<AppState>,
+    Extension(auth_user): Extension<AuthUser>,
+) -> Result<Json<ApiKeyListResponse>, HandlerError> {
+    require_unscoped(&auth_user)?;
+    let keys = state
+        .api_key_repository
+        .list_for_user(user_uuid(&auth_user)?)
+        .await
+        .map_err(database_error)?;
+
+    Ok(Json(ApiKeyListResponse {
+        api_keys: keys.into_iter().map(ApiKeyResponse::from).collect(),
+    }))
+}
+
+/// POST /api/v1/users/me/api-keys -- mint an API key, optionally scoped
+#[utoipa::path(
+    post,
+    path = "/api/v1/users/me/api-keys",
+    tag = "users",
+    request_body = CreateApiKeyRequest,
+    responses(
+        (status = 201, description = "Key created; `key` is shown only in this response", body = ApiKeyResponse),
+        (status = 401, description = "Unauthorized", content(("application/problem+json" = ProblemDetails), ("application/json" = ErrorResponse))),
+        (status = 403, description = "Called with a scoped API key", content(("application/problem+json" = ProblemDetails), ("application/json" = ErrorResponse))),
+        (status = 422, description = "Unknown engine or workflow in a scope, or `expires_at` in the past", content(("application/problem+json" = ProblemDetails), ("application/json" = ErrorResponse))),
+    ),
+    security(
+        ("bearer_auth" = []),
+        ("api_key" = [])
+    )
+)]
+pub async fn create_api_key(
+    State(state): State<AppState>,
+    Extension(auth_user): Extension<AuthUser>,
+    Json(payload): Json<CreateApiKeyRequest>,
+) -> Result<Response, HandlerError> {
+    require_unscoped(&auth_user)?;
+    let user_id = user_uuid(&auth_user)?;
+    let mut errors = validate_scopes(&state, payload.allowed_engines.as_deref(), payload.allowed_workflows.as_deref());
+    if payload.expires_at.is_some_and(|expires_at| expires_at <= Utc::now()) {
+        errors.push(ValidationError::new("expires_at", ValidationCode::OutOfRange, "expires_at must be in the future"));
+    }
+    invalid(errors)?;
+
+    // The key acts as its owner, within its scopes
+    let key = generate_api_key();
+    let stored = state
+        .api_key_repository
+        .create_key(
+            user_id,
+            &NewApiKey {
+                key_hash: sha256_hex(&key),
+                name: payload.name.map(|name| name.trim().to_string()).filter(|name| !name.is_empty()),
+                tier: auth_user.tier.clone(),
+                permissions: auth_user.permissions.clone(),
+                consciousness_level: i32::from(auth_user.consciousness_level),
+                rate_limit: i32::try_from(auth_user.rate_limit).unwrap_or(i32::MAX),
+                allowed_engines: payload.allowed_engines,
+                allowed_workflows: payload.allowed_workflows,
+                expires_at: payload.expires_at,
+            },
+        )
+        .await
+        .map_err(database_error)?;
+
+    let response = ApiKeyResponse {
+        key: Some(key),
+        ..ApiKeyResponse::from(stored)
+    };
+    Ok((StatusCode::CREATED, Json(response)).into_response())
+}
+
+/// PUT /api/v1/users/me/api-keys/:id/scopes -- replace the scopes of a key
+#[utoipa::path(
+    put,
+    path = "/api/v1/users/me/api-keys/{id}/scopes",
+    tag = "users",
+    params(("id" = String, Path, description = "API key id")),
+    request_body = UpdateApiKeyScopesRequest,
+    responses(
+        (status = 200, description = "Scopes replaced", body = ApiKeyResponse),
+        (status = 401, description = "Unauthorized", content(("application/problem+json" = ProblemDetails), ("application/json" = ErrorResponse))),
+        (status = 403, description = "Called with a scoped API key", content(("application/problem+json" = ProblemDetails), ("application/json" = ErrorResponse))),
+        (status = 404, description = "No active key with this id belongs to the user", content(("application/problem+json" = ProblemDetails), ("application/json" = ErrorResponse))),
+        (status = 422, description = "Unknown engine or workflow in a scope", content(("application/problem+json" = ProblemDetails), ("application/json" = ErrorResponse))),
+    ),
+    security(
+        ("bearer_auth" = []),
+        ("api_key" = [])
+    )
+)]
+pub async fn update_api_key_scopes(
+    State(state): State<AppState>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(id): Path<String>,
+    Json(payload): Json<UpdateApiKeyScopesRequest>,
+) -> Result<Json<ApiKeyResponse>, HandlerError> {
+    require_unscoped(&auth_user)?;
+    let key_id = Uuid::parse_str(&id).map_err(|_| api_key_not_found(&id))?;
+    invalid(validate_scopes(&state, payload.allowed_engines.as_deref(), payload.allowed_workflows.as_deref()))?;
+
+    let updated = state
+        .api_key_repository
+        .update_scopes_for_user(
+            key_id,
+            user_uuid(&auth_user)?,
+            payload.allowed_engines.as_deref(),
+            payload.allowed_workflows.as_deref(),
+        )
+        .await
+        .map_err(database_error)?
+        .ok_or_else(|| api_key_not_found(&id))?;
+
+    Ok(Json(ApiKeyResponse::from(updated)))
+}
+
+/// DELETE /api/v1/users/me/api-keys/:id -- revoke a key
+#[utoipa::path(
+    delete,
+    path = "/api/v1/users/me/api-keys/{id}",
+    tag = "users",
+    params(("id" = String, Path, description = "API key id")),
+    responses(
+        (status = 204, description = "Key revoked"),
+        (status = 401, description = "Unauthorized", content(("application/problem+json" = ProblemDetails), ("application/json" = ErrorResponse))),
+        (status = 403, description = "Called with a scoped API key", content(("application/problem+json" = ProblemDetails), ("application/json" = ErrorResponse))),
+        (status = 404, description = "No active key with this id belongs to the user", content(("application/problem+json" = ProblemDetails), ("application/json" = ErrorResponse))),
+    ),
+    security(
+        ("bearer_auth" = []),
+        ("api_key" = [])
+    )
+)]
+pub async fn revoke_api_key(
+    State(state): State<AppState>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(id): Path<String>,
+) -> Result<StatusCode, HandlerError> {
+    require_unscoped(&auth_user)?;
+    let key_id = Uuid::parse_str(&id).map_err(|_| api_key_not_found(&id))?;
+    let revoked = state
+        .api_key_repository
+        .revoke_for_user(key_id, user_uuid(&auth_user)?)
+        .await
+        .map_err(database_error)?;
+
+    if revoked {
+        Ok(StatusCode::NO_CONTENT)
+    } else {
+        Err(api_key_not_found(&id))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unknown_ids_name_their_position() {
+        let known = vec!["panchanga".to_string(), "biorhythm".to_string()];
+        let requested = vec!["biorhythm".to_string(), "astrology".to_string()];
+        let errors = unknown_ids("allowed_engines", Some(&requested), &known, "engine");
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].field, "allowed_engines[1]");
+        assert!(unknown_ids("allowed_engines", None, &known, "engine").is_empty());
+    }
+}
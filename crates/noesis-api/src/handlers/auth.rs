@@ -9,30 +9,33 @@ use noesis_auth::password::{hash_password, verify_password};
 use crate::AppState;
 use crate::error::ApiError;
 use noesis_core::EngineError;
+use noesis_data::models::user::User;
+use noesis_data::repositories::user_repository::RESTORE_WINDOW_DAYS;
 use chrono::{Utc, Duration};
 use uuid::Uuid;
 use tracing::info;
+use utoipa::ToSchema;
 
-#[derive(Deserialize)]
+#[derive(Deserialize, ToSchema)]
 pub struct RegisterRequest {
     pub email: String,
     pub password: String,
     pub full_name: String,
 }
 
-#[derive(Serialize)]
+#[derive(Serialize, ToSchema)]
 pub struct RegisterResponse {
     pub id: String,
     pub message: String,
 }
 
-#[derive(Deserialize)]
+#[derive(Deserialize, ToSchema)]
 pub struct LoginRequest {
     pub email: String,
     pub password: String,
 }
 
-#[derive(Serialize)]
+#[derive(Serialize, ToSchema)]
 pub struct LoginResponse {
     pub token: String,
     pub user_id: String,
@@ -40,27 +43,39 @@ pub struct LoginResponse {
     pub tier: String,
 }
 
-#[derive(Deserialize)]
+#[derive(Deserialize, ToSchema)]
 pub struct ForgotPasswordRequest {
     pub email: String,
 }
 
-#[derive(Serialize)]
+#[derive(Serialize, ToSchema)]
 pub struct ForgotPasswordResponse {
     pub message: String,
 }
 
-#[derive(Deserialize)]
+#[derive(Deserialize, ToSchema)]
 pub struct ResetPasswordRequest {
     pub token: String,
     pub new_password: String,
 }
 
-#[derive(Serialize)]
+#[derive(Serialize, ToSchema)]
 pub struct ResetPasswordResponse {
     pub message: String,
 }
 
+/// POST /api/v1/auth/register -- create an account
+#[utoipa::path(
+    post,
+    path = "/api/v1/auth/register",
+    tag = "auth",
+    request_body = RegisterRequest,
+    responses(
+        (status = 201, description = "User created", body = RegisterResponse),
+        (status = 401, description = "User already exists", content(("application/problem+json" = ProblemDetails), ("application/json" = ErrorResponse))),
+        (status = 500, description = "Database error", content(("application/problem+json" = ProblemDetails), ("application/json" = ErrorResponse))),
+    )
+)]
 pub async fn register(
     State(state): State<AppState>,
     Json(payload): Json<RegisterRequest>,
@@ -73,6 +88,17 @@ pub async fn register(
         return Err(EngineError::AuthError("User already exists".to_string()).into());
     }
 
+    // A deleted account keeps its email until it is purged
+    let deleted_user = state.user_repository.get_restorable_user_by_email(&payload.email).await
+        .map_err(|e| EngineError::InternalError(format!("Database error checking user: {}", e)))?;
+
+    if deleted_user.is_some() {
+        return Err(EngineError::AuthError(format!(
+            "An account with this email was deleted; restore it via /api/v1/auth/restore within {} days of deletion",
+            RESTORE_WINDOW_DAYS
+        )).into());
+    }
+
     // Hash password
     let password_hash = hash_password(&payload.password)?;
 
@@ -92,6 +118,17 @@ pub async fn register(
     Ok((StatusCode::CREATED, Json(response)).into_response())
 }
 
+/// POST /api/v1/auth/login -- exchange credentials for a JWT
+#[utoipa::path(
+    post,
+    path = "/api/v1/auth/login",
+    tag = "auth",
+    request_body = LoginRequest,
+    responses(
+        (status = 200, description = "JWT issued", body = LoginResponse),
+        (status = 401, description = "Invalid email or password", content(("application/problem+json" = ProblemDetails), ("application/json" = ErrorResponse))),
+    )
+)]
 pub async fn login(
     State(state): State<AppState>,
     Json(payload): Json<LoginRequest>,
@@ -108,29 +145,83 @@ pub async fn login(
         return Err(EngineError::AuthError("Invalid email or password".to_string()).into());
     }
 
-    // 3. Generate JWT token
-    // We'll give them standard permissions based on tier for now
-    let permissions = vec!["basic:access".to_string()];
+    // 3. Generate JWT token and return it
+    let response = login_response(&state, user).await?;
+    Ok((StatusCode::OK, Json(response)).into_response())
+}
+
+/// Issue a JWT for an authenticated user
+async fn login_response(state: &AppState, user: User) -> Result<LoginResponse, ApiError> {
+    // Standard permissions, plus client scopes for organization members
+    let mut permissions = vec!["basic:access".to_string()];
+    let roles = state.organization_repository.get_roles_for_user(user.id).await
+        .map_err(|e| EngineError::InternalError(format!("Database error: {}", e)))?;
+    for scope in roles.iter().flat_map(|role| noesis_auth::permissions::for_role(role)) {
+        if !permissions.iter().any(|p| p == scope) {
+            permissions.push(scope.to_string());
+        }
+    }
     let consciousness_level = user.consciousness_level as u8;
     
-    let token = state.auth.generate_jwt_token(
+    let token = state.core.auth.generate_jwt_token(
         &user.id.to_string(),
         &user.tier,
         &permissions,
         consciousness_level
     )?;
 
-    // 4. Return token
-    let response = LoginResponse {
+    Ok(LoginResponse {
         token,
         user_id: user.id.to_string(),
         email: user.email,
         tier: user.tier,
-    };
+    })
+}
+
+/// POST /api/v1/auth/restore -- restore a deleted account within its restore window
+#[utoipa::path(
+    post,
+    path = "/api/v1/auth/restore",
+    tag = "auth",
+    request_body = LoginRequest,
+    responses(
+        (status = 200, description = "Account and its data restored; JWT issued", body = LoginResponse),
+        (status = 401, description = "Invalid email or password, or no restorable account", content(("application/problem+json" = ProblemDetails), ("application/json" = ErrorResponse))),
+    )
+)]
+pub async fn restore_account(
+    State(state): State<AppState>,
+    Json(payload): Json<LoginRequest>,
+) -> Result<Response, ApiError> {
+    let user = state.user_repository.get_restorable_user_by_email(&payload.email).await
+        .map_err(|e| EngineError::InternalError(format!("Database error: {}", e)))?
+        .ok_or_else(|| EngineError::AuthError("Invalid email or password".to_string()))?;
+
+    if !verify_password(&payload.password, &user.password_hash)? {
+        return Err(EngineError::AuthError("Invalid email or password".to_string()).into());
+    }
+
+    // None when the purge job or a concurrent restore got there first
+    let user = state.user_repository.restore_user(user.id).await
+        .map_err(|e| EngineError::InternalError(format!("Database error: {}", e)))?
+        .ok_or_else(|| EngineError::AuthError("Invalid email or password".to_string()))?;
+    info!(user_id = %user.id, "Account restored");
 
+    let response = login_response(&state, user).await?;
     Ok((StatusCode::OK, Json(response)).into_response())
 }
 
+/// POST /api/v1/auth/forgot-password -- request a password reset token
+#[utoipa::path(
+    post,
+    path = "/api/v1/auth/forgot-password",
+    tag = "auth",
+    request_body = ForgotPasswordRequest,
+    responses(
+        (status = 200, description = "Reset requested; the response does not reveal whether the account exists", body = ForgotPasswordResponse),
+        (status = 500, description = "Database error", content(("application/problem+json" = ProblemDetails), ("application/json" = ErrorResponse))),
+    )
+)]
 pub async fn forgot_password(
     State(state): State<AppState>,
     Json(payload): Json<ForgotPasswordRequest>,
@@ -153,6 +244,17 @@ pub async fn forgot_password(
     Ok((StatusCode::OK, Json(response)).into_response())
 }
 
+/// POST /api/v1/auth/reset-password -- set a new password with a reset token
+#[utoipa::path(
+    post,
+    path = "/api/v1/auth/reset-password",
+    tag = "auth",
+    request_body = ResetPasswordRequest,
+    responses(
+        (status = 200, description = "Password reset", body = ResetPasswordResponse),
+        (status = 401, description = "Invalid or expired reset token", content(("application/problem+json" = ProblemDetails), ("application/json" = ErrorResponse))),
+    )
+)]
 pub async fn reset_password(
     State(state): State<AppState>,
     Json(payload): Json<ResetPasswordRequest>,
@@ -0,0 +1,155 @@
+use axum::{
+    extract::{Extension, Json, Path, State},
+    http::StatusCode,
+};
+use noesis_auth::{AuthService, AuthUser};
+use noesis_cache::hot_keys::HotKeyReport;
+use noesis_cache::l1_cache::L1PartitionUsage;
+use serde::Serialize;
+use utoipa::ToSchema;
+
+use super::HandlerError;
+use crate::{engine_error_to_response, permission_denied, AppState};
+
+/// Permission required for `/api/v1/admin/cache`
+const CACHE_PERMISSION: &str = "admin:cache";
+
+#[derive(Serialize, ToSchema)]
+pub struct CacheInvalidationResponse {
+    #[schema(example = "panchanga")]
+    pub engine_id: String,
+    #[schema(example = "1")]
+    pub engine_version: String,
+    /// Entries removed from the in-memory and disk layers
+    pub invalidated: usize,
+    /// Version the engine calculates with now; absent if it isn't registered
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(example = "2")]
+    pub current_version: Option<String>,
+}
+
+#[derive(Serialize, ToSchema)]
+pub struct CacheStatsResponse {
+    pub l1_hits: u64,
+    pub l2_hits: u64,
+    pub l3_hits: u64,
+    pub cache_misses: u64,
+    pub total_requests: u64,
+    /// Share of lookups answered by any layer
+    #[schema(example = 0.82)]
+    pub hit_rate: f64,
+    /// Entries held in memory
+    pub l1_entries: usize,
+    /// In-memory budget of each engine with its own share, then of the
+    /// partition the other engines share
+    pub l1_partitions: Vec<L1PartitionUsage>,
+    /// Most requested keys, to pick what to precompute and spot clients
+    /// repeating one input
+    pub hot_keys: HotKeyReport,
+}
+
+/// GET /api/v1/admin/cache/stats -- hit counts per layer and the hottest keys
+///
+/// Counts run since startup. Hot keys are counted in one-minute windows;
+/// `previous` is the last complete one, and is also exported as the
+/// `noesis_cache_hot_key_requests` and `noesis_cache_hot_key_misses` gauges.
+/// Keys are returned as engines build them and may contain birth data.
+#[utoipa::path(
+    get,
+    path = "/api/v1/admin/cache/stats",
+    tag = "admin",
+    responses(
+        (status = 200, description = "Cache statistics", body = CacheStatsResponse),
+        (status = 401, description = "Unauthorized", content(("application/problem+json" = ProblemDetails), ("application/json" = ErrorResponse))),
+        (status = 403, description = "Missing admin:cache permission", content(("application/problem+json" = ProblemDetails), ("application/json" = ErrorResponse))),
+    ),
+    security(
+        ("bearer_auth" = []),
+        ("api_key" = [])
+    )
+)]
+pub async fn cache_stats(
+    State(state): State<AppState>,
+    Extension(auth_user): Extension<AuthUser>,
+) -> Result<Json<CacheStatsResponse>, HandlerError> {
+    if !AuthService::can_access_endpoint(&auth_user, "/api/v1/admin/cache") {
+        return Err(permission_denied(CACHE_PERMISSION));
+    }
+
+    let cache = &state.core.cache;
+    let stats = cache.get_stats().await;
+    Ok(Json(CacheStatsResponse {
+        hit_rate: stats.hit_rate(),
+        l1_hits: stats.l1_hits,
+        l2_hits: stats.l2_hits,
+        l3_hits: stats.l3_hits,
+        cache_misses: stats.cache_misses,
+        total_requests: stats.total_requests,
+        l1_entries: cache.l1_entry_count(),
+        l1_partitions: cache.l1_partitions(),
+        hot_keys: cache.hot_keys(),
+    }))
+}
+
+/// DELETE /api/v1/admin/cache/engines/:engine_id/versions/:version -- drop one engine version's cached results
+///
+/// Results are cached under their engine version, so an upgraded engine
+/// already stops serving old ones; this frees the space they hold, and clears
+/// a version that shipped a wrong formula without waiting for a bump.
+#[utoipa::path(
+    delete,
+    path = "/api/v1/admin/cache/engines/{engine_id}/versions/{version}",
+    tag = "admin",
+    params(
+        ("engine_id" = String, Path, description = "Engine whose results to drop; need not be registered"),
+        ("version" = String, Path, description = "The `metadata.engine_version` of the results"),
+    ),
+    responses(
+        (status = 200, description = "Cached results removed", body = CacheInvalidationResponse),
+        (status = 401, description = "Unauthorized", content(("application/problem+json" = ProblemDetails), ("application/json" = ErrorResponse))),
+        (status = 403, description = "Missing admin:cache permission", content(("application/problem+json" = ProblemDetails), ("application/json" = ErrorResponse))),
+    ),
+    security(
+        ("bearer_auth" = []),
+        ("api_key" = [])
+    )
+)]
+pub async fn invalidate_engine_version(
+    State(state): State<AppState>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path((engine_id, engine_version)): Path<(String, String)>,
+) -> Result<(StatusCode, Json<CacheInvalidationResponse>), HandlerError> {
+    if !AuthService::can_access_endpoint(&auth_user, "/api/v1/admin/cache") {
+        return Err(permission_denied(CACHE_PERMISSION));
+    }
+
+    let invalidated = state
+        .core
+        .cache
+        .invalidate_engine_version(&engine_id, &engine_version)
+        .await
+        .map_err(engine_error_to_response)?;
+    tracing::info!(
+        engine_id = %engine_id,
+        engine_version = %engine_version,
+        invalidated,
+        user_id = %auth_user.user_id,
+        "Engine version cache invalidated"
+    );
+
+    let current_version = state
+        .core
+        .orchestrator
+        .registry()
+        .get(&engine_id)
+        .map(|engine| engine.engine_version().to_string());
+    Ok((
+        StatusCode::OK,
+        Json(CacheInvalidationResponse {
+            engine_id,
+            engine_version,
+            invalidated,
+            current_version,
+        }),
+    ))
+}
@@ -0,0 +1,474 @@
+use std::collections::HashMap;
+
+use axum::{
+    extract::{Extension, Json, Path, Query, State},
+    http::StatusCode,
+    response::{IntoResponse, Response},
+};
+use chrono::{DateTime, NaiveDate, NaiveTime, Utc};
+use noesis_auth::{permissions, AuthUser};
+use noesis_core::{BirthData, Coordinates, EngineError, EngineInput, Precision, ValidationCode, ValidationError};
+use noesis_data::models::client::{Client, NewClient};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use super::users::LocationResponse;
+use super::{database_error, not_found, user_uuid, HandlerError};
+use crate::{
+    engine_error_to_response, permission_denied, plan_workflow, require_permission, require_workflow_scope,
+    run_workflow, set_as_of, AppState, ErrorResponse, WorkflowExecuteParams,
+};
+use crate::versioning::ApiVersion;
+
+#[derive(Deserialize, ToSchema)]
+pub struct CreateClientRequest {
+    /// Organization the caller belongs to
+    pub organization_id: String,
+    pub full_name: String,
+    pub birth_date: NaiveDate,
+    pub birth_time: Option<NaiveTime>,
+    pub birth_location_lat: f64,
+    pub birth_location_lng: f64,
+    pub birth_location_name: Option<String>,
+    #[schema(example = "Asia/Kolkata")]
+    pub timezone: String,
+    #[serde(default)]
+    pub consent: ConsentUpdate,
+}
+
+/// Consent flags; omitted flags are left unchanged (false for a new client)
+#[derive(Deserialize, Default, ToSchema)]
+pub struct ConsentUpdate {
+    pub calculations: Option<bool>,
+    pub sharing: Option<bool>,
+}
+
+#[derive(Serialize, ToSchema)]
+pub struct ConsentResponse {
+    pub calculations: bool,
+    pub sharing: bool,
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Serialize, ToSchema)]
+pub struct ClientResponse {
+    pub id: String,
+    pub organization_id: String,
+    pub full_name: String,
+    pub birth_date: NaiveDate,
+    pub birth_time: Option<NaiveTime>,
+    pub birth_location: LocationResponse,
+    pub timezone: String,
+    pub consent: ConsentResponse,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Serialize, ToSchema)]
+pub struct ClientListResponse {
+    pub clients: Vec<ClientResponse>,
+}
+
+/// Engine options for a calculation run on a client's behalf; birth data
+/// comes from the client profile
+#[derive(Deserialize, Default, ToSchema)]
+pub struct ClientWorkflowRequest {
+    #[serde(default)]
+    #[schema(value_type = Object)]
+    pub options: HashMap<String, serde_json::Value>,
+    #[serde(default)]
+    pub precision: Option<Precision>,
+}
+
+impl From<Client> for ClientResponse {
+    fn from(client: Client) -> Self {
+        Self {
+            id: client.id.to_string(),
+            organization_id: client.organization_id.to_string(),
+            full_name: client.full_name,
+            birth_date: client.birth_date,
+            birth_time: client.birth_time,
+            birth_location: LocationResponse {
+                lat: client.birth_location_lat,
+                lng: client.birth_location_lng,
+                name: client.birth_location_name,
+            },
+            timezone: client.timezone,
+            consent: ConsentResponse {
+                calculations: client.consent_calculations,
+                sharing: client.consent_sharing,
+                updated_at: client.consent_updated_at,
+            },
+            created_at: client.created_at,
+        }
+    }
+}
+
+impl CreateClientRequest {
+    fn validate(&self) -> Result<(), EngineError> {
+        let mut errors = Vec::new();
+        if self.full_name.trim().is_empty() {
+            errors.push(ValidationError::new("full_name", ValidationCode::Empty, "Client name cannot be empty"));
+        }
+        if !(-90.0..=90.0).contains(&self.birth_location_lat) {
+            errors.push(ValidationError::new("birth_location_lat", ValidationCode::OutOfRange, "Latitude must be between -90 and 90"));
+        }
+        if !(-180.0..=180.0).contains(&self.birth_location_lng) {
+            errors.push(ValidationError::new("birth_location_lng", ValidationCode::OutOfRange, "Longitude must be between -180 and 180"));
+        }
+        if !noesis_core::timezone::is_known_timezone(self.timezone.trim()) {
+            errors.push(ValidationError::new("timezone", ValidationCode::Unknown, format!("Unknown timezone '{}'", self.timezone)));
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(EngineError::InvalidInput(errors))
+        }
+    }
+}
+
+/// POST /api/v1/clients -- create a client profile in one of the caller's organizations
+#[utoipa::path(
+    post,
+    path = "/api/v1/clients",
+    tag = "clients",
+    request_body = CreateClientRequest,
+    responses(
+        (status = 201, description = "Client created", body = ClientResponse),
+        (status = 401, description = "Unauthorized", content(("application/problem+json" = ProblemDetails), ("application/json" = ErrorResponse))),
+        (status = 403, description = "Missing the clients:write permission, or a role in the organization without it", content(("application/problem+json" = ProblemDetails), ("application/json" = ErrorResponse))),
+        (status = 404, description = "Caller is not a member of the organization", content(("application/problem+json" = ProblemDetails), ("application/json" = ErrorResponse))),
+        (status = 422, description = "Validation error", content(("application/problem+json" = ProblemDetails), ("application/json" = ErrorResponse))),
+    ),
+    security(
+        ("bearer_auth" = []),
+        ("api_key" = [])
+    )
+)]
+pub async fn create_client(
+    State(state): State<AppState>,
+    Extension(auth_user): Extension<AuthUser>,
+    Json(payload): Json<CreateClientRequest>,
+) -> Result<Response, HandlerError> {
+    require_permission(&auth_user, permissions::CLIENTS_WRITE)?;
+    payload.validate().map_err(engine_error_to_response)?;
+
+    let caller_id = user_uuid(&auth_user)?;
+    let organization_not_found = || {
+        not_found(
+            "ORGANIZATION_NOT_FOUND",
+            format!("Organization '{}' not found", payload.organization_id),
+        )
+    };
+    let organization_id = Uuid::parse_str(&payload.organization_id).map_err(|_| organization_not_found())?;
+    let role = state
+        .organization_repository
+        .get_member_role(organization_id, caller_id)
+        .await
+        .map_err(database_error)?
+        .ok_or_else(organization_not_found)?;
+    require_role_scope(Some(&role), permissions::CLIENTS_WRITE)?;
+
+    let consent = &payload.consent;
+    let client = state
+        .client_repository
+        .create_client(
+            caller_id,
+            &NewClient {
+                organization_id,
+                full_name: payload.full_name.trim().to_string(),
+                birth_date: payload.birth_date,
+                birth_time: payload.birth_time,
+                birth_location_lat: payload.birth_location_lat,
+                birth_location_lng: payload.birth_location_lng,
+                birth_location_name: payload.birth_location_name.clone(),
+                timezone: payload.timezone.trim().to_string(),
+                consent_calculations: consent.calculations.unwrap_or(false),
+                consent_sharing: consent.sharing.unwrap_or(false),
+            },
+        )
+        .await
+        .map_err(database_error)?;
+
+    Ok((StatusCode::CREATED, Json(ClientResponse::from(client))).into_response())
+}
+
+/// GET /api/v1/clients -- clients of every organization the caller belongs to
+#[utoipa::path(
+    get,
+    path = "/api/v1/clients",
+    tag = "clients",
+    responses(
+        (status = 200, description = "Clients, by name", body = ClientListResponse),
+        (status = 401, description = "Unauthorized", content(("application/problem+json" = ProblemDetails), ("application/json" = ErrorResponse))),
+        (status = 403, description = "Missing the clients:read permission", content(("application/problem+json" = ProblemDetails), ("application/json" = ErrorResponse))),
+    ),
+    security(
+        ("bearer_auth" = []),
+        ("api_key" = [])
+    )
+)]
+pub async fn list_clients(
+    State(state): State<AppState>,
+    Extension(auth_user): Extension<AuthUser>,
+) -> Result<Json<ClientListResponse>, HandlerError> {
+    require_permission(&auth_user, permissions::CLIENTS_READ)?;
+
+    let clients = state
+        .client_repository
+        .list_clients_for_member(user_uuid(&auth_user)?)
+        .await
+        .map_err(database_error)?;
+
+    Ok(Json(ClientListResponse {
+        clients: clients.into_iter().map(ClientResponse::from).collect(),
+    }))
+}
+
+/// GET /api/v1/clients/:id -- one client profile
+#[utoipa::path(
+    get,
+    path = "/api/v1/clients/{id}",
+    tag = "clients",
+    params(("id" = String, Path, description = "Client id")),
+    responses(
+        (status = 200, description = "Client profile", body = ClientResponse),
+        (status = 401, description = "Unauthorized", content(("application/problem+json" = ProblemDetails), ("application/json" = ErrorResponse))),
+        (status = 403, description = "Missing the clients:read permission, or a role in the client's organization without it", content(("application/problem+json" = ProblemDetails), ("application/json" = ErrorResponse))),
+        (status = 404, description = "No such client in the caller's organizations", content(("application/problem+json" = ProblemDetails), ("application/json" = ErrorResponse))),
+    ),
+    security(
+        ("bearer_auth" = []),
+        ("api_key" = [])
+    )
+)]
+pub async fn get_client(
+    State(state): State<AppState>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(id): Path<String>,
+) -> Result<Json<ClientResponse>, HandlerError> {
+    require_permission(&auth_user, permissions::CLIENTS_READ)?;
+    let client = find_member_client(&state, &auth_user, &id, permissions::CLIENTS_READ).await?;
+    Ok(Json(ClientResponse::from(client)))
+}
+
+/// PATCH /api/v1/clients/:id/consent -- record the client's consent choices
+#[utoipa::path(
+    patch,
+    path = "/api/v1/clients/{id}/consent",
+    tag = "clients",
+    params(("id" = String, Path, description = "Client id")),
+    request_body = ConsentUpdate,
+    responses(
+        (status = 200, description = "Updated client profile", body = ClientResponse),
+        (status = 401, description = "Unauthorized", content(("application/problem+json" = ProblemDetails), ("application/json" = ErrorResponse))),
+        (status = 403, description = "Missing the clients:write permission, or a role in the client's organization without it", content(("application/problem+json" = ProblemDetails), ("application/json" = ErrorResponse))),
+        (status = 404, description = "No such client in the caller's organizations", content(("application/problem+json" = ProblemDetails), ("application/json" = ErrorResponse))),
+    ),
+    security(
+        ("bearer_auth" = []),
+        ("api_key" = [])
+    )
+)]
+pub async fn update_consent(
+    State(state): State<AppState>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(id): Path<String>,
+    Json(payload): Json<ConsentUpdate>,
+) -> Result<Json<ClientResponse>, HandlerError> {
+    require_permission(&auth_user, permissions::CLIENTS_WRITE)?;
+    let client = find_member_client(&state, &auth_user, &id, permissions::CLIENTS_WRITE).await?;
+
+    let updated = state
+        .client_repository
+        .update_consent(client.id, payload.calculations, payload.sharing)
+        .await
+        .map_err(database_error)?;
+
+    Ok(Json(ClientResponse::from(updated)))
+}
+
+/// POST /api/v1/clients/:id/workflows/:workflow_id/execute -- run a workflow for a client
+#[utoipa::path(
+    post,
+    path = "/api/v1/clients/{id}/workflows/{workflow_id}/execute",
+    tag = "clients",
+    params(
+        ("id" = String, Path, description = "Client id"),
+        ("workflow_id" = String, Path, description = "Workflow identifier"),
+        ("save" = Option<bool>, Query, description = "Persist the result for the client; its id is returned in `X-Result-Id`"),
+        ("as_of" = Option<String>, Query, description = "Run every engine as of this RFC 3339 time or YYYY-MM-DD date"),
+        ("dry_run" = Option<bool>, Query, description = "Return the execution plan, a `WorkflowPlan`, without running any engine; `save` is ignored"),
+    ),
+    request_body(content = ClientWorkflowRequest, description = "Optional engine options"),
+    responses(
+        (status = 200, description = "Workflow executed with the client's birth data", body = WorkflowResult,
+            headers(("X-Result-Id" = String, description = "Id of the saved result, only with `save=true`"))),
+        (status = 401, description = "Unauthorized", content(("application/problem+json" = ProblemDetails), ("application/json" = ErrorResponse))),
+        (status = 403, description = "Missing clients:calculate or a role in the client's organization granting it, no calculation consent, insufficient consciousness phase, `options.debug` without admin:debug, or an API key not scoped to the workflow", content(("application/problem+json" = ProblemDetails), ("application/json" = ErrorResponse))),
+        (status = 404, description = "Client or workflow not found", content(("application/problem+json" = ProblemDetails), ("application/json" = ErrorResponse))),
+    ),
+    security(
+        ("bearer_auth" = []),
+        ("api_key" = [])
+    )
+)]
+pub async fn execute_client_workflow(
+    State(state): State<AppState>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path((id, workflow_id)): Path<(String, String)>,
+    Query(params): Query<WorkflowExecuteParams>,
+    version: ApiVersion,
+    payload: Option<Json<ClientWorkflowRequest>>,
+) -> Result<Response, HandlerError> {
+    require_permission(&auth_user, permissions::CLIENTS_CALCULATE)?;
+    require_workflow_scope(&auth_user, &workflow_id)?;
+    let client = find_member_client(&state, &auth_user, &id, permissions::CLIENTS_CALCULATE).await?;
+    if !client.consent_calculations {
+        return Err(consent_required(client.id, "calculations"));
+    }
+
+    let payload = payload.map(|Json(p)| p).unwrap_or_default();
+    let mut input = EngineInput {
+        birth_data: Some(client_birth_data(&client)),
+        secondary_birth_data: None,
+        subjects: Vec::new(),
+        current_time: Utc::now(),
+        location: Some(client_location(&client)),
+        precision: payload.precision.unwrap_or(Precision::Standard),
+        options: payload.options,
+    };
+    set_as_of(&mut input, params.as_of);
+
+    if params.dry_run {
+        return plan_workflow(&state, &auth_user, &workflow_id, input).await;
+    }
+    run_workflow(&state, &auth_user, version, &workflow_id, input, params.save, Some(client.id)).await
+}
+
+/// Birth data of a client profile
+pub(crate) fn client_birth_data(client: &Client) -> BirthData {
+    BirthData {
+        name: Some(client.full_name.clone()),
+        display_name: None,
+        date: client.birth_date.format("%Y-%m-%d").to_string(),
+        time: client.birth_time.map(|t| t.format("%H:%M").to_string()),
+        time_unknown: client.birth_time.is_none(),
+        latitude: client.birth_location_lat,
+        longitude: client.birth_location_lng,
+        altitude: None,
+        timezone: client.timezone.clone(),
+        place: None,
+        utc_offset_minutes: None,
+    }
+}
+
+/// A client's birth place, where their readings are taken
+pub(crate) fn client_location(client: &Client) -> Coordinates {
+    Coordinates {
+        latitude: client.birth_location_lat,
+        longitude: client.birth_location_lng,
+        altitude: None,
+        timezone: None,
+    }
+}
+
+/// 403 for a client who has not given `consent`
+pub(crate) fn consent_required(client_id: Uuid, consent: &str) -> HandlerError {
+    (
+        StatusCode::FORBIDDEN,
+        Json(ErrorResponse {
+            error: format!("Client has not consented to {}", consent),
+            error_code: "CONSENT_REQUIRED".to_string(),
+            details: Some(serde_json::json!({ "client_id": client_id, "consent": consent })),
+            request_id: None,
+        }),
+    )
+}
+
+/// The client `id`, if the caller's role in its organization grants `scope`.
+///
+/// Tokens carry the scopes of the caller's roles in every organization, so
+/// they are checked here against the role in the client's own.
+pub(crate) async fn find_member_client(
+    state: &AppState,
+    auth_user: &AuthUser,
+    id: &str,
+    scope: &str,
+) -> Result<Client, HandlerError> {
+    let caller_id = user_uuid(auth_user)?;
+    let client_not_found = || not_found("CLIENT_NOT_FOUND", format!("Client '{}' not found", id));
+    let client_id = Uuid::parse_str(id).map_err(|_| client_not_found())?;
+
+    let client = state
+        .client_repository
+        .get_client_for_member(client_id, caller_id)
+        .await
+        .map_err(database_error)?
+        .ok_or_else(client_not_found)?;
+    let role = state
+        .organization_repository
+        .get_member_role(client.organization_id, caller_id)
+        .await
+        .map_err(database_error)?;
+    require_role_scope(role.as_deref(), scope)?;
+    Ok(client)
+}
+
+/// 403 unless `role` grants `scope`
+fn require_role_scope(role: Option<&str>, scope: &str) -> Result<(), HandlerError> {
+    if role.is_some_and(|role| permissions::for_role(role).contains(&scope)) {
+        Ok(())
+    } else {
+        Err(permission_denied(scope))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn request() -> CreateClientRequest {
+        CreateClientRequest {
+            organization_id: Uuid::new_v4().to_string(),
+            full_name: "Asha".to_string(),
+            birth_date: NaiveDate::from_ymd_opt(1990, 1, 15).unwrap(),
+            birth_time: NaiveTime::from_hms_opt(14, 30, 0),
+            birth_location_lat: 12.97,
+            birth_location_lng: 77.59,
+            birth_location_name: None,
+            timezone: "Asia/Kolkata".to_string(),
+            consent: ConsentUpdate::default(),
+        }
+    }
+
+    #[test]
+    fn test_create_client_validation() {
+        assert!(request().validate().is_ok());
+
+        let invalid = CreateClientRequest {
+            full_name: " ".to_string(),
+            birth_location_lat: 95.0,
+            timezone: "Mars/Olympus".to_string(),
+            ..request()
+        };
+        match invalid.validate() {
+            Err(EngineError::InvalidInput(errors)) => assert_eq!(errors.len(), 3),
+            other => panic!("expected InvalidInput, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_role_scope_is_checked_per_organization() {
+        assert!(require_role_scope(Some(permissions::ROLE_PRACTITIONER), permissions::CLIENTS_CALCULATE).is_ok());
+        assert!(require_role_scope(Some(permissions::ROLE_ASSISTANT), permissions::CLIENTS_READ).is_ok());
+
+        // A practitioner elsewhere is still only an assistant in this organization
+        let (status, Json(body)) =
+            require_role_scope(Some(permissions::ROLE_ASSISTANT), permissions::CLIENTS_CALCULATE).unwrap_err();
+        assert_eq!(status, StatusCode::FORBIDDEN);
+        assert_eq!(body.details.unwrap()["required_permission"], permissions::CLIENTS_CALCULATE);
+        assert!(require_role_scope(None, permissions::CLIENTS_READ).is_err());
+    }
+}
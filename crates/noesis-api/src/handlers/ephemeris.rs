@@ -0,0 +1,323 @@
+use axum::extract::{Json, Query};
+use chrono::{DateTime, Datelike, Duration, NaiveDate, Utc};
+use engine_human_design::gate_transits::{self, GateIngress, DEFAULT_TRANSIT_PLANETS};
+use engine_human_design::stations::{self, RetrogradeStatus, Station};
+use engine_human_design::EphemerisCalculator;
+use engine_panchanga::Ayanamsa;
+use noesis_core::{EngineError, ValidationCode};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use super::HandlerError;
+use crate::engine_error_to_response;
+
+/// Years covered by the bundled Swiss Ephemeris data files
+const SUPPORTED_YEARS: std::ops::RangeInclusive<i32> = 1800..=2399;
+
+/// Window searched for stations when `to` is omitted
+const DEFAULT_STATION_WINDOW_DAYS: i64 = 90;
+
+/// Longest station window, which bounds the ephemeris work per request
+const MAX_STATION_WINDOW_DAYS: i64 = 732;
+
+/// Window searched for gate ingresses when `to` is omitted
+const DEFAULT_TRANSIT_WINDOW_DAYS: i64 = 30;
+
+/// Longest gate ingress window; with the Moon this is some 4,400 ingresses
+const MAX_TRANSIT_WINDOW_DAYS: i64 = 366;
+
+#[derive(Deserialize, ToSchema)]
+pub struct PositionsRequest {
+    /// Instant to calculate for, RFC 3339 or YYYY-MM-DD (midnight UTC)
+    #[schema(example = "2024-03-20T03:06:00Z")]
+    pub datetime: String,
+    /// Reference for `sidereal_longitude`: lahiri (default), raman, krishnamurti or fagan_bradley
+    #[schema(example = "lahiri")]
+    pub ayanamsa: Option<String>,
+}
+
+#[derive(Serialize, ToSchema)]
+pub struct PlanetPositionResponse {
+    /// Lowercase planet name, as in Human Design activation keys
+    #[schema(example = "sun")]
+    pub planet: String,
+    /// Tropical ecliptic longitude, 0-360 degrees
+    pub longitude: f64,
+    /// Longitude minus the ayanamsa, 0-360 degrees
+    pub sidereal_longitude: f64,
+    /// Ecliptic latitude in degrees
+    pub latitude: f64,
+    /// Distance from Earth in AU
+    pub distance: f64,
+    /// Longitude speed in degrees per day; negative while retrograde
+    pub speed: f64,
+    pub retrograde: bool,
+}
+
+#[derive(Serialize, ToSchema)]
+pub struct PositionsResponse {
+    pub datetime: DateTime<Utc>,
+    #[schema(example = 2460389.629)]
+    pub julian_day: f64,
+    #[schema(value_type = String, example = "lahiri")]
+    pub ayanamsa: Ayanamsa,
+    /// Degrees subtracted from every tropical longitude
+    pub ayanamsa_degrees: f64,
+    /// The 13 Human Design bodies: Sun, Earth, Moon, nodes, Mercury through Pluto
+    pub planets: Vec<PlanetPositionResponse>,
+}
+
+#[derive(Deserialize)]
+pub struct StationsParams {
+    from: Option<String>,
+    to: Option<String>,
+}
+
+#[derive(Serialize, ToSchema)]
+pub struct StationsResponse {
+    pub from: DateTime<Utc>,
+    pub to: DateTime<Utc>,
+    /// Status of Mercury through Pluto at `from`
+    #[schema(value_type = Vec<Object>)]
+    pub retrograde: Vec<RetrogradeStatus>,
+    /// Stations in the window, in time order
+    #[schema(value_type = Vec<Object>)]
+    pub stations: Vec<Station>,
+}
+
+#[derive(Deserialize)]
+pub struct GateTransitsParams {
+    from: Option<String>,
+    to: Option<String>,
+    planets: Option<String>,
+}
+
+#[derive(Serialize, ToSchema)]
+pub struct GateTransitsResponse {
+    pub from: DateTime<Utc>,
+    pub to: DateTime<Utc>,
+    /// Lowercase planet names searched
+    #[schema(example = json!(["sun", "earth"]))]
+    pub planets: Vec<String>,
+    /// Ingresses in the window, in time order
+    #[schema(value_type = Vec<Object>)]
+    pub ingresses: Vec<GateIngress>,
+}
+
+/// POST /api/v1/ephemeris/positions -- raw geocentric positions at an instant
+///
+/// The positions Human Design charts are built from, without the chart: for
+/// engines and partner apps that do their own interpretation.
+#[utoipa::path(
+    post,
+    path = "/api/v1/ephemeris/positions",
+    tag = "ephemeris",
+    request_body = PositionsRequest,
+    responses(
+        (status = 200, description = "Positions of all 13 bodies", body = PositionsResponse),
+        (status = 401, description = "Unauthorized", content(("application/problem+json" = ProblemDetails), ("application/json" = ErrorResponse))),
+        (status = 422, description = "Malformed or out-of-range datetime, or unknown ayanamsa", content(("application/problem+json" = ProblemDetails), ("application/json" = ErrorResponse))),
+    ),
+    security(
+        ("bearer_auth" = []),
+        ("api_key" = [])
+    )
+)]
+pub async fn calculate_positions(
+    Json(payload): Json<PositionsRequest>,
+) -> Result<Json<PositionsResponse>, HandlerError> {
+    let datetime = parse_datetime("datetime", &payload.datetime).map_err(engine_error_to_response)?;
+    let ayanamsa = parse_ayanamsa(payload.ayanamsa.as_deref()).map_err(engine_error_to_response)?;
+
+    let calculator = EphemerisCalculator::new("");
+    let julian_day = EphemerisCalculator::julian_day(&datetime);
+    let ayanamsa_degrees = ayanamsa.degrees_at(julian_day);
+    let planets = calculator
+        .get_all_planets(&datetime)
+        .map_err(engine_error_to_response)?
+        .into_iter()
+        .map(|(planet, position)| PlanetPositionResponse {
+            planet: stations::planet_name(planet),
+            longitude: position.longitude,
+            sidereal_longitude: (position.longitude - ayanamsa_degrees).rem_euclid(360.0),
+            latitude: position.latitude,
+            distance: position.distance,
+            speed: position.speed,
+            retrograde: position.speed < 0.0,
+        })
+        .collect();
+
+    Ok(Json(PositionsResponse {
+        datetime,
+        julian_day,
+        ayanamsa,
+        ayanamsa_degrees,
+        planets,
+    }))
+}
+
+/// GET /api/v1/ephemeris/stations -- retrograde status and upcoming stations
+#[utoipa::path(
+    get,
+    path = "/api/v1/ephemeris/stations",
+    tag = "ephemeris",
+    params(
+        ("from" = Option<String>, Query, description = "Window start, RFC 3339 or YYYY-MM-DD (midnight UTC); defaults to now"),
+        ("to" = Option<String>, Query, description = "Window end; defaults to 90 days after `from`, at most 732 days after it"),
+    ),
+    responses(
+        (status = 200, description = "Retrograde status at `from` and stations in the window", body = StationsResponse),
+        (status = 401, description = "Unauthorized", content(("application/problem+json" = ProblemDetails), ("application/json" = ErrorResponse))),
+        (status = 422, description = "Malformed bounds, `to` not after `from`, or window too long", content(("application/problem+json" = ProblemDetails), ("application/json" = ErrorResponse))),
+    ),
+    security(
+        ("bearer_auth" = []),
+        ("api_key" = [])
+    )
+)]
+pub async fn list_stations(
+    Query(params): Query<StationsParams>,
+) -> Result<Json<StationsResponse>, HandlerError> {
+    let from = match params.from.as_deref() {
+        Some(value) => parse_datetime("from", value).map_err(engine_error_to_response)?,
+        None => Utc::now(),
+    };
+    let to = match params.to.as_deref() {
+        Some(value) => parse_datetime("to", value).map_err(engine_error_to_response)?,
+        None => from + Duration::days(DEFAULT_STATION_WINDOW_DAYS),
+    };
+    if to <= from || to - from > Duration::days(MAX_STATION_WINDOW_DAYS) {
+        return Err(engine_error_to_response(EngineError::invalid_field(
+            "to",
+            ValidationCode::OutOfRange,
+            format!("'to' must be after 'from' and at most {} days later", MAX_STATION_WINDOW_DAYS),
+        )));
+    }
+
+    let calculator = EphemerisCalculator::new("");
+    let retrograde = stations::retrograde_status(&calculator, &from).map_err(engine_error_to_response)?;
+    let stations = stations::find_stations(&calculator, &from, &to).map_err(engine_error_to_response)?;
+
+    Ok(Json(StationsResponse {
+        from,
+        to,
+        retrograde,
+        stations,
+    }))
+}
+
+/// GET /api/v1/ephemeris/gate-transits -- upcoming gate ingresses
+///
+/// When each planet enters its next Human Design gate, e.g. the Sun entering
+/// Gate 17 at the March equinox, for transit forecasts and calendars.
+#[utoipa::path(
+    get,
+    path = "/api/v1/ephemeris/gate-transits",
+    tag = "ephemeris",
+    params(
+        ("from" = Option<String>, Query, description = "Window start, RFC 3339 or YYYY-MM-DD (midnight UTC); defaults to now"),
+        ("to" = Option<String>, Query, description = "Window end; defaults to 30 days after `from`, at most 366 days after it"),
+        ("planets" = Option<String>, Query, description = "Comma-separated lowercase planet names, as in `/ephemeris/positions`; defaults to `sun,earth`"),
+    ),
+    responses(
+        (status = 200, description = "Gate ingresses in the window", body = GateTransitsResponse),
+        (status = 401, description = "Unauthorized", content(("application/problem+json" = ProblemDetails), ("application/json" = ErrorResponse))),
+        (status = 422, description = "Malformed bounds, `to` not after `from`, window too long, or unknown planet", content(("application/problem+json" = ProblemDetails), ("application/json" = ErrorResponse))),
+    ),
+    security(
+        ("bearer_auth" = []),
+        ("api_key" = [])
+    )
+)]
+pub async fn list_gate_transits(
+    Query(params): Query<GateTransitsParams>,
+) -> Result<Json<GateTransitsResponse>, HandlerError> {
+    let from = match params.from.as_deref() {
+        Some(value) => parse_datetime("from", value).map_err(engine_error_to_response)?,
+        None => Utc::now(),
+    };
+    let to = match params.to.as_deref() {
+        Some(value) => parse_datetime("to", value).map_err(engine_error_to_response)?,
+        None => from + Duration::days(DEFAULT_TRANSIT_WINDOW_DAYS),
+    };
+    if to <= from || to - from > Duration::days(MAX_TRANSIT_WINDOW_DAYS) {
+        return Err(engine_error_to_response(EngineError::invalid_field(
+            "to",
+            ValidationCode::OutOfRange,
+            format!("'to' must be after 'from' and at most {} days later", MAX_TRANSIT_WINDOW_DAYS),
+        )));
+    }
+    let planets = match params.planets.as_deref() {
+        Some(names) => names
+            .split(',')
+            .map(|name| {
+                gate_transits::parse_planet(name).ok_or_else(|| {
+                    EngineError::invalid_field(
+                        "planets",
+                        ValidationCode::Unknown,
+                        format!("Unknown planet '{}'", name.trim()),
+                    )
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(engine_error_to_response)?,
+        None => DEFAULT_TRANSIT_PLANETS.to_vec(),
+    };
+
+    let calculator = EphemerisCalculator::new("");
+    let ingresses = gate_transits::find_gate_ingresses(&calculator, &planets, &from, &to)
+        .map_err(engine_error_to_response)?;
+
+    Ok(Json(GateTransitsResponse {
+        from,
+        to,
+        planets: planets.into_iter().map(stations::planet_name).collect(),
+        ingresses,
+    }))
+}
+
+/// Parse an RFC 3339 timestamp, or a YYYY-MM-DD date as midnight UTC
+fn parse_datetime(field: &str, value: &str) -> Result<DateTime<Utc>, EngineError> {
+    let datetime = DateTime::parse_from_rfc3339(value)
+        .map(|dt| dt.with_timezone(&Utc))
+        .or_else(|_| {
+            NaiveDate::parse_from_str(value, "%Y-%m-%d")
+                .map(|date| date.and_time(chrono::NaiveTime::MIN).and_utc())
+        })
+        .map_err(|_| {
+            EngineError::invalid_field(
+                field,
+                ValidationCode::InvalidFormat,
+                format!("'{}' is not an RFC 3339 timestamp or YYYY-MM-DD date", value),
+            )
+        })?;
+    if !SUPPORTED_YEARS.contains(&datetime.year()) {
+        return Err(EngineError::invalid_field(
+            field,
+            ValidationCode::OutOfRange,
+            format!(
+                "Year {} is outside the ephemeris range {}-{}",
+                datetime.year(),
+                SUPPORTED_YEARS.start(),
+                SUPPORTED_YEARS.end()
+            ),
+        ));
+    }
+    Ok(datetime)
+}
+
+/// Sidereal positions need an ayanamsa, so tropical is not accepted here
+fn parse_ayanamsa(value: Option<&str>) -> Result<Ayanamsa, EngineError> {
+    match value.map(Ayanamsa::parse) {
+        None => Ok(Ayanamsa::Lahiri),
+        Some(Some(ayanamsa)) if ayanamsa != Ayanamsa::Tropical => Ok(ayanamsa),
+        Some(_) => Err(EngineError::invalid_field(
+            "ayanamsa",
+            ValidationCode::Unknown,
+            format!(
+                "Unknown ayanamsa '{}'. Expected lahiri, raman, krishnamurti or fagan_bradley.",
+                value.unwrap_or_default()
+            ),
+        )),
+    }
+}
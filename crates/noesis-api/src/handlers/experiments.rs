@@ -0,0 +1,250 @@
+use axum::{
+    extract::{Extension, Json, Path, State},
+    http::StatusCode,
+    response::{IntoResponse, Response},
+};
+use noesis_auth::{AuthService, AuthUser};
+use noesis_core::{EngineError, ValidationCode, ValidationError};
+use noesis_orchestrator::experiments::{self, Experiment, Variant};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use super::{not_found, HandlerError};
+use crate::{engine_error_to_response, permission_denied, AppState};
+
+/// Permission required for `/api/v1/admin/experiments`
+const EXPERIMENTS_PERMISSION: &str = "admin:experiments";
+
+/// Outcomes the orchestrator records itself for every exposed calculation
+const RESERVED_OUTCOMES: [&str; 2] = ["success", "error"];
+
+#[derive(Deserialize, ToSchema)]
+pub struct ExperimentRequest {
+    #[schema(example = "panchanga")]
+    pub engine_id: String,
+    /// Defaults to true; false pauses assignment without losing the definition
+    #[serde(default = "default_enabled")]
+    pub enabled: bool,
+    pub variants: Vec<Variant>,
+}
+
+fn default_enabled() -> bool {
+    true
+}
+
+#[derive(Serialize, ToSchema)]
+pub struct ExperimentListResponse {
+    pub experiments: Vec<Experiment>,
+}
+
+#[derive(Deserialize, ToSchema)]
+pub struct ExperimentOutcomeRequest {
+    /// 1-64 characters of a-z, 0-9, '_' or '-'; "success" and "error" are reserved
+    #[schema(example = "prompt_answered")]
+    pub outcome: String,
+}
+
+#[derive(Serialize, ToSchema)]
+pub struct ExperimentOutcomeResponse {
+    pub experiment_id: String,
+    /// Variant the caller is assigned to, which the outcome was counted against
+    pub variant: String,
+    pub outcome: String,
+}
+
+/// GET /api/v1/admin/experiments -- all experiments, running or paused
+#[utoipa::path(
+    get,
+    path = "/api/v1/admin/experiments",
+    tag = "admin",
+    responses(
+        (status = 200, description = "Experiments ordered by id", body = ExperimentListResponse),
+        (status = 401, description = "Unauthorized", content(("application/problem+json" = ProblemDetails), ("application/json" = ErrorResponse))),
+        (status = 403, description = "Missing admin:experiments permission", content(("application/problem+json" = ProblemDetails), ("application/json" = ErrorResponse))),
+    ),
+    security(
+        ("bearer_auth" = []),
+        ("api_key" = [])
+    )
+)]
+pub async fn list_experiments(
+    State(state): State<AppState>,
+    Extension(auth_user): Extension<AuthUser>,
+) -> Result<Json<ExperimentListResponse>, HandlerError> {
+    require_admin(&auth_user)?;
+    Ok(Json(ExperimentListResponse {
+        experiments: state.core.orchestrator.experiments().list(),
+    }))
+}
+
+/// PUT /api/v1/admin/experiments/:id -- create or replace an experiment
+///
+/// Takes effect on the next request served by this process; other replicas
+/// need the same request. Changing weights only reassigns the users
+/// whose bucket crosses a variant boundary.
+#[utoipa::path(
+    put,
+    path = "/api/v1/admin/experiments/{id}",
+    tag = "admin",
+    params(("id" = String, Path, description = "Experiment id, also the `experiment` metric label")),
+    request_body = ExperimentRequest,
+    responses(
+        (status = 200, description = "Experiment replaced", body = Experiment),
+        (status = 201, description = "Experiment created", body = Experiment),
+        (status = 401, description = "Unauthorized", content(("application/problem+json" = ProblemDetails), ("application/json" = ErrorResponse))),
+        (status = 403, description = "Missing admin:experiments permission", content(("application/problem+json" = ProblemDetails), ("application/json" = ErrorResponse))),
+        (status = 404, description = "Engine not registered", content(("application/problem+json" = ProblemDetails), ("application/json" = ErrorResponse))),
+        (status = 422, description = "Invalid or reserved id, variant names or weights", content(("application/problem+json" = ProblemDetails), ("application/json" = ErrorResponse))),
+    ),
+    security(
+        ("bearer_auth" = []),
+        ("api_key" = [])
+    )
+)]
+pub async fn put_experiment(
+    State(state): State<AppState>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(id): Path<String>,
+    Json(payload): Json<ExperimentRequest>,
+) -> Result<Response, HandlerError> {
+    require_admin(&auth_user)?;
+    if id == noesis_witness::packs::EXPERIMENT_ID {
+        return Err(engine_error_to_response(EngineError::invalid_field(
+            "id",
+            ValidationCode::Duplicate,
+            format!("'{}' is reserved for witness prompt packs", id),
+        )));
+    }
+    if state.core.orchestrator.registry().get(&payload.engine_id).is_none() {
+        return Err(engine_error_to_response(EngineError::EngineNotFound(payload.engine_id)));
+    }
+
+    let experiment = Experiment {
+        id,
+        engine_id: payload.engine_id,
+        enabled: payload.enabled,
+        variants: payload.variants,
+    };
+    let replaced = state
+        .core
+        .orchestrator
+        .experiments()
+        .upsert(experiment.clone())
+        .map_err(engine_error_to_response)?;
+
+    tracing::info!(
+        experiment = %experiment.id,
+        engine_id = %experiment.engine_id,
+        enabled = experiment.enabled,
+        user_id = %auth_user.user_id,
+        "Experiment updated"
+    );
+    let status = if replaced.is_some() { StatusCode::OK } else { StatusCode::CREATED };
+    Ok((status, Json(experiment)).into_response())
+}
+
+/// DELETE /api/v1/admin/experiments/:id -- stop an experiment
+#[utoipa::path(
+    delete,
+    path = "/api/v1/admin/experiments/{id}",
+    tag = "admin",
+    params(("id" = String, Path, description = "Experiment id")),
+    responses(
+        (status = 204, description = "Experiment removed; users get default engine options again"),
+        (status = 401, description = "Unauthorized", content(("application/problem+json" = ProblemDetails), ("application/json" = ErrorResponse))),
+        (status = 403, description = "Missing admin:experiments permission", content(("application/problem+json" = ProblemDetails), ("application/json" = ErrorResponse))),
+        (status = 404, description = "No such experiment", content(("application/problem+json" = ProblemDetails), ("application/json" = ErrorResponse))),
+    ),
+    security(
+        ("bearer_auth" = []),
+        ("api_key" = [])
+    )
+)]
+pub async fn delete_experiment(
+    State(state): State<AppState>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(id): Path<String>,
+) -> Result<StatusCode, HandlerError> {
+    require_admin(&auth_user)?;
+    state
+        .core
+        .orchestrator
+        .experiments()
+        .remove(&id)
+        .ok_or_else(|| experiment_not_found(&id))?;
+
+    tracing::info!(experiment = %id, user_id = %auth_user.user_id, "Experiment removed");
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// POST /api/v1/experiments/:id/outcomes -- report an outcome for the caller's variant
+///
+/// For outcomes only the client observes, such as a user answering a witness
+/// prompt. The variant is derived from the caller's user id, so clients never
+/// need to know which arm they are in. The `witness-pack` experiment counts
+/// outcomes against the caller's witness prompt pack.
+#[utoipa::path(
+    post,
+    path = "/api/v1/experiments/{id}/outcomes",
+    tag = "engines",
+    params(("id" = String, Path, description = "Experiment id")),
+    request_body = ExperimentOutcomeRequest,
+    responses(
+        (status = 202, description = "Outcome recorded", body = ExperimentOutcomeResponse),
+        (status = 401, description = "Unauthorized", content(("application/problem+json" = ProblemDetails), ("application/json" = ErrorResponse))),
+        (status = 404, description = "No such experiment, or it is paused", content(("application/problem+json" = ProblemDetails), ("application/json" = ErrorResponse))),
+        (status = 422, description = "Invalid or reserved outcome name", content(("application/problem+json" = ProblemDetails), ("application/json" = ErrorResponse))),
+    ),
+    security(
+        ("bearer_auth" = []),
+        ("api_key" = [])
+    )
+)]
+pub async fn record_outcome(
+    State(state): State<AppState>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(id): Path<String>,
+    Json(payload): Json<ExperimentOutcomeRequest>,
+) -> Result<Response, HandlerError> {
+    if !experiments::is_valid_label(&payload.outcome) || RESERVED_OUTCOMES.contains(&payload.outcome.as_str()) {
+        return Err(engine_error_to_response(EngineError::InvalidInput(vec![ValidationError::new(
+            "outcome",
+            ValidationCode::InvalidFormat,
+            format!(
+                "Outcome must be 1-{} characters of a-z, 0-9, '_' or '-' and not 'success' or 'error'",
+                experiments::MAX_LABEL_LEN
+            ),
+        )])));
+    }
+
+    let orchestrator = &state.core.orchestrator;
+    let variant = if id == noesis_witness::packs::EXPERIMENT_ID {
+        orchestrator.witness_packs().assign(Some(&auth_user.user_id))
+    } else {
+        orchestrator
+            .experiments()
+            .assignment(&id, &auth_user.user_id)
+            .map(|assignment| assignment.variant)
+    };
+    let variant = variant.ok_or_else(|| experiment_not_found(&id))?;
+    noesis_metrics::record_experiment_outcome(&id, &variant, &payload.outcome);
+
+    let response = ExperimentOutcomeResponse {
+        experiment_id: id,
+        variant,
+        outcome: payload.outcome,
+    };
+    Ok((StatusCode::ACCEPTED, Json(response)).into_response())
+}
+
+fn require_admin(auth_user: &AuthUser) -> Result<(), HandlerError> {
+    if AuthService::can_access_endpoint(auth_user, "/api/v1/admin/experiments") {
+        Ok(())
+    } else {
+        Err(permission_denied(EXPERIMENTS_PERMISSION))
+    }
+}
+
+fn experiment_not_found(id: &str) -> HandlerError {
+    not_found("EXPERIMENT_NOT_FOUND", format!("Experiment '{}' not found", id))
+}
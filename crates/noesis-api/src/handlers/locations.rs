@@ -0,0 +1,324 @@
+use axum::{
+    extract::{Extension, Json, Path, State},
+    http::StatusCode,
+    response::{IntoResponse, Response},
+};
+use chrono::{DateTime, Utc};
+use noesis_auth::AuthUser;
+use noesis_core::{Coordinates, EngineError, EngineInput, ValidationCode, ValidationError};
+use noesis_data::models::location::{NewSavedLocation, SavedLocation};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use super::{database_error, not_found, user_uuid, HandlerError};
+use crate::{engine_error_to_response, geo, AppState};
+
+/// `?location=` value that picks the saved location closest to `current_location`
+pub const NEAREST_LOCATION: &str = "nearest";
+
+/// Farthest a saved location may be from `current_location` to be picked
+/// as the nearest; farther away, the sent coordinates are used as they are
+pub const NEAREST_LOCATION_MAX_KM: f64 = 50.0;
+
+#[derive(Deserialize, ToSchema)]
+pub struct CreateSavedLocationRequest {
+    #[schema(example = "Lisbon office")]
+    pub name: String,
+    #[schema(example = 38.7223)]
+    pub latitude: f64,
+    #[schema(example = -9.1393)]
+    pub longitude: f64,
+    /// IANA timezone; resolved from the coordinates when omitted
+    #[schema(example = "Europe/Lisbon")]
+    pub timezone: Option<String>,
+}
+
+#[derive(Serialize, ToSchema)]
+pub struct SavedLocationResponse {
+    pub id: String,
+    pub name: String,
+    pub latitude: f64,
+    pub longitude: f64,
+    pub timezone: String,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Serialize, ToSchema)]
+pub struct SavedLocationListResponse {
+    pub locations: Vec<SavedLocationResponse>,
+}
+
+impl From<SavedLocation> for SavedLocationResponse {
+    fn from(location: SavedLocation) -> Self {
+        Self {
+            id: location.id.to_string(),
+            name: location.name,
+            latitude: location.latitude,
+            longitude: location.longitude,
+            timezone: location.timezone,
+            created_at: location.created_at,
+        }
+    }
+}
+
+impl CreateSavedLocationRequest {
+    fn validate(&self) -> Result<(), EngineError> {
+        let mut errors = Vec::new();
+        if self.name.trim().is_empty() {
+            errors.push(ValidationError::new("name", ValidationCode::Empty, "Location name cannot be empty"));
+        }
+        if !(-90.0..=90.0).contains(&self.latitude) {
+            errors.push(ValidationError::new("latitude", ValidationCode::OutOfRange, "Latitude must be between -90 and 90"));
+        }
+        if !(-180.0..=180.0).contains(&self.longitude) {
+            errors.push(ValidationError::new("longitude", ValidationCode::OutOfRange, "Longitude must be between -180 and 180"));
+        }
+        if let Some(tz) = &self.timezone {
+            if !noesis_core::timezone::is_known_timezone(tz.trim()) {
+                errors.push(ValidationError::new("timezone", ValidationCode::Unknown, format!("Unknown timezone '{}'", tz)));
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(EngineError::InvalidInput(errors))
+        }
+    }
+}
+
+/// GET /api/v1/users/me/locations -- places saved by the authenticated user
+#[utoipa::path(
+    get,
+    path = "/api/v1/users/me/locations",
+    tag = "users",
+    responses(
+        (status = 200, description = "Saved locations, oldest first", body = SavedLocationListResponse),
+        (status = 401, description = "Unauthorized", content(("application/problem+json" = ProblemDetails), ("application/json" = ErrorResponse))),
+    ),
+    security(
+        ("bearer_auth" = []),
+        ("api_key" = [])
+    )
+)]
+pub async fn list_locations(
+    State(state): State<AppState>,
+    Extension(auth_user): Extension<AuthUser>,
+) -> Result<Json<SavedLocationListResponse>, HandlerError> {
+    let locations = state
+        .location_repository
+        .list_for_user(user_uuid(&auth_user)?)
+        .await
+        .map_err(database_error)?;
+
+    Ok(Json(SavedLocationListResponse {
+        locations: locations.into_iter().map(SavedLocationResponse::from).collect(),
+    }))
+}
+
+/// POST /api/v1/users/me/locations -- save a place to read the daily practice from
+#[utoipa::path(
+    post,
+    path = "/api/v1/users/me/locations",
+    tag = "users",
+    request_body = CreateSavedLocationRequest,
+    responses(
+        (status = 201, description = "Location saved", body = SavedLocationResponse),
+        (status = 401, description = "Unauthorized", content(("application/problem+json" = ProblemDetails), ("application/json" = ErrorResponse))),
+        (status = 422, description = "Validation error", content(("application/problem+json" = ProblemDetails), ("application/json" = ErrorResponse))),
+    ),
+    security(
+        ("bearer_auth" = []),
+        ("api_key" = [])
+    )
+)]
+pub async fn create_location(
+    State(state): State<AppState>,
+    Extension(auth_user): Extension<AuthUser>,
+    Json(payload): Json<CreateSavedLocationRequest>,
+) -> Result<Response, HandlerError> {
+    payload.validate().map_err(engine_error_to_response)?;
+
+    let timezone = match payload.timezone.as_deref().map(str::trim) {
+        Some(tz) if !tz.is_empty() => tz.to_string(),
+        _ => geo::timezone_for(payload.latitude, payload.longitude),
+    };
+    let location = state
+        .location_repository
+        .create_location(
+            user_uuid(&auth_user)?,
+            &NewSavedLocation {
+                name: payload.name.trim().to_string(),
+                latitude: payload.latitude,
+                longitude: payload.longitude,
+                timezone,
+            },
+        )
+        .await
+        .map_err(database_error)?;
+
+    Ok((StatusCode::CREATED, Json(SavedLocationResponse::from(location))).into_response())
+}
+
+/// DELETE /api/v1/users/me/locations/:id -- remove a saved location
+#[utoipa::path(
+    delete,
+    path = "/api/v1/users/me/locations/{id}",
+    tag = "users",
+    params(("id" = String, Path, description = "Saved location id")),
+    responses(
+        (status = 204, description = "Location removed"),
+        (status = 401, description = "Unauthorized", content(("application/problem+json" = ProblemDetails), ("application/json" = ErrorResponse))),
+        (status = 404, description = "No location with this id belongs to the user", content(("application/problem+json" = ProblemDetails), ("application/json" = ErrorResponse))),
+    ),
+    security(
+        ("bearer_auth" = []),
+        ("api_key" = [])
+    )
+)]
+pub async fn delete_location(
+    State(state): State<AppState>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(id): Path<String>,
+) -> Result<StatusCode, HandlerError> {
+    let location_id = Uuid::parse_str(&id).map_err(|_| location_not_found(&id))?;
+    let deleted = state
+        .location_repository
+        .delete_for_user(location_id, user_uuid(&auth_user)?)
+        .await
+        .map_err(database_error)?;
+
+    if deleted {
+        Ok(StatusCode::NO_CONTENT)
+    } else {
+        Err(location_not_found(&id))
+    }
+}
+
+/// Replace `input.location` with one of the user's saved locations.
+///
+/// `selector` is a saved location id, or [`NEAREST_LOCATION`] for the saved
+/// location closest to the coordinates sent as `current_location` (for
+/// instance a device's position). A nearest pick farther away than
+/// [`NEAREST_LOCATION_MAX_KM`], or a user without saved locations, keeps the
+/// sent coordinates.
+pub(crate) async fn select_saved_location(
+    state: &AppState,
+    auth_user: &AuthUser,
+    input: &mut EngineInput,
+    selector: &str,
+) -> Result<(), HandlerError> {
+    state.database.ensure_available()?;
+    let user_id = user_uuid(auth_user)?;
+
+    let selected = if selector == NEAREST_LOCATION {
+        let Some(current) = input.location.as_ref() else {
+            return Err(engine_error_to_response(EngineError::invalid_field(
+                "location",
+                ValidationCode::Required,
+                "location=nearest needs the current coordinates in current_location",
+            )));
+        };
+        let locations = state.location_repository.list_for_user(user_id).await.map_err(database_error)?;
+        nearest_location(&locations, current.latitude, current.longitude)
+            .filter(|(_, distance_km)| *distance_km <= NEAREST_LOCATION_MAX_KM)
+            .map(|(location, _)| location.clone())
+    } else {
+        let location_id = Uuid::parse_str(selector).map_err(|_| location_not_found(selector))?;
+        let location = state
+            .location_repository
+            .get_for_user(location_id, user_id)
+            .await
+            .map_err(database_error)?
+            .ok_or_else(|| location_not_found(selector))?;
+        Some(location)
+    };
+
+    if let Some(location) = selected {
+        tracing::debug!(location_id = %location.id, selector, "Using saved location");
+        input.location = Some(Coordinates {
+            latitude: location.latitude,
+            longitude: location.longitude,
+            altitude: input.location.as_ref().and_then(|current| current.altitude),
+            timezone: Some(location.timezone),
+        });
+    }
+    Ok(())
+}
+
+/// The location closest to a point, with its great-circle distance in km
+fn nearest_location(locations: &[SavedLocation], latitude: f64, longitude: f64) -> Option<(&SavedLocation, f64)> {
+    locations
+        .iter()
+        .map(|location| (location, distance_km(latitude, longitude, location.latitude, location.longitude)))
+        .min_by(|a, b| a.1.total_cmp(&b.1))
+}
+
+/// Haversine distance between two points in decimal degrees
+fn distance_km(lat1: f64, lng1: f64, lat2: f64, lng2: f64) -> f64 {
+    const EARTH_RADIUS_KM: f64 = 6371.0;
+    let (phi1, phi2) = (lat1.to_radians(), lat2.to_radians());
+    let d_phi = (lat2 - lat1).to_radians();
+    let d_lambda = (lng2 - lng1).to_radians();
+    let a = (d_phi / 2.0).sin().powi(2) + phi1.cos() * phi2.cos() * (d_lambda / 2.0).sin().powi(2);
+    2.0 * EARTH_RADIUS_KM * a.sqrt().asin()
+}
+
+fn location_not_found(id: &str) -> HandlerError {
+    not_found("LOCATION_NOT_FOUND", format!("Saved location '{}' not found", id))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn saved(name: &str, latitude: f64, longitude: f64) -> SavedLocation {
+        SavedLocation {
+            id: Uuid::new_v4(),
+            user_id: Uuid::nil(),
+            name: name.to_string(),
+            latitude,
+            longitude,
+            timezone: "UTC".to_string(),
+            created_at: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn test_nearest_location() {
+        let locations = [saved("Lisbon", 38.7223, -9.1393), saved("Porto", 41.1579, -8.6291), saved("Berlin", 52.52, 13.405)];
+        // Sintra is about 25 km from Lisbon
+        let (nearest, distance) = nearest_location(&locations, 38.8029, -9.3817).unwrap();
+        assert_eq!(nearest.name, "Lisbon");
+        assert!((20.0..30.0).contains(&distance), "distance = {distance}");
+
+        let (nearest, distance) = nearest_location(&locations, 52.37, 4.90).unwrap();
+        assert_eq!(nearest.name, "Berlin");
+        assert!(distance > NEAREST_LOCATION_MAX_KM);
+
+        assert!(nearest_location(&[], 0.0, 0.0).is_none());
+    }
+
+    #[test]
+    fn test_create_location_validation() {
+        let request = CreateSavedLocationRequest {
+            name: "Home".to_string(),
+            latitude: 38.72,
+            longitude: -9.14,
+            timezone: None,
+        };
+        assert!(request.validate().is_ok());
+
+        let invalid = CreateSavedLocationRequest {
+            name: " ".to_string(),
+            latitude: 95.0,
+            timezone: Some("Mars/Olympus".to_string()),
+            ..request
+        };
+        match invalid.validate() {
+            Err(EngineError::InvalidInput(errors)) => assert_eq!(errors.len(), 3),
+            other => panic!("expected InvalidInput, got {:?}", other),
+        }
+    }
+}
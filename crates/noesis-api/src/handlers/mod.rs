@@ -1,2 +1,53 @@
+//! HTTP handlers grouped by resource
+
+use axum::{http::StatusCode, Json};
+use noesis_auth::AuthUser;
+use noesis_core::EngineError;
+use uuid::Uuid;
+
+use crate::{engine_error_to_response, ErrorResponse};
+
+pub mod analytics;
+pub mod api_keys;
 pub mod auth;
+pub mod cache;
+pub mod clients;
+pub mod ephemeris;
+pub mod experiments;
+pub mod locations;
+pub mod notifications;
+pub mod offline;
+pub mod organizations;
+pub mod planner;
+pub mod practices;
+pub mod recalculate;
+pub mod research_export;
+pub mod results;
+pub mod settings;
 pub mod users;
+pub mod wisdom;
+pub mod workflows;
+
+pub(crate) type HandlerError = (StatusCode, Json<ErrorResponse>);
+
+pub(crate) fn user_uuid(auth_user: &AuthUser) -> Result<Uuid, HandlerError> {
+    Uuid::parse_str(&auth_user.user_id).map_err(|_| {
+        engine_error_to_response(EngineError::AuthError("Invalid user ID in token".to_string()))
+    })
+}
+
+pub(crate) fn not_found(error_code: &str, error: String) -> HandlerError {
+    (
+        StatusCode::NOT_FOUND,
+        Json(ErrorResponse {
+            error,
+            error_code: error_code.to_string(),
+            details: None,
+            request_id: None,
+        }),
+    )
+}
+
+pub(crate) fn database_error(e: sqlx::Error) -> HandlerError {
+    engine_error_to_response(EngineError::InternalError(format!("Database error: {}", e)))
+}
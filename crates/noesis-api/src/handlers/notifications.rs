@@ -0,0 +1,311 @@
+use axum::{
+    extract::{Extension, Json, Path, State},
+    http::StatusCode,
+    response::{IntoResponse, Response},
+};
+use chrono::{DateTime, Utc};
+use noesis_auth::AuthUser;
+use noesis_core::{EngineError, ValidationCode, ValidationError};
+use noesis_data::models::notification::{NewNotificationRule, NotificationRule};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use super::{database_error, not_found, user_uuid, HandlerError};
+use crate::notifications::{NotificationCategory, NotificationChannel, NotificationResponse, MAX_LEAD_DAYS};
+use crate::precompute::birth_data_from_profile;
+use crate::{engine_error_to_response, AppState};
+
+/// Notifications returned by the inbox, newest first
+const INBOX_LIMIT: i64 = 50;
+
+#[derive(Deserialize, ToSchema)]
+pub struct CreateNotificationRuleRequest {
+    pub category: NotificationCategory,
+    /// Days before the event to send the notification, 0 to 30 (default 0,
+    /// on the day)
+    #[schema(example = 1)]
+    pub lead_days: Option<i16>,
+    /// Default `in_app`
+    pub channel: Option<NotificationChannel>,
+    /// HTTPS endpoint the notification is posted to; required for `webhook`
+    #[schema(example = "https://example.com/hooks/noesis")]
+    pub webhook_url: Option<String>,
+}
+
+#[derive(Serialize, ToSchema)]
+pub struct NotificationRuleResponse {
+    pub id: String,
+    #[schema(example = "ekadashi")]
+    pub category: String,
+    pub lead_days: i16,
+    #[schema(example = "in_app")]
+    pub channel: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub webhook_url: Option<String>,
+    pub enabled: bool,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Serialize, ToSchema)]
+pub struct NotificationRuleListResponse {
+    pub rules: Vec<NotificationRuleResponse>,
+}
+
+#[derive(Serialize, ToSchema)]
+pub struct NotificationListResponse {
+    /// In-app notifications, newest first, at most 50
+    pub notifications: Vec<NotificationResponse>,
+}
+
+impl From<NotificationRule> for NotificationRuleResponse {
+    fn from(rule: NotificationRule) -> Self {
+        Self {
+            id: rule.id.to_string(),
+            category: rule.category,
+            lead_days: rule.lead_days,
+            channel: rule.channel,
+            webhook_url: rule.webhook_url,
+            enabled: rule.enabled,
+            created_at: rule.created_at,
+        }
+    }
+}
+
+impl CreateNotificationRuleRequest {
+    fn validate(&self) -> Result<NewNotificationRule, EngineError> {
+        let mut errors = Vec::new();
+        let lead_days = self.lead_days.unwrap_or(0);
+        if !(0..=MAX_LEAD_DAYS).contains(&lead_days) {
+            errors.push(ValidationError::new(
+                "lead_days",
+                ValidationCode::OutOfRange,
+                format!("Lead time must be between 0 and {} days", MAX_LEAD_DAYS),
+            ));
+        }
+        let channel = self.channel.unwrap_or(NotificationChannel::InApp);
+        let webhook_url = self.webhook_url.as_deref().map(str::trim).filter(|url| !url.is_empty());
+        match (channel, webhook_url) {
+            (NotificationChannel::Webhook, None) => {
+                errors.push(ValidationError::new("webhook_url", ValidationCode::Required, "A webhook rule needs a webhook_url"));
+            }
+            (NotificationChannel::Webhook, Some(url)) if !url.starts_with("https://") => {
+                errors.push(ValidationError::new("webhook_url", ValidationCode::InvalidFormat, "Webhook URL must use https"));
+            }
+            (NotificationChannel::InApp, Some(_)) => {
+                errors.push(ValidationError::new("webhook_url", ValidationCode::Conflict, "Only webhook rules take a webhook_url"));
+            }
+            _ => {}
+        }
+
+        if errors.is_empty() {
+            Ok(NewNotificationRule {
+                category: self.category.as_str().to_string(),
+                lead_days,
+                channel: channel.as_str().to_string(),
+                webhook_url: webhook_url.map(str::to_string),
+            })
+        } else {
+            Err(EngineError::InvalidInput(errors))
+        }
+    }
+}
+
+/// GET /api/v1/users/me/notification-rules -- the caller's notification subscriptions
+#[utoipa::path(
+    get,
+    path = "/api/v1/users/me/notification-rules",
+    tag = "users",
+    responses(
+        (status = 200, description = "Notification rules, oldest first", body = NotificationRuleListResponse),
+        (status = 401, description = "Unauthorized", content(("application/problem+json" = ProblemDetails), ("application/json" = ErrorResponse))),
+    ),
+    security(
+        ("bearer_auth" = []),
+        ("api_key" = [])
+    )
+)]
+pub async fn list_rules(
+    State(state): State<AppState>,
+    Extension(auth_user): Extension<AuthUser>,
+) -> Result<Json<NotificationRuleListResponse>, HandlerError> {
+    let rules = state
+        .notification_repository
+        .list_rules_for_user(user_uuid(&auth_user)?)
+        .await
+        .map_err(database_error)?;
+
+    Ok(Json(NotificationRuleListResponse {
+        rules: rules.into_iter().map(NotificationRuleResponse::from).collect(),
+    }))
+}
+
+/// POST /api/v1/users/me/notification-rules -- subscribe to a category of calendar event
+///
+/// Events are found from the caller's saved birth profile, so one with a
+/// birth date, location and timezone is required. A category is open to
+/// callers at the phase of the engine that finds its events.
+#[utoipa::path(
+    post,
+    path = "/api/v1/users/me/notification-rules",
+    tag = "users",
+    request_body = CreateNotificationRuleRequest,
+    responses(
+        (status = 201, description = "Rule created", body = NotificationRuleResponse),
+        (status = 401, description = "Unauthorized", content(("application/problem+json" = ProblemDetails), ("application/json" = ErrorResponse))),
+        (status = 403, description = "The category's engine needs a higher consciousness phase", content(("application/problem+json" = ProblemDetails), ("application/json" = ErrorResponse))),
+        (status = 422, description = "Validation error, or no complete birth profile", content(("application/problem+json" = ProblemDetails), ("application/json" = ErrorResponse))),
+    ),
+    security(
+        ("bearer_auth" = []),
+        ("api_key" = [])
+    )
+)]
+pub async fn create_rule(
+    State(state): State<AppState>,
+    Extension(auth_user): Extension<AuthUser>,
+    Json(payload): Json<CreateNotificationRuleRequest>,
+) -> Result<Response, HandlerError> {
+    let rule = payload.validate().map_err(engine_error_to_response)?;
+
+    if let Some(engine) = state.core.orchestrator.registry().get(payload.category.engine_id()) {
+        if engine.required_phase() > auth_user.consciousness_level {
+            return Err(engine_error_to_response(EngineError::PhaseAccessDenied {
+                required: engine.required_phase(),
+                current: auth_user.consciousness_level,
+            }));
+        }
+    }
+
+    let user_id = user_uuid(&auth_user)?;
+    let profile = state.user_repository.get_profile(user_id).await.map_err(database_error)?;
+    if profile.and_then(|profile| birth_data_from_profile(&profile, "")).is_none() {
+        return Err(engine_error_to_response(EngineError::invalid_field(
+            "birth_data",
+            ValidationCode::Required,
+            "Save a birth date, location and timezone with PATCH /api/v1/users/me first",
+        )));
+    }
+
+    let created = state
+        .notification_repository
+        .create_rule(user_id, &rule)
+        .await
+        .map_err(database_error)?;
+
+    Ok((StatusCode::CREATED, Json(NotificationRuleResponse::from(created))).into_response())
+}
+
+/// DELETE /api/v1/users/me/notification-rules/:id -- unsubscribe
+#[utoipa::path(
+    delete,
+    path = "/api/v1/users/me/notification-rules/{id}",
+    tag = "users",
+    params(("id" = String, Path, description = "Notification rule id")),
+    responses(
+        (status = 204, description = "Rule removed, with the notifications it sent"),
+        (status = 401, description = "Unauthorized", content(("application/problem+json" = ProblemDetails), ("application/json" = ErrorResponse))),
+        (status = 404, description = "No rule with this id belongs to the user", content(("application/problem+json" = ProblemDetails), ("application/json" = ErrorResponse))),
+    ),
+    security(
+        ("bearer_auth" = []),
+        ("api_key" = [])
+    )
+)]
+pub async fn delete_rule(
+    State(state): State<AppState>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(id): Path<String>,
+) -> Result<StatusCode, HandlerError> {
+    let rule_id = Uuid::parse_str(&id).map_err(|_| rule_not_found(&id))?;
+    let deleted = state
+        .notification_repository
+        .delete_rule_for_user(rule_id, user_uuid(&auth_user)?)
+        .await
+        .map_err(database_error)?;
+
+    if deleted {
+        Ok(StatusCode::NO_CONTENT)
+    } else {
+        Err(rule_not_found(&id))
+    }
+}
+
+/// GET /api/v1/users/me/notifications -- the caller's in-app notifications
+#[utoipa::path(
+    get,
+    path = "/api/v1/users/me/notifications",
+    tag = "users",
+    responses(
+        (status = 200, description = "In-app notifications, newest first", body = NotificationListResponse),
+        (status = 401, description = "Unauthorized", content(("application/problem+json" = ProblemDetails), ("application/json" = ErrorResponse))),
+    ),
+    security(
+        ("bearer_auth" = []),
+        ("api_key" = [])
+    )
+)]
+pub async fn list_notifications(
+    State(state): State<AppState>,
+    Extension(auth_user): Extension<AuthUser>,
+) -> Result<Json<NotificationListResponse>, HandlerError> {
+    let notifications = state
+        .notification_repository
+        .list_in_app(user_uuid(&auth_user)?, INBOX_LIMIT)
+        .await
+        .map_err(database_error)?;
+
+    Ok(Json(NotificationListResponse {
+        notifications: notifications.into_iter().map(NotificationResponse::from).collect(),
+    }))
+}
+
+fn rule_not_found(id: &str) -> HandlerError {
+    not_found("NOTIFICATION_RULE_NOT_FOUND", format!("Notification rule '{}' not found", id))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn request(channel: Option<NotificationChannel>, webhook_url: Option<&str>, lead_days: Option<i16>) -> CreateNotificationRuleRequest {
+        CreateNotificationRuleRequest {
+            category: NotificationCategory::Ekadashi,
+            lead_days,
+            channel,
+            webhook_url: webhook_url.map(str::to_string),
+        }
+    }
+
+    fn error_fields(request: &CreateNotificationRuleRequest) -> Vec<(String, ValidationCode)> {
+        match request.validate() {
+            Err(EngineError::InvalidInput(errors)) => errors.into_iter().map(|e| (e.field, e.code)).collect(),
+            other => panic!("expected InvalidInput, got {:?}", other.map(|rule| rule.channel)),
+        }
+    }
+
+    #[test]
+    fn test_validate() {
+        let rule = request(None, None, None).validate().unwrap();
+        assert_eq!((rule.category.as_str(), rule.lead_days, rule.channel.as_str()), ("ekadashi", 0, "in_app"));
+        let webhook = request(Some(NotificationChannel::Webhook), Some(" https://example.com/hook "), Some(3));
+        assert_eq!(webhook.validate().unwrap().webhook_url.as_deref(), Some("https://example.com/hook"));
+
+        assert_eq!(
+            error_fields(&request(None, None, Some(31))),
+            vec![("lead_days".to_string(), ValidationCode::OutOfRange)]
+        );
+        assert_eq!(
+            error_fields(&request(Some(NotificationChannel::Webhook), None, None)),
+            vec![("webhook_url".to_string(), ValidationCode::Required)]
+        );
+        assert_eq!(
+            error_fields(&request(Some(NotificationChannel::Webhook), Some("http://example.com"), None)),
+            vec![("webhook_url".to_string(), ValidationCode::InvalidFormat)]
+        );
+        assert_eq!(
+            error_fields(&request(None, Some("https://example.com"), None)),
+            vec![("webhook_url".to_string(), ValidationCode::Conflict)]
+        );
+    }
+}
@@ -0,0 +1,104 @@
+use axum::extract::{Extension, Query, State};
+use axum::http::header;
+use axum::response::{IntoResponse, Response};
+use chrono::Utc;
+use noesis_auth::AuthUser;
+use noesis_core::{EngineError, ValidationCode};
+use serde::Deserialize;
+use sha2::{Digest, Sha256};
+
+use super::{database_error, user_uuid, HandlerError};
+use crate::offline::{self, DEFAULT_BUNDLE_DAYS, MAX_BUNDLE_DAYS};
+use crate::precompute::{birth_data_from_profile, natal_input};
+use crate::{engine_error_to_response, require_engine_scope, settings, AppState};
+
+#[derive(Deserialize)]
+pub struct OfflineBundleParams {
+    days: Option<u32>,
+    location: Option<String>,
+}
+
+/// GET /api/v1/users/me/offline-bundle -- natal results and upcoming daily data for offline use
+#[utoipa::path(
+    get,
+    path = "/api/v1/users/me/offline-bundle",
+    tag = "users",
+    params(
+        ("days" = Option<u32>, Query, description = "Days of daily data from today (default 30, max 90)"),
+        ("location" = Option<String>, Query, description = "Saved location id to read the daily data for; defaults to the birth place"),
+    ),
+    responses(
+        (status = 200, description = "Gzip-compressed JSON bundle with a checksummed manifest", content_type = "application/gzip", body = OfflineBundle,
+            headers(("ETag" = String, description = "Quoted SHA-256 checksum of the compressed body"))),
+        (status = 401, description = "Unauthorized", content(("application/problem+json" = ProblemDetails), ("application/json" = ErrorResponse))),
+        (status = 403, description = "API key not scoped to every engine in the bundle", content(("application/problem+json" = ProblemDetails), ("application/json" = ErrorResponse))),
+        (status = 404, description = "Saved location not found", content(("application/problem+json" = ProblemDetails), ("application/json" = ErrorResponse))),
+        (status = 422, description = "No saved birth data, or days out of range", content(("application/problem+json" = ProblemDetails), ("application/json" = ErrorResponse))),
+    ),
+    security(
+        ("bearer_auth" = []),
+        ("api_key" = [])
+    )
+)]
+pub async fn get_offline_bundle(
+    State(state): State<AppState>,
+    Extension(auth_user): Extension<AuthUser>,
+    Query(params): Query<OfflineBundleParams>,
+) -> Result<Response, HandlerError> {
+    let days = params.days.unwrap_or(DEFAULT_BUNDLE_DAYS);
+    if !(1..=MAX_BUNDLE_DAYS).contains(&days) {
+        return Err(engine_error_to_response(EngineError::invalid_field(
+            "days",
+            ValidationCode::OutOfRange,
+            format!("'days' must be between 1 and {}", MAX_BUNDLE_DAYS),
+        )));
+    }
+    for engine_id in offline::bundle_engines() {
+        require_engine_scope(&auth_user, engine_id)?;
+    }
+
+    let user_id = user_uuid(&auth_user)?;
+    let user = state
+        .user_repository
+        .get_user_by_id(user_id)
+        .await
+        .map_err(database_error)?
+        .ok_or_else(|| engine_error_to_response(EngineError::AuthError("User not found".to_string())))?;
+    let profile = state.user_repository.get_profile(user_id).await.map_err(database_error)?;
+    let birth_data = profile
+        .and_then(|profile| birth_data_from_profile(&profile, &user.full_name))
+        .ok_or_else(|| {
+            engine_error_to_response(EngineError::invalid_field(
+                "birth_data",
+                ValidationCode::Required,
+                "Save a birth date, location and timezone with PATCH /api/v1/users/me first",
+            ))
+        })?;
+
+    let mut input = natal_input(birth_data);
+    let saved = state.settings.options_for(user_id).await;
+    settings::apply_saved(&mut input, &saved);
+    if let Some(selector) = params.location.as_deref() {
+        super::locations::select_saved_location(&state, &auth_user, &mut input, selector).await?;
+    }
+
+    let bundle = offline::build_bundle(&state.core, &auth_user, input, Utc::now(), days)
+        .await
+        .map_err(engine_error_to_response)?;
+    let body = bundle.to_gzip();
+    let etag = format!("\"{}\"", hex::encode(Sha256::digest(&body)));
+    let disposition = format!(
+        "attachment; filename=\"noesis-offline-{}.json.gz\"",
+        bundle.manifest.valid_from
+    );
+    Ok((
+        [
+            (header::CONTENT_TYPE, "application/gzip".to_string()),
+            (header::CONTENT_DISPOSITION, disposition),
+            (header::ETAG, etag),
+            (header::CACHE_CONTROL, "private, no-store".to_string()),
+        ],
+        body,
+    )
+        .into_response())
+}
@@ -0,0 +1,171 @@
+use axum::{
+    extract::{Extension, Json, Path, State},
+    http::StatusCode,
+    response::{IntoResponse, Response},
+};
+use chrono::{DateTime, Utc};
+use noesis_auth::{permissions, AuthUser};
+use noesis_core::{EngineError, ValidationCode, ValidationError};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use super::{database_error, not_found, user_uuid, HandlerError};
+use crate::{engine_error_to_response, AppState, ErrorResponse};
+
+#[derive(Deserialize, ToSchema)]
+pub struct CreateOrganizationRequest {
+    #[schema(example = "Lotus Coaching")]
+    pub name: String,
+}
+
+#[derive(Serialize, ToSchema)]
+pub struct OrganizationResponse {
+    pub id: String,
+    pub name: String,
+    pub owner_id: String,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Deserialize, ToSchema)]
+pub struct AddMemberRequest {
+    /// Email of an existing account
+    pub email: String,
+    /// "owner", "practitioner" or "assistant"
+    #[schema(example = "practitioner")]
+    pub role: String,
+}
+
+#[derive(Serialize, ToSchema)]
+pub struct MemberResponse {
+    pub organization_id: String,
+    pub user_id: String,
+    pub role: String,
+}
+
+/// POST /api/v1/organizations -- create a practice owned by the caller
+#[utoipa::path(
+    post,
+    path = "/api/v1/organizations",
+    tag = "clients",
+    request_body = CreateOrganizationRequest,
+    responses(
+        (status = 201, description = "Organization created; log in again to receive client permissions", body = OrganizationResponse),
+        (status = 401, description = "Unauthorized", content(("application/problem+json" = ProblemDetails), ("application/json" = ErrorResponse))),
+        (status = 422, description = "Validation error", content(("application/problem+json" = ProblemDetails), ("application/json" = ErrorResponse))),
+    ),
+    security(
+        ("bearer_auth" = []),
+        ("api_key" = [])
+    )
+)]
+pub async fn create_organization(
+    State(state): State<AppState>,
+    Extension(auth_user): Extension<AuthUser>,
+    Json(payload): Json<CreateOrganizationRequest>,
+) -> Result<Response, HandlerError> {
+    let name = payload.name.trim();
+    if name.is_empty() || name.len() > 255 {
+        return Err(engine_error_to_response(EngineError::InvalidInput(vec![ValidationError::new(
+            "name",
+            ValidationCode::OutOfRange,
+            "Organization name must be 1-255 characters",
+        )])));
+    }
+
+    let owner_id = user_uuid(&auth_user)?;
+    let organization = state
+        .organization_repository
+        .create_organization(name, owner_id)
+        .await
+        .map_err(database_error)?;
+
+    let response = OrganizationResponse {
+        id: organization.id.to_string(),
+        name: organization.name,
+        owner_id: organization.owner_id.to_string(),
+        created_at: organization.created_at,
+    };
+    Ok((StatusCode::CREATED, Json(response)).into_response())
+}
+
+/// POST /api/v1/organizations/:id/members -- add a member or change their role
+#[utoipa::path(
+    post,
+    path = "/api/v1/organizations/{id}/members",
+    tag = "clients",
+    params(("id" = String, Path, description = "Organization id")),
+    request_body = AddMemberRequest,
+    responses(
+        (status = 201, description = "Member added or role changed", body = MemberResponse),
+        (status = 401, description = "Unauthorized", content(("application/problem+json" = ProblemDetails), ("application/json" = ErrorResponse))),
+        (status = 403, description = "Caller is not the organization's owner", content(("application/problem+json" = ProblemDetails), ("application/json" = ErrorResponse))),
+        (status = 404, description = "Organization or user not found", content(("application/problem+json" = ProblemDetails), ("application/json" = ErrorResponse))),
+        (status = 422, description = "Unknown role", content(("application/problem+json" = ProblemDetails), ("application/json" = ErrorResponse))),
+    ),
+    security(
+        ("bearer_auth" = []),
+        ("api_key" = [])
+    )
+)]
+pub async fn add_member(
+    State(state): State<AppState>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(id): Path<String>,
+    Json(payload): Json<AddMemberRequest>,
+) -> Result<Response, HandlerError> {
+    if !permissions::is_known_role(&payload.role) {
+        return Err(engine_error_to_response(EngineError::InvalidInput(vec![ValidationError::new(
+            "role",
+            ValidationCode::Unknown,
+            format!("Unknown role '{}'", payload.role),
+        )])));
+    }
+
+    let caller_id = user_uuid(&auth_user)?;
+    let organization_not_found = || not_found("ORGANIZATION_NOT_FOUND", format!("Organization '{}' not found", id));
+    let organization_id = Uuid::parse_str(&id).map_err(|_| organization_not_found())?;
+
+    // Non-members can't tell the organization exists
+    match state
+        .organization_repository
+        .get_member_role(organization_id, caller_id)
+        .await
+        .map_err(database_error)?
+        .as_deref()
+    {
+        None => return Err(organization_not_found()),
+        Some(permissions::ROLE_OWNER) => {}
+        Some(_) => {
+            return Err((
+                StatusCode::FORBIDDEN,
+                Json(ErrorResponse {
+                    error: "Only the organization's owner can manage members".to_string(),
+                    error_code: "FORBIDDEN".to_string(),
+                    details: Some(serde_json::json!({ "required_role": permissions::ROLE_OWNER })),
+                    request_id: None,
+                }),
+            ));
+        }
+    }
+
+    let user = state
+        .user_repository
+        .get_user_by_email(&payload.email)
+        .await
+        .map_err(database_error)?
+        .ok_or_else(|| not_found("USER_NOT_FOUND", format!("No account for '{}'", payload.email)))?;
+
+    let member = state
+        .organization_repository
+        .upsert_member(organization_id, user.id, &payload.role)
+        .await
+        .map_err(database_error)?;
+
+    let response = MemberResponse {
+        organization_id: member.organization_id.to_string(),
+        user_id: member.user_id.to_string(),
+        role: member.role,
+    };
+    Ok((StatusCode::CREATED, Json(response)).into_response())
+}
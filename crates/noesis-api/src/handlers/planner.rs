@@ -0,0 +1,449 @@
+use axum::extract::{Json, State};
+use chrono::{Datelike, Duration, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime, Offset, Utc};
+use engine_biorhythm::ForecastDay;
+use engine_panchanga::muhurta::{day_muhurtas, Muhurta, MuhurtaKind};
+use engine_panchanga::Ayanamsa;
+use engine_vedic_clock::{get_combined_quality, window_for_hour, Activity};
+use noesis_core::options::Constitution;
+use noesis_core::{BirthData, Coordinates, EngineError, EngineInput, Precision, ValidationCode};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use super::HandlerError;
+use crate::{engine_error_to_response, geo, AppState};
+
+/// Longest range planned in one request, in days
+const MAX_PLANNER_DAYS: i64 = 14;
+
+const DEFAULT_LIMIT: usize = 10;
+const MAX_LIMIT: usize = 50;
+
+/// Local start hours of the organ windows offered: 05:00 to 21:00-23:00
+const WINDOW_START_HOURS: std::ops::RangeInclusive<u8> = 5..=21;
+
+/// Window length, matching the organ clock
+const WINDOW_MINUTES: i64 = 120;
+
+// Shares of the three systems in a window's score
+const VEDIC_CLOCK_WEIGHT: f64 = 0.4;
+const BIORHYTHM_WEIGHT: f64 = 0.3;
+const MUHURTA_WEIGHT: f64 = 0.3;
+
+/// Biorhythm score lost per unit of task weight on a critical cycle
+const CRITICAL_DAY_PENALTY: f64 = 0.25;
+
+/// Kinds of task the planner ranks windows for
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum PlannerTask {
+    DeepWork,
+    Workout,
+    DifficultConversation,
+    CreativeWork,
+    Meditation,
+}
+
+impl PlannerTask {
+    const ALL: [PlannerTask; 5] = [
+        PlannerTask::DeepWork,
+        PlannerTask::Workout,
+        PlannerTask::DifficultConversation,
+        PlannerTask::CreativeWork,
+        PlannerTask::Meditation,
+    ];
+
+    /// Parse "deep work", "deep_work", "Deep-Work" and the like
+    fn parse(task: &str) -> Option<Self> {
+        let normalized = task.trim().to_lowercase().replace(['_', '-'], " ");
+        Self::ALL.into_iter().find(|candidate| candidate.display_name() == normalized)
+    }
+
+    fn display_name(&self) -> &'static str {
+        match self {
+            PlannerTask::DeepWork => "deep work",
+            PlannerTask::Workout => "workout",
+            PlannerTask::DifficultConversation => "difficult conversation",
+            PlannerTask::CreativeWork => "creative work",
+            PlannerTask::Meditation => "meditation",
+        }
+    }
+
+    /// The vedic-clock activity the task is timed as
+    fn activity(&self) -> Activity {
+        match self {
+            PlannerTask::DeepWork => Activity::Work,
+            PlannerTask::Workout => Activity::Exercise,
+            PlannerTask::DifficultConversation => Activity::Social,
+            PlannerTask::CreativeWork => Activity::Creative,
+            PlannerTask::Meditation => Activity::Meditation,
+        }
+    }
+
+    /// Weights of the physical, emotional, intellectual and intuitive
+    /// cycles, summing to 1
+    fn cycle_weights(&self) -> [(&'static str, f64); 4] {
+        let [physical, emotional, intellectual, intuitive] = match self {
+            PlannerTask::DeepWork => [0.0, 0.2, 0.8, 0.0],
+            PlannerTask::Workout => [0.8, 0.2, 0.0, 0.0],
+            PlannerTask::DifficultConversation => [0.0, 0.6, 0.3, 0.1],
+            PlannerTask::CreativeWork => [0.0, 0.4, 0.2, 0.4],
+            PlannerTask::Meditation => [0.0, 0.4, 0.0, 0.6],
+        };
+        [
+            ("physical", physical),
+            ("emotional", emotional),
+            ("intellectual", intellectual),
+            ("intuitive", intuitive),
+        ]
+    }
+
+    /// Brahma Muhurta only favours contemplative tasks
+    fn suits_brahma_muhurta(&self) -> bool {
+        matches!(self, PlannerTask::Meditation | PlannerTask::CreativeWork)
+    }
+}
+
+#[derive(Deserialize, ToSchema)]
+pub struct PlannerRequest {
+    /// deep work, workout, difficult conversation, creative work or meditation
+    #[schema(example = "deep work")]
+    pub task: String,
+    /// First day to plan, YYYY-MM-DD
+    #[schema(example = "2026-10-15")]
+    pub from: String,
+    /// Last day to plan, inclusive; defaults to `from`, at most 13 days after it
+    #[schema(example = "2026-10-17")]
+    pub to: Option<String>,
+    pub birth_data: BirthData,
+    /// Where the days are spent; defaults to the birth place
+    pub location: Option<Coordinates>,
+    /// Birth constitution weighting the vedic-clock component
+    pub constitution: Option<Constitution>,
+    /// Windows to return, best first (default 10, at most 50)
+    #[schema(example = 10)]
+    pub limit: Option<usize>,
+}
+
+/// A window's score in each system, 0-1
+#[derive(Serialize, ToSchema)]
+pub struct WindowScores {
+    /// Organ and dosha favourability of the window for the task
+    pub vedic_clock: f64,
+    /// The cycles the task draws on, that day
+    pub biorhythm: f64,
+    /// The day's tithi and nakshatra averaged with its personal Tarabala and
+    /// Chandrabala, with Rahu Kalam, Yamaganda and Gulika Kaal counting
+    /// against and Abhijit for the window
+    pub muhurta: f64,
+}
+
+#[derive(Serialize, ToSchema)]
+pub struct PlannedWindow {
+    /// Local start, RFC 3339
+    #[schema(example = "2026-10-15T09:00:00+05:30")]
+    pub start: String,
+    /// Local end, RFC 3339
+    #[schema(example = "2026-10-15T11:00:00+05:30")]
+    pub end: String,
+    /// Weighted score, 0-1: 40% vedic-clock, 30% biorhythm, 30% muhurta
+    pub score: f64,
+    pub scores: WindowScores,
+    /// Why the window scores as it does, one sentence per finding
+    pub reasons: Vec<String>,
+}
+
+#[derive(Serialize, ToSchema)]
+pub struct PlannerResponse {
+    #[schema(value_type = String, example = "deep_work")]
+    pub task: PlannerTask,
+    pub from: NaiveDate,
+    pub to: NaiveDate,
+    /// Best windows first
+    pub windows: Vec<PlannedWindow>,
+}
+
+/// POST /api/v1/planner/suggest -- rank time windows for a task
+///
+/// Every 2-hour organ window from 05:00 to 23:00 of each day in the range is
+/// scored with vedic-clock favourability, the biorhythm cycles the task draws
+/// on, and the day's panchanga, personal Tarabala and Chandrabala, and
+/// muhurtas.
+#[utoipa::path(
+    post,
+    path = "/api/v1/planner/suggest",
+    tag = "planner",
+    request_body = PlannerRequest,
+    responses(
+        (status = 200, description = "Ranked windows", body = PlannerResponse),
+        (status = 401, description = "Unauthorized", content(("application/problem+json" = ProblemDetails), ("application/json" = ErrorResponse))),
+        (status = 422, description = "Unknown task, malformed or too long a range, invalid birth data or constitution", content(("application/problem+json" = ProblemDetails), ("application/json" = ErrorResponse))),
+    ),
+    security(
+        ("bearer_auth" = []),
+        ("api_key" = [])
+    )
+)]
+pub async fn suggest(
+    State(state): State<AppState>,
+    Json(payload): Json<PlannerRequest>,
+) -> Result<Json<PlannerResponse>, HandlerError> {
+    let task = PlannerTask::parse(&payload.task).ok_or_else(|| {
+        let known: Vec<&str> = PlannerTask::ALL.iter().map(|t| t.display_name()).collect();
+        engine_error_to_response(EngineError::invalid_field(
+            "task",
+            ValidationCode::Unknown,
+            format!("Unknown task '{}'; expected one of: {}", payload.task, known.join(", ")),
+        ))
+    })?;
+    let from = parse_date("from", &payload.from).map_err(engine_error_to_response)?;
+    let to = match payload.to.as_deref() {
+        Some(value) => parse_date("to", value).map_err(engine_error_to_response)?,
+        None => from,
+    };
+    if to < from || (to - from).num_days() >= MAX_PLANNER_DAYS {
+        return Err(engine_error_to_response(EngineError::invalid_field(
+            "to",
+            ValidationCode::OutOfRange,
+            format!("'to' must be from 'from' up to {} days later", MAX_PLANNER_DAYS - 1),
+        )));
+    }
+    let limit = payload.limit.unwrap_or(DEFAULT_LIMIT);
+    if !(1..=MAX_LIMIT).contains(&limit) {
+        return Err(engine_error_to_response(EngineError::invalid_field(
+            "limit",
+            ValidationCode::OutOfRange,
+            format!("'limit' must be between 1 and {}", MAX_LIMIT),
+        )));
+    }
+    if let Some(constitution) = &payload.constitution {
+        constitution
+            .validate()
+            .map_err(|e| engine_error_to_response(unprefix_option(e)))?;
+    }
+
+    // Geocode the birth place and fill in missing timezones as for engines
+    let mut input = EngineInput {
+        birth_data: Some(payload.birth_data),
+        secondary_birth_data: None,
+        subjects: Vec::new(),
+        current_time: Utc::now(),
+        location: payload.location,
+        precision: Precision::Standard,
+        options: Default::default(),
+    };
+    geo::enrich_input(state.geocoder.as_ref(), &mut input)
+        .await
+        .map_err(engine_error_to_response)?;
+    let birth = input.birth_data.as_ref().expect("set above");
+    birth.validate().map_err(engine_error_to_response)?;
+    let birth_date = parse_date("birth_data.date", &birth.date).map_err(engine_error_to_response)?;
+    if from < birth_date {
+        return Err(engine_error_to_response(EngineError::invalid_field(
+            "from",
+            ValidationCode::OutOfRange,
+            "'from' must not be before the birth date",
+        )));
+    }
+
+    let mut windows = Vec::new();
+    let mut date = from;
+    while date <= to {
+        windows.extend(
+            plan_day(&input, task, payload.constitution.as_ref(), birth_date, date)
+                .map_err(engine_error_to_response)?,
+        );
+        date += Duration::days(1);
+    }
+    windows.sort_by(|a, b| b.score.partial_cmp(&a.score).unwrap_or(std::cmp::Ordering::Equal));
+    windows.truncate(limit);
+
+    Ok(Json(PlannerResponse { task, from, to, windows }))
+}
+
+/// Score every window of one day
+fn plan_day(
+    input: &EngineInput,
+    task: PlannerTask,
+    constitution: Option<&Constitution>,
+    birth_date: NaiveDate,
+    date: NaiveDate,
+) -> Result<Vec<PlannedWindow>, EngineError> {
+    let noon = date.and_time(NaiveTime::from_hms_opt(12, 0, 0).unwrap_or_default());
+    let offset_seconds = input.local_offset_at(noon.and_utc())?.unwrap_or(0);
+    let offset = FixedOffset::east_opt(offset_seconds).unwrap_or_else(|| Utc.fix());
+    let tz_hours = offset_seconds as f64 / 3600.0;
+    let (latitude, longitude) = match (&input.location, &input.birth_data) {
+        (Some(location), _) => (location.latitude, location.longitude),
+        (None, Some(birth)) => (birth.latitude, birth.longitude),
+        (None, None) => (0.0, 0.0),
+    };
+
+    let day = date.format("%Y-%m-%d").to_string();
+    let panchanga = engine_panchanga::compute_panchanga(&day, "12:00", tz_hours);
+    let (sunrise, sunset) = engine_panchanga::local_sun_times(date, latitude, longitude, tz_hours)?;
+    let muhurtas = day_muhurtas(sunrise, sunset, date.weekday().num_days_from_sunday() as u8);
+    let panchanga_quality = get_combined_quality(Some(panchanga.tithi_index), Some(panchanga.nakshatra_index));
+    let panchanga_reason = format!(
+        "{} day: {} tithi, {} nakshatra",
+        panchanga_quality.rating.display(),
+        panchanga.tithi_name,
+        panchanga.nakshatra_name
+    );
+    let mut day_quality = panchanga_quality.rating.to_score();
+    let mut strength_reason = None;
+    if let Some(birth) = &input.birth_data {
+        if let Some(strength) =
+            engine_panchanga::daily_strength_on(birth, date, latitude, longitude, tz_hours, Ayanamsa::Tropical)?
+        {
+            day_quality = (day_quality + strength.score) / 2.0;
+            strength_reason = Some(format!(
+                "{} Tarabala, Moon in house {} from your natal Moon{}",
+                strength.tarabala.name,
+                strength.chandrabala.house,
+                if strength.chandrabala.chandrashtama { " (Chandrashtama)" } else { "" }
+            ));
+        }
+    }
+
+    let (biorhythm, biorhythm_reasons) = biorhythm_score(task, birth_date, date);
+
+    let windows = WINDOW_START_HOURS
+        .step_by(2)
+        .map(|hour| {
+            let start = date.and_time(NaiveTime::from_hms_opt(hour as u32, 0, 0).unwrap_or_default());
+            let end = start + Duration::minutes(WINDOW_MINUTES);
+
+            let clock = window_for_hour(task.activity(), hour, constitution);
+            let (muhurta, muhurta_reasons) =
+                muhurta_score(task, day_quality, &muhurtas, start, end);
+            let score = VEDIC_CLOCK_WEIGHT * clock.quality + BIORHYTHM_WEIGHT * biorhythm + MUHURTA_WEIGHT * muhurta;
+
+            let mut reasons = vec![clock.reason];
+            reasons.extend(biorhythm_reasons.iter().cloned());
+            reasons.push(panchanga_reason.clone());
+            reasons.extend(strength_reason.clone());
+            reasons.extend(muhurta_reasons);
+
+            PlannedWindow {
+                start: local_rfc3339(start, offset),
+                end: local_rfc3339(end, offset),
+                score: round(score),
+                scores: WindowScores {
+                    vedic_clock: round(clock.quality),
+                    biorhythm: round(biorhythm),
+                    muhurta: round(muhurta),
+                },
+                reasons,
+            }
+        })
+        .collect();
+    Ok(windows)
+}
+
+/// The task's cycles on `date`, less a penalty for critical cycles
+fn biorhythm_score(task: PlannerTask, birth_date: NaiveDate, date: NaiveDate) -> (f64, Vec<String>) {
+    let Some(cycles) = engine_biorhythm::cycles_on(birth_date, date) else {
+        return (0.5, Vec::new());
+    };
+    let percentage = |name: &str, day: &ForecastDay| match name {
+        "physical" => day.physical,
+        "emotional" => day.emotional,
+        "intellectual" => day.intellectual,
+        _ => day.intuitive,
+    };
+
+    let weights = task.cycle_weights();
+    let mut score: f64 = weights
+        .iter()
+        .map(|(name, weight)| weight * percentage(name, &cycles) / 100.0)
+        .sum();
+    let (main_cycle, _) = weights
+        .iter()
+        .copied()
+        .fold(("physical", 0.0), |best, cycle| if cycle.1 > best.1 { cycle } else { best });
+    let mut reasons = vec![format!(
+        "{} cycle at {:.0}%",
+        capitalize(main_cycle),
+        percentage(main_cycle, &cycles)
+    )];
+
+    for critical in engine_biorhythm::critical_cycles_on(birth_date, date) {
+        let weight = weights.iter().find(|(name, _)| *name == critical).map_or(0.0, |(_, w)| *w);
+        if weight > 0.0 {
+            score -= CRITICAL_DAY_PENALTY * weight;
+            reasons.push(format!("Critical day in the {} cycle", critical));
+        }
+    }
+    (score.clamp(0.0, 1.0), reasons)
+}
+
+/// The day's panchanga quality, moved by the muhurtas the window overlaps
+fn muhurta_score(
+    task: PlannerTask,
+    day_quality: f64,
+    muhurtas: &[Muhurta],
+    start: NaiveDateTime,
+    end: NaiveDateTime,
+) -> (f64, Vec<String>) {
+    let mut score = day_quality;
+    let mut reasons = Vec::new();
+    for muhurta in muhurtas {
+        let overlap = muhurta.overlap_minutes(start, end);
+        if overlap == 0 {
+            continue;
+        }
+        let span = format!("{}-{}", muhurta.start.format("%H:%M"), muhurta.end.format("%H:%M"));
+        if !muhurta.kind.is_auspicious() {
+            score -= 0.5 * overlap as f64 / WINDOW_MINUTES as f64;
+            reasons.push(format!("Overlaps {} ({})", muhurta.kind.display_name(), span));
+        } else if muhurta.kind != MuhurtaKind::BrahmaMuhurta || task.suits_brahma_muhurta() {
+            let length = (muhurta.end - muhurta.start).num_minutes().max(1);
+            score += 0.3 * overlap as f64 / length as f64;
+            reasons.push(format!("Includes {} ({})", muhurta.kind.display_name(), span));
+        }
+    }
+    (score.clamp(0.0, 1.0), reasons)
+}
+
+/// Parse a YYYY-MM-DD date
+fn parse_date(field: &str, value: &str) -> Result<NaiveDate, EngineError> {
+    NaiveDate::parse_from_str(value, "%Y-%m-%d").map_err(|_| {
+        EngineError::invalid_field(
+            field,
+            ValidationCode::InvalidFormat,
+            format!("'{}' is not a YYYY-MM-DD date", value),
+        )
+    })
+}
+
+/// Report `options.constitution.*` errors at the request's `constitution`
+fn unprefix_option(err: EngineError) -> EngineError {
+    match err {
+        EngineError::InvalidInput(mut errors) => {
+            for error in &mut errors {
+                error.field = error.field.replacen("options.", "", 1);
+            }
+            EngineError::InvalidInput(errors)
+        }
+        other => other,
+    }
+}
+
+fn local_rfc3339(local: NaiveDateTime, offset: FixedOffset) -> String {
+    match local.and_local_timezone(offset).single() {
+        Some(datetime) => datetime.to_rfc3339(),
+        None => local.format("%Y-%m-%dT%H:%M:%S").to_string(),
+    }
+}
+
+fn round(score: f64) -> f64 {
+    (score * 1000.0).round() / 1000.0
+}
+
+fn capitalize(s: &str) -> String {
+    let mut chars = s.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().collect::<String>() + chars.as_str(),
+        None => String::new(),
+    }
+}
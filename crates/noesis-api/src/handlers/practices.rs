@@ -0,0 +1,395 @@
+use axum::extract::{Extension, Json, Query, State};
+use chrono::{Duration, NaiveDate, Utc};
+use noesis_auth::AuthUser;
+use noesis_core::{EngineError, ValidationCode, ValidationError};
+use noesis_data::models::practice::{NewPracticeLog, PracticeLog};
+use noesis_orchestrator::experiments;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use super::{database_error, user_uuid, HandlerError};
+use crate::{engine_error_to_response, AppState};
+
+/// Experience points for a practice the first time it is marked followed on a day
+pub const PRACTICE_XP: i32 = 5;
+
+/// Extra experience points each time a streak completes a week
+pub const STREAK_WEEK_XP: i32 = 25;
+
+/// `action_type` of the progression log entries written for practices
+const PRACTICE_ACTION: &str = "practice";
+
+/// Days listed when `from` is omitted, today included
+const DEFAULT_HISTORY_DAYS: i64 = 30;
+
+/// Longest range listed in one request, in days
+const MAX_HISTORY_DAYS: i64 = 366;
+
+/// Longest note kept with an entry, in characters
+const MAX_NOTE_CHARS: usize = 1000;
+
+/// Experiment outcomes recorded when the practice names its engine
+const FOLLOWED_OUTCOME: &str = "practice_followed";
+const SKIPPED_OUTCOME: &str = "practice_skipped";
+
+#[derive(Deserialize, ToSchema)]
+pub struct LogPracticeRequest {
+    /// 1-64 characters of a-z, 0-9, '_' or '-', chosen by the client
+    #[schema(example = "rest_on_critical_day")]
+    pub practice: String,
+    /// Engine whose recommendation this is; its experiments count the outcome
+    #[schema(example = "biorhythm")]
+    pub engine_id: Option<String>,
+    /// Day the practice was for, YYYY-MM-DD; defaults to today (UTC)
+    #[schema(example = "2026-10-14")]
+    pub date: Option<String>,
+    pub followed: bool,
+    /// Free-text reflection, at most 1000 characters
+    pub note: Option<String>,
+}
+
+#[derive(Serialize, ToSchema)]
+pub struct PracticeLogResponse {
+    pub id: String,
+    #[schema(example = "rest_on_critical_day")]
+    pub practice: String,
+    pub engine_id: Option<String>,
+    pub date: NaiveDate,
+    pub followed: bool,
+    pub note: Option<String>,
+}
+
+#[derive(Serialize, ToSchema)]
+pub struct LoggedPracticeResponse {
+    pub entry: PracticeLogResponse,
+    /// Consecutive followed days of this practice up to the entry's day
+    pub streak: u32,
+    /// Experience points this entry earned; 0 when re-logging a followed day
+    pub experience_awarded: i32,
+}
+
+#[derive(Serialize, ToSchema)]
+pub struct PracticeStreak {
+    pub practice: String,
+    /// Consecutive followed days ending on `to`, or the day before when `to` has no entry yet
+    pub current: u32,
+    /// Days followed within the listed range
+    pub followed_days: u32,
+    /// Days logged within the listed range, followed or not
+    pub logged_days: u32,
+}
+
+#[derive(Serialize, ToSchema)]
+pub struct PracticeHistoryResponse {
+    pub from: NaiveDate,
+    pub to: NaiveDate,
+    /// Newest day first
+    pub entries: Vec<PracticeLogResponse>,
+    /// One per practice logged in the range, by practice
+    pub streaks: Vec<PracticeStreak>,
+}
+
+#[derive(Deserialize)]
+pub struct PracticeHistoryParams {
+    pub from: Option<String>,
+    pub to: Option<String>,
+}
+
+impl From<PracticeLog> for PracticeLogResponse {
+    fn from(log: PracticeLog) -> Self {
+        Self {
+            id: log.id.to_string(),
+            practice: log.practice,
+            engine_id: log.engine_id,
+            date: log.practice_date,
+            followed: log.followed,
+            note: log.note,
+        }
+    }
+}
+
+impl LogPracticeRequest {
+    fn validate(&self, state: &AppState, today: NaiveDate) -> Result<NaiveDate, EngineError> {
+        let mut errors = Vec::new();
+        if !experiments::is_valid_label(&self.practice) {
+            errors.push(ValidationError::new(
+                "practice",
+                ValidationCode::InvalidFormat,
+                format!(
+                    "Practice must be 1-{} characters of a-z, 0-9, '_' or '-'",
+                    experiments::MAX_LABEL_LEN
+                ),
+            ));
+        }
+        if let Some(engine_id) = &self.engine_id {
+            if state.core.orchestrator.registry().get(engine_id).is_none() {
+                errors.push(ValidationError::new(
+                    "engine_id",
+                    ValidationCode::Unknown,
+                    format!("Unknown engine '{}'", engine_id),
+                ));
+            }
+        }
+        let date = match self.date.as_deref() {
+            Some(value) => match parse_date("date", value) {
+                // A day ahead of UTC is still today somewhere
+                Ok(date) if date > today + Duration::days(1) => {
+                    errors.push(ValidationError::new("date", ValidationCode::OutOfRange, "Cannot log a practice for a future day"));
+                    today
+                }
+                Ok(date) => date,
+                Err(EngineError::InvalidInput(mut invalid)) => {
+                    errors.append(&mut invalid);
+                    today
+                }
+                Err(other) => return Err(other),
+            },
+            None => today,
+        };
+        if self.note.as_ref().is_some_and(|note| note.chars().count() > MAX_NOTE_CHARS) {
+            errors.push(ValidationError::new(
+                "note",
+                ValidationCode::OutOfRange,
+                format!("Note must be at most {} characters", MAX_NOTE_CHARS),
+            ));
+        }
+
+        if errors.is_empty() {
+            Ok(date)
+        } else {
+            Err(EngineError::InvalidInput(errors))
+        }
+    }
+}
+
+/// POST /api/v1/users/me/practices -- record whether a recommendation was followed
+///
+/// Logging the same practice again for the same day replaces the entry. The
+/// first time a day is marked followed it earns experience points, with a
+/// bonus for every full week of streak. When the entry names an engine, the
+/// outcome is counted against the caller's variant in that engine's running
+/// experiments.
+#[utoipa::path(
+    post,
+    path = "/api/v1/users/me/practices",
+    tag = "users",
+    request_body = LogPracticeRequest,
+    responses(
+        (status = 200, description = "Entry recorded, with the practice's streak", body = LoggedPracticeResponse),
+        (status = 401, description = "Unauthorized", content(("application/problem+json" = ProblemDetails), ("application/json" = ErrorResponse))),
+        (status = 422, description = "Invalid practice, unknown engine, future date or note too long", content(("application/problem+json" = ProblemDetails), ("application/json" = ErrorResponse))),
+    ),
+    security(
+        ("bearer_auth" = []),
+        ("api_key" = [])
+    )
+)]
+pub async fn log_practice(
+    State(state): State<AppState>,
+    Extension(auth_user): Extension<AuthUser>,
+    Json(payload): Json<LogPracticeRequest>,
+) -> Result<Json<LoggedPracticeResponse>, HandlerError> {
+    let today = Utc::now().date_naive();
+    let date = payload.validate(&state, today).map_err(engine_error_to_response)?;
+    let user_id = user_uuid(&auth_user)?;
+
+    let previous = state
+        .practice_repository
+        .get_for_date(user_id, &payload.practice, date)
+        .await
+        .map_err(database_error)?;
+    let note = payload.note.map(|note| note.trim().to_string()).filter(|note| !note.is_empty());
+    let entry = state
+        .practice_repository
+        .log_practice(
+            user_id,
+            &NewPracticeLog {
+                practice: payload.practice,
+                engine_id: payload.engine_id,
+                practice_date: date,
+                followed: payload.followed,
+                note,
+            },
+        )
+        .await
+        .map_err(database_error)?;
+
+    let followed_dates = state
+        .practice_repository
+        .followed_dates(user_id, &entry.practice, date)
+        .await
+        .map_err(database_error)?;
+    let streak = current_streak(&followed_dates, date);
+
+    let newly_followed = entry.followed && !previous.is_some_and(|log| log.followed);
+    let experience_awarded = if newly_followed { practice_experience(streak) } else { 0 };
+    if experience_awarded > 0 {
+        state
+            .user_repository
+            .add_experience(user_id, experience_awarded, PRACTICE_ACTION)
+            .await
+            .map_err(database_error)?;
+    }
+
+    if let Some(engine_id) = &entry.engine_id {
+        let outcome = if entry.followed { FOLLOWED_OUTCOME } else { SKIPPED_OUTCOME };
+        for assignment in state
+            .core
+            .orchestrator
+            .experiments()
+            .assignments_for_engine(engine_id, &auth_user.user_id)
+        {
+            noesis_metrics::record_experiment_outcome(&assignment.experiment_id, &assignment.variant, outcome);
+        }
+    }
+
+    Ok(Json(LoggedPracticeResponse {
+        entry: PracticeLogResponse::from(entry),
+        streak,
+        experience_awarded,
+    }))
+}
+
+/// GET /api/v1/users/me/practices -- logged practices and their streaks
+#[utoipa::path(
+    get,
+    path = "/api/v1/users/me/practices",
+    tag = "users",
+    params(
+        ("from" = Option<String>, Query, description = "First day, YYYY-MM-DD; defaults to 29 days before `to`"),
+        ("to" = Option<String>, Query, description = "Last day, inclusive; defaults to today (UTC), at most 365 days after `from`"),
+    ),
+    responses(
+        (status = 200, description = "Entries in the range and a streak per practice", body = PracticeHistoryResponse),
+        (status = 401, description = "Unauthorized", content(("application/problem+json" = ProblemDetails), ("application/json" = ErrorResponse))),
+        (status = 422, description = "Malformed or too long a range", content(("application/problem+json" = ProblemDetails), ("application/json" = ErrorResponse))),
+    ),
+    security(
+        ("bearer_auth" = []),
+        ("api_key" = [])
+    )
+)]
+pub async fn list_practices(
+    State(state): State<AppState>,
+    Extension(auth_user): Extension<AuthUser>,
+    Query(params): Query<PracticeHistoryParams>,
+) -> Result<Json<PracticeHistoryResponse>, HandlerError> {
+    let to = match params.to.as_deref() {
+        Some(value) => parse_date("to", value).map_err(engine_error_to_response)?,
+        None => Utc::now().date_naive(),
+    };
+    let from = match params.from.as_deref() {
+        Some(value) => parse_date("from", value).map_err(engine_error_to_response)?,
+        None => to - Duration::days(DEFAULT_HISTORY_DAYS - 1),
+    };
+    if to < from || (to - from).num_days() >= MAX_HISTORY_DAYS {
+        return Err(engine_error_to_response(EngineError::invalid_field(
+            "to",
+            ValidationCode::OutOfRange,
+            format!("'to' must be from 'from' up to {} days later", MAX_HISTORY_DAYS - 1),
+        )));
+    }
+
+    let user_id = user_uuid(&auth_user)?;
+    let entries = state
+        .practice_repository
+        .list_for_user(user_id, from, to)
+        .await
+        .map_err(database_error)?;
+
+    let mut practices: Vec<&str> = entries.iter().map(|log| log.practice.as_str()).collect();
+    practices.sort_unstable();
+    practices.dedup();
+    let mut streaks = Vec::with_capacity(practices.len());
+    for practice in practices {
+        let followed_dates = state
+            .practice_repository
+            .followed_dates(user_id, practice, to)
+            .await
+            .map_err(database_error)?;
+        let logged = entries.iter().filter(|log| log.practice == practice);
+        streaks.push(PracticeStreak {
+            practice: practice.to_string(),
+            current: current_streak(&followed_dates, to),
+            followed_days: logged.clone().filter(|log| log.followed).count() as u32,
+            logged_days: logged.count() as u32,
+        });
+    }
+
+    Ok(Json(PracticeHistoryResponse {
+        from,
+        to,
+        entries: entries.into_iter().map(PracticeLogResponse::from).collect(),
+        streaks,
+    }))
+}
+
+/// Consecutive days in `followed` (newest first) ending on `as_of`, or on the
+/// day before when `as_of` is not among them, so a day not yet logged does
+/// not break the streak
+fn current_streak(followed: &[NaiveDate], as_of: NaiveDate) -> u32 {
+    let mut dates = followed.iter().copied().skip_while(|date| *date > as_of).peekable();
+    let mut expected = match dates.peek() {
+        Some(&date) if date == as_of => as_of,
+        _ => as_of - Duration::days(1),
+    };
+    let mut streak = 0;
+    for date in dates {
+        if date != expected {
+            break;
+        }
+        streak += 1;
+        expected -= Duration::days(1);
+    }
+    streak
+}
+
+/// Experience for a newly followed day that brings the streak to `streak`
+fn practice_experience(streak: u32) -> i32 {
+    if streak > 0 && streak.is_multiple_of(7) {
+        PRACTICE_XP + STREAK_WEEK_XP
+    } else {
+        PRACTICE_XP
+    }
+}
+
+/// Parse a YYYY-MM-DD date
+fn parse_date(field: &str, value: &str) -> Result<NaiveDate, EngineError> {
+    NaiveDate::parse_from_str(value, "%Y-%m-%d").map_err(|_| {
+        EngineError::invalid_field(
+            field,
+            ValidationCode::InvalidFormat,
+            format!("'{}' is not a YYYY-MM-DD date", value),
+        )
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn day(d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, 10, d).unwrap()
+    }
+
+    #[test]
+    fn test_current_streak() {
+        let followed = [day(14), day(13), day(12), day(10)];
+        assert_eq!(current_streak(&followed, day(14)), 3);
+        // Today not logged yet: the streak up to yesterday still counts
+        assert_eq!(current_streak(&followed, day(15)), 3);
+        assert_eq!(current_streak(&followed, day(16)), 0);
+        // Later entries are ignored
+        assert_eq!(current_streak(&followed, day(12)), 1);
+        assert_eq!(current_streak(&followed, day(10)), 1);
+        assert_eq!(current_streak(&[], day(10)), 0);
+    }
+
+    #[test]
+    fn test_practice_experience() {
+        assert_eq!(practice_experience(1), PRACTICE_XP);
+        assert_eq!(practice_experience(7), PRACTICE_XP + STREAK_WEEK_XP);
+        assert_eq!(practice_experience(8), PRACTICE_XP);
+        assert_eq!(practice_experience(14), PRACTICE_XP + STREAK_WEEK_XP);
+    }
+}
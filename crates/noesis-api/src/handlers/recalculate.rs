@@ -0,0 +1,278 @@
+use std::sync::Arc;
+
+use axum::{
+    extract::{Extension, Json, Path, Query, State},
+    http::{header::LOCATION, HeaderMap, HeaderValue, StatusCode},
+    response::sse::{Event, KeepAlive, Sse},
+};
+use noesis_auth::{AuthService, AuthUser};
+use noesis_core::{EngineError, ValidationCode};
+use serde::Deserialize;
+use uuid::Uuid;
+
+use super::{database_error, not_found, HandlerError};
+use crate::precompute::birth_data_from_profile;
+use crate::recalculate::{
+    RecalculationItem, RecalculationJob, RecalculationJobResponse, RecalculationStatus, DEFAULT_CONCURRENCY,
+};
+use crate::{engine_error_to_response, require_permission, AppState, ErrorResponse};
+
+/// Permission required for `/api/v1/admin/recalculate`
+const RECALCULATE_PERMISSION: &str = "admin:recalculate";
+
+#[derive(Deserialize)]
+pub struct RecalculateParams {
+    /// Engine to recalculate
+    pub engine: String,
+    /// Refuse to start unless the running engine is at this version or later
+    pub since_version: Option<u32>,
+    /// Profiles recalculated at once
+    pub concurrency: Option<usize>,
+}
+
+#[derive(Deserialize)]
+pub struct RetryParams {
+    pub concurrency: Option<usize>,
+}
+
+type ProgressStream = Sse<futures::stream::BoxStream<'static, Result<Event, std::convert::Infallible>>>;
+
+/// POST /api/v1/admin/recalculate -- recalculate every saved profile with one engine
+///
+/// Meant for after an engine version bump: each complete birth profile is run
+/// through the engine again and its result cached under the new version. The
+/// job runs in the background and the response streams its progress; the
+/// `Location` header points at the job, which keeps running if the stream is
+/// dropped. Profiles that fail are recorded for `POST .../{job_id}/retry`.
+#[utoipa::path(
+    post,
+    path = "/api/v1/admin/recalculate",
+    tag = "admin",
+    params(
+        ("engine" = String, Query, description = "Engine to recalculate, e.g. `panchanga`"),
+        ("since_version" = Option<u32>, Query, description = "Refuse to start unless the running engine is at this version or later"),
+        ("concurrency" = Option<usize>, Query, description = "Profiles recalculated at once; defaults to 4, capped at the caller's tier limit"),
+    ),
+    responses(
+        (status = 200, description = "text/event-stream of `progress` events, then one `complete` event carrying the failures", content_type = "text/event-stream", body = RecalculationJobResponse),
+        (status = 401, description = "Unauthorized", content(("application/problem+json" = ProblemDetails), ("application/json" = ErrorResponse))),
+        (status = 403, description = "Missing admin:recalculate permission", content(("application/problem+json" = ProblemDetails), ("application/json" = ErrorResponse))),
+        (status = 404, description = "Engine not registered", content(("application/problem+json" = ProblemDetails), ("application/json" = ErrorResponse))),
+        (status = 409, description = "A recalculation of this engine is already running", content(("application/problem+json" = ProblemDetails), ("application/json" = ErrorResponse))),
+        (status = 422, description = "Zero concurrency, or the engine is older than `since_version`", content(("application/problem+json" = ProblemDetails), ("application/json" = ErrorResponse))),
+        (status = 503, description = "Database unavailable", content(("application/problem+json" = ProblemDetails), ("application/json" = ErrorResponse))),
+    ),
+    security(
+        ("bearer_auth" = []),
+        ("api_key" = [])
+    )
+)]
+pub async fn start_recalculation(
+    State(state): State<AppState>,
+    Extension(auth_user): Extension<AuthUser>,
+    Query(params): Query<RecalculateParams>,
+) -> Result<(HeaderMap, ProgressStream), HandlerError> {
+    require_permission(&auth_user, RECALCULATE_PERMISSION)?;
+    let concurrency = concurrency(&auth_user, params.concurrency)?;
+    let engine = state
+        .core
+        .orchestrator
+        .registry()
+        .get(&params.engine)
+        .ok_or_else(|| engine_error_to_response(EngineError::EngineNotFound(params.engine.clone())))?;
+    let engine_version = engine.engine_version().to_string();
+    if let Some(since_version) = params.since_version {
+        if engine_version.parse::<u32>().map_or(true, |deployed| deployed < since_version) {
+            return Err(engine_error_to_response(EngineError::invalid_field(
+                "since_version",
+                ValidationCode::OutOfRange,
+                format!(
+                    "{} calculates with version {}; version {} is not deployed yet",
+                    params.engine, engine_version, since_version
+                ),
+            )));
+        }
+    }
+
+    let profiles = state.user_repository.list_birth_profiles().await.map_err(database_error)?;
+    let job = start(&state, &params.engine, &engine_version, items(profiles), concurrency, None)?;
+    tracing::info!(job_id = %job.id(), user_id = %auth_user.user_id, "Bulk recalculation requested");
+    Ok((location(&job), progress_stream(job)))
+}
+
+/// GET /api/v1/admin/recalculate/:job_id -- counts and failures of a recent job
+#[utoipa::path(
+    get,
+    path = "/api/v1/admin/recalculate/{job_id}",
+    tag = "admin",
+    params(("job_id" = String, Path, description = "Job id from the `Location` header or any progress event")),
+    responses(
+        (status = 200, description = "The job, running or finished", body = RecalculationJobResponse),
+        (status = 401, description = "Unauthorized", content(("application/problem+json" = ProblemDetails), ("application/json" = ErrorResponse))),
+        (status = 403, description = "Missing admin:recalculate permission", content(("application/problem+json" = ProblemDetails), ("application/json" = ErrorResponse))),
+        (status = 404, description = "No such job among the recent ones", content(("application/problem+json" = ProblemDetails), ("application/json" = ErrorResponse))),
+    ),
+    security(
+        ("bearer_auth" = []),
+        ("api_key" = [])
+    )
+)]
+pub async fn get_recalculation(
+    State(state): State<AppState>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(job_id): Path<String>,
+) -> Result<Json<RecalculationJobResponse>, HandlerError> {
+    require_permission(&auth_user, RECALCULATE_PERMISSION)?;
+    let job = find_job(&state, &job_id)?;
+    Ok(Json(job.snapshot()))
+}
+
+/// POST /api/v1/admin/recalculate/:job_id/retry -- recalculate a finished job's failures
+///
+/// Starts a new job, streamed like the first, over the profiles that failed.
+/// Their birth data is read again, so corrected profiles are picked up.
+#[utoipa::path(
+    post,
+    path = "/api/v1/admin/recalculate/{job_id}/retry",
+    tag = "admin",
+    params(
+        ("job_id" = String, Path, description = "Finished job whose failures to retry"),
+        ("concurrency" = Option<usize>, Query, description = "Profiles recalculated at once; defaults to 4, capped at the caller's tier limit"),
+    ),
+    responses(
+        (status = 200, description = "text/event-stream of the retry job, as for `POST /api/v1/admin/recalculate`", content_type = "text/event-stream", body = RecalculationJobResponse),
+        (status = 401, description = "Unauthorized", content(("application/problem+json" = ProblemDetails), ("application/json" = ErrorResponse))),
+        (status = 403, description = "Missing admin:recalculate permission", content(("application/problem+json" = ProblemDetails), ("application/json" = ErrorResponse))),
+        (status = 404, description = "No such job, or its engine is no longer registered", content(("application/problem+json" = ProblemDetails), ("application/json" = ErrorResponse))),
+        (status = 409, description = "The job, or another recalculation of its engine, is still running", content(("application/problem+json" = ProblemDetails), ("application/json" = ErrorResponse))),
+        (status = 422, description = "Zero concurrency", content(("application/problem+json" = ProblemDetails), ("application/json" = ErrorResponse))),
+        (status = 503, description = "Database unavailable", content(("application/problem+json" = ProblemDetails), ("application/json" = ErrorResponse))),
+    ),
+    security(
+        ("bearer_auth" = []),
+        ("api_key" = [])
+    )
+)]
+pub async fn retry_recalculation(
+    State(state): State<AppState>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(job_id): Path<String>,
+    Query(params): Query<RetryParams>,
+) -> Result<(HeaderMap, ProgressStream), HandlerError> {
+    require_permission(&auth_user, RECALCULATE_PERMISSION)?;
+    let concurrency = concurrency(&auth_user, params.concurrency)?;
+    let failed = find_job(&state, &job_id)?;
+    let job_id = failed.id();
+    if failed.is_running() {
+        return Err(job_running(job_id));
+    }
+    let progress = failed.progress();
+    let engine = state
+        .core
+        .orchestrator
+        .registry()
+        .get(&progress.engine_id)
+        .ok_or_else(|| engine_error_to_response(EngineError::EngineNotFound(progress.engine_id.clone())))?;
+
+    let profiles = state
+        .user_repository
+        .get_birth_profiles(&failed.failed_user_ids())
+        .await
+        .map_err(database_error)?;
+    let job = start(
+        &state,
+        &progress.engine_id,
+        engine.engine_version(),
+        items(profiles),
+        concurrency,
+        Some(job_id),
+    )?;
+    tracing::info!(job_id = %job.id(), retry_of = %job_id, user_id = %auth_user.user_id, "Bulk recalculation retried");
+    Ok((location(&job), progress_stream(job)))
+}
+
+/// The requested concurrency, capped at the caller's tier limit
+fn concurrency(auth_user: &AuthUser, requested: Option<usize>) -> Result<usize, HandlerError> {
+    let requested = requested.unwrap_or(DEFAULT_CONCURRENCY);
+    if requested == 0 {
+        return Err(engine_error_to_response(EngineError::invalid_field(
+            "concurrency",
+            ValidationCode::OutOfRange,
+            "concurrency must be at least 1",
+        )));
+    }
+    let limit = AuthService::get_tier_limits(&auth_user.tier).max_concurrent_calculations as usize;
+    Ok(requested.min(limit))
+}
+
+fn items(profiles: Vec<noesis_data::models::user::BirthProfile>) -> Vec<RecalculationItem> {
+    profiles
+        .iter()
+        .filter_map(|p| {
+            Some(RecalculationItem {
+                user_id: p.profile.user_id,
+                birth_data: birth_data_from_profile(&p.profile, &p.full_name)?,
+            })
+        })
+        .collect()
+}
+
+fn start(
+    state: &AppState,
+    engine_id: &str,
+    engine_version: &str,
+    items: Vec<RecalculationItem>,
+    concurrency: usize,
+    retry_of: Option<Uuid>,
+) -> Result<Arc<RecalculationJob>, HandlerError> {
+    state
+        .recalculations
+        .start(state.core.orchestrator.clone(), engine_id, engine_version, items, concurrency, retry_of)
+        .map_err(job_running)
+}
+
+fn find_job(state: &AppState, job_id: &str) -> Result<Arc<RecalculationJob>, HandlerError> {
+    Uuid::parse_str(job_id)
+        .ok()
+        .and_then(|id| state.recalculations.get(id))
+        .ok_or_else(|| not_found("RECALCULATION_NOT_FOUND", format!("Recalculation job {} not found", job_id)))
+}
+
+fn job_running(job_id: Uuid) -> HandlerError {
+    (
+        StatusCode::CONFLICT,
+        Json(ErrorResponse {
+            error: format!("Recalculation job {} is still running", job_id),
+            error_code: "RECALCULATION_RUNNING".to_string(),
+            details: Some(serde_json::json!({ "job_id": job_id })),
+            request_id: None,
+        }),
+    )
+}
+
+fn location(job: &RecalculationJob) -> HeaderMap {
+    let mut headers = HeaderMap::new();
+    if let Ok(value) = HeaderValue::from_str(&format!("/api/v1/admin/recalculate/{}", job.id())) {
+        headers.insert(LOCATION, value);
+    }
+    headers
+}
+
+/// `progress` events as the counts change, then a `complete` event with the
+/// failures, after which the stream ends
+fn progress_stream(job: Arc<RecalculationJob>) -> ProgressStream {
+    let receiver = job.subscribe();
+    let events = futures::stream::unfold(Some((job, receiver)), |state| async move {
+        let (job, mut receiver) = state?;
+        // The job holds the sender, so this only fails once it is gone
+        receiver.changed().await.ok()?;
+        let progress = receiver.borrow_and_update().clone();
+        if progress.status == RecalculationStatus::Completed {
+            let data = serde_json::to_string(&job.snapshot()).unwrap_or_default();
+            return Some((Ok(Event::default().event("complete").data(data)), None));
+        }
+        let data = serde_json::to_string(&progress).unwrap_or_default();
+        Some((Ok(Event::default().event("progress").data(data)), Some((job, receiver))))
+    });
+    Sse::new(Box::pin(events) as futures::stream::BoxStream<'static, _>).keep_alive(KeepAlive::default())
+}
@@ -0,0 +1,192 @@
+use axum::{
+    extract::{Extension, Json, Path, Query, State},
+    http::{header::LOCATION, HeaderMap, HeaderValue, StatusCode},
+};
+use chrono::{DateTime, Utc};
+use noesis_auth::AuthUser;
+use noesis_core::{EngineError, ValidationCode};
+use serde::Deserialize;
+use uuid::Uuid;
+
+use super::{not_found, HandlerError};
+use crate::research_export::{
+    ResearchExport, ResearchExportProgress, ResearchExportRequest, DEFAULT_BATCH_SIZE, MAX_BATCH_SIZE,
+};
+use crate::{engine_error_to_response, require_permission, AppState, ErrorResponse};
+
+/// Permission required for `/api/v1/admin/research-exports`
+const RESEARCH_EXPORT_PERMISSION: &str = "admin:research-export";
+
+#[derive(Deserialize)]
+pub struct ResearchExportParams {
+    /// Comma-separated engines; every engine with a research schema when absent
+    pub engines: Option<String>,
+    /// Only results saved at or after this time
+    pub since: Option<DateTime<Utc>>,
+    /// Rows per Parquet file
+    pub batch_size: Option<usize>,
+}
+
+/// POST /api/v1/admin/research-exports -- export saved results as Parquet
+///
+/// Reads every saved result, redacted and with pseudonymous subjects, into
+/// one set of Parquet files per engine in the research export bucket. The
+/// export runs in the background; poll the job in the `Location` header for
+/// its files.
+#[utoipa::path(
+    post,
+    path = "/api/v1/admin/research-exports",
+    tag = "admin",
+    params(
+        ("engines" = Option<String>, Query, description = "Comma-separated engines to export, e.g. `human-design,numerology`; every engine with a research schema when absent"),
+        ("since" = Option<String>, Query, description = "RFC 3339 time; only results saved at or after it"),
+        ("batch_size" = Option<usize>, Query, description = "Rows per Parquet file; defaults to 10000, at most 100000"),
+    ),
+    responses(
+        (status = 202, description = "Export started; `Location` names the job", body = ResearchExportProgress),
+        (status = 401, description = "Unauthorized", content(("application/problem+json" = ProblemDetails), ("application/json" = ErrorResponse))),
+        (status = 403, description = "Missing admin:research-export permission", content(("application/problem+json" = ProblemDetails), ("application/json" = ErrorResponse))),
+        (status = 409, description = "An export is already running", content(("application/problem+json" = ProblemDetails), ("application/json" = ErrorResponse))),
+        (status = 422, description = "An engine without a research schema, or a `batch_size` out of range", content(("application/problem+json" = ProblemDetails), ("application/json" = ErrorResponse))),
+        (status = 503, description = "Database unavailable, or no research export bucket configured", content(("application/problem+json" = ProblemDetails), ("application/json" = ErrorResponse))),
+    ),
+    security(
+        ("bearer_auth" = []),
+        ("api_key" = [])
+    )
+)]
+pub async fn start_research_export(
+    State(state): State<AppState>,
+    Extension(auth_user): Extension<AuthUser>,
+    Query(params): Query<ResearchExportParams>,
+) -> Result<(StatusCode, HeaderMap, Json<ResearchExportProgress>), HandlerError> {
+    require_permission(&auth_user, RESEARCH_EXPORT_PERMISSION)?;
+    let request = ResearchExportRequest {
+        engines: engines(params.engines.as_deref())?,
+        since: params.since,
+        batch_size: batch_size(params.batch_size)?,
+    };
+    let store = state.research_exports.store().ok_or_else(not_configured)?;
+
+    let export = state
+        .research_exports
+        .start(
+            state.result_repository.clone(),
+            store,
+            state.core.orchestrator.redaction(),
+            request,
+        )
+        .map_err(export_running)?;
+    tracing::info!(export_id = %export.id(), user_id = %auth_user.user_id, "Research export requested");
+    Ok((StatusCode::ACCEPTED, location(&export), Json(export.progress())))
+}
+
+/// GET /api/v1/admin/research-exports/:export_id -- progress and files of a recent export
+#[utoipa::path(
+    get,
+    path = "/api/v1/admin/research-exports/{export_id}",
+    tag = "admin",
+    params(("export_id" = String, Path, description = "Export id from the `Location` header")),
+    responses(
+        (status = 200, description = "The export, running or finished", body = ResearchExportProgress),
+        (status = 401, description = "Unauthorized", content(("application/problem+json" = ProblemDetails), ("application/json" = ErrorResponse))),
+        (status = 403, description = "Missing admin:research-export permission", content(("application/problem+json" = ProblemDetails), ("application/json" = ErrorResponse))),
+        (status = 404, description = "No such export among the recent ones", content(("application/problem+json" = ProblemDetails), ("application/json" = ErrorResponse))),
+    ),
+    security(
+        ("bearer_auth" = []),
+        ("api_key" = [])
+    )
+)]
+pub async fn get_research_export(
+    State(state): State<AppState>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(export_id): Path<String>,
+) -> Result<Json<ResearchExportProgress>, HandlerError> {
+    require_permission(&auth_user, RESEARCH_EXPORT_PERMISSION)?;
+    let export = Uuid::parse_str(&export_id)
+        .ok()
+        .and_then(|id| state.research_exports.get(id))
+        .ok_or_else(|| {
+            not_found(
+                "RESEARCH_EXPORT_NOT_FOUND",
+                format!("Research export {} not found", export_id),
+            )
+        })?;
+    Ok(Json(export.progress()))
+}
+
+/// The requested engines, each with a research schema; all of them when absent
+fn engines(requested: Option<&str>) -> Result<Vec<String>, HandlerError> {
+    let Some(requested) = requested else {
+        return Ok(noesis_export::ENGINE_SCHEMAS
+            .iter()
+            .map(|schema| schema.engine_id.to_string())
+            .collect());
+    };
+    let engines: Vec<String> = requested
+        .split(',')
+        .map(str::trim)
+        .filter(|engine| !engine.is_empty())
+        .map(str::to_string)
+        .collect();
+    if engines.is_empty() {
+        return Err(invalid("engines", ValidationCode::Empty, "engines must name at least one engine".to_string()));
+    }
+    if let Some(engine) = engines.iter().find(|engine| noesis_export::engine_schema(engine).is_none()) {
+        return Err(invalid(
+            "engines",
+            ValidationCode::Unknown,
+            format!("{} has no research export schema", engine),
+        ));
+    }
+    Ok(engines)
+}
+
+fn batch_size(requested: Option<usize>) -> Result<usize, HandlerError> {
+    let batch_size = requested.unwrap_or(DEFAULT_BATCH_SIZE);
+    if !(1..=MAX_BATCH_SIZE).contains(&batch_size) {
+        return Err(invalid(
+            "batch_size",
+            ValidationCode::OutOfRange,
+            format!("batch_size must be between 1 and {}", MAX_BATCH_SIZE),
+        ));
+    }
+    Ok(batch_size)
+}
+
+fn invalid(field: &str, code: ValidationCode, message: String) -> HandlerError {
+    engine_error_to_response(EngineError::invalid_field(field, code, message))
+}
+
+fn not_configured() -> HandlerError {
+    (
+        StatusCode::SERVICE_UNAVAILABLE,
+        Json(ErrorResponse {
+            error: "No research export bucket is configured; set RESEARCH_EXPORT_S3_BUCKET".to_string(),
+            error_code: "RESEARCH_EXPORT_NOT_CONFIGURED".to_string(),
+            details: None,
+            request_id: None,
+        }),
+    )
+}
+
+fn export_running(export_id: Uuid) -> HandlerError {
+    (
+        StatusCode::CONFLICT,
+        Json(ErrorResponse {
+            error: format!("Research export {} is still running", export_id),
+            error_code: "RESEARCH_EXPORT_RUNNING".to_string(),
+            details: Some(serde_json::json!({ "export_id": export_id })),
+            request_id: None,
+        }),
+    )
+}
+
+fn location(export: &ResearchExport) -> HeaderMap {
+    let mut headers = HeaderMap::new();
+    if let Ok(value) = HeaderValue::from_str(&format!("/api/v1/admin/research-exports/{}", export.id())) {
+        headers.insert(LOCATION, value);
+    }
+    headers
+}
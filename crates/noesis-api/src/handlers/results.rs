@@ -0,0 +1,583 @@
+use std::collections::HashMap;
+
+use axum::{
+    extract::{Extension, Json, Path, Query, State},
+    http::StatusCode,
+    response::{IntoResponse, Response},
+};
+use chrono::{DateTime, Duration, Utc};
+use noesis_auth::{permissions, AuthUser};
+use noesis_core::redaction::RedactionTarget;
+use noesis_core::{EngineError, EngineInput, EngineOutput, WorkflowResult, DEBUG_KEY, DEBUG_OPTION};
+use noesis_data::models::workflow_result::{NewAnnotation, ResultAnnotation, SavedWorkflowResult};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use super::{database_error, not_found, user_uuid, HandlerError};
+use crate::annotations::{self, Correction};
+use crate::sharing::ShareTokenError;
+use crate::{engine_error_to_response, require_permission, AppState, ErrorResponse, DEBUG_PERMISSION};
+
+#[derive(Serialize, ToSchema)]
+pub struct SavedResultResponse {
+    pub id: String,
+    /// Client the result was calculated for, absent for the user's own readings
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client_id: Option<String>,
+    pub workflow_id: String,
+    pub created_at: DateTime<Utc>,
+    /// The result with its annotations' corrections applied, unless
+    /// `original=true` was requested
+    pub result: WorkflowResult,
+    /// Notes and corrections, oldest first
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub annotations: Vec<AnnotationResponse>,
+}
+
+#[derive(Deserialize, Default)]
+pub struct ResultQuery {
+    /// Return the result as calculated, without corrections
+    #[serde(default)]
+    pub original: bool,
+}
+
+/// A note on a saved result, a correction of one of its values, or both
+#[derive(Deserialize, ToSchema)]
+pub struct CreateAnnotationRequest {
+    /// Key of the engine output in `engine_outputs`; required with `path`
+    #[schema(example = "human-design")]
+    pub engine_id: Option<String>,
+    /// JSON pointer to the corrected value in the engine's `result`
+    #[schema(example = "/authority")]
+    pub path: Option<String>,
+    #[schema(example = "Emotional authority: the solar plexus is defined in the rectified chart")]
+    pub note: Option<String>,
+    /// Replaces the value at `path` in later fetches; the calculation stays
+    /// stored unchanged
+    #[schema(value_type = Object, example = "Emotional")]
+    pub value: Option<serde_json::Value>,
+}
+
+#[derive(Serialize, ToSchema)]
+pub struct AnnotationResponse {
+    pub id: String,
+    /// Who wrote it; absent on shared results
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub author_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub engine_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub path: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub note: Option<String>,
+    /// The corrected value next to the computed one
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub correction: Option<Correction>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl AnnotationResponse {
+    fn new(annotation: ResultAnnotation, corrections: &mut HashMap<Uuid, Correction>) -> Self {
+        Self {
+            id: annotation.id.to_string(),
+            author_id: Some(annotation.author_id.to_string()),
+            correction: corrections.remove(&annotation.id),
+            engine_id: annotation.engine_id,
+            path: annotation.path,
+            note: annotation.note,
+            created_at: annotation.created_at,
+        }
+    }
+}
+
+/// A saved result next to its re-execution with intermediate values
+#[derive(Serialize, ToSchema)]
+pub struct ReplayResponse {
+    pub result_id: String,
+    pub workflow_id: String,
+    /// The result as saved, without annotation corrections
+    pub original: WorkflowResult,
+    /// The saved input run again with `options.debug`: engines that have
+    /// intermediate values add them to their result under `debug`
+    pub replay: WorkflowResult,
+    /// Output keys whose result, `debug` aside, differs from the original,
+    /// e.g. after an engine fix or for an unseeded stochastic engine
+    #[schema(example = json!(["panchanga"]))]
+    pub changed: Vec<String>,
+}
+
+#[derive(Deserialize, Default, ToSchema)]
+pub struct ShareResultRequest {
+    /// Link lifetime in seconds; defaults to and may not exceed `SHARE_LINK_TTL_SECS`
+    #[schema(example = 86400)]
+    pub expires_in_secs: Option<u64>,
+}
+
+#[derive(Serialize, ToSchema)]
+pub struct ShareLinkResponse {
+    /// Read-only URL, usable without credentials until `expires_at`
+    #[schema(example = "https://api.example.com/api/v1/shared/<token>")]
+    pub url: String,
+    pub token: String,
+    pub expires_at: DateTime<Utc>,
+}
+
+/// Read-only view of a shared result; omits the owner, and personal data is
+/// redacted according to `REDACT_SHARING`
+#[derive(Serialize, ToSchema)]
+pub struct SharedResultResponse {
+    pub workflow_id: String,
+    pub created_at: DateTime<Utc>,
+    /// The result with its annotations' corrections applied
+    pub result: WorkflowResult,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub annotations: Vec<AnnotationResponse>,
+}
+
+/// GET /api/v1/results/:id -- a result saved by the authenticated user
+#[utoipa::path(
+    get,
+    path = "/api/v1/results/{id}",
+    tag = "results",
+    params(
+        ("id" = String, Path, description = "Saved result id, from the `X-Result-Id` header of a saved execution"),
+        ("original" = Option<bool>, Query, description = "Return the result as calculated, without the annotations' corrections"),
+    ),
+    responses(
+        (status = 200, description = "Saved result and its annotations", body = SavedResultResponse),
+        (status = 401, description = "Unauthorized", content(("application/problem+json" = ProblemDetails), ("application/json" = ErrorResponse))),
+        (status = 404, description = "No result with this id belongs to the user", content(("application/problem+json" = ProblemDetails), ("application/json" = ErrorResponse))),
+    ),
+    security(
+        ("bearer_auth" = []),
+        ("api_key" = [])
+    )
+)]
+pub async fn get_result(
+    State(state): State<AppState>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(id): Path<String>,
+    Query(query): Query<ResultQuery>,
+) -> Result<Json<SavedResultResponse>, HandlerError> {
+    let saved = find_owned_result(&state, &auth_user, &id).await?;
+    let (result, annotations) = annotated_result(&state, &saved, !query.original).await?;
+
+    Ok(Json(SavedResultResponse {
+        id: saved.id.to_string(),
+        client_id: saved.client_id.map(|id| id.to_string()),
+        workflow_id: saved.workflow_id,
+        created_at: saved.created_at,
+        result,
+        annotations,
+    }))
+}
+
+/// POST /api/v1/results/:id/annotations -- annotate or correct a saved result
+#[utoipa::path(
+    post,
+    path = "/api/v1/results/{id}/annotations",
+    tag = "results",
+    params(("id" = String, Path, description = "Saved result id")),
+    request_body = CreateAnnotationRequest,
+    responses(
+        (status = 201, description = "Annotation added", body = AnnotationResponse),
+        (status = 401, description = "Unauthorized", content(("application/problem+json" = ProblemDetails), ("application/json" = ErrorResponse))),
+        (status = 403, description = "Result belongs to a client and the caller lacks clients:write", content(("application/problem+json" = ProblemDetails), ("application/json" = ErrorResponse))),
+        (status = 404, description = "No result with this id belongs to the user", content(("application/problem+json" = ProblemDetails), ("application/json" = ErrorResponse))),
+        (status = 422, description = "Neither note nor value, or the path is not in the engine's result", content(("application/problem+json" = ProblemDetails), ("application/json" = ErrorResponse))),
+    ),
+    security(
+        ("bearer_auth" = []),
+        ("api_key" = [])
+    )
+)]
+pub async fn create_annotation(
+    State(state): State<AppState>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(id): Path<String>,
+    Json(payload): Json<CreateAnnotationRequest>,
+) -> Result<Response, HandlerError> {
+    let saved = find_owned_result(&state, &auth_user, &id).await?;
+    if saved.client_id.is_some() {
+        require_permission(&auth_user, permissions::CLIENTS_WRITE)?;
+    }
+    let annotation = NewAnnotation {
+        engine_id: payload.engine_id,
+        path: payload.path,
+        note: payload.note.map(|note| note.trim().to_string()),
+        value: payload.value,
+    };
+    annotations::validate(&annotation, &parse_result(&saved)?).map_err(engine_error_to_response)?;
+
+    let created = state
+        .result_repository
+        .add_annotation(saved.id, user_uuid(&auth_user)?, &annotation)
+        .await
+        .map_err(database_error)?;
+    // Apply the result's other corrections too, so the response shows
+    // whether this one is in effect. The list comes from the read pool,
+    // which may not have the new row yet.
+    let mut existing = state
+        .result_repository
+        .list_annotations(saved.id)
+        .await
+        .map_err(database_error)?;
+    existing.retain(|annotation| annotation.id != created.id);
+    existing.push(created);
+    let (_, mut annotations) = annotate(parse_result(&saved)?, existing, true);
+    let response = annotations.pop();
+
+    Ok((StatusCode::CREATED, Json(response)).into_response())
+}
+
+/// DELETE /api/v1/results/:id/annotations/:annotation_id -- remove an annotation
+#[utoipa::path(
+    delete,
+    path = "/api/v1/results/{id}/annotations/{annotation_id}",
+    tag = "results",
+    params(
+        ("id" = String, Path, description = "Saved result id"),
+        ("annotation_id" = String, Path, description = "Annotation id"),
+    ),
+    responses(
+        (status = 204, description = "Annotation removed; a correction it made no longer applies"),
+        (status = 401, description = "Unauthorized", content(("application/problem+json" = ProblemDetails), ("application/json" = ErrorResponse))),
+        (status = 403, description = "Result belongs to a client and the caller lacks clients:write", content(("application/problem+json" = ProblemDetails), ("application/json" = ErrorResponse))),
+        (status = 404, description = "No such result or annotation", content(("application/problem+json" = ProblemDetails), ("application/json" = ErrorResponse))),
+    ),
+    security(
+        ("bearer_auth" = []),
+        ("api_key" = [])
+    )
+)]
+pub async fn delete_annotation(
+    State(state): State<AppState>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path((id, annotation_id)): Path<(String, String)>,
+) -> Result<StatusCode, HandlerError> {
+    let saved = find_owned_result(&state, &auth_user, &id).await?;
+    if saved.client_id.is_some() {
+        require_permission(&auth_user, permissions::CLIENTS_WRITE)?;
+    }
+    let annotation_not_found =
+        || not_found("ANNOTATION_NOT_FOUND", format!("Annotation '{}' not found", annotation_id));
+    let annotation_uuid = Uuid::parse_str(&annotation_id).map_err(|_| annotation_not_found())?;
+
+    let deleted = state
+        .result_repository
+        .delete_annotation(annotation_uuid, saved.id)
+        .await
+        .map_err(database_error)?;
+    if deleted {
+        Ok(StatusCode::NO_CONTENT)
+    } else {
+        Err(annotation_not_found())
+    }
+}
+
+/// POST /api/v1/results/:id/share -- create a signed read-only link
+#[utoipa::path(
+    post,
+    path = "/api/v1/results/{id}/share",
+    tag = "results",
+    params(("id" = String, Path, description = "Saved result id")),
+    request_body(content = ShareResultRequest, description = "Optional; omit for the default lifetime"),
+    responses(
+        (status = 201, description = "Share link created", body = ShareLinkResponse),
+        (status = 401, description = "Unauthorized", content(("application/problem+json" = ProblemDetails), ("application/json" = ErrorResponse))),
+        (status = 403, description = "Result belongs to a client who has not consented to sharing", content(("application/problem+json" = ProblemDetails), ("application/json" = ErrorResponse))),
+        (status = 404, description = "No result with this id belongs to the user", content(("application/problem+json" = ProblemDetails), ("application/json" = ErrorResponse))),
+        (status = 422, description = "Lifetime is zero or above the maximum", content(("application/problem+json" = ProblemDetails), ("application/json" = ErrorResponse))),
+    ),
+    security(
+        ("bearer_auth" = []),
+        ("api_key" = [])
+    )
+)]
+pub async fn share_result(
+    State(state): State<AppState>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(id): Path<String>,
+    payload: Option<Json<ShareResultRequest>>,
+) -> Result<Response, HandlerError> {
+    let payload = payload.map(|Json(p)| p).unwrap_or_default();
+    let max_ttl = state.share_links.max_ttl();
+    let ttl = match payload.expires_in_secs {
+        None => max_ttl,
+        Some(secs) if secs == 0 || secs > max_ttl.num_seconds() as u64 => {
+            return Err(engine_error_to_response(EngineError::ValidationError(format!(
+                "expires_in_secs must be between 1 and {}",
+                max_ttl.num_seconds()
+            ))));
+        }
+        Some(secs) => Duration::seconds(secs as i64),
+    };
+
+    let saved = find_owned_result(&state, &auth_user, &id).await?;
+    if let Some(client_id) = saved.client_id {
+        let consented = state
+            .client_repository
+            .get_client(client_id)
+            .await
+            .map_err(database_error)?
+            .is_some_and(|client| client.consent_sharing);
+        if !consented {
+            return Err(super::clients::consent_required(client_id, "sharing"));
+        }
+    }
+
+    let share = state
+        .result_repository
+        .create_share(saved.id, Utc::now() + ttl)
+        .await
+        .map_err(database_error)?;
+
+    let token = state.share_links.sign(share.id, share.expires_at);
+    let response = ShareLinkResponse {
+        url: state.share_links.url(&token),
+        token,
+        expires_at: share.expires_at,
+    };
+
+    Ok((StatusCode::CREATED, Json(response)).into_response())
+}
+
+/// GET /api/v1/shared/:token -- public read-only view of a shared result
+#[utoipa::path(
+    get,
+    path = "/api/v1/shared/{token}",
+    tag = "results",
+    params(("token" = String, Path, description = "Token from a share link")),
+    responses(
+        (status = 200, description = "Shared result", body = SharedResultResponse),
+        (status = 404, description = "Unknown, forged or revoked link", content(("application/problem+json" = ProblemDetails), ("application/json" = ErrorResponse))),
+        (status = 410, description = "Link has expired", content(("application/problem+json" = ProblemDetails), ("application/json" = ErrorResponse))),
+    )
+)]
+pub async fn get_shared_result(
+    State(state): State<AppState>,
+    Path(token): Path<String>,
+) -> Result<Json<SharedResultResponse>, HandlerError> {
+    let share_id = match state.share_links.verify(&token, Utc::now()) {
+        Ok(share_id) => share_id,
+        Err(ShareTokenError::Expired) => {
+            return Err((
+                StatusCode::GONE,
+                Json(ErrorResponse {
+                    error: "Share link has expired".to_string(),
+                    error_code: "SHARE_LINK_EXPIRED".to_string(),
+                    details: None,
+                    request_id: None,
+                }),
+            ));
+        }
+        Err(_) => return Err(share_link_not_found()),
+    };
+
+    let saved = state
+        .result_repository
+        .get_shared_result(share_id)
+        .await
+        .map_err(database_error)?
+        .ok_or_else(share_link_not_found)?;
+    let (mut result, mut annotations) = annotated_result(&state, &saved, true).await?;
+    // Results saved before inputs were kept only have the redacted keys removed
+    let input = saved.input.clone().and_then(|input| serde_json::from_value::<EngineInput>(input).ok());
+    let redaction = state.core.orchestrator.redaction();
+    redaction.redact_workflow_result(&mut result, RedactionTarget::Sharing, input.as_ref());
+    for annotation in &mut annotations {
+        annotation.author_id = None;
+        if let Some(note) = annotation.note.as_mut() {
+            redaction.redact_text(note, RedactionTarget::Sharing, input.as_ref());
+        }
+        // A correction of a redacted value would reveal it
+        let redacted = match (&annotation.engine_id, &annotation.path) {
+            (Some(engine_id), Some(path)) => result
+                .engine_outputs
+                .get(engine_id)
+                .is_none_or(|output| output.result.pointer(path).is_none()),
+            _ => false,
+        };
+        if redacted {
+            annotation.correction = None;
+        }
+    }
+
+    Ok(Json(SharedResultResponse {
+        workflow_id: saved.workflow_id,
+        created_at: saved.created_at,
+        result,
+        annotations,
+    }))
+}
+
+/// POST /api/v1/admin/results/:id/replay -- re-execute a saved result with debug output
+///
+/// Runs the saved input through the workflow again, at the phase and
+/// `current_time` of the original calculation, with `options.debug` set.
+/// Works on any user's result; nothing is saved.
+#[utoipa::path(
+    post,
+    path = "/api/v1/admin/results/{id}/replay",
+    tag = "admin",
+    params(("id" = String, Path, description = "Saved result id")),
+    responses(
+        (status = 200, description = "The saved result and its replay", body = ReplayResponse),
+        (status = 401, description = "Unauthorized", content(("application/problem+json" = ProblemDetails), ("application/json" = ErrorResponse))),
+        (status = 403, description = "Missing admin:debug permission", content(("application/problem+json" = ProblemDetails), ("application/json" = ErrorResponse))),
+        (status = 404, description = "No result with this id", content(("application/problem+json" = ProblemDetails), ("application/json" = ErrorResponse))),
+        (status = 409, description = "The result was saved without its input", content(("application/problem+json" = ProblemDetails), ("application/json" = ErrorResponse))),
+        (status = 503, description = "Database unavailable", content(("application/problem+json" = ProblemDetails), ("application/json" = ErrorResponse))),
+    ),
+    security(
+        ("bearer_auth" = []),
+        ("api_key" = [])
+    )
+)]
+pub async fn replay_result(
+    State(state): State<AppState>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(id): Path<String>,
+) -> Result<Json<ReplayResponse>, HandlerError> {
+    require_permission(&auth_user, DEBUG_PERMISSION)?;
+    let result_id = Uuid::parse_str(&id).map_err(|_| result_not_found(&id))?;
+    let saved = state
+        .result_repository
+        .get_result(result_id)
+        .await
+        .map_err(database_error)?
+        .ok_or_else(|| result_not_found(&id))?;
+    let original = parse_result(&saved)?;
+    let (Some(input), Some(phase)) = (&saved.input, saved.phase) else {
+        return Err((
+            StatusCode::CONFLICT,
+            Json(ErrorResponse {
+                error: format!("Result '{}' was saved without its input and cannot be replayed", id),
+                error_code: "RESULT_NOT_REPLAYABLE".to_string(),
+                details: Some(serde_json::json!({ "result_id": id })),
+                request_id: None,
+            }),
+        ));
+    };
+    let mut input: EngineInput = serde_json::from_value(input.clone()).map_err(|e| {
+        engine_error_to_response(EngineError::InternalError(format!(
+            "Stored input of result {} is unreadable: {}",
+            saved.id, e
+        )))
+    })?;
+    input.options.insert(DEBUG_OPTION.to_string(), serde_json::Value::Bool(true));
+
+    let replay = state
+        .core
+        .execute_workflow(&saved.workflow_id, input, u8::try_from(phase).unwrap_or_default())
+        .await
+        .map_err(engine_error_to_response)?;
+    tracing::info!(result_id = %saved.id, user_id = %auth_user.user_id, "Saved result replayed with debug output");
+
+    Ok(Json(ReplayResponse {
+        result_id: saved.id.to_string(),
+        workflow_id: saved.workflow_id,
+        changed: changed_outputs(&original, &replay),
+        original,
+        replay,
+    }))
+}
+
+/// Output keys whose result differs between `original` and `replay`, the
+/// replay's debug values aside, sorted
+fn changed_outputs(original: &WorkflowResult, replay: &WorkflowResult) -> Vec<String> {
+    let result = |outputs: &HashMap<String, EngineOutput>, key: &str| {
+        outputs.get(key).map(|output| {
+            let mut result = output.result.clone();
+            if let Some(fields) = result.as_object_mut() {
+                fields.remove(DEBUG_KEY);
+            }
+            result
+        })
+    };
+    let mut keys: Vec<&String> = original.engine_outputs.keys().chain(replay.engine_outputs.keys()).collect();
+    keys.sort();
+    keys.dedup();
+    keys.into_iter()
+        .filter(|key| result(&original.engine_outputs, key) != result(&replay.engine_outputs, key))
+        .cloned()
+        .collect()
+}
+
+fn result_not_found(id: &str) -> HandlerError {
+    (
+        StatusCode::NOT_FOUND,
+        Json(ErrorResponse {
+            error: format!("Result '{}' not found", id),
+            error_code: "RESULT_NOT_FOUND".to_string(),
+            details: Some(serde_json::json!({ "result_id": id })),
+            request_id: None,
+        }),
+    )
+}
+
+async fn find_owned_result(
+    state: &AppState,
+    auth_user: &AuthUser,
+    id: &str,
+) -> Result<SavedWorkflowResult, HandlerError> {
+    let user_uuid = user_uuid(auth_user)?;
+    let result_id = Uuid::parse_str(id).map_err(|_| result_not_found(id))?;
+
+    state
+        .result_repository
+        .get_result_for_user(result_id, user_uuid)
+        .await
+        .map_err(database_error)?
+        .ok_or_else(|| result_not_found(id))
+}
+
+/// `saved`'s result, corrected by its annotations when `apply` is set, and
+/// the annotations
+async fn annotated_result(
+    state: &AppState,
+    saved: &SavedWorkflowResult,
+    apply: bool,
+) -> Result<(WorkflowResult, Vec<AnnotationResponse>), HandlerError> {
+    let annotations = state
+        .result_repository
+        .list_annotations(saved.id)
+        .await
+        .map_err(database_error)?;
+    Ok(annotate(parse_result(saved)?, annotations, apply))
+}
+
+fn annotate(
+    mut result: WorkflowResult,
+    annotations: Vec<ResultAnnotation>,
+    apply: bool,
+) -> (WorkflowResult, Vec<AnnotationResponse>) {
+    let mut corrections = annotations::apply_corrections(&mut result, &annotations, apply);
+    let annotations = annotations
+        .into_iter()
+        .map(|annotation| AnnotationResponse::new(annotation, &mut corrections))
+        .collect();
+    (result, annotations)
+}
+
+fn parse_result(saved: &SavedWorkflowResult) -> Result<WorkflowResult, HandlerError> {
+    serde_json::from_value(saved.result.clone()).map_err(|e| {
+        engine_error_to_response(EngineError::InternalError(format!(
+            "Stored result {} is unreadable: {}",
+            saved.id, e
+        )))
+    })
+}
+
+fn share_link_not_found() -> HandlerError {
+    (
+        StatusCode::NOT_FOUND,
+        Json(ErrorResponse {
+            error: "Share link not found".to_string(),
+            error_code: "SHARE_LINK_NOT_FOUND".to_string(),
+            details: None,
+            request_id: None,
+        }),
+    )
+}
@@ -0,0 +1,61 @@
+use axum::extract::{Extension, Json, State};
+use noesis_auth::AuthUser;
+use serde_json::{Map, Value};
+
+use super::{database_error, user_uuid, HandlerError};
+use crate::settings::UserSettings;
+use crate::{engine_error_to_response, AppState};
+
+/// GET /api/v1/users/me/settings -- calculation settings applied to every request
+#[utoipa::path(
+    get,
+    path = "/api/v1/users/me/settings",
+    tag = "users",
+    responses(
+        (status = 200, description = "Saved settings; unset ones are omitted", body = UserSettings),
+        (status = 401, description = "Unauthorized", content(("application/problem+json" = ProblemDetails), ("application/json" = ErrorResponse))),
+    ),
+    security(
+        ("bearer_auth" = []),
+        ("api_key" = [])
+    )
+)]
+pub async fn get_settings(
+    State(state): State<AppState>,
+    Extension(auth_user): Extension<AuthUser>,
+) -> Result<Json<UserSettings>, HandlerError> {
+    let user_id = user_uuid(&auth_user)?;
+    let settings = state.settings.get(user_id).await.map_err(database_error)?;
+    Ok(Json(settings))
+}
+
+/// PUT /api/v1/users/me/settings -- replace the saved calculation settings
+///
+/// Saved settings fill in the matching engine options of every calculation
+/// and workflow the user runs; options sent with a request take precedence.
+/// Settings left out of the body are cleared.
+#[utoipa::path(
+    put,
+    path = "/api/v1/users/me/settings",
+    tag = "users",
+    request_body = UserSettings,
+    responses(
+        (status = 200, description = "Settings saved, with values normalized", body = UserSettings),
+        (status = 401, description = "Unauthorized", content(("application/problem+json" = ProblemDetails), ("application/json" = ErrorResponse))),
+        (status = 422, description = "Unknown setting or invalid value", content(("application/problem+json" = ProblemDetails), ("application/json" = ErrorResponse))),
+    ),
+    security(
+        ("bearer_auth" = []),
+        ("api_key" = [])
+    )
+)]
+pub async fn put_settings(
+    State(state): State<AppState>,
+    Extension(auth_user): Extension<AuthUser>,
+    Json(payload): Json<Map<String, Value>>,
+) -> Result<Json<UserSettings>, HandlerError> {
+    let settings = UserSettings::from_json(payload).map_err(engine_error_to_response)?;
+    let user_id = user_uuid(&auth_user)?;
+    let saved = state.settings.put(user_id, &settings).await.map_err(database_error)?;
+    Ok(Json(saved))
+}
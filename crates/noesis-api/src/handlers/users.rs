@@ -5,11 +5,13 @@ use axum::{
 };
 use serde::{Deserialize, Serialize};
 use crate::{AppState, error::ApiError};
-use noesis_core::EngineError;
+use noesis_core::{EngineError, ValidationCode, ValidationError};
 use noesis_auth::AuthUser;
-use chrono::{NaiveDate, NaiveTime, Datelike};
+use chrono::{DateTime, Datelike, Duration, NaiveDate, NaiveTime, Utc};
+use noesis_data::repositories::user_repository::RESTORE_WINDOW_DAYS;
+use utoipa::ToSchema;
 
-#[derive(Serialize)]
+#[derive(Serialize, ToSchema)]
 pub struct UserResponse {
     pub id: String,
     pub email: String,
@@ -24,26 +26,41 @@ pub struct UserResponse {
     pub preferences: serde_json::Value,
 }
 
-#[derive(Serialize)]
+#[derive(Serialize, ToSchema)]
 pub struct LocationResponse {
     pub lat: f64,
     pub lng: f64,
     pub name: Option<String>,
 }
 
-#[derive(Deserialize)]
+#[derive(Deserialize, ToSchema)]
 pub struct UpdateUserRequest {
     pub full_name: Option<String>,
     pub email: Option<String>,
     pub birth_date: Option<NaiveDate>,
     pub birth_time: Option<NaiveTime>,
     pub birth_location_lat: Option<f64>,
+    /// East-positive; values from 180 to 360 are wrapped to negative longitudes
     pub birth_location_lng: Option<f64>,
     pub birth_location_name: Option<String>,
     pub timezone: Option<String>,
     pub preferences: Option<serde_json::Value>,
 }
 
+/// GET /api/v1/users/me -- profile of the authenticated user
+#[utoipa::path(
+    get,
+    path = "/api/v1/users/me",
+    tag = "users",
+    responses(
+        (status = 200, description = "Current user with birth profile", body = UserResponse),
+        (status = 401, description = "Unauthorized", content(("application/problem+json" = ProblemDetails), ("application/json" = ErrorResponse))),
+    ),
+    security(
+        ("bearer_auth" = []),
+        ("api_key" = [])
+    )
+)]
 pub async fn get_me(
     State(state): State<AppState>,
     Extension(auth_user): Extension<AuthUser>,
@@ -94,49 +111,89 @@ pub async fn get_me(
 
 impl UpdateUserRequest {
     fn validate(&self) -> Result<(), EngineError> {
+        let mut errors = Vec::new();
         if let Some(email) = &self.email {
             if !email.contains('@') || !email.contains('.') {
-                return Err(EngineError::ValidationError("Invalid email format".into()));
+                errors.push(ValidationError::new("email", ValidationCode::InvalidFormat, "Invalid email format"));
             }
         }
         if let Some(date) = self.birth_date {
             if date.year() < 1000 || date.year() > 3000 {
-                return Err(EngineError::ValidationError(format!("Birth year {} out of supported range (1000-3000)", date.year()).into()));
+                errors.push(ValidationError::new(
+                    "birth_date",
+                    ValidationCode::OutOfRange,
+                    format!("Birth year {} out of supported range (1000-3000)", date.year()),
+                ));
             }
         }
         if let Some(lat) = self.birth_location_lat {
             if lat < -90.0 || lat > 90.0 {
-                return Err(EngineError::ValidationError("Latitude must be between -90 and 90".into()));
+                let swapped = self.birth_location_lng.is_some_and(|lng| noesis_core::geodesy::swapped_coordinates(lat, lng));
+                let message = if swapped {
+                    "Latitude must be between -90 and 90; latitude and longitude look swapped"
+                } else {
+                    "Latitude must be between -90 and 90"
+                };
+                errors.push(ValidationError::new("birth_location_lat", ValidationCode::OutOfRange, message));
             }
         }
         if let Some(lng) = self.birth_location_lng {
             if lng < -180.0 || lng > 180.0 {
-                return Err(EngineError::ValidationError("Longitude must be between -180 and 180".into()));
+                errors.push(ValidationError::new("birth_location_lng", ValidationCode::OutOfRange, "Longitude must be between -180 and 180"));
             }
         }
         if let Some(tz) = &self.timezone {
             if tz.trim().is_empty() {
-                return Err(EngineError::ValidationError("Timezone cannot be empty".into()));
+                errors.push(ValidationError::new("timezone", ValidationCode::Empty, "Timezone cannot be empty"));
+            } else if !noesis_core::timezone::is_known_timezone(tz.trim()) {
+                errors.push(ValidationError::new("timezone", ValidationCode::Unknown, format!("Unknown timezone '{}'", tz)));
             }
         }
-        Ok(())
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(EngineError::InvalidInput(errors))
+        }
     }
 }
 
+/// PATCH /api/v1/users/me -- update account and birth profile fields
+#[utoipa::path(
+    patch,
+    path = "/api/v1/users/me",
+    tag = "users",
+    request_body = UpdateUserRequest,
+    responses(
+        (status = 200, description = "Profile updated", body = Object, example = json!({"message": "Profile updated successfully"})),
+        (status = 401, description = "Unauthorized", content(("application/problem+json" = ProblemDetails), ("application/json" = ErrorResponse))),
+        (status = 422, description = "Validation error", content(("application/problem+json" = ProblemDetails), ("application/json" = ErrorResponse))),
+    ),
+    security(
+        ("bearer_auth" = []),
+        ("api_key" = [])
+    )
+)]
 pub async fn update_me(
     State(state): State<AppState>,
     Extension(auth_user): Extension<AuthUser>,
-    Json(payload): Json<UpdateUserRequest>,
+    Json(mut payload): Json<UpdateUserRequest>,
 ) -> Result<Response, ApiError> {
+    payload.birth_location_lng = payload.birth_location_lng.map(noesis_core::geodesy::normalize_longitude);
     payload.validate()?;
 
      let user_uuid = uuid::Uuid::parse_str(&auth_user.user_id)
         .map_err(|_| EngineError::AuthError("Invalid user ID in token".to_string()))?;
 
+    let birth_fields_changed = payload.birth_date.is_some() || payload.birth_time.is_some() ||
+        payload.birth_location_lat.is_some() || payload.birth_location_lng.is_some() ||
+        payload.timezone.is_some();
+
     // Update User table fields
+    let mut user = None;
     if payload.full_name.is_some() || payload.email.is_some() {
-        state.user_repository.update_user(user_uuid, payload.full_name, payload.email).await
-            .map_err(|e| EngineError::InternalError(format!("Database error: {}", e)))?;
+        user = Some(state.user_repository.update_user(user_uuid, payload.full_name, payload.email).await
+            .map_err(|e| EngineError::InternalError(format!("Database error: {}", e)))?);
     }
 
     // Update Profile table fields
@@ -145,7 +202,7 @@ pub async fn update_me(
        payload.birth_location_name.is_some() || payload.timezone.is_some() || 
        payload.preferences.is_some() {
         
-        state.user_repository.update_profile(
+        let profile = state.user_repository.update_profile(
             user_uuid,
             payload.birth_date,
             payload.birth_time,
@@ -156,11 +213,69 @@ pub async fn update_me(
             payload.preferences,
         ).await
         .map_err(|e| EngineError::InternalError(format!("Database error: {}", e)))?;
+
+        // Warm the cache with natal results once the birth profile is complete
+        if birth_fields_changed {
+            let user = match user {
+                Some(user) => Some(user),
+                None => state.user_repository.get_user_by_id(user_uuid).await
+                    .map_err(|e| EngineError::InternalError(format!("Database error: {}", e)))?,
+            };
+            let full_name = user.map(|u| u.full_name).unwrap_or_default();
+            if let Some(birth_data) = crate::precompute::birth_data_from_profile(&profile, &full_name) {
+                state.precompute.enqueue(birth_data);
+            }
+        }
     }
 
     Ok((StatusCode::OK, Json(serde_json::json!({"message": "Profile updated successfully"}))).into_response())
 }
 
+#[derive(Serialize, ToSchema)]
+pub struct AccountDeletionResponse {
+    pub deleted_at: DateTime<Utc>,
+    /// Last moment `POST /api/v1/auth/restore` can bring the account back
+    pub restorable_until: DateTime<Utc>,
+    pub message: String,
+}
+
+/// DELETE /api/v1/users/me -- soft-delete the account and its data
+#[utoipa::path(
+    delete,
+    path = "/api/v1/users/me",
+    tag = "users",
+    responses(
+        (status = 200, description = "Account deleted; restorable for 30 days", body = AccountDeletionResponse),
+        (status = 401, description = "Unauthorized or account already deleted", content(("application/problem+json" = ProblemDetails), ("application/json" = ErrorResponse))),
+    ),
+    security(
+        ("bearer_auth" = []),
+        ("api_key" = [])
+    )
+)]
+pub async fn delete_me(
+    State(state): State<AppState>,
+    Extension(auth_user): Extension<AuthUser>,
+) -> Result<Response, ApiError> {
+    let user_uuid = uuid::Uuid::parse_str(&auth_user.user_id)
+        .map_err(|_| EngineError::AuthError("Invalid user ID in token".to_string()))?;
+
+    let deleted_at = state.user_repository.soft_delete_user(user_uuid).await
+        .map_err(|e| EngineError::InternalError(format!("Database error: {}", e)))?
+        .ok_or_else(|| EngineError::AuthError("User not found".to_string()))?;
+
+    let response = AccountDeletionResponse {
+        deleted_at,
+        restorable_until: deleted_at + Duration::days(RESTORE_WINDOW_DAYS),
+        message: format!(
+            "Account deleted. Sign in through /api/v1/auth/restore within {} days to restore it.",
+            RESTORE_WINDOW_DAYS
+        ),
+    };
+
+    Ok((StatusCode::OK, Json(response)).into_response())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -192,6 +307,16 @@ mod tests {
         };
         assert!(bad_lat.validate().is_err());
 
+        let swapped = UpdateUserRequest {
+            birth_location_lat: Some(-122.42),
+            birth_location_lng: Some(37.77),
+            ..req_base()
+        };
+        match swapped.validate() {
+            Err(EngineError::InvalidInput(errors)) => assert!(errors[0].message.contains("look swapped")),
+            other => panic!("expected InvalidInput, got {:?}", other),
+        }
+
         let bad_lng = UpdateUserRequest {
             birth_location_lng: Some(-181.0),
             ..req_base()
@@ -0,0 +1,338 @@
+use std::collections::BTreeMap;
+
+use axum::extract::{Extension, Json, Path, Query, State};
+use axum::http::{header, HeaderMap, StatusCode};
+use axum::response::{IntoResponse, Response};
+use noesis_auth::{AuthService, AuthUser};
+use noesis_core::{EngineError, ValidationCode};
+use noesis_witness::packs::LevelTemplates;
+use noesis_witness::PromptPack;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use super::{not_found, HandlerError};
+use crate::{engine_error_to_response, permission_denied, AppState};
+use crate::wisdom::{self, WisdomHit, WisdomSystem, EXPORTABLE_ENGINES, MAX_SEARCH_LIMIT};
+
+/// Results returned when `limit` is omitted
+const DEFAULT_SEARCH_LIMIT: usize = 10;
+
+/// Bundles only change with a deploy, and clients revalidate with the ETag
+const EXPORT_CACHE_CONTROL: &str = "private, max-age=86400";
+
+#[derive(Deserialize)]
+pub struct WisdomSearchParams {
+    q: String,
+    systems: Option<String>,
+    limit: Option<usize>,
+}
+
+#[derive(Serialize, ToSchema)]
+pub struct WisdomSearchResponse {
+    /// The query as given
+    #[schema(example = "creativity")]
+    pub query: String,
+    /// Corpora searched
+    pub systems: Vec<WisdomSystem>,
+    /// Matching entries before `limit` was applied
+    pub total: usize,
+    /// Best matches first
+    pub results: Vec<WisdomHit>,
+}
+
+/// GET /api/v1/wisdom/search -- keyword search across the wisdom corpora
+#[utoipa::path(
+    get,
+    path = "/api/v1/wisdom/search",
+    tag = "wisdom",
+    params(
+        ("q" = String, Query, description = "Keywords, e.g. 'creativity' or 'creative leadership'"),
+        ("systems" = Option<String>, Query, description = "Comma-separated corpora to search: hd, gene-keys, i-ching, numerology (default all)"),
+        ("limit" = Option<usize>, Query, description = "Maximum number of results (default 10, max 50)"),
+    ),
+    responses(
+        (status = 200, description = "Matching gates, channels, keys, hexagrams and numbers, best match first", body = WisdomSearchResponse),
+        (status = 401, description = "Unauthorized", content(("application/problem+json" = ProblemDetails), ("application/json" = ErrorResponse))),
+        (status = 422, description = "Query without keywords, unknown system or limit out of range", content(("application/problem+json" = ProblemDetails), ("application/json" = ErrorResponse))),
+    ),
+    security(
+        ("bearer_auth" = []),
+        ("api_key" = [])
+    )
+)]
+pub async fn search(Query(params): Query<WisdomSearchParams>) -> Result<Json<WisdomSearchResponse>, HandlerError> {
+    if wisdom::tokenize(&params.q).is_empty() {
+        return Err(engine_error_to_response(EngineError::invalid_field(
+            "q",
+            ValidationCode::Empty,
+            "Query parameter 'q' must contain at least one keyword",
+        )));
+    }
+    let systems = parse_systems(params.systems.as_deref()).map_err(engine_error_to_response)?;
+    let limit = params.limit.unwrap_or(DEFAULT_SEARCH_LIMIT);
+    if !(1..=MAX_SEARCH_LIMIT).contains(&limit) {
+        return Err(engine_error_to_response(EngineError::invalid_field(
+            "limit",
+            ValidationCode::OutOfRange,
+            format!("'limit' must be between 1 and {}", MAX_SEARCH_LIMIT),
+        )));
+    }
+
+    let (total, results) = wisdom::index().search(&params.q, &systems, limit);
+    Ok(Json(WisdomSearchResponse {
+        query: params.q,
+        systems,
+        total,
+        results,
+    }))
+}
+
+#[derive(Deserialize)]
+pub struct WisdomExportParams {
+    engine: String,
+}
+
+/// GET /api/v1/wisdom/export -- an engine's full wisdom corpus as a versioned bundle
+#[utoipa::path(
+    get,
+    path = "/api/v1/wisdom/export",
+    tag = "wisdom",
+    params(
+        ("engine" = String, Query, description = "Engine whose wisdom data to export: human-design or gene-keys"),
+        ("If-None-Match" = Option<String>, Header, description = "ETag of a bundle already held; answered with 304 while it is current"),
+    ),
+    responses(
+        (status = 200, description = "The bundle; its ETag is the quoted checksum", body = WisdomBundle,
+            headers(("ETag" = String, description = "Quoted SHA-256 checksum of the bundle content"))),
+        (status = 304, description = "The bundle in `If-None-Match` is current"),
+        (status = 401, description = "Unauthorized", content(("application/problem+json" = ProblemDetails), ("application/json" = ErrorResponse))),
+        (status = 422, description = "Unknown or non-exportable engine", content(("application/problem+json" = ProblemDetails), ("application/json" = ErrorResponse))),
+    ),
+    security(
+        ("bearer_auth" = []),
+        ("api_key" = [])
+    )
+)]
+pub async fn export(Query(params): Query<WisdomExportParams>, headers: HeaderMap) -> Result<Response, HandlerError> {
+    let bundle = wisdom::export_bundle(params.engine.trim()).ok_or_else(|| {
+        engine_error_to_response(EngineError::invalid_field(
+            "engine",
+            ValidationCode::Unknown,
+            format!(
+                "No wisdom export for engine '{}'; expected one of: {}",
+                params.engine,
+                EXPORTABLE_ENGINES.join(", ")
+            ),
+        ))
+    })?;
+
+    let cache_headers = [
+        (header::ETAG, bundle.etag.as_str()),
+        (header::CACHE_CONTROL, EXPORT_CACHE_CONTROL),
+    ];
+    let current = headers
+        .get(header::IF_NONE_MATCH)
+        .and_then(|value| value.to_str().ok())
+        .is_some_and(|value| etag_matches(value, &bundle.etag));
+    if current {
+        return Ok((StatusCode::NOT_MODIFIED, cache_headers).into_response());
+    }
+    Ok((
+        cache_headers,
+        [(header::CONTENT_TYPE, "application/json")],
+        bundle.body.as_str(),
+    )
+        .into_response())
+}
+
+/// Permission required to change witness prompt packs
+const WISDOM_PERMISSION: &str = "admin:wisdom";
+
+#[derive(Deserialize, ToSchema)]
+pub struct WitnessPackRequest {
+    #[serde(default)]
+    pub description: String,
+    /// Relative share of users assigned the pack (default 1); 0 keeps the
+    /// pack without assigning anyone
+    #[serde(default = "default_pack_weight")]
+    pub weight: u32,
+    /// Templates by engine id (`*` for any engine), then level (0-5). A
+    /// template may use `{prompt}`, the engine's own prompt, and `{engine}`
+    #[schema(value_type = Object, example = json!({"*": {"0": ["{prompt}", "Take a breath first. {prompt}"], "3": ["Choose, don't react: {prompt}"]}}))]
+    pub templates: BTreeMap<String, LevelTemplates>,
+}
+
+fn default_pack_weight() -> u32 {
+    1
+}
+
+#[derive(Serialize, ToSchema)]
+pub struct WitnessPackListResponse {
+    pub packs: Vec<PromptPack>,
+}
+
+/// GET /api/v1/wisdom/witness-packs -- witness prompt packs users rotate through
+#[utoipa::path(
+    get,
+    path = "/api/v1/wisdom/witness-packs",
+    tag = "wisdom",
+    responses(
+        (status = 200, description = "Packs ordered by id", body = WitnessPackListResponse),
+        (status = 401, description = "Unauthorized", content(("application/problem+json" = ProblemDetails), ("application/json" = ErrorResponse))),
+        (status = 403, description = "Missing admin:wisdom permission", content(("application/problem+json" = ProblemDetails), ("application/json" = ErrorResponse))),
+    ),
+    security(
+        ("bearer_auth" = []),
+        ("api_key" = [])
+    )
+)]
+pub async fn list_witness_packs(
+    State(state): State<AppState>,
+    Extension(auth_user): Extension<AuthUser>,
+) -> Result<Json<WitnessPackListResponse>, HandlerError> {
+    require_wisdom_admin(&auth_user)?;
+    Ok(Json(WitnessPackListResponse {
+        packs: state.core.orchestrator.witness_packs().list(),
+    }))
+}
+
+/// PUT /api/v1/wisdom/witness-packs/:id -- upload or replace a witness prompt pack
+///
+/// Takes effect on the next request. Users are split between packs by
+/// weight, so uploading a pack next to `classic` starts an A/B test; its
+/// exposures and outcomes are counted under the `witness-pack` experiment.
+#[utoipa::path(
+    put,
+    path = "/api/v1/wisdom/witness-packs/{id}",
+    tag = "wisdom",
+    params(("id" = String, Path, description = "Pack id, also the `variant` metric label")),
+    request_body = WitnessPackRequest,
+    responses(
+        (status = 200, description = "Pack replaced", body = PromptPack),
+        (status = 201, description = "Pack created", body = PromptPack),
+        (status = 401, description = "Unauthorized", content(("application/problem+json" = ProblemDetails), ("application/json" = ErrorResponse))),
+        (status = 403, description = "Missing admin:wisdom permission", content(("application/problem+json" = ProblemDetails), ("application/json" = ErrorResponse))),
+        (status = 422, description = "Invalid id, level or template", content(("application/problem+json" = ProblemDetails), ("application/json" = ErrorResponse))),
+    ),
+    security(
+        ("bearer_auth" = []),
+        ("api_key" = [])
+    )
+)]
+pub async fn put_witness_pack(
+    State(state): State<AppState>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(id): Path<String>,
+    Json(payload): Json<WitnessPackRequest>,
+) -> Result<Response, HandlerError> {
+    require_wisdom_admin(&auth_user)?;
+    let pack = PromptPack {
+        id,
+        description: payload.description,
+        weight: payload.weight,
+        templates: payload.templates,
+    };
+    let replaced = state
+        .core
+        .orchestrator
+        .witness_packs()
+        .upsert(pack.clone())
+        .map_err(engine_error_to_response)?;
+
+    tracing::info!(pack = %pack.id, weight = pack.weight, user_id = %auth_user.user_id, "Witness prompt pack updated");
+    let status = if replaced.is_some() { StatusCode::OK } else { StatusCode::CREATED };
+    Ok((status, Json(pack)).into_response())
+}
+
+/// DELETE /api/v1/wisdom/witness-packs/:id -- stop rotating a pack
+#[utoipa::path(
+    delete,
+    path = "/api/v1/wisdom/witness-packs/{id}",
+    tag = "wisdom",
+    params(("id" = String, Path, description = "Pack id")),
+    responses(
+        (status = 204, description = "Pack removed; its users move to the remaining packs, or get the engines' own prompts if none are left"),
+        (status = 401, description = "Unauthorized", content(("application/problem+json" = ProblemDetails), ("application/json" = ErrorResponse))),
+        (status = 403, description = "Missing admin:wisdom permission", content(("application/problem+json" = ProblemDetails), ("application/json" = ErrorResponse))),
+        (status = 404, description = "No such pack", content(("application/problem+json" = ProblemDetails), ("application/json" = ErrorResponse))),
+    ),
+    security(
+        ("bearer_auth" = []),
+        ("api_key" = [])
+    )
+)]
+pub async fn delete_witness_pack(
+    State(state): State<AppState>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(id): Path<String>,
+) -> Result<StatusCode, HandlerError> {
+    require_wisdom_admin(&auth_user)?;
+    state
+        .core
+        .orchestrator
+        .witness_packs()
+        .remove(&id)
+        .ok_or_else(|| not_found("WITNESS_PACK_NOT_FOUND", format!("Witness prompt pack '{}' not found", id)))?;
+
+    tracing::info!(pack = %id, user_id = %auth_user.user_id, "Witness prompt pack removed");
+    Ok(StatusCode::NO_CONTENT)
+}
+
+fn require_wisdom_admin(auth_user: &AuthUser) -> Result<(), HandlerError> {
+    if AuthService::can_access_endpoint(auth_user, "/api/v1/wisdom/witness-packs") {
+        Ok(())
+    } else {
+        Err(permission_denied(WISDOM_PERMISSION))
+    }
+}
+
+/// Whether an If-None-Match list names the ETag, compared weakly as RFC 9110 requires
+fn etag_matches(if_none_match: &str, etag: &str) -> bool {
+    if_none_match.split(',').map(str::trim).any(|candidate| {
+        candidate == "*" || candidate.strip_prefix("W/").unwrap_or(candidate) == etag
+    })
+}
+
+/// Parse a comma-separated system list; empty or absent means every system
+fn parse_systems(value: Option<&str>) -> Result<Vec<WisdomSystem>, EngineError> {
+    let mut systems = Vec::new();
+    for id in value.unwrap_or_default().split(',').filter(|id| !id.trim().is_empty()) {
+        let system = WisdomSystem::parse(id).ok_or_else(|| {
+            EngineError::invalid_field(
+                "systems",
+                ValidationCode::Unknown,
+                format!("Unknown wisdom system '{}'; expected hd, gene-keys, i-ching or numerology", id.trim()),
+            )
+        })?;
+        if !systems.contains(&system) {
+            systems.push(system);
+        }
+    }
+    if systems.is_empty() {
+        systems = WisdomSystem::ALL.to_vec();
+    }
+    Ok(systems)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_systems() {
+        assert_eq!(parse_systems(None).unwrap(), WisdomSystem::ALL);
+        assert_eq!(
+            parse_systems(Some("hd, i-ching,hd")).unwrap(),
+            [WisdomSystem::HumanDesign, WisdomSystem::IChing]
+        );
+        assert!(parse_systems(Some("hd,tarot")).is_err());
+    }
+
+    #[test]
+    fn test_etag_matches() {
+        assert!(etag_matches("\"abc\"", "\"abc\""));
+        assert!(etag_matches("\"old\", W/\"abc\"", "\"abc\""));
+        assert!(etag_matches("*", "\"abc\""));
+        assert!(!etag_matches("\"abcd\"", "\"abc\""));
+    }
+}
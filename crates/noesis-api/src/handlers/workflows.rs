@@ -0,0 +1,144 @@
+use std::collections::{BTreeMap, HashMap};
+
+use axum::{
+    extract::{Extension, Json, Path, State},
+    http::StatusCode,
+    response::{IntoResponse, Response},
+};
+use noesis_auth::{AuthService, AuthUser};
+use noesis_core::{PhaseVariant, TransformerStep, WorkflowDefinition};
+use serde::Deserialize;
+use serde_json::Value;
+use utoipa::ToSchema;
+
+use super::{not_found, HandlerError};
+use crate::{engine_error_to_response, permission_denied, AppState};
+
+/// Permission required for `/api/v1/admin/workflows`
+const WORKFLOWS_PERMISSION: &str = "admin:workflows";
+
+#[derive(Deserialize, ToSchema)]
+pub struct WorkflowRequest {
+    #[schema(example = "Monthly Rhythm")]
+    pub name: String,
+    #[serde(default)]
+    pub description: String,
+    /// Registered engines, run concurrently
+    #[schema(example = json!(["biorhythm", "panchanga"]))]
+    pub engine_ids: Vec<String>,
+    /// Options per engine id, merged into that engine's input; options the
+    /// caller sends take precedence
+    #[serde(default)]
+    #[schema(value_type = Object, example = json!({ "biorhythm": { "forecast_days": 30 } }))]
+    pub engine_overrides: HashMap<String, Value>,
+    /// Post-processing steps run in order over every engine output
+    #[serde(default)]
+    #[schema(example = json!([{ "id": "strip-fields", "config": { "fields": ["wisdom"] } }]))]
+    pub transformers: Vec<TransformerStep>,
+    /// Options and extra transformers by lowest consciousness phase (0-5); a
+    /// user gets the variant with the highest phase not above their own
+    #[serde(default)]
+    #[schema(value_type = Object, example = json!({
+        "0": { "options": { "consciousness_level": 0 }, "transformers": [{ "id": "strip-fields", "config": { "fields": ["timeline"] } }] },
+        "3": { "options": { "consciousness_level": 5 } }
+    }))]
+    pub phase_variants: BTreeMap<u8, PhaseVariant>,
+}
+
+/// PUT /api/v1/admin/workflows/:id -- create or replace a custom workflow
+///
+/// The workflow is served by `/api/v1/workflows/:id/execute` from the next
+/// request on. Canonical workflows cannot be replaced.
+#[utoipa::path(
+    put,
+    path = "/api/v1/admin/workflows/{id}",
+    tag = "admin",
+    params(("id" = String, Path, description = "Workflow id: 1-64 characters of a-z, 0-9, '_' or '-'")),
+    request_body = WorkflowRequest,
+    responses(
+        (status = 200, description = "Workflow replaced", body = WorkflowDefinition),
+        (status = 201, description = "Workflow created", body = WorkflowDefinition),
+        (status = 401, description = "Unauthorized", content(("application/problem+json" = ProblemDetails), ("application/json" = ErrorResponse))),
+        (status = 403, description = "Missing admin:workflows permission", content(("application/problem+json" = ProblemDetails), ("application/json" = ErrorResponse))),
+        (status = 422, description = "Invalid id, unknown engines or transformers, malformed overrides or phase variants", content(("application/problem+json" = ProblemDetails), ("application/json" = ErrorResponse))),
+    ),
+    security(
+        ("bearer_auth" = []),
+        ("api_key" = [])
+    )
+)]
+pub async fn put_workflow(
+    State(state): State<AppState>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(id): Path<String>,
+    Json(payload): Json<WorkflowRequest>,
+) -> Result<Response, HandlerError> {
+    require_admin(&auth_user)?;
+
+    let workflow = WorkflowDefinition {
+        id,
+        name: payload.name,
+        description: payload.description,
+        engine_ids: payload.engine_ids,
+        engine_overrides: payload.engine_overrides,
+        transformers: payload.transformers,
+        phase_variants: payload.phase_variants,
+    };
+    let replaced = state
+        .core
+        .orchestrator
+        .put_custom_workflow(workflow.clone())
+        .map_err(engine_error_to_response)?;
+
+    tracing::info!(
+        workflow_id = %workflow.id,
+        engine_count = workflow.engine_ids.len(),
+        user_id = %auth_user.user_id,
+        "Custom workflow updated"
+    );
+    let status = if replaced.is_some() { StatusCode::OK } else { StatusCode::CREATED };
+    Ok((status, Json(workflow)).into_response())
+}
+
+/// DELETE /api/v1/admin/workflows/:id -- remove a custom workflow
+#[utoipa::path(
+    delete,
+    path = "/api/v1/admin/workflows/{id}",
+    tag = "admin",
+    params(("id" = String, Path, description = "Workflow id")),
+    responses(
+        (status = 204, description = "Workflow removed"),
+        (status = 401, description = "Unauthorized", content(("application/problem+json" = ProblemDetails), ("application/json" = ErrorResponse))),
+        (status = 403, description = "Missing admin:workflows permission", content(("application/problem+json" = ProblemDetails), ("application/json" = ErrorResponse))),
+        (status = 404, description = "No such workflow", content(("application/problem+json" = ProblemDetails), ("application/json" = ErrorResponse))),
+        (status = 422, description = "Canonical workflows cannot be removed", content(("application/problem+json" = ProblemDetails), ("application/json" = ErrorResponse))),
+    ),
+    security(
+        ("bearer_auth" = []),
+        ("api_key" = [])
+    )
+)]
+pub async fn delete_workflow(
+    State(state): State<AppState>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(id): Path<String>,
+) -> Result<StatusCode, HandlerError> {
+    require_admin(&auth_user)?;
+    state
+        .core
+        .orchestrator
+        .remove_custom_workflow(&id)
+        .map_err(engine_error_to_response)?
+        .ok_or_else(|| not_found("WORKFLOW_NOT_FOUND", format!("Workflow '{}' not found", id)))?;
+
+    tracing::info!(workflow_id = %id, user_id = %auth_user.user_id, "Custom workflow removed");
+    Ok(StatusCode::NO_CONTENT)
+}
+
+fn require_admin(auth_user: &AuthUser) -> Result<(), HandlerError> {
+    if AuthService::can_access_endpoint(auth_user, "/api/v1/admin/workflows") {
+        Ok(())
+    } else {
+        Err(permission_denied(WORKFLOWS_PERMISSION))
+    }
+}
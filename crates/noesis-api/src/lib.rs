@@ -8,30 +8,75 @@ mod config;
 mod logging;
 mod middleware;
 mod handlers;
+mod routes;
+pub mod analytics;
+pub mod annotations;
+pub mod database;
 pub mod error;
+pub mod extract;
+pub mod fanout;
+pub mod geo;
+pub mod notifications;
+pub mod offline;
+pub mod openapi;
+pub mod precompute;
+pub mod presets;
+pub mod problem;
+pub mod public_status;
+pub mod purge;
+pub mod recalculate;
+pub mod research_export;
+pub mod retention;
+pub mod self_test;
+pub mod settings;
+pub mod sharing;
+pub mod validation;
+pub mod versioning;
+pub mod wisdom;
 
 // Re-export configuration and logging for main.rs
 pub use config::ApiConfig;
 pub use logging::{init_tracing, init_tracing_json};
+pub use middleware::MetricsAccess;
 
+use futures::StreamExt;
 use axum::{
-    extract::{Json, Path, State},
+    extract::{DefaultBodyLimit, Json, Path, Query, State},
     http::{HeaderValue, Method, StatusCode},
     middleware as axum_middleware,
-    response::IntoResponse,
+    response::{
+        sse::{Event, KeepAlive, Sse},
+        IntoResponse,
+    },
     routing::{get, post},
     Extension,
     Router,
 };
-use chrono::Timelike;
 use noesis_auth::{AuthService, AuthUser};
-use noesis_cache::CacheManager;
+use noesis_data::DbPools;
 use noesis_data::repositories::user_repository::UserRepository;
-use noesis_core::{EngineError, EngineInput, EngineOutput, ValidationResult, WorkflowResult};
-use noesis_metrics::NoesisMetrics;
-use noesis_orchestrator::WorkflowOrchestrator;
+use noesis_data::repositories::client_repository::ClientRepository;
+use noesis_data::repositories::api_key_repository::ApiKeyRepository;
+use noesis_data::repositories::location_repository::SavedLocationRepository;
+use noesis_data::repositories::notification_repository::NotificationRepository;
+use noesis_data::repositories::practice_repository::PracticeRepository;
+use noesis_data::repositories::settings_repository::SettingsRepository;
+use noesis_data::repositories::organization_repository::OrganizationRepository;
+use noesis_data::repositories::workflow_result_repository::WorkflowResultRepository;
+use extract::{InputJson, WorkflowBody};
+use geo::GeoLocation;
+use noesis_core::{
+    EngineDescription, EngineError, EngineHealth, EngineInput, EngineOutput, PhaseVariant, Precision, TransformerStep,
+    ValidationResult, WorkflowResult, AS_OF_OPTION,
+};
+use noesis_orchestrator::{EngineAlias, PlanStatus, PlannedEngine, WorkflowPlan};
+use noesis_runtime::{AppCore, AppCoreBuilder};
 use serde::{Deserialize, Serialize};
 use std::sync::Arc;
+use openapi::SpecAddon;
+use problem::ProblemDetails;
+use public_status::PublicStatusResponse;
+use versioning::{ApiVersion, Versioned};
 use std::time::{Duration, Instant};
 use tower_http::cors::CorsLayer;
 use tower_http::trace::TraceLayer;
@@ -49,37 +94,277 @@ use sqlx::postgres::PgPoolOptions;
         health_handler,
         readiness_handler,
         status_handler,
+        public_status_handler,
         list_engines_handler,
+        engines_health_handler,
         calculate_handler,
         validate_handler,
         engine_info_handler,
+        engine_examples_handler,
         list_workflows_handler,
         workflow_execute_handler,
         workflow_info_handler,
+        geo_search_handler,
+        handlers::ephemeris::calculate_positions,
+        handlers::ephemeris::list_stations,
+        handlers::ephemeris::list_gate_transits,
+        handlers::planner::suggest,
+        handlers::wisdom::search,
+        handlers::wisdom::export,
+        handlers::wisdom::list_witness_packs,
+        handlers::wisdom::put_witness_pack,
+        handlers::wisdom::delete_witness_pack,
+        validation_report_handler,
+        metrics_handler,
+        handlers::auth::register,
+        handlers::auth::login,
+        handlers::auth::forgot_password,
+        handlers::auth::reset_password,
+        handlers::auth::restore_account,
+        handlers::users::get_me,
+        handlers::users::update_me,
+        handlers::users::delete_me,
+        handlers::locations::list_locations,
+        handlers::locations::create_location,
+        handlers::locations::delete_location,
+        handlers::api_keys::list_api_keys,
+        handlers::api_keys::create_api_key,
+        handlers::api_keys::update_api_key_scopes,
+        handlers::api_keys::revoke_api_key,
+        handlers::notifications::list_rules,
+        handlers::notifications::create_rule,
+        handlers::notifications::delete_rule,
+        handlers::notifications::list_notifications,
+        handlers::offline::get_offline_bundle,
+        handlers::practices::list_practices,
+        handlers::practices::log_practice,
+        handlers::settings::get_settings,
+        handlers::settings::put_settings,
+        handlers::results::get_result,
+        handlers::results::create_annotation,
+        handlers::results::delete_annotation,
+        handlers::results::share_result,
+        handlers::results::get_shared_result,
+        handlers::results::replay_result,
+        handlers::analytics::human_design_distribution,
+        handlers::organizations::create_organization,
+        handlers::organizations::add_member,
+        handlers::clients::create_client,
+        handlers::clients::list_clients,
+        handlers::clients::get_client,
+        handlers::clients::update_consent,
+        handlers::clients::execute_client_workflow,
+        handlers::experiments::list_experiments,
+        handlers::experiments::put_experiment,
+        handlers::experiments::delete_experiment,
+        handlers::experiments::record_outcome,
+        handlers::workflows::put_workflow,
+        handlers::workflows::delete_workflow,
+        handlers::cache::cache_stats,
+        handlers::cache::invalidate_engine_version,
+        handlers::recalculate::start_recalculation,
+        handlers::recalculate::get_recalculation,
+        handlers::recalculate::retry_recalculation,
+        handlers::research_export::start_research_export,
+        handlers::research_export::get_research_export,
+        legacy_panchanga_handler,
+        legacy_panchanga_batch_handler,
+        legacy_ghati_current_handler,
+        legacy_ghati_stream_handler,
     ),
     components(
         schemas(
             EngineInput,
+            noesis_core::BirthData,
+            noesis_core::Coordinates,
+            noesis_core::Subject,
+            noesis_core::SubjectRole,
             EngineOutput,
+            noesis_core::CalculationMetadata,
+            noesis_core::ResourceUsage,
             ValidationResult,
             WorkflowResult,
+            noesis_core::WorkflowUsage,
+            noesis_core::birth_time::BirthTimeReport,
+            noesis_core::birth_time::EngineTimeConfidence,
+            noesis_core::birth_time::TimeStability,
+            noesis_core::TimeSensitivity,
+            WorkflowPlan,
+            PlannedEngine,
+            PlanStatus,
             HealthResponse,
             ReadinessResponse,
             StatusResponse,
+            PublicStatusResponse,
+            public_status::PublicComponents,
+            public_status::PublicIncident,
+            public_status::ComponentStatus,
+            public_status::Component,
             WorkflowSummary,
             EngineInfoResponse,
+            EngineExamplesResponse,
+            EngineExampleResponse,
             EngineListResponse,
+            EngineAlias,
+            EngineHealthResponse,
+            noesis_core::EngineHealth,
             WorkflowListResponse,
             WorkflowInfoResponse,
             ErrorResponse,
+            ProblemDetails,
+            noesis_core::ValidationError,
+            noesis_core::ValidationCode,
+            geo::GeoLocation,
+            GeoSearchResponse,
+            handlers::ephemeris::PositionsRequest,
+            handlers::ephemeris::PositionsResponse,
+            handlers::ephemeris::PlanetPositionResponse,
+            handlers::ephemeris::StationsResponse,
+            handlers::ephemeris::GateTransitsResponse,
+            handlers::planner::PlannerRequest,
+            handlers::planner::PlannerResponse,
+            handlers::planner::PlannedWindow,
+            handlers::planner::WindowScores,
+            handlers::wisdom::WisdomSearchResponse,
+            handlers::wisdom::WitnessPackRequest,
+            handlers::wisdom::WitnessPackListResponse,
+            noesis_witness::PromptPack,
+            wisdom::WisdomHit,
+            wisdom::WisdomRef,
+            wisdom::WisdomSystem,
+            wisdom::WisdomBundle,
+            noesis_metrics::ValidationReport,
+            noesis_metrics::validation::EngineValidationStats,
+            noesis_metrics::validation::QuantityStats,
+            noesis_metrics::ValidationSample,
+            LegacyPanchangaRequest,
+            LegacyPanchangaResponse,
+            LegacyPanchangaBatchRequest,
+            LegacyPanchangaBatchItem,
+            LegacyPanchangaBatchResponse,
+            LegacyGhatiRequest,
+            LegacyGhatiResponse,
+            handlers::auth::RegisterRequest,
+            handlers::auth::RegisterResponse,
+            handlers::auth::LoginRequest,
+            handlers::auth::LoginResponse,
+            handlers::auth::ForgotPasswordRequest,
+            handlers::auth::ForgotPasswordResponse,
+            handlers::auth::ResetPasswordRequest,
+            handlers::auth::ResetPasswordResponse,
+            handlers::users::UserResponse,
+            handlers::users::LocationResponse,
+            handlers::users::UpdateUserRequest,
+            handlers::users::AccountDeletionResponse,
+            handlers::locations::CreateSavedLocationRequest,
+            handlers::locations::SavedLocationResponse,
+            handlers::locations::SavedLocationListResponse,
+            handlers::api_keys::CreateApiKeyRequest,
+            handlers::api_keys::UpdateApiKeyScopesRequest,
+            handlers::api_keys::ApiKeyResponse,
+            handlers::api_keys::ApiKeyListResponse,
+            handlers::notifications::CreateNotificationRuleRequest,
+            handlers::notifications::NotificationRuleResponse,
+            handlers::notifications::NotificationRuleListResponse,
+            handlers::notifications::NotificationListResponse,
+            notifications::NotificationCategory,
+            notifications::NotificationChannel,
+            notifications::NotificationResponse,
+            offline::OfflineBundle,
+            offline::BundleManifest,
+            offline::BundleEntry,
+            offline::SkippedArtifact,
+            handlers::practices::LogPracticeRequest,
+            handlers::practices::PracticeLogResponse,
+            handlers::practices::LoggedPracticeResponse,
+            handlers::practices::PracticeStreak,
+            handlers::practices::PracticeHistoryResponse,
+            settings::UserSettings,
+            handlers::results::SavedResultResponse,
+            handlers::results::CreateAnnotationRequest,
+            handlers::results::AnnotationResponse,
+            annotations::Correction,
+            handlers::results::ShareResultRequest,
+            handlers::results::ShareLinkResponse,
+            handlers::results::ReplayResponse,
+            analytics::HumanDesignDistributionResponse,
+            analytics::Distribution,
+            analytics::DistributionBucket,
+            handlers::results::SharedResultResponse,
+            handlers::organizations::CreateOrganizationRequest,
+            handlers::organizations::OrganizationResponse,
+            handlers::organizations::AddMemberRequest,
+            handlers::organizations::MemberResponse,
+            handlers::clients::CreateClientRequest,
+            handlers::clients::ConsentUpdate,
+            handlers::clients::ConsentResponse,
+            handlers::clients::ClientResponse,
+            handlers::clients::ClientListResponse,
+            handlers::clients::ClientWorkflowRequest,
+            handlers::experiments::ExperimentRequest,
+            handlers::experiments::ExperimentListResponse,
+            handlers::experiments::ExperimentOutcomeRequest,
+            handlers::experiments::ExperimentOutcomeResponse,
+            handlers::workflows::WorkflowRequest,
+            handlers::cache::CacheStatsResponse,
+            handlers::cache::CacheInvalidationResponse,
+            recalculate::RecalculationJobResponse,
+            recalculate::RecalculationProgress,
+            recalculate::RecalculationStatus,
+            recalculate::RecalculationFailure,
+            research_export::ResearchExportProgress,
+            research_export::ResearchExportStatus,
+            research_export::ExportedFile,
+            noesis_cache::l1_cache::L1PartitionUsage,
+            noesis_cache::hot_keys::HotKeyReport,
+            noesis_cache::hot_keys::HotKeyWindow,
+            noesis_cache::hot_keys::HotKey,
+            noesis_core::WorkflowDefinition,
+            noesis_core::TransformerStep,
+            noesis_core::PhaseVariant,
+            noesis_orchestrator::Experiment,
+            noesis_orchestrator::Variant,
+            noesis_core::Precision,
+            openapi::EngineOptions,
+            openapi::WorkflowExecuteRequest,
+            presets::InputPreset,
+            presets::PresetRequest,
+            noesis_core::options::PanchangaOptions,
+            noesis_core::options::RelocateTo,
+            noesis_core::options::Constitution,
+            EngineDescription,
+            noesis_core::description::DescribedInput,
+            noesis_core::description::Implementation,
+            noesis_core::options::TimeBasis,
+            noesis_core::options::NumerologyOptions,
+            noesis_core::options::NumerologySubject,
+            noesis_core::options::NumerologySubjectKind,
+            noesis_core::options::BiorhythmOptions,
+            noesis_core::options::HumanDesignOptions,
+            noesis_core::options::HdGates,
+            noesis_core::options::GeneKeysOptions,
+            noesis_core::options::VimshottariOptions,
+            noesis_core::options::VedicClockOptions,
+            noesis_core::options::FaceReadingOptions,
+            noesis_core::options::BiofieldOptions,
         )
     ),
     tags(
         (name = "health", description = "Health check and monitoring endpoints"),
         (name = "engines", description = "Single engine calculation endpoints"),
         (name = "workflows", description = "Multi-engine workflow execution endpoints"),
+        (name = "geo", description = "Place-name geocoding and timezone lookup"),
+        (name = "ephemeris", description = "Raw planetary positions from Swiss Ephemeris"),
+        (name = "planner", description = "Time windows for a task, ranked across engines"),
+        (name = "wisdom", description = "Keyword search and bulk export of gates, Gene Keys, hexagrams and numerology meanings, and witness prompt packs"),
+        (name = "admin", description = "Operator endpoints; require admin permissions"),
+        (name = "auth", description = "Account registration, login and password reset"),
+        (name = "users", description = "Authenticated user profile"),
+        (name = "results", description = "Saved workflow results and read-only share links"),
+        (name = "clients", description = "Practitioner organizations and the client profiles they manage"),
+        (name = "legacy", description = "Backward-compatible endpoints of the original Selemene API"),
     ),
-    modifiers(&SecurityAddon),
+    modifiers(&SecurityAddon, &SpecAddon),
     info(
         title = "Noesis API",
         version = "0.1.0",
@@ -91,6 +376,11 @@ use sqlx::postgres::PgPoolOptions;
 )]
 struct ApiDoc;
 
+/// The OpenAPI document served at `/api/openapi.json`
+pub fn openapi_spec() -> utoipa::openapi::OpenApi {
+    ApiDoc::openapi()
+}
+
 use utoipa::openapi::security::{HttpAuthScheme, HttpBuilder, SecurityScheme, ApiKey, ApiKeyValue};
 use utoipa::Modify;
 
@@ -126,13 +416,46 @@ impl Modify for SecurityAddon {
 // ---------------------------------------------------------------------------
 
 /// Shared application state threaded through all Axum handlers via `State`.
+///
+/// Calculations go through the embedded [`AppCore`]; the rest is what
+/// serving it over HTTP adds: accounts and saved results in Postgres, share
+/// links, geocoding and precomputation.
 #[derive(Clone)]
 pub struct AppState {
-    pub orchestrator: Arc<WorkflowOrchestrator>,
-    pub cache: Arc<CacheManager>,
-    pub auth: Arc<AuthService>,
-    pub metrics: Arc<NoesisMetrics>,
+    /// Orchestrator, cache, auth and metrics
+    pub core: AppCore,
     pub user_repository: Arc<UserRepository>,
+    pub result_repository: Arc<WorkflowResultRepository>,
+    pub organization_repository: Arc<OrganizationRepository>,
+    pub client_repository: Arc<ClientRepository>,
+    pub location_repository: Arc<SavedLocationRepository>,
+    /// API keys users mint for themselves
+    pub api_key_repository: Arc<ApiKeyRepository>,
+    pub practice_repository: Arc<PracticeRepository>,
+    /// Notification rules and the notifications they sent
+    pub notification_repository: Arc<NotificationRepository>,
+    /// Saved calculation settings, merged into engine options before dispatch
+    pub settings: Arc<settings::SettingsStore>,
+    pub share_links: Arc<sharing::ShareLinks>,
+    pub geocoder: Arc<dyn geo::GeocodingProvider>,
+    /// Natal results to calculate ahead of a user's first request
+    pub precompute: precompute::PrecomputeQueue,
+    /// Admin bulk recalculation jobs, running and recent
+    pub recalculations: Arc<recalculate::RecalculationJobs>,
+    /// Research export bucket and the exports to it, running and recent
+    pub research_exports: Arc<research_export::ResearchExports>,
+    /// Shared producers of streamed events and their subscribers
+    pub fanout: fanout::FanoutHub,
+    /// Cleared while the server runs without its database
+    pub database: Arc<database::DatabaseStatus>,
+    /// Primary and replica pools, sampled into the pool gauges on scrape
+    pub db_pools: DbPools,
+    /// Whether `options.debug` is open to callers without `admin:debug`
+    pub open_debug: bool,
+    /// k-anonymity threshold and cache TTL of the admin analytics reports
+    pub analytics: analytics::AnalyticsSettings,
+    /// Snapshot and incidents behind the public status page
+    pub status_board: Arc<public_status::StatusBoard>,
     pub startup_time: Instant,
 }
 
@@ -170,6 +493,25 @@ fn create_cors_layer(allowed_origins: Vec<String>) -> CorsLayer {
             axum::http::header::CONTENT_TYPE,
             axum::http::header::AUTHORIZATION,
             axum::http::HeaderName::from_static("x-api-key"),
+            axum::http::HeaderName::from_static(noesis_core::correlation::REQUEST_ID_HEADER),
+            axum::http::header::IF_NONE_MATCH,
+        ])
+        .expose_headers([
+            axum::http::HeaderName::from_static(noesis_core::correlation::REQUEST_ID_HEADER),
+            axum::http::HeaderName::from_static(RESULT_ID_HEADER),
+            // Let browser clients back off before they hit 429
+            axum::http::HeaderName::from_static("ratelimit-limit"),
+            axum::http::HeaderName::from_static("ratelimit-remaining"),
+            axum::http::HeaderName::from_static("ratelimit-reset"),
+            axum::http::HeaderName::from_static("ratelimit-policy"),
+            axum::http::header::RETRY_AFTER,
+            // Let browser clients see that their API version is going away
+            axum::http::HeaderName::from_static(versioning::API_VERSION_HEADER),
+            axum::http::HeaderName::from_static("deprecation"),
+            axum::http::HeaderName::from_static("sunset"),
+            axum::http::header::LINK,
+            // Let browser clients revalidate wisdom exports
+            axum::http::header::ETAG,
         ])
         .allow_credentials(true)
         .max_age(Duration::from_secs(3600))
@@ -181,74 +523,80 @@ fn create_cors_layer(allowed_origins: Vec<String>) -> CorsLayer {
 
 /// Build the Axum router with all API routes and middleware.
 ///
+/// Every [`ApiVersion`] is nested under its own prefix, with the routes of its
+/// module in `routes`.
+///
 /// # Arguments
 /// * `state` - Application state with orchestrator, cache, auth, metrics
 /// * `config` - API configuration with CORS, rate limiting, etc.
 pub fn create_router(state: AppState, config: &ApiConfig) -> Router {
-    let auth_state = state.auth.clone();
-    
-    // Create rate limiter with config values
-    let rate_limiter = Arc::new(middleware::RateLimiter::new_with_config(
-        config.rate_limit_requests,
-        config.rate_limit_window_secs,
-    ));
-    
-    let auth_routes = Router::new()
-         .route("/auth/register", post(handlers::auth::register))
-         .route("/auth/login", post(handlers::auth::login))
-         .route("/auth/forgot-password", post(handlers::auth::forgot_password))
-         .route("/auth/reset-password", post(handlers::auth::reset_password));
-
-    let api_v1 = Router::new()
-        .route("/users/me", get(handlers::users::get_me).patch(handlers::users::update_me))
-        .route("/status", get(status_handler))
-        .route("/engines", get(list_engines_handler))
-        .route("/engines/:engine_id/calculate", post(calculate_handler))
-        .route("/engines/:engine_id/validate", post(validate_handler))
-        .route("/engines/:engine_id/info", get(engine_info_handler))
-        .route("/workflows", get(list_workflows_handler))
-        .route(
-            "/workflows/:workflow_id/execute",
-            post(workflow_execute_handler),
-        )
-        .route("/workflows/:workflow_id/info", get(workflow_info_handler))
-        // Layers are applied bottom-to-top, so rate_limit runs AFTER auth
-        .layer(axum_middleware::from_fn_with_state(
-            rate_limiter,
-            middleware::rate_limit_middleware,
-        ))
-        .layer(axum_middleware::from_fn_with_state(
-            auth_state,
-            middleware::auth_middleware,
-        ))
-        .merge(auth_routes);
+    // One set of rate limiting, load shedding and auth layers for every version
+    let layers = routes::ApiLayers::new(&state, config);
 
     // Legacy endpoints for backward compatibility with old Selemene API
     let legacy = Router::new()
         .route("/panchanga/calculate", post(legacy_panchanga_handler))
-        .route("/ghati/current", get(legacy_ghati_current_handler));
+        .route("/panchanga/batch", post(legacy_panchanga_batch_handler))
+        .route("/ghati/current", get(legacy_ghati_current_handler))
+        .route("/ghati/stream", get(legacy_ghati_stream_handler));
 
     // Start with a base router and merge docs first (both have () state)
     let base = Router::new().merge(
         SwaggerUi::new("/api/docs")
-            .url("/api/openapi.json", ApiDoc::openapi())
+            .url("/api/openapi.json", openapi_spec())
     );
 
     // Now add stateful routes
-    base
+    let app = base
         .route("/health", get(health_handler))
         .route("/health/live", get(health_handler))  // Kubernetes liveness probe
         .route("/health/ready", get(readiness_handler))  // Kubernetes readiness probe
-        .route("/ready", get(readiness_handler))
-        .route("/metrics", get(metrics_handler))
-        .nest("/api/v1", api_v1)
+        .route("/ready", get(readiness_handler));
+    // With METRICS_PORT set, `/metrics` is only on the internal listener
+    let app = match config.metrics_port {
+        Some(_) => app,
+        None => app.route("/metrics", metrics_route(config)),
+    };
+    let app = ApiVersion::ALL.into_iter().fold(app, |app, version| {
+        let enabled = !config.disabled_api_versions.contains(&version);
+        app.nest(version.path_prefix(), routes::router(version, &layers, enabled))
+    });
+
+    app
         .nest("/api/legacy", legacy)
+        .layer(axum_middleware::from_fn_with_state(
+            middleware::JsonBodyPolicy {
+                max_bytes: config.max_body_bytes,
+                strict: config.strict_json,
+                names: config.name_normalization,
+            },
+            middleware::json_body_middleware,
+        ))
+        .layer(DefaultBodyLimit::max(config.max_body_bytes))
+        .layer(axum_middleware::from_fn(problem::problem_json_middleware))
         .layer(axum_middleware::from_fn(middleware::request_logging_middleware))
+        .layer(axum_middleware::from_fn(middleware::request_id_middleware))
         .layer(TraceLayer::new_for_http())
         .layer(create_cors_layer(config.allowed_origins.clone()))
         .with_state(state)
 }
 
+/// Router of the internal metrics listener on `METRICS_PORT`: `/metrics`
+/// alone, still behind `METRICS_BEARER_TOKEN` and `METRICS_ALLOWED_IPS`
+pub fn create_metrics_router(state: AppState, config: &ApiConfig) -> Router {
+    Router::new()
+        .route("/metrics", metrics_route(config))
+        .layer(TraceLayer::new_for_http())
+        .with_state(state)
+}
+
+fn metrics_route(config: &ApiConfig) -> axum::routing::MethodRouter<AppState> {
+    get(metrics_handler).route_layer(axum_middleware::from_fn_with_state(
+        Arc::new(config.metrics_access.clone()),
+        middleware::metrics_access_middleware,
+    ))
+}
+
 // ---------------------------------------------------------------------------
 // Response types
 // ---------------------------------------------------------------------------
@@ -266,6 +614,13 @@ struct HealthResponse {
 struct ReadinessResponse {
     redis: String,
     orchestrator: String,
+    /// "ok", or "failing" when a critical engine's health check fails
+    engines: String,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    failing_engines: Vec<String>,
+    /// "ok", or "degraded" while Postgres-backed routes are unavailable;
+    /// engines still serve, so this does not fail readiness
+    database: String,
     overall_status: String,
 }
 
@@ -288,11 +643,58 @@ struct EngineInfoResponse {
     engine_id: String,
     engine_name: String,
     required_phase: u8,
+    /// Recorded in every result's `metadata.engine_version`
+    #[schema(example = "1")]
+    engine_version: String,
+    /// Option keys the engine reads besides `seed`, `as_of` and
+    /// `consciousness_level`; others are reported in `metadata.warnings`.
+    /// Absent for engines that don't declare their options.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(example = json!(["forecast_days"]))]
+    option_keys: Option<Vec<String>>,
+    /// What the engine reads and returns, its traditions and limitations,
+    /// and whether its results are a mock
+    description: EngineDescription,
+}
+
+#[derive(Serialize, ToSchema)]
+struct EngineExamplesResponse {
+    engine_id: String,
+    examples: Vec<EngineExampleResponse>,
+}
+
+/// A sample input with the shape of the `result` it produces
+#[derive(Serialize, ToSchema)]
+struct EngineExampleResponse {
+    #[schema(example = "birth_data")]
+    name: String,
+    summary: String,
+    /// Ready to send to `/api/v1/engines/{engine_id}/calculate`
+    input: EngineInput,
+    /// The `result`, with every leaf replaced by its JSON type name and
+    /// arrays by the shape of their first item. Absent when the example
+    /// failed to calculate.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(value_type = Option<Object>, example = json!({ "life_path": { "value": "integer", "meaning": "string" } }))]
+    result_shape: Option<serde_json::Value>,
+    /// Why the example failed to calculate
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
 }
 
 #[derive(Serialize, ToSchema)]
 struct EngineListResponse {
     engines: Vec<String>,
+    /// Old ids of renamed engines, still resolving until their sunset
+    aliases: Vec<EngineAlias>,
+}
+
+#[derive(Serialize, ToSchema)]
+struct EngineHealthResponse {
+    /// "healthy", "degraded" when only non-critical engines fail, or "unhealthy"
+    #[schema(example = "healthy")]
+    status: String,
+    engines: Vec<EngineHealth>,
 }
 
 #[derive(Serialize, ToSchema)]
@@ -306,14 +708,35 @@ struct WorkflowInfoResponse {
     name: String,
     description: String,
     engine_ids: Vec<String>,
+    /// Options merged into each engine's input, keyed by engine id
+    #[schema(value_type = Object)]
+    engine_overrides: std::collections::HashMap<String, serde_json::Value>,
+    /// Post-processing steps applied to every engine output, in order
+    transformers: Vec<TransformerStep>,
+    /// Options and transformers by lowest consciousness phase they apply to
+    #[schema(value_type = Object)]
+    phase_variants: std::collections::BTreeMap<u8, PhaseVariant>,
 }
 
 #[derive(Serialize, ToSchema)]
+struct GeoSearchResponse {
+    query: String,
+    provider: String,
+    results: Vec<GeoLocation>,
+}
+
+/// Legacy error body, served when the client's `Accept` header asks for
+/// `application/json`. Everyone else gets [`problem::ProblemDetails`].
+#[derive(Serialize, Deserialize, ToSchema)]
 pub struct ErrorResponse {
     pub error: String,
     pub error_code: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub details: Option<serde_json::Value>,
+    /// `X-Request-Id` of the failed request, filled in by the error middleware
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schema(example = "4f1c2a9e-7d3b-4c1e-9a8f-2b6d5e0c1a7f")]
+    pub request_id: Option<String>,
 }
 
 // ---------------------------------------------------------------------------
@@ -331,8 +754,8 @@ pub struct ErrorResponse {
 )]
 async fn health_handler(State(state): State<AppState>) -> Json<HealthResponse> {
     let uptime = state.startup_time.elapsed().as_secs();
-    let engines_loaded = state.orchestrator.list_engines().len();
-    let workflows_loaded = state.orchestrator.list_workflows().len();
+    let engines_loaded = state.core.orchestrator.list_engines().len();
+    let workflows_loaded = state.core.orchestrator.list_workflows().len();
 
     Json(HealthResponse {
         status: "ok".to_string(),
@@ -355,16 +778,30 @@ async fn health_handler(State(state): State<AppState>) -> Json<HealthResponse> {
 )]
 async fn readiness_handler(State(state): State<AppState>) -> impl IntoResponse {
     // Check Redis/cache health
-    let redis_status = match state.cache.health_check().await {
+    let redis_status = match state.core.cache.health_check().await {
         Ok(true) => "ok",
         _ => "down",
     };
 
-    // Check orchestrator readiness
-    let orchestrator_status = match state.orchestrator.is_ready().await {
-        Ok(true) => "ready",
-        _ => "not_ready",
+    // Check orchestrator readiness, including critical engine health
+    let health = if state.core.orchestrator.critical_engines().is_empty() {
+        Vec::new()
+    } else {
+        state.core.orchestrator.engine_health().await
+    };
+    let orchestrator_status = if state.core.orchestrator.is_ready_with(&health) {
+        "ready"
+    } else {
+        "not_ready"
     };
+    let failing_engines: Vec<String> = state
+        .core
+        .orchestrator
+        .critical_engines()
+        .iter()
+        .filter(|id| !health.iter().any(|h| &h.engine_id == *id && h.healthy))
+        .cloned()
+        .collect();
 
     let overall_ready = redis_status == "ok" && orchestrator_status == "ready";
     let overall_status = if overall_ready { "ready" } else { "not_ready" };
@@ -372,6 +809,9 @@ async fn readiness_handler(State(state): State<AppState>) -> impl IntoResponse {
     let response = ReadinessResponse {
         redis: redis_status.to_string(),
         orchestrator: orchestrator_status.to_string(),
+        engines: if failing_engines.is_empty() { "ok" } else { "failing" }.to_string(),
+        failing_engines,
+        database: if state.database.is_available() { "ok" } else { "degraded" }.to_string(),
         overall_status: overall_status.to_string(),
     };
 
@@ -383,8 +823,28 @@ async fn readiness_handler(State(state): State<AppState>) -> impl IntoResponse {
 }
 
 /// GET /metrics -- Prometheus metrics endpoint
-async fn metrics_handler(State(state): State<AppState>) -> impl IntoResponse {
-    match state.metrics.get_metrics_text() {
+#[utoipa::path(
+    get,
+    path = "/metrics",
+    tag = "health",
+    responses(
+        (status = 200, description = "Prometheus text exposition format, or OpenMetrics with trace exemplars when the scraper accepts `application/openmetrics-text`", body = String, content_type = "text/plain"),
+        (status = 401, description = "`METRICS_BEARER_TOKEN` is set and the request doesn't carry it", body = ErrorResponse),
+        (status = 403, description = "`METRICS_ALLOWED_IPS` is set and the scraper's address is outside it", body = ErrorResponse),
+        (status = 500, description = "Metrics could not be encoded", body = String, content_type = "text/plain"),
+    )
+)]
+async fn metrics_handler(State(state): State<AppState>, headers: axum::http::HeaderMap) -> impl IntoResponse {
+    database::record_pool_metrics(&state.core.metrics, &state.db_pools);
+    let wants_openmetrics = headers
+        .get(axum::http::header::ACCEPT)
+        .and_then(|v| v.to_str().ok())
+        .is_some_and(|accept| accept.contains("application/openmetrics-text"));
+    if wants_openmetrics {
+        let content_type = [(axum::http::header::CONTENT_TYPE, noesis_metrics::openmetrics::CONTENT_TYPE)];
+        return (StatusCode::OK, content_type, state.core.metrics.get_openmetrics_text()).into_response();
+    }
+    match state.core.metrics.get_metrics_text() {
         Ok(text) => (StatusCode::OK, text).into_response(),
         Err(e) => (
             StatusCode::INTERNAL_SERVER_ERROR,
@@ -408,8 +868,9 @@ async fn metrics_handler(State(state): State<AppState>) -> impl IntoResponse {
     )
 )]
 async fn status_handler(State(state): State<AppState>) -> Json<StatusResponse> {
-    let engines = state.orchestrator.list_engines();
+    let engines = state.core.orchestrator.list_engines();
     let workflows = state
+        .core
         .orchestrator
         .list_workflows()
         .iter()
@@ -424,6 +885,21 @@ async fn status_handler(State(state): State<AppState>) -> Json<StatusResponse> {
     Json(StatusResponse { engines, workflows })
 }
 
+/// GET /api/v1/status/public -- coarse component health for a status page
+#[utoipa::path(
+    get,
+    path = "/api/v1/status/public",
+    tag = "health",
+    responses(
+        (status = 200, description = "Component health and recent incidents, refreshed at most every 30 seconds", body = PublicStatusResponse),
+    )
+)]
+async fn public_status_handler(State(state): State<AppState>) -> impl IntoResponse {
+    let status = state.status_board.status(&state).await;
+    let cache_control = format!("public, max-age={}", public_status::PUBLIC_STATUS_TTL.as_secs());
+    ([(axum::http::header::CACHE_CONTROL, cache_control)], Json(status))
+}
+
 /// POST /api/v1/engines/:engine_id/calculate -- execute a single engine
 #[utoipa::path(
     post,
@@ -431,15 +907,19 @@ async fn status_handler(State(state): State<AppState>) -> Json<StatusResponse> {
     tag = "engines",
     params(
         ("engine_id" = String, Path, description = "Engine identifier (e.g., 'panchanga', 'numerology', 'biorhythm')"),
+        ("as_of" = Option<String>, Query, description = "Calculate as of this RFC 3339 time or YYYY-MM-DD date; overrides `options.as_of`"),
     ),
     request_body = EngineInput,
     responses(
-        (status = 200, description = "Calculation successful", body = EngineOutput),
-        (status = 401, description = "Unauthorized", body = ErrorResponse),
-        (status = 403, description = "Forbidden - Insufficient consciousness phase", body = ErrorResponse),
-        (status = 404, description = "Engine not found", body = ErrorResponse),
-        (status = 422, description = "Validation error", body = ErrorResponse),
-        (status = 429, description = "Rate limit exceeded", body = ErrorResponse),
+        (status = 200, description = "Calculation successful; requested by a deprecated alias, with `Deprecation`, `Sunset` and successor `Link` headers", body = EngineOutput),
+        (status = 401, description = "Unauthorized", content(("application/problem+json" = ProblemDetails), ("application/json" = ErrorResponse))),
+        (status = 403, description = "Insufficient consciousness phase, `options.debug` without admin:debug, or an API key not scoped to the engine (`details.required_scope`)", content(("application/problem+json" = ProblemDetails), ("application/json" = ErrorResponse))),
+        (status = 404, description = "Engine not found", content(("application/problem+json" = ProblemDetails), ("application/json" = ErrorResponse))),
+        (status = 410, description = "Engine id is an alias past its sunset; `details.replacement` names the engine to use", content(("application/problem+json" = ProblemDetails), ("application/json" = ErrorResponse))),
+        (status = 413, description = "Request body larger than `MAX_BODY_BYTES`", content(("application/problem+json" = ProblemDetails), ("application/json" = ErrorResponse))),
+        (status = 415, description = "Request body is not JSON", content(("application/problem+json" = ProblemDetails), ("application/json" = ErrorResponse))),
+        (status = 422, description = "Validation error, including unknown fields under `STRICT_JSON`", content(("application/problem+json" = ProblemDetails), ("application/json" = ErrorResponse))),
+        (status = 429, description = "Rate limit exceeded", content(("application/problem+json" = ProblemDetails), ("application/json" = ErrorResponse))),
     ),
     security(
         ("bearer_auth" = []),
@@ -450,28 +930,50 @@ async fn calculate_handler(
     State(state): State<AppState>,
     Extension(user): Extension<AuthUser>,
     Path(engine_id): Path<String>,
-    Json(input): Json<EngineInput>,
-) -> Result<Json<EngineOutput>, (StatusCode, Json<ErrorResponse>)> {
+    Query(params): Query<AsOfParams>,
+    version: ApiVersion,
+    InputJson(mut input): InputJson,
+) -> Result<(axum::http::HeaderMap, Versioned<EngineOutput>), (StatusCode, Json<ErrorResponse>)> {
+    // An old id of a renamed engine is served, and measured, as its replacement
+    let alias = state.core.orchestrator.registry().alias(&engine_id).cloned();
+    let engine_id = alias.as_ref().map_or(engine_id, |alias| alias.engine_id.clone());
+    require_engine_scope(&user, &engine_id)?;
+
+    geo::enrich_input(state.geocoder.as_ref(), &mut input)
+        .await
+        .map_err(engine_error_to_response)?;
+    set_as_of(&mut input, params.as_of);
+    apply_user_settings(&state, &user, &mut input).await;
+    require_debug_access(&state, &user, &input)?;
+
+    // Swiss Ephemeris is checked at the birth moment, which a retrospective reading doesn't use
+    let cross_check_input = (engine_id == "panchanga" && !input.options.contains_key(AS_OF_OPTION))
+        .then(|| input.clone());
     let start = Instant::now();
     
     // Execute engine with user's consciousness level
-    let result = state
-        .orchestrator
-        .execute_engine(&engine_id, input, user.consciousness_level)
-        .await;
+    let requested = alias.as_ref().map_or(engine_id.as_str(), |alias| alias.alias.as_str());
+    let result = state.core.calculate_as(&user, requested, input).await;
     
     let duration_secs = start.elapsed().as_secs_f64();
     
     match result {
         Ok(output) => {
-            state.metrics.record_engine_calculation_with_status(&engine_id, "success", duration_secs);
-            Ok(Json(output))
+            state.core.metrics.record_engine_calculation_with_status(&engine_id, "success", duration_secs);
+            if let Some(input) = &cross_check_input {
+                validation::record_cross_check(&state.core.metrics, input, &output);
+            }
+            let headers = alias
+                .map(|alias| versioning::alias_headers(&alias, version))
+                .unwrap_or_default();
+            Ok((headers, Versioned(version, output)))
         }
         Err(e) => {
-            state.metrics.record_engine_calculation_with_status(&engine_id, "failure", duration_secs);
+            state.core.metrics.record_engine_calculation_with_status(&engine_id, "failure", duration_secs);
             
             let error_type = match &e {
                 EngineError::EngineNotFound(_) => "not_found",
+                EngineError::EngineRetired { .. } => "retired",
                 EngineError::PhaseAccessDenied { .. } => "forbidden",
                 EngineError::AuthError(_) => "unauthorized",
                 EngineError::RateLimitExceeded => "rate_limit",
@@ -479,7 +981,7 @@ async fn calculate_handler(
                 _ => "internal_error",
             };
             
-            state.metrics.record_engine_calculation_error(&engine_id, error_type);
+            state.core.metrics.record_engine_calculation_error(&engine_id, error_type);
             Err(engine_error_to_response(e))
         }
     }
@@ -496,7 +998,7 @@ async fn calculate_handler(
     request_body = EngineOutput,
     responses(
         (status = 200, description = "Validation result", body = ValidationResult),
-        (status = 404, description = "Engine not found", body = ErrorResponse),
+        (status = 404, description = "Engine not found", content(("application/problem+json" = ProblemDetails), ("application/json" = ErrorResponse))),
     ),
     security(
         ("bearer_auth" = []),
@@ -508,26 +1010,17 @@ async fn validate_handler(
     Path(engine_id): Path<String>,
     Json(output): Json<EngineOutput>,
 ) -> Result<Json<noesis_core::ValidationResult>, (StatusCode, Json<ErrorResponse>)> {
-    let engine = state
+    let (engine, _) = state
+        .core
         .orchestrator
         .registry()
-        .get(&engine_id)
-        .ok_or_else(|| {
-            (
-                StatusCode::NOT_FOUND,
-                Json(ErrorResponse {
-                    error: format!("Engine '{}' not found", engine_id),
-                    error_code: "ENGINE_NOT_FOUND".to_string(),
-                    details: Some(serde_json::json!({ "engine_id": engine_id })),
-                }),
-            )
-        })?;
+        .resolve(&engine_id)
+        .map_err(engine_error_to_response)?;
 
-    engine
-        .validate(&output)
-        .await
-        .map(Json)
-        .map_err(|e| engine_error_to_response(e))
+    let mut validation = engine.validate(&output).await.map_err(engine_error_to_response)?;
+    // Input problems the calculation recorded, such as ignored option keys
+    validation.messages.extend(output.metadata.warnings.iter().cloned());
+    Ok(Json(validation))
 }
 
 /// GET /api/v1/engines/:engine_id/info -- engine metadata
@@ -539,8 +1032,9 @@ async fn validate_handler(
         ("engine_id" = String, Path, description = "Engine identifier"),
     ),
     responses(
-        (status = 200, description = "Engine information", body = EngineInfoResponse),
-        (status = 404, description = "Engine not found", body = ErrorResponse),
+        (status = 200, description = "Engine information; an alias's describes the engine it resolves to", body = EngineInfoResponse),
+        (status = 404, description = "Engine not found", content(("application/problem+json" = ProblemDetails), ("application/json" = ErrorResponse))),
+        (status = 410, description = "Engine id is an alias past its sunset", content(("application/problem+json" = ProblemDetails), ("application/json" = ErrorResponse))),
     ),
     security(
         ("bearer_auth" = []),
@@ -552,6 +1046,7 @@ async fn engine_info_handler(
     Path(engine_id): Path<String>,
 ) -> Result<Json<EngineInfoResponse>, (StatusCode, Json<ErrorResponse>)> {
     let engine = state
+        .core
         .orchestrator
         .registry()
         .get(&engine_id)
@@ -562,6 +1057,7 @@ async fn engine_info_handler(
                     error: format!("Engine '{}' not found", engine_id),
                     error_code: "ENGINE_NOT_FOUND".to_string(),
                     details: Some(serde_json::json!({ "engine_id": engine_id })),
+                    request_id: None,
                 }),
             )
         })?;
@@ -570,6 +1066,70 @@ async fn engine_info_handler(
         engine_id: engine.engine_id().to_string(),
         engine_name: engine.engine_name().to_string(),
         required_phase: engine.required_phase(),
+        engine_version: engine.engine_version().to_string(),
+        option_keys: engine
+            .option_keys()
+            .map(|keys| keys.iter().map(|key| key.to_string()).collect()),
+        description: engine.describe(),
+    }))
+}
+
+/// GET /api/v1/engines/:engine_id/examples -- sample inputs and result shapes
+#[utoipa::path(
+    get,
+    path = "/api/v1/engines/{engine_id}/examples",
+    tag = "engines",
+    params(
+        ("engine_id" = String, Path, description = "Engine identifier"),
+    ),
+    responses(
+        (status = 200, description = "Inputs the engine is known to calculate, with fake birth data, and the shape of their results", body = EngineExamplesResponse),
+        (status = 404, description = "Engine not found", content(("application/problem+json" = ProblemDetails), ("application/json" = ErrorResponse))),
+        (status = 410, description = "Engine id is an alias past its sunset", content(("application/problem+json" = ProblemDetails), ("application/json" = ErrorResponse))),
+    ),
+    security(
+        ("bearer_auth" = []),
+        ("api_key" = [])
+    )
+)]
+async fn engine_examples_handler(
+    State(state): State<AppState>,
+    Path(engine_id): Path<String>,
+) -> Result<Json<EngineExamplesResponse>, (StatusCode, Json<ErrorResponse>)> {
+    let (engine, _) = state
+        .core
+        .orchestrator
+        .registry()
+        .resolve(&engine_id)
+        .map_err(engine_error_to_response)?;
+
+    // Bridged engines carry a recorded result; the others calculate theirs
+    // directly, so a cached result can't stand in for the current shape
+    let examples = futures::future::join_all(engine.examples().into_iter().map(|example| {
+        let engine = engine.clone();
+        async move {
+            let result = match example.recorded_result {
+                Some(result) => Ok(result),
+                None => engine.calculate(example.input.clone()).await.map(|output| output.result),
+            };
+            let (result_shape, error) = match result {
+                Ok(result) => (Some(noesis_core::examples::result_shape(&result)), None),
+                Err(e) => (None, Some(e.to_string())),
+            };
+            EngineExampleResponse {
+                name: example.name,
+                summary: example.summary,
+                input: example.input,
+                result_shape,
+                error,
+            }
+        }
+    }))
+    .await;
+
+    Ok(Json(EngineExamplesResponse {
+        engine_id: engine.engine_id().to_string(),
+        examples,
     }))
 }
 
@@ -588,7 +1148,37 @@ async fn engine_info_handler(
 )]
 async fn list_engines_handler(State(state): State<AppState>) -> Json<EngineListResponse> {
     Json(EngineListResponse {
-        engines: state.orchestrator.list_engines(),
+        engines: state.core.orchestrator.list_engines(),
+        aliases: state.core.orchestrator.registry().aliases().into_iter().cloned().collect(),
+    })
+}
+
+/// GET /api/v1/engines/health -- run every engine's self-check
+#[utoipa::path(
+    get,
+    path = "/api/v1/engines/health",
+    tag = "engines",
+    responses(
+        (status = 200, description = "Per-engine health; critical engines also gate /ready", body = EngineHealthResponse),
+    ),
+    security(
+        ("bearer_auth" = []),
+        ("api_key" = [])
+    )
+)]
+async fn engines_health_handler(State(state): State<AppState>) -> Json<EngineHealthResponse> {
+    let engines = state.core.orchestrator.engine_health().await;
+    let status = if engines.iter().all(|e| e.healthy) {
+        "healthy"
+    } else if engines.iter().any(|e| e.critical && !e.healthy) {
+        "unhealthy"
+    } else {
+        "degraded"
+    };
+
+    Json(EngineHealthResponse {
+        status: status.to_string(),
+        engines,
     })
 }
 
@@ -599,15 +1189,22 @@ async fn list_engines_handler(State(state): State<AppState>) -> Json<EngineListR
     tag = "workflows",
     params(
         ("workflow_id" = String, Path, description = "Workflow identifier"),
+        ("save" = Option<bool>, Query, description = "Persist the result; its id is returned in `X-Result-Id` and `Location`"),
+        ("as_of" = Option<String>, Query, description = "Run every engine as of this RFC 3339 time or YYYY-MM-DD date; overrides `options.as_of`"),
+        ("location" = Option<String>, Query, description = "Read at a saved location: its id, or `nearest` for the one closest to `current_location`"),
+        ("dry_run" = Option<bool>, Query, description = "Return the execution plan, a `WorkflowPlan`, without running any engine; `save` is ignored"),
     ),
-    request_body = EngineInput,
+    request_body(content = WorkflowExecuteRequest, description = "An `EngineInput`, or a `PresetRequest` expanded from a saved profile"),
     responses(
-        (status = 200, description = "Workflow execution successful", body = WorkflowResult),
-        (status = 401, description = "Unauthorized", body = ErrorResponse),
-        (status = 403, description = "Forbidden - Insufficient consciousness phase", body = ErrorResponse),
-        (status = 404, description = "Workflow not found", body = ErrorResponse),
-        (status = 422, description = "Validation error", body = ErrorResponse),
-        (status = 429, description = "Rate limit exceeded", body = ErrorResponse),
+        (status = 200, description = "Workflow execution successful, or a `WorkflowPlan` with `dry_run=true`", body = WorkflowResult,
+            headers(("X-Result-Id" = String, description = "Id of the saved result, only with `save=true`"))),
+        (status = 401, description = "Unauthorized", content(("application/problem+json" = ProblemDetails), ("application/json" = ErrorResponse))),
+        (status = 403, description = "Insufficient consciousness phase, `options.debug` without admin:debug, a client preset without clients:calculate or consent, or an API key not scoped to the workflow (`details.required_scope`)", content(("application/problem+json" = ProblemDetails), ("application/json" = ErrorResponse))),
+        (status = 404, description = "Workflow or preset profile not found", content(("application/problem+json" = ProblemDetails), ("application/json" = ErrorResponse))),
+        (status = 413, description = "Request body larger than `MAX_BODY_BYTES`", content(("application/problem+json" = ProblemDetails), ("application/json" = ErrorResponse))),
+        (status = 415, description = "Request body is not JSON", content(("application/problem+json" = ProblemDetails), ("application/json" = ErrorResponse))),
+        (status = 422, description = "Validation error, including unknown fields under `STRICT_JSON`", content(("application/problem+json" = ProblemDetails), ("application/json" = ErrorResponse))),
+        (status = 429, description = "Rate limit exceeded", content(("application/problem+json" = ProblemDetails), ("application/json" = ErrorResponse))),
     ),
     security(
         ("bearer_auth" = []),
@@ -618,15 +1215,73 @@ async fn workflow_execute_handler(
     State(state): State<AppState>,
     Extension(user): Extension<AuthUser>,
     Path(workflow_id): Path<String>,
-    Json(input): Json<EngineInput>,
-) -> Result<Json<noesis_core::WorkflowResult>, (StatusCode, Json<ErrorResponse>)> {
+    Query(params): Query<WorkflowExecuteParams>,
+    version: ApiVersion,
+    body: WorkflowBody,
+) -> Result<axum::response::Response, (StatusCode, Json<ErrorResponse>)> {
+    require_workflow_scope(&user, &workflow_id)?;
+    let (mut input, client_id) = match body {
+        WorkflowBody::Input(input) => {
+            let mut input = *input;
+            geo::enrich_input(state.geocoder.as_ref(), &mut input)
+                .await
+                .map_err(engine_error_to_response)?;
+            (input, None)
+        }
+        WorkflowBody::Preset(request) => {
+            let resolved = presets::resolve(&state, &user, request).await?;
+            (resolved.input, resolved.client_id)
+        }
+    };
+    if let Some(selector) = params.location.as_deref() {
+        handlers::locations::select_saved_location(&state, &user, &mut input, selector).await?;
+    }
+    set_as_of(&mut input, params.as_of);
+
+    if params.dry_run {
+        return plan_workflow(&state, &user, &workflow_id, input).await;
+    }
+    run_workflow(&state, &user, version, &workflow_id, input, params.save, client_id).await
+}
+
+/// Add the caller's saved settings to `input`; see [`settings`].
+///
+/// Skipped while the database is unavailable and for callers without an
+/// account, whose token doesn't carry a user UUID.
+async fn apply_user_settings(state: &AppState, user: &AuthUser, input: &mut EngineInput) {
+    if !state.database.is_available() {
+        return;
+    }
+    let Ok(user_id) = uuid::Uuid::parse_str(&user.user_id) else {
+        return;
+    };
+    let saved = state.settings.options_for(user_id).await;
+    settings::apply_saved(input, &saved);
+}
+
+/// Execute a workflow with the user's consciousness level and saved settings,
+/// recording metrics, and save the result (for `client_id` when set) if
+/// `save` is true. The result is returned in `version`'s shape.
+pub(crate) async fn run_workflow(
+    state: &AppState,
+    user: &AuthUser,
+    version: ApiVersion,
+    workflow_id: &str,
+    mut input: EngineInput,
+    save: bool,
+    client_id: Option<uuid::Uuid>,
+) -> Result<axum::response::Response, (StatusCode, Json<ErrorResponse>)> {
+    if save {
+        state.database.ensure_available()?;
+    }
+    apply_user_settings(state, user, &mut input).await;
+    require_debug_access(state, user, &input)?;
+    // Kept with a saved result, so it can be replayed
+    let saved_input = save.then(|| input.clone());
     let start = Instant::now();
     
     // Execute workflow with user's consciousness level
-    let result = state
-        .orchestrator
-        .execute_workflow(&workflow_id, input, user.consciousness_level)
-        .await;
+    let result = state.core.execute_workflow_as(user, workflow_id, input).await;
     
     let duration_secs = start.elapsed().as_secs_f64();
     
@@ -635,11 +1290,24 @@ async fn workflow_execute_handler(
     
     match result {
         Ok(workflow_result) => {
-            state.metrics.record_engine_calculation_with_status(&workflow_label, "success", duration_secs);
-            Ok(Json(workflow_result))
+            state.core.metrics.record_engine_calculation_with_status(&workflow_label, "success", duration_secs);
+            let Some(input) = saved_input else {
+                return Ok(Versioned(version, workflow_result).into_response());
+            };
+
+            let result_id = save_workflow_result(state, user, client_id, &workflow_result, &input).await?;
+            let location = format!("{}/results/{}", version.path_prefix(), result_id);
+            Ok((
+                [
+                    (axum::http::header::LOCATION, location),
+                    (axum::http::HeaderName::from_static(RESULT_ID_HEADER), result_id.to_string()),
+                ],
+                Versioned(version, workflow_result),
+            )
+                .into_response())
         }
         Err(e) => {
-            state.metrics.record_engine_calculation_with_status(&workflow_label, "failure", duration_secs);
+            state.core.metrics.record_engine_calculation_with_status(&workflow_label, "failure", duration_secs);
             
             let error_type = match &e {
                 EngineError::WorkflowNotFound(_) => "not_found",
@@ -650,12 +1318,91 @@ async fn workflow_execute_handler(
                 _ => "internal_error",
             };
             
-            state.metrics.record_engine_calculation_error(&workflow_label, error_type);
+            state.core.metrics.record_engine_calculation_error(&workflow_label, error_type);
             Err(engine_error_to_response(e))
         }
     }
 }
 
+/// The plan of a workflow for the user, with their saved settings, without
+/// running it; see [`noesis_orchestrator::plan`]
+pub(crate) async fn plan_workflow(
+    state: &AppState,
+    user: &AuthUser,
+    workflow_id: &str,
+    mut input: EngineInput,
+) -> Result<axum::response::Response, (StatusCode, Json<ErrorResponse>)> {
+    apply_user_settings(state, user, &mut input).await;
+    let plan = state
+        .core
+        .plan_workflow_as(user, workflow_id, input)
+        .await
+        .map_err(engine_error_to_response)?;
+    Ok(Json(plan).into_response())
+}
+
+/// Header carrying the id of a result saved with `?save=true`
+pub const RESULT_ID_HEADER: &str = "x-result-id";
+
+#[derive(Deserialize)]
+pub(crate) struct WorkflowExecuteParams {
+    #[serde(default)]
+    pub save: bool,
+    pub as_of: Option<String>,
+    /// Saved location id or `nearest`; see `handlers::locations::select_saved_location`
+    pub location: Option<String>,
+    /// Plan instead of running; see [`plan_workflow`]
+    #[serde(default)]
+    pub dry_run: bool,
+}
+
+#[derive(Deserialize)]
+struct AsOfParams {
+    as_of: Option<String>,
+}
+
+/// Copy an `?as_of=` query parameter into the input, where it wins over
+/// `options.as_of`; the orchestrator validates and applies it
+pub(crate) fn set_as_of(input: &mut EngineInput, as_of: Option<String>) {
+    if let Some(as_of) = as_of {
+        input
+            .options
+            .insert(AS_OF_OPTION.to_string(), serde_json::Value::String(as_of));
+    }
+}
+
+async fn save_workflow_result(
+    state: &AppState,
+    user: &AuthUser,
+    client_id: Option<uuid::Uuid>,
+    workflow_result: &WorkflowResult,
+    input: &EngineInput,
+) -> Result<uuid::Uuid, (StatusCode, Json<ErrorResponse>)> {
+    let user_id = uuid::Uuid::parse_str(&user.user_id).map_err(|_| {
+        engine_error_to_response(EngineError::AuthError("Invalid user ID in token".to_string()))
+    })?;
+    let result = serde_json::to_value(workflow_result)
+        .map_err(|e| engine_error_to_response(EngineError::InternalError(e.to_string())))?;
+    let input = serde_json::to_value(input)
+        .map_err(|e| engine_error_to_response(EngineError::InternalError(e.to_string())))?;
+
+    state
+        .result_repository
+        .save_result(
+            user_id,
+            client_id,
+            &workflow_result.workflow_id,
+            &result,
+            &input,
+            i16::from(user.consciousness_level),
+        )
+        .await
+        .map(|saved| saved.id)
+        .map_err(|e| {
+            engine_error_to_response(EngineError::InternalError(format!("Database error: {}", e)))
+        })
+}
+
 /// GET /api/v1/workflows -- list all workflow IDs
 #[utoipa::path(
     get,
@@ -671,6 +1418,7 @@ async fn workflow_execute_handler(
 )]
 async fn list_workflows_handler(State(state): State<AppState>) -> Json<WorkflowListResponse> {
     let workflows = state
+        .core
         .orchestrator
         .list_workflows()
         .iter()
@@ -695,7 +1443,7 @@ async fn list_workflows_handler(State(state): State<AppState>) -> Json<WorkflowL
     ),
     responses(
         (status = 200, description = "Workflow information", body = WorkflowInfoResponse),
-        (status = 404, description = "Workflow not found", body = ErrorResponse),
+        (status = 404, description = "Workflow not found", content(("application/problem+json" = ProblemDetails), ("application/json" = ErrorResponse))),
     ),
     security(
         ("bearer_auth" = []),
@@ -707,6 +1455,7 @@ async fn workflow_info_handler(
     Path(workflow_id): Path<String>,
 ) -> Result<Json<WorkflowInfoResponse>, (StatusCode, Json<ErrorResponse>)> {
     let workflow = state
+        .core
         .orchestrator
         .get_workflow(&workflow_id)
         .ok_or_else(|| {
@@ -716,22 +1465,263 @@ async fn workflow_info_handler(
                     error: format!("Workflow '{}' not found", workflow_id),
                     error_code: "WORKFLOW_NOT_FOUND".to_string(),
                     details: Some(serde_json::json!({ "workflow_id": workflow_id })),
+                    request_id: None,
                 }),
             )
         })?;
 
     Ok(Json(WorkflowInfoResponse {
-        id: workflow.id.clone(),
-        name: workflow.name.clone(),
-        description: workflow.description.clone(),
-        engine_ids: workflow.engine_ids.clone(),
+        id: workflow.id,
+        name: workflow.name,
+        description: workflow.description,
+        engine_ids: workflow.engine_ids,
+        engine_overrides: workflow.engine_overrides,
+        transformers: workflow.transformers,
+        phase_variants: workflow.phase_variants,
     }))
 }
 
+/// Query parameters for place search
+#[derive(Deserialize)]
+struct GeoSearchQuery {
+    q: String,
+    #[serde(default = "default_geo_limit")]
+    limit: usize,
+}
+
+fn default_geo_limit() -> usize {
+    10
+}
+
+/// GET /api/v1/geo/search -- resolve a place name to coordinates and timezone
+#[utoipa::path(
+    get,
+    path = "/api/v1/geo/search",
+    tag = "geo",
+    params(
+        ("q" = String, Query, description = "Place name, optionally qualified by country code or region (e.g. 'Bengaluru' or 'Portland, Oregon')"),
+        ("limit" = Option<usize>, Query, description = "Maximum number of results (default 10, max 50)"),
+    ),
+    responses(
+        (status = 200, description = "Matching places, best match first", body = GeoSearchResponse),
+        (status = 401, description = "Unauthorized", content(("application/problem+json" = ProblemDetails), ("application/json" = ErrorResponse))),
+        (status = 422, description = "Empty query", content(("application/problem+json" = ProblemDetails), ("application/json" = ErrorResponse))),
+    ),
+    security(
+        ("bearer_auth" = []),
+        ("api_key" = [])
+    )
+)]
+async fn geo_search_handler(
+    State(state): State<AppState>,
+    Query(query): Query<GeoSearchQuery>,
+) -> Result<Json<GeoSearchResponse>, (StatusCode, Json<ErrorResponse>)> {
+    if query.q.trim().is_empty() {
+        return Err(engine_error_to_response(EngineError::ValidationError(
+            "Query parameter 'q' must not be empty".to_string(),
+        )));
+    }
+
+    let results = state
+        .geocoder
+        .search(&query.q, query.limit)
+        .await
+        .map_err(engine_error_to_response)?;
+
+    Ok(Json(GeoSearchResponse {
+        query: query.q,
+        provider: state.geocoder.provider_name().to_string(),
+        results,
+    }))
+}
+
+#[derive(Deserialize)]
+struct ValidationReportParams {
+    engine_id: Option<String>,
+    from: Option<String>,
+    to: Option<String>,
+    #[serde(default = "default_validation_worst")]
+    worst: usize,
+    #[serde(default = "default_validation_samples")]
+    samples: usize,
+}
+
+fn default_validation_worst() -> usize {
+    10
+}
+
+fn default_validation_samples() -> usize {
+    5
+}
+
+/// Most worst offenders or sample inputs a report may list per engine
+const MAX_VALIDATION_REPORT_ITEMS: usize = 100;
+
+/// Permission required for `/api/v1/admin/validation/report`
+const VALIDATION_REPORT_PERMISSION: &str = "admin:validation";
+
+impl ValidationReportParams {
+    fn date(field: &str, value: Option<&str>) -> Result<Option<chrono::NaiveDate>, EngineError> {
+        value
+            .map(|v| {
+                chrono::NaiveDate::parse_from_str(v, "%Y-%m-%d").map_err(|_| {
+                    EngineError::invalid_field(
+                        field,
+                        noesis_core::ValidationCode::InvalidFormat,
+                        format!("'{}' must be a date (YYYY-MM-DD)", field),
+                    )
+                })
+            })
+            .transpose()
+    }
+
+    fn into_query(self) -> Result<noesis_metrics::ValidationReportQuery, EngineError> {
+        let from = Self::date("from", self.from.as_deref())?;
+        let to = Self::date("to", self.to.as_deref())?;
+        if let (Some(from), Some(to)) = (from, to) {
+            if from > to {
+                return Err(EngineError::invalid_field(
+                    "to",
+                    noesis_core::ValidationCode::OutOfRange,
+                    "'to' must not be before 'from'",
+                ));
+            }
+        }
+
+        let midnight = |date: chrono::NaiveDate| date.and_hms_opt(0, 0, 0).map(|dt| dt.and_utc());
+        Ok(noesis_metrics::ValidationReportQuery {
+            engine_id: self.engine_id,
+            from: from.and_then(midnight),
+            // `to` is inclusive; the log filter is exclusive
+            to: to.and_then(|d| d.succ_opt()).and_then(midnight),
+            worst: self.worst.min(MAX_VALIDATION_REPORT_ITEMS),
+            samples: self.samples.min(MAX_VALIDATION_REPORT_ITEMS),
+        })
+    }
+}
+
+/// GET /api/v1/admin/validation/report -- native vs Swiss Ephemeris differences
+///
+/// Summarises the cross-backend samples recorded since startup: per-engine
+/// percentiles by quantity, the largest differences and recent inputs. `from`
+/// and `to` filter on the date each compared quantity refers to.
+#[utoipa::path(
+    get,
+    path = "/api/v1/admin/validation/report",
+    tag = "admin",
+    params(
+        ("engine_id" = Option<String>, Query, description = "Only report this engine"),
+        ("from" = Option<String>, Query, description = "First calculation date (YYYY-MM-DD)"),
+        ("to" = Option<String>, Query, description = "Last calculation date, inclusive (YYYY-MM-DD)"),
+        ("worst" = Option<usize>, Query, description = "Worst offenders per engine (default 10, max 100)"),
+        ("samples" = Option<usize>, Query, description = "Recent sample inputs per engine (default 5, max 100)"),
+    ),
+    responses(
+        (status = 200, description = "Validation report", body = ValidationReport),
+        (status = 401, description = "Unauthorized", content(("application/problem+json" = ProblemDetails), ("application/json" = ErrorResponse))),
+        (status = 403, description = "Missing the admin:validation permission", content(("application/problem+json" = ProblemDetails), ("application/json" = ErrorResponse))),
+        (status = 422, description = "Malformed date range", content(("application/problem+json" = ProblemDetails), ("application/json" = ErrorResponse))),
+    ),
+    security(
+        ("bearer_auth" = []),
+        ("api_key" = [])
+    )
+)]
+async fn validation_report_handler(
+    State(state): State<AppState>,
+    Extension(user): Extension<AuthUser>,
+    Query(params): Query<ValidationReportParams>,
+) -> Result<Json<noesis_metrics::ValidationReport>, (StatusCode, Json<ErrorResponse>)> {
+    if !AuthService::can_access_endpoint(&user, "/api/v1/admin/validation/report") {
+        return Err(permission_denied(VALIDATION_REPORT_PERMISSION));
+    }
+
+    let query = params.into_query().map_err(engine_error_to_response)?;
+    Ok(Json(state.core.metrics.validation_log.report(&query)))
+}
+
 // ---------------------------------------------------------------------------
 // Error mapping
 // ---------------------------------------------------------------------------
 
+/// 403 for a caller whose token lacks `permission`
+pub(crate) fn permission_denied(permission: &str) -> (StatusCode, Json<ErrorResponse>) {
+    (
+        StatusCode::FORBIDDEN,
+        Json(ErrorResponse {
+            error: format!("Permission '{}' required", permission),
+            error_code: "FORBIDDEN".to_string(),
+            details: Some(serde_json::json!({ "required_permission": permission })),
+            request_id: None,
+        }),
+    )
+}
+
+/// Permission that lets a caller set `options.debug`, and replay saved results
+pub(crate) const DEBUG_PERMISSION: &str = "admin:debug";
+
+/// Refuse `options.debug` to a caller without [`DEBUG_PERMISSION`], unless
+/// `OPEN_DEBUG` opens it to everyone
+pub(crate) fn require_debug_access(
+    state: &AppState,
+    user: &AuthUser,
+    input: &EngineInput,
+) -> Result<(), (StatusCode, Json<ErrorResponse>)> {
+    if input.debug() && !state.open_debug {
+        require_permission(user, DEBUG_PERMISSION)?;
+    }
+    Ok(())
+}
+
+/// Require `permission` on the caller's token
+pub(crate) fn require_permission(
+    user: &AuthUser,
+    permission: &str,
+) -> Result<(), (StatusCode, Json<ErrorResponse>)> {
+    if AuthService::has_permission(user, permission) {
+        Ok(())
+    } else {
+        Err(permission_denied(permission))
+    }
+}
+
+/// 403 for an API key whose scope leaves out `scope`, e.g. `engine:numerology`
+pub(crate) fn scope_denied(scope: &str) -> (StatusCode, Json<ErrorResponse>) {
+    (
+        StatusCode::FORBIDDEN,
+        Json(ErrorResponse {
+            error: format!("API key is not scoped to '{}'", scope),
+            error_code: "FORBIDDEN".to_string(),
+            details: Some(serde_json::json!({ "required_scope": scope })),
+            request_id: None,
+        }),
+    )
+}
+
+/// Refuse `engine_id` to an API key scoped to other engines
+pub(crate) fn require_engine_scope(
+    user: &AuthUser,
+    engine_id: &str,
+) -> Result<(), (StatusCode, Json<ErrorResponse>)> {
+    if user.can_use_engine(engine_id) {
+        Ok(())
+    } else {
+        Err(scope_denied(&noesis_auth::engine_scope(engine_id)))
+    }
+}
+
+/// Refuse `workflow_id` to an API key scoped to other workflows
+pub(crate) fn require_workflow_scope(
+    user: &AuthUser,
+    workflow_id: &str,
+) -> Result<(), (StatusCode, Json<ErrorResponse>)> {
+    if user.can_run_workflow(workflow_id) {
+        Ok(())
+    } else {
+        Err(scope_denied(&noesis_auth::workflow_scope(workflow_id)))
+    }
+}
+
 pub fn engine_error_to_response(err: EngineError) -> (StatusCode, Json<ErrorResponse>) {
     let (status, error_code, message, details) = match &err {
         EngineError::EngineNotFound(id) => (
@@ -740,6 +1730,16 @@ pub fn engine_error_to_response(err: EngineError) -> (StatusCode, Json<ErrorResp
             err.to_string(),
             Some(serde_json::json!({ "engine_id": id })),
         ),
+        EngineError::EngineRetired { alias, replacement, sunset } => (
+            StatusCode::GONE,
+            "ENGINE_RETIRED".to_string(),
+            err.to_string(),
+            Some(serde_json::json!({
+                "engine_id": alias,
+                "replacement": replacement,
+                "sunset": sunset
+            })),
+        ),
         EngineError::WorkflowNotFound(id) => (
             StatusCode::NOT_FOUND,
             "WORKFLOW_NOT_FOUND".to_string(),
@@ -773,6 +1773,12 @@ pub fn engine_error_to_response(err: EngineError) -> (StatusCode, Json<ErrorResp
             err.to_string(),
             Some(serde_json::json!({ "validation_message": msg })),
         ),
+        EngineError::InvalidInput(errors) => (
+            StatusCode::UNPROCESSABLE_ENTITY,
+            "VALIDATION_ERROR".to_string(),
+            err.to_string(),
+            Some(serde_json::json!({ "errors": errors })),
+        ),
         EngineError::CalculationError(msg) => (
             StatusCode::INTERNAL_SERVER_ERROR,
             "CALCULATION_ERROR".to_string(),
@@ -817,6 +1823,7 @@ pub fn engine_error_to_response(err: EngineError) -> (StatusCode, Json<ErrorResp
             error: message,
             error_code,
             details,
+            request_id: None,
         }),
     )
 }
@@ -826,7 +1833,7 @@ pub fn engine_error_to_response(err: EngineError) -> (StatusCode, Json<ErrorResp
 // ---------------------------------------------------------------------------
 
 /// Legacy request format for Panchanga calculations from old Selemene API
-#[derive(Deserialize)]
+#[derive(Deserialize, ToSchema)]
 struct LegacyPanchangaRequest {
     date: String,        // YYYY-MM-DD
     time: Option<String>, // HH:MM
@@ -835,10 +1842,55 @@ struct LegacyPanchangaRequest {
     timezone: String,
     #[serde(default)]
     name: Option<String>,
+    /// Calculation precision, passed through to `EngineInput.precision`
+    #[serde(default)]
+    precision: Precision,
+    /// Sidereal reference: lahiri, raman, krishnamurti or fagan_bradley.
+    /// Longitudes are tropical when omitted.
+    #[serde(default)]
+    #[schema(example = "lahiri")]
+    ayanamsa: Option<String>,
+}
+
+impl LegacyPanchangaRequest {
+    /// Map the legacy request onto the new EngineInput format
+    fn into_engine_input(self) -> EngineInput {
+        let mut options = std::collections::HashMap::new();
+        if let Some(ayanamsa) = self.ayanamsa {
+            options.insert("ayanamsa".to_string(), serde_json::Value::String(ayanamsa));
+        }
+
+        EngineInput {
+            birth_data: Some(noesis_core::BirthData {
+                name: self.name,
+                display_name: None,
+                date: self.date,
+                time: self.time,
+                time_unknown: false,
+                latitude: self.latitude,
+                longitude: self.longitude,
+                altitude: None,
+                timezone: self.timezone,
+                place: None,
+                utc_offset_minutes: None,
+            }),
+            secondary_birth_data: None,
+            subjects: Vec::new(),
+            current_time: chrono::Utc::now(),
+            location: Some(noesis_core::Coordinates {
+                latitude: self.latitude,
+                longitude: self.longitude,
+                altitude: None,
+                timezone: None,
+            }),
+            precision: self.precision,
+            options,
+        }
+    }
 }
 
 /// Legacy response format for Panchanga calculations
-#[derive(Serialize)]
+#[derive(Serialize, ToSchema)]
 struct LegacyPanchangaResponse {
     // Preserve exact field names from old Selemene API
     tithi_index: u8,
@@ -860,67 +1912,173 @@ struct LegacyPanchangaResponse {
     julian_day: f64,
 }
 
+impl From<noesis_core::results::PanchangaResult> for LegacyPanchangaResponse {
+    fn from(panchanga: noesis_core::results::PanchangaResult) -> Self {
+        Self {
+            tithi_index: panchanga.tithi_index,
+            tithi_name: panchanga.tithi_name,
+            tithi_value: panchanga.tithi_value,
+            nakshatra_index: panchanga.nakshatra_index,
+            nakshatra_name: panchanga.nakshatra_name,
+            nakshatra_value: panchanga.nakshatra_value,
+            yoga_index: panchanga.yoga_index,
+            yoga_name: panchanga.yoga_name,
+            yoga_value: panchanga.yoga_value,
+            karana_index: panchanga.karana_index,
+            karana_name: panchanga.karana_name,
+            karana_value: panchanga.karana_value,
+            vara_index: panchanga.vara_index,
+            vara_name: panchanga.vara_name,
+            solar_longitude: panchanga.solar_longitude,
+            lunar_longitude: panchanga.lunar_longitude,
+            julian_day: panchanga.julian_day,
+        }
+    }
+}
+
+/// Run one legacy Panchanga request through the orchestrator
+async fn execute_legacy_panchanga(
+    state: &AppState,
+    request: LegacyPanchangaRequest,
+) -> Result<LegacyPanchangaResponse, EngineError> {
+    let output = state
+        .core
+        .orchestrator
+        .execute_engine("panchanga", request.into_engine_input(), 0)
+        .await?;
+    let panchanga: noesis_core::results::PanchangaResult = output.parse_as()?;
+    Ok(panchanga.into())
+}
+
 /// POST /api/legacy/panchanga/calculate -- backward compatible Panchanga endpoint
+#[utoipa::path(
+    post,
+    path = "/api/legacy/panchanga/calculate",
+    tag = "legacy",
+    request_body = LegacyPanchangaRequest,
+    responses(
+        (status = 200, description = "Panchanga in the original Selemene field layout", body = LegacyPanchangaResponse),
+        (status = 422, description = "Validation error", content(("application/problem+json" = ProblemDetails), ("application/json" = ErrorResponse))),
+    )
+)]
 async fn legacy_panchanga_handler(
     State(state): State<AppState>,
     Json(request): Json<LegacyPanchangaRequest>,
 ) -> Result<Json<LegacyPanchangaResponse>, (StatusCode, Json<ErrorResponse>)> {
-    // Convert legacy request to new EngineInput format
-    let input = EngineInput {
-        birth_data: Some(noesis_core::BirthData {
-            name: request.name,
-            date: request.date,
-            time: request.time,
-            latitude: request.latitude,
-            longitude: request.longitude,
-            timezone: request.timezone,
-        }),
-        current_time: chrono::Utc::now(),
-        location: Some(noesis_core::Coordinates {
-            latitude: request.latitude,
-            longitude: request.longitude,
-            altitude: None,
-        }),
-        precision: noesis_core::Precision::Standard,
-        options: std::collections::HashMap::new(),
-    };
-
-    // Execute Panchanga engine through orchestrator
-    let output = state
-        .orchestrator
-        .execute_engine("panchanga", input, 0)
+    execute_legacy_panchanga(&state, request)
         .await
-        .map_err(engine_error_to_response)?;
+        .map(Json)
+        .map_err(engine_error_to_response)
+}
 
-    // Extract PanchangaResult from engine output
-    let panchanga_result: serde_json::Value = output.result;
-    
-    // Convert to legacy response format
-    let legacy_response = LegacyPanchangaResponse {
-        tithi_index: panchanga_result["tithi_index"].as_u64().unwrap_or(0) as u8,
-        tithi_name: panchanga_result["tithi_name"].as_str().unwrap_or("").to_string(),
-        tithi_value: panchanga_result["tithi_value"].as_f64().unwrap_or(0.0),
-        nakshatra_index: panchanga_result["nakshatra_index"].as_u64().unwrap_or(0) as u8,
-        nakshatra_name: panchanga_result["nakshatra_name"].as_str().unwrap_or("").to_string(),
-        nakshatra_value: panchanga_result["nakshatra_value"].as_f64().unwrap_or(0.0),
-        yoga_index: panchanga_result["yoga_index"].as_u64().unwrap_or(0) as u8,
-        yoga_name: panchanga_result["yoga_name"].as_str().unwrap_or("").to_string(),
-        yoga_value: panchanga_result["yoga_value"].as_f64().unwrap_or(0.0),
-        karana_index: panchanga_result["karana_index"].as_u64().unwrap_or(0) as u8,
-        karana_name: panchanga_result["karana_name"].as_str().unwrap_or("").to_string(),
-        karana_value: panchanga_result["karana_value"].as_f64().unwrap_or(0.0),
-        vara_index: panchanga_result["vara_index"].as_u64().unwrap_or(0) as u8,
-        vara_name: panchanga_result["vara_name"].as_str().unwrap_or("").to_string(),
-        solar_longitude: panchanga_result["solar_longitude"].as_f64().unwrap_or(0.0),
-        lunar_longitude: panchanga_result["lunar_longitude"].as_f64().unwrap_or(0.0),
-        julian_day: panchanga_result["julian_day"].as_f64().unwrap_or(0.0),
-    };
+/// Maximum number of calculations in one legacy batch request
+const LEGACY_BATCH_MAX: usize = 100;
+
+/// Legacy batch request: independent Panchanga calculations
+#[derive(Deserialize, ToSchema)]
+struct LegacyPanchangaBatchRequest {
+    requests: Vec<LegacyPanchangaRequest>,
+}
 
-    Ok(Json(legacy_response))
+/// Outcome of one calculation in a legacy batch
+#[derive(Serialize, ToSchema)]
+struct LegacyPanchangaBatchItem {
+    /// Position of the request in `requests`
+    index: usize,
+    /// "success" or "error"
+    #[schema(example = "success")]
+    status: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    data: Option<LegacyPanchangaResponse>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<ErrorResponse>,
+}
+
+/// Legacy batch response, one item per request in request order
+#[derive(Serialize, ToSchema)]
+struct LegacyPanchangaBatchResponse {
+    results: Vec<LegacyPanchangaBatchItem>,
+    succeeded: usize,
+    failed: usize,
+}
+
+/// POST /api/legacy/panchanga/batch -- backward compatible batch Panchanga endpoint
+///
+/// Requests are calculated concurrently. A failing request does not fail the
+/// batch; its item carries the error instead.
+#[utoipa::path(
+    post,
+    path = "/api/legacy/panchanga/batch",
+    tag = "legacy",
+    request_body = LegacyPanchangaBatchRequest,
+    responses(
+        (status = 200, description = "Per-request results in request order", body = LegacyPanchangaBatchResponse),
+        (status = 422, description = "Empty batch or more than 100 requests", content(("application/problem+json" = ProblemDetails), ("application/json" = ErrorResponse))),
+    )
+)]
+async fn legacy_panchanga_batch_handler(
+    State(state): State<AppState>,
+    Json(batch): Json<LegacyPanchangaBatchRequest>,
+) -> Result<Json<LegacyPanchangaBatchResponse>, (StatusCode, Json<ErrorResponse>)> {
+    if batch.requests.is_empty() {
+        return Err(engine_error_to_response(EngineError::invalid_field(
+            "requests",
+            noesis_core::ValidationCode::Empty,
+            "Batch must contain at least one request",
+        )));
+    }
+    if batch.requests.len() > LEGACY_BATCH_MAX {
+        return Err(engine_error_to_response(EngineError::invalid_field(
+            "requests",
+            noesis_core::ValidationCode::OutOfRange,
+            format!(
+                "Batch contains {} requests; the maximum is {}",
+                batch.requests.len(),
+                LEGACY_BATCH_MAX
+            ),
+        )));
+    }
+
+    let outcomes = futures::future::join_all(
+        batch
+            .requests
+            .into_iter()
+            .map(|request| execute_legacy_panchanga(&state, request)),
+    )
+    .await;
+
+    let results: Vec<LegacyPanchangaBatchItem> = outcomes
+        .into_iter()
+        .enumerate()
+        .map(|(index, outcome)| match outcome {
+            Ok(data) => LegacyPanchangaBatchItem {
+                index,
+                status: "success".to_string(),
+                data: Some(data),
+                error: None,
+            },
+            Err(err) => {
+                let (_, Json(error)) = engine_error_to_response(err);
+                LegacyPanchangaBatchItem {
+                    index,
+                    status: "error".to_string(),
+                    data: None,
+                    error: Some(error),
+                }
+            }
+        })
+        .collect();
+    let succeeded = results.iter().filter(|r| r.data.is_some()).count();
+
+    Ok(Json(LegacyPanchangaBatchResponse {
+        failed: results.len() - succeeded,
+        succeeded,
+        results,
+    }))
 }
 
 /// Legacy request format for Ghati time queries
-#[derive(Deserialize)]
+#[derive(Deserialize, ToSchema)]
 struct LegacyGhatiRequest {
     #[serde(default)]
     latitude: Option<f64>,
@@ -928,201 +2086,317 @@ struct LegacyGhatiRequest {
     longitude: Option<f64>,
 }
 
-/// Legacy response format for Ghati time
-#[derive(Serialize)]
+impl LegacyGhatiRequest {
+    /// Coordinates, defaulting to Bangalore when not provided
+    fn coordinates(&self) -> (f64, f64) {
+        (self.latitude.unwrap_or(12.9716), self.longitude.unwrap_or(77.5946))
+    }
+}
+
+/// Legacy response format for Ghati time, also sent as ghati stream event data
+#[derive(Serialize, ToSchema)]
 struct LegacyGhatiResponse {
     ghati: u8,
     pala: u8,
     vipala: u8,
     utc_timestamp: String,
+    /// Sunrise that began the current Vedic day
+    sunrise: String,
+    /// Sunrise that ends the current Vedic day
+    next_sunrise: String,
+}
+
+impl LegacyGhatiResponse {
+    fn new(ghati: &engine_panchanga::GhatiTime, at: chrono::DateTime<chrono::Utc>) -> Self {
+        Self {
+            ghati: ghati.ghati,
+            pala: ghati.pala,
+            vipala: ghati.vipala,
+            utc_timestamp: at.to_rfc3339(),
+            sunrise: ghati.sunrise.to_rfc3339(),
+            next_sunrise: ghati.next_sunrise.to_rfc3339(),
+        }
+    }
 }
 
 /// GET /api/legacy/ghati/current -- backward compatible current Ghati time endpoint
+#[utoipa::path(
+    get,
+    path = "/api/legacy/ghati/current",
+    tag = "legacy",
+    request_body = LegacyGhatiRequest,
+    responses(
+        (status = 200, description = "Ghati, pala and vipala elapsed since local sunrise", body = LegacyGhatiResponse),
+        (status = 422, description = "Invalid coordinates", content(("application/problem+json" = ProblemDetails), ("application/json" = ErrorResponse))),
+        (status = 500, description = "Calculation error", content(("application/problem+json" = ProblemDetails), ("application/json" = ErrorResponse))),
+    )
+)]
 async fn legacy_ghati_current_handler(
-    State(state): State<AppState>,
     Json(request): Json<LegacyGhatiRequest>,
 ) -> Result<Json<LegacyGhatiResponse>, (StatusCode, Json<ErrorResponse>)> {
-    // Default to Bangalore coordinates if not provided
-    let latitude = request.latitude.unwrap_or(12.9716);
-    let longitude = request.longitude.unwrap_or(77.5946);
-
-    // Build input for Panchanga calculation (current time)
+    let (latitude, longitude) = request.coordinates();
     let now = chrono::Utc::now();
-    let input = EngineInput {
-        birth_data: Some(noesis_core::BirthData {
-            name: None,
-            date: now.format("%Y-%m-%d").to_string(),
-            time: Some(now.format("%H:%M").to_string()),
-            latitude,
-            longitude,
-            timezone: "UTC".to_string(),
-        }),
-        current_time: now,
-        location: Some(noesis_core::Coordinates {
-            latitude,
-            longitude,
-            altitude: None,
-        }),
-        precision: noesis_core::Precision::Standard,
-        options: std::collections::HashMap::new(),
-    };
+    let ghati = engine_panchanga::compute_ghati(now, latitude, longitude)
+        .map_err(engine_error_to_response)?;
 
-    // Execute Panchanga engine to get current time data
-    let output = state
-        .orchestrator
-        .execute_engine("panchanga", input, 0)
-        .await
+    Ok(Json(LegacyGhatiResponse::new(&ghati, now)))
+}
+
+/// Delay before retrying a failed ghati stream tick
+const GHATI_STREAM_RETRY_SECS: i64 = 24;
+
+/// GET /api/legacy/ghati/stream -- Server-Sent Events for each new pala.
+///
+/// Sends the current time immediately, then one event per pala (about 24
+/// seconds). Events are named `pala`, or `ghati` when a new ghati begins.
+/// Connections for the same location (to four decimal places) share one
+/// ticker through the [`fanout`] hub.
+#[utoipa::path(
+    get,
+    path = "/api/legacy/ghati/stream",
+    tag = "legacy",
+    params(
+        ("latitude" = Option<f64>, Query, description = "Latitude in decimal degrees (default 12.9716)"),
+        ("longitude" = Option<f64>, Query, description = "Longitude in decimal degrees (default 77.5946)"),
+    ),
+    responses(
+        (status = 200, description = "text/event-stream of ghati ticks; each event's data is a LegacyGhatiResponse", content_type = "text/event-stream", body = LegacyGhatiResponse),
+        (status = 422, description = "Invalid coordinates", content(("application/problem+json" = ProblemDetails), ("application/json" = ErrorResponse))),
+    )
+)]
+async fn legacy_ghati_stream_handler(
+    State(state): State<AppState>,
+    Query(request): Query<LegacyGhatiRequest>,
+) -> Result<Sse<impl futures::Stream<Item = Result<Event, std::convert::Infallible>>>, (StatusCode, Json<ErrorResponse>)> {
+    let (latitude, longitude) = request.coordinates();
+    // Reject bad coordinates before the stream starts
+    engine_panchanga::compute_ghati(chrono::Utc::now(), latitude, longitude)
         .map_err(engine_error_to_response)?;
 
-    // Extract tithi value to calculate ghati time
-    // In Vedic time, 1 day = 60 ghatis, 1 ghati = 24 minutes
-    let panchanga_result: serde_json::Value = output.result;
-    let _tithi_value = panchanga_result["tithi_value"].as_f64().unwrap_or(0.0);
-    
-    // Calculate ghati from time of day (simplified - using hour of day)
-    let hour_of_day = now.hour() as f64 + (now.minute() as f64 / 60.0);
-    let ghati_value = (hour_of_day / 24.0) * 60.0;
-    
-    let ghati = ghati_value.floor() as u8;
-    let pala = ((ghati_value.fract() * 60.0).floor()) as u8;
-    let vipala = ((ghati_value.fract() * 60.0).fract() * 60.0).floor() as u8;
-
-    Ok(Json(LegacyGhatiResponse {
-        ghati,
-        pala,
-        vipala,
-        utc_timestamp: now.to_rfc3339(),
-    }))
+    let (latitude, longitude) = ((latitude * 1e4).round() / 1e4, (longitude * 1e4).round() / 1e4);
+    let topic = format!("ghati:{:.4},{:.4}", latitude, longitude);
+    let subscription = state
+        .fanout
+        .subscribe(&topic, None, move |publisher| ghati_ticker(publisher, latitude, longitude))
+        .map_err(fanout_error_to_response)?;
+
+    Ok(Sse::new(subscription.map(|event| Ok(event.to_sse()))).keep_alive(KeepAlive::default()))
+}
+
+/// Publish the ghati time at a location on each new pala until nobody listens
+async fn ghati_ticker(publisher: fanout::Publisher, latitude: f64, longitude: f64) {
+    loop {
+        let now = chrono::Utc::now();
+        let (event, next_at) = match engine_panchanga::compute_ghati(now, latitude, longitude) {
+            Ok(ghati) => {
+                let name = if ghati.pala == 0 { "ghati" } else { "pala" };
+                (fanout::StreamEvent::json(name, &LegacyGhatiResponse::new(&ghati, now)), ghati.next_pala_at())
+            }
+            Err(e) => (
+                fanout::StreamEvent::new("error", e.to_string()),
+                now + chrono::Duration::seconds(GHATI_STREAM_RETRY_SECS),
+            ),
+        };
+        if !publisher.publish(event) {
+            return;
+        }
+        let wait = (next_at - chrono::Utc::now()).to_std().unwrap_or_default();
+        tokio::time::sleep(wait).await;
+    }
+}
+
+/// 429 for a stream refused over the per-user subscription limit
+fn fanout_error_to_response(error: fanout::FanoutError) -> (StatusCode, Json<ErrorResponse>) {
+    let fanout::FanoutError::TooManySubscriptions { limit } = error;
+    (
+        StatusCode::TOO_MANY_REQUESTS,
+        Json(ErrorResponse {
+            error: error.to_string(),
+            error_code: "STREAM_LIMIT_EXCEEDED".to_string(),
+            details: Some(serde_json::json!({ "limit": limit })),
+            request_id: None,
+        }),
+    )
 }
 
 // ---------------------------------------------------------------------------
 // Application state builder
 // ---------------------------------------------------------------------------
 
-/// Build the default `AppState` with all engines registered.
+fn analytics_settings(config: &ApiConfig) -> analytics::AnalyticsSettings {
+    analytics::AnalyticsSettings {
+        min_group_size: config.analytics_min_group_size,
+        cache_ttl: Duration::from_secs(config.analytics_cache_ttl_secs),
+    }
+}
+
+/// Build the default `AppState` around an [`AppCore`] with all engines registered.
 ///
 /// # Arguments
 /// * `config` - API configuration with JWT secret, Redis URL, cache settings, etc.
 ///
 /// # Returns
-/// Configured `AppState` with orchestrator, cache, auth, and metrics
+/// Configured `AppState` with the core, repositories and HTTP-side services
 pub async fn build_app_state(config: &ApiConfig) -> AppState {
-    // -- Orchestrator with engines --
-    let mut orchestrator = WorkflowOrchestrator::new();
-    orchestrator.register_engine(Arc::new(engine_panchanga::PanchangaEngine::new()));
-    orchestrator.register_engine(Arc::new(engine_numerology::NumerologyEngine::new()));
-    orchestrator.register_engine(Arc::new(engine_biorhythm::BiorhythmEngine::new()));
-    
-    // Register HD engine (Phase 1)
-    let hd_engine = Arc::new(engine_human_design::HumanDesignEngine::new());
-    orchestrator.register_engine(hd_engine.clone());
-    
-    // Register Gene Keys engine with HD dependency (Phase 2)
-    let gk_engine = Arc::new(engine_gene_keys::GeneKeysEngine::with_hd_engine(hd_engine.clone()));
-    orchestrator.register_engine(gk_engine);
-
-    // Register Vimshottari Dasha engine with HD dependency (Phase 2)
-    let vim_engine = Arc::new(engine_vimshottari::VimshottariEngine::with_hd_engine(hd_engine));
-    orchestrator.register_engine(vim_engine);
-
-    // Register Biofield engine (Phase 1 - somatic awareness) - returns mock data
-    orchestrator.register_engine(Arc::new(engine_biofield::BiofieldEngine::new()));
-
-    // Register VedicClock-TCM engine (Phase 0 - available to all)
-    orchestrator.register_engine(Arc::new(engine_vedic_clock::VedicClockEngine::new()));
-
-    // -- Cache --
-    let redis_url = config.redis_url.clone().unwrap_or_else(|| String::new());
-    let cache = CacheManager::new(
-        redis_url,               // Redis URL from config
-        100,                     // L1: 100 MB
-        Duration::from_secs(3600), // L2 TTL: 1 hour
-        false,                   // L3 disabled
-    );
+    build_app_state_with(config, |core| core).await
+}
 
-    // -- Database --
-    let pool = PgPoolOptions::new()
-        .max_connections(5)
-        .connect(&config.database_url)
-        .await
-        .expect("Failed to create database pool");
+/// [`build_app_state`], passing the core builder through `configure` first,
+/// e.g. to register bridge engines
+pub async fn build_app_state_with(
+    config: &ApiConfig,
+    configure: impl FnOnce(AppCoreBuilder) -> AppCoreBuilder,
+) -> AppState {
+    // -- Database (retried at boot; degraded mode if unreachable) --
+    let (db_pools, database) = database::connect(config).await;
+    let pool = db_pools.primary.clone();
+
+    // -- Orchestrator, cache and Postgres-backed API key validation --
+    let core = configure(
+        AppCore::builder()
+            .redis_url(config.redis_url.clone())
+            .l1_shares(config.l1_cache_shares.clone())
+            .redaction(config.redaction.clone())
+            .precomputed_natal_cache(true) // L3: precomputed natal results (L3_CACHE_DIR)
+            .critical_engines(config.critical_engines.clone())
+            .auth(AuthService::with_pool(config.jwt_secret.clone(), Some(pool.clone()))),
+    )
+    .build();
+    let (precompute, _) =
+        precompute::spawn_precompute_worker(core.orchestrator.clone(), precompute::PRECOMPUTE_QUEUE_CAPACITY);
 
-    // -- Auth (Postgres-backed API key validation) --
-    let auth = AuthService::with_pool(config.jwt_secret.clone(), Some(pool.clone()));
+    // -- Vedic API client, its responses persisted in the shared cache --
+    let vedic_api = vedic_api_client(&core).await;
 
-    let user_repository = Arc::new(UserRepository::new(pool));
+    // Build the wisdom search index now rather than on the first search
+    tracing::info!(entries = wisdom::index().len(), "Wisdom search index built");
 
-    // -- Metrics --
-    let metrics = NoesisMetrics::new().expect("Failed to initialise NoesisMetrics");
+    // Saved results, client lists, saved locations, practice history, settings and
+    // notifications may be read from the replica; auth, accounts, consent checks and share links always read the primary
+    let user_repository = Arc::new(UserRepository::new(pool.clone()));
+    let result_repository = Arc::new(
+        WorkflowResultRepository::new(pool.clone()).with_read_pool(db_pools.read().clone()),
+    );
+    let organization_repository = Arc::new(OrganizationRepository::new(pool.clone()));
+    let client_repository =
+        Arc::new(ClientRepository::new(pool.clone()).with_read_pool(db_pools.read().clone()));
+    let location_repository =
+        Arc::new(SavedLocationRepository::new(pool.clone()).with_read_pool(db_pools.read().clone()));
+    let api_key_repository = Arc::new(ApiKeyRepository::new(pool.clone()));
+    let practice_repository =
+        Arc::new(PracticeRepository::new(pool.clone()).with_read_pool(db_pools.read().clone()));
+    let notification_repository =
+        Arc::new(NotificationRepository::new(pool.clone()).with_read_pool(db_pools.read().clone()));
+    let settings = Arc::new(settings::SettingsStore::new(
+        SettingsRepository::new(pool).with_read_pool(db_pools.read().clone()),
+    ));
+    let share_links = Arc::new(sharing::ShareLinks::new(
+        &config.jwt_secret,
+        config.share_link_ttl_secs,
+        config.public_base_url.clone(),
+    ));
 
     AppState {
-        orchestrator: Arc::new(orchestrator),
-        cache: Arc::new(cache),
-        auth: Arc::new(auth),
-        metrics: Arc::new(metrics),
+        core,
         user_repository,
+        result_repository,
+        organization_repository,
+        client_repository,
+        location_repository,
+        api_key_repository,
+        practice_repository,
+        notification_repository,
+        settings,
+        share_links,
+        geocoder: Arc::new(geo::EmbeddedGeocoder::new()),
+        precompute,
+        recalculations: Arc::new(recalculate::RecalculationJobs::new()),
+        research_exports: Arc::new(research_export::ResearchExports::from_env()),
+        fanout: fanout::FanoutHub::new(),
+        database,
+        db_pools,
+        open_debug: config.open_debug,
+        analytics: analytics_settings(config),
+        status_board: Arc::new(public_status::StatusBoard::with_vedic_api(vedic_api)),
         startup_time: Instant::now(),
     }
 }
 
+/// The Vedic API client from `FREE_ASTROLOGY_API_KEY`, with the responses
+/// earlier runs persisted through the core's cache reloaded; `None` when unset
+async fn vedic_api_client(core: &AppCore) -> Option<Arc<noesis_vedic_api::CachedVedicClient>> {
+    let metrics = Arc::new(noesis_vedic_api::metrics::NoesisMetrics::new());
+    match noesis_vedic_api::VedicApiService::from_env_with_cache(core.cache.clone(), metrics).await {
+        Ok((service, report)) => {
+            tracing::info!(%report, "Vedic API cache reloaded");
+            Some(Arc::new(service.client().clone()))
+        }
+        Err(error) => {
+            tracing::info!(%error, "Vedic API client not configured");
+            None
+        }
+    }
+}
+
 /// Build `AppState` but create the PostgreSQL pool lazily (no network connection during init).
 ///
 /// This is primarily intended for integration/E2E tests that don't exercise DB-backed
 /// endpoints but still need a fully constructed `AppState`.
 pub async fn build_app_state_lazy_db(config: &ApiConfig) -> AppState {
-    // -- Orchestrator with engines --
-    let mut orchestrator = WorkflowOrchestrator::new();
-    orchestrator.register_engine(Arc::new(engine_panchanga::PanchangaEngine::new()));
-    orchestrator.register_engine(Arc::new(engine_numerology::NumerologyEngine::new()));
-    orchestrator.register_engine(Arc::new(engine_biorhythm::BiorhythmEngine::new()));
-
-    // Register HD engine (Phase 1)
-    let hd_engine = Arc::new(engine_human_design::HumanDesignEngine::new());
-    orchestrator.register_engine(hd_engine.clone());
-
-    // Register Gene Keys engine with HD dependency (Phase 2)
-    let gk_engine = Arc::new(engine_gene_keys::GeneKeysEngine::with_hd_engine(hd_engine.clone()));
-    orchestrator.register_engine(gk_engine);
-
-    // Register Vimshottari Dasha engine with HD dependency (Phase 2)
-    let vim_engine = Arc::new(engine_vimshottari::VimshottariEngine::with_hd_engine(hd_engine));
-    orchestrator.register_engine(vim_engine);
-
-    // Register Biofield engine (Phase 1 - somatic awareness) - returns mock data
-    orchestrator.register_engine(Arc::new(engine_biofield::BiofieldEngine::new()));
-
-    // Register VedicClock-TCM engine (Phase 0 - available to all)
-    orchestrator.register_engine(Arc::new(engine_vedic_clock::VedicClockEngine::new()));
-
-    // -- Cache --
-    let redis_url = config.redis_url.clone().unwrap_or_else(|| String::new());
-    let cache = CacheManager::new(
-        redis_url,                 // Redis URL from config
-        100,                       // L1: 100 MB
-        Duration::from_secs(3600), // L2 TTL: 1 hour
-        false,                     // L3 disabled
-    );
-
     // -- Database (lazy pool) --
     let pool = PgPoolOptions::new()
         .max_connections(5)
         .connect_lazy(&config.database_url)
         .expect("Failed to create lazy database pool");
 
-    // -- Auth (lazy Postgres-backed API key validation) --
-    let auth = AuthService::with_pool(config.jwt_secret.clone(), Some(pool.clone()));
-
-    let user_repository = Arc::new(UserRepository::new(pool));
-
-    // -- Metrics --
-    let metrics = NoesisMetrics::new().expect("Failed to initialise NoesisMetrics");
+    // -- Orchestrator, cache (L3 disabled) and lazy Postgres-backed API key validation --
+    let core = AppCore::builder()
+        .redis_url(config.redis_url.clone())
+        .l1_shares(config.l1_cache_shares.clone())
+        .redaction(config.redaction.clone())
+        .critical_engines(config.critical_engines.clone())
+        .auth(AuthService::with_pool(config.jwt_secret.clone(), Some(pool.clone())))
+        .build();
+    let (precompute, _) =
+        precompute::spawn_precompute_worker(core.orchestrator.clone(), precompute::PRECOMPUTE_QUEUE_CAPACITY);
+
+    let user_repository = Arc::new(UserRepository::new(pool.clone()));
+    let result_repository = Arc::new(WorkflowResultRepository::new(pool.clone()));
+    let organization_repository = Arc::new(OrganizationRepository::new(pool.clone()));
+    let client_repository = Arc::new(ClientRepository::new(pool.clone()));
+    let location_repository = Arc::new(SavedLocationRepository::new(pool.clone()));
+    let api_key_repository = Arc::new(ApiKeyRepository::new(pool.clone()));
+    let practice_repository = Arc::new(PracticeRepository::new(pool.clone()));
+    let notification_repository = Arc::new(NotificationRepository::new(pool.clone()));
+    let settings = Arc::new(settings::SettingsStore::new(SettingsRepository::new(pool.clone())));
+    let share_links = Arc::new(sharing::ShareLinks::new(
+        &config.jwt_secret,
+        config.share_link_ttl_secs,
+        config.public_base_url.clone(),
+    ));
 
     AppState {
-        orchestrator: Arc::new(orchestrator),
-        cache: Arc::new(cache),
-        auth: Arc::new(auth),
-        metrics: Arc::new(metrics),
+        core,
         user_repository,
+        result_repository,
+        organization_repository,
+        client_repository,
+        location_repository,
+        api_key_repository,
+        practice_repository,
+        notification_repository,
+        settings,
+        share_links,
+        geocoder: Arc::new(geo::EmbeddedGeocoder::new()),
+        precompute,
+        recalculations: Arc::new(recalculate::RecalculationJobs::new()),
+        research_exports: Arc::new(research_export::ResearchExports::from_env()),
+        fanout: fanout::FanoutHub::new(),
+        database: Arc::new(database::DatabaseStatus::new(true)),
+        db_pools: DbPools::new(pool),
+        open_debug: config.open_debug,
+        analytics: analytics_settings(config),
+        status_board: Arc::new(public_status::StatusBoard::from_env()),
         startup_time: Instant::now(),
     }
 }
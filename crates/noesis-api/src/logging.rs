@@ -55,7 +55,7 @@ pub fn init_tracing(log_level: &str) {
 /// - Log level from parameter (allows override via env)
 /// - JSON formatter with structured fields
 /// - Includes span context (trace_id, span_id), timestamps, and all structured fields
-/// - Adds custom fields: user_id, engine_id, duration_ms (when present in span context)
+/// - Adds custom fields: user_id, request_id, engine_id, duration_ms (when present in span context)
 ///
 /// # Arguments
 /// * `log_level` - Log level filter string (e.g., "info,noesis_api=debug")
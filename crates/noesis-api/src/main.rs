@@ -2,12 +2,26 @@
 //!
 //! Entry point for the Noesis API server. Initializes tracing, builds the router,
 //! and starts the Axum HTTP server with environment-based configuration.
+//!
+//! With `--self-test` it instead checks every engine, the cache, the database
+//! and the bridge, prints a pass/fail table and exits nonzero on any failure
+//! (see [`noesis_api::self_test`]).
 
-use noesis_api::{build_app_state, create_router, init_tracing, init_tracing_json, ApiConfig};
+use noesis_api::notifications::{spawn_notification_job, NotificationJob};
+use noesis_api::purge::spawn_purge_job;
+use noesis_api::retention::spawn_retention_job;
+use noesis_api::self_test::run_self_test;
+use noesis_api::{build_app_state, create_metrics_router, create_router, init_tracing, init_tracing_json, ApiConfig};
+use noesis_data::repositories::retention_repository::RetentionRepository;
+use std::net::SocketAddr;
+use std::sync::Arc;
+use std::time::Duration;
 use tokio::net::TcpListener;
 
 #[tokio::main]
 async fn main() {
+    let self_test = std::env::args().skip(1).any(|arg| arg == "--self-test");
+
     // Load configuration from environment
     let config = ApiConfig::from_env();
     
@@ -38,6 +52,51 @@ async fn main() {
     let state = build_app_state(&config).await;
     tracing::info!("Application state initialized");
 
+    if self_test {
+        let report = run_self_test(&state).await;
+        println!("{}", report);
+        std::process::exit(report.exit_code());
+    }
+
+    // Remove soft-deleted accounts once their restore window has passed
+    spawn_purge_job(
+        state.user_repository.clone(),
+        Duration::from_secs(config.purge_interval_secs),
+    );
+
+    // Delete data classes past their configured retention period
+    spawn_retention_job(
+        Arc::new(RetentionRepository::new(state.db_pools.primary.clone())),
+        config.retention.clone(),
+        Duration::from_secs(config.retention_interval_secs),
+    );
+
+    // Send the calendar notifications users subscribed to
+    spawn_notification_job(
+        NotificationJob::new(
+            state.notification_repository.clone(),
+            state.user_repository.clone(),
+            state.core.orchestrator.clone(),
+        ),
+        Duration::from_secs(config.notification_interval_secs),
+    );
+
+    // Serve `/metrics` to Prometheus on its own port, off the public ingress
+    if let Some(metrics_addr) = config.metrics_bind_address() {
+        let metrics_app = create_metrics_router(state.clone(), &config);
+        let metrics_listener = TcpListener::bind(&metrics_addr).await.unwrap_or_else(|e| {
+            tracing::error!("Failed to bind metrics listener to {}: {}", metrics_addr, e);
+            std::process::exit(1);
+        });
+        tracing::info!("Metrics: http://{}/metrics", metrics_addr);
+        tokio::spawn(async move {
+            let service = metrics_app.into_make_service_with_connect_info::<SocketAddr>();
+            if let Err(e) = axum::serve(metrics_listener, service).await {
+                tracing::error!("Metrics listener failed: {}", e);
+            }
+        });
+    }
+
     // Create the Axum router with all routes and middleware
     let app = create_router(state, &config);
     tracing::info!("Router configured");
@@ -55,11 +114,14 @@ async fn main() {
 
     tracing::info!("Noesis API server listening on {}", addr);
     tracing::info!("Health check: http://{}/health", addr);
-    tracing::info!("Metrics: http://{}/metrics", addr);
+    if config.metrics_port.is_none() {
+        tracing::info!("Metrics: http://{}/metrics", addr);
+    }
     tracing::info!("API v1: http://{}/api/v1/status", addr);
 
     // Start the server
-    axum::serve(listener, app)
+    // Peer addresses back METRICS_ALLOWED_IPS
+    axum::serve(listener, app.into_make_service_with_connect_info::<SocketAddr>())
         .await
         .expect("Server error");
 }
@@ -1,20 +1,53 @@
 //! Middleware components for request logging, tracing, and response standardization
 
 use axum::{
-    extract::{Request, State},
-    http::{StatusCode, header::AUTHORIZATION},
+    body::HttpBody,
+    extract::{ConnectInfo, Request, State},
+    http::{HeaderMap, HeaderValue, Method, StatusCode, header::{AUTHORIZATION, CONTENT_TYPE, RETRY_AFTER, WWW_AUTHENTICATE}},
     middleware::Next,
     response::{Response, IntoResponse},
     Json,
 };
+use ipnet::IpNet;
+use sha2::{Digest, Sha256};
+use std::net::SocketAddr;
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
 use std::sync::Arc;
 use std::time::Instant;
 use tracing::{info, info_span, Instrument};
+use noesis_core::{correlation, rng, NameNormalization};
 use noesis_metrics::NoesisMetrics;
 use noesis_auth::{AuthService, AuthUser};
 use serde::Serialize;
 use dashmap::DashMap;
+use tokio::sync::{OwnedSemaphorePermit, Semaphore};
 use chrono::{DateTime, Utc, Duration};
+use uuid::Uuid;
+
+/// Request ID middleware that accepts or creates `X-Request-Id`.
+///
+/// A client-supplied ID is reused when it passes
+/// [`correlation::is_valid_request_id`]; otherwise a UUID v4 is generated.
+/// The rest of the stack runs inside [`correlation::scope`], so logging spans,
+/// error bodies and bridge calls all see the same ID, and it is echoed back
+/// in the response header.
+pub async fn request_id_middleware(req: Request, next: Next) -> Response {
+    let request_id = req
+        .headers()
+        .get(correlation::REQUEST_ID_HEADER)
+        .and_then(|v| v.to_str().ok())
+        .filter(|id| correlation::is_valid_request_id(id))
+        .map(str::to_string)
+        .unwrap_or_else(|| Uuid::new_v4().to_string());
+
+    let mut response = correlation::scope(request_id.clone(), next.run(req)).await;
+    if let Ok(value) = HeaderValue::from_str(&request_id) {
+        response
+            .headers_mut()
+            .insert(correlation::REQUEST_ID_HEADER, value);
+    }
+    response
+}
 
 /// Request logging middleware that captures timing and structured request metadata.
 ///
@@ -24,6 +57,7 @@ use chrono::{DateTime, Utc, Duration};
 /// - Response status code
 /// - Duration in milliseconds
 /// - User ID (if available from request extensions)
+/// - Request ID (set by [`request_id_middleware`])
 ///
 /// All logs are wrapped in a tracing span with trace_id and span_id automatically injected.
 pub async fn request_logging_middleware(req: Request, next: Next) -> Response {
@@ -40,11 +74,13 @@ pub async fn request_logging_middleware(req: Request, next: Next) -> Response {
         .map(|s| s.to_string());
 
     // Create a span for this request - this automatically generates trace_id and span_id
+    let request_id = correlation::current_request_id();
     let span = info_span!(
         "http_request",
         method = %method,
         path = %path,
-        user_id = user_id.as_deref().unwrap_or("anonymous")
+        user_id = user_id.as_deref().unwrap_or("anonymous"),
+        request_id = request_id.as_deref().unwrap_or("-")
     );
 
     // Execute the request within the span
@@ -136,7 +172,9 @@ pub struct ErrorResponse {
 /// - OR API key from `X-API-Key` header
 ///
 /// Validates using `AuthService::validate_jwt_token()` or `validate_api_key()`.
-/// Injects `AuthUser` into request extensions for handler access.
+/// Injects `AuthUser` into request extensions for handler access, and runs
+/// the rest of the request inside [`rng::scope`] so engine seeds differ per
+/// user.
 ///
 /// Returns 401 UNAUTHORIZED if authentication fails.
 pub async fn auth_middleware(
@@ -151,8 +189,9 @@ pub async fn auth_middleware(
                 match auth.validate_jwt_token(token).await {
                     Ok(user) => {
                         // Insert authenticated user into request extensions
+                        let user_id = user.user_id.clone();
                         req.extensions_mut().insert(user);
-                        return Ok(next.run(req).await);
+                        return Ok(rng::scope(user_id, next.run(req)).await);
                     }
                     Err(_) => {
                         return Err((
@@ -175,8 +214,9 @@ pub async fn auth_middleware(
             match auth.validate_api_key(api_key).await {
                 Ok(user) => {
                     // Insert authenticated user into request extensions
+                    let user_id = user.user_id.clone();
                     req.extensions_mut().insert(user);
-                    return Ok(next.run(req).await);
+                    return Ok(rng::scope(user_id, next.run(req)).await);
                 }
                 Err(_) => {
                     return Err((
@@ -265,6 +305,27 @@ impl RateLimiter {
             (false, 0, reset_timestamp)
         }
     }
+
+    /// Set the standard `RateLimit-*` headers and their `X-RateLimit-*` predecessors
+    fn insert_headers(
+        &self,
+        headers: &mut HeaderMap,
+        limit: u32,
+        remaining: u32,
+        reset_timestamp: i64,
+        reset_after: i64,
+    ) {
+        headers.insert("RateLimit-Limit", HeaderValue::from(limit));
+        headers.insert("RateLimit-Remaining", HeaderValue::from(remaining));
+        headers.insert("RateLimit-Reset", HeaderValue::from(reset_after));
+        headers.insert(
+            "RateLimit-Policy",
+            HeaderValue::from_str(&format!("{};w={}", limit, self.window_seconds)).unwrap(),
+        );
+        headers.insert("X-RateLimit-Limit", HeaderValue::from(limit));
+        headers.insert("X-RateLimit-Remaining", HeaderValue::from(remaining));
+        headers.insert("X-RateLimit-Reset", HeaderValue::from(reset_timestamp));
+    }
 }
 
 impl Default for RateLimiter {
@@ -285,10 +346,13 @@ impl Default for RateLimiter {
 /// - Returns 429 Too Many Requests when limit exceeded
 /// - Skips rate limiting if no AuthUser present (public routes)
 ///
-/// Response headers:
-/// - X-RateLimit-Limit: Maximum requests per minute
-/// - X-RateLimit-Remaining: Remaining requests in current window
+/// Response headers, with the limit of the user's tier:
+/// - RateLimit-Limit / X-RateLimit-Limit: Maximum requests per window
+/// - RateLimit-Remaining / X-RateLimit-Remaining: Remaining requests in current window
+/// - RateLimit-Reset: Seconds until the window resets
 /// - X-RateLimit-Reset: Unix timestamp when window resets
+/// - RateLimit-Policy: The limit and window, e.g. `100;w=60`
+/// - Retry-After: Seconds to wait before retrying (429 only)
 pub async fn rate_limit_middleware(
     State(limiter): State<Arc<RateLimiter>>,
     req: Request,
@@ -311,37 +375,744 @@ pub async fn rate_limit_middleware(
     
     // Check rate limit
     let (allowed, remaining, reset_timestamp) = limiter.check_and_update(&auth_user.user_id, rate_limit);
+    let reset_after = (reset_timestamp - Utc::now().timestamp()).max(0);
     
     if !allowed {
+        // Never ask for a retry in the past, even when the window is about to roll over
+        let retry_after = reset_after.max(1);
+
         // Rate limit exceeded - return 429 with headers
         let mut response = (
             StatusCode::TOO_MANY_REQUESTS,
             Json(ErrorResponse {
-                error: format!("Rate limit exceeded. Maximum {} requests per minute allowed.", rate_limit),
+                error: format!(
+                    "Rate limit exceeded. Maximum {} requests per minute allowed. Retry in {} seconds.",
+                    rate_limit, retry_after
+                ),
                 error_code: "RATE_LIMIT_EXCEEDED".to_string(),
                 details: Some(serde_json::json!({
                     "limit": rate_limit,
                     "window_seconds": limiter.window_seconds,
                     "reset_at": reset_timestamp,
+                    "retry_after": retry_after,
                 })),
             }),
         ).into_response();
         
-        // Add rate limit headers
         let headers = response.headers_mut();
-        headers.insert("X-RateLimit-Limit", rate_limit.to_string().parse().unwrap());
-        headers.insert("X-RateLimit-Remaining", "0".parse().unwrap());
-        headers.insert("X-RateLimit-Reset", reset_timestamp.to_string().parse().unwrap());
+        limiter.insert_headers(headers, rate_limit, 0, reset_timestamp, reset_after);
+        headers.insert(RETRY_AFTER, HeaderValue::from(retry_after));
         
         return Ok(response);
     }
     
     // Request allowed - process and add rate limit headers to response
     let mut response = next.run(req).await;
-    let headers = response.headers_mut();
-    headers.insert("X-RateLimit-Limit", rate_limit.to_string().parse().unwrap());
-    headers.insert("X-RateLimit-Remaining", remaining.to_string().parse().unwrap());
-    headers.insert("X-RateLimit-Reset", reset_timestamp.to_string().parse().unwrap());
+    limiter.insert_headers(response.headers_mut(), rate_limit, remaining, reset_timestamp, reset_after);
     
     Ok(response)
 }
+
+// ---------------------------------------------------------------------------
+// Load shedding middleware
+// ---------------------------------------------------------------------------
+
+/// Order in which calculation traffic is shed, lowest first
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Priority {
+    /// Free tier and unrecognised tiers
+    Low,
+    /// Premium tier
+    Normal,
+    /// Enterprise tier, never shed
+    Critical,
+}
+
+impl Priority {
+    pub fn for_tier(tier: &str) -> Self {
+        match tier {
+            "enterprise" => Priority::Critical,
+            "premium" => Priority::Normal,
+            _ => Priority::Low,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Priority::Low => "low",
+            Priority::Normal => "normal",
+            Priority::Critical => "critical",
+        }
+    }
+
+    /// Load pressure from which this class is turned away
+    fn shed_at(&self) -> Option<f64> {
+        match self {
+            Priority::Low => Some(1.0),
+            Priority::Normal => Some(2.0),
+            Priority::Critical => None,
+        }
+    }
+}
+
+/// Overload signals shared by every request: requests in flight and the
+/// scheduling lag of the async runtime
+///
+/// Pressure is the larger of `in_flight / max_in_flight` and
+/// `lag / max_lag`; a limit of zero turns that signal off.
+pub struct LoadShedder {
+    max_in_flight: usize,
+    max_lag: std::time::Duration,
+    in_flight: AtomicUsize,
+    lag_micros: AtomicU64,
+}
+
+impl LoadShedder {
+    /// Seconds a shed client is asked to wait
+    pub const RETRY_AFTER_SECS: u64 = 5;
+
+    /// How often the lag probe runs
+    const LAG_PROBE_INTERVAL: std::time::Duration = std::time::Duration::from_millis(250);
+
+    pub fn new(max_in_flight: usize, max_lag: std::time::Duration) -> Self {
+        Self {
+            max_in_flight,
+            max_lag,
+            in_flight: AtomicUsize::new(0),
+            lag_micros: AtomicU64::new(0),
+        }
+    }
+
+    pub fn in_flight(&self) -> usize {
+        self.in_flight.load(Ordering::Relaxed)
+    }
+
+    pub fn lag(&self) -> std::time::Duration {
+        std::time::Duration::from_micros(self.lag_micros.load(Ordering::Relaxed))
+    }
+
+    pub fn pressure(&self) -> f64 {
+        let in_flight = if self.max_in_flight == 0 {
+            0.0
+        } else {
+            self.in_flight() as f64 / self.max_in_flight as f64
+        };
+        let lag = if self.max_lag.is_zero() {
+            0.0
+        } else {
+            self.lag().as_secs_f64() / self.max_lag.as_secs_f64()
+        };
+        in_flight.max(lag)
+    }
+
+    /// Whether a calculation of `priority` is turned away at the current pressure
+    pub fn should_shed(&self, priority: Priority) -> bool {
+        priority.shed_at().is_some_and(|limit| self.pressure() >= limit)
+    }
+
+    /// Count a request as in flight until the guard is dropped
+    fn track(self: &Arc<Self>) -> InFlightGuard {
+        self.in_flight.fetch_add(1, Ordering::Relaxed);
+        InFlightGuard(self.clone())
+    }
+
+    fn record_lag(&self, lag: std::time::Duration) {
+        self.lag_micros.store(lag.as_micros() as u64, Ordering::Relaxed);
+    }
+
+    /// Probe runtime lag in the background for as long as the shedder lives.
+    /// Does nothing outside a Tokio runtime.
+    pub fn start_lag_probe(self: &Arc<Self>) {
+        let Ok(runtime) = tokio::runtime::Handle::try_current() else {
+            return;
+        };
+        let shedder = Arc::downgrade(self);
+        runtime.spawn(async move {
+            loop {
+                let started = Instant::now();
+                tokio::time::sleep(Self::LAG_PROBE_INTERVAL).await;
+                let Some(shedder) = shedder.upgrade() else {
+                    break;
+                };
+                let lag = started.elapsed().saturating_sub(Self::LAG_PROBE_INTERVAL);
+                shedder.record_lag(lag);
+                noesis_metrics::update_load_signals(shedder.in_flight(), lag.as_secs_f64());
+            }
+        });
+    }
+}
+
+struct InFlightGuard(Arc<LoadShedder>);
+
+impl Drop for InFlightGuard {
+    fn drop(&mut self) {
+        self.0.in_flight.fetch_sub(1, Ordering::Relaxed);
+    }
+}
+
+/// Engine calculations and workflow executions, the traffic worth shedding
+fn is_calculation(req: &Request) -> bool {
+    let path = req.uri().path();
+    req.method() == Method::POST
+        && (path.ends_with("/calculate") || path.ends_with("/execute") || path.ends_with("/ephemeris/positions"))
+}
+
+/// Load shedding middleware that turns away low-priority calculations first
+/// when the server is overloaded.
+///
+/// Behavior:
+/// - Counts every request passing through as in flight
+/// - For calculation requests (engine `calculate`, workflow `execute`,
+///   ephemeris positions), derives a [`Priority`] from the user's tier; free
+///   tier and unauthenticated calls are `Low`
+/// - Sheds `Low` once [`LoadShedder::pressure`] reaches 1 and `Normal` at 2;
+///   enterprise traffic is never shed
+/// - Shed requests get 503 SERVICE_OVERLOADED with `Retry-After`
+/// - Records each decision in `noesis_load_shed_decisions_total`
+pub async fn load_shedding_middleware(
+    State(shedder): State<Arc<LoadShedder>>,
+    req: Request,
+    next: Next,
+) -> Response {
+    if is_calculation(&req) {
+        let priority = req
+            .extensions()
+            .get::<AuthUser>()
+            .map_or(Priority::Low, |user| Priority::for_tier(&user.tier));
+        let shed = shedder.should_shed(priority);
+        noesis_metrics::record_load_shed_decision(priority.as_str(), shed);
+        if shed {
+            tracing::warn!(
+                priority = priority.as_str(),
+                in_flight = shedder.in_flight(),
+                lag_ms = shedder.lag().as_millis() as u64,
+                "Shedding calculation request"
+            );
+            let mut response = (
+                StatusCode::SERVICE_UNAVAILABLE,
+                Json(ErrorResponse {
+                    error: format!(
+                        "Server is overloaded. Retry in {} seconds.",
+                        LoadShedder::RETRY_AFTER_SECS
+                    ),
+                    error_code: "SERVICE_OVERLOADED".to_string(),
+                    details: Some(serde_json::json!({
+                        "priority": priority.as_str(),
+                        "retry_after": LoadShedder::RETRY_AFTER_SECS,
+                    })),
+                }),
+            )
+                .into_response();
+            response
+                .headers_mut()
+                .insert(RETRY_AFTER, HeaderValue::from(LoadShedder::RETRY_AFTER_SECS));
+            return response;
+        }
+    }
+
+    let _in_flight = shedder.track();
+    next.run(req).await
+}
+
+// ---------------------------------------------------------------------------
+// Concurrency limiting middleware
+// ---------------------------------------------------------------------------
+
+/// A user's calculation slots, sized by `max_concurrent_calculations` of their tier
+struct UserSlots {
+    semaphore: Arc<Semaphore>,
+    limit: u32,
+    last_used: Instant,
+}
+
+/// Per-user cap on calculations running at once, from
+/// [`TierLimits::max_concurrent_calculations`](noesis_auth::TierLimits)
+///
+/// Each user gets a semaphore the first time they calculate. Semaphores with
+/// no permit held and no use for [`IDLE_TTL`](Self::IDLE_TTL) are dropped by
+/// the cleanup task, so the map stays the size of the active user base.
+pub struct ConcurrencyLimiter {
+    users: DashMap<String, UserSlots>,
+}
+
+impl ConcurrencyLimiter {
+    /// Seconds a rejected client is asked to wait
+    pub const RETRY_AFTER_SECS: u64 = 1;
+
+    /// How long an idle user's semaphore is kept
+    pub const IDLE_TTL: std::time::Duration = std::time::Duration::from_secs(300);
+
+    /// How often idle semaphores are swept
+    const CLEANUP_INTERVAL: std::time::Duration = std::time::Duration::from_secs(60);
+
+    pub fn new() -> Self {
+        Self { users: DashMap::new() }
+    }
+
+    /// Take one of `user_id`'s `limit` slots, or `None` when all are in use.
+    /// The slot is released when the permit is dropped.
+    pub fn try_acquire(&self, user_id: &str, limit: u32) -> Option<OwnedSemaphorePermit> {
+        let semaphore = {
+            let mut slots = self.users.entry(user_id.to_string()).or_insert_with(|| UserSlots {
+                semaphore: Arc::new(Semaphore::new(limit as usize)),
+                limit,
+                last_used: Instant::now(),
+            });
+            // A tier change takes effect for new requests; running ones keep
+            // their permits on the old semaphore
+            if slots.limit != limit {
+                slots.semaphore = Arc::new(Semaphore::new(limit as usize));
+                slots.limit = limit;
+            }
+            slots.last_used = Instant::now();
+            slots.semaphore.clone()
+        };
+        semaphore.try_acquire_owned().ok()
+    }
+
+    /// Drop the semaphores of users with nothing running and no use for `ttl`
+    pub fn remove_idle(&self, ttl: std::time::Duration) {
+        self.users.retain(|_, slots| {
+            slots.last_used.elapsed() < ttl
+                || slots.semaphore.available_permits() < slots.limit as usize
+        });
+    }
+
+    /// Sweep idle semaphores in the background for as long as the limiter
+    /// lives. Does nothing outside a Tokio runtime.
+    pub fn start_cleanup(self: &Arc<Self>) {
+        let Ok(runtime) = tokio::runtime::Handle::try_current() else {
+            return;
+        };
+        let limiter = Arc::downgrade(self);
+        runtime.spawn(async move {
+            loop {
+                tokio::time::sleep(Self::CLEANUP_INTERVAL).await;
+                let Some(limiter) = limiter.upgrade() else {
+                    break;
+                };
+                limiter.remove_idle(Self::IDLE_TTL);
+            }
+        });
+    }
+}
+
+impl Default for ConcurrencyLimiter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Concurrency limiting middleware that caps how many calculations each user
+/// has running at once.
+///
+/// Behavior:
+/// - Applies to the same calculation requests as [`load_shedding_middleware`]
+///   (engine `calculate`, workflow `execute`, ephemeris positions)
+/// - Limits each user to `max_concurrent_calculations` of their tier: 1 for
+///   free, 10 for premium, 100 for enterprise
+/// - Holds the slot until the response is produced
+/// - Returns 429 CONCURRENCY_LIMIT_EXCEEDED with `Retry-After` when every
+///   slot is taken, counted in `noesis_concurrency_rejections_total`
+/// - Skips requests without an AuthUser (public routes)
+pub async fn concurrency_limit_middleware(
+    State(limiter): State<Arc<ConcurrencyLimiter>>,
+    req: Request,
+    next: Next,
+) -> Result<Response, (StatusCode, Json<ErrorResponse>)> {
+    if !is_calculation(&req) {
+        return Ok(next.run(req).await);
+    }
+    let Some(auth_user) = req.extensions().get::<AuthUser>().cloned() else {
+        return Ok(next.run(req).await);
+    };
+
+    let limit = AuthService::get_tier_limits(&auth_user.tier).max_concurrent_calculations;
+    let Some(_permit) = limiter.try_acquire(&auth_user.user_id, limit) else {
+        noesis_metrics::record_concurrency_rejection(&auth_user.tier);
+        tracing::warn!(
+            user_id = %auth_user.user_id,
+            tier = %auth_user.tier,
+            limit,
+            "Rejecting calculation over the tier's concurrency limit"
+        );
+        let mut response = (
+            StatusCode::TOO_MANY_REQUESTS,
+            Json(ErrorResponse {
+                error: format!(
+                    "Concurrency limit exceeded. The {} tier allows {} calculation(s) at a time.",
+                    auth_user.tier, limit
+                ),
+                error_code: "CONCURRENCY_LIMIT_EXCEEDED".to_string(),
+                details: Some(serde_json::json!({
+                    "tier": auth_user.tier,
+                    "limit": limit,
+                    "retry_after": ConcurrencyLimiter::RETRY_AFTER_SECS,
+                })),
+            }),
+        )
+            .into_response();
+        response
+            .headers_mut()
+            .insert(RETRY_AFTER, HeaderValue::from(ConcurrencyLimiter::RETRY_AFTER_SECS));
+        return Ok(response);
+    };
+
+    Ok(next.run(req).await)
+}
+
+// ---------------------------------------------------------------------------
+// Request body middleware
+// ---------------------------------------------------------------------------
+
+/// Limits applied to request bodies, from `MAX_BODY_BYTES` and `STRICT_JSON`,
+/// and the name normalization applied to inputs
+#[derive(Debug, Clone, Copy)]
+pub struct JsonBodyPolicy {
+    /// Largest accepted body in bytes
+    pub max_bytes: usize,
+    /// Reject unknown top-level fields in engine and workflow inputs
+    pub strict: bool,
+    /// Applied to every name of engine and workflow inputs
+    pub names: NameNormalization,
+}
+
+/// Request body middleware enforcing size and media type before any handler runs.
+///
+/// Behavior:
+/// - Returns 413 PAYLOAD_TOO_LARGE when the declared body size exceeds `max_bytes`
+/// - Returns 415 UNSUPPORTED_MEDIA_TYPE when a non-empty body is not
+///   `application/json` or a `+json` type
+/// - Inserts the [`JsonBodyPolicy`] into request extensions so
+///   [`InputJson`](crate::extract::InputJson) knows whether strict mode is on
+///   and how to normalize names
+///
+/// Bodies without a declared size (chunked uploads) are cut off at `max_bytes`
+/// by the `DefaultBodyLimit` layer installed next to this middleware.
+pub async fn json_body_middleware(
+    State(policy): State<JsonBodyPolicy>,
+    mut req: Request,
+    next: Next,
+) -> Result<Response, (StatusCode, Json<ErrorResponse>)> {
+    let size = req.body().size_hint();
+    if size.lower() > policy.max_bytes as u64 {
+        return Err((
+            StatusCode::PAYLOAD_TOO_LARGE,
+            Json(ErrorResponse {
+                error: format!("Request body exceeds the {} byte limit", policy.max_bytes),
+                error_code: "PAYLOAD_TOO_LARGE".to_string(),
+                details: Some(serde_json::json!({
+                    "max_bytes": policy.max_bytes,
+                    "received_bytes": size.lower(),
+                })),
+            }),
+        ));
+    }
+
+    let content_type = req
+        .headers()
+        .get(CONTENT_TYPE)
+        .and_then(|v| v.to_str().ok())
+        .unwrap_or("");
+    if size.upper() != Some(0) && !is_json_media_type(content_type) {
+        return Err((
+            StatusCode::UNSUPPORTED_MEDIA_TYPE,
+            Json(ErrorResponse {
+                error: "Request body must be sent as Content-Type: application/json".to_string(),
+                error_code: "UNSUPPORTED_MEDIA_TYPE".to_string(),
+                details: Some(serde_json::json!({ "content_type": content_type })),
+            }),
+        ));
+    }
+
+    req.extensions_mut().insert(policy);
+    Ok(next.run(req).await)
+}
+
+// ---------------------------------------------------------------------------
+// Metrics scrape protection
+// ---------------------------------------------------------------------------
+
+/// Who may scrape `/metrics`, from `METRICS_BEARER_TOKEN` and
+/// `METRICS_ALLOWED_IPS`; with neither set the endpoint is open
+#[derive(Debug, Clone, Default)]
+pub struct MetricsAccess {
+    /// Token scrapers must send as `Authorization: Bearer <token>`
+    pub bearer_token: Option<String>,
+    /// Networks scrapers must connect from; empty allows any address
+    pub allowed_ips: Vec<IpNet>,
+}
+
+impl MetricsAccess {
+    pub fn is_open(&self) -> bool {
+        self.bearer_token.is_none() && self.allowed_ips.is_empty()
+    }
+
+    /// Whether a scraper connecting from `peer` is allowed; an unknown peer
+    /// only when no allowlist is set
+    fn allows_peer(&self, peer: Option<SocketAddr>) -> bool {
+        if self.allowed_ips.is_empty() {
+            return true;
+        }
+        // An IPv4 scraper reaching a dual-stack listener shows up as ::ffff:a.b.c.d
+        peer.is_some_and(|peer| {
+            let ip = peer.ip().to_canonical();
+            self.allowed_ips.iter().any(|net| net.contains(&ip))
+        })
+    }
+
+    fn allows_token(&self, headers: &HeaderMap) -> bool {
+        let Some(expected) = &self.bearer_token else {
+            return true;
+        };
+        let presented = headers
+            .get(AUTHORIZATION)
+            .and_then(|v| v.to_str().ok())
+            .and_then(|v| v.strip_prefix("Bearer "));
+        // Digests compare in time independent of where the tokens differ
+        presented.is_some_and(|token| Sha256::digest(token) == Sha256::digest(expected))
+    }
+}
+
+/// Scrape protection for `/metrics`.
+///
+/// Behavior:
+/// - Returns 403 METRICS_FORBIDDEN when an allowlist is set and the peer
+///   address is outside it, or unknown because the server wasn't started
+///   with connect info
+/// - Returns 401 UNAUTHORIZED with `WWW-Authenticate: Bearer` when a token is
+///   set and the request doesn't carry it
+///
+/// Behind a proxy the peer is the proxy; serve metrics on `METRICS_PORT`
+/// instead of allowlisting the ingress.
+pub async fn metrics_access_middleware(
+    State(access): State<Arc<MetricsAccess>>,
+    req: Request,
+    next: Next,
+) -> Response {
+    let peer = req.extensions().get::<ConnectInfo<SocketAddr>>().map(|info| info.0);
+    if !access.allows_peer(peer) {
+        tracing::warn!(peer = ?peer, "Metrics scrape refused by METRICS_ALLOWED_IPS");
+        return (
+            StatusCode::FORBIDDEN,
+            Json(ErrorResponse {
+                error: "Metrics are not served to this address".to_string(),
+                error_code: "METRICS_FORBIDDEN".to_string(),
+                details: None,
+            }),
+        )
+            .into_response();
+    }
+    if !access.allows_token(req.headers()) {
+        return (
+            StatusCode::UNAUTHORIZED,
+            [(WWW_AUTHENTICATE, "Bearer realm=\"metrics\"")],
+            Json(ErrorResponse {
+                error: "Metrics require 'Authorization: Bearer <METRICS_BEARER_TOKEN>'".to_string(),
+                error_code: "UNAUTHORIZED".to_string(),
+                details: None,
+            }),
+        )
+            .into_response();
+    }
+    next.run(req).await
+}
+
+/// `application/json` or `application/<subtype>+json`, parameters ignored
+fn is_json_media_type(content_type: &str) -> bool {
+    let essence = content_type
+        .split(';')
+        .next()
+        .unwrap_or("")
+        .trim()
+        .to_ascii_lowercase();
+    essence == "application/json"
+        || essence
+            .strip_prefix("application/")
+            .is_some_and(|subtype| subtype.ends_with("+json"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_json_media_types() {
+        assert!(is_json_media_type("application/json"));
+        assert!(is_json_media_type("Application/JSON; charset=utf-8"));
+        assert!(is_json_media_type("application/merge-patch+json"));
+        assert!(!is_json_media_type("text/plain"));
+        assert!(!is_json_media_type("application/x-www-form-urlencoded"));
+        assert!(!is_json_media_type(""));
+    }
+
+    #[tokio::test]
+    async fn test_metrics_access() {
+        use axum::{body::Body, routing::get, Router};
+        use tower::ServiceExt;
+
+        let access = MetricsAccess {
+            bearer_token: Some("scrape-secret".to_string()),
+            allowed_ips: vec!["10.0.0.0/8".parse().unwrap()],
+        };
+        let router = Router::new()
+            .route("/metrics", get(|| async { "noesis_up 1" }))
+            .layer(axum::middleware::from_fn_with_state(Arc::new(access), metrics_access_middleware));
+        let scrape = |peer: Option<&str>, token: Option<&str>| {
+            let mut request = axum::http::Request::builder().uri("/metrics");
+            if let Some(token) = token {
+                request = request.header(AUTHORIZATION, format!("Bearer {}", token));
+            }
+            let mut request = request.body(Body::empty()).unwrap();
+            if let Some(peer) = peer {
+                request.extensions_mut().insert(ConnectInfo(peer.parse::<SocketAddr>().unwrap()));
+            }
+            router.clone().oneshot(request)
+        };
+
+        let allowed = scrape(Some("10.1.2.3:40000"), Some("scrape-secret")).await.unwrap();
+        assert_eq!(allowed.status(), StatusCode::OK);
+        let mapped = scrape(Some("[::ffff:10.1.2.3]:40000"), Some("scrape-secret")).await.unwrap();
+        assert_eq!(mapped.status(), StatusCode::OK);
+
+        let outside = scrape(Some("203.0.113.9:40000"), Some("scrape-secret")).await.unwrap();
+        assert_eq!(outside.status(), StatusCode::FORBIDDEN);
+        let unknown_peer = scrape(None, Some("scrape-secret")).await.unwrap();
+        assert_eq!(unknown_peer.status(), StatusCode::FORBIDDEN);
+
+        let wrong_token = scrape(Some("10.1.2.3:40000"), Some("guess")).await.unwrap();
+        assert_eq!(wrong_token.status(), StatusCode::UNAUTHORIZED);
+        assert_eq!(wrong_token.headers()[WWW_AUTHENTICATE], "Bearer realm=\"metrics\"");
+        let no_token = scrape(Some("10.1.2.3:40000"), None).await.unwrap();
+        assert_eq!(no_token.status(), StatusCode::UNAUTHORIZED);
+
+        assert!(MetricsAccess::default().is_open());
+        assert!(MetricsAccess::default().allows_peer(None));
+    }
+
+    #[test]
+    fn test_shedding_follows_priority() {
+        let shedder = Arc::new(LoadShedder::new(4, std::time::Duration::from_millis(100)));
+        assert!(!shedder.should_shed(Priority::Low));
+
+        let guards: Vec<_> = (0..4).map(|_| shedder.track()).collect();
+        assert!(shedder.should_shed(Priority::Low));
+        assert!(!shedder.should_shed(Priority::Normal));
+        drop(guards);
+        assert_eq!(shedder.in_flight(), 0);
+
+        shedder.record_lag(std::time::Duration::from_millis(250));
+        assert!(shedder.should_shed(Priority::Normal));
+        assert!(!shedder.should_shed(Priority::Critical));
+        assert_eq!(Priority::for_tier("free"), Priority::Low);
+        assert_eq!(Priority::for_tier("enterprise"), Priority::Critical);
+
+        // Zero limits turn both signals off
+        let disabled = LoadShedder::new(0, std::time::Duration::ZERO);
+        disabled.record_lag(std::time::Duration::from_secs(5));
+        assert_eq!(disabled.pressure(), 0.0);
+    }
+
+    #[tokio::test]
+    async fn test_shed_calculations_get_503() {
+        use axum::{body::Body, routing::{get, post}, Router};
+        use tower::ServiceExt;
+
+        let shedder = Arc::new(LoadShedder::new(1, std::time::Duration::ZERO));
+        let _busy = shedder.track();
+        let router = Router::new()
+            .route("/engines/numerology/calculate", post(|| async { "calculated" }))
+            .route("/engines", get(|| async { "listed" }))
+            .layer(axum::middleware::from_fn_with_state(shedder.clone(), load_shedding_middleware));
+
+        let request = |method: &str, uri: &str| {
+            axum::http::Request::builder().method(method).uri(uri).body(Body::empty()).unwrap()
+        };
+        let response = router.clone().oneshot(request("POST", "/engines/numerology/calculate")).await.unwrap();
+        assert_eq!(response.status(), StatusCode::SERVICE_UNAVAILABLE);
+        assert_eq!(response.headers()[RETRY_AFTER], "5");
+
+        let response = router.clone().oneshot(request("GET", "/engines")).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let mut enterprise = request("POST", "/engines/numerology/calculate");
+        enterprise.extensions_mut().insert(AuthUser {
+            user_id: "acme".into(),
+            tier: "enterprise".into(),
+            permissions: vec![],
+            rate_limit: 0,
+            consciousness_level: 0,
+            allowed_engines: None,
+            allowed_workflows: None,
+        });
+        let response = router.oneshot(enterprise).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(shedder.in_flight(), 1);
+    }
+
+    impl ConcurrencyLimiter {
+        fn in_flight(&self, user_id: &str) -> usize {
+            self.users.get(user_id).map_or(0, |slots| {
+                (slots.limit as usize).saturating_sub(slots.semaphore.available_permits())
+            })
+        }
+    }
+
+    #[test]
+    fn test_concurrency_slots_follow_tier_limit() {
+        let limiter = ConcurrencyLimiter::new();
+        let first = limiter.try_acquire("alice", 1).expect("free tier gets one slot");
+        assert!(limiter.try_acquire("alice", 1).is_none());
+        assert!(limiter.try_acquire("bob", 1).is_some(), "slots are per user");
+        assert_eq!(limiter.in_flight("alice"), 1);
+
+        // Users with a calculation running survive cleanup, idle ones don't
+        limiter.remove_idle(std::time::Duration::ZERO);
+        assert_eq!(limiter.users.len(), 1);
+        drop(first);
+        assert!(limiter.try_acquire("alice", 1).is_some());
+        limiter.remove_idle(std::time::Duration::ZERO);
+        assert_eq!(limiter.users.len(), 0);
+
+        // An upgrade takes effect on the next request
+        let _held: Vec<_> = (0..10).map(|_| limiter.try_acquire("carol", 10).unwrap()).collect();
+        assert!(limiter.try_acquire("carol", 10).is_none());
+    }
+
+    #[tokio::test]
+    async fn test_concurrent_calculations_over_limit_get_429() {
+        use axum::{body::Body, routing::post, Router};
+        use tower::ServiceExt;
+
+        let limiter = Arc::new(ConcurrencyLimiter::new());
+        let _running = limiter.try_acquire("script", 1).unwrap();
+        let router = Router::new()
+            .route("/engines/numerology/calculate", post(|| async { "calculated" }))
+            .route("/engines/numerology/validate", post(|| async { "validated" }))
+            .layer(axum::middleware::from_fn_with_state(limiter.clone(), concurrency_limit_middleware));
+
+        let request = |uri: &str, tier: &str| {
+            let mut request = axum::http::Request::builder().method("POST").uri(uri).body(Body::empty()).unwrap();
+            request.extensions_mut().insert(AuthUser {
+                user_id: "script".into(),
+                tier: tier.into(),
+                permissions: vec![],
+                rate_limit: 0,
+                consciousness_level: 0,
+                allowed_engines: None,
+                allowed_workflows: None,
+            });
+            request
+        };
+        let response = router.clone().oneshot(request("/engines/numerology/calculate", "free")).await.unwrap();
+        assert_eq!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+        assert_eq!(response.headers()[RETRY_AFTER], "1");
+
+        let response = router.clone().oneshot(request("/engines/numerology/validate", "free")).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let response = router.oneshot(request("/engines/numerology/calculate", "premium")).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(limiter.in_flight("script"), 0, "premium slots are released after the response");
+    }
+}
@@ -0,0 +1,548 @@
+//! Calendar notifications
+//!
+//! Users subscribe to categories of event with notification rules
+//! (`/api/v1/users/me/notification-rules`): Ekadashi, full moons, eclipses,
+//! changes of their own dasha periods and biorhythm triple critical days.
+//! Each rule names a lead time in days and a channel. The notification job
+//! runs every `NOTIFICATION_INTERVAL_SECS`, finds the events in each user's
+//! window from their birth profile, and sends what is due: in-app
+//! notifications are written to the user's inbox, webhook ones are posted
+//! as JSON and retried on later runs until the event date has passed. A
+//! rule fires once per event however often the job runs.
+//!
+//! Lunar events are read at the profile's place and in its timezone with
+//! the panchanga engine's formulas: Ekadashi is the day the 11th or 26th
+//! tithi holds at sunrise (or begins and ends between two sunrises), full
+//! moons the local day of the mean syzygy, and an eclipse a new or full
+//! moon close enough to a lunar node. Eclipses are not checked for
+//! visibility from the place.
+
+use std::collections::{BTreeMap, HashMap};
+use std::sync::Arc;
+use std::time::Duration;
+
+use chrono::{DateTime, NaiveDate, Utc};
+use engine_panchanga::{
+    calculate_julian_day, calculate_lunar_node, calculate_lunar_position, calculate_solar_position, local_sun_times,
+};
+use noesis_core::{BirthData, EngineError};
+use noesis_data::models::notification::{NewNotification, Notification, NotificationRule};
+use noesis_data::repositories::notification_repository::NotificationRepository;
+use noesis_data::repositories::user_repository::UserRepository;
+use noesis_orchestrator::WorkflowOrchestrator;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use tokio::task::JoinHandle;
+use tokio::time::MissedTickBehavior;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::precompute::{birth_data_from_profile, natal_input};
+
+/// Longest lead time a rule may ask for, in days
+pub const MAX_LEAD_DAYS: i16 = 30;
+
+/// Sun-node distance, in degrees, within which a new moon eclipses the Sun.
+/// Eclipses up to 18.5 degrees out are possible but not certain; mean
+/// positions are too coarse to tell them apart.
+const SOLAR_ECLIPSE_LIMIT: f64 = 15.4;
+
+/// Greatest Sun-node distance, in degrees, at which a full moon can be eclipsed
+const LUNAR_ECLIPSE_LIMIT: f64 = 12.2;
+
+/// How long a webhook receiver has to accept a notification
+const WEBHOOK_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Events a user can subscribe to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum NotificationCategory {
+    /// The 11th tithi of either paksha
+    Ekadashi,
+    FullMoon,
+    /// A solar or lunar eclipse
+    Eclipse,
+    /// The start of a new mahadasha or antardasha in the user's chart
+    DashaChange,
+    /// All three primary biorhythm cycles critical on one day
+    BiorhythmTripleCritical,
+}
+
+impl NotificationCategory {
+    pub const ALL: [NotificationCategory; 5] = [
+        NotificationCategory::Ekadashi,
+        NotificationCategory::FullMoon,
+        NotificationCategory::Eclipse,
+        NotificationCategory::DashaChange,
+        NotificationCategory::BiorhythmTripleCritical,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            NotificationCategory::Ekadashi => "ekadashi",
+            NotificationCategory::FullMoon => "full_moon",
+            NotificationCategory::Eclipse => "eclipse",
+            NotificationCategory::DashaChange => "dasha_change",
+            NotificationCategory::BiorhythmTripleCritical => "biorhythm_triple_critical",
+        }
+    }
+
+    pub fn parse(category: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|candidate| candidate.as_str() == category)
+    }
+
+    /// The engine whose calculation finds the events, and whose phase a
+    /// subscriber needs
+    pub fn engine_id(&self) -> &'static str {
+        match self {
+            NotificationCategory::Ekadashi | NotificationCategory::FullMoon | NotificationCategory::Eclipse => {
+                "panchanga"
+            }
+            NotificationCategory::DashaChange => "vimshottari",
+            NotificationCategory::BiorhythmTripleCritical => "biorhythm",
+        }
+    }
+}
+
+/// Where a rule's notifications are sent
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum NotificationChannel {
+    /// The user's inbox, `GET /api/v1/users/me/notifications`
+    InApp,
+    /// A JSON POST to the rule's `webhook_url`
+    Webhook,
+}
+
+impl NotificationChannel {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            NotificationChannel::InApp => "in_app",
+            NotificationChannel::Webhook => "webhook",
+        }
+    }
+
+    pub fn parse(channel: &str) -> Option<Self> {
+        [NotificationChannel::InApp, NotificationChannel::Webhook]
+            .into_iter()
+            .find(|candidate| candidate.as_str() == channel)
+    }
+}
+
+/// An event on a user's calendar
+#[derive(Debug, Clone, PartialEq)]
+pub struct CalendarEvent {
+    pub category: NotificationCategory,
+    /// Local date of the event
+    pub date: NaiveDate,
+    /// Names the event, so a rule fires for it once
+    pub key: String,
+    pub title: String,
+}
+
+impl CalendarEvent {
+    fn new(category: NotificationCategory, date: NaiveDate, detail: Option<&str>, title: impl Into<String>) -> Self {
+        let key = match detail {
+            Some(detail) => format!("{}:{}:{}", category.as_str(), date, detail),
+            None => format!("{}:{}", category.as_str(), date),
+        };
+        Self {
+            category,
+            date,
+            key,
+            title: title.into(),
+        }
+    }
+
+    /// Whether a rule sending `lead_days` ahead is due for the event on `today`
+    pub fn is_due(&self, lead_days: i16, today: NaiveDate) -> bool {
+        (self.date - chrono::Duration::days(i64::from(lead_days))) <= today && today <= self.date
+    }
+}
+
+/// A notification as listed in the inbox and posted to webhooks
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct NotificationResponse {
+    pub id: String,
+    pub rule_id: String,
+    #[schema(example = "ekadashi")]
+    pub category: String,
+    #[schema(example = "Krishna Ekadashi")]
+    pub title: String,
+    /// Local date of the event
+    #[schema(value_type = String, format = Date, example = "2026-10-17")]
+    pub event_date: NaiveDate,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<Notification> for NotificationResponse {
+    fn from(notification: Notification) -> Self {
+        Self {
+            id: notification.id.to_string(),
+            rule_id: notification.rule_id.to_string(),
+            category: notification.category,
+            title: notification.title,
+            event_date: notification.event_date,
+            created_at: notification.created_at,
+        }
+    }
+}
+
+/// Ekadashis, full moons and eclipses of the `days` local days from `from`
+/// at a place whose civil time is `tz_offset_hours` ahead of UTC
+pub fn lunar_events(
+    from: NaiveDate,
+    days: i64,
+    latitude: f64,
+    longitude: f64,
+    tz_offset_hours: f64,
+) -> Result<Vec<CalendarEvent>, EngineError> {
+    let julian_day = |date: NaiveDate, time: &str| calculate_julian_day(&date.format("%Y-%m-%d").to_string(), time, tz_offset_hours);
+    let elongation = |jd: f64| (calculate_lunar_position(jd) - calculate_solar_position(jd)).rem_euclid(360.0);
+    let tithi = |jd: f64| (elongation(jd) / 12.0).floor() as i64 % 30 + 1;
+
+    // Tithi at sunrise and elongation at midnight, from the day before
+    // `from` to the day after the last
+    let mut sunrise_tithi = Vec::new();
+    let mut midnight_jd = Vec::new();
+    for offset in -1..=days {
+        let date = from + chrono::Duration::days(offset);
+        let (sunrise, _) = local_sun_times(date, latitude, longitude, tz_offset_hours)?;
+        sunrise_tithi.push(tithi(julian_day(date, &sunrise.format("%H:%M").to_string())));
+        midnight_jd.push(julian_day(date, "00:00"));
+    }
+
+    let mut events = Vec::new();
+    for day in 0..days {
+        let date = from + chrono::Duration::days(day);
+        let i = day as usize + 1;
+        let (previous, current, next) = (sunrise_tithi[i - 1], sunrise_tithi[i], sunrise_tithi[i + 1]);
+        // The tithi at sunrise, unless it already held at yesterday's, and
+        // any that begins and ends before tomorrow's
+        let mut observed = Vec::new();
+        if current != previous {
+            observed.push(current);
+        }
+        if next != current {
+            let mut skipped = current % 30 + 1;
+            while skipped != next {
+                observed.push(skipped);
+                skipped = skipped % 30 + 1;
+            }
+        }
+        if observed.contains(&11) {
+            events.push(CalendarEvent::new(NotificationCategory::Ekadashi, date, None, "Shukla Ekadashi"));
+        }
+        if observed.contains(&26) {
+            events.push(CalendarEvent::new(NotificationCategory::Ekadashi, date, None, "Krishna Ekadashi"));
+        }
+
+        let (start, end) = (elongation(midnight_jd[i]), elongation(midnight_jd[i + 1]));
+        let full_moon = start < 180.0 && end >= 180.0;
+        let new_moon = end < start;
+        let sun = calculate_solar_position(midnight_jd[i]);
+        let from_node = (sun - calculate_lunar_node(midnight_jd[i])).rem_euclid(180.0);
+        let node_distance = from_node.min(180.0 - from_node);
+        if full_moon {
+            events.push(CalendarEvent::new(NotificationCategory::FullMoon, date, None, "Full moon (Purnima)"));
+            if node_distance < LUNAR_ECLIPSE_LIMIT {
+                events.push(CalendarEvent::new(NotificationCategory::Eclipse, date, Some("lunar"), "Lunar eclipse"));
+            }
+        }
+        if new_moon && node_distance < SOLAR_ECLIPSE_LIMIT {
+            events.push(CalendarEvent::new(NotificationCategory::Eclipse, date, Some("solar"), "Solar eclipse"));
+        }
+    }
+    Ok(events)
+}
+
+/// Biorhythm triple critical days of the `days` days from `from`
+pub fn biorhythm_events(birth_date: NaiveDate, from: NaiveDate, days: i64) -> Vec<CalendarEvent> {
+    (0..days)
+        .map(|day| from + chrono::Duration::days(day))
+        .filter(|date| engine_biorhythm::critical_cycles_on(birth_date, *date).len() == 3)
+        .map(|date| {
+            CalendarEvent::new(
+                NotificationCategory::BiorhythmTripleCritical,
+                date,
+                None,
+                "Biorhythm triple critical day",
+            )
+        })
+        .collect()
+}
+
+/// Mahadasha and antardasha changes in a vimshottari `result` falling on
+/// the `days` local days from `from`, dated in civil time `offset_seconds`
+/// ahead of UTC
+pub fn dasha_events(result: &Value, from: NaiveDate, days: i64, offset_seconds: i32) -> Vec<CalendarEvent> {
+    let until = from + chrono::Duration::days(days);
+    let transitions = result["upcoming_transitions"].as_array().map(Vec::as_slice).unwrap_or_default();
+    transitions
+        .iter()
+        .filter_map(|transition| {
+            let level = transition["type"].as_str()?;
+            if level != "Mahadasha" && level != "Antardasha" {
+                return None;
+            }
+            let at = DateTime::parse_from_rfc3339(transition["date"].as_str()?).ok()?;
+            let date = (at.with_timezone(&Utc) + chrono::Duration::seconds(i64::from(offset_seconds))).date_naive();
+            if date < from || date >= until {
+                return None;
+            }
+            let (from_planet, to_planet) = (transition["from_planet"].as_str()?, transition["to_planet"].as_str()?);
+            Some(CalendarEvent::new(
+                NotificationCategory::DashaChange,
+                date,
+                Some(&format!("{}:{}", level.to_lowercase(), to_planet.to_lowercase())),
+                format!("{} {} begins, following {}", to_planet, level, from_planet),
+            ))
+        })
+        .collect()
+}
+
+/// Evaluates notification rules and delivers what is due
+pub struct NotificationJob {
+    pub notifications: Arc<NotificationRepository>,
+    pub users: Arc<UserRepository>,
+    pub orchestrator: Arc<WorkflowOrchestrator>,
+    http: reqwest::Client,
+}
+
+impl NotificationJob {
+    pub fn new(
+        notifications: Arc<NotificationRepository>,
+        users: Arc<UserRepository>,
+        orchestrator: Arc<WorkflowOrchestrator>,
+    ) -> Self {
+        let http = reqwest::Client::builder()
+            .timeout(WEBHOOK_TIMEOUT)
+            .build()
+            .unwrap_or_default();
+        Self {
+            notifications,
+            users,
+            orchestrator,
+            http,
+        }
+    }
+
+    /// Evaluate every enabled rule once. Returns the notifications
+    /// delivered, retried webhooks included.
+    pub async fn run(&self) -> Result<usize, sqlx::Error> {
+        let rules = self.notifications.list_active_rules().await?;
+        let mut by_user: BTreeMap<Uuid, Vec<&NotificationRule>> = BTreeMap::new();
+        for rule in &rules {
+            by_user.entry(rule.user_id).or_default().push(rule);
+        }
+        let mut delivered = 0;
+
+        // Webhooks not accepted on an earlier run, while their event is ahead
+        let by_id: HashMap<Uuid, &NotificationRule> = rules.iter().map(|rule| (rule.id, rule)).collect();
+        let yesterday = Utc::now().date_naive() - chrono::Duration::days(1);
+        for pending in self.notifications.list_undelivered(yesterday).await? {
+            if let Some(rule) = by_id.get(&pending.rule_id) {
+                delivered += usize::from(self.deliver(rule, pending).await);
+            }
+        }
+
+        let user_ids: Vec<Uuid> = by_user.keys().copied().collect();
+        for profile in self.users.get_birth_profiles(&user_ids).await? {
+            let Some(birth_data) = birth_data_from_profile(&profile.profile, &profile.full_name) else {
+                continue;
+            };
+            let user_rules = &by_user[&profile.profile.user_id];
+            let offset_seconds = match birth_data.offset_at(Utc::now()) {
+                Ok(offset) => offset,
+                Err(e) => {
+                    tracing::warn!(user_id = %profile.profile.user_id, error = %e, "Skipping notification rules");
+                    continue;
+                }
+            };
+            let today = (Utc::now() + chrono::Duration::seconds(i64::from(offset_seconds))).date_naive();
+            let days = user_rules.iter().map(|rule| i64::from(rule.lead_days)).max().unwrap_or(0) + 1;
+            let categories: Vec<NotificationCategory> = user_rules
+                .iter()
+                .filter_map(|rule| NotificationCategory::parse(&rule.category))
+                .collect();
+            let events = self.events(&birth_data, &categories, today, days, offset_seconds).await;
+
+            for rule in user_rules {
+                let due = events
+                    .iter()
+                    .filter(|event| event.category.as_str() == rule.category && event.is_due(rule.lead_days, today));
+                for event in due {
+                    let in_app = rule.channel == NotificationChannel::InApp.as_str();
+                    let new = NewNotification {
+                        event_key: event.key.clone(),
+                        event_date: event.date,
+                        title: event.title.clone(),
+                    };
+                    if let Some(notification) = self.notifications.record(rule, &new, in_app).await? {
+                        delivered += usize::from(self.deliver(rule, notification).await);
+                    }
+                }
+            }
+        }
+        Ok(delivered)
+    }
+
+    /// The events of `categories` for the person of `birth_data`
+    async fn events(
+        &self,
+        birth_data: &BirthData,
+        categories: &[NotificationCategory],
+        today: NaiveDate,
+        days: i64,
+        offset_seconds: i32,
+    ) -> Vec<CalendarEvent> {
+        let mut events = Vec::new();
+        let lunar = [NotificationCategory::Ekadashi, NotificationCategory::FullMoon, NotificationCategory::Eclipse];
+        if categories.iter().any(|category| lunar.contains(category)) {
+            let tz_hours = f64::from(offset_seconds) / 3600.0;
+            match lunar_events(today, days, birth_data.latitude, birth_data.longitude, tz_hours) {
+                Ok(lunar) => events.extend(lunar),
+                Err(e) => tracing::warn!(error = %e, "Finding lunar events failed"),
+            }
+        }
+        if categories.contains(&NotificationCategory::BiorhythmTripleCritical) {
+            if let Ok(birth_date) = NaiveDate::parse_from_str(&birth_data.date, "%Y-%m-%d") {
+                events.extend(biorhythm_events(birth_date, today, days));
+            }
+        }
+        if categories.contains(&NotificationCategory::DashaChange) {
+            // Subscribing checked the user's phase; the job runs for them
+            let engine_id = NotificationCategory::DashaChange.engine_id();
+            match self.orchestrator.execute_engine(engine_id, natal_input(birth_data.clone()), u8::MAX).await {
+                Ok(output) => events.extend(dasha_events(&output.result, today, days, offset_seconds)),
+                Err(e) => tracing::warn!(error = %e, "Finding dasha changes failed"),
+            }
+        }
+        events
+    }
+
+    /// Send a recorded notification on its rule's channel; in-app ones are
+    /// delivered once recorded. Returns whether it was delivered.
+    async fn deliver(&self, rule: &NotificationRule, notification: Notification) -> bool {
+        let delivered = match (NotificationChannel::parse(&rule.channel), rule.webhook_url.as_deref()) {
+            (Some(NotificationChannel::InApp), _) => true,
+            (Some(NotificationChannel::Webhook), Some(url)) => {
+                let id = notification.id;
+                let body = NotificationResponse::from(notification);
+                match self.http.post(url).json(&body).send().await.and_then(|r| r.error_for_status()) {
+                    Ok(_) => match self.notifications.mark_delivered(id).await {
+                        Ok(()) => true,
+                        Err(e) => {
+                            tracing::warn!(notification_id = %id, error = %e, "Recording webhook delivery failed");
+                            true
+                        }
+                    },
+                    Err(e) => {
+                        tracing::warn!(rule_id = %rule.id, error = %e, "Notification webhook failed, retrying next run");
+                        false
+                    }
+                }
+            }
+            _ => false,
+        };
+        noesis_metrics::record_notification(&rule.category, &rule.channel, delivered);
+        delivered
+    }
+}
+
+/// Evaluate notification rules now and then every `interval`
+pub fn spawn_notification_job(job: NotificationJob, interval: Duration) -> JoinHandle<()> {
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(interval);
+        ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+        loop {
+            ticker.tick().await;
+            match job.run().await {
+                Ok(0) => {}
+                Ok(delivered) => tracing::info!(delivered, "Delivered notifications"),
+                Err(e) => tracing::warn!(error = %e, "Evaluating notification rules failed"),
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn date(s: &str) -> NaiveDate {
+        NaiveDate::parse_from_str(s, "%Y-%m-%d").unwrap()
+    }
+
+    #[test]
+    fn test_lunar_events_over_a_month() {
+        // Bengaluru, IST
+        let events = lunar_events(date("2026-10-01"), 30, 12.9716, 77.5946, 5.5).unwrap();
+        let count = |category| events.iter().filter(|e| e.category == category).count();
+        assert_eq!(count(NotificationCategory::Ekadashi), 2);
+        assert_eq!(count(NotificationCategory::FullMoon), 1);
+
+        let ekadashis: Vec<_> = events.iter().filter(|e| e.category == NotificationCategory::Ekadashi).collect();
+        let gap = (ekadashis[1].date - ekadashis[0].date).num_days();
+        assert!((13..=16).contains(&gap), "Ekadashis {} days apart", gap);
+        assert_ne!(ekadashis[0].title, ekadashis[1].title);
+        assert!(events.iter().all(|e| e.key.starts_with(e.category.as_str())));
+    }
+
+    #[test]
+    fn test_eclipses_of_a_year() {
+        // 2026 has solar eclipses on 17 February and 12 August and lunar
+        // ones on 3 March and 28 August; mean syzygies may fall a day off
+        let events = lunar_events(date("2026-01-01"), 365, 12.9716, 77.5946, 5.5).unwrap();
+        let eclipses: Vec<_> = events
+            .iter()
+            .filter(|e| e.category == NotificationCategory::Eclipse)
+            .map(|e| (e.date.format("%m").to_string(), e.key.rsplit(':').next().unwrap()))
+            .collect();
+        let expected = [("02", "solar"), ("03", "lunar"), ("08", "solar"), ("08", "lunar")];
+        assert_eq!(eclipses.iter().map(|(m, k)| (m.as_str(), *k)).collect::<Vec<_>>(), expected);
+    }
+
+    #[test]
+    fn test_biorhythm_triple_critical_on_birth_day() {
+        let events = biorhythm_events(date("2026-01-01"), date("2026-01-01"), 3);
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].key, "biorhythm_triple_critical:2026-01-01");
+    }
+
+    #[test]
+    fn test_dasha_events_in_window() {
+        let result = json!({ "upcoming_transitions": [
+            { "type": "Pratyantardasha", "from_planet": "Venus", "to_planet": "Sun", "date": "2026-10-20T00:00:00+00:00" },
+            { "type": "Antardasha", "from_planet": "Mercury", "to_planet": "Ketu", "date": "2026-10-25T20:00:00+00:00" },
+            { "type": "Mahadasha", "from_planet": "Moon", "to_planet": "Mars", "date": "2027-03-01T00:00:00+00:00" },
+        ]});
+        let events = dasha_events(&result, date("2026-10-15"), 31, 19800);
+        assert_eq!(events.len(), 1);
+        // 20:00 UTC is the next day in IST
+        assert_eq!(events[0].date, date("2026-10-26"));
+        assert_eq!(events[0].key, "dasha_change:2026-10-26:antardasha:ketu");
+        assert_eq!(events[0].title, "Ketu Antardasha begins, following Mercury");
+    }
+
+    #[test]
+    fn test_is_due_within_lead_time() {
+        let event = CalendarEvent::new(NotificationCategory::FullMoon, date("2026-10-26"), None, "Full moon (Purnima)");
+        assert!(!event.is_due(3, date("2026-10-22")));
+        assert!(event.is_due(3, date("2026-10-23")));
+        assert!(event.is_due(3, date("2026-10-26")));
+        assert!(!event.is_due(3, date("2026-10-27")));
+        assert!(event.is_due(0, date("2026-10-26")));
+    }
+
+    #[test]
+    fn test_category_names_round_trip() {
+        for category in NotificationCategory::ALL {
+            assert_eq!(NotificationCategory::parse(category.as_str()), Some(category));
+            assert_eq!(serde_json::to_value(category).unwrap(), category.as_str());
+        }
+        assert_eq!(NotificationChannel::parse("webhook"), Some(NotificationChannel::Webhook));
+        assert_eq!(NotificationChannel::parse("email"), None);
+    }
+}
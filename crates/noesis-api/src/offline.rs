@@ -0,0 +1,315 @@
+//! Offline bundles for mobile apps
+//!
+//! A mobile app has to keep working without a connection. `GET
+//! /api/v1/users/me/offline-bundle` packs what it needs for the coming weeks
+//! into one gzip-compressed JSON file: the natal results of
+//! [`PRECOMPUTED_ENGINES`], which never change, and a window of daily data —
+//! a biorhythm series, the panchanga at each local sunrise and each day's
+//! horas — plus the organ and dosha clock the vedic-clock windows repeat
+//! every day.
+//!
+//! Each result is an artifact under a path such as `natal/human-design` or
+//! `days/2024-01-15`. The manifest lists every path with the SHA-256 of the
+//! artifact's compact JSON, so the app can verify what it cached, and the
+//! dates the bundle covers, so it knows when to fetch the next one.
+
+use std::collections::BTreeMap;
+use std::io::Write;
+
+use chrono::{DateTime, Duration, NaiveDate, NaiveTime, TimeZone, Utc};
+use engine_vedic_clock::{day_horas, dosha_times, organ_clock, SunTimes};
+use flate2::write::GzEncoder;
+use flate2::Compression;
+use futures::future::join_all;
+use noesis_auth::AuthUser;
+use noesis_core::timezone::resolve_offset;
+use noesis_core::{EngineError, EngineInput, AS_OF_OPTION};
+use noesis_runtime::AppCore;
+use serde::Serialize;
+use serde_json::{json, Value};
+use sha2::{Digest, Sha256};
+use utoipa::ToSchema;
+
+use crate::precompute::PRECOMPUTED_ENGINES;
+
+/// Layout version of the bundle, bumped when paths or artifacts change shape
+pub const OFFLINE_FORMAT_VERSION: u32 = 1;
+
+/// Days of daily data in a bundle unless `days` says otherwise
+pub const DEFAULT_BUNDLE_DAYS: u32 = 30;
+
+/// Most days of daily data one bundle may hold
+pub const MAX_BUNDLE_DAYS: u32 = 90;
+
+/// Engines a bundle calculates: the natal ones, biorhythm and panchanga
+pub fn bundle_engines() -> impl Iterator<Item = &'static str> {
+    PRECOMPUTED_ENGINES.into_iter().chain(["biorhythm", "panchanga"])
+}
+
+/// An artifact in the bundle and how to verify it
+#[derive(Debug, Clone, PartialEq, Serialize, ToSchema)]
+pub struct BundleEntry {
+    #[schema(example = "natal/human-design")]
+    pub path: String,
+    /// Hex SHA-256 of the artifact serialized as compact JSON
+    pub sha256: String,
+    /// Length of that serialization
+    pub bytes: usize,
+}
+
+/// An artifact that could not be calculated, such as an engine above the
+/// user's phase
+#[derive(Debug, Clone, PartialEq, Serialize, ToSchema)]
+pub struct SkippedArtifact {
+    #[schema(example = "natal/gene-keys")]
+    pub path: String,
+    pub reason: String,
+}
+
+/// What a bundle holds and the dates it covers
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct BundleManifest {
+    pub format_version: u32,
+    pub generated_at: DateTime<Utc>,
+    /// First local date of the daily data
+    pub valid_from: NaiveDate,
+    /// Last local date of the daily data
+    pub valid_until: NaiveDate,
+    /// IANA timezone the dates and local times are in
+    #[schema(example = "Asia/Kolkata")]
+    pub timezone: String,
+    pub entries: Vec<BundleEntry>,
+    pub skipped: Vec<SkippedArtifact>,
+}
+
+/// Natal and daily results packed for offline use
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct OfflineBundle {
+    pub manifest: BundleManifest,
+    /// Artifacts by path: `natal/<engine_id>` engine outputs,
+    /// `temporal/biorhythm`, `temporal/vedic-clock` and one `days/<date>`
+    /// per day with its sunrise, sunset, panchanga and horas
+    #[schema(value_type = Object)]
+    pub artifacts: BTreeMap<String, Value>,
+}
+
+impl OfflineBundle {
+    /// Checksum `artifacts` into a manifest covering `days` from `valid_from`
+    pub fn new(
+        valid_from: NaiveDate,
+        days: u32,
+        timezone: String,
+        artifacts: BTreeMap<String, Value>,
+        skipped: Vec<SkippedArtifact>,
+    ) -> Self {
+        let entries = artifacts
+            .iter()
+            .map(|(path, artifact)| {
+                let compact = serde_json::to_vec(artifact).expect("artifact serializes");
+                BundleEntry {
+                    path: path.clone(),
+                    sha256: hex::encode(Sha256::digest(&compact)),
+                    bytes: compact.len(),
+                }
+            })
+            .collect();
+        let manifest = BundleManifest {
+            format_version: OFFLINE_FORMAT_VERSION,
+            generated_at: Utc::now(),
+            valid_from,
+            valid_until: valid_from + Duration::days(i64::from(days.max(1)) - 1),
+            timezone,
+            entries,
+            skipped,
+        };
+        Self { manifest, artifacts }
+    }
+
+    /// The bundle as gzip-compressed JSON
+    pub fn to_gzip(&self) -> Vec<u8> {
+        let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+        serde_json::to_writer(&mut encoder, self).expect("bundle serializes");
+        encoder.flush().expect("writing to memory cannot fail");
+        encoder.finish().expect("writing to memory cannot fail")
+    }
+}
+
+/// Calculate a bundle for `base`, the user's birth data with their saved
+/// settings and, when chosen, a saved location in `location`. Daily data
+/// covers `days` local dates from the date of `now` at that location, else
+/// the birth place. Engines that fail are listed in the manifest's `skipped`.
+pub async fn build_bundle(
+    core: &AppCore,
+    user: &AuthUser,
+    base: EngineInput,
+    now: DateTime<Utc>,
+    days: u32,
+) -> Result<OfflineBundle, EngineError> {
+    let birth = base
+        .primary_birth_data()
+        .cloned()
+        .ok_or_else(|| EngineError::ValidationError("birth_data required for an offline bundle".to_string()))?;
+    let (latitude, longitude, timezone, offset_override) = match &base.location {
+        Some(place) => (
+            place.latitude,
+            place.longitude,
+            place.timezone.clone().unwrap_or_else(|| birth.timezone.clone()),
+            None,
+        ),
+        None => (birth.latitude, birth.longitude, birth.timezone.clone(), birth.utc_offset_minutes),
+    };
+    let sun = SunTimes::Location { latitude, longitude };
+    let offset = resolve_offset(&timezone, offset_override, now.date_naive(), Some(now.time()))?.offset_minutes();
+    let start = (now + Duration::minutes(i64::from(offset))).date_naive();
+
+    let mut artifacts = BTreeMap::new();
+    let mut skipped = Vec::new();
+    let mut record = |path: String, result: Result<Value, EngineError>| match result {
+        Ok(artifact) => {
+            artifacts.insert(path, artifact);
+        }
+        Err(e) => {
+            tracing::warn!(path, error = %e, "Offline bundle artifact skipped");
+            skipped.push(SkippedArtifact { path, reason: e.to_string() });
+        }
+    };
+
+    let natal = join_all(PRECOMPUTED_ENGINES.iter().map(|engine_id| core.calculate_as(user, engine_id, base.clone())));
+    for (engine_id, output) in PRECOMPUTED_ENGINES.iter().zip(natal.await) {
+        record(format!("natal/{}", engine_id), output.map(|output| json!(output)));
+    }
+
+    let mut biorhythm = base.clone();
+    biorhythm.options.insert(AS_OF_OPTION.to_string(), json!(start.format("%Y-%m-%d").to_string()));
+    biorhythm.options.insert("forecast_days".to_string(), json!(days.saturating_sub(1)));
+    record(
+        "temporal/biorhythm".to_string(),
+        core.calculate_as(user, "biorhythm", biorhythm).await.map(|output| json!(output)),
+    );
+    record(
+        "temporal/vedic-clock".to_string(),
+        Ok(json!({"organ_clock": organ_clock(), "dosha_times": dosha_times()})),
+    );
+
+    let dates: Vec<NaiveDate> = (0..days).map(|day| start + Duration::days(i64::from(day))).collect();
+    let daily = join_all(dates.iter().map(|&date| day_artifact(core, user, &base, &timezone, offset_override, sun, date)));
+    for (date, artifact) in dates.iter().zip(daily.await) {
+        record(format!("days/{}", date), artifact);
+    }
+
+    Ok(OfflineBundle::new(start, days, timezone, artifacts, skipped))
+}
+
+/// Sunrise, sunset, panchanga at sunrise and horas of one local date
+async fn day_artifact(
+    core: &AppCore,
+    user: &AuthUser,
+    base: &EngineInput,
+    timezone: &str,
+    offset_override: Option<i32>,
+    sun: SunTimes,
+    date: NaiveDate,
+) -> Result<Value, EngineError> {
+    let offset = resolve_offset(timezone, offset_override, date, NaiveTime::from_hms_opt(12, 0, 0))?.offset_minutes();
+    let (sunrise, sunset) = sun.for_date(date, offset)?;
+    let sunrise_utc = Utc.from_utc_datetime(&(sunrise - Duration::minutes(i64::from(offset))));
+
+    // The day's tithi, nakshatra and yoga are those prevailing at sunrise
+    let mut panchanga = base.clone();
+    panchanga.options.insert(AS_OF_OPTION.to_string(), json!(sunrise_utc.to_rfc3339()));
+    let panchanga = core.calculate_as(user, "panchanga", panchanga).await?;
+
+    Ok(json!({
+        "date": date,
+        "sunrise": sunrise,
+        "sunset": sunset,
+        "panchanga": panchanga,
+        "horas": day_horas(date, offset, sun)?,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use flate2::read::GzDecoder;
+    use std::io::Read;
+
+    #[test]
+    fn test_manifest_checksums_every_artifact() {
+        let artifacts = BTreeMap::from([
+            ("natal/numerology".to_string(), json!({"result": {"life_path": 7}})),
+            ("days/2024-01-15".to_string(), json!({"date": "2024-01-15", "horas": []})),
+        ]);
+        let skipped = vec![SkippedArtifact { path: "natal/gene-keys".to_string(), reason: "phase".to_string() }];
+        let start = NaiveDate::from_ymd_opt(2024, 1, 15).unwrap();
+        let bundle = OfflineBundle::new(start, 30, "Asia/Kolkata".to_string(), artifacts, skipped);
+
+        assert_eq!(bundle.manifest.valid_until, NaiveDate::from_ymd_opt(2024, 2, 13).unwrap());
+        assert_eq!(bundle.manifest.entries.len(), 2);
+        for entry in &bundle.manifest.entries {
+            let compact = serde_json::to_vec(&bundle.artifacts[&entry.path]).unwrap();
+            assert_eq!(entry.sha256, hex::encode(Sha256::digest(&compact)));
+            assert_eq!(entry.bytes, compact.len());
+        }
+
+        let mut decoded = String::new();
+        GzDecoder::new(bundle.to_gzip().as_slice()).read_to_string(&mut decoded).unwrap();
+        let decoded: Value = serde_json::from_str(&decoded).unwrap();
+        assert_eq!(decoded["manifest"]["format_version"], OFFLINE_FORMAT_VERSION);
+        assert_eq!(decoded["manifest"]["skipped"][0]["path"], "natal/gene-keys");
+        assert_eq!(decoded["artifacts"]["natal/numerology"]["result"]["life_path"], 7);
+    }
+
+    #[tokio::test]
+    async fn test_bundle_lists_every_artifact_once() {
+        let core = AppCore::builder().build();
+        let user = AuthUser {
+            user_id: "offline-user".into(),
+            tier: "free".into(),
+            permissions: vec![],
+            rate_limit: 0,
+            consciousness_level: 0,
+            allowed_engines: None,
+            allowed_workflows: None,
+        };
+        let birth = noesis_core::BirthData {
+            name: Some("Offline User".to_string()),
+            display_name: None,
+            date: "1990-01-15".to_string(),
+            time: Some("14:30".to_string()),
+            time_unknown: false,
+            latitude: 12.9716,
+            longitude: 77.5946,
+            altitude: None,
+            timezone: "Asia/Kolkata".to_string(),
+            place: None,
+            utc_offset_minutes: None,
+        };
+        let now = Utc.with_ymd_and_hms(2024, 1, 14, 20, 0, 0).unwrap();
+
+        let bundle = build_bundle(&core, &user, crate::precompute::natal_input(birth), now, 2).await.unwrap();
+        let manifest = &bundle.manifest;
+        // 20:00 UTC is already the next day in Kolkata
+        assert_eq!(manifest.valid_from, NaiveDate::from_ymd_opt(2024, 1, 15).unwrap());
+        assert_eq!(manifest.valid_until, NaiveDate::from_ymd_opt(2024, 1, 16).unwrap());
+
+        let mut paths: Vec<&str> = manifest.entries.iter().map(|e| e.path.as_str()).collect();
+        paths.extend(manifest.skipped.iter().map(|s| s.path.as_str()));
+        paths.sort_unstable();
+        assert_eq!(
+            paths,
+            [
+                "days/2024-01-15",
+                "days/2024-01-16",
+                "natal/gene-keys",
+                "natal/human-design",
+                "natal/numerology",
+                "natal/vimshottari",
+                "temporal/biorhythm",
+                "temporal/vedic-clock",
+            ]
+        );
+        assert!(bundle.artifacts.contains_key("natal/numerology"));
+        assert!(manifest.skipped.iter().any(|s| s.path == "natal/gene-keys"));
+    }
+}
@@ -0,0 +1,82 @@
+//! OpenAPI schemas that have no runtime counterpart
+//!
+//! `EngineInput.options` is a map on the wire, which engines parse into the
+//! option structs of `noesis_core::options`. [`EngineOptions`] is the
+//! `oneOf` union of those structs, so generated SDKs get typed option
+//! payloads, and [`SpecAddon`] points `EngineInput.options` at it.
+//!
+//! The generated spec is committed at `docs/api/openapi.json`. Regenerate it
+//! with `cargo run -p noesis-api --bin export_openapi` after changing routes
+//! or schemas; `tests/openapi_spec_tests.rs` fails when it is stale.
+
+use noesis_core::options::{
+    BiofieldOptions, BiorhythmOptions, FaceReadingOptions, GeneKeysOptions, HumanDesignOptions, NumerologyOptions,
+    PanchangaOptions, VedicClockOptions, VimshottariOptions,
+};
+use noesis_core::EngineInput;
+use serde::{Deserialize, Serialize};
+use utoipa::openapi::{RefOr, Schema};
+use utoipa::{Modify, ToSchema};
+
+use crate::presets::PresetRequest;
+
+/// Path of the committed spec, relative to the workspace root
+pub const SPEC_PATH: &str = "docs/api/openapi.json";
+
+/// Engine-specific `EngineInput.options`
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(untagged)]
+pub enum EngineOptions {
+    Panchanga(PanchangaOptions),
+    Numerology(NumerologyOptions),
+    Biorhythm(BiorhythmOptions),
+    HumanDesign(HumanDesignOptions),
+    GeneKeys(GeneKeysOptions),
+    Vimshottari(VimshottariOptions),
+    VedicClock(VedicClockOptions),
+    FaceReading(FaceReadingOptions),
+    Biofield(BiofieldOptions),
+}
+
+/// Body of `POST /api/v1/workflows/{workflow_id}/execute`, told apart by
+/// `preset`; see `extract::WorkflowBody`
+#[derive(Debug, Clone, Deserialize, ToSchema)]
+#[serde(untagged)]
+pub enum WorkflowExecuteRequest {
+    Preset(PresetRequest),
+    Input(Box<EngineInput>),
+}
+
+/// Routes served by the same handler under a second path: (alias, path, operation id)
+const PATH_ALIASES: &[(&str, &str, &str)] = &[
+    ("/health/live", "/health", "liveness_probe"),
+    ("/health/ready", "/ready", "readiness_probe"),
+];
+
+/// Spec adjustments the derive macros cannot express
+pub struct SpecAddon;
+
+impl Modify for SpecAddon {
+    fn modify(&self, openapi: &mut utoipa::openapi::OpenApi) {
+        if let Some(RefOr::T(Schema::Object(input))) = openapi
+            .components
+            .as_mut()
+            .and_then(|c| c.schemas.get_mut("EngineInput"))
+        {
+            input.properties.insert(
+                "options".to_string(),
+                utoipa::openapi::Ref::from_schema_name("EngineOptions").into(),
+            );
+        }
+
+        for (alias, target, operation_id) in PATH_ALIASES {
+            if let Some(mut item) = openapi.paths.paths.get(*target).cloned() {
+                // Operation ids must stay unique for SDK generators
+                for operation in item.operations.values_mut() {
+                    operation.operation_id = Some(operation_id.to_string());
+                }
+                openapi.paths.paths.insert(alias.to_string(), item);
+            }
+        }
+    }
+}
@@ -0,0 +1,168 @@
+//! Background precomputation of natal results
+//!
+//! A user's first requests after signup are for results that depend only on
+//! the birth moment, and the Human Design and Gene Keys ephemeris runs take
+//! seconds. When `PATCH /api/v1/users/me` saves a complete birth profile, it
+//! queues the birth data here and a worker runs [`PRECOMPUTED_ENGINES`]
+//! through `WorkflowOrchestrator::precompute`. Natal results land in every
+//! cache layer, including the persistent L3, so the first interactive
+//! request is a cache hit.
+//!
+//! The queue is bounded: a save that finds it full is dropped and counted in
+//! `noesis_precompute_dropped_total`, and that user's first request simply
+//! calculates.
+
+use std::sync::Arc;
+
+use chrono::Utc;
+use noesis_core::{BirthData, EngineInput, Precision};
+use noesis_data::models::user::UserProfile;
+use noesis_orchestrator::WorkflowOrchestrator;
+use tokio::sync::mpsc;
+use tokio::task::JoinHandle;
+
+/// Engines run for every saved birth profile
+pub const PRECOMPUTED_ENGINES: [&str; 4] = ["human-design", "numerology", "gene-keys", "vimshottari"];
+
+/// Profile saves waiting for the worker before new ones are dropped
+pub const PRECOMPUTE_QUEUE_CAPACITY: usize = 256;
+
+/// Hands saved birth profiles to the precompute worker
+#[derive(Clone)]
+pub struct PrecomputeQueue {
+    sender: mpsc::Sender<BirthData>,
+}
+
+impl PrecomputeQueue {
+    /// Queue `birth_data` without waiting; false when it was dropped
+    pub fn enqueue(&self, birth_data: BirthData) -> bool {
+        match self.sender.try_send(birth_data) {
+            Ok(()) => true,
+            Err(e) => {
+                tracing::warn!(error = %e, "Precompute queue unavailable, skipping profile");
+                noesis_metrics::record_precompute_dropped();
+                false
+            }
+        }
+    }
+}
+
+/// Start the worker precomputing for queued profiles one at a time
+pub fn spawn_precompute_worker(
+    orchestrator: Arc<WorkflowOrchestrator>,
+    capacity: usize,
+) -> (PrecomputeQueue, JoinHandle<()>) {
+    let (sender, mut receiver) = mpsc::channel::<BirthData>(capacity);
+    let worker = tokio::spawn(async move {
+        while let Some(birth_data) = receiver.recv().await {
+            let registered = orchestrator.list_engines();
+            for engine_id in PRECOMPUTED_ENGINES.iter().filter(|id| registered.iter().any(|r| r == **id)) {
+                match orchestrator.precompute(engine_id, natal_input(birth_data.clone())).await {
+                    Ok(ttl) => {
+                        tracing::debug!(engine_id, ?ttl, "Precomputed natal result");
+                        noesis_metrics::record_precompute(engine_id, true);
+                    }
+                    Err(e) => {
+                        tracing::warn!(engine_id, error = %e, "Precomputing natal result failed");
+                        noesis_metrics::record_precompute(engine_id, false);
+                    }
+                }
+            }
+        }
+    });
+    (PrecomputeQueue { sender }, worker)
+}
+
+/// Birth data of a saved profile, once its date, coordinates and timezone
+/// are all set. The time is formatted the way clients send it (`HH:MM`) so
+/// the precomputed cache keys match their requests.
+pub fn birth_data_from_profile(profile: &UserProfile, full_name: &str) -> Option<BirthData> {
+    Some(BirthData {
+        name: Some(full_name.trim().to_string()).filter(|name| !name.is_empty()),
+        display_name: None,
+        date: profile.birth_date?.format("%Y-%m-%d").to_string(),
+        time: profile.birth_time.map(|time| time.format("%H:%M").to_string()),
+        time_unknown: profile.birth_time.is_none(),
+        latitude: profile.birth_location_lat?,
+        longitude: profile.birth_location_lng?,
+        altitude: None,
+        timezone: profile.timezone.clone()?,
+        place: None,
+        utc_offset_minutes: None,
+    })
+}
+
+pub(crate) fn natal_input(birth_data: BirthData) -> EngineInput {
+    EngineInput {
+        birth_data: Some(birth_data),
+        secondary_birth_data: None,
+        subjects: Vec::new(),
+        current_time: Utc::now(),
+        location: None,
+        precision: Precision::Standard,
+        options: Default::default(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{NaiveDate, NaiveTime};
+    use noesis_cache::{CacheKey, CacheManager};
+    use noesis_core::ConsciousnessEngine;
+    use std::time::Duration;
+
+    fn profile() -> UserProfile {
+        UserProfile {
+            user_id: uuid::Uuid::nil(),
+            birth_date: NaiveDate::from_ymd_opt(1990, 1, 15),
+            birth_time: NaiveTime::from_hms_opt(14, 30, 0),
+            birth_location_lat: Some(12.9716),
+            birth_location_lng: Some(77.5946),
+            birth_location_name: Some("Bengaluru".to_string()),
+            timezone: Some("Asia/Kolkata".to_string()),
+            preferences: serde_json::json!({}),
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn test_birth_data_from_profile() {
+        let birth = birth_data_from_profile(&profile(), "Ada Lovelace").unwrap();
+        assert_eq!(birth.name.as_deref(), Some("Ada Lovelace"));
+        assert_eq!(birth.date, "1990-01-15");
+        assert_eq!(birth.time.as_deref(), Some("14:30"));
+        assert_eq!(birth.timezone, "Asia/Kolkata");
+        assert!(birth.validate().is_ok());
+
+        assert_eq!(birth_data_from_profile(&profile(), "  ").unwrap().name, None);
+        let no_timezone = UserProfile { timezone: None, ..profile() };
+        assert!(birth_data_from_profile(&no_timezone, "Ada").is_none());
+        let no_location = UserProfile { birth_location_lat: None, ..profile() };
+        assert!(birth_data_from_profile(&no_location, "Ada").is_none());
+    }
+
+    #[tokio::test]
+    async fn test_worker_stores_natal_results() {
+        let cache = Arc::new(CacheManager::new(String::new(), 8, Duration::from_secs(60), false));
+        let numerology = Arc::new(engine_numerology::NumerologyEngine::new());
+        let mut orchestrator = WorkflowOrchestrator::new();
+        orchestrator.register_engine(numerology.clone());
+        orchestrator.set_result_cache(cache.clone());
+
+        let (queue, worker) = spawn_precompute_worker(Arc::new(orchestrator), 4);
+        let birth = birth_data_from_profile(&profile(), "Ada Lovelace").unwrap();
+        assert!(queue.enqueue(birth.clone()));
+        // Closing the queue lets the worker finish once it is drained
+        drop(queue);
+        worker.await.unwrap();
+
+        let key = CacheKey::new(format!(
+            "{}:v={}",
+            numerology.cache_key(&natal_input(birth)),
+            numerology.engine_version()
+        ));
+        assert!(cache.get(&key).await.unwrap().is_some());
+    }
+}
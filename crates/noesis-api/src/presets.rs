@@ -0,0 +1,214 @@
+//! Named input presets for workflow execution
+//!
+//! Instead of a full `EngineInput`, `POST /api/v1/workflows/:id/execute`
+//! accepts `{ "profile_id": "me", "preset": "today" }` and builds the input
+//! server-side:
+//!
+//! - birth data comes from the profile: the caller's own (`me` or their user
+//!   id) or an organization client's, which needs `clients:calculate` and the
+//!   client's calculation consent, and saves the result for the client
+//! - the preset sets `current_time` and whether a location is read
+//! - the location is the one picked with `?location=`, else the caller's
+//!   first saved location, else the birth place
+//! - saved settings are added as for any workflow run; `options` and
+//!   `precision` in the body still override them
+
+use std::collections::HashMap;
+
+use chrono::{DateTime, Duration, Utc};
+use noesis_auth::{permissions, AuthUser};
+use noesis_core::{BirthData, Coordinates, EngineError, EngineInput, Precision, ValidationCode};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::handlers::clients::{client_birth_data, client_location, consent_required, find_member_client};
+use crate::handlers::{database_error, not_found, user_uuid, HandlerError};
+use crate::precompute::birth_data_from_profile;
+use crate::{engine_error_to_response, require_permission, AppState};
+
+/// `profile_id` of the caller's own profile
+pub const OWN_PROFILE: &str = "me";
+
+/// What a preset reads
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum InputPreset {
+    /// Now, at the reading location
+    Today,
+    /// This time tomorrow, at the reading location
+    Tomorrow,
+    /// The birth chart alone, without a reading location
+    Natal,
+}
+
+/// Workflow input built from a saved profile
+#[derive(Debug, Clone, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct PresetRequest {
+    /// `me`, the caller's user id, or a client id
+    #[schema(example = "me")]
+    pub profile_id: String,
+    pub preset: InputPreset,
+    #[serde(default)]
+    pub precision: Option<Precision>,
+    #[serde(default)]
+    #[schema(value_type = Object)]
+    pub options: HashMap<String, serde_json::Value>,
+}
+
+impl PresetRequest {
+    /// The input the preset stands for at `now`; `location` is ignored by
+    /// presets that don't read one
+    pub fn expand(self, birth_data: BirthData, location: Option<Coordinates>, now: DateTime<Utc>) -> EngineInput {
+        let (current_time, location) = match self.preset {
+            InputPreset::Today => (now, location),
+            InputPreset::Tomorrow => (now + Duration::days(1), location),
+            InputPreset::Natal => (now, None),
+        };
+        EngineInput {
+            birth_data: Some(birth_data),
+            secondary_birth_data: None,
+            subjects: Vec::new(),
+            current_time,
+            location,
+            precision: self.precision.unwrap_or(Precision::Standard),
+            options: self.options,
+        }
+    }
+}
+
+/// A preset resolved against the database
+pub(crate) struct ResolvedPreset {
+    pub input: EngineInput,
+    /// Client the profile belongs to, whose result it is when saved
+    pub client_id: Option<Uuid>,
+}
+
+/// Look up the profile and expand the preset; the `?location=` selector is
+/// applied by the caller afterwards
+pub(crate) async fn resolve(
+    state: &AppState,
+    auth_user: &AuthUser,
+    request: PresetRequest,
+) -> Result<ResolvedPreset, HandlerError> {
+    state.database.ensure_available()?;
+    let caller_id = user_uuid(auth_user)?;
+    let own_profile = request.profile_id == OWN_PROFILE || Uuid::parse_str(&request.profile_id) == Ok(caller_id);
+
+    if !own_profile {
+        require_permission(auth_user, permissions::CLIENTS_CALCULATE)?;
+        let client = find_member_client(state, auth_user, &request.profile_id, permissions::CLIENTS_CALCULATE).await?;
+        if !client.consent_calculations {
+            return Err(consent_required(client.id, "calculations"));
+        }
+        let (birth_data, location) = (client_birth_data(&client), client_location(&client));
+        return Ok(ResolvedPreset {
+            input: request.expand(birth_data, Some(location), Utc::now()),
+            client_id: Some(client.id),
+        });
+    }
+
+    let profile_not_found = || not_found("PROFILE_NOT_FOUND", format!("Profile '{}' not found", request.profile_id));
+    let user = state
+        .user_repository
+        .get_user_by_id(caller_id)
+        .await
+        .map_err(database_error)?
+        .ok_or_else(profile_not_found)?;
+    let profile = state
+        .user_repository
+        .get_profile(caller_id)
+        .await
+        .map_err(database_error)?
+        .ok_or_else(profile_not_found)?;
+    let birth_data = birth_data_from_profile(&profile, &user.full_name).ok_or_else(|| {
+        engine_error_to_response(EngineError::invalid_field(
+            "profile_id",
+            ValidationCode::Required,
+            "Profile has no complete birth data; set the birth date, location and timezone",
+        ))
+    })?;
+
+    let saved = state.location_repository.list_for_user(caller_id).await.map_err(database_error)?;
+    let location = saved
+        .into_iter()
+        .next()
+        .map(|location| Coordinates {
+            latitude: location.latitude,
+            longitude: location.longitude,
+            altitude: None,
+            timezone: Some(location.timezone),
+        })
+        .unwrap_or_else(|| Coordinates {
+            latitude: birth_data.latitude,
+            longitude: birth_data.longitude,
+            altitude: None,
+            timezone: Some(birth_data.timezone.clone()),
+        });
+
+    Ok(ResolvedPreset {
+        input: request.expand(birth_data, Some(location), Utc::now()),
+        client_id: None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn birth_data() -> BirthData {
+        BirthData {
+            name: Some("Ada".to_string()),
+            display_name: None,
+            date: "1990-01-15".to_string(),
+            time: Some("14:30".to_string()),
+            time_unknown: false,
+            latitude: 12.9716,
+            longitude: 77.5946,
+            altitude: None,
+            timezone: "Asia/Kolkata".to_string(),
+            place: None,
+            utc_offset_minutes: None,
+        }
+    }
+
+    fn lisbon() -> Coordinates {
+        Coordinates { latitude: 38.7223, longitude: -9.1393, altitude: None, timezone: Some("Europe/Lisbon".to_string()) }
+    }
+
+    fn request(preset: &str) -> PresetRequest {
+        serde_json::from_value(json!({ "profile_id": "me", "preset": preset, "options": { "forecast_days": 3 } })).unwrap()
+    }
+
+    #[test]
+    fn test_today_reads_now_at_the_location() {
+        let now = Utc::now();
+        let input = request("today").expand(birth_data(), Some(lisbon()), now);
+        assert_eq!(input.current_time, now);
+        assert_eq!(input.location.map(|l| l.latitude), Some(38.7223));
+        assert_eq!(input.birth_data.map(|b| b.date).as_deref(), Some("1990-01-15"));
+        assert_eq!(input.options.get("forecast_days"), Some(&json!(3)));
+        assert_eq!(input.precision, Precision::Standard);
+    }
+
+    #[test]
+    fn test_tomorrow_and_natal() {
+        let now = Utc::now();
+        let tomorrow = request("tomorrow").expand(birth_data(), Some(lisbon()), now);
+        assert_eq!(tomorrow.current_time, now + Duration::days(1));
+
+        let natal = request("natal").expand(birth_data(), Some(lisbon()), now);
+        assert!(natal.location.is_none());
+    }
+
+    #[test]
+    fn test_unknown_preset_or_field_is_rejected() {
+        assert!(serde_json::from_value::<PresetRequest>(json!({ "profile_id": "me", "preset": "yesterday" })).is_err());
+        assert!(serde_json::from_value::<PresetRequest>(
+            json!({ "profile_id": "me", "preset": "today", "birth_data": {} })
+        )
+        .is_err());
+    }
+}
@@ -0,0 +1,286 @@
+//! RFC 7807 problem details for error responses
+//!
+//! Handlers keep building [`ErrorResponse`] through
+//! [`engine_error_to_response`](crate::engine_error_to_response).
+//! [`problem_json_middleware`] then rewrites error bodies into
+//! `application/problem+json`, carrying `error_code` and `details` as extension
+//! members. Clients whose `Accept` header asks for `application/json` without
+//! also accepting `application/problem+json` keep receiving the original
+//! `ErrorResponse` shape, except under `/api/v2`, which only serves problem
+//! documents. Either way the body gains the request's
+//! `X-Request-Id` as `request_id`, so a reported failure can be found in logs.
+
+use axum::{
+    body::{to_bytes, Body},
+    extract::Request,
+    http::{header, HeaderMap, HeaderValue, StatusCode},
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use noesis_core::correlation;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::versioning::ApiVersion;
+use crate::ErrorResponse;
+
+/// Media type of RFC 7807 problem documents
+pub const PROBLEM_JSON: &str = "application/problem+json";
+
+/// Base URI for problem `type` identifiers, suffixed with the kebab-cased error code
+pub const PROBLEM_TYPE_BASE: &str = "https://docs.selemene.io/problems/";
+
+/// Largest error body the middleware will buffer for rewriting
+const MAX_ERROR_BODY_BYTES: usize = 64 * 1024;
+
+/// RFC 7807 problem document
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct ProblemDetails {
+    /// URI identifying the problem type
+    #[serde(rename = "type")]
+    #[schema(example = "https://docs.selemene.io/problems/validation-error")]
+    pub problem_type: String,
+    /// Short summary of the problem type
+    #[schema(example = "Unprocessable Entity")]
+    pub title: String,
+    /// HTTP status code
+    #[schema(example = 422)]
+    pub status: u16,
+    /// Explanation specific to this occurrence
+    pub detail: String,
+    /// Request path that produced the problem
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(example = "/api/v1/engines/panchanga/calculate")]
+    pub instance: Option<String>,
+    /// Stable machine-readable error code (extension member)
+    #[schema(example = "VALIDATION_ERROR")]
+    pub error_code: String,
+    /// Structured error context (extension member)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub details: Option<serde_json::Value>,
+    /// `X-Request-Id` of the failed request (extension member)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(example = "4f1c2a9e-7d3b-4c1e-9a8f-2b6d5e0c1a7f")]
+    pub request_id: Option<String>,
+}
+
+impl ProblemDetails {
+    pub fn new(status: StatusCode, error_code: &str, detail: impl Into<String>) -> Self {
+        Self {
+            problem_type: format!(
+                "{}{}",
+                PROBLEM_TYPE_BASE,
+                error_code.to_ascii_lowercase().replace('_', "-")
+            ),
+            title: status.canonical_reason().unwrap_or("Error").to_string(),
+            status: status.as_u16(),
+            detail: detail.into(),
+            instance: None,
+            error_code: error_code.to_string(),
+            details: None,
+            request_id: None,
+        }
+    }
+
+    /// Convert a legacy error body into a problem document
+    pub fn from_error_response(status: StatusCode, err: ErrorResponse) -> Self {
+        Self {
+            details: err.details,
+            request_id: err.request_id,
+            ..Self::new(status, &err.error_code, err.error)
+        }
+    }
+
+    pub fn with_instance(mut self, instance: impl Into<String>) -> Self {
+        self.instance = Some(instance.into());
+        self
+    }
+
+    pub fn with_request_id(mut self, request_id: Option<String>) -> Self {
+        if request_id.is_some() {
+            self.request_id = request_id;
+        }
+        self
+    }
+}
+
+impl IntoResponse for ProblemDetails {
+    fn into_response(self) -> Response {
+        let status = StatusCode::from_u16(self.status).unwrap_or(StatusCode::INTERNAL_SERVER_ERROR);
+        let body = serde_json::to_vec(&self).unwrap_or_default();
+        (
+            status,
+            [(header::CONTENT_TYPE, HeaderValue::from_static(PROBLEM_JSON))],
+            body,
+        )
+            .into_response()
+    }
+}
+
+/// Whether the `Accept` header asks for the legacy `ErrorResponse` shape.
+///
+/// True when `application/json` is listed and either `application/problem+json`
+/// is absent or has a lower quality value. Wildcards and a missing header get
+/// problem documents.
+pub fn prefers_legacy_errors(headers: &HeaderMap) -> bool {
+    let Some(accept) = headers.get(header::ACCEPT).and_then(|v| v.to_str().ok()) else {
+        return false;
+    };
+
+    let mut json_q: Option<f32> = None;
+    let mut problem_q: Option<f32> = None;
+    for range in accept.split(',') {
+        let mut params = range.split(';');
+        let media = params.next().unwrap_or("").trim().to_ascii_lowercase();
+        let q = params
+            .filter_map(|p| p.trim().strip_prefix("q="))
+            .find_map(|q| q.parse::<f32>().ok())
+            .unwrap_or(1.0);
+        let slot = match media.as_str() {
+            "application/json" => &mut json_q,
+            PROBLEM_JSON => &mut problem_q,
+            _ => continue,
+        };
+        *slot = Some(slot.map_or(q, |prev| prev.max(q)));
+    }
+
+    match (json_q, problem_q) {
+        (Some(json), Some(problem)) => json > problem,
+        (Some(json), None) => json > 0.0,
+        _ => false,
+    }
+}
+
+/// Rewrite 4xx/5xx `ErrorResponse` and plain-text bodies as problem documents.
+///
+/// Legacy clients keep their `ErrorResponse` body, with `request_id` added.
+/// Response headers such as `X-RateLimit-*` and `Retry-After` are preserved.
+pub async fn problem_json_middleware(req: Request, next: Next) -> Response {
+    let instance = req.uri().path().to_string();
+    let legacy = prefers_legacy_errors(req.headers())
+        && ApiVersion::from_path(&instance).is_none_or(|version| version.negotiates_legacy_errors());
+    let request_id = correlation::current_request_id();
+
+    let response = next.run(req).await;
+    let status = response.status();
+    if !(status.is_client_error() || status.is_server_error()) {
+        return response;
+    }
+
+    let content_type = response
+        .headers()
+        .get(header::CONTENT_TYPE)
+        .and_then(|v| v.to_str().ok())
+        .unwrap_or("")
+        .to_ascii_lowercase();
+    let is_json = content_type.starts_with("application/json");
+    if !is_json && (legacy || !content_type.starts_with("text/plain")) {
+        return response;
+    }
+
+    let (parts, body) = response.into_parts();
+    let bytes = match to_bytes(body, MAX_ERROR_BODY_BYTES).await {
+        Ok(bytes) => bytes,
+        Err(_) => {
+            let code = default_error_code(status);
+            return ProblemDetails::new(status, &code, "Error body could not be read")
+                .with_instance(instance)
+                .with_request_id(request_id)
+                .into_response();
+        }
+    };
+
+    let problem = if is_json {
+        match serde_json::from_slice::<ErrorResponse>(&bytes) {
+            Ok(mut err) if legacy => {
+                err.request_id = err.request_id.or(request_id);
+                let mut parts = parts;
+                parts.headers.remove(header::CONTENT_LENGTH);
+                let body = serde_json::to_vec(&err).unwrap_or_else(|_| bytes.to_vec());
+                return Response::from_parts(parts, Body::from(body));
+            }
+            Ok(err) => ProblemDetails::from_error_response(status, err),
+            // Some other JSON document: leave it untouched
+            Err(_) => return Response::from_parts(parts, Body::from(bytes)),
+        }
+    } else {
+        // Framework rejections (malformed JSON body, missing query parameters, ...)
+        ProblemDetails::new(
+            status,
+            &default_error_code(status),
+            String::from_utf8_lossy(&bytes).trim(),
+        )
+    };
+
+    let mut rewritten = problem
+        .with_instance(instance)
+        .with_request_id(request_id)
+        .into_response();
+    for (name, value) in parts.headers.iter() {
+        if name != header::CONTENT_TYPE && name != header::CONTENT_LENGTH {
+            rewritten.headers_mut().append(name.clone(), value.clone());
+        }
+    }
+    rewritten
+}
+
+/// "UNPROCESSABLE_ENTITY" for 422, "BAD_REQUEST" for 400, ...
+fn default_error_code(status: StatusCode) -> String {
+    status
+        .canonical_reason()
+        .unwrap_or("ERROR")
+        .to_ascii_uppercase()
+        .replace([' ', '-'], "_")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn accept(value: &str) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        headers.insert(header::ACCEPT, HeaderValue::from_str(value).unwrap());
+        headers
+    }
+
+    #[test]
+    fn test_accept_negotiation() {
+        assert!(!prefers_legacy_errors(&HeaderMap::new()));
+        assert!(!prefers_legacy_errors(&accept("*/*")));
+        assert!(!prefers_legacy_errors(&accept("application/problem+json")));
+        assert!(prefers_legacy_errors(&accept("application/json")));
+        assert!(prefers_legacy_errors(&accept("application/json, */*;q=0.8")));
+        assert!(!prefers_legacy_errors(&accept(
+            "application/problem+json, application/json;q=0.5"
+        )));
+        assert!(prefers_legacy_errors(&accept(
+            "application/problem+json;q=0.2, application/json"
+        )));
+    }
+
+    #[test]
+    fn test_problem_from_error_response() {
+        let err = ErrorResponse {
+            error: "Engine not found: tarot".to_string(),
+            error_code: "ENGINE_NOT_FOUND".to_string(),
+            details: Some(serde_json::json!({ "engine_id": "tarot" })),
+            request_id: None,
+        };
+        let problem = ProblemDetails::from_error_response(StatusCode::NOT_FOUND, err)
+            .with_instance("/api/v1/engines/tarot/info");
+
+        let json = serde_json::to_value(&problem).unwrap();
+        assert_eq!(json["type"], "https://docs.selemene.io/problems/engine-not-found");
+        assert_eq!(json["title"], "Not Found");
+        assert_eq!(json["status"], 404);
+        assert_eq!(json["detail"], "Engine not found: tarot");
+        assert_eq!(json["instance"], "/api/v1/engines/tarot/info");
+        assert_eq!(json["details"]["engine_id"], "tarot");
+    }
+
+    #[test]
+    fn test_default_error_code() {
+        assert_eq!(default_error_code(StatusCode::UNPROCESSABLE_ENTITY), "UNPROCESSABLE_ENTITY");
+        assert_eq!(default_error_code(StatusCode::BAD_REQUEST), "BAD_REQUEST");
+    }
+}
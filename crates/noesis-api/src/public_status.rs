@@ -0,0 +1,352 @@
+//! Public status page (`GET /api/v1/status/public`)
+//!
+//! Unauthenticated and meant to back a status page: each component is
+//! reported as operational, degraded or outage, with none of the engine
+//! names, errors and latencies `/ready` and `/api/v1/engines/health` carry.
+//! The checks run at most once per [`PUBLIC_STATUS_TTL`] and callers in
+//! between get the last snapshot, so polling the page can't put health
+//! checks on the engines or the TypeScript engine server.
+//!
+//! A component leaving `operational` opens an incident, which resolves when
+//! it comes back; resolved incidents stay listed for
+//! [`RECENT_INCIDENT_HOURS`].
+
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use chrono::{DateTime, Utc};
+use noesis_bridge::BridgeManager;
+use noesis_vedic_api::{CachedVedicClient, RateLimitStatus};
+use serde::{Deserialize, Serialize};
+use tokio::sync::Mutex;
+use utoipa::ToSchema;
+
+use crate::AppState;
+
+/// How long a status snapshot is served before the checks run again
+pub const PUBLIC_STATUS_TTL: Duration = Duration::from_secs(30);
+
+/// How long a resolved incident stays listed
+pub const RECENT_INCIDENT_HOURS: i64 = 24;
+
+/// Coarse health of one component
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ComponentStatus {
+    Operational,
+    /// Working with reduced capacity or features, e.g. the Vedic API quota
+    /// nearly spent
+    Degraded,
+    Outage,
+    /// Not part of this deployment, e.g. the TS bridge without `TS_ENGINES_URL`
+    NotConfigured,
+}
+
+impl ComponentStatus {
+    fn severity(self) -> u8 {
+        match self {
+            ComponentStatus::NotConfigured | ComponentStatus::Operational => 0,
+            ComponentStatus::Degraded => 1,
+            ComponentStatus::Outage => 2,
+        }
+    }
+
+    fn is_incident(self) -> bool {
+        self.severity() > 0
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum Component {
+    Api,
+    Cache,
+    Database,
+    TsBridge,
+    VedicApi,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct PublicComponents {
+    /// Outage while a critical engine fails, degraded while any other does
+    pub api: ComponentStatus,
+    pub cache: ComponentStatus,
+    /// Outage while the server runs without its database: accounts, saved
+    /// results and clients are unavailable, calculations still work
+    pub database: ComponentStatus,
+    /// The TypeScript engine server behind the bridged engines
+    pub ts_bridge: ComponentStatus,
+    /// Daily quota of the external Vedic API: degraded at a fifth left,
+    /// outage once spent
+    pub vedic_api: ComponentStatus,
+}
+
+impl PublicComponents {
+    fn iter(&self) -> [(Component, ComponentStatus); 5] {
+        [
+            (Component::Api, self.api),
+            (Component::Cache, self.cache),
+            (Component::Database, self.database),
+            (Component::TsBridge, self.ts_bridge),
+            (Component::VedicApi, self.vedic_api),
+        ]
+    }
+
+    /// The worst component; only the API itself failing is a full outage
+    pub fn overall(&self) -> ComponentStatus {
+        self.iter()
+            .into_iter()
+            .map(|(component, status)| match (component, status) {
+                (Component::Api, status) | (_, status @ ComponentStatus::Degraded) => status,
+                (_, ComponentStatus::Outage) => ComponentStatus::Degraded,
+                _ => ComponentStatus::Operational,
+            })
+            .max_by_key(|status| status.severity())
+            .unwrap_or(ComponentStatus::Operational)
+    }
+}
+
+/// A stretch of time a component was not operational
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct PublicIncident {
+    pub component: Component,
+    /// Worst status while the incident lasted
+    pub status: ComponentStatus,
+    pub started_at: DateTime<Utc>,
+    /// Absent while the incident is ongoing
+    pub resolved_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct PublicStatusResponse {
+    /// Worst of the components, outage only when the API itself is down
+    pub status: ComponentStatus,
+    pub components: PublicComponents,
+    /// Ongoing incidents and those resolved in the last 24 hours, newest first
+    pub incidents: Vec<PublicIncident>,
+    pub checked_at: DateTime<Utc>,
+}
+
+#[derive(Default)]
+struct Board {
+    snapshot: Option<(Instant, PublicStatusResponse)>,
+    incidents: Vec<PublicIncident>,
+}
+
+/// Latest public status snapshot and the incidents seen so far
+pub struct StatusBoard {
+    bridge: Option<BridgeManager>,
+    vedic_api: Option<Arc<CachedVedicClient>>,
+    board: Mutex<Board>,
+}
+
+impl StatusBoard {
+    pub fn new(bridge: Option<BridgeManager>, vedic_api: Option<Arc<CachedVedicClient>>) -> Self {
+        Self {
+            bridge,
+            vedic_api,
+            board: Mutex::new(Board::default()),
+        }
+    }
+
+    /// The bridge from `TS_ENGINES_URL` and the Vedic API client from
+    /// `FREE_ASTROLOGY_API_KEY`; either unset leaves its component `not_configured`
+    pub fn from_env() -> Self {
+        Self::with_vedic_api(CachedVedicClient::from_env().ok().map(Arc::new))
+    }
+
+    /// The bridge from `TS_ENGINES_URL` and the Vedic API client the server
+    /// already built, so the status page reports the quota of the client in use
+    pub fn with_vedic_api(vedic_api: Option<Arc<CachedVedicClient>>) -> Self {
+        let bridge = std::env::var("TS_ENGINES_URL").is_ok().then(BridgeManager::from_env);
+        Self::new(bridge, vedic_api)
+    }
+
+    /// The Vedic API client whose quota is reported
+    pub fn vedic_api(&self) -> Option<&Arc<CachedVedicClient>> {
+        self.vedic_api.as_ref()
+    }
+
+    /// The last snapshot, checking the components again once it is older
+    /// than [`PUBLIC_STATUS_TTL`]
+    pub async fn status(&self, state: &AppState) -> PublicStatusResponse {
+        // Held across the checks, so concurrent callers wait for one run
+        let mut board = self.board.lock().await;
+        if let Some((taken, snapshot)) = &board.snapshot {
+            if taken.elapsed() < PUBLIC_STATUS_TTL {
+                return snapshot.clone();
+            }
+        }
+
+        let components = self.check(state).await;
+        let snapshot = board.record(components, Utc::now());
+        board.snapshot = Some((Instant::now(), snapshot.clone()));
+        snapshot
+    }
+
+    async fn check(&self, state: &AppState) -> PublicComponents {
+        let api = async {
+            let health = state.core.orchestrator.engine_health().await;
+            if !state.core.orchestrator.is_ready_with(&health) {
+                ComponentStatus::Outage
+            } else if health.iter().any(|engine| !engine.healthy) {
+                ComponentStatus::Degraded
+            } else {
+                ComponentStatus::Operational
+            }
+        };
+        let cache = async {
+            match state.core.cache.health_check().await {
+                Ok(true) => ComponentStatus::Operational,
+                // Calculations still run, uncached or from the in-memory layer
+                _ => ComponentStatus::Degraded,
+            }
+        };
+        let ts_bridge = async {
+            match &self.bridge {
+                Some(bridge) => match bridge.health_check().await {
+                    Ok(()) => ComponentStatus::Operational,
+                    Err(_) => ComponentStatus::Outage,
+                },
+                None => ComponentStatus::NotConfigured,
+            }
+        };
+        let vedic_api = async {
+            match &self.vedic_api {
+                Some(client) => quota_status(&client.rate_limit_status().await),
+                None => ComponentStatus::NotConfigured,
+            }
+        };
+        let (api, cache, ts_bridge, vedic_api) = tokio::join!(api, cache, ts_bridge, vedic_api);
+
+        PublicComponents {
+            api,
+            cache,
+            database: if state.database.is_available() {
+                ComponentStatus::Operational
+            } else {
+                ComponentStatus::Outage
+            },
+            ts_bridge,
+            vedic_api,
+        }
+    }
+}
+
+impl Board {
+    /// Open, update and resolve incidents from `components` and build the snapshot
+    fn record(&mut self, components: PublicComponents, now: DateTime<Utc>) -> PublicStatusResponse {
+        for (component, status) in components.iter() {
+            let open = self
+                .incidents
+                .iter_mut()
+                .find(|incident| incident.component == component && incident.resolved_at.is_none());
+            match (open, status.is_incident()) {
+                (Some(incident), true) => {
+                    if status.severity() > incident.status.severity() {
+                        incident.status = status;
+                    }
+                }
+                (Some(incident), false) => incident.resolved_at = Some(now),
+                (None, true) => self.incidents.push(PublicIncident {
+                    component,
+                    status,
+                    started_at: now,
+                    resolved_at: None,
+                }),
+                (None, false) => {}
+            }
+        }
+
+        let cutoff = now - chrono::Duration::hours(RECENT_INCIDENT_HOURS);
+        self.incidents
+            .retain(|incident| incident.resolved_at.is_none_or(|resolved| resolved > cutoff));
+        let mut incidents = self.incidents.clone();
+        incidents.sort_by_key(|incident| std::cmp::Reverse(incident.started_at));
+
+        PublicStatusResponse {
+            status: components.overall(),
+            components,
+            incidents,
+            checked_at: now,
+        }
+    }
+}
+
+fn quota_status(quota: &RateLimitStatus) -> ComponentStatus {
+    if quota.effective_remaining == 0 {
+        ComponentStatus::Outage
+    } else if quota.effective_remaining * 5 <= quota.daily_limit {
+        ComponentStatus::Degraded
+    } else {
+        ComponentStatus::Operational
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn components(database: ComponentStatus, ts_bridge: ComponentStatus) -> PublicComponents {
+        PublicComponents {
+            api: ComponentStatus::Operational,
+            cache: ComponentStatus::Operational,
+            database,
+            ts_bridge,
+            vedic_api: ComponentStatus::NotConfigured,
+        }
+    }
+
+    #[test]
+    fn test_overall_is_outage_only_when_the_api_is_down() {
+        use ComponentStatus::*;
+        assert_eq!(components(Operational, NotConfigured).overall(), Operational);
+        assert_eq!(components(Outage, Operational).overall(), Degraded);
+        let mut down = components(Operational, Operational);
+        down.api = Outage;
+        assert_eq!(down.overall(), Outage);
+    }
+
+    #[test]
+    fn test_incidents_open_escalate_and_resolve() {
+        use ComponentStatus::*;
+        let mut board = Board::default();
+        let start = Utc::now();
+
+        let status = board.record(components(Operational, Degraded), start);
+        assert_eq!(status.incidents.len(), 1);
+        assert_eq!(status.incidents[0].component, Component::TsBridge);
+
+        let later = start + chrono::Duration::minutes(5);
+        let status = board.record(components(Outage, Outage), later);
+        assert_eq!(status.incidents.len(), 2);
+        assert_eq!(status.incidents[0].component, Component::Database);
+        assert_eq!(status.incidents[1].status, Outage);
+        assert_eq!(status.incidents[1].started_at, start);
+
+        let resolved = later + chrono::Duration::minutes(5);
+        let status = board.record(components(Operational, Operational), resolved);
+        assert!(status.incidents.iter().all(|incident| incident.resolved_at == Some(resolved)));
+
+        // Resolved incidents drop off after a day
+        let status = board.record(
+            components(Operational, Operational),
+            resolved + chrono::Duration::hours(RECENT_INCIDENT_HOURS),
+        );
+        assert!(status.incidents.is_empty());
+    }
+
+    #[test]
+    fn test_quota_status() {
+        let quota = |effective_remaining| RateLimitStatus {
+            daily_limit: 50,
+            remaining_today: effective_remaining + 5,
+            buffer: 5,
+            effective_remaining,
+            used_today: 45 - effective_remaining,
+        };
+        assert_eq!(quota_status(&quota(30)), ComponentStatus::Operational);
+        assert_eq!(quota_status(&quota(10)), ComponentStatus::Degraded);
+        assert_eq!(quota_status(&quota(0)), ComponentStatus::Outage);
+    }
+}
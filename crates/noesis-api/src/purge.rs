@@ -0,0 +1,34 @@
+//! Background purge of soft-deleted accounts
+//!
+//! `DELETE /api/v1/users/me` only stamps `deleted_at`, so the account can be
+//! restored through `POST /api/v1/auth/restore`. Once
+//! [`RESTORE_WINDOW_DAYS`] have passed, this job removes the account for good,
+//! along with its profile, saved results, progression history, practice log and
+//! settings.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use noesis_data::repositories::user_repository::{UserRepository, RESTORE_WINDOW_DAYS};
+use tokio::task::JoinHandle;
+use tokio::time::MissedTickBehavior;
+
+/// Purge expired accounts now and then every `interval`
+pub fn spawn_purge_job(users: Arc<UserRepository>, interval: Duration) -> JoinHandle<()> {
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(interval);
+        ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+        loop {
+            ticker.tick().await;
+            match users.purge_deleted_users().await {
+                Ok(0) => {}
+                Ok(purged) => tracing::info!(
+                    purged,
+                    "Purged accounts deleted more than {} days ago",
+                    RESTORE_WINDOW_DAYS
+                ),
+                Err(e) => tracing::warn!(error = %e, "Purging deleted accounts failed"),
+            }
+        }
+    })
+}
@@ -0,0 +1,339 @@
+//! Bulk recalculation of saved profiles after an engine version bump
+//!
+//! Cached results are keyed by engine version, so a bumped engine stops
+//! serving the old ones and each user's next request would calculate afresh.
+//! `POST /api/v1/admin/recalculate` does that work ahead of them: it runs the
+//! engine for every saved birth profile through
+//! `WorkflowOrchestrator::precompute`, as the precompute worker does for a
+//! single profile save, so natal results land in the persistent L3 again.
+//!
+//! Profiles are read once when the job starts and run `concurrency` at a
+//! time. A failing profile is recorded with its error and the job moves on;
+//! the failures can be retried as a new job. Jobs run in the background, so a
+//! client dropping the progress stream does not stop one.
+
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex};
+
+use chrono::{DateTime, Utc};
+use futures::StreamExt;
+use noesis_core::BirthData;
+use noesis_orchestrator::WorkflowOrchestrator;
+use serde::Serialize;
+use tokio::sync::watch;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::precompute::natal_input;
+
+/// Profiles recalculated at once when the request does not say
+pub const DEFAULT_CONCURRENCY: usize = 4;
+
+/// One saved profile to recalculate
+#[derive(Debug, Clone)]
+pub struct RecalculationItem {
+    pub user_id: Uuid,
+    pub birth_data: BirthData,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum RecalculationStatus {
+    Running,
+    Completed,
+}
+
+/// Counts of a recalculation job, sent as each profile finishes
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct RecalculationProgress {
+    #[schema(value_type = String)]
+    pub job_id: Uuid,
+    #[schema(example = "panchanga")]
+    pub engine_id: String,
+    /// Version the results are recalculated with
+    #[schema(example = "3")]
+    pub engine_version: String,
+    pub status: RecalculationStatus,
+    /// Profiles the job covers
+    pub total: usize,
+    pub succeeded: usize,
+    pub failed: usize,
+    pub started_at: DateTime<Utc>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub finished_at: Option<DateTime<Utc>>,
+    /// Job whose failures this one retries
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(value_type = Option<String>)]
+    pub retry_of: Option<Uuid>,
+}
+
+impl RecalculationProgress {
+    /// Profiles finished so far, successfully or not
+    pub fn processed(&self) -> usize {
+        self.succeeded + self.failed
+    }
+}
+
+/// A profile the job could not recalculate
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct RecalculationFailure {
+    #[schema(value_type = String)]
+    pub user_id: Uuid,
+    pub error: String,
+}
+
+/// A job's counts with the profiles that failed so far
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct RecalculationJobResponse {
+    #[serde(flatten)]
+    pub progress: RecalculationProgress,
+    pub failures: Vec<RecalculationFailure>,
+}
+
+/// A recalculation job, running or finished
+pub struct RecalculationJob {
+    progress: watch::Sender<RecalculationProgress>,
+    failures: Mutex<Vec<RecalculationFailure>>,
+}
+
+impl RecalculationJob {
+    pub fn id(&self) -> Uuid {
+        self.progress.borrow().job_id
+    }
+
+    pub fn progress(&self) -> RecalculationProgress {
+        self.progress.borrow().clone()
+    }
+
+    pub fn is_running(&self) -> bool {
+        self.progress.borrow().status == RecalculationStatus::Running
+    }
+
+    /// Users whose profile failed, to retry
+    pub fn failed_user_ids(&self) -> Vec<Uuid> {
+        self.failures.lock().unwrap().iter().map(|f| f.user_id).collect()
+    }
+
+    pub fn snapshot(&self) -> RecalculationJobResponse {
+        RecalculationJobResponse {
+            progress: self.progress(),
+            failures: self.failures.lock().unwrap().clone(),
+        }
+    }
+
+    /// Progress updates; the current counts are marked unseen
+    pub fn subscribe(&self) -> watch::Receiver<RecalculationProgress> {
+        let mut receiver = self.progress.subscribe();
+        receiver.mark_changed();
+        receiver
+    }
+
+    fn record(&self, engine_id: &str, user_id: Uuid, outcome: Result<(), String>) {
+        noesis_metrics::record_recalculation(engine_id, outcome.is_ok());
+        match outcome {
+            Ok(()) => self.progress.send_modify(|p| p.succeeded += 1),
+            Err(error) => {
+                tracing::warn!(engine_id, %user_id, error = %error, "Recalculating saved profile failed");
+                self.failures.lock().unwrap().push(RecalculationFailure { user_id, error });
+                self.progress.send_modify(|p| p.failed += 1);
+            }
+        }
+    }
+}
+
+/// Recent recalculation jobs, at most one running per engine
+#[derive(Default)]
+pub struct RecalculationJobs {
+    jobs: Mutex<VecDeque<Arc<RecalculationJob>>>,
+}
+
+impl RecalculationJobs {
+    /// Finished jobs kept for their failures, oldest dropped first
+    pub const KEPT_JOBS: usize = 20;
+
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn get(&self, job_id: Uuid) -> Option<Arc<RecalculationJob>> {
+        self.jobs.lock().unwrap().iter().find(|job| job.id() == job_id).cloned()
+    }
+
+    /// Start recalculating `items` with `engine_id` in the background. Fails
+    /// with the running job's id when that engine already has one.
+    pub fn start(
+        &self,
+        orchestrator: Arc<WorkflowOrchestrator>,
+        engine_id: &str,
+        engine_version: &str,
+        items: Vec<RecalculationItem>,
+        concurrency: usize,
+        retry_of: Option<Uuid>,
+    ) -> Result<Arc<RecalculationJob>, Uuid> {
+        let job = {
+            let mut jobs = self.jobs.lock().unwrap();
+            if let Some(running) = jobs
+                .iter()
+                .find(|job| job.is_running() && job.progress.borrow().engine_id == engine_id)
+            {
+                return Err(running.id());
+            }
+
+            let (progress, _) = watch::channel(RecalculationProgress {
+                job_id: Uuid::new_v4(),
+                engine_id: engine_id.to_string(),
+                engine_version: engine_version.to_string(),
+                status: RecalculationStatus::Running,
+                total: items.len(),
+                succeeded: 0,
+                failed: 0,
+                started_at: Utc::now(),
+                finished_at: None,
+                retry_of,
+            });
+            let job = Arc::new(RecalculationJob {
+                progress,
+                failures: Mutex::new(Vec::new()),
+            });
+            jobs.push_back(job.clone());
+            while jobs.len() > Self::KEPT_JOBS {
+                match jobs.iter().position(|job| !job.is_running()) {
+                    Some(oldest_finished) => drop(jobs.remove(oldest_finished)),
+                    None => break,
+                }
+            }
+            job
+        };
+
+        tracing::info!(
+            job_id = %job.id(),
+            engine_id,
+            engine_version,
+            total = items.len(),
+            concurrency,
+            "Starting bulk recalculation"
+        );
+        tokio::spawn(run(job.clone(), orchestrator, engine_id.to_string(), items, concurrency));
+        Ok(job)
+    }
+}
+
+async fn run(
+    job: Arc<RecalculationJob>,
+    orchestrator: Arc<WorkflowOrchestrator>,
+    engine_id: String,
+    items: Vec<RecalculationItem>,
+    concurrency: usize,
+) {
+    let mut outcomes = futures::stream::iter(items)
+        .map(|item| {
+            let orchestrator = orchestrator.clone();
+            let engine_id = engine_id.clone();
+            async move {
+                let outcome = orchestrator
+                    .precompute(&engine_id, natal_input(item.birth_data))
+                    .await
+                    .map(|_| ())
+                    .map_err(|e| e.to_string());
+                (item.user_id, outcome)
+            }
+        })
+        .buffer_unordered(concurrency.max(1));
+    while let Some((user_id, outcome)) = outcomes.next().await {
+        job.record(&engine_id, user_id, outcome);
+    }
+
+    job.progress.send_modify(|p| {
+        p.status = RecalculationStatus::Completed;
+        p.finished_at = Some(Utc::now());
+    });
+    let progress = job.progress();
+    tracing::info!(
+        job_id = %progress.job_id,
+        engine_id = %progress.engine_id,
+        succeeded = progress.succeeded,
+        failed = progress.failed,
+        "Bulk recalculation finished"
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use noesis_cache::CacheManager;
+    use std::time::Duration;
+
+    fn birth(date: &str) -> BirthData {
+        BirthData {
+            name: Some("Ada Lovelace".to_string()),
+            display_name: None,
+            date: date.to_string(),
+            time: Some("14:30".to_string()),
+            time_unknown: false,
+            latitude: 12.9716,
+            longitude: 77.5946,
+            altitude: None,
+            timezone: "Asia/Kolkata".to_string(),
+            place: None,
+            utc_offset_minutes: None,
+        }
+    }
+
+    fn orchestrator() -> Arc<WorkflowOrchestrator> {
+        let mut orchestrator = WorkflowOrchestrator::new();
+        orchestrator.register_engine(Arc::new(engine_numerology::NumerologyEngine::new()));
+        orchestrator.set_result_cache(Arc::new(CacheManager::new(String::new(), 8, Duration::from_secs(60), false)));
+        Arc::new(orchestrator)
+    }
+
+    async fn finished(job: &RecalculationJob) -> RecalculationProgress {
+        let mut receiver = job.subscribe();
+        let progress = receiver
+            .wait_for(|p| p.status == RecalculationStatus::Completed)
+            .await
+            .unwrap()
+            .clone();
+        progress
+    }
+
+    #[tokio::test]
+    async fn test_job_records_each_failure_and_finishes() {
+        let jobs = RecalculationJobs::new();
+        let (good, bad) = (Uuid::new_v4(), Uuid::new_v4());
+        let items = vec![
+            RecalculationItem { user_id: good, birth_data: birth("1990-01-15") },
+            RecalculationItem { user_id: bad, birth_data: birth("not-a-date") },
+        ];
+
+        let job = jobs.start(orchestrator(), "numerology", "1", items, 2, None).unwrap();
+        let progress = finished(&job).await;
+
+        assert_eq!((progress.total, progress.succeeded, progress.failed), (2, 1, 1));
+        assert_eq!(progress.processed(), 2);
+        assert!(progress.finished_at.is_some());
+        assert_eq!(job.failed_user_ids(), [bad]);
+        assert!(jobs.get(progress.job_id).is_some());
+    }
+
+    #[tokio::test]
+    async fn test_one_running_job_per_engine() {
+        let jobs = RecalculationJobs::new();
+        let items: Vec<RecalculationItem> = (0..50)
+            .map(|_| RecalculationItem { user_id: Uuid::new_v4(), birth_data: birth("1990-01-15") })
+            .collect();
+
+        // The test runtime is single-threaded: the job cannot finish before
+        // this test awaits
+        let first = jobs.start(orchestrator(), "numerology", "1", items, 1, None).unwrap();
+        let conflict = jobs.start(orchestrator(), "numerology", "1", Vec::new(), 1, None);
+        assert_eq!(conflict.err(), Some(first.id()));
+        finished(&first).await;
+
+        let retry = jobs
+            .start(orchestrator(), "numerology", "1", Vec::new(), 1, Some(first.id()))
+            .unwrap();
+        let progress = finished(&retry).await;
+        assert_eq!(progress.retry_of, Some(first.id()));
+        assert_eq!(progress.total, 0);
+    }
+}
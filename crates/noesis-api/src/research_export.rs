@@ -0,0 +1,602 @@
+//! Research exports of saved results as Parquet
+//!
+//! The research team analyzes calculation outputs in Python. Rather than
+//! scrape JSON out of Postgres, they start an export with
+//! `POST /api/v1/admin/research-exports`. Every saved result is read a page
+//! at a time, leaving out deleted ones and those calculated for
+//! practitioners' clients, and redacted as for any export. Each engine output
+//! with a research schema becomes a row of that engine's Parquet files (see
+//! [`noesis_export`]). A file holds up to `batch_size` rows and is uploaded
+//! as `{prefix}/{export_id}/{engine_id}/part-00000.parquet`, so one export
+//! of an engine loads as a single dataset.
+//!
+//! Rows are pseudonymous. The subject is a hash of the user id with a salt
+//! drawn for the export and never stored, so one person's rows link up within
+//! an export but not across exports or back to the account.
+//!
+//! Configuration, read like L3's bucket (see [`noesis_cache::object_storage`]):
+//! - `RESEARCH_EXPORT_S3_BUCKET`: Bucket; exports are refused without it
+//! - `RESEARCH_EXPORT_S3_REGION`, `RESEARCH_EXPORT_S3_ENDPOINT`: As for L3
+//! - `RESEARCH_EXPORT_S3_PREFIX`: Key prefix (default: "noesis/research")
+
+use std::collections::{BTreeMap, VecDeque};
+use std::sync::{Arc, Mutex};
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use noesis_cache::object_storage::{S3Client, S3Config};
+use noesis_core::redaction::{RedactionMode, RedactionPolicies, RedactionTarget};
+use noesis_core::{EngineError, EngineInput, WorkflowResult};
+use noesis_data::models::workflow_result::SavedWorkflowResult;
+use noesis_data::repositories::workflow_result_repository::WorkflowResultRepository;
+use noesis_export::{engine_schema, EngineSchema, ExportRow};
+use serde::Serialize;
+use sha2::{Digest, Sha256};
+use tokio::sync::watch;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+/// Rows per Parquet file when the request does not say
+pub const DEFAULT_BATCH_SIZE: usize = 10_000;
+
+/// Largest `batch_size`; a file's rows are held in memory until written
+pub const MAX_BATCH_SIZE: usize = 100_000;
+
+/// Saved results read from the database at once
+const PAGE_SIZE: i64 = 500;
+
+/// Where an export reads saved results from
+#[async_trait]
+pub trait SavedResultSource: Send + Sync {
+    /// Results saved at or after `since`, oldest first, following the
+    /// `(created_at, id)` the previous page ended on
+    async fn page(
+        &self,
+        since: Option<DateTime<Utc>>,
+        after: Option<(DateTime<Utc>, Uuid)>,
+        limit: i64,
+    ) -> Result<Vec<SavedWorkflowResult>, String>;
+}
+
+#[async_trait]
+impl SavedResultSource for WorkflowResultRepository {
+    async fn page(
+        &self,
+        since: Option<DateTime<Utc>>,
+        after: Option<(DateTime<Utc>, Uuid)>,
+        limit: i64,
+    ) -> Result<Vec<SavedWorkflowResult>, String> {
+        self.list_results_for_export(since, after, limit)
+            .await
+            .map_err(|e| format!("Database error: {}", e))
+    }
+}
+
+/// Where an export writes its files
+#[async_trait]
+pub trait ExportStore: Send + Sync {
+    /// Store `body` under `key`, relative to the store's prefix
+    async fn put(&self, key: &str, body: Vec<u8>) -> Result<(), EngineError>;
+
+    /// Where researchers find the object stored under `key`
+    fn url(&self, key: &str) -> String;
+}
+
+#[async_trait]
+impl ExportStore for S3Client {
+    async fn put(&self, key: &str, body: Vec<u8>) -> Result<(), EngineError> {
+        self.put_object(&format!("{}/{}", self.config().prefix, key), body).await
+    }
+
+    fn url(&self, key: &str) -> String {
+        let config = self.config();
+        format!("s3://{}/{}/{}", config.bucket, config.prefix, key)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ResearchExportStatus {
+    Running,
+    Completed,
+    /// Stopped by a database or upload error; the files listed were written
+    Failed,
+}
+
+/// One Parquet file an export wrote
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct ExportedFile {
+    #[schema(example = "human-design")]
+    pub engine_id: String,
+    #[schema(example = "s3://research/noesis/research/0f6c…/human-design/part-00000.parquet")]
+    pub url: String,
+    pub rows: usize,
+    pub bytes: usize,
+}
+
+/// What to export
+#[derive(Debug, Clone)]
+pub struct ResearchExportRequest {
+    /// Engines to export; each must have a research schema
+    pub engines: Vec<String>,
+    /// Only results saved at or after this time
+    pub since: Option<DateTime<Utc>>,
+    /// Rows per file
+    pub batch_size: usize,
+}
+
+/// Progress and files of a research export
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct ResearchExportProgress {
+    #[schema(value_type = String)]
+    pub export_id: Uuid,
+    pub status: ResearchExportStatus,
+    /// Engines exported
+    #[schema(example = json!(["human-design", "numerology"]))]
+    pub engines: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub since: Option<DateTime<Utc>>,
+    pub batch_size: usize,
+    /// `noesis.schema_version` of the files
+    pub schema_version: u32,
+    /// Saved results read so far
+    pub results_read: usize,
+    /// Saved results that don't parse as a workflow result, left out
+    pub unreadable: usize,
+    pub rows_written: usize,
+    pub files: Vec<ExportedFile>,
+    /// Outputs of engines without a research schema, left out, by engine
+    pub skipped: BTreeMap<String, usize>,
+    pub started_at: DateTime<Utc>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub finished_at: Option<DateTime<Utc>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// A research export, running or finished
+pub struct ResearchExport {
+    progress: watch::Sender<ResearchExportProgress>,
+}
+
+impl ResearchExport {
+    pub fn id(&self) -> Uuid {
+        self.progress.borrow().export_id
+    }
+
+    pub fn progress(&self) -> ResearchExportProgress {
+        self.progress.borrow().clone()
+    }
+
+    pub fn is_running(&self) -> bool {
+        self.progress.borrow().status == ResearchExportStatus::Running
+    }
+
+    /// Progress updates; the current progress is marked unseen
+    pub fn subscribe(&self) -> watch::Receiver<ResearchExportProgress> {
+        let mut receiver = self.progress.subscribe();
+        receiver.mark_changed();
+        receiver
+    }
+}
+
+/// The export bucket and recent exports, at most one running
+pub struct ResearchExports {
+    store: Option<Arc<dyn ExportStore>>,
+    exports: Mutex<VecDeque<Arc<ResearchExport>>>,
+}
+
+impl ResearchExports {
+    /// Finished exports kept for their file lists, oldest dropped first
+    pub const KEPT_EXPORTS: usize = 20;
+
+    pub fn new(store: Option<Arc<dyn ExportStore>>) -> Self {
+        Self {
+            store,
+            exports: Mutex::new(VecDeque::new()),
+        }
+    }
+
+    /// The bucket from the environment (see the module docs); exports are
+    /// refused while `RESEARCH_EXPORT_S3_BUCKET` is unset
+    pub fn from_env() -> Self {
+        if std::env::var("RESEARCH_EXPORT_S3_BUCKET").is_err() {
+            return Self::new(None);
+        }
+        match S3Config::from_env_prefixed("RESEARCH_EXPORT_S3", "noesis/research") {
+            Ok(config) => Self::new(Some(Arc::new(S3Client::new(config)))),
+            Err(e) => {
+                tracing::error!(error = %e, "Research export bucket misconfigured; exports are disabled");
+                Self::new(None)
+            }
+        }
+    }
+
+    pub fn store(&self) -> Option<Arc<dyn ExportStore>> {
+        self.store.clone()
+    }
+
+    pub fn get(&self, export_id: Uuid) -> Option<Arc<ResearchExport>> {
+        self.exports
+            .lock()
+            .unwrap()
+            .iter()
+            .find(|export| export.id() == export_id)
+            .cloned()
+    }
+
+    /// Start exporting in the background to `store`. Fails with the running
+    /// export's id when there is one.
+    pub fn start(
+        &self,
+        source: Arc<dyn SavedResultSource>,
+        store: Arc<dyn ExportStore>,
+        redaction: &RedactionPolicies,
+        request: ResearchExportRequest,
+    ) -> Result<Arc<ResearchExport>, Uuid> {
+        let export = {
+            let mut exports = self.exports.lock().unwrap();
+            if let Some(running) = exports.iter().find(|export| export.is_running()) {
+                return Err(running.id());
+            }
+
+            let (progress, _) = watch::channel(ResearchExportProgress {
+                export_id: Uuid::new_v4(),
+                status: ResearchExportStatus::Running,
+                engines: request.engines,
+                since: request.since,
+                batch_size: request.batch_size.clamp(1, MAX_BATCH_SIZE),
+                schema_version: noesis_export::SCHEMA_VERSION,
+                results_read: 0,
+                unreadable: 0,
+                rows_written: 0,
+                files: Vec::new(),
+                skipped: BTreeMap::new(),
+                started_at: Utc::now(),
+                finished_at: None,
+                error: None,
+            });
+            let export = Arc::new(ResearchExport { progress });
+            exports.push_back(export.clone());
+            while exports.len() > Self::KEPT_EXPORTS {
+                match exports.iter().position(|export| !export.is_running()) {
+                    Some(oldest_finished) => drop(exports.remove(oldest_finished)),
+                    None => break,
+                }
+            }
+            export
+        };
+
+        let progress = export.progress();
+        tracing::info!(
+            export_id = %progress.export_id,
+            engines = ?progress.engines,
+            batch_size = progress.batch_size,
+            "Starting research export"
+        );
+        tokio::spawn(run(export.clone(), source, store, research_redaction(redaction)));
+        Ok(export)
+    }
+}
+
+/// The export policy, stripping even when `REDACT_EXPORT=off`: research
+/// files leave the deployment for good
+fn research_redaction(policies: &RedactionPolicies) -> RedactionPolicies {
+    let mut policies = policies.clone();
+    if policies.export == RedactionMode::Off {
+        policies.export = RedactionMode::Strip;
+    }
+    policies
+}
+
+/// Stand-in for `user_id` in one export's rows
+fn pseudonym(salt: &str, user_id: Uuid) -> String {
+    let digest = Sha256::new()
+        .chain_update(salt.as_bytes())
+        .chain_update(user_id.as_bytes())
+        .finalize();
+    hex::encode(&digest[..8])
+}
+
+async fn run(
+    export: Arc<ResearchExport>,
+    source: Arc<dyn SavedResultSource>,
+    store: Arc<dyn ExportStore>,
+    redaction: RedactionPolicies,
+) {
+    let outcome = write_files(&export, source.as_ref(), store.as_ref(), &redaction).await;
+    export.progress.send_modify(|p| {
+        p.status = match outcome {
+            Ok(()) => ResearchExportStatus::Completed,
+            Err(_) => ResearchExportStatus::Failed,
+        };
+        p.error = outcome.err();
+        p.finished_at = Some(Utc::now());
+    });
+
+    let progress = export.progress();
+    match &progress.error {
+        None => tracing::info!(
+            export_id = %progress.export_id,
+            results_read = progress.results_read,
+            rows_written = progress.rows_written,
+            files = progress.files.len(),
+            "Research export finished"
+        ),
+        Some(error) => tracing::error!(
+            export_id = %progress.export_id,
+            files = progress.files.len(),
+            error = %error,
+            "Research export failed"
+        ),
+    }
+}
+
+async fn write_files(
+    export: &ResearchExport,
+    source: &dyn SavedResultSource,
+    store: &dyn ExportStore,
+    redaction: &RedactionPolicies,
+) -> Result<(), String> {
+    let ResearchExportProgress {
+        export_id,
+        engines,
+        since,
+        batch_size,
+        ..
+    } = export.progress();
+    let salt = Uuid::new_v4().to_string();
+    let mut batches: BTreeMap<&'static str, Vec<ExportRow>> = BTreeMap::new();
+    let mut after = None;
+
+    loop {
+        let page = source.page(since, after, PAGE_SIZE).await?;
+        let Some(last) = page.last() else {
+            break;
+        };
+        after = Some((last.created_at, last.id));
+        let last_page = page.len() < PAGE_SIZE as usize;
+
+        for saved in page {
+            export.progress.send_modify(|p| p.results_read += 1);
+            let Ok(mut result) = serde_json::from_value::<WorkflowResult>(saved.result) else {
+                export.progress.send_modify(|p| p.unreadable += 1);
+                continue;
+            };
+            let input = saved
+                .input
+                .and_then(|input| serde_json::from_value::<EngineInput>(input).ok());
+            redaction.redact_workflow_result(&mut result, RedactionTarget::Export, input.as_ref());
+
+            let subject = pseudonym(&salt, saved.user_id);
+            for (engine_id, output) in result.engine_outputs {
+                let Some(schema) = engine_schema(&engine_id) else {
+                    export.progress.send_modify(|p| *p.skipped.entry(engine_id).or_default() += 1);
+                    continue;
+                };
+                if !engines.iter().any(|engine| engine == schema.engine_id) {
+                    continue;
+                }
+                let rows = batches.entry(schema.engine_id).or_default();
+                rows.push(ExportRow::new(
+                    subject.clone(),
+                    result.workflow_id.clone(),
+                    saved.created_at.date_naive(),
+                    saved.phase,
+                    output,
+                ));
+                if rows.len() >= batch_size {
+                    write_file(export, store, export_id, schema, rows).await?;
+                }
+            }
+        }
+
+        if last_page {
+            break;
+        }
+    }
+
+    for (engine_id, rows) in batches.iter_mut() {
+        if let Some(schema) = engine_schema(engine_id).filter(|_| !rows.is_empty()) {
+            write_file(export, store, export_id, schema, rows).await?;
+        }
+    }
+    Ok(())
+}
+
+/// Upload `rows` as the engine's next file and clear them
+async fn write_file(
+    export: &ResearchExport,
+    store: &dyn ExportStore,
+    export_id: Uuid,
+    schema: &EngineSchema,
+    rows: &mut Vec<ExportRow>,
+) -> Result<(), String> {
+    let part = export
+        .progress
+        .borrow()
+        .files
+        .iter()
+        .filter(|file| file.engine_id == schema.engine_id)
+        .count();
+    let key = format!("{}/{}/part-{:05}.parquet", export_id, schema.engine_id, part);
+    let body = schema
+        .to_parquet(rows)
+        .map_err(|e| format!("Writing {} rows of {}: {}", rows.len(), schema.engine_id, e))?;
+    let bytes = body.len();
+    store.put(&key, body).await.map_err(|e| e.to_string())?;
+
+    let file = ExportedFile {
+        engine_id: schema.engine_id.to_string(),
+        url: store.url(&key),
+        rows: rows.len(),
+        bytes,
+    };
+    export.progress.send_modify(|p| {
+        p.rows_written += file.rows;
+        p.files.push(file);
+    });
+    rows.clear();
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    /// Saved results served in pages, as the repository does
+    struct Saved(Vec<SavedWorkflowResult>);
+
+    #[async_trait]
+    impl SavedResultSource for Saved {
+        async fn page(
+            &self,
+            _since: Option<DateTime<Utc>>,
+            after: Option<(DateTime<Utc>, Uuid)>,
+            limit: i64,
+        ) -> Result<Vec<SavedWorkflowResult>, String> {
+            Ok(self
+                .0
+                .iter()
+                .filter(|saved| after.is_none_or(|after| (saved.created_at, saved.id) > after))
+                .take(limit as usize)
+                .cloned()
+                .collect())
+        }
+    }
+
+    #[derive(Default)]
+    struct Bucket(Mutex<BTreeMap<String, Vec<u8>>>);
+
+    #[async_trait]
+    impl ExportStore for Bucket {
+        async fn put(&self, key: &str, body: Vec<u8>) -> Result<(), EngineError> {
+            self.0.lock().unwrap().insert(key.to_string(), body);
+            Ok(())
+        }
+
+        fn url(&self, key: &str) -> String {
+            format!("mem://{}", key)
+        }
+    }
+
+    fn output(engine_id: &str, result: serde_json::Value) -> serde_json::Value {
+        json!({
+            "engine_id": engine_id,
+            "result": result,
+            "witness_prompt": "What does Ada notice?",
+            "consciousness_level": 2,
+            "metadata": {
+                "calculation_time_ms": 1.0,
+                "backend": "native",
+                "precision_achieved": "standard",
+                "cached": false,
+                "timestamp": "2026-10-15T09:00:00Z",
+                "engine_version": "1"
+            }
+        })
+    }
+
+    fn saved(user_id: Uuid, minute: i64) -> SavedWorkflowResult {
+        let result = json!({
+            "workflow_id": "birth-blueprint",
+            "engine_outputs": {
+                "numerology": output("numerology", json!({ "life_path": { "value": 7, "is_master": false } })),
+                "tarot": output("tarot", json!({ "cards": [] }))
+            },
+            "synthesis": null,
+            "total_time_ms": 2.0,
+            "timestamp": "2026-10-15T09:00:00Z"
+        });
+        SavedWorkflowResult {
+            id: Uuid::new_v4(),
+            user_id,
+            client_id: None,
+            workflow_id: "birth-blueprint".to_string(),
+            result,
+            created_at: "2026-10-15T09:00:00Z".parse::<DateTime<Utc>>().unwrap() + chrono::Duration::minutes(minute),
+            input: None,
+            phase: Some(2),
+        }
+    }
+
+    fn request(batch_size: usize) -> ResearchExportRequest {
+        ResearchExportRequest {
+            engines: vec!["numerology".to_string()],
+            since: None,
+            batch_size,
+        }
+    }
+
+    async fn finished(export: &ResearchExport) -> ResearchExportProgress {
+        let mut receiver = export.subscribe();
+        let progress = receiver
+            .wait_for(|p| p.status != ResearchExportStatus::Running)
+            .await
+            .unwrap()
+            .clone();
+        progress
+    }
+
+    #[tokio::test]
+    async fn test_export_writes_batches_per_engine() {
+        let user = Uuid::new_v4();
+        let mut results: Vec<SavedWorkflowResult> = (0..5).map(|minute| saved(user, minute)).collect();
+        results.push(SavedWorkflowResult {
+            result: json!("not a workflow result"),
+            ..saved(Uuid::new_v4(), 10)
+        });
+        let bucket = Arc::new(Bucket::default());
+        let exports = ResearchExports::new(Some(bucket.clone()));
+
+        let export = exports
+            .start(Arc::new(Saved(results)), bucket.clone(), &RedactionPolicies::default(), request(2))
+            .unwrap();
+        let progress = finished(&export).await;
+
+        assert_eq!(progress.status, ResearchExportStatus::Completed);
+        assert_eq!((progress.results_read, progress.unreadable, progress.rows_written), (6, 1, 5));
+        assert_eq!(progress.skipped["tarot"], 5);
+        let rows: Vec<usize> = progress.files.iter().map(|file| file.rows).collect();
+        assert_eq!(rows, [2, 2, 1]);
+        let keys: Vec<String> = bucket.0.lock().unwrap().keys().cloned().collect();
+        assert_eq!(keys.len(), 3);
+        assert!(keys[2].ends_with("/numerology/part-00002.parquet"));
+        assert_eq!(progress.files[0].url, format!("mem://{}", keys[0]));
+        assert!(exports.get(progress.export_id).is_some());
+    }
+
+    #[tokio::test]
+    async fn test_one_running_export() {
+        let results = (0..50).map(|minute| saved(Uuid::new_v4(), minute)).collect();
+        let bucket = Arc::new(Bucket::default());
+        let exports = ResearchExports::new(Some(bucket.clone()));
+
+        // The test runtime is single-threaded: the export cannot finish
+        // before this test awaits
+        let policies = RedactionPolicies::default();
+        let first = exports
+            .start(Arc::new(Saved(results)), bucket.clone(), &policies, request(10))
+            .unwrap();
+        let conflict = exports.start(Arc::new(Saved(Vec::new())), bucket.clone(), &policies, request(10));
+        assert_eq!(conflict.err(), Some(first.id()));
+        finished(&first).await;
+        assert!(exports.start(Arc::new(Saved(Vec::new())), bucket, &policies, request(10)).is_ok());
+    }
+
+    #[test]
+    fn test_subjects_are_pseudonymous_per_export() {
+        let user = Uuid::new_v4();
+        assert_eq!(pseudonym("a", user), pseudonym("a", user));
+        assert_ne!(pseudonym("a", user), pseudonym("b", user));
+        assert!(!pseudonym("a", user).contains(&user.simple().to_string()));
+
+        assert_eq!(research_redaction(&RedactionPolicies::disabled()).export, RedactionMode::Strip);
+        let hashed = RedactionPolicies {
+            export: RedactionMode::Hash,
+            ..RedactionPolicies::default()
+        };
+        assert_eq!(research_redaction(&hashed).export, RedactionMode::Hash);
+    }
+}
@@ -0,0 +1,74 @@
+//! Background purge of data past its retention period
+//!
+//! Each data class (saved calculation history, practice journal entries,
+//! usage audit logs and practitioners' guest client profiles) can be given a
+//! TTL in days through the `RETENTION_*_DAYS` variables. This job deletes the
+//! rows older than that on every run, or in dry-run mode only counts them, so
+//! the effect of a new policy can be checked in the metrics before it deletes
+//! anything. Classes without a TTL are never touched.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use chrono::Utc;
+use noesis_data::repositories::retention_repository::{DataClass, RetentionPolicies, RetentionRepository};
+use tokio::task::JoinHandle;
+use tokio::time::MissedTickBehavior;
+
+/// Purge every class with a TTL once. Returns the rows purged, or counted in
+/// dry-run mode, per class; classes whose query failed are left out.
+pub async fn run_retention(retention: &RetentionRepository, policies: &RetentionPolicies) -> Vec<(DataClass, u64)> {
+    let now = Utc::now();
+    let mut purged = Vec::new();
+    for class in DataClass::ALL {
+        let Some(cutoff) = policies.cutoff(class, now) else {
+            continue;
+        };
+        let rows = if policies.dry_run {
+            retention.count_expired(class, cutoff).await
+        } else {
+            retention.purge_expired(class, cutoff).await
+        };
+        match rows {
+            Ok(rows) => {
+                noesis_metrics::record_retention(class.as_str(), rows, policies.dry_run);
+                if policies.dry_run {
+                    tracing::info!(data_class = %class, rows, %cutoff, "Rows past retention, not purged in dry run");
+                } else if rows > 0 {
+                    tracing::info!(data_class = %class, rows, %cutoff, "Purged rows past retention");
+                }
+                purged.push((class, rows));
+            }
+            Err(e) => {
+                noesis_metrics::record_retention_error(class.as_str());
+                tracing::warn!(data_class = %class, error = %e, "Retention purge failed");
+            }
+        }
+    }
+    purged
+}
+
+/// Apply `policies` now and then every `interval`. Does nothing when no
+/// class has a TTL.
+pub fn spawn_retention_job(
+    retention: Arc<RetentionRepository>,
+    policies: RetentionPolicies,
+    interval: Duration,
+) -> Option<JoinHandle<()>> {
+    if policies.ttl_days.is_empty() {
+        return None;
+    }
+    tracing::info!(
+        policies = ?policies.ttl_days,
+        dry_run = policies.dry_run,
+        "Retention policies active"
+    );
+    Some(tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(interval);
+        ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+        loop {
+            ticker.tick().await;
+            run_retention(&retention, &policies).await;
+        }
+    }))
+}
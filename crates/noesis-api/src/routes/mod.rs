@@ -0,0 +1,231 @@
+//! Routers of the versioned `/api/{version}` surface
+//!
+//! Each [`ApiVersion`] has a module building its router, starting from the
+//! [`shared`] route table, so a version can add, drop or replace a route
+//! without touching the others. The rate limiter, load shedder and
+//! authentication in [`ApiLayers`] are built once and shared by every
+//! version, so one quota covers a client's calls to all of them.
+
+mod v1;
+mod v2;
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use axum::{
+    middleware as axum_middleware,
+    routing::{delete, get, patch, post, put},
+    Router,
+};
+use noesis_auth::AuthService;
+
+use crate::config::ApiConfig;
+use crate::versioning::{self, ApiVersion};
+use crate::{database, handlers, middleware, AppState};
+use crate::{
+    calculate_handler, engine_examples_handler, engine_info_handler, engines_health_handler, geo_search_handler,
+    list_engines_handler, list_workflows_handler, public_status_handler, status_handler, validate_handler,
+    validation_report_handler, workflow_execute_handler, workflow_info_handler,
+};
+
+/// Middleware state shared by the routers of every version
+pub(crate) struct ApiLayers {
+    rate_limiter: Arc<middleware::RateLimiter>,
+    load_shedder: Arc<middleware::LoadShedder>,
+    concurrency_limiter: Arc<middleware::ConcurrencyLimiter>,
+    auth: Arc<AuthService>,
+    database: Arc<database::DatabaseStatus>,
+}
+
+impl ApiLayers {
+    pub(crate) fn new(state: &AppState, config: &ApiConfig) -> Self {
+        // Free-tier calculations are turned away first when the server is overloaded
+        let load_shedder = Arc::new(middleware::LoadShedder::new(
+            config.load_shed_max_in_flight,
+            Duration::from_millis(config.load_shed_max_lag_ms),
+        ));
+        load_shedder.start_lag_probe();
+
+        // Each user runs at most `max_concurrent_calculations` of their tier at once
+        let concurrency_limiter = Arc::new(middleware::ConcurrencyLimiter::new());
+        concurrency_limiter.start_cleanup();
+
+        Self {
+            rate_limiter: Arc::new(middleware::RateLimiter::new_with_config(
+                config.rate_limit_requests,
+                config.rate_limit_window_secs,
+            )),
+            load_shedder,
+            concurrency_limiter,
+            auth: state.core.auth.clone(),
+            database: state.database.clone(),
+        }
+    }
+}
+
+/// Router for `version`, to nest under its [`path_prefix`](ApiVersion::path_prefix).
+///
+/// Every response carries the version's lifecycle headers; a version
+/// disabled by `API_DISABLED_VERSIONS` answers 410 Gone to everything.
+pub(crate) fn router(version: ApiVersion, layers: &ApiLayers, enabled: bool) -> Router<AppState> {
+    let routes = match (enabled, version) {
+        (false, _) => Router::new().fallback_service(
+            Router::new()
+                .fallback(versioning::disabled_version_handler)
+                .with_state(version),
+        ),
+        (true, ApiVersion::V1) => v1::router(layers),
+        (true, ApiVersion::V2) => v2::router(layers),
+    };
+    routes.layer(axum_middleware::from_fn_with_state(version, versioning::version_middleware))
+}
+
+/// The routes every version has served unchanged so far
+fn shared(layers: &ApiLayers) -> Router<AppState> {
+    // Routes backed by Postgres answer 503 while the database is unavailable
+    let require_database = || {
+        axum_middleware::from_fn_with_state(layers.database.clone(), database::require_database)
+    };
+
+    let auth_routes = Router::new()
+         .route("/auth/register", post(handlers::auth::register))
+         .route("/auth/login", post(handlers::auth::login))
+         .route("/auth/forgot-password", post(handlers::auth::forgot_password))
+         .route("/auth/reset-password", post(handlers::auth::reset_password))
+         .route("/auth/restore", post(handlers::auth::restore_account))
+         .route_layer(require_database());
+
+    // Share links are the credential, so they bypass auth
+    let shared_routes = Router::new()
+        .route("/shared/:token", get(handlers::results::get_shared_result))
+        .route_layer(require_database());
+
+    // The status page is public and serves a snapshot, so it skips auth and quotas
+    let public_routes = Router::new().route("/status/public", get(public_status_handler));
+
+    let database_routes = Router::new()
+        .route(
+            "/users/me",
+            get(handlers::users::get_me)
+                .patch(handlers::users::update_me)
+                .delete(handlers::users::delete_me),
+        )
+        .route(
+            "/users/me/locations",
+            get(handlers::locations::list_locations).post(handlers::locations::create_location),
+        )
+        .route("/users/me/locations/:id", delete(handlers::locations::delete_location))
+        .route(
+            "/users/me/api-keys",
+            get(handlers::api_keys::list_api_keys).post(handlers::api_keys::create_api_key),
+        )
+        .route("/users/me/api-keys/:id", delete(handlers::api_keys::revoke_api_key))
+        .route("/users/me/api-keys/:id/scopes", put(handlers::api_keys::update_api_key_scopes))
+        .route(
+            "/users/me/notification-rules",
+            get(handlers::notifications::list_rules).post(handlers::notifications::create_rule),
+        )
+        .route("/users/me/notification-rules/:id", delete(handlers::notifications::delete_rule))
+        .route("/users/me/notifications", get(handlers::notifications::list_notifications))
+        .route("/users/me/offline-bundle", get(handlers::offline::get_offline_bundle))
+        .route(
+            "/users/me/practices",
+            get(handlers::practices::list_practices).post(handlers::practices::log_practice),
+        )
+        .route(
+            "/users/me/settings",
+            get(handlers::settings::get_settings).put(handlers::settings::put_settings),
+        )
+        .route("/organizations", post(handlers::organizations::create_organization))
+        .route("/organizations/:id/members", post(handlers::organizations::add_member))
+        .route("/clients", get(handlers::clients::list_clients).post(handlers::clients::create_client))
+        .route("/clients/:id", get(handlers::clients::get_client))
+        .route("/clients/:id/consent", patch(handlers::clients::update_consent))
+        .route(
+            "/clients/:id/workflows/:workflow_id/execute",
+            post(handlers::clients::execute_client_workflow),
+        )
+        .route("/results/:id", get(handlers::results::get_result))
+        .route("/results/:id/annotations", post(handlers::results::create_annotation))
+        .route(
+            "/results/:id/annotations/:annotation_id",
+            delete(handlers::results::delete_annotation),
+        )
+        .route("/results/:id/share", post(handlers::results::share_result))
+        .route("/admin/analytics/human-design", get(handlers::analytics::human_design_distribution))
+        .route("/admin/results/:id/replay", post(handlers::results::replay_result))
+        .route("/admin/recalculate", post(handlers::recalculate::start_recalculation))
+        .route("/admin/recalculate/:job_id/retry", post(handlers::recalculate::retry_recalculation))
+        .route("/admin/research-exports", post(handlers::research_export::start_research_export))
+        .route_layer(require_database());
+
+    Router::new()
+        .route("/status", get(status_handler))
+        .route("/engines", get(list_engines_handler))
+        .route("/engines/health", get(engines_health_handler))
+        .route("/engines/:engine_id/calculate", post(calculate_handler))
+        .route("/engines/:engine_id/validate", post(validate_handler))
+        .route("/engines/:engine_id/info", get(engine_info_handler))
+        .route("/engines/:engine_id/examples", get(engine_examples_handler))
+        .route("/workflows", get(list_workflows_handler))
+        .route(
+            "/workflows/:workflow_id/execute",
+            post(workflow_execute_handler),
+        )
+        .route("/workflows/:workflow_id/info", get(workflow_info_handler))
+        .route("/geo/search", get(geo_search_handler))
+        .route("/ephemeris/positions", post(handlers::ephemeris::calculate_positions))
+        .route("/ephemeris/stations", get(handlers::ephemeris::list_stations))
+        .route("/ephemeris/gate-transits", get(handlers::ephemeris::list_gate_transits))
+        .route("/planner/suggest", post(handlers::planner::suggest))
+        .route("/wisdom/search", get(handlers::wisdom::search))
+        .route("/wisdom/export", get(handlers::wisdom::export))
+        .route("/wisdom/witness-packs", get(handlers::wisdom::list_witness_packs))
+        .route(
+            "/wisdom/witness-packs/:id",
+            put(handlers::wisdom::put_witness_pack).delete(handlers::wisdom::delete_witness_pack),
+        )
+        .route("/experiments/:id/outcomes", post(handlers::experiments::record_outcome))
+        .route("/admin/validation/report", get(validation_report_handler))
+        .route("/admin/experiments", get(handlers::experiments::list_experiments))
+        .route(
+            "/admin/experiments/:id",
+            put(handlers::experiments::put_experiment).delete(handlers::experiments::delete_experiment),
+        )
+        .route(
+            "/admin/workflows/:id",
+            put(handlers::workflows::put_workflow).delete(handlers::workflows::delete_workflow),
+        )
+        .route("/admin/cache/stats", get(handlers::cache::cache_stats))
+        .route(
+            "/admin/cache/engines/:engine_id/versions/:version",
+            delete(handlers::cache::invalidate_engine_version),
+        )
+        .route("/admin/recalculate/:job_id", get(handlers::recalculate::get_recalculation))
+        .route(
+            "/admin/research-exports/:export_id",
+            get(handlers::research_export::get_research_export),
+        )
+        .merge(database_routes)
+        // Layers are applied bottom-to-top: auth, then load shedding, then
+        // rate_limit, then concurrency limiting
+        .layer(axum_middleware::from_fn_with_state(
+            layers.concurrency_limiter.clone(),
+            middleware::concurrency_limit_middleware,
+        ))
+        .layer(axum_middleware::from_fn_with_state(
+            layers.rate_limiter.clone(),
+            middleware::rate_limit_middleware,
+        ))
+        .layer(axum_middleware::from_fn_with_state(
+            layers.load_shedder.clone(),
+            middleware::load_shedding_middleware,
+        ))
+        .layer(axum_middleware::from_fn_with_state(
+            layers.auth.clone(),
+            middleware::auth_middleware,
+        ))
+        .merge(auth_routes)
+        .merge(shared_routes)
+        .merge(public_routes)
+}
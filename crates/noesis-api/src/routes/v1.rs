@@ -0,0 +1,11 @@
+//! `/api/v1`: the original surface, deprecated in favour of v2 (see
+//! [`API_VERSIONS`](crate::versioning::API_VERSIONS))
+
+use axum::Router;
+
+use super::ApiLayers;
+use crate::AppState;
+
+pub(super) fn router(layers: &ApiLayers) -> Router<AppState> {
+    super::shared(layers)
+}
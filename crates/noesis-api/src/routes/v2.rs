@@ -0,0 +1,11 @@
+//! `/api/v2`: the current surface. Errors are always problem documents;
+//! routes whose bodies change in v2 are swapped in here as the changes land.
+
+use axum::Router;
+
+use super::ApiLayers;
+use crate::AppState;
+
+pub(super) fn router(layers: &ApiLayers) -> Router<AppState> {
+    super::shared(layers)
+}
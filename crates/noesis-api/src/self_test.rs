@@ -0,0 +1,250 @@
+//! Deployment self-test (`noesis-server --self-test`)
+//!
+//! Runs a fixed birth input through every registered engine, round-trips a
+//! value through the cache, pings the database and, when `TS_ENGINES_URL` is
+//! set, the TypeScript engine server. Meant for Kubernetes init containers
+//! and pre-rollout jobs: a broken ephemeris path, missing wisdom data or an
+//! unreachable dependency fails the deployment instead of the first users'
+//! requests. Each check is bounded by [`SELF_TEST_CHECK_TIMEOUT`].
+
+use std::fmt;
+use std::future::Future;
+use std::time::{Duration, Instant};
+
+use noesis_bridge::BridgeManager;
+use noesis_cache::CacheKey;
+use noesis_core::{EngineError, EngineInput};
+use serde_json::json;
+
+use crate::AppState;
+
+/// Longest any one check may take before it fails
+pub const SELF_TEST_CHECK_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Outcome of one check
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CheckStatus {
+    Pass,
+    Fail,
+    /// Not applicable to this deployment, e.g. bridge checks without a bridge
+    Skip,
+}
+
+impl CheckStatus {
+    fn as_str(self) -> &'static str {
+        match self {
+            CheckStatus::Pass => "PASS",
+            CheckStatus::Fail => "FAIL",
+            CheckStatus::Skip => "SKIP",
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct CheckResult {
+    pub name: String,
+    pub status: CheckStatus,
+    pub detail: String,
+    pub elapsed: Duration,
+}
+
+/// Results of every check, printed as a table
+#[derive(Debug, Clone, Default)]
+pub struct SelfTestReport {
+    pub checks: Vec<CheckResult>,
+}
+
+impl SelfTestReport {
+    /// Whether no check failed; skipped checks don't count against it
+    pub fn passed(&self) -> bool {
+        self.checks.iter().all(|check| check.status != CheckStatus::Fail)
+    }
+
+    /// Process exit code: 0 when every check passed, 1 otherwise
+    pub fn exit_code(&self) -> i32 {
+        if self.passed() {
+            0
+        } else {
+            1
+        }
+    }
+}
+
+impl fmt::Display for SelfTestReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let width = self
+            .checks
+            .iter()
+            .map(|check| check.name.len())
+            .chain(["CHECK".len()])
+            .max()
+            .unwrap_or(0);
+        writeln!(f, "{:<width$}  {:<6}  {:>8}  DETAIL", "CHECK", "STATUS", "MS")?;
+        for check in &self.checks {
+            writeln!(
+                f,
+                "{:<width$}  {:<6}  {:>8.1}  {}",
+                check.name,
+                check.status.as_str(),
+                check.elapsed.as_secs_f64() * 1000.0,
+                check.detail
+            )?;
+        }
+        let failed = self.checks.iter().filter(|check| check.status == CheckStatus::Fail).count();
+        if failed == 0 {
+            write!(f, "Self-test passed ({} checks)", self.checks.len())
+        } else {
+            write!(f, "Self-test FAILED: {} of {} checks failed", failed, self.checks.len())
+        }
+    }
+}
+
+/// Birth data every engine can calculate from, at a fixed moment
+pub fn self_test_input() -> EngineInput {
+    serde_json::from_value(json!({
+        "birth_data": {
+            "name": "Self Test",
+            "date": "1990-01-15",
+            "time": "14:30",
+            "latitude": 12.9716,
+            "longitude": 77.5946,
+            "timezone": "Asia/Kolkata"
+        },
+        "current_time": "2024-03-20T12:00:00Z",
+        "location": { "latitude": 12.9716, "longitude": 77.5946 },
+        "precision": "Standard"
+    }))
+    .expect("self-test input is valid")
+}
+
+/// Run every check in order: engines by ID, then cache, database and bridge.
+pub async fn run_self_test(state: &AppState) -> SelfTestReport {
+    let mut report = SelfTestReport::default();
+
+    let mut engine_ids = state.core.orchestrator.list_engines();
+    engine_ids.sort();
+    for engine_id in engine_ids {
+        report.checks.push(check(format!("engine:{}", engine_id), check_engine(state, &engine_id)).await);
+    }
+    report.checks.push(check("cache".to_string(), check_cache(state)).await);
+    report.checks.push(check("database".to_string(), check_database(state)).await);
+
+    match std::env::var("TS_ENGINES_URL") {
+        Ok(_) => report.checks.push(check("bridge".to_string(), check_bridge()).await),
+        Err(_) => report.checks.push(CheckResult {
+            name: "bridge".to_string(),
+            status: CheckStatus::Skip,
+            detail: "TS_ENGINES_URL not set".to_string(),
+            elapsed: Duration::ZERO,
+        }),
+    }
+
+    report
+}
+
+async fn check(name: String, run: impl Future<Output = Result<String, String>>) -> CheckResult {
+    let started = Instant::now();
+    let (status, detail) = match tokio::time::timeout(SELF_TEST_CHECK_TIMEOUT, run).await {
+        Ok(Ok(detail)) => (CheckStatus::Pass, detail),
+        Ok(Err(detail)) => (CheckStatus::Fail, detail),
+        Err(_) => (
+            CheckStatus::Fail,
+            format!("timed out after {}s", SELF_TEST_CHECK_TIMEOUT.as_secs()),
+        ),
+    };
+    CheckResult {
+        name,
+        status,
+        detail,
+        elapsed: started.elapsed(),
+    }
+}
+
+/// Calculate directly with the engine, so a cached result can't hide a
+/// broken one
+async fn check_engine(state: &AppState, engine_id: &str) -> Result<String, String> {
+    let engine = state
+        .core
+        .orchestrator
+        .registry()
+        .get(engine_id)
+        .ok_or_else(|| "not registered".to_string())?;
+    let output = engine.calculate(self_test_input()).await.map_err(|e| e.to_string())?;
+    if output.engine_id != engine_id {
+        return Err(format!("output claims engine '{}'", output.engine_id));
+    }
+    let validation = engine.validate(&output).await.map_err(|e| e.to_string())?;
+    if !validation.valid {
+        return Err(format!("output failed validation: {}", validation.messages.join("; ")));
+    }
+    Ok(format!("version {}", engine.engine_version()))
+}
+
+async fn check_cache(state: &AppState) -> Result<String, String> {
+    let cache = &state.core.cache;
+    let key = CacheKey::new(format!("self-test:{}", uuid::Uuid::new_v4()));
+    let value = json!({ "self_test": true });
+
+    cache.store(&key, &value).await.map_err(|e| format!("store failed: {}", e))?;
+    let read = cache.get_with_layer(&key).await;
+    let _ = cache.invalidate(&key).await;
+    match read.map_err(|e| format!("lookup failed: {}", e))? {
+        Some((read, layer)) if read == value => Ok(format!("round trip via {}", layer)),
+        Some(_) => Err("read back a different value".to_string()),
+        None => Err("stored value was not found".to_string()),
+    }
+}
+
+async fn check_database(state: &AppState) -> Result<String, String> {
+    sqlx::query("SELECT 1")
+        .execute(&state.db_pools.primary)
+        .await
+        .map_err(|e| format!("primary: {}", e))?;
+    if let Some(replica) = &state.db_pools.replica {
+        sqlx::query("SELECT 1")
+            .execute(replica)
+            .await
+            .map_err(|e| format!("replica: {}", e))?;
+        return Ok("primary and replica reachable".to_string());
+    }
+    Ok("primary reachable".to_string())
+}
+
+async fn check_bridge() -> Result<String, String> {
+    let manager = BridgeManager::from_env();
+    manager
+        .health_check()
+        .await
+        .map_err(|e: EngineError| e.to_string())?;
+    Ok(format!("{} reachable", manager.base_url()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn result(name: &str, status: CheckStatus) -> CheckResult {
+        CheckResult {
+            name: name.to_string(),
+            status,
+            detail: String::new(),
+            elapsed: Duration::from_millis(3),
+        }
+    }
+
+    #[test]
+    fn test_skipped_checks_do_not_fail_the_report() {
+        let mut report = SelfTestReport {
+            checks: vec![result("engine:numerology", CheckStatus::Pass), result("bridge", CheckStatus::Skip)],
+        };
+        assert!(report.passed());
+        assert_eq!(report.exit_code(), 0);
+        assert!(report.to_string().ends_with("Self-test passed (2 checks)"));
+
+        report.checks.push(result("database", CheckStatus::Fail));
+        assert_eq!(report.exit_code(), 1);
+        let table = report.to_string();
+        assert!(table.lines().any(|line| line.starts_with("database") && line.contains("FAIL")));
+        assert!(table.ends_with("Self-test FAILED: 1 of 3 checks failed"));
+    }
+}
@@ -0,0 +1,261 @@
+//! Per-user calculation settings
+//!
+//! Users save the options they always want through
+//! `PUT /api/v1/users/me/settings` instead of resending them with every
+//! request. Before a calculation or workflow runs, the saved settings are put
+//! under `options.settings` ([`SETTINGS_OPTION`]); the orchestrator hands each
+//! engine the entries it reads and the request doesn't set itself, so
+//! explicit options always win and engines that don't read a setting never
+//! warn about it.
+//!
+//! Settings are looked up on every calculation, so [`SettingsStore`] keeps
+//! them in memory for [`SETTINGS_CACHE_TTL`]; writes through another instance
+//! show up within that time. A lookup that fails or outlasts
+//! [`SETTINGS_LOOKUP_TIMEOUT`] is logged and the request runs without
+//! settings, as it would for a user who saved none.
+
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use dashmap::DashMap;
+use engine_human_design::DesignMethod;
+use engine_panchanga::Ayanamsa;
+use noesis_core::{EngineError, EngineInput, ValidationCode, ValidationError, SETTINGS_OPTION};
+use noesis_data::repositories::settings_repository::SettingsRepository;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+/// How long looked-up settings are reused before the database is read again
+pub const SETTINGS_CACHE_TTL: Duration = Duration::from_secs(60);
+
+/// Longest a calculation waits for the caller's settings
+pub const SETTINGS_LOOKUP_TIMEOUT: Duration = Duration::from_millis(500);
+
+/// Highest `consciousness_level` engines write witness prompts for
+const MAX_CONSCIOUSNESS_LEVEL: u64 = 5;
+
+/// Engine options a user applies to every calculation; all optional
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, ToSchema)]
+#[serde(default)]
+pub struct UserSettings {
+    /// Sidereal reference for panchanga: tropical, lahiri, raman, krishnamurti or fagan_bradley
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(example = "lahiri")]
+    pub ayanamsa: Option<String>,
+    /// Human Design Design placement: arc_88deg or days_88
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(example = "days_88")]
+    pub design_method: Option<String>,
+    /// Depth witness prompts are written for (0-5)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(example = 3, minimum = 0, maximum = 5)]
+    pub consciousness_level: Option<u8>,
+}
+
+impl UserSettings {
+    /// Settings that can be saved, by their option names
+    pub const KEYS: [&'static str; 3] = ["ayanamsa", "design_method", "consciousness_level"];
+
+    /// Validate a request body, normalizing values to the names engines report.
+    ///
+    /// Null or absent settings are cleared. Unknown names are rejected rather
+    /// than stored, so a typo doesn't silently do nothing on every request.
+    pub fn from_json(body: Map<String, Value>) -> Result<Self, EngineError> {
+        let mut settings = Self::default();
+        let mut errors = Vec::new();
+        for (key, value) in body {
+            if value.is_null() {
+                if !Self::KEYS.contains(&key.as_str()) {
+                    errors.push(unknown_setting(key));
+                }
+                continue;
+            }
+            match key.as_str() {
+                "ayanamsa" => match value.as_str().and_then(Ayanamsa::parse) {
+                    Some(ayanamsa) => {
+                        settings.ayanamsa = serde_json::to_value(ayanamsa)
+                            .ok()
+                            .and_then(|name| name.as_str().map(str::to_string))
+                    }
+                    None => errors.push(ValidationError::new(
+                        key,
+                        ValidationCode::Unknown,
+                        format!(
+                            "Unknown ayanamsa {}. Expected tropical, lahiri, raman, krishnamurti or fagan_bradley.",
+                            value
+                        ),
+                    )),
+                },
+                "design_method" => match value.as_str().and_then(DesignMethod::parse) {
+                    Some(method) => settings.design_method = Some(method.as_str().to_string()),
+                    None => errors.push(ValidationError::new(
+                        key,
+                        ValidationCode::Unknown,
+                        format!("Unknown design method {}. Expected arc_88deg or days_88.", value),
+                    )),
+                },
+                "consciousness_level" => match value.as_u64().filter(|level| *level <= MAX_CONSCIOUSNESS_LEVEL) {
+                    Some(level) => settings.consciousness_level = Some(level as u8),
+                    None => errors.push(ValidationError::new(
+                        key,
+                        ValidationCode::OutOfRange,
+                        format!("Consciousness level must be an integer from 0 to {}", MAX_CONSCIOUSNESS_LEVEL),
+                    )),
+                },
+                _ => errors.push(unknown_setting(key)),
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(settings)
+        } else {
+            Err(EngineError::InvalidInput(errors))
+        }
+    }
+
+    /// The settings as engine options, leaving out unset ones
+    pub fn to_options(&self) -> Map<String, Value> {
+        match serde_json::to_value(self) {
+            Ok(Value::Object(options)) => options,
+            _ => Map::new(),
+        }
+    }
+}
+
+fn unknown_setting(key: String) -> ValidationError {
+    let message = format!("Unknown setting '{}'; expected one of: {}", key, UserSettings::KEYS.join(", "));
+    ValidationError::new(key, ValidationCode::Unknown, message)
+}
+
+/// Put `saved` under `options.settings`; entries the request already sends
+/// there win, as do the options it sets directly
+pub fn apply_saved(input: &mut EngineInput, saved: &Map<String, Value>) {
+    if saved.is_empty() {
+        return;
+    }
+    let mut settings = saved.clone();
+    if let Some(Value::Object(sent)) = input.options.get(SETTINGS_OPTION) {
+        settings.extend(sent.clone());
+    }
+    input.options.insert(SETTINGS_OPTION.to_string(), Value::Object(settings));
+}
+
+/// Saved settings, with an in-memory cache in front of the database
+pub struct SettingsStore {
+    repository: SettingsRepository,
+    cache: DashMap<Uuid, (Instant, Arc<Map<String, Value>>)>,
+}
+
+impl SettingsStore {
+    pub fn new(repository: SettingsRepository) -> Self {
+        Self {
+            repository,
+            cache: DashMap::new(),
+        }
+    }
+
+    /// The user's saved settings, read from the database
+    pub async fn get(&self, user_id: Uuid) -> Result<UserSettings, sqlx::Error> {
+        let settings = self
+            .repository
+            .get(user_id)
+            .await?
+            .and_then(|row| serde_json::from_value(row.settings).ok())
+            .unwrap_or_default();
+        self.remember(user_id, &settings);
+        Ok(settings)
+    }
+
+    /// Replace the user's saved settings
+    pub async fn put(&self, user_id: Uuid, settings: &UserSettings) -> Result<UserSettings, sqlx::Error> {
+        let row = self
+            .repository
+            .upsert(user_id, &Value::Object(settings.to_options()))
+            .await?;
+        let saved = serde_json::from_value(row.settings).unwrap_or_default();
+        self.remember(user_id, &saved);
+        Ok(saved)
+    }
+
+    /// Saved settings as engine options, empty when the lookup fails
+    pub async fn options_for(&self, user_id: Uuid) -> Arc<Map<String, Value>> {
+        if let Some(entry) = self.cache.get(&user_id) {
+            let (cached_at, options) = entry.value();
+            if cached_at.elapsed() < SETTINGS_CACHE_TTL {
+                return options.clone();
+            }
+        }
+
+        match tokio::time::timeout(SETTINGS_LOOKUP_TIMEOUT, self.get(user_id)).await {
+            Ok(Ok(settings)) => Arc::new(settings.to_options()),
+            Ok(Err(e)) => {
+                tracing::warn!(%user_id, error = %e, "Saved settings lookup failed; calculating without them");
+                Arc::default()
+            }
+            Err(_) => {
+                tracing::warn!(%user_id, "Saved settings lookup timed out; calculating without them");
+                Arc::default()
+            }
+        }
+    }
+
+    fn remember(&self, user_id: Uuid, settings: &UserSettings) {
+        self.cache
+            .insert(user_id, (Instant::now(), Arc::new(settings.to_options())));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn body(value: Value) -> Map<String, Value> {
+        value.as_object().cloned().unwrap()
+    }
+
+    #[test]
+    fn test_from_json_normalizes_values() {
+        let settings =
+            UserSettings::from_json(body(json!({"ayanamsa": "KP", "design_method": "days-88", "consciousness_level": 2})))
+                .unwrap();
+        assert_eq!(
+            settings.to_options(),
+            body(json!({"ayanamsa": "krishnamurti", "design_method": "days_88", "consciousness_level": 2}))
+        );
+        assert_eq!(UserSettings::from_json(body(json!({"ayanamsa": null}))).unwrap(), UserSettings::default());
+    }
+
+    #[test]
+    fn test_from_json_names_every_invalid_setting() {
+        let err = UserSettings::from_json(body(json!({
+            "ayanamsa": "galactic",
+            "consciousness_level": 9,
+            "house_system": "placidus"
+        })))
+        .unwrap_err();
+        let EngineError::InvalidInput(errors) = err else {
+            panic!("expected InvalidInput");
+        };
+        let mut fields: Vec<&str> = errors.iter().map(|e| e.field.as_str()).collect();
+        fields.sort_unstable();
+        assert_eq!(fields, ["ayanamsa", "consciousness_level", "house_system"]);
+    }
+
+    #[test]
+    fn test_apply_saved_keeps_settings_sent_with_the_request() {
+        let mut input: EngineInput = serde_json::from_value(json!({
+            "current_time": "2026-10-15T00:00:00Z",
+            "options": {"settings": {"ayanamsa": "raman"}}
+        }))
+        .unwrap();
+        apply_saved(&mut input, &body(json!({"ayanamsa": "lahiri", "design_method": "days_88"})));
+
+        assert_eq!(
+            input.options[SETTINGS_OPTION],
+            json!({"ayanamsa": "raman", "design_method": "days_88"})
+        );
+    }
+}
@@ -0,0 +1,151 @@
+//! Signed share links for saved workflow results
+//!
+//! A share token is `<share_id>.<expires_at>.<signature>`, where `expires_at`
+//! is a Unix timestamp and the signature is a hex HMAC-SHA256 of the first two
+//! parts keyed with the server secret. Forged, altered and expired tokens are
+//! rejected before the database is consulted; the `workflow_result_shares`
+//! row is still required so a link can be revoked by deleting it.
+
+use chrono::{DateTime, Duration, TimeZone, Utc};
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+use uuid::Uuid;
+
+type HmacSha256 = Hmac<Sha256>;
+
+/// Separates share signatures from any other use of the same secret
+const SIGNING_CONTEXT: &[u8] = b"noesis-result-share:v1:";
+
+/// Why a share token was rejected
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ShareTokenError {
+    /// Not three dot-separated parts, or a part fails to parse
+    Malformed,
+    InvalidSignature,
+    Expired,
+}
+
+/// Issues and verifies share tokens
+pub struct ShareLinks {
+    secret: Vec<u8>,
+    ttl: Duration,
+    public_base_url: Option<String>,
+}
+
+impl ShareLinks {
+    /// # Arguments
+    /// * `secret` - Signing key, the JWT secret in production
+    /// * `ttl_secs` - Default and maximum link lifetime
+    /// * `public_base_url` - Prefix for absolute URLs, relative paths when `None`
+    pub fn new(secret: &str, ttl_secs: u64, public_base_url: Option<String>) -> Self {
+        Self {
+            secret: secret.as_bytes().to_vec(),
+            ttl: Duration::seconds(ttl_secs.min(i64::MAX as u64) as i64),
+            public_base_url: public_base_url.map(|url| url.trim_end_matches('/').to_string()),
+        }
+    }
+
+    /// Longest lifetime a link may be issued with
+    pub fn max_ttl(&self) -> Duration {
+        self.ttl
+    }
+
+    pub fn sign(&self, share_id: Uuid, expires_at: DateTime<Utc>) -> String {
+        let payload = format!("{}.{}", share_id, expires_at.timestamp());
+        let signature = hex::encode(self.mac(&payload).finalize().into_bytes());
+        format!("{}.{}", payload, signature)
+    }
+
+    /// Check `token` and return the share id it names
+    pub fn verify(&self, token: &str, now: DateTime<Utc>) -> Result<Uuid, ShareTokenError> {
+        let mut parts = token.split('.');
+        let (Some(id), Some(expires), Some(signature), None) =
+            (parts.next(), parts.next(), parts.next(), parts.next())
+        else {
+            return Err(ShareTokenError::Malformed);
+        };
+
+        let share_id = Uuid::parse_str(id).map_err(|_| ShareTokenError::Malformed)?;
+        let expires_at = expires
+            .parse::<i64>()
+            .ok()
+            .and_then(|ts| Utc.timestamp_opt(ts, 0).single())
+            .ok_or(ShareTokenError::Malformed)?;
+        let signature = hex::decode(signature).map_err(|_| ShareTokenError::Malformed)?;
+
+        self.mac(&format!("{}.{}", id, expires))
+            .verify_slice(&signature)
+            .map_err(|_| ShareTokenError::InvalidSignature)?;
+
+        if expires_at <= now {
+            return Err(ShareTokenError::Expired);
+        }
+        Ok(share_id)
+    }
+
+    /// Public URL of the read-only view for `token`
+    pub fn url(&self, token: &str) -> String {
+        format!(
+            "{}/api/v1/shared/{}",
+            self.public_base_url.as_deref().unwrap_or(""),
+            token
+        )
+    }
+
+    fn mac(&self, payload: &str) -> HmacSha256 {
+        let mut mac = HmacSha256::new_from_slice(&self.secret).expect("HMAC accepts keys of any length");
+        mac.update(SIGNING_CONTEXT);
+        mac.update(payload.as_bytes());
+        mac
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn links() -> ShareLinks {
+        ShareLinks::new("test-secret-at-least-32-chars-long", 3600, None)
+    }
+
+    #[test]
+    fn test_sign_and_verify_round_trip() {
+        let links = links();
+        let id = Uuid::new_v4();
+        let token = links.sign(id, Utc::now() + Duration::hours(1));
+        assert_eq!(links.verify(&token, Utc::now()), Ok(id));
+    }
+
+    #[test]
+    fn test_verify_rejects_tampering() {
+        let links = links();
+        let expires = Utc::now() + Duration::hours(1);
+        let token = links.sign(Uuid::new_v4(), expires);
+
+        // Extending the expiry invalidates the signature
+        let (payload, signature) = token.rsplit_once('.').unwrap();
+        let (id, _) = payload.split_once('.').unwrap();
+        let extended = format!("{}.{}.{}", id, expires.timestamp() + 86_400, signature);
+        assert_eq!(links.verify(&extended, Utc::now()), Err(ShareTokenError::InvalidSignature));
+
+        let other_key = ShareLinks::new("another-secret-at-least-32-chars", 3600, None);
+        assert_eq!(other_key.verify(&token, Utc::now()), Err(ShareTokenError::InvalidSignature));
+
+        assert_eq!(links.verify("not-a-token", Utc::now()), Err(ShareTokenError::Malformed));
+        assert_eq!(links.verify(&format!("{}.extra", token), Utc::now()), Err(ShareTokenError::Malformed));
+    }
+
+    #[test]
+    fn test_verify_rejects_expired() {
+        let links = links();
+        let token = links.sign(Uuid::new_v4(), Utc::now() - Duration::seconds(1));
+        assert_eq!(links.verify(&token, Utc::now()), Err(ShareTokenError::Expired));
+    }
+
+    #[test]
+    fn test_url_uses_public_base() {
+        let links = ShareLinks::new("secret", 60, Some("https://api.example.com/".to_string()));
+        assert_eq!(links.url("abc"), "https://api.example.com/api/v1/shared/abc");
+        assert_eq!(self::links().url("abc"), "/api/v1/shared/abc");
+    }
+}
@@ -0,0 +1,109 @@
+//! Cross-backend validation of engine results
+//!
+//! Panchanga longitudes come from low-order native formulas. Each successful
+//! calculation is checked against Swiss Ephemeris at the same instant and the
+//! differences are recorded in [`NoesisMetrics::validation_log`], which backs
+//! `GET /api/v1/admin/validation/report`.
+
+use std::sync::OnceLock;
+
+use chrono::{DateTime, Utc};
+use engine_human_design::{EphemerisCalculator, HDPlanet};
+use noesis_core::results::PanchangaResult;
+use noesis_core::{EngineInput, EngineOutput};
+use noesis_metrics::{NoesisMetrics, ValidationSample};
+
+/// Backend names used in recorded samples
+pub const NATIVE_BACKEND: &str = "native";
+pub const SWISS_BACKEND: &str = "swiss_ephemeris";
+
+/// Julian Day of the Unix epoch
+const UNIX_EPOCH_JD: f64 = 2_440_587.5;
+
+fn ephemeris() -> &'static EphemerisCalculator {
+    static EPHEMERIS: OnceLock<EphemerisCalculator> = OnceLock::new();
+    EPHEMERIS.get_or_init(|| EphemerisCalculator::new(""))
+}
+
+fn jd_to_datetime(jd: f64) -> Option<DateTime<Utc>> {
+    let millis = ((jd - UNIX_EPOCH_JD) * 86_400_000.0).round() as i64;
+    DateTime::from_timestamp_millis(millis)
+}
+
+/// Smallest separation between two longitudes, in arcseconds
+fn angular_difference_arcseconds(a: f64, b: f64) -> f64 {
+    let d = (a - b).rem_euclid(360.0);
+    d.min(360.0 - d) * 3600.0
+}
+
+/// Compare a panchanga result's tropical Sun and Moon longitudes with Swiss
+/// Ephemeris.
+///
+/// Returns no samples when the output does not parse or the ephemeris fails;
+/// validation must never fail the calculation it observes.
+pub fn cross_check_panchanga(input: &EngineInput, output: &EngineOutput) -> Vec<ValidationSample> {
+    let Ok(result) = output.parse_as::<PanchangaResult>() else {
+        return Vec::new();
+    };
+    let Some(calculated_for) = jd_to_datetime(result.julian_day) else {
+        return Vec::new();
+    };
+
+    // Sidereal results have the ayanamsa subtracted; compare tropical values
+    let ayanamsa = result.ayanamsa_degrees.unwrap_or(0.0);
+
+    // Keep what reproduces the calculation, not who asked for it
+    let mut input = input.clone();
+    for birth in input.birth_data_mut() {
+        birth.name = None;
+    }
+    let input = serde_json::to_value(&input).unwrap_or_default();
+    let recorded_at = Utc::now();
+
+    [
+        ("solar_longitude", HDPlanet::Sun, result.solar_longitude),
+        ("lunar_longitude", HDPlanet::Moon, result.lunar_longitude),
+    ]
+    .into_iter()
+    .filter_map(|(quantity, planet, longitude)| {
+        let reference = ephemeris().get_planet_position(planet, &calculated_for).ok()?;
+        Some(ValidationSample {
+            engine_id: output.engine_id.clone(),
+            quantity: quantity.to_string(),
+            reference_backend: SWISS_BACKEND.to_string(),
+            candidate_backend: NATIVE_BACKEND.to_string(),
+            difference_arcseconds: angular_difference_arcseconds(longitude + ayanamsa, reference.longitude),
+            calculated_for,
+            recorded_at,
+            input: input.clone(),
+        })
+    })
+    .collect()
+}
+
+/// Record the cross-check of a successful engine calculation, if the engine has one.
+pub fn record_cross_check(metrics: &NoesisMetrics, input: &EngineInput, output: &EngineOutput) {
+    if output.engine_id != "panchanga" {
+        return;
+    }
+    for sample in cross_check_panchanga(input, output) {
+        metrics.record_validation_sample(sample);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn angular_difference_wraps_at_360() {
+        assert!((angular_difference_arcseconds(359.5, 0.5) - 3600.0).abs() < 1e-6);
+        assert!((angular_difference_arcseconds(10.0, 9.0) - 3600.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn jd_to_datetime_matches_j2000() {
+        let dt = jd_to_datetime(2_451_545.0).unwrap();
+        assert_eq!(dt.to_rfc3339(), "2000-01-01T12:00:00+00:00");
+    }
+}
@@ -0,0 +1,407 @@
+//! API versions and their lifecycle
+//!
+//! Versioned routes are served under `/api/{version}`, one router per version
+//! (see `routes`). Which versions exist, and when each was deprecated and
+//! goes away, is declared once in [`API_VERSIONS`]; [`version_middleware`]
+//! turns that into `Deprecation` (RFC 9745), `Sunset` (RFC 8594) and
+//! successor `Link` headers on every response of a deprecated version.
+//! `API_DISABLED_VERSIONS` turns a version off: its routes answer 410 Gone.
+//! A renamed engine's old id gets the same headers from [`alias_headers`],
+//! which take precedence over the version's.
+//!
+//! Handlers that return shared types through [`Versioned`] serialize them
+//! with [`VersionedSerialize`], so a breaking change to a body lands in the
+//! new version's arm while older versions keep their shape. Error bodies
+//! follow [`ApiVersion::negotiates_legacy_errors`]. v1 bridged results carry
+//! the camelCase keys they had before the casing policy next to the
+//! snake_case ones (see [`noesis_core::casing`]).
+//!
+//! The scheme follows `noesis_vedic_api::versioning`: URI-path versions, each
+//! going `active` -> `deprecated` -> `sunset`.
+
+use std::fmt;
+
+use axum::{
+    extract::{FromRequestParts, Request, State},
+    http::{header, request::Parts, HeaderMap, HeaderName, HeaderValue, StatusCode},
+    middleware::Next,
+    response::{IntoResponse, Response},
+    Json,
+};
+use chrono::{DateTime, Utc};
+use noesis_core::casing::camel_case_twins;
+use noesis_core::{EngineOutput, WorkflowResult};
+use noesis_orchestrator::EngineAlias;
+use serde::Serialize;
+
+use crate::ErrorResponse;
+
+/// Header naming the version that served a response
+pub const API_VERSION_HEADER: &str = "x-api-version";
+
+/// A version of the `/api/{version}` surface
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum ApiVersion {
+    /// The original surface; errors negotiate between problem documents and
+    /// the legacy `ErrorResponse` body
+    V1,
+    /// Structured errors: always `application/problem+json`. Other routes
+    /// serve v1's bodies until their breaking changes land.
+    V2,
+}
+
+impl ApiVersion {
+    /// Every version, oldest first
+    pub const ALL: [ApiVersion; 2] = [ApiVersion::V1, ApiVersion::V2];
+
+    /// The version new integrations should use
+    pub const CURRENT: ApiVersion = ApiVersion::V2;
+
+    /// The version a request path is served by, if it is versioned
+    pub fn from_path(path: &str) -> Option<Self> {
+        let rest = path.strip_prefix("/api/")?;
+        let segment = rest.split('/').next().unwrap_or(rest);
+        Self::ALL.into_iter().find(|version| version.as_str() == segment)
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ApiVersion::V1 => "v1",
+            ApiVersion::V2 => "v2",
+        }
+    }
+
+    /// Where this version's routes are nested
+    pub fn path_prefix(&self) -> &'static str {
+        match self {
+            ApiVersion::V1 => "/api/v1",
+            ApiVersion::V2 => "/api/v2",
+        }
+    }
+
+    /// Whether error bodies may still come as the legacy `ErrorResponse`
+    /// when the client's `Accept` header prefers `application/json`
+    pub fn negotiates_legacy_errors(&self) -> bool {
+        matches!(self, ApiVersion::V1)
+    }
+
+    /// This version's entry in [`API_VERSIONS`]
+    pub fn spec(&self) -> &'static VersionSpec {
+        API_VERSIONS
+            .iter()
+            .find(|spec| spec.version == *self)
+            .expect("every ApiVersion is declared in API_VERSIONS")
+    }
+}
+
+impl fmt::Display for ApiVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl std::str::FromStr for ApiVersion {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let name = s.trim().to_ascii_lowercase();
+        Self::ALL
+            .into_iter()
+            .find(|version| version.as_str() == name)
+            .ok_or_else(|| format!("Unknown API version '{}'; known versions are v1, v2", s.trim()))
+    }
+}
+
+/// Lifecycle of one API version
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VersionSpec {
+    pub version: ApiVersion,
+    /// When the version was deprecated, in Unix seconds
+    pub deprecated_at: Option<i64>,
+    /// When the version stops being served, in Unix seconds
+    pub sunset_at: Option<i64>,
+    /// Version to migrate to
+    pub successor: Option<ApiVersion>,
+}
+
+/// The declared lifecycle of every API version
+pub const API_VERSIONS: &[VersionSpec] = &[
+    VersionSpec {
+        version: ApiVersion::V1,
+        // 2026-10-14T00:00:00Z
+        deprecated_at: Some(1_791_936_000),
+        // 2027-10-14T00:00:00Z
+        sunset_at: Some(1_823_472_000),
+        successor: Some(ApiVersion::V2),
+    },
+    VersionSpec {
+        version: ApiVersion::V2,
+        deprecated_at: None,
+        sunset_at: None,
+        successor: None,
+    },
+];
+
+impl VersionSpec {
+    pub fn is_deprecated(&self) -> bool {
+        self.deprecated_at.is_some()
+    }
+
+    /// Headers for every response of this version: the version itself, and
+    /// for a deprecated version `Deprecation`, `Sunset` and a
+    /// `successor-version` link
+    pub fn response_headers(&self) -> Vec<(HeaderName, HeaderValue)> {
+        let mut headers = vec![(
+            HeaderName::from_static(API_VERSION_HEADER),
+            HeaderValue::from_static(self.version.as_str()),
+        )];
+        let Some(deprecated_at) = self.deprecated_at else {
+            return headers;
+        };
+        let successor = self.successor.map(|successor| successor.path_prefix());
+        headers.extend(lifecycle_headers(deprecated_at, self.sunset_at, successor));
+        headers
+    }
+}
+
+/// `Deprecation`, `Sunset` and `successor-version` link of something
+/// deprecated at `deprecated_at`
+fn lifecycle_headers(
+    deprecated_at: i64,
+    sunset_at: Option<i64>,
+    successor: Option<&str>,
+) -> Vec<(HeaderName, HeaderValue)> {
+    let mut headers = Vec::new();
+    // RFC 9745 structured-field date
+    if let Ok(value) = HeaderValue::from_str(&format!("@{}", deprecated_at)) {
+        headers.push((HeaderName::from_static("deprecation"), value));
+    }
+    if let Some(sunset) = sunset_at.and_then(http_date) {
+        if let Ok(value) = HeaderValue::from_str(&sunset) {
+            headers.push((HeaderName::from_static("sunset"), value));
+        }
+    }
+    if let Some(successor) = successor {
+        let link = format!("<{}>; rel=\"successor-version\"", successor);
+        if let Ok(value) = HeaderValue::from_str(&link) {
+            headers.push((header::LINK, value));
+        }
+    }
+    headers
+}
+
+/// Lifecycle headers of a calculation requested by a renamed engine's old
+/// id, linking the replacement's calculate route in `version`
+pub fn alias_headers(alias: &EngineAlias, version: ApiVersion) -> HeaderMap {
+    let successor = format!("{}/engines/{}/calculate", version.path_prefix(), alias.engine_id);
+    lifecycle_headers(
+        alias.deprecated_at.timestamp(),
+        alias.sunset_at.map(|sunset| sunset.timestamp()),
+        Some(&successor),
+    )
+    .into_iter()
+    .collect()
+}
+
+/// IMF-fixdate, as `Sunset` and other HTTP date headers carry it
+fn http_date(unix_seconds: i64) -> Option<String> {
+    DateTime::<Utc>::from_timestamp(unix_seconds, 0).map(|at| at.format("%a, %d %b %Y %H:%M:%S GMT").to_string())
+}
+
+/// Record the version serving a request, for [`ApiVersion`] extraction, and
+/// add its lifecycle headers to the response. `Deprecation` and `Sunset` a
+/// handler set (see [`alias_headers`]) are kept; links are added alongside.
+pub async fn version_middleware(State(version): State<ApiVersion>, mut req: Request, next: Next) -> Response {
+    req.extensions_mut().insert(version);
+    let mut response = next.run(req).await;
+    let headers = response.headers_mut();
+    for (name, value) in version.spec().response_headers() {
+        if name == header::LINK {
+            headers.append(name, value);
+        } else if name == API_VERSION_HEADER || !headers.contains_key(&name) {
+            headers.insert(name, value);
+        }
+    }
+    response
+}
+
+/// Answer every request to a disabled version with 410 Gone
+pub async fn disabled_version_handler(State(version): State<ApiVersion>) -> Response {
+    let successor = version.spec().successor.unwrap_or(ApiVersion::CURRENT);
+    (
+        StatusCode::GONE,
+        Json(ErrorResponse {
+            error: format!("API {} is no longer served; use {}", version, successor.path_prefix()),
+            error_code: "API_VERSION_DISABLED".to_string(),
+            details: Some(serde_json::json!({
+                "version": version.as_str(),
+                "successor": successor.path_prefix(),
+            })),
+            request_id: None,
+        }),
+    )
+        .into_response()
+}
+
+/// Handlers read the version serving them as an extractor; outside a
+/// versioned router it is v1
+#[axum::async_trait]
+impl<S: Send + Sync> FromRequestParts<S> for ApiVersion {
+    type Rejection = std::convert::Infallible;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        Ok(parts.extensions.get::<ApiVersion>().copied().unwrap_or(ApiVersion::V1))
+    }
+}
+
+/// A body shared between versions, serialized in each version's shape
+pub trait VersionedSerialize {
+    fn to_versioned_json(&self, version: ApiVersion) -> serde_json::Value;
+}
+
+/// Serialize with serde: the shape every version has had so far
+fn serde_shape<T: Serialize>(value: &T) -> serde_json::Value {
+    serde_json::to_value(value).unwrap_or(serde_json::Value::Null)
+}
+
+impl VersionedSerialize for EngineOutput {
+    fn to_versioned_json(&self, version: ApiVersion) -> serde_json::Value {
+        match version {
+            ApiVersion::V1 => legacy_casing(self, serde_shape(self)),
+            ApiVersion::V2 => serde_shape(self),
+        }
+    }
+}
+
+impl VersionedSerialize for WorkflowResult {
+    fn to_versioned_json(&self, version: ApiVersion) -> serde_json::Value {
+        match version {
+            ApiVersion::V1 => {
+                let mut body = serde_shape(self);
+                for (engine_id, output) in &self.engine_outputs {
+                    if let Some(slot) = body["engine_outputs"].get_mut(engine_id) {
+                        *slot = legacy_casing(output, slot.take());
+                    }
+                }
+                body
+            }
+            ApiVersion::V2 => serde_shape(self),
+        }
+    }
+}
+
+/// `body` of a bridged `output` with camelCase twins of its result keys
+fn legacy_casing(output: &EngineOutput, mut body: serde_json::Value) -> serde_json::Value {
+    if output.metadata.backend == noesis_bridge::wire::BACKEND {
+        if let Some(result) = body.get_mut("result") {
+            camel_case_twins(result);
+        }
+    }
+    body
+}
+
+/// JSON response body in the shape of the version serving the request
+pub struct Versioned<T>(pub ApiVersion, pub T);
+
+impl<T: VersionedSerialize> IntoResponse for Versioned<T> {
+    fn into_response(self) -> Response {
+        Json(self.1.to_versioned_json(self.0)).into_response()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_version_from_path() {
+        assert_eq!(ApiVersion::from_path("/api/v1/engines"), Some(ApiVersion::V1));
+        assert_eq!(ApiVersion::from_path("/api/v2"), Some(ApiVersion::V2));
+        assert_eq!(ApiVersion::from_path("/api/v3/engines"), None);
+        assert_eq!(ApiVersion::from_path("/api/legacy/panchanga/calculate"), None);
+        assert_eq!(ApiVersion::from_path("/health"), None);
+        assert_eq!("V2".parse::<ApiVersion>(), Ok(ApiVersion::V2));
+        assert!("v9".parse::<ApiVersion>().is_err());
+    }
+
+    #[test]
+    fn test_every_version_is_declared() {
+        for version in ApiVersion::ALL {
+            assert_eq!(version.spec().version, version);
+        }
+        assert!(!ApiVersion::CURRENT.spec().is_deprecated());
+    }
+
+    #[test]
+    fn test_deprecated_version_headers() {
+        let headers = ApiVersion::V1.spec().response_headers();
+        let get = |name: &str| {
+            headers
+                .iter()
+                .find(|(n, _)| n.as_str() == name)
+                .map(|(_, v)| v.to_str().unwrap().to_string())
+        };
+        assert_eq!(get("x-api-version").as_deref(), Some("v1"));
+        assert_eq!(get("deprecation").as_deref(), Some("@1791936000"));
+        assert_eq!(get("sunset").as_deref(), Some("Thu, 14 Oct 2027 00:00:00 GMT"));
+        assert_eq!(get("link").as_deref(), Some("</api/v2>; rel=\"successor-version\""));
+
+        let current = ApiVersion::V2.spec().response_headers();
+        assert_eq!(current.len(), 1);
+    }
+
+    #[test]
+    fn test_v1_bridged_results_keep_camel_case_keys() {
+        let output = EngineOutput {
+            engine_id: "tarot".to_string(),
+            result: serde_json::json!({ "cards": [{ "name": "The Tower", "is_reversed": true }] }),
+            witness_prompt: String::new(),
+            summary: None,
+            consciousness_level: 1,
+            metadata: noesis_core::CalculationMetadata {
+                calculation_time_ms: 1.0,
+                backend: noesis_bridge::wire::BACKEND.to_string(),
+                precision_achieved: "Standard".to_string(),
+                cached: false,
+                timestamp: Utc::now(),
+                engine_version: None,
+                warnings: Vec::new(),
+                usage: None,
+            },
+        };
+        let v1 = output.to_versioned_json(ApiVersion::V1);
+        assert_eq!(v1["result"]["cards"][0]["is_reversed"], true);
+        assert_eq!(v1["result"]["cards"][0]["isReversed"], true);
+        let v2 = output.to_versioned_json(ApiVersion::V2);
+        assert!(v2["result"]["cards"][0].get("isReversed").is_none());
+
+        let workflow = WorkflowResult {
+            workflow_id: "decision-support".to_string(),
+            engine_outputs: [("tarot".to_string(), output)].into(),
+            synthesis: None,
+            total_time_ms: 1.0,
+            timestamp: Utc::now(),
+            usage: None,
+            time_confidence: None,
+        };
+        let v1 = workflow.to_versioned_json(ApiVersion::V1);
+        assert_eq!(v1["engine_outputs"]["tarot"]["result"]["cards"][0]["isReversed"], true);
+    }
+
+    #[test]
+    fn test_alias_headers() {
+        let alias = EngineAlias::new(
+            "vedic-clock",
+            "vedic-clock-tcm",
+            DateTime::from_timestamp(1_791_936_000, 0).unwrap(),
+        )
+        .with_sunset(DateTime::from_timestamp(1_823_472_000, 0).unwrap());
+        let headers = alias_headers(&alias, ApiVersion::V2);
+        assert_eq!(headers["deprecation"], "@1791936000");
+        assert_eq!(headers["sunset"], "Thu, 14 Oct 2027 00:00:00 GMT");
+        assert_eq!(
+            headers[header::LINK],
+            "</api/v2/engines/vedic-clock-tcm/calculate>; rel=\"successor-version\""
+        );
+    }
+}
@@ -0,0 +1,575 @@
+//! Keyword search over the wisdom corpora
+//!
+//! Human Design gates and channels, Gene Keys, I Ching hexagrams and
+//! numerology meanings are indexed once into an in-memory inverted index,
+//! built from the engines' wisdom data at startup. A query term matches a
+//! word exactly or, from five letters on, by its stem ("creativity" finds
+//! "creative"); names weigh more than keywords, and keywords more than
+//! descriptions. Documents matching more of the query's terms rank higher.
+//!
+//! The 64 gates, Gene Keys and hexagrams share their numbering, so every hit
+//! is cross-referenced to its counterparts in the other systems, and gates
+//! and channels to each other.
+//!
+//! The same data is exported whole, per engine, as versioned bundles whose
+//! SHA-256 checksum doubles as the HTTP ETag.
+
+use engine_gene_keys::{gene_keys, GeneKeysEngine};
+use engine_human_design::{HumanDesignEngine, CENTERS, CHANNELS, GATES};
+use noesis_core::ConsciousnessEngine;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::{BTreeMap, HashMap};
+use std::sync::OnceLock;
+use utoipa::ToSchema;
+
+/// Maximum number of results a search may return
+pub const MAX_SEARCH_LIMIT: usize = 50;
+
+// Weights of the fields a word appears in
+const TITLE_WEIGHT: f64 = 3.0;
+const KEYWORD_WEIGHT: f64 = 2.0;
+const TEXT_WEIGHT: f64 = 1.0;
+
+/// Share of an exact match's weight earned by a stem match
+const STEM_MATCH_FACTOR: f64 = 0.5;
+
+/// Shortest query term matched by stem
+const MIN_STEM_TERM_LEN: usize = 5;
+
+/// Letters a stem drops from a query term, keeping at least four
+const STEM_SUFFIX_LEN: usize = 3;
+
+/// Words too common to be worth indexing
+const STOP_WORDS: &[&str] = &[
+    "an", "and", "are", "as", "at", "be", "by", "for", "from", "in", "into", "is", "it", "its", "of", "on", "or",
+    "that", "the", "their", "this", "through", "to", "with", "your",
+];
+
+/// A wisdom corpus
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize, ToSchema)]
+pub enum WisdomSystem {
+    #[serde(rename = "hd")]
+    HumanDesign,
+    #[serde(rename = "gene-keys")]
+    GeneKeys,
+    #[serde(rename = "i-ching")]
+    IChing,
+    #[serde(rename = "numerology")]
+    Numerology,
+}
+
+impl WisdomSystem {
+    pub const ALL: [WisdomSystem; 4] = [
+        WisdomSystem::HumanDesign,
+        WisdomSystem::GeneKeys,
+        WisdomSystem::IChing,
+        WisdomSystem::Numerology,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            WisdomSystem::HumanDesign => "hd",
+            WisdomSystem::GeneKeys => "gene-keys",
+            WisdomSystem::IChing => "i-ching",
+            WisdomSystem::Numerology => "numerology",
+        }
+    }
+
+    /// Parse a system id; "human-design" is accepted for "hd"
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_lowercase().as_str() {
+            "human-design" => Some(WisdomSystem::HumanDesign),
+            other => Self::ALL.into_iter().find(|system| system.as_str() == other),
+        }
+    }
+}
+
+/// A document of another system related to a hit
+#[derive(Debug, Clone, PartialEq, Serialize, ToSchema)]
+pub struct WisdomRef {
+    pub system: WisdomSystem,
+    #[schema(example = "gene-key-1")]
+    pub id: String,
+    #[schema(example = "Gene Key 1: The Creative")]
+    pub title: String,
+}
+
+/// One search result
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct WisdomHit {
+    pub system: WisdomSystem,
+    /// Stable id: gate-N, channel-A-B, gene-key-N, hexagram-N or number-N
+    #[schema(example = "gate-1")]
+    pub id: String,
+    #[schema(example = "Gate 1: The Creative")]
+    pub title: String,
+    /// Keynote or short meaning
+    #[schema(example = "Self-Expression")]
+    pub summary: String,
+    /// Relevance, comparable only within one search
+    pub score: f64,
+    /// Query terms the document matched
+    pub matched_terms: Vec<String>,
+    /// The same number in the other systems, and the gates and channels it connects to
+    pub related: Vec<WisdomRef>,
+}
+
+struct WisdomDocument {
+    system: WisdomSystem,
+    id: String,
+    title: String,
+    summary: String,
+    related: Vec<String>,
+}
+
+/// One document's weight for an indexed word
+struct Posting {
+    document: usize,
+    weight: f64,
+}
+
+/// Inverted index over wisdom documents
+#[derive(Default)]
+pub struct WisdomIndex {
+    documents: Vec<WisdomDocument>,
+    positions: HashMap<String, usize>,
+    postings: BTreeMap<String, Vec<Posting>>,
+}
+
+impl WisdomIndex {
+    /// Add a document; each field is text and the weight of the words in it
+    fn add(&mut self, document: WisdomDocument, fields: &[(&str, f64)]) {
+        let position = self.documents.len();
+        let mut weights: HashMap<String, f64> = HashMap::new();
+        for (text, weight) in fields {
+            let mut words = tokenize(text);
+            words.sort_unstable();
+            words.dedup();
+            for word in words {
+                *weights.entry(word).or_default() += weight;
+            }
+        }
+        for (word, weight) in weights {
+            self.postings.entry(word).or_default().push(Posting { document: position, weight });
+        }
+        self.positions.insert(document.id.clone(), position);
+        self.documents.push(document);
+    }
+
+    /// Number of indexed documents
+    pub fn len(&self) -> usize {
+        self.documents.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.documents.is_empty()
+    }
+
+    /// The best `limit` matches for `query` in `systems`, with the number of
+    /// matching documents
+    pub fn search(&self, query: &str, systems: &[WisdomSystem], limit: usize) -> (usize, Vec<WisdomHit>) {
+        let mut terms: Vec<String> = Vec::new();
+        for term in tokenize(query) {
+            if !terms.contains(&term) {
+                terms.push(term);
+            }
+        }
+        let total_documents = self.documents.len() as f64;
+
+        // Per document: score and the terms it matched
+        let mut matches: HashMap<usize, (f64, Vec<&str>)> = HashMap::new();
+        for term in &terms {
+            let mut best: HashMap<usize, f64> = HashMap::new();
+            for (word, factor) in self.matching_words(term) {
+                for posting in &self.postings[word] {
+                    let weight = best.entry(posting.document).or_default();
+                    *weight = weight.max(posting.weight * factor);
+                }
+            }
+            let idf = (1.0 + total_documents / best.len().max(1) as f64).ln();
+            for (document, weight) in best {
+                let entry = matches.entry(document).or_insert((0.0, Vec::new()));
+                entry.0 += weight * idf;
+                entry.1.push(term);
+            }
+        }
+
+        let mut ranked: Vec<(usize, f64, Vec<&str>)> = matches
+            .into_iter()
+            .filter(|(document, _)| systems.contains(&self.documents[*document].system))
+            .map(|(document, (score, matched))| {
+                // Favour documents that cover the whole query
+                let coverage = matched.len() as f64 / terms.len() as f64;
+                (document, score * coverage, matched)
+            })
+            .collect();
+        ranked.sort_by(|a, b| b.1.total_cmp(&a.1).then(a.0.cmp(&b.0)));
+        let total = ranked.len();
+        ranked.truncate(limit);
+
+        let hits = ranked
+            .into_iter()
+            .map(|(position, score, matched)| {
+                let document = &self.documents[position];
+                WisdomHit {
+                    system: document.system,
+                    id: document.id.clone(),
+                    title: document.title.clone(),
+                    summary: document.summary.clone(),
+                    score: (score * 1000.0).round() / 1000.0,
+                    matched_terms: matched.into_iter().map(str::to_string).collect(),
+                    related: self.related(document),
+                }
+            })
+            .collect();
+        (total, hits)
+    }
+
+    /// Indexed words matching a query term, with the share of weight they earn
+    fn matching_words<'a>(&'a self, term: &'a str) -> impl Iterator<Item = (&'a String, f64)> + 'a {
+        let stem = if term.chars().count() >= MIN_STEM_TERM_LEN {
+            let keep = (term.chars().count() - STEM_SUFFIX_LEN).max(4);
+            term.chars().take(keep).collect::<String>()
+        } else {
+            term.to_string()
+        };
+        self.postings
+            .range(stem.clone()..)
+            .take_while(move |(word, _)| word.starts_with(&stem))
+            .map(move |(word, _)| (word, if word == term { 1.0 } else { STEM_MATCH_FACTOR }))
+    }
+
+    fn related(&self, document: &WisdomDocument) -> Vec<WisdomRef> {
+        document
+            .related
+            .iter()
+            .filter_map(|id| self.positions.get(id))
+            .map(|&position| {
+                let related = &self.documents[position];
+                WisdomRef {
+                    system: related.system,
+                    id: related.id.clone(),
+                    title: related.title.clone(),
+                }
+            })
+            .collect()
+    }
+}
+
+/// Lowercase words of two or more letters or digits, without stop words
+pub fn tokenize(text: &str) -> Vec<String> {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|word| word.chars().count() >= 2)
+        .map(str::to_lowercase)
+        .filter(|word| !STOP_WORDS.contains(&word.as_str()))
+        .collect()
+}
+
+/// The index over every corpus, built on first use
+pub fn index() -> &'static WisdomIndex {
+    static INDEX: OnceLock<WisdomIndex> = OnceLock::new();
+    INDEX.get_or_init(build_index)
+}
+
+#[derive(Deserialize)]
+struct HexagramsFile {
+    hexagrams: HashMap<String, Hexagram>,
+}
+
+#[derive(Deserialize)]
+struct Hexagram {
+    number: u8,
+    name: String,
+    #[serde(default)]
+    keywords: Vec<String>,
+    #[serde(default)]
+    judgment: String,
+    #[serde(default)]
+    image: String,
+    #[serde(default)]
+    meaning: String,
+}
+
+fn build_index() -> WisdomIndex {
+    let mut index = WisdomIndex::default();
+
+    // Channels each gate takes part in
+    let mut gate_channels: HashMap<u8, Vec<String>> = HashMap::new();
+    let mut channels: Vec<_> = CHANNELS.iter().collect();
+    channels.sort_by(|a, b| (a.1.gates.first(), a.0).cmp(&(b.1.gates.first(), b.0)));
+    for (key, channel) in &channels {
+        for gate in &channel.gates {
+            gate_channels.entry(*gate).or_default().push(format!("channel-{}", key));
+        }
+    }
+    let counterparts = |number: u8, own: WisdomSystem| -> Vec<String> {
+        [
+            (WisdomSystem::HumanDesign, format!("gate-{}", number)),
+            (WisdomSystem::GeneKeys, format!("gene-key-{}", number)),
+            (WisdomSystem::IChing, format!("hexagram-{}", number)),
+        ]
+        .into_iter()
+        .filter(|(system, _)| *system != own)
+        .map(|(_, id)| id)
+        .collect()
+    };
+
+    let mut gates: Vec<_> = GATES.values().collect();
+    gates.sort_by_key(|gate| gate.number);
+    for gate in gates {
+        let mut related = counterparts(gate.number, WisdomSystem::HumanDesign);
+        related.extend(gate_channels.get(&gate.number).cloned().unwrap_or_default());
+        let qualities = [&gate.gift, &gate.shadow, &gate.siddhi]
+            .into_iter()
+            .flatten()
+            .cloned()
+            .collect::<Vec<_>>()
+            .join(" ");
+        index.add(
+            WisdomDocument {
+                system: WisdomSystem::HumanDesign,
+                id: format!("gate-{}", gate.number),
+                title: format!("Gate {}: {}", gate.number, gate.name),
+                summary: gate.keynote.clone(),
+                related,
+            },
+            &[
+                (&gate.name, TITLE_WEIGHT),
+                (&gate.keynote, KEYWORD_WEIGHT),
+                (&qualities, KEYWORD_WEIGHT),
+                (&gate.description, TEXT_WEIGHT),
+                (&gate.center, TEXT_WEIGHT),
+            ],
+        );
+    }
+
+    for (key, channel) in channels {
+        index.add(
+            WisdomDocument {
+                system: WisdomSystem::HumanDesign,
+                id: format!("channel-{}", key),
+                title: format!("{} ({})", channel.name, key),
+                summary: if channel.theme.is_empty() { channel.keynote.clone() } else { channel.theme.clone() },
+                related: channel.gates.iter().map(|gate| format!("gate-{}", gate)).collect(),
+            },
+            &[
+                (&channel.name, TITLE_WEIGHT),
+                (&channel.theme, KEYWORD_WEIGHT),
+                (&channel.keynote, KEYWORD_WEIGHT),
+                (&channel.description, TEXT_WEIGHT),
+                (&channel.circuitry, TEXT_WEIGHT),
+            ],
+        );
+    }
+
+    let mut keys: Vec<_> = gene_keys().values().collect();
+    keys.sort_by_key(|key| key.number);
+    for key in keys {
+        let qualities = format!("{} {} {}", key.shadow, key.gift, key.siddhi);
+        index.add(
+            WisdomDocument {
+                system: WisdomSystem::GeneKeys,
+                id: format!("gene-key-{}", key.number),
+                title: format!("Gene Key {}: {}", key.number, key.name),
+                summary: format!("Shadow of {}, Gift of {}, Siddhi of {}", key.shadow, key.gift, key.siddhi),
+                related: counterparts(key.number, WisdomSystem::GeneKeys),
+            },
+            &[
+                (&key.name, TITLE_WEIGHT),
+                (&qualities, KEYWORD_WEIGHT),
+                (&key.shadow_description, TEXT_WEIGHT),
+                (&key.gift_description, TEXT_WEIGHT),
+                (&key.siddhi_description, TEXT_WEIGHT),
+            ],
+        );
+    }
+
+    const HEXAGRAMS_JSON: &str = include_str!("../../../data/i-ching/hexagrams.json");
+    match serde_json::from_str::<HexagramsFile>(HEXAGRAMS_JSON) {
+        Ok(file) => {
+            let mut hexagrams: Vec<_> = file.hexagrams.into_values().collect();
+            hexagrams.sort_by_key(|hexagram| hexagram.number);
+            for hexagram in hexagrams {
+                let keywords = hexagram.keywords.join(" ");
+                index.add(
+                    WisdomDocument {
+                        system: WisdomSystem::IChing,
+                        id: format!("hexagram-{}", hexagram.number),
+                        title: format!("Hexagram {}: {}", hexagram.number, hexagram.name),
+                        summary: hexagram.keywords.join(", "),
+                        related: counterparts(hexagram.number, WisdomSystem::IChing),
+                    },
+                    &[
+                        (&hexagram.name, TITLE_WEIGHT),
+                        (&keywords, KEYWORD_WEIGHT),
+                        (&hexagram.meaning, TEXT_WEIGHT),
+                        (&hexagram.judgment, TEXT_WEIGHT),
+                        (&hexagram.image, TEXT_WEIGHT),
+                    ],
+                );
+            }
+        }
+        Err(e) => tracing::error!(error = %e, "Failed to parse I Ching hexagrams; they are not searchable"),
+    }
+
+    for number in engine_numerology::MEANINGFUL_NUMBERS {
+        let meaning = engine_numerology::meaning_for(number);
+        index.add(
+            WisdomDocument {
+                system: WisdomSystem::Numerology,
+                id: format!("number-{}", number),
+                title: format!("Number {}", number),
+                summary: meaning.clone(),
+                related: Vec::new(),
+            },
+            &[(&meaning, KEYWORD_WEIGHT)],
+        );
+    }
+
+    index
+}
+
+/// Layout version of export bundles; bump it when the bundle's shape changes
+pub const EXPORT_FORMAT_VERSION: u32 = 1;
+
+/// Engines whose wisdom data can be exported
+pub const EXPORTABLE_ENGINES: [&str; 2] = ["human-design", "gene-keys"];
+
+/// An engine's complete wisdom corpus, versioned and checksummed
+#[derive(Debug, Serialize, ToSchema)]
+pub struct WisdomBundle {
+    #[schema(example = "human-design")]
+    pub engine: String,
+    /// Layout version of the bundle
+    #[schema(example = 1)]
+    pub format_version: u32,
+    /// Version of the engine the data belongs to
+    #[schema(example = "1")]
+    pub engine_version: String,
+    /// Hex SHA-256 of `content` as compact JSON; quoted, it is the ETag
+    pub checksum: String,
+    /// Tables of the corpus, e.g. gates, centers and channels, each keyed by id
+    #[schema(value_type = Object)]
+    pub content: serde_json::Value,
+}
+
+/// A bundle serialized once, ready to be served
+pub struct ExportedBundle {
+    pub etag: String,
+    pub body: String,
+}
+
+impl ExportedBundle {
+    fn new(engine: &dyn ConsciousnessEngine, content: serde_json::Value) -> Self {
+        let compact = serde_json::to_vec(&content).expect("wisdom data serializes");
+        let checksum = hex::encode(Sha256::digest(&compact));
+        let bundle = WisdomBundle {
+            engine: engine.engine_id().to_string(),
+            format_version: EXPORT_FORMAT_VERSION,
+            engine_version: engine.engine_version().to_string(),
+            checksum: checksum.clone(),
+            content,
+        };
+        Self {
+            etag: format!("\"{}\"", checksum),
+            body: serde_json::to_string(&bundle).expect("wisdom bundle serializes"),
+        }
+    }
+}
+
+/// The export bundle of an engine in [`EXPORTABLE_ENGINES`], built on first use
+pub fn export_bundle(engine_id: &str) -> Option<&'static ExportedBundle> {
+    static HUMAN_DESIGN: OnceLock<ExportedBundle> = OnceLock::new();
+    static GENE_KEYS: OnceLock<ExportedBundle> = OnceLock::new();
+    match engine_id {
+        "human-design" => Some(HUMAN_DESIGN.get_or_init(|| {
+            // Sorted maps, so the checksum only changes with the data
+            let content = serde_json::json!({
+                "gates": GATES.values().map(|gate| (gate.number, gate)).collect::<BTreeMap<_, _>>(),
+                "centers": CENTERS.iter().collect::<BTreeMap<_, _>>(),
+                "channels": CHANNELS.iter().collect::<BTreeMap<_, _>>(),
+            });
+            ExportedBundle::new(&HumanDesignEngine::new(), content)
+        })),
+        "gene-keys" => Some(GENE_KEYS.get_or_init(|| {
+            let content = serde_json::json!({
+                "gene_keys": gene_keys().iter().collect::<BTreeMap<_, _>>(),
+            });
+            ExportedBundle::new(&GeneKeysEngine::new(), content)
+        })),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tokenize_drops_stop_words_and_punctuation() {
+        assert_eq!(tokenize("The Creative, self-expression!"), ["creative", "self", "expression"]);
+        assert!(tokenize("of a").is_empty());
+    }
+
+    #[test]
+    fn test_index_covers_every_corpus() {
+        let index = index();
+        // 64 gates, 36 channels, 64 Gene Keys, 64 hexagrams, 12 numbers
+        assert_eq!(index.len(), 64 + CHANNELS.len() + 64 + 64 + 12);
+        for system in WisdomSystem::ALL {
+            let (total, _) = index.search("creativity", &[system], 5);
+            assert!(total > 0, "no {} match for creativity", system.as_str());
+        }
+    }
+
+    #[test]
+    fn test_search_ranks_and_cross_references() {
+        let (total, hits) = index().search("creativity", &WisdomSystem::ALL, 3);
+        assert!(total > hits.len() && hits.len() == 3);
+        assert!(hits.windows(2).all(|pair| pair[0].score >= pair[1].score));
+
+        let gate = hits.iter().find(|hit| hit.id == "gate-1").expect("gate 1 matches creativity");
+        let related: Vec<&str> = gate.related.iter().map(|r| r.id.as_str()).collect();
+        assert!(related.contains(&"gene-key-1"));
+        assert!(related.contains(&"hexagram-1"));
+        assert!(related.contains(&"channel-1-8"));
+    }
+
+    #[test]
+    fn test_search_filters_systems_and_prefers_full_matches() {
+        let (_, hits) = index().search("creative leadership", &[WisdomSystem::IChing], 3);
+        assert!(hits.iter().all(|hit| hit.system == WisdomSystem::IChing));
+        assert_eq!(hits[0].id, "hexagram-1");
+        assert_eq!(hits[0].matched_terms, ["creative", "leadership"]);
+
+        assert_eq!(index().search("zzzz", &WisdomSystem::ALL, 10).0, 0);
+    }
+
+    #[test]
+    fn test_parse_system() {
+        assert_eq!(WisdomSystem::parse("HD"), Some(WisdomSystem::HumanDesign));
+        assert_eq!(WisdomSystem::parse("human-design"), Some(WisdomSystem::HumanDesign));
+        assert_eq!(WisdomSystem::parse("i-ching"), Some(WisdomSystem::IChing));
+        assert_eq!(WisdomSystem::parse("tarot"), None);
+    }
+
+    #[test]
+    fn test_export_bundle_is_checksummed_and_complete() {
+        for engine in EXPORTABLE_ENGINES {
+            let exported = export_bundle(engine).unwrap();
+            let bundle: serde_json::Value = serde_json::from_str(&exported.body).unwrap();
+            assert_eq!(bundle["engine"], engine);
+            assert_eq!(bundle["format_version"], EXPORT_FORMAT_VERSION);
+            let checksum = hex::encode(Sha256::digest(serde_json::to_vec(&bundle["content"]).unwrap()));
+            assert_eq!(bundle["checksum"], checksum);
+            assert_eq!(exported.etag, format!("\"{}\"", checksum));
+        }
+
+        let human_design: serde_json::Value = serde_json::from_str(&export_bundle("human-design").unwrap().body).unwrap();
+        assert_eq!(human_design["content"]["gates"].as_object().unwrap().len(), 64);
+        assert_eq!(human_design["content"]["centers"].as_object().unwrap().len(), 9);
+        assert_eq!(human_design["content"]["channels"].as_object().unwrap().len(), CHANNELS.len());
+        assert!(export_bundle("tarot").is_none());
+    }
+}
@@ -0,0 +1,156 @@
+//! Determinism harness
+//!
+//! Every engine must return byte-identical results for the same input once
+//! `options.seed` is fixed, otherwise caching and replay break. Engines that
+//! draw at random must also be stable without a seed (see `noesis_core::rng`). Each engine is
+//! run twice with one seeded input; only `metadata` (timing, timestamp) may
+//! differ between the two outputs.
+//!
+//! Engines run one at a time: some ephemeris dependencies are not safe to call
+//! concurrently.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use chrono::{TimeZone, Utc};
+use engine_face_reading::FaceReadingEngine;
+use noesis_api::{build_app_state_lazy_db, ApiConfig};
+use noesis_core::{rng, BirthData, ConsciousnessEngine, Coordinates, EngineInput, Precision, SEED_OPTION};
+use serde_json::json;
+use tokio::sync::OnceCell;
+
+fn seeded_input(engine_id: &str, seed: u64) -> EngineInput {
+    let mut options = HashMap::new();
+    options.insert(SEED_OPTION.to_string(), json!(seed));
+    options.insert("consciousness_level".to_string(), json!(5));
+
+    let mut input = EngineInput {
+        birth_data: Some(BirthData {
+            name: Some("Determinism Reference".to_string()),
+            display_name: None,
+            date: "1990-01-15".to_string(),
+            time: Some("14:30".to_string()),
+            time_unknown: false,
+            latitude: 12.9716,
+            longitude: 77.5946,
+            altitude: None,
+            timezone: "Asia/Kolkata".to_string(),
+            place: None,
+            utc_offset_minutes: None,
+        }),
+        secondary_birth_data: None,
+        subjects: Vec::new(),
+        current_time: Utc.with_ymd_and_hms(2025, 6, 21, 6, 30, 0).unwrap(),
+        location: Some(Coordinates {
+            latitude: 12.9716,
+            longitude: 77.5946,
+            altitude: None,
+            timezone: None,
+        }),
+        precision: Precision::Standard,
+        options,
+    };
+
+    // Gene Keys cannot yet derive a chart from birth data (HD chart round-trip,
+    // see e2e_engine_tests); use its gate mode instead
+    if engine_id == "gene-keys" {
+        input.birth_data = None;
+        input.options.insert(
+            "hd_gates".to_string(),
+            json!({ "personality_sun": 17, "personality_earth": 18, "design_sun": 45, "design_earth": 26 }),
+        );
+    }
+    input
+}
+
+static ENGINES: OnceCell<Vec<Arc<dyn ConsciousnessEngine>>> = OnceCell::const_new();
+
+/// Engines under test: everything the API registers, plus engines not yet routed
+async fn engines() -> &'static [Arc<dyn ConsciousnessEngine>] {
+    ENGINES
+        .get_or_init(|| async {
+            let state = build_app_state_lazy_db(&ApiConfig::from_env()).await;
+            let registry = state.core.orchestrator.registry();
+            let mut engines: Vec<Arc<dyn ConsciousnessEngine>> = registry
+                .list()
+                .into_iter()
+                .filter_map(|id| registry.get(id))
+                .collect();
+            engines.push(Arc::new(FaceReadingEngine::new()));
+            engines.sort_by(|a, b| a.engine_id().cmp(b.engine_id()));
+            engines
+        })
+        .await
+}
+
+#[tokio::test]
+async fn every_engine_is_deterministic_for_a_fixed_seed() {
+    let mut failures = Vec::new();
+
+    for engine in engines().await {
+        let id = engine.engine_id().to_string();
+        let input = seeded_input(&id, 42);
+        let first = engine.calculate(input.clone()).await;
+        let second = engine.calculate(input.clone()).await;
+        let (first, second) = match (first, second) {
+            (Ok(first), Ok(second)) => (first, second),
+            (first, second) => {
+                failures.push(format!("{id}: calculation failed: {:?} / {:?}", first.err(), second.err()));
+                continue;
+            }
+        };
+
+        let first_bytes = serde_json::to_vec(&first.result).unwrap();
+        let second_bytes = serde_json::to_vec(&second.result).unwrap();
+        if first_bytes != second_bytes {
+            failures.push(format!(
+                "{id}: result differs\n  first:  {}\n  second: {}",
+                String::from_utf8_lossy(&first_bytes),
+                String::from_utf8_lossy(&second_bytes)
+            ));
+        }
+        if first.witness_prompt != second.witness_prompt {
+            failures.push(format!(
+                "{id}: witness prompt differs: {:?} / {:?}",
+                first.witness_prompt, second.witness_prompt
+            ));
+        }
+    }
+
+    assert!(failures.is_empty(), "non-deterministic engines:\n{}", failures.join("\n"));
+}
+
+#[tokio::test]
+async fn stochastic_engines_are_stable_per_question_and_user() {
+    for engine in engines().await {
+        let id = engine.engine_id();
+        if !matches!(id, "biofield" | "face-reading" | "vedic-clock") {
+            continue;
+        }
+        let input = seeded_input(id, 7);
+        let mut unseeded = input.clone();
+        unseeded.options.remove(SEED_OPTION);
+
+        let seeded = engine.calculate(input.clone()).await.unwrap();
+        assert_eq!(seeded.result["seed"], json!(7), "{id} should echo options.seed");
+
+        // Without a seed the same question gets the same answer, at any time
+        let first = engine.calculate(unseeded.clone()).await.unwrap();
+        let mut later = unseeded.clone();
+        later.current_time = unseeded.current_time + chrono::Duration::minutes(1);
+        let repeated = engine.calculate(later).await.unwrap();
+        assert!(first.result.get("seed").is_none(), "{id} should only echo a given seed");
+        assert_eq!(first.witness_prompt, repeated.witness_prompt, "{id} repeat prompt should match");
+        let as_user = rng::scope("user-1".to_string(), engine.calculate(unseeded.clone())).await.unwrap();
+        let as_user_again = rng::scope("user-1".to_string(), engine.calculate(unseeded.clone())).await.unwrap();
+        assert_eq!(as_user.result, as_user_again.result, "{id} should be stable per user");
+        assert_eq!(as_user.witness_prompt, as_user_again.witness_prompt, "{id} prompt should be stable per user");
+
+        // Vedic Clock only draws its witness prompt, from a handful per organ
+        if id != "vedic-clock" {
+            let as_other = rng::scope("user-2".to_string(), engine.calculate(unseeded.clone())).await.unwrap();
+            assert_ne!(as_user.result, as_other.result, "{id} should differ between users");
+            assert_ne!(first.result, seeded.result, "{id} options.seed should re-roll the answer");
+        }
+    }
+}
@@ -83,17 +83,25 @@ fn reference_birth_input() -> EngineInput {
     EngineInput {
         birth_data: Some(noesis_core::BirthData {
             name: Some("E2E Reference".to_string()),
+            display_name: None,
             date: "1990-01-15".to_string(),
             time: Some("14:30".to_string()),
+            time_unknown: false,
             latitude: 40.7128,
             longitude: -74.0060,
+            altitude: None,
             timezone: "America/New_York".to_string(),
+            place: None,
+            utc_offset_minutes: None,
         }),
+        secondary_birth_data: None,
+        subjects: Vec::new(),
         current_time: chrono::Utc::now(),
         location: Some(noesis_core::Coordinates {
             latitude: 40.7128,
             longitude: -74.0060,
             altitude: None,
+            timezone: None,
         }),
         precision: noesis_core::Precision::Standard,
         options: std::collections::HashMap::new(),
@@ -112,6 +120,8 @@ async fn authed_request(
     let builder = Request::builder()
         .method(method)
         .uri(uri)
+        // Legacy ErrorResponse shape; problem+json is covered in error_handling_tests
+        .header(header::ACCEPT, "application/json")
         .header(header::AUTHORIZATION, format!("Bearer {}", token))
         .header(header::CONTENT_TYPE, "application/json");
 
@@ -142,6 +152,8 @@ async fn unauthed_request(
     let builder = Request::builder()
         .method(method)
         .uri(uri)
+        // Legacy ErrorResponse shape; problem+json is covered in error_handling_tests
+        .header(header::ACCEPT, "application/json")
         .header(header::CONTENT_TYPE, "application/json");
 
     let body = match body {
@@ -282,6 +294,8 @@ async fn test_hd_invalid_input_missing_birth_data() {
 
     let input = EngineInput {
         birth_data: None,
+        secondary_birth_data: None,
+        subjects: Vec::new(),
         current_time: chrono::Utc::now(),
         location: None,
         precision: noesis_core::Precision::Standard,
@@ -312,12 +326,19 @@ async fn test_hd_invalid_input_missing_time() {
     let input = EngineInput {
         birth_data: Some(noesis_core::BirthData {
             name: Some("No Time".to_string()),
+            display_name: None,
             date: "1990-01-15".to_string(),
-            time: None, // HD requires time
+            time: None,
+            time_unknown: false, // HD requires time
             latitude: 40.7128,
             longitude: -74.0060,
+            altitude: None,
             timezone: "America/New_York".to_string(),
+            place: None,
+            utc_offset_minutes: None,
         }),
+        secondary_birth_data: None,
+        subjects: Vec::new(),
         current_time: chrono::Utc::now(),
         location: None,
         precision: noesis_core::Precision::Standard,
@@ -357,12 +378,19 @@ async fn test_hd_idempotent_results() {
     let input = EngineInput {
         birth_data: Some(noesis_core::BirthData {
             name: Some("Idempotent Test".to_string()),
+            display_name: None,
             date: "1990-01-15".to_string(),
             time: Some("14:30".to_string()),
+            time_unknown: false,
             latitude: 40.7128,
             longitude: -74.0060,
+            altitude: None,
             timezone: "America/New_York".to_string(),
+            place: None,
+            utc_offset_minutes: None,
         }),
+        secondary_birth_data: None,
+        subjects: Vec::new(),
         current_time: fixed_time,
         location: None,
         precision: noesis_core::Precision::Standard,
@@ -445,6 +473,8 @@ async fn test_gene_keys_full_chart_e2e_mode2() {
 
     let input = EngineInput {
         birth_data: None,
+        secondary_birth_data: None,
+        subjects: Vec::new(),
         current_time: chrono::Utc::now(),
         location: None,
         precision: noesis_core::Precision::Standard,
@@ -560,6 +590,8 @@ async fn test_gene_keys_hd_integration_mode() {
 
         let gk_input = EngineInput {
             birth_data: None,
+            secondary_birth_data: None,
+            subjects: Vec::new(),
             current_time: chrono::Utc::now(),
             location: None,
             precision: noesis_core::Precision::Standard,
@@ -603,6 +635,8 @@ async fn test_gene_keys_auth_required() {
 
     let input = EngineInput {
         birth_data: None,
+        secondary_birth_data: None,
+        subjects: Vec::new(),
         current_time: chrono::Utc::now(),
         location: None,
         precision: noesis_core::Precision::Standard,
@@ -633,6 +667,8 @@ async fn test_gene_keys_phase_gating() {
 
     let input = EngineInput {
         birth_data: None,
+        secondary_birth_data: None,
+        subjects: Vec::new(),
         current_time: chrono::Utc::now(),
         location: None,
         precision: noesis_core::Precision::Standard,
@@ -660,6 +696,8 @@ async fn test_gene_keys_invalid_input_no_data() {
 
     let input = EngineInput {
         birth_data: None,
+        secondary_birth_data: None,
+        subjects: Vec::new(),
         current_time: chrono::Utc::now(),
         location: None,
         precision: noesis_core::Precision::Standard,
@@ -697,6 +735,8 @@ async fn test_gene_keys_invalid_gate_range() {
 
     let input = EngineInput {
         birth_data: None,
+        secondary_birth_data: None,
+        subjects: Vec::new(),
         current_time: chrono::Utc::now(),
         location: None,
         precision: noesis_core::Precision::Standard,
@@ -732,6 +772,8 @@ async fn test_gene_keys_idempotent_results() {
 
     let input = EngineInput {
         birth_data: None,
+        secondary_birth_data: None,
+        subjects: Vec::new(),
         current_time: chrono::Utc::now(),
         location: None,
         precision: noesis_core::Precision::Standard,
@@ -803,6 +845,8 @@ async fn test_vimshottari_full_timeline_e2e() {
 
     let input = EngineInput {
         birth_data: None,
+        secondary_birth_data: None,
+        subjects: Vec::new(),
         current_time: chrono::Utc::now(),
         location: None,
         precision: noesis_core::Precision::Standard,
@@ -907,6 +951,8 @@ async fn test_vimshottari_moon_longitude_mode() {
 
     let input = EngineInput {
         birth_data: None,
+        secondary_birth_data: None,
+        subjects: Vec::new(),
         current_time: chrono::Utc::now(),
         location: None,
         precision: noesis_core::Precision::Standard,
@@ -978,6 +1024,8 @@ async fn test_vimshottari_invalid_input_no_data() {
 
     let input = EngineInput {
         birth_data: None,
+        secondary_birth_data: None,
+        subjects: Vec::new(),
         current_time: chrono::Utc::now(),
         location: None,
         precision: noesis_core::Precision::Standard,
@@ -1011,6 +1059,8 @@ async fn test_vimshottari_invalid_moon_longitude() {
 
     let input = EngineInput {
         birth_data: None,
+        secondary_birth_data: None,
+        subjects: Vec::new(),
         current_time: chrono::Utc::now(),
         location: None,
         precision: noesis_core::Precision::Standard,
@@ -1045,6 +1095,8 @@ async fn test_vimshottari_date_continuity() {
 
     let input = EngineInput {
         birth_data: None,
+        secondary_birth_data: None,
+        subjects: Vec::new(),
         current_time: chrono::Utc::now(),
         location: None,
         precision: noesis_core::Precision::Standard,
@@ -1096,6 +1148,8 @@ async fn test_vimshottari_idempotent_results() {
 
     let input = EngineInput {
         birth_data: None,
+        secondary_birth_data: None,
+        subjects: Vec::new(),
         current_time: chrono::Utc::now(),
         location: None,
         precision: noesis_core::Precision::Standard,
@@ -1180,6 +1234,26 @@ async fn test_all_three_engines_registered() {
     assert!(engine_ids.contains(&"vimshottari"), "vimshottari not in engine list");
 }
 
+/// Health: every registered engine passes its self-check; HD finds its ephemeris files.
+#[tokio::test]
+async fn test_engine_health_reports_every_engine() {
+    let token = test_jwt(0);
+
+    let (status, body) = authed_request("GET", "/api/v1/engines/health", &token, None).await;
+
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["status"], "healthy", "unexpected engine health: {}", body);
+
+    let engines = body["engines"].as_array().unwrap();
+    let hd = engines
+        .iter()
+        .find(|e| e["engine_id"] == "human-design")
+        .expect("human-design missing from health report");
+    assert_eq!(hd["healthy"], true);
+    assert_eq!(hd["critical"], true);
+    assert!(engines.iter().any(|e| e["engine_id"] == "gene-keys" && e["critical"] == false));
+}
+
 /// Metrics: After calculations, Prometheus metrics contain engine data.
 #[tokio::test]
 async fn test_metrics_contain_engine_calculations() {
@@ -1194,6 +1268,8 @@ async fn test_metrics_contain_engine_calculations() {
 
     let input = EngineInput {
         birth_data: None,
+        secondary_birth_data: None,
+        subjects: Vec::new(),
         current_time: chrono::Utc::now(),
         location: None,
         precision: noesis_core::Precision::Standard,
@@ -1274,6 +1350,8 @@ async fn test_concurrent_multi_engine_calculations() {
     }));
     let gk_input = serde_json::to_value(&EngineInput {
         birth_data: None,
+        secondary_birth_data: None,
+        subjects: Vec::new(),
         current_time: chrono::Utc::now(),
         location: None,
         precision: noesis_core::Precision::Standard,
@@ -1287,6 +1365,8 @@ async fn test_concurrent_multi_engine_calculations() {
     vim_options.insert("birth_date".to_string(), json!("1988-03-20"));
     let vim_input = serde_json::to_value(&EngineInput {
         birth_data: None,
+        secondary_birth_data: None,
+        subjects: Vec::new(),
         current_time: chrono::Utc::now(),
         location: None,
         precision: noesis_core::Precision::Standard,
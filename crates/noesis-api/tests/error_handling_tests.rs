@@ -9,10 +9,13 @@ use axum::{
     http::{header, Request, StatusCode},
     Router,
 };
-use noesis_api::{build_app_state_lazy_db, create_router, ApiConfig};
+use noesis_api::database::DatabaseStatus;
+use noesis_api::versioning::ApiVersion;
+use noesis_api::{build_app_state_lazy_db, create_router, ApiConfig, AppState};
 use noesis_auth::AuthService;
 use noesis_core::EngineInput;
 use serde_json::{json, Value};
+use std::sync::Arc;
 use tokio::sync::OnceCell;
 use tower::ServiceExt;
 
@@ -20,15 +23,19 @@ use tower::ServiceExt;
 // Test fixtures (shared singleton router)
 // ---------------------------------------------------------------------------
 
+static ERROR_TEST_STATE: OnceCell<AppState> = OnceCell::const_new();
 static ERROR_TEST_ROUTER: OnceCell<Router> = OnceCell::const_new();
 
+/// Metrics register globally, so every router in this binary shares one state
+async fn get_state() -> &'static AppState {
+    ERROR_TEST_STATE
+        .get_or_init(|| async { build_app_state_lazy_db(&ApiConfig::from_env()).await })
+        .await
+}
+
 async fn get_router() -> &'static Router {
     ERROR_TEST_ROUTER
-        .get_or_init(|| async {
-            let config = ApiConfig::from_env();
-            let state = build_app_state_lazy_db(&config).await;
-            create_router(state, &config)
-        })
+        .get_or_init(|| async { create_router(get_state().await.clone(), &ApiConfig::from_env()) })
         .await
 }
 
@@ -49,17 +56,25 @@ fn create_birth_input() -> EngineInput {
     EngineInput {
         birth_data: Some(noesis_core::BirthData {
             name: Some("Error Test User".to_string()),
+            display_name: None,
             date: "1990-01-15".to_string(),
             time: Some("14:30".to_string()),
+            time_unknown: false,
             latitude: 12.9716,
             longitude: 77.5946,
+            altitude: None,
             timezone: "Asia/Kolkata".to_string(),
+            place: None,
+            utc_offset_minutes: None,
         }),
+        secondary_birth_data: None,
+        subjects: Vec::new(),
         current_time: chrono::Utc::now(),
         location: Some(noesis_core::Coordinates {
             latitude: 12.9716,
             longitude: 77.5946,
             altitude: None,
+            timezone: None,
         }),
         precision: noesis_core::Precision::Standard,
         options: std::collections::HashMap::new(),
@@ -77,6 +92,8 @@ async fn send_authenticated(
     let request_builder = Request::builder()
         .method(method)
         .uri(uri)
+        // Legacy ErrorResponse shape; problem+json is covered in the tests below
+        .header(header::ACCEPT, "application/json")
         .header(header::AUTHORIZATION, format!("Bearer {}", token))
         .header(header::CONTENT_TYPE, "application/json");
 
@@ -114,6 +131,8 @@ async fn send_unauthenticated(
     let request_builder = Request::builder()
         .method(method)
         .uri(uri)
+        // Legacy ErrorResponse shape; problem+json is covered in the tests below
+        .header(header::ACCEPT, "application/json")
         .header(header::CONTENT_TYPE, "application/json");
 
     let body = if let Some(json_body) = body {
@@ -199,6 +218,14 @@ async fn test_unauthorized_no_token_list_engines() {
     assert_eq!(body["error_code"], "UNAUTHORIZED");
 }
 
+#[tokio::test]
+async fn test_unauthorized_no_token_delete_account() {
+    let (status, _, body) = send_unauthenticated("DELETE", "/api/v1/users/me", None).await;
+
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+    assert_eq!(body["error_code"], "UNAUTHORIZED");
+}
+
 // ---------------------------------------------------------------------------
 // 403 Forbidden tests (Phase Access Denied)
 // ---------------------------------------------------------------------------
@@ -628,6 +655,28 @@ async fn test_metrics_endpoint_no_auth_required() {
 
     let response = router.clone().oneshot(request).await.unwrap();
     assert_eq!(response.status(), StatusCode::OK);
+
+    // Pool gauges are sampled on every scrape
+    let bytes = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    let text = String::from_utf8_lossy(&bytes);
+    assert!(text.contains(r#"noesis_db_pool_max_connections{pool="primary"} 5"#));
+    assert!(text.contains("noesis_db_pool_connections"));
+
+    // Scrapers that accept OpenMetrics get it, so exemplars can be attached
+    let request = Request::builder()
+        .method("GET")
+        .uri("/metrics")
+        .header("accept", "application/openmetrics-text;version=1.0.0,text/plain;version=0.0.4;q=0.5")
+        .body(Body::empty())
+        .unwrap();
+    let response = router.clone().oneshot(request).await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    assert!(response.headers()["content-type"]
+        .to_str()
+        .unwrap()
+        .starts_with("application/openmetrics-text"));
+    let bytes = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    assert!(String::from_utf8_lossy(&bytes).ends_with("# EOF\n"));
 }
 
 // ---------------------------------------------------------------------------
@@ -702,3 +751,901 @@ async fn test_error_code_mapping_unauthorized() {
     assert_eq!(status, StatusCode::UNAUTHORIZED);
     assert_eq!(body["error_code"], "UNAUTHORIZED");
 }
+
+// ---------------------------------------------------------------------------
+// RFC 7807 problem+json responses
+// ---------------------------------------------------------------------------
+
+/// Helper: send a request with an optional bearer token and Accept header,
+/// returning the raw body alongside its parsed JSON.
+async fn send_with_accept(
+    method: &str,
+    uri: &str,
+    token: Option<&str>,
+    accept: Option<&str>,
+    body: Body,
+) -> (StatusCode, axum::http::HeaderMap, Value) {
+    let router = get_router().await;
+    let mut builder = Request::builder()
+        .method(method)
+        .uri(uri)
+        .header(header::CONTENT_TYPE, "application/json");
+    if let Some(token) = token {
+        builder = builder.header(header::AUTHORIZATION, format!("Bearer {}", token));
+    }
+    if let Some(accept) = accept {
+        builder = builder.header(header::ACCEPT, accept);
+    }
+
+    let response = router.clone().oneshot(builder.body(body).unwrap()).await.unwrap();
+    let status = response.status();
+    let headers = response.headers().clone();
+    let body_bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    (status, headers, serde_json::from_slice(&body_bytes).unwrap_or(json!({})))
+}
+
+#[tokio::test]
+async fn test_problem_json_is_default_error_format() {
+    let (status, headers, body) =
+        send_with_accept("GET", "/api/v1/engines", None, None, Body::empty()).await;
+
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+    assert_eq!(headers[header::CONTENT_TYPE], "application/problem+json");
+    assert_eq!(body["type"], "https://docs.selemene.io/problems/unauthorized");
+    assert_eq!(body["title"], "Unauthorized");
+    assert_eq!(body["status"], 401);
+    assert!(body["detail"].as_str().unwrap().contains("Authentication required"));
+    assert_eq!(body["instance"], "/api/v1/engines");
+    assert_eq!(body["error_code"], "UNAUTHORIZED");
+    assert!(body.get("error").is_none());
+}
+
+#[tokio::test]
+async fn test_problem_json_keeps_details_extension() {
+    let token = generate_token(5);
+    let input = serde_json::to_vec(&create_birth_input()).unwrap();
+
+    let (status, headers, body) = send_with_accept(
+        "POST",
+        "/api/v1/engines/missing-engine/calculate",
+        Some(&token),
+        Some("application/problem+json, application/json;q=0.5"),
+        Body::from(input),
+    )
+    .await;
+
+    assert_eq!(status, StatusCode::NOT_FOUND);
+    assert_eq!(headers[header::CONTENT_TYPE], "application/problem+json");
+    assert_eq!(body["error_code"], "ENGINE_NOT_FOUND");
+    assert_eq!(body["details"]["engine_id"], "missing-engine");
+    // Rate limit headers set by the inner middleware survive the rewrite
+    assert!(headers.contains_key("x-ratelimit-limit"));
+}
+
+#[tokio::test]
+async fn test_problem_json_wraps_malformed_body_rejection() {
+    let token = generate_token(5);
+
+    let (status, headers, body) = send_with_accept(
+        "POST",
+        "/api/v1/engines/panchanga/calculate",
+        Some(&token),
+        None,
+        Body::from("{not json"),
+    )
+    .await;
+
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(headers[header::CONTENT_TYPE], "application/problem+json");
+    assert_eq!(body["error_code"], "BAD_REQUEST");
+    assert!(body["detail"].is_string());
+}
+
+#[tokio::test]
+async fn test_accept_json_keeps_legacy_error_shape() {
+    let (status, headers, body) = send_with_accept(
+        "GET",
+        "/api/v1/engines",
+        None,
+        Some("application/json"),
+        Body::empty(),
+    )
+    .await;
+
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+    assert_eq!(headers[header::CONTENT_TYPE], "application/json");
+    assert!(body["error"].is_string());
+    assert_eq!(body["error_code"], "UNAUTHORIZED");
+    assert!(body.get("type").is_none());
+}
+
+// ---------------------------------------------------------------------------
+// Request ID correlation
+// ---------------------------------------------------------------------------
+
+async fn send_with_request_id(
+    accept: &str,
+    request_id: Option<&str>,
+) -> (StatusCode, axum::http::HeaderMap, Value) {
+    let router = get_router().await;
+    let mut builder = Request::builder()
+        .method("GET")
+        .uri("/api/v1/engines")
+        .header(header::ACCEPT, accept);
+    if let Some(request_id) = request_id {
+        builder = builder.header("x-request-id", request_id);
+    }
+
+    let response = router
+        .clone()
+        .oneshot(builder.body(Body::empty()).unwrap())
+        .await
+        .unwrap();
+    let status = response.status();
+    let headers = response.headers().clone();
+    let body_bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    (status, headers, serde_json::from_slice(&body_bytes).unwrap_or(json!({})))
+}
+
+#[tokio::test]
+async fn test_request_id_is_generated_and_returned_in_problem() {
+    let (status, headers, body) = send_with_request_id("application/problem+json", None).await;
+
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+    let request_id = headers["x-request-id"].to_str().unwrap();
+    assert!(uuid::Uuid::parse_str(request_id).is_ok());
+    assert_eq!(body["request_id"], request_id);
+}
+
+#[tokio::test]
+async fn test_client_request_id_is_echoed_in_legacy_error() {
+    let (status, headers, body) =
+        send_with_request_id("application/json", Some("support-ticket-1234")).await;
+
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+    assert_eq!(headers["x-request-id"], "support-ticket-1234");
+    assert_eq!(body["error_code"], "UNAUTHORIZED");
+    assert_eq!(body["request_id"], "support-ticket-1234");
+}
+
+#[tokio::test]
+async fn test_unusable_client_request_id_is_replaced() {
+    let too_long = "a".repeat(200);
+    let (_, headers, body) = send_with_request_id("application/json", Some(&too_long)).await;
+
+    let request_id = headers["x-request-id"].to_str().unwrap();
+    assert_ne!(request_id, too_long);
+    assert!(uuid::Uuid::parse_str(request_id).is_ok());
+    assert_eq!(body["request_id"], request_id);
+}
+
+// ---------------------------------------------------------------------------
+// Share links: checked before the database, no auth required
+// ---------------------------------------------------------------------------
+
+fn share_links() -> noesis_api::sharing::ShareLinks {
+    let jwt_secret = std::env::var("JWT_SECRET")
+        .unwrap_or_else(|_| "noesis-dev-secret-change-in-production".to_string());
+    noesis_api::sharing::ShareLinks::new(&jwt_secret, 3600, None)
+}
+
+#[tokio::test]
+async fn test_forged_share_link_not_found_without_auth() {
+    let forged = noesis_api::sharing::ShareLinks::new("not-the-server-secret-at-all-xxxx", 3600, None)
+        .sign(uuid::Uuid::new_v4(), chrono::Utc::now() + chrono::Duration::hours(1));
+    let (status, _, body) =
+        send_unauthenticated("GET", &format!("/api/v1/shared/{}", forged), None).await;
+
+    assert_eq!(status, StatusCode::NOT_FOUND);
+    assert_eq!(body["error_code"], "SHARE_LINK_NOT_FOUND");
+}
+
+#[tokio::test]
+async fn test_expired_share_link_is_gone() {
+    let expired = share_links().sign(
+        uuid::Uuid::new_v4(),
+        chrono::Utc::now() - chrono::Duration::seconds(1),
+    );
+    let (status, _, body) =
+        send_unauthenticated("GET", &format!("/api/v1/shared/{}", expired), None).await;
+
+    assert_eq!(status, StatusCode::GONE);
+    assert_eq!(body["error_code"], "SHARE_LINK_EXPIRED");
+}
+
+#[tokio::test]
+async fn test_share_result_requires_auth() {
+    let uri = format!("/api/v1/results/{}/share", uuid::Uuid::new_v4());
+    let (status, _, _) = send_unauthenticated("POST", &uri, None).await;
+
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+}
+
+#[tokio::test]
+async fn test_annotate_result_requires_auth() {
+    let uri = format!("/api/v1/results/{}/annotations", uuid::Uuid::new_v4());
+    let body = serde_json::json!({ "note": "Borderline authority" });
+    let (status, _, _) = send_unauthenticated("POST", &uri, Some(body)).await;
+
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+}
+
+// ---------------------------------------------------------------------------
+// Client endpoints: scopes are checked before the database
+// ---------------------------------------------------------------------------
+
+#[tokio::test]
+async fn test_client_endpoints_require_client_scopes() {
+    let token = generate_token(5);
+    let client_id = uuid::Uuid::new_v4();
+
+    let (status, _, body) = send_authenticated("GET", "/api/v1/clients", &token, None).await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+    assert_eq!(body["error_code"], "FORBIDDEN");
+    assert_eq!(body["details"]["required_permission"], "clients:read");
+
+    let uri = format!("/api/v1/clients/{}/workflows/birth-blueprint/execute", client_id);
+    let (status, _, body) = send_authenticated("POST", &uri, &token, Some(json!({}))).await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+    assert_eq!(body["details"]["required_permission"], "clients:calculate");
+}
+
+#[tokio::test]
+async fn test_experiment_admin_lifecycle() {
+    let jwt_secret = std::env::var("JWT_SECRET")
+        .unwrap_or_else(|_| "noesis-dev-secret-change-in-production".to_string());
+    let admin = AuthService::new(jwt_secret)
+        .generate_jwt_token("experiment-admin", "enterprise", &["admin:experiments".to_string()], 5)
+        .expect("Failed to generate admin JWT");
+    let user = generate_token(5);
+    let experiment = json!({
+        "engine_id": "numerology",
+        "variants": [{ "name": "treatment", "weight": 1, "options": { "system": "chaldean" } }]
+    });
+
+    let (status, _, body) =
+        send_authenticated("PUT", "/api/v1/admin/experiments/test-lifecycle", &user, Some(experiment.clone())).await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+    assert_eq!(body["details"]["required_permission"], "admin:experiments");
+
+    let (status, _, body) =
+        send_authenticated("PUT", "/api/v1/admin/experiments/test-lifecycle", &admin, Some(experiment)).await;
+    assert_eq!(status, StatusCode::CREATED);
+    assert_eq!(body["enabled"], true);
+
+    let (status, _, body) = send_authenticated("GET", "/api/v1/admin/experiments", &admin, None).await;
+    assert_eq!(status, StatusCode::OK);
+    assert!(body["experiments"].as_array().unwrap().iter().any(|e| e["id"] == "test-lifecycle"));
+
+    let uri = "/api/v1/experiments/test-lifecycle/outcomes";
+    let (status, _, body) = send_authenticated("POST", uri, &user, Some(json!({ "outcome": "prompt_answered" }))).await;
+    assert_eq!(status, StatusCode::ACCEPTED);
+    assert_eq!(body["variant"], "treatment");
+
+    let (status, _, _) = send_authenticated("POST", uri, &user, Some(json!({ "outcome": "success" }))).await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+
+    let (status, _, _) = send_authenticated("DELETE", "/api/v1/admin/experiments/test-lifecycle", &admin, None).await;
+    assert_eq!(status, StatusCode::NO_CONTENT);
+    let (status, _, body) = send_authenticated("DELETE", "/api/v1/admin/experiments/test-lifecycle", &admin, None).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+    assert_eq!(body["error_code"], "EXPERIMENT_NOT_FOUND");
+}
+
+#[tokio::test]
+async fn test_custom_workflow_engine_overrides() {
+    let jwt_secret = std::env::var("JWT_SECRET")
+        .unwrap_or_else(|_| "noesis-dev-secret-change-in-production".to_string());
+    let admin = AuthService::new(jwt_secret)
+        .generate_jwt_token("workflow-admin", "enterprise", &["admin:workflows".to_string()], 5)
+        .expect("Failed to generate admin JWT");
+    let user = generate_token(5);
+    let workflow = json!({
+        "name": "Monthly Rhythm",
+        "engine_ids": ["biorhythm", "numerology"],
+        "engine_overrides": { "biorhythm": { "forecast_days": 30 } }
+    });
+    let uri = "/api/v1/admin/workflows/test-monthly";
+
+    let (status, _, body) = send_authenticated("PUT", uri, &user, Some(workflow.clone())).await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+    assert_eq!(body["details"]["required_permission"], "admin:workflows");
+
+    let (status, _, body) = send_authenticated("PUT", uri, &admin, Some(workflow)).await;
+    assert_eq!(status, StatusCode::CREATED);
+    assert_eq!(body["engine_overrides"]["biorhythm"]["forecast_days"], 30);
+
+    let (status, _, body) =
+        send_authenticated("GET", "/api/v1/workflows/test-monthly/info", &user, None).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["engine_overrides"]["biorhythm"]["forecast_days"], 30);
+
+    let input = serde_json::to_value(create_birth_input()).unwrap();
+    let (status, _, body) =
+        send_authenticated("POST", "/api/v1/workflows/test-monthly/execute", &user, Some(input)).await;
+    assert_eq!(status, StatusCode::OK);
+    let forecast = body["engine_outputs"]["biorhythm"]["result"]["forecast"].as_array().unwrap();
+    assert_eq!(forecast.len(), 30);
+
+    let bad = json!({ "name": "Bad", "engine_ids": ["biorhythm"], "engine_overrides": { "panchanga": {} } });
+    let (status, _, body) = send_authenticated("PUT", uri, &admin, Some(bad)).await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    assert_eq!(body["details"]["errors"][0]["field"], "engine_overrides.panchanga");
+
+    let bad = json!({ "name": "Bad", "engine_ids": ["biorhythm"], "phase_variants": { "9": {} } });
+    let (status, _, body) = send_authenticated("PUT", uri, &admin, Some(bad)).await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    assert_eq!(body["details"]["errors"][0]["field"], "phase_variants.9");
+
+    let (status, _, _) =
+        send_authenticated("DELETE", "/api/v1/admin/workflows/daily-practice", &admin, None).await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    let (status, _, _) = send_authenticated("DELETE", uri, &admin, None).await;
+    assert_eq!(status, StatusCode::NO_CONTENT);
+    let (status, _, body) = send_authenticated("DELETE", uri, &admin, None).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+    assert_eq!(body["error_code"], "WORKFLOW_NOT_FOUND");
+}
+
+#[tokio::test]
+async fn test_unknown_options_are_reported() {
+    let token = generate_token(5);
+    let mut input = serde_json::to_value(create_birth_input()).unwrap();
+    input["options"] = json!({"forcast_days": 3});
+
+    let (status, _, output) =
+        send_authenticated("POST", "/api/v1/engines/biorhythm/calculate", &token, Some(input.clone())).await;
+    assert_eq!(status, StatusCode::OK);
+    let warning = "Unknown option 'forcast_days' ignored by biorhythm; did you mean 'forecast_days'?";
+    assert_eq!(output["metadata"]["warnings"], json!([warning]));
+
+    let (status, _, validation) =
+        send_authenticated("POST", "/api/v1/engines/biorhythm/validate", &token, Some(output)).await;
+    assert_eq!(status, StatusCode::OK);
+    assert!(validation["messages"].as_array().unwrap().iter().any(|m| m == warning));
+
+    let (_, _, info) = send_authenticated("GET", "/api/v1/engines/biorhythm/info", &token, None).await;
+    assert!(info["option_keys"].as_array().unwrap().iter().any(|k| k == "forecast_days"));
+
+    input["options"] = json!({"forecast_days": "three"});
+    let (status, _, body) =
+        send_authenticated("POST", "/api/v1/engines/biorhythm/calculate", &token, Some(input)).await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    assert_eq!(body["details"]["errors"][0]["field"], "options.forecast_days");
+}
+
+#[tokio::test]
+async fn test_engine_version_cache_invalidation() {
+    let jwt_secret = std::env::var("JWT_SECRET")
+        .unwrap_or_else(|_| "noesis-dev-secret-change-in-production".to_string());
+    let admin = AuthService::new(jwt_secret)
+        .generate_jwt_token("cache-admin", "enterprise", &["admin:cache".to_string()], 5)
+        .expect("Failed to generate admin JWT");
+    let user = generate_token(5);
+    let mut input = create_birth_input();
+    // A name no other test uses, so nothing else fills this cache entry
+    input.birth_data.as_mut().unwrap().name = Some("Cache Version Test".to_string());
+    let input = serde_json::to_value(input).unwrap();
+    let calculate = "/api/v1/engines/numerology/calculate";
+
+    let (status, _, body) = send_authenticated("POST", calculate, &user, Some(input.clone())).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["metadata"]["engine_version"], "1");
+    let (_, _, body) = send_authenticated("POST", calculate, &user, Some(input.clone())).await;
+    assert_eq!(body["metadata"]["cached"], true);
+
+    let (_, _, info) = send_authenticated("GET", "/api/v1/engines/numerology/info", &user, None).await;
+    assert_eq!(info["engine_version"], "1");
+
+    let uri = "/api/v1/admin/cache/engines/numerology/versions/1";
+    let (status, _, body) = send_authenticated("DELETE", uri, &user, None).await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+    assert_eq!(body["details"]["required_permission"], "admin:cache");
+
+    let (status, _, body) = send_authenticated("DELETE", uri, &admin, None).await;
+    assert_eq!(status, StatusCode::OK);
+    assert!(body["invalidated"].as_u64().unwrap() >= 1);
+    assert_eq!(body["current_version"], "1");
+
+    let (_, _, body) = send_authenticated("POST", calculate, &user, Some(input)).await;
+    assert_eq!(body["metadata"]["cached"], false);
+}
+
+#[tokio::test]
+async fn test_recalculation_admin_checks() {
+    let jwt_secret = std::env::var("JWT_SECRET")
+        .unwrap_or_else(|_| "noesis-dev-secret-change-in-production".to_string());
+    let admin = AuthService::new(jwt_secret)
+        .generate_jwt_token("recalc-admin", "enterprise", &["admin:recalculate".to_string()], 5)
+        .expect("Failed to generate admin JWT");
+    let user = generate_token(5);
+
+    let uri = "/api/v1/admin/recalculate?engine=numerology";
+    let (status, _, body) = send_authenticated("POST", uri, &user, None).await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+    assert_eq!(body["details"]["required_permission"], "admin:recalculate");
+
+    let (status, _, body) =
+        send_authenticated("POST", "/api/v1/admin/recalculate?engine=no-such-engine", &admin, None).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+    assert_eq!(body["error_code"], "ENGINE_NOT_FOUND");
+
+    // numerology calculates with version 1
+    let (status, _, body) =
+        send_authenticated("POST", "/api/v1/admin/recalculate?engine=numerology&since_version=3", &admin, None).await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    assert_eq!(body["details"]["errors"][0]["field"], "since_version");
+
+    let (status, _, body) =
+        send_authenticated("POST", "/api/v1/admin/recalculate?engine=numerology&concurrency=0", &admin, None).await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    assert_eq!(body["details"]["errors"][0]["field"], "concurrency");
+
+    let missing = format!("/api/v1/admin/recalculate/{}", uuid::Uuid::new_v4());
+    let (status, _, body) = send_authenticated("GET", &missing, &admin, None).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+    assert_eq!(body["error_code"], "RECALCULATION_NOT_FOUND");
+
+    // A job with nothing to do finishes at once and stays listed
+    let state = get_state().await;
+    let job = state
+        .recalculations
+        .start(state.core.orchestrator.clone(), "numerology", "1", Vec::new(), 1, None)
+        .unwrap();
+    let mut progress = job.subscribe();
+    progress
+        .wait_for(|p| p.status == noesis_api::recalculate::RecalculationStatus::Completed)
+        .await
+        .unwrap();
+    let (status, _, body) =
+        send_authenticated("GET", &format!("/api/v1/admin/recalculate/{}", job.id()), &admin, None).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["status"], "completed");
+    assert_eq!(body["engine_version"], "1");
+    assert_eq!(body["total"], 0);
+    assert!(body["failures"].as_array().unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_research_export_admin_checks() {
+    let jwt_secret = std::env::var("JWT_SECRET")
+        .unwrap_or_else(|_| "noesis-dev-secret-change-in-production".to_string());
+    let admin = AuthService::new(jwt_secret)
+        .generate_jwt_token("research-admin", "enterprise", &["admin:research-export".to_string()], 5)
+        .expect("Failed to generate admin JWT");
+    let user = generate_token(5);
+
+    let (status, _, body) = send_authenticated("POST", "/api/v1/admin/research-exports", &user, None).await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+    assert_eq!(body["details"]["required_permission"], "admin:research-export");
+
+    // Mock and bridged engines have no research schema
+    let (status, _, body) =
+        send_authenticated("POST", "/api/v1/admin/research-exports?engines=numerology,biofield", &admin, None).await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    assert_eq!(body["details"]["errors"][0]["field"], "engines");
+
+    let (status, _, body) =
+        send_authenticated("POST", "/api/v1/admin/research-exports?batch_size=0", &admin, None).await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    assert_eq!(body["details"]["errors"][0]["field"], "batch_size");
+
+    // The test server has no export bucket
+    let (status, _, body) = send_authenticated("POST", "/api/v1/admin/research-exports", &admin, None).await;
+    assert_eq!(status, StatusCode::SERVICE_UNAVAILABLE);
+    assert_eq!(body["error_code"], "RESEARCH_EXPORT_NOT_CONFIGURED");
+
+    let missing = format!("/api/v1/admin/research-exports/{}", uuid::Uuid::new_v4());
+    let (status, _, body) = send_authenticated("GET", &missing, &admin, None).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+    assert_eq!(body["error_code"], "RESEARCH_EXPORT_NOT_FOUND");
+}
+
+#[tokio::test]
+async fn test_debug_option_requires_admin_debug() {
+    let jwt_secret = std::env::var("JWT_SECRET")
+        .unwrap_or_else(|_| "noesis-dev-secret-change-in-production".to_string());
+    let admin = AuthService::new(jwt_secret)
+        .generate_jwt_token("debug-admin", "enterprise", &["admin:debug".to_string()], 5)
+        .expect("Failed to generate admin JWT");
+    let user = generate_token(5);
+    let mut input = create_birth_input();
+    input.options.insert("debug".to_string(), serde_json::json!(true));
+    let input = serde_json::to_value(input).unwrap();
+
+    let calculate = "/api/v1/engines/numerology/calculate";
+    let (status, _, body) = send_authenticated("POST", calculate, &user, Some(input.clone())).await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+    assert_eq!(body["details"]["required_permission"], "admin:debug");
+
+    let (status, _, body) = send_authenticated("POST", calculate, &admin, Some(input.clone())).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["result"]["debug"]["letters"][0]["letter"], "E");
+    assert_eq!(body["result"]["debug"]["life_path_parts"]["year"], serde_json::json!([1990, 19, 10, 1]));
+    // A common option, so not reported as unknown
+    assert!(!body["metadata"]["warnings"].to_string().contains("debug"));
+
+    let (status, _, _) =
+        send_authenticated("POST", "/api/v1/workflows/birth-blueprint/execute", &user, Some(input)).await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+
+    let replay = format!("/api/v1/admin/results/{}/replay", uuid::Uuid::new_v4());
+    let (status, _, body) = send_authenticated("POST", &replay, &user, None).await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+    assert_eq!(body["details"]["required_permission"], "admin:debug");
+}
+
+#[tokio::test]
+async fn test_cache_stats_report_hot_keys() {
+    let jwt_secret = std::env::var("JWT_SECRET")
+        .unwrap_or_else(|_| "noesis-dev-secret-change-in-production".to_string());
+    let admin = AuthService::new(jwt_secret)
+        .generate_jwt_token("cache-admin", "enterprise", &["admin:cache".to_string()], 5)
+        .expect("Failed to generate admin JWT");
+    let user = generate_token(5);
+    let mut input = create_birth_input();
+    input.birth_data.as_mut().unwrap().name = Some("Hot Key Test".to_string());
+    let input = serde_json::to_value(input).unwrap();
+    for _ in 0..25 {
+        let (status, _, _) =
+            send_authenticated("POST", "/api/v1/engines/numerology/calculate", &user, Some(input.clone())).await;
+        assert_eq!(status, StatusCode::OK);
+    }
+
+    let (status, _, body) = send_authenticated("GET", "/api/v1/admin/cache/stats", &user, None).await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+    assert_eq!(body["details"]["required_permission"], "admin:cache");
+
+    let (status, _, body) = send_authenticated("GET", "/api/v1/admin/cache/stats", &admin, None).await;
+    assert_eq!(status, StatusCode::OK, "body: {:?}", body);
+    assert!(body["total_requests"].as_u64().unwrap() >= 25);
+    assert_eq!(body["hot_keys"]["window_secs"], 60);
+    let partitions: Vec<&str> = body["l1_partitions"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|partition| partition["name"].as_str().unwrap())
+        .collect();
+    assert_eq!(partitions, ["gene-keys", "human-design", "vimshottari", "shared"]);
+    // Other tests share the cache, so only require the key to be among the hottest
+    let top = body["hot_keys"]["current"]["top"].as_array().unwrap();
+    assert!(
+        top.iter().any(|hot| hot["requests"].as_u64().unwrap() >= 25 && hot["misses"].as_u64().unwrap() >= 1),
+        "top: {:?}",
+        top
+    );
+}
+
+#[tokio::test]
+async fn test_as_of_before_birth_is_rejected() {
+    let token = generate_token(5);
+    let input = serde_json::to_value(create_birth_input()).unwrap();
+
+    let (status, _, body) = send_authenticated(
+        "POST",
+        "/api/v1/engines/biorhythm/calculate?as_of=1980-01-01",
+        &token,
+        Some(input.clone()),
+    )
+    .await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    assert_eq!(body["details"]["errors"][0]["field"], "options.as_of");
+
+    let (status, _, body) = send_authenticated(
+        "POST",
+        "/api/v1/engines/biorhythm/calculate?as_of=2000-01-15",
+        &token,
+        Some(input),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["result"]["target_date"], "2000-01-15");
+}
+
+#[tokio::test]
+async fn test_current_location_and_saved_location_selection() {
+    let token = generate_token(5);
+    let mut input = serde_json::to_value(create_birth_input()).unwrap();
+    input["options"] = json!({"as_of": "2024-07-01T11:00:00Z"});
+    let calculate = "/api/v1/engines/panchanga/calculate";
+    let (_, _, at_birth_place) = send_authenticated("POST", calculate, &token, Some(input.clone())).await;
+
+    // The reading follows where the person is, with the timezone resolved
+    // from the coordinates
+    input.as_object_mut().unwrap().remove("location");
+    input["current_location"] = json!({"latitude": 38.7223, "longitude": -9.1393, "altitude": null});
+    let (status, _, in_lisbon) = send_authenticated("POST", calculate, &token, Some(input.clone())).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_ne!(in_lisbon["result"]["sunrise"], at_birth_place["result"]["sunrise"]);
+
+    let jwt_secret = std::env::var("JWT_SECRET")
+        .unwrap_or_else(|_| "noesis-dev-secret-change-in-production".to_string());
+    let traveler = AuthService::new(jwt_secret)
+        .generate_jwt_token(&uuid::Uuid::new_v4().to_string(), "premium", &["read".to_string()], 5)
+        .expect("Failed to generate test JWT");
+    let execute = "/api/v1/workflows/daily-practice/execute";
+
+    let (status, _, body) =
+        send_authenticated("POST", &format!("{}?location=home", execute), &traveler, Some(input.clone())).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+    assert_eq!(body["error_code"], "LOCATION_NOT_FOUND");
+
+    input.as_object_mut().unwrap().remove("current_location");
+    let (status, _, body) =
+        send_authenticated("POST", &format!("{}?location=nearest", execute), &traveler, Some(input)).await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    assert_eq!(body["details"]["errors"][0]["field"], "location");
+}
+
+#[tokio::test]
+async fn test_relocation_compares_against_home() {
+    let token = generate_token(5);
+    let mut input = serde_json::to_value(create_birth_input()).unwrap();
+    input["options"] = json!({"as_of": "2024-07-01T11:00:00Z", "relocate_to": {"lat": 40.7128, "lon": -74.0060}});
+    let calculate = "/api/v1/engines/panchanga/calculate";
+
+    let (status, _, body) = send_authenticated("POST", calculate, &token, Some(input.clone())).await;
+    assert_eq!(status, StatusCode::OK);
+    let relocation = &body["result"]["relocation"];
+    // The timezone is resolved from the coordinates when omitted
+    assert_eq!(relocation["relocated_to"]["tz"], "America/New_York");
+    assert!(relocation["relocated"]["local_time"].as_str().unwrap().starts_with("2024-07-01 07:00"));
+    assert!(relocation["changed"].as_array().unwrap().contains(&json!("sunrise")));
+    assert_eq!(body["result"]["sunrise"], relocation["relocated"]["sunrise"]);
+
+    input["options"]["relocate_to"] = json!({"lat": 95.0, "lon": -74.0060});
+    let (status, _, body) = send_authenticated("POST", calculate, &token, Some(input)).await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    assert_eq!(body["details"]["errors"][0]["field"], "options.relocate_to.lat");
+}
+
+#[tokio::test]
+async fn test_practice_log_validation() {
+    let token = generate_token(5);
+    let practices = "/api/v1/users/me/practices";
+
+    let (status, _, body) = send_authenticated(
+        "POST",
+        practices,
+        &token,
+        Some(json!({
+            "practice": "Rest On Critical Day",
+            "engine_id": "astrology",
+            "date": "2999-01-01",
+            "followed": true
+        })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY, "body: {:?}", body);
+    let fields: Vec<&str> = body["details"]["errors"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|e| e["field"].as_str().unwrap())
+        .collect();
+    assert_eq!(fields, ["practice", "engine_id", "date"]);
+
+    let (status, _, body) =
+        send_authenticated("GET", &format!("{}?from=2026-01-01&to=2025-01-01", practices), &token, None).await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    assert_eq!(body["details"]["errors"][0]["field"], "to");
+}
+
+#[tokio::test]
+async fn test_settings_validation() {
+    let token = generate_token(5);
+
+    let (status, _, body) = send_authenticated(
+        "PUT",
+        "/api/v1/users/me/settings",
+        &token,
+        Some(json!({
+            "ayanamsa": "galactic",
+            "design_method": "arc_88deg",
+            "house_system": "placidus"
+        })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY, "body: {:?}", body);
+    let mut fields: Vec<&str> = body["details"]["errors"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|e| e["field"].as_str().unwrap())
+        .collect();
+    fields.sort_unstable();
+    assert_eq!(fields, ["ayanamsa", "house_system"]);
+}
+
+#[tokio::test]
+async fn test_oversized_body_is_rejected_with_413() {
+    let token = generate_token(5);
+    let body = vec![b' '; 1_048_577];
+
+    let (status, headers, body) = send_with_accept(
+        "POST",
+        "/api/v1/engines/numerology/calculate",
+        Some(&token),
+        None,
+        Body::from(body),
+    )
+    .await;
+
+    assert_eq!(status, StatusCode::PAYLOAD_TOO_LARGE);
+    assert_eq!(headers[header::CONTENT_TYPE], "application/problem+json");
+    assert_eq!(body["error_code"], "PAYLOAD_TOO_LARGE");
+    assert_eq!(body["details"]["max_bytes"], 1_048_576);
+}
+
+#[tokio::test]
+async fn test_non_json_body_is_rejected_with_415() {
+    let router = get_router().await;
+    let token = generate_token(5);
+
+    let request = Request::builder()
+        .method("POST")
+        .uri("/api/v1/engines/numerology/calculate")
+        .header(header::AUTHORIZATION, format!("Bearer {}", token))
+        .header(header::CONTENT_TYPE, "text/plain")
+        .body(Body::from(serde_json::to_vec(&create_birth_input()).unwrap()))
+        .unwrap();
+
+    let response = router.clone().oneshot(request).await.unwrap();
+    assert_eq!(response.status(), StatusCode::UNSUPPORTED_MEDIA_TYPE);
+    let bytes = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    let body: Value = serde_json::from_slice(&bytes).unwrap();
+    assert_eq!(body["error_code"], "UNSUPPORTED_MEDIA_TYPE");
+    assert_eq!(body["details"]["content_type"], "text/plain");
+}
+
+#[tokio::test]
+async fn test_input_type_error_names_the_field() {
+    let token = generate_token(5);
+    let mut input = serde_json::to_value(create_birth_input()).unwrap();
+    input["birth_data"]["latitude"] = json!("north");
+
+    let (status, _, body) =
+        send_authenticated("POST", "/api/v1/engines/numerology/calculate", &token, Some(input)).await;
+
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    assert_eq!(body["error_code"], "VALIDATION_ERROR");
+    assert_eq!(body["details"]["errors"][0]["field"], "birth_data.latitude");
+}
+
+#[tokio::test]
+async fn test_strict_json_rejects_unknown_fields() {
+    let token = generate_token(5);
+    let mut input = serde_json::to_value(create_birth_input()).unwrap();
+    input["brith_data"] = json!({});
+
+    // Lenient by default: the typo is ignored
+    let (status, _, _) =
+        send_authenticated("POST", "/api/v1/engines/numerology/calculate", &token, Some(input.clone())).await;
+    assert_eq!(status, StatusCode::OK);
+
+    let config = ApiConfig {
+        strict_json: true,
+        ..ApiConfig::from_env()
+    };
+    let router = create_router(get_state().await.clone(), &config);
+    let request = Request::builder()
+        .method("POST")
+        .uri("/api/v1/engines/numerology/calculate")
+        .header(header::AUTHORIZATION, format!("Bearer {}", token))
+        .header(header::CONTENT_TYPE, "application/json")
+        .body(Body::from(serde_json::to_vec(&input).unwrap()))
+        .unwrap();
+
+    let response = router.oneshot(request).await.unwrap();
+    assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    let bytes = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    let body: Value = serde_json::from_slice(&bytes).unwrap();
+    assert_eq!(body["error_code"], "VALIDATION_ERROR");
+    assert_eq!(body["details"]["errors"][0]["field"], "brith_data");
+    assert_eq!(body["details"]["errors"][0]["code"], "unknown");
+}
+
+#[tokio::test]
+async fn test_api_versions_and_deprecation_headers() {
+    let token = generate_token(5);
+    let input = serde_json::to_value(create_birth_input()).unwrap();
+
+    let (status, headers, v1) =
+        send_authenticated("POST", "/api/v1/engines/numerology/calculate", &token, Some(input.clone())).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(headers["x-api-version"], "v1");
+    assert_eq!(headers["deprecation"], "@1791936000");
+    assert_eq!(headers["sunset"], "Thu, 14 Oct 2027 00:00:00 GMT");
+    assert_eq!(headers[header::LINK], "</api/v2>; rel=\"successor-version\"");
+
+    let (status, headers, v2) =
+        send_authenticated("POST", "/api/v2/engines/numerology/calculate", &token, Some(input.clone())).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(headers["x-api-version"], "v2");
+    assert!(headers.get("deprecation").is_none());
+    assert_eq!(v2["result"], v1["result"]);
+
+    // v2 errors are problem documents even for clients asking for application/json
+    let (status, headers, body) =
+        send_authenticated("POST", "/api/v2/engines/unknown-engine/calculate", &token, Some(input.clone())).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+    assert_eq!(headers[header::CONTENT_TYPE], "application/problem+json");
+    assert_eq!(body["instance"], "/api/v2/engines/unknown-engine/calculate");
+
+    let config = ApiConfig {
+        disabled_api_versions: vec![ApiVersion::V1],
+        ..ApiConfig::from_env()
+    };
+    let router = create_router(get_state().await.clone(), &config);
+    let request = Request::builder()
+        .method("GET")
+        .uri("/api/v1/engines")
+        .header(header::AUTHORIZATION, format!("Bearer {}", token))
+        .body(Body::empty())
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+    assert_eq!(response.status(), StatusCode::GONE);
+    assert_eq!(response.headers()["deprecation"], "@1791936000");
+    let bytes = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    let body: Value = serde_json::from_slice(&bytes).unwrap();
+    assert_eq!(body["error_code"], "API_VERSION_DISABLED");
+    assert_eq!(body["details"]["successor"], "/api/v2");
+}
+
+#[tokio::test]
+async fn test_unreachable_database_starts_degraded() {
+    let config = ApiConfig {
+        database_url: "postgres://postgres@127.0.0.1:1/noesis".to_string(),
+        db_connect_attempts: 1,
+        ..ApiConfig::from_env()
+    };
+    let (_pool, status) = noesis_api::database::connect(&config).await;
+    assert!(!status.is_available());
+}
+
+#[tokio::test]
+async fn test_degraded_mode_serves_engines_but_not_database_routes() {
+    let token = generate_token(5);
+    let mut state = get_state().await.clone();
+    state.database = Arc::new(DatabaseStatus::new(false));
+    let router = create_router(state, &ApiConfig::from_env());
+
+    let send = |method: &str, uri: &str, body: Option<Value>| {
+        let mut builder = Request::builder()
+            .method(method)
+            .uri(uri)
+            .header(header::AUTHORIZATION, format!("Bearer {}", token))
+            .header(header::CONTENT_TYPE, "application/json");
+        if body.is_none() {
+            builder = builder.header(header::ACCEPT, "application/json");
+        }
+        let body = body.map_or_else(Body::empty, |b| Body::from(serde_json::to_vec(&b).unwrap()));
+        router.clone().oneshot(builder.body(body).unwrap())
+    };
+    let input = serde_json::to_value(create_birth_input()).unwrap();
+
+    let response = send("POST", "/api/v1/engines/numerology/calculate", Some(input.clone())).await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let response = send("GET", "/api/v1/users/me", None).await.unwrap();
+    assert_eq!(response.status(), StatusCode::SERVICE_UNAVAILABLE);
+    assert_eq!(response.headers()[header::RETRY_AFTER], "30");
+    let bytes = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    let body: Value = serde_json::from_slice(&bytes).unwrap();
+    assert_eq!(body["error_code"], "DATABASE_UNAVAILABLE");
+
+    let response = send("POST", "/api/v1/auth/login", Some(json!({ "email": "a@b.c", "password": "x" }))).await.unwrap();
+    assert_eq!(response.status(), StatusCode::SERVICE_UNAVAILABLE);
+
+    let response = send("POST", "/api/v1/workflows/birth-blueprint/execute?save=true", Some(input)).await.unwrap();
+    assert_eq!(response.status(), StatusCode::SERVICE_UNAVAILABLE);
+
+    let response = send("GET", "/ready", None).await.unwrap();
+    let bytes = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    let body: Value = serde_json::from_slice(&bytes).unwrap();
+    assert_eq!(body["database"], "degraded");
+}
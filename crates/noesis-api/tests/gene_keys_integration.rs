@@ -58,6 +58,8 @@ async fn make_authenticated_request(
     let request_builder = Request::builder()
         .method(method)
         .uri(uri)
+        // Legacy ErrorResponse shape; problem+json is covered in error_handling_tests
+        .header(header::ACCEPT, "application/json")
         .header(header::AUTHORIZATION, format!("Bearer {}", token))
         .header(header::CONTENT_TYPE, "application/json");
 
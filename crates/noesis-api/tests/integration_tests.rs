@@ -55,22 +55,41 @@ fn generate_test_token(consciousness_level: u8) -> String {
     .expect("Failed to generate test JWT")
 }
 
+/// Generate a valid JWT token carrying the given permissions
+fn generate_test_token_with_permissions(permissions: &[&str]) -> String {
+    let jwt_secret = std::env::var("JWT_SECRET")
+        .unwrap_or_else(|_| "noesis-dev-secret-change-in-production".to_string());
+    let auth = AuthService::new(jwt_secret);
+    let permissions: Vec<String> = permissions.iter().map(|p| p.to_string()).collect();
+
+    auth.generate_jwt_token("test-admin-123", "enterprise", &permissions, 5)
+        .expect("Failed to generate test JWT")
+}
+
 /// Generate a test engine input for birth data calculations
 fn create_test_birth_input() -> EngineInput {
     EngineInput {
         birth_data: Some(noesis_core::BirthData {
             name: Some("Test User".to_string()),
+            display_name: None,
             date: "1990-01-15".to_string(),
             time: Some("14:30".to_string()),
+            time_unknown: false,
             latitude: 12.9716,
             longitude: 77.5946,
+            altitude: None,
             timezone: "Asia/Kolkata".to_string(),
+            place: None,
+            utc_offset_minutes: None,
         }),
+        secondary_birth_data: None,
+        subjects: Vec::new(),
         current_time: chrono::Utc::now(),
         location: Some(noesis_core::Coordinates {
             latitude: 12.9716,
             longitude: 77.5946,
             altitude: None,
+            timezone: None,
         }),
         precision: noesis_core::Precision::Standard,
         options: std::collections::HashMap::new(),
@@ -88,6 +107,8 @@ async fn make_authenticated_request(
     let request_builder = Request::builder()
         .method(method)
         .uri(uri)
+        // Legacy ErrorResponse shape; problem+json is covered in error_handling_tests
+        .header(header::ACCEPT, "application/json")
         .header(header::AUTHORIZATION, format!("Bearer {}", token))
         .header(header::CONTENT_TYPE, "application/json");
 
@@ -124,6 +145,8 @@ async fn make_unauthenticated_request(
     let request_builder = Request::builder()
         .method(method)
         .uri(uri)
+        // Legacy ErrorResponse shape; problem+json is covered in error_handling_tests
+        .header(header::ACCEPT, "application/json")
         .header(header::CONTENT_TYPE, "application/json");
 
     let body = if let Some(json_body) = body {
@@ -169,6 +192,26 @@ async fn test_health_check_no_auth_required() {
     assert_eq!(body["version"], "0.1.0");
 }
 
+#[tokio::test]
+async fn test_public_status_no_auth_required() {
+    let router = get_test_router().await;
+
+    let (status, body) = make_unauthenticated_request(
+        router,
+        "GET",
+        "/api/v1/status/public",
+        None,
+    ).await;
+
+    assert_eq!(status, StatusCode::OK);
+    for component in ["api", "cache", "database", "ts_bridge", "vedic_api"] {
+        assert!(body["components"][component].is_string(), "missing {}", component);
+    }
+    assert!(body["incidents"].is_array());
+    // Nothing beyond the coarse status leaks out
+    assert!(body.get("failing_engines").is_none());
+}
+
 // ---------------------------------------------------------------------------
 // Engine route tests - Happy paths
 // ---------------------------------------------------------------------------
@@ -212,6 +255,94 @@ async fn test_engine_info_panchanga_success() {
     assert_eq!(body["engine_id"], "panchanga");
     assert!(body["engine_name"].is_string());
     assert!(body["required_phase"].is_number());
+    assert_eq!(body["description"]["implementation"], "real");
+    assert!(!body["description"]["summary"].as_str().unwrap().is_empty());
+    assert!(body["description"]["inputs"].as_array().unwrap().iter().any(|i| i["field"] == "birth_data.date"));
+}
+
+#[tokio::test]
+async fn test_engine_info_reports_mock_engines() {
+    let router = get_test_router().await;
+    let token = generate_test_token(5);
+
+    let (status, body) = make_authenticated_request(
+        router,
+        "GET",
+        "/api/v1/engines/biofield/info",
+        &token,
+        None,
+    ).await;
+
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["description"]["implementation"], "mock");
+    assert!(!body["description"]["limitations"].as_array().unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_engine_examples_calculate() {
+    let router = get_test_router().await;
+    let token = generate_test_token(5);
+
+    let (status, body) =
+        make_authenticated_request(router, "GET", "/api/v1/engines/numerology/examples", &token, None).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["engine_id"], "numerology");
+    let examples = body["examples"].as_array().unwrap();
+    assert!(examples.len() >= 2);
+
+    for example in examples {
+        let shape = example["result_shape"].as_object().expect("example calculates");
+        let (status, output) = make_authenticated_request(
+            router,
+            "POST",
+            "/api/v1/engines/numerology/calculate",
+            &token,
+            Some(example["input"].clone()),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK, "{}", example["name"]);
+        let result = output["result"].as_object().unwrap();
+        assert!(shape.keys().eq(result.keys()), "{}", example["name"]);
+    }
+
+    // Examples without birth data work as well
+    let (status, body) =
+        make_authenticated_request(router, "GET", "/api/v1/engines/gene-keys/examples", &token, None).await;
+    assert_eq!(status, StatusCode::OK);
+    let hd_gates = body["examples"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|example| example["name"] == "hd_gates")
+        .unwrap();
+    assert!(hd_gates["input"]["birth_data"].is_null());
+    assert!(hd_gates["result_shape"].is_object(), "{}", hd_gates["error"]);
+
+    let (status, body) =
+        make_authenticated_request(router, "GET", "/api/v1/engines/fake-engine/examples", &token, None).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+    assert_eq!(body["error_code"], "ENGINE_NOT_FOUND");
+}
+
+#[tokio::test]
+async fn test_every_engine_has_working_examples() {
+    let router = get_test_router().await;
+    let token = generate_test_token(5);
+
+    let (status, body) = make_authenticated_request(router, "GET", "/api/v1/engines", &token, None).await;
+    assert_eq!(status, StatusCode::OK);
+    for engine_id in body["engines"].as_array().unwrap() {
+        let engine_id = engine_id.as_str().unwrap();
+        let uri = format!("/api/v1/engines/{}/examples", engine_id);
+        let (status, body) = make_authenticated_request(router, "GET", &uri, &token, None).await;
+        assert_eq!(status, StatusCode::OK, "{}", engine_id);
+        let examples = body["examples"].as_array().unwrap();
+        assert!(!examples.is_empty(), "{} has no examples", engine_id);
+        for example in examples {
+            assert!(example["error"].is_null(), "{} {}: {}", engine_id, example["name"], example["error"]);
+            assert!(example["result_shape"].is_object(), "{} {}", engine_id, example["name"]);
+        }
+    }
 }
 
 #[tokio::test]
@@ -253,6 +384,27 @@ async fn test_calculate_numerology_success() {
     assert!(body["result"].is_object());
 }
 
+#[tokio::test]
+async fn test_name_spellings_calculate_alike() {
+    let router = get_test_router().await;
+    let token = generate_test_token(5);
+    let calculate = |name: &str| {
+        let mut input = serde_json::to_value(create_test_birth_input()).unwrap();
+        input["birth_data"]["name"] = json!(name);
+        make_authenticated_request(router, "POST", "/api/v1/engines/numerology/calculate", &token, Some(input))
+    };
+
+    // Accents count as their base letters, however they were composed
+    let (status, plain) = calculate("Jose OBrien").await;
+    assert_eq!(status, StatusCode::OK);
+    let (status, composed) = calculate("  Jos\u{e9}  O\u{2019}Brien ").await;
+    assert_eq!(status, StatusCode::OK);
+    let (_, decomposed) = calculate("Jose\u{301} O'Brien").await;
+    assert_eq!(composed["result"]["expression"], plain["result"]["expression"]);
+    assert_eq!(decomposed["result"]["expression"], plain["result"]["expression"]);
+    assert_eq!(composed["result"]["soul_urge"], plain["result"]["soul_urge"]);
+}
+
 #[tokio::test]
 async fn test_calculate_biorhythm_success() {
     let router = get_test_router().await;
@@ -364,6 +516,134 @@ async fn test_workflow_execute_daily_practice_success() {
     assert!(body["engine_outputs"].is_object());
 }
 
+#[tokio::test]
+async fn test_workflow_execute_unknown_preset_422() {
+    let router = get_test_router().await;
+    let token = generate_test_token(5);
+
+    let (status, body) = make_authenticated_request(
+        router,
+        "POST",
+        "/api/v1/workflows/daily-practice/execute",
+        &token,
+        Some(json!({ "profile_id": "me", "preset": "someday" })),
+    ).await;
+
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    assert_eq!(body["error_code"], "VALIDATION_ERROR");
+}
+
+#[tokio::test]
+async fn test_workflow_execute_unknown_birth_time_reports_confidence() {
+    let router = get_test_router().await;
+    let token = generate_test_token(5);
+    let mut input = create_test_birth_input();
+    let birth = input.birth_data.as_mut().unwrap();
+    birth.time = None;
+    birth.time_unknown = true;
+
+    let (status, body) = make_authenticated_request(
+        router,
+        "POST",
+        "/api/v1/workflows/daily-practice/execute",
+        &token,
+        Some(serde_json::to_value(&input).unwrap()),
+    ).await;
+
+    assert_eq!(status, StatusCode::OK, "{}", body);
+    let report = &body["time_confidence"];
+    assert_eq!(report["assumed_time"], "12:00");
+    assert_eq!(report["engines"]["panchanga"]["sensitivity"], "partial");
+    assert_eq!(report["engines"]["biorhythm"]["sensitivity"], "insensitive");
+    assert_eq!(report["engines"]["biorhythm"]["confidence"], 1.0);
+    assert!(body["engine_outputs"]["panchanga"]["result"]["time_stability"].is_object());
+
+    // A known birth time gets no report
+    let (status, body) = make_authenticated_request(
+        router,
+        "POST",
+        "/api/v1/workflows/daily-practice/execute",
+        &token,
+        Some(serde_json::to_value(create_test_birth_input()).unwrap()),
+    ).await;
+    assert_eq!(status, StatusCode::OK, "{}", body);
+    assert!(body.get("time_confidence").is_none());
+}
+
+#[tokio::test]
+async fn test_workflow_execute_relationship_success() {
+    let router = get_test_router().await;
+    let token = generate_test_token(5);
+    let mut input = create_test_birth_input();
+
+    // Without a second person the workflow is rejected
+    let (status, body) = make_authenticated_request(
+        router,
+        "POST",
+        "/api/v1/workflows/relationship/execute",
+        &token,
+        Some(serde_json::to_value(&input).unwrap()),
+    ).await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY, "{}", body);
+
+    let mut partner = input.birth_data.clone().unwrap();
+    partner.date = "1988-07-04".to_string();
+    input.secondary_birth_data = Some(partner);
+    let (status, body) = make_authenticated_request(
+        router,
+        "POST",
+        "/api/v1/workflows/relationship/execute",
+        &token,
+        Some(serde_json::to_value(&input).unwrap()),
+    ).await;
+
+    assert_eq!(status, StatusCode::OK, "{}", body);
+    assert_eq!(body["workflow_id"], "relationship");
+    assert!(body["engine_outputs"]["numerology"].is_object());
+    assert!(body["engine_outputs"]["numerology:secondary"].is_object());
+    assert!(body["synthesis"]["summary"].as_str().unwrap().contains("Second person: Life Path"));
+}
+
+#[tokio::test]
+async fn test_workflow_dry_run_plans_without_running() {
+    let router = get_test_router().await;
+    let token = generate_test_token(1);
+    let mut input = create_test_birth_input();
+    input.birth_data.as_mut().unwrap().name = None;
+
+    let (status, body) = make_authenticated_request(
+        router,
+        "POST",
+        "/api/v1/workflows/birth-blueprint/execute?dry_run=true",
+        &token,
+        Some(serde_json::to_value(&input).unwrap()),
+    )
+    .await;
+
+    assert_eq!(status, StatusCode::OK, "{}", body);
+    assert_eq!(body["workflow_id"], "birth-blueprint");
+    assert_eq!(body["user_phase"], 1);
+    assert!(body.get("engine_outputs").is_none());
+    let engines = body["engines"].as_array().unwrap();
+    let status_of = |id: &str| engines.iter().find(|e| e["engine_id"] == id).unwrap()["status"].clone();
+    // Numerology needs the name; gene-keys needs phase 2
+    assert_eq!(status_of("numerology"), "invalid_input");
+    assert!(status_of("human-design") == "run" || status_of("human-design") == "cached");
+    assert_eq!(status_of("gene-keys"), "phase_denied");
+    assert_eq!(body["engines_to_run"], 1);
+
+    let (status, body) = make_authenticated_request(
+        router,
+        "POST",
+        "/api/v1/workflows/fake-workflow/execute?dry_run=true",
+        &token,
+        Some(serde_json::to_value(&input).unwrap()),
+    )
+    .await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+    assert_eq!(body["error_code"], "WORKFLOW_NOT_FOUND");
+}
+
 // ---------------------------------------------------------------------------
 // Legacy route tests
 // ---------------------------------------------------------------------------
@@ -399,6 +679,101 @@ async fn test_legacy_panchanga_calculate_success() {
     assert!(body["lunar_longitude"].is_number());
 }
 
+#[tokio::test]
+async fn test_legacy_panchanga_ayanamsa_passthrough() {
+    let router = get_test_router().await;
+    let mut legacy_request = json!({
+        "date": "2024-01-15",
+        "time": "14:30",
+        "latitude": 12.9716,
+        "longitude": 77.5946,
+        "timezone": "Asia/Kolkata",
+        "precision": "High"
+    });
+
+    let (_, tropical) = make_unauthenticated_request(
+        router,
+        "POST",
+        "/api/legacy/panchanga/calculate",
+        Some(legacy_request.clone()),
+    ).await;
+
+    legacy_request["ayanamsa"] = json!("lahiri");
+    let (status, sidereal) = make_unauthenticated_request(
+        router,
+        "POST",
+        "/api/legacy/panchanga/calculate",
+        Some(legacy_request),
+    ).await;
+
+    assert_eq!(status, StatusCode::OK);
+    let shift = tropical["lunar_longitude"].as_f64().unwrap()
+        - sidereal["lunar_longitude"].as_f64().unwrap();
+    assert!((shift.rem_euclid(360.0) - 24.19).abs() < 0.05, "Lahiri shift was {}", shift);
+    assert_eq!(tropical["tithi_index"], sidereal["tithi_index"]);
+}
+
+#[tokio::test]
+async fn test_legacy_panchanga_batch_mixed_results() {
+    let router = get_test_router().await;
+    let batch = json!({
+        "requests": [
+            {
+                "date": "2025-01-27",
+                "latitude": 19.0760,
+                "longitude": 72.8777,
+                "timezone": "Asia/Kolkata"
+            },
+            {
+                "date": "2025-13-40",
+                "latitude": 28.6139,
+                "longitude": 77.2090,
+                "timezone": "Asia/Kolkata"
+            },
+            {
+                "date": "2025-06-15",
+                "time": "06:00",
+                "latitude": 28.6139,
+                "longitude": 77.2090,
+                "timezone": "Asia/Kolkata",
+                "ayanamsa": "raman"
+            }
+        ]
+    });
+
+    let (status, body) = make_unauthenticated_request(
+        router,
+        "POST",
+        "/api/legacy/panchanga/batch",
+        Some(batch),
+    ).await;
+
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["succeeded"], 2);
+    assert_eq!(body["failed"], 1);
+    let results = body["results"].as_array().unwrap();
+    assert_eq!(results[0]["status"], "success");
+    assert!(results[0]["data"]["tithi_name"].is_string());
+    assert_eq!(results[1]["status"], "error");
+    assert_eq!(results[1]["error"]["error_code"], "VALIDATION_ERROR");
+    assert_eq!(results[2]["index"], 2);
+}
+
+#[tokio::test]
+async fn test_legacy_panchanga_batch_rejects_empty() {
+    let router = get_test_router().await;
+
+    let (status, body) = make_unauthenticated_request(
+        router,
+        "POST",
+        "/api/legacy/panchanga/batch",
+        Some(json!({ "requests": [] })),
+    ).await;
+
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    assert_eq!(body["details"]["errors"][0]["field"], "requests");
+}
+
 #[tokio::test]
 async fn test_legacy_ghati_current_success() {
     let router = get_test_router().await;
@@ -422,6 +797,51 @@ async fn test_legacy_ghati_current_success() {
     assert!(body["pala"].is_number());
     assert!(body["vipala"].is_number());
     assert!(body["utc_timestamp"].is_string());
+    assert!(body["sunrise"].as_str().unwrap() <= body["utc_timestamp"].as_str().unwrap());
+    assert!(body["next_sunrise"].is_string());
+}
+
+#[tokio::test]
+async fn test_legacy_ghati_stream_sends_current_tick() {
+    use http_body_util::BodyExt;
+
+    let router = get_test_router().await;
+    let request = Request::builder()
+        .method("GET")
+        .uri("/api/legacy/ghati/stream?latitude=12.9716&longitude=77.5946")
+        .body(Body::empty())
+        .unwrap();
+    let response = router.clone().oneshot(request).await.unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.headers()[header::CONTENT_TYPE], "text/event-stream");
+
+    // The first event is sent without waiting for the next pala
+    let mut body = response.into_body();
+    let frame = tokio::time::timeout(std::time::Duration::from_secs(5), body.frame())
+        .await
+        .expect("first event should arrive immediately")
+        .unwrap()
+        .unwrap();
+    let text = String::from_utf8(frame.into_data().unwrap().to_vec()).unwrap();
+    assert!(text.starts_with("event: pala\n") || text.starts_with("event: ghati\n"), "{}", text);
+    let data: Value = serde_json::from_str(text.lines().nth(1).unwrap().trim_start_matches("data: ")).unwrap();
+    assert!(data["ghati"].as_u64().unwrap() < 60);
+}
+
+#[tokio::test]
+async fn test_legacy_ghati_stream_rejects_bad_coordinates() {
+    let router = get_test_router().await;
+
+    let (status, body) = make_unauthenticated_request(
+        router,
+        "GET",
+        "/api/legacy/ghati/stream?latitude=120&longitude=0",
+        None,
+    ).await;
+
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    assert_eq!(body["details"]["errors"][0]["field"], "latitude");
 }
 
 // ---------------------------------------------------------------------------
@@ -689,6 +1109,453 @@ async fn test_status_endpoint_with_auth() {
     assert!(body["workflows"].is_array());
 }
 
+#[tokio::test]
+async fn test_validation_report_records_panchanga_cross_check() {
+    let router = get_test_router().await;
+    let input = create_test_birth_input();
+
+    let (status, _) = make_authenticated_request(
+        router,
+        "POST",
+        "/api/v1/engines/panchanga/calculate",
+        &generate_test_token(5),
+        Some(serde_json::to_value(input).unwrap()),
+    ).await;
+    assert_eq!(status, StatusCode::OK);
+
+    let (status, body) = make_authenticated_request(
+        router,
+        "GET",
+        "/api/v1/admin/validation/report?engine_id=panchanga&from=1990-01-15&to=1990-01-15&worst=3",
+        &generate_test_token_with_permissions(&["admin:validation"]),
+        None,
+    ).await;
+
+    assert_eq!(status, StatusCode::OK);
+    let engine = &body["engines"][0];
+    assert_eq!(engine["engine_id"], "panchanga");
+    assert!(engine["samples"].as_u64().unwrap() >= 2);
+    assert!(engine["worst_offenders"].as_array().unwrap().len() <= 3);
+
+    let solar = engine["quantities"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|q| q["quantity"] == "solar_longitude")
+        .expect("solar longitude compared");
+    assert_eq!(solar["reference_backend"], "swiss_ephemeris");
+    assert_eq!(solar["candidate_backend"], "native");
+    // The native mean-Sun formula stays within the equation of centre (~2°)
+    assert!(solar["max"].as_f64().unwrap() < 3.0 * 3600.0);
+
+    // Inputs are kept for reproduction, without the user's name
+    assert!(engine["sample_inputs"][0]["birth_data"]["name"].is_null());
+}
+
+#[tokio::test]
+async fn test_validation_report_requires_admin_permission() {
+    let router = get_test_router().await;
+
+    let (status, body) = make_authenticated_request(
+        router,
+        "GET",
+        "/api/v1/admin/validation/report",
+        &generate_test_token(5),
+        None,
+    ).await;
+
+    assert_eq!(status, StatusCode::FORBIDDEN);
+    assert_eq!(body["error_code"], "FORBIDDEN");
+    assert_eq!(body["details"]["required_permission"], "admin:validation");
+
+    let (status, _) = make_unauthenticated_request(
+        router,
+        "GET",
+        "/api/v1/admin/validation/report",
+        None,
+    ).await;
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+}
+
+#[tokio::test]
+async fn test_validation_report_rejects_inverted_range() {
+    let router = get_test_router().await;
+
+    let (status, body) = make_authenticated_request(
+        router,
+        "GET",
+        "/api/v1/admin/validation/report?from=2025-02-01&to=2025-01-01",
+        &generate_test_token_with_permissions(&["admin:validation"]),
+        None,
+    ).await;
+
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    assert_eq!(body["details"]["errors"][0]["field"], "to");
+}
+
+#[tokio::test]
+async fn test_geo_search_resolves_place() {
+    let router = get_test_router().await;
+    let token = generate_test_token(0);
+
+    let (status, body) = make_authenticated_request(
+        router,
+        "GET",
+        "/api/v1/geo/search?q=Bengaluru&limit=3",
+        &token,
+        None,
+    ).await;
+
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["results"][0]["name"], "Bengaluru");
+    assert_eq!(body["results"][0]["timezone"], "Asia/Kolkata");
+}
+
+#[tokio::test]
+async fn test_geo_search_empty_query_422() {
+    let router = get_test_router().await;
+    let token = generate_test_token(0);
+
+    let (status, body) = make_authenticated_request(
+        router,
+        "GET",
+        "/api/v1/geo/search?q=",
+        &token,
+        None,
+    ).await;
+
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    assert_eq!(body["error_code"], "VALIDATION_ERROR");
+}
+
+#[tokio::test]
+async fn test_ephemeris_positions_at_equinox() {
+    let router = get_test_router().await;
+    let token = generate_test_token(0);
+
+    let (status, body) = make_authenticated_request(
+        router,
+        "POST",
+        "/api/v1/ephemeris/positions",
+        &token,
+        Some(json!({ "datetime": "2024-03-20T03:06:00Z" })),
+    ).await;
+
+    assert_eq!(status, StatusCode::OK, "body: {:?}", body);
+    assert_eq!(body["ayanamsa"], "lahiri");
+    let planets = body["planets"].as_array().unwrap();
+    assert_eq!(planets.len(), 13);
+
+    let sun = &planets[0];
+    assert_eq!(sun["planet"], "sun");
+    let longitude = sun["longitude"].as_f64().unwrap();
+    assert!(!(0.01..=359.99).contains(&longitude), "sun at {}", longitude);
+    assert_eq!(sun["retrograde"], false);
+    let sidereal = sun["sidereal_longitude"].as_f64().unwrap();
+    let ayanamsa = body["ayanamsa_degrees"].as_f64().unwrap();
+    assert!(((longitude - sidereal).rem_euclid(360.0) - ayanamsa).abs() < 1e-9);
+
+    // Mercury stationed retrograde on 2024-04-01
+    let (_, body) = make_authenticated_request(
+        router,
+        "POST",
+        "/api/v1/ephemeris/positions",
+        &token,
+        Some(json!({ "datetime": "2024-04-10T00:00:00Z", "ayanamsa": "raman" })),
+    ).await;
+    let mercury = body["planets"].as_array().unwrap().iter().find(|p| p["planet"] == "mercury").unwrap();
+    assert_eq!(mercury["retrograde"], true);
+    assert_eq!(body["ayanamsa"], "raman");
+}
+
+#[tokio::test]
+async fn test_ephemeris_positions_rejects_bad_input() {
+    let router = get_test_router().await;
+    let token = generate_test_token(0);
+
+    for (payload, field) in [
+        (json!({ "datetime": "yesterday" }), "datetime"),
+        (json!({ "datetime": "1700-01-01T00:00:00Z" }), "datetime"),
+        (json!({ "datetime": "2024-01-01T00:00:00Z", "ayanamsa": "tropical" }), "ayanamsa"),
+    ] {
+        let (status, body) = make_authenticated_request(
+            router,
+            "POST",
+            "/api/v1/ephemeris/positions",
+            &token,
+            Some(payload),
+        ).await;
+
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY, "body: {:?}", body);
+        assert_eq!(body["details"]["errors"][0]["field"], field);
+    }
+}
+
+#[tokio::test]
+async fn test_ephemeris_stations_in_window() {
+    let router = get_test_router().await;
+    let token = generate_test_token(0);
+
+    let (status, body) = make_authenticated_request(
+        router,
+        "GET",
+        "/api/v1/ephemeris/stations?from=2024-03-20&to=2024-05-01",
+        &token,
+        None,
+    ).await;
+
+    assert_eq!(status, StatusCode::OK, "body: {:?}", body);
+    let mercury = body["retrograde"].as_array().unwrap().iter().find(|p| p["planet"] == "mercury").unwrap();
+    assert_eq!(mercury["retrograde"], false);
+    let kinds: Vec<&str> = body["stations"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .filter(|s| s["planet"] == "mercury")
+        .map(|s| s["kind"].as_str().unwrap())
+        .collect();
+    assert_eq!(kinds, ["retrograde", "direct"]);
+
+    let (status, body) = make_authenticated_request(
+        router,
+        "GET",
+        "/api/v1/ephemeris/stations?from=2024-01-01&to=2027-01-01",
+        &token,
+        None,
+    ).await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    assert_eq!(body["details"]["errors"][0]["field"], "to");
+}
+
+#[tokio::test]
+async fn test_ephemeris_gate_transits() {
+    let router = get_test_router().await;
+    let token = generate_test_token(0);
+
+    let (status, body) = make_authenticated_request(
+        router,
+        "GET",
+        "/api/v1/ephemeris/gate-transits?from=2024-03-15&to=2024-03-25",
+        &token,
+        None,
+    ).await;
+    assert_eq!(status, StatusCode::OK, "body: {:?}", body);
+    assert_eq!(body["planets"], json!(["sun", "earth"]));
+    let gates: Vec<(&str, u64)> = body["ingresses"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|i| (i["planet"].as_str().unwrap(), i["gate"].as_u64().unwrap()))
+        .collect();
+    assert!(gates.contains(&("sun", 17)) && gates.contains(&("earth", 18)), "{:?}", gates);
+
+    let (status, body) = make_authenticated_request(
+        router,
+        "GET",
+        "/api/v1/ephemeris/gate-transits?planets=sun,vulcan",
+        &token,
+        None,
+    ).await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    assert_eq!(body["details"]["errors"][0]["field"], "planets");
+}
+
+#[tokio::test]
+async fn test_planner_ranks_windows_across_engines() {
+    let router = get_test_router().await;
+    let token = generate_test_token(0);
+    let request = |task: &str, to: &str, limit: usize| {
+        json!({
+            "task": task,
+            "from": "2024-03-18",
+            "to": to,
+            "birth_data": {
+                "date": "1990-01-15", "time": "14:30",
+                "latitude": 12.9716, "longitude": 77.5946, "timezone": "Asia/Kolkata"
+            },
+            "limit": limit
+        })
+    };
+    let suggest = |payload: serde_json::Value| {
+        make_authenticated_request(router, "POST", "/api/v1/planner/suggest", &token, Some(payload))
+    };
+
+    let (status, body) = suggest(request("deep work", "2024-03-19", 5)).await;
+    assert_eq!(status, StatusCode::OK, "body: {:?}", body);
+    assert_eq!(body["task"], "deep_work");
+    let windows = body["windows"].as_array().unwrap();
+    assert_eq!(windows.len(), 5);
+    let scores: Vec<f64> = windows.iter().map(|w| w["score"].as_f64().unwrap()).collect();
+    assert!(scores.windows(2).all(|pair| pair[0] >= pair[1]), "{:?}", scores);
+    for window in windows {
+        assert!(window["start"].as_str().unwrap().ends_with("+05:30"));
+        let reasons = window["reasons"].as_array().unwrap();
+        assert!(reasons.iter().any(|r| r.as_str().unwrap().starts_with("Intellectual cycle")));
+        assert!(reasons.iter().any(|r| r.as_str().unwrap().contains("tithi")));
+        assert!(reasons.iter().any(|r| r.as_str().unwrap().contains("Tarabala")));
+    }
+
+    // Every window of a Monday: Rahu Kalam takes the second eighth of daylight
+    let (_, body) = suggest(request("workout", "2024-03-18", 50)).await;
+    let windows = body["windows"].as_array().unwrap();
+    assert_eq!(windows.len(), 9);
+    let in_rahu_kalam = windows
+        .iter()
+        .find(|w| w["start"].as_str().unwrap().starts_with("2024-03-18T07:00"))
+        .unwrap();
+    assert!(in_rahu_kalam["reasons"].as_array().unwrap().iter().any(|r| r.as_str().unwrap().starts_with("Overlaps Rahu Kalam")));
+
+    let mut bad_constitution = request("workout", "2024-03-18", 5);
+    bad_constitution["constitution"] = json!({"vata": -1, "pitta": 1, "kapha": 1});
+    for (payload, field) in [
+        (request("nap", "2024-03-18", 5), "task"),
+        (request("workout", "2024-04-30", 5), "to"),
+        (request("workout", "2024-03-18", 0), "limit"),
+        (bad_constitution, "constitution.vata"),
+    ] {
+        let (status, body) = suggest(payload).await;
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY, "body: {:?}", body);
+        assert_eq!(body["details"]["errors"][0]["field"], field);
+    }
+}
+
+#[tokio::test]
+async fn test_wisdom_search_cross_references_systems() {
+    let router = get_test_router().await;
+    let token = generate_test_token(0);
+
+    let (status, body) = make_authenticated_request(
+        router,
+        "GET",
+        "/api/v1/wisdom/search?q=creativity&systems=hd,gene-keys,i-ching",
+        &token,
+        None,
+    ).await;
+
+    assert_eq!(status, StatusCode::OK, "body: {:?}", body);
+    assert_eq!(body["systems"], json!(["hd", "gene-keys", "i-ching"]));
+    let results = body["results"].as_array().unwrap();
+    assert!(results.iter().all(|hit| hit["system"] != "numerology"));
+    let gate = results.iter().find(|hit| hit["id"] == "gate-1").expect("gate 1 matches creativity");
+    assert!(gate["related"].as_array().unwrap().iter().any(|r| r["id"] == "gene-key-1"));
+
+    for (query, field) in [
+        ("/api/v1/wisdom/search?q=the", "q"),
+        ("/api/v1/wisdom/search?q=creativity&systems=tarot", "systems"),
+        ("/api/v1/wisdom/search?q=creativity&limit=0", "limit"),
+    ] {
+        let (status, body) = make_authenticated_request(router, "GET", query, &token, None).await;
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY, "body: {:?}", body);
+        assert_eq!(body["details"]["errors"][0]["field"], field);
+    }
+}
+
+#[tokio::test]
+async fn test_wisdom_export_is_checksummed_and_revalidates() {
+    let router = get_test_router().await;
+    let token = generate_test_token(0);
+    let export = |if_none_match: Option<&str>| {
+        let mut request = Request::builder()
+            .method("GET")
+            .uri("/api/v1/wisdom/export?engine=human-design")
+            .header(header::AUTHORIZATION, format!("Bearer {}", token));
+        if let Some(etag) = if_none_match {
+            request = request.header(header::IF_NONE_MATCH, etag);
+        }
+        router.clone().oneshot(request.body(Body::empty()).unwrap())
+    };
+
+    let response = export(None).await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let etag = response.headers()[header::ETAG].to_str().unwrap().to_string();
+    let body_bytes = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    let bundle: Value = serde_json::from_slice(&body_bytes).unwrap();
+    assert_eq!(bundle["engine"], "human-design");
+    assert_eq!(etag, format!("\"{}\"", bundle["checksum"].as_str().unwrap()));
+    assert_eq!(bundle["content"]["gates"].as_object().unwrap().len(), 64);
+    assert_eq!(bundle["content"]["centers"].as_object().unwrap().len(), 9);
+
+    let response = export(Some(&etag)).await.unwrap();
+    assert_eq!(response.status(), StatusCode::NOT_MODIFIED);
+    assert_eq!(response.headers()[header::ETAG], etag.as_str());
+    assert_eq!(export(Some("\"stale\"")).await.unwrap().status(), StatusCode::OK);
+
+    let (status, body) = make_authenticated_request(
+        router,
+        "GET",
+        "/api/v1/wisdom/export?engine=tarot",
+        &token,
+        None,
+    ).await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY, "body: {:?}", body);
+    assert_eq!(body["details"]["errors"][0]["field"], "engine");
+}
+
+#[tokio::test]
+async fn test_calculate_with_place_only_birth_data() {
+    let router = get_test_router().await;
+    let token = generate_test_token(5);
+
+    let input = json!({
+        "birth_data": {
+            "date": "1990-01-15",
+            "time": "14:30",
+            "place": "Bangalore"
+        }
+    });
+
+    let (status, body) = make_authenticated_request(
+        router,
+        "POST",
+        "/api/v1/engines/panchanga/calculate",
+        &token,
+        Some(input),
+    ).await;
+
+    assert_eq!(status, StatusCode::OK, "body: {:?}", body);
+    assert_eq!(body["engine_id"], "panchanga");
+}
+
+#[tokio::test]
+async fn test_calculate_reports_field_validation_errors() {
+    let router = get_test_router().await;
+    let token = generate_test_token(5);
+
+    let input = json!({
+        "birth_data": {
+            "date": "1990-13-45",
+            "time": "14:30",
+            "latitude": 95.0,
+            "longitude": 77.59,
+            "timezone": "Asia/Bangalore"
+        }
+    });
+
+    let (status, body) = make_authenticated_request(
+        router,
+        "POST",
+        "/api/v1/engines/panchanga/calculate",
+        &token,
+        Some(input),
+    ).await;
+
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    assert_eq!(body["error_code"], "VALIDATION_ERROR");
+    let errors = body["details"]["errors"].as_array().expect("errors list");
+    let fields: Vec<(&str, &str)> = errors
+        .iter()
+        .map(|e| (e["field"].as_str().unwrap(), e["code"].as_str().unwrap()))
+        .collect();
+    assert_eq!(
+        fields,
+        vec![
+            ("birth_data.date", "invalid_format"),
+            ("birth_data.latitude", "out_of_range"),
+            ("birth_data.timezone", "unknown"),
+        ]
+    );
+}
+
 #[tokio::test]
 async fn test_concurrent_engine_calculations() {
     let token = generate_test_token(5);
@@ -762,17 +1629,25 @@ fn create_hd_test_input() -> EngineInput {
     EngineInput {
         birth_data: Some(noesis_core::BirthData {
             name: Some("Generator 1/3 Test".to_string()),
+            display_name: None,
             date: "1970-10-05".to_string(),
             time: Some("00:00:00".to_string()),
+            time_unknown: false,
             latitude: 0.0,
             longitude: 0.0,
+            altitude: None,
             timezone: "UTC".to_string(),
+            place: None,
+            utc_offset_minutes: None,
         }),
+        secondary_birth_data: None,
+        subjects: Vec::new(),
         current_time: chrono::Utc::now(),
         location: Some(noesis_core::Coordinates {
             latitude: 0.0,
             longitude: 0.0,
             altitude: None,
+            timezone: None,
         }),
         precision: noesis_core::Precision::Standard,
         options: std::collections::HashMap::new(),
@@ -826,6 +1701,8 @@ async fn test_hd_engine_missing_birth_date_422() {
     // Missing birth_data entirely
     let invalid_input = EngineInput {
         birth_data: None,
+        secondary_birth_data: None,
+        subjects: Vec::new(),
         current_time: chrono::Utc::now(),
         location: None,
         precision: noesis_core::Precision::Standard,
@@ -855,12 +1732,19 @@ async fn test_hd_engine_invalid_coordinates_422() {
     let invalid_input = EngineInput {
         birth_data: Some(noesis_core::BirthData {
             name: Some("Invalid Coords Test".to_string()),
+            display_name: None,
             date: "1985-06-15".to_string(),
             time: Some("14:30:00".to_string()),
+            time_unknown: false,
             latitude: 91.0, // Invalid: > 90°
             longitude: 181.0, // Invalid: > 180°
+            altitude: None,
             timezone: "UTC".to_string(),
+            place: None,
+            utc_offset_minutes: None,
         }),
+        secondary_birth_data: None,
+        subjects: Vec::new(),
         current_time: chrono::Utc::now(),
         location: None,
         precision: noesis_core::Precision::Standard,
@@ -1073,12 +1957,19 @@ async fn test_hd_to_gene_keys_workflow() {
     let birth_input = EngineInput {
         birth_data: Some(noesis_core::BirthData {
             name: Some("Cross-Engine Test".to_string()),
+            display_name: None,
             date: "1985-06-15".to_string(),
             time: Some("14:30".to_string()),
+            time_unknown: false,
             latitude: 40.7128,
             longitude: -74.0060,
+            altitude: None,
             timezone: "America/New_York".to_string(),
+            place: None,
+            utc_offset_minutes: None,
         }),
+        secondary_birth_data: None,
+        subjects: Vec::new(),
         current_time: chrono::Utc::now(),
         location: None,
         precision: noesis_core::Precision::Standard,
@@ -1149,6 +2040,8 @@ async fn test_hd_to_gene_keys_workflow() {
     
     let gk_input = EngineInput {
         birth_data: None,
+        secondary_birth_data: None,
+        subjects: Vec::new(),
         current_time: chrono::Utc::now(),
         location: None,
         precision: noesis_core::Precision::Standard,
@@ -1227,12 +2120,19 @@ async fn test_gene_keys_directly_from_birth_data() {
     let birth_input = EngineInput {
         birth_data: Some(noesis_core::BirthData {
             name: Some("Direct Birth Data Test".to_string()),
+            display_name: None,
             date: "1990-01-15".to_string(),
             time: Some("12:00".to_string()),
+            time_unknown: false,
             latitude: 28.6139,
             longitude: 77.2090,
+            altitude: None,
             timezone: "Asia/Kolkata".to_string(),
+            place: None,
+            utc_offset_minutes: None,
         }),
+        secondary_birth_data: None,
+        subjects: Vec::new(),
         current_time: chrono::Utc::now(),
         location: None,
         precision: noesis_core::Precision::Standard,
@@ -1307,6 +2207,8 @@ async fn test_gene_keys_consciousness_level_affects_witness_prompt() {
         
         let input = EngineInput {
             birth_data: None,
+            secondary_birth_data: None,
+            subjects: Vec::new(),
             current_time: chrono::Utc::now(),
             location: None,
             precision: noesis_core::Precision::Standard,
@@ -1331,3 +2233,102 @@ async fn test_gene_keys_consciousness_level_affects_witness_prompt() {
     assert_ne!(prompts[0], prompts[1], "Level 2 and 3 prompts should differ");
     assert_ne!(prompts[1], prompts[2], "Level 3 and 6 prompts should differ");
 }
+
+#[tokio::test]
+async fn test_self_test_calculates_with_every_engine() {
+    use noesis_api::self_test::{run_self_test, CheckStatus};
+
+    let config = noesis_api::ApiConfig::from_env();
+    let state = build_app_state_lazy_db(&config).await;
+    let report = run_self_test(&state).await;
+
+    let engines: Vec<&str> = report
+        .checks
+        .iter()
+        .filter_map(|check| check.name.strip_prefix("engine:"))
+        .collect();
+    assert_eq!(engines.len(), state.core.orchestrator.list_engines().len());
+    for check in &report.checks {
+        if check.name.starts_with("engine:") || check.name == "cache" {
+            assert_eq!(check.status, CheckStatus::Pass, "{}: {}", check.name, check.detail);
+        }
+    }
+    // The database check depends on the environment; it only has to report
+    assert!(report.checks.iter().any(|check| check.name == "database"));
+    assert_eq!(report.exit_code(), if report.passed() { 0 } else { 1 });
+}
+
+// ---------------------------------------------------------------------------
+// Casing contract
+// ---------------------------------------------------------------------------
+
+/// Paths of every camelCase object key in `value`
+fn camel_case_paths(path: &str, value: &Value, found: &mut Vec<String>) {
+    match value {
+        Value::Object(map) => {
+            for (key, v) in map {
+                let child = format!("{}.{}", path, key);
+                if noesis_core::casing::is_camel_case(key) {
+                    found.push(child.clone());
+                }
+                camel_case_paths(&child, v, found);
+            }
+        }
+        Value::Array(items) => {
+            for (i, v) in items.iter().enumerate() {
+                camel_case_paths(&format!("{}[{}]", path, i), v, found);
+            }
+        }
+        _ => {}
+    }
+}
+
+#[tokio::test]
+async fn test_wire_format_is_snake_case() {
+    let router = get_test_router().await;
+    let token = generate_test_token(5);
+    let input = serde_json::to_value(create_test_birth_input()).unwrap();
+
+    let engines = [
+        "panchanga", "numerology", "biorhythm", "human-design", "gene-keys",
+        "vimshottari", "vedic-clock", "biofield",
+    ];
+    for engine_id in engines {
+        let uri = format!("/api/v2/engines/{}/calculate", engine_id);
+        let (status, body) = make_authenticated_request(router, "POST", &uri, &token, Some(input.clone())).await;
+        let mut found = Vec::new();
+        camel_case_paths(engine_id, &body, &mut found);
+        assert!(found.is_empty(), "{} ({}) has camelCase keys: {:?}", engine_id, status, found);
+    }
+
+    let (status, body) = make_authenticated_request(
+        router,
+        "POST",
+        "/api/v2/workflows/birth-blueprint/execute",
+        &token,
+        Some(input),
+    ).await;
+    assert_eq!(status, StatusCode::OK);
+    let mut found = Vec::new();
+    camel_case_paths("birth-blueprint", &body, &mut found);
+    assert!(found.is_empty(), "workflow has camelCase keys: {:?}", found);
+}
+
+#[tokio::test]
+async fn test_camel_case_input_is_accepted() {
+    let router = get_test_router().await;
+    let token = generate_test_token(5);
+    let snake = serde_json::to_value(create_test_birth_input()).unwrap();
+    let camel = json!({
+        "birthData": snake["birth_data"],
+        "currentTime": snake["current_time"],
+        "location": snake["location"],
+    });
+
+    let uri = "/api/v2/engines/numerology/calculate";
+    let (status, from_snake) = make_authenticated_request(router, "POST", uri, &token, Some(snake)).await;
+    assert_eq!(status, StatusCode::OK);
+    let (status, from_camel) = make_authenticated_request(router, "POST", uri, &token, Some(camel)).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(from_camel["result"], from_snake["result"]);
+}
@@ -0,0 +1,148 @@
+//! The published OpenAPI spec must cover every route and match the code
+
+use std::path::PathBuf;
+
+fn committed_spec_path() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("../..")
+        .join(noesis_api::openapi::SPEC_PATH)
+}
+
+#[test]
+fn test_spec_documents_every_route() {
+    let spec = noesis_api::openapi_spec();
+    let expected = [
+        ("/health", "get"),
+        ("/health/live", "get"),
+        ("/health/ready", "get"),
+        ("/ready", "get"),
+        ("/metrics", "get"),
+        ("/api/v1/status", "get"),
+        ("/api/v1/engines", "get"),
+        ("/api/v1/engines/health", "get"),
+        ("/api/v1/engines/{engine_id}/calculate", "post"),
+        ("/api/v1/engines/{engine_id}/validate", "post"),
+        ("/api/v1/engines/{engine_id}/info", "get"),
+        ("/api/v1/workflows", "get"),
+        ("/api/v1/workflows/{workflow_id}/execute", "post"),
+        ("/api/v1/workflows/{workflow_id}/info", "get"),
+        ("/api/v1/geo/search", "get"),
+        ("/api/v1/ephemeris/positions", "post"),
+        ("/api/v1/ephemeris/stations", "get"),
+        ("/api/v1/planner/suggest", "post"),
+        ("/api/v1/wisdom/search", "get"),
+        ("/api/v1/wisdom/export", "get"),
+        ("/api/v1/admin/validation/report", "get"),
+        ("/api/v1/admin/experiments", "get"),
+        ("/api/v1/admin/experiments/{id}", "put"),
+        ("/api/v1/admin/experiments/{id}", "delete"),
+        ("/api/v1/admin/cache/stats", "get"),
+        ("/api/v1/admin/cache/engines/{engine_id}/versions/{version}", "delete"),
+        ("/api/v1/experiments/{id}/outcomes", "post"),
+        ("/api/v1/auth/register", "post"),
+        ("/api/v1/auth/login", "post"),
+        ("/api/v1/auth/forgot-password", "post"),
+        ("/api/v1/auth/reset-password", "post"),
+        ("/api/v1/users/me", "get"),
+        ("/api/v1/users/me", "patch"),
+        ("/api/v1/users/me/locations", "get"),
+        ("/api/v1/users/me/locations", "post"),
+        ("/api/v1/users/me/locations/{id}", "delete"),
+        ("/api/v1/users/me/notification-rules", "get"),
+        ("/api/v1/users/me/notification-rules", "post"),
+        ("/api/v1/users/me/notification-rules/{id}", "delete"),
+        ("/api/v1/users/me/notifications", "get"),
+        ("/api/v1/users/me/practices", "get"),
+        ("/api/v1/users/me/practices", "post"),
+        ("/api/v1/users/me/settings", "get"),
+        ("/api/v1/users/me/settings", "put"),
+        ("/api/v1/results/{id}", "get"),
+        ("/api/v1/results/{id}/annotations", "post"),
+        ("/api/v1/results/{id}/annotations/{annotation_id}", "delete"),
+        ("/api/v1/results/{id}/share", "post"),
+        ("/api/v1/admin/results/{id}/replay", "post"),
+        ("/api/v1/shared/{token}", "get"),
+        ("/api/v1/organizations", "post"),
+        ("/api/v1/organizations/{id}/members", "post"),
+        ("/api/v1/clients", "get"),
+        ("/api/v1/clients", "post"),
+        ("/api/v1/clients/{id}", "get"),
+        ("/api/v1/clients/{id}/consent", "patch"),
+        ("/api/v1/clients/{id}/workflows/{workflow_id}/execute", "post"),
+        ("/api/legacy/panchanga/calculate", "post"),
+        ("/api/legacy/panchanga/batch", "post"),
+        ("/api/legacy/ghati/current", "get"),
+        ("/api/legacy/ghati/stream", "get"),
+    ];
+
+    let json = serde_json::to_value(&spec).unwrap();
+    for (path, method) in expected {
+        assert!(
+            json["paths"][path][method].is_object(),
+            "{} {} is missing from the OpenAPI spec",
+            method.to_uppercase(),
+            path
+        );
+    }
+
+    let mut operation_ids: Vec<&str> = json["paths"]
+        .as_object()
+        .unwrap()
+        .values()
+        .flat_map(|item| item.as_object().unwrap().values())
+        .filter_map(|op| op["operationId"].as_str())
+        .collect();
+    let total = operation_ids.len();
+    operation_ids.sort_unstable();
+    operation_ids.dedup();
+    assert_eq!(operation_ids.len(), total, "operationIds must be unique");
+}
+
+#[test]
+fn test_engine_options_are_one_of() {
+    let json = serde_json::to_value(noesis_api::openapi_spec()).unwrap();
+    let schemas = &json["components"]["schemas"];
+
+    assert_eq!(
+        schemas["EngineInput"]["properties"]["options"]["$ref"],
+        "#/components/schemas/EngineOptions"
+    );
+    let variants = schemas["EngineOptions"]["oneOf"].as_array().unwrap();
+    assert_eq!(variants.len(), 9);
+    assert!(schemas["VedicClockOptions"]["properties"]["timezone_offset"].is_object());
+}
+
+#[test]
+fn test_every_schema_ref_resolves() {
+    fn collect_refs<'a>(value: &'a serde_json::Value, refs: &mut Vec<&'a str>) {
+        match value {
+            serde_json::Value::Object(map) => {
+                if let Some(serde_json::Value::String(target)) = map.get("$ref") {
+                    refs.push(target);
+                }
+                map.values().for_each(|v| collect_refs(v, refs));
+            }
+            serde_json::Value::Array(items) => items.iter().for_each(|v| collect_refs(v, refs)),
+            _ => {}
+        }
+    }
+
+    let json = serde_json::to_value(noesis_api::openapi_spec()).unwrap();
+    let mut refs = Vec::new();
+    collect_refs(&json, &mut refs);
+    for target in refs {
+        let name = target.trim_start_matches("#/components/schemas/");
+        assert!(json["components"]["schemas"].get(name).is_some(), "dangling $ref {}", target);
+    }
+}
+
+#[test]
+fn test_committed_spec_is_current() {
+    let generated = noesis_api::openapi_spec().to_pretty_json().unwrap();
+    let committed = std::fs::read_to_string(committed_spec_path()).unwrap_or_default();
+    assert!(
+        committed.trim_end() == generated,
+        "{} is stale; regenerate it with `cargo run -p noesis-api --bin export_openapi`",
+        noesis_api::openapi::SPEC_PATH
+    );
+}
@@ -9,6 +9,14 @@ use noesis_api::ApiConfig;
 use noesis_auth::{ApiKey, AuthService};
 use noesis_cache::CacheManager;
 use noesis_data::repositories::user_repository::UserRepository;
+use noesis_data::repositories::client_repository::ClientRepository;
+use noesis_data::repositories::api_key_repository::ApiKeyRepository;
+use noesis_data::repositories::location_repository::SavedLocationRepository;
+use noesis_data::repositories::notification_repository::NotificationRepository;
+use noesis_data::repositories::practice_repository::PracticeRepository;
+use noesis_data::repositories::settings_repository::SettingsRepository;
+use noesis_data::repositories::organization_repository::OrganizationRepository;
+use noesis_data::repositories::workflow_result_repository::WorkflowResultRepository;
 use noesis_orchestrator::WorkflowOrchestrator;
 use sqlx::postgres::PgPoolOptions;
 use tower::ServiceExt;
@@ -55,6 +63,30 @@ fn build_test_app_state() -> (noesis_api::AppState, ApiConfig) {
         request_timeout_secs: 30,
         log_level: "info".to_string(),
         log_format: "pretty".to_string(),
+        critical_engines: vec![],
+        share_link_ttl_secs: 604_800,
+        public_base_url: None,
+        max_body_bytes: 1_048_576,
+        strict_json: false,
+        name_normalization: Default::default(),
+        open_debug: false,
+        purge_interval_secs: 3600,
+        retention: Default::default(),
+        retention_interval_secs: 86_400,
+        notification_interval_secs: 3600,
+        analytics_min_group_size: 10,
+        analytics_cache_ttl_secs: 3600,
+        db_auto_migrate: true,
+        db_connect_attempts: 5,
+        database_replica_url: None,
+        db_statement_timeout_ms: 5000,
+        load_shed_max_in_flight: 512,
+        load_shed_max_lag_ms: 100,
+        disabled_api_versions: vec![],
+        l1_cache_shares: Default::default(),
+        redaction: Default::default(),
+        metrics_access: Default::default(),
+        metrics_port: None,
     };
 
     // -- User repository --
@@ -63,7 +95,15 @@ fn build_test_app_state() -> (noesis_api::AppState, ApiConfig) {
         .max_connections(1)
         .connect_lazy(&database_url)
         .expect("Invalid DATABASE_URL");
-    let user_repository = Arc::new(UserRepository::new(pool));
+    let user_repository = Arc::new(UserRepository::new(pool.clone()));
+    let result_repository = Arc::new(WorkflowResultRepository::new(pool.clone()));
+    let organization_repository = Arc::new(OrganizationRepository::new(pool.clone()));
+    let client_repository = Arc::new(ClientRepository::new(pool.clone()));
+    let location_repository = Arc::new(SavedLocationRepository::new(pool.clone()));
+    let api_key_repository = Arc::new(ApiKeyRepository::new(pool.clone()));
+    let practice_repository = Arc::new(PracticeRepository::new(pool.clone()));
+    let notification_repository = Arc::new(NotificationRepository::new(pool.clone()));
+    let settings = Arc::new(noesis_api::settings::SettingsStore::new(SettingsRepository::new(pool.clone())));
 
     // -- Metrics -- initialize only once globally
     static mut METRICS: Option<Arc<noesis_metrics::NoesisMetrics>> = None;
@@ -76,12 +116,43 @@ fn build_test_app_state() -> (noesis_api::AppState, ApiConfig) {
         METRICS.as_ref().unwrap().clone()
     };
 
+    let orchestrator = Arc::new(orchestrator);
+    let (precompute, _) = noesis_api::precompute::spawn_precompute_worker(orchestrator.clone(), 1);
+
     let state = noesis_api::AppState {
-        orchestrator: Arc::new(orchestrator),
-        cache: Arc::new(cache),
-        auth: Arc::new(auth),
-        metrics,
+        core: noesis_runtime::AppCore {
+            orchestrator,
+            cache: Arc::new(cache),
+            auth: Arc::new(auth),
+            metrics,
+        },
         user_repository,
+        result_repository,
+        organization_repository,
+        client_repository,
+        location_repository,
+        api_key_repository,
+        practice_repository,
+        notification_repository,
+        settings,
+        share_links: Arc::new(noesis_api::sharing::ShareLinks::new(
+            &config.jwt_secret,
+            config.share_link_ttl_secs,
+            None,
+        )),
+        geocoder: Arc::new(noesis_api::geo::EmbeddedGeocoder::new()),
+        precompute,
+        recalculations: Arc::new(noesis_api::recalculate::RecalculationJobs::new()),
+        research_exports: Arc::new(noesis_api::research_export::ResearchExports::new(None)),
+        fanout: noesis_api::fanout::FanoutHub::new(),
+        database: Arc::new(noesis_api::database::DatabaseStatus::new(true)),
+        db_pools: noesis_data::DbPools::new(pool),
+        open_debug: false,
+        analytics: noesis_api::analytics::AnalyticsSettings {
+            min_group_size: config.analytics_min_group_size,
+            cache_ttl: Duration::from_secs(config.analytics_cache_ttl_secs),
+        },
+        status_board: Arc::new(noesis_api::public_status::StatusBoard::new(None, None)),
         startup_time: Instant::now(),
     };
 
@@ -102,6 +173,8 @@ async fn create_test_api_key(auth: &Arc<AuthService>, user_id: &str, rate_limit:
         last_used: None,
         rate_limit,
         consciousness_level: 0,
+        allowed_engines: None,
+        allowed_workflows: None,
     };
     
     auth.add_api_key(api_key).await.expect("Failed to add API key");
@@ -111,7 +184,7 @@ async fn create_test_api_key(auth: &Arc<AuthService>, user_id: &str, rate_limit:
 #[tokio::test]
 async fn test_rate_limit_allows_requests_under_limit() {
     let (state, config) = build_test_app_state();
-    let api_key = create_test_api_key(&state.auth, "user1", 5).await;
+    let api_key = create_test_api_key(&state.core.auth, "user1", 5).await;
     let app = create_router(state, &config);
     
     // Make 5 requests (all should succeed)
@@ -152,7 +225,7 @@ async fn test_rate_limit_allows_requests_under_limit() {
 #[tokio::test]
 async fn test_rate_limit_blocks_requests_over_limit() {
     let (state, config) = build_test_app_state();
-    let api_key = create_test_api_key(&state.auth, "user2", 3).await;
+    let api_key = create_test_api_key(&state.core.auth, "user2", 3).await;
     let app = create_router(state, &config);
     
     // Make 3 requests (should all succeed)
@@ -199,8 +272,8 @@ async fn test_rate_limit_blocks_requests_over_limit() {
 #[tokio::test]
 async fn test_rate_limit_per_user_isolation() {
     let (state, config) = build_test_app_state();
-    let api_key1 = create_test_api_key(&state.auth, "user3", 2).await;
-    let api_key2 = create_test_api_key(&state.auth, "user4", 2).await;
+    let api_key1 = create_test_api_key(&state.core.auth, "user3", 2).await;
+    let api_key2 = create_test_api_key(&state.core.auth, "user4", 2).await;
     let app = create_router(state, &config);
     
     // User1 makes 2 requests (reaches limit)
@@ -268,7 +341,7 @@ async fn test_rate_limit_skips_public_routes() {
 #[tokio::test]
 async fn test_rate_limit_response_format() {
     let (state, config) = build_test_app_state();
-    let api_key = create_test_api_key(&state.auth, "user5", 1).await;
+    let api_key = create_test_api_key(&state.core.auth, "user5", 1).await;
     let app = create_router(state, &config);
     
     // First request succeeds
@@ -285,6 +358,7 @@ async fn test_rate_limit_response_format() {
     let request = Request::builder()
         .uri("/api/v1/status")
         .header("X-API-Key", &api_key)
+        .header("Accept", "application/json")
         .body(Body::empty())
         .unwrap();
     
@@ -311,7 +385,7 @@ async fn test_rate_limit_response_format() {
 async fn test_rate_limit_default_100_per_minute() {
     let (state, config) = build_test_app_state();
     // Create API key with rate_limit = 0 (should use default 100)
-    let api_key = create_test_api_key(&state.auth, "user6", 0).await;
+    let api_key = create_test_api_key(&state.core.auth, "user6", 0).await;
     let app = create_router(state, &config);
     
     let request = Request::builder()
@@ -333,3 +407,90 @@ async fn test_rate_limit_default_100_per_minute() {
     
     assert_eq!(limit, "100", "Default rate limit should be 100");
 }
+
+#[tokio::test]
+async fn test_rate_limit_standard_headers_and_retry_after() {
+    let (state, config) = build_test_app_state();
+    let api_key = create_test_api_key(&state.core.auth, "user7", 2).await;
+    let app = create_router(state, &config);
+
+    let header = |response: &axum::response::Response, name: &str| -> i64 {
+        response.headers().get(name).unwrap().to_str().unwrap().parse().unwrap()
+    };
+    let request = || {
+        Request::builder()
+            .uri("/api/v1/status")
+            .header("X-API-Key", &api_key)
+            .body(Body::empty())
+            .unwrap()
+    };
+
+    let response = app.clone().oneshot(request()).await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(header(&response, "RateLimit-Limit"), 2);
+    assert_eq!(header(&response, "RateLimit-Remaining"), 1);
+    assert!((0..=60).contains(&header(&response, "RateLimit-Reset")));
+    assert_eq!(response.headers()["RateLimit-Policy"], "2;w=60");
+    assert!(!response.headers().contains_key("Retry-After"));
+
+    app.clone().oneshot(request()).await.unwrap();
+    let response = app.clone().oneshot(request()).await.unwrap();
+    assert_eq!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+    assert_eq!(header(&response, "RateLimit-Remaining"), 0);
+    let retry_after = header(&response, "Retry-After");
+    assert!((1..=60).contains(&retry_after), "Retry-After {}", retry_after);
+    assert!(retry_after >= header(&response, "RateLimit-Reset"));
+}
+
+#[tokio::test]
+async fn test_scoped_api_key_is_refused_other_engines() {
+    let (state, config) = build_test_app_state();
+    state
+        .core
+        .auth
+        .add_api_key(ApiKey {
+            key: "test-key-scoped".to_string(),
+            user_id: "user8".to_string(),
+            tier: "test".to_string(),
+            permissions: vec!["basic:access".to_string()],
+            created_at: Utc::now(),
+            expires_at: None,
+            last_used: None,
+            rate_limit: 10,
+            consciousness_level: 5,
+            allowed_engines: Some(vec!["panchanga".to_string()]),
+            allowed_workflows: Some(vec![]),
+        })
+        .await
+        .unwrap();
+    let app = create_router(state, &config);
+
+    let request = |uri: &str| {
+        let body = serde_json::json!({
+            "birth_data": { "name": "Ada Lovelace", "date": "1815-12-10" },
+            "options": {}
+        });
+        Request::builder()
+            .method("POST")
+            .uri(uri)
+            .header("X-API-Key", "test-key-scoped")
+            .header("Accept", "application/json")
+            .header("Content-Type", "application/json")
+            .body(Body::from(body.to_string()))
+            .unwrap()
+    };
+    let body = |response: axum::response::Response| async {
+        let bytes = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        serde_json::from_slice::<serde_json::Value>(&bytes).unwrap()
+    };
+
+    let response = app.clone().oneshot(request("/api/v1/engines/numerology/calculate")).await.unwrap();
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+    let error = body(response).await;
+    assert_eq!(error["error_code"], "FORBIDDEN");
+    assert_eq!(error["details"]["required_scope"], "engine:numerology");
+
+    let response = app.clone().oneshot(request("/api/v1/workflows/birth-blueprint/execute")).await.unwrap();
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+    assert_eq!(body(response).await["details"]["required_scope"], "workflow:birth-blueprint");
+}
@@ -84,6 +84,7 @@ impl ConsciousnessEngine for DelayMockEngine {
                 "delay_ms": self.delay.as_millis(),
             }),
             witness_prompt: format!("What does {} reveal about your journey?", self.id),
+            summary: None,
             consciousness_level: self.phase,
             metadata: CalculationMetadata {
                 calculation_time_ms: self.delay.as_millis() as f64,
@@ -91,6 +92,9 @@ impl ConsciousnessEngine for DelayMockEngine {
                 precision_achieved: "standard".to_string(),
                 cached: false,
                 timestamp: Utc::now(),
+                engine_version: None,
+                warnings: Vec::new(),
+                usage: None,
             },
         })
     }
@@ -113,12 +117,19 @@ fn test_input() -> EngineInput {
     EngineInput {
         birth_data: Some(BirthData {
             name: Some("Parallel Test".to_string()),
+            display_name: None,
             date: "1990-01-15".to_string(),
             time: Some("14:30".to_string()),
+            time_unknown: false,
             latitude: 12.9716,
             longitude: 77.5946,
+            altitude: None,
             timezone: "Asia/Kolkata".to_string(),
+            place: None,
+            utc_offset_minutes: None,
         }),
+        secondary_birth_data: None,
+        subjects: Vec::new(),
         current_time: Utc::now(),
         location: None,
         precision: Precision::Standard,
@@ -12,6 +12,7 @@ use jsonwebtoken::{decode, DecodingKey, Validation, Algorithm, encode, EncodingK
 use serde::{Deserialize, Serialize};
 
 pub mod password;
+pub mod permissions;
 
 use std::collections::HashMap;
 use std::sync::Arc;
@@ -47,6 +48,12 @@ pub struct ApiKey {
     pub last_used: Option<DateTime<Utc>>,
     pub rate_limit: u32,       // Requests per minute
     pub consciousness_level: u8, // User consciousness level (0-5)
+    /// Engines the key may calculate; any engine when `None`
+    #[serde(default)]
+    pub allowed_engines: Option<Vec<String>>,
+    /// Workflows the key may execute; any workflow when `None`
+    #[serde(default)]
+    pub allowed_workflows: Option<Vec<String>>,
 }
 
 /// User authentication information
@@ -57,6 +64,41 @@ pub struct AuthUser {
     pub permissions: Vec<String>,
     pub rate_limit: u32,
     pub consciousness_level: u8,
+    /// Engine scope of the API key used; `None` for JWTs and unscoped keys
+    pub allowed_engines: Option<Vec<String>>,
+    /// Workflow scope of the API key used; `None` for JWTs and unscoped keys
+    pub allowed_workflows: Option<Vec<String>>,
+}
+
+impl AuthUser {
+    /// Whether the credential may calculate `engine_id`
+    pub fn can_use_engine(&self, engine_id: &str) -> bool {
+        in_scope(&self.allowed_engines, engine_id)
+    }
+
+    /// Whether the credential may execute `workflow_id`
+    pub fn can_run_workflow(&self, workflow_id: &str) -> bool {
+        in_scope(&self.allowed_workflows, workflow_id)
+    }
+
+    /// Whether the credential is an API key limited to some engines or workflows
+    pub fn is_scoped(&self) -> bool {
+        self.allowed_engines.is_some() || self.allowed_workflows.is_some()
+    }
+}
+
+fn in_scope(allowed: &Option<Vec<String>>, id: &str) -> bool {
+    allowed.as_ref().is_none_or(|ids| ids.iter().any(|allowed| allowed == id))
+}
+
+/// Scope naming `engine_id`, as reported when a key lacks it
+pub fn engine_scope(engine_id: &str) -> String {
+    format!("engine:{}", engine_id)
+}
+
+/// Scope naming `workflow_id`, as reported when a key lacks it
+pub fn workflow_scope(workflow_id: &str) -> String {
+    format!("workflow:{}", workflow_id)
 }
 
 /// Row returned by the api_keys Postgres query
@@ -75,6 +117,8 @@ struct ApiKeyRecord {
     pub expires_at: Option<DateTime<Utc>>,
     pub last_used: Option<DateTime<Utc>>,
     pub is_active: bool,
+    pub allowed_engines: Option<serde_json::Value>,
+    pub allowed_workflows: Option<serde_json::Value>,
 }
 
 /// Authentication service
@@ -145,6 +189,8 @@ impl AuthService {
             permissions: claims.permissions,
             rate_limit,
             consciousness_level: claims.consciousness_level,
+            allowed_engines: None,
+            allowed_workflows: None,
         })
     }
 
@@ -181,6 +227,8 @@ impl AuthService {
             let permissions = key_info.permissions.clone();
             let rate_limit = key_info.rate_limit;
             let consciousness_level = key_info.consciousness_level;
+            let allowed_engines = key_info.allowed_engines.clone();
+            let allowed_workflows = key_info.allowed_workflows.clone();
             drop(keys);
             self.update_api_key_usage(api_key).await?;
 
@@ -190,6 +238,8 @@ impl AuthService {
                 permissions,
                 rate_limit,
                 consciousness_level,
+                allowed_engines,
+                allowed_workflows,
             })
         } else {
             Err(EngineError::AuthError("Invalid API key".to_string()))
@@ -198,8 +248,8 @@ impl AuthService {
 
     /// Validate an API key against the PostgreSQL api_keys table.
     ///
-    /// Hashes the raw key with SHA-256, queries for an active non-expired row,
-    /// and spawns an async task to update `last_used` without blocking the caller.
+    /// Hashes the raw key with SHA-256, queries for an active non-expired row
+    /// not deleted with its account, and spawns an async task to update `last_used` without blocking the caller.
     #[cfg(feature = "postgres")]
     async fn validate_from_postgres(&self, pool: &PgPool, api_key: &str) -> Result<AuthUser, EngineError> {
         let key_hash = sha256_hex(api_key);
@@ -209,9 +259,10 @@ impl AuthService {
             std::time::Duration::from_secs(5),
             sqlx::query_as::<_, ApiKeyRecord>(
                 "SELECT id, key_hash, user_id, tier, permissions, consciousness_level, \
-                 rate_limit, created_at, expires_at, last_used, is_active \
+                 rate_limit, created_at, expires_at, last_used, is_active, \
+                 allowed_engines, allowed_workflows \
                  FROM api_keys \
-                 WHERE key_hash = $1 AND is_active = true \
+                 WHERE key_hash = $1 AND is_active = true AND deleted_at IS NULL \
                    AND (expires_at IS NULL OR expires_at > NOW())"
             )
             .bind(&key_hash)
@@ -224,23 +275,28 @@ impl AuthService {
         // Update last_used asynchronously (fire-and-forget)
         let pool_clone = pool.clone();
         let key_hash_clone = key_hash.clone();
-        tokio::spawn(async move {
+        tokio::spawn(noesis_core::correlation::propagate(async move {
             let _ = sqlx::query("UPDATE api_keys SET last_used = NOW() WHERE key_hash = $1")
                 .bind(&key_hash_clone)
                 .execute(&pool_clone)
                 .await;
-        });
+        }));
 
         // Parse permissions from JSONB
         let permissions: Vec<String> = serde_json::from_value(record.permissions)
             .unwrap_or_default();
 
+        // A scope that fails to parse allows nothing rather than everything
+        let scope = |ids: Option<serde_json::Value>| ids.map(|ids| serde_json::from_value(ids).unwrap_or_default());
+
         Ok(AuthUser {
             user_id: record.user_id.to_string(),
             tier: record.tier,
             permissions,
             rate_limit: record.rate_limit as u32,
             consciousness_level: record.consciousness_level as u8,
+            allowed_engines: scope(record.allowed_engines),
+            allowed_workflows: scope(record.allowed_workflows),
         })
     }
 
@@ -310,6 +366,11 @@ impl AuthService {
             "/api/v1/panchanga/batch" => vec!["panchanga:batch"],
             "/api/v1/admin/users" => vec!["admin:users"],
             "/api/v1/admin/analytics" => vec!["admin:analytics"],
+            "/api/v1/admin/validation/report" => vec!["admin:validation"],
+            "/api/v1/admin/experiments" => vec!["admin:experiments"],
+            "/api/v1/admin/workflows" => vec!["admin:workflows"],
+            "/api/v1/admin/cache" => vec!["admin:cache"],
+            "/api/v1/wisdom/witness-packs" => vec!["admin:wisdom"],
             _ => vec!["basic:access"], // Default permission
         };
 
@@ -364,6 +425,20 @@ pub fn sha256_hex(input: &str) -> String {
     format!("{:x}", hasher.finalize())
 }
 
+/// Prefix of generated API keys, so they are easy to recognise
+pub const API_KEY_PREFIX: &str = "nk_";
+
+/// A new random API key: [`API_KEY_PREFIX`] and 32 alphanumeric characters
+pub fn generate_api_key() -> String {
+    use rand::Rng;
+    let random: String = rand::thread_rng()
+        .sample_iter(&rand::distributions::Alphanumeric)
+        .take(32)
+        .map(char::from)
+        .collect();
+    format!("{}{}", API_KEY_PREFIX, random)
+}
+
 /// Tier limits for different user levels
 #[derive(Debug, Clone)]
 pub struct TierLimits {
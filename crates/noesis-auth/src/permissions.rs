@@ -0,0 +1,44 @@
+//! Permission scopes for practitioner access to client profiles
+//!
+//! Members of an organization receive client scopes at login according to
+//! their role. API keys can carry a subset, e.g. a booking integration with
+//! `clients:read` only.
+
+/// List and view client profiles
+pub const CLIENTS_READ: &str = "clients:read";
+/// Create client profiles and change their consent flags
+pub const CLIENTS_WRITE: &str = "clients:write";
+/// Run calculations on a client's behalf
+pub const CLIENTS_CALCULATE: &str = "clients:calculate";
+
+/// Organization roles, as stored in `organization_members.role`
+pub const ROLE_OWNER: &str = "owner";
+pub const ROLE_PRACTITIONER: &str = "practitioner";
+pub const ROLE_ASSISTANT: &str = "assistant";
+
+/// Client scopes granted to a member with `role`; none for unknown roles
+pub fn for_role(role: &str) -> &'static [&'static str] {
+    match role {
+        ROLE_OWNER | ROLE_PRACTITIONER => &[CLIENTS_READ, CLIENTS_WRITE, CLIENTS_CALCULATE],
+        ROLE_ASSISTANT => &[CLIENTS_READ],
+        _ => &[],
+    }
+}
+
+/// Whether `role` can be assigned to a member
+pub fn is_known_role(role: &str) -> bool {
+    matches!(role, ROLE_OWNER | ROLE_PRACTITIONER | ROLE_ASSISTANT)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_role_scopes() {
+        assert!(for_role(ROLE_PRACTITIONER).contains(&CLIENTS_CALCULATE));
+        assert_eq!(for_role(ROLE_ASSISTANT), &[CLIENTS_READ]);
+        assert!(for_role("guest").is_empty());
+        assert!(!is_known_role("guest"));
+    }
+}
@@ -109,6 +109,8 @@ mod postgres_tests {
             last_used: None,
             rate_limit: 60,
             consciousness_level: 0,
+            allowed_engines: None,
+            allowed_workflows: None,
         };
         auth.add_api_key(api_key).await.unwrap();
 
@@ -117,6 +119,31 @@ mod postgres_tests {
         assert_eq!(user.tier, "free");
     }
 
+    #[tokio::test]
+    async fn test_memory_fallback_carries_scopes() {
+        let auth = AuthService::with_pool("test-secret".to_string(), None);
+        let api_key = ApiKey {
+            key: "scoped-key".to_string(),
+            user_id: "user-3".to_string(),
+            tier: "free".to_string(),
+            permissions: vec![],
+            created_at: Utc::now(),
+            expires_at: None,
+            last_used: None,
+            rate_limit: 60,
+            consciousness_level: 0,
+            allowed_engines: Some(vec!["panchanga".to_string(), "biorhythm".to_string()]),
+            allowed_workflows: Some(vec![]),
+        };
+        auth.add_api_key(api_key).await.unwrap();
+
+        let user = auth.validate_api_key("scoped-key").await.unwrap();
+        assert!(user.is_scoped());
+        assert!(user.can_use_engine("biorhythm"));
+        assert!(!user.can_use_engine("numerology"));
+        assert!(!user.can_run_workflow("daily-practice"));
+    }
+
     #[tokio::test]
     async fn test_memory_fallback_invalid_key() {
         let auth = AuthService::with_pool("test-secret".to_string(), None);
@@ -137,6 +164,8 @@ mod postgres_tests {
             last_used: None,
             rate_limit: 60,
             consciousness_level: 0,
+            allowed_engines: None,
+            allowed_workflows: None,
         };
         auth.add_api_key(api_key).await.unwrap();
 
@@ -25,6 +25,25 @@ pub enum BridgeError {
 
     #[error("Server unavailable: {0}")]
     ServerUnavailable(String),
+
+    #[error("Engine {engine_id} response rejected: {violation}")]
+    SandboxViolation {
+        engine_id: String,
+        violation: SandboxViolation,
+    },
+}
+
+/// Ways a TS engine response can break the limits in [`BridgeLimits`](crate::BridgeLimits)
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum SandboxViolation {
+    #[error("body exceeds {limit} bytes")]
+    ResponseTooLarge { limit: usize },
+
+    #[error("JSON nesting exceeds depth {limit}")]
+    JsonTooDeep { limit: usize },
+
+    #[error("content type '{found}' is not application/json")]
+    UnexpectedContentType { found: String },
 }
 
 impl BridgeError {
@@ -33,3 +52,9 @@ impl BridgeError {
         self.to_string()
     }
 }
+
+impl From<BridgeError> for noesis_core::EngineError {
+    fn from(err: BridgeError) -> Self {
+        noesis_core::EngineError::BridgeError(err.to_engine_error_message())
+    }
+}
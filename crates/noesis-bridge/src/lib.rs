@@ -16,18 +16,28 @@
 //! ```
 
 use std::sync::Arc;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
 use async_trait::async_trait;
+use serde::{de::DeserializeOwned, Serialize};
 use sha2::{Digest, Sha256};
 use tracing::{debug, info, warn};
 
 pub mod error;
-pub use error::BridgeError;
+pub mod limits;
+pub mod retry;
+pub mod wire;
+pub use error::{BridgeError, SandboxViolation};
+pub use limits::BridgeLimits;
+pub use retry::RetryConfig;
+pub use wire::{CalculateRequest, CalculateResponse};
 
 pub use noesis_core::{
     ConsciousnessEngine, EngineError, EngineInput, EngineOutput, ValidationResult,
 };
+use noesis_core::casing::snake_case_keys;
+use noesis_core::description::EngineDescription;
+use noesis_core::examples::{self, EngineExample};
 
 /// Default URL for the TypeScript engines server.
 pub const DEFAULT_TS_SERVER_URL: &str = "http://localhost:3001";
@@ -35,6 +45,17 @@ pub const DEFAULT_TS_SERVER_URL: &str = "http://localhost:3001";
 /// Default timeout for HTTP requests in seconds.
 pub const DEFAULT_TIMEOUT_SECS: u64 = 5;
 
+/// Exchanges recorded from the TS server, by engine id. The contract tests
+/// replay them; [`BridgeEngine::examples`] serves them, so trying an engine
+/// out doesn't need the server.
+const CONTRACT_FIXTURES: [(&str, &str); 5] = [
+    ("enneagram", include_str!("../tests/fixtures/contract/enneagram.json")),
+    ("i-ching", include_str!("../tests/fixtures/contract/i-ching.json")),
+    ("sacred-geometry", include_str!("../tests/fixtures/contract/sacred-geometry.json")),
+    ("sigil-forge", include_str!("../tests/fixtures/contract/sigil-forge.json")),
+    ("tarot", include_str!("../tests/fixtures/contract/tarot.json")),
+];
+
 // ---------------------------------------------------------------------------
 // BridgeEngine
 // ---------------------------------------------------------------------------
@@ -42,7 +63,9 @@ pub const DEFAULT_TIMEOUT_SECS: u64 = 5;
 /// HTTP adapter that proxies trait calls to a TypeScript engine running on Bun.
 ///
 /// Each instance targets a single engine endpoint on the Bun server.
-/// All HTTP errors are mapped to `EngineError::BridgeError`.
+/// All HTTP errors are mapped to `EngineError::BridgeError`. Responses are
+/// checked against [`BridgeLimits`] before they are deserialized, and
+/// transient failures are retried per [`RetryConfig`].
 pub struct BridgeEngine {
     engine_id: String,
     engine_name: String,
@@ -50,6 +73,14 @@ pub struct BridgeEngine {
     base_url: String,
     client: reqwest::Client,
     timeout: Duration,
+    limits: BridgeLimits,
+    retry: RetryConfig,
+}
+
+/// Failure of a single bridge attempt and whether it may be retried
+struct AttemptError {
+    error: EngineError,
+    retryable: bool,
 }
 
 impl BridgeEngine {
@@ -95,6 +126,215 @@ impl BridgeEngine {
             base_url: base_url.into().trim_end_matches('/').to_owned(),
             client,
             timeout,
+            limits: BridgeLimits::default(),
+            retry: RetryConfig::default(),
+        }
+    }
+
+    /// Replace the response limits for this engine.
+    pub fn with_limits(mut self, limits: BridgeLimits) -> Self {
+        self.limits = limits;
+        self
+    }
+
+    /// Response limits applied to this engine.
+    pub fn limits(&self) -> &BridgeLimits {
+        &self.limits
+    }
+
+    /// Replace the retry policy for this engine.
+    pub fn with_retry(mut self, retry: RetryConfig) -> Self {
+        self.retry = retry;
+        self
+    }
+
+    /// Retry policy applied to this engine.
+    pub fn retry_config(&self) -> &RetryConfig {
+        &self.retry
+    }
+
+    /// POST `body` to the engine's `operation` endpoint, retrying transient
+    /// failures according to the retry policy.
+    async fn post<B, T>(&self, operation: &'static str, body: &B, type_name: &str) -> Result<T, EngineError>
+    where
+        B: Serialize + ?Sized,
+        T: DeserializeOwned,
+    {
+        let url = format!("{}/engines/{}/{}", self.base_url, self.engine_id, operation);
+        let mut attempt: u32 = 0;
+
+        loop {
+            let started = Instant::now();
+            let result = self.attempt::<B, T>(&url, operation, body, type_name).await;
+            let elapsed = started.elapsed().as_secs_f64();
+
+            match result {
+                Ok(value) => {
+                    noesis_metrics::record_bridge_attempt(&self.engine_id, operation, "success", elapsed);
+                    if attempt > 0 {
+                        info!(engine = %self.engine_id, operation, attempts = attempt + 1, "bridge call succeeded after retry");
+                    }
+                    return Ok(value);
+                }
+                Err(failure) if failure.retryable && self.retry.should_retry(attempt) => {
+                    noesis_metrics::record_bridge_attempt(&self.engine_id, operation, "retry", elapsed);
+                    let delay = self.retry.delay_for_attempt(attempt);
+                    warn!(
+                        engine = %self.engine_id,
+                        operation,
+                        attempt = attempt + 1,
+                        ?delay,
+                        error = %failure.error,
+                        "bridge attempt failed, retrying"
+                    );
+                    tokio::time::sleep(delay).await;
+                    attempt += 1;
+                }
+                Err(failure) => {
+                    noesis_metrics::record_bridge_attempt(&self.engine_id, operation, "failure", elapsed);
+                    return Err(match failure.error {
+                        EngineError::BridgeError(msg) if attempt > 0 => EngineError::BridgeError(format!(
+                            "{} (gave up after {} attempts)", msg, attempt + 1
+                        )),
+                        error => error,
+                    });
+                }
+            }
+        }
+    }
+
+    /// A single request/response exchange with the TS server.
+    async fn attempt<B, T>(
+        &self,
+        url: &str,
+        operation: &str,
+        body: &B,
+        type_name: &str,
+    ) -> Result<T, AttemptError>
+    where
+        B: Serialize + ?Sized,
+        T: DeserializeOwned,
+    {
+        debug!(
+            engine = %self.engine_id,
+            %url,
+            operation,
+            timeout_secs = self.timeout.as_secs(),
+            "bridge request"
+        );
+
+        let mut request = self.client.post(url).json(body);
+        if let Some(request_id) = noesis_core::correlation::current_request_id() {
+            request = request.header(noesis_core::correlation::REQUEST_ID_HEADER, request_id);
+        }
+
+        let sent = Instant::now();
+        noesis_core::usage::record_bridge_round_trip();
+        let response = request.send().await;
+        let status_label = match &response {
+            Ok(response) => response.status().as_str().to_string(),
+            Err(e) if e.is_timeout() => "timeout".to_string(),
+            Err(e) if e.is_connect() => "connect".to_string(),
+            Err(_) => "error".to_string(),
+        };
+        noesis_metrics::record_bridge_http_response(
+            &self.engine_id,
+            operation,
+            &status_label,
+            sent.elapsed().as_secs_f64(),
+        );
+
+        let response = response.map_err(|e| {
+            let message = if e.is_timeout() {
+                warn!(engine = %self.engine_id, operation, "Bridge request timed out");
+                format!(
+                    "Request to {} {} timed out after {}s",
+                    self.engine_id,
+                    operation,
+                    self.timeout.as_secs()
+                )
+            } else if e.is_connect() {
+                warn!(engine = %self.engine_id, %url, "Bridge connection refused");
+                format!(
+                    "Connection to {} refused (is the TS server running at {}?)",
+                    self.engine_id,
+                    self.base_url
+                )
+            } else {
+                warn!(engine = %self.engine_id, error = %e, "Bridge HTTP error");
+                format!("HTTP request to {} failed: {}", url, e)
+            };
+            AttemptError {
+                error: EngineError::BridgeError(message),
+                retryable: e.is_timeout() || e.is_connect(),
+            }
+        })?;
+
+        let status = response.status();
+        if !status.is_success() {
+            let body = limits::read_error_body(response, limits::MAX_ERROR_BODY_BYTES).await;
+            warn!(
+                engine = %self.engine_id,
+                operation,
+                %status,
+                %body,
+                "bridge returned non-2xx"
+            );
+            return Err(AttemptError {
+                error: EngineError::BridgeError(format!(
+                    "Engine {} {} returned {}: {}", self.engine_id, operation, status, body
+                )),
+                retryable: retry::is_retryable_status(status),
+            });
+        }
+
+        self.parse_response(response, type_name).await
+    }
+
+    /// Read a successful response within the sandbox limits and deserialize it.
+    async fn parse_response<T: DeserializeOwned>(
+        &self,
+        response: reqwest::Response,
+        type_name: &str,
+    ) -> Result<T, AttemptError> {
+        let content_type = response
+            .headers()
+            .get(reqwest::header::CONTENT_TYPE)
+            .and_then(|v| v.to_str().ok())
+            .map(str::to_owned);
+
+        self.limits
+            .check_content_type(content_type.as_deref())
+            .map_err(|v| self.violation(v))?;
+
+        let body = limits::read_body(response, self.limits.max_response_bytes)
+            .await
+            .map_err(|e| AttemptError {
+                error: EngineError::BridgeError(format!(
+                    "Failed to read response from {}: {}", self.engine_id, e
+                )),
+                retryable: true,
+            })?
+            .map_err(|v| self.violation(v))?;
+        limits::check_json_depth(&body, self.limits.max_json_depth).map_err(|v| self.violation(v))?;
+
+        serde_json::from_slice::<T>(&body).map_err(|e| AttemptError {
+            error: EngineError::BridgeError(format!(
+                "Failed to deserialize {} from {}: {}", type_name, self.engine_id, e
+            )),
+            retryable: false,
+        })
+    }
+
+    fn violation(&self, violation: SandboxViolation) -> AttemptError {
+        warn!(engine = %self.engine_id, %violation, "bridge response violated sandbox limits");
+        AttemptError {
+            error: BridgeError::SandboxViolation {
+                engine_id: self.engine_id.clone(),
+                violation,
+            }
+            .into(),
+            retryable: false,
         }
     }
 
@@ -168,125 +408,49 @@ impl ConsciousnessEngine for BridgeEngine {
     }
 
     async fn calculate(&self, input: EngineInput) -> Result<EngineOutput, EngineError> {
-        let url = format!(
-            "{}/engines/{}/calculate",
-            self.base_url, self.engine_id
-        );
-
-        debug!(
-            engine = %self.engine_id,
-            %url,
-            timeout_secs = self.timeout.as_secs(),
-            "bridge calculate request"
-        );
-
+        let request = CalculateRequest::from_input(&input, self.required_phase);
         let response = self
-            .client
-            .post(&url)
-            .json(&input)
-            .send()
-            .await
-            .map_err(|e| {
-                if e.is_timeout() {
-                    warn!(engine = %self.engine_id, "Bridge request timed out");
-                    EngineError::BridgeError(format!(
-                        "Request to {} timed out after {}s",
-                        self.engine_id,
-                        self.timeout.as_secs()
-                    ))
-                } else if e.is_connect() {
-                    warn!(engine = %self.engine_id, %url, "Bridge connection refused");
-                    EngineError::BridgeError(format!(
-                        "Connection to {} refused (is the TS server running at {}?)",
-                        self.engine_id,
-                        self.base_url
-                    ))
-                } else {
-                    warn!(engine = %self.engine_id, error = %e, "Bridge HTTP error");
-                    EngineError::BridgeError(format!(
-                        "HTTP request to {} failed: {}",
-                        url, e
-                    ))
-                }
-            })?;
-
-        if !response.status().is_success() {
-            let status = response.status();
-            let body = response.text().await.unwrap_or_default();
-            warn!(
-                engine = %self.engine_id,
-                %status,
-                %body,
-                "bridge calculate returned non-2xx"
-            );
-            return Err(EngineError::BridgeError(format!(
-                "Engine {} returned {}: {}", self.engine_id, status, body
-            )));
-        }
-
+            .post::<_, CalculateResponse>("calculate", &request, "CalculateResponse")
+            .await?;
         info!(engine = %self.engine_id, "Bridge calculate succeeded");
-        
-        response.json::<EngineOutput>().await.map_err(|e| {
-            EngineError::BridgeError(format!(
-                "Failed to deserialize EngineOutput from {}: {}", self.engine_id, e
-            ))
-        })
+        Ok(response.into_output(&request, &input))
     }
 
     async fn validate(&self, output: &EngineOutput) -> Result<ValidationResult, EngineError> {
-        let url = format!(
-            "{}/engines/{}/validate",
-            self.base_url, self.engine_id
-        );
-
-        debug!(engine = %self.engine_id, %url, "bridge validate request");
+        self.post("validate", output, "ValidationResult").await
+    }
 
-        let response = self
-            .client
-            .post(&url)
-            .json(output)
-            .send()
-            .await
-            .map_err(|e| {
-                if e.is_timeout() {
-                    EngineError::BridgeError(format!(
-                        "Validate request to {} timed out after {}s",
-                        self.engine_id,
-                        self.timeout.as_secs()
-                    ))
-                } else if e.is_connect() {
-                    EngineError::BridgeError(format!(
-                        "Connection to {} refused (is the TS server running at {}?)",
-                        self.engine_id,
-                        self.base_url
-                    ))
-                } else {
-                    EngineError::BridgeError(format!(
-                        "HTTP request to {} failed: {}",
-                        url, e
-                    ))
-                }
-            })?;
+    /// Ping the TS server and check that it has this engine registered.
+    async fn health(&self) -> Result<(), EngineError> {
+        let response = fetch_health(&self.client, &self.base_url).await?;
+        let health: serde_json::Value = response.json().await.map_err(|e| {
+            EngineError::BridgeError(format!("Health response from {} is not JSON: {}", self.base_url, e))
+        })?;
 
-        if !response.status().is_success() {
-            let status = response.status();
-            let body = response.text().await.unwrap_or_default();
-            warn!(
-                engine = %self.engine_id,
-                %status,
-                %body,
-                "bridge validate returned non-2xx"
-            );
-            return Err(EngineError::BridgeError(format!(
-                "Engine {} validate returned {}: {}", self.engine_id, status, body
-            )));
+        let registered = health["engines"]
+            .as_array()
+            .is_some_and(|engines| engines.iter().any(|id| id == self.engine_id.as_str()));
+        if registered {
+            Ok(())
+        } else {
+            Err(EngineError::BridgeError(format!(
+                "TS server at {} does not have engine '{}' registered",
+                self.base_url, self.engine_id
+            )))
         }
+    }
 
-        response.json::<ValidationResult>().await.map_err(|e| {
-            EngineError::BridgeError(format!(
-                "Failed to deserialize ValidationResult from {}: {}", self.engine_id, e
-            ))
-        })
+    /// The recorded contract exchange, with its result snake_cased as
+    /// [`CalculateResponse::into_output`] would
+    fn examples(&self) -> Vec<EngineExample> {
+        recorded_example(&self.engine_id)
+            .map(|example| vec![example])
+            .unwrap_or_else(|| vec![examples::birth_data_example()])
+    }
+
+    fn describe(&self) -> EngineDescription {
+        bridged_description(&self.engine_id)
+            .with_limitation("Calculated by the TypeScript engine server; unavailable while it is unreachable")
     }
 
     fn cache_key(&self, input: &EngineInput) -> String {
@@ -297,6 +461,100 @@ impl ConsciousnessEngine for BridgeEngine {
     }
 }
 
+/// Catalog entry of a TS engine, following its metadata on the TS server
+fn bridged_description(engine_id: &str) -> EngineDescription {
+    match engine_id {
+        "tarot" => EngineDescription::new(
+            "Draws a tarot spread, seeded so a reading can be repeated, and reads each card in its \
+             position, with witness prompts for self-reflection.",
+        )
+        .with_input("options.spread", false, "single_card, three_card (default), celtic_cross, relationship or career")
+        .with_input("options.question", false, "Question or intention for the reading")
+        .with_outputs("The spread, each position with its card, orientation and interpretation, and the seed")
+        .with_traditions(&["Tarot (Rider-Waite-Smith)"]),
+        "i-ching" => EngineDescription::new(
+            "Casts an I Ching hexagram, with changing lines producing a relating hexagram, and reads \
+             both with witness prompts.",
+        )
+        .with_input("options.method", false, "three_coins (default) or yarrow_stalks")
+        .with_input("options.hexagram", false, "Hexagram number (1-64) to read instead of casting")
+        .with_outputs("The primary hexagram, changing lines, relating hexagram, the casting and the seed")
+        .with_traditions(&["I Ching (King Wen sequence)"]),
+        "enneagram" => EngineDescription::new(
+            "Enneagram type analysis, from the 45-question assessment or a known type and wing. \
+             Describes patterns of perception and behavior, not fixed identities.",
+        )
+        .with_input("options.answers", false, "45 answers on a 1-5 scale, to run the assessment")
+        .with_input("options.type", false, "Type number (1-9) to read directly")
+        .with_input("options.wing", false, "Wing, read with options.type")
+        .with_outputs("The type analysis with its wing, integration and stress directions, and witness prompts")
+        .with_traditions(&["Enneagram of Personality"])
+        .with_limitation("A self-report assessment; the type is a starting point for inquiry, not a diagnosis"),
+        "sacred-geometry" => EngineDescription::new(
+            "Offers a sacred geometric form to contemplate, such as the Flower of Life, a Platonic \
+             solid or the Sri Yantra, with its symbolism and meditation guidance.",
+        )
+        .with_input("options.form", false, "Form to contemplate; chosen at random when absent")
+        .with_input("options.intention", false, "Intention to hold while contemplating")
+        .with_outputs("The form with its symbolism, a meditation, the intention and the seed")
+        .with_traditions(&["Sacred geometry", "Platonic solids", "Tantric yantra"]),
+        "sigil-forge" => EngineDescription::new(
+            "Guides the making of a sigil for an intention, step by step, with a method chosen for the \
+             intention and suggestions for charging it.",
+        )
+        .with_input("options.question", false, "Intention to encode, as a present-tense statement")
+        .with_input("options.intention", false, "Read when options.question is absent")
+        .with_input("options.method", false, "Sigil method; recommended from the intention when absent")
+        .with_outputs("The intention, the method, processing steps, charging suggestions and guidance")
+        .with_traditions(&["Chaos magic sigil craft (Austin Osman Spare)"])
+        .with_limitation("Gives instructions; no sigil image is drawn"),
+        _ => EngineDescription::default(),
+    }
+}
+
+/// The [`CONTRACT_FIXTURES`] exchange of `engine_id` as an example
+fn recorded_example(engine_id: &str) -> Option<EngineExample> {
+    let (_, fixture) = CONTRACT_FIXTURES.iter().find(|(id, _)| *id == engine_id)?;
+    let fixture: serde_json::Value = serde_json::from_str(fixture).ok()?;
+    let mut result = fixture["response"]["result"].clone();
+    snake_case_keys(&mut result);
+    let input = examples::options_input(fixture["input"]["options"].clone());
+    Some(
+        EngineExample::new("recorded", "Input and result recorded from the TS server", input)
+            .with_recorded_result(result),
+    )
+}
+
+/// GET `{base_url}/health`, mapping transport failures and non-2xx statuses
+/// to `EngineError::BridgeError`.
+async fn fetch_health(
+    client: &reqwest::Client,
+    base_url: &str,
+) -> Result<reqwest::Response, EngineError> {
+    let url = format!("{}/health", base_url);
+
+    let response = client.get(&url).send().await.map_err(|e| {
+        if e.is_connect() {
+            EngineError::BridgeError(format!(
+                "TS server not reachable at {} (connection refused)",
+                base_url
+            ))
+        } else if e.is_timeout() {
+            EngineError::BridgeError(format!("TS server health check timed out at {}", base_url))
+        } else {
+            EngineError::BridgeError(format!("Health check failed for {}: {}", url, e))
+        }
+    })?;
+
+    if response.status().is_success() {
+        Ok(response)
+    } else {
+        Err(EngineError::BridgeError(format!(
+            "Health check returned {}", response.status()
+        )))
+    }
+}
+
 // ---------------------------------------------------------------------------
 // BridgeManager
 // ---------------------------------------------------------------------------
@@ -315,15 +573,39 @@ impl BridgeManager {
     ///
     /// Instantiates `BridgeEngine` wrappers for all five TypeScript engines.
     pub fn new(base_url: impl Into<String>) -> Self {
+        Self::with_limits(base_url, |_| BridgeLimits::default())
+    }
+
+    /// Create a manager whose engines use the limits returned by `limits_for`
+    /// for their engine id.
+    pub fn with_limits(
+        base_url: impl Into<String>,
+        limits_for: impl Fn(&str) -> BridgeLimits,
+    ) -> Self {
+        Self::with_config(base_url, |engine| {
+            let limits = limits_for(engine.engine_id());
+            engine.with_limits(limits)
+        })
+    }
+
+    /// Create a manager, passing each engine through `configure` (e.g. to set
+    /// per-engine limits or retry policies) before registering it.
+    pub fn with_config(
+        base_url: impl Into<String>,
+        configure: impl Fn(BridgeEngine) -> BridgeEngine,
+    ) -> Self {
         let base_url: String = base_url.into();
 
-        let engines: Vec<Arc<dyn ConsciousnessEngine>> = vec![
-            Arc::new(BridgeEngine::tarot_with_url(&base_url)),
-            Arc::new(BridgeEngine::i_ching_with_url(&base_url)),
-            Arc::new(BridgeEngine::enneagram_with_url(&base_url)),
-            Arc::new(BridgeEngine::sacred_geometry_with_url(&base_url)),
-            Arc::new(BridgeEngine::sigil_forge_with_url(&base_url)),
-        ];
+        let engines: Vec<Arc<dyn ConsciousnessEngine>> = [
+            BridgeEngine::tarot_with_url(&base_url),
+            BridgeEngine::i_ching_with_url(&base_url),
+            BridgeEngine::enneagram_with_url(&base_url),
+            BridgeEngine::sacred_geometry_with_url(&base_url),
+            BridgeEngine::sigil_forge_with_url(&base_url),
+        ]
+        .into_iter()
+        .map(|engine| Arc::new(configure(engine)) as Arc<dyn ConsciousnessEngine>)
+        .collect();
 
         info!(
             base_url = %base_url,
@@ -336,11 +618,18 @@ impl BridgeManager {
 
     /// Create a new manager using the `TS_ENGINES_URL` environment variable,
     /// or falling back to the default URL if not set.
+    ///
+    /// Response limits are read with [`BridgeLimits::from_env`] and the retry
+    /// policy with [`RetryConfig::from_env`].
     pub fn from_env() -> Self {
         let url = std::env::var("TS_ENGINES_URL")
             .unwrap_or_else(|_| DEFAULT_TS_SERVER_URL.to_string());
         info!(url = %url, "BridgeManager loading from environment");
-        Self::new(url)
+        let retry = RetryConfig::from_env();
+        Self::with_config(url, |engine| {
+            let limits = BridgeLimits::from_env(engine.engine_id());
+            engine.with_limits(limits).with_retry(retry.clone())
+        })
     }
 
     /// Return all bridged engines as trait objects.
@@ -358,37 +647,14 @@ impl BridgeManager {
     /// Returns `Ok(())` when the server responds with 2xx, or an
     /// `EngineError::BridgeError` on failure.
     pub async fn health_check(&self) -> Result<(), EngineError> {
-        let url = format!("{}/health", self.base_url);
-
         let client = reqwest::Client::builder()
             .timeout(Duration::from_secs(2))
             .build()
             .map_err(|e| EngineError::BridgeError(format!("Failed to create client: {}", e)))?;
 
-        let response = client.get(&url).send().await.map_err(|e| {
-            if e.is_connect() {
-                EngineError::BridgeError(format!(
-                    "TS server not reachable at {} (connection refused)",
-                    self.base_url
-                ))
-            } else if e.is_timeout() {
-                EngineError::BridgeError(format!(
-                    "TS server health check timed out at {}",
-                    self.base_url
-                ))
-            } else {
-                EngineError::BridgeError(format!("Health check failed for {}: {}", url, e))
-            }
-        })?;
-
-        if response.status().is_success() {
-            info!(url = %self.base_url, "TS server health check passed");
-            Ok(())
-        } else {
-            Err(EngineError::BridgeError(format!(
-                "Health check returned {}", response.status()
-            )))
-        }
+        fetch_health(&client, &self.base_url).await?;
+        info!(url = %self.base_url, "TS server health check passed");
+        Ok(())
     }
 
     /// Check if the TS server is available (non-blocking, returns false on error).
@@ -410,6 +676,8 @@ mod tests {
     fn test_input() -> EngineInput {
         EngineInput {
             birth_data: None,
+            secondary_birth_data: None,
+            subjects: Vec::new(),
             current_time: Utc::now(),
             location: None,
             precision: noesis_core::Precision::Standard,
@@ -457,6 +725,24 @@ mod tests {
         assert_eq!(engine.required_phase(), 3);
     }
 
+    #[test]
+    fn bridge_engine_examples_are_recorded() {
+        for engine in [
+            BridgeEngine::tarot(),
+            BridgeEngine::i_ching(),
+            BridgeEngine::enneagram(),
+            BridgeEngine::sacred_geometry(),
+            BridgeEngine::sigil_forge(),
+        ] {
+            let examples = engine.examples();
+            assert_eq!(examples.len(), 1, "{}", engine.engine_id());
+            let result = examples[0].recorded_result.as_ref().expect("recorded result");
+            assert!(result.is_object(), "{}", engine.engine_id());
+            assert!(!noesis_core::casing::has_camel_case_keys(result), "{}", engine.engine_id());
+            assert!(examples[0].input.birth_data.is_none());
+        }
+    }
+
     #[test]
     fn bridge_engine_with_custom_url() {
         let engine = BridgeEngine::tarot_with_url("http://custom:4000");
@@ -536,6 +822,218 @@ mod tests {
         }
     }
 
+    /// Serve canned responses, one per connection, on a local port. No
+    /// Content-Length is sent so bodies are read until the connection closes.
+    async fn serve(responses: Vec<(u16, &'static str, String)>) -> String {
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            for (status, content_type, body) in responses {
+                let (mut socket, _) = listener.accept().await.unwrap();
+                let mut request = [0u8; 16 * 1024];
+                let _ = socket.read(&mut request).await;
+                let head = format!(
+                    "HTTP/1.1 {} X\r\ncontent-type: {}\r\nconnection: close\r\n\r\n",
+                    status, content_type
+                );
+                let _ = socket.write_all(head.as_bytes()).await;
+                let _ = socket.write_all(body.as_bytes()).await;
+            }
+        });
+        format!("http://{}", addr)
+    }
+
+    async fn serve_once(content_type: &'static str, body: String) -> String {
+        serve(vec![(200, content_type, body)]).await
+    }
+
+    fn quick_retry(max_attempts: u32) -> RetryConfig {
+        RetryConfig {
+            max_attempts,
+            initial_delay: Duration::from_millis(1),
+            jitter: false,
+            ..RetryConfig::default()
+        }
+    }
+
+    fn output_json() -> String {
+        serde_json::json!({
+            "engine_id": "test",
+            "result": { "card": "The Fool" },
+            "witness_prompts": [{ "prompt": "What begins?" }],
+            "calculated_at": Utc::now().to_rfc3339(),
+            "processing_time_ms": 1,
+        })
+        .to_string()
+    }
+
+    async fn calculate_against(
+        content_type: &'static str,
+        body: String,
+        limits: BridgeLimits,
+    ) -> Result<EngineOutput, EngineError> {
+        let url = serve_once(content_type, body).await;
+        BridgeEngine::new("test", "Test", 0, url)
+            .with_limits(limits)
+            .calculate(test_input())
+            .await
+    }
+
+    fn expect_violation(result: Result<EngineOutput, EngineError>, expected: &SandboxViolation) {
+        let expected = BridgeError::SandboxViolation {
+            engine_id: "test".to_string(),
+            violation: expected.clone(),
+        };
+        match result {
+            Err(EngineError::BridgeError(msg)) => assert_eq!(msg, expected.to_string()),
+            other => panic!("Expected sandbox violation, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn bridge_engine_forwards_request_id() {
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let server = tokio::spawn(async move {
+            let (mut socket, _) = listener.accept().await.unwrap();
+            let mut request = [0u8; 16 * 1024];
+            let n = socket.read(&mut request).await.unwrap();
+            let head = "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\nconnection: close\r\n\r\n";
+            let _ = socket.write_all(head.as_bytes()).await;
+            let _ = socket.write_all(output_json().as_bytes()).await;
+            String::from_utf8_lossy(&request[..n]).to_ascii_lowercase()
+        });
+
+        let engine = BridgeEngine::new("test", "Test", 0, url);
+        noesis_core::correlation::scope("req-bridge-1".to_string(), engine.calculate(test_input()))
+            .await
+            .unwrap();
+
+        assert!(server.await.unwrap().contains("x-request-id: req-bridge-1"));
+    }
+
+    #[tokio::test]
+    async fn bridge_engine_accepts_response_within_limits() {
+        let output = calculate_against("application/json", output_json(), BridgeLimits::default())
+            .await
+            .unwrap();
+        assert_eq!(output.result["card"], "The Fool");
+        assert_eq!(output.witness_prompt, "What begins?");
+    }
+
+    #[tokio::test]
+    async fn bridge_engine_rejects_oversized_response() {
+        let limits = BridgeLimits { max_response_bytes: 1024, ..BridgeLimits::default() };
+        let body = format!("{{\"padding\":\"{}\"}}", "x".repeat(64 * 1024));
+        let result = calculate_against("application/json", body, limits).await;
+        expect_violation(result, &SandboxViolation::ResponseTooLarge { limit: 1024 });
+    }
+
+    #[tokio::test]
+    async fn bridge_engine_rejects_deep_json() {
+        let limits = BridgeLimits { max_json_depth: 8, ..BridgeLimits::default() };
+        let body = format!("{}{}", "[".repeat(20), "]".repeat(20));
+        let result = calculate_against("application/json", body, limits).await;
+        expect_violation(result, &SandboxViolation::JsonTooDeep { limit: 8 });
+    }
+
+    #[tokio::test]
+    async fn bridge_engine_rejects_non_json_content_type() {
+        let result = calculate_against("text/html", output_json(), BridgeLimits::default()).await;
+        expect_violation(
+            result,
+            &SandboxViolation::UnexpectedContentType { found: "text/html".to_string() },
+        );
+    }
+
+    #[tokio::test]
+    async fn bridge_engine_retries_unavailable_server() {
+        let url = serve(vec![
+            (503, "text/plain", "restarting".to_string()),
+            (200, "application/json", output_json()),
+        ])
+        .await;
+        let engine = BridgeEngine::new("retry-test", "Retry Test", 0, url).with_retry(quick_retry(3));
+
+        let (output, usage) = noesis_core::usage::measure(engine.calculate(test_input())).await;
+        assert_eq!(output.unwrap().result["card"], "The Fool");
+        // The retried exchange counts against the request too
+        assert_eq!(usage.bridge_round_trips, 2);
+
+        let attempts = |outcome: &str| {
+            noesis_metrics::BRIDGE_ATTEMPTS_TOTAL
+                .with_label_values(&["retry-test", "calculate", outcome])
+                .get()
+        };
+        assert_eq!(attempts("retry"), 1);
+        assert_eq!(attempts("success"), 1);
+
+        let responses = |status: &str| {
+            noesis_metrics::BRIDGE_HTTP_RESPONSES_TOTAL
+                .with_label_values(&["retry-test", "calculate", status])
+                .get()
+        };
+        assert_eq!(responses("503"), 1);
+        assert_eq!(responses("200"), 1);
+    }
+
+    #[tokio::test]
+    async fn bridge_engine_does_not_retry_client_errors() {
+        let url = serve(vec![
+            (422, "application/json", r#"{"error":"bad spread"}"#.to_string()),
+            (200, "application/json", output_json()),
+        ])
+        .await;
+        let engine = BridgeEngine::new("test", "Test", 0, url).with_retry(quick_retry(3));
+
+        match engine.calculate(test_input()).await {
+            Err(EngineError::BridgeError(msg)) => {
+                assert!(msg.contains("422"), "{}", msg);
+                assert!(!msg.contains("gave up"), "{}", msg);
+            }
+            other => panic!("Expected BridgeError, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn bridge_engine_reports_exhausted_retries() {
+        let engine = BridgeEngine::new("test", "Test", 0, "http://localhost:59999")
+            .with_retry(quick_retry(2));
+
+        match engine.calculate(test_input()).await {
+            Err(EngineError::BridgeError(msg)) => {
+                assert!(msg.contains("gave up after 2 attempts"), "{}", msg)
+            }
+            other => panic!("Expected BridgeError, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn bridge_engine_health_requires_registered_engine() {
+        let health = r#"{"status":"healthy","engines":["tarot","i-ching"]}"#;
+        let url = serve(vec![
+            (200, "application/json", health.to_string()),
+            (200, "application/json", health.to_string()),
+        ])
+        .await;
+
+        assert!(BridgeEngine::tarot_with_url(url.clone()).health().await.is_ok());
+        match BridgeEngine::enneagram_with_url(url).health().await {
+            Err(EngineError::BridgeError(msg)) => assert!(msg.contains("'enneagram'")),
+            other => panic!("Expected unregistered engine error, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn bridge_engine_health_fails_when_server_is_down() {
+        let engine = BridgeEngine::new("test", "Test", 0, "http://localhost:59999");
+        assert!(matches!(engine.health().await, Err(EngineError::BridgeError(_))));
+    }
+
     #[tokio::test]
     async fn bridge_manager_health_check_fails_gracefully() {
         let manager = BridgeManager::new("http://localhost:59999");
@@ -0,0 +1,183 @@
+//! Sandbox limits applied to TS engine responses
+//!
+//! Bridged engines run out of process and are not trusted to return sane
+//! payloads. Bodies are read incrementally so an oversized response is cut
+//! off at the limit instead of being buffered, and JSON nesting is checked
+//! before deserialization.
+
+use crate::error::SandboxViolation;
+
+/// Default cap on a response body (2 MiB)
+pub const DEFAULT_MAX_RESPONSE_BYTES: usize = 2 * 1024 * 1024;
+
+/// Default cap on JSON object/array nesting
+pub const DEFAULT_MAX_JSON_DEPTH: usize = 64;
+
+/// Largest non-2xx body kept for error messages
+pub(crate) const MAX_ERROR_BODY_BYTES: usize = 4 * 1024;
+
+/// Per-engine limits on what a bridged engine may return
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BridgeLimits {
+    /// Maximum response body size in bytes
+    pub max_response_bytes: usize,
+    /// Maximum nesting of JSON objects and arrays
+    pub max_json_depth: usize,
+    /// Reject successful responses whose `Content-Type` is not JSON
+    pub enforce_content_type: bool,
+}
+
+impl Default for BridgeLimits {
+    fn default() -> Self {
+        Self {
+            max_response_bytes: DEFAULT_MAX_RESPONSE_BYTES,
+            max_json_depth: DEFAULT_MAX_JSON_DEPTH,
+            enforce_content_type: true,
+        }
+    }
+}
+
+impl BridgeLimits {
+    /// Limits from the environment for `engine_id`.
+    ///
+    /// `TS_ENGINE_MAX_RESPONSE_BYTES` and `TS_ENGINE_MAX_JSON_DEPTH` apply to
+    /// every engine; a suffix with the upper-cased engine id (e.g.
+    /// `TS_ENGINE_MAX_RESPONSE_BYTES_SACRED_GEOMETRY`) overrides one engine.
+    pub fn from_env(engine_id: &str) -> Self {
+        let suffix = engine_id.to_ascii_uppercase().replace('-', "_");
+        let read = |name: &str| {
+            std::env::var(format!("{}_{}", name, suffix))
+                .or_else(|_| std::env::var(name))
+                .ok()
+                .and_then(|v| v.trim().parse::<usize>().ok())
+        };
+
+        let defaults = Self::default();
+        Self {
+            max_response_bytes: read("TS_ENGINE_MAX_RESPONSE_BYTES")
+                .unwrap_or(defaults.max_response_bytes),
+            max_json_depth: read("TS_ENGINE_MAX_JSON_DEPTH").unwrap_or(defaults.max_json_depth),
+            enforce_content_type: defaults.enforce_content_type,
+        }
+    }
+
+    /// Check the `Content-Type` of a successful response
+    pub(crate) fn check_content_type(&self, content_type: Option<&str>) -> Result<(), SandboxViolation> {
+        if !self.enforce_content_type {
+            return Ok(());
+        }
+        let found = content_type.unwrap_or("").trim();
+        let essence = found.split(';').next().unwrap_or("").trim().to_ascii_lowercase();
+        if essence == "application/json" || essence.ends_with("+json") {
+            Ok(())
+        } else {
+            Err(SandboxViolation::UnexpectedContentType {
+                found: found.to_string(),
+            })
+        }
+    }
+}
+
+/// Read a response body, failing as soon as it grows past `limit` bytes
+pub(crate) async fn read_body(
+    mut response: reqwest::Response,
+    limit: usize,
+) -> Result<Result<Vec<u8>, SandboxViolation>, reqwest::Error> {
+    let too_large = Err(SandboxViolation::ResponseTooLarge { limit });
+    if response.content_length().is_some_and(|len| len > limit as u64) {
+        return Ok(too_large);
+    }
+
+    let mut body = Vec::new();
+    while let Some(chunk) = response.chunk().await? {
+        if body.len() + chunk.len() > limit {
+            return Ok(too_large);
+        }
+        body.extend_from_slice(&chunk);
+    }
+    Ok(Ok(body))
+}
+
+/// Read at most `limit` bytes of an error body as text
+pub(crate) async fn read_error_body(mut response: reqwest::Response, limit: usize) -> String {
+    let mut body = Vec::new();
+    while let Ok(Some(chunk)) = response.chunk().await {
+        body.extend_from_slice(&chunk[..chunk.len().min(limit - body.len())]);
+        if body.len() >= limit {
+            break;
+        }
+    }
+    String::from_utf8_lossy(&body).into_owned()
+}
+
+/// Fail if objects/arrays in `json` nest deeper than `limit`.
+///
+/// Brackets inside strings are ignored; the JSON is otherwise not validated.
+pub(crate) fn check_json_depth(json: &[u8], limit: usize) -> Result<(), SandboxViolation> {
+    let mut depth = 0usize;
+    let mut in_string = false;
+    let mut escaped = false;
+
+    for &byte in json {
+        if in_string {
+            match byte {
+                _ if escaped => escaped = false,
+                b'\\' => escaped = true,
+                b'"' => in_string = false,
+                _ => {}
+            }
+            continue;
+        }
+        match byte {
+            b'"' => in_string = true,
+            b'{' | b'[' => {
+                depth += 1;
+                if depth > limit {
+                    return Err(SandboxViolation::JsonTooDeep { limit });
+                }
+            }
+            b'}' | b']' => depth = depth.saturating_sub(1),
+            _ => {}
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn json_depth_counts_nesting_outside_strings() {
+        assert!(check_json_depth(br#"{"a":[1,{"b":2}]}"#, 3).is_ok());
+        assert_eq!(
+            check_json_depth(br#"{"a":[1,{"b":2}]}"#, 2),
+            Err(SandboxViolation::JsonTooDeep { limit: 2 })
+        );
+        assert!(check_json_depth(br#"{"s":"[[[[\"{{{{"}"#, 1).is_ok());
+        assert!(check_json_depth("[".repeat(1000).as_bytes(), DEFAULT_MAX_JSON_DEPTH).is_err());
+    }
+
+    #[test]
+    fn content_type_enforcement() {
+        let limits = BridgeLimits::default();
+        assert!(limits.check_content_type(Some("application/json")).is_ok());
+        assert!(limits.check_content_type(Some("application/json; charset=utf-8")).is_ok());
+        assert!(limits.check_content_type(Some("application/problem+json")).is_ok());
+        assert!(limits.check_content_type(Some("text/html")).is_err());
+        assert!(limits.check_content_type(None).is_err());
+
+        let relaxed = BridgeLimits { enforce_content_type: false, ..limits };
+        assert!(relaxed.check_content_type(Some("text/plain")).is_ok());
+    }
+
+    #[test]
+    fn env_override_applies_to_one_engine() {
+        std::env::set_var("TS_ENGINE_MAX_RESPONSE_BYTES_LIMITS_TEST", "4096");
+
+        let limits = BridgeLimits::from_env("limits-test");
+        assert_eq!(limits.max_response_bytes, 4096);
+        assert_eq!(limits.max_json_depth, DEFAULT_MAX_JSON_DEPTH);
+        assert_eq!(BridgeLimits::from_env("other-engine"), BridgeLimits::default());
+    }
+}
@@ -0,0 +1,118 @@
+//! Retry with exponential backoff for bridged engine calls
+//!
+//! `calculate` and `validate` have no side effects on the TS server, so a
+//! request can be repeated safely. Only failures that suggest a transient
+//! server problem are retried: connection errors, timeouts and 502/503/504
+//! responses. Invalid input, sandbox violations and malformed responses fail
+//! immediately.
+
+use std::time::Duration;
+
+/// Retry configuration for a [`BridgeEngine`](crate::BridgeEngine)
+#[derive(Debug, Clone, PartialEq)]
+pub struct RetryConfig {
+    /// Total attempts including the first (1 disables retries)
+    pub max_attempts: u32,
+    /// Delay before the first retry
+    pub initial_delay: Duration,
+    /// Maximum delay between retries
+    pub max_delay: Duration,
+    /// Multiplier for exponential growth
+    pub multiplier: f64,
+    /// Add up to 25% random jitter so concurrent workflows do not retry in lockstep
+    pub jitter: bool,
+}
+
+impl Default for RetryConfig {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            initial_delay: Duration::from_millis(100),
+            max_delay: Duration::from_secs(2),
+            multiplier: 2.0,
+            jitter: true,
+        }
+    }
+}
+
+impl RetryConfig {
+    /// A single attempt, no retries
+    pub fn disabled() -> Self {
+        Self {
+            max_attempts: 1,
+            ..Default::default()
+        }
+    }
+
+    /// Defaults overridden by `TS_ENGINE_RETRY_MAX_ATTEMPTS` and
+    /// `TS_ENGINE_RETRY_INITIAL_DELAY_MS`.
+    pub fn from_env() -> Self {
+        let read = |name: &str| std::env::var(name).ok().and_then(|v| v.trim().parse::<u64>().ok());
+        let defaults = Self::default();
+        Self {
+            max_attempts: read("TS_ENGINE_RETRY_MAX_ATTEMPTS")
+                .map(|n| n.max(1) as u32)
+                .unwrap_or(defaults.max_attempts),
+            initial_delay: read("TS_ENGINE_RETRY_INITIAL_DELAY_MS")
+                .map(Duration::from_millis)
+                .unwrap_or(defaults.initial_delay),
+            ..defaults
+        }
+    }
+
+    /// Delay after failed attempt `attempt` (0-indexed)
+    pub fn delay_for_attempt(&self, attempt: u32) -> Duration {
+        let delay_ms = self.initial_delay.as_millis() as f64 * self.multiplier.powi(attempt as i32);
+        let capped_ms = delay_ms.min(self.max_delay.as_millis() as f64) as u64;
+
+        if self.jitter {
+            let jitter = (capped_ms as f64 * 0.25 * noesis_core::rng::jitter_fraction()) as u64;
+            Duration::from_millis(capped_ms + jitter)
+        } else {
+            Duration::from_millis(capped_ms)
+        }
+    }
+
+    /// Whether another attempt may follow failed attempt `attempt` (0-indexed)
+    pub fn should_retry(&self, attempt: u32) -> bool {
+        attempt + 1 < self.max_attempts
+    }
+}
+
+/// Whether an HTTP status from the TS server indicates a transient failure
+pub(crate) fn is_retryable_status(status: reqwest::StatusCode) -> bool {
+    matches!(status.as_u16(), 502..=504)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn backoff_grows_and_caps() {
+        let config = RetryConfig { jitter: false, ..Default::default() };
+        assert_eq!(config.delay_for_attempt(0), Duration::from_millis(100));
+        assert_eq!(config.delay_for_attempt(1), Duration::from_millis(200));
+        assert_eq!(config.delay_for_attempt(10), Duration::from_secs(2));
+
+        let jittered = RetryConfig::default().delay_for_attempt(1);
+        assert!(jittered >= Duration::from_millis(200) && jittered <= Duration::from_millis(250));
+    }
+
+    #[test]
+    fn attempts_include_the_first_call() {
+        let config = RetryConfig::default();
+        assert!(config.should_retry(0));
+        assert!(config.should_retry(1));
+        assert!(!config.should_retry(2));
+        assert!(!RetryConfig::disabled().should_retry(0));
+    }
+
+    #[test]
+    fn only_gateway_errors_are_retryable() {
+        assert!(is_retryable_status(reqwest::StatusCode::SERVICE_UNAVAILABLE));
+        assert!(is_retryable_status(reqwest::StatusCode::BAD_GATEWAY));
+        assert!(!is_retryable_status(reqwest::StatusCode::UNPROCESSABLE_ENTITY));
+        assert!(!is_retryable_status(reqwest::StatusCode::INTERNAL_SERVER_ERROR));
+    }
+}
@@ -0,0 +1,150 @@
+//! Wire format of the TypeScript engine server
+//!
+//! Mirrors `EngineInput`, `EngineOutput` and `ErrorResponse` in
+//! `ts-engines/src/types/engine.ts`. The bridge converts an
+//! [`EngineInput`] into a [`CalculateRequest`] before posting it and a
+//! [`CalculateResponse`] back into an [`EngineOutput`], rewriting the
+//! server's camelCase result keys to the platform's snake_case (see
+//! [`noesis_core::casing`]). The contract tests in
+//! `tests/contract_tests.rs` check both shapes against exchanges recorded
+//! from the server, so a rename on either side fails there first.
+
+use chrono::{DateTime, Utc};
+use noesis_core::casing::{snake_case_keys, to_camel_case};
+use noesis_core::{CalculationMetadata, EngineInput, EngineOutput, SEED_OPTION};
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+/// Option promoted to [`CalculateRequest::consciousness_level`]
+pub use noesis_core::CONSCIOUSNESS_LEVEL_OPTION;
+
+/// Option promoted to [`CalculateRequest::question`]
+pub const QUESTION_OPTION: &str = "question";
+
+/// Highest consciousness level the server accepts
+pub const MAX_CONSCIOUSNESS_LEVEL: u8 = 5;
+
+/// [`CalculationMetadata::backend`] of bridged outputs
+pub const BACKEND: &str = "ts-engines";
+
+/// Body of `POST /engines/:id/calculate`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CalculateRequest {
+    pub consciousness_level: u8,
+    /// Every option except the ones promoted to their own field
+    pub parameters: Map<String, Value>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub seed: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub question: Option<String>,
+}
+
+impl CalculateRequest {
+    /// Build the request for an engine that requires `required_phase`.
+    ///
+    /// The orchestrator has already checked phase access, so
+    /// `options.consciousness_level` is raised to `required_phase` when it is
+    /// lower or absent; otherwise the server would refuse the call again.
+    ///
+    /// The API snake_cases the keys of camelCase inputs, options included,
+    /// while the server's engines read camelCase parameters. Each multi-word
+    /// parameter is therefore sent in both spellings.
+    pub fn from_input(input: &EngineInput, required_phase: u8) -> Self {
+        let level = input
+            .options
+            .get(CONSCIOUSNESS_LEVEL_OPTION)
+            .and_then(Value::as_u64)
+            .map_or(0, |level| level.min(MAX_CONSCIOUSNESS_LEVEL as u64) as u8);
+        let mut parameters: Map<String, Value> = input
+            .options
+            .iter()
+            .filter(|(key, _)| ![CONSCIOUSNESS_LEVEL_OPTION, SEED_OPTION, QUESTION_OPTION].contains(&key.as_str()))
+            .map(|(key, value)| (key.clone(), value.clone()))
+            .collect();
+        let twins: Vec<(String, Value)> = parameters
+            .iter()
+            .filter(|(key, _)| key.starts_with(|c: char| c.is_ascii_lowercase()))
+            .map(|(key, value)| (to_camel_case(key), value.clone()))
+            .filter(|(camel, _)| !parameters.contains_key(camel))
+            .collect();
+        parameters.extend(twins);
+        Self {
+            consciousness_level: level.max(required_phase),
+            parameters,
+            seed: input.seed().ok().flatten(),
+            question: input
+                .options
+                .get(QUESTION_OPTION)
+                .and_then(Value::as_str)
+                .map(str::to_owned),
+        }
+    }
+}
+
+/// Self-inquiry prompt as the server returns it
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WitnessPrompt {
+    pub prompt: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub context: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub themes: Option<Vec<String>>,
+}
+
+/// Successful response of `POST /engines/:id/calculate`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CalculateResponse {
+    pub engine_id: String,
+    pub result: Map<String, Value>,
+    pub witness_prompts: Vec<WitnessPrompt>,
+    /// RFC 3339 timestamp
+    pub calculated_at: String,
+    pub processing_time_ms: f64,
+}
+
+impl CalculateResponse {
+    /// Convert into the engine output of `request`.
+    ///
+    /// The first witness prompt becomes [`EngineOutput::witness_prompt`]; the
+    /// server orders them most relevant first. Result keys are snake_cased.
+    pub fn into_output(self, request: &CalculateRequest, input: &EngineInput) -> EngineOutput {
+        let timestamp = DateTime::parse_from_rfc3339(&self.calculated_at)
+            .map(|at| at.with_timezone(&Utc))
+            .unwrap_or_else(|_| Utc::now());
+        let mut result = Value::Object(self.result);
+        snake_case_keys(&mut result);
+        EngineOutput {
+            engine_id: self.engine_id,
+            result,
+            witness_prompt: self
+                .witness_prompts
+                .into_iter()
+                .next()
+                .map(|p| p.prompt)
+                .unwrap_or_default(),
+            summary: None,
+            consciousness_level: request.consciousness_level,
+            metadata: CalculationMetadata {
+                calculation_time_ms: self.processing_time_ms,
+                backend: BACKEND.to_string(),
+                precision_achieved: format!("{:?}", input.precision),
+                cached: false,
+                timestamp,
+                engine_version: None,
+                warnings: Vec::new(),
+                usage: None,
+            },
+        }
+    }
+}
+
+/// Body of every non-2xx response
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ErrorResponse {
+    pub error: String,
+    pub error_code: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub details: Option<Map<String, Value>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub request_id: Option<String>,
+}
@@ -0,0 +1,262 @@
+//! Contract tests between the bridge and the TypeScript engine server
+//!
+//! Each file in `tests/fixtures/contract` holds one recorded exchange: the
+//! `EngineInput` the orchestrator passes, the request the bridge must post for
+//! it, and the status and body the server answered with. The fixtures are
+//! recorded with `bun run record:contract` in `ts-engines`.
+//!
+//! Without a server the recordings are replayed by a stub provider. With
+//! `TS_ENGINES_URL` set (e.g. `http://localhost:3001`), the same inputs are
+//! also sent through the bridge to that server and its answers are checked
+//! against the recorded shapes:
+//!
+//!   TS_ENGINES_URL=http://localhost:3001 cargo test -p noesis-bridge --test contract_tests
+//!
+//! The server's result keys are partly camelCase; what the bridge hands on
+//! must be the recording with every key snake_cased.
+
+use noesis_bridge::wire::{CalculateRequest, CalculateResponse, ErrorResponse};
+use noesis_bridge::{BridgeEngine, BridgeManager, ConsciousnessEngine, EngineError, EngineInput, RetryConfig};
+use noesis_core::casing::{has_camel_case_keys, snake_case_keys};
+use serde::Deserialize;
+use serde_json::Value;
+use std::path::PathBuf;
+use std::sync::Arc;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpListener;
+
+#[derive(Debug, Deserialize)]
+struct Fixture {
+    engine_id: String,
+    input: EngineInput,
+    request: Value,
+    status: u16,
+    response: Value,
+}
+
+fn load_fixtures() -> Vec<(String, Fixture)> {
+    let dir = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/contract");
+    let mut fixtures: Vec<(String, Fixture)> = std::fs::read_dir(&dir)
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "json"))
+        .map(|path| {
+            let name = path.file_name().unwrap().to_string_lossy().into_owned();
+            let text = std::fs::read_to_string(&path).unwrap();
+            let fixture = serde_json::from_str(&text).unwrap_or_else(|e| panic!("{}: {}", name, e));
+            (name, fixture)
+        })
+        .collect();
+    fixtures.sort_by(|a, b| a.0.cmp(&b.0));
+    assert!(fixtures.len() >= 6, "expected a fixture per engine and an error case");
+    fixtures
+}
+
+/// The bridge for `engine_id` as the API registers it, or a phase-0 bridge
+/// for ids the server doesn't know.
+fn bridge_for(engine_id: &str, base_url: &str) -> Arc<dyn ConsciousnessEngine> {
+    BridgeManager::with_config(base_url, |engine| engine.with_retry(RetryConfig::disabled()))
+        .engines()
+        .into_iter()
+        .find(|engine| engine.engine_id() == engine_id)
+        .unwrap_or_else(|| {
+            Arc::new(BridgeEngine::new(engine_id, engine_id, 0, base_url).with_retry(RetryConfig::disabled()))
+        })
+}
+
+/// Provider stub: answer one request with the recorded exchange and hand
+/// back the JSON body the bridge posted.
+async fn replay(fixture: &Fixture) -> (String, tokio::task::JoinHandle<Value>) {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = format!("http://{}", listener.local_addr().unwrap());
+    let path = format!("POST /engines/{}/calculate ", fixture.engine_id);
+    let status = fixture.status;
+    let body = fixture.response.to_string();
+
+    let server = tokio::spawn(async move {
+        let (mut socket, _) = listener.accept().await.unwrap();
+        let mut request = Vec::new();
+        let mut buf = [0u8; 8 * 1024];
+        let body_start = loop {
+            let n = socket.read(&mut buf).await.unwrap();
+            assert!(n > 0, "connection closed before the request was complete");
+            request.extend_from_slice(&buf[..n]);
+            if let Some(end) = request.windows(4).position(|w| w == b"\r\n\r\n") {
+                break end + 4;
+            }
+        };
+        let head = String::from_utf8_lossy(&request[..body_start]).to_ascii_lowercase();
+        assert!(head.starts_with(&path.to_ascii_lowercase()), "unexpected request line: {}", head);
+        let length: usize = head
+            .lines()
+            .find_map(|line| line.strip_prefix("content-length:"))
+            .map(|v| v.trim().parse().unwrap())
+            .expect("request without content-length");
+        while request.len() < body_start + length {
+            let n = socket.read(&mut buf).await.unwrap();
+            request.extend_from_slice(&buf[..n]);
+        }
+
+        let response = format!(
+            "HTTP/1.1 {} X\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{}",
+            status,
+            body.len(),
+            body
+        );
+        socket.write_all(response.as_bytes()).await.unwrap();
+        serde_json::from_slice(&request[body_start..body_start + length]).unwrap()
+    });
+    (url, server)
+}
+
+/// Equal JSON, comparing numbers by value so `3` matches `3.0`
+fn assert_same_json(name: &str, path: &str, expected: &Value, actual: &Value) {
+    match (expected, actual) {
+        (Value::Number(e), Value::Number(a)) => {
+            assert_eq!(e.as_f64(), a.as_f64(), "{}: {} differs", name, path)
+        }
+        (Value::Object(e), Value::Object(a)) => {
+            let mut keys: Vec<&String> = e.keys().chain(a.keys()).collect();
+            keys.sort();
+            keys.dedup();
+            for key in keys {
+                let (Some(ev), Some(av)) = (e.get(key), a.get(key)) else {
+                    panic!("{}: {}.{} is only on one side", name, path, key);
+                };
+                assert_same_json(name, &format!("{}.{}", path, key), ev, av);
+            }
+        }
+        (Value::Array(e), Value::Array(a)) => {
+            assert_eq!(e.len(), a.len(), "{}: {} length differs", name, path);
+            for (i, (ev, av)) in e.iter().zip(a).enumerate() {
+                assert_same_json(name, &format!("{}[{}]", path, i), ev, av);
+            }
+        }
+        _ => assert_eq!(expected, actual, "{}: {} differs", name, path),
+    }
+}
+
+/// Same keys and value types, ignoring the values themselves
+fn assert_same_shape(name: &str, path: &str, expected: &Value, actual: &Value) {
+    match (expected, actual) {
+        (Value::Object(e), Value::Object(a)) => {
+            for (key, ev) in e {
+                let av = a
+                    .get(key)
+                    .unwrap_or_else(|| panic!("{}: {}.{} is missing", name, path, key));
+                assert_same_shape(name, &format!("{}.{}", path, key), ev, av);
+            }
+        }
+        (Value::Array(e), Value::Array(a)) => {
+            if let (Some(ev), Some(av)) = (e.first(), a.first()) {
+                assert_same_shape(name, &format!("{}[0]", path), ev, av);
+            }
+        }
+        (Value::Number(_), Value::Number(_))
+        | (Value::String(_), Value::String(_))
+        | (Value::Bool(_), Value::Bool(_))
+        | (Value::Null, Value::Null) => {}
+        _ => panic!("{}: {} changed type from {} to {}", name, path, expected, actual),
+    }
+}
+
+#[test]
+fn bridge_builds_recorded_requests() {
+    for (name, fixture) in load_fixtures() {
+        let phase = bridge_for(&fixture.engine_id, "http://unused").required_phase();
+        let request = serde_json::to_value(CalculateRequest::from_input(&fixture.input, phase)).unwrap();
+        assert_same_json(&name, "request", &fixture.request, &request);
+    }
+}
+
+#[test]
+fn bridge_sends_camel_case_parameters() {
+    // The enneagram engine reads `parameters.includeAssessment`; the API has
+    // snake_cased the option by the time the bridge sees it
+    let mut body = serde_json::json!({ "options": { "type": 4, "includeAssessment": true } });
+    snake_case_keys(&mut body);
+    let input: EngineInput = serde_json::from_value(body).unwrap();
+    let request = CalculateRequest::from_input(&input, 1);
+    assert_eq!(request.parameters["includeAssessment"], true);
+    assert_eq!(request.parameters["include_assessment"], true);
+    assert_eq!(request.parameters["type"], 4);
+    assert_eq!(request.parameters.len(), 3);
+}
+
+#[test]
+fn recorded_responses_match_wire_types() {
+    for (name, fixture) in load_fixtures() {
+        // Re-encoding must reproduce every recorded field, so a field renamed
+        // or dropped on either side shows up here
+        let encoded = if fixture.status == 200 {
+            let response: CalculateResponse = serde_json::from_value(fixture.response.clone())
+                .unwrap_or_else(|e| panic!("{}: {}", name, e));
+            assert_eq!(response.engine_id, fixture.engine_id, "{}", name);
+            assert!(!response.witness_prompts.is_empty(), "{}: no witness prompts", name);
+            serde_json::to_value(response).unwrap()
+        } else {
+            let error: ErrorResponse = serde_json::from_value(fixture.response.clone())
+                .unwrap_or_else(|e| panic!("{}: {}", name, e));
+            serde_json::to_value(error).unwrap()
+        };
+        assert_same_json(&name, "response", &fixture.response, &encoded);
+    }
+}
+
+#[tokio::test]
+async fn bridge_round_trips_recorded_exchanges() {
+    for (name, fixture) in load_fixtures() {
+        let (url, server) = replay(&fixture).await;
+        let result = bridge_for(&fixture.engine_id, &url).calculate(fixture.input.clone()).await;
+        assert_same_json(&name, "request", &fixture.request, &server.await.unwrap());
+
+        match result {
+            Ok(output) => {
+                assert_eq!(fixture.status, 200, "{}", name);
+                assert_eq!(output.engine_id, fixture.engine_id);
+                let mut expected = fixture.response["result"].clone();
+                snake_case_keys(&mut expected);
+                assert_eq!(output.result, expected, "{}", name);
+                assert!(!has_camel_case_keys(&output.result), "{}: camelCase keys reached the platform", name);
+                assert_eq!(output.witness_prompt, fixture.response["witness_prompts"][0]["prompt"], "{}", name);
+                assert_eq!(output.consciousness_level as u64, fixture.request["consciousness_level"], "{}", name);
+                assert_eq!(
+                    output.metadata.calculation_time_ms,
+                    fixture.response["processing_time_ms"].as_f64().unwrap()
+                );
+                // The orchestrator can re-encode what the bridge returns
+                serde_json::to_value(&output).unwrap();
+            }
+            Err(EngineError::BridgeError(message)) => {
+                assert_ne!(fixture.status, 200, "{}: {}", name, message);
+                let code = fixture.response["error_code"].as_str().unwrap();
+                assert!(message.contains(code), "{}: {}", name, message);
+            }
+            Err(other) => panic!("{}: unexpected error {:?}", name, other),
+        }
+    }
+}
+
+#[tokio::test]
+async fn live_server_matches_recordings() {
+    let Ok(base_url) = std::env::var("TS_ENGINES_URL") else {
+        eprintln!("TS_ENGINES_URL not set; skipping live contract check");
+        return;
+    };
+    for (name, fixture) in load_fixtures() {
+        let result = bridge_for(&fixture.engine_id, &base_url).calculate(fixture.input.clone()).await;
+        match result {
+            Ok(output) => {
+                assert_eq!(fixture.status, 200, "{}", name);
+                let mut expected = fixture.response["result"].clone();
+                snake_case_keys(&mut expected);
+                assert_same_shape(&name, "result", &expected, &output.result);
+            }
+            Err(e) => {
+                assert_ne!(fixture.status, 200, "{}: {}", name, e);
+                let code = fixture.response["error_code"].as_str().unwrap();
+                assert!(e.to_string().contains(code), "{}: {}", name, e);
+            }
+        }
+    }
+}
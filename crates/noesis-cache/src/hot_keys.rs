@@ -0,0 +1,295 @@
+//! Hot-key tracking
+//!
+//! Counts lookups per cache key in fixed windows with a count-min sketch, so
+//! memory stays constant however many distinct keys are requested, and keeps
+//! the `top_k` most requested keys of the window as candidates. A key with
+//! many misses is being calculated over and over (a stampede, or a result too
+//! short-lived to cache); a key with many hits is worth precomputing or is
+//! being hammered by one client.
+//!
+//! Sketch estimates never undercount and overcount by at most about
+//! `2 / SKETCH_WIDTH` of the window's lookups.
+
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "openapi")]
+use utoipa::ToSchema;
+
+use crate::CacheKey;
+
+/// Length of a counting window
+pub const DEFAULT_HOT_KEY_WINDOW: Duration = Duration::from_secs(60);
+
+/// Keys reported per window
+pub const DEFAULT_HOT_KEY_TOP_K: usize = 10;
+
+/// One row per 32 bits of the MD5 key hash
+const SKETCH_DEPTH: usize = 4;
+
+/// Counters per row
+const SKETCH_WIDTH: usize = 2048;
+
+/// Count-min sketch over MD5 key hashes
+struct CountMinSketch {
+    counters: Vec<u32>,
+}
+
+impl CountMinSketch {
+    fn new() -> Self {
+        Self {
+            counters: vec![0; SKETCH_DEPTH * SKETCH_WIDTH],
+        }
+    }
+
+    /// Counter of each row for a key; MD5 is uniform, so its four 32-bit
+    /// words index the rows without hashing again
+    fn cells(hash: &str) -> [usize; SKETCH_DEPTH] {
+        let digest = u128::from_str_radix(hash, 16).unwrap_or_else(|_| u128::from_le_bytes(md5::compute(hash).0));
+        std::array::from_fn(|row| row * SKETCH_WIDTH + ((digest >> (32 * row)) as u32 as usize % SKETCH_WIDTH))
+    }
+
+    /// Count one occurrence, returning the key's new estimate
+    fn add(&mut self, hash: &str) -> u32 {
+        Self::cells(hash)
+            .into_iter()
+            .map(|cell| {
+                self.counters[cell] = self.counters[cell].saturating_add(1);
+                self.counters[cell]
+            })
+            .min()
+            .unwrap_or(0)
+    }
+
+    fn estimate(&self, hash: &str) -> u32 {
+        Self::cells(hash).into_iter().map(|cell| self.counters[cell]).min().unwrap_or(0)
+    }
+
+    fn clear(&mut self) {
+        self.counters.fill(0);
+    }
+}
+
+/// One of the most requested keys of a window
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct HotKey {
+    /// Key as the engine built it; may contain birth data
+    #[cfg_attr(feature = "openapi", schema(example = "panchanga:1990-01-15:14:30:12.97:77.59:solar:v=1"))]
+    pub key: String,
+    /// MD5 of `key`, as stored in Redis and on disk
+    pub hash: String,
+    /// Estimated lookups in the window
+    pub requests: u64,
+    /// Estimated lookups that missed every layer
+    pub misses: u64,
+}
+
+/// Most requested keys of one window
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct HotKeyWindow {
+    /// Seconds the window covers; less than the window length while it is in progress
+    pub duration_secs: u64,
+    /// Lookups of all keys in the window
+    pub requests: u64,
+    /// Most requested first
+    pub top: Vec<HotKey>,
+}
+
+/// Hot keys of the window in progress and of the one before it
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct HotKeyReport {
+    /// Length of a window in seconds
+    pub window_secs: u64,
+    pub current: HotKeyWindow,
+    /// Last completed window; absent until one has completed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub previous: Option<HotKeyWindow>,
+}
+
+struct Candidate {
+    key: String,
+    requests: u32,
+}
+
+/// Windowed count-min tracker of the most requested cache keys
+pub struct HotKeyTracker {
+    window: Duration,
+    top_k: usize,
+    started: Instant,
+    requests: u64,
+    lookups: CountMinSketch,
+    misses: CountMinSketch,
+    /// Up to `top_k` keys by hash, with their estimates when last seen
+    candidates: HashMap<String, Candidate>,
+    previous: Option<HotKeyWindow>,
+}
+
+impl HotKeyTracker {
+    pub fn new(window: Duration, top_k: usize) -> Self {
+        Self {
+            window,
+            top_k,
+            started: Instant::now(),
+            requests: 0,
+            lookups: CountMinSketch::new(),
+            misses: CountMinSketch::new(),
+            candidates: HashMap::new(),
+            previous: None,
+        }
+    }
+
+    /// Count a lookup of `key`. Returns the window that just completed, if
+    /// this lookup started a new one.
+    pub fn record(&mut self, key: &CacheKey, hit: bool) -> Option<HotKeyWindow> {
+        self.record_at(key, hit, Instant::now())
+    }
+
+    fn record_at(&mut self, key: &CacheKey, hit: bool, now: Instant) -> Option<HotKeyWindow> {
+        let completed = self.roll_at(now);
+
+        self.requests += 1;
+        let requests = self.lookups.add(&key.hash);
+        if !hit {
+            self.misses.add(&key.hash);
+        }
+
+        if let Some(candidate) = self.candidates.get_mut(&key.hash) {
+            candidate.requests = requests;
+        } else if self.candidates.len() < self.top_k {
+            self.candidates
+                .insert(key.hash.clone(), Candidate { key: key.raw.clone(), requests });
+        } else if let Some((coldest, _)) = self
+            .candidates
+            .iter()
+            .filter(|(_, candidate)| candidate.requests < requests)
+            .min_by_key(|(_, candidate)| candidate.requests)
+        {
+            let coldest = coldest.clone();
+            self.candidates.remove(&coldest);
+            self.candidates
+                .insert(key.hash.clone(), Candidate { key: key.raw.clone(), requests });
+        }
+
+        completed
+    }
+
+    /// Current and previous windows
+    pub fn report(&mut self) -> HotKeyReport {
+        self.report_at(Instant::now())
+    }
+
+    fn report_at(&mut self, now: Instant) -> HotKeyReport {
+        self.roll_at(now);
+        HotKeyReport {
+            window_secs: self.window.as_secs(),
+            current: self.snapshot(now),
+            previous: self.previous.clone(),
+        }
+    }
+
+    /// Start a new window once the current one has run its length,
+    /// returning the completed one. Lookups roll windows too; this covers
+    /// the quiet periods in between.
+    pub fn roll(&mut self) -> Option<HotKeyWindow> {
+        self.roll_at(Instant::now())
+    }
+
+    fn roll_at(&mut self, now: Instant) -> Option<HotKeyWindow> {
+        if now.duration_since(self.started) < self.window {
+            return None;
+        }
+        let completed = self.snapshot(self.started + self.window);
+        self.started = now;
+        self.requests = 0;
+        self.lookups.clear();
+        self.misses.clear();
+        self.candidates.clear();
+        self.previous = Some(completed.clone());
+        Some(completed)
+    }
+
+    fn snapshot(&self, now: Instant) -> HotKeyWindow {
+        let mut top: Vec<HotKey> = self
+            .candidates
+            .iter()
+            .map(|(hash, candidate)| HotKey {
+                key: candidate.key.clone(),
+                hash: hash.clone(),
+                requests: u64::from(self.lookups.estimate(hash)),
+                misses: u64::from(self.misses.estimate(hash)),
+            })
+            .collect();
+        top.sort_by(|a, b| b.requests.cmp(&a.requests).then_with(|| a.hash.cmp(&b.hash)));
+        HotKeyWindow {
+            duration_secs: now.duration_since(self.started).as_secs(),
+            requests: self.requests,
+            top,
+        }
+    }
+}
+
+impl Default for HotKeyTracker {
+    fn default() -> Self {
+        Self::new(DEFAULT_HOT_KEY_WINDOW, DEFAULT_HOT_KEY_TOP_K)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key(i: usize) -> CacheKey {
+        CacheKey::new(format!("numerology:{}", i))
+    }
+
+    #[test]
+    fn test_top_keys_are_ranked_by_requests() {
+        let mut tracker = HotKeyTracker::new(Duration::from_secs(60), 3);
+        let start = Instant::now();
+        // Key i is requested i times, interleaved with a long tail of one-offs
+        for i in 1..=20 {
+            for _ in 0..i {
+                tracker.record_at(&key(i), true, start);
+            }
+            tracker.record_at(&key(1000 + i), false, start);
+        }
+        tracker.record_at(&key(20), false, start);
+
+        let report = tracker.report_at(start);
+        let top: Vec<(&str, u64, u64)> = report
+            .current
+            .top
+            .iter()
+            .map(|hot| (hot.key.as_str(), hot.requests, hot.misses))
+            .collect();
+        assert_eq!(top, [("numerology:20", 21, 1), ("numerology:19", 19, 0), ("numerology:18", 18, 0)]);
+        assert_eq!(report.current.requests, (1..=20).sum::<u64>() + 21);
+        assert!(report.previous.is_none());
+    }
+
+    #[test]
+    fn test_windows_roll_over() {
+        let mut tracker = HotKeyTracker::new(Duration::from_secs(60), 3);
+        let start = Instant::now();
+        tracker.record_at(&key(1), false, start);
+        tracker.record_at(&key(1), true, start + Duration::from_secs(30));
+
+        let completed = tracker
+            .record_at(&key(2), false, start + Duration::from_secs(61))
+            .expect("the first window completed");
+        assert_eq!(completed.duration_secs, 60);
+        assert_eq!(completed.top[0].key, "numerology:1");
+        assert_eq!((completed.top[0].requests, completed.top[0].misses), (2, 1));
+
+        let report = tracker.report_at(start + Duration::from_secs(70));
+        assert_eq!(report.previous, Some(completed));
+        assert_eq!(report.current.requests, 1);
+        assert_eq!(report.current.duration_secs, 9);
+        assert_eq!(report.current.top[0].key, "numerology:2");
+    }
+}
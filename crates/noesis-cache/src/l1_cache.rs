@@ -1,16 +1,39 @@
-//! L1 Cache -- In-memory cache with LRU eviction
+//! L1 Cache -- In-memory cache with size-aware LRU eviction
 //!
-//! Stores `serde_json::Value` payloads in a `DashMap` for lock-free concurrent
-//! reads with size-bounded eviction.
+//! Stores `serde_json::Value` payloads weighted by their serialised size, so
+//! the byte budget holds whether entries are 2 KB numerology readings or
+//! 200 KB Human Design charts.
+//!
+//! The budget is split into partitions: engines given a share of it (see
+//! [`L1Cache::with_shares`]) evict only their own least recently used
+//! entries, and every other engine shares what is left. An engine with large
+//! results therefore can't push everyone else's out. The partition is picked
+//! by the `engine_id` of the stored value; values without one go to the
+//! shared partition.
 
 use crate::{CacheKey, CachedResult};
-use dashmap::DashMap;
+use lru::LruCache;
 use noesis_core::EngineError;
+use serde::{Deserialize, Serialize};
 use serde_json::Value;
-use std::sync::Arc;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, MutexGuard};
 use std::time::{Duration, Instant};
 use tokio::sync::RwLock;
 
+#[cfg(feature = "openapi")]
+use utoipa::ToSchema;
+
+/// Partition of engines without a share of their own
+pub const SHARED_PARTITION: &str = "shared";
+
+/// Shares given to the engines with the largest results by default
+pub const DEFAULT_L1_SHARES: [(&str, f64); 3] = [("human-design", 0.25), ("gene-keys", 0.15), ("vimshottari", 0.15)];
+
+/// Least of the budget left to the shared partition; larger shares are
+/// scaled down to keep it
+const MIN_SHARED_SHARE: f64 = 0.1;
+
 /// Per-layer statistics.
 #[derive(Debug, Clone, Default)]
 pub(crate) struct L1CacheStats {
@@ -20,98 +43,234 @@ pub(crate) struct L1CacheStats {
     pub total_requests: u64,
 }
 
+/// Usage of one L1 partition
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct L1PartitionUsage {
+    /// Engine ID, or "shared" for engines without a share
+    #[cfg_attr(feature = "openapi", schema(example = "human-design"))]
+    pub name: String,
+    pub capacity_bytes: usize,
+    pub used_bytes: usize,
+    pub entries: usize,
+}
+
+struct Entry {
+    cached: CachedResult,
+    size: usize,
+}
+
+struct Partition {
+    entries: LruCache<CacheKey, Entry>,
+    capacity_bytes: usize,
+    used_bytes: usize,
+}
+
+impl Partition {
+    fn new(capacity_bytes: usize) -> Self {
+        Self {
+            entries: LruCache::unbounded(),
+            capacity_bytes,
+            used_bytes: 0,
+        }
+    }
+
+    fn remove(&mut self, key: &CacheKey) -> Option<Entry> {
+        let entry = self.entries.pop(key)?;
+        self.used_bytes -= entry.size;
+        Some(entry)
+    }
+}
+
+#[derive(Default)]
+struct L1State {
+    partitions: HashMap<String, Partition>,
+    /// Partition holding each key
+    index: HashMap<CacheKey, String>,
+}
+
+impl L1State {
+    fn remove(&mut self, key: &CacheKey) -> Option<Entry> {
+        let name = self.index.remove(key)?;
+        self.partitions.get_mut(&name)?.remove(key)
+    }
+}
+
 /// L1 Cache -- fast, in-memory, size-bounded.
 pub struct L1Cache {
-    cache: Arc<DashMap<CacheKey, CachedResult>>,
+    state: Arc<Mutex<L1State>>,
     max_size_bytes: usize,
-    current_size_bytes: Arc<RwLock<usize>>,
     stats: Arc<RwLock<L1CacheStats>>,
 }
 
 impl L1Cache {
+    /// One partition shared by every engine.
     pub fn new(max_size_mb: usize) -> Self {
+        Self::with_shares(max_size_mb, &HashMap::new())
+    }
+
+    /// Give each engine in `shares` that fraction of the budget to itself;
+    /// the rest is shared by the other engines.
+    pub fn with_shares(max_size_mb: usize, shares: &HashMap<String, f64>) -> Self {
         let max_size_bytes = max_size_mb * 1024 * 1024;
+        let shares: Vec<(&String, f64)> = shares
+            .iter()
+            .filter(|(name, share)| name.as_str() != SHARED_PARTITION && **share > 0.0)
+            .map(|(name, share)| (name, *share))
+            .collect();
+        let total: f64 = shares.iter().map(|(_, share)| share).sum();
+        let scale = if total > 1.0 - MIN_SHARED_SHARE {
+            (1.0 - MIN_SHARED_SHARE) / total
+        } else {
+            1.0
+        };
+
+        let mut state = L1State::default();
+        let mut reserved = 0;
+        for (name, share) in shares {
+            let capacity_bytes = (max_size_bytes as f64 * share * scale) as usize;
+            reserved += capacity_bytes;
+            state.partitions.insert(name.clone(), Partition::new(capacity_bytes));
+        }
+        state
+            .partitions
+            .insert(SHARED_PARTITION.to_string(), Partition::new(max_size_bytes.saturating_sub(reserved)));
+
         Self {
-            cache: Arc::new(DashMap::new()),
+            state: Arc::new(Mutex::new(state)),
             max_size_bytes,
-            current_size_bytes: Arc::new(RwLock::new(0)),
             stats: Arc::new(RwLock::new(L1CacheStats::default())),
         }
     }
 
-    /// Retrieve a value from L1, updating access metadata on hit.
+    fn state(&self) -> MutexGuard<'_, L1State> {
+        self.state.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    /// Retrieve a value from L1, marking it most recently used on hit.
     pub async fn get(&self, key: &CacheKey) -> Result<Option<Value>, EngineError> {
         {
             let mut stats = self.stats.write().await;
             stats.total_requests += 1;
         }
 
-        if let Some(entry) = self.cache.get(key) {
-            let mut cached = entry.clone();
-            cached.accessed_at = Instant::now();
-            cached.access_count += 1;
-            let value = cached.value.clone();
-            // Update the entry in-place
-            drop(entry);
-            self.cache.insert(key.clone(), cached);
+        let value = {
+            let mut state = self.state();
+            let partition = state
+                .index
+                .get(key)
+                .cloned()
+                .and_then(|name| state.partitions.get_mut(&name));
+            match partition.and_then(|partition| partition.entries.get_mut(key)) {
+                Some(entry) if entry.cached.is_expired() => {
+                    state.remove(key);
+                    None
+                }
+                Some(entry) => {
+                    entry.cached.accessed_at = Instant::now();
+                    entry.cached.access_count += 1;
+                    Some(entry.cached.value.clone())
+                }
+                None => None,
+            }
+        };
 
-            let mut stats = self.stats.write().await;
+        let mut stats = self.stats.write().await;
+        if value.is_some() {
             stats.hits += 1;
-            Ok(Some(value))
         } else {
-            let mut stats = self.stats.write().await;
             stats.misses += 1;
-            Ok(None)
         }
+        Ok(value)
     }
 
-    /// Store a JSON value in L1, evicting LRU entries if necessary.
+    /// Store a JSON value in L1, evicting LRU entries of its partition if necessary.
     pub async fn store(&self, key: &CacheKey, value: &Value) -> Result<(), EngineError> {
-        let estimated_size = Self::estimate_value_size(value);
+        self.store_with_ttl(key, value, None).await
+    }
 
-        if estimated_size > self.max_size_bytes {
-            return Err(EngineError::CacheError(
-                "Value too large for L1 cache".to_string(),
-            ));
-        }
+    /// Store a JSON value that is served for `ttl`, or until evicted when `None`.
+    pub async fn store_with_ttl(
+        &self,
+        key: &CacheKey,
+        value: &Value,
+        ttl: Option<Duration>,
+    ) -> Result<(), EngineError> {
+        let size = Self::estimate_value_size(value);
+        let evictions = {
+            let mut state = self.state();
+            let name = match value.get("engine_id").and_then(Value::as_str) {
+                Some(engine_id) if state.partitions.contains_key(engine_id) => engine_id.to_string(),
+                _ => SHARED_PARTITION.to_string(),
+            };
+            state.remove(key);
+
+            let L1State { partitions, index } = &mut *state;
+            let Some(partition) = partitions.get_mut(&name) else {
+                return Ok(());
+            };
+            if size > partition.capacity_bytes {
+                return Err(EngineError::CacheError(
+                    "Value too large for L1 cache".to_string(),
+                ));
+            }
 
-        self.ensure_space(estimated_size).await?;
+            let mut evictions = 0;
+            while partition.used_bytes + size > partition.capacity_bytes {
+                let Some((evicted, entry)) = partition.entries.pop_lru() else {
+                    break;
+                };
+                partition.used_bytes -= entry.size;
+                index.remove(&evicted);
+                evictions += 1;
+            }
 
-        let cached = CachedResult {
-            value: value.clone(),
-            created_at: Instant::now(),
-            accessed_at: Instant::now(),
-            access_count: 1,
+            let now = Instant::now();
+            let cached = CachedResult {
+                value: value.clone(),
+                created_at: now,
+                accessed_at: now,
+                access_count: 1,
+                expires_at: ttl.map(|ttl| now + ttl),
+            };
+            partition.entries.put(key.clone(), Entry { cached, size });
+            partition.used_bytes += size;
+            index.insert(key.clone(), name);
+            evictions
         };
 
-        self.cache.insert(key.clone(), cached);
-
-        let mut current_size = self.current_size_bytes.write().await;
-        *current_size += estimated_size;
+        if evictions > 0 {
+            let mut stats = self.stats.write().await;
+            stats.evictions += evictions;
+        }
         Ok(())
     }
 
     /// Remove a single entry.
     pub async fn invalidate(&self, key: &CacheKey) -> Result<(), EngineError> {
-        if let Some((_, cached)) = self.cache.remove(key) {
-            let estimated_size = Self::estimate_value_size(&cached.value);
-            let mut current_size = self.current_size_bytes.write().await;
-            *current_size = current_size.saturating_sub(estimated_size);
-        }
+        self.state().remove(key);
         Ok(())
     }
 
+    /// Remove every entry whose value matches `predicate`; returns how many.
+    pub async fn invalidate_matching(&self, predicate: impl Fn(&Value) -> bool) -> Result<usize, EngineError> {
+        Ok(self.remove_where(|entry| predicate(&entry.value)))
+    }
+
     /// Drop all entries.
     pub async fn clear(&self) -> Result<(), EngineError> {
-        self.cache.clear();
-        let mut current_size = self.current_size_bytes.write().await;
-        *current_size = 0;
+        let mut state = self.state();
+        state.index.clear();
+        for partition in state.partitions.values_mut() {
+            partition.entries.clear();
+            partition.used_bytes = 0;
+        }
         Ok(())
     }
 
     /// Current size snapshot.
     pub async fn get_current_size(&self) -> usize {
-        *self.current_size_bytes.read().await
+        self.state().partitions.values().map(|partition| partition.used_bytes).sum()
     }
 
     /// Configured maximum size.
@@ -121,7 +280,24 @@ impl L1Cache {
 
     /// Number of entries.
     pub fn get_entry_count(&self) -> usize {
-        self.cache.len()
+        self.state().index.len()
+    }
+
+    /// Capacity and usage of each partition, shared partition last.
+    pub fn partition_usage(&self) -> Vec<L1PartitionUsage> {
+        let state = self.state();
+        let mut usage: Vec<L1PartitionUsage> = state
+            .partitions
+            .iter()
+            .map(|(name, partition)| L1PartitionUsage {
+                name: name.clone(),
+                capacity_bytes: partition.capacity_bytes,
+                used_bytes: partition.used_bytes,
+                entries: partition.entries.len(),
+            })
+            .collect();
+        usage.sort_by_key(|partition| (partition.name == SHARED_PARTITION, partition.name.clone()));
+        usage
     }
 
     #[allow(dead_code)]
@@ -132,68 +308,26 @@ impl L1Cache {
     /// Evict expired entries older than `max_age`.
     pub async fn cleanup_expired(&self, max_age: Duration) -> Result<usize, EngineError> {
         let now = Instant::now();
-        let mut to_remove = Vec::new();
-
-        for entry in self.cache.iter() {
-            if now.duration_since(entry.value().created_at) > max_age {
-                to_remove.push(entry.key().clone());
-            }
-        }
-
-        let mut cleaned = 0;
-        for key in to_remove {
-            if let Some((_, cached)) = self.cache.remove(&key) {
-                let sz = Self::estimate_value_size(&cached.value);
-                let mut current_size = self.current_size_bytes.write().await;
-                *current_size = current_size.saturating_sub(sz);
-                cleaned += 1;
-            }
-        }
-        Ok(cleaned)
+        Ok(self.remove_where(|entry| now.duration_since(entry.created_at) > max_age))
     }
 
     // -----------------------------------------------------------------------
     // Internal helpers
     // -----------------------------------------------------------------------
 
-    /// Make room by evicting LRU entries until `required_bytes` fits.
-    async fn ensure_space(&self, required_bytes: usize) -> Result<(), EngineError> {
-        {
-            let current_size = self.current_size_bytes.read().await;
-            if *current_size + required_bytes <= self.max_size_bytes {
-                return Ok(());
-            }
-        }
-
-        // Collect, sort by LRU, evict.
-        let mut entries: Vec<_> = self
-            .cache
-            .iter()
-            .map(|e| (e.key().clone(), e.value().clone()))
+    fn remove_where(&self, predicate: impl Fn(&CachedResult) -> bool) -> usize {
+        let mut state = self.state();
+        let matching: Vec<CacheKey> = state
+            .partitions
+            .values()
+            .flat_map(|partition| partition.entries.iter())
+            .filter(|(_, entry)| predicate(&entry.cached))
+            .map(|(key, _)| key.clone())
             .collect();
-
-        entries.sort_by(|a, b| {
-            a.1.accessed_at
-                .cmp(&b.1.accessed_at)
-                .then(a.1.access_count.cmp(&b.1.access_count))
-        });
-
-        let mut evicted_bytes = 0usize;
-        for (key, cached) in entries {
-            if evicted_bytes >= required_bytes {
-                break;
-            }
-            let sz = Self::estimate_value_size(&cached.value);
-            self.cache.remove(&key);
-            evicted_bytes += sz;
-
-            let mut stats = self.stats.write().await;
-            stats.evictions += 1;
+        for key in &matching {
+            state.remove(key);
         }
-
-        let mut current_size = self.current_size_bytes.write().await;
-        *current_size = current_size.saturating_sub(evicted_bytes);
-        Ok(())
+        matching.len()
     }
 
     /// Rough byte-size estimate of a JSON value (serialised length).
@@ -203,3 +337,76 @@ impl L1Cache {
         serde_json::to_string(value).map(|s| s.len()).unwrap_or(256)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn output(engine_id: &str, bytes: usize) -> Value {
+        json!({ "engine_id": engine_id, "result": "x".repeat(bytes) })
+    }
+
+    fn key(engine_id: &str, i: usize) -> CacheKey {
+        CacheKey::new(format!("{}:{}", engine_id, i))
+    }
+
+    #[tokio::test]
+    async fn test_large_entries_evict_only_their_partition() {
+        let shares = HashMap::from([("human-design".to_string(), 0.5)]);
+        let cache = L1Cache::with_shares(1, &shares);
+
+        for i in 0..100 {
+            cache.store(&key("numerology", i), &output("numerology", 2_000)).await.unwrap();
+        }
+        // Twenty 200 KB charts need 4 MB; the partition holds two
+        for i in 0..20 {
+            cache.store(&key("human-design", i), &output("human-design", 200_000)).await.unwrap();
+        }
+
+        let usage = cache.partition_usage();
+        assert_eq!(usage[0].name, "human-design");
+        assert_eq!(usage[0].entries, 2);
+        assert!(usage[0].used_bytes <= usage[0].capacity_bytes);
+        assert_eq!(usage[1].name, SHARED_PARTITION);
+        assert_eq!(usage[1].entries, 100, "Numerology entries must survive the charts");
+        assert!(cache.get(&key("human-design", 19)).await.unwrap().is_some());
+        assert!(cache.get(&key("human-design", 0)).await.unwrap().is_none());
+        assert_eq!(cache.get_stats().await.evictions, 18);
+    }
+
+    #[tokio::test]
+    async fn test_eviction_is_least_recently_used_and_size_aware() {
+        let cache = L1Cache::new(1);
+
+        cache.store(&key("panchanga", 0), &output("panchanga", 270_000)).await.unwrap();
+        cache.store(&key("panchanga", 1), &output("panchanga", 270_000)).await.unwrap();
+        cache.store(&key("panchanga", 2), &output("panchanga", 270_000)).await.unwrap();
+        // Reading the oldest makes the second the least recently used
+        assert!(cache.get(&key("panchanga", 0)).await.unwrap().is_some());
+        cache.store(&key("panchanga", 3), &output("panchanga", 270_000)).await.unwrap();
+
+        assert!(cache.get(&key("panchanga", 1)).await.unwrap().is_none());
+        for i in [0, 2, 3] {
+            assert!(cache.get(&key("panchanga", i)).await.unwrap().is_some(), "entry {}", i);
+        }
+
+        // Small entries fill what the large ones leave without evicting them
+        for i in 0..100 {
+            cache.store(&key("numerology", i), &output("numerology", 2_000)).await.unwrap();
+        }
+        assert_eq!(cache.get_entry_count(), 103);
+        assert!(cache.get_current_size().await <= cache.get_max_size());
+    }
+
+    #[test]
+    fn test_shares_leave_room_for_other_engines() {
+        let shares = HashMap::from([("human-design".to_string(), 0.9), ("gene-keys".to_string(), 0.9)]);
+        let usage = L1Cache::with_shares(10, &shares).partition_usage();
+
+        let shared = usage.iter().find(|partition| partition.name == SHARED_PARTITION).unwrap();
+        let max = 10 * 1024 * 1024;
+        assert!(shared.capacity_bytes >= (max as f64 * MIN_SHARED_SHARE) as usize);
+        assert!(usage.iter().map(|partition| partition.capacity_bytes).sum::<usize>() <= max);
+    }
+}
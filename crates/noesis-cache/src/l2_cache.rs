@@ -33,12 +33,30 @@ impl L2Cache {
         Ok(())
     }
 
+    /// Store a value with its own TTL, or without expiry when `None`
+    /// (temporarily disabled).
+    pub async fn store_with_ttl(
+        &self,
+        _key: &CacheKey,
+        _value: &Value,
+        _ttl: Option<Duration>,
+    ) -> Result<(), EngineError> {
+        // TODO: Re-enable Redis cache
+        Ok(())
+    }
+
     /// Remove a single key from Redis (temporarily disabled).
     pub async fn remove(&self, _key: &CacheKey) -> Result<(), EngineError> {
         // TODO: Re-enable Redis cache
         Ok(())
     }
 
+    /// Remove every value matching `predicate` (temporarily disabled).
+    pub async fn invalidate_matching(&self, _predicate: impl Fn(&Value) -> bool) -> Result<usize, EngineError> {
+        // TODO: Re-enable Redis cache
+        Ok(0)
+    }
+
     /// Clear all keys managed by this cache (temporarily disabled).
     pub async fn clear(&self) -> Result<(), EngineError> {
         // TODO: Re-enable Redis cache
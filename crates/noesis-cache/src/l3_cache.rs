@@ -1,14 +1,22 @@
-//! L3 Cache -- Precomputed results persisted to disk
+//! L3 Cache -- Precomputed results persisted to durable storage
 //!
-//! Stores JSON values as files keyed by MD5 hash.  A memory-backed HashMap
-//! provides fast repeated lookups; disk is the authoritative store.
+//! Stores JSON values keyed by MD5 hash in an [`L3Storage`] backend. A
+//! memory-backed HashMap provides fast repeated lookups; the backend is the
+//! authoritative store.
+//!
+//! `L3_CACHE_BACKEND` picks the backend: `disk` (the default) writes files
+//! under `L3_CACHE_DIR`, which is fine for development but lost with the
+//! disk of an ephemeral pod; `s3` keeps them in an S3-compatible bucket (see
+//! [`crate::object_storage`]) so they survive redeploys.
 
+use crate::object_storage::{ObjectStorage, S3Config};
 use crate::CacheKey;
+use async_trait::async_trait;
 use noesis_core::EngineError;
 use serde_json::Value;
 use std::collections::HashMap;
 use std::fs;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 use tokio::sync::RwLock;
 
@@ -25,6 +33,179 @@ pub(crate) struct L3CacheStats {
     pub total_requests: u64,
 }
 
+// -----------------------------------------------------------------------
+// L3Storage
+// -----------------------------------------------------------------------
+
+/// Where L3 entries are persisted.
+///
+/// Entries are serialized JSON. A backend may group entries by
+/// [`CacheKey::namespace`]; one that does can drop a whole namespace in
+/// [`remove_namespace`](L3Storage::remove_namespace) without reading it.
+#[async_trait]
+pub trait L3Storage: Send + Sync {
+    /// Short name for logs and diagnostics, e.g. "disk".
+    fn name(&self) -> &'static str;
+
+    /// The stored bytes of `key`, if any.
+    async fn load(&self, key: &CacheKey) -> Result<Option<Vec<u8>>, EngineError>;
+
+    async fn save(&self, key: &CacheKey, bytes: Vec<u8>) -> Result<(), EngineError>;
+
+    async fn remove(&self, key: &CacheKey) -> Result<(), EngineError>;
+
+    /// Remove the entries of `namespace`, or every entry whose value matches
+    /// `predicate`; returns how many were removed.
+    async fn remove_namespace(
+        &self,
+        namespace: &str,
+        predicate: &(dyn for<'v> Fn(&'v Value) -> bool + Sync),
+    ) -> Result<usize, EngineError>;
+
+    async fn clear(&self) -> Result<(), EngineError>;
+
+    /// Entries stored and their total size in bytes.
+    async fn usage(&self) -> Result<(usize, u64), EngineError>;
+}
+
+/// Files named by key hash in one directory. Namespaces are ignored, so
+/// files written before they existed are still found.
+pub struct DiskStorage {
+    dir: PathBuf,
+}
+
+impl DiskStorage {
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self { dir: dir.into() }
+    }
+
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    fn file_path(&self, key: &CacheKey) -> PathBuf {
+        self.dir.join(format!("{}.json", key.hash))
+    }
+
+    fn directory_size(path: &Path) -> u64 {
+        let mut total = 0u64;
+        if let Ok(entries) = fs::read_dir(path) {
+            for entry in entries.flatten() {
+                let p = entry.path();
+                if p.is_file() {
+                    if let Ok(meta) = fs::metadata(&p) {
+                        total += meta.len();
+                    }
+                } else if p.is_dir() {
+                    total += Self::directory_size(&p);
+                }
+            }
+        }
+        total
+    }
+}
+
+#[async_trait]
+impl L3Storage for DiskStorage {
+    fn name(&self) -> &'static str {
+        "disk"
+    }
+
+    async fn load(&self, key: &CacheKey) -> Result<Option<Vec<u8>>, EngineError> {
+        let file_path = self.file_path(key);
+        if !file_path.exists() {
+            return Ok(None);
+        }
+        match fs::read(&file_path) {
+            Ok(bytes) => Ok(Some(bytes)),
+            Err(e) => {
+                tracing::warn!("Failed to read L3 cache file: {}", e);
+                Ok(None)
+            }
+        }
+    }
+
+    async fn save(&self, key: &CacheKey, bytes: Vec<u8>) -> Result<(), EngineError> {
+        fs::create_dir_all(&self.dir)
+            .map_err(|e| EngineError::CacheError(format!("Failed to create cache directory: {}", e)))?;
+        fs::write(self.file_path(key), bytes)
+            .map_err(|e| EngineError::CacheError(format!("Failed to write cache file: {}", e)))
+    }
+
+    async fn remove(&self, key: &CacheKey) -> Result<(), EngineError> {
+        let file_path = self.file_path(key);
+        if file_path.exists() {
+            fs::remove_file(&file_path)
+                .map_err(|e| EngineError::CacheError(format!("Failed to remove cache file: {}", e)))?;
+        }
+        Ok(())
+    }
+
+    async fn remove_namespace(
+        &self,
+        _namespace: &str,
+        predicate: &(dyn for<'v> Fn(&'v Value) -> bool + Sync),
+    ) -> Result<usize, EngineError> {
+        let mut removed = 0;
+        if let Ok(entries) = fs::read_dir(&self.dir) {
+            for path in entries.flatten().map(|entry| entry.path()) {
+                let matches = fs::read_to_string(&path)
+                    .ok()
+                    .and_then(|content| serde_json::from_str::<Value>(&content).ok())
+                    .is_some_and(|value| predicate(&value));
+                if matches {
+                    fs::remove_file(&path)
+                        .map_err(|e| EngineError::CacheError(format!("Failed to remove cache file: {}", e)))?;
+                    removed += 1;
+                }
+            }
+        }
+        Ok(removed)
+    }
+
+    async fn clear(&self) -> Result<(), EngineError> {
+        if self.dir.exists() {
+            fs::remove_dir_all(&self.dir)
+                .map_err(|e| EngineError::CacheError(format!("Failed to clear cache dir: {}", e)))?;
+        }
+        Ok(())
+    }
+
+    async fn usage(&self) -> Result<(usize, u64), EngineError> {
+        if !self.dir.exists() {
+            return Ok((0, 0));
+        }
+        let entries = fs::read_dir(&self.dir).map(|e| e.count()).unwrap_or(0);
+        Ok((entries, Self::directory_size(&self.dir)))
+    }
+}
+
+/// The backend `L3_CACHE_BACKEND` selects; disk when it is unset, unknown
+/// or its configuration is incomplete.
+pub fn storage_from_env() -> Arc<dyn L3Storage> {
+    let disk = || {
+        let dir = std::env::var("L3_CACHE_DIR").unwrap_or_else(|_| "./data/precomputed".to_string());
+        Arc::new(DiskStorage::new(dir)) as Arc<dyn L3Storage>
+    };
+    match std::env::var("L3_CACHE_BACKEND").as_deref() {
+        Err(_) | Ok("") | Ok("disk") => disk(),
+        Ok("s3") => match S3Config::from_env() {
+            Ok(config) => {
+                tracing::info!(bucket = %config.bucket, prefix = %config.prefix, "L3 cache in object storage");
+                Arc::new(ObjectStorage::new(config))
+            }
+            Err(e) => {
+                tracing::error!("L3 object storage misconfigured, using disk: {}", e);
+                disk()
+            }
+        },
+        Ok(other) => {
+            tracing::warn!("Unknown L3_CACHE_BACKEND '{}', using disk", other);
+            disk()
+        }
+    }
+}
+
 // -----------------------------------------------------------------------
 // L3CacheInfo
 // -----------------------------------------------------------------------
@@ -33,6 +214,9 @@ pub(crate) struct L3CacheStats {
 #[derive(Debug, Clone)]
 pub struct L3CacheInfo {
     pub memory_entries: usize,
+    /// Backend name, e.g. "disk" or "s3"
+    pub backend: &'static str,
+    /// Entries and bytes in the backend
     pub disk_entries: usize,
     pub disk_size_bytes: u64,
     pub enabled: bool,
@@ -42,22 +226,25 @@ pub struct L3CacheInfo {
 // L3Cache
 // -----------------------------------------------------------------------
 
-/// L3 Cache -- disk-backed precomputed results.
+/// L3 Cache -- durable precomputed results.
 pub struct L3Cache {
     enabled: bool,
-    cache_dir: String,
+    storage: Arc<dyn L3Storage>,
     memory_cache: Arc<RwLock<HashMap<CacheKey, Value>>>,
     stats: Arc<RwLock<L3CacheStats>>,
 }
 
 impl L3Cache {
+    /// Cache in the backend configured by the environment (see
+    /// [`storage_from_env`]).
     pub fn new(enabled: bool) -> Self {
-        let cache_dir = std::env::var("L3_CACHE_DIR")
-            .unwrap_or_else(|_| "./data/precomputed".to_string());
+        Self::with_storage(enabled, storage_from_env())
+    }
 
+    pub fn with_storage(enabled: bool, storage: Arc<dyn L3Storage>) -> Self {
         Self {
             enabled,
-            cache_dir,
+            storage,
             memory_cache: Arc::new(RwLock::new(HashMap::new())),
             stats: Arc::new(RwLock::new(L3CacheStats::default())),
         }
@@ -65,7 +252,7 @@ impl L3Cache {
 
     // -- public API --------------------------------------------------------
 
-    /// Retrieve a cached value (memory first, then disk).
+    /// Retrieve a cached value (memory first, then storage).
     pub async fn get(&self, key: &CacheKey) -> Result<Option<Value>, EngineError> {
         if !self.enabled {
             return Ok(None);
@@ -83,8 +270,8 @@ impl L3Cache {
             return Ok(Some(value));
         }
 
-        // Disk
-        if let Some(value) = self.load_from_disk(key).await? {
+        // Storage
+        if let Some(value) = self.load(key).await? {
             self.memory_cache.write().await.insert(key.clone(), value.clone());
             let mut stats = self.stats.write().await;
             stats.hits += 1;
@@ -97,37 +284,55 @@ impl L3Cache {
         Ok(None)
     }
 
-    /// Persist a value to disk and cache in memory.
+    /// Persist a value to storage and cache in memory.
     pub async fn store(&self, key: &CacheKey, value: &Value) -> Result<(), EngineError> {
         if !self.enabled {
             return Ok(());
         }
 
         self.memory_cache.write().await.insert(key.clone(), value.clone());
-        self.save_to_disk(key, value).await?;
+        let bytes = serde_json::to_vec_pretty(value)
+            .map_err(|e| EngineError::CacheError(format!("Serialization failed: {}", e)))?;
+        self.storage.save(key, bytes).await?;
 
         let mut stats = self.stats.write().await;
         stats.saves += 1;
         Ok(())
     }
 
-    /// Remove from both memory and disk.
+    /// Remove from both memory and storage.
     pub async fn invalidate(&self, key: &CacheKey) -> Result<(), EngineError> {
         if !self.enabled {
             return Ok(());
         }
         self.memory_cache.write().await.remove(key);
-        self.remove_from_disk(key).await?;
+        self.storage.remove(key).await?;
         Ok(())
     }
 
+    /// Remove the entries of `namespace`: the values matching `predicate`
+    /// from memory, and from storage whatever its backend holds for the
+    /// namespace (see [`L3Storage::remove_namespace`]). Returns how many
+    /// stored entries were removed.
+    pub async fn invalidate_namespace(
+        &self,
+        namespace: &str,
+        predicate: &(dyn for<'v> Fn(&'v Value) -> bool + Sync),
+    ) -> Result<usize, EngineError> {
+        if !self.enabled {
+            return Ok(0);
+        }
+        self.memory_cache.write().await.retain(|_, value| !predicate(value));
+        self.storage.remove_namespace(namespace, predicate).await
+    }
+
     /// Wipe everything.
     pub async fn clear(&self) -> Result<(), EngineError> {
         if !self.enabled {
             return Ok(());
         }
         self.memory_cache.write().await.clear();
-        self.clear_disk_cache().await?;
+        self.storage.clear().await?;
         Ok(())
     }
 
@@ -139,12 +344,9 @@ impl L3Cache {
         self.enabled = enabled;
     }
 
-    pub fn get_cache_dir(&self) -> &str {
-        &self.cache_dir
-    }
-
-    pub fn set_cache_dir(&mut self, cache_dir: String) {
-        self.cache_dir = cache_dir;
+    /// Name of the storage backend, e.g. "disk" or "s3".
+    pub fn backend(&self) -> &'static str {
+        self.storage.name()
     }
 
     #[allow(dead_code)]
@@ -173,101 +375,30 @@ impl L3Cache {
     /// Diagnostic snapshot.
     pub async fn get_cache_info(&self) -> Result<L3CacheInfo, EngineError> {
         let memory_entries = self.memory_cache.read().await.len();
-
-        let disk_entries = if Path::new(&self.cache_dir).exists() {
-            fs::read_dir(&self.cache_dir).map(|e| e.count()).unwrap_or(0)
-        } else {
-            0
-        };
-
-        let disk_size = if Path::new(&self.cache_dir).exists() {
-            Self::calculate_directory_size(&self.cache_dir).unwrap_or(0)
-        } else {
-            0
-        };
+        let (disk_entries, disk_size_bytes) = self.storage.usage().await?;
 
         Ok(L3CacheInfo {
             memory_entries,
+            backend: self.storage.name(),
             disk_entries,
-            disk_size_bytes: disk_size,
+            disk_size_bytes,
             enabled: self.enabled,
         })
     }
 
     // -- internal ----------------------------------------------------------
 
-    async fn load_from_disk(&self, key: &CacheKey) -> Result<Option<Value>, EngineError> {
-        let file_path = self.cache_file_path(key);
-
-        if !Path::new(&file_path).exists() {
+    async fn load(&self, key: &CacheKey) -> Result<Option<Value>, EngineError> {
+        let Some(bytes) = self.storage.load(key).await? else {
             return Ok(None);
-        }
-
-        match fs::read_to_string(&file_path) {
-            Ok(content) => match serde_json::from_str::<Value>(&content) {
-                Ok(value) => Ok(Some(value)),
-                Err(e) => {
-                    tracing::warn!("Corrupted L3 cache file, removing: {}", e);
-                    let _ = fs::remove_file(&file_path);
-                    Ok(None)
-                }
-            },
+        };
+        match serde_json::from_slice::<Value>(&bytes) {
+            Ok(value) => Ok(Some(value)),
             Err(e) => {
-                tracing::warn!("Failed to read L3 cache file: {}", e);
+                tracing::warn!("Corrupted L3 cache entry, removing: {}", e);
+                let _ = self.storage.remove(key).await;
                 Ok(None)
             }
         }
     }
-
-    async fn save_to_disk(&self, key: &CacheKey, value: &Value) -> Result<(), EngineError> {
-        fs::create_dir_all(&self.cache_dir)
-            .map_err(|e| EngineError::CacheError(format!("Failed to create cache directory: {}", e)))?;
-
-        let file_path = self.cache_file_path(key);
-        let content = serde_json::to_string_pretty(value)
-            .map_err(|e| EngineError::CacheError(format!("Serialization failed: {}", e)))?;
-
-        fs::write(&file_path, content)
-            .map_err(|e| EngineError::CacheError(format!("Failed to write cache file: {}", e)))?;
-
-        Ok(())
-    }
-
-    async fn remove_from_disk(&self, key: &CacheKey) -> Result<(), EngineError> {
-        let file_path = self.cache_file_path(key);
-        if Path::new(&file_path).exists() {
-            fs::remove_file(&file_path)
-                .map_err(|e| EngineError::CacheError(format!("Failed to remove cache file: {}", e)))?;
-        }
-        Ok(())
-    }
-
-    async fn clear_disk_cache(&self) -> Result<(), EngineError> {
-        if Path::new(&self.cache_dir).exists() {
-            fs::remove_dir_all(&self.cache_dir)
-                .map_err(|e| EngineError::CacheError(format!("Failed to clear cache dir: {}", e)))?;
-        }
-        Ok(())
-    }
-
-    fn cache_file_path(&self, key: &CacheKey) -> String {
-        format!("{}/{}.json", self.cache_dir, key.hash)
-    }
-
-    fn calculate_directory_size(path: &str) -> Result<u64, EngineError> {
-        let mut total = 0u64;
-        if let Ok(entries) = fs::read_dir(path) {
-            for entry in entries.flatten() {
-                let p = entry.path();
-                if p.is_file() {
-                    if let Ok(meta) = fs::metadata(&p) {
-                        total += meta.len();
-                    }
-                } else if p.is_dir() {
-                    total += Self::calculate_directory_size(p.to_str().unwrap_or(""))?;
-                }
-            }
-        }
-        Ok(total)
-    }
 }
@@ -3,21 +3,34 @@
 //! Engine-agnostic cache that stores `serde_json::Value` payloads.
 //! Engines provide their own cache keys via the `ConsciousnessEngine::cache_key` trait method;
 //! this crate hashes those keys and manages L1/L2/L3 storage transparently.
-
+//! How long a result is served comes from `ConsciousnessEngine::cache_ttl` when it
+//! is stored with [`CacheManager::store_with_ttl`].
+//! Lookups are also counted per key to report the hottest ones (see
+//! [`hot_keys`]). L1 evicts by size, and engines can be given their own share
+//! of it (see [`l1_cache`]). L3 keeps its entries on local disk or, where
+//! disks don't outlive a deploy, in S3-compatible object storage (see
+//! [`l3_cache`]).
+
+pub mod hot_keys;
 pub mod l1_cache;
 pub mod l2_cache;
 pub mod l3_cache;
+pub mod object_storage;
 
-use noesis_core::EngineError;
+use noesis_core::{CacheTtl, EngineError};
+use noesis_metrics::NoesisMetrics;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
-use std::sync::Arc;
-use std::time::Duration;
+use std::collections::HashMap;
+use std::future::Future;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
 use tokio::sync::RwLock;
 
-use l1_cache::L1Cache;
+use hot_keys::{HotKeyReport, HotKeyTracker};
+use l1_cache::{L1Cache, L1PartitionUsage};
 use l2_cache::L2Cache;
-use l3_cache::L3Cache;
+use l3_cache::{L3Cache, L3Storage};
 
 // ---------------------------------------------------------------------------
 // CacheKey
@@ -26,13 +39,18 @@ use l3_cache::L3Cache;
 /// A deterministic cache key derived from an engine-provided string.
 ///
 /// The raw key is hashed with MD5 to produce a fixed-length hex string that is
-/// safe for use as a file name and Redis key.
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+/// safe for use as a file name and Redis key. Keys are equal when their
+/// hashes are; the namespace only says where L3 object storage keeps them.
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CacheKey {
     /// The original key string provided by the engine.
     pub raw: String,
     /// MD5 hex digest of `raw`.
     pub hash: String,
+    /// Group of keys stored and invalidated together, such as one engine
+    /// version's results (see [`CacheKey::engine_namespace`]).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub namespace: Option<String>,
 }
 
 impl CacheKey {
@@ -41,7 +59,33 @@ impl CacheKey {
     pub fn new(raw: impl Into<String>) -> Self {
         let raw = raw.into();
         let hash = format!("{:x}", md5::compute(&raw));
-        Self { raw, hash }
+        Self { raw, hash, namespace: None }
+    }
+
+    /// Place the key in `namespace`.
+    pub fn with_namespace(mut self, namespace: impl Into<String>) -> Self {
+        self.namespace = Some(namespace.into());
+        self
+    }
+
+    /// Namespace of the results `engine_id` calculates at `engine_version`,
+    /// e.g. "panchanga/v2".
+    pub fn engine_namespace(engine_id: &str, engine_version: &str) -> String {
+        format!("{}/v{}", engine_id, engine_version)
+    }
+}
+
+impl PartialEq for CacheKey {
+    fn eq(&self, other: &Self) -> bool {
+        self.hash == other.hash
+    }
+}
+
+impl Eq for CacheKey {}
+
+impl std::hash::Hash for CacheKey {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.hash.hash(state);
     }
 }
 
@@ -57,6 +101,8 @@ pub struct CachedResult {
     pub created_at: std::time::Instant,
     pub accessed_at: std::time::Instant,
     pub access_count: u64,
+    /// When the entry stops being served; `None` keeps it until evicted.
+    pub expires_at: Option<std::time::Instant>,
 }
 
 impl CachedResult {
@@ -67,8 +113,14 @@ impl CachedResult {
             created_at: now,
             accessed_at: now,
             access_count: 0,
+            expires_at: None,
         }
     }
+
+    /// Whether the entry has outlived its TTL.
+    pub fn is_expired(&self) -> bool {
+        self.expires_at.is_some_and(|at| Instant::now() >= at)
+    }
 }
 
 // ---------------------------------------------------------------------------
@@ -99,12 +151,29 @@ impl CacheStats {
 // CacheManager
 // ---------------------------------------------------------------------------
 
+fn publish_hot_keys(window: &hot_keys::HotKeyWindow) {
+    noesis_metrics::record_cache_hot_keys(
+        window
+            .top
+            .iter()
+            .map(|hot| (hot.hash.as_str(), hot.requests, hot.misses)),
+    );
+}
+
 /// Multi-layer cache manager (L1 in-memory, L2 Redis, L3 disk).
+///
+/// Every layer lookup and write is recorded in the per-layer Prometheus
+/// metrics (`noesis_cache_layer_*`). The overall `noesis_cache_hits_total` /
+/// `noesis_cache_misses_total` counters are updated once a [`NoesisMetrics`]
+/// handle is attached with [`CacheManager::with_metrics`]. The hottest keys
+/// of each completed window are published as `noesis_cache_hot_key_*`.
 pub struct CacheManager {
     l1_cache: Arc<L1Cache>,
     l2_cache: Arc<L2Cache>,
     l3_cache: Arc<L3Cache>,
     stats: Arc<RwLock<CacheStats>>,
+    hot_keys: Arc<Mutex<HotKeyTracker>>,
+    metrics: Option<Arc<NoesisMetrics>>,
 }
 
 impl CacheManager {
@@ -119,58 +188,147 @@ impl CacheManager {
             l2_cache: Arc::new(L2Cache::new(redis_url, l2_ttl)),
             l3_cache: Arc::new(L3Cache::new(l3_enabled)),
             stats: Arc::new(RwLock::new(CacheStats::default())),
+            hot_keys: Arc::new(Mutex::new(HotKeyTracker::default())),
+            metrics: None,
+        }
+    }
+
+    /// Give each engine in `shares` that fraction of L1 to itself, so its
+    /// entries only evict each other; other engines share the rest. Call
+    /// before the cache is used, as it starts L1 empty.
+    pub fn with_l1_shares(mut self, shares: &HashMap<String, f64>) -> Self {
+        self.l1_cache = Arc::new(L1Cache::with_shares(
+            self.l1_cache.get_max_size() / (1024 * 1024),
+            shares,
+        ));
+        self
+    }
+
+    /// Keep L3 entries in `storage` instead of the backend the environment
+    /// selects. Call before the cache is used.
+    pub fn with_l3_storage(mut self, storage: Arc<dyn L3Storage>) -> Self {
+        self.l3_cache = Arc::new(L3Cache::with_storage(self.l3_cache.is_enabled(), storage));
+        self
+    }
+
+    /// Report overall hits and misses to `metrics`.
+    pub fn with_metrics(mut self, metrics: Arc<NoesisMetrics>) -> Self {
+        self.metrics = Some(metrics);
+        self
+    }
+
+    /// Run one layer lookup, recording its outcome and latency.
+    async fn lookup(
+        layer: &str,
+        lookup: impl Future<Output = Result<Option<Value>, EngineError>>,
+    ) -> Result<Option<Value>, EngineError> {
+        let started = Instant::now();
+        let value = lookup.await?;
+        noesis_metrics::record_cache_layer_lookup(layer, value.is_some(), started.elapsed().as_secs_f64());
+        Ok(value)
+    }
+
+    /// Run one layer write, recording its latency.
+    async fn write(
+        layer: &str,
+        write: impl Future<Output = Result<(), EngineError>>,
+    ) -> Result<(), EngineError> {
+        let started = Instant::now();
+        write.await?;
+        noesis_metrics::record_cache_layer_store(layer, started.elapsed().as_secs_f64());
+        Ok(())
+    }
+
+    fn record_outcome(&self, key: &CacheKey, hit: bool) {
+        let completed = self.hot_keys.lock().map(|mut tracker| tracker.record(key, hit)).unwrap_or(None);
+        if let Some(window) = completed {
+            publish_hot_keys(&window);
+        }
+        if let Some(metrics) = &self.metrics {
+            if hit {
+                metrics.record_cache_hit();
+            } else {
+                metrics.record_cache_miss();
+            }
         }
     }
 
     /// Retrieve a cached value, checking L1 -> L2 -> L3 in order.
     pub async fn get(&self, key: &CacheKey) -> Result<Option<Value>, EngineError> {
+        Ok(self.get_with_layer(key).await?.map(|(value, _)| value))
+    }
+
+    /// [`get`](Self::get), also naming the layer that answered: "l1", "l2"
+    /// or "l3".
+    pub async fn get_with_layer(&self, key: &CacheKey) -> Result<Option<(Value, &'static str)>, EngineError> {
         {
             let mut stats = self.stats.write().await;
             stats.total_requests += 1;
         }
 
         // L1 -- in-memory
-        if let Some(value) = self.l1_cache.get(key).await? {
+        if let Some(value) = Self::lookup("l1", self.l1_cache.get(key)).await? {
             let mut stats = self.stats.write().await;
             stats.l1_hits += 1;
-            return Ok(Some(value));
+            self.record_outcome(key, true);
+            return Ok(Some((value, "l1")));
         }
 
         // L2 -- Redis
-        if let Some(value) = self.l2_cache.get(key).await? {
+        if let Some(value) = Self::lookup("l2", self.l2_cache.get(key)).await? {
             // Populate L1
-            self.l1_cache.store(key, &value).await?;
+            Self::write("l1", self.l1_cache.store(key, &value)).await?;
             let mut stats = self.stats.write().await;
             stats.l2_hits += 1;
-            return Ok(Some(value));
+            self.record_outcome(key, true);
+            return Ok(Some((value, "l2")));
         }
 
         // L3 -- disk
-        if let Some(value) = self.l3_cache.get(key).await? {
+        if let Some(value) = Self::lookup("l3", self.l3_cache.get(key)).await? {
             // Populate higher layers
-            self.l1_cache.store(key, &value).await?;
-            self.l2_cache.store(key, &value).await?;
+            Self::write("l1", self.l1_cache.store(key, &value)).await?;
+            Self::write("l2", self.l2_cache.store(key, &value)).await?;
             let mut stats = self.stats.write().await;
             stats.l3_hits += 1;
-            return Ok(Some(value));
+            self.record_outcome(key, true);
+            return Ok(Some((value, "l3")));
         }
 
         let mut stats = self.stats.write().await;
         stats.cache_misses += 1;
+        self.record_outcome(key, false);
         Ok(None)
     }
 
     /// Store a value in L1 and L2.
     pub async fn store(&self, key: &CacheKey, value: &Value) -> Result<(), EngineError> {
-        self.l1_cache.store(key, value).await?;
-        self.l2_cache.store(key, value).await?;
+        Self::write("l1", self.l1_cache.store(key, value)).await?;
+        Self::write("l2", self.l2_cache.store(key, value)).await?;
+        Ok(())
+    }
+
+    /// Store a value in L1 and L2 for as long as `ttl` allows.
+    ///
+    /// Pass the engine's [`ConsciousnessEngine::cache_ttl`] so time-sensitive
+    /// readings expire at their next transition and natal results are kept
+    /// until evicted, instead of the blanket L2 TTL. A result whose
+    /// transition has already passed is not stored.
+    ///
+    /// [`ConsciousnessEngine::cache_ttl`]: noesis_core::ConsciousnessEngine::cache_ttl
+    pub async fn store_with_ttl(&self, key: &CacheKey, value: &Value, ttl: CacheTtl) -> Result<(), EngineError> {
+        let expires_in = ttl.expires_in();
+        if expires_in == Some(Duration::ZERO) {
+            return Ok(());
+        }
+        Self::write("l1", self.l1_cache.store_with_ttl(key, value, expires_in)).await?;
+        Self::write("l2", self.l2_cache.store_with_ttl(key, value, expires_in)).await?;
         Ok(())
     }
 
     /// Store a value in L3 (precomputed / persistent).
     pub async fn store_precomputed(&self, key: &CacheKey, value: &Value) -> Result<(), EngineError> {
-        self.l3_cache.store(key, value).await?;
-        Ok(())
+        Self::write("l3", self.l3_cache.store(key, value)).await
     }
 
     /// Invalidate a key across all layers.
@@ -181,6 +339,32 @@ impl CacheManager {
         Ok(())
     }
 
+    /// Remove the results `engine_id` calculated at `engine_version` from
+    /// every layer; returns how many entries L1 and L3 held.
+    ///
+    /// Matches on the `engine_id` and `metadata.engine_version` of the cached
+    /// engine output, so results stored without a version are left alone.
+    /// Cached workflow results holding such an output in `engine_outputs` go
+    /// too. L3 object storage instead drops the version's whole namespace.
+    pub async fn invalidate_engine_version(&self, engine_id: &str, engine_version: &str) -> Result<usize, EngineError> {
+        let is_output = |value: &Value| {
+            value.get("engine_id").and_then(Value::as_str) == Some(engine_id)
+                && value.pointer("/metadata/engine_version").and_then(Value::as_str) == Some(engine_version)
+        };
+        let matches = |value: &Value| {
+            is_output(value)
+                || value
+                    .get("engine_outputs")
+                    .and_then(Value::as_object)
+                    .is_some_and(|outputs| outputs.values().any(is_output))
+        };
+        let l1 = self.l1_cache.invalidate_matching(matches).await?;
+        self.l2_cache.invalidate_matching(matches).await?;
+        let namespace = CacheKey::engine_namespace(engine_id, engine_version);
+        let l3 = self.l3_cache.invalidate_namespace(&namespace, &matches).await?;
+        Ok(l1 + l3)
+    }
+
     /// Clear every layer.
     pub async fn clear_all(&self) -> Result<(), EngineError> {
         self.l1_cache.clear().await?;
@@ -194,6 +378,17 @@ impl CacheManager {
         self.stats.read().await.clone()
     }
 
+    /// The most requested keys of the current and the last completed window.
+    pub fn hot_keys(&self) -> HotKeyReport {
+        let Ok(mut tracker) = self.hot_keys.lock() else {
+            return HotKeyReport::default();
+        };
+        if let Some(window) = tracker.roll() {
+            publish_hot_keys(&window);
+        }
+        tracker.report()
+    }
+
     /// Reset aggregate statistics to zero.
     pub async fn reset_stats(&self) {
         let mut stats = self.stats.write().await;
@@ -217,9 +412,9 @@ impl CacheManager {
 
     /// Store a value across all three layers (L1, L2, L3).
     pub async fn store_all_layers(&self, key: &CacheKey, value: &Value) -> Result<(), EngineError> {
-        self.l1_cache.store(key, value).await?;
-        self.l2_cache.store(key, value).await?;
-        self.l3_cache.store(key, value).await?;
+        Self::write("l1", self.l1_cache.store(key, value)).await?;
+        Self::write("l2", self.l2_cache.store(key, value)).await?;
+        Self::write("l3", self.l3_cache.store(key, value)).await?;
         Ok(())
     }
 
@@ -228,6 +423,11 @@ impl CacheManager {
         self.l1_cache.get_entry_count()
     }
 
+    /// Capacity and usage of each L1 partition.
+    pub fn l1_partitions(&self) -> Vec<L1PartitionUsage> {
+        self.l1_cache.partition_usage()
+    }
+
     /// Preload common calculations into the cache (engine-specific hook).
     pub async fn preload_common_calculations(&self) -> Result<(), EngineError> {
         // Hook for engines to preload frequently-requested results.
@@ -728,3 +728,229 @@ async fn test_l3_clear_removes_disk_files() {
         "L3 directory should be removed after clear"
     );
 }
+
+/// Test 24: Lookups are reported per layer and to the attached NoesisMetrics.
+#[tokio::test]
+async fn test_lookups_are_recorded_in_metrics() {
+    use noesis_metrics::{NoesisMetrics, CACHE_LAYER_LOOKUPS_TOTAL};
+    use std::sync::Arc;
+
+    let metrics = Arc::new(NoesisMetrics::new().unwrap());
+    let cm = test_cache_manager_l1_only().with_metrics(metrics.clone());
+    let l1_hits = CACHE_LAYER_LOOKUPS_TOTAL.with_label_values(&["l1", "hit"]).get();
+    let l3_misses = CACHE_LAYER_LOOKUPS_TOTAL.with_label_values(&["l3", "miss"]).get();
+
+    let key = make_key("metrics", 0);
+    assert!(cm.get(&key).await.unwrap().is_none());
+    cm.store(&key, &make_value(0)).await.unwrap();
+    assert!(cm.get(&key).await.unwrap().is_some());
+
+    // Other tests share the global counters, so compare lower bounds
+    assert!(CACHE_LAYER_LOOKUPS_TOTAL.with_label_values(&["l1", "hit"]).get() > l1_hits);
+    assert!(CACHE_LAYER_LOOKUPS_TOTAL.with_label_values(&["l3", "miss"]).get() > l3_misses);
+    assert_eq!(metrics.cache_hits.get(), 1.0);
+    assert_eq!(metrics.cache_misses.get(), 1.0);
+}
+
+/// Test 25: Results stored with an engine TTL expire on their own schedule.
+#[tokio::test]
+async fn test_store_with_ttl_expires_per_entry() {
+    use noesis_core::CacheTtl;
+
+    let cm = test_cache_manager_l1_only();
+    let natal = make_key("hd", 25);
+    let clock = make_key("vedic-clock", 25);
+    let passed = make_key("vedic-clock", 26);
+
+    cm.store_with_ttl(&natal, &make_value(1), CacheTtl::Infinite).await.unwrap();
+    cm.store_with_ttl(&clock, &make_value(2), CacheTtl::Duration(Duration::from_millis(50)))
+        .await
+        .unwrap();
+    let earlier = CacheTtl::UntilNextTransition(chrono::Utc::now() - chrono::Duration::minutes(1));
+    cm.store_with_ttl(&passed, &make_value(3), earlier).await.unwrap();
+
+    assert!(cm.get(&clock).await.unwrap().is_some());
+    assert!(cm.get(&passed).await.unwrap().is_none(), "A passed transition is not stored");
+
+    tokio::time::sleep(Duration::from_millis(80)).await;
+    assert!(cm.get(&clock).await.unwrap().is_none(), "Entry should expire after its TTL");
+    assert!(cm.get(&natal).await.unwrap().is_some());
+    assert_eq!(cm.l1_entry_count(), 1);
+}
+
+/// Test 26: Invalidating an engine version removes only that version's outputs.
+#[tokio::test]
+async fn test_invalidate_engine_version() {
+    let tmp = tempfile::tempdir().unwrap();
+    let cm = test_cache_manager(tmp.path().to_str().unwrap());
+    let output = |engine_id: &str, version: &str| {
+        json!({ "engine_id": engine_id, "result": {}, "metadata": { "engine_version": version } })
+    };
+
+    let old = make_key("panchanga", 26);
+    let current = make_key("panchanga", 27);
+    let other = make_key("numerology", 26);
+    cm.store_all_layers(&old, &output("panchanga", "1")).await.unwrap();
+    cm.store_all_layers(&current, &output("panchanga", "2")).await.unwrap();
+    cm.store_all_layers(&other, &output("numerology", "1")).await.unwrap();
+
+    // One L1 entry plus its L3 file
+    assert_eq!(cm.invalidate_engine_version("panchanga", "1").await.unwrap(), 2);
+    assert!(cm.get(&old).await.unwrap().is_none(), "Old version must not survive on disk");
+    assert!(cm.get(&current).await.unwrap().is_some());
+    assert!(cm.get(&other).await.unwrap().is_some());
+    assert_eq!(cm.invalidate_engine_version("panchanga", "1").await.unwrap(), 0);
+
+    // A workflow result goes with any engine output it holds
+    let workflow = make_key("workflow", 26);
+    let outputs = json!({ "panchanga": output("panchanga", "2"), "numerology": output("numerology", "1") });
+    cm.store_with_ttl(&workflow, &json!({ "workflow_id": "daily-practice", "engine_outputs": outputs }), noesis_core::CacheTtl::DEFAULT)
+        .await
+        .unwrap();
+    assert_eq!(cm.invalidate_engine_version("panchanga", "2").await.unwrap(), 3);
+    assert!(cm.get(&workflow).await.unwrap().is_none());
+    assert!(cm.get(&other).await.unwrap().is_some());
+}
+
+/// Test 27: Repeated lookups of one key make it the hottest, with its misses.
+#[tokio::test]
+async fn test_hot_keys_rank_repeated_lookups() {
+    let cm = test_cache_manager_l1_only();
+    let hot = make_key("panchanga", 28);
+    let cold = make_key("panchanga", 29);
+
+    // Concurrent misses on one key look like a stampede
+    for _ in 0..3 {
+        assert!(cm.get(&hot).await.unwrap().is_none());
+    }
+    cm.store(&hot, &make_value(28)).await.unwrap();
+    for _ in 0..5 {
+        assert!(cm.get(&hot).await.unwrap().is_some());
+    }
+    assert!(cm.get(&cold).await.unwrap().is_none());
+
+    let report = cm.hot_keys();
+    assert_eq!(report.current.requests, 9);
+    assert!(report.previous.is_none());
+    let hottest = &report.current.top[0];
+    assert_eq!(hottest.key, hot.raw);
+    assert_eq!(hottest.hash, hot.hash);
+    assert_eq!((hottest.requests, hottest.misses), (8, 3));
+    assert_eq!(report.current.top[1].key, cold.raw);
+}
+
+/// Test 28: An engine with its own L1 share evicts only its own entries.
+#[tokio::test]
+async fn test_l1_shares_isolate_large_results() {
+    use std::collections::HashMap;
+
+    let shares = HashMap::from([("human-design".to_string(), 0.5)]);
+    let cm = test_cache_manager_l1_only().with_l1_shares(&shares);
+    let chart = |i: u32| json!({ "engine_id": "human-design", "seed": i, "chart": "x".repeat(200_000) });
+
+    for i in 0..50 {
+        cm.store(&make_key("numerology", i), &make_value(i)).await.unwrap();
+    }
+    for i in 0..10 {
+        cm.store(&make_key("hd", i), &chart(i)).await.unwrap();
+    }
+
+    for i in 0..50 {
+        assert!(cm.get(&make_key("numerology", i)).await.unwrap().is_some(), "entry {}", i);
+    }
+    assert!(cm.get(&make_key("hd", 9)).await.unwrap().is_some());
+    assert!(cm.get(&make_key("hd", 0)).await.unwrap().is_none());
+
+    let partitions = cm.l1_partitions();
+    let names: Vec<&str> = partitions.iter().map(|p| p.name.as_str()).collect();
+    assert_eq!(names, ["human-design", "shared"]);
+    assert_eq!((partitions[0].entries, partitions[1].entries), (2, 50));
+}
+
+/// Test 29: A pluggable L3 backend gets entries by namespace, and engine
+/// version invalidation hands it the version's namespace.
+#[tokio::test]
+async fn test_l3_storage_is_pluggable() {
+    use async_trait::async_trait;
+    use noesis_cache::l3_cache::L3Storage;
+    use noesis_core::EngineError;
+    use std::collections::HashMap;
+    use std::sync::{Arc, Mutex};
+
+    /// Objects by "namespace/hash", as object storage lays them out
+    #[derive(Default)]
+    struct MemoryStorage {
+        objects: Mutex<HashMap<String, Vec<u8>>>,
+    }
+
+    fn path(key: &CacheKey) -> String {
+        format!("{}/{}", key.namespace.as_deref().unwrap_or("_shared"), key.hash)
+    }
+
+    #[async_trait]
+    impl L3Storage for MemoryStorage {
+        fn name(&self) -> &'static str {
+            "memory"
+        }
+        async fn load(&self, key: &CacheKey) -> Result<Option<Vec<u8>>, EngineError> {
+            Ok(self.objects.lock().unwrap().get(&path(key)).cloned())
+        }
+        async fn save(&self, key: &CacheKey, bytes: Vec<u8>) -> Result<(), EngineError> {
+            self.objects.lock().unwrap().insert(path(key), bytes);
+            Ok(())
+        }
+        async fn remove(&self, key: &CacheKey) -> Result<(), EngineError> {
+            self.objects.lock().unwrap().remove(&path(key));
+            Ok(())
+        }
+        async fn remove_namespace(
+            &self,
+            namespace: &str,
+            _predicate: &(dyn for<'v> Fn(&'v Value) -> bool + Sync),
+        ) -> Result<usize, EngineError> {
+            let mut objects = self.objects.lock().unwrap();
+            let before = objects.len();
+            objects.retain(|path, _| !path.starts_with(&format!("{}/", namespace)));
+            Ok(before - objects.len())
+        }
+        async fn clear(&self) -> Result<(), EngineError> {
+            self.objects.lock().unwrap().clear();
+            Ok(())
+        }
+        async fn usage(&self) -> Result<(usize, u64), EngineError> {
+            let objects = self.objects.lock().unwrap();
+            Ok((objects.len(), objects.values().map(|bytes| bytes.len() as u64).sum()))
+        }
+    }
+
+    let storage = Arc::new(MemoryStorage::default());
+    // L3 stays disabled unless the manager enabled it
+    let disabled = CacheManager::new(String::new(), 1, Duration::from_secs(3600), false).with_l3_storage(storage.clone());
+    disabled.store_precomputed(&make_key("panchanga", 1), &make_value(1)).await.unwrap();
+    assert!(storage.objects.lock().unwrap().is_empty());
+
+    let tmp = tempfile::tempdir().unwrap();
+    let cm = test_cache_manager(tmp.path().to_str().unwrap()).with_l3_storage(storage.clone());
+    let output = json!({ "engine_id": "panchanga", "result": {}, "metadata": { "engine_version": "2" } });
+    let key = make_key("panchanga", 2).with_namespace(CacheKey::engine_namespace("panchanga", "2"));
+    let shared = make_key("vedic-index", 0);
+    cm.store_all_layers(&key, &output).await.unwrap();
+    cm.store_all_layers(&shared, &make_value(0)).await.unwrap();
+
+    let paths: Vec<String> = {
+        let mut paths: Vec<String> = storage.objects.lock().unwrap().keys().cloned().collect();
+        paths.sort();
+        paths
+    };
+    assert_eq!(paths, [format!("_shared/{}", shared.hash), format!("panchanga/v2/{}", key.hash)]);
+    // Nothing was written to the disk directory
+    assert!(std::fs::read_dir(tmp.path()).unwrap().next().is_none());
+
+    // Served from the backend by a manager that never held it in memory
+    let fresh = test_cache_manager(tmp.path().to_str().unwrap()).with_l3_storage(storage.clone());
+    assert_eq!(fresh.get(&key).await.unwrap(), Some(output));
+
+    fresh.invalidate_engine_version("panchanga", "2").await.unwrap();
+    let paths: Vec<String> = storage.objects.lock().unwrap().keys().cloned().collect();
+    assert_eq!(paths, [format!("_shared/{}", shared.hash)]);
+}
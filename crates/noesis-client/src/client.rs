@@ -0,0 +1,426 @@
+//! The API client and its builder
+
+use std::fmt;
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use futures::{Stream, StreamExt};
+use noesis_core::{EngineInput, EngineOutput, WorkflowResult};
+use reqwest::{header, Method, RequestBuilder, Response, StatusCode, Url};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use tracing::{debug, warn};
+
+use crate::error::{ClientError, Result};
+use crate::sse::{self, EventStream};
+use crate::types::*;
+
+/// Version of `/api/{version}` a new client talks to
+pub const DEFAULT_API_VERSION: &str = "v2";
+
+/// How to authenticate requests
+#[derive(Clone)]
+pub enum Credentials {
+    /// JWT from [`NoesisClient::login`], sent as `Authorization: Bearer`
+    Bearer(String),
+    /// API key, sent as `X-API-Key`
+    ApiKey(String),
+}
+
+impl fmt::Debug for Credentials {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Credentials::Bearer(_) => f.write_str("Bearer(***)"),
+            Credentials::ApiKey(_) => f.write_str("ApiKey(***)"),
+        }
+    }
+}
+
+/// How requests answered with 429 Too Many Requests are retried
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RetryPolicy {
+    /// Retries after the first attempt; 0 disables retrying
+    pub max_retries: u32,
+    /// Longest `Retry-After` honoured; a longer one fails at once with
+    /// [`ClientError::RateLimited`]
+    pub max_wait: Duration,
+    /// Wait when a 429 carries no usable `Retry-After`
+    pub default_wait: Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_retries: 3,
+            max_wait: Duration::from_secs(60),
+            default_wait: Duration::from_secs(1),
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Never retry
+    pub fn none() -> Self {
+        Self { max_retries: 0, ..Self::default() }
+    }
+}
+
+/// Builder for [`NoesisClient`]
+#[derive(Debug)]
+pub struct NoesisClientBuilder {
+    base_url: String,
+    api_version: String,
+    credentials: Option<Credentials>,
+    retry: RetryPolicy,
+    timeout: Duration,
+    http: Option<reqwest::Client>,
+}
+
+impl NoesisClientBuilder {
+    /// Path version to call, e.g. `"v1"`; defaults to [`DEFAULT_API_VERSION`]
+    pub fn api_version(mut self, version: impl Into<String>) -> Self {
+        self.api_version = version.into();
+        self
+    }
+
+    pub fn credentials(mut self, credentials: Credentials) -> Self {
+        self.credentials = Some(credentials);
+        self
+    }
+
+    pub fn bearer_token(self, token: impl Into<String>) -> Self {
+        self.credentials(Credentials::Bearer(token.into()))
+    }
+
+    pub fn api_key(self, key: impl Into<String>) -> Self {
+        self.credentials(Credentials::ApiKey(key.into()))
+    }
+
+    pub fn retry_policy(mut self, retry: RetryPolicy) -> Self {
+        self.retry = retry;
+        self
+    }
+
+    /// Timeout of each request (default 30 seconds); event streams are not
+    /// subject to it
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Send requests through this `reqwest` client, e.g. to share its
+    /// connection pool or set a proxy
+    pub fn http_client(mut self, http: reqwest::Client) -> Self {
+        self.http = Some(http);
+        self
+    }
+
+    pub fn build(self) -> Result<NoesisClient> {
+        let base_url = self.base_url.trim_end_matches('/').to_string();
+        match Url::parse(&base_url) {
+            Ok(url) if matches!(url.scheme(), "http" | "https") => {}
+            _ => return Err(ClientError::InvalidBaseUrl(self.base_url)),
+        }
+
+        let http = match self.http {
+            Some(http) => http,
+            None => reqwest::Client::builder()
+                .user_agent(format!("noesis-client/{}", env!("CARGO_PKG_VERSION")))
+                .build()?,
+        };
+
+        Ok(NoesisClient {
+            http,
+            base_url,
+            api_version: self.api_version,
+            credentials: self.credentials,
+            retry: self.retry,
+            timeout: self.timeout,
+        })
+    }
+}
+
+/// Async client for the Noesis HTTP API.
+///
+/// Cloning is cheap and shares the connection pool.
+#[derive(Debug, Clone)]
+pub struct NoesisClient {
+    http: reqwest::Client,
+    base_url: String,
+    api_version: String,
+    credentials: Option<Credentials>,
+    retry: RetryPolicy,
+    timeout: Duration,
+}
+
+impl NoesisClient {
+    /// Client for the API at `base_url` (e.g. `https://api.selemene.io`)
+    /// with default settings and no credentials
+    pub fn new(base_url: impl Into<String>) -> Result<Self> {
+        Self::builder(base_url).build()
+    }
+
+    pub fn builder(base_url: impl Into<String>) -> NoesisClientBuilder {
+        NoesisClientBuilder {
+            base_url: base_url.into(),
+            api_version: DEFAULT_API_VERSION.to_string(),
+            credentials: None,
+            retry: RetryPolicy::default(),
+            timeout: Duration::from_secs(30),
+            http: None,
+        }
+    }
+
+    /// A copy of this client authenticating with `credentials`
+    pub fn with_credentials(&self, credentials: Credentials) -> Self {
+        Self { credentials: Some(credentials), ..self.clone() }
+    }
+
+    pub fn api_version(&self) -> &str {
+        &self.api_version
+    }
+
+    // ==================== ENGINES ====================
+
+    /// Ids of the registered engines
+    pub async fn list_engines(&self) -> Result<Vec<String>> {
+        let list: EngineList = self.get("/engines").await?;
+        Ok(list.engines)
+    }
+
+    /// Run one engine
+    pub async fn calculate(&self, engine_id: &str, input: &EngineInput) -> Result<EngineOutput> {
+        self.post(&format!("/engines/{}/calculate", engine_id), input).await
+    }
+
+    // ==================== WORKFLOWS ====================
+
+    pub async fn list_workflows(&self) -> Result<Vec<WorkflowSummary>> {
+        let list: WorkflowList = self.get("/workflows").await?;
+        Ok(list.workflows)
+    }
+
+    /// Run every engine of a workflow and synthesize their results
+    pub async fn execute_workflow(&self, workflow_id: &str, input: &EngineInput) -> Result<WorkflowResult> {
+        self.post(&format!("/workflows/{}/execute", workflow_id), input).await
+    }
+
+    // ==================== AUTH ====================
+
+    pub async fn register(&self, request: &RegisterRequest) -> Result<RegisterResponse> {
+        self.post("/auth/register", request).await
+    }
+
+    /// Exchange email and password for a JWT; pass
+    /// `Credentials::Bearer(response.token)` to [`with_credentials`](Self::with_credentials)
+    pub async fn login(&self, email: &str, password: &str) -> Result<LoginResponse> {
+        let request = LoginRequest { email: email.to_string(), password: password.to_string() };
+        self.post("/auth/login", &request).await
+    }
+
+    pub async fn forgot_password(&self, email: &str) -> Result<MessageResponse> {
+        self.post("/auth/forgot-password", &serde_json::json!({ "email": email })).await
+    }
+
+    pub async fn reset_password(&self, token: &str, new_password: &str) -> Result<MessageResponse> {
+        let request = serde_json::json!({ "token": token, "new_password": new_password });
+        self.post("/auth/reset-password", &request).await
+    }
+
+    // ==================== PROFILES ====================
+
+    /// The authenticated user's profile
+    pub async fn me(&self) -> Result<User> {
+        self.get("/users/me").await
+    }
+
+    pub async fn update_me(&self, update: &UpdateUserRequest) -> Result<User> {
+        self.json(self.request(Method::PATCH, &self.versioned("/users/me")).json(update)).await
+    }
+
+    pub async fn list_locations(&self) -> Result<Vec<SavedLocation>> {
+        let list: SavedLocationList = self.get("/users/me/locations").await?;
+        Ok(list.locations)
+    }
+
+    pub async fn create_location(&self, request: &CreateSavedLocationRequest) -> Result<SavedLocation> {
+        self.post("/users/me/locations", request).await
+    }
+
+    pub async fn delete_location(&self, id: &str) -> Result<()> {
+        let url = self.versioned(&format!("/users/me/locations/{}", id));
+        self.send(self.request(Method::DELETE, &url)).await?;
+        Ok(())
+    }
+
+    // ==================== CLIENT PROFILES ====================
+
+    /// Clients of every organization the caller belongs to
+    pub async fn list_clients(&self) -> Result<Vec<ClientProfile>> {
+        let list: ClientProfileList = self.get("/clients").await?;
+        Ok(list.clients)
+    }
+
+    pub async fn create_client(&self, request: &CreateClientRequest) -> Result<ClientProfile> {
+        self.post("/clients", request).await
+    }
+
+    pub async fn get_client(&self, id: &str) -> Result<ClientProfile> {
+        self.get(&format!("/clients/{}", id)).await
+    }
+
+    pub async fn update_consent(&self, id: &str, consent: &ConsentUpdate) -> Result<ClientProfile> {
+        let url = self.versioned(&format!("/clients/{}/consent", id));
+        self.json(self.request(Method::PATCH, &url).json(consent)).await
+    }
+
+    /// Run a workflow with a client's birth data
+    pub async fn execute_client_workflow(
+        &self,
+        client_id: &str,
+        workflow_id: &str,
+        request: &ClientWorkflowRequest,
+    ) -> Result<WorkflowResult> {
+        self.post(&format!("/clients/{}/workflows/{}/execute", client_id, workflow_id), request).await
+    }
+
+    // ==================== STREAMS ====================
+
+    /// Ghati time at a location, one tick per pala (about 24 seconds).
+    ///
+    /// `error` events the server sends while it retries become
+    /// [`ClientError::Stream`] items; the stream goes on after them.
+    pub async fn ghati_stream(
+        &self,
+        latitude: f64,
+        longitude: f64,
+    ) -> Result<impl Stream<Item = Result<GhatiTick>>> {
+        let path = format!("/api/legacy/ghati/stream?latitude={}&longitude={}", latitude, longitude);
+        let events = self.events(&path).await?;
+        Ok(events.map(|event| {
+            let event = event?;
+            match event.event.as_str() {
+                "error" => Err(ClientError::Stream(event.data)),
+                _ => event.json(),
+            }
+        }))
+    }
+
+    /// Open any `text/event-stream` route; `path` starts at the host, e.g.
+    /// `/api/legacy/ghati/stream`
+    pub async fn events(&self, path: &str) -> Result<EventStream> {
+        let url = format!("{}{}", self.base_url, path);
+        let request = self
+            .authenticate(self.http.request(Method::GET, &url))
+            .header(header::ACCEPT, "text/event-stream");
+        Ok(sse::event_stream(self.send(request).await?))
+    }
+
+    // ==================== TRANSPORT ====================
+
+    fn versioned(&self, route: &str) -> String {
+        format!("{}/api/{}{}", self.base_url, self.api_version, route)
+    }
+
+    fn authenticate(&self, request: RequestBuilder) -> RequestBuilder {
+        match &self.credentials {
+            Some(Credentials::Bearer(token)) => request.bearer_auth(token),
+            Some(Credentials::ApiKey(key)) => request.header("X-API-Key", key),
+            None => request,
+        }
+    }
+
+    fn request(&self, method: Method, url: &str) -> RequestBuilder {
+        debug!("Building {} request to {}", method, url);
+        self.authenticate(self.http.request(method, url))
+            .header(header::ACCEPT, "application/json")
+            .timeout(self.timeout)
+    }
+
+    async fn get<T: DeserializeOwned>(&self, route: &str) -> Result<T> {
+        self.json(self.request(Method::GET, &self.versioned(route))).await
+    }
+
+    async fn post<B: Serialize + ?Sized, T: DeserializeOwned>(&self, route: &str, body: &B) -> Result<T> {
+        self.json(self.request(Method::POST, &self.versioned(route)).json(body)).await
+    }
+
+    async fn json<T: DeserializeOwned>(&self, request: RequestBuilder) -> Result<T> {
+        let body = self.send(request).await?.bytes().await?;
+        Ok(serde_json::from_slice(&body)?)
+    }
+
+    /// Send `request`, retrying on 429 as the [`RetryPolicy`] allows, and
+    /// turn error statuses into [`ClientError::Api`]
+    async fn send(&self, request: RequestBuilder) -> Result<Response> {
+        let mut retries = 0;
+        loop {
+            // Bodies built here are all buffered, so cloning only fails for
+            // requests that can't be retried anyway
+            let Some(attempt) = request.try_clone() else {
+                return error_for_status(request.send().await?).await;
+            };
+            let response = attempt.send().await?;
+            if response.status() != StatusCode::TOO_MANY_REQUESTS {
+                return error_for_status(response).await;
+            }
+
+            let retry_after = retry_after(response.headers());
+            let wait = retry_after.unwrap_or(self.retry.default_wait);
+            if retries >= self.retry.max_retries || wait > self.retry.max_wait {
+                return Err(ClientError::RateLimited { retry_after });
+            }
+            retries += 1;
+            warn!("Rate limited (429); retry {} of {} in {:?}", retries, self.retry.max_retries, wait);
+            tokio::time::sleep(wait).await;
+        }
+    }
+}
+
+async fn error_for_status(response: Response) -> Result<Response> {
+    let status = response.status();
+    if status.is_success() {
+        return Ok(response);
+    }
+    let body = response.text().await.unwrap_or_default();
+    Err(ClientError::from_body(status.as_u16(), &body))
+}
+
+/// `Retry-After` as delay-seconds or an HTTP date
+fn retry_after(headers: &header::HeaderMap) -> Option<Duration> {
+    let value = headers.get(header::RETRY_AFTER)?.to_str().ok()?.trim();
+    if let Ok(seconds) = value.parse::<u64>() {
+        return Some(Duration::from_secs(seconds));
+    }
+    let at = DateTime::parse_from_rfc2822(value).ok()?.with_timezone(&Utc);
+    Some((at - Utc::now()).to_std().unwrap_or(Duration::ZERO))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_retry_after_forms() {
+        let mut headers = header::HeaderMap::new();
+        assert_eq!(retry_after(&headers), None);
+
+        headers.insert(header::RETRY_AFTER, "7".parse().unwrap());
+        assert_eq!(retry_after(&headers), Some(Duration::from_secs(7)));
+
+        headers.insert(header::RETRY_AFTER, "Wed, 21 Oct 2015 07:28:00 GMT".parse().unwrap());
+        assert_eq!(retry_after(&headers), Some(Duration::ZERO));
+
+        headers.insert(header::RETRY_AFTER, "soon".parse().unwrap());
+        assert_eq!(retry_after(&headers), None);
+    }
+
+    #[test]
+    fn test_builder_rejects_bad_base_url() {
+        assert!(matches!(NoesisClient::new("localhost:8080"), Err(ClientError::InvalidBaseUrl(_))));
+
+        let client = NoesisClient::builder("http://localhost:8080/").api_key("nk_test").build().unwrap();
+        assert_eq!(client.versioned("/engines"), "http://localhost:8080/api/v2/engines");
+        assert!(!format!("{:?}", client).contains("nk_test"));
+    }
+}
@@ -0,0 +1,119 @@
+//! Errors returned by [`NoesisClient`](crate::NoesisClient)
+
+use std::time::Duration;
+
+use serde::Deserialize;
+use thiserror::Error;
+
+/// Result type alias for client operations
+pub type Result<T> = std::result::Result<T, ClientError>;
+
+/// Errors that can occur calling the API
+#[derive(Debug, Error)]
+pub enum ClientError {
+    /// The request could not be sent or its response not read
+    #[error("HTTP error: {0}")]
+    Http(#[from] reqwest::Error),
+
+    /// The API answered with an error status
+    #[error("API error {status} ({error_code}): {message}")]
+    Api {
+        status: u16,
+        /// Stable machine-readable code, e.g. `VALIDATION_ERROR`
+        error_code: String,
+        message: String,
+        details: Option<serde_json::Value>,
+        /// `X-Request-Id` of the failed request, for support tickets
+        request_id: Option<String>,
+    },
+
+    /// Still rate limited after the configured retries, or asked to wait
+    /// longer than [`RetryPolicy::max_wait`](crate::RetryPolicy::max_wait)
+    #[error("Rate limited; retry after {retry_after:?}")]
+    RateLimited { retry_after: Option<Duration> },
+
+    /// The server reported an error inside an event stream
+    #[error("Stream error: {0}")]
+    Stream(String),
+
+    /// A response body did not have the expected shape
+    #[error("Invalid response body: {0}")]
+    Decode(#[from] serde_json::Error),
+
+    /// The configured base URL cannot be used
+    #[error("Invalid base URL '{0}'")]
+    InvalidBaseUrl(String),
+}
+
+impl ClientError {
+    /// HTTP status of an API error
+    pub fn status(&self) -> Option<u16> {
+        match self {
+            ClientError::Api { status, .. } => Some(*status),
+            ClientError::RateLimited { .. } => Some(429),
+            ClientError::Http(e) => e.status().map(|s| s.as_u16()),
+            _ => None,
+        }
+    }
+
+    /// Build an API error from a response body, which is either an RFC 9457
+    /// problem document or the legacy `ErrorResponse`
+    pub(crate) fn from_body(status: u16, body: &str) -> Self {
+        match serde_json::from_str::<ErrorBody>(body) {
+            Ok(parsed) => ClientError::Api {
+                status,
+                error_code: parsed.error_code.unwrap_or_else(|| "UNKNOWN".to_string()),
+                message: parsed.detail.unwrap_or_default(),
+                details: parsed.details,
+                request_id: parsed.request_id,
+            },
+            Err(_) => ClientError::Api {
+                status,
+                error_code: "UNKNOWN".to_string(),
+                message: body.to_string(),
+                details: None,
+                request_id: None,
+            },
+        }
+    }
+}
+
+/// The members problem documents and `ErrorResponse` bodies share
+#[derive(Deserialize)]
+struct ErrorBody {
+    /// `detail` of a problem document, `error` of an `ErrorResponse`
+    #[serde(alias = "error")]
+    detail: Option<String>,
+    error_code: Option<String>,
+    details: Option<serde_json::Value>,
+    request_id: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parses_both_error_shapes() {
+        let problem = r#"{"type":"https://docs.selemene.io/problems/validation-error","title":"Unprocessable Entity",
+            "status":422,"detail":"latitude out of range","error_code":"VALIDATION_ERROR","request_id":"abc"}"#;
+        let legacy = r#"{"error":"latitude out of range","error_code":"VALIDATION_ERROR","details":{"field":"latitude"}}"#;
+
+        for body in [problem, legacy] {
+            match ClientError::from_body(422, body) {
+                ClientError::Api { status, error_code, message, .. } => {
+                    assert_eq!(status, 422);
+                    assert_eq!(error_code, "VALIDATION_ERROR");
+                    assert_eq!(message, "latitude out of range");
+                }
+                other => panic!("unexpected {other:?}"),
+            }
+        }
+
+        let ClientError::Api { message, error_code, .. } = ClientError::from_body(502, "Bad Gateway") else {
+            panic!("expected an API error");
+        };
+        assert_eq!(message, "Bad Gateway");
+        assert_eq!(error_code, "UNKNOWN");
+    }
+}
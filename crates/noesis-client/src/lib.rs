@@ -0,0 +1,35 @@
+//! Async Rust client for the Tryambakam Noesis HTTP API
+//!
+//! [`NoesisClient`] has a typed method per route for engine calculations,
+//! workflows, auth, and user and client profiles, and streams Server-Sent
+//! Events routes as [`futures::Stream`]s. Requests answered with 429 Too Many
+//! Requests are retried after the server's `Retry-After`, as the
+//! [`RetryPolicy`] allows; other errors come back as [`ClientError::Api`]
+//! with the server's `error_code`, whichever error format the API version
+//! uses.
+//!
+//! ```no_run
+//! use noesis_client::{Credentials, NoesisClient};
+//! use noesis_core::EngineInput;
+//!
+//! # async fn run(input: EngineInput) -> noesis_client::Result<()> {
+//! let client = NoesisClient::new("https://api.selemene.io")?;
+//! let login = client.login("seeker@example.com", "correct horse").await?;
+//! let client = client.with_credentials(Credentials::Bearer(login.token));
+//!
+//! let output = client.calculate("numerology", &input).await?;
+//! println!("{}", output.result);
+//! # Ok(())
+//! # }
+//! ```
+
+pub mod client;
+pub mod error;
+pub mod sse;
+pub mod types;
+
+pub use client::{Credentials, NoesisClient, NoesisClientBuilder, RetryPolicy, DEFAULT_API_VERSION};
+pub use error::{ClientError, Result};
+pub use noesis_core::{EngineInput, EngineOutput, WorkflowResult};
+pub use sse::{EventStream, SseEvent};
+pub use types::*;
@@ -0,0 +1,149 @@
+//! Server-Sent Events streams
+//!
+//! [`NoesisClient::events`](crate::NoesisClient::events) reads a
+//! `text/event-stream` response as it arrives and yields one [`SseEvent`]
+//! per event. Comments (the server's keep-alives) are skipped. Lines may end
+//! in `\n` or `\r\n`.
+
+use std::collections::VecDeque;
+use std::pin::Pin;
+
+use futures::Stream;
+use serde::de::DeserializeOwned;
+
+use crate::error::{ClientError, Result};
+
+/// A stream of Server-Sent Events; it ends when the server closes the response
+pub type EventStream = Pin<Box<dyn Stream<Item = Result<SseEvent>> + Send>>;
+
+/// One Server-Sent Event
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SseEvent {
+    /// `event:` field, `message` when the server sent none
+    pub event: String,
+    /// `data:` lines joined with `\n`
+    pub data: String,
+    /// `id:` field, if any
+    pub id: Option<String>,
+}
+
+impl SseEvent {
+    /// Deserialize the event's data as JSON
+    pub fn json<T: DeserializeOwned>(&self) -> Result<T> {
+        Ok(serde_json::from_str(&self.data)?)
+    }
+}
+
+/// Incremental `text/event-stream` parser
+#[derive(Debug, Default)]
+pub(crate) struct SseParser {
+    /// Bytes of a line not yet terminated
+    partial: Vec<u8>,
+    event: Option<String>,
+    data: Option<String>,
+    id: Option<String>,
+}
+
+impl SseParser {
+    /// Consume the next chunk of the body, returning the events it completed
+    pub(crate) fn feed(&mut self, chunk: &[u8]) -> Vec<SseEvent> {
+        let mut events = Vec::new();
+        for &byte in chunk {
+            if byte != b'\n' {
+                self.partial.push(byte);
+                continue;
+            }
+            let mut line = std::mem::take(&mut self.partial);
+            if line.last() == Some(&b'\r') {
+                line.pop();
+            }
+            if let Some(event) = self.line(&String::from_utf8_lossy(&line)) {
+                events.push(event);
+            }
+        }
+        events
+    }
+
+    fn line(&mut self, line: &str) -> Option<SseEvent> {
+        if line.is_empty() {
+            return self.dispatch();
+        }
+        if line.starts_with(':') {
+            return None;
+        }
+
+        let (field, value) = match line.split_once(':') {
+            Some((field, value)) => (field, value.strip_prefix(' ').unwrap_or(value)),
+            None => (line, ""),
+        };
+        match field {
+            "event" => self.event = Some(value.to_string()),
+            "data" => match &mut self.data {
+                Some(data) => {
+                    data.push('\n');
+                    data.push_str(value);
+                }
+                None => self.data = Some(value.to_string()),
+            },
+            "id" => self.id = Some(value.to_string()),
+            // `retry` and unknown fields don't change what the client sees
+            _ => {}
+        }
+        None
+    }
+
+    /// End of an event; one without data is dropped, as browsers do
+    fn dispatch(&mut self) -> Option<SseEvent> {
+        let event = self.event.take();
+        let data = self.data.take()?;
+        Some(SseEvent {
+            event: event.unwrap_or_else(|| "message".to_string()),
+            data,
+            id: self.id.clone(),
+        })
+    }
+}
+
+/// Turn a `text/event-stream` response into a stream of its events
+pub(crate) fn event_stream(response: reqwest::Response) -> EventStream {
+    let state = (response, SseParser::default(), VecDeque::new());
+    Box::pin(futures::stream::unfold(Some(state), |state| async move {
+        let (mut response, mut parser, mut pending) = state?;
+        loop {
+            if let Some(event) = pending.pop_front() {
+                return Some((Ok(event), Some((response, parser, pending))));
+            }
+            match response.chunk().await {
+                Ok(Some(chunk)) => pending.extend(parser.feed(&chunk)),
+                Ok(None) => return None,
+                Err(e) => return Some((Err(ClientError::Http(e)), None)),
+            }
+        }
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parses_events_split_across_chunks() {
+        let mut parser = SseParser::default();
+        let mut events = parser.feed(b": keep-alive\n\nevent: pala\ndata: {\"pala\"");
+        assert!(events.is_empty());
+
+        events.extend(parser.feed(b":1}\r\n\r\ndata: first\ndata:second\nid: 7\n\n"));
+        assert_eq!(
+            events,
+            vec![
+                SseEvent { event: "pala".into(), data: "{\"pala\":1}".into(), id: None },
+                SseEvent { event: "message".into(), data: "first\nsecond".into(), id: Some("7".into()) },
+            ]
+        );
+        assert_eq!(events[0].json::<serde_json::Value>().unwrap()["pala"], 1);
+
+        // The id carries over to later events; an event without data is dropped
+        let events = parser.feed(b"event: ghati\n\ndata\n\n");
+        assert_eq!(events, vec![SseEvent { event: "message".into(), data: "".into(), id: Some("7".into()) }]);
+    }
+}
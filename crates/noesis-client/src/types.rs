@@ -0,0 +1,227 @@
+//! Request and response bodies of the API's account, profile and workflow
+//! routes
+//!
+//! Calculation bodies are the shared `noesis_core` types ([`EngineInput`],
+//! [`EngineOutput`], [`WorkflowResult`]), re-exported from the crate root.
+//!
+//! [`EngineInput`]: noesis_core::EngineInput
+//! [`EngineOutput`]: noesis_core::EngineOutput
+//! [`WorkflowResult`]: noesis_core::WorkflowResult
+
+use std::collections::HashMap;
+
+use chrono::{DateTime, NaiveDate, NaiveTime, Utc};
+use noesis_core::Precision;
+use serde::{Deserialize, Serialize};
+
+// ---------------------------------------------------------------------------
+// Auth
+// ---------------------------------------------------------------------------
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RegisterRequest {
+    pub email: String,
+    pub password: String,
+    pub full_name: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RegisterResponse {
+    pub id: String,
+    pub message: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LoginRequest {
+    pub email: String,
+    pub password: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LoginResponse {
+    /// JWT to send as the bearer token
+    pub token: String,
+    pub user_id: String,
+    pub email: String,
+    pub tier: String,
+}
+
+/// Reply of the password reset routes
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MessageResponse {
+    pub message: String,
+}
+
+// ---------------------------------------------------------------------------
+// Profiles
+// ---------------------------------------------------------------------------
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Location {
+    pub lat: f64,
+    pub lng: f64,
+    pub name: Option<String>,
+}
+
+/// The authenticated user with their birth profile
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct User {
+    pub id: String,
+    pub email: String,
+    pub full_name: String,
+    pub tier: String,
+    pub consciousness_level: i32,
+    pub experience_points: i32,
+    pub birth_date: Option<NaiveDate>,
+    pub birth_time: Option<NaiveTime>,
+    pub birth_location: Option<Location>,
+    pub timezone: Option<String>,
+    pub preferences: serde_json::Value,
+}
+
+/// Changes to the authenticated user; `None` fields are left unchanged
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct UpdateUserRequest {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub full_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub birth_date: Option<NaiveDate>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub birth_time: Option<NaiveTime>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub birth_location_lat: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub birth_location_lng: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub birth_location_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timezone: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub preferences: Option<serde_json::Value>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateSavedLocationRequest {
+    pub name: String,
+    pub latitude: f64,
+    pub longitude: f64,
+    /// IANA timezone; resolved from the coordinates when omitted
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timezone: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SavedLocation {
+    pub id: String,
+    pub name: String,
+    pub latitude: f64,
+    pub longitude: f64,
+    pub timezone: String,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct SavedLocationList {
+    pub locations: Vec<SavedLocation>,
+}
+
+// ---------------------------------------------------------------------------
+// Client profiles
+// ---------------------------------------------------------------------------
+
+/// Consent flags; `None` flags are left unchanged (false for a new client)
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ConsentUpdate {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub calculations: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sharing: Option<bool>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Consent {
+    pub calculations: bool,
+    pub sharing: bool,
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateClientRequest {
+    /// Organization the caller belongs to
+    pub organization_id: String,
+    pub full_name: String,
+    pub birth_date: NaiveDate,
+    pub birth_time: Option<NaiveTime>,
+    pub birth_location_lat: f64,
+    pub birth_location_lng: f64,
+    pub birth_location_name: Option<String>,
+    pub timezone: String,
+    #[serde(default)]
+    pub consent: ConsentUpdate,
+}
+
+/// A practitioner's client and their birth profile
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ClientProfile {
+    pub id: String,
+    pub organization_id: String,
+    pub full_name: String,
+    pub birth_date: NaiveDate,
+    pub birth_time: Option<NaiveTime>,
+    pub birth_location: Location,
+    pub timezone: String,
+    pub consent: Consent,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct ClientProfileList {
+    pub clients: Vec<ClientProfile>,
+}
+
+/// Engine options for a workflow run on a client's behalf; birth data comes
+/// from the client profile
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ClientWorkflowRequest {
+    #[serde(default)]
+    pub options: HashMap<String, serde_json::Value>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub precision: Option<Precision>,
+}
+
+// ---------------------------------------------------------------------------
+// Engines and workflows
+// ---------------------------------------------------------------------------
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct EngineList {
+    pub engines: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WorkflowSummary {
+    pub id: String,
+    pub name: String,
+    pub description: String,
+    pub engine_count: usize,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct WorkflowList {
+    pub workflows: Vec<WorkflowSummary>,
+}
+
+/// One tick of `GET /api/legacy/ghati/stream`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GhatiTick {
+    pub ghati: u8,
+    pub pala: u8,
+    pub vipala: u8,
+    pub utc_timestamp: String,
+    /// Sunrise that began the current Vedic day
+    pub sunrise: String,
+    /// Sunrise that ends the current Vedic day
+    pub next_sunrise: String,
+}
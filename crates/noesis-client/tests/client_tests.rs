@@ -0,0 +1,163 @@
+//! Tests of the client against a mock API server
+
+use std::time::{Duration, Instant};
+
+use futures::StreamExt;
+use serde_json::json;
+use wiremock::matchers::{header, method, path, query_param};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+use noesis_client::{ClientError, EngineInput, NoesisClient, RetryPolicy};
+
+fn birth_input() -> EngineInput {
+    serde_json::from_value(json!({
+        "birth_data": { "date": "1990-01-15", "time": "14:30", "latitude": 12.9716, "longitude": 77.5946, "timezone": "Asia/Kolkata" },
+        "current_time": "2026-10-14T00:00:00Z",
+        "location": null,
+        "options": {}
+    }))
+    .unwrap()
+}
+
+fn engine_output() -> serde_json::Value {
+    json!({
+        "engine_id": "numerology",
+        "result": { "life_path": 7 },
+        "witness_prompt": "What are you seeking?",
+        "consciousness_level": 1,
+        "metadata": {
+            "calculation_time_ms": 1.5,
+            "backend": "native",
+            "precision_achieved": "Standard",
+            "cached": false,
+            "timestamp": "2026-10-14T00:00:00Z",
+            "engine_version": "1"
+        }
+    })
+}
+
+#[tokio::test]
+async fn test_calculate_returns_typed_output() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/api/v1/engines/numerology/calculate"))
+        .and(header("X-API-Key", "nk_test"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(engine_output()))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = NoesisClient::builder(server.uri()).api_version("v1").api_key("nk_test").build().unwrap();
+    let output = client.calculate("numerology", &birth_input()).await.unwrap();
+
+    assert_eq!(output.engine_id, "numerology");
+    assert_eq!(output.result["life_path"], 7);
+    assert_eq!(output.metadata.engine_version.as_deref(), Some("1"));
+}
+
+#[tokio::test]
+async fn test_429_is_retried_after_retry_after() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/api/v2/engines"))
+        .respond_with(ResponseTemplate::new(429).append_header("Retry-After", "1"))
+        .up_to_n_times(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/api/v2/engines"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "engines": ["numerology", "panchanga"] })))
+        .mount(&server)
+        .await;
+
+    let client = NoesisClient::new(server.uri()).unwrap();
+    let started = Instant::now();
+    let engines = client.list_engines().await.unwrap();
+
+    assert_eq!(engines, vec!["numerology", "panchanga"]);
+    assert!(started.elapsed() >= Duration::from_secs(1));
+    assert_eq!(server.received_requests().await.unwrap().len(), 2);
+}
+
+#[tokio::test]
+async fn test_errors_carry_the_server_error_code() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/api/v2/workflows"))
+        .respond_with(ResponseTemplate::new(429).append_header("Retry-After", "3600"))
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/api/v2/workflows/birth-blueprint/execute"))
+        .respond_with(
+            ResponseTemplate::new(422)
+                .insert_header("Content-Type", "application/problem+json")
+                .set_body_json(json!({
+                    "type": "https://docs.selemene.io/problems/validation-error",
+                    "title": "Unprocessable Entity",
+                    "status": 422,
+                    "detail": "birth_data.latitude must be between -90 and 90",
+                    "error_code": "VALIDATION_ERROR",
+                    "details": { "field": "birth_data.latitude" },
+                    "request_id": "req-1"
+                })),
+        )
+        .mount(&server)
+        .await;
+
+    let client = NoesisClient::new(server.uri()).unwrap();
+
+    // A wait beyond the policy's max_wait fails without retrying
+    let err = client.list_workflows().await.unwrap_err();
+    assert!(matches!(err, ClientError::RateLimited { retry_after: Some(d) } if d == Duration::from_secs(3600)));
+    assert_eq!(server.received_requests().await.unwrap().len(), 1);
+
+    let err = client.execute_workflow("birth-blueprint", &birth_input()).await.unwrap_err();
+    match err {
+        ClientError::Api { status, error_code, details, request_id, .. } => {
+            assert_eq!(status, 422);
+            assert_eq!(error_code, "VALIDATION_ERROR");
+            assert_eq!(details.unwrap()["field"], "birth_data.latitude");
+            assert_eq!(request_id.as_deref(), Some("req-1"));
+        }
+        other => panic!("unexpected {other:?}"),
+    }
+
+    // ClientError::status covers rate limiting too
+    let client = NoesisClient::builder(server.uri()).retry_policy(RetryPolicy::none()).build().unwrap();
+    assert_eq!(client.list_workflows().await.unwrap_err().status(), Some(429));
+}
+
+#[tokio::test]
+async fn test_ghati_stream_yields_ticks() {
+    let tick = |pala: u8| {
+        json!({
+            "ghati": 12, "pala": pala, "vipala": 0,
+            "utc_timestamp": "2026-10-14T06:00:00+00:00",
+            "sunrise": "2026-10-14T00:40:00+00:00",
+            "next_sunrise": "2026-10-15T00:40:00+00:00"
+        })
+    };
+    let body = format!(
+        "event: pala\ndata: {}\n\n:\n\nevent: error\ndata: ephemeris unavailable\n\nevent: pala\ndata: {}\n\n",
+        tick(3),
+        tick(4)
+    );
+
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/api/legacy/ghati/stream"))
+        .and(query_param("latitude", "12.9716"))
+        .and(header("Accept", "text/event-stream"))
+        .respond_with(ResponseTemplate::new(200).set_body_raw(body, "text/event-stream"))
+        .mount(&server)
+        .await;
+
+    let client = NoesisClient::new(server.uri()).unwrap();
+    let items: Vec<_> = client.ghati_stream(12.9716, 77.5946).await.unwrap().collect().await;
+
+    assert_eq!(items.len(), 3);
+    assert_eq!(items[0].as_ref().unwrap().pala, 3);
+    assert!(matches!(&items[1], Err(ClientError::Stream(message)) if message == "ephemeris unavailable"));
+    assert_eq!(items[2].as_ref().unwrap().pala, 4);
+}
@@ -0,0 +1,239 @@
+//! Unknown birth times
+//!
+//! Many people don't know when they were born. With
+//! [`BirthData::time_unknown`](crate::BirthData::time_unknown) set, or no
+//! `time` at all, engines read the chart at [`STAND_IN_TIME`] instead of
+//! failing, and engines whose result depends on the time say how much:
+//! they embed a [`TimeStability`] under [`TIME_STABILITY_KEY`], measured by
+//! recalculating across the birth day (see [`stability_across_day`]).
+//! [`ConsciousnessEngine::birth_time_sensitivity`](crate::ConsciousnessEngine::birth_time_sensitivity)
+//! says which engines those are, and a workflow collects every engine's
+//! confidence into a [`BirthTimeReport`].
+
+use std::collections::BTreeMap;
+
+use chrono::{NaiveTime, Timelike};
+use serde::{Deserialize, Serialize};
+
+use crate::{BirthData, EngineError, EngineInput, EngineOutput};
+
+#[cfg(feature = "openapi")]
+use utoipa::ToSchema;
+
+/// Local time a chart is read at when the birth time is unknown
+pub const STAND_IN_TIME: &str = "12:00";
+
+/// Key under which engines embed their [`TimeStability`] in their result JSON
+pub const TIME_STABILITY_KEY: &str = "time_stability";
+
+/// How much of an engine's result the birth time decides
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum TimeSensitivity {
+    /// The date alone decides the result (numerology, biorhythm)
+    Insensitive,
+    /// Some values can change during the day (the birth panchanga)
+    Partial,
+    /// The chart turns on the hour (Human Design, Vimshottari)
+    Decisive,
+}
+
+/// How a result read at [`STAND_IN_TIME`] holds across the birth day
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct TimeStability {
+    /// The local time the result was read at
+    #[cfg_attr(feature = "openapi", schema(example = "12:00"))]
+    pub assumed_time: String,
+    /// Share of the birth day, 0 to 1, over which the engine's headline
+    /// reading (e.g. Human Design type, authority, profile and definition)
+    /// is the one returned
+    #[cfg_attr(feature = "openapi", schema(example = 0.62))]
+    pub confidence: f64,
+    /// Result fields that differ at other times of the birth day
+    #[cfg_attr(feature = "openapi", schema(example = json!(["authority", "personality_activations"])))]
+    pub varies: Vec<String>,
+}
+
+impl TimeStability {
+    /// Stability of a result read at [`STAND_IN_TIME`]
+    pub fn at_noon(confidence: f64, varies: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        Self {
+            assumed_time: STAND_IN_TIME.to_string(),
+            confidence,
+            varies: varies.into_iter().map(Into::into).collect(),
+        }
+    }
+
+    /// The stability an engine embedded in `output`, if any
+    pub fn of(output: &EngineOutput) -> Option<Self> {
+        output
+            .result
+            .get(TIME_STABILITY_KEY)
+            .and_then(|value| serde_json::from_value(value.clone()).ok())
+    }
+}
+
+/// One engine's entry in a [`BirthTimeReport`]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct EngineTimeConfidence {
+    pub sensitivity: TimeSensitivity,
+    /// The engine's [`TimeStability::confidence`]; 1 for insensitive engines
+    pub confidence: f64,
+}
+
+/// Per-engine confidence of a workflow run on a birth time that is unknown
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct BirthTimeReport {
+    #[cfg_attr(feature = "openapi", schema(example = "12:00"))]
+    pub assumed_time: String,
+    /// By engine output key
+    pub engines: BTreeMap<String, EngineTimeConfidence>,
+    /// The lowest engine confidence
+    pub confidence: f64,
+}
+
+impl BirthTimeReport {
+    /// Report on `outputs` when a birth time of `input` is unknown.
+    /// `sensitivity` looks an output key's engine up.
+    pub fn for_outputs<'a>(
+        input: &EngineInput,
+        outputs: impl IntoIterator<Item = (&'a String, &'a EngineOutput)>,
+        sensitivity: impl Fn(&str) -> TimeSensitivity,
+    ) -> Option<Self> {
+        let unknown = [input.birth_data.as_ref(), input.secondary_birth_data.as_ref()]
+            .into_iter()
+            .flatten()
+            .chain(input.subjects.iter().map(|subject| &subject.birth_data))
+            .any(BirthData::time_is_unknown);
+        if !unknown {
+            return None;
+        }
+        let engines: BTreeMap<String, EngineTimeConfidence> = outputs
+            .into_iter()
+            .map(|(key, output)| {
+                let confidence = TimeStability::of(output).map_or(1.0, |stability| stability.confidence);
+                (
+                    key.clone(),
+                    EngineTimeConfidence {
+                        sensitivity: sensitivity(key),
+                        confidence,
+                    },
+                )
+            })
+            .collect();
+        let confidence = engines.values().map(|engine| engine.confidence).fold(1.0, f64::min);
+        Some(Self {
+            assumed_time: STAND_IN_TIME.to_string(),
+            engines,
+            confidence,
+        })
+    }
+}
+
+impl BirthData {
+    /// Whether the birth time is unknown: flagged, or simply missing
+    pub fn time_is_unknown(&self) -> bool {
+        self.time_unknown || self.time.is_none()
+    }
+
+    /// `time`, or [`STAND_IN_TIME`] when it is unknown
+    pub fn time_or_stand_in(&self) -> &str {
+        self.time.as_deref().unwrap_or(STAND_IN_TIME)
+    }
+}
+
+/// Share of the birth day over which `read` gives what it gives at
+/// [`STAND_IN_TIME`], sampling local times every `step_minutes` from 00:00
+/// through 23:59
+pub fn stability_across_day<T, F>(step_minutes: u32, mut read: F) -> Result<f64, EngineError>
+where
+    T: PartialEq,
+    F: FnMut(NaiveTime) -> Result<T, EngineError>,
+{
+    let noon = NaiveTime::parse_from_str(STAND_IN_TIME, "%H:%M").expect("stand-in time parses");
+    let reference = read(noon)?;
+    let mut samples = 0u32;
+    let mut matching = 0u32;
+    for minute in (0..24 * 60).step_by(step_minutes.max(1) as usize) {
+        let time = NaiveTime::from_num_seconds_from_midnight_opt(minute * 60, 0).expect("minute of the day");
+        samples += 1;
+        if time.num_seconds_from_midnight() == noon.num_seconds_from_midnight() || read(time)? == reference {
+            matching += 1;
+        }
+    }
+    Ok(f64::from(matching) / f64::from(samples))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{CalculationMetadata, Precision};
+    use chrono::Utc;
+    use serde_json::json;
+    use std::collections::HashMap;
+
+    fn output(result: serde_json::Value) -> EngineOutput {
+        EngineOutput {
+            engine_id: "test".to_string(),
+            result,
+            witness_prompt: String::new(),
+            summary: None,
+            consciousness_level: 0,
+            metadata: CalculationMetadata {
+                calculation_time_ms: 0.0,
+                backend: "native".to_string(),
+                precision_achieved: "Standard".to_string(),
+                cached: false,
+                timestamp: Utc::now(),
+                engine_version: None,
+                warnings: Vec::new(),
+                usage: None,
+            },
+        }
+    }
+
+    fn input(time: Option<&str>) -> EngineInput {
+        let mut birth_data = crate::examples::sample_birth_data();
+        birth_data.time = time.map(str::to_string);
+        EngineInput {
+            birth_data: Some(birth_data),
+            secondary_birth_data: None,
+            subjects: Vec::new(),
+            current_time: Utc::now(),
+            location: None,
+            precision: Precision::Standard,
+            options: HashMap::new(),
+        }
+    }
+
+    #[test]
+    fn test_stability_across_day() {
+        // Changes at 18:00: a quarter of the day reads differently
+        let stability = stability_across_day(60, |time| Ok(time.hour() < 18)).unwrap();
+        assert_eq!(stability, 0.75);
+        assert_eq!(stability_across_day(30, |_| Ok(())).unwrap(), 1.0);
+    }
+
+    #[test]
+    fn test_report_only_for_unknown_times() {
+        let stability = TimeStability::at_noon(0.5, ["authority", "personality_activations"]);
+        let outputs = HashMap::from([
+            ("human-design".to_string(), output(json!({ TIME_STABILITY_KEY: stability }))),
+            ("numerology".to_string(), output(json!({ "life_path": 7 }))),
+        ]);
+        let sensitivity = |key: &str| match key {
+            "human-design" => TimeSensitivity::Decisive,
+            _ => TimeSensitivity::Insensitive,
+        };
+
+        assert!(BirthTimeReport::for_outputs(&input(Some("14:30")), &outputs, sensitivity).is_none());
+        let report = BirthTimeReport::for_outputs(&input(None), &outputs, sensitivity).unwrap();
+        assert_eq!(report.confidence, 0.5);
+        assert_eq!(report.engines["numerology"].confidence, 1.0);
+        assert_eq!(report.engines["human-design"].sensitivity, TimeSensitivity::Decisive);
+    }
+}
@@ -0,0 +1,180 @@
+//! Wire casing policy
+//!
+//! Every JSON object key the platform emits is snake_case: engine results,
+//! metadata, workflow results and errors alike. Rust structs follow it through
+//! their field names and need no `rename_all`; enum values are data, not keys,
+//! and keep their own spelling ("Generator", "Sun"). Bridged TypeScript
+//! engines answer partly in camelCase, so the bridge rewrites their results
+//! with [`snake_case_keys`].
+//!
+//! camelCase stays accepted until [`CAMEL_CASE_SUNSET`]: request bodies are
+//! read through [`snake_case_keys`], [`EngineOutput::parse_as`] reads results
+//! stored before the rewrite, and API v1 adds [`camel_case_twins`] to bridged
+//! results so its clients keep finding the keys they know.
+//!
+//! [`EngineOutput::parse_as`]: crate::EngineOutput::parse_as
+
+use chrono::{DateTime, Utc};
+use serde_json::{Map, Value};
+
+/// 2027-10-14T00:00:00Z, with the sunset of API v1: after it camelCase
+/// request keys are no longer rewritten
+pub const CAMEL_CASE_SUNSET: i64 = 1_823_472_000;
+
+/// Whether camelCase request keys are still rewritten at `now`
+pub fn accepts_camel_case(now: DateTime<Utc>) -> bool {
+    now.timestamp() < CAMEL_CASE_SUNSET
+}
+
+/// `isReversed`-style keys: a lowercase start, then letters and digits with
+/// at least one uppercase. PascalCase keys such as planet names are data.
+pub fn is_camel_case(key: &str) -> bool {
+    key.starts_with(|c: char| c.is_ascii_lowercase())
+        && key.chars().all(|c| c.is_ascii_alphanumeric())
+        && key.chars().any(|c| c.is_ascii_uppercase())
+}
+
+/// `coreFear` -> `core_fear`, `natalHDChart` -> `natal_hd_chart`
+pub fn to_snake_case(key: &str) -> String {
+    let chars: Vec<char> = key.chars().collect();
+    let mut snake = String::with_capacity(key.len() + 4);
+    for (i, &c) in chars.iter().enumerate() {
+        if c.is_ascii_uppercase() && i > 0 {
+            let prev = chars[i - 1];
+            let ends_acronym = prev.is_ascii_uppercase() && chars.get(i + 1).is_some_and(|n| n.is_ascii_lowercase());
+            if prev.is_ascii_lowercase() || prev.is_ascii_digit() || ends_acronym {
+                snake.push('_');
+            }
+        }
+        snake.push(c.to_ascii_lowercase());
+    }
+    snake
+}
+
+/// `core_fear` -> `coreFear`
+pub fn to_camel_case(key: &str) -> String {
+    let mut camel = String::with_capacity(key.len());
+    let mut upper = false;
+    for c in key.chars() {
+        if c == '_' {
+            upper = !camel.is_empty();
+        } else if upper {
+            camel.push(c.to_ascii_uppercase());
+            upper = false;
+        } else {
+            camel.push(c);
+        }
+    }
+    camel
+}
+
+/// Whether any object key in `value` is camelCase
+pub fn has_camel_case_keys(value: &Value) -> bool {
+    match value {
+        Value::Object(map) => map.iter().any(|(key, v)| is_camel_case(key) || has_camel_case_keys(v)),
+        Value::Array(items) => items.iter().any(has_camel_case_keys),
+        _ => false,
+    }
+}
+
+/// Rename every camelCase object key in `value` to snake_case, at any depth.
+///
+/// Where both spellings are present the snake_case value wins. Returns the
+/// number of keys renamed or dropped.
+pub fn snake_case_keys(value: &mut Value) -> usize {
+    match value {
+        Value::Object(map) => {
+            let mut renamed = 0;
+            if map.keys().any(|key| is_camel_case(key)) {
+                let entries = std::mem::take(map);
+                let (camel, snake): (Vec<_>, Vec<_>) = entries.into_iter().partition(|(key, _)| is_camel_case(key));
+                *map = snake.into_iter().collect::<Map<String, Value>>();
+                for (key, v) in camel {
+                    renamed += 1;
+                    map.entry(to_snake_case(&key)).or_insert(v);
+                }
+            }
+            renamed + map.values_mut().map(snake_case_keys).sum::<usize>()
+        }
+        Value::Array(items) => items.iter_mut().map(snake_case_keys).sum(),
+        _ => 0,
+    }
+}
+
+/// Next to every multi-word snake_case key in `value`, add its camelCase
+/// spelling with the same value, at any depth
+pub fn camel_case_twins(value: &mut Value) {
+    match value {
+        Value::Object(map) => {
+            map.values_mut().for_each(camel_case_twins);
+            let twins: Vec<(String, Value)> = map
+                .iter()
+                .filter(|(key, _)| is_snake_case_words(key))
+                .map(|(key, v)| (to_camel_case(key), v.clone()))
+                .collect();
+            for (key, v) in twins {
+                map.entry(key).or_insert(v);
+            }
+        }
+        Value::Array(items) => items.iter_mut().for_each(camel_case_twins),
+        _ => {}
+    }
+}
+
+/// `core_fear`, but not `fear`, `_id` or `Sun_sign`
+fn is_snake_case_words(key: &str) -> bool {
+    key.contains('_')
+        && key.starts_with(|c: char| c.is_ascii_lowercase())
+        && key.chars().all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_key_conversions() {
+        assert!(is_camel_case("isReversed"));
+        assert!(!is_camel_case("is_reversed"));
+        assert!(!is_camel_case("Sun"));
+        assert!(!is_camel_case("human-design"));
+        assert!(!is_camel_case("The Fool"));
+        assert_eq!(to_snake_case("coreFear"), "core_fear");
+        assert_eq!(to_snake_case("natalHDChart"), "natal_hd_chart");
+        assert_eq!(to_snake_case("line2Name"), "line2_name");
+        assert_eq!(to_camel_case("core_fear"), "coreFear");
+        assert_eq!(to_camel_case("changing_lines"), "changingLines");
+        assert!(accepts_camel_case(DateTime::from_timestamp(CAMEL_CASE_SUNSET - 1, 0).unwrap()));
+        assert!(!accepts_camel_case(DateTime::from_timestamp(CAMEL_CASE_SUNSET, 0).unwrap()));
+    }
+
+    #[test]
+    fn test_snake_case_keys() {
+        let mut value = json!({
+            "birthData": { "date": "1990-01-15", "timezone": "UTC" },
+            "cards": [{ "name": "The Fool", "isReversed": false }],
+            "Sun": { "gateNumber": 41 },
+            "current_time": "2025-01-01T00:00:00Z",
+            "currentTime": "ignored"
+        });
+        assert_eq!(snake_case_keys(&mut value), 4);
+        assert_eq!(value["birth_data"]["date"], "1990-01-15");
+        assert_eq!(value["cards"][0]["is_reversed"], false);
+        assert_eq!(value["Sun"]["gate_number"], 41);
+        assert_eq!(value["current_time"], "2025-01-01T00:00:00Z");
+        assert!(value.get("currentTime").is_none());
+        assert!(!has_camel_case_keys(&value));
+        assert_eq!(snake_case_keys(&mut value), 0);
+    }
+
+    #[test]
+    fn test_camel_case_twins() {
+        let mut value = json!({ "changing_lines": [3], "cards": [{ "is_reversed": true, "name": "Tower" }] });
+        camel_case_twins(&mut value);
+        assert_eq!(value["changing_lines"], json!([3]));
+        assert_eq!(value["changingLines"], json!([3]));
+        assert_eq!(value["cards"][0]["isReversed"], true);
+        assert!(value["cards"][0].get("Name").is_none());
+    }
+}
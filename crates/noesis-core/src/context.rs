@@ -0,0 +1,135 @@
+//! Intermediate results shared between the engines of one execution
+//!
+//! A workflow runs several engines on the same birth moment, and Human
+//! Design, Gene Keys and Vimshottari would each derive the same planetary
+//! positions and natal chart for it. The orchestrator runs a workflow's
+//! engines inside [`scope`]; engines look up what they need with [`shared`],
+//! under a key naming the artifact and everything it depends on, so the
+//! first engine to need an artifact computes it and the others reuse it.
+//! Outside [`scope`] (a single engine calculation, or work handed to
+//! `tokio::spawn`) nothing is shared and [`shared`] just computes.
+
+use std::any::Any;
+use std::collections::HashMap;
+use std::future::Future;
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::{Arc, Mutex};
+
+type Slot = Arc<Mutex<Option<Arc<dyn Any + Send + Sync>>>>;
+
+#[derive(Default)]
+struct CalculationContext {
+    artifacts: Mutex<HashMap<String, Slot>>,
+    reused: AtomicU32,
+    computed: AtomicU32,
+}
+
+tokio::task_local! {
+    static CONTEXT: Arc<CalculationContext>;
+}
+
+/// How a [`scope`] used its artifacts
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ContextStats {
+    /// Lookups answered by an artifact another engine computed
+    pub reused: u32,
+    /// Artifacts computed
+    pub computed: u32,
+}
+
+/// Run `fut` with a fresh context, returning its output with how the
+/// context was used
+pub async fn scope<F: Future>(fut: F) -> (F::Output, ContextStats) {
+    let context = Arc::new(CalculationContext::default());
+    let output = CONTEXT.scope(context.clone(), fut).await;
+    let stats = ContextStats {
+        reused: context.reused.load(Ordering::Relaxed),
+        computed: context.computed.load(Ordering::Relaxed),
+    };
+    (output, stats)
+}
+
+/// The artifact stored under `key` in the current context, computing and
+/// storing it with `compute` if no engine has yet.
+///
+/// `key` must identify the artifact completely, e.g.
+/// `human-design:chart:<birth instant>:<design method>`. Errors are not
+/// stored, so the next lookup tries again.
+pub fn shared<T, E, F>(key: &str, compute: F) -> Result<T, E>
+where
+    T: Clone + Send + Sync + 'static,
+    F: FnOnce() -> Result<T, E>,
+{
+    let Ok(slot) = CONTEXT.try_with(|context| context.slot(key)) else {
+        return compute();
+    };
+    // Held while computing, so an engine needing the same artifact waits for
+    // it rather than computing it again
+    let mut artifact = slot.lock().unwrap_or_else(|e| e.into_inner());
+    if let Some(value) = artifact.as_ref().and_then(|value| value.downcast_ref::<T>()) {
+        let _ = CONTEXT.try_with(|context| context.reused.fetch_add(1, Ordering::Relaxed));
+        return Ok(value.clone());
+    }
+    let value = compute()?;
+    let _ = CONTEXT.try_with(|context| context.computed.fetch_add(1, Ordering::Relaxed));
+    if artifact.is_none() {
+        *artifact = Some(Arc::new(value.clone()));
+    }
+    Ok(value)
+}
+
+impl CalculationContext {
+    fn slot(&self, key: &str) -> Slot {
+        let mut artifacts = self.artifacts.lock().unwrap_or_else(|e| e.into_inner());
+        artifacts.entry(key.to_string()).or_default().clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_outside_scope_always_computes() {
+        let mut calls = 0;
+        for _ in 0..2 {
+            let value: Result<u32, ()> = shared("answer", || {
+                calls += 1;
+                Ok(42)
+            });
+            assert_eq!(value, Ok(42));
+        }
+        assert_eq!(calls, 2);
+    }
+
+    #[tokio::test]
+    async fn test_scope_computes_each_key_once() {
+        let calls = AtomicU32::new(0);
+        let compute = |value: u32| {
+            calls.fetch_add(1, Ordering::Relaxed);
+            Ok::<_, ()>(value)
+        };
+
+        let (values, stats) = scope(async {
+            (shared("chart:a", || compute(1)), shared("chart:a", || compute(2)), shared("chart:b", || compute(3)))
+        })
+        .await;
+
+        assert_eq!(values, (Ok(1), Ok(1), Ok(3)));
+        assert_eq!(calls.load(Ordering::Relaxed), 2);
+        assert_eq!(stats, ContextStats { reused: 1, computed: 2 });
+    }
+
+    #[tokio::test]
+    async fn test_errors_are_not_stored() {
+        let (values, stats) = scope(async {
+            let failed: Result<u32, &str> = shared("position", || Err("ephemeris unavailable"));
+            let retried: Result<u32, &str> = shared("position", || Ok(7));
+            (failed, retried)
+        })
+        .await;
+
+        assert_eq!(values, (Err("ephemeris unavailable"), Ok(7)));
+        assert_eq!(stats, ContextStats { reused: 0, computed: 1 });
+    }
+}
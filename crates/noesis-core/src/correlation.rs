@@ -0,0 +1,77 @@
+//! Request correlation IDs
+//!
+//! The API assigns every request an ID (taken from `X-Request-Id` when the
+//! client sends a usable one) and runs the handler inside [`scope`]. Anything
+//! awaited on that task can read it with [`current_request_id`]: the bridge
+//! forwards it to the TS engines and error bodies echo it back. Work handed to
+//! `tokio::spawn` leaves the task, so wrap it in [`propagate`] first.
+
+use std::future::Future;
+
+/// Header carrying the request ID, in both directions
+pub const REQUEST_ID_HEADER: &str = "x-request-id";
+
+/// Longest client-supplied request ID that is accepted as-is
+pub const MAX_REQUEST_ID_LEN: usize = 128;
+
+tokio::task_local! {
+    static REQUEST_ID: String;
+}
+
+/// Request ID of the request being served on this task, if any
+pub fn current_request_id() -> Option<String> {
+    REQUEST_ID.try_with(|id| id.clone()).ok()
+}
+
+/// Run `fut` with `request_id` as the current request ID
+pub async fn scope<F: Future>(request_id: String, fut: F) -> F::Output {
+    REQUEST_ID.scope(request_id, fut).await
+}
+
+/// Carry the current request ID (if any) into `fut`, for spawned background work
+pub fn propagate<F: Future>(fut: F) -> impl Future<Output = F::Output> {
+    let request_id = current_request_id();
+    async move {
+        match request_id {
+            Some(id) => REQUEST_ID.scope(id, fut).await,
+            None => fut.await,
+        }
+    }
+}
+
+/// Whether a client-supplied ID can be reused: 1 to [`MAX_REQUEST_ID_LEN`]
+/// visible ASCII characters, so it is safe to log and to send on in a header.
+pub fn is_valid_request_id(id: &str) -> bool {
+    !id.is_empty() && id.len() <= MAX_REQUEST_ID_LEN && id.bytes().all(|b| b.is_ascii_graphic())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn request_id_is_scoped_and_propagated() {
+        assert_eq!(current_request_id(), None);
+
+        scope("req-1".to_string(), async {
+            assert_eq!(current_request_id().as_deref(), Some("req-1"));
+            let spawned = tokio::spawn(propagate(async { current_request_id() }));
+            assert_eq!(spawned.await.unwrap().as_deref(), Some("req-1"));
+            let detached = tokio::spawn(async { current_request_id() });
+            assert_eq!(detached.await.unwrap(), None);
+        })
+        .await;
+
+        assert_eq!(current_request_id(), None);
+    }
+
+    #[test]
+    fn request_id_validation() {
+        assert!(is_valid_request_id("4f1c2a9e-7d3b-4c1e-9a8f-2b6d5e0c1a7f"));
+        assert!(is_valid_request_id("client:trace/42"));
+        assert!(!is_valid_request_id(""));
+        assert!(!is_valid_request_id("has space"));
+        assert!(!is_valid_request_id("line\nbreak"));
+        assert!(!is_valid_request_id(&"a".repeat(MAX_REQUEST_ID_LEN + 1)));
+    }
+}
@@ -0,0 +1,112 @@
+//! What an engine does, for the engine catalog
+//!
+//! Every engine describes itself through
+//! [`ConsciousnessEngine::describe`](crate::ConsciousnessEngine::describe):
+//! what it reads and returns, the traditions it draws on, what it can't do,
+//! and whether its results are calculated or simulated. The API serves the
+//! description at `GET /api/v1/engines/:id/info`, so the catalog shows users
+//! that the biofield and face-reading results are mock data.
+
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "openapi")]
+use utoipa::ToSchema;
+
+/// Whether an engine's results come from a real calculation
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum Implementation {
+    #[default]
+    Real,
+    /// Simulated results, seeded so they are reproducible, standing in for an
+    /// analysis that needs hardware or models the engine doesn't have yet
+    Mock,
+}
+
+/// One part of [`EngineInput`](crate::EngineInput) an engine reads
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct DescribedInput {
+    /// Path in the input, e.g. `birth_data.time` or `options.hd_gates`
+    #[cfg_attr(feature = "openapi", schema(example = "birth_data.time"))]
+    pub field: String,
+    pub required: bool,
+    #[cfg_attr(feature = "openapi", schema(example = "Birth time; read at noon when unknown"))]
+    pub description: String,
+}
+
+/// Catalog entry of an engine
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct EngineDescription {
+    /// A paragraph on what the engine reads and why
+    pub summary: String,
+    pub inputs: Vec<DescribedInput>,
+    /// What the result contains
+    pub outputs: String,
+    /// Systems and sources the readings come from
+    #[cfg_attr(feature = "openapi", schema(example = json!(["Vedic Jyotish"])))]
+    pub traditions: Vec<String>,
+    /// What the engine doesn't do or may get wrong
+    pub limitations: Vec<String>,
+    pub implementation: Implementation,
+}
+
+impl EngineDescription {
+    pub fn new(summary: impl Into<String>) -> Self {
+        Self {
+            summary: summary.into(),
+            ..Self::default()
+        }
+    }
+
+    pub fn with_input(mut self, field: impl Into<String>, required: bool, description: impl Into<String>) -> Self {
+        self.inputs.push(DescribedInput {
+            field: field.into(),
+            required,
+            description: description.into(),
+        });
+        self
+    }
+
+    pub fn with_outputs(mut self, outputs: impl Into<String>) -> Self {
+        self.outputs = outputs.into();
+        self
+    }
+
+    pub fn with_traditions(mut self, traditions: &[&str]) -> Self {
+        self.traditions = traditions.iter().map(|t| t.to_string()).collect();
+        self
+    }
+
+    pub fn with_limitation(mut self, limitation: impl Into<String>) -> Self {
+        self.limitations.push(limitation.into());
+        self
+    }
+
+    pub fn mock(mut self) -> Self {
+        self.implementation = Implementation::Mock;
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_description_serializes_for_the_catalog() {
+        let description = EngineDescription::new("Reads the face")
+            .with_input("options.image_url", false, "Reserved for image analysis")
+            .with_traditions(&["Mian Xiang"])
+            .with_limitation("No image is analyzed")
+            .mock();
+        let json = serde_json::to_value(&description).unwrap();
+        assert_eq!(json["implementation"], "mock");
+        assert_eq!(json["inputs"][0]["field"], "options.image_url");
+        assert_eq!(json["inputs"][0]["required"], false);
+        assert_eq!(json["traditions"][0], "Mian Xiang");
+        assert_eq!(serde_json::to_value(EngineDescription::default()).unwrap()["implementation"], "real");
+    }
+}
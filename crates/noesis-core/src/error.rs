@@ -1,5 +1,10 @@
 //! Unified error types for the Noesis platform
 
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "openapi")]
+use utoipa::ToSchema;
+
 /// Main error type for all Noesis engines and services
 #[derive(Debug, thiserror::Error)]
 pub enum EngineError {
@@ -9,6 +14,10 @@ pub enum EngineError {
     #[error("Validation error: {0}")]
     ValidationError(String),
 
+    /// One or more input fields failed validation
+    #[error("Validation error: {}", join_validation_errors(.0))]
+    InvalidInput(Vec<ValidationError>),
+
     #[error("Cache error: {0}")]
     CacheError(String),
 
@@ -24,6 +33,10 @@ pub enum EngineError {
     #[error("Engine not found: {0}")]
     EngineNotFound(String),
 
+    /// A renamed engine's old id, requested after its alias's sunset
+    #[error("Engine '{alias}' was retired on {sunset}; use '{replacement}'")]
+    EngineRetired { alias: String, replacement: String, sunset: String },
+
     #[error("Workflow not found: {0}")]
     WorkflowNotFound(String),
 
@@ -39,3 +52,85 @@ pub enum EngineError {
     #[error("Internal error: {0}")]
     InternalError(String),
 }
+
+impl EngineError {
+    /// Shorthand for an [`EngineError::InvalidInput`] with a single field error
+    pub fn invalid_field(field: impl Into<String>, code: ValidationCode, message: impl Into<String>) -> Self {
+        EngineError::InvalidInput(vec![ValidationError::new(field, code, message)])
+    }
+}
+
+/// Machine-readable reason an input field was rejected
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum ValidationCode {
+    /// The field is missing
+    Required,
+    /// The field is present but blank
+    Empty,
+    /// The value does not parse (e.g. a malformed date)
+    InvalidFormat,
+    /// The value parses but is outside the accepted range
+    OutOfRange,
+    /// The value is not a recognised identifier (e.g. an unknown timezone)
+    Unknown,
+    /// A lookup for the value found nothing (e.g. an ungeocodable place)
+    NotFound,
+    /// The value repeats one that must be unique (e.g. a subject role)
+    Duplicate,
+    /// The value contradicts another field (e.g. a birth time marked unknown)
+    Conflict,
+}
+
+impl ValidationCode {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ValidationCode::Required => "required",
+            ValidationCode::Empty => "empty",
+            ValidationCode::InvalidFormat => "invalid_format",
+            ValidationCode::OutOfRange => "out_of_range",
+            ValidationCode::Unknown => "unknown",
+            ValidationCode::NotFound => "not_found",
+            ValidationCode::Duplicate => "duplicate",
+            ValidationCode::Conflict => "conflict",
+        }
+    }
+}
+
+/// A single field-level validation failure
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct ValidationError {
+    /// Dotted path to the offending field (e.g. "birth_data.latitude")
+    #[cfg_attr(feature = "openapi", schema(example = "birth_data.latitude"))]
+    pub field: String,
+    pub code: ValidationCode,
+    /// Human-readable description
+    #[cfg_attr(feature = "openapi", schema(example = "Latitude 91 must be between -90 and 90"))]
+    pub message: String,
+}
+
+impl ValidationError {
+    pub fn new(field: impl Into<String>, code: ValidationCode, message: impl Into<String>) -> Self {
+        Self {
+            field: field.into(),
+            code,
+            message: message.into(),
+        }
+    }
+}
+
+impl std::fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {}", self.field, self.message)
+    }
+}
+
+fn join_validation_errors(errors: &[ValidationError]) -> String {
+    errors
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join("; ")
+}
@@ -0,0 +1,167 @@
+//! Sample inputs for trying engines out
+//!
+//! Every engine lists a few [`EngineExample`]s through
+//! [`ConsciousnessEngine::examples`](crate::ConsciousnessEngine::examples):
+//! inputs taken from its tests, with the birth data of a made-up person, that
+//! are known to calculate. The API serves them with the shape of the result
+//! they produce (see [`result_shape`]), so the Swagger UI and new partners
+//! start from an input that works instead of guessing one.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use std::collections::HashMap;
+
+use crate::{BirthData, Coordinates, EngineInput, Precision};
+
+#[cfg(feature = "openapi")]
+use utoipa::ToSchema;
+
+/// 2024-03-20T12:00:00Z, the moment every example is read at
+pub const SAMPLE_MOMENT: i64 = 1_710_936_000;
+
+/// A named input an engine is known to calculate
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct EngineExample {
+    #[cfg_attr(feature = "openapi", schema(example = "birth_data"))]
+    pub name: String,
+    /// What the example shows
+    #[cfg_attr(feature = "openapi", schema(example = "Natal reading from birth data"))]
+    pub summary: String,
+    pub input: EngineInput,
+    /// `result` recorded for the input, for engines that can't calculate it
+    /// offline (bridged engines)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "openapi", schema(value_type = Option<Object>))]
+    pub recorded_result: Option<Value>,
+}
+
+impl EngineExample {
+    pub fn new(name: impl Into<String>, summary: impl Into<String>, input: EngineInput) -> Self {
+        Self {
+            name: name.into(),
+            summary: summary.into(),
+            input,
+            recorded_result: None,
+        }
+    }
+
+    pub fn with_recorded_result(mut self, result: Value) -> Self {
+        self.recorded_result = Some(result);
+        self
+    }
+}
+
+/// Birth data of a made-up person, born in Bengaluru
+pub fn sample_birth_data() -> BirthData {
+    BirthData {
+        name: Some("Sample Person".to_string()),
+        display_name: None,
+        date: "1990-01-15".to_string(),
+        time: Some("14:30".to_string()),
+        time_unknown: false,
+        latitude: 12.9716,
+        longitude: 77.5946,
+        altitude: None,
+        timezone: "Asia/Kolkata".to_string(),
+        place: None,
+        utc_offset_minutes: None,
+    }
+}
+
+/// [`sample_birth_data`] read at [`SAMPLE_MOMENT`] with `options`
+pub fn sample_input(options: Value) -> EngineInput {
+    let options: HashMap<String, Value> = match options {
+        Value::Object(map) => map.into_iter().collect(),
+        _ => HashMap::new(),
+    };
+    let birth_data = sample_birth_data();
+    EngineInput {
+        location: Some(Coordinates {
+            latitude: birth_data.latitude,
+            longitude: birth_data.longitude,
+            altitude: None,
+            timezone: Some(birth_data.timezone.clone()),
+        }),
+        birth_data: Some(birth_data),
+        secondary_birth_data: None,
+        subjects: Vec::new(),
+        current_time: sample_moment(),
+        precision: Precision::Standard,
+        options,
+    }
+}
+
+/// [`sample_input`] without birth data, for engines that read only options
+pub fn options_input(options: Value) -> EngineInput {
+    EngineInput {
+        birth_data: None,
+        location: None,
+        ..sample_input(options)
+    }
+}
+
+/// The example of engines that don't curate their own
+pub fn birth_data_example() -> EngineExample {
+    EngineExample::new("birth_data", "Reading from birth data alone", sample_input(Value::Null))
+}
+
+fn sample_moment() -> DateTime<Utc> {
+    DateTime::from_timestamp(SAMPLE_MOMENT, 0).expect("sample moment is valid")
+}
+
+/// The shape of a result: every leaf replaced with its JSON type name
+/// ("string", "integer", "number", "boolean" or "null"), and arrays with the
+/// shape of their first item
+pub fn result_shape(value: &Value) -> Value {
+    match value {
+        Value::Object(map) => Value::Object(
+            map.iter()
+                .map(|(key, v)| (key.clone(), result_shape(v)))
+                .collect::<Map<String, Value>>(),
+        ),
+        Value::Array(items) => Value::Array(items.first().map(result_shape).into_iter().collect()),
+        Value::String(_) => Value::from("string"),
+        Value::Number(n) if n.is_f64() => Value::from("number"),
+        Value::Number(_) => Value::from("integer"),
+        Value::Bool(_) => Value::from("boolean"),
+        Value::Null => Value::from("null"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_sample_input() {
+        let input = sample_input(json!({ "forecast_days": 14 }));
+        assert_eq!(input.current_time.to_rfc3339(), "2024-03-20T12:00:00+00:00");
+        assert_eq!(input.birth_data.as_ref().unwrap().date, "1990-01-15");
+        assert_eq!(input.options["forecast_days"], 14);
+        assert!(options_input(json!({})).birth_data.is_none());
+    }
+
+    #[test]
+    fn test_result_shape() {
+        let shape = result_shape(&json!({
+            "life_path": 7,
+            "ratio": 0.5,
+            "cards": [{ "name": "Tower", "is_reversed": true }, { "name": "Star" }],
+            "empty": [],
+            "note": null
+        }));
+        assert_eq!(
+            shape,
+            json!({
+                "life_path": "integer",
+                "ratio": "number",
+                "cards": [{ "name": "string", "is_reversed": "boolean" }],
+                "empty": [],
+                "note": "null"
+            })
+        );
+    }
+}
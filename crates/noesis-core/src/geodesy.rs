@@ -0,0 +1,218 @@
+//! Coordinate checks and observer geometry for birth locations
+//!
+//! Longitudes are east-positive in [-180, 180]. Inputs in the 0-360
+//! east-positive convention some atlases use are wrapped into that range by
+//! [`BirthData::normalize_coordinates`], which the orchestrator applies before
+//! any engine sees the input.
+//!
+//! Swapped latitude and longitude are the commonest coordinate mistake. When
+//! the latitude is out of range but the pair is valid the other way round the
+//! validation error says so; when both are in range but only the swapped pair
+//! fits the timezone, [`BirthData::swap_warning`] reports it without failing
+//! the calculation.
+//!
+//! Charts are geocentric by default. At `Precision::High` and above, engines
+//! that support it place the Moon as seen from the birth location
+//! ([`topocentric_ecliptic`]), including its `altitude`; the lunar parallax
+//! moves it by up to a degree.
+
+use chrono::{DateTime, Utc};
+
+use crate::BirthData;
+
+/// Lowest accepted birth altitude in meters, below the Dead Sea shore
+pub const MIN_ALTITUDE_METERS: f64 = -500.0;
+
+/// Highest accepted birth altitude in meters, above the summit of Everest
+pub const MAX_ALTITUDE_METERS: f64 = 9_000.0;
+
+/// How far a longitude may sit from its timezone's meridian before a swap is
+/// considered. Zones such as China's span 60 degrees, so this is generous.
+const SWAP_LONGITUDE_TOLERANCE: f64 = 30.0;
+
+/// How close the latitude, read as a longitude, must sit to the meridian
+const SWAP_LATITUDE_TOLERANCE: f64 = 15.0;
+
+/// Equatorial radius of the WGS 84 ellipsoid, in meters
+const EARTH_RADIUS_METERS: f64 = 6_378_137.0;
+
+/// Polar over equatorial radius of the WGS 84 ellipsoid
+const EARTH_AXIS_RATIO: f64 = 0.996_647_19;
+
+/// Equatorial horizontal parallax of a body 1 AU away, in degrees (8.794")
+const SOLAR_PARALLAX: f64 = 8.794 / 3600.0;
+
+/// Julian Day of the Unix epoch
+const UNIX_EPOCH_JD: f64 = 2_440_587.5;
+
+/// `longitude` wrapped into [-180, 180]. Values from -360 to 360 are wrapped;
+/// anything further out is returned unchanged for validation to reject.
+pub fn normalize_longitude(longitude: f64) -> f64 {
+    if (-180.0..=180.0).contains(&longitude) || !(-360.0..=360.0).contains(&longitude) {
+        return longitude;
+    }
+    (longitude + 180.0).rem_euclid(360.0) - 180.0
+}
+
+/// Whether an out-of-range `latitude` would be valid as the longitude, with
+/// `longitude` valid as the latitude
+pub fn swapped_coordinates(latitude: f64, longitude: f64) -> bool {
+    !(-90.0..=90.0).contains(&latitude)
+        && (-180.0..=180.0).contains(&latitude)
+        && (-90.0..=90.0).contains(&longitude)
+}
+
+/// Whether a UTC offset fits `latitude` read as a longitude, but not
+/// `longitude` itself. A zone's meridian is 15 degrees per hour of offset.
+pub fn offset_suggests_swap(latitude: f64, longitude: f64, offset_seconds: i32) -> bool {
+    let meridian = offset_seconds as f64 / 240.0;
+    angular_distance(longitude, meridian) > SWAP_LONGITUDE_TOLERANCE
+        && angular_distance(latitude, meridian) <= SWAP_LATITUDE_TOLERANCE
+}
+
+fn angular_distance(a: f64, b: f64) -> f64 {
+    let d = (a - b).rem_euclid(360.0);
+    d.min(360.0 - d)
+}
+
+/// A place on the Earth's surface
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Observer {
+    /// Geodetic latitude in degrees
+    pub latitude: f64,
+    /// East-positive longitude in degrees
+    pub longitude: f64,
+    /// Height above sea level in meters
+    pub altitude: f64,
+}
+
+impl Observer {
+    /// `(ρ sin φ′, ρ cos φ′)`: the observer's distance from the Earth's
+    /// axis and equatorial plane in equatorial radii (Meeus, ch. 11)
+    pub fn geocentric_terms(&self) -> (f64, f64) {
+        let phi = self.latitude.to_radians();
+        let u = (EARTH_AXIS_RATIO * phi.tan()).atan();
+        let height = self.altitude / EARTH_RADIUS_METERS;
+        (
+            EARTH_AXIS_RATIO * u.sin() + height * phi.sin(),
+            u.cos() + height * phi.cos(),
+        )
+    }
+}
+
+/// Ecliptic longitude and latitude, in degrees, of a body at geocentric
+/// `longitude`/`latitude` and `distance_au` as seen by `observer` at `at`
+/// (Meeus, ch. 40). The shift is only significant for the Moon.
+pub fn topocentric_ecliptic(
+    longitude: f64,
+    latitude: f64,
+    distance_au: f64,
+    at: DateTime<Utc>,
+    observer: &Observer,
+) -> (f64, f64) {
+    let jd = UNIX_EPOCH_JD + at.timestamp_millis() as f64 / 86_400_000.0;
+    let t = (jd - 2_451_545.0) / 36_525.0;
+    let sidereal = (280.460_618_37 + 360.985_647_366_29 * (jd - 2_451_545.0) + 0.000_387_933 * t * t
+        - t * t * t / 38_710_000.0
+        + observer.longitude)
+        .to_radians();
+    let obliquity = (23.439_291_1 - 0.013_004_2 * t - 0.000_000_16 * t * t + 0.000_000_504 * t * t * t).to_radians();
+    let sin_parallax = SOLAR_PARALLAX.to_radians().sin() / distance_au;
+    let (s, c) = observer.geocentric_terms();
+
+    let (lambda, beta) = (longitude.to_radians(), latitude.to_radians());
+    let n = lambda.cos() * beta.cos() - c * sin_parallax * sidereal.cos();
+    let lambda_topo = (lambda.sin() * beta.cos()
+        - sin_parallax * (s * obliquity.sin() + c * obliquity.cos() * sidereal.sin()))
+    .atan2(n);
+    let beta_topo = (lambda_topo.cos()
+        * (beta.sin() - sin_parallax * (s * obliquity.cos() - c * obliquity.sin() * sidereal.sin()))
+        / n)
+        .atan();
+
+    (lambda_topo.to_degrees().rem_euclid(360.0), beta_topo.to_degrees())
+}
+
+impl BirthData {
+    /// Wrap the longitude into [-180, 180] (see [`normalize_longitude`])
+    pub fn normalize_coordinates(&mut self) {
+        self.longitude = normalize_longitude(self.longitude);
+    }
+
+    /// Where the birth took place; sea level when no altitude is given
+    pub fn observer(&self) -> Observer {
+        Observer {
+            latitude: self.latitude,
+            longitude: self.longitude,
+            altitude: self.altitude.unwrap_or(0.0),
+        }
+    }
+
+    /// A warning when the coordinates fit the birth timezone only with
+    /// latitude and longitude swapped. Valid coordinates are never rejected
+    /// for this: a birth recorded in another zone's time looks the same.
+    pub fn swap_warning(&self) -> Option<String> {
+        let offset = self.resolve_offset().ok()?;
+        offset_suggests_swap(self.latitude, self.longitude, offset.offset_seconds).then(|| {
+            format!(
+                "Longitude {} is far from timezone '{}'; latitude and longitude may be swapped",
+                self.longitude, offset.timezone
+            )
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_normalize_longitude() {
+        assert_eq!(normalize_longitude(77.59), 77.59);
+        assert_eq!(normalize_longitude(-180.0), -180.0);
+        assert!((normalize_longitude(282.41) - -77.59).abs() < 1e-9);
+        assert!((normalize_longitude(-200.0) - 160.0).abs() < 1e-9);
+        assert_eq!(normalize_longitude(360.0), 0.0);
+        assert_eq!(normalize_longitude(540.0), 540.0);
+        assert!(normalize_longitude(f64::NAN).is_nan());
+    }
+
+    #[test]
+    fn test_swap_detection() {
+        // Bengaluru written longitude first
+        assert!(!swapped_coordinates(77.59, 12.97));
+        assert!(swapped_coordinates(-122.42, 37.77));
+        assert!(!swapped_coordinates(95.0, 120.0));
+
+        assert!(offset_suggests_swap(77.59, 12.97, 19_800));
+        assert!(!offset_suggests_swap(12.97, 77.59, 19_800));
+        // Kashgar keeps Beijing time, 44 degrees west of its meridian
+        assert!(!offset_suggests_swap(39.47, 75.99, 28_800));
+        assert!(offset_suggests_swap(-74.0, 40.71, -18_000));
+    }
+
+    #[test]
+    fn test_geocentric_terms() {
+        // Meeus example 11.a: Palomar, 33°21'22" N, 1706 m
+        let palomar = Observer { latitude: 33.356_111, longitude: -116.8625, altitude: 1706.0 };
+        let (s, c) = palomar.geocentric_terms();
+        assert!((s - 0.546_861).abs() < 1e-5, "{}", s);
+        assert!((c - 0.836_339).abs() < 1e-5, "{}", c);
+    }
+
+    #[test]
+    fn test_lunar_parallax() {
+        let observer = Observer { latitude: 12.97, longitude: 77.59, altitude: 920.0 };
+        let at = Utc.with_ymd_and_hms(1990, 1, 15, 9, 0, 0).unwrap();
+        let moon_distance_au = 384_400.0 / 149_597_870.7;
+        let (longitude, latitude) = topocentric_ecliptic(200.0, 3.0, moon_distance_au, at, &observer);
+        let shift = angular_distance(longitude, 200.0);
+        assert!(shift > 0.0 && shift < 1.0, "{}", shift);
+        assert!(latitude < 3.0);
+
+        // A distant body barely moves
+        let (longitude, _) = topocentric_ecliptic(200.0, 3.0, 5.0, at, &observer);
+        assert!(angular_distance(longitude, 200.0) < 0.001);
+    }
+}
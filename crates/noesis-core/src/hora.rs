@@ -0,0 +1,303 @@
+//! Hora (planetary hour) calculations shared across engines
+//!
+//! The Vedic day runs from one sunrise to the next. Daylight and night are each
+//! split into 12 equal horas, so hora length varies with the season and latitude.
+//! The first hora of the day is ruled by the weekday lord, and subsequent horas
+//! follow the Chaldean order (Saturn → Jupiter → Mars → Sun → Venus → Mercury → Moon).
+
+use chrono::{Datelike, Duration, NaiveDateTime, Weekday};
+use serde::{Deserialize, Serialize};
+
+use crate::EngineError;
+
+#[cfg(feature = "openapi")]
+use utoipa::ToSchema;
+
+/// The seven classical planets that rule the horas
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum HoraPlanet {
+    Sun,
+    Moon,
+    Mars,
+    Mercury,
+    Jupiter,
+    Venus,
+    Saturn,
+}
+
+/// Hora succession order. Each hora is ruled by the planet that follows the
+/// previous ruler in this sequence.
+pub const HORA_SEQUENCE: [HoraPlanet; 7] = [
+    HoraPlanet::Sun,
+    HoraPlanet::Venus,
+    HoraPlanet::Mercury,
+    HoraPlanet::Moon,
+    HoraPlanet::Saturn,
+    HoraPlanet::Jupiter,
+    HoraPlanet::Mars,
+];
+
+impl HoraPlanet {
+    /// English planet name
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            HoraPlanet::Sun => "Sun",
+            HoraPlanet::Moon => "Moon",
+            HoraPlanet::Mars => "Mars",
+            HoraPlanet::Mercury => "Mercury",
+            HoraPlanet::Jupiter => "Jupiter",
+            HoraPlanet::Venus => "Venus",
+            HoraPlanet::Saturn => "Saturn",
+        }
+    }
+
+    /// Sanskrit graha name
+    pub fn sanskrit_name(&self) -> &'static str {
+        match self {
+            HoraPlanet::Sun => "Surya",
+            HoraPlanet::Moon => "Chandra",
+            HoraPlanet::Mars => "Mangala",
+            HoraPlanet::Mercury => "Budha",
+            HoraPlanet::Jupiter => "Guru",
+            HoraPlanet::Venus => "Shukra",
+            HoraPlanet::Saturn => "Shani",
+        }
+    }
+
+    /// Lord of the given weekday, which also rules the first hora after sunrise
+    pub fn day_lord(weekday: Weekday) -> Self {
+        match weekday {
+            Weekday::Sun => HoraPlanet::Sun,
+            Weekday::Mon => HoraPlanet::Moon,
+            Weekday::Tue => HoraPlanet::Mars,
+            Weekday::Wed => HoraPlanet::Mercury,
+            Weekday::Thu => HoraPlanet::Jupiter,
+            Weekday::Fri => HoraPlanet::Venus,
+            Weekday::Sat => HoraPlanet::Saturn,
+        }
+    }
+
+    /// Ruler of the hora that follows this one
+    pub fn next_hora_ruler(&self) -> Self {
+        let idx = HORA_SEQUENCE.iter().position(|p| p == self).unwrap_or(0);
+        HORA_SEQUENCE[(idx + 1) % HORA_SEQUENCE.len()]
+    }
+
+    /// Whether the planet is traditionally considered benefic for a hora
+    pub fn is_benefic(&self) -> bool {
+        matches!(
+            self,
+            HoraPlanet::Moon | HoraPlanet::Mercury | HoraPlanet::Jupiter | HoraPlanet::Venus
+        )
+    }
+}
+
+/// A single planetary hour
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct Hora {
+    /// Hora number within the Vedic day (1-24, 1 starts at sunrise)
+    pub number: u8,
+    /// Ruling planet
+    pub ruler: HoraPlanet,
+    /// Local start time
+    #[cfg_attr(feature = "openapi", schema(value_type = String, example = "2024-01-15T06:45:00"))]
+    pub start: NaiveDateTime,
+    /// Local end time
+    #[cfg_attr(feature = "openapi", schema(value_type = String, example = "2024-01-15T07:41:00"))]
+    pub end: NaiveDateTime,
+    /// True for the 12 horas between sunrise and sunset
+    pub is_day: bool,
+}
+
+impl Hora {
+    /// Whether the given local time falls inside this hora (start inclusive, end exclusive)
+    pub fn contains(&self, local_time: NaiveDateTime) -> bool {
+        local_time >= self.start && local_time < self.end
+    }
+
+    /// Hora length in minutes
+    pub fn duration_minutes(&self) -> f64 {
+        (self.end - self.start).num_seconds() as f64 / 60.0
+    }
+}
+
+/// The 24 hora rulers for a weekday, starting with the sunrise hora
+pub fn hora_rulers(weekday: Weekday) -> [HoraPlanet; 24] {
+    let mut rulers = [HoraPlanet::day_lord(weekday); 24];
+    for i in 1..24 {
+        rulers[i] = rulers[i - 1].next_hora_ruler();
+    }
+    rulers
+}
+
+/// Calculate all 24 horas of a Vedic day from local solar events.
+///
+/// The weekday lord is taken from the local date of `sunrise`. All three
+/// timestamps must be local wall-clock times in the same offset.
+pub fn calculate_horas(
+    sunrise: NaiveDateTime,
+    sunset: NaiveDateTime,
+    next_sunrise: NaiveDateTime,
+) -> Result<Vec<Hora>, EngineError> {
+    calculate_horas_for_weekday(sunrise.date().weekday(), sunrise, sunset, next_sunrise)
+}
+
+/// Calculate all 24 horas with an explicit weekday lord.
+///
+/// Useful when the weekday comes from an external source (e.g. an API's vara)
+/// rather than from the calendar date of `sunrise`.
+pub fn calculate_horas_for_weekday(
+    weekday: Weekday,
+    sunrise: NaiveDateTime,
+    sunset: NaiveDateTime,
+    next_sunrise: NaiveDateTime,
+) -> Result<Vec<Hora>, EngineError> {
+    if sunset <= sunrise || next_sunrise <= sunset {
+        return Err(EngineError::CalculationError(format!(
+            "Invalid solar events for hora calculation: sunrise {}, sunset {}, next sunrise {}",
+            sunrise, sunset, next_sunrise
+        )));
+    }
+
+    let rulers = hora_rulers(weekday);
+    let mut horas = Vec::with_capacity(24);
+    push_period_horas(&mut horas, &rulers[0..12], sunrise, sunset, 1, true);
+    push_period_horas(&mut horas, &rulers[12..24], sunset, next_sunrise, 13, false);
+    Ok(horas)
+}
+
+/// Calculate the 24 horas for the Vedic day containing `local_time`.
+///
+/// `sunrise_sunset` is called with a calendar date and must return that date's
+/// local sunrise and sunset. Times before sunrise belong to the previous Vedic day.
+pub fn horas_for_moment<F>(local_time: NaiveDateTime, sunrise_sunset: F) -> Result<Vec<Hora>, EngineError>
+where
+    F: Fn(chrono::NaiveDate) -> Result<(NaiveDateTime, NaiveDateTime), EngineError>,
+{
+    let today = local_time.date();
+    let (sunrise, sunset) = sunrise_sunset(today)?;
+
+    if local_time < sunrise {
+        let yesterday = today - Duration::days(1);
+        let (prev_sunrise, prev_sunset) = sunrise_sunset(yesterday)?;
+        calculate_horas(prev_sunrise, prev_sunset, sunrise)
+    } else {
+        let (next_sunrise, _) = sunrise_sunset(today + Duration::days(1))?;
+        calculate_horas(sunrise, sunset, next_sunrise)
+    }
+}
+
+/// Find the hora active at the given local time
+pub fn hora_at(horas: &[Hora], local_time: NaiveDateTime) -> Option<&Hora> {
+    horas.iter().find(|h| h.contains(local_time))
+}
+
+fn push_period_horas(
+    horas: &mut Vec<Hora>,
+    rulers: &[HoraPlanet],
+    period_start: NaiveDateTime,
+    period_end: NaiveDateTime,
+    first_number: u8,
+    is_day: bool,
+) {
+    let period_secs = (period_end - period_start).num_seconds();
+    let count = rulers.len() as i64;
+
+    for (i, ruler) in rulers.iter().enumerate() {
+        let i = i as i64;
+        // Boundaries are derived from the period start to avoid accumulating rounding drift
+        let start = period_start + Duration::seconds(period_secs * i / count);
+        let end = period_start + Duration::seconds(period_secs * (i + 1) / count);
+        horas.push(Hora {
+            number: first_number + i as u8,
+            ruler: *ruler,
+            start,
+            end,
+            is_day,
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::NaiveDate;
+
+    fn at(date: &str, time: &str) -> NaiveDateTime {
+        NaiveDateTime::parse_from_str(&format!("{date} {time}"), "%Y-%m-%d %H:%M").unwrap()
+    }
+
+    #[test]
+    fn test_sequence_starts_with_day_lord() {
+        assert_eq!(hora_rulers(Weekday::Sun)[0], HoraPlanet::Sun);
+        assert_eq!(hora_rulers(Weekday::Sat)[0], HoraPlanet::Saturn);
+        // Sun → Venus → Mercury → Moon ...
+        assert_eq!(hora_rulers(Weekday::Sun)[1], HoraPlanet::Venus);
+        assert_eq!(hora_rulers(Weekday::Sun)[3], HoraPlanet::Moon);
+    }
+
+    #[test]
+    fn test_sequence_rolls_into_next_day_lord() {
+        // The 25th hora (first of the next day) must be the next weekday's lord
+        for (day, next) in [
+            (Weekday::Sun, Weekday::Mon),
+            (Weekday::Wed, Weekday::Thu),
+            (Weekday::Sat, Weekday::Sun),
+        ] {
+            let last = hora_rulers(day)[23];
+            assert_eq!(last.next_hora_ruler(), HoraPlanet::day_lord(next));
+        }
+    }
+
+    #[test]
+    fn test_unequal_day_and_night_horas() {
+        // 2024-06-16 is a Sunday; long summer day
+        let horas = calculate_horas(
+            at("2024-06-16", "05:00"),
+            at("2024-06-16", "20:00"),
+            at("2024-06-17", "05:00"),
+        )
+        .unwrap();
+
+        assert_eq!(horas.len(), 24);
+        assert_eq!(horas[0].ruler, HoraPlanet::Sun);
+        assert!((horas[0].duration_minutes() - 75.0).abs() < 0.01);
+        assert!((horas[12].duration_minutes() - 45.0).abs() < 0.01);
+        assert_eq!(horas[11].end, at("2024-06-16", "20:00"));
+        assert_eq!(horas[23].end, at("2024-06-17", "05:00"));
+        assert!(horas[..12].iter().all(|h| h.is_day));
+        assert!(horas[12..].iter().all(|h| !h.is_day));
+    }
+
+    #[test]
+    fn test_invalid_solar_events_rejected() {
+        let result = calculate_horas(
+            at("2024-06-16", "20:00"),
+            at("2024-06-16", "05:00"),
+            at("2024-06-17", "05:00"),
+        );
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_pre_sunrise_belongs_to_previous_day() {
+        let fixed = |d: NaiveDate| {
+            Ok((
+                d.and_hms_opt(6, 0, 0).unwrap(),
+                d.and_hms_opt(18, 0, 0).unwrap(),
+            ))
+        };
+        // Monday 2024-06-17 at 03:00 is still Sunday's Vedic day
+        let local = at("2024-06-17", "03:00");
+        let horas = horas_for_moment(local, fixed).unwrap();
+        assert_eq!(horas[0].start, at("2024-06-16", "06:00"));
+        assert_eq!(horas[0].ruler, HoraPlanet::Sun);
+
+        let current = hora_at(&horas, local).unwrap();
+        assert_eq!(current.number, 22);
+        assert!(!current.is_day);
+    }
+}
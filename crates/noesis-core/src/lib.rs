@@ -5,9 +5,30 @@
 
 pub mod types;
 pub mod error;
+pub mod correlation;
+pub mod hora;
+pub mod timezone;
+pub mod geodesy;
+pub mod options;
+pub mod usage;
+pub mod context;
+pub mod redaction;
+pub mod rng;
+pub mod casing;
+pub mod examples;
+pub mod description;
+pub mod birth_time;
+pub mod names;
+#[cfg(feature = "results")]
+pub mod results;
 
 pub use types::*;
 pub use error::*;
+pub use examples::EngineExample;
+pub use description::EngineDescription;
+pub use birth_time::TimeSensitivity;
+pub use names::NameNormalization;
+pub use options::{TypedOptions, COMMON_OPTIONS, CONSCIOUSNESS_LEVEL_OPTION, SETTINGS_OPTION};
 
 use async_trait::async_trait;
 
@@ -35,9 +56,73 @@ pub trait ConsciousnessEngine: Send + Sync {
     /// Validate an engine output for correctness
     async fn validate(&self, output: &EngineOutput) -> Result<ValidationResult, EngineError>;
 
+    /// Version of the engine's formulas. Bump it with any fix that changes
+    /// results, so results cached by the previous version stop being served.
+    fn engine_version(&self) -> &str {
+        "1"
+    }
+
+    /// Option keys the engine reads, besides the
+    /// [`COMMON_OPTIONS`], usually `Some(<its options struct>::keys())`.
+    /// Other keys are reported in `metadata.warnings`; `None` means the
+    /// engine doesn't declare its options and nothing is reported.
+    fn option_keys(&self) -> Option<&'static [&'static str]> {
+        None
+    }
+
+    /// Reject `input` without calculating: the checks `calculate` makes
+    /// before any work, such as required birth data and option types.
+    /// Workflow dry runs report them per engine; engines that don't check
+    /// accept every input here.
+    fn check_input(&self, _input: &EngineInput) -> Result<(), EngineError> {
+        Ok(())
+    }
+
+    /// How much the engine's result depends on the birth time. Sensitive
+    /// engines read an unknown time at noon and embed a
+    /// [`TimeStability`](birth_time::TimeStability) in their result.
+    fn birth_time_sensitivity(&self) -> TimeSensitivity {
+        TimeSensitivity::Insensitive
+    }
+
+    /// Inputs the engine is known to calculate, for trying it out. Engines
+    /// without their own list offer [`examples::birth_data_example`].
+    fn examples(&self) -> Vec<EngineExample> {
+        vec![examples::birth_data_example()]
+    }
+
+    /// What the engine reads and returns, the traditions it draws on, its
+    /// limitations and whether it is a mock, for the engine catalog.
+    /// Engines that don't describe themselves return an empty description.
+    fn describe(&self) -> EngineDescription {
+        EngineDescription::default()
+    }
+
     /// Generate a deterministic cache key for the given input.
     /// Uses SHA-256 to ensure consistency across restarts.
     fn cache_key(&self, input: &EngineInput) -> String;
+
+    /// How long the result for `input` may be served from cache.
+    ///
+    /// Natal results never change and return [`CacheTtl::Infinite`]; readings
+    /// of the present moment expire at their next transition. Engines that
+    /// don't know keep [`CacheTtl::DEFAULT`].
+    fn cache_ttl(&self, _input: &EngineInput) -> CacheTtl {
+        CacheTtl::DEFAULT
+    }
+
+    /// Whether the engine compares `birth_data` with `secondary_birth_data`
+    /// itself. The `relationship` workflow runs every other engine once per
+    /// person instead.
+    fn supports_compatibility(&self) -> bool {
+        false
+    }
+
+    /// Check that the engine's dependencies (data files, upstream servers,
+    /// API budgets) are usable. Engines without dependencies keep the default.
+    async fn health(&self) -> Result<(), EngineError> {
+        Ok(())
+    }
 }
 
 /// Result of validating an engine output
@@ -51,3 +136,18 @@ pub struct ValidationResult {
     /// Validation messages or warnings
     pub messages: Vec<String>,
 }
+
+/// Outcome of one engine's [`ConsciousnessEngine::health`] check
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct EngineHealth {
+    pub engine_id: String,
+    pub healthy: bool,
+    /// Whether a failure of this engine makes the service not ready
+    pub critical: bool,
+    /// Why the check failed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    /// Time taken by the check in milliseconds
+    pub latency_ms: f64,
+}
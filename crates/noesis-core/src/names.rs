@@ -0,0 +1,170 @@
+//! Name normalization for calculations and cache keys
+//!
+//! The same name arrives in many spellings: with stray or doubled spaces,
+//! composed or decomposed accents, typographic apostrophes, fullwidth forms.
+//! Numerology only counts Latin letters, and engines hash the name into their
+//! cache key, so each spelling read as a different person. The API passes
+//! every birth data through [`BirthData::normalize_name`](crate::BirthData::normalize_name)
+//! when an input arrives: `name` becomes the normalized form engines calculate
+//! with, and the name as given is kept in `display_name` for reports.
+//!
+//! [`normalize_name`] always applies NFKC and trims and collapses whitespace,
+//! dropping control and zero-width characters. [`NameNormalization`] turns on
+//! folding Latin letters to their base (`José` → `Jose`, `Ærø` → `AEro`) and
+//! dropping punctuation (`O'Brien` → `OBrien`, `Jean-Luc` → `Jean Luc`).
+//! Letters of other scripts are kept as they are.
+
+use unicode_normalization::char::is_combining_mark;
+use unicode_normalization::UnicodeNormalization;
+
+/// What [`normalize_name`] strips beyond whitespace
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NameNormalization {
+    /// Fold accented and ligature Latin letters to their base letters
+    pub strip_diacritics: bool,
+    /// Drop apostrophes and turn other punctuation into spaces
+    pub strip_punctuation: bool,
+}
+
+impl Default for NameNormalization {
+    fn default() -> Self {
+        Self {
+            strip_diacritics: true,
+            strip_punctuation: true,
+        }
+    }
+}
+
+impl NameNormalization {
+    /// Only Unicode normalization and whitespace
+    pub const MINIMAL: Self = Self {
+        strip_diacritics: false,
+        strip_punctuation: false,
+    };
+}
+
+/// `name` in the form engines calculate with; see the [module docs](self)
+pub fn normalize_name(name: &str, rules: &NameNormalization) -> String {
+    let mut folded = String::with_capacity(name.len());
+    for ch in name.nfkc() {
+        if is_invisible(ch) {
+            continue;
+        }
+        if rules.strip_punctuation && is_apostrophe(ch) {
+            continue;
+        }
+        if rules.strip_punctuation && !ch.is_alphanumeric() && !is_combining_mark(ch) {
+            folded.push(' ');
+            continue;
+        }
+        if rules.strip_diacritics {
+            if let Some(base) = latin_base(ch) {
+                folded.push_str(base);
+                continue;
+            }
+        }
+        folded.push(ch);
+    }
+
+    let folded: String = if rules.strip_diacritics {
+        // Marks on Latin letters go; marks carry vowels in Devanagari and the like
+        let mut stripped = String::with_capacity(folded.len());
+        let mut after_latin = false;
+        for ch in folded.nfd() {
+            if is_combining_mark(ch) {
+                if !after_latin {
+                    stripped.push(ch);
+                }
+                continue;
+            }
+            after_latin = ch.is_ascii_alphabetic();
+            stripped.push(ch);
+        }
+        stripped.nfc().collect()
+    } else {
+        folded
+    };
+    folded.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Control, zero-width and byte-order characters; joiners are kept, since
+/// Indic scripts spell with them
+fn is_invisible(ch: char) -> bool {
+    (ch.is_control() && !ch.is_whitespace()) || matches!(ch, '\u{200B}' | '\u{2060}' | '\u{FEFF}' | '\u{00AD}')
+}
+
+fn is_apostrophe(ch: char) -> bool {
+    matches!(ch, '\'' | '\u{2019}' | '\u{2018}' | '\u{02BC}' | '`' | '\u{00B4}')
+}
+
+/// Latin letters without a canonical decomposition into base and mark
+fn latin_base(ch: char) -> Option<&'static str> {
+    Some(match ch {
+        'ß' => "ss",
+        'ẞ' => "SS",
+        'Æ' => "AE",
+        'æ' => "ae",
+        'Œ' => "OE",
+        'œ' => "oe",
+        'Ø' => "O",
+        'ø' => "o",
+        'Đ' | 'Ð' => "D",
+        'đ' | 'ð' => "d",
+        'Ł' => "L",
+        'ł' => "l",
+        'Þ' => "TH",
+        'þ' => "th",
+        'ı' => "i",
+        'Ħ' => "H",
+        'ħ' => "h",
+        _ => return None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn normalize(name: &str) -> String {
+        normalize_name(name, &NameNormalization::default())
+    }
+
+    #[test]
+    fn test_whitespace_and_invisible_characters() {
+        assert_eq!(normalize("  Ada \t  Lovelace\u{200B} "), "Ada Lovelace");
+        assert_eq!(normalize_name("Ada\u{00A0}Lovelace\n", &NameNormalization::MINIMAL), "Ada Lovelace");
+    }
+
+    #[test]
+    fn test_spellings_of_one_name_agree() {
+        let composed = "Jos\u{00E9} O\u{2019}Brien";
+        let decomposed = "Jose\u{0301} O'Brien";
+        assert_eq!(normalize(composed), "Jose OBrien");
+        assert_eq!(normalize(decomposed), normalize(composed));
+        assert_eq!(
+            normalize_name(decomposed, &NameNormalization::MINIMAL),
+            normalize_name(composed, &NameNormalization::MINIMAL).replace('\u{2019}', "'")
+        );
+        // Fullwidth forms fold to ASCII
+        assert_eq!(normalize("ＡＤＡ"), "ADA");
+    }
+
+    #[test]
+    fn test_stripping_is_configurable() {
+        let name = "Jean-Luc Ærø";
+        assert_eq!(normalize(name), "Jean Luc AEro");
+        let keep_punctuation = NameNormalization {
+            strip_punctuation: false,
+            ..NameNormalization::default()
+        };
+        assert_eq!(normalize_name(name, &keep_punctuation), "Jean-Luc AEro");
+        assert_eq!(normalize_name(name, &NameNormalization::MINIMAL), name);
+    }
+
+    #[test]
+    fn test_other_scripts_are_kept() {
+        // Devanagari vowel signs are combining marks and must survive
+        assert_eq!(normalize(" प्रिया  शर्मा "), "प्रिया शर्मा");
+        assert_eq!(normalize("Ана-Мария 李"), "Ана Мария 李");
+    }
+}
@@ -0,0 +1,655 @@
+//! Typed engine options
+//!
+//! `EngineInput::options` is a free-form map, so a misspelt key such as
+//! `forcast_days` used to be ignored without a trace. Each engine that reads
+//! options declares them as a struct here; engines parse it with
+//! [`EngineInput::engine_options`], which rejects values of the wrong type,
+//! and report its keys through
+//! [`ConsciousnessEngine::option_keys`](crate::ConsciousnessEngine::option_keys).
+//! The orchestrator lists every other key in the output's
+//! `metadata.warnings` (see [`unknown_option_warnings`]), and the structs are
+//! exported as OpenAPI schemas so SDKs can offer the keys for completion.
+//!
+//! The keys of [`COMMON_OPTIONS`] are read outside the engines and are
+//! accepted everywhere. [`SETTINGS_OPTION`] carries defaults, such as a
+//! user's saved settings, that the orchestrator resolves against each
+//! engine's keys with [`EngineInput::apply_settings`] before it runs.
+
+use std::collections::HashMap;
+
+use serde::de::{self, DeserializeOwned, Deserializer, Visitor};
+use serde::{Deserialize, Serialize};
+use chrono::{DateTime, Utc};
+use serde_json::Value;
+
+use crate::{EngineError, EngineInput, ValidationCode, AS_OF_OPTION, DEBUG_OPTION, SEED_OPTION};
+
+#[cfg(feature = "openapi")]
+use utoipa::ToSchema;
+
+/// Key in [`EngineInput::options`] holding the consciousness level (0-5)
+/// witness prompts are written for
+pub const CONSCIOUSNESS_LEVEL_OPTION: &str = "consciousness_level";
+
+/// Options every engine accepts, on top of its own
+pub const COMMON_OPTIONS: [&str; 4] = [SEED_OPTION, AS_OF_OPTION, CONSCIOUSNESS_LEVEL_OPTION, DEBUG_OPTION];
+
+/// Key in [`EngineInput::options`] holding option defaults, such as a user's
+/// saved settings, as an object of option name to value
+pub const SETTINGS_OPTION: &str = "settings";
+
+/// A struct of the options one engine reads.
+///
+/// Fields are optional and missing keys take their defaults, so implementors
+/// derive `Deserialize` with `#[serde(default)]` on the container.
+pub trait TypedOptions: DeserializeOwned + Default {
+    /// Option keys the struct reads, taken from its serde field names
+    fn keys() -> &'static [&'static str] {
+        struct_fields::<Self>()
+    }
+}
+
+/// Options of an engine that reads none (numerology)
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct NoOptions {}
+
+impl TypedOptions for NoOptions {}
+
+/// Key in [`EngineInput::options`] holding a [`RelocateTo`]
+pub const RELOCATE_TO_OPTION: &str = "relocate_to";
+
+/// A hypothetical place to read location-sensitive outputs for, with the
+/// birth data and reading moment unchanged
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct RelocateTo {
+    /// Latitude in decimal degrees
+    #[cfg_attr(feature = "openapi", schema(example = 38.7223, minimum = -90, maximum = 90))]
+    pub lat: f64,
+    /// Longitude in decimal degrees
+    #[cfg_attr(feature = "openapi", schema(example = -9.1393, minimum = -180, maximum = 180))]
+    pub lon: f64,
+    /// IANA timezone or fixed offset; the API resolves it from the
+    /// coordinates when omitted, engines fall back to the nautical zone of
+    /// the longitude
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "openapi", schema(example = "Europe/Lisbon"))]
+    pub tz: Option<String>,
+}
+
+impl RelocateTo {
+    /// Reject coordinates out of range and unknown timezones
+    pub fn validate(&self) -> Result<(), EngineError> {
+        let field = |name: &str| format!("options.{}.{}", RELOCATE_TO_OPTION, name);
+        if !(-90.0..=90.0).contains(&self.lat) {
+            return Err(EngineError::invalid_field(
+                field("lat"),
+                ValidationCode::OutOfRange,
+                "Latitude must be between -90 and 90",
+            ));
+        }
+        if !(-180.0..=180.0).contains(&self.lon) {
+            return Err(EngineError::invalid_field(
+                field("lon"),
+                ValidationCode::OutOfRange,
+                "Longitude must be between -180 and 180",
+            ));
+        }
+        if let Some(tz) = &self.tz {
+            if !crate::timezone::is_known_timezone(tz.trim()) {
+                return Err(EngineError::invalid_field(
+                    field("tz"),
+                    ValidationCode::Unknown,
+                    format!("Unknown timezone '{}'", tz),
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    /// Offset in seconds of the place's civil time at `instant`
+    pub fn offset_at(&self, instant: DateTime<Utc>) -> Result<i32, EngineError> {
+        match self.tz.as_deref() {
+            Some(tz) => crate::timezone::zone_offset_at(tz, &format!("options.{}.tz", RELOCATE_TO_OPTION), instant),
+            None => Ok((self.lon / 15.0).round() as i32 * 3600),
+        }
+    }
+}
+
+/// Key in [`EngineInput::options`] holding a [`Constitution`]
+pub const CONSTITUTION_OPTION: &str = "constitution";
+
+/// A birth constitution (prakriti) as relative scores of the three doshas,
+/// e.g. from a questionnaire or the face-reading engine's analysis
+///
+/// Scores need not sum to 1; only their proportions are used.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct Constitution {
+    #[cfg_attr(feature = "openapi", schema(example = 0.2, minimum = 0))]
+    pub vata: f64,
+    #[cfg_attr(feature = "openapi", schema(example = 0.6, minimum = 0))]
+    pub pitta: f64,
+    #[cfg_attr(feature = "openapi", schema(example = 0.2, minimum = 0))]
+    pub kapha: f64,
+}
+
+impl Constitution {
+    /// Reject negative or non-finite scores and an all-zero constitution
+    pub fn validate(&self) -> Result<(), EngineError> {
+        for (name, score) in [("vata", self.vata), ("pitta", self.pitta), ("kapha", self.kapha)] {
+            if !score.is_finite() || score < 0.0 {
+                return Err(EngineError::invalid_field(
+                    format!("options.{}.{}", CONSTITUTION_OPTION, name),
+                    ValidationCode::OutOfRange,
+                    "Dosha scores must be non-negative numbers",
+                ));
+            }
+        }
+        if self.vata + self.pitta + self.kapha <= 0.0 {
+            return Err(EngineError::invalid_field(
+                format!("options.{}", CONSTITUTION_OPTION),
+                ValidationCode::OutOfRange,
+                "At least one dosha score must be positive",
+            ));
+        }
+        Ok(())
+    }
+
+    /// The scores scaled to sum to 1, as (vata, pitta, kapha)
+    pub fn shares(&self) -> (f64, f64, f64) {
+        let total = self.vata + self.pitta + self.kapha;
+        (self.vata / total, self.pitta / total, self.kapha / total)
+    }
+}
+
+/// Clock the vedic clock's organ and dosha windows are read on
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum TimeBasis {
+    /// Local wall-clock time
+    #[default]
+    Clock,
+    /// Apparent solar time: clock time shifted by the longitude's distance
+    /// from the timezone meridian and by the equation of time
+    Solar,
+}
+
+/// `panchanga` options
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+#[serde(default)]
+pub struct PanchangaOptions {
+    /// Local sunrise ("HH:MM") overriding the computed solar time
+    #[cfg_attr(feature = "openapi", schema(example = "06:12"))]
+    pub sunrise: Option<String>,
+    /// Local sunset ("HH:MM") overriding the computed solar time
+    #[cfg_attr(feature = "openapi", schema(example = "18:24"))]
+    pub sunset: Option<String>,
+    /// Sidereal reference: tropical (default), lahiri, raman, krishnamurti or fagan_bradley
+    #[cfg_attr(feature = "openapi", schema(example = "lahiri"))]
+    pub ayanamsa: Option<String>,
+    /// Reference time (RFC 3339 or YYYY-MM-DD) of a retrospective reading, calculated instead of the birth moment
+    #[cfg_attr(feature = "openapi", schema(example = "2020-03-01T09:00:00Z"))]
+    pub as_of: Option<String>,
+    /// Read sunrise, sunset and hora at this place instead, compared with home in `relocation`
+    pub relocate_to: Option<RelocateTo>,
+}
+
+impl TypedOptions for PanchangaOptions {}
+
+/// `numerology` options
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+#[serde(default)]
+pub struct NumerologyOptions {
+    /// Systems to read side by side in `systems`: pythagorean, chaldean,
+    /// kabbalistic (22-letter gematria) or vedic (psychic/destiny pairing)
+    #[cfg_attr(feature = "openapi", schema(example = json!(["pythagorean", "kabbalistic", "vedic"])))]
+    pub systems: Option<Vec<String>>,
+    /// Read a business name, address or phone number instead of a person;
+    /// birth data is then not needed
+    pub subject: Option<NumerologySubject>,
+}
+
+impl TypedOptions for NumerologyOptions {}
+
+/// What a [`NumerologySubject`] is
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum NumerologySubjectKind {
+    Business,
+    Address,
+    Phone,
+}
+
+/// A name or number read by `numerology` without birth data
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct NumerologySubject {
+    pub kind: NumerologySubjectKind,
+    #[cfg_attr(feature = "openapi", schema(example = "Blue Lotus Studio"))]
+    pub value: String,
+}
+
+/// `biorhythm` options
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+#[serde(default)]
+pub struct BiorhythmOptions {
+    /// Number of days after `current_time` to forecast (default 7)
+    #[cfg_attr(feature = "openapi", schema(example = 7, minimum = 0))]
+    pub forecast_days: Option<i64>,
+    /// Reference time (RFC 3339 or YYYY-MM-DD) replacing `current_time` for every engine of a workflow
+    #[cfg_attr(feature = "openapi", schema(example = "2020-03-01T09:00:00Z"))]
+    pub as_of: Option<String>,
+}
+
+impl TypedOptions for BiorhythmOptions {}
+
+/// `human-design` options
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+#[serde(default)]
+pub struct HumanDesignOptions {
+    /// How the Design moment is placed before birth: arc_88deg (default, the
+    /// Sun 88° before its birth longitude) or days_88 (exactly 88 days earlier)
+    #[cfg_attr(feature = "openapi", schema(example = "arc_88deg"))]
+    pub design_method: Option<String>,
+    /// Minutes of uncertainty either side of the birth time (1-720). When
+    /// set, the result gains a `rectification` listing where type,
+    /// authority, profile or definition change across the window.
+    #[cfg_attr(feature = "openapi", schema(example = 120, minimum = 1, maximum = 720))]
+    pub rectify_window_minutes: Option<u32>,
+    /// Depth of the witness prompt (0-5)
+    #[cfg_attr(feature = "openapi", schema(example = 1, minimum = 0, maximum = 5))]
+    pub consciousness_level: Option<u8>,
+}
+
+impl TypedOptions for HumanDesignOptions {}
+
+/// Gate numbers for the Gene Keys activation sequence
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct HdGates {
+    #[cfg_attr(feature = "openapi", schema(example = 17, minimum = 1, maximum = 64))]
+    pub personality_sun: u8,
+    #[cfg_attr(feature = "openapi", schema(example = 18, minimum = 1, maximum = 64))]
+    pub personality_earth: u8,
+    #[cfg_attr(feature = "openapi", schema(example = 45, minimum = 1, maximum = 64))]
+    pub design_sun: u8,
+    #[cfg_attr(feature = "openapi", schema(example = 26, minimum = 1, maximum = 64))]
+    pub design_earth: u8,
+}
+
+/// `gene-keys` options. `hd_gates` is required when `birth_data` is absent.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+#[serde(default)]
+pub struct GeneKeysOptions {
+    pub hd_gates: Option<HdGates>,
+    /// Depth of the witness prompt (0-5)
+    #[cfg_attr(feature = "openapi", schema(example = 2, minimum = 0, maximum = 5))]
+    pub consciousness_level: Option<u8>,
+}
+
+impl TypedOptions for GeneKeysOptions {}
+
+/// `vimshottari` options. Used instead of `birth_data` when the Moon's
+/// sidereal longitude is already known.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+#[serde(default)]
+pub struct VimshottariOptions {
+    /// Sidereal Moon longitude in degrees (0-360)
+    #[cfg_attr(feature = "openapi", schema(example = 125.5))]
+    pub moon_longitude: Option<f64>,
+    /// Birth date (YYYY-MM-DD), required with `moon_longitude`
+    #[cfg_attr(feature = "openapi", schema(example = "1985-06-15"))]
+    pub birth_date: Option<String>,
+    /// Birth time (HH:MM, UTC)
+    #[cfg_attr(feature = "openapi", schema(example = "14:30"))]
+    pub birth_time: Option<String>,
+    /// Reference time (RFC 3339 or YYYY-MM-DD) replacing `current_time` for every engine of a workflow
+    #[cfg_attr(feature = "openapi", schema(example = "2020-03-01T09:00:00Z"))]
+    pub as_of: Option<String>,
+    /// Add `dasha_transits`: the running dasha lords' transits, their aspects
+    /// to the natal chart and flagged combinations with severities
+    #[cfg_attr(feature = "openapi", schema(example = true))]
+    pub include_transits: Option<bool>,
+    #[cfg_attr(feature = "openapi", schema(example = 3, minimum = 0, maximum = 5))]
+    pub consciousness_level: Option<u8>,
+}
+
+impl TypedOptions for VimshottariOptions {}
+
+/// `vedic-clock` options
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+#[serde(default)]
+pub struct VedicClockOptions {
+    /// UTC offset in minutes for `current_time`; defaults to the birth timezone's offset, else 0
+    #[cfg_attr(feature = "openapi", schema(example = 330))]
+    pub timezone_offset: Option<i32>,
+    /// Reference time (RFC 3339 or YYYY-MM-DD) replacing `current_time` for every engine of a workflow
+    #[cfg_attr(feature = "openapi", schema(example = "2020-03-01T09:00:00Z"))]
+    pub as_of: Option<String>,
+    /// Activity to time: meditation, exercise, work, eating, sleep, creative, social
+    #[cfg_attr(feature = "openapi", schema(example = "meditation"))]
+    pub activity: Option<String>,
+    /// Tithi index (0-29) from a prior panchanga calculation
+    pub tithi_index: Option<u8>,
+    /// Nakshatra index (0-26) from a prior panchanga calculation
+    pub nakshatra_index: Option<u8>,
+    /// Local sunrise ("HH:MM") for the planetary hour; needs `sunset`
+    #[cfg_attr(feature = "openapi", schema(example = "06:12"))]
+    pub sunrise: Option<String>,
+    /// Local sunset ("HH:MM") for the planetary hour; needs `sunrise`
+    #[cfg_attr(feature = "openapi", schema(example = "18:24"))]
+    pub sunset: Option<String>,
+    /// Seed for reproducible witness prompt selection
+    #[cfg_attr(feature = "openapi", schema(example = 42))]
+    pub seed: Option<u64>,
+    #[cfg_attr(feature = "openapi", schema(minimum = 0, maximum = 5))]
+    pub consciousness_level: Option<u8>,
+    /// Read the organ, dosha and hora windows at this place instead, compared
+    /// with home in `relocation`
+    pub relocate_to: Option<RelocateTo>,
+    /// Birth constitution weighting activity timing: windows of a dosha the
+    /// person already has in excess count for less
+    pub constitution: Option<Constitution>,
+    /// Read the organ and dosha windows on clock time (default) or on
+    /// apparent solar time at the location; the shift is reported in `time_basis`
+    pub time_basis: TimeBasis,
+}
+
+impl TypedOptions for VedicClockOptions {}
+
+/// `face-reading` options
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+#[serde(default)]
+pub struct FaceReadingOptions {
+    /// Seed for reproducible simulated analysis
+    #[cfg_attr(feature = "openapi", schema(example = 42))]
+    pub seed: Option<u64>,
+    /// Base64-encoded image (reserved for image analysis)
+    pub image_data: Option<String>,
+    /// Image URL (reserved for image analysis)
+    pub image_url: Option<String>,
+}
+
+impl TypedOptions for FaceReadingOptions {}
+
+/// `biofield` options
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+#[serde(default)]
+pub struct BiofieldOptions {
+    /// Seed for reproducible simulated metrics
+    #[cfg_attr(feature = "openapi", schema(example = 42))]
+    pub seed: Option<u64>,
+    /// Reads as this user instead of the authenticated one
+    pub user_id: Option<String>,
+    #[cfg_attr(feature = "openapi", schema(minimum = 0, maximum = 5))]
+    pub consciousness_level: Option<u8>,
+}
+
+impl TypedOptions for BiofieldOptions {}
+
+impl EngineInput {
+    /// `options` as the engine's typed struct.
+    ///
+    /// Keys the struct doesn't read are left alone; a value of the wrong
+    /// type fails with `InvalidInput` on `options.<key>`.
+    pub fn engine_options<T: TypedOptions>(&self) -> Result<T, EngineError> {
+        let known = T::keys();
+        let map: serde_json::Map<String, Value> = self
+            .options
+            .iter()
+            .filter(|(key, _)| known.contains(&key.as_str()))
+            .map(|(key, value)| (key.clone(), value.clone()))
+            .collect();
+        serde_json::from_value(Value::Object(map.clone())).map_err(|e| {
+            // Name the offending key by retrying them one at a time
+            let key = map
+                .iter()
+                .find(|(key, value)| {
+                    let single = serde_json::Map::from_iter([((*key).clone(), (*value).clone())]);
+                    serde_json::from_value::<T>(Value::Object(single)).is_err()
+                })
+                .map(|(key, _)| key.as_str())
+                .unwrap_or("");
+            EngineError::invalid_field(format!("options.{}", key), ValidationCode::InvalidFormat, e.to_string())
+        })
+    }
+
+    /// Resolve [`SETTINGS_OPTION`] into `options` for an engine reading `known`.
+    ///
+    /// Each entry the engine reads, or that is one of [`COMMON_OPTIONS`], is
+    /// copied unless `options` already sets it, so explicit options win.
+    /// Entries the engine doesn't read are dropped rather than warned about,
+    /// since defaults are meant for many engines. `None` means the engine
+    /// doesn't declare its keys, and every entry is copied. [`DEBUG_OPTION`]
+    /// is never taken from defaults: callers gate it per request.
+    pub fn apply_settings(&mut self, known: Option<&[&str]>) {
+        let Some(Value::Object(settings)) = self.options.remove(SETTINGS_OPTION) else {
+            return;
+        };
+        for (key, value) in settings {
+            let read = key != SETTINGS_OPTION
+                && key != DEBUG_OPTION
+                && known.is_none_or(|known| known.contains(&key.as_str()) || COMMON_OPTIONS.contains(&key.as_str()));
+            if read {
+                self.options.entry(key).or_insert(value);
+            }
+        }
+    }
+}
+
+/// Keys of `options` that neither `known` nor [`COMMON_OPTIONS`] contain, sorted
+pub fn unknown_options<'a>(known: &[&str], options: &'a HashMap<String, Value>) -> Vec<&'a str> {
+    let mut unknown: Vec<&str> = options
+        .keys()
+        .map(String::as_str)
+        .filter(|key| !known.contains(key) && !COMMON_OPTIONS.contains(key))
+        .collect();
+    unknown.sort_unstable();
+    unknown
+}
+
+/// One warning per key of `options` that `engine_id` ignores, suggesting
+/// the known key it is most likely a typo of
+pub fn unknown_option_warnings(engine_id: &str, known: &[&str], options: &HashMap<String, Value>) -> Vec<String> {
+    unknown_options(known, options)
+        .into_iter()
+        .map(|key| {
+            let suggestion = known
+                .iter()
+                .chain(COMMON_OPTIONS.iter())
+                .map(|candidate| (edit_distance(key, candidate), candidate))
+                .filter(|(distance, _)| *distance <= 2)
+                .min()
+                .map(|(_, candidate)| format!("; did you mean '{}'?", candidate))
+                .unwrap_or_default();
+            format!("Unknown option '{}' ignored by {}{}", key, engine_id, suggestion)
+        })
+        .collect()
+}
+
+/// Levenshtein distance between two keys
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let above = row[j + 1];
+            row[j + 1] = (above + 1).min(row[j] + 1).min(diagonal + usize::from(ca != *cb));
+            diagonal = above;
+        }
+    }
+    row[b.len()]
+}
+
+/// Field names a derived `Deserialize` struct asks its deserializer for
+fn struct_fields<T: DeserializeOwned>() -> &'static [&'static str] {
+    struct Probe<'a>(&'a mut &'static [&'static str]);
+
+    impl<'de> Deserializer<'de> for Probe<'_> {
+        type Error = de::value::Error;
+
+        fn deserialize_any<V: Visitor<'de>>(self, _visitor: V) -> Result<V::Value, Self::Error> {
+            Err(de::Error::custom("not a struct"))
+        }
+
+        fn deserialize_struct<V: Visitor<'de>>(
+            self,
+            _name: &'static str,
+            fields: &'static [&'static str],
+            _visitor: V,
+        ) -> Result<V::Value, Self::Error> {
+            *self.0 = fields;
+            Err(de::Error::custom("fields recorded"))
+        }
+
+        serde::forward_to_deserialize_any! {
+            bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+            bytes byte_buf option unit unit_struct newtype_struct seq tuple
+            tuple_struct map enum identifier ignored_any
+        }
+    }
+
+    let mut fields: &'static [&'static str] = &[];
+    let _ = T::deserialize(Probe(&mut fields));
+    fields
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Precision;
+    use chrono::Utc;
+    use serde_json::json;
+
+    fn input(options: Value) -> EngineInput {
+        EngineInput {
+            birth_data: None,
+            secondary_birth_data: None,
+            subjects: Vec::new(),
+            current_time: Utc::now(),
+            location: None,
+            precision: Precision::Standard,
+            options: serde_json::from_value(options).unwrap(),
+        }
+    }
+
+    #[test]
+    fn test_keys_follow_struct_fields() {
+        assert_eq!(BiorhythmOptions::keys(), ["forecast_days", "as_of"]);
+        assert_eq!(PanchangaOptions::keys(), ["sunrise", "sunset", "ayanamsa", "as_of", "relocate_to"]);
+        assert_eq!(NumerologyOptions::keys(), ["systems", "subject"]);
+        assert!(NoOptions::keys().is_empty());
+    }
+
+    #[test]
+    fn test_apply_settings() {
+        let settings = json!({"ayanamsa": "raman", "design_method": "days_88", "consciousness_level": 2, "as_of": null});
+
+        let mut panchanga = input(json!({"settings": settings, "ayanamsa": "lahiri"}));
+        panchanga.apply_settings(Some(PanchangaOptions::keys()));
+        assert_eq!(
+            serde_json::to_value(&panchanga.options).unwrap(),
+            json!({"ayanamsa": "lahiri", "consciousness_level": 2, "as_of": null})
+        );
+
+        let mut undeclared = input(json!({"settings": settings}));
+        undeclared.apply_settings(None);
+        assert_eq!(serde_json::to_value(&undeclared.options).unwrap(), settings);
+
+        let mut debug = input(json!({"settings": {"debug": true}}));
+        debug.apply_settings(None);
+        assert!(!debug.debug());
+
+        let mut not_an_object = input(json!({"settings": "lahiri"}));
+        not_an_object.apply_settings(None);
+        assert!(not_an_object.options.is_empty());
+    }
+
+    #[test]
+    fn test_engine_options_parse_and_reject_wrong_types() {
+        let parsed: BiorhythmOptions = input(json!({"forecast_days": 30, "seed": 4})).engine_options().unwrap();
+        assert_eq!(parsed.forecast_days, Some(30));
+        assert_eq!(input(json!({})).engine_options::<BiorhythmOptions>().unwrap(), BiorhythmOptions::default());
+
+        let err = input(json!({"ayanamsa": "lahiri", "sunrise": 6}))
+            .engine_options::<PanchangaOptions>()
+            .unwrap_err();
+        match err {
+            EngineError::InvalidInput(errors) => {
+                assert_eq!(errors[0].field, "options.sunrise");
+                assert_eq!(errors[0].code, ValidationCode::InvalidFormat);
+            }
+            other => panic!("unexpected error {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_relocate_to() {
+        let parsed: PanchangaOptions =
+            input(json!({"relocate_to": {"lat": 38.72, "lon": -9.14, "tz": "Europe/Lisbon"}})).engine_options().unwrap();
+        let relocate_to = parsed.relocate_to.unwrap();
+        assert!(relocate_to.validate().is_ok());
+        let summer = chrono::NaiveDate::from_ymd_opt(2024, 7, 1).unwrap().and_hms_opt(12, 0, 0).unwrap().and_utc();
+        assert_eq!(relocate_to.offset_at(summer).unwrap(), 3600);
+
+        // Without a timezone, the nautical zone of the longitude
+        let at_sea = RelocateTo { lat: 0.0, lon: -61.0, tz: None };
+        assert_eq!(at_sea.offset_at(summer).unwrap(), -4 * 3600);
+
+        let err = RelocateTo { lat: 95.0, ..at_sea }.validate().unwrap_err();
+        assert!(matches!(err, EngineError::InvalidInput(ref e) if e[0].field == "options.relocate_to.lat"));
+    }
+
+    #[test]
+    fn test_constitution() {
+        let parsed: VedicClockOptions =
+            input(json!({"constitution": {"vata": 1, "pitta": 3, "kapha": 1}})).engine_options().unwrap();
+        let constitution = parsed.constitution.unwrap();
+        assert!(constitution.validate().is_ok());
+        assert_eq!(constitution.shares(), (0.2, 0.6, 0.2));
+
+        let err = Constitution { kapha: -0.1, ..constitution }.validate().unwrap_err();
+        assert!(matches!(err, EngineError::InvalidInput(ref e) if e[0].field == "options.constitution.kapha"));
+        let none = Constitution { vata: 0.0, pitta: 0.0, kapha: 0.0 };
+        assert!(matches!(none.validate(), Err(EngineError::InvalidInput(ref e)) if e[0].field == "options.constitution"));
+    }
+
+    #[test]
+    fn test_time_basis() {
+        let parsed: VedicClockOptions = input(json!({})).engine_options().unwrap();
+        assert_eq!(parsed.time_basis, TimeBasis::Clock);
+        let parsed: VedicClockOptions = input(json!({"time_basis": "solar"})).engine_options().unwrap();
+        assert_eq!(parsed.time_basis, TimeBasis::Solar);
+        let err = input(json!({"time_basis": "sidereal"})).engine_options::<VedicClockOptions>().unwrap_err();
+        assert!(matches!(err, EngineError::InvalidInput(ref e) if e[0].field == "options.time_basis"));
+    }
+
+    #[test]
+    fn test_unknown_option_warnings() {
+        let options = input(json!({"forcast_days": 3, "seed": 1, "colour": "red"})).options;
+        assert_eq!(unknown_options(BiorhythmOptions::keys(), &options), ["colour", "forcast_days"]);
+
+        let warnings = unknown_option_warnings("biorhythm", BiorhythmOptions::keys(), &options);
+        assert_eq!(warnings.len(), 2);
+        assert_eq!(warnings[0], "Unknown option 'colour' ignored by biorhythm");
+        assert_eq!(
+            warnings[1],
+            "Unknown option 'forcast_days' ignored by biorhythm; did you mean 'forecast_days'?"
+        );
+    }
+}
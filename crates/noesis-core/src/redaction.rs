@@ -0,0 +1,399 @@
+//! Redaction of personal data in engine outputs
+//!
+//! Outputs echo the birth data they were calculated from (Vimshottari's
+//! `birth_date`, for example) and witness prompts may address the person by
+//! name. [`RedactionPolicies`] removes or hashes that data before an output
+//! leaves the request that produced it, with one [`RedactionMode`] per
+//! [`RedactionTarget`]:
+//!
+//! - `logging`: outputs written to logs
+//! - `sharing`: results served through public share links
+//! - `export`: results sent outside the deployment, including the LLM
+//!   synthesis adapter
+//!
+//! Two things are redacted. Keys named in [`fields`](RedactionPolicies::fields)
+//! are removed or hashed at any depth of the result. When the input is at
+//! hand, the subjects' names, birth dates and birth times are also replaced
+//! wherever they appear in the result, witness prompt and summary. Shared
+//! results no longer have their input, so only the key rule applies to them.
+
+use std::fmt;
+use std::str::FromStr;
+
+use serde_json::Value;
+use sha2::{Digest, Sha256};
+
+use crate::types::{EngineInput, EngineOutput, SubjectRole, WorkflowResult};
+
+/// Keys holding personal data in engine results, redacted by default
+pub const DEFAULT_PII_FIELDS: &[&str] = &[
+    "birth_data",
+    "secondary_birth_data",
+    "subjects",
+    "birth_date",
+    "birth_time",
+    "birth_location",
+];
+
+/// Text that replaces a stripped value inside a string
+pub const REDACTED: &str = "[redacted]";
+
+/// Where a redacted output is going
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RedactionTarget {
+    Logging,
+    Sharing,
+    Export,
+}
+
+/// What happens to personal data on its way to a [`RedactionTarget`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RedactionMode {
+    /// Left as is
+    Off,
+    /// Keys removed, text replaced with [`REDACTED`]
+    Strip,
+    /// Replaced with a salted hash, so equal values still match up
+    Hash,
+}
+
+impl RedactionMode {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            RedactionMode::Off => "off",
+            RedactionMode::Strip => "strip",
+            RedactionMode::Hash => "hash",
+        }
+    }
+}
+
+impl fmt::Display for RedactionMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for RedactionMode {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "off" | "none" => Ok(RedactionMode::Off),
+            "strip" => Ok(RedactionMode::Strip),
+            "hash" => Ok(RedactionMode::Hash),
+            other => Err(format!("unknown redaction mode '{}' (expected off, strip or hash)", other)),
+        }
+    }
+}
+
+/// A deployment's redaction settings
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RedactionPolicies {
+    pub logging: RedactionMode,
+    pub sharing: RedactionMode,
+    pub export: RedactionMode,
+    /// Result keys removed or hashed at any depth
+    pub fields: Vec<String>,
+    /// Mixed into every hash, so hashed birth dates can't be looked up in a
+    /// table of all dates
+    pub hash_salt: String,
+}
+
+impl Default for RedactionPolicies {
+    /// Hash in logs, so entries for one person can still be correlated;
+    /// strip everywhere else
+    fn default() -> Self {
+        Self {
+            logging: RedactionMode::Hash,
+            sharing: RedactionMode::Strip,
+            export: RedactionMode::Strip,
+            fields: DEFAULT_PII_FIELDS.iter().map(|f| f.to_string()).collect(),
+            hash_salt: String::new(),
+        }
+    }
+}
+
+impl RedactionPolicies {
+    /// Nothing redacted anywhere
+    pub fn disabled() -> Self {
+        Self {
+            logging: RedactionMode::Off,
+            sharing: RedactionMode::Off,
+            export: RedactionMode::Off,
+            ..Self::default()
+        }
+    }
+
+    pub fn mode(&self, target: RedactionTarget) -> RedactionMode {
+        match target {
+            RedactionTarget::Logging => self.logging,
+            RedactionTarget::Sharing => self.sharing,
+            RedactionTarget::Export => self.export,
+        }
+    }
+
+    /// Redact `output` for `target`. `input` is the request it was
+    /// calculated from, when still available.
+    pub fn redact_output(&self, output: &mut EngineOutput, target: RedactionTarget, input: Option<&EngineInput>) {
+        let Some(redactor) = self.redactor(target, input) else {
+            return;
+        };
+        redactor.value(&mut output.result);
+        redactor.text(&mut output.witness_prompt);
+        if let Some(summary) = output.summary.as_mut() {
+            redactor.text(summary);
+        }
+    }
+
+    /// Redact every engine output and the synthesis of `result` for `target`
+    pub fn redact_workflow_result(
+        &self,
+        result: &mut WorkflowResult,
+        target: RedactionTarget,
+        input: Option<&EngineInput>,
+    ) {
+        let Some(redactor) = self.redactor(target, input) else {
+            return;
+        };
+        for output in result.engine_outputs.values_mut() {
+            redactor.value(&mut output.result);
+            redactor.text(&mut output.witness_prompt);
+            if let Some(summary) = output.summary.as_mut() {
+                redactor.text(summary);
+            }
+        }
+        if let Some(synthesis) = result.synthesis.as_mut() {
+            redactor.value(synthesis);
+        }
+    }
+
+    /// Redact free text, such as a summary bound for the narrator
+    pub fn redact_text(&self, text: &mut String, target: RedactionTarget, input: Option<&EngineInput>) {
+        if let Some(redactor) = self.redactor(target, input) {
+            redactor.text(text);
+        }
+    }
+
+    /// `output` as JSON, redacted for logging
+    pub fn loggable(&self, output: &EngineOutput, input: Option<&EngineInput>) -> String {
+        let mut output = output.clone();
+        self.redact_output(&mut output, RedactionTarget::Logging, input);
+        serde_json::to_string(&output).unwrap_or_default()
+    }
+
+    fn redactor(&self, target: RedactionTarget, input: Option<&EngineInput>) -> Option<Redactor<'_>> {
+        let mode = self.mode(target);
+        if mode == RedactionMode::Off {
+            return None;
+        }
+        Some(Redactor {
+            mode,
+            fields: &self.fields,
+            salt: &self.hash_salt,
+            identifiers: input.map(identifiers).unwrap_or_default(),
+        })
+    }
+}
+
+/// Names as normalized and as given, name parts, birth dates and birth times
+/// of the input's subjects, longest first so a full name is replaced before
+/// its parts
+fn identifiers(input: &EngineInput) -> Vec<String> {
+    let mut identifiers = Vec::new();
+    for birth in [SubjectRole::Primary, SubjectRole::Secondary]
+        .into_iter()
+        .filter_map(|role| input.subject(role))
+    {
+        let names = [birth.name.as_deref(), birth.display_name.as_deref()];
+        for name in names.into_iter().flatten().map(str::trim).filter(|name| !name.is_empty()) {
+            identifiers.push(name.to_string());
+            // Single letters and particles ("de", "al") would hit ordinary words
+            identifiers.extend(
+                name.split_whitespace()
+                    .filter(|part| part.chars().count() >= 3)
+                    .map(str::to_string),
+            );
+        }
+        identifiers.push(birth.date.clone());
+        identifiers.extend(birth.time.clone());
+    }
+    identifiers.retain(|id| !id.is_empty());
+    identifiers.sort_by(|a, b| b.len().cmp(&a.len()).then_with(|| a.cmp(b)));
+    identifiers.dedup();
+    identifiers
+}
+
+struct Redactor<'a> {
+    mode: RedactionMode,
+    fields: &'a [String],
+    salt: &'a str,
+    identifiers: Vec<String>,
+}
+
+impl Redactor<'_> {
+    fn hash(&self, data: &str) -> String {
+        let digest = Sha256::digest(format!("{}{}", self.salt, data).as_bytes());
+        format!("sha256:{}", &format!("{:x}", digest)[..16])
+    }
+
+    fn value(&self, value: &mut Value) {
+        match value {
+            Value::Object(map) => {
+                if self.mode == RedactionMode::Strip {
+                    map.retain(|key, _| !self.fields.contains(key));
+                }
+                for (key, field) in map.iter_mut() {
+                    if self.fields.contains(key) {
+                        *field = Value::String(self.hash(&field.to_string()));
+                    } else {
+                        self.value(field);
+                    }
+                }
+            }
+            Value::Array(items) => items.iter_mut().for_each(|item| self.value(item)),
+            Value::String(text) => self.text(text),
+            _ => {}
+        }
+    }
+
+    fn text(&self, text: &mut String) {
+        for identifier in &self.identifiers {
+            if let Some(replaced) = replace_word(text, identifier, || match self.mode {
+                RedactionMode::Hash => self.hash(identifier),
+                _ => REDACTED.to_string(),
+            }) {
+                *text = replaced;
+            }
+        }
+    }
+}
+
+/// `text` with every whole-word, ASCII-case-insensitive occurrence of `word`
+/// replaced, or `None` when there is none
+fn replace_word(text: &str, word: &str, replacement: impl Fn() -> String) -> Option<String> {
+    let is_word_char = |c: char| c.is_alphanumeric();
+    let mut out = String::with_capacity(text.len());
+    let mut rest = text;
+    let mut replaced = false;
+    let mut previous: Option<char> = None;
+    while !rest.is_empty() {
+        let matches = !previous.is_some_and(is_word_char)
+            && rest
+                .get(..word.len())
+                .is_some_and(|candidate| candidate.eq_ignore_ascii_case(word))
+            && !rest[word.len()..].chars().next().is_some_and(is_word_char);
+        if matches {
+            out.push_str(&replacement());
+            previous = word.chars().last();
+            rest = &rest[word.len()..];
+            replaced = true;
+        } else {
+            let c = rest.chars().next().expect("rest is not empty");
+            out.push(c);
+            previous = Some(c);
+            rest = &rest[c.len_utf8()..];
+        }
+    }
+    replaced.then_some(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{BirthData, CalculationMetadata};
+    use chrono::Utc;
+    use serde_json::json;
+
+    fn input() -> EngineInput {
+        serde_json::from_value(json!({
+            "birth_data": {
+                "name": "Maya Devi Rao",
+                "date": "1990-01-15",
+                "time": "14:30",
+                "latitude": 12.9716,
+                "longitude": 77.5946,
+                "timezone": "Asia/Kolkata"
+            }
+        }))
+        .unwrap()
+    }
+
+    fn output() -> EngineOutput {
+        EngineOutput {
+            engine_id: "vimshottari".to_string(),
+            result: json!({
+                "timeline": { "birth_date": "1990-01-15", "total_years": 120 },
+                "birth_nakshatra": { "name": "Rohini" },
+                "note": "Maya, born 1990-01-15 at 14:30"
+            }),
+            witness_prompt: "What does Maya notice about mayapur?".to_string(),
+            summary: Some("Maya Devi Rao is in a Venus period.".to_string()),
+            consciousness_level: 0,
+            metadata: CalculationMetadata {
+                calculation_time_ms: 1.0,
+                backend: "native".to_string(),
+                precision_achieved: "standard".to_string(),
+                cached: false,
+                timestamp: Utc::now(),
+                engine_version: None,
+                warnings: vec![],
+                usage: None,
+            },
+        }
+    }
+
+    #[test]
+    fn test_strip_removes_fields_and_identifiers() {
+        let mut out = output();
+        RedactionPolicies::default().redact_output(&mut out, RedactionTarget::Sharing, Some(&input()));
+
+        assert_eq!(out.result["timeline"], json!({ "total_years": 120 }));
+        assert_eq!(out.result["birth_nakshatra"]["name"], "Rohini");
+        assert_eq!(out.result["note"], "[redacted], born [redacted] at [redacted]");
+        // Whole words only: "mayapur" is not the name
+        assert_eq!(out.witness_prompt, "What does [redacted] notice about mayapur?");
+        assert_eq!(out.summary.as_deref(), Some("[redacted] is in a Venus period."));
+    }
+
+    #[test]
+    fn test_hash_is_salted_and_stable() {
+        let policies = RedactionPolicies {
+            hash_salt: "deployment-a".to_string(),
+            ..RedactionPolicies::default()
+        };
+        let mut first = output();
+        let mut second = output();
+        policies.redact_output(&mut first, RedactionTarget::Logging, None);
+        policies.redact_output(&mut second, RedactionTarget::Logging, None);
+
+        let hashed = first.result["timeline"]["birth_date"].as_str().unwrap().to_string();
+        assert!(hashed.starts_with("sha256:") && !hashed.contains("1990"));
+        assert_eq!(second.result["timeline"]["birth_date"], hashed.as_str());
+        // Without the input, text can't be matched against the subject
+        assert_eq!(first.witness_prompt, "What does Maya notice about mayapur?");
+
+        let mut other_salt = output();
+        RedactionPolicies::default().redact_output(&mut other_salt, RedactionTarget::Logging, None);
+        assert_ne!(other_salt.result["timeline"]["birth_date"], hashed.as_str());
+    }
+
+    #[test]
+    fn test_off_and_modes() {
+        let mut out = output();
+        RedactionPolicies::disabled().redact_output(&mut out, RedactionTarget::Export, Some(&input()));
+        assert_eq!(out.result, output().result);
+
+        assert_eq!("HASH".parse::<RedactionMode>(), Ok(RedactionMode::Hash));
+        assert_eq!("none".parse::<RedactionMode>(), Ok(RedactionMode::Off));
+        assert!("mask".parse::<RedactionMode>().is_err());
+
+        // Short name parts are left alone, the full name is not
+        let mut text = "Al Li met Al".to_string();
+        let input = EngineInput {
+            birth_data: Some(BirthData { name: Some("Al Li".to_string()), ..input().birth_data.unwrap() }),
+            ..input()
+        };
+        RedactionPolicies::default().redact_text(&mut text, RedactionTarget::Export, Some(&input));
+        assert_eq!(text, "[redacted] met Al");
+    }
+}
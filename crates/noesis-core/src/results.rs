@@ -0,0 +1,775 @@
+//! Typed views of engine results
+//!
+//! `EngineOutput.result` is a `serde_json::Value` so that bridged TypeScript
+//! engines can return arbitrary documents. The structs here mirror the JSON
+//! emitted by the Rust engines, letting consumers in other crates read results
+//! with [`EngineOutput::parse_as`] instead of indexing by string key.
+//!
+//! Fields an engine only emits in some modes are `Option` and default when
+//! absent. Each engine crate has a schema test asserting its output still
+//! parses into the matching struct.
+
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::birth_time::TimeStability;
+use crate::hora::{Hora, HoraPlanet};
+use crate::options::RelocateTo;
+use crate::timezone::ResolvedOffset;
+use crate::{EngineError, EngineOutput};
+
+// ---------------------------------------------------------------------------
+// Panchanga
+// ---------------------------------------------------------------------------
+
+/// Result of the `panchanga` engine
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PanchangaResult {
+    pub tithi_index: u8,
+    pub tithi_name: String,
+    pub tithi_value: f64,
+    pub nakshatra_index: u8,
+    pub nakshatra_name: String,
+    pub nakshatra_value: f64,
+    pub yoga_index: u8,
+    pub yoga_name: String,
+    pub yoga_value: f64,
+    pub karana_index: u8,
+    pub karana_name: String,
+    pub karana_value: f64,
+    /// Day of week, 0 = Sunday
+    pub vara_index: u8,
+    pub vara_name: String,
+    pub solar_longitude: f64,
+    pub lunar_longitude: f64,
+    pub julian_day: f64,
+    #[serde(default)]
+    pub hora: Option<Hora>,
+    /// Local sunrise ("HH:MM")
+    #[serde(default)]
+    pub sunrise: Option<String>,
+    /// Local sunset ("HH:MM")
+    #[serde(default)]
+    pub sunset: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timezone_resolution: Option<ResolvedOffset>,
+    /// Sidereal reference ("lahiri", ...); absent for tropical longitudes
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ayanamsa: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ayanamsa_degrees: Option<f64>,
+    /// Home reading, when the result was read for `options.relocate_to`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub relocation: Option<Relocation>,
+    /// Personal strength of the day of `as_of`, else of `current_time`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub daily_strength: Option<DailyStrength>,
+    /// How the reading holds across the birth day, when the birth time is unknown
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub time_stability: Option<TimeStability>,
+}
+
+/// Tarabala: the day's nakshatra counted from the birth nakshatra
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Tarabala {
+    /// 1-27, the birth nakshatra being 1
+    pub count: u8,
+    /// 1-9 within the cycle of nine taras
+    pub tara: u8,
+    /// Janma, Sampat, Vipat, Kshema, Pratyari, Sadhana, Naidhana, Mitra or Parama Mitra
+    pub name: String,
+    /// 0-1
+    pub score: f64,
+    pub favourable: bool,
+}
+
+/// Chandrabala: the day's Moon sign counted from the natal Moon sign
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Chandrabala {
+    /// 1-12, the natal Moon sign being 1
+    pub house: u8,
+    /// 0-1
+    pub score: f64,
+    pub favourable: bool,
+    /// The 8th house from the natal Moon, the day to avoid new undertakings
+    pub chandrashtama: bool,
+}
+
+/// Personal strength of a day, from the Moon at local sunrise against the
+/// natal Moon
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DailyStrength {
+    /// Local date, YYYY-MM-DD
+    pub date: String,
+    pub birth_nakshatra: String,
+    pub nakshatra: String,
+    pub tarabala: Tarabala,
+    pub chandrabala: Chandrabala,
+    /// Mean of the two scores, 0-1
+    pub score: f64,
+}
+
+// ---------------------------------------------------------------------------
+// Relocation
+// ---------------------------------------------------------------------------
+
+/// A reading for `options.relocate_to` set against the same reading at home
+/// (the current location, else the birth place)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Relocation {
+    pub relocated_to: RelocateTo,
+    /// Location-sensitive values at home
+    pub home: BTreeMap<String, Value>,
+    /// The same values at `relocated_to`
+    pub relocated: BTreeMap<String, Value>,
+    /// Keys whose value differs between the two, in key order
+    pub changed: Vec<String>,
+}
+
+impl Relocation {
+    pub fn compare(
+        relocated_to: RelocateTo,
+        home: BTreeMap<String, Value>,
+        relocated: BTreeMap<String, Value>,
+    ) -> Self {
+        let changed = home
+            .iter()
+            .filter(|(key, value)| relocated.get(*key) != Some(value))
+            .map(|(key, _)| key.clone())
+            .collect();
+        Self {
+            relocated_to,
+            home,
+            relocated,
+            changed,
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Numerology
+// ---------------------------------------------------------------------------
+
+/// A reduced numerology number
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NumerologyNumber {
+    pub value: u32,
+    pub is_master: bool,
+    pub reduction_chain: Vec<u32>,
+    pub meaning: String,
+}
+
+/// Result of the `numerology` engine
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NumerologyResult {
+    pub life_path: NumerologyNumber,
+    pub expression: NumerologyNumber,
+    pub soul_urge: NumerologyNumber,
+    pub personality: NumerologyNumber,
+    pub birthday: NumerologyNumber,
+    pub chaldean_name: NumerologyNumber,
+    /// Readings per system, when `options.systems` asked for them
+    #[serde(default)]
+    pub systems: Option<Value>,
+}
+
+// ---------------------------------------------------------------------------
+// Biorhythm
+// ---------------------------------------------------------------------------
+
+/// State of a single biorhythm cycle on the target date
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CycleResult {
+    pub value: f64,
+    pub percentage: f64,
+    pub phase: String,
+    pub days_until_peak: i64,
+    pub days_until_critical: i64,
+    pub is_critical: bool,
+    pub cycle_day: i64,
+}
+
+/// One day of a biorhythm forecast
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ForecastDay {
+    pub date: String,
+    pub days_alive: i64,
+    pub physical: f64,
+    pub emotional: f64,
+    pub intellectual: f64,
+    pub intuitive: f64,
+    pub overall_energy: f64,
+}
+
+/// Result of the `biorhythm` engine
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BiorhythmResult {
+    pub days_alive: i64,
+    pub target_date: String,
+    pub physical: CycleResult,
+    pub emotional: CycleResult,
+    pub intellectual: CycleResult,
+    pub intuitive: CycleResult,
+    pub mastery: f64,
+    pub passion: f64,
+    pub wisdom: f64,
+    pub critical_days: Vec<String>,
+    pub overall_energy: f64,
+    #[serde(default)]
+    pub forecast: Option<Vec<ForecastDay>>,
+}
+
+// ---------------------------------------------------------------------------
+// Human Design
+// ---------------------------------------------------------------------------
+
+/// Gate and line activated by a planet
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GateActivation {
+    pub gate: u8,
+    pub line: u8,
+    pub longitude: f64,
+}
+
+/// Result of the `human-design` engine
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HDChartSummary {
+    /// e.g. "Generator", "ManifestingGenerator"
+    pub hd_type: String,
+    pub authority: String,
+    /// Conscious/unconscious lines, e.g. "1/3"
+    pub profile: String,
+    pub definition: String,
+    pub defined_centers: Vec<String>,
+    /// Channels as "gate1-gate2"
+    pub active_channels: Vec<String>,
+    /// Keyed by lowercase planet name ("sun", "northnode", ...)
+    pub personality_activations: BTreeMap<String, GateActivation>,
+    pub design_activations: BTreeMap<String, GateActivation>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timezone_resolution: Option<ResolvedOffset>,
+    /// How the reading holds across the birth day, when the birth time is unknown
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub time_stability: Option<TimeStability>,
+}
+
+// ---------------------------------------------------------------------------
+// Gene Keys
+// ---------------------------------------------------------------------------
+
+/// The four prime gifts, each as `[key, line]`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ActivationSequence {
+    pub lifes_work: [u8; 2],
+    pub evolution: [u8; 2],
+    pub radiance: [u8; 2],
+    pub purpose: [u8; 2],
+}
+
+/// An activated Gene Key, with wisdom text when available
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ActiveKey {
+    pub key_number: u8,
+    pub line: u8,
+    /// Activation source, e.g. "PersonalitySun"
+    pub source: String,
+    #[serde(default)]
+    pub name: Option<String>,
+    #[serde(default)]
+    pub shadow: Option<String>,
+    #[serde(default)]
+    pub gift: Option<String>,
+    #[serde(default)]
+    pub siddhi: Option<String>,
+}
+
+/// Result of the `gene-keys` engine
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GeneKeysResult {
+    pub activation_sequence: ActivationSequence,
+    pub active_keys: Vec<ActiveKey>,
+    #[serde(default)]
+    pub frequency_assessments: Value,
+    /// How the reading holds across the birth day, when the birth time is unknown
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub time_stability: Option<TimeStability>,
+}
+
+// ---------------------------------------------------------------------------
+// Vimshottari
+// ---------------------------------------------------------------------------
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BirthNakshatra {
+    pub name: String,
+    pub number: u8,
+    pub moon_longitude: f64,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MahadashaSummary {
+    pub planet: String,
+    /// RFC 3339
+    pub start_date: String,
+    pub end_date: String,
+    pub duration_years: f64,
+    pub antardasha_count: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DashaTimeline {
+    pub birth_date: String,
+    pub total_years: u32,
+    pub mahadashas: Vec<MahadashaSummary>,
+}
+
+/// A running dasha period; `years` for maha/antar, `days` for pratyantar
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DashaPeriod {
+    pub planet: String,
+    pub start: String,
+    pub end: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub years: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub days: Option<f64>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CurrentDashaPeriod {
+    pub mahadasha: DashaPeriod,
+    pub antardasha: DashaPeriod,
+    pub pratyantardasha: DashaPeriod,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DashaTransition {
+    /// "Mahadasha", "Antardasha" or "Pratyantardasha"
+    #[serde(rename = "type")]
+    pub transition_type: String,
+    pub from_planet: String,
+    pub to_planet: String,
+    pub date: String,
+    pub days_until: i64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PeriodEnrichment {
+    pub mahadasha_themes: Vec<String>,
+    pub antardasha_themes: Vec<String>,
+    pub pratyantardasha_themes: Vec<String>,
+    pub combined_description: String,
+    pub life_areas: Vec<String>,
+    pub opportunities: Vec<String>,
+    pub challenges: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TransitAspect {
+    pub natal: String,
+    /// "conjunction", "third", "fourth", "fifth", "seventh", "eighth", "ninth" or "tenth"
+    pub aspect: String,
+    pub orb: f64,
+}
+
+/// Where a running dasha lord is transiting
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DashaLordTransit {
+    /// "Mahadasha", "Antardasha" or "Pratyantardasha"
+    pub level: String,
+    pub planet: String,
+    pub longitude: f64,
+    pub sign: u8,
+    pub house_from_moon: u8,
+    pub aspects: Vec<TransitAspect>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DashaTransitCombination {
+    pub id: String,
+    pub planet: String,
+    pub level: String,
+    /// "low", "moderate", "high" or "critical"
+    pub severity: String,
+    /// "supportive" or "challenging"
+    pub nature: String,
+    pub description: String,
+}
+
+/// Transits of the running dasha lords, present with `include_transits`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DashaTransits {
+    pub calculated_at: String,
+    pub natal_planets: Vec<String>,
+    pub lords: Vec<DashaLordTransit>,
+    pub combinations: Vec<DashaTransitCombination>,
+}
+
+/// Result of the `vimshottari` engine
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct VimshottariResult {
+    pub birth_nakshatra: BirthNakshatra,
+    pub timeline: DashaTimeline,
+    #[serde(default)]
+    pub current_period: Option<CurrentDashaPeriod>,
+    pub upcoming_transitions: Vec<DashaTransition>,
+    #[serde(default)]
+    pub period_enrichment: Option<PeriodEnrichment>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub dasha_transits: Option<DashaTransits>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timezone_resolution: Option<ResolvedOffset>,
+    /// How the reading holds across the birth day, when the birth time is unknown
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub time_stability: Option<TimeStability>,
+}
+
+// ---------------------------------------------------------------------------
+// Vedic Clock
+// ---------------------------------------------------------------------------
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ActivityRecommendation {
+    pub activity: String,
+    /// "favorable", "neutral" or "avoid"
+    pub quality: String,
+    pub reason: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct OrganSummary {
+    pub organ: String,
+    pub element: String,
+    /// e.g. "03:00-05:00"
+    pub time_window: String,
+    pub peak_energy: String,
+    pub associated_emotion: String,
+    pub recommended_activities: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DoshaSummary {
+    pub dosha: String,
+    pub qualities: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TemporalRecommendation {
+    pub time_window: String,
+    pub organ: String,
+    pub dosha: String,
+    pub activities: Vec<ActivityRecommendation>,
+    #[serde(default)]
+    pub panchanga_quality: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TimingWindow {
+    pub time_window: String,
+    pub quality: f64,
+    pub reason: String,
+}
+
+/// Present when an `activity` option was requested
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ActivityTiming {
+    pub activity: String,
+    pub is_favorable_now: bool,
+    pub reason: String,
+    pub optimal_windows: Vec<TimingWindow>,
+}
+
+/// Present when a `constitution` option was given: the constitution's
+/// dosha shares and what the current dosha period means for it
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ConstitutionReading {
+    pub vata: f64,
+    pub pitta: f64,
+    pub kapha: f64,
+    pub dominant: String,
+    pub current_period: String,
+    pub favored_activities: Vec<String>,
+    pub activities_to_ease: Vec<String>,
+}
+
+/// The planetary hour active at the calculation moment
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CurrentHora {
+    pub number: u8,
+    pub ruler: HoraPlanet,
+    pub is_day: bool,
+    /// Local "HH:MM"
+    pub start: String,
+    pub end: String,
+    pub recommendations: Vec<ActivityRecommendation>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ClockTransition {
+    pub time: String,
+    pub description: String,
+    #[serde(default)]
+    pub new_organ: Option<String>,
+    #[serde(default)]
+    pub new_dosha: Option<String>,
+}
+
+/// How far apparent solar time ran from clock time at the calculation moment
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SolarTimeShift {
+    /// Longitude the solar time was read at
+    pub longitude: f64,
+    /// Local "HH:MM" on the clock
+    pub clock_time: String,
+    /// Local "HH:MM" on the sun, which the organ and dosha windows follow
+    pub solar_time: String,
+    /// Solar minus clock time in whole minutes
+    pub difference_minutes: i32,
+    /// Part of the difference from the longitude's distance to the timezone meridian
+    pub longitude_minutes: f64,
+    /// Part of the difference from the equation of time
+    pub equation_of_time_minutes: f64,
+}
+
+/// Result of the `vedic-clock` engine
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct VedicClockResult {
+    pub current_organ: OrganSummary,
+    pub current_dosha: DoshaSummary,
+    pub recommendation: TemporalRecommendation,
+    pub synthesis: String,
+    pub calculated_for: String,
+    #[serde(default)]
+    pub activity_timing: Option<ActivityTiming>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub constitution: Option<ConstitutionReading>,
+    #[serde(default)]
+    pub current_hora: Option<CurrentHora>,
+    #[serde(default)]
+    pub upcoming_transitions: Option<Vec<ClockTransition>>,
+    /// Seed the witness prompt was drawn with (`options.seed`)
+    #[serde(default)]
+    pub seed: Option<u64>,
+    /// Home reading, when the result was read for `options.relocate_to`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub relocation: Option<Relocation>,
+    /// Present when the windows were read on solar time (`options.time_basis`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub time_basis: Option<SolarTimeShift>,
+}
+
+// ---------------------------------------------------------------------------
+// Face Reading
+// ---------------------------------------------------------------------------
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ConstitutionDescriptions {
+    pub dosha: String,
+    pub element: String,
+    pub body_type: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Constitution {
+    pub primary_dosha: String,
+    #[serde(default)]
+    pub secondary_dosha: Option<String>,
+    pub tcm_element: String,
+    pub body_type: String,
+    pub descriptions: ConstitutionDescriptions,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PersonalityIndicator {
+    pub trait_name: String,
+    pub facial_indicator: String,
+    pub description: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ElementalBalance {
+    pub wood: f64,
+    pub fire: f64,
+    pub earth: f64,
+    pub metal: f64,
+    pub water: f64,
+    pub dominant: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HealthIndicator {
+    pub zone: String,
+    pub associated_organ: String,
+    pub observation: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FaceAnalysis {
+    pub constitution: Constitution,
+    pub personality_indicators: Vec<PersonalityIndicator>,
+    pub elemental_balance: ElementalBalance,
+    pub health_indicators: Vec<HealthIndicator>,
+    pub is_mock_data: bool,
+}
+
+/// Result of the `face-reading` engine
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FaceReadingResult {
+    pub analysis: FaceAnalysis,
+    pub notice: String,
+    pub traditions: Vec<String>,
+    pub future_capabilities: Vec<String>,
+    pub disclaimer: String,
+    /// Seed the mock analysis was drawn with (`options.seed`)
+    #[serde(default)]
+    pub seed: Option<u64>,
+}
+
+// ---------------------------------------------------------------------------
+// Biofield
+// ---------------------------------------------------------------------------
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BiofieldMetrics {
+    pub fractal_dimension: f64,
+    pub entropy: f64,
+    pub coherence: f64,
+    pub symmetry: f64,
+    pub vitality_index: f64,
+    /// RFC 3339
+    pub timestamp: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ChakraReading {
+    pub chakra: String,
+    pub chakra_name: String,
+    pub activity_level: f64,
+    pub balance: f64,
+    pub color_intensity: String,
+    #[serde(default)]
+    pub location: Option<String>,
+    #[serde(default)]
+    pub element: Option<String>,
+}
+
+/// Result of the `biofield` engine
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BiofieldResult {
+    pub metrics: BiofieldMetrics,
+    pub chakra_readings: Vec<ChakraReading>,
+    pub interpretation: String,
+    pub areas_of_attention: Vec<String>,
+    pub is_mock_data: bool,
+    pub notice: String,
+    pub future_capabilities: Vec<String>,
+    /// Seed the mock metrics were drawn with (`options.seed`, or derived from `user_id`)
+    #[serde(default)]
+    pub seed: Option<u64>,
+}
+
+// ---------------------------------------------------------------------------
+// Dispatch by engine id
+// ---------------------------------------------------------------------------
+
+/// A typed engine result, selected by the output's `engine_id`
+#[derive(Debug, Clone, PartialEq)]
+pub enum EngineResult {
+    Panchanga(Box<PanchangaResult>),
+    Numerology(Box<NumerologyResult>),
+    Biorhythm(Box<BiorhythmResult>),
+    HumanDesign(Box<HDChartSummary>),
+    GeneKeys(Box<GeneKeysResult>),
+    Vimshottari(Box<VimshottariResult>),
+    VedicClock(Box<VedicClockResult>),
+    FaceReading(Box<FaceReadingResult>),
+    Biofield(Box<BiofieldResult>),
+    /// Engines without a typed result (e.g. bridged TypeScript engines)
+    Other(Value),
+}
+
+impl EngineResult {
+    /// Parse `output.result` into the struct for its engine
+    pub fn from_output(output: &EngineOutput) -> Result<Self, EngineError> {
+        Ok(match output.engine_id.as_str() {
+            "panchanga" => Self::Panchanga(output.parse_as()?),
+            "numerology" => Self::Numerology(output.parse_as()?),
+            "biorhythm" => Self::Biorhythm(output.parse_as()?),
+            "human-design" => Self::HumanDesign(output.parse_as()?),
+            "gene-keys" => Self::GeneKeys(output.parse_as()?),
+            "vimshottari" => Self::Vimshottari(output.parse_as()?),
+            "vedic-clock" => Self::VedicClock(output.parse_as()?),
+            "face-reading" => Self::FaceReading(output.parse_as()?),
+            "biofield" => Self::Biofield(output.parse_as()?),
+            _ => Self::Other(output.result.clone()),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::CalculationMetadata;
+    use chrono::Utc;
+    use serde_json::json;
+
+    fn output(engine_id: &str, result: Value) -> EngineOutput {
+        EngineOutput {
+            engine_id: engine_id.to_string(),
+            result,
+            witness_prompt: "?".to_string(),
+            summary: None,
+            consciousness_level: 0,
+            metadata: CalculationMetadata {
+                calculation_time_ms: 0.0,
+                backend: "test".to_string(),
+                precision_achieved: "Standard".to_string(),
+                cached: false,
+                timestamp: Utc::now(),
+                engine_version: None,
+                warnings: Vec::new(),
+                usage: None,
+            },
+        }
+    }
+
+    #[test]
+    fn test_parse_as_reports_mismatch() {
+        let out = output("numerology", json!({ "life_path": 7 }));
+        let err = out.parse_as::<NumerologyResult>().unwrap_err();
+        assert!(matches!(err, EngineError::InternalError(ref m) if m.contains("numerology")));
+    }
+
+    #[test]
+    fn test_engine_result_dispatch() {
+        let number = json!({ "value": 7, "is_master": false, "reduction_chain": [25, 7], "meaning": "Seeker" });
+        let out = output(
+            "numerology",
+            json!({
+                "life_path": number, "expression": number, "soul_urge": number,
+                "personality": number, "birthday": number, "chaldean_name": number,
+            }),
+        );
+        match EngineResult::from_output(&out).unwrap() {
+            EngineResult::Numerology(r) => assert_eq!(r.life_path.reduction_chain, vec![25, 7]),
+            other => panic!("unexpected {:?}", other),
+        }
+
+        // Stored before the casing policy
+        let camel = output(
+            "numerology",
+            json!({
+                "lifePath": { "value": 7, "isMaster": false, "reductionChain": [25, 7], "meaning": "Seeker" },
+                "expression": number, "soulUrge": number,
+                "personality": number, "birthday": number, "chaldeanName": number,
+            }),
+        );
+        let parsed = camel.parse_as::<NumerologyResult>().unwrap();
+        assert_eq!(parsed.life_path.reduction_chain, vec![25, 7]);
+
+        let bridged = output("tarot", json!({ "cards": [] }));
+        assert!(matches!(EngineResult::from_output(&bridged).unwrap(), EngineResult::Other(_)));
+    }
+}
@@ -0,0 +1,170 @@
+//! Deterministic randomness for engines that draw at random
+//!
+//! Biofield, Face Reading and the Vedic Clock witness prompt simulate or pick
+//! parts of their output at random. Each draws from one [`EngineRng`] seeded
+//! by [`engine_seed`], a SHA-256 of the engine ID, the user asking, the input
+//! and `options.seed`. The same question from the same user always gets the
+//! same answer, so repeated and cached results agree, while another user
+//! asking it gets a different one. A different `options.seed` re-rolls it.
+//!
+//! The API runs each authenticated request inside [`scope`] with the user's
+//! ID. Outside it (tests, background jobs, work handed to `tokio::spawn`)
+//! the seed has no user part.
+
+use std::future::Future;
+
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+use serde_json::Value;
+use sha2::{Digest, Sha256};
+
+use crate::{EngineError, EngineInput, SEED_OPTION};
+
+/// Generator every engine draws from
+pub type EngineRng = ChaCha8Rng;
+
+tokio::task_local! {
+    static USER_ID: String;
+}
+
+/// ID of the user whose request is being served on this task, if any
+pub fn current_user_id() -> Option<String> {
+    USER_ID.try_with(|id| id.clone()).ok()
+}
+
+/// Run `fut` with `user_id` as the user seeds are derived for
+pub async fn scope<F: Future>(user_id: String, fut: F) -> F::Output {
+    USER_ID.scope(user_id, fut).await
+}
+
+/// Hex SHA-256 of what `input` asks.
+///
+/// Leaves out `current_time`, which defaults to the moment of the request,
+/// `options.seed`, which [`derive_seed`] takes on its own, and display names,
+/// so spellings of a name that normalize alike draw alike. Options are
+/// hashed in key order, so the hash doesn't depend on how they were sent.
+pub fn input_hash(input: &EngineInput) -> String {
+    let mut input = input.clone();
+    for birth in input.birth_data_mut() {
+        birth.display_name = None;
+    }
+    let mut value = serde_json::to_value(&input).unwrap_or(Value::Null);
+    if let Value::Object(fields) = &mut value {
+        fields.remove("current_time");
+        if let Some(Value::Object(options)) = fields.get_mut("options") {
+            options.remove(SEED_OPTION);
+        }
+    }
+    format!("{:x}", Sha256::digest(value.to_string().as_bytes()))
+}
+
+/// Seed for `engine_id` answering `user_id` about the input hashed to
+/// `input_hash`, re-rolled by `user_seed`
+pub fn derive_seed(engine_id: &str, user_id: Option<&str>, input_hash: &str, user_seed: Option<u64>) -> u64 {
+    let mut hasher = Sha256::new();
+    // Length-prefixed, so no two combinations hash the same bytes
+    for part in [Some(engine_id), user_id, Some(input_hash)] {
+        match part {
+            Some(part) => {
+                hasher.update([1]);
+                hasher.update((part.len() as u64).to_be_bytes());
+                hasher.update(part.as_bytes());
+            }
+            None => hasher.update([0]),
+        }
+    }
+    match user_seed {
+        Some(seed) => {
+            hasher.update([1]);
+            hasher.update(seed.to_be_bytes());
+        }
+        None => hasher.update([0]),
+    }
+    let digest = hasher.finalize();
+    u64::from_be_bytes(digest[..8].try_into().expect("SHA-256 digest has 32 bytes"))
+}
+
+/// Seed for `engine_id` calculating `input` for the current user.
+///
+/// Fails on an `options.seed` that isn't an unsigned integer.
+pub fn engine_seed(engine_id: &str, input: &EngineInput) -> Result<u64, EngineError> {
+    Ok(derive_seed(engine_id, current_user_id().as_deref(), &input_hash(input), input.seed()?))
+}
+
+/// Generator replaying the draws of `seed`
+pub fn seeded(seed: u64) -> EngineRng {
+    EngineRng::seed_from_u64(seed)
+}
+
+/// Generator for `seed`, or seeded from the OS when absent
+pub fn from_seed(seed: Option<u64>) -> EngineRng {
+    match seed {
+        Some(seed) => seeded(seed),
+        None => EngineRng::from_entropy(),
+    }
+}
+
+/// Fraction in `[0, 1)` for spreading out retry delays.
+///
+/// Not seeded: concurrent retries must not wait in lockstep.
+pub fn jitter_fraction() -> f64 {
+    rand::random::<f64>()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::examples;
+    use rand::Rng;
+    use serde_json::json;
+
+    #[tokio::test]
+    async fn test_seed_is_stable_per_question_and_user() {
+        let input = examples::sample_input(json!({}));
+        let anonymous = engine_seed("biofield", &input).unwrap();
+        assert_eq!(engine_seed("biofield", &input).unwrap(), anonymous);
+        assert_ne!(engine_seed("face-reading", &input).unwrap(), anonymous);
+
+        let (alice, bob) = (
+            scope("alice".to_string(), async { engine_seed("biofield", &input).unwrap() }).await,
+            scope("bob".to_string(), async { engine_seed("biofield", &input).unwrap() }).await,
+        );
+        assert_ne!(alice, bob);
+        assert_ne!(alice, anonymous);
+        assert_eq!(scope("alice".to_string(), async { engine_seed("biofield", &input).unwrap() }).await, alice);
+    }
+
+    #[test]
+    fn test_seed_follows_the_input_but_not_its_time() {
+        let input = examples::sample_input(json!({}));
+        let mut later = input.clone();
+        later.current_time += chrono::Duration::hours(3);
+        assert_eq!(input_hash(&later), input_hash(&input));
+
+        let mut reseeded = input.clone();
+        reseeded.options.insert(SEED_OPTION.to_string(), json!(7));
+        assert_eq!(input_hash(&reseeded), input_hash(&input));
+        assert_ne!(engine_seed("biofield", &reseeded).unwrap(), engine_seed("biofield", &input).unwrap());
+
+        let mut other = input.clone();
+        other.options.insert("consciousness_level".to_string(), json!(4));
+        assert_ne!(input_hash(&other), input_hash(&input));
+
+        reseeded.options.insert(SEED_OPTION.to_string(), json!("seven"));
+        assert!(engine_seed("biofield", &reseeded).is_err());
+    }
+
+    #[test]
+    fn test_derive_seed_separates_its_parts() {
+        assert_ne!(derive_seed("ab", Some("c"), "h", None), derive_seed("a", Some("bc"), "h", None));
+        assert_ne!(derive_seed("a", None, "h", None), derive_seed("a", Some(""), "h", None));
+        assert_ne!(derive_seed("a", None, "h", None), derive_seed("a", None, "h", Some(0)));
+    }
+
+    #[test]
+    fn test_seeded_generators_replay() {
+        let draws = |mut rng: EngineRng| (0..4).map(|_| rng.gen::<u32>()).collect::<Vec<_>>();
+        assert_eq!(draws(seeded(42)), draws(from_seed(Some(42))));
+        assert_ne!(draws(seeded(42)), draws(seeded(43)));
+    }
+}
@@ -0,0 +1,512 @@
+//! Historical UTC offset resolution for birth data
+//!
+//! A birth chart needs the offset that was in force at the birth moment, not
+//! the zone's present-day offset. IANA names are resolved against the full tz
+//! database bundled with `chrono-tz`, which carries every historical transition
+//! back to local mean time — e.g. `Asia/Kolkata` was UTC+06:30 during the
+//! 1942-45 war time, and `America/New_York` kept local mean time (UTC-04:56:02)
+//! until 1883.
+//!
+//! Local times that fall in a DST fold (occur twice) or gap (never occur) are
+//! still resolved, but the result carries an [`OffsetAmbiguity`] so engines
+//! can surface a warning in their [`ValidationResult`](crate::ValidationResult).
+//! `BirthData::utc_offset_minutes` bypasses the database entirely.
+
+use chrono::{DateTime, Duration, LocalResult, NaiveDate, NaiveDateTime, NaiveTime, Offset, TimeZone, Utc};
+use chrono_tz::Tz;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::{BirthData, Coordinates, EngineError, EngineInput, ValidationCode};
+
+#[cfg(feature = "openapi")]
+use utoipa::ToSchema;
+
+/// Largest accepted UTC offset override, in minutes (±15 hours)
+pub const MAX_OFFSET_MINUTES: i32 = 15 * 60;
+
+/// Key under which engines embed the [`ResolvedOffset`] in their result JSON
+pub const RESOLUTION_KEY: &str = "timezone_resolution";
+
+/// Where a resolved offset came from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum OffsetSource {
+    /// Explicit `utc_offset_minutes` on the birth data
+    Override,
+    /// Historical lookup of an IANA zone in the tz database
+    TzDatabase,
+    /// Fixed offset string such as "+05:30" or "UTC"
+    Fixed,
+}
+
+/// Why a resolved offset may not be the one the user intended
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum OffsetAmbiguity {
+    /// The local time occurred twice (clocks set back); the earlier occurrence was used
+    Repeated { alternative_offset_seconds: i32 },
+    /// The local time never occurred (clocks set forward); the pre-transition offset was used
+    Skipped { alternative_offset_seconds: i32 },
+    /// No birth time was given and the offset changed during the birth date
+    ChangesDuringDay { alternative_offset_seconds: i32 },
+}
+
+/// UTC offset in force at a birth moment
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct ResolvedOffset {
+    /// Timezone as given on the birth data
+    pub timezone: String,
+    /// Offset east of UTC in seconds (local mean time offsets are not whole minutes)
+    pub offset_seconds: i32,
+    pub source: OffsetSource,
+    #[cfg_attr(feature = "openapi", schema(nullable = true))]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ambiguity: Option<OffsetAmbiguity>,
+}
+
+impl ResolvedOffset {
+    /// Offset in whole minutes, rounded to the nearest minute
+    pub fn offset_minutes(&self) -> i32 {
+        (self.offset_seconds as f64 / 60.0).round() as i32
+    }
+
+    /// Offset in fractional hours
+    pub fn offset_hours(&self) -> f64 {
+        self.offset_seconds as f64 / 3600.0
+    }
+
+    /// Convert a local wall-clock time to UTC with this offset
+    pub fn to_utc(&self, local: NaiveDateTime) -> NaiveDateTime {
+        local - Duration::seconds(self.offset_seconds as i64)
+    }
+
+    /// Human-readable warning when the offset is ambiguous
+    pub fn warning(&self) -> Option<String> {
+        let used = format_offset(self.offset_seconds);
+        let message = match self.ambiguity? {
+            OffsetAmbiguity::Repeated { alternative_offset_seconds } => format!(
+                "Birth time occurs twice in {} (UTC{} or UTC{}); using UTC{}",
+                self.timezone,
+                used,
+                format_offset(alternative_offset_seconds),
+                used
+            ),
+            OffsetAmbiguity::Skipped { alternative_offset_seconds } => format!(
+                "Birth time does not exist in {} (clocks moved from UTC{} to UTC{}); using UTC{}",
+                self.timezone,
+                used,
+                format_offset(alternative_offset_seconds),
+                used
+            ),
+            OffsetAmbiguity::ChangesDuringDay { alternative_offset_seconds } => format!(
+                "UTC offset in {} changes on the birth date (UTC{} / UTC{}) and no birth time was given; using UTC{}",
+                self.timezone,
+                used,
+                format_offset(alternative_offset_seconds),
+                used
+            ),
+        };
+        Some(format!("{message}. Set utc_offset_minutes to override."))
+    }
+}
+
+impl BirthData {
+    /// Resolve the UTC offset in force at this birth moment.
+    ///
+    /// A missing birth time is evaluated at local noon.
+    pub fn resolve_offset(&self) -> Result<ResolvedOffset, EngineError> {
+        let (date, time) = self.local_birth_time()?;
+        resolve_offset(&self.timezone, self.utc_offset_minutes, date, time)
+    }
+
+    /// The birth moment in UTC; a missing birth time is taken as local noon.
+    pub fn birth_instant(&self) -> Result<DateTime<Utc>, EngineError> {
+        let (date, time) = self.local_birth_time()?;
+        let resolved = resolve_offset(&self.timezone, self.utc_offset_minutes, date, time)?;
+        let noon = NaiveTime::from_hms_opt(12, 0, 0).unwrap_or_default();
+        Ok(resolved.to_utc(date.and_time(time.unwrap_or(noon))).and_utc())
+    }
+
+    /// Offset in seconds of the birth timezone at a later `instant`.
+    ///
+    /// Reads reference times such as "now" or `as_of` in the user's civil
+    /// time. IANA zones are evaluated at `instant`, so the DST rules of that
+    /// moment apply; `utc_offset_minutes` and fixed offsets apply unchanged.
+    pub fn offset_at(&self, instant: DateTime<Utc>) -> Result<i32, EngineError> {
+        if let Some(minutes) = self.utc_offset_minutes {
+            return Ok(minutes * 60);
+        }
+        zone_offset_at(&self.timezone, "birth_data.timezone", instant)
+    }
+
+    fn local_birth_time(&self) -> Result<(NaiveDate, Option<NaiveTime>), EngineError> {
+        let date = NaiveDate::parse_from_str(&self.date, "%Y-%m-%d").map_err(|e| {
+            EngineError::invalid_field(
+                "birth_data.date",
+                ValidationCode::InvalidFormat,
+                format!("Invalid date format '{}': {}", self.date, e),
+            )
+        })?;
+        let time = self
+            .time
+            .as_deref()
+            .map(|t| {
+                NaiveTime::parse_from_str(t, "%H:%M")
+                    .or_else(|_| NaiveTime::parse_from_str(t, "%H:%M:%S"))
+                    .map_err(|e| {
+                        EngineError::invalid_field(
+                            "birth_data.time",
+                            ValidationCode::InvalidFormat,
+                            format!("Invalid time format '{}': {}", t, e),
+                        )
+                    })
+            })
+            .transpose()?;
+        Ok((date, time))
+    }
+}
+
+impl Coordinates {
+    /// Offset in seconds of the location's timezone at `instant`, if it has one
+    pub fn offset_at(&self, instant: DateTime<Utc>) -> Result<Option<i32>, EngineError> {
+        self.timezone
+            .as_deref()
+            .map(|timezone| zone_offset_at(timezone, "location.timezone", instant))
+            .transpose()
+    }
+}
+
+impl EngineInput {
+    /// Offset in seconds of the civil time at `instant` where the person is:
+    /// the timezone of `location` when it has one, otherwise the birth
+    /// timezone. `None` without either.
+    pub fn local_offset_at(&self, instant: DateTime<Utc>) -> Result<Option<i32>, EngineError> {
+        if let Some(offset) = self.location.as_ref().map(|l| l.offset_at(instant)).transpose()?.flatten() {
+            return Ok(Some(offset));
+        }
+        self.primary_birth_data().map(|birth| birth.offset_at(instant)).transpose()
+    }
+}
+
+/// Offset of an IANA zone or fixed offset string at `instant`; `field`
+/// names the input it came from in the error for an unknown zone
+pub(crate) fn zone_offset_at(timezone: &str, field: &str, instant: DateTime<Utc>) -> Result<i32, EngineError> {
+    let timezone = timezone.trim();
+    if let Some(offset_seconds) = parse_fixed_offset(timezone) {
+        return Ok(offset_seconds);
+    }
+    let tz: Tz = timezone.parse().map_err(|_| {
+        EngineError::invalid_field(field, ValidationCode::Unknown, format!("Unknown timezone '{}'", timezone))
+    })?;
+    Ok(tz.offset_from_utc_datetime(&instant.naive_utc()).fix().local_minus_utc())
+}
+
+/// Resolve the UTC offset for a local birth date and optional time.
+///
+/// `timezone` may be an IANA name, "UTC"/"GMT"/"Z", or a fixed offset such as
+/// "+05:30", "-0800" or "UTC+2". `override_minutes` takes precedence over it.
+pub fn resolve_offset(
+    timezone: &str,
+    override_minutes: Option<i32>,
+    date: NaiveDate,
+    time: Option<NaiveTime>,
+) -> Result<ResolvedOffset, EngineError> {
+    let timezone = timezone.trim();
+
+    if let Some(minutes) = override_minutes {
+        if !(-MAX_OFFSET_MINUTES..=MAX_OFFSET_MINUTES).contains(&minutes) {
+            return Err(EngineError::invalid_field(
+                "birth_data.utc_offset_minutes",
+                ValidationCode::OutOfRange,
+                format!("utc_offset_minutes {} out of range (±{})", minutes, MAX_OFFSET_MINUTES),
+            ));
+        }
+        return Ok(ResolvedOffset {
+            timezone: timezone.to_string(),
+            offset_seconds: minutes * 60,
+            source: OffsetSource::Override,
+            ambiguity: None,
+        });
+    }
+
+    if timezone.is_empty() {
+        return Err(EngineError::invalid_field(
+            "birth_data.timezone",
+            ValidationCode::Required,
+            "Timezone or utc_offset_minutes is required",
+        ));
+    }
+
+    if let Some(offset_seconds) = parse_fixed_offset(timezone) {
+        return Ok(ResolvedOffset {
+            timezone: timezone.to_string(),
+            offset_seconds,
+            source: OffsetSource::Fixed,
+            ambiguity: None,
+        });
+    }
+
+    let tz: Tz = timezone.parse().map_err(|_| {
+        EngineError::invalid_field(
+            "birth_data.timezone",
+            ValidationCode::Unknown,
+            format!("Unknown timezone '{}'", timezone),
+        )
+    })?;
+
+    let noon = NaiveTime::from_hms_opt(12, 0, 0).unwrap_or_default();
+    let local = date.and_time(time.unwrap_or(noon));
+    let (offset_seconds, mut ambiguity) = tz_offset_at(&tz, local);
+
+    if time.is_none() && ambiguity.is_none() {
+        // Without a birth time, any transition on the date leaves the offset open
+        let day_start = tz_offset_at(&tz, date.and_time(NaiveTime::MIN)).0;
+        let day_end = date
+            .and_hms_opt(23, 59, 59)
+            .map(|end| tz_offset_at(&tz, end).0)
+            .unwrap_or(day_start);
+        if let Some(alternative) = [day_start, day_end].into_iter().find(|o| *o != offset_seconds) {
+            ambiguity = Some(OffsetAmbiguity::ChangesDuringDay {
+                alternative_offset_seconds: alternative,
+            });
+        }
+    }
+
+    Ok(ResolvedOffset {
+        timezone: timezone.to_string(),
+        offset_seconds,
+        source: OffsetSource::TzDatabase,
+        ambiguity,
+    })
+}
+
+/// Whether `timezone` is an IANA zone in the tz database or a fixed offset string
+pub fn is_known_timezone(timezone: &str) -> bool {
+    parse_fixed_offset(timezone).is_some() || timezone.parse::<Tz>().is_ok()
+}
+
+/// Ambiguity warnings recorded in an engine result under [`RESOLUTION_KEY`]
+pub fn resolution_warnings(result: &Value) -> Vec<String> {
+    result
+        .get(RESOLUTION_KEY)
+        .cloned()
+        .and_then(|v| serde_json::from_value::<ResolvedOffset>(v).ok())
+        .and_then(|r| r.warning())
+        .into_iter()
+        .collect()
+}
+
+/// Offset of `tz` at a local wall-clock time, with fold/gap detection
+fn tz_offset_at(tz: &Tz, local: NaiveDateTime) -> (i32, Option<OffsetAmbiguity>) {
+    let seconds = |dt: chrono::DateTime<Tz>| dt.offset().fix().local_minus_utc();
+
+    match tz.from_local_datetime(&local) {
+        LocalResult::Single(dt) => (seconds(dt), None),
+        LocalResult::Ambiguous(earliest, latest) => (
+            seconds(earliest),
+            Some(OffsetAmbiguity::Repeated {
+                alternative_offset_seconds: seconds(latest),
+            }),
+        ),
+        LocalResult::None => {
+            // Transitions are never closer than a day apart, so the offsets a
+            // day either side bracket the gap
+            let before = tz
+                .offset_from_utc_datetime(&(local - Duration::days(1)))
+                .fix()
+                .local_minus_utc();
+            let after = tz
+                .offset_from_utc_datetime(&(local + Duration::days(1)))
+                .fix()
+                .local_minus_utc();
+            (
+                before,
+                Some(OffsetAmbiguity::Skipped {
+                    alternative_offset_seconds: after,
+                }),
+            )
+        }
+    }
+}
+
+/// Parse "UTC", "GMT", "Z", "+05:30", "-0800", "+5", "UTC+05:30", "GMT-3"
+fn parse_fixed_offset(tz: &str) -> Option<i32> {
+    let upper = tz.to_ascii_uppercase();
+    let rest = match upper.as_str() {
+        "UTC" | "GMT" | "Z" => return Some(0),
+        s => s
+            .strip_prefix("UTC")
+            .or_else(|| s.strip_prefix("GMT"))
+            .unwrap_or(s),
+    };
+
+    let (sign, digits) = match rest.as_bytes().first()? {
+        b'+' => (1, &rest[1..]),
+        b'-' => (-1, &rest[1..]),
+        _ => return None,
+    };
+
+    let (hours, minutes) = match digits.split_once(':') {
+        Some((h, m)) => (h, m),
+        None if digits.len() == 4 => digits.split_at(2),
+        None => (digits, "0"),
+    };
+    let hours: i32 = hours.parse().ok()?;
+    let minutes: i32 = minutes.parse().ok()?;
+    if hours > 15 || minutes >= 60 {
+        return None;
+    }
+
+    Some(sign * (hours * 3600 + minutes * 60))
+}
+
+/// "+05:30", "-08:00", "-04:56:02"
+fn format_offset(seconds: i32) -> String {
+    let sign = if seconds < 0 { '-' } else { '+' };
+    let abs = seconds.abs();
+    let (h, m, s) = (abs / 3600, abs % 3600 / 60, abs % 60);
+    if s == 0 {
+        format!("{sign}{h:02}:{m:02}")
+    } else {
+        format!("{sign}{h:02}:{m:02}:{s:02}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(s: &str) -> NaiveDate {
+        NaiveDate::parse_from_str(s, "%Y-%m-%d").unwrap()
+    }
+
+    fn time(s: &str) -> Option<NaiveTime> {
+        Some(NaiveTime::parse_from_str(s, "%H:%M").unwrap())
+    }
+
+    #[test]
+    fn test_historical_war_time_and_lmt() {
+        // India observed war time (UTC+06:30) from 1942 to 1945
+        let war = resolve_offset("Asia/Kolkata", None, date("1943-06-01"), time("10:00")).unwrap();
+        assert_eq!(war.offset_minutes(), 390);
+        assert_eq!(war.source, OffsetSource::TzDatabase);
+
+        let modern = resolve_offset("Asia/Kolkata", None, date("1990-06-01"), time("10:00")).unwrap();
+        assert_eq!(modern.offset_minutes(), 330);
+
+        // New York kept local mean time (UTC-04:56:02) until 1883
+        let lmt = resolve_offset("America/New_York", None, date("1880-01-15"), time("12:00")).unwrap();
+        assert_eq!(lmt.offset_seconds, -(4 * 3600 + 56 * 60 + 2));
+        assert!(lmt.ambiguity.is_none());
+    }
+
+    #[test]
+    fn test_dst_fold_and_gap_flagged() {
+        // 2023-11-05 01:30 happens twice in New York
+        let fold = resolve_offset("America/New_York", None, date("2023-11-05"), time("01:30")).unwrap();
+        assert_eq!(fold.offset_minutes(), -240);
+        assert_eq!(
+            fold.ambiguity,
+            Some(OffsetAmbiguity::Repeated { alternative_offset_seconds: -5 * 3600 })
+        );
+        assert!(fold.warning().unwrap().contains("occurs twice"));
+
+        // 2023-03-12 02:30 never happened
+        let gap = resolve_offset("America/New_York", None, date("2023-03-12"), time("02:30")).unwrap();
+        assert_eq!(gap.offset_minutes(), -300);
+        assert!(matches!(gap.ambiguity, Some(OffsetAmbiguity::Skipped { .. })));
+
+        // Unknown birth time on a transition date
+        let untimed = resolve_offset("America/New_York", None, date("2023-03-12"), None).unwrap();
+        assert!(matches!(untimed.ambiguity, Some(OffsetAmbiguity::ChangesDuringDay { .. })));
+    }
+
+    #[test]
+    fn test_override_and_fixed_offsets() {
+        let over = resolve_offset("Asia/Kolkata", Some(353), date("1850-01-01"), time("06:00")).unwrap();
+        assert_eq!(over.source, OffsetSource::Override);
+        assert_eq!(over.offset_seconds, 353 * 60);
+        assert!(resolve_offset("Asia/Kolkata", Some(2000), date("1850-01-01"), None).is_err());
+
+        for (tz, secs) in [("UTC", 0), ("+05:30", 19800), ("-0800", -28800), ("GMT+2", 7200)] {
+            let r = resolve_offset(tz, None, date("2000-01-01"), None).unwrap();
+            assert_eq!((r.offset_seconds, r.source), (secs, OffsetSource::Fixed), "{tz}");
+        }
+
+        assert!(matches!(
+            resolve_offset("Mars/Olympus_Mons", None, date("2000-01-01"), None),
+            Err(EngineError::InvalidInput(ref e)) if e[0].code == ValidationCode::Unknown
+        ));
+    }
+
+    #[test]
+    fn test_resolution_warnings_from_result() {
+        let fold = resolve_offset("Europe/London", None, date("1990-10-28"), time("01:30")).unwrap();
+        let result = serde_json::json!({ RESOLUTION_KEY: fold });
+        let warnings = resolution_warnings(&result);
+        assert_eq!(warnings.len(), 1);
+        assert!(warnings[0].contains("utc_offset_minutes"));
+
+        assert!(resolution_warnings(&serde_json::json!({})).is_empty());
+    }
+
+    #[test]
+    fn test_birth_instant_and_later_offsets() {
+        let birth = BirthData {
+            name: None,
+            display_name: None,
+            date: "1990-01-15".to_string(),
+            time: Some("09:00".to_string()),
+            time_unknown: false,
+            latitude: 40.7,
+            longitude: -74.0,
+            altitude: None,
+            timezone: "America/New_York".to_string(),
+            place: None,
+            utc_offset_minutes: None,
+        };
+        assert_eq!(birth.birth_instant().unwrap().to_rfc3339(), "1990-01-15T14:00:00+00:00");
+
+        // Later instants use the DST rules in force then
+        let summer = date("2024-07-01").and_hms_opt(12, 0, 0).unwrap().and_utc();
+        assert_eq!(birth.offset_at(summer).unwrap(), -4 * 3600);
+
+        let fixed = BirthData { utc_offset_minutes: Some(330), ..birth };
+        assert_eq!(fixed.offset_at(summer).unwrap(), 330 * 60);
+    }
+
+    #[test]
+    fn test_local_offset_prefers_current_location() {
+        let birth = BirthData {
+            name: None,
+            display_name: None,
+            date: "1990-01-15".to_string(),
+            time: Some("09:00".to_string()),
+            time_unknown: false,
+            latitude: 40.7,
+            longitude: -74.0,
+            altitude: None,
+            timezone: "America/New_York".to_string(),
+            place: None,
+            utc_offset_minutes: None,
+        };
+        let summer = date("2024-07-01").and_hms_opt(12, 0, 0).unwrap().and_utc();
+        let mut input: EngineInput = serde_json::from_value(serde_json::json!({
+            "birth_data": birth,
+            "current_location": { "latitude": 38.72, "longitude": -9.14, "altitude": null, "timezone": "Europe/Lisbon" }
+        }))
+        .unwrap();
+        assert_eq!(input.local_offset_at(summer).unwrap(), Some(3600));
+
+        // A location without a timezone reads in the birth timezone
+        input.location.as_mut().unwrap().timezone = None;
+        assert_eq!(input.local_offset_at(summer).unwrap(), Some(-4 * 3600));
+
+        input.location.as_mut().unwrap().timezone = Some("Mars/Olympus".to_string());
+        assert!(input.local_offset_at(summer).is_err());
+    }
+}
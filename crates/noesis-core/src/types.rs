@@ -1,9 +1,13 @@
 //! Shared types used across all Noesis engines and services
 
-use chrono::{DateTime, Utc};
-use serde::{Deserialize, Serialize};
+use chrono::{DateTime, Datelike, NaiveDate, NaiveDateTime, NaiveTime, Utc};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
 use serde_json::Value;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
+
+use crate::birth_time::BirthTimeReport;
+use crate::names::{normalize_name, NameNormalization};
+use crate::{EngineError, ValidationCode, ValidationError};
 
 #[cfg(feature = "openapi")]
 use utoipa::ToSchema;
@@ -15,10 +19,22 @@ pub struct EngineInput {
     /// Birth data (required for birth-chart-based engines)
     #[cfg_attr(feature = "openapi", schema(nullable = true))]
     pub birth_data: Option<BirthData>,
+    /// Second person's birth data, for compatibility engines and the
+    /// `relationship` workflow; ignored by single-person engines
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "openapi", schema(nullable = true))]
+    pub secondary_birth_data: Option<BirthData>,
+    /// Role-tagged birth data; a subject takes precedence over
+    /// `birth_data` / `secondary_birth_data` for its role
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub subjects: Vec<Subject>,
     /// Current timestamp for time-based calculations
     #[serde(default = "default_current_time")]
     pub current_time: DateTime<Utc>,
-    /// Geographic location
+    /// Where the person is at `current_time`, for readings of the present
+    /// moment; engines fall back to the birth place without it. Also accepted
+    /// as `current_location`.
+    #[serde(alias = "current_location")]
     #[cfg_attr(feature = "openapi", schema(nullable = true))]
     pub location: Option<Coordinates>,
     /// Calculation precision level
@@ -33,6 +49,252 @@ fn default_current_time() -> DateTime<Utc> {
     Utc::now()
 }
 
+/// Role a person plays in a calculation
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum SubjectRole {
+    /// The person the reading is for, as in `birth_data`
+    Primary,
+    /// The other person of a compatibility reading, as in `secondary_birth_data`
+    Secondary,
+}
+
+impl SubjectRole {
+    /// The other role, for seeing a two-person input from the second person
+    pub fn swapped(self) -> Self {
+        match self {
+            SubjectRole::Primary => SubjectRole::Secondary,
+            SubjectRole::Secondary => SubjectRole::Primary,
+        }
+    }
+}
+
+/// Birth data tagged with the role its person plays
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct Subject {
+    pub role: SubjectRole,
+    pub birth_data: BirthData,
+}
+
+/// Key in [`EngineInput::options`] holding the seed of stochastic engines
+pub const SEED_OPTION: &str = "seed";
+
+/// Key in [`EngineInput::options`] holding the reference time of a retrospective calculation
+pub const AS_OF_OPTION: &str = "as_of";
+
+/// Key in [`EngineInput::options`] asking engines for their intermediate
+/// values. Engines that have any add them to their result under
+/// [`DEBUG_KEY`].
+pub const DEBUG_OPTION: &str = "debug";
+
+/// Key under which engines embed their intermediate values in their result JSON
+pub const DEBUG_KEY: &str = "debug";
+
+impl EngineInput {
+    /// Top-level JSON fields of an input, for callers that reject unknown ones
+    pub const FIELDS: &'static [&'static str] = &[
+        "birth_data",
+        "secondary_birth_data",
+        "subjects",
+        "current_time",
+        "location",
+        "precision",
+        "options",
+    ];
+
+    /// Accepted alternative names of [`FIELDS`](Self::FIELDS)
+    pub const FIELD_ALIASES: &'static [&'static str] = &["current_location"];
+
+    /// Birth data of the person in `role`.
+    ///
+    /// Reads the subject with that role when there is one, otherwise
+    /// `birth_data` for the primary and `secondary_birth_data` for the
+    /// secondary person. Engines read birth data through this so both
+    /// request shapes work.
+    pub fn subject(&self, role: SubjectRole) -> Option<&BirthData> {
+        self.subjects
+            .iter()
+            .find(|subject| subject.role == role)
+            .map(|subject| &subject.birth_data)
+            .or(match role {
+                SubjectRole::Primary => self.birth_data.as_ref(),
+                SubjectRole::Secondary => self.secondary_birth_data.as_ref(),
+            })
+    }
+
+    /// Birth data of the primary person; see [`subject`](Self::subject).
+    pub fn primary_birth_data(&self) -> Option<&BirthData> {
+        self.subject(SubjectRole::Primary)
+    }
+
+    /// Mutable access to every birth data in the input, legacy fields first.
+    pub fn birth_data_mut(&mut self) -> impl Iterator<Item = &mut BirthData> {
+        self.birth_data
+            .iter_mut()
+            .chain(self.secondary_birth_data.iter_mut())
+            .chain(self.subjects.iter_mut().map(|subject| &mut subject.birth_data))
+    }
+
+    /// Copy what the subjects resolve to into `birth_data` and
+    /// `secondary_birth_data`, for consumers that only read those fields.
+    pub fn fill_legacy_birth_data(&mut self) {
+        self.birth_data = self.primary_birth_data().cloned();
+        self.secondary_birth_data = self.subject(SubjectRole::Secondary).cloned();
+    }
+
+    /// Reject `subjects` that give a role more than once.
+    pub fn validate_subjects(&self) -> Result<(), EngineError> {
+        for (i, subject) in self.subjects.iter().enumerate() {
+            if self.subjects[..i].iter().any(|earlier| earlier.role == subject.role) {
+                return Err(EngineError::invalid_field(
+                    format!("subjects[{}].role", i),
+                    ValidationCode::Duplicate,
+                    format!("More than one subject has role {:?}", subject.role),
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    /// `options.seed`, if given.
+    ///
+    /// Engines that use randomness derive all of it from one seed (see
+    /// [`crate::rng`]), so equal inputs give byte-identical results and cached
+    /// or replayed calculations match the original.
+    pub fn seed(&self) -> Result<Option<u64>, EngineError> {
+        match self.options.get(SEED_OPTION) {
+            None | Some(Value::Null) => Ok(None),
+            Some(value) => value.as_u64().map(Some).ok_or_else(|| {
+                EngineError::invalid_field(
+                    format!("options.{}", SEED_OPTION),
+                    ValidationCode::InvalidFormat,
+                    format!("Seed must be a non-negative integer, got {}", value),
+                )
+            }),
+        }
+    }
+
+    /// `options.as_of`, if given.
+    ///
+    /// Accepts an RFC 3339 timestamp, or a YYYY-MM-DD date read as local noon
+    /// in the birth timezone, so [`local_time`](Self::local_time) falls on
+    /// that date. Without birth data, or when its timezone can't be
+    /// resolved, the date is read as 12:00 UTC.
+    pub fn as_of(&self) -> Result<Option<DateTime<Utc>>, EngineError> {
+        let value = match self.options.get(AS_OF_OPTION) {
+            None | Some(Value::Null) => return Ok(None),
+            Some(value) => value,
+        };
+        let parsed = value.as_str().and_then(|s| {
+            DateTime::parse_from_rfc3339(s)
+                .map(|dt| dt.with_timezone(&Utc))
+                .ok()
+                .or_else(|| {
+                    NaiveDate::parse_from_str(s, "%Y-%m-%d")
+                        .ok()
+                        .and_then(|d| d.and_hms_opt(12, 0, 0))
+                        .map(|noon| self.birth_local_to_utc(noon))
+                })
+        });
+        parsed.map(Some).ok_or_else(|| {
+            EngineError::invalid_field(
+                format!("options.{}", AS_OF_OPTION),
+                ValidationCode::InvalidFormat,
+                format!("as_of must be an RFC 3339 timestamp or YYYY-MM-DD, got {}", value),
+            )
+        })
+    }
+
+    /// Whether `options.debug` is `true`
+    pub fn debug(&self) -> bool {
+        matches!(self.options.get(DEBUG_OPTION), Some(Value::Bool(true)))
+    }
+
+    /// Whether a cached result may stand in for calculating this input:
+    /// not when pinned to another moment with `options.as_of`, nor when
+    /// asking for intermediate values with `options.debug`
+    pub fn is_cacheable(&self) -> bool {
+        !self.options.contains_key(AS_OF_OPTION) && !self.debug()
+    }
+
+    /// Make `options.as_of` the reference time of the calculation.
+    ///
+    /// Sets `current_time` to it and normalises the option to RFC 3339, so
+    /// every engine of a workflow reads the same instant. Fails if the birth
+    /// moment comes after it. Without `as_of` the input is left unchanged.
+    pub fn apply_as_of(&mut self) -> Result<(), EngineError> {
+        let Some(as_of) = self.as_of()? else {
+            return Ok(());
+        };
+        if let Some(birth) = self.primary_birth_data().and_then(|b| b.birth_instant().ok()) {
+            if birth > as_of {
+                return Err(EngineError::invalid_field(
+                    format!("options.{}", AS_OF_OPTION),
+                    ValidationCode::OutOfRange,
+                    format!("as_of {} is before the birth moment {}", as_of.to_rfc3339(), birth.to_rfc3339()),
+                ));
+            }
+        }
+        self.current_time = as_of;
+        self.options
+            .insert(AS_OF_OPTION.to_string(), Value::String(as_of.to_rfc3339()));
+        Ok(())
+    }
+
+    /// The same input seen from the second person.
+    ///
+    /// Swaps the primary and secondary person, in `subjects` as well as the
+    /// legacy fields, so a single-person engine calculates the partner's
+    /// chart. `None` without secondary birth data.
+    pub fn for_secondary(&self) -> Option<EngineInput> {
+        let secondary = self.subject(SubjectRole::Secondary)?.clone();
+        Some(EngineInput {
+            birth_data: Some(secondary),
+            secondary_birth_data: self.primary_birth_data().cloned(),
+            subjects: self
+                .subjects
+                .iter()
+                .map(|subject| Subject {
+                    role: subject.role.swapped(),
+                    birth_data: subject.birth_data.clone(),
+                })
+                .collect(),
+            ..self.clone()
+        })
+    }
+
+    /// `current_time` as wall-clock time in the birth timezone.
+    ///
+    /// Engines that work on calendar dates or hours of the day use this so a
+    /// reading refers to the same local day everywhere. Falls back to UTC
+    /// without birth data or when its timezone can't be resolved.
+    pub fn local_time(&self) -> NaiveDateTime {
+        let offset = self
+            .primary_birth_data()
+            .and_then(|b| b.offset_at(self.current_time).ok())
+            .unwrap_or(0);
+        self.current_time.naive_utc() + chrono::Duration::seconds(offset as i64)
+    }
+
+    /// Wall-clock time `local` in the birth timezone as UTC, the inverse of
+    /// [`local_time`](Self::local_time)
+    fn birth_local_to_utc(&self, local: NaiveDateTime) -> DateTime<Utc> {
+        let offset_at = |instant: DateTime<Utc>| {
+            let offset = self
+                .primary_birth_data()
+                .and_then(|b| b.offset_at(instant).ok())
+                .unwrap_or(0);
+            chrono::Duration::seconds(offset as i64)
+        };
+        // The offset at a first guess, then at the instant it gives, in case
+        // a DST change lies between the two
+        let guess = local.and_utc() - offset_at(local.and_utc());
+        local.and_utc() - offset_at(guess)
+    }
+}
+
 /// Output from any consciousness engine
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[cfg_attr(feature = "openapi", derive(ToSchema))]
@@ -43,68 +305,243 @@ pub struct EngineOutput {
     pub result: Value,
     /// Self-inquiry question generated from the calculation
     pub witness_prompt: String,
+    /// Two or three plain-language sentences describing the result, built
+    /// from fixed templates; `None` for engines without a summarizer
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub summary: Option<String>,
     /// User's current consciousness development level (0-5)
     pub consciousness_level: u8,
     /// Calculation metadata (timing, backend, precision)
     pub metadata: CalculationMetadata,
 }
 
+impl EngineOutput {
+    /// Deserialize `result` into a typed result struct.
+    ///
+    /// See the `results` feature for the structs matching each engine's output.
+    /// camelCase keys of results stored before the casing policy are read as
+    /// their snake_case spelling (see [`casing`](crate::casing)).
+    pub fn parse_as<T: DeserializeOwned>(&self) -> Result<T, EngineError> {
+        let parsed = if crate::casing::has_camel_case_keys(&self.result) {
+            let mut result = self.result.clone();
+            crate::casing::snake_case_keys(&mut result);
+            T::deserialize(result)
+        } else {
+            T::deserialize(&self.result)
+        };
+        parsed.map_err(|e| {
+            EngineError::InternalError(format!(
+                "Result of engine '{}' does not match {}: {}",
+                self.engine_id,
+                std::any::type_name::<T>(),
+                e
+            ))
+        })
+    }
+}
+
 /// Birth data for chart-based calculations
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[cfg_attr(feature = "openapi", derive(ToSchema))]
 pub struct BirthData {
+    /// Name engines calculate with; the API normalizes it on arrival (see
+    /// [`names`](crate::names))
     #[cfg_attr(feature = "openapi", schema(nullable = true))]
     pub name: Option<String>,
+    /// The name as given, kept for display when normalization changed it
+    #[cfg_attr(feature = "openapi", schema(example = "José O’Brien", nullable = true))]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub display_name: Option<String>,
     /// Date in YYYY-MM-DD format
     #[cfg_attr(feature = "openapi", schema(example = "1990-01-01"))]
     pub date: String,
     /// Time in HH:MM format
     #[cfg_attr(feature = "openapi", schema(example = "14:30", nullable = true))]
     pub time: Option<String>,
+    /// The birth time is not known; `time` must be absent. Engines that need
+    /// a time read the chart at noon and report how much it depends on the
+    /// time (see [`birth_time`](crate::birth_time)), and engines that
+    /// cannot do without one refuse a missing `time` unless this is set.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub time_unknown: bool,
     /// Latitude in decimal degrees
     #[cfg_attr(feature = "openapi", schema(example = 12.9716))]
+    #[serde(default)]
     pub latitude: f64,
     /// Longitude in decimal degrees
     #[cfg_attr(feature = "openapi", schema(example = 77.5946))]
+    #[serde(default)]
     pub longitude: f64,
+    /// Height above sea level in meters. Only topocentric calculations at
+    /// `Precision::High` and above read it (see [`geodesy`](crate::geodesy)).
+    #[cfg_attr(feature = "openapi", schema(example = 920.0, nullable = true))]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub altitude: Option<f64>,
     /// IANA timezone identifier
     #[cfg_attr(feature = "openapi", schema(example = "Asia/Kolkata"))]
+    #[serde(default)]
     pub timezone: String,
+    /// Place name (e.g. "Bengaluru, IN"). When coordinates or timezone are
+    /// omitted, the API resolves them from this place before calculation.
+    #[cfg_attr(feature = "openapi", schema(example = "Bengaluru", nullable = true))]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub place: Option<String>,
+    /// Explicit UTC offset in minutes for the birth moment. Overrides the
+    /// offset derived from `timezone` (e.g. for records with a known
+    /// historical or local mean time offset).
+    #[cfg_attr(feature = "openapi", schema(example = 390, nullable = true))]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub utc_offset_minutes: Option<i32>,
 }
 
 impl BirthData {
-    /// Validate birth data for correctness
-    pub fn validate(&self) -> Result<(), String> {
+    /// Replace `name` with its normalized form, moving the name as given to
+    /// `display_name` if that changes it. Normalizing again leaves the
+    /// original in place.
+    pub fn normalize_name(&mut self, rules: &NameNormalization) {
+        let Some(name) = self.name.as_deref() else {
+            return;
+        };
+        let normalized = normalize_name(name, rules);
+        if normalized != name {
+            let given = self.name.replace(normalized);
+            self.display_name = self.display_name.take().or(given);
+        }
+    }
+
+    /// The name to show: as given, falling back to `name`
+    pub fn display_name(&self) -> Option<&str> {
+        self.display_name.as_deref().or(self.name.as_deref())
+    }
+
+    /// Validate birth data for correctness, reporting every invalid field
+    pub fn validate(&self) -> Result<(), EngineError> {
+        let errors = self.validation_errors();
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(EngineError::InvalidInput(errors))
+        }
+    }
+
+    /// Field-level problems with this birth data, with paths under `birth_data.`
+    pub fn validation_errors(&self) -> Vec<ValidationError> {
+        let mut errors: Vec<ValidationError> = self.date_error().into_iter().collect();
+
+        if let Some(name) = &self.name {
+            if name.trim().is_empty() {
+                errors.push(ValidationError::new(
+                    "birth_data.name",
+                    ValidationCode::Empty,
+                    "Name must not be empty when provided",
+                ));
+            }
+        }
+
+        if self.time_unknown && self.time.is_some() {
+            errors.push(ValidationError::new(
+                "birth_data.time_unknown",
+                ValidationCode::Conflict,
+                "time_unknown cannot be set together with a birth time",
+            ));
+        } else if let Some(time) = &self.time {
+            let parsed = NaiveTime::parse_from_str(time, "%H:%M")
+                .or_else(|_| NaiveTime::parse_from_str(time, "%H:%M:%S"));
+            if parsed.is_err() {
+                errors.push(ValidationError::new(
+                    "birth_data.time",
+                    ValidationCode::InvalidFormat,
+                    format!("Invalid time '{}'. Expected HH:MM or HH:MM:SS.", time),
+                ));
+            }
+        }
+
         // Validate Latitude (-90 to 90)
         if !(self.latitude >= -90.0 && self.latitude <= 90.0) {
-           return Err(format!("Invalid latitude: {}. Must be between -90 and 90.", self.latitude));
+            let hint = if crate::geodesy::swapped_coordinates(self.latitude, self.longitude) {
+                " Latitude and longitude look swapped."
+            } else {
+                ""
+            };
+            errors.push(ValidationError::new(
+                "birth_data.latitude",
+                ValidationCode::OutOfRange,
+                format!("Invalid latitude: {}. Must be between -90 and 90.{}", self.latitude, hint),
+            ));
         }
 
         // Validate Longitude (-180 to 180)
         if !(self.longitude >= -180.0 && self.longitude <= 180.0) {
-           return Err(format!("Invalid longitude: {}. Must be between -180 and 180.", self.longitude));
+            errors.push(ValidationError::new(
+                "birth_data.longitude",
+                ValidationCode::OutOfRange,
+                format!("Invalid longitude: {}. Must be between -180 and 180.", self.longitude),
+            ));
+        }
+
+        if let Some(altitude) = self.altitude {
+            let (min, max) = (crate::geodesy::MIN_ALTITUDE_METERS, crate::geodesy::MAX_ALTITUDE_METERS);
+            if !(altitude >= min && altitude <= max) {
+                errors.push(ValidationError::new(
+                    "birth_data.altitude",
+                    ValidationCode::OutOfRange,
+                    format!("Invalid altitude: {} m. Must be between {} and {} meters.", altitude, min, max),
+                ));
+            }
         }
 
-        // Validate Date (basic format check YYYY-MM-DD)
-        if self.date.len() != 10 || self.date.chars().nth(4) != Some('-') || self.date.chars().nth(7) != Some('-') {
-             return Err("Invalid date format. Expected YYYY-MM-DD.".to_string());
+        let timezone = self.timezone.trim();
+        if timezone.is_empty() && self.utc_offset_minutes.is_none() {
+            errors.push(ValidationError::new(
+                "birth_data.timezone",
+                ValidationCode::Required,
+                "Timezone or utc_offset_minutes is required",
+            ));
+        } else if !timezone.is_empty() && !crate::timezone::is_known_timezone(timezone) {
+            errors.push(ValidationError::new(
+                "birth_data.timezone",
+                ValidationCode::Unknown,
+                format!("Unknown timezone '{}'. Use an IANA name or a ±HH:MM offset.", timezone),
+            ));
         }
-        
-        // Check realistic year (1000 - 3000)
-        if let Ok(year) = self.date[0..4].parse::<i32>() {
-            if year < 1000 || year > 3000 {
-                return Err(format!("Year {} out of supported range (1000-3000)", year));
+
+        // Real-world offsets range from UTC-12 to UTC+14
+        if let Some(offset) = self.utc_offset_minutes {
+            let max = crate::timezone::MAX_OFFSET_MINUTES;
+            if !(-max..=max).contains(&offset) {
+                errors.push(ValidationError::new(
+                    "birth_data.utc_offset_minutes",
+                    ValidationCode::OutOfRange,
+                    format!("Invalid UTC offset: {} minutes. Must be within ±15 hours.", offset),
+                ));
             }
-        } else {
-            return Err("Invalid year format".to_string());
         }
 
-        // Validate Timezone (basic check)
-        if self.timezone.trim().is_empty() {
-             return Err("Timezone is required".to_string());
+        errors
+    }
+
+    /// Problem with the birth date, if any: it must be YYYY-MM-DD within years 1000-3000
+    pub fn date_error(&self) -> Option<ValidationError> {
+        let date = match NaiveDate::parse_from_str(&self.date, "%Y-%m-%d") {
+            Ok(date) if self.date.len() == 10 => date,
+            _ => {
+                return Some(ValidationError::new(
+                    "birth_data.date",
+                    ValidationCode::InvalidFormat,
+                    format!("Invalid date '{}'. Expected YYYY-MM-DD.", self.date),
+                ))
+            }
+        };
+
+        if !(1000..=3000).contains(&date.year()) {
+            return Some(ValidationError::new(
+                "birth_data.date",
+                ValidationCode::OutOfRange,
+                format!("Year {} out of supported range (1000-3000)", date.year()),
+            ));
         }
 
-        Ok(())
+        None
     }
 }
 
@@ -119,6 +556,11 @@ pub struct Coordinates {
     /// Altitude in meters above sea level
     #[cfg_attr(feature = "openapi", schema(nullable = true))]
     pub altitude: Option<f64>,
+    /// IANA timezone of the location; the API resolves it from the
+    /// coordinates when omitted
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "openapi", schema(nullable = true, example = "Europe/Lisbon"))]
+    pub timezone: Option<String>,
 }
 
 /// Calculation precision levels
@@ -150,6 +592,124 @@ pub struct CalculationMetadata {
     pub cached: bool,
     /// Timestamp of calculation
     pub timestamp: DateTime<Utc>,
+    /// [`engine_version`](crate::ConsciousnessEngine::engine_version) that
+    /// calculated the result, set by the orchestrator
+    #[cfg_attr(feature = "openapi", schema(example = "1", nullable = true))]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub engine_version: Option<String>,
+    /// Problems with the input that didn't stop the calculation, such as
+    /// option keys the engine ignores
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub warnings: Vec<String>,
+    /// Resources this request consumed, set by the orchestrator
+    #[cfg_attr(feature = "openapi", schema(nullable = true))]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub usage: Option<ResourceUsage>,
+}
+
+/// Resources an engine request consumed, from [`usage::measure`](crate::usage::measure)
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct ResourceUsage {
+    /// CPU time spent calculating, in milliseconds; zero for a cached result
+    pub cpu_time_ms: f64,
+    /// Requests sent to external APIs, such as an LLM or ephemeris service
+    pub external_api_calls: u32,
+    /// Request/response exchanges with the TypeScript engine server
+    pub bridge_round_trips: u32,
+    /// Result cache layer that answered ("l1", "l2" or "l3"); absent when
+    /// the engine calculated
+    #[cfg_attr(feature = "openapi", schema(example = "l1", nullable = true))]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cache_layer: Option<String>,
+}
+
+impl ResourceUsage {
+    /// What serving a result from `layer` of the result cache costs
+    pub fn cached(layer: &str) -> Self {
+        Self {
+            cache_layer: Some(layer.to_string()),
+            ..Self::default()
+        }
+    }
+}
+
+/// Resources a workflow run consumed, summed over its engines and narration
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct WorkflowUsage {
+    pub cpu_time_ms: f64,
+    pub external_api_calls: u32,
+    pub bridge_round_trips: u32,
+    /// Engines that calculated rather than being served from the cache
+    pub engines_calculated: u32,
+    /// Engines answered by each result cache layer
+    pub cache_hits: BTreeMap<String, u32>,
+    /// Result cache layer that served the whole run, without running its
+    /// engines or narration
+    #[cfg_attr(feature = "openapi", schema(nullable = true))]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cache_layer: Option<String>,
+}
+
+impl WorkflowUsage {
+    /// Add the usage of one engine request, or of the narration
+    pub fn add(&mut self, usage: &ResourceUsage) {
+        self.cpu_time_ms += usage.cpu_time_ms;
+        self.external_api_calls += usage.external_api_calls;
+        self.bridge_round_trips += usage.bridge_round_trips;
+    }
+
+    /// Add an engine's usage, counting where its result came from
+    pub fn add_engine(&mut self, usage: &ResourceUsage) {
+        self.add(usage);
+        match &usage.cache_layer {
+            Some(layer) => *self.cache_hits.entry(layer.clone()).or_default() += 1,
+            None => self.engines_calculated += 1,
+        }
+    }
+}
+
+/// How long a cached engine result stays valid, from
+/// [`ConsciousnessEngine::cache_ttl`](crate::ConsciousnessEngine::cache_ttl)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CacheTtl {
+    /// The result depends only on the input, so it never goes stale
+    Infinite,
+    /// Valid for a fixed time after it was calculated
+    Duration(std::time::Duration),
+    /// Valid until the given moment, when the reading moves on
+    UntilNextTransition(DateTime<Utc>),
+}
+
+impl CacheTtl {
+    /// What engines without time-sensitivity information get
+    pub const DEFAULT: CacheTtl = CacheTtl::Duration(std::time::Duration::from_secs(3600));
+
+    /// Time left at `now`; `None` when the result never expires, zero once
+    /// the transition has passed.
+    pub fn remaining(&self, now: DateTime<Utc>) -> Option<std::time::Duration> {
+        match self {
+            CacheTtl::Infinite => None,
+            CacheTtl::Duration(ttl) => Some(*ttl),
+            CacheTtl::UntilNextTransition(at) => Some((*at - now).to_std().unwrap_or_default()),
+        }
+    }
+
+    /// [`remaining`](Self::remaining) from the current time.
+    pub fn expires_in(&self) -> Option<std::time::Duration> {
+        self.remaining(Utc::now())
+    }
+
+    /// Whichever of `self` and `other` runs out first at `now`, e.g. for a
+    /// result built from several cached ones
+    pub fn shorter(self, other: CacheTtl, now: DateTime<Utc>) -> CacheTtl {
+        match (self.remaining(now), other.remaining(now)) {
+            (Some(ours), Some(theirs)) if theirs < ours => other,
+            (Some(_), _) => self,
+            (None, _) => other,
+        }
+    }
 }
 
 /// Multi-engine workflow definition
@@ -160,6 +720,69 @@ pub struct WorkflowDefinition {
     pub name: String,
     pub description: String,
     pub engine_ids: Vec<String>,
+    /// Options per engine id, merged into that engine's `EngineInput.options`
+    /// when the workflow runs, e.g. `{"biorhythm": {"forecast_days": 30}}`
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    #[cfg_attr(feature = "openapi", schema(value_type = Object))]
+    pub engine_overrides: HashMap<String, Value>,
+    /// Post-processing applied in order to every engine output of the workflow
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub transformers: Vec<TransformerStep>,
+    /// Depth by consciousness phase, keyed by the lowest phase each variant
+    /// applies to; a user gets the variant with the highest key at or below
+    /// their phase, e.g. `{"0": {...}, "3": {...}}`
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    #[cfg_attr(feature = "openapi", schema(value_type = Object))]
+    pub phase_variants: BTreeMap<u8, PhaseVariant>,
+}
+
+/// Options and post-processing a workflow adds for one range of phases
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct PhaseVariant {
+    /// Merged into every engine's options, e.g. `{"consciousness_level": 0}`
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    #[cfg_attr(feature = "openapi", schema(value_type = Object))]
+    pub options: HashMap<String, Value>,
+    /// Run after the workflow's own `transformers`
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub transformers: Vec<TransformerStep>,
+}
+
+/// One step of a workflow's output post-processing pipeline
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct TransformerStep {
+    /// Id of a transformer registered with the orchestrator
+    #[cfg_attr(feature = "openapi", schema(example = "strip-fields"))]
+    pub id: String,
+    /// Transformer-specific settings, e.g. `{"fields": ["wisdom"]}`
+    #[serde(default)]
+    #[cfg_attr(feature = "openapi", schema(value_type = Object))]
+    pub config: Value,
+}
+
+impl WorkflowDefinition {
+    /// The variant for a user at `phase`, with the phase it starts at
+    pub fn phase_variant(&self, phase: u8) -> Option<(u8, &PhaseVariant)> {
+        self.phase_variants
+            .range(..=phase)
+            .next_back()
+            .map(|(from, variant)| (*from, variant))
+    }
+
+    /// Merge this workflow's overrides for `engine_id` into `input.options`.
+    ///
+    /// Options the caller set explicitly are kept; an override only fills in
+    /// keys the input does not have.
+    pub fn apply_engine_overrides(&self, engine_id: &str, input: &mut EngineInput) {
+        let Some(Value::Object(overrides)) = self.engine_overrides.get(engine_id) else {
+            return;
+        };
+        for (key, value) in overrides {
+            input.options.entry(key.clone()).or_insert_with(|| value.clone());
+        }
+    }
 }
 
 /// Result from executing a multi-engine workflow
@@ -172,4 +795,283 @@ pub struct WorkflowResult {
     pub synthesis: Option<Value>,
     pub total_time_ms: f64,
     pub timestamp: DateTime<Utc>,
+    /// Resources the run consumed; absent on results recorded before it was
+    /// tracked
+    #[cfg_attr(feature = "openapi", schema(nullable = true))]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub usage: Option<WorkflowUsage>,
+    /// Per-engine confidence when a birth time is unknown; absent otherwise
+    #[cfg_attr(feature = "openapi", schema(nullable = true))]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub time_confidence: Option<BirthTimeReport>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn birth() -> BirthData {
+        BirthData {
+            name: Some("Test".to_string()),
+            display_name: None,
+            date: "1990-01-15".to_string(),
+            time: Some("14:30".to_string()),
+            time_unknown: false,
+            latitude: 12.9716,
+            longitude: 77.5946,
+            altitude: None,
+            timezone: "Asia/Kolkata".to_string(),
+            place: None,
+            utc_offset_minutes: None,
+        }
+    }
+
+    #[test]
+    fn test_validation_reports_every_invalid_field() {
+        assert!(birth().validate().is_ok());
+
+        let bad = BirthData {
+            name: Some("  ".to_string()),
+            display_name: None,
+            date: "15/01/1990".to_string(),
+            latitude: 91.0,
+            timezone: "Mars/Olympus_Mons".to_string(),
+            ..birth()
+        };
+        let errors = bad.validation_errors();
+        let fields: Vec<(&str, ValidationCode)> =
+            errors.iter().map(|e| (e.field.as_str(), e.code)).collect();
+        assert_eq!(
+            fields,
+            vec![
+                ("birth_data.date", ValidationCode::InvalidFormat),
+                ("birth_data.name", ValidationCode::Empty),
+                ("birth_data.latitude", ValidationCode::OutOfRange),
+                ("birth_data.timezone", ValidationCode::Unknown),
+            ]
+        );
+        assert!(matches!(bad.validate(), Err(EngineError::InvalidInput(e)) if e.len() == 4));
+
+        let swapped = BirthData { latitude: -122.42, longitude: 37.77, altitude: Some(9_500.0), ..birth() };
+        let errors = swapped.validation_errors();
+        assert!(errors[0].message.ends_with("Latitude and longitude look swapped."));
+        assert_eq!(errors[1].field, "birth_data.altitude");
+
+        let unknown = BirthData { time: None, time_unknown: true, ..birth() };
+        assert!(unknown.validate().is_ok());
+        let contradictory = BirthData { time_unknown: true, ..birth() };
+        let errors = contradictory.validation_errors();
+        assert_eq!(errors[0].field, "birth_data.time_unknown");
+        assert_eq!(errors[0].code, ValidationCode::Conflict);
+    }
+
+    #[test]
+    fn test_seed_option() {
+        let mut input = EngineInput {
+            birth_data: None,
+            secondary_birth_data: None,
+            subjects: Vec::new(),
+            current_time: Utc::now(),
+            location: None,
+            precision: Precision::Standard,
+            options: HashMap::new(),
+        };
+        assert_eq!(input.seed().unwrap(), None);
+
+        input.options.insert(SEED_OPTION.to_string(), serde_json::json!(42));
+        assert_eq!(input.seed().unwrap(), Some(42));
+
+        input.options.insert(SEED_OPTION.to_string(), serde_json::json!("forty-two"));
+        assert!(matches!(
+            input.seed(),
+            Err(EngineError::InvalidInput(e)) if e[0].field == "options.seed"
+        ));
+    }
+
+    #[test]
+    fn test_apply_as_of() {
+        let mut input = EngineInput {
+            birth_data: Some(birth()),
+            secondary_birth_data: None,
+            subjects: Vec::new(),
+            current_time: Utc::now(),
+            location: None,
+            precision: Precision::Standard,
+            options: HashMap::new(),
+        };
+        input.apply_as_of().unwrap();
+        assert!(input.as_of().unwrap().is_none());
+
+        // A date is noon in the birth timezone, 06:30 UTC for Kolkata
+        input.options.insert(AS_OF_OPTION.to_string(), serde_json::json!("2001-09-09"));
+        input.apply_as_of().unwrap();
+        assert_eq!(input.current_time.to_rfc3339(), "2001-09-09T06:30:00+00:00");
+        assert_eq!(input.options[AS_OF_OPTION], "2001-09-09T06:30:00+00:00");
+        assert_eq!(input.local_time().format("%Y-%m-%d %H:%M").to_string(), "2001-09-09 12:00");
+
+        // Past UTC+12, where 12:00 UTC is already the next day
+        let kiritimati = BirthData { timezone: "Pacific/Kiritimati".to_string(), ..birth() };
+        let mut far_east = EngineInput { birth_data: Some(kiritimati), ..input.clone() };
+        far_east.options.insert(AS_OF_OPTION.to_string(), serde_json::json!("2001-09-09"));
+        far_east.apply_as_of().unwrap();
+        assert_eq!(far_east.local_time().format("%Y-%m-%d %H:%M").to_string(), "2001-09-09 12:00");
+
+        // Without birth data a date is 12:00 UTC
+        let mut no_birth = EngineInput { birth_data: None, ..input.clone() };
+        no_birth.options.insert(AS_OF_OPTION.to_string(), serde_json::json!("2001-09-09"));
+        no_birth.apply_as_of().unwrap();
+        assert_eq!(no_birth.current_time.to_rfc3339(), "2001-09-09T12:00:00+00:00");
+
+        // Born 1990-01-15 14:30 IST = 09:00 UTC
+        input.options.insert(AS_OF_OPTION.to_string(), serde_json::json!("1990-01-15T08:59:00Z"));
+        assert!(matches!(
+            input.apply_as_of(),
+            Err(EngineError::InvalidInput(e)) if e[0].field == "options.as_of" && e[0].code == ValidationCode::OutOfRange
+        ));
+
+        input.options.insert(AS_OF_OPTION.to_string(), serde_json::json!("last tuesday"));
+        assert!(input.apply_as_of().is_err());
+    }
+
+    #[test]
+    fn test_subjects_take_precedence_over_legacy_fields() {
+        let partner = BirthData { date: "1988-07-04".to_string(), ..birth() };
+        let mut input: EngineInput = serde_json::from_value(serde_json::json!({
+            "birth_data": birth(),
+            "subjects": [{ "role": "secondary", "birth_data": partner }],
+        }))
+        .unwrap();
+        assert_eq!(input.primary_birth_data().unwrap().date, "1990-01-15");
+        assert_eq!(input.subject(SubjectRole::Secondary).unwrap().date, "1988-07-04");
+
+        let swapped = input.for_secondary().unwrap();
+        assert_eq!(swapped.primary_birth_data().unwrap().date, "1988-07-04");
+        assert_eq!(swapped.subject(SubjectRole::Secondary).unwrap().date, "1990-01-15");
+
+        input.fill_legacy_birth_data();
+        assert_eq!(input.secondary_birth_data.as_ref().unwrap().date, "1988-07-04");
+        assert!(input.validate_subjects().is_ok());
+
+        input.subjects.push(Subject { role: SubjectRole::Secondary, birth_data: birth() });
+        assert!(matches!(
+            input.validate_subjects(),
+            Err(EngineError::InvalidInput(e)) if e[0].field == "subjects[1].role" && e[0].code == ValidationCode::Duplicate
+        ));
+    }
+
+    #[test]
+    fn test_fields_match_serialized_input() {
+        let input = EngineInput {
+            birth_data: Some(birth()),
+            secondary_birth_data: Some(birth()),
+            subjects: vec![Subject { role: SubjectRole::Primary, birth_data: birth() }],
+            current_time: Utc::now(),
+            location: None,
+            precision: Precision::Standard,
+            options: HashMap::new(),
+        };
+        let value = serde_json::to_value(&input).unwrap();
+        let mut keys: Vec<&str> = value.as_object().unwrap().keys().map(String::as_str).collect();
+        let mut fields = EngineInput::FIELDS.to_vec();
+        keys.sort_unstable();
+        fields.sort_unstable();
+        assert_eq!(keys, fields);
+    }
+
+    #[test]
+    fn test_engine_overrides_fill_missing_options() {
+        let workflow: WorkflowDefinition = serde_json::from_value(serde_json::json!({
+            "id": "monthly",
+            "name": "Monthly",
+            "description": "",
+            "engine_ids": ["biorhythm", "panchanga"],
+            "engine_overrides": { "biorhythm": { "forecast_days": 30, "precision": "high" } }
+        }))
+        .unwrap();
+        let mut input = EngineInput {
+            birth_data: Some(birth()),
+            secondary_birth_data: None,
+            subjects: Vec::new(),
+            current_time: Utc::now(),
+            location: None,
+            precision: Precision::Standard,
+            options: HashMap::from([("precision".to_string(), serde_json::json!("low"))]),
+        };
+
+        workflow.apply_engine_overrides("panchanga", &mut input);
+        assert_eq!(input.options.len(), 1);
+
+        workflow.apply_engine_overrides("biorhythm", &mut input);
+        assert_eq!(input.options["forecast_days"], 30);
+        assert_eq!(input.options["precision"], "low");
+    }
+
+    #[test]
+    fn test_phase_variant_resolution() {
+        let workflow: WorkflowDefinition = serde_json::from_value(serde_json::json!({
+            "id": "depth",
+            "name": "Depth",
+            "description": "",
+            "engine_ids": ["numerology"],
+            "phase_variants": {
+                "1": { "options": { "consciousness_level": 1 } },
+                "3": { "options": { "consciousness_level": 5 } }
+            }
+        }))
+        .unwrap();
+
+        assert!(workflow.phase_variant(0).is_none());
+        assert_eq!(workflow.phase_variant(2).unwrap().0, 1);
+        let (from, variant) = workflow.phase_variant(5).unwrap();
+        assert_eq!(from, 3);
+        assert_eq!(variant.options["consciousness_level"], 5);
+    }
+
+    #[test]
+    fn test_offset_override_satisfies_timezone() {
+        let b = BirthData {
+            timezone: String::new(),
+            utc_offset_minutes: Some(330),
+            ..birth()
+        };
+        assert!(b.validation_errors().is_empty());
+
+        let b = BirthData { utc_offset_minutes: None, ..b };
+        assert_eq!(b.validation_errors()[0].code, ValidationCode::Required);
+    }
+
+    #[test]
+    fn test_normalize_name_keeps_name_as_given() {
+        let mut b = BirthData {
+            name: Some(" José  O’Brien ".to_string()),
+            ..birth()
+        };
+        b.normalize_name(&NameNormalization::default());
+        assert_eq!(b.name.as_deref(), Some("Jose OBrien"));
+        assert_eq!(b.display_name(), Some(" José  O’Brien "));
+
+        b.normalize_name(&NameNormalization::default());
+        assert_eq!(b.display_name.as_deref(), Some(" José  O’Brien "));
+
+        let mut plain = birth();
+        plain.normalize_name(&NameNormalization::default());
+        assert!(plain.display_name.is_none());
+        assert_eq!(plain.display_name(), Some("Test"));
+    }
+
+    #[test]
+    fn test_cache_ttl_remaining() {
+        let now = Utc::now();
+        assert_eq!(CacheTtl::Infinite.remaining(now), None);
+        assert_eq!(CacheTtl::DEFAULT.remaining(now), Some(std::time::Duration::from_secs(3600)));
+        let next = CacheTtl::UntilNextTransition(now + chrono::Duration::minutes(7));
+        assert_eq!(next.remaining(now), Some(std::time::Duration::from_secs(420)));
+        let passed = CacheTtl::UntilNextTransition(now - chrono::Duration::minutes(1));
+        assert_eq!(passed.remaining(now), Some(std::time::Duration::ZERO));
+
+        assert_eq!(CacheTtl::Infinite.shorter(next, now), next);
+        assert_eq!(CacheTtl::DEFAULT.shorter(next, now), next);
+        assert_eq!(next.shorter(CacheTtl::Infinite, now), next);
+        assert_eq!(CacheTtl::Infinite.shorter(CacheTtl::Infinite, now), CacheTtl::Infinite);
+    }
 }
@@ -0,0 +1,113 @@
+//! Resource accounting for engine calculations
+//!
+//! The orchestrator runs each calculation inside [`measure`], which adds up
+//! the CPU time spent polling it and reports it as a [`ResourceUsage`]. Calls
+//! that leave the process are counted where they are sent: the bridge calls
+//! [`record_bridge_round_trip`] for every exchange with the TS server, and
+//! the external API clients call [`record_external_api_call`] for every
+//! request. Outside [`measure`] (or in work handed to `tokio::spawn`) the
+//! counters are no-ops.
+
+use std::future::Future;
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+use crate::types::ResourceUsage;
+
+#[derive(Default)]
+struct Counters {
+    external_api_calls: AtomicU32,
+    bridge_round_trips: AtomicU32,
+}
+
+tokio::task_local! {
+    static COUNTERS: Arc<Counters>;
+}
+
+/// Count one request to an external API against the current calculation
+pub fn record_external_api_call() {
+    let _ = COUNTERS.try_with(|c| c.external_api_calls.fetch_add(1, Ordering::Relaxed));
+}
+
+/// Count one request/response exchange with the TS engine server against the
+/// current calculation
+pub fn record_bridge_round_trip() {
+    let _ = COUNTERS.try_with(|c| c.bridge_round_trips.fetch_add(1, Ordering::Relaxed));
+}
+
+/// Run `fut`, returning its output with the resources it used.
+///
+/// CPU time is that of the threads that polled `fut`, so it leaves out time
+/// spent waiting on I/O and work moved to other tasks. It is only measured on
+/// Unix; elsewhere it is reported as zero.
+pub async fn measure<F: Future>(fut: F) -> (F::Output, ResourceUsage) {
+    let counters = Arc::new(Counters::default());
+    let mut fut = std::pin::pin!(COUNTERS.scope(counters.clone(), fut));
+    let mut cpu_time = Duration::ZERO;
+    let output = std::future::poll_fn(|cx| {
+        let started = thread_cpu_time();
+        let poll = fut.as_mut().poll(cx);
+        cpu_time += thread_cpu_time().saturating_sub(started);
+        poll
+    })
+    .await;
+
+    let usage = ResourceUsage {
+        cpu_time_ms: cpu_time.as_secs_f64() * 1000.0,
+        external_api_calls: counters.external_api_calls.load(Ordering::Relaxed),
+        bridge_round_trips: counters.bridge_round_trips.load(Ordering::Relaxed),
+        cache_layer: None,
+    };
+    (output, usage)
+}
+
+/// CPU time the current thread has used so far
+#[cfg(unix)]
+fn thread_cpu_time() -> Duration {
+    let mut ts = libc::timespec { tv_sec: 0, tv_nsec: 0 };
+    // SAFETY: `ts` is a valid, writable timespec for the duration of the call
+    if unsafe { libc::clock_gettime(libc::CLOCK_THREAD_CPUTIME_ID, &mut ts) } != 0 {
+        return Duration::ZERO;
+    }
+    Duration::new(ts.tv_sec as u64, ts.tv_nsec as u32)
+}
+
+#[cfg(not(unix))]
+fn thread_cpu_time() -> Duration {
+    Duration::ZERO
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn measure_counts_calls_inside_its_scope() {
+        record_external_api_call();
+
+        let (value, usage) = measure(async {
+            record_external_api_call();
+            record_bridge_round_trip();
+            record_bridge_round_trip();
+            tokio::task::yield_now().await;
+            // Spin long enough for the thread clock to tick
+            let mut x = 0u64;
+            for i in 0..2_000_000u64 {
+                x = std::hint::black_box(x.wrapping_add(i));
+            }
+            x
+        })
+        .await;
+
+        assert!(value > 0);
+        assert_eq!(usage.external_api_calls, 1);
+        assert_eq!(usage.bridge_round_trips, 2);
+        assert_eq!(usage.cache_layer, None);
+        #[cfg(unix)]
+        assert!(usage.cpu_time_ms > 0.0);
+
+        let (_, idle) = measure(async {}).await;
+        assert_eq!(idle.external_api_calls, 0);
+    }
+}
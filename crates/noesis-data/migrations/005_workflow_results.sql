@@ -0,0 +1,33 @@
+-- Migration: 005_workflow_results
+-- Description: Persist workflow results and signed read-only share links
+
+-- ============================================================
+-- Saved workflow results
+-- result holds the serialized WorkflowResult exactly as returned by
+-- POST /api/v1/workflows/:id/execute?save=true.
+-- ============================================================
+CREATE TABLE IF NOT EXISTS workflow_results (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    workflow_id VARCHAR(100) NOT NULL,
+    result JSONB NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+-- List a user's saved results, newest first
+CREATE INDEX IF NOT EXISTS idx_workflow_results_user_id ON workflow_results(user_id, created_at DESC);
+
+-- ============================================================
+-- Share links
+-- The link token carries the share id and expiry signed with the server
+-- secret; the row must still exist and be unexpired for the link to resolve.
+-- Deleting the row (or the result) revokes the link.
+-- ============================================================
+CREATE TABLE IF NOT EXISTS workflow_result_shares (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    result_id UUID NOT NULL REFERENCES workflow_results(id) ON DELETE CASCADE,
+    expires_at TIMESTAMPTZ NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_workflow_result_shares_result_id ON workflow_result_shares(result_id);
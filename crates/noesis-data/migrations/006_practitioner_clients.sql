@@ -0,0 +1,62 @@
+-- Migration: 006_practitioner_clients
+-- Description: Organizations, practitioner membership and client profiles with consent flags
+
+-- ============================================================
+-- Organizations
+-- A practice owned by one user; members work with its clients.
+-- ============================================================
+CREATE TABLE IF NOT EXISTS organizations (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    name VARCHAR(255) NOT NULL,
+    owner_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+-- ============================================================
+-- Organization members
+-- role is 'owner', 'practitioner' or 'assistant' and decides the client
+-- permissions granted at login (see noesis_auth::permissions).
+-- ============================================================
+CREATE TABLE IF NOT EXISTS organization_members (
+    organization_id UUID NOT NULL REFERENCES organizations(id) ON DELETE CASCADE,
+    user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    role VARCHAR(50) NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (organization_id, user_id)
+);
+
+-- Memberships of a user (login, access checks)
+CREATE INDEX IF NOT EXISTS idx_organization_members_user_id ON organization_members(user_id);
+
+-- ============================================================
+-- Clients
+-- Birth profiles managed by an organization on a client's behalf.
+-- Calculations require consent_calculations; sharing results requires
+-- consent_sharing. consent_updated_at records the last change to either.
+-- Coordinates are DOUBLE PRECISION so they decode directly into f64.
+-- ============================================================
+CREATE TABLE IF NOT EXISTS clients (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    organization_id UUID NOT NULL REFERENCES organizations(id) ON DELETE CASCADE,
+    created_by UUID REFERENCES users(id) ON DELETE SET NULL,
+    full_name VARCHAR(255) NOT NULL,
+    birth_date DATE NOT NULL,
+    birth_time TIME,
+    birth_location_lat DOUBLE PRECISION NOT NULL,
+    birth_location_lng DOUBLE PRECISION NOT NULL,
+    birth_location_name VARCHAR(255),
+    timezone VARCHAR(50) NOT NULL,
+    consent_calculations BOOLEAN NOT NULL DEFAULT false,
+    consent_sharing BOOLEAN NOT NULL DEFAULT false,
+    consent_updated_at TIMESTAMPTZ,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_clients_organization_id ON clients(organization_id);
+
+-- Results calculated for a client; NULL for a user's own readings
+ALTER TABLE workflow_results
+    ADD COLUMN IF NOT EXISTS client_id UUID REFERENCES clients(id) ON DELETE CASCADE;
+
+CREATE INDEX IF NOT EXISTS idx_workflow_results_client_id ON workflow_results(client_id);
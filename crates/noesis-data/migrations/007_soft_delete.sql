@@ -0,0 +1,27 @@
+-- Migration: 007_soft_delete
+-- Description: Soft-delete accounts and their data, restorable for 30 days
+
+-- ============================================================
+-- deleted_at
+-- Deleting an account stamps the user and everything written on their
+-- behalf (profile, saved results, progression history) with the same
+-- deleted_at. Restoring clears every row carrying that timestamp. Reads
+-- skip rows where deleted_at IS NOT NULL.
+-- ============================================================
+ALTER TABLE users ADD COLUMN IF NOT EXISTS deleted_at TIMESTAMPTZ;
+ALTER TABLE user_profiles ADD COLUMN IF NOT EXISTS deleted_at TIMESTAMPTZ;
+ALTER TABLE workflow_results ADD COLUMN IF NOT EXISTS deleted_at TIMESTAMPTZ;
+ALTER TABLE progression_logs ADD COLUMN IF NOT EXISTS deleted_at TIMESTAMPTZ;
+
+-- The purge job looks up accounts whose restore window has passed
+CREATE INDEX IF NOT EXISTS idx_users_deleted_at ON users(deleted_at) WHERE deleted_at IS NOT NULL;
+
+-- ============================================================
+-- Purge
+-- Purging deletes the users row; dependent rows go with it. Progression
+-- logs were created without a cascade, so add one.
+-- ============================================================
+ALTER TABLE progression_logs DROP CONSTRAINT IF EXISTS progression_logs_user_id_fkey;
+ALTER TABLE progression_logs
+    ADD CONSTRAINT progression_logs_user_id_fkey
+    FOREIGN KEY (user_id) REFERENCES users(id) ON DELETE CASCADE;
@@ -0,0 +1,23 @@
+-- Migration: 008_saved_locations
+-- Description: Places a user reads their daily practice from, besides the birth place
+
+-- ============================================================
+-- Saved locations
+-- Named places of a user (home, office, a city they travel to). A
+-- workflow request picks one by id, or the nearest to the coordinates it
+-- sends, as its current location. Coordinates are DOUBLE PRECISION so
+-- they decode directly into f64; timezone is an IANA name. Soft-deleted
+-- with the account like the rest of a user's data.
+-- ============================================================
+CREATE TABLE IF NOT EXISTS saved_locations (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    name VARCHAR(255) NOT NULL,
+    latitude DOUBLE PRECISION NOT NULL,
+    longitude DOUBLE PRECISION NOT NULL,
+    timezone VARCHAR(50) NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    deleted_at TIMESTAMPTZ
+);
+
+CREATE INDEX IF NOT EXISTS idx_saved_locations_user_id ON saved_locations(user_id);
@@ -0,0 +1,29 @@
+-- Migration: 009_practice_logs
+-- Description: Whether users followed the practices engines recommend
+
+-- ============================================================
+-- Practice logs
+-- One row per user, practice and day: whether the user followed a
+-- recommendation such as resting on a biorhythm critical day or
+-- contemplating the weekly Gene Key. practice is a short slug chosen by
+-- the client; engine_id names the engine that recommended it, when there
+-- is one, so outcomes can be counted against its experiments. Logging the
+-- same practice again on the same day replaces the entry. Consecutive
+-- followed days make a practice's streak. Soft-deleted with the account
+-- like the rest of a user's data.
+-- ============================================================
+CREATE TABLE IF NOT EXISTS practice_logs (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    practice VARCHAR(64) NOT NULL,
+    engine_id VARCHAR(50),
+    practice_date DATE NOT NULL,
+    followed BOOLEAN NOT NULL,
+    note TEXT,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    deleted_at TIMESTAMPTZ,
+    UNIQUE (user_id, practice, practice_date)
+);
+
+CREATE INDEX IF NOT EXISTS idx_practice_logs_user_date ON practice_logs(user_id, practice_date);
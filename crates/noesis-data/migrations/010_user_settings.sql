@@ -0,0 +1,19 @@
+-- Migration: 010_user_settings
+-- Description: Calculation settings users save instead of resending them
+
+-- ============================================================
+-- User settings
+-- One row per user holding the engine options applied to every
+-- calculation and workflow they run, such as the panchanga ayanamsa or
+-- the Human Design design_method. settings is a JSON object of option
+-- name to value, validated by the API before it is stored; options sent
+-- with a request override it. Soft-deleted with the account like the
+-- rest of a user's data.
+-- ============================================================
+CREATE TABLE IF NOT EXISTS user_settings (
+    user_id UUID PRIMARY KEY REFERENCES users(id) ON DELETE CASCADE,
+    settings JSONB NOT NULL DEFAULT '{}',
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    deleted_at TIMESTAMPTZ
+);
@@ -0,0 +1,13 @@
+-- Migration: 011_retention
+-- Description: Index the timestamps retention purges by
+
+-- ============================================================
+-- Retention
+-- The retention job deletes rows of each data class older than its
+-- configured TTL (see RETENTION_*_DAYS): saved results by created_at,
+-- practice logs and client profiles by updated_at. usage_logs already
+-- has an index on created_at.
+-- ============================================================
+CREATE INDEX IF NOT EXISTS idx_workflow_results_created_at ON workflow_results(created_at);
+CREATE INDEX IF NOT EXISTS idx_practice_logs_updated_at ON practice_logs(updated_at);
+CREATE INDEX IF NOT EXISTS idx_clients_updated_at ON clients(updated_at);
@@ -0,0 +1,27 @@
+-- Migration: 012_result_annotations
+-- Description: Notes and corrections practitioners attach to saved results
+
+-- ============================================================
+-- Result annotations
+-- A note on a saved workflow result, an override of one computed value,
+-- or both. engine_id and path locate the value: path is a JSON pointer
+-- into that engine's result, such as /authority. An override replaces the
+-- value when the result is fetched; workflow_results.result is never
+-- rewritten, so the original calculation stays available. When several
+-- overrides target the same value the newest applies. Deleted with the
+-- result.
+-- ============================================================
+CREATE TABLE IF NOT EXISTS result_annotations (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    result_id UUID NOT NULL REFERENCES workflow_results(id) ON DELETE CASCADE,
+    author_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    engine_id VARCHAR(50),
+    path VARCHAR(200),
+    note TEXT,
+    value JSONB,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    CHECK (note IS NOT NULL OR value IS NOT NULL),
+    CHECK (value IS NULL OR (engine_id IS NOT NULL AND path IS NOT NULL))
+);
+
+CREATE INDEX IF NOT EXISTS idx_result_annotations_result_id ON result_annotations(result_id, created_at);
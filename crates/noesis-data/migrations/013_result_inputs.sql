@@ -0,0 +1,13 @@
+-- Migration: 013_result_inputs
+-- Description: Keep what a saved result was calculated from, for replays
+
+-- ============================================================
+-- Result inputs
+-- input holds the EngineInput the workflow ran on, with the caller's saved
+-- settings already merged in, and phase the consciousness phase it ran at.
+-- Together they let an admin re-execute the calculation with debug output
+-- (POST /api/v1/admin/results/:id/replay). Results saved before this
+-- migration have neither and cannot be replayed.
+-- ============================================================
+ALTER TABLE workflow_results ADD COLUMN IF NOT EXISTS input JSONB;
+ALTER TABLE workflow_results ADD COLUMN IF NOT EXISTS phase SMALLINT;
@@ -0,0 +1,49 @@
+-- Migration: 014_notification_rules
+-- Description: Calendar notifications users subscribe to, and what was sent
+
+-- ============================================================
+-- Notification rules
+-- A user's subscription to one category of event (ekadashi, full_moon,
+-- eclipse, dasha_change, biorhythm_triple_critical), sent lead_days
+-- before the event's local date on one channel: in_app, or webhook to
+-- webhook_url. Several reminders for the same category are several
+-- rules. Evaluated by the notification job against the user's birth
+-- profile; rules of deleted accounts are skipped and go with the account.
+-- ============================================================
+CREATE TABLE IF NOT EXISTS notification_rules (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    category VARCHAR(40) NOT NULL,
+    lead_days SMALLINT NOT NULL DEFAULT 0 CHECK (lead_days >= 0),
+    channel VARCHAR(20) NOT NULL,
+    webhook_url TEXT,
+    enabled BOOLEAN NOT NULL DEFAULT TRUE,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    CHECK (channel <> 'webhook' OR webhook_url IS NOT NULL)
+);
+
+CREATE INDEX IF NOT EXISTS idx_notification_rules_user_id ON notification_rules(user_id);
+
+-- ============================================================
+-- Notifications
+-- One event a rule fired for. event_key names the event (e.g.
+-- ekadashi:2026-10-17), so the unique constraint keeps a rule from
+-- firing twice for it however often the job runs. In-app notifications
+-- are delivered when written; webhook ones when the receiver accepts
+-- them, and are retried until the event date has passed.
+-- ============================================================
+CREATE TABLE IF NOT EXISTS notifications (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    rule_id UUID NOT NULL REFERENCES notification_rules(id) ON DELETE CASCADE,
+    user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    category VARCHAR(40) NOT NULL,
+    event_key VARCHAR(120) NOT NULL,
+    event_date DATE NOT NULL,
+    title TEXT NOT NULL,
+    channel VARCHAR(20) NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    delivered_at TIMESTAMPTZ,
+    UNIQUE (rule_id, event_key)
+);
+
+CREATE INDEX IF NOT EXISTS idx_notifications_user_id ON notifications(user_id, created_at DESC);
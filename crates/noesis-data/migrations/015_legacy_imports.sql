@@ -0,0 +1,21 @@
+-- Migration: 015_legacy_imports
+-- Description: Record rows imported from the legacy Selemene deployment
+
+-- ============================================================
+-- Legacy imports
+-- One row per legacy record the import_legacy tool wrote: source is
+-- 'user' (a birth record, now a user and profile) or 'panchanga_result'
+-- (a cached calculation, now a saved workflow result), legacy_id the id it
+-- had in the old deployment and target_id the row it became. checksum is
+-- the SHA-256 of what was imported. The primary key makes re-running an
+-- import skip what already came over, and lets results find the user
+-- their legacy owner was imported as.
+-- ============================================================
+CREATE TABLE IF NOT EXISTS legacy_imports (
+    source VARCHAR(20) NOT NULL,
+    legacy_id VARCHAR(100) NOT NULL,
+    target_id UUID NOT NULL,
+    checksum CHAR(64) NOT NULL,
+    imported_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (source, legacy_id)
+);
@@ -0,0 +1,12 @@
+-- Migration: 016_chart_analytics
+-- Description: Index the saved results chart analytics aggregate
+
+-- ============================================================
+-- Chart analytics
+-- GET /api/v1/admin/analytics/human-design aggregates each user's latest
+-- saved result carrying a Human Design chart. The partial index lets it
+-- find those without scanning every saved result.
+-- ============================================================
+CREATE INDEX IF NOT EXISTS idx_workflow_results_human_design
+    ON workflow_results(user_id, created_at DESC)
+    WHERE deleted_at IS NULL AND client_id IS NULL AND result->'engine_outputs' ? 'human-design';
@@ -0,0 +1,13 @@
+-- Migration: 017_api_key_scopes
+-- Description: Name API keys and scope them to specific engines and workflows
+
+-- ============================================================
+-- API key scopes
+-- A partner integration can be given a key that only calculates, say,
+-- panchanga and biorhythm. allowed_engines and allowed_workflows hold
+-- JSON arrays of ids; NULL allows every engine or workflow, an empty
+-- array none. name is a label the owner chooses, shown when listing keys.
+-- ============================================================
+ALTER TABLE api_keys ADD COLUMN IF NOT EXISTS name VARCHAR(255);
+ALTER TABLE api_keys ADD COLUMN IF NOT EXISTS allowed_engines JSONB;
+ALTER TABLE api_keys ADD COLUMN IF NOT EXISTS allowed_workflows JSONB;
@@ -0,0 +1,13 @@
+-- Migration: 018_research_exports
+-- Description: Index the saved results research exports page through
+
+-- ============================================================
+-- Research exports
+-- POST /api/v1/admin/research-exports reads every user's saved results
+-- in (created_at, id) order, a page at a time, leaving out deleted results
+-- and those calculated for practitioners' clients. The partial index lets
+-- each page start where the last one ended.
+-- ============================================================
+CREATE INDEX IF NOT EXISTS idx_workflow_results_research_export
+    ON workflow_results(created_at, id)
+    WHERE deleted_at IS NULL AND client_id IS NULL;
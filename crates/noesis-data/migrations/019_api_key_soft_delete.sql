@@ -0,0 +1,11 @@
+-- Migration: 019_api_key_soft_delete
+-- Description: Soft-delete API keys with the account that owns them
+
+-- ============================================================
+-- deleted_at
+-- Deleting an account stamps its API keys with the account's deleted_at,
+-- so they stop authenticating at once; restoring clears it again. Keys
+-- revoked before the deletion stay revoked, since revoking clears
+-- is_active rather than setting deleted_at.
+-- ============================================================
+ALTER TABLE api_keys ADD COLUMN IF NOT EXISTS deleted_at TIMESTAMPTZ;
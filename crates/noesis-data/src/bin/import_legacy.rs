@@ -0,0 +1,83 @@
+//! Import users and cached results from the legacy Selemene deployment
+//!
+//! Usage:
+//!   cargo run -p noesis-data --bin import_legacy -- [--dry-run] [--users FILE] [--results FILE]
+//!
+//! FILEs are JSON lines exports of the legacy tables (see
+//! `noesis_data::legacy` for the row formats); users are imported before
+//! results. The database is `DATABASE_URL`, migrated first. `--dry-run`
+//! checks every row without connecting.
+//!
+//! One JSON report per row is printed to stdout and a summary to stderr.
+//! Exits 1 if any row failed.
+
+use std::collections::BTreeMap;
+use std::fs::File;
+use std::io::BufReader;
+use std::process::ExitCode;
+
+use noesis_data::legacy::{ImportStatus, LegacyImporter, RowReport};
+use sqlx::PgPool;
+
+const USAGE: &str = "Usage: import_legacy [--dry-run] [--users FILE] [--results FILE]";
+
+fn open(path: &str) -> BufReader<File> {
+    BufReader::new(File::open(path).unwrap_or_else(|e| panic!("Failed to open {}: {}", path, e)))
+}
+
+#[tokio::main]
+async fn main() -> ExitCode {
+    let mut dry_run = false;
+    let mut users = None;
+    let mut results = None;
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--dry-run" => dry_run = true,
+            "--users" => users = args.next(),
+            "--results" => results = args.next(),
+            _ => {
+                eprintln!("{}", USAGE);
+                return ExitCode::FAILURE;
+            }
+        }
+    }
+    if users.is_none() && results.is_none() {
+        eprintln!("{}", USAGE);
+        return ExitCode::FAILURE;
+    }
+
+    let importer = if dry_run {
+        let pool = PgPool::connect_lazy("postgres://localhost").expect("Failed to create pool");
+        LegacyImporter::new(pool).dry_run()
+    } else {
+        let url = std::env::var("DATABASE_URL").expect("DATABASE_URL must be set");
+        let pool = noesis_data::create_pool(&url).await.expect("Failed to connect to database");
+        noesis_data::run_migrations(&pool).await.expect("Failed to run migrations");
+        LegacyImporter::new(pool)
+    };
+
+    let mut reports: Vec<RowReport> = Vec::new();
+    if let Some(path) = &users {
+        reports.extend(importer.import_users(open(path)).await);
+    }
+    if let Some(path) = &results {
+        reports.extend(importer.import_results(open(path)).await);
+    }
+
+    let mut counts: BTreeMap<(&str, String), usize> = BTreeMap::new();
+    for report in &reports {
+        println!("{}", serde_json::to_string(report).expect("Failed to serialize report"));
+        let status = serde_json::to_value(report.status).expect("Failed to serialize status");
+        *counts.entry((report.source, status.as_str().unwrap_or_default().to_string())).or_default() += 1;
+    }
+    for ((source, status), count) in &counts {
+        eprintln!("{}: {} {}", source, count, status);
+    }
+
+    if reports.iter().any(|report| report.status == ImportStatus::Failed) {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
@@ -1,9 +1,17 @@
-use sqlx::postgres::PgPoolOptions;
+use sqlx::migrate::{MigrateError, Migrator};
+use sqlx::postgres::{PgConnectOptions, PgPoolOptions};
 use sqlx::{Pool, Postgres};
+use std::str::FromStr;
 use std::time::Duration;
 
 pub type DbPool = Pool<Postgres>;
 
+/// Schema migrations from `crates/noesis-data/migrations`, embedded at compile time
+pub static MIGRATOR: Migrator = sqlx::migrate!("./migrations");
+
+/// Longest wait between two connection attempts
+const MAX_RETRY_DELAY: Duration = Duration::from_secs(30);
+
 pub async fn create_pool(connection_string: &str) -> Result<DbPool, sqlx::Error> {
     PgPoolOptions::new()
         .max_connections(50)
@@ -11,3 +19,91 @@ pub async fn create_pool(connection_string: &str) -> Result<DbPool, sqlx::Error>
         .connect(connection_string)
         .await
 }
+
+/// The primary pool, which takes every write, and an optional read replica
+///
+/// Repositories send reads that tolerate replication lag to [`DbPools::read`];
+/// without a replica that is the primary as well.
+#[derive(Debug, Clone)]
+pub struct DbPools {
+    pub primary: DbPool,
+    pub replica: Option<DbPool>,
+}
+
+impl DbPools {
+    pub fn new(primary: DbPool) -> Self {
+        Self { primary, replica: None }
+    }
+
+    pub fn with_replica(mut self, replica: DbPool) -> Self {
+        self.replica = Some(replica);
+        self
+    }
+
+    /// Pool for reads that may lag behind the primary
+    pub fn read(&self) -> &DbPool {
+        self.replica.as_ref().unwrap_or(&self.primary)
+    }
+}
+
+/// Parse a connection string, applying `statement_timeout` to every session.
+///
+/// Queries running longer than the timeout are cancelled by Postgres; `None`
+/// keeps the server default.
+pub fn connect_options(
+    connection_string: &str,
+    statement_timeout: Option<Duration>,
+) -> Result<PgConnectOptions, sqlx::Error> {
+    let options = PgConnectOptions::from_str(connection_string)?;
+    Ok(match statement_timeout {
+        Some(timeout) => options.options([(
+            "statement_timeout",
+            format!("{}ms", timeout.as_millis()),
+        )]),
+        None => options,
+    })
+}
+
+/// Connect with exponential backoff, trying up to `max_attempts` times.
+///
+/// The wait starts at `initial_delay` and doubles after each failure, capped
+/// at 30 seconds. Returns the last error once every attempt has failed.
+pub async fn connect_with_retry(
+    options: PgPoolOptions,
+    connect_options: PgConnectOptions,
+    max_attempts: u32,
+    initial_delay: Duration,
+) -> Result<DbPool, sqlx::Error> {
+    let mut delay = initial_delay;
+    let mut attempt = 1;
+    loop {
+        match options.clone().connect_with(connect_options.clone()).await {
+            Ok(pool) => return Ok(pool),
+            Err(e) if attempt >= max_attempts => return Err(e),
+            Err(e) => {
+                tracing::warn!(
+                    attempt,
+                    max_attempts,
+                    retry_in_ms = delay.as_millis() as u64,
+                    error = %e,
+                    "Database connection failed, retrying"
+                );
+                tokio::time::sleep(delay).await;
+                delay = (delay * 2).min(MAX_RETRY_DELAY);
+                attempt += 1;
+            }
+        }
+    }
+}
+
+/// Apply pending migrations; already applied ones are skipped.
+///
+/// The statement timeout is lifted while migrating, since a migration may
+/// rewrite a large table.
+pub async fn run_migrations(pool: &DbPool) -> Result<(), MigrateError> {
+    let mut conn = pool.acquire().await?;
+    sqlx::query("SET statement_timeout = 0").execute(&mut *conn).await?;
+    let result = MIGRATOR.run_direct(&mut *conn).await;
+    sqlx::query("RESET statement_timeout").execute(&mut *conn).await?;
+    result
+}
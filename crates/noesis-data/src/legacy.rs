@@ -0,0 +1,567 @@
+//! Import of user data from the legacy Selemene deployment
+//!
+//! The old deployment kept birth records and the panchanga results it had
+//! cached for them. Both are exported as JSON lines, one legacy row per line
+//! (e.g. `COPY (SELECT row_to_json(u) FROM users u) TO ...`), and imported
+//! with the `import_legacy` binary:
+//!
+//! - A birth record becomes a user with a complete birth profile. Accounts
+//!   get no password; users sign in after a password reset. A record whose
+//!   email already has an account is linked to it and the account is left
+//!   alone.
+//! - A cached result becomes a saved result of its owner's history, under
+//!   the `legacy-panchanga` workflow id. The legacy result is kept verbatim
+//!   as the `panchanga` output, with the request rebuilt as its input.
+//!
+//! Each row's checksum is recomputed. The legacy cache named results by the
+//! MD5 of their cache key, so a result whose stored checksum no longer
+//! matches its request is rejected. What is imported is recorded in
+//! `legacy_imports` with its SHA-256, so an import can be re-run and skips
+//! what already came over.
+//!
+//! Every row gets a [`RowReport`]; a bad row never stops the import.
+
+use std::io::BufRead;
+
+use chrono::{DateTime, NaiveDate, NaiveTime, Utc};
+use noesis_core::{
+    BirthData, CalculationMetadata, Coordinates, EngineInput, EngineOutput, Precision, WorkflowResult,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use sha2::{Digest, Sha256};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::models::legacy::{NewLegacyResult, NewLegacyUser, PANCHANGA_RESULT_SOURCE, USER_SOURCE};
+use crate::repositories::legacy_import_repository::LegacyImportRepository;
+use crate::repositories::user_repository::UserRepository;
+
+/// Workflow id of imported results
+pub const LEGACY_WORKFLOW_ID: &str = "legacy-panchanga";
+
+/// Engine version recorded on imported outputs
+pub const LEGACY_ENGINE_VERSION: &str = "legacy";
+
+/// Legacy ids were integers in some tables and text in others
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(untagged)]
+pub enum LegacyId {
+    Number(i64),
+    Text(String),
+}
+
+impl std::fmt::Display for LegacyId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LegacyId::Number(id) => write!(f, "{}", id),
+            LegacyId::Text(id) => f.write_str(id),
+        }
+    }
+}
+
+/// A legacy birth record
+#[derive(Debug, Clone, Deserialize)]
+pub struct LegacyUserRow {
+    pub id: LegacyId,
+    pub email: String,
+    #[serde(default)]
+    pub name: Option<String>,
+    /// YYYY-MM-DD
+    pub birth_date: String,
+    /// HH:MM or HH:MM:SS; absent when the time was not known
+    #[serde(default)]
+    pub birth_time: Option<String>,
+    pub latitude: f64,
+    pub longitude: f64,
+    /// IANA name or ±HH:MM offset
+    pub timezone: String,
+    #[serde(default)]
+    pub location_name: Option<String>,
+    #[serde(default)]
+    pub created_at: Option<DateTime<Utc>>,
+}
+
+/// The request a legacy panchanga result was calculated for
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LegacyPanchangaRequest {
+    pub date: String,
+    #[serde(default)]
+    pub latitude: Option<f64>,
+    #[serde(default)]
+    pub longitude: Option<f64>,
+    #[serde(default)]
+    pub timezone: Option<String>,
+    #[serde(default)]
+    pub precision: Option<Precision>,
+}
+
+/// A legacy cached panchanga result
+#[derive(Debug, Clone, Deserialize)]
+pub struct LegacyResultRow {
+    pub id: LegacyId,
+    /// Birth record the result was calculated for; results the cache
+    /// precomputed for nobody have none
+    #[serde(default)]
+    pub user_id: Option<LegacyId>,
+    pub request: LegacyPanchangaRequest,
+    /// The legacy `PanchangaResult`
+    pub result: Value,
+    /// MD5 of the legacy cache key, hex encoded
+    #[serde(default)]
+    pub checksum: Option<String>,
+    #[serde(default)]
+    pub created_at: Option<DateTime<Utc>>,
+}
+
+/// The legacy L3 cache key, serialized in its field order
+#[derive(Serialize)]
+struct LegacyCacheKey<'a> {
+    date: &'a str,
+    latitude: Option<f64>,
+    longitude: Option<f64>,
+    precision: u8,
+    backend: &'a str,
+}
+
+/// Outcome of importing one row
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ImportStatus {
+    /// Written to the new tables
+    Imported,
+    /// Birth record whose email already has an account, linked to it
+    Linked,
+    /// Imported by an earlier run, or a result without an owner
+    Skipped,
+    /// Dry run: the row maps cleanly and would be imported
+    Valid,
+    Failed,
+}
+
+/// What happened to one row of an import
+#[derive(Debug, Clone, Serialize)]
+pub struct RowReport {
+    /// `user` or `panchanga_result`
+    pub source: &'static str,
+    /// Line of the export the row was read from, from 1
+    pub line: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub legacy_id: Option<String>,
+    pub status: ImportStatus,
+    /// The user or saved result the row became
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub target_id: Option<Uuid>,
+    /// SHA-256 of the imported data
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub checksum: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+}
+
+impl RowReport {
+    fn new(source: &'static str, line: usize, legacy_id: Option<String>) -> Self {
+        Self { source, line, legacy_id, status: ImportStatus::Failed, target_id: None, checksum: None, reason: None }
+    }
+
+    fn status(mut self, status: ImportStatus, target_id: Option<Uuid>, checksum: Option<String>) -> Self {
+        self.status = status;
+        self.target_id = target_id;
+        self.checksum = checksum;
+        self
+    }
+
+    fn failed(mut self, reason: impl Into<String>) -> Self {
+        self.status = ImportStatus::Failed;
+        self.reason = Some(reason.into());
+        self
+    }
+
+    fn skipped(mut self, reason: impl Into<String>, target_id: Option<Uuid>) -> Self {
+        self.status = ImportStatus::Skipped;
+        self.target_id = target_id;
+        self.reason = Some(reason.into());
+        self
+    }
+}
+
+/// SHA-256 of `value`'s JSON, hex encoded
+pub fn checksum(value: &Value) -> String {
+    format!("{:x}", Sha256::digest(value.to_string().as_bytes()))
+}
+
+/// MD5 of the legacy cache key of a result, as the legacy cache named it.
+/// `None` when the result lacks the precision or backend the key needs.
+pub fn legacy_cache_checksum(request: &LegacyPanchangaRequest, result: &Value) -> Option<String> {
+    let key = LegacyCacheKey {
+        date: &request.date,
+        latitude: request.latitude,
+        longitude: request.longitude,
+        precision: u8::try_from(result.get("precision")?.as_u64()?).ok()?,
+        backend: result.get("backend")?.as_str()?,
+    };
+    let json = serde_json::to_string(&key).ok()?;
+    Some(format!("{:x}", md5::compute(json)))
+}
+
+/// Map a legacy birth record, checking it as birth data is checked for
+/// engines; the problems found otherwise
+pub fn map_user(row: &LegacyUserRow) -> Result<NewLegacyUser, String> {
+    let email = row.email.trim();
+    if !email.contains('@') {
+        return Err(format!("Invalid email '{}'", row.email));
+    }
+    let mut birth = BirthData {
+        name: None,
+        display_name: None,
+        date: row.birth_date.clone(),
+        time: row.birth_time.clone(),
+        time_unknown: row.birth_time.is_none(),
+        latitude: row.latitude,
+        longitude: row.longitude,
+        altitude: None,
+        timezone: row.timezone.trim().to_string(),
+        place: None,
+        utc_offset_minutes: None,
+    };
+    birth.normalize_coordinates();
+    let errors = birth.validation_errors();
+    if !errors.is_empty() {
+        return Err(errors.iter().map(|e| e.message.as_str()).collect::<Vec<_>>().join("; "));
+    }
+
+    // Validated above
+    let birth_date = NaiveDate::parse_from_str(&birth.date, "%Y-%m-%d").map_err(|e| e.to_string())?;
+    let birth_time = birth
+        .time
+        .as_deref()
+        .map(|time| NaiveTime::parse_from_str(time, "%H:%M:%S").or_else(|_| NaiveTime::parse_from_str(time, "%H:%M")))
+        .transpose()
+        .map_err(|e| e.to_string())?;
+
+    Ok(NewLegacyUser {
+        email: email.to_string(),
+        full_name: row.name.as_deref().map(str::trim).unwrap_or_default().to_string(),
+        birth_date,
+        birth_time,
+        birth_location_lat: birth.latitude,
+        birth_location_lng: birth.longitude,
+        birth_location_name: row.location_name.clone().filter(|name| !name.trim().is_empty()),
+        timezone: birth.timezone,
+        created_at: row.created_at.unwrap_or_else(Utc::now),
+    })
+}
+
+/// What a mapped birth record is checksummed as
+fn user_checksum(user: &NewLegacyUser) -> String {
+    checksum(&json!({
+        "email": user.email,
+        "full_name": user.full_name,
+        "birth_date": user.birth_date,
+        "birth_time": user.birth_time,
+        "latitude": user.birth_location_lat,
+        "longitude": user.birth_location_lng,
+        "location_name": user.birth_location_name,
+        "timezone": user.timezone,
+    }))
+}
+
+/// Map a legacy result to a saved result of `user_id`, after checking its
+/// stored checksum against its request
+pub fn map_result(row: &LegacyResultRow, user_id: Uuid) -> Result<NewLegacyResult, String> {
+    if let Some(stored) = &row.checksum {
+        let recomputed = legacy_cache_checksum(&row.request, &row.result)
+            .ok_or("Result lacks the precision or backend its checksum covers")?;
+        if !stored.trim().eq_ignore_ascii_case(&recomputed) {
+            return Err(format!("Checksum mismatch: stored {}, recomputed {}", stored.trim(), recomputed));
+        }
+    }
+    let date = NaiveDate::parse_from_str(&row.request.date, "%Y-%m-%d")
+        .map_err(|_| format!("Invalid request date '{}'", row.request.date))?;
+    let calculated_at = row
+        .result
+        .get("calculation_time")
+        .and_then(|at| serde_json::from_value::<DateTime<Utc>>(at.clone()).ok())
+        .or(row.created_at)
+        .unwrap_or_else(Utc::now);
+    let precision = row.request.precision.unwrap_or_default();
+
+    let output = EngineOutput {
+        engine_id: "panchanga".to_string(),
+        result: row.result.clone(),
+        witness_prompt: String::new(),
+        summary: None,
+        consciousness_level: 0,
+        metadata: CalculationMetadata {
+            calculation_time_ms: 0.0,
+            backend: row.result.get("backend").and_then(Value::as_str).unwrap_or("legacy").to_string(),
+            precision_achieved: format!("{:?}", precision),
+            cached: false,
+            timestamp: calculated_at,
+            engine_version: Some(LEGACY_ENGINE_VERSION.to_string()),
+            warnings: Vec::new(),
+            usage: None,
+        },
+    };
+    let result = WorkflowResult {
+        workflow_id: LEGACY_WORKFLOW_ID.to_string(),
+        engine_outputs: [("panchanga".to_string(), output)].into_iter().collect(),
+        synthesis: None,
+        total_time_ms: 0.0,
+        timestamp: calculated_at,
+        usage: None,
+        time_confidence: None,
+    };
+    let location = match (row.request.latitude, row.request.longitude) {
+        (Some(latitude), Some(longitude)) => Some(Coordinates {
+            latitude,
+            longitude: noesis_core::geodesy::normalize_longitude(longitude),
+            altitude: None,
+            timezone: row.request.timezone.clone(),
+        }),
+        _ => None,
+    };
+    let input = EngineInput {
+        birth_data: None,
+        secondary_birth_data: None,
+        subjects: Vec::new(),
+        current_time: date.and_time(NaiveTime::MIN).and_utc(),
+        location,
+        precision,
+        options: Default::default(),
+    };
+
+    Ok(NewLegacyResult {
+        user_id,
+        workflow_id: LEGACY_WORKFLOW_ID.to_string(),
+        result: serde_json::to_value(&result).map_err(|e| e.to_string())?,
+        input: serde_json::to_value(&input).map_err(|e| e.to_string())?,
+        created_at: row.created_at.unwrap_or(calculated_at),
+    })
+}
+
+/// Rows of a JSON lines export with their line numbers, blank lines skipped
+fn rows<T: for<'de> Deserialize<'de>>(export: impl BufRead) -> impl Iterator<Item = (usize, Result<T, String>)> {
+    export.lines().enumerate().filter_map(|(i, line)| match line {
+        Ok(line) if line.trim().is_empty() => None,
+        Ok(line) => Some((i + 1, serde_json::from_str(&line).map_err(|e| format!("Unreadable row: {}", e)))),
+        Err(e) => Some((i + 1, Err(format!("Unreadable line: {}", e)))),
+    })
+}
+
+/// Imports legacy exports into the database, or only checks them
+pub struct LegacyImporter {
+    imports: LegacyImportRepository,
+    users: UserRepository,
+    dry_run: bool,
+}
+
+impl LegacyImporter {
+    pub fn new(pool: PgPool) -> Self {
+        Self {
+            imports: LegacyImportRepository::new(pool.clone()),
+            users: UserRepository::new(pool),
+            dry_run: false,
+        }
+    }
+
+    /// Map and check every row without touching the database; rows that
+    /// would be imported are reported [`ImportStatus::Valid`]
+    pub fn dry_run(mut self) -> Self {
+        self.dry_run = true;
+        self
+    }
+
+    /// Import the birth records of a `users` export
+    pub async fn import_users(&self, export: impl BufRead) -> Vec<RowReport> {
+        let mut reports = Vec::new();
+        for (line, row) in rows::<LegacyUserRow>(export) {
+            let report = match row {
+                Ok(row) => self.import_user(line, &row).await,
+                Err(reason) => RowReport::new(USER_SOURCE, line, None).failed(reason),
+            };
+            reports.push(report);
+        }
+        reports
+    }
+
+    async fn import_user(&self, line: usize, row: &LegacyUserRow) -> RowReport {
+        let legacy_id = row.id.to_string();
+        let report = RowReport::new(USER_SOURCE, line, Some(legacy_id.clone()));
+        let user = match map_user(row) {
+            Ok(user) => user,
+            Err(reason) => return report.failed(reason),
+        };
+        let checksum = user_checksum(&user);
+        if self.dry_run {
+            return report.status(ImportStatus::Valid, None, Some(checksum));
+        }
+
+        let imported = async {
+            if let Some(done) = self.imports.find(USER_SOURCE, &legacy_id).await? {
+                return Ok(report.clone().skipped("Already imported", Some(done.target_id)));
+            }
+            let (status, import) = match self.users.get_user_by_email(&user.email).await? {
+                Some(existing) => (ImportStatus::Linked, self.imports.link_user(&legacy_id, existing.id, &checksum).await?),
+                None => (ImportStatus::Imported, self.imports.import_user(&legacy_id, &user, &checksum).await?),
+            };
+            Ok::<_, sqlx::Error>(report.clone().status(status, Some(import.target_id), Some(import.checksum)))
+        };
+        imported.await.unwrap_or_else(|e| report.failed(format!("Database error: {}", e)))
+    }
+
+    /// Import the cached results of a `panchanga_results` export. Run it
+    /// after the users, whose imports the results are attached to.
+    pub async fn import_results(&self, export: impl BufRead) -> Vec<RowReport> {
+        let mut reports = Vec::new();
+        for (line, row) in rows::<LegacyResultRow>(export) {
+            let report = match row {
+                Ok(row) => self.import_result(line, &row).await,
+                Err(reason) => RowReport::new(PANCHANGA_RESULT_SOURCE, line, None).failed(reason),
+            };
+            reports.push(report);
+        }
+        reports
+    }
+
+    async fn import_result(&self, line: usize, row: &LegacyResultRow) -> RowReport {
+        let legacy_id = row.id.to_string();
+        let report = RowReport::new(PANCHANGA_RESULT_SOURCE, line, Some(legacy_id.clone()));
+        let Some(owner) = row.user_id.as_ref().map(LegacyId::to_string) else {
+            return report.skipped("Result has no owner", None);
+        };
+        if self.dry_run {
+            return match map_result(row, Uuid::nil()) {
+                Ok(result) => report.status(ImportStatus::Valid, None, Some(checksum(&result.result))),
+                Err(reason) => report.failed(reason),
+            };
+        }
+
+        let imported = async {
+            if let Some(done) = self.imports.find(PANCHANGA_RESULT_SOURCE, &legacy_id).await? {
+                return Ok(report.clone().skipped("Already imported", Some(done.target_id)));
+            }
+            let Some(user) = self.imports.find(USER_SOURCE, &owner).await? else {
+                return Ok(report.clone().failed(format!("Owner {} has not been imported", owner)));
+            };
+            let result = match map_result(row, user.target_id) {
+                Ok(result) => result,
+                Err(reason) => return Ok(report.clone().failed(reason)),
+            };
+            let import = self.imports.import_result(&legacy_id, &result, &checksum(&result.result)).await?;
+            Ok::<_, sqlx::Error>(report.clone().status(ImportStatus::Imported, Some(import.target_id), Some(import.checksum)))
+        };
+        imported.await.unwrap_or_else(|e| report.failed(format!("Database error: {}", e)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn user_row() -> LegacyUserRow {
+        serde_json::from_value(json!({
+            "id": 42,
+            "email": " ada@example.com ",
+            "name": "Ada",
+            "birth_date": "1990-01-15",
+            "birth_time": "14:30",
+            "latitude": 12.9716,
+            "longitude": 77.5946,
+            "timezone": "Asia/Kolkata",
+            "location_name": "Bengaluru",
+            "created_at": "2024-03-01T10:00:00Z"
+        }))
+        .unwrap()
+    }
+
+    fn result_row() -> LegacyResultRow {
+        serde_json::from_value(json!({
+            "id": "c-7",
+            "user_id": 42,
+            "request": { "date": "2024-03-01", "latitude": 12.9716, "longitude": 77.5946, "timezone": "Asia/Kolkata", "precision": "High" },
+            "result": {
+                "date": "2024-03-01", "tithi": 20.4, "nakshatra": 14.1, "yoga": 3.0, "karana": 40.8, "vara": 5.0,
+                "solar_longitude": 340.9, "lunar_longitude": 185.3, "precision": 2, "backend": "native",
+                "calculation_time": "2024-03-01T06:00:00Z"
+            },
+            "created_at": "2024-03-01T06:00:01Z"
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_map_user() {
+        let user = map_user(&user_row()).unwrap();
+        assert_eq!(user.email, "ada@example.com");
+        assert_eq!(user.birth_time, NaiveTime::from_hms_opt(14, 30, 0));
+        assert_eq!(user.birth_location_name.as_deref(), Some("Bengaluru"));
+        assert_eq!(user.created_at.to_rfc3339(), "2024-03-01T10:00:00+00:00");
+
+        let wrapped = LegacyUserRow { longitude: 282.41, birth_time: None, ..user_row() };
+        let user = map_user(&wrapped).unwrap();
+        assert!((user.birth_location_lng - -77.59).abs() < 1e-9);
+        assert_eq!(user.birth_time, None);
+
+        let swapped = LegacyUserRow { latitude: -122.42, longitude: 37.77, ..user_row() };
+        assert!(map_user(&swapped).unwrap_err().contains("look swapped"));
+        let no_email = LegacyUserRow { email: "ada".to_string(), ..user_row() };
+        assert!(map_user(&no_email).is_err());
+    }
+
+    #[test]
+    fn test_legacy_checksum_is_verified() {
+        let row = result_row();
+        let stored = legacy_cache_checksum(&row.request, &row.result).unwrap();
+        assert_eq!(stored.len(), 32);
+        let verified = LegacyResultRow { checksum: Some(stored.to_uppercase()), ..result_row() };
+        assert!(map_result(&verified, Uuid::nil()).is_ok());
+
+        let mut tampered = LegacyResultRow { checksum: Some(stored), ..result_row() };
+        tampered.request.date = "2024-03-02".to_string();
+        assert!(map_result(&tampered, Uuid::nil()).unwrap_err().starts_with("Checksum mismatch"));
+    }
+
+    #[test]
+    fn test_map_result() {
+        let user_id = Uuid::new_v4();
+        let mapped = map_result(&result_row(), user_id).unwrap();
+        assert_eq!(mapped.user_id, user_id);
+
+        let result: WorkflowResult = serde_json::from_value(mapped.result.clone()).unwrap();
+        assert_eq!(result.workflow_id, LEGACY_WORKFLOW_ID);
+        let output = &result.engine_outputs["panchanga"];
+        assert_eq!(output.result, result_row().result);
+        assert_eq!(output.metadata.backend, "native");
+        assert_eq!(output.metadata.engine_version.as_deref(), Some(LEGACY_ENGINE_VERSION));
+        assert_eq!(output.metadata.timestamp.to_rfc3339(), "2024-03-01T06:00:00+00:00");
+
+        let input: EngineInput = serde_json::from_value(mapped.input).unwrap();
+        assert_eq!(input.precision, Precision::High);
+        assert_eq!(input.current_time.to_rfc3339(), "2024-03-01T00:00:00+00:00");
+        assert_eq!(input.location.unwrap().timezone.as_deref(), Some("Asia/Kolkata"));
+        assert_eq!(checksum(&mapped.result).len(), 64);
+    }
+
+    #[tokio::test]
+    async fn test_dry_run_reports_every_row() {
+        // A lazy pool is never connected in a dry run
+        let pool = PgPool::connect_lazy("postgres://localhost/unused").unwrap();
+        let importer = LegacyImporter::new(pool).dry_run();
+
+        let users = format!(
+            "{}\n\n{}\nnot json\n",
+            json!({"id": 1, "email": "a@example.com", "birth_date": "1990-01-15", "latitude": 10.0, "longitude": 20.0, "timezone": "UTC"}),
+            json!({"id": 2, "email": "b@example.com", "birth_date": "15/01/1990", "latitude": 10.0, "longitude": 20.0, "timezone": "UTC"}),
+        );
+        let reports = importer.import_users(users.as_bytes()).await;
+        let statuses: Vec<(usize, ImportStatus)> = reports.iter().map(|r| (r.line, r.status)).collect();
+        assert_eq!(statuses, [(1, ImportStatus::Valid), (3, ImportStatus::Failed), (4, ImportStatus::Failed)]);
+        assert_eq!(reports[1].legacy_id.as_deref(), Some("2"));
+        assert!(reports[2].reason.as_deref().unwrap().starts_with("Unreadable row"));
+
+        let results = format!("{}\n", json!({"id": 9, "request": {"date": "2024-03-01"}, "result": {}}));
+        let reports = importer.import_results(results.as_bytes()).await;
+        assert_eq!(reports[0].status, ImportStatus::Skipped);
+    }
+}
@@ -1,5 +1,8 @@
 pub mod db;
+pub mod legacy;
 pub mod models;
 pub mod repositories;
 
-pub use db::{create_pool, DbPool};
+pub use db::{
+    connect_options, connect_with_retry, create_pool, run_migrations, DbPool, DbPools, MIGRATOR,
+};
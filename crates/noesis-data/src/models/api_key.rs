@@ -0,0 +1,40 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::types::Json;
+use sqlx::FromRow;
+use uuid::Uuid;
+
+/// An API key as its owner sees it; the key itself is only stored hashed
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct StoredApiKey {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub name: Option<String>,
+    pub tier: String,
+    pub permissions: Json<Vec<String>>,
+    pub consciousness_level: i32,
+    pub rate_limit: i32,
+    /// Engines the key may calculate; any engine when `None`
+    pub allowed_engines: Option<Json<Vec<String>>>,
+    /// Workflows the key may execute; any workflow when `None`
+    pub allowed_workflows: Option<Json<Vec<String>>>,
+    pub created_at: DateTime<Utc>,
+    pub expires_at: Option<DateTime<Utc>>,
+    pub last_used: Option<DateTime<Utc>>,
+    pub is_active: bool,
+}
+
+/// Fields for a new API key
+#[derive(Debug, Clone)]
+pub struct NewApiKey {
+    /// SHA-256 hex digest of the key
+    pub key_hash: String,
+    pub name: Option<String>,
+    pub tier: String,
+    pub permissions: Vec<String>,
+    pub consciousness_level: i32,
+    pub rate_limit: i32,
+    pub allowed_engines: Option<Vec<String>>,
+    pub allowed_workflows: Option<Vec<String>>,
+    pub expires_at: Option<DateTime<Utc>>,
+}
@@ -0,0 +1,41 @@
+use chrono::{DateTime, NaiveDate, NaiveTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+/// A birth profile an organization manages on a client's behalf
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct Client {
+    pub id: Uuid,
+    pub organization_id: Uuid,
+    pub created_by: Option<Uuid>,
+    pub full_name: String,
+    pub birth_date: NaiveDate,
+    pub birth_time: Option<NaiveTime>,
+    pub birth_location_lat: f64,
+    pub birth_location_lng: f64,
+    pub birth_location_name: Option<String>,
+    pub timezone: String,
+    /// Client agreed to calculations being run for them
+    pub consent_calculations: bool,
+    /// Client agreed to their results being shared by link
+    pub consent_sharing: bool,
+    pub consent_updated_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// Fields for a new client profile
+#[derive(Debug, Clone)]
+pub struct NewClient {
+    pub organization_id: Uuid,
+    pub full_name: String,
+    pub birth_date: NaiveDate,
+    pub birth_time: Option<NaiveTime>,
+    pub birth_location_lat: f64,
+    pub birth_location_lng: f64,
+    pub birth_location_name: Option<String>,
+    pub timezone: String,
+    pub consent_calculations: bool,
+    pub consent_sharing: bool,
+}
@@ -0,0 +1,48 @@
+use chrono::{DateTime, NaiveDate, NaiveTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+/// `legacy_imports.source` of birth records
+pub const USER_SOURCE: &str = "user";
+
+/// `legacy_imports.source` of cached panchanga results
+pub const PANCHANGA_RESULT_SOURCE: &str = "panchanga_result";
+
+/// A legacy row and what it was imported as
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct LegacyImport {
+    pub source: String,
+    pub legacy_id: String,
+    pub target_id: Uuid,
+    /// SHA-256 of the imported data, hex encoded
+    pub checksum: String,
+    pub imported_at: DateTime<Utc>,
+}
+
+/// A legacy birth record mapped to a user and profile
+#[derive(Debug, Clone)]
+pub struct NewLegacyUser {
+    pub email: String,
+    pub full_name: String,
+    pub birth_date: NaiveDate,
+    pub birth_time: Option<NaiveTime>,
+    pub birth_location_lat: f64,
+    pub birth_location_lng: f64,
+    pub birth_location_name: Option<String>,
+    pub timezone: String,
+    /// When the legacy record was created, kept on the new account
+    pub created_at: DateTime<Utc>,
+}
+
+/// A legacy panchanga result mapped to a saved workflow result
+#[derive(Debug, Clone)]
+pub struct NewLegacyResult {
+    pub user_id: Uuid,
+    pub workflow_id: String,
+    /// Serialized WorkflowResult
+    pub result: serde_json::Value,
+    /// Serialized EngineInput rebuilt from the legacy request
+    pub input: serde_json::Value,
+    pub created_at: DateTime<Utc>,
+}
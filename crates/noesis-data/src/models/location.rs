@@ -0,0 +1,25 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+/// A named place a user reads their daily practice from
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct SavedLocation {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub name: String,
+    pub latitude: f64,
+    pub longitude: f64,
+    pub timezone: String,
+    pub created_at: DateTime<Utc>,
+}
+
+/// Fields for a new saved location
+#[derive(Debug, Clone)]
+pub struct NewSavedLocation {
+    pub name: String,
+    pub latitude: f64,
+    pub longitude: f64,
+    pub timezone: String,
+}
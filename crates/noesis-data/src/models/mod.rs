@@ -1 +1,10 @@
+pub mod api_key;
+pub mod client;
+pub mod legacy;
+pub mod location;
+pub mod notification;
+pub mod organization;
+pub mod practice;
+pub mod settings;
 pub mod user;
+pub mod workflow_result;
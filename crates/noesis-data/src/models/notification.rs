@@ -0,0 +1,51 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+/// A user's subscription to one category of calendar event
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct NotificationRule {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub category: String,
+    /// Days before the event's date the notification is sent
+    pub lead_days: i16,
+    pub channel: String,
+    pub webhook_url: Option<String>,
+    pub enabled: bool,
+    pub created_at: DateTime<Utc>,
+}
+
+/// Fields of a new notification rule
+#[derive(Debug, Clone)]
+pub struct NewNotificationRule {
+    pub category: String,
+    pub lead_days: i16,
+    pub channel: String,
+    pub webhook_url: Option<String>,
+}
+
+/// An event a rule fired for
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct Notification {
+    pub id: Uuid,
+    pub rule_id: Uuid,
+    pub user_id: Uuid,
+    pub category: String,
+    pub event_key: String,
+    pub event_date: NaiveDate,
+    pub title: String,
+    pub channel: String,
+    pub created_at: DateTime<Utc>,
+    /// Unset while a webhook has not accepted it
+    pub delivered_at: Option<DateTime<Utc>>,
+}
+
+/// What a rule fired for, before it is recorded
+#[derive(Debug, Clone)]
+pub struct NewNotification {
+    pub event_key: String,
+    pub event_date: NaiveDate,
+    pub title: String,
+}
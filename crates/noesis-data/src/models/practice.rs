@@ -0,0 +1,28 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+/// Whether a user followed a recommended practice on a day
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct PracticeLog {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub practice: String,
+    pub engine_id: Option<String>,
+    pub practice_date: NaiveDate,
+    pub followed: bool,
+    pub note: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// Fields of a practice log entry
+#[derive(Debug, Clone)]
+pub struct NewPracticeLog {
+    pub practice: String,
+    pub engine_id: Option<String>,
+    pub practice_date: NaiveDate,
+    pub followed: bool,
+    pub note: Option<String>,
+}
@@ -0,0 +1,14 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+/// Engine options a user has saved to apply to every calculation
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct UserSettings {
+    pub user_id: Uuid,
+    /// JSON object of option name to value
+    pub settings: serde_json::Value,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
@@ -17,6 +17,8 @@ pub struct User {
     pub updated_at: DateTime<Utc>,
     pub reset_token: Option<String>,
     pub reset_token_expires_at: Option<DateTime<Utc>>,
+    /// Set while the account is soft-deleted and awaiting restore or purge
+    pub deleted_at: Option<DateTime<Utc>>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
@@ -32,3 +34,11 @@ pub struct UserProfile {
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
+
+/// A profile with complete birth data, with its owner's name
+#[derive(Debug, Clone, FromRow)]
+pub struct BirthProfile {
+    #[sqlx(flatten)]
+    pub profile: UserProfile,
+    pub full_name: String,
+}
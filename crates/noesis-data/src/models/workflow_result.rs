@@ -0,0 +1,66 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+/// A workflow result saved by its owner
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct SavedWorkflowResult {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    /// Set when a practitioner calculated the result for a client
+    pub client_id: Option<Uuid>,
+    pub workflow_id: String,
+    pub result: serde_json::Value, // serialized WorkflowResult
+    pub created_at: DateTime<Utc>,
+    /// Serialized EngineInput the workflow ran on; absent on results saved
+    /// before inputs were kept
+    pub input: Option<serde_json::Value>,
+    /// Consciousness phase the workflow ran at, kept with `input`
+    pub phase: Option<i16>,
+}
+
+/// A read-only share link for a saved result
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct WorkflowResultShare {
+    pub id: Uuid,
+    pub result_id: Uuid,
+    pub expires_at: DateTime<Utc>,
+    pub created_at: DateTime<Utc>,
+}
+
+/// A note or correction attached to a saved result; the result itself is
+/// never rewritten
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct ResultAnnotation {
+    pub id: Uuid,
+    pub result_id: Uuid,
+    pub author_id: Uuid,
+    /// Engine output the annotation is about; `None` for the whole result
+    pub engine_id: Option<String>,
+    /// JSON pointer into the engine's `result`, e.g. `/authority`
+    pub path: Option<String>,
+    pub note: Option<String>,
+    /// Replaces the value at `path` when the result is fetched
+    pub value: Option<serde_json::Value>,
+    pub created_at: DateTime<Utc>,
+}
+
+/// Fields for a new annotation
+#[derive(Debug, Clone, Default)]
+pub struct NewAnnotation {
+    pub engine_id: Option<String>,
+    pub path: Option<String>,
+    pub note: Option<String>,
+    pub value: Option<serde_json::Value>,
+}
+
+/// Users whose latest Human Design chart has `value` in `dimension`
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct ChartDistributionCount {
+    /// "charts" (the total, with an empty value), "type", "authority",
+    /// "profile", "definition", "center", "channel" or "gate"
+    pub dimension: String,
+    pub value: String,
+    pub users: i64,
+}
@@ -0,0 +1,97 @@
+use sqlx::types::Json;
+use sqlx::{PgPool, Error};
+use uuid::Uuid;
+use crate::models::api_key::{NewApiKey, StoredApiKey};
+
+const COLUMNS: &str = "id, user_id, name, tier, permissions, consciousness_level, rate_limit, \
+                       allowed_engines, allowed_workflows, created_at, expires_at, last_used, is_active";
+
+/// API keys of a user. Keys are validated by `noesis_auth::AuthService`,
+/// which reads the same table; reads here always use the primary so a
+/// revoked key stops showing at once.
+pub struct ApiKeyRepository {
+    pool: PgPool,
+}
+
+impl ApiKeyRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    pub async fn create_key(&self, user_id: Uuid, key: &NewApiKey) -> Result<StoredApiKey, Error> {
+        let created = sqlx::query_as::<_, StoredApiKey>(&format!(
+            r#"
+            INSERT INTO api_keys (id, key_hash, user_id, name, tier, permissions, consciousness_level,
+                                  rate_limit, allowed_engines, allowed_workflows, expires_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11)
+            RETURNING {COLUMNS}
+            "#
+        ))
+        .bind(Uuid::new_v4())
+        .bind(&key.key_hash)
+        .bind(user_id)
+        .bind(&key.name)
+        .bind(&key.tier)
+        .bind(Json(&key.permissions))
+        .bind(key.consciousness_level)
+        .bind(key.rate_limit)
+        .bind(key.allowed_engines.as_ref().map(Json))
+        .bind(key.allowed_workflows.as_ref().map(Json))
+        .bind(key.expires_at)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(created)
+    }
+
+    /// Active keys of `user_id`, oldest first
+    pub async fn list_for_user(&self, user_id: Uuid) -> Result<Vec<StoredApiKey>, Error> {
+        let keys = sqlx::query_as::<_, StoredApiKey>(&format!(
+            "SELECT {COLUMNS} FROM api_keys WHERE user_id = $1 AND is_active = true AND deleted_at IS NULL ORDER BY created_at, id"
+        ))
+        .bind(user_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(keys)
+    }
+
+    /// Replace the scopes of an active key of `user_id`; `None` when there
+    /// is no such key
+    pub async fn update_scopes_for_user(
+        &self,
+        id: Uuid,
+        user_id: Uuid,
+        allowed_engines: Option<&[String]>,
+        allowed_workflows: Option<&[String]>,
+    ) -> Result<Option<StoredApiKey>, Error> {
+        let updated = sqlx::query_as::<_, StoredApiKey>(&format!(
+            r#"
+            UPDATE api_keys SET allowed_engines = $3, allowed_workflows = $4
+            WHERE id = $1 AND user_id = $2 AND is_active = true AND deleted_at IS NULL
+            RETURNING {COLUMNS}
+            "#
+        ))
+        .bind(id)
+        .bind(user_id)
+        .bind(allowed_engines.map(Json))
+        .bind(allowed_workflows.map(Json))
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(updated)
+    }
+
+    /// Deactivate an active key of `user_id`; false when there was none
+    pub async fn revoke_for_user(&self, id: Uuid, user_id: Uuid) -> Result<bool, Error> {
+        let result = sqlx::query(
+            "UPDATE api_keys SET is_active = false WHERE id = $1 AND user_id = $2 AND is_active = true AND deleted_at IS NULL"
+        )
+        .bind(id)
+        .bind(user_id)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+}
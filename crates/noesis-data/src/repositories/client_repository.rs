@@ -0,0 +1,131 @@
+use sqlx::{PgPool, Error};
+use uuid::Uuid;
+use chrono::Utc;
+use crate::models::client::{Client, NewClient};
+
+pub struct ClientRepository {
+    pool: PgPool,
+    /// Serves the reads that may lag behind writes; `pool` unless a replica is set
+    read_pool: PgPool,
+}
+
+impl ClientRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self {
+            read_pool: pool.clone(),
+            pool,
+        }
+    }
+
+    /// Route lag-tolerant reads to `read_pool`, typically a read replica
+    pub fn with_read_pool(mut self, read_pool: PgPool) -> Self {
+        self.read_pool = read_pool;
+        self
+    }
+
+    pub async fn create_client(&self, created_by: Uuid, client: &NewClient) -> Result<Client, Error> {
+        let now = Utc::now();
+        let created = sqlx::query_as::<_, Client>(
+            r#"
+            INSERT INTO clients (
+                id, organization_id, created_by, full_name, birth_date, birth_time,
+                birth_location_lat, birth_location_lng, birth_location_name, timezone,
+                consent_calculations, consent_sharing, consent_updated_at, created_at, updated_at
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15)
+            RETURNING *
+            "#
+        )
+        .bind(Uuid::new_v4())
+        .bind(client.organization_id)
+        .bind(created_by)
+        .bind(&client.full_name)
+        .bind(client.birth_date)
+        .bind(client.birth_time)
+        .bind(client.birth_location_lat)
+        .bind(client.birth_location_lng)
+        .bind(&client.birth_location_name)
+        .bind(&client.timezone)
+        .bind(client.consent_calculations)
+        .bind(client.consent_sharing)
+        .bind(now)
+        .bind(now)
+        .bind(now)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(created)
+    }
+
+    pub async fn get_client(&self, id: Uuid) -> Result<Option<Client>, Error> {
+        let client = sqlx::query_as::<_, Client>("SELECT * FROM clients WHERE id = $1")
+            .bind(id)
+            .fetch_optional(&self.pool)
+            .await?;
+
+        Ok(client)
+    }
+
+    /// Fetch a client, only if `user_id` is a member of its organization.
+    /// Reads the primary, since consent checks depend on it.
+    pub async fn get_client_for_member(&self, id: Uuid, user_id: Uuid) -> Result<Option<Client>, Error> {
+        let client = sqlx::query_as::<_, Client>(
+            r#"
+            SELECT c.* FROM clients c
+            JOIN organization_members m ON m.organization_id = c.organization_id
+            WHERE c.id = $1 AND m.user_id = $2
+            "#
+        )
+        .bind(id)
+        .bind(user_id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(client)
+    }
+
+    /// Clients of every organization `user_id` belongs to, by name, from `read_pool`
+    pub async fn list_clients_for_member(&self, user_id: Uuid) -> Result<Vec<Client>, Error> {
+        let clients = sqlx::query_as::<_, Client>(
+            r#"
+            SELECT c.* FROM clients c
+            JOIN organization_members m ON m.organization_id = c.organization_id
+            WHERE m.user_id = $1
+            ORDER BY c.full_name, c.created_at
+            "#
+        )
+        .bind(user_id)
+        .fetch_all(&self.read_pool)
+        .await?;
+
+        Ok(clients)
+    }
+
+    /// Change consent flags; `None` leaves a flag as it is
+    pub async fn update_consent(
+        &self,
+        id: Uuid,
+        calculations: Option<bool>,
+        sharing: Option<bool>,
+    ) -> Result<Client, Error> {
+        let client = sqlx::query_as::<_, Client>(
+            r#"
+            UPDATE clients SET
+                consent_calculations = COALESCE($2, consent_calculations),
+                consent_sharing = COALESCE($3, consent_sharing),
+                consent_updated_at = $4,
+                updated_at = $4
+            WHERE id = $1
+            RETURNING *
+            "#
+        )
+        .bind(id)
+        .bind(calculations)
+        .bind(sharing)
+        .bind(Utc::now())
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(client)
+    }
+}
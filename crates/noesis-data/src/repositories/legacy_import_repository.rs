@@ -0,0 +1,142 @@
+use sqlx::{PgPool, Error};
+use uuid::Uuid;
+use chrono::Utc;
+use crate::models::legacy::{LegacyImport, NewLegacyResult, NewLegacyUser, PANCHANGA_RESULT_SOURCE, USER_SOURCE};
+
+/// Password hash of imported accounts. It is not a valid PHC string, so no
+/// password matches it until the user sets one through a password reset.
+pub const LEGACY_PASSWORD_HASH: &str = "!legacy-import";
+
+pub struct LegacyImportRepository {
+    pool: PgPool,
+}
+
+impl LegacyImportRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    /// What the legacy row `legacy_id` of `source` was imported as, if it was
+    pub async fn find(&self, source: &str, legacy_id: &str) -> Result<Option<LegacyImport>, Error> {
+        sqlx::query_as::<_, LegacyImport>(
+            "SELECT * FROM legacy_imports WHERE source = $1 AND legacy_id = $2"
+        )
+        .bind(source)
+        .bind(legacy_id)
+        .fetch_optional(&self.pool)
+        .await
+    }
+
+    /// Create the account and profile of a legacy birth record, recording
+    /// the import in the same transaction
+    pub async fn import_user(
+        &self,
+        legacy_id: &str,
+        user: &NewLegacyUser,
+        checksum: &str,
+    ) -> Result<LegacyImport, Error> {
+        let mut tx = self.pool.begin().await?;
+        let user_id = Uuid::new_v4();
+        sqlx::query(
+            r#"
+            INSERT INTO users (id, email, password_hash, full_name, tier, consciousness_level, created_at, updated_at)
+            VALUES ($1, $2, $3, $4, 'Free', 0, $5, $6)
+            "#
+        )
+        .bind(user_id)
+        .bind(&user.email)
+        .bind(LEGACY_PASSWORD_HASH)
+        .bind(&user.full_name)
+        .bind(user.created_at)
+        .bind(Utc::now())
+        .execute(&mut *tx)
+        .await?;
+
+        sqlx::query(
+            r#"
+            INSERT INTO user_profiles (
+                user_id, birth_date, birth_time, birth_location_lat, birth_location_lng,
+                birth_location_name, timezone, preferences, created_at, updated_at
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7, '{}'::jsonb, $8, $9)
+            "#
+        )
+        .bind(user_id)
+        .bind(user.birth_date)
+        .bind(user.birth_time)
+        .bind(user.birth_location_lat)
+        .bind(user.birth_location_lng)
+        .bind(&user.birth_location_name)
+        .bind(&user.timezone)
+        .bind(user.created_at)
+        .bind(Utc::now())
+        .execute(&mut *tx)
+        .await?;
+
+        let import = Self::record(&mut tx, USER_SOURCE, legacy_id, user_id, checksum).await?;
+        tx.commit().await?;
+        Ok(import)
+    }
+
+    /// Record that a legacy birth record belongs to an existing account,
+    /// leaving the account as it is
+    pub async fn link_user(&self, legacy_id: &str, user_id: Uuid, checksum: &str) -> Result<LegacyImport, Error> {
+        let mut tx = self.pool.begin().await?;
+        let import = Self::record(&mut tx, USER_SOURCE, legacy_id, user_id, checksum).await?;
+        tx.commit().await?;
+        Ok(import)
+    }
+
+    /// Save a legacy result to its owner's history, recording the import in
+    /// the same transaction. No phase is stored, so it cannot be replayed.
+    pub async fn import_result(
+        &self,
+        legacy_id: &str,
+        result: &NewLegacyResult,
+        checksum: &str,
+    ) -> Result<LegacyImport, Error> {
+        let mut tx = self.pool.begin().await?;
+        let result_id = Uuid::new_v4();
+        sqlx::query(
+            r#"
+            INSERT INTO workflow_results (id, user_id, workflow_id, result, input, created_at)
+            VALUES ($1, $2, $3, $4, $5, $6)
+            "#
+        )
+        .bind(result_id)
+        .bind(result.user_id)
+        .bind(&result.workflow_id)
+        .bind(&result.result)
+        .bind(&result.input)
+        .bind(result.created_at)
+        .execute(&mut *tx)
+        .await?;
+
+        let import = Self::record(&mut tx, PANCHANGA_RESULT_SOURCE, legacy_id, result_id, checksum).await?;
+        tx.commit().await?;
+        Ok(import)
+    }
+
+    async fn record(
+        tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+        source: &str,
+        legacy_id: &str,
+        target_id: Uuid,
+        checksum: &str,
+    ) -> Result<LegacyImport, Error> {
+        sqlx::query_as::<_, LegacyImport>(
+            r#"
+            INSERT INTO legacy_imports (source, legacy_id, target_id, checksum, imported_at)
+            VALUES ($1, $2, $3, $4, $5)
+            RETURNING *
+            "#
+        )
+        .bind(source)
+        .bind(legacy_id)
+        .bind(target_id)
+        .bind(checksum)
+        .bind(Utc::now())
+        .fetch_one(&mut **tx)
+        .await
+    }
+}
@@ -0,0 +1,84 @@
+use sqlx::{PgPool, Error};
+use uuid::Uuid;
+use chrono::Utc;
+use crate::models::location::{NewSavedLocation, SavedLocation};
+
+pub struct SavedLocationRepository {
+    pool: PgPool,
+    /// Serves the reads that may lag behind writes; `pool` unless a replica is set
+    read_pool: PgPool,
+}
+
+impl SavedLocationRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self {
+            read_pool: pool.clone(),
+            pool,
+        }
+    }
+
+    /// Route lag-tolerant reads to `read_pool`, typically a read replica
+    pub fn with_read_pool(mut self, read_pool: PgPool) -> Self {
+        self.read_pool = read_pool;
+        self
+    }
+
+    pub async fn create_location(&self, user_id: Uuid, location: &NewSavedLocation) -> Result<SavedLocation, Error> {
+        let created = sqlx::query_as::<_, SavedLocation>(
+            r#"
+            INSERT INTO saved_locations (id, user_id, name, latitude, longitude, timezone, created_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7)
+            RETURNING *
+            "#
+        )
+        .bind(Uuid::new_v4())
+        .bind(user_id)
+        .bind(&location.name)
+        .bind(location.latitude)
+        .bind(location.longitude)
+        .bind(&location.timezone)
+        .bind(Utc::now())
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(created)
+    }
+
+    /// Locations saved by `user_id`, oldest first
+    pub async fn list_for_user(&self, user_id: Uuid) -> Result<Vec<SavedLocation>, Error> {
+        let locations = sqlx::query_as::<_, SavedLocation>(
+            "SELECT * FROM saved_locations WHERE user_id = $1 AND deleted_at IS NULL ORDER BY created_at, id"
+        )
+        .bind(user_id)
+        .fetch_all(&self.read_pool)
+        .await?;
+
+        Ok(locations)
+    }
+
+    /// Fetch a saved location, only if it belongs to `user_id`
+    pub async fn get_for_user(&self, id: Uuid, user_id: Uuid) -> Result<Option<SavedLocation>, Error> {
+        let location = sqlx::query_as::<_, SavedLocation>(
+            "SELECT * FROM saved_locations WHERE id = $1 AND user_id = $2 AND deleted_at IS NULL"
+        )
+        .bind(id)
+        .bind(user_id)
+        .fetch_optional(&self.read_pool)
+        .await?;
+
+        Ok(location)
+    }
+
+    /// Delete a saved location of `user_id`; false when there was none
+    pub async fn delete_for_user(&self, id: Uuid, user_id: Uuid) -> Result<bool, Error> {
+        let result = sqlx::query(
+            "DELETE FROM saved_locations WHERE id = $1 AND user_id = $2 AND deleted_at IS NULL"
+        )
+        .bind(id)
+        .bind(user_id)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+}
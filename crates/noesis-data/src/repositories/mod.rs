@@ -1 +1,11 @@
+pub mod api_key_repository;
+pub mod client_repository;
+pub mod legacy_import_repository;
+pub mod location_repository;
+pub mod notification_repository;
+pub mod organization_repository;
+pub mod practice_repository;
+pub mod retention_repository;
+pub mod settings_repository;
 pub mod user_repository;
+pub mod workflow_result_repository;
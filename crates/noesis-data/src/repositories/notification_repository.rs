@@ -0,0 +1,152 @@
+use sqlx::{PgPool, Error};
+use uuid::Uuid;
+use chrono::{NaiveDate, Utc};
+use crate::models::notification::{NewNotification, NewNotificationRule, Notification, NotificationRule};
+
+pub struct NotificationRepository {
+    pool: PgPool,
+    /// Serves the reads that may lag behind writes; `pool` unless a replica is set
+    read_pool: PgPool,
+}
+
+impl NotificationRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self {
+            read_pool: pool.clone(),
+            pool,
+        }
+    }
+
+    /// Route lag-tolerant reads to `read_pool`, typically a read replica
+    pub fn with_read_pool(mut self, read_pool: PgPool) -> Self {
+        self.read_pool = read_pool;
+        self
+    }
+
+    pub async fn create_rule(&self, user_id: Uuid, rule: &NewNotificationRule) -> Result<NotificationRule, Error> {
+        let created = sqlx::query_as::<_, NotificationRule>(
+            r#"
+            INSERT INTO notification_rules (id, user_id, category, lead_days, channel, webhook_url, enabled, created_at)
+            VALUES ($1, $2, $3, $4, $5, $6, TRUE, $7)
+            RETURNING *
+            "#
+        )
+        .bind(Uuid::new_v4())
+        .bind(user_id)
+        .bind(&rule.category)
+        .bind(rule.lead_days)
+        .bind(&rule.channel)
+        .bind(&rule.webhook_url)
+        .bind(Utc::now())
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(created)
+    }
+
+    /// Rules of `user_id`, oldest first
+    pub async fn list_rules_for_user(&self, user_id: Uuid) -> Result<Vec<NotificationRule>, Error> {
+        let rules = sqlx::query_as::<_, NotificationRule>(
+            "SELECT * FROM notification_rules WHERE user_id = $1 ORDER BY created_at, id"
+        )
+        .bind(user_id)
+        .fetch_all(&self.read_pool)
+        .await?;
+
+        Ok(rules)
+    }
+
+    /// Delete a rule of `user_id` with the notifications it sent; false
+    /// when there was none
+    pub async fn delete_rule_for_user(&self, id: Uuid, user_id: Uuid) -> Result<bool, Error> {
+        let result = sqlx::query("DELETE FROM notification_rules WHERE id = $1 AND user_id = $2")
+            .bind(id)
+            .bind(user_id)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// Enabled rules of active accounts, grouped by user
+    pub async fn list_active_rules(&self) -> Result<Vec<NotificationRule>, Error> {
+        sqlx::query_as::<_, NotificationRule>(
+            r#"
+            SELECT r.* FROM notification_rules r
+            JOIN users u ON u.id = r.user_id
+            WHERE r.enabled AND u.deleted_at IS NULL
+            ORDER BY r.user_id, r.created_at
+            "#
+        )
+        .fetch_all(&self.pool)
+        .await
+    }
+
+    /// Record that `rule` fired for an event, stamped delivered when
+    /// `delivered`. `None` when the rule already fired for it.
+    pub async fn record(
+        &self,
+        rule: &NotificationRule,
+        notification: &NewNotification,
+        delivered: bool,
+    ) -> Result<Option<Notification>, Error> {
+        let now = Utc::now();
+        sqlx::query_as::<_, Notification>(
+            r#"
+            INSERT INTO notifications (id, rule_id, user_id, category, event_key, event_date, title, channel, created_at, delivered_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
+            ON CONFLICT (rule_id, event_key) DO NOTHING
+            RETURNING *
+            "#
+        )
+        .bind(Uuid::new_v4())
+        .bind(rule.id)
+        .bind(rule.user_id)
+        .bind(&rule.category)
+        .bind(&notification.event_key)
+        .bind(notification.event_date)
+        .bind(&notification.title)
+        .bind(&rule.channel)
+        .bind(now)
+        .bind(delivered.then_some(now))
+        .fetch_optional(&self.pool)
+        .await
+    }
+
+    pub async fn mark_delivered(&self, id: Uuid) -> Result<(), Error> {
+        sqlx::query("UPDATE notifications SET delivered_at = $2 WHERE id = $1")
+            .bind(id)
+            .bind(Utc::now())
+            .execute(&self.pool)
+            .await?;
+        Ok(())
+    }
+
+    /// Notifications not yet delivered whose event is on or after `since`
+    pub async fn list_undelivered(&self, since: NaiveDate) -> Result<Vec<Notification>, Error> {
+        sqlx::query_as::<_, Notification>(
+            "SELECT * FROM notifications WHERE delivered_at IS NULL AND event_date >= $1 ORDER BY created_at"
+        )
+        .bind(since)
+        .fetch_all(&self.pool)
+        .await
+    }
+
+    /// In-app notifications of `user_id`, newest first
+    pub async fn list_in_app(&self, user_id: Uuid, limit: i64) -> Result<Vec<Notification>, Error> {
+        let notifications = sqlx::query_as::<_, Notification>(
+            r#"
+            SELECT * FROM notifications
+            WHERE user_id = $1 AND channel = 'in_app'
+            ORDER BY created_at DESC, id
+            LIMIT $2
+            "#
+        )
+        .bind(user_id)
+        .bind(limit)
+        .fetch_all(&self.read_pool)
+        .await?;
+
+        Ok(notifications)
+    }
+}
@@ -0,0 +1,94 @@
+use sqlx::{PgPool, Error};
+use uuid::Uuid;
+use chrono::Utc;
+use crate::models::organization::{Organization, OrganizationMember};
+
+pub struct OrganizationRepository {
+    pool: PgPool,
+}
+
+impl OrganizationRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    /// Create an organization with `owner_id` as its first member
+    pub async fn create_organization(&self, name: &str, owner_id: Uuid) -> Result<Organization, Error> {
+        let mut tx = self.pool.begin().await?;
+
+        let organization = sqlx::query_as::<_, Organization>(
+            r#"
+            INSERT INTO organizations (id, name, owner_id, created_at)
+            VALUES ($1, $2, $3, $4)
+            RETURNING *
+            "#
+        )
+        .bind(Uuid::new_v4())
+        .bind(name)
+        .bind(owner_id)
+        .bind(Utc::now())
+        .fetch_one(&mut *tx)
+        .await?;
+
+        sqlx::query(
+            "INSERT INTO organization_members (organization_id, user_id, role, created_at) VALUES ($1, $2, 'owner', $3)"
+        )
+        .bind(organization.id)
+        .bind(owner_id)
+        .bind(Utc::now())
+        .execute(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+        Ok(organization)
+    }
+
+    /// Add a member, or change the role of an existing one
+    pub async fn upsert_member(
+        &self,
+        organization_id: Uuid,
+        user_id: Uuid,
+        role: &str,
+    ) -> Result<OrganizationMember, Error> {
+        let member = sqlx::query_as::<_, OrganizationMember>(
+            r#"
+            INSERT INTO organization_members (organization_id, user_id, role, created_at)
+            VALUES ($1, $2, $3, $4)
+            ON CONFLICT (organization_id, user_id) DO UPDATE SET role = EXCLUDED.role
+            RETURNING *
+            "#
+        )
+        .bind(organization_id)
+        .bind(user_id)
+        .bind(role)
+        .bind(Utc::now())
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(member)
+    }
+
+    pub async fn get_member_role(&self, organization_id: Uuid, user_id: Uuid) -> Result<Option<String>, Error> {
+        let role = sqlx::query_scalar::<_, String>(
+            "SELECT role FROM organization_members WHERE organization_id = $1 AND user_id = $2"
+        )
+        .bind(organization_id)
+        .bind(user_id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(role)
+    }
+
+    /// Roles the user holds across all organizations
+    pub async fn get_roles_for_user(&self, user_id: Uuid) -> Result<Vec<String>, Error> {
+        let roles = sqlx::query_scalar::<_, String>(
+            "SELECT DISTINCT role FROM organization_members WHERE user_id = $1"
+        )
+        .bind(user_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(roles)
+    }
+}
@@ -0,0 +1,104 @@
+use sqlx::{PgPool, Error};
+use uuid::Uuid;
+use chrono::{NaiveDate, Utc};
+use crate::models::practice::{NewPracticeLog, PracticeLog};
+
+pub struct PracticeRepository {
+    pool: PgPool,
+    /// Serves the reads that may lag behind writes; `pool` unless a replica is set
+    read_pool: PgPool,
+}
+
+impl PracticeRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self {
+            read_pool: pool.clone(),
+            pool,
+        }
+    }
+
+    /// Route lag-tolerant reads to `read_pool`, typically a read replica
+    pub fn with_read_pool(mut self, read_pool: PgPool) -> Self {
+        self.read_pool = read_pool;
+        self
+    }
+
+    /// Record a practice for a day, replacing any entry for the same practice and day
+    pub async fn log_practice(&self, user_id: Uuid, log: &NewPracticeLog) -> Result<PracticeLog, Error> {
+        let now = Utc::now();
+        let logged = sqlx::query_as::<_, PracticeLog>(
+            r#"
+            INSERT INTO practice_logs (id, user_id, practice, engine_id, practice_date, followed, note, created_at, updated_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $8)
+            ON CONFLICT (user_id, practice, practice_date) DO UPDATE SET
+                engine_id = EXCLUDED.engine_id,
+                followed = EXCLUDED.followed,
+                note = EXCLUDED.note,
+                updated_at = EXCLUDED.updated_at
+            RETURNING *
+            "#
+        )
+        .bind(Uuid::new_v4())
+        .bind(user_id)
+        .bind(&log.practice)
+        .bind(&log.engine_id)
+        .bind(log.practice_date)
+        .bind(log.followed)
+        .bind(&log.note)
+        .bind(now)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(logged)
+    }
+
+    /// The entry for a practice on a day, if any
+    pub async fn get_for_date(&self, user_id: Uuid, practice: &str, date: NaiveDate) -> Result<Option<PracticeLog>, Error> {
+        let log = sqlx::query_as::<_, PracticeLog>(
+            "SELECT * FROM practice_logs WHERE user_id = $1 AND practice = $2 AND practice_date = $3 AND deleted_at IS NULL"
+        )
+        .bind(user_id)
+        .bind(practice)
+        .bind(date)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(log)
+    }
+
+    /// Entries of `user_id` from `from` to `to` inclusive, newest day first
+    pub async fn list_for_user(&self, user_id: Uuid, from: NaiveDate, to: NaiveDate) -> Result<Vec<PracticeLog>, Error> {
+        let logs = sqlx::query_as::<_, PracticeLog>(
+            r#"
+            SELECT * FROM practice_logs
+            WHERE user_id = $1 AND practice_date BETWEEN $2 AND $3 AND deleted_at IS NULL
+            ORDER BY practice_date DESC, practice
+            "#
+        )
+        .bind(user_id)
+        .bind(from)
+        .bind(to)
+        .fetch_all(&self.read_pool)
+        .await?;
+
+        Ok(logs)
+    }
+
+    /// Days up to `until` on which `user_id` followed `practice`, newest first
+    pub async fn followed_dates(&self, user_id: Uuid, practice: &str, until: NaiveDate) -> Result<Vec<NaiveDate>, Error> {
+        let dates = sqlx::query_scalar::<_, NaiveDate>(
+            r#"
+            SELECT practice_date FROM practice_logs
+            WHERE user_id = $1 AND practice = $2 AND practice_date <= $3 AND followed AND deleted_at IS NULL
+            ORDER BY practice_date DESC
+            "#
+        )
+        .bind(user_id)
+        .bind(practice)
+        .bind(until)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(dates)
+    }
+}
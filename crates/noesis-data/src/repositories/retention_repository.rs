@@ -0,0 +1,126 @@
+use std::collections::BTreeMap;
+use std::fmt;
+use std::str::FromStr;
+
+use sqlx::{PgPool, Error};
+use chrono::{DateTime, Utc};
+
+/// Kinds of stored data with their own retention period
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum DataClass {
+    /// Saved workflow results (`workflow_results`), by creation
+    CalculationHistory,
+    /// Practice log entries and their notes (`practice_logs`), by last update
+    Journal,
+    /// Per-request usage records (`usage_logs`), by creation
+    AuditLog,
+    /// Client profiles practitioners keep for people without an account
+    /// (`clients`), by last update; their saved results go with them
+    GuestData,
+}
+
+impl DataClass {
+    pub const ALL: [DataClass; 4] = [
+        DataClass::CalculationHistory,
+        DataClass::Journal,
+        DataClass::AuditLog,
+        DataClass::GuestData,
+    ];
+
+    /// Name used in configuration, logs and metric labels
+    pub fn as_str(self) -> &'static str {
+        match self {
+            DataClass::CalculationHistory => "calculation_history",
+            DataClass::Journal => "journal",
+            DataClass::AuditLog => "audit_log",
+            DataClass::GuestData => "guest_data",
+        }
+    }
+
+    /// Table and the timestamp column its age is measured from
+    fn table(self) -> (&'static str, &'static str) {
+        match self {
+            DataClass::CalculationHistory => ("workflow_results", "created_at"),
+            DataClass::Journal => ("practice_logs", "updated_at"),
+            DataClass::AuditLog => ("usage_logs", "created_at"),
+            DataClass::GuestData => ("clients", "updated_at"),
+        }
+    }
+}
+
+impl fmt::Display for DataClass {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for DataClass {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        DataClass::ALL
+            .into_iter()
+            .find(|class| class.as_str() == s)
+            .ok_or_else(|| {
+                format!(
+                    "unknown data class '{}', expected one of calculation_history, journal, audit_log, guest_data",
+                    s
+                )
+            })
+    }
+}
+
+/// How long each data class is kept. Classes without a TTL are kept
+/// indefinitely; nothing is purged by default.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RetentionPolicies {
+    /// Days a row is kept, by data class
+    pub ttl_days: BTreeMap<DataClass, u32>,
+    /// Count what would be purged without deleting it
+    pub dry_run: bool,
+}
+
+impl RetentionPolicies {
+    /// Oldest timestamp `class` keeps at `now`, None when it is kept indefinitely
+    pub fn cutoff(&self, class: DataClass, now: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        self.ttl_days
+            .get(&class)
+            .map(|days| now - chrono::Duration::days(i64::from(*days)))
+    }
+}
+
+/// Deletes rows past their retention period
+pub struct RetentionRepository {
+    pool: PgPool,
+}
+
+impl RetentionRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    /// Rows of `class` older than `cutoff`
+    pub async fn count_expired(&self, class: DataClass, cutoff: DateTime<Utc>) -> Result<u64, Error> {
+        let (table, column) = class.table();
+        let count: i64 = sqlx::query_scalar(&format!("SELECT COUNT(*) FROM {} WHERE {} < $1", table, column))
+            .bind(cutoff)
+            .fetch_one(&self.pool)
+            .await?;
+
+        Ok(count as u64)
+    }
+
+    /// Delete rows of `class` older than `cutoff`, soft-deleted or not.
+    ///
+    /// Returns the number of rows deleted; rows removed through `ON DELETE
+    /// CASCADE`, such as a guest's saved results, are not counted.
+    pub async fn purge_expired(&self, class: DataClass, cutoff: DateTime<Utc>) -> Result<u64, Error> {
+        let (table, column) = class.table();
+        let result = sqlx::query(&format!("DELETE FROM {} WHERE {} < $1", table, column))
+            .bind(cutoff)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(result.rows_affected())
+    }
+}
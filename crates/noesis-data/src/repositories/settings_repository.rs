@@ -0,0 +1,59 @@
+use sqlx::{PgPool, Error};
+use uuid::Uuid;
+use chrono::Utc;
+use crate::models::settings::UserSettings;
+
+pub struct SettingsRepository {
+    pool: PgPool,
+    /// Serves the reads that may lag behind writes; `pool` unless a replica is set
+    read_pool: PgPool,
+}
+
+impl SettingsRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self {
+            read_pool: pool.clone(),
+            pool,
+        }
+    }
+
+    /// Route lag-tolerant reads to `read_pool`, typically a read replica
+    pub fn with_read_pool(mut self, read_pool: PgPool) -> Self {
+        self.read_pool = read_pool;
+        self
+    }
+
+    /// The user's saved settings, if they have any
+    pub async fn get(&self, user_id: Uuid) -> Result<Option<UserSettings>, Error> {
+        let settings = sqlx::query_as::<_, UserSettings>(
+            "SELECT * FROM user_settings WHERE user_id = $1 AND deleted_at IS NULL"
+        )
+        .bind(user_id)
+        .fetch_optional(&self.read_pool)
+        .await?;
+
+        Ok(settings)
+    }
+
+    /// Replace the user's settings
+    pub async fn upsert(&self, user_id: Uuid, settings: &serde_json::Value) -> Result<UserSettings, Error> {
+        let now = Utc::now();
+        let saved = sqlx::query_as::<_, UserSettings>(
+            r#"
+            INSERT INTO user_settings (user_id, settings, created_at, updated_at)
+            VALUES ($1, $2, $3, $3)
+            ON CONFLICT (user_id) DO UPDATE SET
+                settings = EXCLUDED.settings,
+                updated_at = EXCLUDED.updated_at
+            RETURNING *
+            "#
+        )
+        .bind(user_id)
+        .bind(settings)
+        .bind(now)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(saved)
+    }
+}
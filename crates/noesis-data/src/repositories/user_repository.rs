@@ -1,7 +1,28 @@
 use sqlx::{PgPool, Error};
 use uuid::Uuid;
-use chrono::{Utc, DateTime, NaiveDate, NaiveTime};
-use crate::models::user::{User, UserProfile};
+use chrono::{Utc, DateTime, Duration, NaiveDate, NaiveTime};
+use crate::models::user::{BirthProfile, User, UserProfile};
+
+/// Days a soft-deleted account can be restored before it is purged
+pub const RESTORE_WINDOW_DAYS: i64 = 30;
+
+/// Tables holding a user's data, soft-deleted and restored with the account
+const USER_DATA_TABLES: [&str; 7] = [
+    "user_profiles",
+    "workflow_results",
+    "progression_logs",
+    "saved_locations",
+    "practice_logs",
+    "user_settings",
+    "api_keys",
+];
+
+/// Complete birth profiles of accounts that are not soft-deleted
+const BIRTH_PROFILES_QUERY: &str = "SELECT p.*, u.full_name FROM user_profiles p \
+     JOIN users u ON u.id = p.user_id \
+     WHERE u.deleted_at IS NULL AND p.deleted_at IS NULL \
+     AND p.birth_date IS NOT NULL AND p.birth_location_lat IS NOT NULL \
+     AND p.birth_location_lng IS NOT NULL AND p.timezone IS NOT NULL";
 
 pub struct UserRepository {
     pool: PgPool,
@@ -41,7 +62,7 @@ impl UserRepository {
 
     pub async fn get_user_by_email(&self, email: &str) -> Result<Option<User>, Error> {
         let user = sqlx::query_as::<_, User>(
-            "SELECT * FROM users WHERE email = $1"
+            "SELECT * FROM users WHERE email = $1 AND deleted_at IS NULL"
         )
         .bind(email)
         .fetch_optional(&self.pool)
@@ -52,7 +73,7 @@ impl UserRepository {
 
     pub async fn get_user_by_id(&self, id: Uuid) -> Result<Option<User>, Error> {
         let user = sqlx::query_as::<_, User>(
-            "SELECT * FROM users WHERE id = $1"
+            "SELECT * FROM users WHERE id = $1 AND deleted_at IS NULL"
         )
         .bind(id)
         .fetch_optional(&self.pool)
@@ -100,13 +121,35 @@ impl UserRepository {
 
     pub async fn get_profile(&self, user_id: Uuid) -> Result<Option<UserProfile>, Error> {
          sqlx::query_as::<_, UserProfile>(
-            "SELECT * FROM user_profiles WHERE user_id = $1"
+            "SELECT * FROM user_profiles WHERE user_id = $1 AND deleted_at IS NULL"
         )
         .bind(user_id)
         .fetch_optional(&self.pool)
         .await
     }
 
+    /// Profiles of active accounts whose birth date, coordinates and
+    /// timezone are all set, in `user_id` order
+    pub async fn list_birth_profiles(&self) -> Result<Vec<BirthProfile>, Error> {
+        sqlx::query_as::<_, BirthProfile>(&format!(
+            "{} ORDER BY p.user_id",
+            BIRTH_PROFILES_QUERY
+        ))
+        .fetch_all(&self.pool)
+        .await
+    }
+
+    /// The complete birth profiles among `user_ids`, in `user_id` order
+    pub async fn get_birth_profiles(&self, user_ids: &[Uuid]) -> Result<Vec<BirthProfile>, Error> {
+        sqlx::query_as::<_, BirthProfile>(&format!(
+            "{} AND p.user_id = ANY($1) ORDER BY p.user_id",
+            BIRTH_PROFILES_QUERY
+        ))
+        .bind(user_ids)
+        .fetch_all(&self.pool)
+        .await
+    }
+
     pub async fn update_user(
         &self, 
         user_id: Uuid, 
@@ -189,7 +232,7 @@ impl UserRepository {
 
     pub async fn set_password_reset_token(&self, email: &str, token: &str, expires_at: DateTime<Utc>) -> Result<bool, Error> {
         let result = sqlx::query(
-            "UPDATE users SET reset_token = $1, reset_token_expires_at = $2 WHERE email = $3"
+            "UPDATE users SET reset_token = $1, reset_token_expires_at = $2 WHERE email = $3 AND deleted_at IS NULL"
         )
         .bind(token)
         .bind(expires_at)
@@ -203,7 +246,7 @@ impl UserRepository {
 
     pub async fn find_user_by_reset_token(&self, token: &str) -> Result<Option<User>, Error> {
         sqlx::query_as::<_, User>(
-            "SELECT * FROM users WHERE reset_token = $1 AND reset_token_expires_at > $2"
+            "SELECT * FROM users WHERE reset_token = $1 AND reset_token_expires_at > $2 AND deleted_at IS NULL"
         )
         .bind(token)
         .bind(Utc::now())
@@ -260,4 +303,107 @@ impl UserRepository {
 
         Ok(updated_user)
     }
+
+    /// A soft-deleted account that can still be restored
+    pub async fn get_restorable_user_by_email(&self, email: &str) -> Result<Option<User>, Error> {
+        sqlx::query_as::<_, User>(
+            "SELECT * FROM users WHERE email = $1 AND deleted_at > $2"
+        )
+        .bind(email)
+        .bind(Utc::now() - Duration::days(RESTORE_WINDOW_DAYS))
+        .fetch_optional(&self.pool)
+        .await
+    }
+
+    /// Soft-delete an account and its profile, saved results, progression history,
+    /// saved locations and practice log.
+    ///
+    /// Returns the deletion time, or `None` when the user does not exist or is
+    /// already deleted.
+    pub async fn soft_delete_user(&self, user_id: Uuid) -> Result<Option<DateTime<Utc>>, Error> {
+        let deleted_at = Utc::now();
+        let mut tx = self.pool.begin().await?;
+
+        let result = sqlx::query(
+            "UPDATE users SET deleted_at = $1, reset_token = NULL, reset_token_expires_at = NULL WHERE id = $2 AND deleted_at IS NULL"
+        )
+        .bind(deleted_at)
+        .bind(user_id)
+        .execute(&mut *tx)
+        .await?;
+        if result.rows_affected() == 0 {
+            return Ok(None);
+        }
+
+        for table in USER_DATA_TABLES {
+            sqlx::query(&format!(
+                "UPDATE {} SET deleted_at = $1 WHERE user_id = $2 AND deleted_at IS NULL",
+                table
+            ))
+            .bind(deleted_at)
+            .bind(user_id)
+            .execute(&mut *tx)
+            .await?;
+        }
+
+        tx.commit().await?;
+        Ok(Some(deleted_at))
+    }
+
+    /// Undo [`soft_delete_user`](Self::soft_delete_user) within the restore window.
+    ///
+    /// Only rows deleted together with the account come back. Returns the
+    /// restored user, or `None` when there is nothing restorable.
+    pub async fn restore_user(&self, user_id: Uuid) -> Result<Option<User>, Error> {
+        let mut tx = self.pool.begin().await?;
+
+        let deleted_at: Option<DateTime<Utc>> = sqlx::query_scalar(
+            "SELECT deleted_at FROM users WHERE id = $1 AND deleted_at > $2 FOR UPDATE"
+        )
+        .bind(user_id)
+        .bind(Utc::now() - Duration::days(RESTORE_WINDOW_DAYS))
+        .fetch_optional(&mut *tx)
+        .await?
+        .flatten();
+        let Some(deleted_at) = deleted_at else {
+            return Ok(None);
+        };
+
+        for table in USER_DATA_TABLES {
+            sqlx::query(&format!(
+                "UPDATE {} SET deleted_at = NULL WHERE user_id = $1 AND deleted_at = $2",
+                table
+            ))
+            .bind(user_id)
+            .bind(deleted_at)
+            .execute(&mut *tx)
+            .await?;
+        }
+
+        let user = sqlx::query_as::<_, User>(
+            "UPDATE users SET deleted_at = NULL, updated_at = $2 WHERE id = $1 RETURNING *"
+        )
+        .bind(user_id)
+        .bind(Utc::now())
+        .fetch_one(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+        Ok(Some(user))
+    }
+
+    /// Hard-delete accounts soft-deleted more than [`RESTORE_WINDOW_DAYS`] ago.
+    ///
+    /// Dependent rows are removed by their `ON DELETE CASCADE` foreign keys.
+    /// Returns the number of accounts purged.
+    pub async fn purge_deleted_users(&self) -> Result<u64, Error> {
+        let result = sqlx::query(
+            "DELETE FROM users WHERE deleted_at <= $1"
+        )
+        .bind(Utc::now() - Duration::days(RESTORE_WINDOW_DAYS))
+        .execute(&self.pool)
+        .await?;
+
+        Ok(result.rows_affected())
+    }
 }
@@ -0,0 +1,263 @@
+use sqlx::{PgPool, Error};
+use uuid::Uuid;
+use chrono::{Utc, DateTime};
+use crate::models::workflow_result::{ChartDistributionCount, NewAnnotation, ResultAnnotation, SavedWorkflowResult, WorkflowResultShare};
+
+pub struct WorkflowResultRepository {
+    pool: PgPool,
+    /// Serves the reads that may lag behind writes; `pool` unless a replica is set
+    read_pool: PgPool,
+}
+
+impl WorkflowResultRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self {
+            read_pool: pool.clone(),
+            pool,
+        }
+    }
+
+    /// Route lag-tolerant reads to `read_pool`, typically a read replica
+    pub fn with_read_pool(mut self, read_pool: PgPool) -> Self {
+        self.read_pool = read_pool;
+        self
+    }
+
+    /// Save a result with the `input` it was calculated from at `phase`,
+    /// so it can be replayed
+    pub async fn save_result(
+        &self,
+        user_id: Uuid,
+        client_id: Option<Uuid>,
+        workflow_id: &str,
+        result: &serde_json::Value,
+        input: &serde_json::Value,
+        phase: i16,
+    ) -> Result<SavedWorkflowResult, Error> {
+        let saved = sqlx::query_as::<_, SavedWorkflowResult>(
+            r#"
+            INSERT INTO workflow_results (id, user_id, client_id, workflow_id, result, input, phase, created_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+            RETURNING *
+            "#
+        )
+        .bind(Uuid::new_v4())
+        .bind(user_id)
+        .bind(client_id)
+        .bind(workflow_id)
+        .bind(result)
+        .bind(input)
+        .bind(phase)
+        .bind(Utc::now())
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(saved)
+    }
+
+    /// Fetch a saved result, only if it belongs to `user_id`
+    pub async fn get_result_for_user(
+        &self,
+        id: Uuid,
+        user_id: Uuid,
+    ) -> Result<Option<SavedWorkflowResult>, Error> {
+        let saved = sqlx::query_as::<_, SavedWorkflowResult>(
+            "SELECT * FROM workflow_results WHERE id = $1 AND user_id = $2 AND deleted_at IS NULL"
+        )
+        .bind(id)
+        .bind(user_id)
+        .fetch_optional(&self.read_pool)
+        .await?;
+
+        Ok(saved)
+    }
+
+    /// Fetch a saved result whoever it belongs to, for admin tools. Reads
+    /// the primary, so a result is found right after it was saved.
+    pub async fn get_result(&self, id: Uuid) -> Result<Option<SavedWorkflowResult>, Error> {
+        let saved = sqlx::query_as::<_, SavedWorkflowResult>(
+            "SELECT * FROM workflow_results WHERE id = $1 AND deleted_at IS NULL"
+        )
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(saved)
+    }
+
+    pub async fn create_share(
+        &self,
+        result_id: Uuid,
+        expires_at: DateTime<Utc>,
+    ) -> Result<WorkflowResultShare, Error> {
+        let share = sqlx::query_as::<_, WorkflowResultShare>(
+            r#"
+            INSERT INTO workflow_result_shares (id, result_id, expires_at, created_at)
+            VALUES ($1, $2, $3, $4)
+            RETURNING *
+            "#
+        )
+        .bind(Uuid::new_v4())
+        .bind(result_id)
+        .bind(expires_at)
+        .bind(Utc::now())
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(share)
+    }
+
+    /// Resolve an unexpired share link to its result. Reads the primary, so
+    /// a link resolves as soon as it is handed out, and stops once the
+    /// result is deleted.
+    pub async fn get_shared_result(
+        &self,
+        share_id: Uuid,
+    ) -> Result<Option<SavedWorkflowResult>, Error> {
+        let saved = sqlx::query_as::<_, SavedWorkflowResult>(
+            r#"
+            SELECT r.* FROM workflow_results r
+            JOIN workflow_result_shares s ON s.result_id = r.id
+            WHERE s.id = $1 AND s.expires_at > NOW() AND r.deleted_at IS NULL
+            "#
+        )
+        .bind(share_id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(saved)
+    }
+
+    pub async fn add_annotation(
+        &self,
+        result_id: Uuid,
+        author_id: Uuid,
+        annotation: &NewAnnotation,
+    ) -> Result<ResultAnnotation, Error> {
+        let saved = sqlx::query_as::<_, ResultAnnotation>(
+            r#"
+            INSERT INTO result_annotations (id, result_id, author_id, engine_id, path, note, value, created_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+            RETURNING *
+            "#
+        )
+        .bind(Uuid::new_v4())
+        .bind(result_id)
+        .bind(author_id)
+        .bind(&annotation.engine_id)
+        .bind(&annotation.path)
+        .bind(&annotation.note)
+        .bind(&annotation.value)
+        .bind(Utc::now())
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(saved)
+    }
+
+    /// A result's annotations, oldest first
+    pub async fn list_annotations(&self, result_id: Uuid) -> Result<Vec<ResultAnnotation>, Error> {
+        let annotations = sqlx::query_as::<_, ResultAnnotation>(
+            "SELECT * FROM result_annotations WHERE result_id = $1 ORDER BY created_at, id"
+        )
+        .bind(result_id)
+        .fetch_all(&self.read_pool)
+        .await?;
+
+        Ok(annotations)
+    }
+
+    /// Delete an annotation of `result_id`; false when there is none with `id`
+    pub async fn delete_annotation(&self, id: Uuid, result_id: Uuid) -> Result<bool, Error> {
+        let deleted = sqlx::query("DELETE FROM result_annotations WHERE id = $1 AND result_id = $2")
+            .bind(id)
+            .bind(result_id)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(deleted.rows_affected() > 0)
+    }
+
+    /// A page of saved results for a research export, oldest first,
+    /// starting after the `(created_at, id)` the last page ended on.
+    /// Results calculated for practitioners' clients are left out.
+    pub async fn list_results_for_export(
+        &self,
+        since: Option<DateTime<Utc>>,
+        after: Option<(DateTime<Utc>, Uuid)>,
+        limit: i64,
+    ) -> Result<Vec<SavedWorkflowResult>, Error> {
+        let (after_created_at, after_id) = after.unzip();
+        sqlx::query_as::<_, SavedWorkflowResult>(
+            r#"
+            SELECT * FROM workflow_results
+            WHERE deleted_at IS NULL AND client_id IS NULL
+              AND ($1::timestamptz IS NULL OR created_at >= $1)
+              AND ($2::timestamptz IS NULL OR (created_at, id) > ($2, $3::uuid))
+            ORDER BY created_at, id
+            LIMIT $4
+            "#
+        )
+        .bind(since)
+        .bind(after_created_at)
+        .bind(after_id)
+        .bind(limit)
+        .fetch_all(&self.read_pool)
+        .await
+    }
+
+    /// How many users' latest saved Human Design chart has each type,
+    /// authority, profile, definition, defined center, channel and activated
+    /// gate, plus the number of such users as the "charts" row. Results
+    /// calculated for practitioners' clients are left out.
+    pub async fn human_design_distribution(&self) -> Result<Vec<ChartDistributionCount>, Error> {
+        sqlx::query_as::<_, ChartDistributionCount>(
+            r#"
+            WITH charts AS (
+                SELECT DISTINCT ON (user_id) user_id, result->'engine_outputs'->'human-design'->'result' AS chart
+                FROM workflow_results
+                WHERE deleted_at IS NULL AND client_id IS NULL AND result->'engine_outputs' ? 'human-design'
+                ORDER BY user_id, created_at DESC
+            ),
+            gates AS (
+                SELECT user_id, activation->>'gate' AS gate
+                FROM charts, jsonb_each(
+                    CASE WHEN jsonb_typeof(chart->'personality_activations') = 'object'
+                    THEN chart->'personality_activations' ELSE '{}'::jsonb END
+                ) AS personality(planet, activation)
+                UNION
+                SELECT user_id, activation->>'gate' AS gate
+                FROM charts, jsonb_each(
+                    CASE WHEN jsonb_typeof(chart->'design_activations') = 'object'
+                    THEN chart->'design_activations' ELSE '{}'::jsonb END
+                ) AS design(planet, activation)
+            )
+            SELECT 'charts' AS dimension, '' AS value, COUNT(*) AS users FROM charts
+            UNION ALL
+            SELECT 'type', chart->>'hd_type', COUNT(*) FROM charts WHERE chart ? 'hd_type' GROUP BY 2
+            UNION ALL
+            SELECT 'authority', chart->>'authority', COUNT(*) FROM charts WHERE chart ? 'authority' GROUP BY 2
+            UNION ALL
+            SELECT 'profile', chart->>'profile', COUNT(*) FROM charts WHERE chart ? 'profile' GROUP BY 2
+            UNION ALL
+            SELECT 'definition', chart->>'definition', COUNT(*) FROM charts WHERE chart ? 'definition' GROUP BY 2
+            UNION ALL
+            SELECT 'center', center, COUNT(*)
+            FROM charts, jsonb_array_elements_text(
+                CASE WHEN jsonb_typeof(chart->'defined_centers') = 'array' THEN chart->'defined_centers' ELSE '[]'::jsonb END
+            ) AS center
+            GROUP BY 2
+            UNION ALL
+            SELECT 'channel', channel, COUNT(*)
+            FROM charts, jsonb_array_elements_text(
+                CASE WHEN jsonb_typeof(chart->'active_channels') = 'array' THEN chart->'active_channels' ELSE '[]'::jsonb END
+            ) AS channel
+            GROUP BY 2
+            UNION ALL
+            SELECT 'gate', gate, COUNT(*) FROM gates WHERE gate IS NOT NULL GROUP BY 2
+            "#
+        )
+        .fetch_all(&self.read_pool)
+        .await
+    }
+}
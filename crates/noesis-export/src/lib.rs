@@ -0,0 +1,314 @@
+//! Noesis Export — engine results as Parquet for research
+//!
+//! The research team analyzes calculation outputs in Python. This crate
+//! writes a batch of one engine's results as a Parquet file that pandas or
+//! polars load as is. All files of an engine have the same columns: the
+//! shared ones first (see [`EngineSchema::arrow_schema`]), then the engine's
+//! own from [`schema`]. The schema depends on the engine and
+//! [`SCHEMA_VERSION`] only, never on the rows in a file; a value missing from
+//! a result, or not of its column's type, is a null.
+//!
+//! Rows carry no account data. The subject is a pseudonym chosen by the
+//! caller, and the date a result was saved is kept to the day.
+
+pub mod schema;
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use arrow_array::builder::{Int64Builder, ListBuilder, StringBuilder};
+use arrow_array::types::Date32Type;
+use arrow_array::{
+    ArrayRef, BooleanArray, Date32Array, Float64Array, Int16Array, Int64Array, RecordBatch, StringArray,
+};
+use arrow_schema::{ArrowError, DataType, Field, Schema};
+use chrono::NaiveDate;
+use noesis_core::EngineOutput;
+use parquet::arrow::ArrowWriter;
+use parquet::basic::Compression;
+use parquet::errors::ParquetError;
+use parquet::file::metadata::KeyValue;
+use parquet::file::properties::WriterProperties;
+use serde_json::Value;
+
+pub use schema::{engine_schema, Column, ColumnType, EngineSchema, ENGINE_SCHEMAS};
+
+/// Version of the column layout, stored in each file's metadata as
+/// `noesis.schema_version`
+pub const SCHEMA_VERSION: u32 = 1;
+
+#[derive(Debug, thiserror::Error)]
+pub enum ExportError {
+    #[error("Arrow error: {0}")]
+    Arrow(#[from] ArrowError),
+
+    #[error("Parquet error: {0}")]
+    Parquet(#[from] ParquetError),
+}
+
+/// One engine result to export
+#[derive(Debug, Clone)]
+pub struct ExportRow {
+    /// Pseudonym of the person, the same for all their rows in one export
+    pub subject: String,
+    pub workflow_id: String,
+    pub saved_on: NaiveDate,
+    /// Consciousness phase the workflow ran at, when it was recorded
+    pub phase: Option<i16>,
+    pub engine_version: Option<String>,
+    pub consciousness_level: u8,
+    pub result: Value,
+}
+
+impl ExportRow {
+    /// A row of `output`. Results stored with camelCase keys are read with
+    /// their snake_case spelling, as the columns' pointers expect.
+    pub fn new(
+        subject: impl Into<String>,
+        workflow_id: impl Into<String>,
+        saved_on: NaiveDate,
+        phase: Option<i16>,
+        output: EngineOutput,
+    ) -> Self {
+        let mut result = output.result;
+        if noesis_core::casing::has_camel_case_keys(&result) {
+            noesis_core::casing::snake_case_keys(&mut result);
+        }
+        Self {
+            subject: subject.into(),
+            workflow_id: workflow_id.into(),
+            saved_on,
+            phase,
+            engine_version: output.metadata.engine_version,
+            consciousness_level: output.consciousness_level,
+            result,
+        }
+    }
+}
+
+impl ColumnType {
+    fn data_type(self) -> DataType {
+        let item = |data_type| DataType::List(Arc::new(Field::new("item", data_type, true)));
+        match self {
+            ColumnType::Utf8 => DataType::Utf8,
+            ColumnType::Int64 => DataType::Int64,
+            ColumnType::Float64 => DataType::Float64,
+            ColumnType::Boolean => DataType::Boolean,
+            ColumnType::Utf8List => item(DataType::Utf8),
+            ColumnType::Int64List => item(DataType::Int64),
+        }
+    }
+}
+
+impl EngineSchema {
+    /// `subject`, `workflow_id`, `saved_on`, `phase`, `engine_version` and
+    /// `consciousness_level`, then the engine's columns
+    pub fn arrow_schema(&self) -> Schema {
+        let mut fields = vec![
+            Field::new("subject", DataType::Utf8, false),
+            Field::new("workflow_id", DataType::Utf8, false),
+            Field::new("saved_on", DataType::Date32, false),
+            Field::new("phase", DataType::Int16, true),
+            Field::new("engine_version", DataType::Utf8, true),
+            Field::new("consciousness_level", DataType::Int16, false),
+        ];
+        fields.extend(
+            self.columns
+                .iter()
+                .map(|column| Field::new(column.name, column.column_type.data_type(), true)),
+        );
+        let metadata = HashMap::from([
+            ("noesis.engine_id".to_string(), self.engine_id.to_string()),
+            ("noesis.schema_version".to_string(), SCHEMA_VERSION.to_string()),
+        ]);
+        Schema::new_with_metadata(fields, metadata)
+    }
+
+    pub fn record_batch(&self, rows: &[ExportRow]) -> Result<RecordBatch, ExportError> {
+        let mut arrays: Vec<ArrayRef> = vec![
+            Arc::new(rows.iter().map(|row| Some(row.subject.as_str())).collect::<StringArray>()),
+            Arc::new(rows.iter().map(|row| Some(row.workflow_id.as_str())).collect::<StringArray>()),
+            Arc::new(
+                rows.iter()
+                    .map(|row| Some(Date32Type::from_naive_date(row.saved_on)))
+                    .collect::<Date32Array>(),
+            ),
+            Arc::new(rows.iter().map(|row| row.phase).collect::<Int16Array>()),
+            Arc::new(rows.iter().map(|row| row.engine_version.as_deref()).collect::<StringArray>()),
+            Arc::new(
+                rows.iter()
+                    .map(|row| Some(i16::from(row.consciousness_level)))
+                    .collect::<Int16Array>(),
+            ),
+        ];
+        arrays.extend(self.columns.iter().map(|column| column_array(column, rows)));
+        Ok(RecordBatch::try_new(Arc::new(self.arrow_schema()), arrays)?)
+    }
+
+    /// `rows` as a Snappy-compressed Parquet file
+    pub fn to_parquet(&self, rows: &[ExportRow]) -> Result<Vec<u8>, ExportError> {
+        let batch = self.record_batch(rows)?;
+        // Also as file metadata, for readers that don't restore the Arrow schema
+        let metadata = batch
+            .schema()
+            .metadata()
+            .iter()
+            .map(|(key, value)| KeyValue::new(key.clone(), value.clone()))
+            .collect();
+        let properties = WriterProperties::builder()
+            .set_compression(Compression::SNAPPY)
+            .set_key_value_metadata(Some(metadata))
+            .build();
+        let mut writer = ArrowWriter::try_new(Vec::new(), batch.schema(), Some(properties))?;
+        writer.write(&batch)?;
+        Ok(writer.into_inner()?)
+    }
+}
+
+fn column_array(column: &Column, rows: &[ExportRow]) -> ArrayRef {
+    let values = rows.iter().map(|row| row.result.pointer(column.pointer));
+    match column.column_type {
+        ColumnType::Utf8 => Arc::new(values.map(|v| v.and_then(Value::as_str)).collect::<StringArray>()),
+        ColumnType::Int64 => Arc::new(values.map(|v| v.and_then(Value::as_i64)).collect::<Int64Array>()),
+        ColumnType::Float64 => Arc::new(values.map(|v| v.and_then(Value::as_f64)).collect::<Float64Array>()),
+        ColumnType::Boolean => Arc::new(values.map(|v| v.and_then(Value::as_bool)).collect::<BooleanArray>()),
+        ColumnType::Utf8List => {
+            let mut builder = ListBuilder::new(StringBuilder::new());
+            for items in values.map(|v| v.and_then(Value::as_array)) {
+                if let Some(items) = items {
+                    for item in items {
+                        builder.values().append_option(item.as_str());
+                    }
+                }
+                builder.append(items.is_some());
+            }
+            Arc::new(builder.finish())
+        }
+        ColumnType::Int64List => {
+            let mut builder = ListBuilder::new(Int64Builder::new());
+            for items in values.map(|v| v.and_then(Value::as_array)) {
+                if let Some(items) = items {
+                    for item in items {
+                        builder.values().append_option(item.as_i64());
+                    }
+                }
+                builder.append(items.is_some());
+            }
+            Arc::new(builder.finish())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow_array::{Array, ListArray};
+    use parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
+    use serde_json::json;
+
+    fn row(subject: &str, result: Value) -> ExportRow {
+        ExportRow {
+            subject: subject.to_string(),
+            workflow_id: "birth-blueprint".to_string(),
+            saved_on: NaiveDate::from_ymd_opt(2026, 10, 15).unwrap(),
+            phase: Some(2),
+            engine_version: Some("1".to_string()),
+            consciousness_level: 2,
+            result,
+        }
+    }
+
+    /// The file's first batch and its key-value metadata
+    fn read(bytes: Vec<u8>) -> (RecordBatch, HashMap<String, String>) {
+        let builder = ParquetRecordBatchReaderBuilder::try_new(bytes::Bytes::from(bytes)).unwrap();
+        let metadata = builder
+            .metadata()
+            .file_metadata()
+            .key_value_metadata()
+            .into_iter()
+            .flatten()
+            .filter_map(|kv| Some((kv.key.clone(), kv.value.clone()?)))
+            .collect();
+        (builder.build().unwrap().map(Result::unwrap).next().unwrap(), metadata)
+    }
+
+    #[test]
+    fn test_parquet_round_trip_with_nulls_for_missing_values() {
+        let schema = engine_schema("human-design").unwrap();
+        let rows = vec![
+            row(
+                "a1",
+                json!({
+                    "hd_type": "Generator",
+                    "defined_centers": ["Sacral", "Root"],
+                    "personality_activations": { "sun": { "gate": 41, "line": 3 } }
+                }),
+            ),
+            // A value of the wrong type is a null, not an error
+            row("b2", json!({ "hd_type": 7 })),
+        ];
+
+        let (batch, metadata) = read(schema.to_parquet(&rows).unwrap());
+        assert_eq!(batch.num_rows(), 2);
+        assert_eq!(metadata["noesis.engine_id"], "human-design");
+        assert_eq!(metadata["noesis.schema_version"], SCHEMA_VERSION.to_string());
+
+        let hd_type = batch.column_by_name("hd_type").unwrap();
+        let hd_type = hd_type.as_any().downcast_ref::<StringArray>().unwrap();
+        assert_eq!(hd_type.value(0), "Generator");
+        assert!(hd_type.is_null(1));
+
+        let gate = batch.column_by_name("personality_sun_gate").unwrap();
+        assert_eq!(gate.as_any().downcast_ref::<Int64Array>().unwrap().value(0), 41);
+
+        let centers = batch.column_by_name("defined_centers").unwrap();
+        let centers = centers.as_any().downcast_ref::<ListArray>().unwrap();
+        assert_eq!(centers.value(0).len(), 2);
+        assert!(centers.is_null(1));
+    }
+
+    #[test]
+    fn test_schema_does_not_depend_on_the_rows() {
+        let schema = engine_schema("numerology").unwrap();
+        let empty = schema.record_batch(&[]).unwrap();
+        let full = schema
+            .record_batch(&[row("a1", json!({ "life_path": { "value": 11, "is_master": true } }))])
+            .unwrap();
+        assert_eq!(empty.schema(), full.schema());
+        assert_eq!(
+            full.schema().fields().len(),
+            6 + schema.columns.len(),
+            "shared columns, then the engine's"
+        );
+    }
+
+    #[test]
+    fn test_rows_read_camel_case_results() {
+        let output: EngineOutput = serde_json::from_value(json!({
+            "engine_id": "human-design",
+            "result": { "hdType": "Projector" },
+            "witness_prompt": "",
+            "consciousness_level": 1,
+            "metadata": {
+                "calculation_time_ms": 1.0,
+                "backend": "native",
+                "precision_achieved": "standard",
+                "cached": false,
+                "timestamp": "2026-10-15T00:00:00Z",
+                "engine_version": "4"
+            }
+        }))
+        .unwrap();
+        let row = ExportRow::new("a1", "birth-blueprint", NaiveDate::from_ymd_opt(2026, 10, 15).unwrap(), None, output);
+        assert_eq!(row.result["hd_type"], "Projector");
+        assert_eq!(row.engine_version.as_deref(), Some("4"));
+    }
+
+    #[test]
+    fn test_engine_ids_are_unique() {
+        for (i, schema) in ENGINE_SCHEMAS.iter().enumerate() {
+            assert!(ENGINE_SCHEMAS[..i].iter().all(|other| other.engine_id != schema.engine_id));
+            assert!(schema.columns.iter().all(|column| column.pointer.starts_with('/')));
+        }
+    }
+}
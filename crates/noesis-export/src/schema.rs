@@ -0,0 +1,147 @@
+//! Columns of each engine's research export
+//!
+//! A column reads one value out of the engine's `result` by JSON pointer.
+//! Changing a column's name or type breaks the notebooks reading the files,
+//! so it goes with a bump of [`SCHEMA_VERSION`](crate::SCHEMA_VERSION);
+//! appending a column does not.
+//!
+//! Values that could lead back to the person are left out even where the
+//! result has them: birth dates, biorhythm's `days_alive` (the birth date
+//! once the calculation date is known) and exact Moon longitudes.
+//! Biofield and face-reading return mock data and have no schema, nor do the
+//! bridged engines, whose results aren't typed on this side.
+
+/// Arrow type of a column; every column is nullable
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ColumnType {
+    Utf8,
+    Int64,
+    Float64,
+    Boolean,
+    /// Array of strings, e.g. Human Design's defined centers
+    Utf8List,
+    /// Array of integers, e.g. a Gene Keys sphere's key and line
+    Int64List,
+}
+
+/// One engine-specific column
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Column {
+    pub name: &'static str,
+    /// JSON pointer into the engine's `result`
+    pub pointer: &'static str,
+    pub column_type: ColumnType,
+}
+
+const fn column(name: &'static str, pointer: &'static str, column_type: ColumnType) -> Column {
+    Column {
+        name,
+        pointer,
+        column_type,
+    }
+}
+
+/// The columns an engine's results are exported with
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EngineSchema {
+    pub engine_id: &'static str,
+    pub columns: &'static [Column],
+}
+
+use ColumnType::*;
+
+const PANCHANGA: &[Column] = &[
+    column("tithi_index", "/tithi_index", Int64),
+    column("tithi_name", "/tithi_name", Utf8),
+    column("nakshatra_index", "/nakshatra_index", Int64),
+    column("nakshatra_name", "/nakshatra_name", Utf8),
+    column("yoga_index", "/yoga_index", Int64),
+    column("yoga_name", "/yoga_name", Utf8),
+    column("karana_index", "/karana_index", Int64),
+    column("karana_name", "/karana_name", Utf8),
+    column("vara_index", "/vara_index", Int64),
+    column("vara_name", "/vara_name", Utf8),
+    column("solar_longitude", "/solar_longitude", Float64),
+    column("lunar_longitude", "/lunar_longitude", Float64),
+    column("ayanamsa", "/ayanamsa", Utf8),
+];
+
+const NUMEROLOGY: &[Column] = &[
+    column("life_path", "/life_path/value", Int64),
+    column("life_path_master", "/life_path/is_master", Boolean),
+    column("expression", "/expression/value", Int64),
+    column("soul_urge", "/soul_urge/value", Int64),
+    column("personality", "/personality/value", Int64),
+    column("birthday", "/birthday/value", Int64),
+    column("chaldean_name", "/chaldean_name/value", Int64),
+];
+
+const BIORHYTHM: &[Column] = &[
+    column("physical", "/physical/value", Float64),
+    column("physical_phase", "/physical/phase", Utf8),
+    column("emotional", "/emotional/value", Float64),
+    column("emotional_phase", "/emotional/phase", Utf8),
+    column("intellectual", "/intellectual/value", Float64),
+    column("intellectual_phase", "/intellectual/phase", Utf8),
+    column("intuitive", "/intuitive/value", Float64),
+    column("intuitive_phase", "/intuitive/phase", Utf8),
+    column("mastery", "/mastery", Float64),
+    column("passion", "/passion", Float64),
+    column("wisdom", "/wisdom", Float64),
+    column("overall_energy", "/overall_energy", Float64),
+];
+
+const HUMAN_DESIGN: &[Column] = &[
+    column("hd_type", "/hd_type", Utf8),
+    column("authority", "/authority", Utf8),
+    column("profile", "/profile", Utf8),
+    column("definition", "/definition", Utf8),
+    column("defined_centers", "/defined_centers", Utf8List),
+    column("active_channels", "/active_channels", Utf8List),
+    column("personality_sun_gate", "/personality_activations/sun/gate", Int64),
+    column("personality_sun_line", "/personality_activations/sun/line", Int64),
+    column("design_sun_gate", "/design_activations/sun/gate", Int64),
+    column("design_sun_line", "/design_activations/sun/line", Int64),
+];
+
+const GENE_KEYS: &[Column] = &[
+    column("lifes_work", "/activation_sequence/lifes_work", Int64List),
+    column("evolution", "/activation_sequence/evolution", Int64List),
+    column("radiance", "/activation_sequence/radiance", Int64List),
+    column("purpose", "/activation_sequence/purpose", Int64List),
+];
+
+const VIMSHOTTARI: &[Column] = &[
+    column("birth_nakshatra", "/birth_nakshatra/name", Utf8),
+    column("birth_nakshatra_number", "/birth_nakshatra/number", Int64),
+    column("mahadasha", "/current_period/mahadasha/planet", Utf8),
+    column("antardasha", "/current_period/antardasha/planet", Utf8),
+    column("pratyantardasha", "/current_period/pratyantardasha/planet", Utf8),
+];
+
+const VEDIC_CLOCK: &[Column] = &[
+    column("organ", "/current_organ/organ", Utf8),
+    column("organ_element", "/current_organ/element", Utf8),
+    column("dosha", "/current_dosha/dosha", Utf8),
+    column("hora_ruler", "/current_hora/ruler", Utf8),
+    column("constitution_dominant", "/constitution/dominant", Utf8),
+    column("vata", "/constitution/vata", Float64),
+    column("pitta", "/constitution/pitta", Float64),
+    column("kapha", "/constitution/kapha", Float64),
+];
+
+/// Every engine with a research schema
+pub const ENGINE_SCHEMAS: &[EngineSchema] = &[
+    EngineSchema { engine_id: "panchanga", columns: PANCHANGA },
+    EngineSchema { engine_id: "numerology", columns: NUMEROLOGY },
+    EngineSchema { engine_id: "biorhythm", columns: BIORHYTHM },
+    EngineSchema { engine_id: "human-design", columns: HUMAN_DESIGN },
+    EngineSchema { engine_id: "gene-keys", columns: GENE_KEYS },
+    EngineSchema { engine_id: "vimshottari", columns: VIMSHOTTARI },
+    EngineSchema { engine_id: "vedic-clock", columns: VEDIC_CLOCK },
+];
+
+/// The research schema of `engine_id`, if it has one
+pub fn engine_schema(engine_id: &str) -> Option<&'static EngineSchema> {
+    ENGINE_SCHEMAS.iter().find(|schema| schema.engine_id == engine_id)
+}
@@ -80,11 +80,16 @@ impl BirthProfile {
     pub fn to_core_birth_data(&self) -> noesis_core::BirthData {
         noesis_core::BirthData {
             name: None,
+            display_name: None,
             date: self.date.clone(),
             time: self.time.clone(),
+            time_unknown: false,
             latitude: self.latitude,
             longitude: self.longitude,
+            altitude: None,
             timezone: self.timezone.clone(),
+            place: None,
+            utc_offset_minutes: None,
         }
     }
     
@@ -0,0 +1,338 @@
+//! Per-engine latency histograms
+//!
+//! Engine latencies differ by orders of magnitude: numerology answers in
+//! microseconds, human-design runs an ephemeris pass per activation and the
+//! bridged TS engines pay an HTTP round trip. One bucket layout resolves none
+//! of them, so each engine's series of `noesis_engine_calculation_duration_seconds`
+//! uses the buckets of its [`LatencyProfile`].
+//!
+//! Each profile also sets the engine's p99 target, which is one of its bucket
+//! bounds. Calculations slower than the target count toward
+//! `noesis_engine_latency_slo_breaches_total`, the error ratio behind the
+//! latency burn-rate alerts, and the target itself is exported as
+//! `noesis_engine_latency_slo_seconds`.
+//!
+//! Observations made inside a sampled trace keep its trace id as the exemplar
+//! of their bucket; they are exposed by the OpenMetrics encoding (see
+//! [`crate::openmetrics`]).
+
+use prometheus::core::{Collector, Desc};
+use prometheus::proto::MetricFamily;
+use prometheus::{Gauge, Histogram, HistogramOpts, IntCounter, Opts};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, RwLock};
+
+pub const ENGINE_DURATION_METRIC: &str = "noesis_engine_calculation_duration_seconds";
+const SLO_TARGET_METRIC: &str = "noesis_engine_latency_slo_seconds";
+const SLO_BREACHES_METRIC: &str = "noesis_engine_latency_slo_breaches_total";
+
+/// Bucket layout and p99 target shared by engines of similar cost
+#[derive(Debug, PartialEq)]
+pub struct LatencyProfile {
+    pub name: &'static str,
+    /// Histogram upper bounds in seconds
+    pub buckets: &'static [f64],
+    /// 99% of calculations should finish within this many seconds
+    pub slo_seconds: f64,
+}
+
+/// Pure arithmetic over the birth data
+pub const IN_MEMORY: LatencyProfile = LatencyProfile {
+    name: "in-memory",
+    buckets: &[0.000_05, 0.000_1, 0.000_25, 0.000_5, 0.001, 0.002_5, 0.005, 0.01, 0.025, 0.05],
+    slo_seconds: 0.005,
+};
+
+/// A handful of ephemeris lookups
+pub const EPHEMERIS: LatencyProfile = LatencyProfile {
+    name: "ephemeris",
+    buckets: &[0.000_5, 0.001, 0.002_5, 0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0],
+    slo_seconds: 0.1,
+};
+
+/// Full chart calculations: two ephemeris passes over every planet
+pub const CHART: LatencyProfile = LatencyProfile {
+    name: "chart",
+    buckets: &[0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0],
+    slo_seconds: 0.5,
+};
+
+/// TS engines reached over HTTP through the bridge
+pub const BRIDGE: LatencyProfile = LatencyProfile {
+    name: "bridge",
+    buckets: &[0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0],
+    slo_seconds: 1.0,
+};
+
+/// Whole workflows, recorded under `workflow:<id>`
+pub const WORKFLOW: LatencyProfile = LatencyProfile {
+    name: "workflow",
+    buckets: &[0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0, 30.0],
+    slo_seconds: 2.5,
+};
+
+/// Engines without a profile of their own
+pub const DEFAULT: LatencyProfile = LatencyProfile {
+    name: "default",
+    buckets: &[0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0],
+    slo_seconds: 1.0,
+};
+
+/// The profile an engine's latency series is built with
+pub fn latency_profile(engine_id: &str) -> &'static LatencyProfile {
+    if engine_id.starts_with("workflow:") {
+        return &WORKFLOW;
+    }
+    match engine_id {
+        "numerology" | "biorhythm" | "vedic-clock" | "face-reading" | "biofield" => &IN_MEMORY,
+        "panchanga" | "vimshottari" => &EPHEMERIS,
+        "human-design" | "gene-keys" => &CHART,
+        "tarot" | "i-ching" | "enneagram" | "sacred-geometry" | "sigil-forge" => &BRIDGE,
+        _ => &DEFAULT,
+    }
+}
+
+/// Trace id of the current span, if it belongs to a sampled trace
+pub fn current_trace_id() -> Option<String> {
+    use opentelemetry::trace::TraceContextExt;
+    use tracing_opentelemetry::OpenTelemetrySpanExt;
+
+    let context = tracing::Span::current().context();
+    let span = context.span();
+    let span_context = span.span_context();
+    (span_context.is_valid() && span_context.is_sampled()).then(|| span_context.trace_id().to_string())
+}
+
+/// The latest observation of a bucket that was made inside a trace
+#[derive(Debug, Clone, PartialEq)]
+pub struct Exemplar {
+    pub trace_id: String,
+    pub value: f64,
+    /// Unix time in seconds
+    pub timestamp: f64,
+}
+
+struct Series {
+    profile: &'static LatencyProfile,
+    histogram: Histogram,
+    target: Gauge,
+    breaches: IntCounter,
+    /// One slot per bucket, then one for `+Inf`
+    exemplars: Mutex<Vec<Option<Exemplar>>>,
+}
+
+impl Series {
+    fn new(engine_id: &str) -> Self {
+        let profile = latency_profile(engine_id);
+        let histogram = Histogram::with_opts(
+            HistogramOpts::new(ENGINE_DURATION_METRIC, "Calculation duration per engine in seconds")
+                .const_label("engine_id", engine_id)
+                .buckets(profile.buckets.to_vec()),
+        )
+        .expect("profile buckets are valid");
+        let target = Gauge::with_opts(
+            Opts::new(SLO_TARGET_METRIC, "p99 calculation latency target per engine in seconds")
+                .const_label("engine_id", engine_id),
+        )
+        .expect("valid gauge opts");
+        target.set(profile.slo_seconds);
+        let breaches = IntCounter::with_opts(
+            Opts::new(SLO_BREACHES_METRIC, "Calculations slower than the engine's latency target")
+                .const_label("engine_id", engine_id),
+        )
+        .expect("valid counter opts");
+        Self {
+            profile,
+            histogram,
+            target,
+            breaches,
+            exemplars: Mutex::new(vec![None; profile.buckets.len() + 1]),
+        }
+    }
+
+    fn observe(&self, seconds: f64, trace_id: Option<&str>) {
+        self.histogram.observe(seconds);
+        if seconds > self.profile.slo_seconds {
+            self.breaches.inc();
+        }
+        if let Some(trace_id) = trace_id {
+            let bucket = self
+                .profile
+                .buckets
+                .iter()
+                .position(|bound| seconds <= *bound)
+                .unwrap_or(self.profile.buckets.len());
+            let timestamp = chrono::Utc::now().timestamp_millis() as f64 / 1000.0;
+            let mut exemplars = self.exemplars.lock().unwrap_or_else(|e| e.into_inner());
+            exemplars[bucket] = Some(Exemplar {
+                trace_id: trace_id.to_string(),
+                value: seconds,
+                timestamp,
+            });
+        }
+    }
+}
+
+/// `noesis_engine_calculation_duration_seconds` and its SLO series, one set
+/// per engine, each with the buckets of the engine's profile
+#[derive(Clone)]
+pub struct EngineLatencyHistograms {
+    descs: Arc<Vec<Desc>>,
+    series: Arc<RwLock<HashMap<String, Series>>>,
+}
+
+impl EngineLatencyHistograms {
+    pub fn new() -> Result<Self, prometheus::Error> {
+        let labels = vec!["engine_id".to_string()];
+        let descs = vec![
+            Desc::new(
+                ENGINE_DURATION_METRIC.into(),
+                "Calculation duration per engine in seconds".into(),
+                labels.clone(),
+                HashMap::new(),
+            )?,
+            Desc::new(
+                SLO_TARGET_METRIC.into(),
+                "p99 calculation latency target per engine in seconds".into(),
+                labels.clone(),
+                HashMap::new(),
+            )?,
+            Desc::new(
+                SLO_BREACHES_METRIC.into(),
+                "Calculations slower than the engine's latency target".into(),
+                labels,
+                HashMap::new(),
+            )?,
+        ];
+        Ok(Self {
+            descs: Arc::new(descs),
+            series: Arc::new(RwLock::new(HashMap::new())),
+        })
+    }
+
+    /// Record one calculation, keeping `trace_id` as its bucket's exemplar
+    pub fn observe(&self, engine_id: &str, seconds: f64, trace_id: Option<&str>) {
+        {
+            let series = self.series.read().unwrap_or_else(|e| e.into_inner());
+            if let Some(series) = series.get(engine_id) {
+                series.observe(seconds, trace_id);
+                return;
+            }
+        }
+        let mut series = self.series.write().unwrap_or_else(|e| e.into_inner());
+        series
+            .entry(engine_id.to_string())
+            .or_insert_with(|| Series::new(engine_id))
+            .observe(seconds, trace_id);
+    }
+
+    /// Observations recorded for `engine_id`
+    pub fn sample_count(&self, engine_id: &str) -> u64 {
+        let series = self.series.read().unwrap_or_else(|e| e.into_inner());
+        series.get(engine_id).map_or(0, |s| s.histogram.get_sample_count())
+    }
+
+    /// Mean recorded calculation time of `engine_id` in seconds
+    pub fn mean_seconds(&self, engine_id: &str) -> Option<f64> {
+        let series = self.series.read().unwrap_or_else(|e| e.into_inner());
+        let histogram = &series.get(engine_id)?.histogram;
+        let count = histogram.get_sample_count();
+        (count > 0).then(|| histogram.get_sample_sum() / count as f64)
+    }
+
+    /// Calculations of `engine_id` slower than its target
+    pub fn slo_breaches(&self, engine_id: &str) -> u64 {
+        let series = self.series.read().unwrap_or_else(|e| e.into_inner());
+        series.get(engine_id).map_or(0, |s| s.breaches.get())
+    }
+
+    /// Exemplar of the bucket of `engine_id` with this upper bound
+    /// (`f64::INFINITY` for the overflow bucket)
+    pub fn exemplar(&self, engine_id: &str, upper_bound: f64) -> Option<Exemplar> {
+        let series = self.series.read().unwrap_or_else(|e| e.into_inner());
+        let series = series.get(engine_id)?;
+        let bucket = if upper_bound.is_infinite() {
+            series.profile.buckets.len()
+        } else {
+            series.profile.buckets.iter().position(|bound| *bound == upper_bound)?
+        };
+        let exemplars = series.exemplars.lock().unwrap_or_else(|e| e.into_inner());
+        exemplars[bucket].clone()
+    }
+}
+
+impl Collector for EngineLatencyHistograms {
+    fn desc(&self) -> Vec<&Desc> {
+        self.descs.iter().collect()
+    }
+
+    // The registry merges families of the same name, so each series can
+    // report its own
+    fn collect(&self) -> Vec<MetricFamily> {
+        let series = self.series.read().unwrap_or_else(|e| e.into_inner());
+        series
+            .values()
+            .flat_map(|s| {
+                let mut families = s.histogram.collect();
+                families.extend(s.target.collect());
+                families.extend(s.breaches.collect());
+                families
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_profiles_by_engine() {
+        assert_eq!(latency_profile("numerology").name, "in-memory");
+        assert_eq!(latency_profile("human-design").name, "chart");
+        assert_eq!(latency_profile("tarot").name, "bridge");
+        assert_eq!(latency_profile("workflow:birth-blueprint").name, "workflow");
+        assert_eq!(latency_profile("unknown").name, "default");
+
+        for profile in [&IN_MEMORY, &EPHEMERIS, &CHART, &BRIDGE, &WORKFLOW, &DEFAULT] {
+            assert!(profile.buckets.windows(2).all(|w| w[0] < w[1]), "{}", profile.name);
+            assert!(profile.buckets.contains(&profile.slo_seconds), "{}", profile.name);
+        }
+    }
+
+    #[test]
+    fn test_series_use_their_engine_buckets() {
+        let histograms = EngineLatencyHistograms::new().unwrap();
+        histograms.observe("numerology", 0.000_2, None);
+        histograms.observe("numerology", 0.02, Some("4bf92f3577b34da6a3ce929d0e0e4736"));
+        histograms.observe("human-design", 0.3, None);
+
+        assert_eq!(histograms.sample_count("numerology"), 2);
+        assert_eq!(histograms.slo_breaches("numerology"), 1);
+        assert_eq!(histograms.slo_breaches("human-design"), 0);
+        assert!((histograms.mean_seconds("numerology").unwrap() - 0.0101).abs() < 1e-9);
+        assert_eq!(histograms.mean_seconds("tarot"), None);
+
+        let exemplar = histograms.exemplar("numerology", 0.025).unwrap();
+        assert_eq!(exemplar.trace_id, "4bf92f3577b34da6a3ce929d0e0e4736");
+        assert_eq!(exemplar.value, 0.02);
+        assert!(histograms.exemplar("numerology", 0.000_25).is_none());
+
+        let families = histograms.collect();
+        let bounds = |engine_id: &str| -> Vec<f64> {
+            families
+                .iter()
+                .filter(|f| f.get_name() == ENGINE_DURATION_METRIC)
+                .flat_map(|f| f.get_metric())
+                .find(|m| m.get_label()[0].get_value() == engine_id)
+                .unwrap()
+                .get_histogram()
+                .get_bucket()
+                .iter()
+                .map(|b| b.get_upper_bound())
+                .collect()
+        };
+        assert_eq!(bounds("numerology"), IN_MEMORY.buckets);
+        assert_eq!(bounds("human-design"), CHART.buckets);
+    }
+}
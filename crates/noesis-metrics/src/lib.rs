@@ -2,15 +2,24 @@
 //!
 //! Migrated from the original Selemene Engine metrics system.
 //! All metric names use the `noesis_` prefix. Includes per-engine
-//! calculation counters and duration histograms keyed by `engine_id`.
+//! calculation counters and duration histograms keyed by `engine_id`, with
+//! buckets and a latency target per engine (see [`latency`]).
 
 use prometheus::{
-    Counter, Gauge, Histogram, HistogramOpts, HistogramVec, IntCounterVec, Opts, Registry,
+    Counter, Gauge, Histogram, HistogramOpts, HistogramVec, IntCounterVec, IntGaugeVec, Opts,
+    Registry,
 };
 use std::sync::Arc;
 
 use lazy_static::lazy_static;
 
+pub mod latency;
+pub mod openmetrics;
+pub mod validation;
+
+pub use latency::{EngineLatencyHistograms, Exemplar, LatencyProfile};
+pub use validation::{ValidationLog, ValidationReport, ValidationReportQuery, ValidationSample};
+
 lazy_static! {
     pub static ref REGISTRY: Registry = Registry::new();
 }
@@ -101,6 +110,8 @@ pub struct NoesisMetrics {
     // -- Accuracy metrics ----------------------------------------------------
     pub validation_differences: Histogram,
     pub precision_achieved: Histogram,
+    /// Recent samples behind `validation_differences`, with their inputs
+    pub validation_log: ValidationLog,
 
     // -- System metrics ------------------------------------------------------
     pub memory_usage_bytes: Gauge,
@@ -110,8 +121,9 @@ pub struct NoesisMetrics {
     // -- Per-engine metrics (NEW) --------------------------------------------
     /// Total calculations broken down by `engine_id` label.
     pub engine_calculations_total: IntCounterVec,
-    /// Calculation duration broken down by `engine_id` label.
-    pub engine_calculation_duration: HistogramVec,
+    /// Calculation duration broken down by `engine_id` label, bucketed per
+    /// engine, with trace exemplars and latency SLO breaches.
+    pub engine_calculation_duration: EngineLatencyHistograms,
     /// Total calculations broken down by `engine_id` and `status` labels.
     pub engine_calculation_status_total: IntCounterVec,
     /// Total calculation errors broken down by `engine_id` and `error_type` labels.
@@ -209,13 +221,7 @@ impl NoesisMetrics {
             &["engine_id"],
         )?;
 
-        let engine_calculation_duration = HistogramVec::new(
-            HistogramOpts::new(
-                "noesis_engine_calculation_duration_seconds",
-                "Calculation duration per engine in seconds",
-            ),
-            &["engine_id"],
-        )?;
+        let engine_calculation_duration = EngineLatencyHistograms::new()?;
 
         let engine_calculation_status_total = IntCounterVec::new(
             Opts::new(
@@ -267,6 +273,7 @@ impl NoesisMetrics {
             cache_misses,
             validation_differences,
             precision_achieved,
+            validation_log: ValidationLog::default(),
             memory_usage_bytes,
             cpu_usage_percent,
             uptime_seconds,
@@ -299,13 +306,14 @@ impl NoesisMetrics {
     }
 
     /// Record a per-engine calculation (counter + duration histogram).
+    ///
+    /// Inside a sampled trace, the trace id becomes the bucket's exemplar.
     pub fn record_engine_calculation(&self, engine_id: &str, duration: f64) {
         self.engine_calculations_total
             .with_label_values(&[engine_id])
             .inc();
         self.engine_calculation_duration
-            .with_label_values(&[engine_id])
-            .observe(duration);
+            .observe(engine_id, duration, latency::current_trace_id().as_deref());
     }
 
     /// Record a per-engine calculation with status.
@@ -314,8 +322,7 @@ impl NoesisMetrics {
             .with_label_values(&[engine_id])
             .inc();
         self.engine_calculation_duration
-            .with_label_values(&[engine_id])
-            .observe(duration);
+            .observe(engine_id, duration, latency::current_trace_id().as_deref());
         self.engine_calculation_status_total
             .with_label_values(&[engine_id, status])
             .inc();
@@ -348,6 +355,12 @@ impl NoesisMetrics {
         self.validation_differences.observe(difference_arcseconds);
     }
 
+    /// Record a cross-backend comparison, keeping it for the validation report.
+    pub fn record_validation_sample(&self, sample: ValidationSample) {
+        self.validation_differences.observe(sample.difference_arcseconds);
+        self.validation_log.record(sample);
+    }
+
     /// Update system-level gauges.
     pub fn update_system_metrics(
         &self,
@@ -365,6 +378,16 @@ impl NoesisMetrics {
         self.active_connections.set(count);
     }
 
+    /// Snapshot a database pool: `size` open connections, `idle` of them unused.
+    pub fn update_db_pool(&self, pool: &str, size: u32, idle: usize, max_connections: u32) {
+        let idle = idle as i64;
+        DB_POOL_CONNECTIONS.with_label_values(&[pool, "idle"]).set(idle);
+        DB_POOL_CONNECTIONS
+            .with_label_values(&[pool, "in_use"])
+            .set((size as i64 - idle).max(0));
+        DB_POOL_MAX_CONNECTIONS.with_label_values(&[pool]).set(max_connections as i64);
+    }
+
     /// Encode all registered metrics in Prometheus text exposition format.
     pub fn get_metrics_text(&self) -> Result<String, Box<dyn std::error::Error>> {
         use prometheus::Encoder;
@@ -373,6 +396,532 @@ impl NoesisMetrics {
         encoder.encode(&REGISTRY.gather(), &mut buffer)?;
         Ok(String::from_utf8(buffer)?)
     }
+
+    /// Encode all registered metrics as OpenMetrics, with trace exemplars on
+    /// the engine duration buckets.
+    pub fn get_openmetrics_text(&self) -> String {
+        openmetrics::encode(&REGISTRY.gather(), |family, labels, upper_bound| {
+            if family != latency::ENGINE_DURATION_METRIC {
+                return None;
+            }
+            let engine_id = labels.iter().find(|l| l.get_name() == "engine_id")?.get_value();
+            self.engine_calculation_duration.exemplar(engine_id, upper_bound)
+        })
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Bridge metrics
+// ---------------------------------------------------------------------------
+
+lazy_static! {
+    /// Bridged TS engine call attempts by `engine_id`, `operation` and
+    /// `outcome` (`success`, `retry` or `failure`).
+    pub static ref BRIDGE_ATTEMPTS_TOTAL: IntCounterVec = prometheus::register_int_counter_vec_with_registry!(
+        Opts::new("noesis_bridge_attempts_total", "Bridged engine call attempts by outcome"),
+        &["engine_id", "operation", "outcome"],
+        REGISTRY
+    )
+    .expect("bridge attempt counter registers once");
+
+    /// Duration of each bridged TS engine call attempt.
+    pub static ref BRIDGE_ATTEMPT_DURATION: HistogramVec = prometheus::register_histogram_vec_with_registry!(
+        HistogramOpts::new(
+            "noesis_bridge_attempt_duration_seconds",
+            "Duration of each bridged engine call attempt in seconds"
+        ),
+        &["engine_id", "operation"],
+        REGISTRY
+    )
+    .expect("bridge attempt histogram registers once");
+}
+
+/// Record one attempt of a bridged engine call.
+///
+/// Bridge engines are built without a [`NoesisMetrics`] handle, so these
+/// metrics live in statics registered with [`REGISTRY`] on first use.
+pub fn record_bridge_attempt(engine_id: &str, operation: &str, outcome: &str, duration: f64) {
+    BRIDGE_ATTEMPTS_TOTAL
+        .with_label_values(&[engine_id, operation, outcome])
+        .inc();
+    BRIDGE_ATTEMPT_DURATION
+        .with_label_values(&[engine_id, operation])
+        .observe(duration);
+}
+
+lazy_static! {
+    /// Bridge HTTP responses by `engine_id`, `operation` and `status` (the
+    /// status code, or `timeout` / `connect` / `error` when none arrived).
+    pub static ref BRIDGE_HTTP_RESPONSES_TOTAL: IntCounterVec = prometheus::register_int_counter_vec_with_registry!(
+        Opts::new("noesis_bridge_http_responses_total", "Bridge HTTP responses by status code"),
+        &["engine_id", "operation", "status"],
+        REGISTRY
+    )
+    .expect("bridge http counter registers once");
+
+    /// Time until the TS server answered, by status.
+    pub static ref BRIDGE_HTTP_DURATION: HistogramVec = prometheus::register_histogram_vec_with_registry!(
+        HistogramOpts::new(
+            "noesis_bridge_http_duration_seconds",
+            "Bridge HTTP request latency in seconds"
+        ),
+        &["engine_id", "operation", "status"],
+        REGISTRY
+    )
+    .expect("bridge http histogram registers once");
+}
+
+/// Record one HTTP exchange with the TS engine server.
+pub fn record_bridge_http_response(engine_id: &str, operation: &str, status: &str, duration: f64) {
+    BRIDGE_HTTP_RESPONSES_TOTAL
+        .with_label_values(&[engine_id, operation, status])
+        .inc();
+    BRIDGE_HTTP_DURATION
+        .with_label_values(&[engine_id, operation, status])
+        .observe(duration);
+}
+
+// ---------------------------------------------------------------------------
+// Cache metrics
+// ---------------------------------------------------------------------------
+
+lazy_static! {
+    /// Cache lookups by `layer` (`l1`, `l2`, `l3`) and `result` (`hit` or `miss`).
+    pub static ref CACHE_LAYER_LOOKUPS_TOTAL: IntCounterVec = prometheus::register_int_counter_vec_with_registry!(
+        Opts::new("noesis_cache_layer_lookups_total", "Cache lookups per layer by result"),
+        &["layer", "result"],
+        REGISTRY
+    )
+    .expect("cache lookup counter registers once");
+
+    /// Cache operation latency by `layer` and `operation` (`get` or `store`).
+    /// Buckets start at 10µs so in-memory lookups are resolved.
+    pub static ref CACHE_LAYER_DURATION: HistogramVec = prometheus::register_histogram_vec_with_registry!(
+        HistogramOpts::new(
+            "noesis_cache_layer_duration_seconds",
+            "Cache operation latency per layer in seconds"
+        )
+        .buckets(prometheus::exponential_buckets(0.000_01, 4.0, 10).expect("valid buckets")),
+        &["layer", "operation"],
+        REGISTRY
+    )
+    .expect("cache duration histogram registers once");
+
+    /// Lookups of the most requested cache keys in the last completed
+    /// hot-key window, by `rank` (1 is the hottest) and `key` (the MD5 key
+    /// hash; raw keys may contain birth data). Replaced as a whole each window.
+    pub static ref CACHE_HOT_KEY_REQUESTS: IntGaugeVec = prometheus::register_int_gauge_vec_with_registry!(
+        Opts::new("noesis_cache_hot_key_requests", "Lookups of the top cache keys in the last hot-key window"),
+        &["rank", "key"],
+        REGISTRY
+    )
+    .expect("hot key requests gauge registers once");
+
+    /// Lookups of the same keys that missed every layer; many misses on one
+    /// key mean it is being recalculated concurrently or can't stay cached.
+    pub static ref CACHE_HOT_KEY_MISSES: IntGaugeVec = prometheus::register_int_gauge_vec_with_registry!(
+        Opts::new("noesis_cache_hot_key_misses", "Missed lookups of the top cache keys in the last hot-key window"),
+        &["rank", "key"],
+        REGISTRY
+    )
+    .expect("hot key misses gauge registers once");
+}
+
+/// Record a lookup against one cache layer.
+pub fn record_cache_layer_lookup(layer: &str, hit: bool, duration: f64) {
+    let result = if hit { "hit" } else { "miss" };
+    CACHE_LAYER_LOOKUPS_TOTAL.with_label_values(&[layer, result]).inc();
+    CACHE_LAYER_DURATION.with_label_values(&[layer, "get"]).observe(duration);
+}
+
+/// Record a write to one cache layer.
+pub fn record_cache_layer_store(layer: &str, duration: f64) {
+    CACHE_LAYER_DURATION.with_label_values(&[layer, "store"]).observe(duration);
+}
+
+/// Publish a completed hot-key window: `(key hash, requests, misses)`,
+/// hottest first. Keys of earlier windows are dropped.
+pub fn record_cache_hot_keys<'a>(keys: impl IntoIterator<Item = (&'a str, u64, u64)>) {
+    CACHE_HOT_KEY_REQUESTS.reset();
+    CACHE_HOT_KEY_MISSES.reset();
+    for (rank, (key, requests, misses)) in keys.into_iter().enumerate() {
+        let rank = (rank + 1).to_string();
+        CACHE_HOT_KEY_REQUESTS.with_label_values(&[&rank, key]).set(requests as i64);
+        CACHE_HOT_KEY_MISSES.with_label_values(&[&rank, key]).set(misses as i64);
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Orchestrator metrics
+// ---------------------------------------------------------------------------
+
+lazy_static! {
+    /// Wall-clock duration of workflow executions by `workflow_id`.
+    pub static ref WORKFLOW_DURATION: HistogramVec = prometheus::register_histogram_vec_with_registry!(
+        HistogramOpts::new(
+            "noesis_workflow_duration_seconds",
+            "Workflow execution duration in seconds"
+        ),
+        &["workflow_id"],
+        REGISTRY
+    )
+    .expect("workflow duration histogram registers once");
+
+    /// Engines that succeeded in the most recent run of each workflow.
+    pub static ref WORKFLOW_ENGINES_SUCCEEDED: IntGaugeVec = prometheus::register_int_gauge_vec_with_registry!(
+        Opts::new(
+            "noesis_workflow_engines_succeeded",
+            "Engines that succeeded in the last run of a workflow"
+        ),
+        &["workflow_id"],
+        REGISTRY
+    )
+    .expect("workflow engines gauge registers once");
+
+    /// Engine executions refused by phase gating, by `engine_id`.
+    pub static ref PHASE_DENIED_TOTAL: IntCounterVec = prometheus::register_int_counter_vec_with_registry!(
+        Opts::new("noesis_phase_denied_total", "Engine executions denied by phase gating"),
+        &["engine_id"],
+        REGISTRY
+    )
+    .expect("phase denied counter registers once");
+
+    /// Intermediate results (natal charts, planetary positions) of workflow
+    /// engines, by whether another engine of the run had already computed them.
+    pub static ref WORKFLOW_ARTIFACTS_TOTAL: IntCounterVec = prometheus::register_int_counter_vec_with_registry!(
+        Opts::new(
+            "noesis_workflow_artifacts_total",
+            "Intermediate results of workflow engines, computed or reused from another engine"
+        ),
+        &["outcome"],
+        REGISTRY
+    )
+    .expect("workflow artifacts counter registers once");
+
+    /// Engine requests made through a deprecated alias, by the old id.
+    pub static ref ENGINE_ALIAS_REQUESTS_TOTAL: IntCounterVec = prometheus::register_int_counter_vec_with_registry!(
+        Opts::new("noesis_engine_alias_requests_total", "Engine requests made through a deprecated alias"),
+        &["alias"],
+        REGISTRY
+    )
+    .expect("engine alias counter registers once");
+
+    /// Request bodies whose camelCase keys were read as snake_case, to see
+    /// who still relies on the casing transition window.
+    pub static ref CAMEL_CASE_INPUTS_TOTAL: prometheus::IntCounter = prometheus::register_int_counter_with_registry!(
+        Opts::new("noesis_camel_case_inputs_total", "Request bodies whose camelCase keys were read as snake_case"),
+        REGISTRY
+    )
+    .expect("camel case input counter registers once");
+}
+
+/// Record a completed workflow execution.
+pub fn record_workflow_execution(workflow_id: &str, engines_succeeded: usize, duration: f64) {
+    WORKFLOW_DURATION.with_label_values(&[workflow_id]).observe(duration);
+    WORKFLOW_ENGINES_SUCCEEDED
+        .with_label_values(&[workflow_id])
+        .set(engines_succeeded as i64);
+}
+
+/// Record the intermediate results a workflow's engines computed and reused.
+pub fn record_workflow_artifacts(computed: u32, reused: u32) {
+    WORKFLOW_ARTIFACTS_TOTAL.with_label_values(&["computed"]).inc_by(u64::from(computed));
+    WORKFLOW_ARTIFACTS_TOTAL.with_label_values(&["reused"]).inc_by(u64::from(reused));
+}
+
+/// Record an engine execution refused by phase gating.
+pub fn record_phase_denied(engine_id: &str) {
+    PHASE_DENIED_TOTAL.with_label_values(&[engine_id]).inc();
+}
+
+/// Record an engine request made through a deprecated alias.
+pub fn record_engine_alias_request(alias: &str) {
+    ENGINE_ALIAS_REQUESTS_TOTAL.with_label_values(&[alias]).inc();
+}
+
+/// Record a request body sent with camelCase keys.
+pub fn record_camel_case_input() {
+    CAMEL_CASE_INPUTS_TOTAL.inc();
+}
+
+// ---------------------------------------------------------------------------
+// Experiment metrics
+// ---------------------------------------------------------------------------
+
+lazy_static! {
+    /// Executions that ran with an experiment variant's options.
+    pub static ref EXPERIMENT_EXPOSURES_TOTAL: IntCounterVec = prometheus::register_int_counter_vec_with_registry!(
+        Opts::new(
+            "noesis_experiment_exposures_total",
+            "Engine executions that ran with an experiment variant"
+        ),
+        &["experiment", "variant"],
+        REGISTRY
+    )
+    .expect("experiment exposures counter registers once");
+
+    /// Outcomes per variant: `success`/`error` of the exposed execution, plus
+    /// outcomes reported by clients.
+    pub static ref EXPERIMENT_OUTCOMES_TOTAL: IntCounterVec = prometheus::register_int_counter_vec_with_registry!(
+        Opts::new(
+            "noesis_experiment_outcomes_total",
+            "Outcomes recorded for experiment variants"
+        ),
+        &["experiment", "variant", "outcome"],
+        REGISTRY
+    )
+    .expect("experiment outcomes counter registers once");
+}
+
+/// Record an execution exposed to `variant` of `experiment`.
+pub fn record_experiment_exposure(experiment: &str, variant: &str) {
+    EXPERIMENT_EXPOSURES_TOTAL.with_label_values(&[experiment, variant]).inc();
+}
+
+/// Record an outcome for `variant` of `experiment`.
+pub fn record_experiment_outcome(experiment: &str, variant: &str, outcome: &str) {
+    EXPERIMENT_OUTCOMES_TOTAL
+        .with_label_values(&[experiment, variant, outcome])
+        .inc();
+}
+
+// ---------------------------------------------------------------------------
+// Database pool metrics
+// ---------------------------------------------------------------------------
+
+lazy_static! {
+    /// Open connections by `pool` (`primary` or `replica`) and `state`
+    /// (`idle` or `in_use`).
+    pub static ref DB_POOL_CONNECTIONS: IntGaugeVec = prometheus::register_int_gauge_vec_with_registry!(
+        Opts::new("noesis_db_pool_connections", "Open database connections per pool by state"),
+        &["pool", "state"],
+        REGISTRY
+    )
+    .expect("db pool connections gauge registers once");
+
+    /// Configured connection limit of each `pool`.
+    pub static ref DB_POOL_MAX_CONNECTIONS: IntGaugeVec = prometheus::register_int_gauge_vec_with_registry!(
+        Opts::new("noesis_db_pool_max_connections", "Connection limit per database pool"),
+        &["pool"],
+        REGISTRY
+    )
+    .expect("db pool max connections gauge registers once");
+}
+
+// ---------------------------------------------------------------------------
+// Load shedding metrics
+// ---------------------------------------------------------------------------
+
+lazy_static! {
+    /// Admission decisions for calculation requests by `priority` (`low`,
+    /// `normal`, `critical`) and `decision` (`admitted` or `shed`).
+    pub static ref LOAD_SHED_DECISIONS_TOTAL: IntCounterVec = prometheus::register_int_counter_vec_with_registry!(
+        Opts::new("noesis_load_shed_decisions_total", "Calculation admission decisions by priority"),
+        &["priority", "decision"],
+        REGISTRY
+    )
+    .expect("load shed counter registers once");
+
+    /// Requests being handled, as seen by the load shedder.
+    pub static ref IN_FLIGHT_REQUESTS: prometheus::IntGauge = prometheus::register_int_gauge_with_registry!(
+        Opts::new("noesis_in_flight_requests", "API requests currently being handled"),
+        REGISTRY
+    )
+    .expect("in-flight gauge registers once");
+
+    /// Delay of the latest scheduler probe past its deadline.
+    pub static ref EVENT_LOOP_LAG: Gauge = prometheus::register_gauge_with_registry!(
+        Opts::new("noesis_event_loop_lag_seconds", "Latest async runtime scheduling lag in seconds"),
+        REGISTRY
+    )
+    .expect("event loop lag gauge registers once");
+
+    /// Calculations turned away because the user already had
+    /// `max_concurrent_calculations` running, by `tier`.
+    pub static ref CONCURRENCY_REJECTIONS_TOTAL: IntCounterVec = prometheus::register_int_counter_vec_with_registry!(
+        Opts::new("noesis_concurrency_rejections_total", "Calculations rejected over the tier's concurrency limit"),
+        &["tier"],
+        REGISTRY
+    )
+    .expect("concurrency rejection counter registers once");
+}
+
+/// Record whether a calculation request of `priority` was admitted or shed.
+pub fn record_load_shed_decision(priority: &str, shed: bool) {
+    let decision = if shed { "shed" } else { "admitted" };
+    LOAD_SHED_DECISIONS_TOTAL.with_label_values(&[priority, decision]).inc();
+}
+
+/// Record a calculation rejected over the concurrency limit of `tier`.
+pub fn record_concurrency_rejection(tier: &str) {
+    CONCURRENCY_REJECTIONS_TOTAL.with_label_values(&[tier]).inc();
+}
+
+/// Snapshot the signals the load shedder decides on.
+pub fn update_load_signals(in_flight: usize, event_loop_lag: f64) {
+    IN_FLIGHT_REQUESTS.set(in_flight as i64);
+    EVENT_LOOP_LAG.set(event_loop_lag);
+}
+
+// ---------------------------------------------------------------------------
+// Stream fanout metrics
+// ---------------------------------------------------------------------------
+
+lazy_static! {
+    /// Connections subscribed to a fanout topic, by topic `kind` (e.g. `ghati`).
+    pub static ref FANOUT_SUBSCRIBERS: IntGaugeVec = prometheus::register_int_gauge_vec_with_registry!(
+        Opts::new("noesis_fanout_subscribers", "Stream connections subscribed through the fanout hub"),
+        &["kind"],
+        REGISTRY
+    )
+    .expect("fanout subscribers gauge registers once");
+
+    /// Topics with a running producer, by `kind`.
+    pub static ref FANOUT_TOPICS: IntGaugeVec = prometheus::register_int_gauge_vec_with_registry!(
+        Opts::new("noesis_fanout_topics", "Fanout topics with a running producer"),
+        &["kind"],
+        REGISTRY
+    )
+    .expect("fanout topics gauge registers once");
+
+    /// Events queued to subscribers, by `kind`.
+    pub static ref FANOUT_EVENTS_TOTAL: IntCounterVec = prometheus::register_int_counter_vec_with_registry!(
+        Opts::new("noesis_fanout_events_total", "Events queued to fanout subscribers"),
+        &["kind"],
+        REGISTRY
+    )
+    .expect("fanout events counter registers once");
+
+    /// Subscribers disconnected by `kind` and `reason` (`slow` when their
+    /// buffer filled, `user_limit` when refused over the per-user limit).
+    pub static ref FANOUT_DISCONNECTS_TOTAL: IntCounterVec = prometheus::register_int_counter_vec_with_registry!(
+        Opts::new("noesis_fanout_disconnects_total", "Fanout subscribers dropped or refused"),
+        &["kind", "reason"],
+        REGISTRY
+    )
+    .expect("fanout disconnects counter registers once");
+}
+
+/// Record a subscriber joining (`delta` 1) or leaving (-1) a `kind` topic.
+pub fn record_fanout_subscribers(kind: &str, delta: i64) {
+    FANOUT_SUBSCRIBERS.with_label_values(&[kind]).add(delta);
+}
+
+/// Record a `kind` topic's producer starting (`delta` 1) or stopping (-1).
+pub fn record_fanout_topics(kind: &str, delta: i64) {
+    FANOUT_TOPICS.with_label_values(&[kind]).add(delta);
+}
+
+/// Record `delivered` events queued to subscribers of a `kind` topic.
+pub fn record_fanout_events(kind: &str, delivered: u64) {
+    FANOUT_EVENTS_TOTAL.with_label_values(&[kind]).inc_by(delivered);
+}
+
+/// Record a subscriber of a `kind` topic dropped or refused for `reason`.
+pub fn record_fanout_disconnect(kind: &str, reason: &str) {
+    FANOUT_DISCONNECTS_TOTAL.with_label_values(&[kind, reason]).inc();
+}
+
+// ---------------------------------------------------------------------------
+// Precomputation metrics
+// ---------------------------------------------------------------------------
+
+lazy_static! {
+    /// Natal results precomputed after a profile save by `engine_id` and
+    /// `outcome` (`stored` or `error`).
+    pub static ref PRECOMPUTE_RESULTS_TOTAL: IntCounterVec = prometheus::register_int_counter_vec_with_registry!(
+        Opts::new("noesis_precompute_results_total", "Engine results precomputed after a profile save"),
+        &["engine_id", "outcome"],
+        REGISTRY
+    )
+    .expect("precompute results counter registers once");
+
+    /// Profile saves not precomputed because the queue was full.
+    pub static ref PRECOMPUTE_DROPPED_TOTAL: prometheus::IntCounter = prometheus::register_int_counter_with_registry!(
+        Opts::new("noesis_precompute_dropped_total", "Profile saves skipped by a full precompute queue"),
+        REGISTRY
+    )
+    .expect("precompute dropped counter registers once");
+
+    /// Saved profiles recalculated by admin bulk recalculation jobs.
+    pub static ref RECALCULATION_RESULTS_TOTAL: IntCounterVec = prometheus::register_int_counter_vec_with_registry!(
+        Opts::new("noesis_recalculation_results_total", "Saved profiles recalculated by bulk recalculation jobs"),
+        &["engine_id", "outcome"],
+        REGISTRY
+    )
+    .expect("recalculation results counter registers once");
+}
+
+/// Record the precomputation of `engine_id`'s result.
+pub fn record_precompute(engine_id: &str, stored: bool) {
+    let outcome = if stored { "stored" } else { "error" };
+    PRECOMPUTE_RESULTS_TOTAL.with_label_values(&[engine_id, outcome]).inc();
+}
+
+/// Record a profile save dropped by a full precompute queue.
+pub fn record_precompute_dropped() {
+    PRECOMPUTE_DROPPED_TOTAL.inc();
+}
+
+/// Record the recalculation of one saved profile's `engine_id` result.
+pub fn record_recalculation(engine_id: &str, stored: bool) {
+    let outcome = if stored { "stored" } else { "error" };
+    RECALCULATION_RESULTS_TOTAL.with_label_values(&[engine_id, outcome]).inc();
+}
+
+// ---------------------------------------------------------------------------
+// Retention metrics
+// ---------------------------------------------------------------------------
+
+lazy_static! {
+    /// Rows past their retention period by `data_class` and `mode`
+    /// (`purged`, or `dry_run` when only counted).
+    pub static ref RETENTION_ROWS_TOTAL: IntCounterVec = prometheus::register_int_counter_vec_with_registry!(
+        Opts::new("noesis_retention_rows_total", "Rows past their retention period, purged or counted by a dry run"),
+        &["data_class", "mode"],
+        REGISTRY
+    )
+    .expect("retention rows counter registers once");
+
+    /// Retention runs of a data class that failed.
+    pub static ref RETENTION_ERRORS_TOTAL: IntCounterVec = prometheus::register_int_counter_vec_with_registry!(
+        Opts::new("noesis_retention_errors_total", "Failed retention purges"),
+        &["data_class"],
+        REGISTRY
+    )
+    .expect("retention errors counter registers once");
+}
+
+/// Record `rows` of `data_class` purged, or only counted when `dry_run`.
+pub fn record_retention(data_class: &str, rows: u64, dry_run: bool) {
+    let mode = if dry_run { "dry_run" } else { "purged" };
+    RETENTION_ROWS_TOTAL.with_label_values(&[data_class, mode]).inc_by(rows);
+}
+
+/// Record a failed retention purge of `data_class`.
+pub fn record_retention_error(data_class: &str) {
+    RETENTION_ERRORS_TOTAL.with_label_values(&[data_class]).inc();
+}
+
+// ---------------------------------------------------------------------------
+// Notification metrics
+// ---------------------------------------------------------------------------
+
+lazy_static! {
+    /// Notifications sent by rules, by `category`, `channel` and `outcome`
+    /// (`delivered`, or `failed` when a webhook did not accept one).
+    pub static ref NOTIFICATIONS_TOTAL: IntCounterVec = prometheus::register_int_counter_vec_with_registry!(
+        Opts::new("noesis_notifications_total", "Notifications sent by notification rules"),
+        &["category", "channel", "outcome"],
+        REGISTRY
+    )
+    .expect("notifications counter registers once");
+}
+
+/// Record a notification of `category` sent on `channel`.
+pub fn record_notification(category: &str, channel: &str, delivered: bool) {
+    let outcome = if delivered { "delivered" } else { "failed" };
+    NOTIFICATIONS_TOTAL.with_label_values(&[category, channel, outcome]).inc();
 }
 
 // ---------------------------------------------------------------------------
@@ -460,11 +1009,87 @@ mod tests {
         metrics.record_validation_difference(0.5);
         metrics.update_system_metrics(1024.0, 12.5, 3600.0);
         metrics.update_active_connections(5.0);
+        metrics.update_db_pool("primary", 4, 1, 5);
 
         let text = metrics
             .get_metrics_text()
             .expect("should encode metrics text");
         assert!(text.contains("noesis_requests_total"));
         assert!(text.contains("noesis_engine_calculations_total"));
+        assert_eq!(DB_POOL_CONNECTIONS.with_label_values(&["primary", "in_use"]).get(), 3);
+        assert!(text.contains("noesis_db_pool_max_connections"));
+        assert!(text.contains("noesis_engine_latency_slo_seconds{engine_id=\"solar_v1\"} 1"));
+
+        let openmetrics = metrics.get_openmetrics_text();
+        assert!(openmetrics.contains("# TYPE noesis_requests counter\nnoesis_requests_total 1\n"));
+        assert!(openmetrics.ends_with("# EOF\n"));
+    }
+
+    #[test]
+    fn bridge_attempts_are_registered() {
+        record_bridge_attempt("tarot", "calculate", "retry", 0.01);
+        record_bridge_attempt("tarot", "calculate", "success", 0.02);
+
+        assert_eq!(
+            BRIDGE_ATTEMPTS_TOTAL
+                .with_label_values(&["tarot", "calculate", "retry"])
+                .get(),
+            1
+        );
+        let names: Vec<String> = REGISTRY.gather().iter().map(|f| f.get_name().to_string()).collect();
+        assert!(names.contains(&"noesis_bridge_attempt_duration_seconds".to_string()));
+    }
+
+    #[test]
+    fn cache_and_workflow_metrics_are_registered() {
+        record_cache_layer_lookup("l1", true, 0.000_02);
+        record_cache_layer_lookup("l2", false, 0.001);
+        record_cache_layer_store("l1", 0.000_03);
+        record_workflow_execution("daily-practice", 3, 0.25);
+        record_workflow_execution("daily-practice", 2, 0.30);
+        record_phase_denied("gene-keys");
+        record_engine_alias_request("vedic-clock");
+        record_bridge_http_response("tarot", "calculate", "503", 0.05);
+        record_experiment_exposure("prompt-style", "reflective");
+        record_experiment_outcome("prompt-style", "reflective", "success");
+        record_load_shed_decision("low", true);
+        update_load_signals(12, 0.004);
+        record_concurrency_rejection("free");
+        record_cache_hot_keys([("a1b2", 40, 3), ("c3d4", 12, 0)]);
+        record_cache_hot_keys([("c3d4", 30, 1)]);
+
+        assert_eq!(CACHE_LAYER_LOOKUPS_TOTAL.with_label_values(&["l1", "hit"]).get(), 1);
+        assert_eq!(CACHE_LAYER_LOOKUPS_TOTAL.with_label_values(&["l2", "miss"]).get(), 1);
+        assert_eq!(CACHE_HOT_KEY_REQUESTS.with_label_values(&["1", "c3d4"]).get(), 30);
+        assert_eq!(CACHE_HOT_KEY_MISSES.with_label_values(&["1", "c3d4"]).get(), 1);
+        // The previous window's keys are gone rather than left at stale values
+        let hot_keys = REGISTRY
+            .gather()
+            .into_iter()
+            .find(|family| family.get_name() == "noesis_cache_hot_key_requests")
+            .unwrap();
+        assert_eq!(hot_keys.get_metric().len(), 1);
+        assert_eq!(WORKFLOW_ENGINES_SUCCEEDED.with_label_values(&["daily-practice"]).get(), 2);
+        assert_eq!(WORKFLOW_DURATION.with_label_values(&["daily-practice"]).get_sample_count(), 2);
+        assert_eq!(PHASE_DENIED_TOTAL.with_label_values(&["gene-keys"]).get(), 1);
+        assert_eq!(ENGINE_ALIAS_REQUESTS_TOTAL.with_label_values(&["vedic-clock"]).get(), 1);
+        assert_eq!(
+            EXPERIMENT_EXPOSURES_TOTAL.with_label_values(&["prompt-style", "reflective"]).get(),
+            1
+        );
+        assert_eq!(
+            EXPERIMENT_OUTCOMES_TOTAL
+                .with_label_values(&["prompt-style", "reflective", "success"])
+                .get(),
+            1
+        );
+        assert_eq!(LOAD_SHED_DECISIONS_TOTAL.with_label_values(&["low", "shed"]).get(), 1);
+        assert_eq!(IN_FLIGHT_REQUESTS.get(), 12);
+        assert_eq!(
+            BRIDGE_HTTP_RESPONSES_TOTAL
+                .with_label_values(&["tarot", "calculate", "503"])
+                .get(),
+            1
+        );
     }
 }
@@ -0,0 +1,156 @@
+//! OpenMetrics text encoding
+//!
+//! The Prometheus text format has no room for exemplars, so `/metrics`
+//! answers scrapers that accept `application/openmetrics-text` with this
+//! encoding instead. It differs from the text format in that counter families
+//! drop their `_total` suffix, the output ends in `# EOF`, and histogram
+//! buckets may carry an exemplar.
+
+use crate::latency::Exemplar;
+use prometheus::proto::{LabelPair, MetricFamily, MetricType};
+use std::fmt::Write;
+
+pub const CONTENT_TYPE: &str = "application/openmetrics-text; version=1.0.0; charset=utf-8";
+
+/// Encode `families`, asking `exemplar` for each histogram bucket with the
+/// family name, the series labels and the bucket's upper bound.
+pub fn encode<F>(families: &[MetricFamily], exemplar: F) -> String
+where
+    F: Fn(&str, &[LabelPair], f64) -> Option<Exemplar>,
+{
+    let mut out = String::new();
+    for family in families {
+        let name = family.get_name();
+        let (family_name, kind) = match family.get_field_type() {
+            MetricType::COUNTER => (name.strip_suffix("_total").unwrap_or(name), "counter"),
+            MetricType::GAUGE => (name, "gauge"),
+            MetricType::HISTOGRAM => (name, "histogram"),
+            MetricType::SUMMARY => (name, "summary"),
+            MetricType::UNTYPED => (name, "unknown"),
+        };
+        let _ = writeln!(out, "# HELP {} {}", family_name, escape(family.get_help()));
+        let _ = writeln!(out, "# TYPE {} {}", family_name, kind);
+
+        for metric in family.get_metric() {
+            let labels = metric.get_label();
+            match family.get_field_type() {
+                MetricType::COUNTER => {
+                    sample(&mut out, &format!("{}_total", family_name), labels, None, metric.get_counter().get_value())
+                }
+                MetricType::GAUGE => sample(&mut out, name, labels, None, metric.get_gauge().get_value()),
+                MetricType::UNTYPED => sample(&mut out, name, labels, None, metric.get_untyped().get_value()),
+                MetricType::HISTOGRAM => {
+                    let histogram = metric.get_histogram();
+                    let bucket_name = format!("{}_bucket", name);
+                    let bounds = histogram
+                        .get_bucket()
+                        .iter()
+                        .map(|b| (b.get_upper_bound(), b.get_cumulative_count()))
+                        .chain(std::iter::once((f64::INFINITY, histogram.get_sample_count())));
+                    for (upper_bound, count) in bounds {
+                        let le = ("le", float(upper_bound));
+                        sample(&mut out, &bucket_name, labels, Some(le), count as f64);
+                        if let Some(exemplar) = exemplar(name, labels, upper_bound) {
+                            out.pop();
+                            let _ = writeln!(
+                                out,
+                                " # {{trace_id=\"{}\"}} {} {}",
+                                escape(&exemplar.trace_id),
+                                float(exemplar.value),
+                                float(exemplar.timestamp)
+                            );
+                        }
+                    }
+                    sample(&mut out, &format!("{}_count", name), labels, None, histogram.get_sample_count() as f64);
+                    sample(&mut out, &format!("{}_sum", name), labels, None, histogram.get_sample_sum());
+                }
+                MetricType::SUMMARY => {
+                    let summary = metric.get_summary();
+                    for quantile in summary.get_quantile() {
+                        let q = ("quantile", float(quantile.get_quantile()));
+                        sample(&mut out, name, labels, Some(q), quantile.get_value());
+                    }
+                    sample(&mut out, &format!("{}_count", name), labels, None, summary.get_sample_count() as f64);
+                    sample(&mut out, &format!("{}_sum", name), labels, None, summary.get_sample_sum());
+                }
+            }
+        }
+    }
+    out.push_str("# EOF\n");
+    out
+}
+
+fn sample(out: &mut String, name: &str, labels: &[LabelPair], extra: Option<(&str, String)>, value: f64) {
+    out.push_str(name);
+    let mut pairs: Vec<String> = labels
+        .iter()
+        .map(|l| format!("{}=\"{}\"", l.get_name(), escape(l.get_value())))
+        .collect();
+    if let Some((label, value)) = extra {
+        pairs.push(format!("{}=\"{}\"", label, value));
+    }
+    if !pairs.is_empty() {
+        let _ = write!(out, "{{{}}}", pairs.join(","));
+    }
+    let _ = writeln!(out, " {}", float(value));
+}
+
+fn float(value: f64) -> String {
+    if value.is_nan() {
+        "NaN".to_string()
+    } else if value.is_infinite() {
+        if value > 0.0 { "+Inf" } else { "-Inf" }.to_string()
+    } else {
+        value.to_string()
+    }
+}
+
+fn escape(text: &str) -> String {
+    text.replace('\\', "\\\\").replace('"', "\\\"").replace('\n', "\\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use prometheus::{HistogramOpts, HistogramVec, IntCounterVec, Opts, Registry};
+
+    #[test]
+    fn test_encodes_families_with_exemplars() {
+        let registry = Registry::new();
+        let calls = IntCounterVec::new(Opts::new("noesis_calls_total", "Calls"), &["engine_id"]).unwrap();
+        let latency = HistogramVec::new(
+            HistogramOpts::new("noesis_call_seconds", "Call \"latency\"").buckets(vec![0.1, 1.0]),
+            &["engine_id"],
+        )
+        .unwrap();
+        registry.register(Box::new(calls.clone())).unwrap();
+        registry.register(Box::new(latency.clone())).unwrap();
+        calls.with_label_values(&["tarot"]).inc();
+        latency.with_label_values(&["tarot"]).observe(0.5);
+
+        let text = encode(&registry.gather(), |family, labels, upper_bound| {
+            (family == "noesis_call_seconds" && labels[0].get_value() == "tarot" && upper_bound == 1.0).then(|| {
+                Exemplar {
+                    trace_id: "4bf92f3577b34da6a3ce929d0e0e4736".into(),
+                    value: 0.5,
+                    timestamp: 1_700_000_000.5,
+                }
+            })
+        });
+
+        assert_eq!(
+            text,
+            "# HELP noesis_call_seconds Call \\\"latency\\\"\n\
+             # TYPE noesis_call_seconds histogram\n\
+             noesis_call_seconds_bucket{engine_id=\"tarot\",le=\"0.1\"} 0\n\
+             noesis_call_seconds_bucket{engine_id=\"tarot\",le=\"1\"} 1 # {trace_id=\"4bf92f3577b34da6a3ce929d0e0e4736\"} 0.5 1700000000.5\n\
+             noesis_call_seconds_bucket{engine_id=\"tarot\",le=\"+Inf\"} 1\n\
+             noesis_call_seconds_count{engine_id=\"tarot\"} 1\n\
+             noesis_call_seconds_sum{engine_id=\"tarot\"} 0.5\n\
+             # HELP noesis_calls Calls\n\
+             # TYPE noesis_calls counter\n\
+             noesis_calls_total{engine_id=\"tarot\"} 1\n\
+             # EOF\n"
+        );
+    }
+}
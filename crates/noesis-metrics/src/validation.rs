@@ -0,0 +1,329 @@
+//! Cross-backend validation samples
+//!
+//! Wherever two backends compute the same quantity (native formulas, Swiss
+//! Ephemeris, an external API), the difference is recorded as a
+//! [`ValidationSample`]. The Prometheus histogram only keeps the overall
+//! distribution; [`ValidationLog`] keeps the most recent samples with their
+//! inputs so accuracy drift can be traced back to specific dates after a
+//! formula change.
+
+use std::collections::{BTreeMap, VecDeque};
+use std::sync::Mutex;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+#[cfg(feature = "openapi")]
+use utoipa::ToSchema;
+
+/// Samples kept by [`ValidationLog::default`]
+pub const DEFAULT_CAPACITY: usize = 10_000;
+
+/// One comparison of a quantity computed by two backends
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct ValidationSample {
+    #[cfg_attr(feature = "openapi", schema(example = "panchanga"))]
+    pub engine_id: String,
+    /// Compared quantity
+    #[cfg_attr(feature = "openapi", schema(example = "solar_longitude"))]
+    pub quantity: String,
+    /// Backend treated as correct
+    #[cfg_attr(feature = "openapi", schema(example = "swiss_ephemeris"))]
+    pub reference_backend: String,
+    /// Backend being checked
+    #[cfg_attr(feature = "openapi", schema(example = "native"))]
+    pub candidate_backend: String,
+    /// Absolute difference in arcseconds
+    pub difference_arcseconds: f64,
+    /// Instant the compared quantity refers to
+    #[cfg_attr(feature = "openapi", schema(value_type = String, format = DateTime))]
+    pub calculated_for: DateTime<Utc>,
+    #[cfg_attr(feature = "openapi", schema(value_type = String, format = DateTime))]
+    pub recorded_at: DateTime<Utc>,
+    /// Input that produced the sample, for reproducing it
+    #[cfg_attr(feature = "openapi", schema(value_type = Object))]
+    pub input: Value,
+}
+
+/// Filter and sizes for [`ValidationLog::report`]
+#[derive(Debug, Clone)]
+pub struct ValidationReportQuery {
+    pub engine_id: Option<String>,
+    /// Inclusive lower bound on `calculated_for`
+    pub from: Option<DateTime<Utc>>,
+    /// Exclusive upper bound on `calculated_for`
+    pub to: Option<DateTime<Utc>>,
+    /// Worst offenders listed per engine
+    pub worst: usize,
+    /// Most recent inputs listed per engine
+    pub samples: usize,
+}
+
+impl Default for ValidationReportQuery {
+    fn default() -> Self {
+        Self {
+            engine_id: None,
+            from: None,
+            to: None,
+            worst: 10,
+            samples: 5,
+        }
+    }
+}
+
+/// Difference distribution for one quantity and backend pair
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct QuantityStats {
+    pub quantity: String,
+    pub reference_backend: String,
+    pub candidate_backend: String,
+    pub samples: usize,
+    /// Differences in arcseconds
+    pub mean: f64,
+    pub p50: f64,
+    pub p90: f64,
+    pub p99: f64,
+    pub max: f64,
+}
+
+/// Validation summary for one engine
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct EngineValidationStats {
+    pub engine_id: String,
+    pub samples: usize,
+    pub quantities: Vec<QuantityStats>,
+    /// Largest differences, worst first
+    pub worst_offenders: Vec<ValidationSample>,
+    /// Most recent inputs, newest first
+    #[cfg_attr(feature = "openapi", schema(value_type = Vec<Object>))]
+    pub sample_inputs: Vec<Value>,
+}
+
+/// Cross-backend validation report
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct ValidationReport {
+    #[cfg_attr(feature = "openapi", schema(value_type = String, format = DateTime))]
+    pub generated_at: DateTime<Utc>,
+    #[cfg_attr(feature = "openapi", schema(value_type = Option<String>, format = DateTime))]
+    pub from: Option<DateTime<Utc>>,
+    #[cfg_attr(feature = "openapi", schema(value_type = Option<String>, format = DateTime))]
+    pub to: Option<DateTime<Utc>>,
+    /// Samples matching the filter
+    pub total_samples: usize,
+    /// Samples currently held, oldest are dropped past the capacity
+    pub retained_samples: usize,
+    pub engines: Vec<EngineValidationStats>,
+}
+
+/// Bounded in-memory log of validation samples
+#[derive(Debug)]
+pub struct ValidationLog {
+    capacity: usize,
+    samples: Mutex<VecDeque<ValidationSample>>,
+}
+
+impl Default for ValidationLog {
+    fn default() -> Self {
+        Self::new(DEFAULT_CAPACITY)
+    }
+}
+
+impl ValidationLog {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity: capacity.max(1),
+            samples: Mutex::new(VecDeque::new()),
+        }
+    }
+
+    /// Append a sample, dropping the oldest once full.
+    pub fn record(&self, sample: ValidationSample) {
+        let mut samples = self.samples.lock().unwrap_or_else(|e| e.into_inner());
+        if samples.len() == self.capacity {
+            samples.pop_front();
+        }
+        samples.push_back(sample);
+    }
+
+    pub fn len(&self) -> usize {
+        self.samples.lock().unwrap_or_else(|e| e.into_inner()).len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Summarise the samples matching `query`, per engine.
+    pub fn report(&self, query: &ValidationReportQuery) -> ValidationReport {
+        let samples = self.samples.lock().unwrap_or_else(|e| e.into_inner());
+        let retained_samples = samples.len();
+
+        let mut by_engine: BTreeMap<&str, Vec<&ValidationSample>> = BTreeMap::new();
+        for sample in samples.iter().filter(|s| Self::matches(query, s)) {
+            by_engine.entry(sample.engine_id.as_str()).or_default().push(sample);
+        }
+
+        let engines: Vec<EngineValidationStats> = by_engine
+            .into_iter()
+            .map(|(engine_id, samples)| Self::engine_stats(engine_id, &samples, query))
+            .collect();
+
+        ValidationReport {
+            generated_at: Utc::now(),
+            from: query.from,
+            to: query.to,
+            total_samples: engines.iter().map(|e| e.samples).sum(),
+            retained_samples,
+            engines,
+        }
+    }
+
+    fn matches(query: &ValidationReportQuery, sample: &ValidationSample) -> bool {
+        query.engine_id.as_deref().is_none_or(|id| sample.engine_id == id)
+            && query.from.is_none_or(|from| sample.calculated_for >= from)
+            && query.to.is_none_or(|to| sample.calculated_for < to)
+    }
+
+    fn engine_stats(
+        engine_id: &str,
+        samples: &[&ValidationSample],
+        query: &ValidationReportQuery,
+    ) -> EngineValidationStats {
+        let mut groups: BTreeMap<(&str, &str, &str), Vec<f64>> = BTreeMap::new();
+        for sample in samples {
+            groups
+                .entry((
+                    sample.quantity.as_str(),
+                    sample.reference_backend.as_str(),
+                    sample.candidate_backend.as_str(),
+                ))
+                .or_default()
+                .push(sample.difference_arcseconds);
+        }
+
+        let quantities = groups
+            .into_iter()
+            .map(|((quantity, reference, candidate), mut differences)| {
+                differences.sort_by(f64::total_cmp);
+                QuantityStats {
+                    quantity: quantity.to_string(),
+                    reference_backend: reference.to_string(),
+                    candidate_backend: candidate.to_string(),
+                    samples: differences.len(),
+                    mean: differences.iter().sum::<f64>() / differences.len() as f64,
+                    p50: percentile(&differences, 50.0),
+                    p90: percentile(&differences, 90.0),
+                    p99: percentile(&differences, 99.0),
+                    max: differences[differences.len() - 1],
+                }
+            })
+            .collect();
+
+        let mut worst: Vec<&ValidationSample> = samples.to_vec();
+        worst.sort_by(|a, b| b.difference_arcseconds.total_cmp(&a.difference_arcseconds));
+
+        EngineValidationStats {
+            engine_id: engine_id.to_string(),
+            samples: samples.len(),
+            quantities,
+            worst_offenders: worst.into_iter().take(query.worst).cloned().collect(),
+            sample_inputs: samples
+                .iter()
+                .rev()
+                .take(query.samples)
+                .map(|s| s.input.clone())
+                .collect(),
+        }
+    }
+}
+
+/// Nearest-rank percentile of ascending `sorted`, which must not be empty
+fn percentile(sorted: &[f64], p: f64) -> f64 {
+    let rank = (p / 100.0 * sorted.len() as f64).ceil() as usize;
+    sorted[rank.clamp(1, sorted.len()) - 1]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn sample(engine_id: &str, quantity: &str, difference: f64, day: u32) -> ValidationSample {
+        ValidationSample {
+            engine_id: engine_id.to_string(),
+            quantity: quantity.to_string(),
+            reference_backend: "swiss_ephemeris".to_string(),
+            candidate_backend: "native".to_string(),
+            difference_arcseconds: difference,
+            calculated_for: Utc.with_ymd_and_hms(2025, 1, day, 12, 0, 0).unwrap(),
+            recorded_at: Utc::now(),
+            input: serde_json::json!({ "day": day }),
+        }
+    }
+
+    #[test]
+    fn report_computes_percentiles_per_quantity() {
+        let log = ValidationLog::default();
+        for (i, difference) in (1..=100).map(f64::from).enumerate() {
+            log.record(sample("panchanga", "solar_longitude", difference, (i % 28) as u32 + 1));
+        }
+        log.record(sample("panchanga", "lunar_longitude", 500.0, 1));
+
+        let report = log.report(&ValidationReportQuery::default());
+        assert_eq!(report.total_samples, 101);
+        let engine = &report.engines[0];
+        let solar = engine
+            .quantities
+            .iter()
+            .find(|q| q.quantity == "solar_longitude")
+            .unwrap();
+        assert_eq!(solar.samples, 100);
+        assert_eq!(solar.p50, 50.0);
+        assert_eq!(solar.p90, 90.0);
+        assert_eq!(solar.p99, 99.0);
+        assert_eq!(solar.max, 100.0);
+        assert!((solar.mean - 50.5).abs() < 1e-9);
+
+        assert_eq!(engine.worst_offenders.len(), 10);
+        assert_eq!(engine.worst_offenders[0].quantity, "lunar_longitude");
+        assert_eq!(engine.worst_offenders[1].difference_arcseconds, 100.0);
+        assert_eq!(engine.sample_inputs[0], serde_json::json!({ "day": 1 }));
+    }
+
+    #[test]
+    fn report_filters_by_engine_and_date_range() {
+        let log = ValidationLog::default();
+        log.record(sample("panchanga", "solar_longitude", 10.0, 5));
+        log.record(sample("panchanga", "solar_longitude", 20.0, 10));
+        log.record(sample("human-design", "solar_longitude", 30.0, 10));
+
+        let report = log.report(&ValidationReportQuery {
+            engine_id: Some("panchanga".to_string()),
+            from: Some(Utc.with_ymd_and_hms(2025, 1, 6, 0, 0, 0).unwrap()),
+            to: Some(Utc.with_ymd_and_hms(2025, 1, 11, 0, 0, 0).unwrap()),
+            ..Default::default()
+        });
+        assert_eq!(report.total_samples, 1);
+        assert_eq!(report.retained_samples, 3);
+        assert_eq!(report.engines.len(), 1);
+        assert_eq!(report.engines[0].worst_offenders[0].difference_arcseconds, 20.0);
+    }
+
+    #[test]
+    fn log_drops_oldest_past_capacity() {
+        let log = ValidationLog::new(2);
+        log.record(sample("panchanga", "solar_longitude", 1.0, 1));
+        log.record(sample("panchanga", "solar_longitude", 2.0, 2));
+        log.record(sample("panchanga", "solar_longitude", 3.0, 3));
+
+        assert_eq!(log.len(), 2);
+        let report = log.report(&ValidationReportQuery::default());
+        assert_eq!(report.engines[0].quantities[0].max, 3.0);
+        assert_eq!(report.engines[0].quantities[0].p50, 2.0);
+    }
+}
@@ -60,6 +60,7 @@ impl ConsciousnessEngine for BenchMockEngine {
             engine_id: self.id.clone(),
             result: serde_json::json!({ "bench": true }),
             witness_prompt: "Benchmark prompt".to_string(),
+            summary: None,
             consciousness_level: 0,
             metadata: CalculationMetadata {
                 calculation_time_ms: self.delay_us as f64 / 1000.0,
@@ -67,6 +68,9 @@ impl ConsciousnessEngine for BenchMockEngine {
                 precision_achieved: "standard".to_string(),
                 cached: false,
                 timestamp: Utc::now(),
+                engine_version: None,
+                warnings: Vec::new(),
+                usage: None,
             },
         })
     }
@@ -91,6 +95,8 @@ impl ConsciousnessEngine for BenchMockEngine {
 fn bench_input() -> EngineInput {
     EngineInput {
         birth_data: None,
+        secondary_birth_data: None,
+        subjects: Vec::new(),
         current_time: Utc::now(),
         location: None,
         precision: Precision::Standard,
@@ -295,6 +301,8 @@ fn bench_cache(c: &mut Criterion) {
                 synthesis: None,
                 total_time_ms: 100.0,
                 timestamp: Utc::now(),
+                usage: None,
+                time_confidence: None,
             };
             cache.set(key, result, Duration::from_secs(3600)).await;
         }
@@ -331,6 +339,8 @@ fn bench_cache(c: &mut Criterion) {
                 synthesis: None,
                 total_time_ms: 100.0,
                 timestamp: Utc::now(),
+                usage: None,
+                time_confidence: None,
             };
             runtime.block_on(async {
                 black_box(cache.set(key, result, Duration::from_secs(60)).await)
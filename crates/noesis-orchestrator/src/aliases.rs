@@ -0,0 +1,75 @@
+//! Renamed and merged engines
+//!
+//! When an engine is renamed (say `vedic-clock` to `vedic-clock-tcm`) or
+//! folded into another, its old id is registered as an [`EngineAlias`]
+//! rather than dropped. Until the alias's `sunset_at` the old id resolves to
+//! the new engine and every output says so in `metadata.warnings`; the API
+//! adds `Deprecation`, `Sunset` and successor `Link` headers. After the
+//! sunset the old id fails with [`EngineError::EngineRetired`], naming the
+//! replacement, instead of a bare "not found".
+
+use chrono::{DateTime, Utc};
+use noesis_core::EngineError;
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "openapi")]
+use utoipa::ToSchema;
+
+/// An old engine id kept resolving to its replacement for a while
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct EngineAlias {
+    /// The retired id
+    #[cfg_attr(feature = "openapi", schema(example = "vedic-clock"))]
+    pub alias: String,
+    /// Engine the alias resolves to
+    #[cfg_attr(feature = "openapi", schema(example = "vedic-clock-tcm"))]
+    pub engine_id: String,
+    /// When the old id was deprecated
+    pub deprecated_at: DateTime<Utc>,
+    /// When the old id stops resolving; never when absent
+    pub sunset_at: Option<DateTime<Utc>>,
+}
+
+impl EngineAlias {
+    pub fn new(alias: impl Into<String>, engine_id: impl Into<String>, deprecated_at: DateTime<Utc>) -> Self {
+        Self {
+            alias: alias.into(),
+            engine_id: engine_id.into(),
+            deprecated_at,
+            sunset_at: None,
+        }
+    }
+
+    /// Stop resolving the alias at `sunset_at`
+    pub fn with_sunset(mut self, sunset_at: DateTime<Utc>) -> Self {
+        self.sunset_at = Some(sunset_at);
+        self
+    }
+
+    pub fn is_sunset(&self, now: DateTime<Utc>) -> bool {
+        self.sunset_at.is_some_and(|sunset| now >= sunset)
+    }
+
+    /// The `metadata.warnings` entry of outputs requested through the alias
+    pub fn warning(&self) -> String {
+        match self.sunset_at {
+            Some(sunset) => format!(
+                "Engine id '{}' is deprecated and stops working on {}; use '{}'",
+                self.alias,
+                sunset.format("%Y-%m-%d"),
+                self.engine_id
+            ),
+            None => format!("Engine id '{}' is deprecated; use '{}'", self.alias, self.engine_id),
+        }
+    }
+
+    /// The error for a request through the alias after its sunset
+    pub fn retired_error(&self) -> EngineError {
+        EngineError::EngineRetired {
+            alias: self.alias.clone(),
+            replacement: self.engine_id.clone(),
+            sunset: self.sunset_at.map(|sunset| sunset.format("%Y-%m-%d").to_string()).unwrap_or_default(),
+        }
+    }
+}
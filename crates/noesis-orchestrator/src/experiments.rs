@@ -0,0 +1,358 @@
+//! Engine A/B experiments
+//!
+//! An [`Experiment`] targets one engine and splits callers between weighted
+//! [`Variant`]s, each carrying engine options to inject (for example a
+//! different `ayanamsa` for panchanga). A user is assigned by hashing the
+//! experiment id together with their user id, so the same user lands in the
+//! same variant on every request, and on every replica given the same
+//! definition, without stored assignments. Definitions themselves are held
+//! in the [`ExperimentRegistry`] of one process only.
+//!
+//! Options set explicitly by the caller always win over variant options; a
+//! variant without options is the control arm. Exposures and outcomes are
+//! exported through `noesis-metrics` (`noesis_experiment_*`), labelled by
+//! experiment and variant.
+
+use noesis_core::{EngineError, EngineInput};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use sha2::{Digest, Sha256};
+use std::collections::{BTreeMap, HashMap};
+use std::sync::RwLock;
+
+#[cfg(feature = "openapi")]
+use utoipa::ToSchema;
+
+/// Longest experiment id, variant name or outcome accepted as a metric label
+pub const MAX_LABEL_LEN: usize = 64;
+
+/// One arm of an experiment
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct Variant {
+    #[cfg_attr(feature = "openapi", schema(example = "lahiri"))]
+    pub name: String,
+    /// Relative share of traffic; 0 disables the arm without removing it
+    #[cfg_attr(feature = "openapi", schema(example = 50))]
+    pub weight: u32,
+    /// Engine options injected for users in this arm
+    #[serde(default)]
+    #[cfg_attr(feature = "openapi", schema(value_type = Object))]
+    pub options: HashMap<String, Value>,
+}
+
+/// A weighted split of one engine's callers
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct Experiment {
+    #[cfg_attr(feature = "openapi", schema(example = "panchanga-ayanamsa"))]
+    pub id: String,
+    #[cfg_attr(feature = "openapi", schema(example = "panchanga"))]
+    pub engine_id: String,
+    /// Paused experiments assign nobody and record nothing
+    #[serde(default = "default_enabled")]
+    pub enabled: bool,
+    pub variants: Vec<Variant>,
+}
+
+fn default_enabled() -> bool {
+    true
+}
+
+/// The variant a user was placed in
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Assignment {
+    pub experiment_id: String,
+    pub variant: String,
+}
+
+/// Whether `value` is usable as an id or metric label: 1-64 of `[a-z0-9_-]`
+pub fn is_valid_label(value: &str) -> bool {
+    !value.is_empty()
+        && value.len() <= MAX_LABEL_LEN
+        && value
+            .bytes()
+            .all(|b| b.is_ascii_lowercase() || b.is_ascii_digit() || b == b'_' || b == b'-')
+}
+
+impl Experiment {
+    /// Check ids, variant names and weights before the experiment goes live
+    pub fn validate(&self) -> Result<(), EngineError> {
+        if !is_valid_label(&self.id) {
+            return Err(EngineError::ValidationError(format!(
+                "Experiment id '{}' must be 1-{} characters of a-z, 0-9, '_' or '-'",
+                self.id, MAX_LABEL_LEN
+            )));
+        }
+        if self.engine_id.is_empty() {
+            return Err(EngineError::ValidationError("engine_id must not be empty".to_string()));
+        }
+        if self.variants.is_empty() {
+            return Err(EngineError::ValidationError(format!(
+                "Experiment '{}' needs at least one variant",
+                self.id
+            )));
+        }
+        for (i, variant) in self.variants.iter().enumerate() {
+            if !is_valid_label(&variant.name) {
+                return Err(EngineError::ValidationError(format!(
+                    "Variant name '{}' must be 1-{} characters of a-z, 0-9, '_' or '-'",
+                    variant.name, MAX_LABEL_LEN
+                )));
+            }
+            if self.variants[..i].iter().any(|v| v.name == variant.name) {
+                return Err(EngineError::ValidationError(format!(
+                    "Variant '{}' appears more than once",
+                    variant.name
+                )));
+            }
+        }
+        if self.total_weight() == 0 {
+            return Err(EngineError::ValidationError(format!(
+                "Experiment '{}' has no variant with a positive weight",
+                self.id
+            )));
+        }
+        Ok(())
+    }
+
+    /// The variant `subject` (a user id) falls into
+    ///
+    /// Returns `None` only if every weight is 0, which [`validate`](Self::validate) rejects.
+    pub fn assign(&self, subject: &str) -> Option<&Variant> {
+        let total = self.total_weight();
+        if total == 0 {
+            return None;
+        }
+        let mut bucket = bucket(&self.id, subject) % total;
+        self.variants.iter().find(|variant| {
+            let weight = u64::from(variant.weight);
+            if bucket < weight {
+                true
+            } else {
+                bucket -= weight;
+                false
+            }
+        })
+    }
+
+    fn total_weight(&self) -> u64 {
+        self.variants.iter().map(|v| u64::from(v.weight)).sum()
+    }
+}
+
+/// Stable hash of `experiment_id:subject`, independent of process and platform
+fn bucket(experiment_id: &str, subject: &str) -> u64 {
+    let digest = Sha256::new()
+        .chain_update(experiment_id.as_bytes())
+        .chain_update(b":")
+        .chain_update(subject.as_bytes())
+        .finalize();
+    let mut prefix = [0u8; 8];
+    prefix.copy_from_slice(&digest[..8]);
+    u64::from_be_bytes(prefix)
+}
+
+/// Live experiments of this process, changeable at runtime through the admin API
+#[derive(Default)]
+pub struct ExperimentRegistry {
+    experiments: RwLock<BTreeMap<String, Experiment>>,
+}
+
+impl ExperimentRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add or replace an experiment; returns the one it replaced
+    pub fn upsert(&self, experiment: Experiment) -> Result<Option<Experiment>, EngineError> {
+        experiment.validate()?;
+        Ok(self.write().insert(experiment.id.clone(), experiment))
+    }
+
+    pub fn remove(&self, id: &str) -> Option<Experiment> {
+        self.write().remove(id)
+    }
+
+    pub fn get(&self, id: &str) -> Option<Experiment> {
+        self.read().get(id).cloned()
+    }
+
+    /// All experiments, ordered by id
+    pub fn list(&self) -> Vec<Experiment> {
+        self.read().values().cloned().collect()
+    }
+
+    /// Inject variant options for `subject` into an input bound for `engine_id`
+    ///
+    /// Enabled experiments on the engine are applied in id order. An option key
+    /// already present, whether set by the caller or an earlier experiment, is
+    /// left untouched.
+    pub fn apply(&self, engine_id: &str, subject: &str, input: &mut EngineInput) -> Vec<Assignment> {
+        let experiments = self.read();
+        let mut assignments = Vec::new();
+        for experiment in experiments
+            .values()
+            .filter(|e| e.enabled && e.engine_id == engine_id)
+        {
+            let Some(variant) = experiment.assign(subject) else {
+                continue;
+            };
+            for (key, value) in &variant.options {
+                input.options.entry(key.clone()).or_insert_with(|| value.clone());
+            }
+            assignments.push(Assignment {
+                experiment_id: experiment.id.clone(),
+                variant: variant.name.clone(),
+            });
+        }
+        assignments
+    }
+
+    /// The variant `subject` is in, if the experiment exists and is enabled
+    pub fn assignment(&self, experiment_id: &str, subject: &str) -> Option<Assignment> {
+        let experiments = self.read();
+        let experiment = experiments.get(experiment_id).filter(|e| e.enabled)?;
+        experiment.assign(subject).map(|variant| Assignment {
+            experiment_id: experiment.id.clone(),
+            variant: variant.name.clone(),
+        })
+    }
+
+    /// The variants `subject` is in across the enabled experiments on `engine_id`,
+    /// in id order
+    pub fn assignments_for_engine(&self, engine_id: &str, subject: &str) -> Vec<Assignment> {
+        self.read()
+            .values()
+            .filter(|e| e.enabled && e.engine_id == engine_id)
+            .filter_map(|experiment| {
+                experiment.assign(subject).map(|variant| Assignment {
+                    experiment_id: experiment.id.clone(),
+                    variant: variant.name.clone(),
+                })
+            })
+            .collect()
+    }
+
+    fn read(&self) -> std::sync::RwLockReadGuard<'_, BTreeMap<String, Experiment>> {
+        self.experiments.read().unwrap_or_else(|e| e.into_inner())
+    }
+
+    fn write(&self) -> std::sync::RwLockWriteGuard<'_, BTreeMap<String, Experiment>> {
+        self.experiments.write().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+    use noesis_core::Precision;
+    use serde_json::json;
+
+    fn ayanamsa_experiment(control: u32, treatment: u32) -> Experiment {
+        Experiment {
+            id: "panchanga-ayanamsa".to_string(),
+            engine_id: "panchanga".to_string(),
+            enabled: true,
+            variants: vec![
+                Variant {
+                    name: "control".to_string(),
+                    weight: control,
+                    options: HashMap::new(),
+                },
+                Variant {
+                    name: "krishnamurti".to_string(),
+                    weight: treatment,
+                    options: HashMap::from([("ayanamsa".to_string(), json!("krishnamurti"))]),
+                },
+            ],
+        }
+    }
+
+    fn input() -> EngineInput {
+        EngineInput {
+            birth_data: None,
+            secondary_birth_data: None,
+            subjects: Vec::new(),
+            current_time: Utc::now(),
+            location: None,
+            precision: Precision::Standard,
+            options: HashMap::new(),
+        }
+    }
+
+    #[test]
+    fn test_assignment_is_deterministic_and_weighted() {
+        let experiment = ayanamsa_experiment(1, 3);
+        let first = experiment.assign("user-42").unwrap().name.clone();
+        for _ in 0..10 {
+            assert_eq!(experiment.assign("user-42").unwrap().name, first);
+        }
+
+        let treated = (0..4000)
+            .filter(|i| experiment.assign(&format!("user-{}", i)).unwrap().name == "krishnamurti")
+            .count();
+        assert!((2800..3200).contains(&treated), "treated {} of 4000", treated);
+
+        let all_control = ayanamsa_experiment(1, 0);
+        assert!((0..100).all(|i| all_control.assign(&i.to_string()).unwrap().name == "control"));
+    }
+
+    #[test]
+    fn test_apply_keeps_caller_options() {
+        let registry = ExperimentRegistry::new();
+        registry.upsert(ayanamsa_experiment(0, 1)).unwrap();
+
+        let mut injected = input();
+        let assignments = registry.apply("panchanga", "user-1", &mut injected);
+        assert_eq!(assignments[0].variant, "krishnamurti");
+        assert_eq!(injected.options["ayanamsa"], json!("krishnamurti"));
+
+        let mut explicit = input();
+        explicit.options.insert("ayanamsa".to_string(), json!("raman"));
+        registry.apply("panchanga", "user-1", &mut explicit);
+        assert_eq!(explicit.options["ayanamsa"], json!("raman"));
+
+        assert!(registry.apply("numerology", "user-1", &mut input()).is_empty());
+    }
+
+    #[test]
+    fn test_disabled_experiments_are_skipped() {
+        let registry = ExperimentRegistry::new();
+        let mut experiment = ayanamsa_experiment(0, 1);
+        experiment.enabled = false;
+        registry.upsert(experiment).unwrap();
+
+        let mut input = input();
+        assert!(registry.apply("panchanga", "user-1", &mut input).is_empty());
+        assert!(input.options.is_empty());
+        assert!(registry.assignment("panchanga-ayanamsa", "user-1").is_none());
+        assert!(registry.assignments_for_engine("panchanga", "user-1").is_empty());
+    }
+
+    #[test]
+    fn test_assignments_for_engine() {
+        let registry = ExperimentRegistry::new();
+        registry.upsert(ayanamsa_experiment(0, 1)).unwrap();
+
+        let assignments = registry.assignments_for_engine("panchanga", "user-1");
+        assert_eq!(assignments.len(), 1);
+        assert_eq!(assignments[0], registry.assignment("panchanga-ayanamsa", "user-1").unwrap());
+        assert!(registry.assignments_for_engine("numerology", "user-1").is_empty());
+    }
+
+    #[test]
+    fn test_validate_rejects_bad_experiments() {
+        let mut bad_id = ayanamsa_experiment(1, 1);
+        bad_id.id = "Has Spaces".to_string();
+        assert!(bad_id.validate().is_err());
+
+        let mut duplicate = ayanamsa_experiment(1, 1);
+        duplicate.variants[1].name = "control".to_string();
+        assert!(duplicate.validate().is_err());
+
+        assert!(ayanamsa_experiment(0, 0).validate().is_err());
+        assert!(ExperimentRegistry::new().upsert(ayanamsa_experiment(0, 0)).is_err());
+    }
+}
@@ -8,7 +8,9 @@
 //! The orchestrator has two main components:
 //!
 //! - **`EngineRegistry`** -- stores `Arc<dyn ConsciousnessEngine>` trait objects
-//!   keyed by engine ID and supports phase-gated lookups.
+//!   keyed by engine ID and supports phase-gated lookups. Old ids of renamed
+//!   engines resolve through [`EngineAlias`]es until their sunset (see
+//!   [`aliases`]).
 //!
 //! - **`WorkflowOrchestrator`** -- owns a registry and a set of predefined
 //!   `WorkflowDefinition`s. It executes single engines or entire workflows
@@ -18,6 +20,72 @@
 //! `engine.required_phase() <= user_phase`. Otherwise `EngineError::PhaseAccessDenied`
 //! is returned.
 //!
+//! Workflow durations, engines succeeded per workflow and phase denials are
+//! exported through `noesis-metrics` (`noesis_workflow_*`, `noesis_phase_denied_total`).
+//!
+//! # Experiments
+//!
+//! The `*_for` execution methods take the caller's user id and apply the
+//! [`experiments::ExperimentRegistry`]: variant options are injected into the
+//! engine input and an exposure plus a `success`/`error` outcome is recorded
+//! per experiment. The plain methods never enrol anyone.
+//!
+//! The same methods rotate each output's `witness_prompt` through the
+//! caller's witness prompt pack (see [`noesis_witness::packs`]), after the
+//! result cache, so cached results are rephrased per user and day too.
+//!
+//! # Time travel
+//!
+//! An `options.as_of` timestamp replaces `current_time` before any engine runs
+//! (see [`EngineInput::apply_as_of`]), so a retrospective workflow reads one
+//! instant across biorhythm, panchanga, vedic-clock and vimshottari.
+//!
+//! # Result cache
+//!
+//! With a cache set through [`WorkflowOrchestrator::set_result_cache`], an
+//! engine whose `cache_key` was calculated before is answered from the cache
+//! with `metadata.cached` set, and fresh results are stored for the engine's
+//! `cache_ttl`. [`WorkflowOrchestrator::precompute`] fills the cache ahead of
+//! the first request, e.g. when a user saves their birth profile.
+//!
+//! Every output carries the `engine_version` that calculated it, and the
+//! version is part of the cache key, so bumping an engine's version after a
+//! formula fix stops its old results from being served.
+//!
+//! Whole workflow runs are cached the same way, keyed by workflow id, phase,
+//! input and the versions of the workflow's engines, so a repeated
+//! birth-blueprint for one profile skips its engines and narration entirely
+//! and an engine upgrade misses the runs its old version took part in. See
+//! `workflow_cache_entry` for what the key covers.
+//!
+//! # Output transformers
+//!
+//! A workflow's `transformers` steps run in order over each engine output
+//! before synthesis (see [`transform`]). An output whose pipeline fails is
+//! dropped like a failed engine, so a redaction step never fails open.
+//!
+//! # Phase variants
+//!
+//! A workflow's `phase_variants` are keyed by the lowest phase they apply to;
+//! the caller gets the one with the highest key at or below `user_phase`.
+//! Its options fill gaps in the input before `engine_overrides` do, and its
+//! transformers run after the workflow's own.
+//!
+//! # Synthesis narratives
+//!
+//! Every workflow's `synthesis` carries a `narrative` built from the engine
+//! summaries and a `narrative_source`. A [`SynthesisNarrator`] set with
+//! [`WorkflowOrchestrator::set_narrator`] writes it; otherwise, or when the
+//! narrator fails or times out, the deterministic template does (see
+//! [`narrative`]). The narrator only sees summaries redacted by the `export`
+//! mode of [`WorkflowOrchestrator::set_redaction`].
+//!
+//! # Dry runs
+//!
+//! [`WorkflowOrchestrator::plan_workflow`] reports what a workflow would do
+//! for a caller, engine by engine, without calculating anything (see
+//! [`plan`]).
+//!
 //! # TypeScript Engine Bridge
 //!
 //! The orchestrator can register TypeScript-based engines via `noesis-bridge`.
@@ -27,9 +95,19 @@
 // Workflow module with full spectrum, caching, and synthesis
 pub mod workflow;
 
+pub mod experiments;
+
+pub mod transform;
+
+pub mod narrative;
+
+pub mod aliases;
+
+pub mod plan;
+
 pub use noesis_core::{
-    ConsciousnessEngine, EngineError, EngineInput, EngineOutput,
-    WorkflowDefinition, WorkflowResult,
+    ConsciousnessEngine, EngineError, EngineHealth, EngineInput, EngineOutput,
+    PhaseVariant, WorkflowDefinition, WorkflowResult,
 };
 
 // Re-export workflow types
@@ -39,12 +117,14 @@ pub use workflow::{
     // New workflow infrastructure
     WorkflowExecutor, WorkflowRegistry, WorkflowOutput,
     Theme, ExtAlignment as Alignment, ExtTension as Tension,
-    WitnessPrompt, InquiryType, TemporalWindow, SynthesisType,
+    WitnessPrompt, InquiryType, TemporalWindow, SynthesisType, RelationshipWorkflow,
+    VocationBlueprintWorkflow, VocationProfile,
 };
 pub use workflow::models::SynthesisResult;
 pub use workflow::synthesis::{
     CrossEngineTheme, FullSpectrumSynthesizer, ThemeCategory,
-    BirthBlueprintSynthesizer, DailyPracticeSynthesizer, Synthesizer,
+    BirthBlueprintSynthesizer, DailyPracticeSynthesizer, RelationshipSynthesizer, Synthesizer,
+    VocationBlueprintSynthesizer,
 };
 
 // Re-export bridge types for convenience
@@ -53,12 +133,34 @@ pub use noesis_bridge::{BridgeEngine, BridgeManager, DEFAULT_TS_SERVER_URL};
 // Re-export engine types for convenience
 pub use engine_biofield::BiofieldEngine;
 
+pub use aliases::EngineAlias;
+pub use experiments::{Experiment, ExperimentRegistry, Variant};
+pub use transform::{OutputTransformer, TransformerRegistry};
+pub use plan::{PlanStatus, PlannedEngine, WorkflowPlan};
+pub use narrative::{NarrativeRequest, NarrativeSource, SynthesisNarrator};
+#[cfg(feature = "llm-synthesis")]
+pub use narrative::{LlmConfig, LlmNarrator};
+
 use chrono::Utc;
 use futures::future::join_all;
-use std::collections::HashMap;
-use std::sync::Arc;
-use std::time::Instant;
-use tracing::{info, warn, instrument};
+use noesis_bridge::wire::CONSCIOUSNESS_LEVEL_OPTION;
+use noesis_cache::{CacheKey, CacheManager};
+use noesis_core::birth_time::BirthTimeReport;
+use noesis_core::options::unknown_option_warnings;
+use noesis_core::redaction::{RedactionPolicies, RedactionTarget};
+use noesis_core::{CacheTtl, ResourceUsage, TimeSensitivity, ValidationCode, ValidationError, WorkflowUsage};
+use noesis_witness::PromptPacks;
+use sha2::{Digest, Sha256};
+use std::collections::{BTreeMap, HashMap};
+use std::sync::{Arc, RwLock, RwLockReadGuard, RwLockWriteGuard};
+use std::time::{Duration, Instant};
+use tracing::{debug, info, warn, instrument};
+
+/// Longest an engine's health check may take before it counts as failed
+pub const ENGINE_HEALTH_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// Highest consciousness phase a workflow variant can target
+pub const MAX_CONSCIOUSNESS_PHASE: u8 = 5;
 
 // ---------------------------------------------------------------------------
 // EngineRegistry
@@ -70,6 +172,7 @@ use tracing::{info, warn, instrument};
 /// concurrent workflow executions without cloning.
 pub struct EngineRegistry {
     engines: HashMap<String, Arc<dyn ConsciousnessEngine>>,
+    aliases: HashMap<String, EngineAlias>,
 }
 
 impl EngineRegistry {
@@ -77,6 +180,7 @@ impl EngineRegistry {
     pub fn new() -> Self {
         Self {
             engines: HashMap::new(),
+            aliases: HashMap::new(),
         }
     }
 
@@ -88,9 +192,53 @@ impl EngineRegistry {
         self.engines.insert(id, engine);
     }
 
-    /// Retrieve an engine by ID.
+    /// Keep `alias.alias` resolving to `alias.engine_id` until its sunset.
+    /// A registered engine of the same id takes precedence over the alias.
+    pub fn register_alias(&mut self, alias: EngineAlias) {
+        info!(alias = %alias.alias, engine_id = %alias.engine_id, "Registering engine alias");
+        self.aliases.insert(alias.alias.clone(), alias);
+    }
+
+    /// Retrieve an engine by ID, or by an alias that has not reached its sunset.
     pub fn get(&self, engine_id: &str) -> Option<Arc<dyn ConsciousnessEngine>> {
-        self.engines.get(engine_id).cloned()
+        self.resolve(engine_id).ok().map(|(engine, _)| engine)
+    }
+
+    /// The engine `engine_id` names, with the alias it was reached through.
+    ///
+    /// Fails with `EngineRetired` for an alias past its sunset and
+    /// `EngineNotFound` for anything else unknown.
+    pub fn resolve(
+        &self,
+        engine_id: &str,
+    ) -> Result<(Arc<dyn ConsciousnessEngine>, Option<&EngineAlias>), EngineError> {
+        if let Some(engine) = self.engines.get(engine_id) {
+            return Ok((engine.clone(), None));
+        }
+        let alias = self
+            .aliases
+            .get(engine_id)
+            .ok_or_else(|| EngineError::EngineNotFound(engine_id.to_string()))?;
+        if alias.is_sunset(Utc::now()) {
+            return Err(alias.retired_error());
+        }
+        let engine = self
+            .engines
+            .get(&alias.engine_id)
+            .ok_or_else(|| EngineError::EngineNotFound(engine_id.to_string()))?;
+        Ok((engine.clone(), Some(alias)))
+    }
+
+    /// The alias declared for `engine_id`, if it names no registered engine.
+    pub fn alias(&self, engine_id: &str) -> Option<&EngineAlias> {
+        self.aliases.get(engine_id).filter(|_| !self.engines.contains_key(engine_id))
+    }
+
+    /// Every declared alias, sorted by old id.
+    pub fn aliases(&self) -> Vec<&EngineAlias> {
+        let mut aliases: Vec<&EngineAlias> = self.aliases.values().collect();
+        aliases.sort_by(|a, b| a.alias.cmp(&b.alias));
+        aliases
     }
 
     /// List all registered engine IDs (sorted for deterministic output).
@@ -135,21 +283,41 @@ impl Default for EngineRegistry {
 
 /// Top-level orchestrator that coordinates multi-engine workflow execution.
 ///
-/// Holds a registry of engines and a map of predefined workflow definitions.
-/// Workflows execute all their constituent engines concurrently using
+/// Holds a registry of engines and a map of workflow definitions: the
+/// canonical ones plus custom workflows added at startup or through the admin
+/// API. Workflows execute all their constituent engines concurrently using
 /// `futures::future::join_all`.
 pub struct WorkflowOrchestrator {
     registry: EngineRegistry,
-    workflows: HashMap<String, WorkflowDefinition>,
+    workflows: RwLock<HashMap<String, WorkflowDefinition>>,
+    /// Engines whose failing health check makes the orchestrator not ready
+    critical_engines: Vec<String>,
+    experiments: Arc<ExperimentRegistry>,
+    /// Witness prompt packs callers rotate through
+    witness_packs: Arc<PromptPacks>,
+    transformers: TransformerRegistry,
+    /// Writes synthesis narratives, with the longest it may take
+    narrator: Option<(Arc<dyn SynthesisNarrator>, Duration)>,
+    /// Serves repeated engine results; see [`set_result_cache`](Self::set_result_cache)
+    result_cache: Option<Arc<CacheManager>>,
+    /// Personal data removed from logged outputs and narrator requests
+    redaction: RedactionPolicies,
 }
 
 impl WorkflowOrchestrator {
-    /// Create a new orchestrator pre-loaded with the 6 canonical workflows.
+    /// Create a new orchestrator pre-loaded with the 8 canonical workflows.
     pub fn new() -> Self {
         let workflows = Self::default_workflows();
         Self {
             registry: EngineRegistry::new(),
-            workflows,
+            workflows: RwLock::new(workflows),
+            critical_engines: Vec::new(),
+            experiments: Arc::new(ExperimentRegistry::new()),
+            witness_packs: Arc::new(PromptPacks::new()),
+            transformers: TransformerRegistry::new(),
+            narrator: None,
+            result_cache: None,
+            redaction: RedactionPolicies::default(),
         }
     }
 
@@ -158,10 +326,258 @@ impl WorkflowOrchestrator {
         self.registry.register(engine);
     }
 
+    /// Keep a renamed engine's old id working until the alias's sunset.
+    pub fn register_alias(&mut self, alias: EngineAlias) {
+        self.registry.register_alias(alias);
+    }
+
+    /// Set the engines that [`is_ready`](Self::is_ready) requires to be healthy.
+    pub fn set_critical_engines<I, S>(&mut self, engine_ids: I)
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        let mut ids: Vec<String> = engine_ids.into_iter().map(Into::into).collect();
+        ids.sort();
+        ids.dedup();
+        self.critical_engines = ids;
+    }
+
+    /// Engines whose health gates readiness.
+    pub fn critical_engines(&self) -> &[String] {
+        &self.critical_engines
+    }
+
+    /// Live A/B experiments, shared with the admin API.
+    pub fn experiments(&self) -> &Arc<ExperimentRegistry> {
+        &self.experiments
+    }
+
+    /// Witness prompt packs, shared with the admin API.
+    pub fn witness_packs(&self) -> &Arc<PromptPacks> {
+        &self.witness_packs
+    }
+
+    /// Make an output transformer available to workflows.
+    pub fn register_transformer(&mut self, transformer: Arc<dyn OutputTransformer>) {
+        info!(transformer_id = %transformer.id(), "Registering output transformer");
+        self.transformers.register(transformer);
+    }
+
+    /// Output transformers workflows can reference.
+    pub fn transformers(&self) -> &TransformerRegistry {
+        &self.transformers
+    }
+
+    /// Have `narrator` write synthesis narratives, falling back to the
+    /// template when it errors or takes longer than `timeout`.
+    pub fn set_narrator(&mut self, narrator: Arc<dyn SynthesisNarrator>, timeout: Duration) {
+        info!(timeout_ms = timeout.as_millis() as u64, "Synthesis narrator enabled");
+        self.narrator = Some((narrator, timeout));
+    }
+
+    /// Serve engine results from `cache` when the engine's cache key was
+    /// calculated before, and store every fresh result for its
+    /// [`cache_ttl`](ConsciousnessEngine::cache_ttl).
+    pub fn set_result_cache(&mut self, cache: Arc<CacheManager>) {
+        self.result_cache = Some(cache);
+    }
+
+    /// Redact engine outputs with `policies` before they are logged or sent
+    /// to the narrator (its `export` mode).
+    pub fn set_redaction(&mut self, policies: RedactionPolicies) {
+        self.redaction = policies;
+    }
+
+    /// Redaction policies of this deployment, also applied to shared results.
+    pub fn redaction(&self) -> &RedactionPolicies {
+        &self.redaction
+    }
+
+    /// Log `output` at debug level, redacted for logging
+    fn log_output(&self, engine_id: &str, output: &EngineOutput, input: Option<&EngineInput>) {
+        if tracing::enabled!(tracing::Level::DEBUG) {
+            debug!(engine_id, output = %self.redaction.loggable(output, input), "Engine output");
+        }
+    }
+
+    /// Write the narrative for a workflow run, and say who wrote it.
+    ///
+    /// The narrator may be an outside service, so it gets the summaries
+    /// redacted for export; the template runs locally on the originals.
+    async fn narrate(&self, request: &NarrativeRequest, input: &EngineInput) -> (String, NarrativeSource) {
+        if let (Some((narrator, timeout)), false) = (&self.narrator, request.summaries.is_empty()) {
+            let mut redacted = request.clone();
+            for entry in &mut redacted.summaries {
+                self.redaction
+                    .redact_text(&mut entry.summary, RedactionTarget::Export, Some(input));
+            }
+            match tokio::time::timeout(*timeout, narrator.narrate(&redacted)).await {
+                Ok(Ok(narrative)) => return (narrative, NarrativeSource::Narrator),
+                Ok(Err(e)) => {
+                    warn!(workflow_id = %request.workflow_id, error = %e, "Narrator failed, using template");
+                }
+                Err(_) => {
+                    warn!(workflow_id = %request.workflow_id, "Narrator timed out, using template");
+                }
+            }
+        }
+        (narrative::template_narrative(request), NarrativeSource::Template)
+    }
+
     /// Register a custom workflow definition.
     pub fn register_workflow(&mut self, workflow: WorkflowDefinition) {
         info!(workflow_id = %workflow.id, "Registering workflow");
-        self.workflows.insert(workflow.id.clone(), workflow);
+        self.workflows
+            .get_mut()
+            .unwrap_or_else(|e| e.into_inner())
+            .insert(workflow.id.clone(), workflow);
+    }
+
+    /// Add or replace a custom workflow at runtime; returns the one it replaced.
+    ///
+    /// Canonical workflows cannot be replaced. Every engine must be registered,
+    /// each `engine_overrides` entry must be an object of options for one of
+    /// the workflow's engines, and every transformer step must name a
+    /// registered transformer with a config it accepts.
+    pub fn put_custom_workflow(
+        &self,
+        workflow: WorkflowDefinition,
+    ) -> Result<Option<WorkflowDefinition>, EngineError> {
+        self.validate_custom_workflow(&workflow)?;
+        info!(workflow_id = %workflow.id, "Registering custom workflow");
+        Ok(self.write_workflows().insert(workflow.id.clone(), workflow))
+    }
+
+    /// Remove a custom workflow; canonical workflows cannot be removed.
+    pub fn remove_custom_workflow(&self, workflow_id: &str) -> Result<Option<WorkflowDefinition>, EngineError> {
+        if Self::is_canonical_workflow(workflow_id) {
+            return Err(EngineError::ValidationError(format!(
+                "'{}' is a built-in workflow and cannot be removed",
+                workflow_id
+            )));
+        }
+        Ok(self.write_workflows().remove(workflow_id))
+    }
+
+    fn is_canonical_workflow(workflow_id: &str) -> bool {
+        Self::default_workflows().contains_key(workflow_id)
+    }
+
+    fn validate_custom_workflow(&self, workflow: &WorkflowDefinition) -> Result<(), EngineError> {
+        let mut errors = Vec::new();
+        if !experiments::is_valid_label(&workflow.id) {
+            errors.push(ValidationError::new(
+                "id",
+                ValidationCode::InvalidFormat,
+                format!(
+                    "Workflow id must be 1-{} characters of a-z, 0-9, '_' or '-'",
+                    experiments::MAX_LABEL_LEN
+                ),
+            ));
+        } else if Self::is_canonical_workflow(&workflow.id) {
+            errors.push(ValidationError::new(
+                "id",
+                ValidationCode::Duplicate,
+                format!("'{}' is a built-in workflow and cannot be replaced", workflow.id),
+            ));
+        }
+        if workflow.name.trim().is_empty() {
+            errors.push(ValidationError::new("name", ValidationCode::Empty, "Workflow name must not be empty"));
+        }
+        if workflow.engine_ids.is_empty() {
+            errors.push(ValidationError::new(
+                "engine_ids",
+                ValidationCode::Required,
+                "A workflow needs at least one engine",
+            ));
+        }
+        for (i, engine_id) in workflow.engine_ids.iter().enumerate() {
+            let field = format!("engine_ids[{}]", i);
+            if workflow.engine_ids[..i].contains(engine_id) {
+                errors.push(ValidationError::new(
+                    field,
+                    ValidationCode::Duplicate,
+                    format!("Engine '{}' appears more than once", engine_id),
+                ));
+            } else if self.registry.get(engine_id).is_none() {
+                errors.push(ValidationError::new(
+                    field,
+                    ValidationCode::Unknown,
+                    format!("Engine '{}' is not registered", engine_id),
+                ));
+            }
+        }
+        let mut override_ids: Vec<&String> = workflow.engine_overrides.keys().collect();
+        override_ids.sort();
+        for engine_id in override_ids {
+            let field = format!("engine_overrides.{}", engine_id);
+            if !workflow.engine_ids.contains(engine_id) {
+                errors.push(ValidationError::new(
+                    field,
+                    ValidationCode::Unknown,
+                    format!("Engine '{}' is not part of this workflow", engine_id),
+                ));
+            } else if !workflow.engine_overrides[engine_id].is_object() {
+                errors.push(ValidationError::new(
+                    field,
+                    ValidationCode::InvalidFormat,
+                    "Engine overrides must be an object of options",
+                ));
+            }
+        }
+        self.validate_transformer_steps("transformers", &workflow.transformers, &mut errors);
+        for (phase, variant) in &workflow.phase_variants {
+            let field = format!("phase_variants.{}", phase);
+            if *phase > MAX_CONSCIOUSNESS_PHASE {
+                errors.push(ValidationError::new(
+                    field.as_str(),
+                    ValidationCode::OutOfRange,
+                    format!("Phases run from 0 to {}", MAX_CONSCIOUSNESS_PHASE),
+                ));
+            }
+            self.validate_transformer_steps(&format!("{}.transformers", field), &variant.transformers, &mut errors);
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(EngineError::InvalidInput(errors))
+        }
+    }
+
+    fn validate_transformer_steps(
+        &self,
+        field: &str,
+        steps: &[noesis_core::TransformerStep],
+        errors: &mut Vec<ValidationError>,
+    ) {
+        for (i, step) in steps.iter().enumerate() {
+            match self.transformers.get(&step.id) {
+                None => errors.push(ValidationError::new(
+                    format!("{}[{}].id", field, i),
+                    ValidationCode::Unknown,
+                    format!("Transformer '{}' is not registered", step.id),
+                )),
+                Some(transformer) => {
+                    if let Err(message) = transformer.validate_config(&step.config) {
+                        errors.push(ValidationError::new(
+                            format!("{}[{}].config", field, i),
+                            ValidationCode::InvalidFormat,
+                            message,
+                        ));
+                    }
+                }
+            }
+        }
+    }
+
+    fn read_workflows(&self) -> RwLockReadGuard<'_, HashMap<String, WorkflowDefinition>> {
+        self.workflows.read().unwrap_or_else(|e| e.into_inner())
+    }
+
+    fn write_workflows(&self) -> RwLockWriteGuard<'_, HashMap<String, WorkflowDefinition>> {
+        self.workflows.write().unwrap_or_else(|e| e.into_inner())
     }
 
     // -- Bridge engine registration ----------------------------------------
@@ -213,17 +629,42 @@ impl WorkflowOrchestrator {
     ///
     /// Phase gating is enforced: if the engine requires a higher phase than
     /// `user_phase`, `EngineError::PhaseAccessDenied` is returned.
-    #[instrument(skip(self, input), fields(engine_id = %engine_id, user_phase))]
     pub async fn execute_engine(
         &self,
         engine_id: &str,
         input: EngineInput,
         user_phase: u8,
     ) -> Result<EngineOutput, EngineError> {
-        let engine = self
-            .registry
-            .get(engine_id)
-            .ok_or_else(|| EngineError::EngineNotFound(engine_id.to_string()))?;
+        self.execute_engine_as(engine_id, input, user_phase, None).await
+    }
+
+    /// Execute a single engine for a user, applying any experiments on it.
+    pub async fn execute_engine_for(
+        &self,
+        engine_id: &str,
+        input: EngineInput,
+        user_phase: u8,
+        user_id: &str,
+    ) -> Result<EngineOutput, EngineError> {
+        self.execute_engine_as(engine_id, input, user_phase, Some(user_id)).await
+    }
+
+    #[instrument(skip(self, input, subject), fields(engine_id = %engine_id, user_phase))]
+    async fn execute_engine_as(
+        &self,
+        engine_id: &str,
+        mut input: EngineInput,
+        user_phase: u8,
+        subject: Option<&str>,
+    ) -> Result<EngineOutput, EngineError> {
+        let (engine, alias) = self.registry.resolve(engine_id)?;
+        let deprecation = alias.map(|alias| {
+            warn!(alias = %alias.alias, engine_id = %alias.engine_id, "Engine requested by deprecated alias");
+            noesis_metrics::record_engine_alias_request(&alias.alias);
+            alias.warning()
+        });
+        let engine_id = engine.engine_id();
+        input.apply_as_of()?;
 
         // Phase gate
         let required = engine.required_phase();
@@ -234,6 +675,7 @@ impl WorkflowOrchestrator {
                 user_phase,
                 "Phase access denied"
             );
+            noesis_metrics::record_phase_denied(engine_id);
             return Err(EngineError::PhaseAccessDenied {
                 required,
                 current: user_phase,
@@ -241,7 +683,166 @@ impl WorkflowOrchestrator {
         }
 
         info!(engine_id, "Executing engine");
-        engine.calculate(input).await
+        let mut output = self
+            .calculate_with_experiments(engine.as_ref(), engine_id, input, subject, OptionScope::Engine)
+            .await?;
+        output.metadata.warnings.extend(deprecation);
+        Ok(output)
+    }
+
+    /// Run `engine`, injecting variant options and recording exposures and
+    /// outcomes when a `subject` is given.
+    ///
+    /// `options.settings` is first resolved against the engine's option keys
+    /// (see [`EngineInput::apply_settings`]) and birth longitudes are wrapped
+    /// into [-180, 180]. Option keys the engine ignores, unless `scope` makes
+    /// them known, and coordinates that look swapped, are listed in
+    /// `metadata.warnings`. They are added after the result cache, since the
+    /// engines' cache keys leave ignored keys out. The witness prompt is then
+    /// rotated through the subject's prompt pack for the day of `current_time`.
+    async fn calculate_with_experiments(
+        &self,
+        engine: &dyn ConsciousnessEngine,
+        engine_id: &str,
+        mut input: EngineInput,
+        subject: Option<&str>,
+        scope: OptionScope<'_>,
+    ) -> Result<EngineOutput, EngineError> {
+        prepare_input(engine, &mut input);
+        let mut warnings = option_warnings(engine, engine_id, &input, scope);
+        warnings.extend(input.primary_birth_data().and_then(|birth| birth.swap_warning()));
+        let logged_input = tracing::enabled!(tracing::Level::DEBUG).then(|| input.clone());
+        let day = input.current_time.date_naive();
+        let mut output = self.calculate_enrolled(engine, engine_id, input, subject).await?;
+        output.metadata.warnings.extend(warnings);
+        if let Some(subject) = subject {
+            self.rotate_witness_prompt(&mut output, day, subject);
+        }
+        self.log_output(engine_id, &output, logged_input.as_ref());
+        Ok(output)
+    }
+
+    /// [`calculate_with_experiments`](Self::calculate_with_experiments)
+    /// before the option warnings are added
+    async fn calculate_enrolled(
+        &self,
+        engine: &dyn ConsciousnessEngine,
+        engine_id: &str,
+        mut input: EngineInput,
+        subject: Option<&str>,
+    ) -> Result<EngineOutput, EngineError> {
+        let Some(subject) = subject else {
+            return self.calculate_cached(engine, input).await;
+        };
+
+        let assignments = self.experiments.apply(engine_id, subject, &mut input);
+        if assignments.is_empty() {
+            return self.calculate_cached(engine, input).await;
+        }
+        for assignment in &assignments {
+            debug!(
+                engine_id,
+                experiment = %assignment.experiment_id,
+                variant = %assignment.variant,
+                "Experiment variant applied"
+            );
+            noesis_metrics::record_experiment_exposure(&assignment.experiment_id, &assignment.variant);
+        }
+
+        let result = calculate_versioned(engine, input).await;
+        let outcome = if result.is_ok() { "success" } else { "error" };
+        for assignment in &assignments {
+            noesis_metrics::record_experiment_outcome(&assignment.experiment_id, &assignment.variant, outcome);
+        }
+        result
+    }
+
+    /// Rephrase `output.witness_prompt` with the template `subject`'s pack
+    /// rotates to on `day`, recording the pack as a `witness-pack` exposure.
+    fn rotate_witness_prompt(&self, output: &mut EngineOutput, day: chrono::NaiveDate, subject: &str) {
+        let rotated = self.witness_packs.rotate(
+            &output.engine_id,
+            output.consciousness_level,
+            day,
+            Some(subject),
+            &output.witness_prompt,
+        );
+        if let Some((pack, prompt)) = rotated {
+            noesis_metrics::record_experiment_exposure(noesis_witness::packs::EXPERIMENT_ID, &pack);
+            output.witness_prompt = prompt;
+        }
+    }
+
+    /// Run `engine` through the result cache, if one is set.
+    ///
+    /// Inputs that aren't [cacheable](EngineInput::is_cacheable) always
+    /// calculate, as do subjects enrolled in an experiment on the engine, so a cached
+    /// result never stands in for a variant. Cache failures only cost the
+    /// hit.
+    async fn calculate_cached(
+        &self,
+        engine: &dyn ConsciousnessEngine,
+        input: EngineInput,
+    ) -> Result<EngineOutput, EngineError> {
+        let Some(cache) = self.result_cache.as_ref().filter(|_| input.is_cacheable()) else {
+            return calculate_versioned(engine, input).await;
+        };
+
+        let key = result_cache_key(engine, &input);
+        match cache.get_with_layer(&key).await {
+            Ok(Some((value, layer))) => match serde_json::from_value::<EngineOutput>(value) {
+                Ok(mut output) => {
+                    debug!(engine_id = %engine.engine_id(), layer, "Serving cached engine result");
+                    output.metadata.cached = true;
+                    output.metadata.usage = Some(ResourceUsage::cached(layer));
+                    return Ok(output);
+                }
+                Err(e) => warn!(engine_id = %engine.engine_id(), error = %e, "Discarding unreadable cached result"),
+            },
+            Ok(None) => {}
+            Err(e) => warn!(engine_id = %engine.engine_id(), error = %e, "Result cache lookup failed"),
+        }
+
+        let ttl = engine.cache_ttl(&input);
+        let output = calculate_versioned(engine, input).await?;
+        match serde_json::to_value(&output) {
+            Ok(value) => {
+                if let Err(e) = cache.store_with_ttl(&key, &value, ttl).await {
+                    warn!(engine_id = %engine.engine_id(), error = %e, "Storing engine result failed");
+                }
+            }
+            Err(e) => warn!(engine_id = %engine.engine_id(), error = %e, "Engine result is not cacheable"),
+        }
+        Ok(output)
+    }
+
+    /// Calculate `engine_id` for `input` at its `current_time`, ahead of any
+    /// request, and keep the result in the result cache.
+    ///
+    /// Results with [`CacheTtl::Infinite`] are written to every layer,
+    /// including the persistent L3, so they survive restarts; the rest are
+    /// kept for their TTL. No phase gate or experiment applies: nothing is
+    /// returned to a caller, and later requests are still gated before the
+    /// cache is read. Fails with `ConfigError` without a result cache.
+    #[instrument(skip(self, input), fields(engine_id = %engine_id))]
+    pub async fn precompute(&self, engine_id: &str, input: EngineInput) -> Result<CacheTtl, EngineError> {
+        let cache = self
+            .result_cache
+            .as_ref()
+            .ok_or_else(|| EngineError::ConfigError("No result cache to precompute into".to_string()))?;
+        let (engine, _) = self.registry.resolve(engine_id)?;
+
+        let key = result_cache_key(engine.as_ref(), &input);
+        let ttl = engine.cache_ttl(&input);
+        let output = calculate_versioned(engine.as_ref(), input).await?;
+        let value = serde_json::to_value(&output)
+            .map_err(|e| EngineError::CacheError(format!("Engine result is not cacheable: {}", e)))?;
+        if ttl == CacheTtl::Infinite {
+            cache.store_all_layers(&key, &value).await?;
+        } else {
+            cache.store_with_ttl(&key, &value, ttl).await?;
+        }
+        Ok(ttl)
     }
 
     // -- Workflow execution ------------------------------------------------
@@ -251,17 +852,39 @@ impl WorkflowOrchestrator {
     /// Each engine in the workflow runs concurrently. If an individual engine
     /// fails or is phase-gated, its error is logged but the overall workflow
     /// still succeeds -- the failed engine is simply omitted from the results.
-    #[instrument(skip(self, input), fields(workflow_id = %workflow_id, user_phase))]
     pub async fn execute_workflow(
         &self,
         workflow_id: &str,
         input: EngineInput,
         user_phase: u8,
+    ) -> Result<WorkflowResult, EngineError> {
+        self.execute_workflow_as(workflow_id, input, user_phase, None).await
+    }
+
+    /// Execute a workflow for a user, applying experiments on each engine.
+    pub async fn execute_workflow_for(
+        &self,
+        workflow_id: &str,
+        input: EngineInput,
+        user_phase: u8,
+        user_id: &str,
+    ) -> Result<WorkflowResult, EngineError> {
+        self.execute_workflow_as(workflow_id, input, user_phase, Some(user_id)).await
+    }
+
+    #[instrument(skip(self, input, subject), fields(workflow_id = %workflow_id, user_phase))]
+    async fn execute_workflow_as(
+        &self,
+        workflow_id: &str,
+        mut input: EngineInput,
+        user_phase: u8,
+        subject: Option<&str>,
     ) -> Result<WorkflowResult, EngineError> {
         let workflow = self
-            .workflows
-            .get(workflow_id)
+            .get_workflow(workflow_id)
             .ok_or_else(|| EngineError::WorkflowNotFound(workflow_id.to_string()))?;
+        // Resolved once so every engine reads the same reference time
+        input.apply_as_of()?;
 
         info!(
             workflow_id,
@@ -269,29 +892,281 @@ impl WorkflowOrchestrator {
             "Starting workflow execution"
         );
 
+        let secondary_input = if workflow_id == RelationshipWorkflow::ID {
+            Some(RelationshipWorkflow::secondary_input(&input)?)
+        } else {
+            None
+        };
+
+        // The phase variant's options sit under the caller's and above the
+        // workflow's per-engine overrides
+        let variant = workflow.phase_variant(user_phase);
+        if let Some((variant_phase, variant)) = variant {
+            debug!(variant_phase, "Applying phase variant");
+            for (key, value) in &variant.options {
+                input.options.entry(key.clone()).or_insert_with(|| value.clone());
+            }
+        }
+        let variant_transformers = variant.map_or(&[][..], |(_, v)| v.transformers.as_slice());
+
         let start = Instant::now();
 
-        // Build futures for all engines in the workflow.
-        let futures: Vec<_> = workflow
-            .engine_ids
-            .iter()
+        let cached = self.workflow_cache_entry(&workflow, &input, secondary_input.as_ref(), user_phase, subject);
+        if let Some((cache, key, _)) = &cached {
+            if let Some(result) = self.cached_workflow(cache, key, workflow_id, start).await {
+                return Ok(result);
+            }
+        }
+
+        let mut results = self
+            .run_workflow_engines(workflow.engine_ids.iter(), &workflow, &input, user_phase, subject)
+            .await;
+
+        // Second person's pass for engines that only read `birth_data`
+        if let Some(secondary_input) = &secondary_input {
+            let single_person_ids = workflow.engine_ids.iter().filter(|eid| {
+                self.registry
+                    .get(eid)
+                    .is_some_and(|engine| !engine.supports_compatibility())
+            });
+            let secondary = self
+                .run_workflow_engines(single_person_ids, &workflow, secondary_input, user_phase, subject)
+                .await;
+            results.extend(
+                secondary
+                    .into_iter()
+                    .map(|(eid, result)| (RelationshipWorkflow::secondary_key(&eid), result)),
+            );
+        }
+
+        // Collect successful outputs through the transformer pipeline; log failures.
+        // A run missing an engine for any reason but the phase gate isn't cached.
+        let mut engine_outputs = HashMap::new();
+        let mut complete = true;
+        for (eid, result) in results {
+            match result {
+                Ok(mut output) => {
+                    let applied = self
+                        .transformers
+                        .apply(&workflow.transformers, &mut output)
+                        .and_then(|_| self.transformers.apply(variant_transformers, &mut output));
+                    if let Err(e) = applied {
+                        warn!(engine_id = %eid, error = %e, "Output transformer failed, omitting from results");
+                        complete = false;
+                        continue;
+                    }
+                    info!(engine_id = %eid, "Engine completed successfully");
+                    engine_outputs.insert(eid, output);
+                }
+                Err(e) => {
+                    warn!(engine_id = %eid, error = %e, "Engine failed, omitting from results");
+                    complete &= matches!(e, EngineError::PhaseAccessDenied { .. });
+                }
+            }
+        }
+
+        let mut synthesis = match secondary_input {
+            Some(_) => serde_json::to_value(RelationshipSynthesizer::synthesize(&engine_outputs, &input)).ok(),
+            None if workflow_id == VocationBlueprintWorkflow::ID => {
+                VocationBlueprintSynthesizer::report(&engine_outputs, &input)
+            }
+            None => None, // Structured synthesis for the other workflows is a future enhancement
+        };
+        let mut usage = WorkflowUsage::default();
+        for output in engine_outputs.values() {
+            if let Some(engine_usage) = &output.metadata.usage {
+                usage.add_engine(engine_usage);
+            }
+        }
+        let ((narrative, source), narration_usage) = noesis_core::usage::measure(
+            self.narrate(&NarrativeRequest::from_outputs(&workflow, &engine_outputs), &input),
+        )
+        .await;
+        usage.add(&narration_usage);
+        let synthesis_map = match synthesis.get_or_insert_with(|| serde_json::json!({})) {
+            serde_json::Value::Object(map) => Some(map),
+            _ => None,
+        };
+        if let Some(map) = synthesis_map {
+            map.insert("narrative".into(), narrative.into());
+            map.insert("narrative_source".into(), source.as_str().into());
+        }
+
+        let elapsed = start.elapsed().as_secs_f64();
+        let total_time_ms = elapsed * 1000.0;
+        noesis_metrics::record_workflow_execution(workflow_id, engine_outputs.len(), elapsed);
+
+        info!(
+            workflow_id,
+            engines_succeeded = engine_outputs.len(),
+            total_time_ms,
+            "Workflow execution complete"
+        );
+
+        let time_confidence = BirthTimeReport::for_outputs(&input, &engine_outputs, |key| {
+            let engine_id = key.trim_end_matches(RelationshipWorkflow::SECONDARY_SUFFIX);
+            self.registry
+                .get(engine_id)
+                .map_or(TimeSensitivity::Insensitive, |engine| engine.birth_time_sensitivity())
+        });
+
+        let result = WorkflowResult {
+            workflow_id: workflow_id.to_string(),
+            engine_outputs,
+            synthesis,
+            total_time_ms,
+            timestamp: Utc::now(),
+            usage: Some(usage),
+            time_confidence,
+        };
+        if let (Some((cache, key, ttl)), true) = (cached, complete) {
+            match serde_json::to_value(&result) {
+                Ok(value) => {
+                    if let Err(e) = cache.store_with_ttl(&key, &value, ttl).await {
+                        warn!(workflow_id, error = %e, "Storing workflow result failed");
+                    }
+                }
+                Err(e) => warn!(workflow_id, error = %e, "Workflow result is not cacheable"),
+            }
+        }
+        Ok(result)
+    }
+
+    /// Result cache, key and TTL of a workflow run, or `None` when it
+    /// always runs: without a result cache, for an input that isn't
+    /// [cacheable](EngineInput::is_cacheable), and for a subject enrolled in
+    /// an experiment on one of its engines.
+    ///
+    /// The key combines the workflow id, the phase, an input hash and the
+    /// engine versions. The input hash covers the workflow definition and
+    /// each engine's own result cache key for the input it gets, so a run is
+    /// reused exactly while all of its engine results would be, and lasts as
+    /// long as the shortest-lived of them. Witness prompts rotate per subject
+    /// and day, so those are hashed too when there is a subject.
+    fn workflow_cache_entry(
+        &self,
+        workflow: &WorkflowDefinition,
+        input: &EngineInput,
+        secondary_input: Option<&EngineInput>,
+        user_phase: u8,
+        subject: Option<&str>,
+    ) -> Option<(Arc<CacheManager>, CacheKey, CacheTtl)> {
+        let cache = self.result_cache.as_ref().filter(|_| input.is_cacheable())?;
+        let now = Utc::now();
+        let mut hasher = Sha256::new();
+        // Through `Value`, whose maps are sorted, so every instance hashes alike
+        hasher.update(serde_json::to_value(workflow).ok()?.to_string());
+        if let Some(subject) = subject {
+            hasher.update(format!("\0{}\0{}", subject, input.current_time.date_naive()));
+        }
+
+        let mut versions = Vec::new();
+        let mut ttl = CacheTtl::Infinite;
+        for eid in &workflow.engine_ids {
+            let Ok((engine, _)) = self.registry.resolve(eid) else {
+                versions.push(format!("{}=none", eid));
+                continue;
+            };
+            if subject.is_some_and(|s| !self.experiments.assignments_for_engine(engine.engine_id(), s).is_empty()) {
+                return None;
+            }
+            versions.push(format!("{}={}", eid, engine.engine_version()));
+            let secondary = secondary_input.filter(|_| !engine.supports_compatibility());
+            for input in std::iter::once(input).chain(secondary) {
+                let mut engine_input = input.clone();
+                workflow.apply_engine_overrides(eid, &mut engine_input);
+                prepare_input(engine.as_ref(), &mut engine_input);
+                hasher.update(format!("\0{}", result_cache_key(engine.as_ref(), &engine_input).raw));
+                ttl = ttl.shorter(engine.cache_ttl(&engine_input), now);
+            }
+        }
+
+        let key = CacheKey::new(format!(
+            "workflow:{}:p{}:{:x}:{}",
+            workflow.id,
+            user_phase,
+            hasher.finalize(),
+            versions.join(",")
+        ));
+        Some((cache.clone(), key, ttl))
+    }
+
+    /// A run of `workflow_id` stored under `key`, its engine outputs marked as
+    /// served from the layer that had it. Cache failures only cost the hit.
+    async fn cached_workflow(
+        &self,
+        cache: &CacheManager,
+        key: &CacheKey,
+        workflow_id: &str,
+        start: Instant,
+    ) -> Option<WorkflowResult> {
+        let (value, layer) = match cache.get_with_layer(key).await {
+            Ok(Some(found)) => found,
+            Ok(None) => return None,
+            Err(e) => {
+                warn!(workflow_id, error = %e, "Workflow cache lookup failed");
+                return None;
+            }
+        };
+        let mut result = match serde_json::from_value::<WorkflowResult>(value) {
+            Ok(result) => result,
+            Err(e) => {
+                warn!(workflow_id, error = %e, "Discarding unreadable cached workflow result");
+                return None;
+            }
+        };
+
+        let mut usage = WorkflowUsage {
+            cache_layer: Some(layer.to_string()),
+            ..WorkflowUsage::default()
+        };
+        for output in result.engine_outputs.values_mut() {
+            output.metadata.cached = true;
+            output.metadata.usage = Some(ResourceUsage::cached(layer));
+            usage.add_engine(&ResourceUsage::cached(layer));
+        }
+        result.usage = Some(usage);
+        let elapsed = start.elapsed().as_secs_f64();
+        result.total_time_ms = elapsed * 1000.0;
+        noesis_metrics::record_workflow_execution(workflow_id, result.engine_outputs.len(), elapsed);
+        info!(workflow_id, layer, "Serving cached workflow result");
+        Some(result)
+    }
+
+    /// Run a workflow's engines concurrently against one input, each with the
+    /// workflow's overrides for it merged in.
+    async fn run_workflow_engines<'a>(
+        &self,
+        engine_ids: impl Iterator<Item = &'a String>,
+        workflow: &WorkflowDefinition,
+        input: &EngineInput,
+        user_phase: u8,
+        subject: Option<&str>,
+    ) -> Vec<(String, Result<EngineOutput, EngineError>)> {
+        let workflow_keys = self.workflow_option_keys(workflow);
+        let scope = Self::workflow_option_scope(&workflow_keys);
+        let futures: Vec<_> = engine_ids
             .map(|eid| {
-                let engine_opt = self.registry.get(eid);
-                let input_clone = input.clone();
+                let resolved = self.registry.resolve(eid);
+                let mut input_clone = input.clone();
+                workflow.apply_engine_overrides(eid, &mut input_clone);
                 let eid_owned = eid.clone();
 
                 async move {
-                    let engine = match engine_opt {
-                        Some(e) => e,
-                        None => {
-                            warn!(engine_id = %eid_owned, "Engine not found in registry, skipping");
-                            let err = EngineError::EngineNotFound(eid_owned.clone());
+                    let (engine, alias) = match resolved {
+                        Ok(resolved) => resolved,
+                        Err(err) => {
+                            warn!(engine_id = %eid_owned, error = %err, "Engine not found in registry, skipping");
                             return (
                                 eid_owned,
                                 Err(err),
                             );
                         }
                     };
+                    let deprecation = alias.map(|alias| {
+                        noesis_metrics::record_engine_alias_request(&alias.alias);
+                        alias.warning()
+                    });
 
                     // Phase gate
                     let required = engine.required_phase();
@@ -302,6 +1177,7 @@ impl WorkflowOrchestrator {
                             user_phase,
                             "Phase access denied, skipping engine"
                         );
+                        noesis_metrics::record_phase_denied(&eid_owned);
                         return (
                             eid_owned,
                             Err(EngineError::PhaseAccessDenied {
@@ -312,53 +1188,62 @@ impl WorkflowOrchestrator {
                     }
 
                     info!(engine_id = %eid_owned, "Executing engine in workflow");
-                    let result = engine.calculate(input_clone).await;
+                    let result = self
+                        .calculate_with_experiments(engine.as_ref(), engine.engine_id(), input_clone, subject, scope)
+                        .await
+                        .map(|mut output| {
+                            output.metadata.warnings.extend(deprecation);
+                            output
+                        });
                     (eid_owned, result)
                 }
             })
             .collect();
 
-        // Run all engines concurrently.
-        let results = join_all(futures).await;
+        // Engines share the charts and positions they derive for the birth moment
+        let (results, artifacts) = noesis_core::context::scope(join_all(futures)).await;
+        debug!(computed = artifacts.computed, reused = artifacts.reused, "Workflow artifacts");
+        noesis_metrics::record_workflow_artifacts(artifacts.computed, artifacts.reused);
+        results
+    }
 
-        // Collect successful outputs; log failures.
-        let mut engine_outputs = HashMap::new();
-        for (eid, result) in results {
-            match result {
-                Ok(output) => {
-                    info!(engine_id = %eid, "Engine completed successfully");
-                    engine_outputs.insert(eid, output);
-                }
-                Err(e) => {
-                    warn!(engine_id = %eid, error = %e, "Engine failed, omitting from results");
-                }
-            }
+    /// Option keys some engine of `workflow` reads, or that its
+    /// `engine_overrides` and phase variants set, so one engine doesn't
+    /// report another's options as unknown. `None` when an engine doesn't
+    /// declare its keys, since any key may be one it reads.
+    fn workflow_option_keys(&self, workflow: &WorkflowDefinition) -> Option<Vec<String>> {
+        let mut keys = Vec::new();
+        for eid in &workflow.engine_ids {
+            let Ok((engine, _)) = self.registry.resolve(eid) else {
+                continue;
+            };
+            keys.extend(engine.option_keys()?.iter().map(|key| key.to_string()));
         }
+        for overrides in workflow.engine_overrides.values() {
+            keys.extend(overrides.as_object().into_iter().flat_map(|o| o.keys().cloned()));
+        }
+        for variant in workflow.phase_variants.values() {
+            keys.extend(variant.options.keys().cloned());
+        }
+        keys.sort_unstable();
+        keys.dedup();
+        Some(keys)
+    }
 
-        let total_time_ms = start.elapsed().as_secs_f64() * 1000.0;
-
-        info!(
-            workflow_id,
-            engines_succeeded = engine_outputs.len(),
-            total_time_ms,
-            "Workflow execution complete"
-        );
-
-        Ok(WorkflowResult {
-            workflow_id: workflow_id.to_string(),
-            engine_outputs,
-            synthesis: None, // Synthesis is a future enhancement
-            total_time_ms,
-            timestamp: Utc::now(),
-        })
+    /// [`OptionScope`] of the engines of `workflow`
+    fn workflow_option_scope(keys: &Option<Vec<String>>) -> OptionScope<'_> {
+        match keys {
+            Some(keys) => OptionScope::Workflow(keys),
+            None => OptionScope::Unchecked,
+        }
     }
 
     // -- Query methods -----------------------------------------------------
 
-    /// List all predefined workflow definitions.
-    pub fn list_workflows(&self) -> Vec<&WorkflowDefinition> {
-        let mut wfs: Vec<&WorkflowDefinition> = self.workflows.values().collect();
-        wfs.sort_by_key(|w| &w.id);
+    /// List all workflow definitions, canonical and custom, by id.
+    pub fn list_workflows(&self) -> Vec<WorkflowDefinition> {
+        let mut wfs: Vec<WorkflowDefinition> = self.read_workflows().values().cloned().collect();
+        wfs.sort_by(|a, b| a.id.cmp(&b.id));
         wfs
     }
 
@@ -368,8 +1253,8 @@ impl WorkflowOrchestrator {
     }
 
     /// Get a specific workflow definition by ID.
-    pub fn get_workflow(&self, workflow_id: &str) -> Option<&WorkflowDefinition> {
-        self.workflows.get(workflow_id)
+    pub fn get_workflow(&self, workflow_id: &str) -> Option<WorkflowDefinition> {
+        self.read_workflows().get(workflow_id).cloned()
     }
 
     /// Get access to the underlying engine registry.
@@ -390,6 +1275,9 @@ impl WorkflowOrchestrator {
                     "human-design".into(),
                     "gene-keys".into(),
                 ],
+                engine_overrides: HashMap::new(),
+                transformers: Vec::new(),
+                phase_variants: Self::canonical_phase_variants(),
             },
             WorkflowDefinition {
                 id: "daily-practice".into(),
@@ -400,6 +1288,9 @@ impl WorkflowOrchestrator {
                     "vedic-clock".into(),
                     "biorhythm".into(),
                 ],
+                engine_overrides: HashMap::new(),
+                transformers: Vec::new(),
+                phase_variants: Self::canonical_phase_variants(),
             },
             WorkflowDefinition {
                 id: "decision-support".into(),
@@ -410,6 +1301,9 @@ impl WorkflowOrchestrator {
                     "i-ching".into(),
                     "human-design".into(),
                 ],
+                engine_overrides: HashMap::new(),
+                transformers: Vec::new(),
+                phase_variants: Self::canonical_phase_variants(),
             },
             WorkflowDefinition {
                 id: "self-inquiry".into(),
@@ -419,6 +1313,9 @@ impl WorkflowOrchestrator {
                     "gene-keys".into(),
                     "enneagram".into(),
                 ],
+                engine_overrides: HashMap::new(),
+                transformers: Vec::new(),
+                phase_variants: Self::canonical_phase_variants(),
             },
             WorkflowDefinition {
                 id: "creative-expression".into(),
@@ -428,6 +1325,17 @@ impl WorkflowOrchestrator {
                     "sigil-forge".into(),
                     "sacred-geometry".into(),
                 ],
+                engine_overrides: HashMap::new(),
+                transformers: Vec::new(),
+                phase_variants: Self::canonical_phase_variants(),
+            },
+            WorkflowDefinition {
+                phase_variants: Self::canonical_phase_variants(),
+                ..RelationshipWorkflow::base_definition()
+            },
+            WorkflowDefinition {
+                phase_variants: Self::canonical_phase_variants(),
+                ..VocationBlueprintWorkflow::base_definition()
             },
             WorkflowDefinition {
                 id: "full-spectrum".into(),
@@ -449,6 +1357,9 @@ impl WorkflowOrchestrator {
                     "sacred-geometry".into(),
                     "sigil-forge".into(),
                 ],
+                engine_overrides: HashMap::new(),
+                transformers: Vec::new(),
+                phase_variants: Self::canonical_phase_variants(),
             },
         ];
 
@@ -458,20 +1369,104 @@ impl WorkflowOrchestrator {
             .collect()
     }
 
+    /// Depth by phase for the canonical workflows: below phase 3 users get
+    /// observational prompts without the full charts, from phase 3 the
+    /// complete results and authorship prompts.
+    fn canonical_phase_variants() -> BTreeMap<u8, PhaseVariant> {
+        BTreeMap::from([
+            (
+                0,
+                PhaseVariant {
+                    options: HashMap::from([("consciousness_level".to_string(), serde_json::json!(0))]),
+                    transformers: vec![noesis_core::TransformerStep {
+                        id: "strip-fields".into(),
+                        config: serde_json::json!({
+                            "fields": ["personality_activations", "design_activations", "timeline"]
+                        }),
+                    }],
+                },
+            ),
+            (
+                3,
+                PhaseVariant {
+                    options: HashMap::from([("consciousness_level".to_string(), serde_json::json!(5))]),
+                    transformers: Vec::new(),
+                },
+            ),
+        ])
+    }
+
     // -- Health check -----------------------------------------------------
 
+    /// Run every registered engine's health check concurrently.
+    ///
+    /// Each check is bounded by [`ENGINE_HEALTH_TIMEOUT`]. Critical engines
+    /// that were never registered are reported as unhealthy. Results are
+    /// sorted by engine ID.
+    pub async fn engine_health(&self) -> Vec<EngineHealth> {
+        let checks = self.registry.list().into_iter().filter_map(|id| {
+            let engine = self.registry.get(id)?;
+            let critical = self.critical_engines.iter().any(|c| c == id);
+            Some(async move {
+                let start = Instant::now();
+                let outcome = match tokio::time::timeout(ENGINE_HEALTH_TIMEOUT, engine.health()).await {
+                    Ok(outcome) => outcome.map_err(|e| e.to_string()),
+                    Err(_) => Err(format!(
+                        "Health check timed out after {}s",
+                        ENGINE_HEALTH_TIMEOUT.as_secs()
+                    )),
+                };
+                if let Err(error) = &outcome {
+                    warn!(engine_id = %engine.engine_id(), %error, "Engine health check failed");
+                }
+                EngineHealth {
+                    engine_id: engine.engine_id().to_string(),
+                    healthy: outcome.is_ok(),
+                    critical,
+                    error: outcome.err(),
+                    latency_ms: start.elapsed().as_secs_f64() * 1000.0,
+                }
+            })
+        });
+
+        let mut reports = join_all(checks).await;
+        for id in &self.critical_engines {
+            if self.registry.get(id).is_none() {
+                reports.push(EngineHealth {
+                    engine_id: id.clone(),
+                    healthy: false,
+                    critical: true,
+                    error: Some("Engine is not registered".to_string()),
+                    latency_ms: 0.0,
+                });
+            }
+        }
+        reports.sort_by(|a, b| a.engine_id.cmp(&b.engine_id));
+        reports
+    }
+
     /// Readiness check for probe endpoint
-    /// 
-    /// TODO: Implement full readiness check that verifies:
-    /// - Engine registry has at least one engine loaded
-    /// - All critical engines are initialized and responsive
-    /// - Required workflows are properly defined
+    ///
+    /// Ready when engines and workflows are loaded and every critical engine
+    /// passes its health check.
     pub async fn is_ready(&self) -> Result<bool, EngineError> {
-        // Placeholder: Check if we have engines and workflows loaded
-        // Full implementation should verify individual engine health
-        let has_engines = !self.registry.is_empty();
-        let has_workflows = !self.workflows.is_empty();
-        Ok(has_engines && has_workflows)
+        let health = if self.critical_engines.is_empty() {
+            Vec::new()
+        } else {
+            self.engine_health().await
+        };
+        Ok(self.is_ready_with(&health))
+    }
+
+    /// [`is_ready`](Self::is_ready) against health reports the caller already has.
+    pub fn is_ready_with(&self, health: &[EngineHealth]) -> bool {
+        !self.registry.is_empty()
+            && !self.read_workflows().is_empty()
+            && self.critical_engines.iter().all(|id| {
+                health
+                    .iter()
+                    .any(|report| &report.engine_id == id && report.healthy)
+            })
     }
 }
 
@@ -481,6 +1476,74 @@ impl Default for WorkflowOrchestrator {
     }
 }
 
+/// Run `engine` and record its version and resource usage in the output
+/// metadata.
+async fn calculate_versioned(engine: &dyn ConsciousnessEngine, input: EngineInput) -> Result<EngineOutput, EngineError> {
+    let (output, usage) = noesis_core::usage::measure(engine.calculate(input)).await;
+    let mut output = output?;
+    output.metadata.engine_version = Some(engine.engine_version().to_string());
+    output.metadata.usage = Some(usage);
+    Ok(output)
+}
+
+/// Resolve `options.settings` against the engine's option keys and wrap
+/// birth longitudes, before the input is calculated or keyed
+fn prepare_input(engine: &dyn ConsciousnessEngine, input: &mut EngineInput) {
+    input.apply_settings(engine.option_keys());
+    for birth in input.birth_data_mut() {
+        birth.normalize_coordinates();
+    }
+}
+
+/// Which option keys count as known when warning about the ones an engine ignores
+#[derive(Clone, Copy)]
+enum OptionScope<'a> {
+    /// The engine's own
+    Engine,
+    /// Also those other engines of the workflow read or its overrides set
+    Workflow(&'a [String]),
+    /// None are reported: an engine of the workflow doesn't declare its keys
+    Unchecked,
+}
+
+/// One warning per option key of `input` that `engine` ignores and `scope`
+/// doesn't make known; none for engines that don't declare their keys
+fn option_warnings(
+    engine: &dyn ConsciousnessEngine,
+    engine_id: &str,
+    input: &EngineInput,
+    scope: OptionScope<'_>,
+) -> Vec<String> {
+    match (engine.option_keys(), scope) {
+        (Some(known), OptionScope::Engine) => unknown_option_warnings(engine_id, known, &input.options),
+        (Some(known), OptionScope::Workflow(workflow_keys)) => {
+            let known: Vec<&str> = known
+                .iter()
+                .copied()
+                .chain(workflow_keys.iter().map(String::as_str))
+                .collect();
+            unknown_option_warnings(engine_id, &known, &input.options)
+        }
+        (None, _) | (_, OptionScope::Unchecked) => Vec::new(),
+    }
+}
+
+/// Result cache key of `engine` for `input`.
+///
+/// The engine version is part of the key, so an upgraded engine never serves
+/// what its previous version cached. Engines also adapt their witness prompt
+/// to `options.consciousness_level`, which their own cache keys leave out, so
+/// the level is appended when given. Keys are namespaced by engine version,
+/// which L3 object storage uses as the key prefix.
+fn result_cache_key(engine: &dyn ConsciousnessEngine, input: &EngineInput) -> CacheKey {
+    let key = format!("{}:v={}", engine.cache_key(input), engine.engine_version());
+    let key = match input.options.get(CONSCIOUSNESS_LEVEL_OPTION) {
+        Some(level) => CacheKey::new(format!("{}:level={}", key, level)),
+        None => CacheKey::new(key),
+    };
+    key.with_namespace(CacheKey::engine_namespace(engine.engine_id(), engine.engine_version()))
+}
+
 // ===========================================================================
 // Tests
 // ===========================================================================
@@ -489,7 +1552,7 @@ impl Default for WorkflowOrchestrator {
 mod tests {
     use super::*;
     use async_trait::async_trait;
-    use noesis_core::{CalculationMetadata, ValidationResult};
+    use noesis_core::{CalculationMetadata, ValidationResult, AS_OF_OPTION};
 
     // -- Mock engine for testing ------------------------------------------
 
@@ -500,6 +1563,10 @@ mod tests {
         phase: u8,
         /// If true, `calculate` will return an error.
         should_fail: bool,
+        /// If true, `health` will return an error.
+        unhealthy: bool,
+        version: &'static str,
+        option_keys: &'static [&'static str],
     }
 
     impl MockEngine {
@@ -509,6 +1576,20 @@ mod tests {
                 name: format!("Mock {}", id),
                 phase,
                 should_fail: false,
+                unhealthy: false,
+                version: "1",
+                option_keys: &["forecast_days"],
+            }
+        }
+
+        fn with_option_keys(self, option_keys: &'static [&'static str]) -> Self {
+            Self { option_keys, ..self }
+        }
+
+        fn unhealthy(id: &str, phase: u8) -> Self {
+            Self {
+                unhealthy: true,
+                ..Self::new(id, phase)
             }
         }
 
@@ -518,6 +1599,9 @@ mod tests {
                 name: format!("Failing Mock {}", id),
                 phase,
                 should_fail: true,
+                unhealthy: false,
+                version: "1",
+                option_keys: &["forecast_days"],
             }
         }
     }
@@ -536,7 +1620,11 @@ mod tests {
             self.phase
         }
 
-        async fn calculate(&self, _input: EngineInput) -> Result<EngineOutput, EngineError> {
+        fn engine_version(&self) -> &str {
+            self.version
+        }
+
+        async fn calculate(&self, input: EngineInput) -> Result<EngineOutput, EngineError> {
             if self.should_fail {
                 return Err(EngineError::CalculationError(format!(
                     "{} intentionally failed",
@@ -546,8 +1634,15 @@ mod tests {
 
             Ok(EngineOutput {
                 engine_id: self.id.clone(),
-                result: serde_json::json!({ "mock": true, "engine": self.id }),
+                result: serde_json::json!({
+                    "mock": true,
+                    "engine": self.id,
+                    "options": input.options,
+                    "longitude": input.birth_data.as_ref().map(|b| b.longitude),
+                    "birth_date": input.birth_data.map(|b| b.date),
+                }),
                 witness_prompt: format!("Witness prompt from {}", self.id),
+                summary: Some(format!("Summary from {}.", self.id)),
                 consciousness_level: self.phase,
                 metadata: CalculationMetadata {
                     calculation_time_ms: 1.0,
@@ -555,6 +1650,9 @@ mod tests {
                     precision_achieved: "standard".to_string(),
                     cached: false,
                     timestamp: Utc::now(),
+                    engine_version: None,
+                    warnings: Vec::new(),
+                    usage: None,
                 },
             })
         }
@@ -567,15 +1665,75 @@ mod tests {
             })
         }
 
+        fn option_keys(&self) -> Option<&'static [&'static str]> {
+            Some(self.option_keys)
+        }
+
+        fn check_input(&self, _input: &EngineInput) -> Result<(), EngineError> {
+            if self.should_fail {
+                return Err(EngineError::CalculationError(format!("{} rejects the input", self.id)));
+            }
+            Ok(())
+        }
+
         fn cache_key(&self, _input: &EngineInput) -> String {
             format!("mock-{}", self.id)
         }
+
+        async fn health(&self) -> Result<(), EngineError> {
+            if self.unhealthy {
+                return Err(EngineError::ConfigError(format!("{} data missing", self.id)));
+            }
+            Ok(())
+        }
+    }
+
+    /// An engine that derives a natal chart through the shared context,
+    /// counting how often it actually computes one.
+    struct ChartEngine {
+        id: &'static str,
+        charts_computed: Arc<std::sync::atomic::AtomicU32>,
+    }
+
+    #[async_trait]
+    impl ConsciousnessEngine for ChartEngine {
+        fn engine_id(&self) -> &str {
+            self.id
+        }
+
+        fn engine_name(&self) -> &str {
+            self.id
+        }
+
+        fn required_phase(&self) -> u8 {
+            0
+        }
+
+        async fn calculate(&self, input: EngineInput) -> Result<EngineOutput, EngineError> {
+            let chart: String = noesis_core::context::shared("mock:chart", || {
+                self.charts_computed.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+                Ok::<_, EngineError>("chart".to_string())
+            })?;
+            let mut output = MockEngine::new(self.id, 0).calculate(input).await?;
+            output.result["chart"] = chart.into();
+            Ok(output)
+        }
+
+        async fn validate(&self, _output: &EngineOutput) -> Result<ValidationResult, EngineError> {
+            Ok(ValidationResult { valid: true, confidence: 1.0, messages: vec![] })
+        }
+
+        fn cache_key(&self, _input: &EngineInput) -> String {
+            format!("chart-{}", self.id)
+        }
     }
 
     /// Helper to build a standard test input.
     fn test_input() -> EngineInput {
         EngineInput {
             birth_data: None,
+            secondary_birth_data: None,
+            subjects: Vec::new(),
             current_time: Utc::now(),
             location: None,
             precision: noesis_core::Precision::Standard,
@@ -644,13 +1802,57 @@ mod tests {
         assert_eq!(engine.required_phase(), 2);
     }
 
+    #[test]
+    fn registry_resolves_aliases_until_sunset() {
+        let mut registry = EngineRegistry::new();
+        registry.register(Arc::new(MockEngine::new("vedic-clock-tcm", 0)));
+        let deprecated_at = Utc::now() - chrono::Duration::days(30);
+        registry.register_alias(EngineAlias::new("vedic-clock", "vedic-clock-tcm", deprecated_at));
+        registry.register_alias(
+            EngineAlias::new("tcm-clock", "vedic-clock-tcm", deprecated_at)
+                .with_sunset(Utc::now() - chrono::Duration::days(1)),
+        );
+
+        let (engine, alias) = registry.resolve("vedic-clock").unwrap();
+        assert_eq!(engine.engine_id(), "vedic-clock-tcm");
+        assert_eq!(alias.unwrap().engine_id, "vedic-clock-tcm");
+        assert!(registry.get("vedic-clock").is_some());
+        assert!(registry.resolve("vedic-clock-tcm").unwrap().1.is_none());
+
+        assert!(registry.get("tcm-clock").is_none());
+        assert!(matches!(
+            registry.resolve("tcm-clock"),
+            Err(EngineError::EngineRetired { ref replacement, .. }) if replacement == "vedic-clock-tcm"
+        ));
+        assert!(matches!(registry.resolve("nonexistent"), Err(EngineError::EngineNotFound(_))));
+
+        assert_eq!(registry.list(), vec!["vedic-clock-tcm"]);
+        let aliases: Vec<&str> = registry.aliases().iter().map(|a| a.alias.as_str()).collect();
+        assert_eq!(aliases, vec!["tcm-clock", "vedic-clock"]);
+    }
+
+    #[tokio::test]
+    async fn execute_engine_by_alias_warns() {
+        let mut orchestrator = WorkflowOrchestrator::new();
+        orchestrator.register_engine(Arc::new(MockEngine::new("numerology", 0)));
+        orchestrator.register_alias(EngineAlias::new("numbers", "numerology", Utc::now()));
+
+        let output = orchestrator.execute_engine("numbers", test_input(), 0).await.unwrap();
+        assert_eq!(output.engine_id, "numerology");
+        assert!(output
+            .metadata
+            .warnings
+            .iter()
+            .any(|w| w == "Engine id 'numbers' is deprecated; use 'numerology'"));
+    }
+
     // -- WorkflowOrchestrator tests ----------------------------------------
 
     #[test]
     fn orchestrator_has_default_workflows() {
         let orchestrator = WorkflowOrchestrator::new();
         let workflows = orchestrator.list_workflows();
-        assert_eq!(workflows.len(), 6);
+        assert_eq!(workflows.len(), 8);
 
         let ids: Vec<&str> = workflows.iter().map(|w| w.id.as_str()).collect();
         assert!(ids.contains(&"birth-blueprint"));
@@ -658,6 +1860,8 @@ mod tests {
         assert!(ids.contains(&"decision-support"));
         assert!(ids.contains(&"self-inquiry"));
         assert!(ids.contains(&"creative-expression"));
+        assert!(ids.contains(&"relationship"));
+        assert!(ids.contains(&"vocation-blueprint"));
         assert!(ids.contains(&"full-spectrum"));
     }
 
@@ -718,6 +1922,143 @@ mod tests {
         ));
     }
 
+    fn result_cache() -> Arc<CacheManager> {
+        Arc::new(CacheManager::new(String::new(), 1, Duration::from_secs(60), false))
+    }
+
+    #[tokio::test]
+    async fn execute_single_engine_serves_cached_result() {
+        let mut orchestrator = WorkflowOrchestrator::new();
+        orchestrator.register_engine(Arc::new(MockEngine::new("numerology", 0)));
+        orchestrator.set_result_cache(result_cache());
+
+        let first = orchestrator.execute_engine("numerology", test_input(), 0).await.unwrap();
+        let second = orchestrator.execute_engine("numerology", test_input(), 0).await.unwrap();
+
+        assert!(!first.metadata.cached);
+        assert!(second.metadata.cached);
+        assert_eq!(second.metadata.timestamp, first.metadata.timestamp);
+        assert_eq!(first.metadata.usage.as_ref().unwrap().cache_layer, None);
+        assert_eq!(second.metadata.usage, Some(ResourceUsage::cached("l1")));
+
+        // Witness prompts follow the consciousness level, so it is part of the key
+        let mut input = test_input();
+        input.options.insert(CONSCIOUSNESS_LEVEL_OPTION.to_string(), serde_json::json!(3));
+        let leveled = orchestrator.execute_engine("numerology", input, 0).await.unwrap();
+        assert!(!leveled.metadata.cached);
+    }
+
+    #[tokio::test]
+    async fn execute_single_engine_as_of_bypasses_cache() {
+        let mut orchestrator = WorkflowOrchestrator::new();
+        orchestrator.register_engine(Arc::new(MockEngine::new("numerology", 0)));
+        orchestrator.set_result_cache(result_cache());
+        orchestrator.execute_engine("numerology", test_input(), 0).await.unwrap();
+
+        let mut input = test_input();
+        input.options.insert(AS_OF_OPTION.to_string(), serde_json::json!("2001-09-09"));
+        let output = orchestrator.execute_engine("numerology", input, 0).await.unwrap();
+
+        assert!(!output.metadata.cached);
+        assert_eq!(output.result["options"][AS_OF_OPTION], "2001-09-09T12:00:00+00:00");
+    }
+
+    #[tokio::test]
+    async fn execute_engine_warns_about_unknown_options() {
+        let mut orchestrator = WorkflowOrchestrator::new();
+        orchestrator.register_engine(Arc::new(MockEngine::new("biorhythm", 0)));
+        orchestrator.set_result_cache(result_cache());
+
+        let mut input = test_input();
+        input.options.insert("forcast_days".to_string(), serde_json::json!(3));
+        input.options.insert(AS_OF_OPTION.to_string(), serde_json::json!("2001-09-09"));
+        let output = orchestrator.execute_engine("biorhythm", input, 0).await.unwrap();
+        assert_eq!(
+            output.metadata.warnings,
+            ["Unknown option 'forcast_days' ignored by biorhythm; did you mean 'forecast_days'?"]
+        );
+
+        // Warnings belong to the request, not to the cached result
+        let mut input = test_input();
+        input.options.insert("forcast_days".to_string(), serde_json::json!(3));
+        orchestrator.execute_engine("biorhythm", input, 0).await.unwrap();
+        let output = orchestrator.execute_engine("biorhythm", test_input(), 0).await.unwrap();
+        assert!(output.metadata.cached);
+        assert!(output.metadata.warnings.is_empty());
+    }
+
+    #[tokio::test]
+    async fn execute_engine_normalizes_coordinates_and_warns_about_swaps() {
+        let mut orchestrator = WorkflowOrchestrator::new();
+        orchestrator.register_engine(Arc::new(MockEngine::new("panchanga", 0)));
+
+        let mut input = birth_input();
+        if let Some(birth) = input.birth_data.as_mut() {
+            birth.longitude = -282.41;
+        }
+        let output = orchestrator.execute_engine("panchanga", input, 0).await.unwrap();
+        assert!((output.result["longitude"].as_f64().unwrap() - 77.59).abs() < 1e-9);
+        assert!(output.metadata.warnings.is_empty());
+
+        let mut input = birth_input();
+        if let Some(birth) = input.birth_data.as_mut() {
+            (birth.latitude, birth.longitude) = (77.59, 12.97);
+        }
+        let output = orchestrator.execute_engine("panchanga", input, 0).await.unwrap();
+        assert_eq!(
+            output.metadata.warnings,
+            ["Longitude 12.97 is far from timezone 'Asia/Kolkata'; latitude and longitude may be swapped"]
+        );
+    }
+
+    #[tokio::test]
+    async fn execute_engine_applies_settings_under_explicit_options() {
+        let mut orchestrator = WorkflowOrchestrator::new();
+        orchestrator.register_engine(Arc::new(MockEngine::new("biorhythm", 0)));
+
+        let mut input = test_input();
+        input.options.insert(
+            noesis_core::SETTINGS_OPTION.to_string(),
+            serde_json::json!({"forecast_days": 30, "ayanamsa": "raman", "consciousness_level": 2}),
+        );
+        input.options.insert("consciousness_level".to_string(), serde_json::json!(4));
+        let output = orchestrator.execute_engine("biorhythm", input, 0).await.unwrap();
+
+        assert_eq!(
+            output.result["options"],
+            serde_json::json!({"forecast_days": 30, "consciousness_level": 4})
+        );
+        // Settings the engine doesn't read are not the caller's typo
+        assert!(output.metadata.warnings.is_empty());
+    }
+
+    #[tokio::test]
+    async fn precompute_fills_result_cache_without_phase_gate() {
+        let mut orchestrator = WorkflowOrchestrator::new();
+        orchestrator.register_engine(Arc::new(MockEngine::new("vimshottari", 2)));
+        orchestrator.set_result_cache(result_cache());
+
+        let ttl = orchestrator.precompute("vimshottari", test_input()).await.unwrap();
+        assert_eq!(ttl, CacheTtl::DEFAULT);
+
+        // Still gated when requested
+        assert!(matches!(
+            orchestrator.execute_engine("vimshottari", test_input(), 1).await,
+            Err(EngineError::PhaseAccessDenied { .. })
+        ));
+        let output = orchestrator.execute_engine("vimshottari", test_input(), 2).await.unwrap();
+        assert!(output.metadata.cached);
+    }
+
+    #[tokio::test]
+    async fn precompute_requires_result_cache() {
+        let mut orchestrator = WorkflowOrchestrator::new();
+        orchestrator.register_engine(Arc::new(MockEngine::new("numerology", 0)));
+
+        let result = orchestrator.precompute("numerology", test_input()).await;
+        assert!(matches!(result, Err(EngineError::ConfigError(_))));
+    }
+
     #[tokio::test]
     async fn execute_workflow_success() {
         let mut orchestrator = WorkflowOrchestrator::new();
@@ -738,6 +2079,107 @@ mod tests {
         assert!(result.total_time_ms >= 0.0);
     }
 
+    #[tokio::test]
+    async fn execute_workflow_sums_engine_usage() {
+        let mut orchestrator = WorkflowOrchestrator::new();
+        orchestrator.register_engine(Arc::new(MockEngine::new("numerology", 0)));
+        orchestrator.register_engine(Arc::new(MockEngine::new("human-design", 0)));
+        orchestrator.register_engine(Arc::new(MockEngine::new("gene-keys", 0)));
+        orchestrator.set_result_cache(result_cache());
+
+        let first = orchestrator.execute_workflow("birth-blueprint", test_input(), 1).await.unwrap();
+        let usage = first.usage.unwrap();
+        assert_eq!(usage.engines_calculated, 3);
+        assert!(usage.cache_hits.is_empty());
+        assert_eq!(usage.external_api_calls, 0);
+        let engine_cpu: f64 = first
+            .engine_outputs
+            .values()
+            .map(|output| output.metadata.usage.as_ref().unwrap().cpu_time_ms)
+            .sum();
+        assert!(usage.cpu_time_ms >= engine_cpu);
+
+        let second = orchestrator.execute_workflow("birth-blueprint", test_input(), 1).await.unwrap();
+        let usage = second.usage.unwrap();
+        assert_eq!(usage.engines_calculated, 0);
+        assert_eq!(usage.cache_hits, BTreeMap::from([("l1".to_string(), 3)]));
+    }
+
+    #[tokio::test]
+    async fn execute_workflow_serves_cached_run_until_an_engine_changes() {
+        let orchestrator = |numerology_version: &'static str| {
+            let mut orchestrator = WorkflowOrchestrator::new();
+            orchestrator.register_engine(Arc::new(MockEngine {
+                version: numerology_version,
+                ..MockEngine::new("numerology", 0)
+            }));
+            orchestrator.register_engine(Arc::new(MockEngine::new("human-design", 0)));
+            orchestrator.register_engine(Arc::new(MockEngine::new("gene-keys", 2)));
+            orchestrator
+        };
+        let cache = result_cache();
+        let mut v1 = orchestrator("1");
+        v1.set_result_cache(cache.clone());
+
+        let first = v1.execute_workflow("birth-blueprint", test_input(), 1).await.unwrap();
+        assert_eq!(first.usage.as_ref().unwrap().cache_layer, None);
+        // Gene Keys is phase-gated, which doesn't keep the run out of the cache
+        assert_eq!(first.engine_outputs.len(), 2);
+
+        let second = v1.execute_workflow("birth-blueprint", test_input(), 1).await.unwrap();
+        assert_eq!(second.usage.as_ref().unwrap().cache_layer.as_deref(), Some("l1"));
+        assert_eq!(second.timestamp, first.timestamp);
+        assert_eq!(second.synthesis, first.synthesis);
+        assert!(second.engine_outputs.values().all(|output| output.metadata.cached));
+
+        // Another phase is another run
+        let higher = v1.execute_workflow("birth-blueprint", test_input(), 2).await.unwrap();
+        assert_eq!(higher.usage.as_ref().unwrap().cache_layer, None);
+        assert_eq!(higher.engine_outputs.len(), 3);
+
+        // A new numerology version misses the run, while human-design's own result still hits
+        let mut v2 = orchestrator("2");
+        v2.set_result_cache(cache);
+        let upgraded = v2.execute_workflow("birth-blueprint", test_input(), 1).await.unwrap();
+        let usage = upgraded.usage.unwrap();
+        assert_eq!(usage.cache_layer, None);
+        assert_eq!(usage.engines_calculated, 1);
+        assert_eq!(upgraded.engine_outputs["numerology"].metadata.engine_version.as_deref(), Some("2"));
+    }
+
+    #[tokio::test]
+    async fn execute_workflow_caches_only_complete_runs() {
+        let mut orchestrator = WorkflowOrchestrator::new();
+        orchestrator.register_engine(Arc::new(MockEngine::new("numerology", 0)));
+        orchestrator.register_engine(Arc::new(MockEngine::failing("human-design", 0)));
+        orchestrator.register_engine(Arc::new(MockEngine::new("gene-keys", 0)));
+        orchestrator.set_result_cache(result_cache());
+
+        orchestrator.execute_workflow("birth-blueprint", test_input(), 1).await.unwrap();
+        let again = orchestrator.execute_workflow("birth-blueprint", test_input(), 1).await.unwrap();
+        let usage = again.usage.unwrap();
+        assert_eq!(usage.cache_layer, None);
+        assert_eq!(usage.cache_hits, BTreeMap::from([("l1".to_string(), 2)]));
+    }
+
+    #[tokio::test]
+    async fn execute_workflow_shares_artifacts_between_engines() {
+        let charts_computed = Arc::new(std::sync::atomic::AtomicU32::new(0));
+        let mut orchestrator = WorkflowOrchestrator::new();
+        orchestrator.register_engine(Arc::new(MockEngine::new("numerology", 0)));
+        for id in ["human-design", "gene-keys"] {
+            orchestrator.register_engine(Arc::new(ChartEngine { id, charts_computed: charts_computed.clone() }));
+        }
+
+        let result = orchestrator.execute_workflow("birth-blueprint", test_input(), 1).await.unwrap();
+        assert_eq!(result.engine_outputs["gene-keys"].result["chart"], "chart");
+        assert_eq!(charts_computed.load(std::sync::atomic::Ordering::Relaxed), 1);
+
+        // Each execution starts with an empty context
+        orchestrator.execute_workflow("birth-blueprint", test_input(), 1).await.unwrap();
+        assert_eq!(charts_computed.load(std::sync::atomic::Ordering::Relaxed), 2);
+    }
+
     #[tokio::test]
     async fn execute_workflow_not_found() {
         let orchestrator = WorkflowOrchestrator::new();
@@ -767,6 +2209,80 @@ mod tests {
         assert!(!result.engine_outputs.contains_key("gene-keys"));
     }
 
+    #[tokio::test]
+    async fn plan_workflow_reports_engines_without_running_them() {
+        let mut orchestrator = WorkflowOrchestrator::new();
+        orchestrator.register_engine(Arc::new(MockEngine::new("numerology", 0)));
+        orchestrator.register_engine(Arc::new(MockEngine::failing("human-design", 0)));
+        orchestrator.register_engine(Arc::new(MockEngine::new("gene-keys", 3)));
+        orchestrator.set_result_cache(result_cache());
+
+        let mut input = test_input();
+        input.options.insert("forcast_days".to_string(), serde_json::json!(3));
+        let plan = orchestrator.plan_workflow("birth-blueprint", input.clone(), 1).await.unwrap();
+        let status = |plan: &WorkflowPlan, id: &str| plan.engines.iter().find(|e| e.engine_id == id).unwrap().status;
+
+        assert_eq!(plan.engines.len(), 3);
+        assert_eq!(status(&plan, "numerology"), PlanStatus::Run);
+        assert_eq!(status(&plan, "human-design"), PlanStatus::InvalidInput);
+        assert_eq!(status(&plan, "gene-keys"), PlanStatus::PhaseDenied);
+        assert_eq!((plan.engines_to_run, plan.calculations), (1, 1));
+        let numerology = &plan.engines[0];
+        assert!(numerology.warnings[0].contains("forcast_days"));
+        assert!(plan.engines[1].reason.as_ref().unwrap().contains("rejects the input"));
+
+        // Planning stored nothing; a real run does
+        let again = orchestrator.plan_workflow("birth-blueprint", input.clone(), 1).await.unwrap();
+        assert_eq!(status(&again, "numerology"), PlanStatus::Run);
+        orchestrator.execute_workflow("birth-blueprint", input.clone(), 1).await.unwrap();
+        let cached = orchestrator.plan_workflow("birth-blueprint", input, 1).await.unwrap();
+        assert_eq!(status(&cached, "numerology"), PlanStatus::Cached);
+        assert_eq!((cached.engines_to_run, cached.calculations), (1, 0));
+
+        let missing = orchestrator.plan_workflow("no-such-workflow", test_input(), 1).await;
+        assert!(matches!(missing, Err(EngineError::WorkflowNotFound(_))));
+    }
+
+    #[tokio::test]
+    async fn execute_workflow_records_metrics() {
+        let mut orchestrator = WorkflowOrchestrator::new();
+        orchestrator.register_engine(Arc::new(MockEngine::new("metrics-open", 0)));
+        orchestrator.register_engine(Arc::new(MockEngine::new("metrics-gated", 3)));
+        orchestrator.register_workflow(WorkflowDefinition {
+            id: "metrics-probe".into(),
+            name: "Metrics Probe".into(),
+            description: "Workflow used to check instrumentation".into(),
+            engine_ids: vec!["metrics-open".into(), "metrics-gated".into()],
+            engine_overrides: HashMap::new(),
+            transformers: Vec::new(),
+            phase_variants: BTreeMap::new(),
+        });
+
+        orchestrator
+            .execute_workflow("metrics-probe", test_input(), 1)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            noesis_metrics::WORKFLOW_ENGINES_SUCCEEDED
+                .with_label_values(&["metrics-probe"])
+                .get(),
+            1
+        );
+        assert_eq!(
+            noesis_metrics::WORKFLOW_DURATION
+                .with_label_values(&["metrics-probe"])
+                .get_sample_count(),
+            1
+        );
+        assert_eq!(
+            noesis_metrics::PHASE_DENIED_TOTAL
+                .with_label_values(&["metrics-gated"])
+                .get(),
+            1
+        );
+    }
+
     #[tokio::test]
     async fn execute_workflow_handles_engine_failure() {
         let mut orchestrator = WorkflowOrchestrator::new();
@@ -816,9 +2332,541 @@ mod tests {
             name: "Custom".into(),
             description: "A custom workflow".into(),
             engine_ids: vec!["numerology".into()],
+            engine_overrides: HashMap::new(),
+            transformers: Vec::new(),
+            phase_variants: BTreeMap::new(),
         });
 
         assert!(orchestrator.get_workflow("custom").is_some());
-        assert_eq!(orchestrator.list_workflows().len(), 7);
+        assert_eq!(orchestrator.list_workflows().len(), 9);
+    }
+
+    #[tokio::test]
+    async fn custom_workflow_merges_engine_overrides() {
+        let mut orchestrator = WorkflowOrchestrator::new();
+        orchestrator.register_engine(Arc::new(MockEngine::new("alpha", 0)));
+        orchestrator.register_engine(Arc::new(MockEngine::new("beta", 0)));
+        let replaced = orchestrator
+            .put_custom_workflow(WorkflowDefinition {
+                id: "monthly".into(),
+                name: "Monthly".into(),
+                description: String::new(),
+                engine_ids: vec!["alpha".into(), "beta".into()],
+                engine_overrides: HashMap::from([(
+                    "alpha".to_string(),
+                    serde_json::json!({ "forecast_days": 30, "mode": "workflow" }),
+                )]),
+                transformers: Vec::new(),
+                phase_variants: BTreeMap::new(),
+            })
+            .unwrap();
+        assert!(replaced.is_none());
+
+        let mut input = test_input();
+        input.options.insert("mode".into(), serde_json::json!("caller"));
+        let result = orchestrator.execute_workflow("monthly", input, 0).await.unwrap();
+
+        let alpha = &result.engine_outputs["alpha"].result["options"];
+        assert_eq!(alpha["forecast_days"], 30);
+        assert_eq!(alpha["mode"], "caller");
+        assert!(result.engine_outputs["beta"].result["options"].get("forecast_days").is_none());
+
+        assert!(orchestrator.remove_custom_workflow("monthly").unwrap().is_some());
+        assert!(orchestrator.get_workflow("monthly").is_none());
+    }
+
+    #[tokio::test]
+    async fn workflow_warns_only_about_options_no_engine_reads() {
+        let mut orchestrator = WorkflowOrchestrator::new();
+        orchestrator.register_engine(Arc::new(MockEngine::new("alpha", 0)));
+        orchestrator.register_engine(Arc::new(MockEngine::new("beta", 0).with_option_keys(&["mode"])));
+        orchestrator.register_workflow(WorkflowDefinition {
+            id: "weekly".into(),
+            name: "Weekly".into(),
+            description: String::new(),
+            engine_ids: vec!["alpha".into(), "beta".into()],
+            engine_overrides: HashMap::from([("alpha".to_string(), serde_json::json!({ "depth": 2 }))]),
+            transformers: Vec::new(),
+            phase_variants: BTreeMap::new(),
+        });
+
+        // `mode` is beta's and `depth` is set by the workflow; only the typo is reported
+        let mut input = test_input();
+        input.options.insert("mode".into(), serde_json::json!("weekly"));
+        input.options.insert("forcast_days".into(), serde_json::json!(7));
+        let result = orchestrator.execute_workflow("weekly", input, 0).await.unwrap();
+        for engine_id in ["alpha", "beta"] {
+            assert_eq!(
+                result.engine_outputs[engine_id].metadata.warnings,
+                [format!("Unknown option 'forcast_days' ignored by {}; did you mean 'forecast_days'?", engine_id)]
+            );
+        }
+
+        // Run on its own, alpha reports beta's option
+        let mut input = test_input();
+        input.options.insert("mode".into(), serde_json::json!("weekly"));
+        let output = orchestrator.execute_engine("alpha", input, 0).await.unwrap();
+        assert_eq!(output.metadata.warnings, ["Unknown option 'mode' ignored by alpha"]);
+    }
+
+    #[tokio::test]
+    async fn workflow_transformers_rewrite_outputs() {
+        let mut orchestrator = WorkflowOrchestrator::new();
+        orchestrator.register_engine(Arc::new(MockEngine::new("alpha", 0)));
+        orchestrator.register_workflow(WorkflowDefinition {
+            id: "redacted".into(),
+            name: "Redacted".into(),
+            description: String::new(),
+            engine_ids: vec!["alpha".into()],
+            engine_overrides: HashMap::new(),
+            transformers: vec![noesis_core::TransformerStep {
+                id: "strip-fields".into(),
+                config: serde_json::json!({ "fields": ["options", "witness_prompt"] }),
+            }],
+            phase_variants: BTreeMap::new(),
+        });
+        orchestrator.register_workflow(WorkflowDefinition {
+            id: "broken".into(),
+            name: "Broken".into(),
+            description: String::new(),
+            engine_ids: vec!["alpha".into()],
+            engine_overrides: HashMap::new(),
+            transformers: vec![noesis_core::TransformerStep {
+                id: "strip-fields".into(),
+                config: serde_json::Value::Null,
+            }],
+            phase_variants: BTreeMap::new(),
+        });
+
+        let result = orchestrator.execute_workflow("redacted", test_input(), 0).await.unwrap();
+        let alpha = &result.engine_outputs["alpha"];
+        assert!(alpha.result.get("options").is_none());
+        assert_eq!(alpha.result["engine"], "alpha");
+        assert!(alpha.witness_prompt.is_empty());
+
+        // A failing pipeline drops the output instead of returning it unredacted
+        let result = orchestrator.execute_workflow("broken", test_input(), 0).await.unwrap();
+        assert!(result.engine_outputs.is_empty());
+    }
+
+    #[tokio::test]
+    async fn phase_variant_follows_user_phase() {
+        let mut orchestrator = WorkflowOrchestrator::new();
+        orchestrator.register_engine(Arc::new(MockEngine::new("alpha", 0)));
+        orchestrator.register_workflow(WorkflowDefinition {
+            id: "layered".into(),
+            name: "Layered".into(),
+            description: String::new(),
+            engine_ids: vec!["alpha".into()],
+            engine_overrides: HashMap::from([("alpha".to_string(), serde_json::json!({ "detail": "override" }))]),
+            transformers: Vec::new(),
+            phase_variants: BTreeMap::from([
+                (
+                    0,
+                    PhaseVariant {
+                        options: HashMap::from([("detail".to_string(), serde_json::json!("summary"))]),
+                        transformers: vec![noesis_core::TransformerStep {
+                            id: "strip-fields".into(),
+                            config: serde_json::json!({ "fields": ["witness_prompt"] }),
+                        }],
+                    },
+                ),
+                (
+                    3,
+                    PhaseVariant {
+                        options: HashMap::from([("detail".to_string(), serde_json::json!("full"))]),
+                        transformers: Vec::new(),
+                    },
+                ),
+            ]),
+        });
+
+        // Phases 1 and 2 fall through to the phase-0 variant
+        let result = orchestrator.execute_workflow("layered", test_input(), 2).await.unwrap();
+        let alpha = &result.engine_outputs["alpha"];
+        assert_eq!(alpha.result["options"]["detail"], "summary");
+        assert!(alpha.witness_prompt.is_empty());
+
+        let result = orchestrator.execute_workflow("layered", test_input(), 4).await.unwrap();
+        let alpha = &result.engine_outputs["alpha"];
+        assert_eq!(alpha.result["options"]["detail"], "full");
+        assert!(!alpha.witness_prompt.is_empty());
+
+        // The caller's own options still win
+        let mut input = test_input();
+        input.options.insert("detail".into(), serde_json::json!("caller"));
+        let result = orchestrator.execute_workflow("layered", input, 0).await.unwrap();
+        assert_eq!(result.engine_outputs["alpha"].result["options"]["detail"], "caller");
+    }
+
+    /// Narrator that records what it was sent
+    struct RecordingNarrator {
+        reply: Result<String, String>,
+        delay: Duration,
+        seen: std::sync::Mutex<Vec<NarrativeRequest>>,
+    }
+
+    impl RecordingNarrator {
+        fn new(reply: Result<&str, &str>, delay: Duration) -> Arc<Self> {
+            Arc::new(Self {
+                reply: reply.map(String::from).map_err(String::from),
+                delay,
+                seen: std::sync::Mutex::new(Vec::new()),
+            })
+        }
+    }
+
+    #[async_trait]
+    impl SynthesisNarrator for RecordingNarrator {
+        async fn narrate(&self, request: &NarrativeRequest) -> Result<String, EngineError> {
+            self.seen.lock().unwrap().push(request.clone());
+            tokio::time::sleep(self.delay).await;
+            self.reply.clone().map_err(EngineError::CalculationError)
+        }
+    }
+
+    fn birth_blueprint_orchestrator() -> WorkflowOrchestrator {
+        let mut orchestrator = WorkflowOrchestrator::new();
+        for id in ["numerology", "human-design", "gene-keys"] {
+            orchestrator.register_engine(Arc::new(MockEngine::new(id, 0)));
+        }
+        orchestrator
+    }
+
+    fn birth_input() -> EngineInput {
+        EngineInput {
+            birth_data: Some(noesis_core::BirthData {
+                name: Some("Ada Example".into()),
+                display_name: None,
+                date: "1990-01-15".into(),
+                time: Some("14:30".into()),
+                time_unknown: false,
+                latitude: 12.97,
+                longitude: 77.59,
+                altitude: None,
+                timezone: "Asia/Kolkata".into(),
+                place: None,
+                utc_offset_minutes: None,
+            }),
+            ..test_input()
+        }
+    }
+
+    #[tokio::test]
+    async fn workflow_synthesis_uses_template_without_narrator() {
+        let orchestrator = birth_blueprint_orchestrator();
+        let result = orchestrator.execute_workflow("birth-blueprint", birth_input(), 1).await.unwrap();
+
+        let synthesis = result.synthesis.expect("every workflow has a narrative");
+        assert_eq!(synthesis["narrative_source"], "template");
+        assert_eq!(
+            synthesis["narrative"],
+            "Birth Blueprint combines 3 readings. Summary from gene-keys. \
+             Summary from human-design. Summary from numerology."
+        );
+    }
+
+    #[tokio::test]
+    async fn narrator_sees_only_engine_summaries() {
+        let mut orchestrator = birth_blueprint_orchestrator();
+        let narrator = RecordingNarrator::new(Ok("A written narrative."), Duration::ZERO);
+        orchestrator.set_narrator(narrator.clone(), Duration::from_secs(1));
+
+        let result = orchestrator.execute_workflow("birth-blueprint", birth_input(), 1).await.unwrap();
+        let synthesis = result.synthesis.unwrap();
+        assert_eq!(synthesis["narrative"], "A written narrative.");
+        assert_eq!(synthesis["narrative_source"], "narrator");
+
+        let seen = narrator.seen.lock().unwrap();
+        let sent = serde_json::to_string(&seen[0]).unwrap();
+        assert!(!sent.contains("1990-01-15") && !sent.contains("Ada Example"), "{}", sent);
+        assert_eq!(seen[0].summaries.len(), 3);
+    }
+
+    #[tokio::test]
+    async fn narrator_sees_summaries_redacted_for_export() {
+        /// Addresses the person by name, as some witness prompts do
+        struct Greeting;
+
+        impl OutputTransformer for Greeting {
+            fn id(&self) -> &str {
+                "greeting"
+            }
+
+            fn transform(&self, output: &mut EngineOutput, _config: &serde_json::Value) -> Result<(), EngineError> {
+                output.summary = Some(format!("Ada, born 1990-01-15, reads {}.", output.engine_id));
+                Ok(())
+            }
+        }
+
+        let mut orchestrator = birth_blueprint_orchestrator();
+        orchestrator.register_transformer(Arc::new(Greeting));
+        orchestrator.register_workflow(WorkflowDefinition {
+            id: "greeted".into(),
+            name: "Greeted".into(),
+            description: String::new(),
+            engine_ids: vec!["numerology".into()],
+            engine_overrides: HashMap::new(),
+            transformers: vec![noesis_core::TransformerStep { id: "greeting".into(), config: serde_json::Value::Null }],
+            phase_variants: BTreeMap::new(),
+        });
+        let narrator = RecordingNarrator::new(Ok("A written narrative."), Duration::ZERO);
+        orchestrator.set_narrator(narrator.clone(), Duration::from_secs(1));
+
+        let result = orchestrator.execute_workflow("greeted", birth_input(), 1).await.unwrap();
+        // The caller still gets their own summary
+        assert_eq!(
+            result.engine_outputs["numerology"].summary.as_deref(),
+            Some("Ada, born 1990-01-15, reads numerology.")
+        );
+        assert_eq!(
+            narrator.seen.lock().unwrap()[0].summaries[0].summary,
+            "[redacted], born [redacted], reads numerology."
+        );
+
+        orchestrator.set_redaction(noesis_core::redaction::RedactionPolicies::disabled());
+        orchestrator.execute_workflow("greeted", birth_input(), 1).await.unwrap();
+        assert_eq!(
+            narrator.seen.lock().unwrap()[1].summaries[0].summary,
+            "Ada, born 1990-01-15, reads numerology."
+        );
+    }
+
+    #[tokio::test]
+    async fn narrator_failure_or_timeout_falls_back_to_template() {
+        for narrator in [
+            RecordingNarrator::new(Err("endpoint down"), Duration::ZERO),
+            RecordingNarrator::new(Ok("Too late."), Duration::from_secs(5)),
+        ] {
+            let mut orchestrator = birth_blueprint_orchestrator();
+            orchestrator.set_narrator(narrator, Duration::from_millis(50));
+            let result = orchestrator.execute_workflow("birth-blueprint", birth_input(), 1).await.unwrap();
+            let synthesis = result.synthesis.unwrap();
+            assert_eq!(synthesis["narrative_source"], "template");
+            assert!(synthesis["narrative"].as_str().unwrap().starts_with("Birth Blueprint combines 3"));
+        }
+    }
+
+    #[test]
+    fn custom_workflow_validation() {
+        let mut orchestrator = WorkflowOrchestrator::new();
+        orchestrator.register_engine(Arc::new(MockEngine::new("alpha", 0)));
+
+        let err = orchestrator
+            .put_custom_workflow(WorkflowDefinition {
+                id: "daily-practice".into(),
+                name: "Daily".into(),
+                description: String::new(),
+                engine_ids: vec!["alpha".into(), "missing".into()],
+                engine_overrides: HashMap::from([
+                    ("alpha".to_string(), serde_json::json!(30)),
+                    ("gamma".to_string(), serde_json::json!({})),
+                ]),
+                transformers: vec![
+                    noesis_core::TransformerStep { id: "localize".into(), config: serde_json::Value::Null },
+                    noesis_core::TransformerStep {
+                        id: "truncate-text".into(),
+                        config: serde_json::json!({ "max_chars": 0 }),
+                    },
+                ],
+                phase_variants: BTreeMap::from([
+                    (1, PhaseVariant::default()),
+                    (
+                        6,
+                        PhaseVariant {
+                            options: HashMap::new(),
+                            transformers: vec![noesis_core::TransformerStep {
+                                id: "localize".into(),
+                                config: serde_json::Value::Null,
+                            }],
+                        },
+                    ),
+                ]),
+            })
+            .unwrap_err();
+        let EngineError::InvalidInput(errors) = err else {
+            panic!("expected field errors, got {:?}", err);
+        };
+        let fields: Vec<(&str, ValidationCode)> =
+            errors.iter().map(|e| (e.field.as_str(), e.code)).collect();
+        assert_eq!(
+            fields,
+            [
+                ("id", ValidationCode::Duplicate),
+                ("engine_ids[1]", ValidationCode::Unknown),
+                ("engine_overrides.alpha", ValidationCode::InvalidFormat),
+                ("engine_overrides.gamma", ValidationCode::Unknown),
+                ("transformers[0].id", ValidationCode::Unknown),
+                ("transformers[1].config", ValidationCode::InvalidFormat),
+                ("phase_variants.6", ValidationCode::OutOfRange),
+                ("phase_variants.6.transformers[0].id", ValidationCode::Unknown),
+            ]
+        );
+        assert!(orchestrator.remove_custom_workflow("daily-practice").is_err());
+    }
+
+    // -- Health tests -----------------------------------------------------
+
+    #[tokio::test]
+    async fn engine_health_reports_each_engine() {
+        let mut orchestrator = WorkflowOrchestrator::new();
+        orchestrator.register_engine(Arc::new(MockEngine::new("numerology", 0)));
+        orchestrator.register_engine(Arc::new(MockEngine::unhealthy("human-design", 1)));
+        orchestrator.set_critical_engines(["human-design", "panchanga"]);
+
+        let reports = orchestrator.engine_health().await;
+        let ids: Vec<&str> = reports.iter().map(|r| r.engine_id.as_str()).collect();
+        assert_eq!(ids, vec!["human-design", "numerology", "panchanga"]);
+
+        assert!(!reports[0].healthy);
+        assert!(reports[0].critical);
+        assert_eq!(reports[0].error.as_deref(), Some("Configuration error: human-design data missing"));
+        assert!(reports[1].healthy);
+        assert!(!reports[1].critical);
+        assert_eq!(reports[2].error.as_deref(), Some("Engine is not registered"));
+    }
+
+    #[tokio::test]
+    async fn is_ready_requires_healthy_critical_engines() {
+        let mut orchestrator = WorkflowOrchestrator::new();
+        orchestrator.register_engine(Arc::new(MockEngine::new("numerology", 0)));
+        orchestrator.register_engine(Arc::new(MockEngine::unhealthy("human-design", 1)));
+        assert!(orchestrator.is_ready().await.unwrap());
+
+        orchestrator.set_critical_engines(["numerology"]);
+        assert!(orchestrator.is_ready().await.unwrap());
+
+        orchestrator.set_critical_engines(["numerology", "human-design"]);
+        assert!(!orchestrator.is_ready().await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn execute_for_user_applies_experiments() {
+        let mut orchestrator = WorkflowOrchestrator::new();
+        orchestrator.register_engine(Arc::new(MockEngine::new("panchanga", 0)));
+        orchestrator
+            .experiments()
+            .upsert(Experiment {
+                id: "panchanga-ayanamsa".to_string(),
+                engine_id: "panchanga".to_string(),
+                enabled: true,
+                variants: vec![Variant {
+                    name: "krishnamurti".to_string(),
+                    weight: 1,
+                    options: HashMap::from([("ayanamsa".to_string(), serde_json::json!("krishnamurti"))]),
+                }],
+            })
+            .unwrap();
+
+        let output = orchestrator
+            .execute_engine_for("panchanga", test_input(), 0, "user-1")
+            .await
+            .unwrap();
+        assert_eq!(output.result["options"]["ayanamsa"], "krishnamurti");
+
+        // Without a user nobody is enrolled
+        let output = orchestrator.execute_engine("panchanga", test_input(), 0).await.unwrap();
+        assert!(output.result["options"].get("ayanamsa").is_none());
+    }
+
+    #[tokio::test]
+    async fn execute_for_user_rotates_witness_prompt() {
+        let mut orchestrator = WorkflowOrchestrator::new();
+        orchestrator.register_engine(Arc::new(MockEngine::new("numerology", 0)));
+        let plain = orchestrator.execute_engine("numerology", test_input(), 0).await.unwrap();
+
+        orchestrator.witness_packs().remove(noesis_witness::DEFAULT_PACK);
+        orchestrator
+            .witness_packs()
+            .upsert(noesis_witness::PromptPack {
+                id: "framed".to_string(),
+                description: String::new(),
+                weight: 1,
+                templates: std::collections::BTreeMap::from([(
+                    "numerology".to_string(),
+                    std::collections::BTreeMap::from([(0, vec!["[{engine}] {prompt}".to_string()])]),
+                )]),
+            })
+            .unwrap();
+
+        let output = orchestrator
+            .execute_engine_for("numerology", test_input(), 0, "user-1")
+            .await
+            .unwrap();
+        assert_eq!(output.witness_prompt, format!("[numerology] {}", plain.witness_prompt));
+
+        // Without a user the engine's own prompt is kept
+        let output = orchestrator.execute_engine("numerology", test_input(), 0).await.unwrap();
+        assert_eq!(output.witness_prompt, plain.witness_prompt);
+    }
+
+    #[tokio::test]
+    async fn workflow_as_of_is_shared_by_every_engine() {
+        let mut orchestrator = WorkflowOrchestrator::new();
+        orchestrator.register_engine(Arc::new(MockEngine::new("numerology", 0)));
+        orchestrator.register_engine(Arc::new(MockEngine::new("human-design", 0)));
+
+        let mut input = test_input();
+        input.options.insert("as_of".to_string(), serde_json::json!("2001-09-09"));
+        let result = orchestrator.execute_workflow("birth-blueprint", input, 5).await.unwrap();
+        for output in result.engine_outputs.values() {
+            assert_eq!(output.result["options"]["as_of"], "2001-09-09T12:00:00+00:00");
+        }
+
+        let mut invalid = test_input();
+        invalid.options.insert("as_of".to_string(), serde_json::json!("yesterday"));
+        let err = orchestrator.execute_workflow("birth-blueprint", invalid, 5).await.unwrap_err();
+        assert!(matches!(err, EngineError::InvalidInput(ref e) if e[0].field == "options.as_of"));
+    }
+
+    #[tokio::test]
+    async fn relationship_workflow_runs_engines_for_both_people() {
+        let mut orchestrator = WorkflowOrchestrator::new();
+        orchestrator.register_engine(Arc::new(MockEngine::new("numerology", 0)));
+        orchestrator.register_engine(Arc::new(MockEngine::new("human-design", 0)));
+
+        let err = orchestrator.execute_workflow("relationship", test_input(), 5).await.unwrap_err();
+        assert!(matches!(err, EngineError::InvalidInput(ref e) if e[0].field == "secondary_birth_data"));
+
+        let birth = |date: &str| noesis_core::BirthData {
+            name: None,
+            display_name: None,
+            date: date.to_string(),
+            time: Some("12:00".to_string()),
+            time_unknown: false,
+            latitude: 51.5,
+            longitude: -0.12,
+            altitude: None,
+            timezone: "Europe/London".to_string(),
+            place: None,
+            utc_offset_minutes: None,
+        };
+        let input = EngineInput {
+            birth_data: Some(birth("1990-01-15")),
+            secondary_birth_data: Some(birth("1988-07-04")),
+            ..test_input()
+        };
+        let result = orchestrator.execute_workflow("relationship", input, 5).await.unwrap();
+
+        assert_eq!(result.engine_outputs.len(), 4);
+        assert_eq!(result.engine_outputs["numerology"].result["birth_date"], "1990-01-15");
+        assert_eq!(result.engine_outputs["numerology:secondary"].result["birth_date"], "1988-07-04");
+        let synthesis = result.synthesis.expect("relationship synthesis");
+        assert!(synthesis["summary"].as_str().unwrap().starts_with("First person:"));
+    }
+
+    #[tokio::test]
+    async fn vocation_blueprint_workflow_reports_a_vocation_profile() {
+        let mut orchestrator = WorkflowOrchestrator::new();
+        orchestrator.register_engine(Arc::new(MockEngine::new("human-design", 0)));
+        orchestrator.register_engine(Arc::new(MockEngine::new("gene-keys", 0)));
+
+        let result = orchestrator.execute_workflow("vocation-blueprint", test_input(), 5).await.unwrap();
+
+        assert_eq!(result.engine_outputs.len(), 2);
+        let synthesis = result.synthesis.expect("vocation blueprint synthesis");
+        assert!(synthesis["vocation_profile"]["decision_cadence"].is_string());
+        assert!(synthesis["vocation_profile"]["pearl_sequence"].as_array().unwrap().is_empty());
+        assert!(synthesis["narrative"].is_string());
     }
 }
@@ -0,0 +1,338 @@
+//! Chat-completions narrator
+//!
+//! Posts a [`NarrativeRequest`] to an OpenAI-compatible `chat/completions`
+//! endpoint. Calls are bounded three ways: a request timeout, `max_tokens`
+//! per reply and a daily token budget shared by every workflow. Replies are
+//! cached by prompt, so repeated readings of the same chart cost nothing.
+
+use super::{NarrativeRequest, SynthesisNarrator};
+use async_trait::async_trait;
+use chrono::{NaiveDate, Utc};
+use noesis_core::EngineError;
+use serde::Deserialize;
+use serde_json::json;
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// Most cached replies kept before the oldest is evicted
+const MAX_CACHE_ENTRIES: usize = 1024;
+
+const SYSTEM_PROMPT: &str = "You write the synthesis for a self-inquiry workflow. \
+    Combine the engine summaries into one paragraph of at most 120 words, in the second person. \
+    Only use what the summaries say: do not predict events, diagnose, or add facts.";
+
+/// Endpoint and limits for an [`LlmNarrator`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct LlmConfig {
+    /// Full URL of the chat-completions endpoint
+    pub endpoint: String,
+    /// Sent as a bearer token when set
+    pub api_key: Option<String>,
+    pub model: String,
+    /// Longest a single call may take
+    pub timeout: Duration,
+    /// `max_tokens` for each reply
+    pub max_output_tokens: u32,
+    /// Tokens (prompt and reply) all calls may use per UTC day
+    pub daily_token_budget: u64,
+    /// How long a reply is reused for the same summaries
+    pub cache_ttl: Duration,
+}
+
+impl LlmConfig {
+    /// Read from `LLM_SYNTHESIS_*` variables; `None` unless both
+    /// `LLM_SYNTHESIS_URL` and `LLM_SYNTHESIS_MODEL` are set.
+    pub fn from_env() -> Option<Self> {
+        let text = |name: &str| {
+            std::env::var(name)
+                .ok()
+                .map(|v| v.trim().to_string())
+                .filter(|v| !v.is_empty())
+        };
+        let number = |name: &str| text(name).and_then(|v| v.parse::<u64>().ok());
+        Some(Self {
+            endpoint: text("LLM_SYNTHESIS_URL")?,
+            model: text("LLM_SYNTHESIS_MODEL")?,
+            api_key: text("LLM_SYNTHESIS_API_KEY"),
+            timeout: Duration::from_millis(number("LLM_SYNTHESIS_TIMEOUT_MS").unwrap_or(5_000)),
+            max_output_tokens: number("LLM_SYNTHESIS_MAX_TOKENS").unwrap_or(300) as u32,
+            daily_token_budget: number("LLM_SYNTHESIS_DAILY_TOKEN_BUDGET").unwrap_or(200_000),
+            cache_ttl: Duration::from_secs(number("LLM_SYNTHESIS_CACHE_TTL_SECS").unwrap_or(86_400)),
+        })
+    }
+}
+
+struct TokenBudget {
+    day: NaiveDate,
+    used: u64,
+}
+
+#[derive(Deserialize)]
+struct CompletionResponse {
+    choices: Vec<Choice>,
+    usage: Option<Usage>,
+}
+
+#[derive(Deserialize)]
+struct Choice {
+    message: Message,
+}
+
+#[derive(Deserialize)]
+struct Message {
+    content: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct Usage {
+    total_tokens: u64,
+}
+
+/// [`SynthesisNarrator`] backed by a language model
+pub struct LlmNarrator {
+    config: LlmConfig,
+    client: reqwest::Client,
+    cache: Mutex<HashMap<String, (String, Instant)>>,
+    budget: Mutex<TokenBudget>,
+}
+
+impl LlmNarrator {
+    pub fn new(config: LlmConfig) -> Result<Self, EngineError> {
+        let client = reqwest::Client::builder()
+            .timeout(config.timeout)
+            .build()
+            .map_err(|e| EngineError::ConfigError(format!("Failed to build LLM client: {}", e)))?;
+        Ok(Self {
+            config,
+            client,
+            cache: Mutex::new(HashMap::new()),
+            budget: Mutex::new(TokenBudget {
+                day: Utc::now().date_naive(),
+                used: 0,
+            }),
+        })
+    }
+
+    pub fn config(&self) -> &LlmConfig {
+        &self.config
+    }
+
+    fn user_prompt(request: &NarrativeRequest) -> String {
+        let mut prompt = format!("Workflow: {}\n", request.workflow_name);
+        for entry in &request.summaries {
+            prompt.push_str(&format!("- {}: {}\n", entry.engine_id, entry.summary));
+        }
+        prompt
+    }
+
+    fn cache_key(&self, prompt: &str) -> String {
+        let digest = Sha256::digest(format!("{}\n{}", self.config.model, prompt).as_bytes());
+        format!("{:x}", digest)
+    }
+
+    fn cached(&self, key: &str) -> Option<String> {
+        let cache = self.cache.lock().unwrap_or_else(|e| e.into_inner());
+        cache
+            .get(key)
+            .filter(|(_, stored)| stored.elapsed() < self.config.cache_ttl)
+            .map(|(narrative, _)| narrative.clone())
+    }
+
+    fn store(&self, key: String, narrative: String) {
+        let mut cache = self.cache.lock().unwrap_or_else(|e| e.into_inner());
+        if cache.len() >= MAX_CACHE_ENTRIES {
+            let ttl = self.config.cache_ttl;
+            cache.retain(|_, (_, stored)| stored.elapsed() < ttl);
+        }
+        if cache.len() >= MAX_CACHE_ENTRIES {
+            let oldest = cache
+                .iter()
+                .min_by_key(|(_, (_, stored))| *stored)
+                .map(|(k, _)| k.clone());
+            if let Some(oldest) = oldest {
+                cache.remove(&oldest);
+            }
+        }
+        cache.insert(key, (narrative, Instant::now()));
+    }
+
+    /// Reserve room for a call, or fail once today's budget is spent
+    fn reserve(&self, estimate: u64) -> Result<(), EngineError> {
+        let mut budget = self.budget.lock().unwrap_or_else(|e| e.into_inner());
+        let today = Utc::now().date_naive();
+        if budget.day != today {
+            budget.day = today;
+            budget.used = 0;
+        }
+        if budget.used + estimate > self.config.daily_token_budget {
+            return Err(EngineError::CalculationError(
+                "LLM synthesis daily token budget exhausted".to_string(),
+            ));
+        }
+        budget.used += estimate;
+        Ok(())
+    }
+
+    /// Replace a reservation with what the call actually used
+    fn settle(&self, estimate: u64, actual: u64) {
+        let mut budget = self.budget.lock().unwrap_or_else(|e| e.into_inner());
+        budget.used = (budget.used + actual).saturating_sub(estimate);
+    }
+}
+
+#[async_trait]
+impl SynthesisNarrator for LlmNarrator {
+    async fn narrate(&self, request: &NarrativeRequest) -> Result<String, EngineError> {
+        let prompt = Self::user_prompt(request);
+        let key = self.cache_key(&prompt);
+        if let Some(narrative) = self.cached(&key) {
+            return Ok(narrative);
+        }
+
+        // About four characters per token, plus the full reply allowance
+        let estimate = ((SYSTEM_PROMPT.len() + prompt.len()) / 4) as u64 + self.config.max_output_tokens as u64;
+        self.reserve(estimate)?;
+
+        let body = json!({
+            "model": self.config.model,
+            "max_tokens": self.config.max_output_tokens,
+            "temperature": 0,
+            "messages": [
+                { "role": "system", "content": SYSTEM_PROMPT },
+                { "role": "user", "content": prompt },
+            ],
+        });
+        let mut call = self.client.post(&self.config.endpoint).json(&body);
+        if let Some(api_key) = &self.config.api_key {
+            call = call.bearer_auth(api_key);
+        }
+
+        noesis_core::usage::record_external_api_call();
+        let response = match call.send().await {
+            Ok(response) => response,
+            Err(e) => {
+                self.settle(estimate, 0);
+                return Err(EngineError::CalculationError(format!("LLM synthesis request failed: {}", e)));
+            }
+        };
+        if !response.status().is_success() {
+            self.settle(estimate, 0);
+            return Err(EngineError::CalculationError(format!(
+                "LLM synthesis endpoint returned {}",
+                response.status()
+            )));
+        }
+        // An unreadable reply probably still cost tokens, so the reservation stands
+        let completion: CompletionResponse = response
+            .json()
+            .await
+            .map_err(|e| EngineError::CalculationError(format!("Invalid LLM synthesis response: {}", e)))?;
+        self.settle(estimate, completion.usage.map_or(estimate, |u| u.total_tokens));
+
+        let narrative = completion
+            .choices
+            .into_iter()
+            .next()
+            .and_then(|choice| choice.message.content)
+            .map(|content| content.trim().to_string())
+            .filter(|content| !content.is_empty())
+            .ok_or_else(|| EngineError::CalculationError("LLM synthesis returned no text".to_string()))?;
+
+        self.store(key, narrative.clone());
+        Ok(narrative)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::narrative::EngineSummary;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::TcpListener;
+
+    /// Serve one canned completion per connection; returns the URL and a hit counter
+    async fn completion_server(reply: &'static str, total_tokens: u64) -> (String, Arc<AtomicUsize>) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}/v1/chat/completions", listener.local_addr().unwrap());
+        let hits = Arc::new(AtomicUsize::new(0));
+        let counter = hits.clone();
+        tokio::spawn(async move {
+            while let Ok((mut socket, _)) = listener.accept().await {
+                counter.fetch_add(1, Ordering::SeqCst);
+                let mut buf = vec![0u8; 16 * 1024];
+                let _ = socket.read(&mut buf).await;
+                let body = json!({
+                    "choices": [{ "message": { "content": reply } }],
+                    "usage": { "total_tokens": total_tokens },
+                })
+                .to_string();
+                let response = format!(
+                    "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{}",
+                    body.len(),
+                    body
+                );
+                let _ = socket.write_all(response.as_bytes()).await;
+            }
+        });
+        (url, hits)
+    }
+
+    fn config(endpoint: String, daily_token_budget: u64) -> LlmConfig {
+        LlmConfig {
+            endpoint,
+            api_key: Some("test-key".into()),
+            model: "test-model".into(),
+            timeout: Duration::from_secs(2),
+            max_output_tokens: 50,
+            daily_token_budget,
+            cache_ttl: Duration::from_secs(60),
+        }
+    }
+
+    fn request(summary: &str) -> NarrativeRequest {
+        NarrativeRequest {
+            workflow_id: "birth-blueprint".into(),
+            workflow_name: "Birth Blueprint".into(),
+            summaries: vec![EngineSummary {
+                engine_id: "numerology".into(),
+                summary: summary.into(),
+            }],
+        }
+    }
+
+    #[tokio::test]
+    async fn test_replies_are_cached_by_prompt() {
+        let (url, hits) = completion_server("Service runs through your chart.", 120).await;
+        let narrator = LlmNarrator::new(config(url, 10_000)).unwrap();
+
+        let first = narrator.narrate(&request("Your Life Path number is 9.")).await.unwrap();
+        let second = narrator.narrate(&request("Your Life Path number is 9.")).await.unwrap();
+        assert_eq!(first, "Service runs through your chart.");
+        assert_eq!(first, second);
+        assert_eq!(hits.load(Ordering::SeqCst), 1);
+
+        narrator.narrate(&request("Your Life Path number is 4.")).await.unwrap();
+        assert_eq!(hits.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_budget_stops_calls() {
+        let (url, hits) = completion_server("Short.", 150).await;
+        let narrator = LlmNarrator::new(config(url, 200)).unwrap();
+
+        narrator.narrate(&request("Your Life Path number is 9.")).await.unwrap();
+        let err = narrator.narrate(&request("Your Life Path number is 4.")).await.unwrap_err();
+        assert!(err.to_string().contains("budget"), "{}", err);
+        assert_eq!(hits.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_unreachable_endpoint_is_an_error() {
+        let narrator = LlmNarrator::new(config("http://127.0.0.1:9/v1/chat/completions".into(), 10_000)).unwrap();
+        assert!(narrator.narrate(&request("Your Life Path number is 9.")).await.is_err());
+    }
+}
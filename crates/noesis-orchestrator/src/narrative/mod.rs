@@ -0,0 +1,135 @@
+//! Workflow synthesis narratives
+//!
+//! Every workflow result carries `synthesis.narrative`, a short prose reading
+//! of the engine outputs. It is written from each engine's plain-language
+//! [`EngineOutput::summary`] only: a [`NarrativeRequest`] never holds the
+//! input, birth data or raw results. Before a narrator sees the summaries,
+//! names and birth dates in them are redacted for export.
+//!
+//! By default [`template_narrative`] joins the summaries. A
+//! [`SynthesisNarrator`] set on the orchestrator can write the narrative
+//! instead; with the `llm-synthesis` feature, [`LlmNarrator`] sends the
+//! request to a chat-completions endpoint. Whenever the narrator is unset,
+//! fails or runs past its timeout, the template is used, so synthesis never
+//! depends on an outside service.
+
+#[cfg(feature = "llm-synthesis")]
+mod llm;
+
+#[cfg(feature = "llm-synthesis")]
+pub use llm::{LlmConfig, LlmNarrator};
+
+use async_trait::async_trait;
+use noesis_core::{EngineError, EngineOutput, WorkflowDefinition};
+use serde::Serialize;
+use std::collections::HashMap;
+
+/// One engine's contribution to a narrative
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct EngineSummary {
+    pub engine_id: String,
+    pub summary: String,
+}
+
+/// Everything a narrator is allowed to see about a workflow run
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct NarrativeRequest {
+    pub workflow_id: String,
+    pub workflow_name: String,
+    /// Summaries sorted by engine id; outputs without one are left out
+    pub summaries: Vec<EngineSummary>,
+}
+
+impl NarrativeRequest {
+    pub fn from_outputs(workflow: &WorkflowDefinition, outputs: &HashMap<String, EngineOutput>) -> Self {
+        let mut summaries: Vec<EngineSummary> = outputs
+            .iter()
+            .filter_map(|(engine_id, output)| {
+                output.summary.as_ref().map(|summary| EngineSummary {
+                    engine_id: engine_id.clone(),
+                    summary: summary.clone(),
+                })
+            })
+            .collect();
+        summaries.sort_by(|a, b| a.engine_id.cmp(&b.engine_id));
+        Self {
+            workflow_id: workflow.id.clone(),
+            workflow_name: workflow.name.clone(),
+            summaries,
+        }
+    }
+}
+
+/// Who wrote a narrative, reported as `synthesis.narrative_source`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum NarrativeSource {
+    Template,
+    Narrator,
+}
+
+impl NarrativeSource {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            NarrativeSource::Template => "template",
+            NarrativeSource::Narrator => "narrator",
+        }
+    }
+}
+
+/// Writes a workflow narrative from engine summaries
+#[async_trait]
+pub trait SynthesisNarrator: Send + Sync {
+    async fn narrate(&self, request: &NarrativeRequest) -> Result<String, EngineError>;
+}
+
+/// The deterministic narrative: a count of readings followed by each summary
+pub fn template_narrative(request: &NarrativeRequest) -> String {
+    match request.summaries.len() {
+        0 => format!("{} produced no engine summaries to combine.", request.workflow_name),
+        count => {
+            let readings = if count == 1 { "reading" } else { "readings" };
+            let mut narrative = format!("{} combines {} {}.", request.workflow_name, count, readings);
+            for entry in &request.summaries {
+                narrative.push(' ');
+                narrative.push_str(&entry.summary);
+            }
+            narrative
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn request(summaries: &[(&str, &str)]) -> NarrativeRequest {
+        NarrativeRequest {
+            workflow_id: "birth-blueprint".into(),
+            workflow_name: "Birth Blueprint".into(),
+            summaries: summaries
+                .iter()
+                .map(|(engine_id, summary)| EngineSummary {
+                    engine_id: engine_id.to_string(),
+                    summary: summary.to_string(),
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn test_template_joins_summaries_in_order() {
+        let narrative = template_narrative(&request(&[
+            ("human-design", "You are a Generator."),
+            ("numerology", "Your Life Path number is 9."),
+        ]));
+        assert_eq!(
+            narrative,
+            "Birth Blueprint combines 2 readings. You are a Generator. Your Life Path number is 9."
+        );
+        assert_eq!(
+            template_narrative(&request(&[])),
+            "Birth Blueprint produced no engine summaries to combine."
+        );
+    }
+}
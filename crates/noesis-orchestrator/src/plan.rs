@@ -0,0 +1,278 @@
+//! Workflow dry runs
+//!
+//! [`WorkflowOrchestrator::plan_workflow`] goes through a workflow the way
+//! execution would, applying the phase variant, the per-engine overrides and
+//! the caller's settings, but stops short of calculating. Each engine gets a
+//! [`PlanStatus`]: whether the caller's phase reaches it, whether its input
+//! passes [`ConsciousnessEngine::check_input`], and whether the result cache
+//! would answer it. [`WorkflowPlan::estimate_latency`] adds expected
+//! latencies from recorded history, so a UI can show "this will run 5 of 14
+//! engines for your phase" before anything runs.
+
+use noesis_core::{ConsciousnessEngine, EngineError, EngineInput};
+use serde::{Deserialize, Serialize};
+
+use crate::{option_warnings, result_cache_key, OptionScope, RelationshipWorkflow, WorkflowOrchestrator};
+
+#[cfg(feature = "openapi")]
+use utoipa::ToSchema;
+
+/// What executing the workflow would do with one engine
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum PlanStatus {
+    /// Calculated
+    Run,
+    /// Answered from the result cache
+    Cached,
+    /// Skipped: the engine needs a higher consciousness phase
+    PhaseDenied,
+    /// Would fail on the input; `reason` says why
+    InvalidInput,
+    /// Not registered, or an alias past its sunset
+    Unavailable,
+}
+
+impl PlanStatus {
+    /// Whether the engine contributes an output
+    pub fn runs(self) -> bool {
+        matches!(self, Self::Run | Self::Cached)
+    }
+}
+
+/// One engine of a [`WorkflowPlan`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct PlannedEngine {
+    /// Key of the engine's output; `<engine>:secondary` for the second
+    /// person's pass of the relationship workflow
+    #[cfg_attr(feature = "openapi", schema(example = "human-design"))]
+    pub engine_id: String,
+    /// Absent when the engine is unavailable
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub required_phase: Option<u8>,
+    pub status: PlanStatus,
+    /// Why the engine won't run
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+    /// What the output's `metadata.warnings` would hold, such as ignored options
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub warnings: Vec<String>,
+    /// Mean of the engine's recorded calculation times; 0 when cached,
+    /// absent without history
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub estimated_latency_ms: Option<f64>,
+}
+
+/// A workflow's execution plan, with nothing calculated
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct WorkflowPlan {
+    #[cfg_attr(feature = "openapi", schema(example = "full-spectrum"))]
+    pub workflow_id: String,
+    pub user_phase: u8,
+    pub engines: Vec<PlannedEngine>,
+    /// Engines that would contribute an output, cached ones included
+    #[cfg_attr(feature = "openapi", schema(example = 5))]
+    pub engines_to_run: usize,
+    /// Engines that would calculate rather than come from the cache
+    pub calculations: usize,
+    /// Expected wall time: engines run concurrently, so the slowest one's
+    /// estimate, else the workflow's recorded mean. Absent without history.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub estimated_latency_ms: Option<f64>,
+}
+
+impl WorkflowPlan {
+    /// Fill in latency estimates from `mean_seconds`, the mean recorded
+    /// calculation time of a metrics label: an engine id, or
+    /// `workflow:<id>` for whole workflows
+    pub fn estimate_latency(&mut self, mean_seconds: impl Fn(&str) -> Option<f64>) {
+        for engine in &mut self.engines {
+            engine.estimated_latency_ms = match engine.status {
+                PlanStatus::Cached => Some(0.0),
+                PlanStatus::Run => {
+                    let engine_id = engine.engine_id.trim_end_matches(RelationshipWorkflow::SECONDARY_SUFFIX);
+                    mean_seconds(engine_id).map(|seconds| seconds * 1000.0)
+                }
+                _ => None,
+            };
+        }
+        let running: Vec<_> = self.engines.iter().filter(|e| e.status.runs()).collect();
+        self.estimated_latency_ms = if !running.is_empty() && running.iter().all(|e| e.estimated_latency_ms.is_some()) {
+            running.iter().filter_map(|e| e.estimated_latency_ms).reduce(f64::max)
+        } else {
+            mean_seconds(&format!("workflow:{}", self.workflow_id)).map(|seconds| seconds * 1000.0)
+        };
+    }
+}
+
+impl WorkflowOrchestrator {
+    /// Plan `workflow_id` for a caller at `user_phase` without running any
+    /// engine.
+    ///
+    /// Fails like execution would before any engine runs: unknown workflow,
+    /// invalid `as_of`, or a relationship request without
+    /// `secondary_birth_data`. Per-engine problems are reported in the plan.
+    pub async fn plan_workflow(
+        &self,
+        workflow_id: &str,
+        mut input: EngineInput,
+        user_phase: u8,
+    ) -> Result<WorkflowPlan, EngineError> {
+        let workflow = self
+            .get_workflow(workflow_id)
+            .ok_or_else(|| EngineError::WorkflowNotFound(workflow_id.to_string()))?;
+        input.apply_as_of()?;
+        let secondary_input = if workflow_id == RelationshipWorkflow::ID {
+            Some(RelationshipWorkflow::secondary_input(&input)?)
+        } else {
+            None
+        };
+        if let Some((_, variant)) = workflow.phase_variant(user_phase) {
+            for (key, value) in &variant.options {
+                input.options.entry(key.clone()).or_insert_with(|| value.clone());
+            }
+        }
+
+        let workflow_keys = self.workflow_option_keys(&workflow);
+        let scope = Self::workflow_option_scope(&workflow_keys);
+        let mut engines = Vec::new();
+        for eid in &workflow.engine_ids {
+            let mut engine_input = input.clone();
+            workflow.apply_engine_overrides(eid, &mut engine_input);
+            engines.push(self.plan_engine(eid.clone(), eid, engine_input, user_phase, scope).await);
+        }
+        if let Some(secondary_input) = &secondary_input {
+            for eid in &workflow.engine_ids {
+                if self.registry.get(eid).is_none_or(|engine| engine.supports_compatibility()) {
+                    continue;
+                }
+                let mut engine_input = secondary_input.clone();
+                workflow.apply_engine_overrides(eid, &mut engine_input);
+                let key = RelationshipWorkflow::secondary_key(eid);
+                engines.push(self.plan_engine(key, eid, engine_input, user_phase, scope).await);
+            }
+        }
+
+        Ok(WorkflowPlan {
+            workflow_id: workflow_id.to_string(),
+            user_phase,
+            engines_to_run: engines.iter().filter(|e| e.status.runs()).count(),
+            calculations: engines.iter().filter(|e| e.status == PlanStatus::Run).count(),
+            engines,
+            estimated_latency_ms: None,
+        })
+    }
+
+    /// What running `engine_id` on `input` would do, checked in the order
+    /// execution fails: registry, phase gate, input
+    async fn plan_engine(
+        &self,
+        key: String,
+        engine_id: &str,
+        mut input: EngineInput,
+        user_phase: u8,
+        scope: OptionScope<'_>,
+    ) -> PlannedEngine {
+        let mut planned = PlannedEngine {
+            engine_id: key,
+            required_phase: None,
+            status: PlanStatus::Unavailable,
+            reason: None,
+            warnings: Vec::new(),
+            estimated_latency_ms: None,
+        };
+        let (engine, alias) = match self.registry.resolve(engine_id) {
+            Ok(resolved) => resolved,
+            Err(e) => {
+                planned.reason = Some(e.to_string());
+                return planned;
+            }
+        };
+        planned.required_phase = Some(engine.required_phase());
+        planned.warnings.extend(alias.map(|alias| alias.warning()));
+        if engine.required_phase() > user_phase {
+            planned.status = PlanStatus::PhaseDenied;
+            planned.reason = Some(
+                EngineError::PhaseAccessDenied {
+                    required: engine.required_phase(),
+                    current: user_phase,
+                }
+                .to_string(),
+            );
+            return planned;
+        }
+
+        input.apply_settings(engine.option_keys());
+        planned.warnings.extend(option_warnings(engine.as_ref(), engine.engine_id(), &input, scope));
+        if let Err(e) = engine.check_input(&input) {
+            planned.status = PlanStatus::InvalidInput;
+            planned.reason = Some(e.to_string());
+            return planned;
+        }
+        planned.status = if self.is_cached(engine.as_ref(), &input).await {
+            PlanStatus::Cached
+        } else {
+            PlanStatus::Run
+        };
+        planned
+    }
+
+    /// Whether the result cache holds `engine`'s result for `input`, as
+    /// [`calculate_cached`](Self::calculate_cached) would look it up
+    async fn is_cached(&self, engine: &dyn ConsciousnessEngine, input: &EngineInput) -> bool {
+        let Some(cache) = self.result_cache.as_ref().filter(|_| input.is_cacheable()) else {
+            return false;
+        };
+        matches!(cache.get(&result_cache_key(engine, input)).await, Ok(Some(_)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn planned(engine_id: &str, status: PlanStatus) -> PlannedEngine {
+        PlannedEngine {
+            engine_id: engine_id.to_string(),
+            required_phase: Some(0),
+            status,
+            reason: None,
+            warnings: Vec::new(),
+            estimated_latency_ms: None,
+        }
+    }
+
+    #[test]
+    fn test_estimate_latency() {
+        let mut plan = WorkflowPlan {
+            workflow_id: "daily-practice".to_string(),
+            user_phase: 1,
+            engines: vec![
+                planned("panchanga", PlanStatus::Run),
+                planned("biorhythm", PlanStatus::Cached),
+                planned("gene-keys", PlanStatus::PhaseDenied),
+            ],
+            engines_to_run: 2,
+            calculations: 1,
+            estimated_latency_ms: None,
+        };
+        let history = |label: &str| match label {
+            "panchanga" => Some(0.02),
+            "workflow:daily-practice" => Some(0.05),
+            _ => None,
+        };
+        plan.estimate_latency(history);
+        assert_eq!(plan.engines[0].estimated_latency_ms, Some(20.0));
+        assert_eq!(plan.engines[1].estimated_latency_ms, Some(0.0));
+        assert_eq!(plan.engines[2].estimated_latency_ms, None);
+        assert_eq!(plan.estimated_latency_ms, Some(20.0));
+
+        // An engine without history falls back to the workflow's mean
+        plan.engines[0].engine_id = "vedic-clock".to_string();
+        plan.estimate_latency(history);
+        assert_eq!(plan.estimated_latency_ms, Some(50.0));
+    }
+}
@@ -0,0 +1,266 @@
+//! Engine output post-processing
+//!
+//! An [`OutputTransformer`] rewrites an [`EngineOutput`] after the engine has
+//! run: redacting fields for clients that don't want them, trimming long text
+//! for low-bandwidth clients, or adding derived values. Transformers are
+//! registered once on the orchestrator and referenced by id from a workflow's
+//! `transformers` list, which sets their order and their per-workflow config.
+//!
+//! Built in:
+//! - `strip-fields`: removes the keys named in `{"fields": [...]}` anywhere in
+//!   the result; `witness_prompt` clears the prompt and `summary` drops the
+//!   summary
+//! - `truncate-text`: shortens every string in the result, the witness
+//!   prompt and the summary to `{"max_chars": n}` characters
+
+use noesis_core::{EngineError, EngineOutput, TransformerStep};
+use serde::Deserialize;
+use serde_json::Value;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// A post-processing step over one engine's output
+pub trait OutputTransformer: Send + Sync {
+    /// Id workflows use to reference this transformer
+    fn id(&self) -> &str;
+
+    /// Reject a workflow's config for this transformer before it goes live
+    fn validate_config(&self, _config: &Value) -> Result<(), String> {
+        Ok(())
+    }
+
+    /// Rewrite `output` in place according to `config`
+    fn transform(&self, output: &mut EngineOutput, config: &Value) -> Result<(), EngineError>;
+}
+
+/// Transformers available to workflows, keyed by id
+#[derive(Clone)]
+pub struct TransformerRegistry {
+    transformers: HashMap<String, Arc<dyn OutputTransformer>>,
+}
+
+impl TransformerRegistry {
+    /// A registry holding the built-in transformers
+    pub fn new() -> Self {
+        let mut registry = Self {
+            transformers: HashMap::new(),
+        };
+        registry.register(Arc::new(StripFields));
+        registry.register(Arc::new(TruncateText));
+        registry
+    }
+
+    /// Add a transformer, replacing any with the same id
+    pub fn register(&mut self, transformer: Arc<dyn OutputTransformer>) {
+        self.transformers.insert(transformer.id().to_string(), transformer);
+    }
+
+    pub fn get(&self, id: &str) -> Option<Arc<dyn OutputTransformer>> {
+        self.transformers.get(id).cloned()
+    }
+
+    /// Registered ids, sorted
+    pub fn list(&self) -> Vec<&str> {
+        let mut ids: Vec<&str> = self.transformers.keys().map(String::as_str).collect();
+        ids.sort();
+        ids
+    }
+
+    /// Run `steps` in order over `output`, stopping at the first failure
+    pub fn apply(&self, steps: &[TransformerStep], output: &mut EngineOutput) -> Result<(), EngineError> {
+        for step in steps {
+            let transformer = self.get(&step.id).ok_or_else(|| {
+                EngineError::ConfigError(format!("Transformer '{}' is not registered", step.id))
+            })?;
+            transformer.transform(output, &step.config)?;
+        }
+        Ok(())
+    }
+}
+
+impl Default for TransformerRegistry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn parse_config<T: for<'de> Deserialize<'de>>(id: &str, config: &Value) -> Result<T, String> {
+    T::deserialize(config).map_err(|e| format!("Invalid config for '{}': {}", id, e))
+}
+
+#[derive(Deserialize)]
+struct StripFieldsConfig {
+    fields: Vec<String>,
+}
+
+/// Removes named keys at any depth of the result
+struct StripFields;
+
+impl StripFields {
+    const ID: &'static str = "strip-fields";
+
+    fn strip(value: &mut Value, fields: &[String]) {
+        match value {
+            Value::Object(map) => {
+                map.retain(|key, _| !fields.contains(key));
+                map.values_mut().for_each(|v| Self::strip(v, fields));
+            }
+            Value::Array(items) => items.iter_mut().for_each(|v| Self::strip(v, fields)),
+            _ => {}
+        }
+    }
+}
+
+impl OutputTransformer for StripFields {
+    fn id(&self) -> &str {
+        Self::ID
+    }
+
+    fn validate_config(&self, config: &Value) -> Result<(), String> {
+        parse_config::<StripFieldsConfig>(Self::ID, config).map(|_| ())
+    }
+
+    fn transform(&self, output: &mut EngineOutput, config: &Value) -> Result<(), EngineError> {
+        let config: StripFieldsConfig =
+            parse_config(Self::ID, config).map_err(EngineError::ConfigError)?;
+        if config.fields.iter().any(|f| f == "witness_prompt") {
+            output.witness_prompt.clear();
+        }
+        if config.fields.iter().any(|f| f == "summary") {
+            output.summary = None;
+        }
+        Self::strip(&mut output.result, &config.fields);
+        Ok(())
+    }
+}
+
+#[derive(Deserialize)]
+struct TruncateTextConfig {
+    max_chars: usize,
+}
+
+/// Caps the length of every string in the output
+struct TruncateText;
+
+impl TruncateText {
+    const ID: &'static str = "truncate-text";
+
+    fn truncate(text: &mut String, max_chars: usize) {
+        if let Some((cut, _)) = text.char_indices().nth(max_chars) {
+            text.truncate(cut);
+            text.push('…');
+        }
+    }
+
+    fn truncate_value(value: &mut Value, max_chars: usize) {
+        match value {
+            Value::String(text) => Self::truncate(text, max_chars),
+            Value::Object(map) => map.values_mut().for_each(|v| Self::truncate_value(v, max_chars)),
+            Value::Array(items) => items.iter_mut().for_each(|v| Self::truncate_value(v, max_chars)),
+            _ => {}
+        }
+    }
+}
+
+impl OutputTransformer for TruncateText {
+    fn id(&self) -> &str {
+        Self::ID
+    }
+
+    fn validate_config(&self, config: &Value) -> Result<(), String> {
+        match parse_config::<TruncateTextConfig>(Self::ID, config)? {
+            TruncateTextConfig { max_chars: 0 } => Err("max_chars must be at least 1".to_string()),
+            _ => Ok(()),
+        }
+    }
+
+    fn transform(&self, output: &mut EngineOutput, config: &Value) -> Result<(), EngineError> {
+        let config: TruncateTextConfig =
+            parse_config(Self::ID, config).map_err(EngineError::ConfigError)?;
+        Self::truncate(&mut output.witness_prompt, config.max_chars);
+        if let Some(summary) = output.summary.as_mut() {
+            Self::truncate(summary, config.max_chars);
+        }
+        Self::truncate_value(&mut output.result, config.max_chars);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+    use noesis_core::CalculationMetadata;
+    use serde_json::json;
+
+    fn output() -> EngineOutput {
+        EngineOutput {
+            engine_id: "gene-keys".to_string(),
+            result: json!({
+                "keys": [{ "name": "Life's Work", "wisdom": "A long passage", "gift": "Vision" }],
+                "wisdom": "Top-level passage"
+            }),
+            witness_prompt: "What are you noticing?".to_string(),
+            summary: Some("Your keys point to vision.".to_string()),
+            consciousness_level: 1,
+            metadata: CalculationMetadata {
+                calculation_time_ms: 1.0,
+                backend: "native".to_string(),
+                precision_achieved: "standard".to_string(),
+                cached: false,
+                timestamp: Utc::now(),
+                engine_version: None,
+                warnings: Vec::new(),
+                usage: None,
+            },
+        }
+    }
+
+    fn step(id: &str, config: Value) -> TransformerStep {
+        TransformerStep { id: id.to_string(), config }
+    }
+
+    #[test]
+    fn test_steps_run_in_order() {
+        let registry = TransformerRegistry::new();
+        let mut out = output();
+        registry
+            .apply(
+                &[
+                    step("strip-fields", json!({ "fields": ["wisdom", "witness_prompt"] })),
+                    step("truncate-text", json!({ "max_chars": 3 })),
+                ],
+                &mut out,
+            )
+            .unwrap();
+
+        assert_eq!(out.result, json!({ "keys": [{ "name": "Lif…", "gift": "Vis…" }] }));
+        assert_eq!(out.witness_prompt, "");
+        assert_eq!(out.summary.as_deref(), Some("You…"));
+    }
+
+    #[test]
+    fn test_strip_summary() {
+        let registry = TransformerRegistry::new();
+        let mut out = output();
+        registry
+            .apply(&[step("strip-fields", json!({ "fields": ["summary"] }))], &mut out)
+            .unwrap();
+        assert!(out.summary.is_none());
+        assert_eq!(out.witness_prompt, "What are you noticing?");
+    }
+
+    #[test]
+    fn test_invalid_config_and_unknown_ids() {
+        let registry = TransformerRegistry::new();
+        assert_eq!(registry.list(), ["strip-fields", "truncate-text"]);
+
+        let truncate = registry.get("truncate-text").unwrap();
+        assert!(truncate.validate_config(&json!({ "max_chars": 0 })).is_err());
+        assert!(truncate.validate_config(&Value::Null).is_err());
+        assert!(registry.get("strip-fields").unwrap().validate_config(&json!({ "fields": [] })).is_ok());
+
+        let err = registry.apply(&[step("localize", Value::Null)], &mut output()).unwrap_err();
+        assert!(matches!(err, EngineError::ConfigError(_)));
+    }
+}
@@ -32,14 +32,14 @@ pub struct NumerologyData {
 impl NumerologyData {
     /// Extract from engine output JSON
     pub fn from_json(value: &Value) -> Option<Self> {
-        let life_path = value.get("life_path")?.as_u64()? as u8;
+        let life_path = number_value(value.get("life_path")?)? as u8;
         let expression = value.get("expression_number")
             .or_else(|| value.get("expression"))
-            .and_then(|v| v.as_u64())
+            .and_then(number_value)
             .unwrap_or(0) as u8;
         let soul_urge = value.get("soul_urge_number")
             .or_else(|| value.get("soul_urge"))
-            .and_then(|v| v.as_u64())
+            .and_then(number_value)
             .unwrap_or(0) as u8;
 
         Some(Self {
@@ -62,6 +62,11 @@ impl NumerologyData {
     }
 }
 
+/// A number given either bare or as the numerology engine's `{ "value": n, ... }`
+fn number_value(value: &Value) -> Option<u64> {
+    value.as_u64().or_else(|| value.get("value")?.as_u64())
+}
+
 /// Human Design engine data relevant to synthesis
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct HumanDesignData {
@@ -75,8 +80,11 @@ pub struct HumanDesignData {
 impl HumanDesignData {
     /// Extract from engine output JSON
     pub fn from_json(value: &Value) -> Option<Self> {
+        // The engine emits `hd_type` with variant names ("ManifestingGenerator")
         let hd_type = value.get("type")
+            .or_else(|| value.get("hd_type"))
             .and_then(|v| v.as_str())
+            .map(|s| if s == "ManifestingGenerator" { "Manifesting Generator" } else { s })
             .map(|s| s.to_string())
             .unwrap_or_else(|| "Unknown".to_string());
         
@@ -241,6 +249,8 @@ fn dasha_lord(dasha: &str) -> String {
 pub fn create_birth_blueprint_input(birth_data: BirthData) -> EngineInput {
     EngineInput {
         birth_data: Some(birth_data),
+        secondary_birth_data: None,
+        subjects: Vec::new(),
         current_time: chrono::Utc::now(),
         location: None,
         precision: noesis_core::Precision::Standard,
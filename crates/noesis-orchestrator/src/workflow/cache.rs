@@ -51,6 +51,8 @@ impl WorkflowTtl {
             "decision-support" => Self::Archetypal,
             "self-inquiry" => Self::Natal,
             "creative-expression" => Self::Archetypal,
+            "relationship" => Self::Natal,
+            "vocation-blueprint" => Self::Natal,
             "full-spectrum" => Self::FullSpectrum,
             _ => Self::Temporal, // Default
         }
@@ -376,6 +378,8 @@ mod tests {
             synthesis: None,
             total_time_ms: 100.0,
             timestamp: Utc::now(),
+            usage: None,
+            time_confidence: None,
         }
     }
 
@@ -17,6 +17,8 @@ pub struct DailyPracticeData {
     pub vedic_clock: Option<VedicClockData>,
     /// From Biorhythm
     pub biorhythm: Option<BiorhythmData>,
+    /// From Panchanga, when birth data was supplied
+    pub daily_strength: Option<DailyStrengthData>,
 }
 
 /// Panchanga engine data relevant to synthesis
@@ -121,6 +123,36 @@ impl PanchangaData {
     }
 }
 
+/// The person's Tarabala and Chandrabala for the day, from Panchanga
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DailyStrengthData {
+    pub tara: String,
+    pub tarabala_favourable: bool,
+    /// House of the day's Moon from the natal Moon
+    pub moon_house: u8,
+    pub chandrabala_favourable: bool,
+    pub chandrashtama: bool,
+    /// 0-1
+    pub score: f64,
+}
+
+impl DailyStrengthData {
+    /// Extract from the `daily_strength` of a panchanga result
+    pub fn from_json(value: &Value) -> Option<Self> {
+        let strength = value.get("daily_strength")?;
+        let tarabala = strength.get("tarabala")?;
+        let chandrabala = strength.get("chandrabala")?;
+        Some(Self {
+            tara: tarabala.get("name")?.as_str()?.to_string(),
+            tarabala_favourable: tarabala.get("favourable").and_then(|v| v.as_bool()).unwrap_or(false),
+            moon_house: chandrabala.get("house")?.as_u64()? as u8,
+            chandrabala_favourable: chandrabala.get("favourable").and_then(|v| v.as_bool()).unwrap_or(false),
+            chandrashtama: chandrabala.get("chandrashtama").and_then(|v| v.as_bool()).unwrap_or(false),
+            score: strength.get("score").and_then(|v| v.as_f64()).unwrap_or(0.5),
+        })
+    }
+}
+
 /// Vedic Clock engine data relevant to synthesis
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct VedicClockData {
@@ -347,11 +379,14 @@ fn tithi_quality(number: u8) -> String {
 pub fn create_daily_practice_input(current_time: DateTime<Utc>, latitude: f64, longitude: f64) -> EngineInput {
     EngineInput {
         birth_data: None,
+        secondary_birth_data: None,
+        subjects: Vec::new(),
         current_time,
         location: Some(noesis_core::Coordinates {
             latitude,
             longitude,
             altitude: None,
+            timezone: None,
         }),
         precision: noesis_core::Precision::Standard,
         options: std::collections::HashMap::new(),
@@ -402,6 +437,28 @@ mod tests {
         assert_eq!(data.tithi.name, "Shukla Panchami");
         assert_eq!(data.tithi.number, 5);
         assert_eq!(data.nakshatra.name, "Rohini");
+        assert!(DailyStrengthData::from_json(&json).is_none());
+    }
+
+    #[test]
+    fn daily_strength_from_json() {
+        let json = json!({
+            "nakshatra_name": "Hasta",
+            "daily_strength": {
+                "date": "2026-10-15",
+                "birth_nakshatra": "Rohini",
+                "nakshatra": "Hasta",
+                "tarabala": {"count": 10, "tara": 1, "name": "Janma", "score": 0.4, "favourable": false},
+                "chandrabala": {"house": 5, "score": 0.5, "favourable": true, "chandrashtama": false},
+                "score": 0.45
+            }
+        });
+
+        let data = DailyStrengthData::from_json(&json).unwrap();
+        assert_eq!(data.tara, "Janma");
+        assert!(!data.tarabala_favourable && data.chandrabala_favourable);
+        assert_eq!(data.moon_house, 5);
+        assert_eq!(data.score, 0.45);
     }
 
     #[test]
@@ -4,9 +4,12 @@
 
 use super::models::{SynthesisResult, WorkflowOutput};
 use super::registry::WorkflowRegistry;
-use super::synthesis::{BirthBlueprintSynthesizer, DailyPracticeSynthesizer, Synthesizer};
+use super::synthesis::{
+    BirthBlueprintSynthesizer, DailyPracticeSynthesizer, RelationshipSynthesizer, Synthesizer,
+    VocationBlueprintSynthesizer,
+};
 use super::witness::generate_workflow_witness_prompts;
-use super::{ExtendedWorkflowDefinition, SynthesisType};
+use super::{ExtendedWorkflowDefinition, RelationshipWorkflow, SynthesisType};
 use crate::EngineRegistry;
 use chrono::Utc;
 use futures::future::join_all;
@@ -71,10 +74,15 @@ impl WorkflowExecutor {
     pub async fn execute_workflow(
         &self,
         workflow: &ExtendedWorkflowDefinition,
-        input: EngineInput,
+        mut input: EngineInput,
         user_phase: u8,
     ) -> Result<WorkflowOutput, EngineError> {
         let start = Instant::now();
+        input.apply_as_of()?;
+        let secondary_input = match workflow.synthesis_type {
+            SynthesisType::Relationship => Some(RelationshipWorkflow::secondary_input(&input)?),
+            _ => None,
+        };
 
         info!(
             workflow_id = %workflow.id,
@@ -83,10 +91,32 @@ impl WorkflowExecutor {
         );
 
         // Execute all engines in parallel
-        let engine_results = self
+        let mut engine_results = self
             .execute_engines_parallel(&workflow.engine_ids, input.clone(), user_phase)
             .await;
 
+        // Second person's pass for engines that only read `birth_data`
+        if let Some(secondary_input) = secondary_input {
+            let single_person_ids: Vec<String> = workflow
+                .engine_ids
+                .iter()
+                .filter(|id| {
+                    self.engine_registry
+                        .get(id.as_str())
+                        .is_some_and(|engine| !engine.supports_compatibility())
+                })
+                .cloned()
+                .collect();
+            let secondary_results = self
+                .execute_engines_parallel(&single_person_ids, secondary_input, user_phase)
+                .await;
+            engine_results.extend(
+                secondary_results
+                    .into_iter()
+                    .map(|(id, output)| (RelationshipWorkflow::secondary_key(&id), output)),
+            );
+        }
+
         let execution_time_ms = start.elapsed().as_millis() as u64;
 
         info!(
@@ -102,6 +132,12 @@ impl WorkflowExecutor {
         // Generate witness prompts from synthesis
         let witness_prompts = generate_workflow_witness_prompts(&synthesis, user_phase);
 
+        noesis_metrics::record_workflow_execution(
+            &workflow.id,
+            engine_results.len(),
+            start.elapsed().as_secs_f64(),
+        );
+
         Ok(WorkflowOutput {
             workflow_id: workflow.id.clone(),
             engine_results,
@@ -144,6 +180,7 @@ impl WorkflowExecutor {
                             user_phase,
                             "Phase access denied, skipping"
                         );
+                        noesis_metrics::record_phase_denied(&engine_id_owned);
                         return (engine_id_owned, None);
                     }
 
@@ -183,6 +220,12 @@ impl WorkflowExecutor {
             SynthesisType::DailyPractice => {
                 DailyPracticeSynthesizer::synthesize(results, input)
             }
+            SynthesisType::Relationship => {
+                RelationshipSynthesizer::synthesize(results, input)
+            }
+            SynthesisType::VocationBlueprint => {
+                VocationBlueprintSynthesizer::synthesize(results, input)
+            }
             // TODO: Implement other synthesizers
             _ => self.generic_synthesis(results),
         }
@@ -255,6 +298,7 @@ mod tests {
                 engine_id: self.id.clone(),
                 result: serde_json::json!({ "mock": true }),
                 witness_prompt: format!("Witness from {}", self.id),
+                summary: None,
                 consciousness_level: self.phase,
                 metadata: CalculationMetadata {
                     calculation_time_ms: 1.0,
@@ -262,6 +306,9 @@ mod tests {
                     precision_achieved: "standard".to_string(),
                     cached: false,
                     timestamp: Utc::now(),
+                    engine_version: None,
+                    warnings: Vec::new(),
+                    usage: None,
                 },
             })
         }
@@ -282,6 +329,8 @@ mod tests {
     fn test_input() -> EngineInput {
         EngineInput {
             birth_data: None,
+            secondary_birth_data: None,
+            subjects: Vec::new(),
             current_time: Utc::now(),
             location: None,
             precision: Precision::Standard,
@@ -342,4 +391,34 @@ mod tests {
             Err(EngineError::PhaseAccessDenied { required: 3, current: 1 })
         ));
     }
+
+    #[tokio::test]
+    async fn execute_relationship_runs_each_engine_per_person() {
+        let executor = setup_executor_with_mocks();
+        let result = executor.execute("relationship", test_input(), 5).await;
+        assert!(matches!(result, Err(EngineError::InvalidInput(_))));
+
+        let birth = noesis_core::BirthData {
+            name: None,
+            display_name: None,
+            date: "1990-01-15".to_string(),
+            time: Some("12:00".to_string()),
+            time_unknown: false,
+            latitude: 51.5,
+            longitude: -0.12,
+            altitude: None,
+            timezone: "Europe/London".to_string(),
+            place: None,
+            utc_offset_minutes: None,
+        };
+        let input = EngineInput {
+            birth_data: Some(birth.clone()),
+            secondary_birth_data: Some(birth),
+            ..test_input()
+        };
+        let output = executor.execute("relationship", input, 5).await.unwrap();
+
+        assert_eq!(output.engine_results.len(), 6);
+        assert!(output.engine_results.contains_key("human-design:secondary"));
+    }
 }
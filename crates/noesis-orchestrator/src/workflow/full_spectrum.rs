@@ -212,9 +212,10 @@ impl FullSpectrumWorkflow {
     }
 
     /// Execute all engines in parallel
-    pub async fn execute(&self, input: EngineInput) -> Result<FullSpectrumResult, EngineError> {
+    pub async fn execute(&self, mut input: EngineInput) -> Result<FullSpectrumResult, EngineError> {
         let execution_id = format!("fs-{}", Utc::now().timestamp_millis());
         let start = Instant::now();
+        input.apply_as_of()?;
 
         info!(
             execution_id = %execution_id,
@@ -288,11 +289,12 @@ impl FullSpectrumWorkflow {
     /// Execute only engines in specific categories
     pub async fn execute_categories(
         &self,
-        input: EngineInput,
+        mut input: EngineInput,
         categories: &[EngineCategory],
     ) -> Result<FullSpectrumResult, EngineError> {
         let execution_id = format!("fs-cat-{}", Utc::now().timestamp_millis());
         let start = Instant::now();
+        input.apply_as_of()?;
 
         // Filter engines by category
         let category_engine_ids: Vec<&str> = categories
@@ -430,6 +432,7 @@ mod tests {
                 engine_id: self.id.clone(),
                 result: serde_json::json!({ "mock": true }),
                 witness_prompt: format!("Witness from {}", self.id),
+                summary: None,
                 consciousness_level: self.phase,
                 metadata: CalculationMetadata {
                     calculation_time_ms: 1.0,
@@ -437,6 +440,9 @@ mod tests {
                     precision_achieved: "standard".to_string(),
                     cached: false,
                     timestamp: Utc::now(),
+                    engine_version: None,
+                    warnings: Vec::new(),
+                    usage: None,
                 },
             })
         }
@@ -457,6 +463,8 @@ mod tests {
     fn test_input() -> EngineInput {
         EngineInput {
             birth_data: None,
+            secondary_birth_data: None,
+            subjects: Vec::new(),
             current_time: Utc::now(),
             location: None,
             precision: noesis_core::Precision::Standard,
@@ -10,6 +10,8 @@
 //! - **Decision Support**: Multi-perspective guidance (tarot, i-ching, human-design)
 //! - **Self-Inquiry**: Shadow work synthesis (gene-keys, enneagram)
 //! - **Creative Expression**: Generative guidance (sigil-forge, sacred-geometry)
+//! - **Relationship**: Two people's natal patterns (numerology, human-design, vimshottari per person)
+//! - **Vocation Blueprint**: Work style from HD type, authority and profile lines with the Gene Keys Pearl sequence
 //! - **Full Spectrum**: All-engine integration
 
 pub mod cache;
@@ -23,6 +25,8 @@ pub mod daily_practice;
 pub mod decision_support;
 pub mod self_inquiry;
 pub mod creative_expression;
+pub mod relationship;
+pub mod vocation_blueprint;
 pub mod witness;
 
 // Re-export primary types
@@ -39,6 +43,8 @@ pub use executor::WorkflowExecutor;
 pub use decision_support::DecisionSupportWorkflow;
 pub use self_inquiry::SelfInquiryWorkflow;
 pub use creative_expression::CreativeExpressionWorkflow;
+pub use relationship::RelationshipWorkflow;
+pub use vocation_blueprint::{VocationBlueprintWorkflow, VocationProfile};
 
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
@@ -57,6 +63,10 @@ pub enum SynthesisType {
     SelfInquiry,
     /// Creative expression: Sigil + Sacred Geometry combination
     CreativeExpression,
+    /// Relationship: two people's natal engines side by side
+    Relationship,
+    /// Vocation blueprint: HD work style + Gene Keys Pearl sequence
+    VocationBlueprint,
     /// Full spectrum: All engines integration
     FullSpectrum,
     /// No synthesis (raw engine outputs only)
@@ -130,6 +140,9 @@ impl ExtendedWorkflowDefinition {
             name: self.name.clone(),
             description: self.description.clone(),
             engine_ids: self.engine_ids.clone(),
+            engine_overrides: HashMap::new(),
+            transformers: Vec::new(),
+            phase_variants: Default::default(),
         }
     }
 }
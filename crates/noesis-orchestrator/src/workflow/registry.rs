@@ -1,8 +1,8 @@
 //! Workflow Registry — Pre-defined workflow definitions
 //!
-//! Manages the 6 canonical workflows plus any custom-registered workflows.
+//! Manages the 8 canonical workflows plus any custom-registered workflows.
 
-use super::{ExtendedWorkflowDefinition, SynthesisType};
+use super::{ExtendedWorkflowDefinition, RelationshipWorkflow, SynthesisType, VocationBlueprintWorkflow};
 use std::collections::HashMap;
 
 /// Registry of all available workflow definitions
@@ -11,7 +11,7 @@ pub struct WorkflowRegistry {
 }
 
 impl WorkflowRegistry {
-    /// Create a new registry pre-populated with the 6 canonical workflows
+    /// Create a new registry pre-populated with the 8 canonical workflows
     pub fn new() -> Self {
         let mut registry = Self {
             workflows: HashMap::new(),
@@ -20,7 +20,7 @@ impl WorkflowRegistry {
         registry
     }
 
-    /// Register the 6 canonical workflows
+    /// Register the 8 canonical workflows
     fn register_default_workflows(&mut self) {
         // Birth Blueprint: Core identity mapping through birth data
         self.register(ExtendedWorkflowDefinition {
@@ -95,6 +95,12 @@ impl WorkflowRegistry {
             default_options: HashMap::new(),
         });
 
+        // Relationship: Two people's natal engines side by side
+        self.register(RelationshipWorkflow::definition());
+
+        // Vocation Blueprint: Work style from HD and the Gene Keys Pearl sequence
+        self.register(VocationBlueprintWorkflow::definition());
+
         // Full Spectrum: All 11+ engines integrated
         self.register(ExtendedWorkflowDefinition {
             id: "full-spectrum".into(),
@@ -179,9 +185,9 @@ mod tests {
     use super::*;
 
     #[test]
-    fn new_registry_has_six_workflows() {
+    fn new_registry_has_eight_workflows() {
         let registry = WorkflowRegistry::new();
-        assert_eq!(registry.len(), 6);
+        assert_eq!(registry.len(), 8);
     }
 
     #[test]
@@ -213,7 +219,7 @@ mod tests {
         
         // Phase 3 should get all
         let phase3 = registry.list_for_phase(3);
-        assert_eq!(phase3.len(), 6);
+        assert_eq!(phase3.len(), 8);
     }
 
     #[test]
@@ -240,7 +246,7 @@ mod tests {
             default_options: HashMap::new(),
         });
 
-        assert_eq!(registry.len(), 7);
+        assert_eq!(registry.len(), 9);
         assert!(registry.contains("custom"));
     }
 }
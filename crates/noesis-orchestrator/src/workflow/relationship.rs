@@ -0,0 +1,122 @@
+//! Relationship Workflow
+//!
+//! Looks at two people together: the `primary` subject (or `birth_data`) is
+//! the first person and the `secondary` subject (or `secondary_birth_data`)
+//! the second.
+//!
+//! # Engines
+//! - **Numerology**, **Human Design**, **Vimshottari**: calculated once per
+//!   person; the second person's output is keyed `<engine>:secondary`
+//! - Engines whose `supports_compatibility()` is true run once and compare
+//!   both births themselves
+
+use super::{ExtendedWorkflowDefinition, SynthesisType};
+use noesis_core::{EngineError, EngineInput, ValidationCode, WorkflowDefinition};
+use std::collections::HashMap;
+
+/// Relationship Workflow implementation
+pub struct RelationshipWorkflow;
+
+impl RelationshipWorkflow {
+    /// Workflow identifier
+    pub const ID: &'static str = "relationship";
+
+    /// Required consciousness phase
+    pub const REQUIRED_PHASE: u8 = 1;
+
+    /// Suffix of the engine output keys calculated for the second person
+    pub const SECONDARY_SUFFIX: &'static str = ":secondary";
+
+    /// Returns the extended workflow definition with synthesis support
+    pub fn definition() -> ExtendedWorkflowDefinition {
+        ExtendedWorkflowDefinition {
+            id: Self::ID.to_string(),
+            name: "Relationship".to_string(),
+            description: "Two birth charts side by side: shared themes, \
+                         alignments and tensions between both people".to_string(),
+            engine_ids: vec![
+                "numerology".to_string(),
+                "human-design".to_string(),
+                "vimshottari".to_string(),
+            ],
+            synthesis_type: SynthesisType::Relationship,
+            required_phase: Self::REQUIRED_PHASE,
+            default_options: HashMap::new(),
+        }
+    }
+
+    /// Returns the base workflow definition
+    pub fn base_definition() -> WorkflowDefinition {
+        Self::definition().to_base()
+    }
+
+    /// Output key for an engine calculated for the second person
+    pub fn secondary_key(engine_id: &str) -> String {
+        format!("{}{}", engine_id, Self::SECONDARY_SUFFIX)
+    }
+
+    /// The input for the second person's runs; fails without `secondary_birth_data`
+    pub fn secondary_input(input: &EngineInput) -> Result<EngineInput, EngineError> {
+        input.for_secondary().ok_or_else(|| {
+            EngineError::invalid_field(
+                "secondary_birth_data",
+                ValidationCode::Required,
+                "The relationship workflow needs birth data for both people",
+            )
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+    use noesis_core::{BirthData, Precision};
+
+    fn birth(date: &str) -> BirthData {
+        BirthData {
+            name: None,
+            display_name: None,
+            date: date.to_string(),
+            time: Some("12:00".to_string()),
+            time_unknown: false,
+            latitude: 51.5,
+            longitude: -0.12,
+            altitude: None,
+            timezone: "Europe/London".to_string(),
+            place: None,
+            utc_offset_minutes: None,
+        }
+    }
+
+    #[test]
+    fn test_definition() {
+        let def = RelationshipWorkflow::definition();
+        assert_eq!(def.id, "relationship");
+        assert_eq!(def.synthesis_type, SynthesisType::Relationship);
+        assert_eq!(RelationshipWorkflow::base_definition().engine_ids, def.engine_ids);
+        assert_eq!(RelationshipWorkflow::secondary_key("numerology"), "numerology:secondary");
+    }
+
+    #[test]
+    fn test_secondary_input_swaps_people() {
+        let mut input = EngineInput {
+            birth_data: Some(birth("1990-01-15")),
+            secondary_birth_data: None,
+            subjects: Vec::new(),
+            current_time: Utc::now(),
+            location: None,
+            precision: Precision::Standard,
+            options: HashMap::new(),
+        };
+        assert!(matches!(
+            RelationshipWorkflow::secondary_input(&input),
+            Err(EngineError::InvalidInput(e)) if e[0].field == "secondary_birth_data"
+        ));
+
+        input.secondary_birth_data = Some(birth("1988-07-04"));
+        let secondary = RelationshipWorkflow::secondary_input(&input).unwrap();
+        assert_eq!(secondary.birth_data.unwrap().date, "1988-07-04");
+        assert_eq!(secondary.secondary_birth_data.unwrap().date, "1990-01-15");
+    }
+}
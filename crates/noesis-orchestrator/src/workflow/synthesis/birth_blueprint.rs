@@ -319,6 +319,7 @@ mod tests {
             engine_id: engine_id.to_string(),
             result,
             witness_prompt: String::new(),
+            summary: None,
             consciousness_level: 0,
             metadata: noesis_core::CalculationMetadata {
                 calculation_time_ms: 1.0,
@@ -326,6 +327,9 @@ mod tests {
                 precision_achieved: "standard".to_string(),
                 cached: false,
                 timestamp: chrono::Utc::now(),
+                engine_version: None,
+                warnings: Vec::new(),
+                usage: None,
             },
         }
     }
@@ -358,6 +362,8 @@ mod tests {
 
         let input = EngineInput {
             birth_data: None,
+            secondary_birth_data: None,
+            subjects: Vec::new(),
             current_time: chrono::Utc::now(),
             location: None,
             precision: noesis_core::Precision::Standard,
@@ -393,6 +399,8 @@ mod tests {
 
         let input = EngineInput {
             birth_data: None,
+            secondary_birth_data: None,
+            subjects: Vec::new(),
             current_time: chrono::Utc::now(),
             location: None,
             precision: noesis_core::Precision::Standard,
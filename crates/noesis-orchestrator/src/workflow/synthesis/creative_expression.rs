@@ -19,6 +19,8 @@ impl CreativeExpressionSynthesis {
     pub fn synthesize_results(results: &HashMap<String, EngineOutput>) -> SynthesisResult {
         let dummy_input = EngineInput {
             birth_data: None,
+            secondary_birth_data: None,
+            subjects: Vec::new(),
             current_time: chrono::Utc::now(),
             location: None,
             precision: noesis_core::Precision::Standard,
@@ -394,10 +396,14 @@ mod tests {
                 "method": "letter_elimination"
             }),
             witness_prompt: "What does this sigil evoke?".to_string(),
+            summary: None,
             consciousness_level: 1,
             metadata: CalculationMetadata {
                 calculation_time_ms: 5.0, backend: "bridge".to_string(),
                 precision_achieved: "standard".to_string(), cached: false, timestamp: Utc::now(),
+                engine_version: None,
+                warnings: Vec::new(),
+                usage: None,
             },
         }
     }
@@ -411,17 +417,22 @@ mod tests {
                 "meditation": "Contemplate the seven circles"
             }),
             witness_prompt: "What do you see in this form?".to_string(),
+            summary: None,
             consciousness_level: 1,
             metadata: CalculationMetadata {
                 calculation_time_ms: 8.0, backend: "bridge".to_string(),
                 precision_achieved: "standard".to_string(), cached: false, timestamp: Utc::now(),
+                engine_version: None,
+                warnings: Vec::new(),
+                usage: None,
             },
         }
     }
 
     fn test_input() -> EngineInput {
         EngineInput {
-            birth_data: None, current_time: Utc::now(), location: None,
+            birth_data: None, secondary_birth_data: None, subjects: Vec::new(),
+            current_time: Utc::now(), location: None,
             precision: noesis_core::Precision::Standard, options: HashMap::new(),
         }
     }
@@ -4,9 +4,10 @@
 //! - Panchanga: Tithi, Nakshatra, Yoga quality
 //! - Vedic Clock: Dosha time, Muhurta quality
 //! - Biorhythm: Physical, emotional, intellectual cycles
+//! - Personal day strength: Tarabala and Chandrabala from Panchanga
 
 use super::Synthesizer;
-use crate::workflow::daily_practice::{BiorhythmData, PanchangaData, VedicClockData};
+use crate::workflow::daily_practice::{BiorhythmData, DailyStrengthData, PanchangaData, VedicClockData};
 use crate::workflow::models::{
     Alignment, SynthesisResult as ExtSynthesisResult, Tension, Theme,
 };
@@ -37,6 +38,10 @@ impl Synthesizer for DailyPracticeSynthesizer {
             .get("biorhythm")
             .and_then(|o| BiorhythmData::from_json(&o.result));
 
+        let strength = results
+            .get("panchanga")
+            .and_then(|o| DailyStrengthData::from_json(&o.result));
+
         let mut themes = Vec::new();
         let mut alignments = Vec::new();
         let mut tensions = Vec::new();
@@ -105,8 +110,22 @@ impl Synthesizer for DailyPracticeSynthesizer {
             tensions.push(tension);
         }
 
+        if let Some(tension) = find_strength_tension(&strength, &biorhythm) {
+            tensions.push(tension);
+        } else if let Some(alignment) = find_strength_alignment(&strength, &biorhythm) {
+            alignments.push(alignment);
+        }
+
         // Generate summary
-        let summary = generate_daily_summary(&panchanga, &vedic_clock, &biorhythm, &themes, &alignments, &tensions);
+        let mut summary = generate_daily_summary(&panchanga, &vedic_clock, &biorhythm, &themes, &alignments, &tensions);
+        if let Some(ref strength) = strength {
+            summary.push_str(&format!(
+                " Your day strength is {:.0}%: {} Tarabala, Moon in house {} from your natal Moon.",
+                strength.score * 100.0,
+                strength.tara,
+                strength.moon_house
+            ));
+        }
 
         SynthesisResult {
             themes,
@@ -330,6 +349,68 @@ fn find_rhythm_tension(
     None
 }
 
+/// Find alignment between a strong personal day and rising cycles
+fn find_strength_alignment(
+    strength: &Option<DailyStrengthData>,
+    biorhythm: &Option<BiorhythmData>,
+) -> Option<Alignment> {
+    let strength = strength.as_ref()?;
+    if !(strength.tarabala_favourable && strength.chandrabala_favourable) {
+        return None;
+    }
+    let mut engines = vec!["panchanga".to_string()];
+    let mut confidence = 0.6;
+    if biorhythm.as_ref().is_some_and(|bio| bio.composite > 0.3) {
+        engines.push("biorhythm".to_string());
+        confidence = 0.8;
+    }
+    Some(
+        Alignment::new(
+            "Personal day strength",
+            format!(
+                "{} Tarabala and Chandrabala from house {} both support you today",
+                strength.tara, strength.moon_house
+            ),
+        )
+        .with_engines(engines)
+        .with_confidence(confidence),
+    )
+}
+
+/// Find tension between a weak personal day and high personal cycles
+fn find_strength_tension(
+    strength: &Option<DailyStrengthData>,
+    biorhythm: &Option<BiorhythmData>,
+) -> Option<Tension> {
+    let strength = strength.as_ref()?;
+    let bio = biorhythm.as_ref()?;
+    let weak_day = strength.chandrashtama || !strength.tarabala_favourable;
+    if !weak_day || bio.composite <= 0.3 {
+        return None;
+    }
+    let weakness = if strength.chandrashtama {
+        "Chandrashtama: the Moon is in the 8th house from your natal Moon".to_string()
+    } else {
+        format!("{} Tarabala, an unfavourable tara for you today", strength.tara)
+    };
+    Some(
+        Tension::new(
+            "Day Strength vs Personal Energy",
+            "High personal energy on a weak day for your birth star"
+        )
+        .with_perspectives(
+            "panchanga",
+            weakness,
+            "biorhythm",
+            format!("Your cycles show {:.1} composite energy", bio.composite)
+        )
+        .with_integration_hint(
+            "Use the energy for routine work and follow-through; keep new undertakings \
+            and important commitments for a stronger day."
+        )
+    )
+}
+
 /// Generate daily summary
 fn generate_daily_summary(
     panchanga: &Option<PanchangaData>,
@@ -415,6 +496,7 @@ mod tests {
             engine_id: engine_id.to_string(),
             result,
             witness_prompt: String::new(),
+            summary: None,
             consciousness_level: 0,
             metadata: noesis_core::CalculationMetadata {
                 calculation_time_ms: 1.0,
@@ -422,6 +504,9 @@ mod tests {
                 precision_achieved: "standard".to_string(),
                 cached: false,
                 timestamp: chrono::Utc::now(),
+                engine_version: None,
+                warnings: Vec::new(),
+                usage: None,
             },
         }
     }
@@ -467,6 +552,8 @@ mod tests {
 
         let input = EngineInput {
             birth_data: None,
+            secondary_birth_data: None,
+            subjects: Vec::new(),
             current_time: chrono::Utc::now(),
             location: None,
             precision: noesis_core::Precision::Standard,
@@ -482,6 +569,45 @@ mod tests {
         assert!(!synthesis.summary.is_empty());
     }
 
+    #[test]
+    fn weak_day_strength_with_high_cycles_is_a_tension() {
+        let mut results = HashMap::new();
+        results.insert("panchanga".to_string(), mock_output("panchanga", json!({
+            "nakshatra_name": "Shravana",
+            "daily_strength": {
+                "date": "2026-10-15",
+                "birth_nakshatra": "Rohini",
+                "nakshatra": "Shravana",
+                "tarabala": {"count": 19, "tara": 1, "name": "Janma", "score": 0.4, "favourable": false},
+                "chandrabala": {"house": 8, "score": 0.0, "favourable": false, "chandrashtama": true},
+                "score": 0.2
+            }
+        })));
+        results.insert("biorhythm".to_string(), mock_output("biorhythm", json!({
+            "physical": 0.7,
+            "emotional": 0.6,
+            "intellectual": 0.5
+        })));
+        let input = EngineInput {
+            birth_data: None,
+            secondary_birth_data: None,
+            subjects: Vec::new(),
+            current_time: chrono::Utc::now(),
+            location: None,
+            precision: noesis_core::Precision::Standard,
+            options: HashMap::new(),
+        };
+
+        let synthesis = DailyPracticeSynthesizer::synthesize(&results, &input);
+        let tension = synthesis
+            .tensions
+            .iter()
+            .find(|t| t.aspect == "Day Strength vs Personal Energy")
+            .expect("weak day tension");
+        assert!(tension.perspective_a.1.contains("Chandrashtama"));
+        assert!(synthesis.summary.contains("Janma Tarabala"));
+    }
+
     #[test]
     fn categorize_activities() {
         assert_eq!(categorize_activity("Physical exercise"), "Physical Activity");
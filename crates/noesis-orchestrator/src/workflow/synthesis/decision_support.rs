@@ -33,6 +33,8 @@ impl DecisionSupportSynthesis {
         // Create a dummy input for the trait method
         let dummy_input = EngineInput {
             birth_data: None,
+            secondary_birth_data: None,
+            subjects: Vec::new(),
             current_time: chrono::Utc::now(),
             location: None,
             precision: noesis_core::Precision::Standard,
@@ -539,6 +541,7 @@ mod tests {
                 ]
             }),
             witness_prompt: "What do these images evoke?".to_string(),
+            summary: None,
             consciousness_level: 1,
             metadata: CalculationMetadata {
                 calculation_time_ms: 10.0,
@@ -546,6 +549,9 @@ mod tests {
                 precision_achieved: "standard".to_string(),
                 cached: false,
                 timestamp: Utc::now(),
+                engine_version: None,
+                warnings: Vec::new(),
+                usage: None,
             },
         }
     }
@@ -558,6 +564,7 @@ mod tests {
                 "changing_lines": [2, 5]
             }),
             witness_prompt: "What does this hexagram show you?".to_string(),
+            summary: None,
             consciousness_level: 1,
             metadata: CalculationMetadata {
                 calculation_time_ms: 8.0,
@@ -565,6 +572,9 @@ mod tests {
                 precision_achieved: "standard".to_string(),
                 cached: false,
                 timestamp: Utc::now(),
+                engine_version: None,
+                warnings: Vec::new(),
+                usage: None,
             },
         }
     }
@@ -574,6 +584,7 @@ mod tests {
             engine_id: "human-design".to_string(),
             result: json!({ "authority": "sacral", "type": "Generator" }),
             witness_prompt: "How does your sacral respond?".to_string(),
+            summary: None,
             consciousness_level: 1,
             metadata: CalculationMetadata {
                 calculation_time_ms: 15.0,
@@ -581,6 +592,9 @@ mod tests {
                 precision_achieved: "standard".to_string(),
                 cached: false,
                 timestamp: Utc::now(),
+                engine_version: None,
+                warnings: Vec::new(),
+                usage: None,
             },
         }
     }
@@ -588,6 +602,8 @@ mod tests {
     fn test_input() -> EngineInput {
         EngineInput {
             birth_data: None,
+            secondary_birth_data: None,
+            subjects: Vec::new(),
             current_time: Utc::now(),
             location: None,
             precision: noesis_core::Precision::Standard,
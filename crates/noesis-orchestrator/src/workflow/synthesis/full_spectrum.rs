@@ -534,6 +534,7 @@ mod tests {
             engine_id: engine_id.to_string(),
             result,
             witness_prompt: prompt.to_string(),
+            summary: None,
             consciousness_level: 0,
             metadata: CalculationMetadata {
                 calculation_time_ms: 1.0,
@@ -541,6 +542,9 @@ mod tests {
                 precision_achieved: "standard".to_string(),
                 cached: false,
                 timestamp: Utc::now(),
+                engine_version: None,
+                warnings: Vec::new(),
+                usage: None,
             },
         }
     }
@@ -10,6 +10,8 @@
 //! - **DecisionSupportSynthesis**: Aligns Tarot, I-Ching, and HD Authority perspectives
 //! - **SelfInquirySynthesis**: Maps Gene Keys shadows to Enneagram core patterns
 //! - **CreativeExpressionSynthesis**: Combines Sigil and Sacred Geometry for creative direction
+//! - **RelationshipSynthesizer**: Compares two people's numerology, HD and vimshottari outputs
+//! - **VocationBlueprintSynthesizer**: Reads HD work style against the Gene Keys Pearl sequence
 //! - **FullSpectrumSynthesizer**: Integrates all engines
 
 pub mod full_spectrum;
@@ -18,6 +20,8 @@ pub mod daily_practice;
 pub mod decision_support;
 pub mod self_inquiry;
 pub mod creative_expression;
+pub mod relationship;
+pub mod vocation_blueprint;
 
 pub use full_spectrum::{CrossEngineTheme, FullSpectrumSynthesizer, ThemeCategory};
 pub use birth_blueprint::BirthBlueprintSynthesizer;
@@ -25,6 +29,8 @@ pub use daily_practice::DailyPracticeSynthesizer;
 pub use decision_support::DecisionSupportSynthesis;
 pub use self_inquiry::SelfInquirySynthesis;
 pub use creative_expression::CreativeExpressionSynthesis;
+pub use relationship::RelationshipSynthesizer;
+pub use vocation_blueprint::VocationBlueprintSynthesizer;
 
 use crate::workflow::models::SynthesisResult as ExtSynthesisResult;
 use noesis_core::{EngineInput, EngineOutput};
@@ -0,0 +1,277 @@
+//! Relationship Synthesis — Two natal patterns side by side
+//!
+//! Compares the first person's engine outputs with the `:secondary` outputs
+//! of the second person:
+//! - Themes both charts carry
+//! - Shared Life Path, Authority or Mahadasha lord as alignments
+//! - Differing HD Type or Authority as tensions framed for inquiry
+
+use super::Synthesizer;
+use crate::workflow::birth_blueprint::{HumanDesignData, NumerologyData, VimshottariData};
+use crate::workflow::models::{
+    Alignment, SynthesisResult as ExtSynthesisResult, Tension, Theme,
+};
+use crate::workflow::relationship::RelationshipWorkflow;
+use noesis_core::{EngineInput, EngineOutput};
+use std::collections::{BTreeMap, HashMap};
+
+/// Synthesizer for the Relationship workflow
+pub struct RelationshipSynthesizer;
+
+/// What the synthesis reads from one person's outputs
+struct Person {
+    numerology: Option<NumerologyData>,
+    human_design: Option<HumanDesignData>,
+    vimshottari: Option<VimshottariData>,
+}
+
+impl Person {
+    fn from_results(results: &HashMap<String, EngineOutput>, suffix: &str) -> Self {
+        let result = |engine_id: &str| results.get(&format!("{}{}", engine_id, suffix)).map(|o| &o.result);
+        Self {
+            numerology: result("numerology").and_then(NumerologyData::from_json),
+            human_design: result("human-design").and_then(HumanDesignData::from_json),
+            vimshottari: result("vimshottari").and_then(VimshottariData::from_json),
+        }
+    }
+
+    /// Theme names with their description and the engine they came from
+    fn themes(&self) -> Vec<(String, String, &'static str)> {
+        let mut themes = Vec::new();
+        if let Some(num) = &self.numerology {
+            themes.extend(num.themes().into_iter().map(|(n, d)| (n, d, "numerology")));
+        }
+        if let Some(hd) = &self.human_design {
+            themes.extend(hd.themes().into_iter().map(|(n, d)| (n, d, "human-design")));
+        }
+        if let Some(vim) = &self.vimshottari {
+            themes.extend(vim.themes().into_iter().map(|(n, d)| (n, d, "vimshottari")));
+        }
+        themes
+    }
+
+    fn describe(&self) -> String {
+        let mut parts = Vec::new();
+        if let Some(num) = &self.numerology {
+            parts.push(format!("Life Path {}", num.life_path));
+        }
+        if let Some(hd) = &self.human_design {
+            parts.push(hd.hd_type.clone());
+        }
+        if let Some(vim) = &self.vimshottari {
+            parts.push(format!("{} Mahadasha", vim.current_mahadasha_lord));
+        }
+        if parts.is_empty() {
+            "no chart data".to_string()
+        } else {
+            parts.join(", ")
+        }
+    }
+}
+
+impl Synthesizer for RelationshipSynthesizer {
+    fn synthesize(
+        results: &HashMap<String, EngineOutput>,
+        _input: &EngineInput,
+    ) -> ExtSynthesisResult {
+        let first = Person::from_results(results, "");
+        let second = Person::from_results(results, RelationshipWorkflow::SECONDARY_SUFFIX);
+
+        let themes = shared_themes(&first, &second);
+        let mut alignments = Vec::new();
+        let mut tensions = Vec::new();
+
+        if let (Some(a), Some(b)) = (&first.numerology, &second.numerology) {
+            if a.life_path == b.life_path {
+                alignments.push(
+                    Alignment::new(
+                        "Shared Life Path",
+                        format!("Both walk Life Path {}: {}", a.life_path, a.life_path_name),
+                    )
+                    .with_engines(engines_pair("numerology"))
+                    .with_confidence(0.8),
+                );
+            }
+        }
+
+        if let (Some(a), Some(b)) = (&first.human_design, &second.human_design) {
+            if !a.authority.is_empty() && a.authority == b.authority {
+                alignments.push(
+                    Alignment::new(
+                        "Shared decision style",
+                        format!("Both decide through {} Authority", a.authority),
+                    )
+                    .with_engines(engines_pair("human-design"))
+                    .with_confidence(0.7),
+                );
+            } else if !a.authority.is_empty() && !b.authority.is_empty() {
+                tensions.push(
+                    Tension::new(
+                        "Decision timing",
+                        "Each person reaches clarity in a different way",
+                    )
+                    .with_perspectives(
+                        "human-design",
+                        format!("{} Authority", a.authority),
+                        RelationshipWorkflow::secondary_key("human-design"),
+                        format!("{} Authority", b.authority),
+                    )
+                    .with_integration_hint("What happens when shared decisions wait for both kinds of clarity?"),
+                );
+            }
+
+            if a.hd_type != b.hd_type {
+                tensions.push(
+                    Tension::new(
+                        "Energy types",
+                        format!("A {} and a {} meet life with different rhythms", a.hd_type, b.hd_type),
+                    )
+                    .with_perspectives(
+                        "human-design",
+                        a.hd_type.clone(),
+                        RelationshipWorkflow::secondary_key("human-design"),
+                        b.hd_type.clone(),
+                    )
+                    .with_integration_hint("Where does one rhythm carry the other, and where do they pull apart?"),
+                );
+            }
+        }
+
+        if let (Some(a), Some(b)) = (&first.vimshottari, &second.vimshottari) {
+            if !a.current_mahadasha_lord.is_empty() && a.current_mahadasha_lord == b.current_mahadasha_lord {
+                alignments.push(
+                    Alignment::new(
+                        "Shared planetary period",
+                        format!("Both are in a {} Mahadasha", a.current_mahadasha_lord),
+                    )
+                    .with_engines(engines_pair("vimshottari"))
+                    .with_confidence(0.6),
+                );
+            }
+        }
+
+        let summary = format!(
+            "First person: {}. Second person: {}. {} shared themes, {} alignments, {} tensions.",
+            first.describe(),
+            second.describe(),
+            themes.len(),
+            alignments.len(),
+            tensions.len()
+        );
+
+        ExtSynthesisResult {
+            themes,
+            alignments,
+            tensions,
+            summary,
+        }
+    }
+}
+
+/// Themes that appear in both people's charts, sourced from each side
+fn shared_themes(first: &Person, second: &Person) -> Vec<Theme> {
+    let second_themes: BTreeMap<String, &'static str> = second
+        .themes()
+        .into_iter()
+        .map(|(name, _, engine)| (name, engine))
+        .collect();
+
+    let mut themes: BTreeMap<String, Theme> = BTreeMap::new();
+    for (name, description, engine) in first.themes() {
+        let Some(second_engine) = second_themes.get(&name) else {
+            continue;
+        };
+        themes.entry(name.clone()).or_insert_with(|| {
+            Theme::new(name, format!("Both charts carry this: {}", description)).with_sources(vec![
+                engine.to_string(),
+                RelationshipWorkflow::secondary_key(second_engine),
+            ])
+        });
+    }
+    themes.into_values().collect()
+}
+
+fn engines_pair(engine_id: &str) -> Vec<String> {
+    vec![engine_id.to_string(), RelationshipWorkflow::secondary_key(engine_id)]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+    use noesis_core::{CalculationMetadata, Precision};
+    use serde_json::{json, Value};
+
+    fn output(engine_id: &str, result: Value) -> EngineOutput {
+        EngineOutput {
+            engine_id: engine_id.to_string(),
+            result,
+            witness_prompt: String::new(),
+            summary: None,
+            consciousness_level: 1,
+            metadata: CalculationMetadata {
+                calculation_time_ms: 1.0,
+                backend: "mock".to_string(),
+                precision_achieved: "standard".to_string(),
+                cached: false,
+                timestamp: Utc::now(),
+                engine_version: None,
+                warnings: Vec::new(),
+                usage: None,
+            },
+        }
+    }
+
+    fn input() -> EngineInput {
+        EngineInput {
+            birth_data: None,
+            secondary_birth_data: None,
+            subjects: Vec::new(),
+            current_time: Utc::now(),
+            location: None,
+            precision: Precision::Standard,
+            options: HashMap::new(),
+        }
+    }
+
+    #[test]
+    fn test_compares_both_people() {
+        let results = HashMap::from([
+            ("numerology".to_string(), output("numerology", json!({ "life_path": { "value": 7 } }))),
+            (
+                "numerology:secondary".to_string(),
+                output("numerology", json!({ "life_path": { "value": 7 } })),
+            ),
+            (
+                "human-design".to_string(),
+                output("human-design", json!({ "hd_type": "Generator", "authority": "Sacral" })),
+            ),
+            (
+                "human-design:secondary".to_string(),
+                output("human-design", json!({ "hd_type": "Projector", "authority": "Splenic" })),
+            ),
+        ]);
+
+        let synthesis = RelationshipSynthesizer::synthesize(&results, &input());
+
+        assert!(synthesis.alignments.iter().any(|a| a.aspect == "Shared Life Path"));
+        let aspects: Vec<&str> = synthesis.tensions.iter().map(|t| t.aspect.as_str()).collect();
+        assert_eq!(aspects, ["Decision timing", "Energy types"]);
+        assert_eq!(synthesis.tensions[1].perspective_b.0, "human-design:secondary");
+        assert!(synthesis.themes.iter().any(|t| t.sources == ["numerology", "numerology:secondary"]));
+        assert!(synthesis.summary.contains("Life Path 7, Projector"));
+    }
+
+    #[test]
+    fn test_missing_partner_outputs_yield_no_comparisons() {
+        let results = HashMap::from([(
+            "numerology".to_string(),
+            output("numerology", json!({ "life_path": 3 })),
+        )]);
+
+        let synthesis = RelationshipSynthesizer::synthesize(&results, &input());
+        assert!(synthesis.themes.is_empty());
+        assert!(synthesis.alignments.is_empty());
+        assert!(synthesis.summary.contains("Second person: no chart data"));
+    }
+}
@@ -25,6 +25,8 @@ impl SelfInquirySynthesis {
     pub fn synthesize_results(results: &HashMap<String, EngineOutput>) -> SynthesisResult {
         let dummy_input = EngineInput {
             birth_data: None,
+            secondary_birth_data: None,
+            subjects: Vec::new(),
             current_time: chrono::Utc::now(),
             location: None,
             precision: noesis_core::Precision::Standard,
@@ -420,10 +422,14 @@ mod tests {
                 }
             }),
             witness_prompt: "What frequency are you operating from?".to_string(),
+            summary: None,
             consciousness_level: 2,
             metadata: CalculationMetadata {
                 calculation_time_ms: 25.0, backend: "native".to_string(),
                 precision_achieved: "standard".to_string(), cached: false, timestamp: Utc::now(),
+                engine_version: None,
+                warnings: Vec::new(),
+                usage: None,
             },
         }
     }
@@ -437,17 +443,22 @@ mod tests {
                 "integration": 1
             }),
             witness_prompt: "What is your true identity?".to_string(),
+            summary: None,
             consciousness_level: 1,
             metadata: CalculationMetadata {
                 calculation_time_ms: 12.0, backend: "bridge".to_string(),
                 precision_achieved: "standard".to_string(), cached: false, timestamp: Utc::now(),
+                engine_version: None,
+                warnings: Vec::new(),
+                usage: None,
             },
         }
     }
 
     fn test_input() -> EngineInput {
         EngineInput {
-            birth_data: None, current_time: Utc::now(), location: None,
+            birth_data: None, secondary_birth_data: None, subjects: Vec::new(),
+            current_time: Utc::now(), location: None,
             precision: noesis_core::Precision::Standard, options: HashMap::new(),
         }
     }
@@ -0,0 +1,218 @@
+//! Vocation Blueprint Synthesis — Work style across HD and the Pearl sequence
+//!
+//! Reads the `VocationProfile` built from the human-design and gene-keys
+//! outputs:
+//! - Type, decision cadence and each Pearl sphere's Gift as themes
+//! - Pearl spheres whose line matches a profile line as alignments
+//! - The Vocation Shadow against the type's strategy as a tension
+
+use super::Synthesizer;
+use crate::workflow::models::{
+    Alignment, SynthesisResult as ExtSynthesisResult, Tension, Theme,
+};
+use crate::workflow::vocation_blueprint::VocationProfile;
+use noesis_core::{EngineInput, EngineOutput};
+use serde_json::Value;
+use std::collections::HashMap;
+
+/// Synthesizer for the Vocation Blueprint workflow
+pub struct VocationBlueprintSynthesizer;
+
+impl VocationBlueprintSynthesizer {
+    /// The synthesis as JSON with the structured `vocation_profile` added
+    pub fn report(results: &HashMap<String, EngineOutput>, input: &EngineInput) -> Option<Value> {
+        let mut report = serde_json::to_value(Self::synthesize(results, input)).ok()?;
+        if let (Value::Object(map), Some(profile)) = (&mut report, VocationProfile::from_results(results)) {
+            map.insert("vocation_profile".into(), serde_json::to_value(profile).ok()?);
+        }
+        Some(report)
+    }
+}
+
+impl Synthesizer for VocationBlueprintSynthesizer {
+    fn synthesize(
+        results: &HashMap<String, EngineOutput>,
+        _input: &EngineInput,
+    ) -> ExtSynthesisResult {
+        let Some(profile) = VocationProfile::from_results(results) else {
+            return ExtSynthesisResult {
+                themes: Vec::new(),
+                alignments: Vec::new(),
+                tensions: Vec::new(),
+                summary: "No Human Design result to read a work style from.".to_string(),
+            };
+        };
+
+        let mut themes = vec![
+            Theme::new(profile.hd_type.clone(), profile.collaboration_style.clone())
+                .with_sources(vec!["human-design".to_string()]),
+            Theme::new("Decision cadence", profile.decision_cadence.clone())
+                .with_sources(vec!["human-design".to_string()]),
+        ];
+        for pearl in profile.pearl_sequence.iter().filter(|p| !p.gift.is_empty()) {
+            themes.push(
+                Theme::new(
+                    format!("{}: {}", pearl.sphere, pearl.gift),
+                    format!("{} Gene Key {}.{}: {}", pearl.sphere, pearl.key_number, pearl.line, pearl.gift),
+                )
+                .with_sources(vec!["gene-keys".to_string()]),
+            );
+        }
+
+        let mut alignments = Vec::new();
+        for pearl in &profile.pearl_sequence {
+            if let Some(line) = profile.profile_lines.iter().find(|l| l.line == pearl.line) {
+                alignments.push(
+                    Alignment::new(
+                        format!("{} line", pearl.sphere),
+                        format!(
+                            "{} Gene Key {} sits on line {}, the {} line of the {} profile",
+                            pearl.sphere, pearl.key_number, pearl.line, line.role, profile.profile
+                        ),
+                    )
+                    .with_engines(vec!["human-design".to_string(), "gene-keys".to_string()])
+                    .with_confidence(0.7),
+                );
+            }
+        }
+
+        let mut tensions = Vec::new();
+        if let Some(vocation) = profile.sphere("Vocation").filter(|v| !v.shadow.is_empty()) {
+            tensions.push(
+                Tension::new(
+                    "Vocation shadow",
+                    format!("The Shadow of {} can rush work ahead of the strategy", vocation.shadow),
+                )
+                .with_perspectives(
+                    "human-design",
+                    format!("{}: {}", profile.hd_type, profile.strategy),
+                    "gene-keys",
+                    format!("Vocation Gene Key {}: {} → {}", vocation.key_number, vocation.shadow, vocation.gift),
+                )
+                .with_integration_hint(format!(
+                    "Where does {} show up at work, and what changes when you {}?",
+                    vocation.shadow.to_lowercase(),
+                    profile.strategy.to_lowercase()
+                )),
+            );
+        }
+
+        let mut summary = format!(
+            "{} ({}) with {} authority and a {} profile. Decision cadence: {}.",
+            profile.hd_type,
+            profile.strategy,
+            if profile.authority.is_empty() { "undetermined" } else { &profile.authority },
+            if profile.profile.is_empty() { "undetermined" } else { &profile.profile },
+            profile.decision_cadence
+        );
+        let pearls: Vec<String> = profile
+            .pearl_sequence
+            .iter()
+            .map(|p| format!("{} {}.{}", p.sphere, p.key_number, p.line))
+            .collect();
+        if !pearls.is_empty() {
+            summary.push_str(&format!(" Pearl sequence: {}.", pearls.join(", ")));
+        }
+
+        ExtSynthesisResult {
+            themes,
+            alignments,
+            tensions,
+            summary,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+    use noesis_core::{CalculationMetadata, Precision};
+    use serde_json::json;
+
+    fn output(engine_id: &str, result: Value) -> EngineOutput {
+        EngineOutput {
+            engine_id: engine_id.to_string(),
+            result,
+            witness_prompt: String::new(),
+            summary: None,
+            consciousness_level: 2,
+            metadata: CalculationMetadata {
+                calculation_time_ms: 1.0,
+                backend: "mock".to_string(),
+                precision_achieved: "standard".to_string(),
+                cached: false,
+                timestamp: Utc::now(),
+                engine_version: None,
+                warnings: Vec::new(),
+                usage: None,
+            },
+        }
+    }
+
+    fn input() -> EngineInput {
+        EngineInput {
+            birth_data: None,
+            secondary_birth_data: None,
+            subjects: Vec::new(),
+            current_time: Utc::now(),
+            location: None,
+            precision: Precision::Standard,
+            options: HashMap::new(),
+        }
+    }
+
+    fn results() -> HashMap<String, EngineOutput> {
+        HashMap::from([
+            (
+                "human-design".to_string(),
+                output(
+                    "human-design",
+                    json!({ "hd_type": "ManifestingGenerator", "authority": "Emotional", "profile": "5/1" }),
+                ),
+            ),
+            (
+                "gene-keys".to_string(),
+                output("gene-keys", json!({ "active_keys": [
+                    { "key_number": 28, "line": 5, "source": "DesignMars",
+                      "shadow": "Purposelessness", "gift": "Totality", "siddhi": "Immortality" },
+                    { "key_number": 16, "line": 3, "source": "PersonalityJupiter",
+                      "shadow": "Indifference", "gift": "Versatility", "siddhi": "Mastery" },
+                ]})),
+            ),
+        ])
+    }
+
+    #[test]
+    fn test_reads_work_style_and_pearl_sequence() {
+        let synthesis = VocationBlueprintSynthesizer::synthesize(&results(), &input());
+
+        assert_eq!(synthesis.themes[0].name, "Manifesting Generator");
+        assert!(synthesis.themes.iter().any(|t| t.name == "Vocation: Totality"));
+        let aspects: Vec<&str> = synthesis.alignments.iter().map(|a| a.aspect.as_str()).collect();
+        assert_eq!(aspects, ["Vocation line"]);
+        assert_eq!(synthesis.tensions[0].aspect, "Vocation shadow");
+        assert_eq!(synthesis.tensions[0].perspective_a.1, "Manifesting Generator: Wait to respond, then inform");
+        assert!(synthesis.summary.starts_with("Manifesting Generator (Wait to respond, then inform) with Emotional"));
+        assert!(synthesis.summary.contains("Pearl sequence: Vocation 28.5, Pearl 16.3."));
+    }
+
+    #[test]
+    fn test_report_carries_the_structured_profile() {
+        let report = VocationBlueprintSynthesizer::report(&results(), &input()).unwrap();
+        let profile = &report["vocation_profile"];
+        assert!(profile["decision_cadence"].as_str().unwrap().starts_with("Wave-paced"));
+        assert_eq!(profile["profile_lines"][0]["role"], "Heretic");
+        assert_eq!(profile["pearl_sequence"][1]["sphere"], "Pearl");
+        assert!(report["summary"].is_string());
+    }
+
+    #[test]
+    fn test_without_human_design_there_is_no_profile() {
+        let mut results = results();
+        results.remove("human-design");
+        let report = VocationBlueprintSynthesizer::report(&results, &input()).unwrap();
+        assert!(report.get("vocation_profile").is_none());
+        assert!(report["themes"].as_array().unwrap().is_empty());
+    }
+}
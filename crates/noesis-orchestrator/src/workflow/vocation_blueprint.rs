@@ -0,0 +1,332 @@
+//! Vocation Blueprint Workflow — Work style from natal design
+//!
+//! Executes: human-design, gene-keys
+//! Reads HD type, strategy, authority and profile lines together with the
+//! Gene Keys Pearl sequence into a work-style report: how decisions are
+//! paced, how the person collaborates and what their working environment
+//! needs.
+//!
+//! # Pearl Sequence
+//! - **Vocation**: Design Mars
+//! - **Culture**: Design Jupiter
+//! - **Pearl**: Personality Jupiter
+//! - **Brand**: Personality Sun, the Life's Work the sequence is carried by
+
+use super::birth_blueprint::HumanDesignData;
+use super::{ExtendedWorkflowDefinition, SynthesisType};
+use noesis_core::{EngineOutput, WorkflowDefinition};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+
+/// Vocation Blueprint Workflow implementation
+pub struct VocationBlueprintWorkflow;
+
+impl VocationBlueprintWorkflow {
+    /// Workflow identifier
+    pub const ID: &'static str = "vocation-blueprint";
+
+    /// Required consciousness phase (Gene Keys needs phase 2)
+    pub const REQUIRED_PHASE: u8 = 2;
+
+    /// Returns the extended workflow definition with synthesis support
+    pub fn definition() -> ExtendedWorkflowDefinition {
+        ExtendedWorkflowDefinition {
+            id: Self::ID.to_string(),
+            name: "Vocation Blueprint".to_string(),
+            description: "Work-style report from Human Design type, authority and \
+                         profile lines with the Gene Keys Pearl sequence".to_string(),
+            engine_ids: vec![
+                "human-design".to_string(),
+                "gene-keys".to_string(),
+            ],
+            synthesis_type: SynthesisType::VocationBlueprint,
+            required_phase: Self::REQUIRED_PHASE,
+            default_options: HashMap::new(),
+        }
+    }
+
+    /// Returns the base workflow definition
+    pub fn base_definition() -> WorkflowDefinition {
+        Self::definition().to_base()
+    }
+}
+
+/// Spheres of the Pearl sequence with the activation each is read from
+const PEARL_SPHERES: [(&str, &str); 4] = [
+    ("Vocation", "DesignMars"),
+    ("Culture", "DesignJupiter"),
+    ("Pearl", "PersonalityJupiter"),
+    ("Brand", "PersonalitySun"),
+];
+
+/// One sphere of the Pearl sequence
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PearlActivation {
+    /// Sphere name ("Vocation", "Culture", "Pearl", "Brand")
+    pub sphere: String,
+    pub key_number: u8,
+    pub line: u8,
+    #[serde(default)]
+    pub shadow: String,
+    #[serde(default)]
+    pub gift: String,
+    #[serde(default)]
+    pub siddhi: String,
+}
+
+impl PearlActivation {
+    /// The Pearl sequence from a gene-keys result's `active_keys`, in sphere
+    /// order; spheres whose activation the result lacks are left out
+    pub fn sequence_from_json(value: &Value) -> Vec<Self> {
+        let keys = value
+            .get("active_keys")
+            .and_then(|v| v.as_array())
+            .map(Vec::as_slice)
+            .unwrap_or_default();
+        let text = |key: &Value, field: &str| {
+            key.get(field).and_then(|v| v.as_str()).unwrap_or_default().to_string()
+        };
+
+        PEARL_SPHERES
+            .iter()
+            .filter_map(|(sphere, source)| {
+                let key = keys.iter().find(|k| k.get("source").and_then(|s| s.as_str()) == Some(source))?;
+                Some(Self {
+                    sphere: sphere.to_string(),
+                    key_number: key.get("key_number")?.as_u64()? as u8,
+                    line: key.get("line").and_then(|v| v.as_u64()).unwrap_or(0) as u8,
+                    shadow: text(key, "shadow"),
+                    gift: text(key, "gift"),
+                    siddhi: text(key, "siddhi"),
+                })
+            })
+            .collect()
+    }
+}
+
+/// A profile line read as a working role
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProfileLine {
+    pub line: u8,
+    /// "Investigator", "Hermit", ...
+    pub role: String,
+    /// How the line shows up at work
+    pub work_style: String,
+}
+
+impl ProfileLine {
+    fn new(line: u8) -> Option<Self> {
+        let (role, work_style) = match line {
+            1 => ("Investigator", "Researches until the ground feels solid before committing"),
+            2 => ("Hermit", "Natural talent that ripens alone and waits to be called out"),
+            3 => ("Martyr", "Learns by trial and error and finds what does not work"),
+            4 => ("Opportunist", "Works through a close network of trusted relationships"),
+            5 => ("Heretic", "Practical problem-solver others project their hopes onto"),
+            6 => ("Role Model", "Steps back for perspective and leads by example"),
+            _ => return None,
+        };
+        Some(Self { line, role: role.to_string(), work_style: work_style.to_string() })
+    }
+
+    /// What the line needs from a working environment
+    fn environment_need(&self) -> &'static str {
+        match self.line {
+            1 => "Time and material to research before delivering",
+            2 => "Uninterrupted solo time between collaborations",
+            3 => "Room to experiment and fail without penalty",
+            4 => "A stable circle of colleagues to share opportunities with",
+            5 => "Clear mandates for practical fixes and a way out when projections sour",
+            _ => "Distance from day-to-day detail to see the whole",
+        }
+    }
+}
+
+/// Structured work-style report of the Vocation Blueprint workflow
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VocationProfile {
+    pub hd_type: String,
+    pub strategy: String,
+    pub authority: String,
+    pub profile: String,
+    /// How quickly and by what inner signal work decisions are reached
+    pub decision_cadence: String,
+    /// How the person works alongside others
+    pub collaboration_style: String,
+    /// What the working environment needs to provide
+    pub environment_needs: Vec<String>,
+    /// Conscious then unconscious profile line
+    pub profile_lines: Vec<ProfileLine>,
+    /// Vocation, Culture, Pearl and Brand, where the gene-keys result has them
+    pub pearl_sequence: Vec<PearlActivation>,
+}
+
+impl VocationProfile {
+    /// Build the report from the workflow's engine outputs; `None` without a
+    /// human-design result
+    pub fn from_results(results: &HashMap<String, EngineOutput>) -> Option<Self> {
+        let hd = HumanDesignData::from_json(&results.get("human-design")?.result)?;
+        let pearl_sequence = results
+            .get("gene-keys")
+            .map(|o| PearlActivation::sequence_from_json(&o.result))
+            .unwrap_or_default();
+        Some(Self::new(&hd, pearl_sequence))
+    }
+
+    pub fn new(hd: &HumanDesignData, pearl_sequence: Vec<PearlActivation>) -> Self {
+        let profile_lines: Vec<ProfileLine> = hd
+            .profile
+            .split('/')
+            .filter_map(|line| line.trim().parse().ok())
+            .filter_map(ProfileLine::new)
+            .collect();
+
+        let mut collaboration_style = type_collaboration(&hd.hd_type).to_string();
+        if !profile_lines.is_empty() {
+            let roles: Vec<String> = profile_lines
+                .iter()
+                .map(|l| format!("{} ({})", l.role, l.work_style.to_lowercase()))
+                .collect();
+            collaboration_style.push_str(&format!(". Profile {}: {}", hd.profile, roles.join("; ")));
+        }
+
+        let mut environment_needs = vec![type_environment(&hd.hd_type).to_string()];
+        for line in &profile_lines {
+            let need = line.environment_need().to_string();
+            if !environment_needs.contains(&need) {
+                environment_needs.push(need);
+            }
+        }
+
+        Self {
+            strategy: strategy(&hd.hd_type).to_string(),
+            decision_cadence: decision_cadence(&hd.authority).to_string(),
+            hd_type: hd.hd_type.clone(),
+            authority: hd.authority.clone(),
+            profile: hd.profile.clone(),
+            collaboration_style,
+            environment_needs,
+            profile_lines,
+            pearl_sequence,
+        }
+    }
+
+    /// The Pearl sequence sphere called `sphere`
+    pub fn sphere(&self, sphere: &str) -> Option<&PearlActivation> {
+        self.pearl_sequence.iter().find(|p| p.sphere == sphere)
+    }
+}
+
+fn strategy(hd_type: &str) -> &'static str {
+    match hd_type {
+        "Generator" => "Wait to respond",
+        "Manifesting Generator" => "Wait to respond, then inform",
+        "Projector" => "Wait for the invitation",
+        "Manifestor" => "Inform before acting",
+        "Reflector" => "Wait a lunar cycle",
+        _ => "Unknown",
+    }
+}
+
+fn decision_cadence(authority: &str) -> &'static str {
+    if authority.contains("Emotional") || authority.contains("Solar Plexus") {
+        "Wave-paced: sleep on work decisions and commit once the emotional wave has settled"
+    } else if authority.contains("Sacral") {
+        "Immediate: commit to the work that draws a clear gut response in the moment"
+    } else if authority.contains("Splenic") {
+        "Instant: act on the first quiet intuitive hit, which does not repeat itself"
+    } else if authority.contains("Heart") || authority.contains("Ego") {
+        "Will-driven: commit only to what you truly want and have the resources to sustain"
+    } else if authority.contains("GCenter") || authority.contains("Self") {
+        "Spoken: talk direction through and listen for your own voice before committing"
+    } else if authority.contains("Mental") || authority.contains("Environment") {
+        "Sounding-board: talk options over with trusted people in the right place and let clarity come"
+    } else if authority.contains("Lunar") {
+        "Monthly: let significant work decisions ripen over a full lunar cycle"
+    } else {
+        "Not determined"
+    }
+}
+
+fn type_collaboration(hd_type: &str) -> &'static str {
+    match hd_type {
+        "Generator" => "Sustains the work itself and is at its best answering what others bring",
+        "Manifesting Generator" => "Sustains several streams at once, skipping steps and informing others of pivots",
+        "Projector" => "Guides and coordinates other people's energy once recognised and invited",
+        "Manifestor" => "Initiates and hands off, keeping collaborators informed rather than asking leave",
+        "Reflector" => "Reads the health of the whole team and mirrors it back",
+        _ => "Collaboration style not determined",
+    }
+}
+
+fn type_environment(hd_type: &str) -> &'static str {
+    match hd_type {
+        "Generator" => "Work that draws a sustained response and uses up the day's energy",
+        "Manifesting Generator" => "Variety and freedom to move fast between projects",
+        "Projector" => "Recognition, invitations and regular rest instead of long fixed hours",
+        "Manifestor" => "Autonomy to start things without waiting for permission",
+        "Reflector" => "A healthy team and a physical place that feels right",
+        _ => "Environment needs not determined",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn hd(hd_type: &str, authority: &str, profile: &str) -> HumanDesignData {
+        HumanDesignData {
+            hd_type: hd_type.to_string(),
+            authority: authority.to_string(),
+            profile: profile.to_string(),
+            defined_centers: Vec::new(),
+            undefined_centers: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_definition() {
+        let def = VocationBlueprintWorkflow::definition();
+        assert_eq!(def.id, "vocation-blueprint");
+        assert_eq!(def.engine_ids, ["human-design", "gene-keys"]);
+        assert_eq!(def.synthesis_type, SynthesisType::VocationBlueprint);
+    }
+
+    #[test]
+    fn test_pearl_sequence_in_sphere_order() {
+        let gene_keys = json!({ "active_keys": [
+            { "key_number": 1, "line": 4, "source": "PersonalitySun", "gift": "Freshness" },
+            { "key_number": 16, "line": 2, "source": "PersonalityJupiter", "gift": "Versatility" },
+            { "key_number": 28, "line": 5, "source": "DesignMars", "shadow": "Purposelessness", "gift": "Totality" },
+        ]});
+
+        let sequence = PearlActivation::sequence_from_json(&gene_keys);
+        let spheres: Vec<&str> = sequence.iter().map(|p| p.sphere.as_str()).collect();
+        assert_eq!(spheres, ["Vocation", "Pearl", "Brand"]);
+        assert_eq!((sequence[0].key_number, sequence[0].line), (28, 5));
+        assert_eq!(sequence[0].shadow, "Purposelessness");
+        assert!(PearlActivation::sequence_from_json(&json!({})).is_empty());
+    }
+
+    #[test]
+    fn test_work_style_from_type_authority_and_lines() {
+        let profile = VocationProfile::new(&hd("Projector", "Splenic", "2/4"), Vec::new());
+
+        assert_eq!(profile.strategy, "Wait for the invitation");
+        assert!(profile.decision_cadence.starts_with("Instant"));
+        let roles: Vec<&str> = profile.profile_lines.iter().map(|l| l.role.as_str()).collect();
+        assert_eq!(roles, ["Hermit", "Opportunist"]);
+        assert!(profile.collaboration_style.contains("Profile 2/4: Hermit"));
+        assert_eq!(profile.environment_needs.len(), 3);
+        assert!(profile.environment_needs[0].starts_with("Recognition"));
+    }
+
+    #[test]
+    fn test_unknown_design_is_reported_as_undetermined() {
+        let profile = VocationProfile::new(&hd("Unknown", "", ""), Vec::new());
+        assert_eq!(profile.decision_cadence, "Not determined");
+        assert!(profile.profile_lines.is_empty());
+        assert_eq!(profile.environment_needs, ["Environment needs not determined"]);
+    }
+}
@@ -99,6 +99,7 @@ impl ConsciousnessEngine for MockEngine {
             engine_id: self.id.clone(),
             result: self.result_data.clone(),
             witness_prompt: format!("Witness prompt from {}", self.id),
+            summary: None,
             consciousness_level: self.phase,
             metadata: CalculationMetadata {
                 calculation_time_ms: self.delay_ms as f64,
@@ -106,6 +107,9 @@ impl ConsciousnessEngine for MockEngine {
                 precision_achieved: "standard".to_string(),
                 cached: false,
                 timestamp: Utc::now(),
+                engine_version: None,
+                warnings: Vec::new(),
+                usage: None,
             },
         })
     }
@@ -159,17 +163,25 @@ fn create_birth_input() -> EngineInput {
     EngineInput {
         birth_data: Some(BirthData {
             name: Some("Test User".to_string()),
+            display_name: None,
             date: "1990-01-15".to_string(),
             time: Some("14:30".to_string()),
+            time_unknown: false,
             latitude: 12.9716,
             longitude: 77.5946,
+            altitude: None,
             timezone: "Asia/Kolkata".to_string(),
+            place: None,
+            utc_offset_minutes: None,
         }),
+        secondary_birth_data: None,
+        subjects: Vec::new(),
         current_time: Utc::now(),
         location: Some(Coordinates {
             latitude: 12.9716,
             longitude: 77.5946,
             altitude: None,
+            timezone: None,
         }),
         precision: Precision::Standard,
         options: HashMap::new(),
@@ -530,6 +542,8 @@ async fn test_workflow_cache_basic() {
         synthesis: None,
         total_time_ms: 100.0,
         timestamp: Utc::now(),
+        usage: None,
+        time_confidence: None,
     };
 
     cache
@@ -563,6 +577,8 @@ async fn test_workflow_cache_invalidation() {
         synthesis: None,
         total_time_ms: 100.0,
         timestamp: Utc::now(),
+        usage: None,
+        time_confidence: None,
     };
 
     cache.set(key1.clone(), result.clone(), Duration::from_secs(60)).await;
@@ -623,6 +639,7 @@ async fn test_synthesis_theme_detection() {
             engine_id: "engine1".to_string(),
             result: json!({"gift": "leadership"}),
             witness_prompt: "Reflect on leadership".to_string(),
+            summary: None,
             consciousness_level: 0,
             metadata: CalculationMetadata {
                 calculation_time_ms: 1.0,
@@ -630,6 +647,9 @@ async fn test_synthesis_theme_detection() {
                 precision_achieved: "standard".to_string(),
                 cached: false,
                 timestamp: Utc::now(),
+                engine_version: None,
+                warnings: Vec::new(),
+                usage: None,
             },
         },
     );
@@ -639,6 +659,7 @@ async fn test_synthesis_theme_detection() {
             engine_id: "engine2".to_string(),
             result: json!({"strength": "leadership"}),
             witness_prompt: "Your leadership quality".to_string(),
+            summary: None,
             consciousness_level: 0,
             metadata: CalculationMetadata {
                 calculation_time_ms: 1.0,
@@ -646,6 +667,9 @@ async fn test_synthesis_theme_detection() {
                 precision_achieved: "standard".to_string(),
                 cached: false,
                 timestamp: Utc::now(),
+                engine_version: None,
+                warnings: Vec::new(),
+                usage: None,
             },
         },
     );
@@ -655,6 +679,7 @@ async fn test_synthesis_theme_detection() {
             engine_id: "engine3".to_string(),
             result: json!({"theme": "leadership path"}),
             witness_prompt: "Walk your leadership path".to_string(),
+            summary: None,
             consciousness_level: 0,
             metadata: CalculationMetadata {
                 calculation_time_ms: 1.0,
@@ -662,6 +687,9 @@ async fn test_synthesis_theme_detection() {
                 precision_achieved: "standard".to_string(),
                 cached: false,
                 timestamp: Utc::now(),
+                engine_version: None,
+                warnings: Vec::new(),
+                usage: None,
             },
         },
     );
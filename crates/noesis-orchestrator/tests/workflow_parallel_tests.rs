@@ -58,6 +58,7 @@ impl ConsciousnessEngine for TimedMockEngine {
                 "delay_ms": self.delay_ms
             }),
             witness_prompt: format!("Witness prompt from {}", self.id),
+            summary: None,
             consciousness_level: 0,
             metadata: CalculationMetadata {
                 calculation_time_ms: self.delay_ms as f64,
@@ -65,6 +66,9 @@ impl ConsciousnessEngine for TimedMockEngine {
                 precision_achieved: "standard".to_string(),
                 cached: false,
                 timestamp: Utc::now(),
+                engine_version: None,
+                warnings: Vec::new(),
+                usage: None,
             },
         })
     }
@@ -85,6 +89,8 @@ impl ConsciousnessEngine for TimedMockEngine {
 fn test_input() -> EngineInput {
     EngineInput {
         birth_data: None,
+        secondary_birth_data: None,
+        subjects: Vec::new(),
         current_time: Utc::now(),
         location: None,
         precision: Precision::Standard,
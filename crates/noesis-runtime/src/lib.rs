@@ -0,0 +1,337 @@
+//! The Noesis platform without HTTP
+//!
+//! [`AppCore`] is the orchestrator with every native engine registered, the
+//! layered result cache, authentication and metrics, built by
+//! [`AppCoreBuilder`]. `noesis-api` serves it over HTTP; the CLI, batch jobs
+//! and tests can call it directly:
+//!
+//! ```no_run
+//! # async fn run(input: noesis_core::EngineInput) -> Result<(), noesis_core::EngineError> {
+//! let runtime = noesis_runtime::AppCore::builder().build();
+//! let output = runtime.calculate("numerology", input, 0).await?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! Calls are phase-gated exactly as over HTTP: an engine requiring a higher
+//! consciousness phase than the one passed fails with
+//! `EngineError::PhaseAccessDenied`.
+
+use std::collections::HashMap;
+use std::sync::{Arc, OnceLock};
+use std::time::Duration;
+
+use noesis_auth::{AuthService, AuthUser};
+use noesis_cache::l1_cache::DEFAULT_L1_SHARES;
+use noesis_cache::CacheManager;
+use noesis_core::{ConsciousnessEngine, EngineError, EngineInput, EngineOutput, WorkflowResult};
+use noesis_core::redaction::RedactionPolicies;
+use noesis_metrics::NoesisMetrics;
+use noesis_orchestrator::{EngineAlias, WorkflowDefinition, WorkflowOrchestrator, WorkflowPlan};
+use rand::RngCore;
+
+/// The orchestrator, cache, auth and metrics of one Noesis instance.
+///
+/// Cloning is cheap; clones share everything.
+#[derive(Clone)]
+pub struct AppCore {
+    pub orchestrator: Arc<WorkflowOrchestrator>,
+    pub cache: Arc<CacheManager>,
+    pub auth: Arc<AuthService>,
+    pub metrics: Arc<NoesisMetrics>,
+}
+
+impl AppCore {
+    pub fn builder() -> AppCoreBuilder {
+        AppCoreBuilder::default()
+    }
+
+    /// Run one engine for a caller at consciousness phase `phase`
+    pub async fn calculate(&self, engine_id: &str, input: EngineInput, phase: u8) -> Result<EngineOutput, EngineError> {
+        self.orchestrator.execute_engine(engine_id, input, phase).await
+    }
+
+    /// Run one engine for an authenticated user, at their phase and with
+    /// the experiments they are enrolled in
+    pub async fn calculate_as(
+        &self,
+        user: &AuthUser,
+        engine_id: &str,
+        input: EngineInput,
+    ) -> Result<EngineOutput, EngineError> {
+        self.orchestrator
+            .execute_engine_for(engine_id, input, user.consciousness_level, &user.user_id)
+            .await
+    }
+
+    /// Run a workflow for a caller at consciousness phase `phase`
+    pub async fn execute_workflow(
+        &self,
+        workflow_id: &str,
+        input: EngineInput,
+        phase: u8,
+    ) -> Result<WorkflowResult, EngineError> {
+        self.orchestrator.execute_workflow(workflow_id, input, phase).await
+    }
+
+    /// Run a workflow for an authenticated user
+    pub async fn execute_workflow_as(
+        &self,
+        user: &AuthUser,
+        workflow_id: &str,
+        input: EngineInput,
+    ) -> Result<WorkflowResult, EngineError> {
+        self.orchestrator
+            .execute_workflow_for(workflow_id, input, user.consciousness_level, &user.user_id)
+            .await
+    }
+
+    /// Plan a workflow for an authenticated user without running it, with
+    /// latencies estimated from the calculations recorded so far
+    pub async fn plan_workflow_as(
+        &self,
+        user: &AuthUser,
+        workflow_id: &str,
+        input: EngineInput,
+    ) -> Result<WorkflowPlan, EngineError> {
+        let mut plan = self
+            .orchestrator
+            .plan_workflow(workflow_id, input, user.consciousness_level)
+            .await?;
+        plan.estimate_latency(|label| self.metrics.engine_calculation_duration.mean_seconds(label));
+        Ok(plan)
+    }
+
+    /// The user a JWT was issued to
+    pub async fn authenticate_token(&self, token: &str) -> Result<AuthUser, EngineError> {
+        self.auth.validate_jwt_token(token).await
+    }
+
+    /// The user an API key belongs to
+    pub async fn authenticate_api_key(&self, api_key: &str) -> Result<AuthUser, EngineError> {
+        self.auth.validate_api_key(api_key).await
+    }
+
+    pub fn list_engines(&self) -> Vec<String> {
+        self.orchestrator.list_engines()
+    }
+
+    pub fn list_workflows(&self) -> Vec<WorkflowDefinition> {
+        self.orchestrator.list_workflows()
+    }
+}
+
+/// Builder for [`AppCore`]
+pub struct AppCoreBuilder {
+    redis_url: Option<String>,
+    l1_size_mb: usize,
+    l1_shares: HashMap<String, f64>,
+    l2_ttl: Duration,
+    precomputed_natal_cache: bool,
+    critical_engines: Vec<String>,
+    engines: Vec<Arc<dyn ConsciousnessEngine>>,
+    aliases: Vec<EngineAlias>,
+    auth: Option<AuthService>,
+    jwt_secret: Option<String>,
+    metrics: Option<Arc<NoesisMetrics>>,
+    redaction: RedactionPolicies,
+}
+
+impl Default for AppCoreBuilder {
+    fn default() -> Self {
+        Self {
+            redis_url: None,
+            l1_size_mb: 100,
+            l1_shares: DEFAULT_L1_SHARES
+                .iter()
+                .map(|(engine_id, share)| (engine_id.to_string(), *share))
+                .collect(),
+            l2_ttl: Duration::from_secs(3600),
+            precomputed_natal_cache: false,
+            critical_engines: Vec::new(),
+            engines: Vec::new(),
+            aliases: Vec::new(),
+            auth: None,
+            jwt_secret: None,
+            metrics: None,
+            redaction: RedactionPolicies::default(),
+        }
+    }
+}
+
+impl AppCoreBuilder {
+    /// Redis for the L2 cache; without it only L1 (and L3, if enabled) serve
+    pub fn redis_url(mut self, redis_url: Option<String>) -> Self {
+        self.redis_url = redis_url;
+        self
+    }
+
+    /// Size of the in-memory L1 cache (default 100 MB)
+    pub fn l1_size_mb(mut self, size_mb: usize) -> Self {
+        self.l1_size_mb = size_mb;
+        self
+    }
+
+    /// Fraction of L1 reserved for each listed engine; the rest is shared by
+    /// the others (default [`DEFAULT_L1_SHARES`], empty shares all of it)
+    pub fn l1_shares(mut self, shares: HashMap<String, f64>) -> Self {
+        self.l1_shares = shares;
+        self
+    }
+
+    /// TTL of L2 entries (default 1 hour)
+    pub fn l2_ttl(mut self, ttl: Duration) -> Self {
+        self.l2_ttl = ttl;
+        self
+    }
+
+    /// Serve precomputed natal results from `L3_CACHE_DIR` (default off)
+    pub fn precomputed_natal_cache(mut self, enabled: bool) -> Self {
+        self.precomputed_natal_cache = enabled;
+        self
+    }
+
+    /// Engines whose health check failing makes the instance unready
+    pub fn critical_engines(mut self, engine_ids: Vec<String>) -> Self {
+        self.critical_engines = engine_ids;
+        self
+    }
+
+    /// Register an engine besides the native ones, e.g. a bridge engine
+    pub fn engine(mut self, engine: Arc<dyn ConsciousnessEngine>) -> Self {
+        self.engines.push(engine);
+        self
+    }
+
+    /// Keep a renamed engine's old id resolving until the alias's sunset
+    pub fn engine_alias(mut self, alias: EngineAlias) -> Self {
+        self.aliases.push(alias);
+        self
+    }
+
+    /// Authenticate with `auth`, e.g. one validating API keys against Postgres
+    pub fn auth(mut self, auth: AuthService) -> Self {
+        self.auth = Some(auth);
+        self
+    }
+
+    /// Sign and validate JWTs with `secret`; ignored when [`auth`](Self::auth)
+    /// is set. Without either, a random secret is used, so only tokens
+    /// minted by this core's `auth` validate.
+    pub fn jwt_secret(mut self, secret: impl Into<String>) -> Self {
+        self.jwt_secret = Some(secret.into());
+        self
+    }
+
+    /// Redact personal data from logged outputs, shared results and narrator
+    /// requests with `policies` (default [`RedactionPolicies::default`])
+    pub fn redaction(mut self, policies: RedactionPolicies) -> Self {
+        self.redaction = policies;
+        self
+    }
+
+    /// Record metrics in `metrics`; defaults to [`shared_metrics`]
+    pub fn metrics(mut self, metrics: Arc<NoesisMetrics>) -> Self {
+        self.metrics = Some(metrics);
+        self
+    }
+
+    pub fn build(self) -> AppCore {
+        let mut orchestrator = WorkflowOrchestrator::new();
+        register_native_engines(&mut orchestrator);
+        for engine in self.engines {
+            orchestrator.register_engine(engine);
+        }
+        for alias in self.aliases {
+            orchestrator.register_alias(alias);
+        }
+        orchestrator.set_critical_engines(self.critical_engines);
+        orchestrator.set_redaction(self.redaction);
+        configure_narrator(&mut orchestrator);
+
+        let metrics = self.metrics.unwrap_or_else(shared_metrics);
+        let cache = Arc::new(
+            CacheManager::new(
+                self.redis_url.unwrap_or_default(),
+                self.l1_size_mb,
+                self.l2_ttl,
+                self.precomputed_natal_cache,
+            )
+            .with_l1_shares(&self.l1_shares)
+            .with_metrics(metrics.clone()),
+        );
+        orchestrator.set_result_cache(cache.clone());
+
+        let auth = self
+            .auth
+            .unwrap_or_else(|| AuthService::new(self.jwt_secret.unwrap_or_else(random_secret)));
+
+        AppCore {
+            orchestrator: Arc::new(orchestrator),
+            cache,
+            auth: Arc::new(auth),
+            metrics,
+        }
+    }
+}
+
+/// Register every native Rust engine
+pub fn register_native_engines(orchestrator: &mut WorkflowOrchestrator) {
+    orchestrator.register_engine(Arc::new(engine_panchanga::PanchangaEngine::new()));
+    orchestrator.register_engine(Arc::new(engine_numerology::NumerologyEngine::new()));
+    orchestrator.register_engine(Arc::new(engine_biorhythm::BiorhythmEngine::new()));
+
+    // Register HD engine (Phase 1); with incomplete wisdom data it refuses
+    // charts and fails its health check, so say why at startup
+    if let Err(error) = engine_human_design::wisdom_integrity() {
+        tracing::error!(%error, "Human Design wisdom data failed validation");
+    }
+    let hd_engine = Arc::new(engine_human_design::HumanDesignEngine::new());
+    orchestrator.register_engine(hd_engine.clone());
+
+    // Register Gene Keys engine with HD dependency (Phase 2)
+    let gk_engine = Arc::new(engine_gene_keys::GeneKeysEngine::with_hd_engine(hd_engine.clone()));
+    orchestrator.register_engine(gk_engine);
+
+    // Register Vimshottari Dasha engine with HD dependency (Phase 2)
+    let vim_engine = Arc::new(engine_vimshottari::VimshottariEngine::with_hd_engine(hd_engine));
+    orchestrator.register_engine(vim_engine);
+
+    // Register Biofield engine (Phase 1 - somatic awareness) - returns mock data
+    orchestrator.register_engine(Arc::new(engine_biofield::BiofieldEngine::new()));
+
+    // Register VedicClock-TCM engine (Phase 0 - available to all)
+    orchestrator.register_engine(Arc::new(engine_vedic_clock::VedicClockEngine::new()));
+}
+
+/// Metrics register in a process-wide Prometheus registry, which rejects a
+/// second registration, so every core built in a process shares one set
+pub fn shared_metrics() -> Arc<NoesisMetrics> {
+    static METRICS: OnceLock<Arc<NoesisMetrics>> = OnceLock::new();
+    METRICS
+        .get_or_init(|| Arc::new(NoesisMetrics::new().expect("Failed to initialise NoesisMetrics")))
+        .clone()
+}
+
+fn random_secret() -> String {
+    let mut bytes = [0u8; 32];
+    rand::thread_rng().fill_bytes(&mut bytes);
+    hex::encode(bytes)
+}
+
+/// Let a language model write synthesis narratives when `LLM_SYNTHESIS_URL`
+/// and `LLM_SYNTHESIS_MODEL` are set; the template is used otherwise.
+#[cfg(feature = "llm-synthesis")]
+fn configure_narrator(orchestrator: &mut WorkflowOrchestrator) {
+    let Some(config) = noesis_orchestrator::LlmConfig::from_env() else {
+        return;
+    };
+    let timeout = config.timeout;
+    match noesis_orchestrator::LlmNarrator::new(config) {
+        Ok(narrator) => orchestrator.set_narrator(Arc::new(narrator), timeout),
+        Err(e) => tracing::warn!(error = %e, "LLM synthesis disabled"),
+    }
+}
+
+#[cfg(not(feature = "llm-synthesis"))]
+fn configure_narrator(_orchestrator: &mut WorkflowOrchestrator) {}
@@ -0,0 +1,65 @@
+//! Tests of the embedded runtime facade
+
+use std::collections::HashMap;
+
+use noesis_core::{BirthData, EngineError, EngineInput, Precision};
+use noesis_runtime::AppCore;
+
+fn birth_input() -> EngineInput {
+    EngineInput {
+        birth_data: Some(BirthData {
+            name: Some("Embedded User".to_string()),
+            display_name: None,
+            date: "1990-01-15".to_string(),
+            time: Some("14:30".to_string()),
+            time_unknown: false,
+            latitude: 12.9716,
+            longitude: 77.5946,
+            altitude: None,
+            timezone: "Asia/Kolkata".to_string(),
+            place: None,
+            utc_offset_minutes: None,
+        }),
+        secondary_birth_data: None,
+        subjects: Vec::new(),
+        current_time: chrono::Utc::now(),
+        location: None,
+        precision: Precision::Standard,
+        options: HashMap::new(),
+    }
+}
+
+#[tokio::test]
+async fn test_calculate_in_process() {
+    let runtime = AppCore::builder().build();
+    assert!(runtime.list_engines().contains(&"numerology".to_string()));
+
+    let output = runtime.calculate("numerology", birth_input(), 0).await.unwrap();
+    assert_eq!(output.engine_id, "numerology");
+
+    // Same input again is served from the cache
+    let again = runtime.calculate("numerology", birth_input(), 0).await.unwrap();
+    assert!(again.metadata.cached);
+
+    let denied = runtime.calculate("gene-keys", birth_input(), 0).await;
+    assert!(matches!(denied, Err(EngineError::PhaseAccessDenied { .. })));
+}
+
+#[tokio::test]
+async fn test_authenticated_workflow_in_process() {
+    let runtime = AppCore::builder().jwt_secret("embedded-test-secret").build();
+    let token = runtime
+        .auth
+        .generate_jwt_token("embedded-user", "premium", &["read".to_string()], 5)
+        .unwrap();
+
+    let user = runtime.authenticate_token(&token).await.unwrap();
+    assert_eq!(user.consciousness_level, 5);
+
+    let result = runtime.execute_workflow_as(&user, "birth-blueprint", birth_input()).await.unwrap();
+    assert!(!result.engine_outputs.is_empty());
+
+    // A second core in the same process has its own secret
+    let other = AppCore::builder().build();
+    assert!(other.authenticate_token(&token).await.is_err());
+}
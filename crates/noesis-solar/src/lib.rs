@@ -0,0 +1,360 @@
+//! Noesis Solar — sunrise, sunset, solar noon and twilight
+//!
+//! Implements the NOAA solar calculator equations for any latitude, longitude
+//! and date. Events are returned in UTC; use [`SolarEvent::local`] to convert to
+//! local wall-clock time with a fixed UTC offset.
+//!
+//! Inside the polar circles the sun may not cross a given altitude at all on a
+//! date. Those days are reported as [`SolarEvent::AlwaysAbove`] (midnight sun,
+//! white nights) or [`SolarEvent::AlwaysBelow`] (polar night) instead of an error.
+
+mod noaa;
+
+use chrono::{DateTime, Duration, NaiveDate, NaiveDateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Errors from solar calculations
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+pub enum SolarError {
+    #[error("Invalid latitude: {0}. Must be between -90 and 90.")]
+    InvalidLatitude(f64),
+
+    #[error("Invalid longitude: {0}. Must be between -180 and 180.")]
+    InvalidLongitude(f64),
+}
+
+/// Sun altitudes that define the standard solar events
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SunAltitude {
+    /// Upper limb on the horizon, corrected for atmospheric refraction (-0.833°)
+    Horizon,
+    /// Civil twilight boundary (-6°)
+    Civil,
+    /// Nautical twilight boundary (-12°)
+    Nautical,
+    /// Astronomical twilight boundary (-18°)
+    Astronomical,
+}
+
+impl SunAltitude {
+    /// Altitude of the sun's centre in degrees
+    pub fn degrees(&self) -> f64 {
+        match self {
+            SunAltitude::Horizon => -0.833,
+            SunAltitude::Civil => -6.0,
+            SunAltitude::Nautical => -12.0,
+            SunAltitude::Astronomical => -18.0,
+        }
+    }
+}
+
+/// Outcome of a rising or setting event on a given day
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "status", content = "time", rename_all = "snake_case")]
+pub enum SolarEvent {
+    /// The sun crosses the altitude at this instant
+    At(DateTime<Utc>),
+    /// The sun stays above the altitude all day
+    AlwaysAbove,
+    /// The sun stays below the altitude all day
+    AlwaysBelow,
+}
+
+impl SolarEvent {
+    /// Event instant in UTC, if the sun crosses the altitude
+    pub fn time(&self) -> Option<DateTime<Utc>> {
+        match self {
+            SolarEvent::At(t) => Some(*t),
+            _ => None,
+        }
+    }
+
+    /// Event as local wall-clock time for a UTC offset in minutes
+    pub fn local(&self, offset_minutes: i32) -> Option<NaiveDateTime> {
+        self.time()
+            .map(|t| t.naive_utc() + Duration::minutes(offset_minutes as i64))
+    }
+}
+
+/// Morning and evening crossings of a twilight altitude
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Twilight {
+    /// Start of morning twilight
+    pub dawn: SolarEvent,
+    /// End of evening twilight
+    pub dusk: SolarEvent,
+}
+
+/// All solar events for one date and location
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SolarDay {
+    /// Local calendar date the events belong to
+    pub date: NaiveDate,
+    pub latitude: f64,
+    pub longitude: f64,
+    /// Meridian transit of the sun
+    pub solar_noon: DateTime<Utc>,
+    pub sunrise: SolarEvent,
+    pub sunset: SolarEvent,
+    pub civil: Twilight,
+    pub nautical: Twilight,
+    pub astronomical: Twilight,
+}
+
+impl SolarDay {
+    /// Calculate every solar event for `date` at the given location.
+    ///
+    /// Events are those surrounding the local solar noon of `date`, so the UTC
+    /// date of an event may differ from `date` at large longitudes.
+    pub fn calculate(date: NaiveDate, latitude: f64, longitude: f64) -> Result<Self, SolarError> {
+        validate(latitude, longitude)?;
+
+        let jd0 = noaa::julian_day(date);
+        let twilight = |altitude: SunAltitude| Twilight {
+            dawn: event_at(date, jd0, latitude, longitude, altitude, true),
+            dusk: event_at(date, jd0, latitude, longitude, altitude, false),
+        };
+
+        Ok(Self {
+            date,
+            latitude,
+            longitude,
+            solar_noon: from_minutes(date, noaa::solar_noon_minutes(jd0, longitude)),
+            sunrise: event_at(date, jd0, latitude, longitude, SunAltitude::Horizon, true),
+            sunset: event_at(date, jd0, latitude, longitude, SunAltitude::Horizon, false),
+            civil: twilight(SunAltitude::Civil),
+            nautical: twilight(SunAltitude::Nautical),
+            astronomical: twilight(SunAltitude::Astronomical),
+        })
+    }
+
+    /// Length of daylight in minutes (1440 for midnight sun, 0 for polar night)
+    pub fn day_length_minutes(&self) -> f64 {
+        match (self.sunrise, self.sunset) {
+            (SolarEvent::At(rise), SolarEvent::At(set)) => (set - rise).num_seconds() as f64 / 60.0,
+            (SolarEvent::AlwaysAbove, _) => 1440.0,
+            _ => 0.0,
+        }
+    }
+
+    /// True when the sun does not set on this date
+    pub fn is_polar_day(&self) -> bool {
+        self.sunrise == SolarEvent::AlwaysAbove
+    }
+
+    /// True when the sun does not rise on this date
+    pub fn is_polar_night(&self) -> bool {
+        self.sunrise == SolarEvent::AlwaysBelow
+    }
+
+    /// Local sunrise and sunset for a UTC offset in minutes.
+    ///
+    /// Returns `None` on polar days and nights.
+    pub fn local_sunrise_sunset(&self, offset_minutes: i32) -> Option<(NaiveDateTime, NaiveDateTime)> {
+        Some((
+            self.sunrise.local(offset_minutes)?,
+            self.sunset.local(offset_minutes)?,
+        ))
+    }
+}
+
+/// Calculate a single rising (`rising = true`) or setting event
+pub fn solar_event(
+    date: NaiveDate,
+    latitude: f64,
+    longitude: f64,
+    altitude: SunAltitude,
+    rising: bool,
+) -> Result<SolarEvent, SolarError> {
+    validate(latitude, longitude)?;
+    Ok(event_at(date, noaa::julian_day(date), latitude, longitude, altitude, rising))
+}
+
+/// Sunrise and sunset for `date`
+pub fn sunrise_sunset(
+    date: NaiveDate,
+    latitude: f64,
+    longitude: f64,
+) -> Result<(SolarEvent, SolarEvent), SolarError> {
+    Ok((
+        solar_event(date, latitude, longitude, SunAltitude::Horizon, true)?,
+        solar_event(date, latitude, longitude, SunAltitude::Horizon, false)?,
+    ))
+}
+
+/// Solar noon (meridian transit) for `date` at the given longitude
+pub fn solar_noon(date: NaiveDate, longitude: f64) -> Result<DateTime<Utc>, SolarError> {
+    validate(0.0, longitude)?;
+    Ok(from_minutes(date, noaa::solar_noon_minutes(noaa::julian_day(date), longitude)))
+}
+
+/// Equation of time at `at` in minutes: how far apparent solar time (a
+/// sundial) runs ahead of mean solar time, between about -14 in February and
+/// +16 in November
+pub fn equation_of_time(at: DateTime<Utc>) -> f64 {
+    noaa::sun_position(noaa::julian_day_at(at)).equation_of_time
+}
+
+/// Apparent solar time at `at` for the given longitude, where 12:00 is the
+/// sun's meridian transit
+pub fn apparent_solar_time(at: DateTime<Utc>, longitude: f64) -> Result<NaiveDateTime, SolarError> {
+    validate(0.0, longitude)?;
+    let minutes = 4.0 * longitude + equation_of_time(at);
+    Ok(at.naive_utc() + Duration::milliseconds((minutes * 60_000.0).round() as i64))
+}
+
+fn validate(latitude: f64, longitude: f64) -> Result<(), SolarError> {
+    if !(-90.0..=90.0).contains(&latitude) {
+        return Err(SolarError::InvalidLatitude(latitude));
+    }
+    if !(-180.0..=180.0).contains(&longitude) {
+        return Err(SolarError::InvalidLongitude(longitude));
+    }
+    Ok(())
+}
+
+fn event_at(
+    date: NaiveDate,
+    jd0: f64,
+    latitude: f64,
+    longitude: f64,
+    altitude: SunAltitude,
+    rising: bool,
+) -> SolarEvent {
+    match noaa::event_minutes(jd0, latitude, longitude, altitude.degrees(), rising) {
+        Ok(minutes) => SolarEvent::At(from_minutes(date, minutes)),
+        Err(noaa::HourAngle::AlwaysAbove) => SolarEvent::AlwaysAbove,
+        Err(_) => SolarEvent::AlwaysBelow,
+    }
+}
+
+/// UTC instant `minutes` after 00:00 UTC of `date`, rounded to the second
+fn from_minutes(date: NaiveDate, minutes: f64) -> DateTime<Utc> {
+    let midnight = date.and_hms_opt(0, 0, 0).unwrap_or_default().and_utc();
+    midnight + Duration::seconds((minutes * 60.0).round() as i64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Timelike;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    /// Assert a UTC event is within `tolerance` minutes of HH:MM
+    fn assert_near(event: SolarEvent, hh: u32, mm: u32, tolerance: i64) {
+        let t = event.time().expect("expected a timed event");
+        let actual = (t.hour() * 60 + t.minute()) as i64;
+        let expected = (hh * 60 + mm) as i64;
+        assert!(
+            (actual - expected).abs() <= tolerance,
+            "expected ~{:02}:{:02} UTC, got {}",
+            hh,
+            mm,
+            t
+        );
+    }
+
+    #[test]
+    fn test_london_summer_solstice() {
+        let day = SolarDay::calculate(date(2024, 6, 20), 51.5074, -0.1278).unwrap();
+        // Sunrise 04:43 BST, sunset 21:21 BST
+        assert_near(day.sunrise, 3, 43, 2);
+        assert_near(day.sunset, 20, 21, 2);
+        assert_near(SolarEvent::At(day.solar_noon), 12, 2, 1);
+        // The sun never gets 18° below the horizon in London in June
+        assert_eq!(day.astronomical.dawn, SolarEvent::AlwaysAbove);
+    }
+
+    #[test]
+    fn test_bangalore_equinox_local_times() {
+        let day = SolarDay::calculate(date(2024, 3, 20), 12.9716, 77.5946).unwrap();
+        let (rise, set) = day.local_sunrise_sunset(330).unwrap();
+        // Sunrise ~06:24 IST, sunset ~18:30 IST
+        assert_eq!(rise.date(), date(2024, 3, 20));
+        assert!((rise.hour(), rise.minute()) >= (6, 20) && (rise.hour(), rise.minute()) <= (6, 28), "{rise}");
+        assert!((set.hour(), set.minute()) >= (18, 26) && (set.hour(), set.minute()) <= (18, 34), "{set}");
+        assert!((day.day_length_minutes() - 727.0).abs() < 5.0);
+    }
+
+    #[test]
+    fn test_twilight_ordering() {
+        let day = SolarDay::calculate(date(2024, 9, 1), 40.7128, -74.0060).unwrap();
+        let seq = [
+            day.astronomical.dawn,
+            day.nautical.dawn,
+            day.civil.dawn,
+            day.sunrise,
+            day.sunset,
+            day.civil.dusk,
+            day.nautical.dusk,
+            day.astronomical.dusk,
+        ];
+        let times: Vec<_> = seq.iter().map(|e| e.time().unwrap()).collect();
+        assert!(times.windows(2).all(|w| w[0] < w[1]));
+    }
+
+    #[test]
+    fn test_polar_day_and_night() {
+        // Tromsø, Norway
+        let summer = SolarDay::calculate(date(2024, 6, 21), 69.6492, 18.9553).unwrap();
+        assert!(summer.is_polar_day());
+        assert_eq!(summer.sunset, SolarEvent::AlwaysAbove);
+        assert_eq!(summer.day_length_minutes(), 1440.0);
+        assert!(summer.local_sunrise_sunset(120).is_none());
+
+        let winter = SolarDay::calculate(date(2024, 12, 21), 69.6492, 18.9553).unwrap();
+        assert!(winter.is_polar_night());
+        assert_eq!(winter.day_length_minutes(), 0.0);
+        // Civil twilight still happens around noon
+        assert!(winter.civil.dawn.time().is_some());
+        assert!(winter.civil.dusk.time().is_some());
+    }
+
+    #[test]
+    fn test_poles() {
+        let north = SolarDay::calculate(date(2024, 6, 21), 90.0, 0.0).unwrap();
+        assert!(north.is_polar_day());
+        let south = SolarDay::calculate(date(2024, 6, 21), -90.0, 0.0).unwrap();
+        assert!(south.is_polar_night());
+        assert_eq!(south.astronomical.dawn, SolarEvent::AlwaysBelow);
+    }
+
+    #[test]
+    fn test_apparent_solar_time() {
+        // Early November the sundial runs ~16 minutes fast, mid February ~14 slow
+        let november = date(2024, 11, 3).and_hms_opt(12, 0, 0).unwrap().and_utc();
+        assert!((equation_of_time(november) - 16.4).abs() < 0.5);
+        let february = date(2024, 2, 11).and_hms_opt(12, 0, 0).unwrap().and_utc();
+        assert!((equation_of_time(february) + 14.2).abs() < 0.5);
+
+        // At solar noon the apparent solar time is noon
+        let noon = solar_noon(date(2024, 6, 20), 77.5946).unwrap();
+        let solar = apparent_solar_time(noon, 77.5946).unwrap();
+        let minutes = solar.hour() as i64 * 60 + solar.minute() as i64;
+        assert!((minutes - 720).abs() <= 1, "{solar}");
+        assert!(apparent_solar_time(noon, 200.0).is_err());
+    }
+
+    #[test]
+    fn test_invalid_coordinates() {
+        assert_eq!(
+            SolarDay::calculate(date(2024, 1, 1), 91.0, 0.0),
+            Err(SolarError::InvalidLatitude(91.0))
+        );
+        assert_eq!(
+            sunrise_sunset(date(2024, 1, 1), 0.0, -181.0),
+            Err(SolarError::InvalidLongitude(-181.0))
+        );
+        assert!(solar_noon(date(2024, 1, 1), f64::NAN).is_err());
+    }
+
+    #[test]
+    fn test_event_serialization() {
+        let json = serde_json::to_value(SolarEvent::AlwaysBelow).unwrap();
+        assert_eq!(json, serde_json::json!({ "status": "always_below" }));
+    }
+}
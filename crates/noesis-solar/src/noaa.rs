@@ -0,0 +1,160 @@
+//! NOAA solar position equations
+//!
+//! Port of the NOAA Solar Calculator spreadsheet (after Meeus, *Astronomical
+//! Algorithms*). Accurate to about a minute for dates between 1800 and 2100 and
+//! latitudes within the polar circles.
+
+use chrono::{DateTime, NaiveDate, Utc};
+
+/// Julian Day of 1970-01-01T00:00Z
+const JD_UNIX_EPOCH: f64 = 2440587.5;
+/// Julian Day of J2000.0
+const JD_J2000: f64 = 2451545.0;
+
+/// Julian Day at 00:00 UTC of the given date
+pub(crate) fn julian_day(date: NaiveDate) -> f64 {
+    let epoch = NaiveDate::from_ymd_opt(1970, 1, 1).unwrap_or_default();
+    JD_UNIX_EPOCH + (date - epoch).num_days() as f64
+}
+
+/// Julian Day of an instant
+pub(crate) fn julian_day_at(at: DateTime<Utc>) -> f64 {
+    JD_UNIX_EPOCH + at.timestamp_millis() as f64 / 86_400_000.0
+}
+
+/// Apparent solar position quantities needed for rise/set calculations
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct SunPosition {
+    /// Apparent declination in degrees
+    pub declination: f64,
+    /// Equation of time in minutes (apparent minus mean solar time)
+    pub equation_of_time: f64,
+}
+
+/// Solar declination and equation of time at the given Julian Day
+pub(crate) fn sun_position(jd: f64) -> SunPosition {
+    let t = (jd - JD_J2000) / 36525.0;
+
+    let mean_long = (280.46646 + t * (36000.76983 + t * 0.0003032)).rem_euclid(360.0);
+    let mean_anom = 357.52911 + t * (35999.05029 - 0.0001537 * t);
+    let eccent = 0.016708634 - t * (0.000042037 + 0.0000001267 * t);
+
+    let m = mean_anom.to_radians();
+    let eq_of_center = m.sin() * (1.914602 - t * (0.004817 + 0.000014 * t))
+        + (2.0 * m).sin() * (0.019993 - 0.000101 * t)
+        + (3.0 * m).sin() * 0.000289;
+
+    let true_long = mean_long + eq_of_center;
+    let omega = (125.04 - 1934.136 * t).to_radians();
+    let apparent_long = true_long - 0.00569 - 0.00478 * omega.sin();
+
+    let mean_obliq = 23.0 + (26.0 + (21.448 - t * (46.815 + t * (0.00059 - t * 0.001813))) / 60.0) / 60.0;
+    let obliq = (mean_obliq + 0.00256 * omega.cos()).to_radians();
+
+    let declination = (obliq.sin() * apparent_long.to_radians().sin()).asin().to_degrees();
+
+    let y = (obliq / 2.0).tan().powi(2);
+    let l0 = mean_long.to_radians();
+    let equation_of_time = 4.0
+        * (y * (2.0 * l0).sin() - 2.0 * eccent * m.sin()
+            + 4.0 * eccent * y * m.sin() * (2.0 * l0).cos()
+            - 0.5 * y * y * (4.0 * l0).sin()
+            - 1.25 * eccent * eccent * (2.0 * m).sin())
+        .to_degrees();
+
+    SunPosition {
+        declination,
+        equation_of_time,
+    }
+}
+
+/// Result of solving for the hour angle at which the sun crosses an altitude
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum HourAngle {
+    /// Hour angle in degrees (always positive; apply the sign for rising/setting)
+    Degrees(f64),
+    /// The sun stays above the altitude for the whole day
+    AlwaysAbove,
+    /// The sun stays below the altitude for the whole day
+    AlwaysBelow,
+}
+
+/// Hour angle at which the sun reaches `altitude` degrees at `latitude`
+pub(crate) fn hour_angle(latitude: f64, declination: f64, altitude: f64) -> HourAngle {
+    let lat = latitude.to_radians();
+    let dec = declination.to_radians();
+    let cos_h = (altitude.to_radians().sin() - lat.sin() * dec.sin()) / (lat.cos() * dec.cos());
+
+    if cos_h > 1.0 {
+        HourAngle::AlwaysBelow
+    } else if cos_h < -1.0 {
+        HourAngle::AlwaysAbove
+    } else {
+        HourAngle::Degrees(cos_h.acos().to_degrees())
+    }
+}
+
+/// Minutes after 00:00 UTC of `jd0` at which the sun transits the meridian
+pub(crate) fn solar_noon_minutes(jd0: f64, longitude: f64) -> f64 {
+    let mean_noon = 720.0 - 4.0 * longitude;
+    // One refinement: evaluate the equation of time at the noon estimate itself
+    let first = mean_noon - sun_position(jd0 + mean_noon / 1440.0).equation_of_time;
+    mean_noon - sun_position(jd0 + first / 1440.0).equation_of_time
+}
+
+/// Minutes after 00:00 UTC of `jd0` at which the sun crosses `altitude`.
+///
+/// Polar conditions are decided at solar noon; the event time is then refined
+/// by re-evaluating the sun's position at the previous estimate.
+pub(crate) fn event_minutes(
+    jd0: f64,
+    latitude: f64,
+    longitude: f64,
+    altitude: f64,
+    rising: bool,
+) -> Result<f64, HourAngle> {
+    let sign = if rising { -1.0 } else { 1.0 };
+    let mut minutes = solar_noon_minutes(jd0, longitude);
+
+    for i in 0..3 {
+        let pos = sun_position(jd0 + minutes / 1440.0);
+        let noon = 720.0 - 4.0 * longitude - pos.equation_of_time;
+        match hour_angle(latitude, pos.declination, altitude) {
+            HourAngle::Degrees(h) => minutes = noon + sign * 4.0 * h,
+            // Near the polar threshold a refinement step can tip over; keep the last estimate
+            polar if i == 0 => return Err(polar),
+            _ => break,
+        }
+    }
+
+    Ok(minutes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_julian_day_j2000() {
+        let jd = julian_day(NaiveDate::from_ymd_opt(2000, 1, 1).unwrap());
+        assert!((jd + 0.5 - JD_J2000).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_declination_at_solstices() {
+        let june = sun_position(julian_day(NaiveDate::from_ymd_opt(2024, 6, 20).unwrap()) + 0.5);
+        let dec = sun_position(julian_day(NaiveDate::from_ymd_opt(2024, 12, 21).unwrap()) + 0.5);
+        assert!((june.declination - 23.44).abs() < 0.05, "{}", june.declination);
+        assert!((dec.declination + 23.44).abs() < 0.05, "{}", dec.declination);
+    }
+
+    #[test]
+    fn test_equation_of_time_extremes() {
+        // Early November: sundial runs ~16.4 minutes fast
+        let nov = sun_position(julian_day(NaiveDate::from_ymd_opt(2024, 11, 3).unwrap()) + 0.5);
+        assert!((nov.equation_of_time - 16.4).abs() < 0.3, "{}", nov.equation_of_time);
+        // Mid February: ~14.2 minutes slow
+        let feb = sun_position(julian_day(NaiveDate::from_ymd_opt(2024, 2, 11).unwrap()) + 0.5);
+        assert!((feb.equation_of_time + 14.2).abs() < 0.3, "{}", feb.equation_of_time);
+    }
+}
@@ -5,11 +5,18 @@
 //! - Panchang: 24 hours (same date/location)
 //! - Transits: 1 hour (current positions)
 //! - Dasha periods: Forever (based on birth data)
+//!
+//! The in-memory maps are lost on restart. With [`ApiCache::with_persistence`]
+//! every entry is also written through to a shared [`CacheManager`] (Redis and
+//! L3 disk) together with its wall-clock expiry, and [`ApiCache::warm`] reloads
+//! the unexpired entries on startup so they do not cost API calls again.
 
 use std::collections::HashMap;
-use std::hash::Hash;
 use std::sync::Arc;
-use tokio::sync::RwLock;
+use std::time::{SystemTime, UNIX_EPOCH};
+use noesis_cache::{CacheKey, CacheManager};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use tokio::sync::{Mutex, RwLock};
 use tokio::time::{Duration, Instant};
 use tracing::{debug, info, warn};
 
@@ -43,6 +50,143 @@ impl<T> CacheEntry<T> {
     }
 }
 
+/// Prefix for keys written to the shared [`CacheManager`]
+const PERSISTED_PREFIX: &str = "vedic-api:";
+/// Key of the list of persisted entries, read by [`ApiCache::warm`]
+const INDEX_KEY: &str = "vedic-api:index";
+
+/// Kinds of cached responses
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+enum EntryKind {
+    Panchang,
+    Dasha,
+    BirthChart,
+}
+
+/// Entry of the persisted index
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct IndexEntry {
+    kind: EntryKind,
+    key: String,
+    /// Unix seconds; the entry is stale from this moment
+    expires_at: u64,
+}
+
+/// A cached value as written to the shared cache
+#[derive(Debug, Serialize, Deserialize)]
+struct PersistedEntry {
+    expires_at: u64,
+    value: serde_json::Value,
+}
+
+fn unix_now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs()
+}
+
+/// Outcome of reloading persisted entries on startup
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CacheWarmReport {
+    pub panchang_loaded: usize,
+    pub dasha_loaded: usize,
+    pub birth_chart_loaded: usize,
+    /// Entries past their TTL, dropped from the index
+    pub expired: usize,
+    /// Entries listed in the index but missing or unreadable
+    pub failed: usize,
+}
+
+impl CacheWarmReport {
+    /// Entries now served without an API call
+    pub fn loaded(&self) -> usize {
+        self.panchang_loaded + self.dasha_loaded + self.birth_chart_loaded
+    }
+}
+
+impl std::fmt::Display for CacheWarmReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Cache warm: {} loaded (Panchang={}, Dasha={}, BirthChart={}), {} expired, {} failed",
+            self.loaded(),
+            self.panchang_loaded,
+            self.dasha_loaded,
+            self.birth_chart_loaded,
+            self.expired,
+            self.failed
+        )
+    }
+}
+
+/// Write-through to the shared cache
+#[derive(Clone)]
+struct Persistence {
+    manager: Arc<CacheManager>,
+    /// Serializes read-modify-write of the index
+    index_lock: Arc<Mutex<()>>,
+}
+
+impl std::fmt::Debug for Persistence {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Persistence").finish_non_exhaustive()
+    }
+}
+
+impl Persistence {
+    async fn load_index(&self) -> Vec<IndexEntry> {
+        match self.manager.get(&CacheKey::new(INDEX_KEY)).await {
+            Ok(Some(value)) => serde_json::from_value(value).unwrap_or_else(|e| {
+                warn!("Discarding unreadable Vedic API cache index: {}", e);
+                Vec::new()
+            }),
+            Ok(None) => Vec::new(),
+            Err(e) => {
+                warn!("Failed to read Vedic API cache index: {}", e);
+                Vec::new()
+            }
+        }
+    }
+
+    async fn store_index(&self, index: &[IndexEntry]) {
+        let value = serde_json::to_value(index).unwrap_or_default();
+        if let Err(e) = self.manager.store_all_layers(&CacheKey::new(INDEX_KEY), &value).await {
+            warn!("Failed to persist Vedic API cache index: {}", e);
+        }
+    }
+
+    async fn load(&self, key: &str) -> Option<PersistedEntry> {
+        let value = self
+            .manager
+            .get(&CacheKey::new(format!("{}{}", PERSISTED_PREFIX, key)))
+            .await
+            .ok()??;
+        serde_json::from_value(value).ok()
+    }
+
+    async fn store(&self, kind: EntryKind, key: &str, value: serde_json::Value, expires_at: u64) {
+        let entry = serde_json::to_value(PersistedEntry { expires_at, value }).unwrap_or_default();
+        let cache_key = CacheKey::new(format!("{}{}", PERSISTED_PREFIX, key));
+        if let Err(e) = self.manager.store_all_layers(&cache_key, &entry).await {
+            warn!("Failed to persist Vedic API cache entry {}: {}", key, e);
+            return;
+        }
+
+        let _guard = self.index_lock.lock().await;
+        let now = unix_now();
+        let mut index = self.load_index().await;
+        index.retain(|e| e.key != key && e.expires_at > now);
+        index.push(IndexEntry {
+            kind,
+            key: key.to_string(),
+            expires_at,
+        });
+        self.store_index(&index).await;
+    }
+}
+
 /// Multi-tier cache for API responses
 #[derive(Debug, Clone)]
 pub struct ApiCache {
@@ -59,6 +203,9 @@ pub struct ApiCache {
     // Stats
     hits: Arc<RwLock<u64>>,
     misses: Arc<RwLock<u64>>,
+
+    // L2/L3: shared persistent cache, if configured
+    persistence: Option<Persistence>,
 }
 
 impl ApiCache {
@@ -93,22 +240,115 @@ impl ApiCache {
             ttl_birth_chart: birth_chart_ttl,
             hits: Arc::new(RwLock::new(0)),
             misses: Arc::new(RwLock::new(0)),
+            persistence: None,
+        }
+    }
+
+    /// Create with the TTLs from `config` (birth data forever when 0, daily data 24h by default)
+    pub fn from_config(config: &crate::Config) -> Self {
+        Self::with_ttls(
+            Duration::from_secs(config.cache_ttl_daily),
+            Duration::from_secs(config.cache_ttl_birth_data),
+            Duration::from_secs(config.cache_ttl_birth_data),
+        )
+    }
+
+    /// Write entries through to a shared cache so they survive restarts.
+    ///
+    /// Call [`warm`](Self::warm) afterwards to reload what earlier runs stored.
+    pub fn with_persistence(mut self, manager: Arc<CacheManager>) -> Self {
+        self.persistence = Some(Persistence {
+            manager,
+            index_lock: Arc::new(Mutex::new(())),
+        });
+        self
+    }
+
+    /// Whether entries are written through to a shared cache
+    pub fn is_persistent(&self) -> bool {
+        self.persistence.is_some()
+    }
+
+    /// Reload unexpired persisted entries into memory.
+    ///
+    /// Expired entries are dropped from the persisted index. Without
+    /// persistence this returns an empty report.
+    pub async fn warm(&self) -> CacheWarmReport {
+        let mut report = CacheWarmReport::default();
+        let Some(persistence) = &self.persistence else {
+            return report;
+        };
+
+        let _guard = persistence.index_lock.lock().await;
+        let now = unix_now();
+        let mut kept = Vec::new();
+
+        for entry in persistence.load_index().await {
+            if entry.expires_at <= now {
+                report.expired += 1;
+                continue;
+            }
+            let Some(persisted) = persistence.load(&entry.key).await else {
+                report.failed += 1;
+                continue;
+            };
+            let ttl = Duration::from_secs(persisted.expires_at.saturating_sub(now));
+
+            let loaded = match entry.kind {
+                EntryKind::Panchang => Self::restore(&self.panchang, &entry.key, persisted.value, ttl).await,
+                EntryKind::Dasha => Self::restore(&self.dasha, &entry.key, persisted.value, ttl).await,
+                EntryKind::BirthChart => Self::restore(&self.birth_chart, &entry.key, persisted.value, ttl).await,
+            };
+            if !loaded {
+                report.failed += 1;
+                continue;
+            }
+            match entry.kind {
+                EntryKind::Panchang => report.panchang_loaded += 1,
+                EntryKind::Dasha => report.dasha_loaded += 1,
+                EntryKind::BirthChart => report.birth_chart_loaded += 1,
+            }
+            kept.push(entry);
+        }
+
+        if report.expired > 0 || report.failed > 0 {
+            persistence.store_index(&kept).await;
+        }
+        info!("{}", report);
+        report
+    }
+
+    async fn restore<V: DeserializeOwned>(
+        cache: &Arc<RwLock<HashMap<String, CacheEntry<V>>>>,
+        key: &str,
+        value: serde_json::Value,
+        ttl: Duration,
+    ) -> bool {
+        match serde_json::from_value::<V>(value) {
+            Ok(value) => {
+                cache.write().await.insert(key.to_string(), CacheEntry::new(value, ttl));
+                true
+            }
+            Err(e) => {
+                warn!("Discarding persisted cache entry {}: {}", key, e);
+                false
+            }
         }
     }
     
     /// Get Panchang from cache
     pub async fn get_panchang(&self, key: &str) -> Option<Panchang> {
-        self.get(&self.panchang, &key.to_string()).await
+        self.get(&self.panchang, key).await
     }
     
     /// Store Panchang in cache
     pub async fn set_panchang(&self, key: &str, value: Panchang) {
-        self.set(&self.panchang, key.to_string(), value, self.ttl_panchang).await;
+        self.set(&self.panchang, EntryKind::Panchang, key, value, self.ttl_panchang).await;
     }
     
     /// Get Dasha from cache
     pub async fn get_dasha(&self, key: &str) -> Option<VimshottariDasha> {
-        self.get(&self.dasha, &key.to_string()).await
+        self.get(&self.dasha, key).await
     }
     
     /// Store Dasha in cache
@@ -118,12 +358,12 @@ impl ApiCache {
         } else {
             self.ttl_dasha
         };
-        self.set(&self.dasha, key.to_string(), value, ttl).await;
+        self.set(&self.dasha, EntryKind::Dasha, key, value, ttl).await;
     }
     
     /// Get Birth Chart from cache
     pub async fn get_birth_chart(&self, key: &str) -> Option<BirthChart> {
-        self.get(&self.birth_chart, &key.to_string()).await
+        self.get(&self.birth_chart, key).await
     }
     
     /// Store Birth Chart in cache
@@ -133,52 +373,78 @@ impl ApiCache {
         } else {
             self.ttl_birth_chart
         };
-        self.set(&self.birth_chart, key.to_string(), value, ttl).await;
+        self.set(&self.birth_chart, EntryKind::BirthChart, key, value, ttl).await;
     }
     
-    /// Generic get from cache
-    async fn get<K, V>(
+    /// Generic get from cache, falling back to the persistent cache
+    async fn get<V>(
         &self,
-        cache: &Arc<RwLock<HashMap<K, CacheEntry<V>>>>,
-        key: &K,
+        cache: &Arc<RwLock<HashMap<String, CacheEntry<V>>>>,
+        key: &str,
     ) -> Option<V>
     where
-        K: Eq + Hash,
-        V: Clone,
+        V: Clone + DeserializeOwned,
     {
-        let mut cache = cache.write().await;
-        
-        if let Some(entry) = cache.get_mut(key) {
-            if entry.is_expired() {
-                debug!("Cache entry expired, removing");
-                cache.remove(key);
-                self.increment_misses().await;
-                return None;
+        {
+            let mut cache = cache.write().await;
+
+            if let Some(entry) = cache.get_mut(key) {
+                if entry.is_expired() {
+                    debug!("Cache entry expired, removing");
+                    cache.remove(key);
+                    self.increment_misses().await;
+                    return None;
+                }
+
+                entry.touch();
+                self.increment_hits().await;
+                debug!("Cache hit, access count: {}", entry.access_count);
+                return Some(entry.value.clone());
             }
-            
-            entry.touch();
-            self.increment_hits().await;
-            debug!("Cache hit, access count: {}", entry.access_count);
-            return Some(entry.value.clone());
         }
-        
+
+        // Written by another instance, or after this one warmed up
+        if let Some(persistence) = &self.persistence {
+            let now = unix_now();
+            if let Some(persisted) = persistence.load(key).await.filter(|p| p.expires_at > now) {
+                if let Ok(value) = serde_json::from_value::<V>(persisted.value) {
+                    let ttl = Duration::from_secs(persisted.expires_at - now);
+                    cache.write().await.insert(key.to_string(), CacheEntry::new(value.clone(), ttl));
+                    self.increment_hits().await;
+                    debug!("Persistent cache hit for {}", key);
+                    return Some(value);
+                }
+            }
+        }
+
         self.increment_misses().await;
         None
     }
     
-    /// Generic set in cache
-    async fn set<K, V>(
+    /// Generic set in cache, writing through to the persistent cache
+    async fn set<V>(
         &self,
-        cache: &Arc<RwLock<HashMap<K, CacheEntry<V>>>>,
-        key: K,
+        cache: &Arc<RwLock<HashMap<String, CacheEntry<V>>>>,
+        kind: EntryKind,
+        key: &str,
         value: V,
         ttl: Duration,
     ) where
-        K: Eq + Hash,
+        V: Serialize,
     {
+        if let Some(persistence) = &self.persistence {
+            match serde_json::to_value(&value) {
+                Ok(json) => {
+                    let expires_at = unix_now().saturating_add(ttl.as_secs());
+                    persistence.store(kind, key, json, expires_at).await;
+                }
+                Err(e) => warn!("Failed to serialize cache entry {}: {}", key, e),
+            }
+        }
+
         let mut cache = cache.write().await;
         let entry = CacheEntry::new(value, ttl);
-        cache.insert(key, entry);
+        cache.insert(key.to_string(), entry);
         debug!("Cached entry with TTL {:?}", ttl);
     }
     
@@ -415,4 +681,41 @@ mod tests {
         assert!(key.contains("13:31"));
         assert!(key.contains("12.9716"));
     }
+
+    #[tokio::test]
+    async fn test_warm_without_persistence_is_empty() {
+        let cache = ApiCache::new();
+        assert!(!cache.is_persistent());
+        assert_eq!(cache.warm().await, CacheWarmReport::default());
+    }
+
+    #[tokio::test]
+    async fn test_persisted_entries_survive_restart() {
+        let dir = std::env::temp_dir().join(format!("vedic-api-cache-{}", std::process::id()));
+        std::env::set_var("L3_CACHE_DIR", &dir);
+        let manager = || Arc::new(CacheManager::new(String::new(), 16, Duration::from_secs(60), true));
+
+        // Daily data expires immediately, birth data is kept forever
+        let ttls = (Duration::from_secs(0), Duration::from_secs(0), Duration::from_secs(0));
+        let first = ApiCache::with_ttls(ttls.0, ttls.1, ttls.2).with_persistence(manager());
+        first.set_dasha("dasha_key", crate::mocks::mock_vimshottari_dasha()).await;
+        first.set_birth_chart("chart_key", crate::mocks::mock_birth_chart()).await;
+        // Stored last so no later write prunes it from the index
+        first.set_panchang("panchang_key", crate::mocks::mock_panchang()).await;
+
+        let restarted = ApiCache::with_ttls(ttls.0, ttls.1, ttls.2).with_persistence(manager());
+        let report = restarted.warm().await;
+        assert_eq!(report.dasha_loaded, 1);
+        assert_eq!(report.birth_chart_loaded, 1);
+        assert_eq!(report.panchang_loaded, 0);
+        assert_eq!(report.expired, 1);
+        assert_eq!(report.failed, 0);
+
+        assert!(restarted.get_dasha("dasha_key").await.is_some());
+        assert!(restarted.get_birth_chart("chart_key").await.is_some());
+        assert!(restarted.get_panchang("panchang_key").await.is_none());
+        assert_eq!(restarted.stats().await.hits, 2);
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
 }
@@ -6,7 +6,11 @@
 //! - Fallback to native calculations
 //! - Comprehensive Panchang support (Muhurtas, Hora, Choghadiya)
 
+use std::sync::Arc;
+
 use chrono::Datelike;
+use noesis_cache::CacheManager;
+use noesis_core::EngineError;
 use tracing::{debug, info, warn};
 
 use crate::{
@@ -14,7 +18,7 @@ use crate::{
     error::Result, 
     error::VedicApiError,
     client::VedicApiClient,
-    cache::{ApiCache, birth_key, panchang_key},
+    cache::{ApiCache, CacheWarmReport, birth_key, panchang_key},
     rate_limiter::{RateLimiter, RateLimitStatus},
     panchang::{
         Panchang, CompletePanchang, PanchangMetadata, PanchangQuery,
@@ -38,11 +42,26 @@ pub struct CachedVedicClient {
 impl CachedVedicClient {
     /// Create new cached client
     pub fn new(config: Config) -> Self {
+        Self::with_cache(config.clone(), ApiCache::from_config(&config))
+    }
+
+    /// Create a cached client whose cache survives restarts.
+    ///
+    /// Responses are written through to `manager`; call
+    /// [`warm_cache`](Self::warm_cache) on startup to reload them.
+    pub fn with_cache_manager(config: Config, manager: Arc<CacheManager>) -> Self {
+        let cache = ApiCache::from_config(&config).with_persistence(manager);
+        Self::with_cache(config, cache)
+    }
+
+    fn with_cache(config: Config, cache: ApiCache) -> Self {
         let inner = VedicApiClient::new(config.clone());
-        let cache = ApiCache::new();
         let rate_limiter = RateLimiter::new();
         
-        info!("CachedVedicClient initialized with rate limiting and caching");
+        info!(
+            "CachedVedicClient initialized with rate limiting and {} caching",
+            if cache.is_persistent() { "persistent" } else { "in-memory" }
+        );
         
         Self {
             inner,
@@ -57,6 +76,11 @@ impl CachedVedicClient {
         let config = Config::from_env()?;
         Ok(Self::new(config))
     }
+
+    /// Reload responses persisted by earlier runs. A no-op without a cache manager.
+    pub async fn warm_cache(&self) -> CacheWarmReport {
+        self.cache.warm().await
+    }
     
     /// Get Panchang with caching
     pub async fn get_panchang(
@@ -557,6 +581,18 @@ impl CachedVedicClient {
         self.rate_limiter.can_request()
     }
 
+    /// Self-check for engines backed by this client, to return from
+    /// `ConsciousnessEngine::health`: fails once the daily request budget is spent.
+    pub async fn health(&self) -> std::result::Result<(), EngineError> {
+        let status = self.rate_limiter.status();
+        if status.effective_remaining > 0 {
+            Ok(())
+        } else {
+            warn!("Vedic API budget exhausted: {}", status);
+            Err(EngineError::RateLimitExceeded)
+        }
+    }
+
     /// Get rate limit status
     pub async fn rate_limit_status(&self) -> RateLimitStatus {
         self.rate_limiter.status()
@@ -749,6 +785,12 @@ mod tests {
         assert!(is_time_in_range("11:30", "11:30", "12:30"));
     }
 
+    #[tokio::test]
+    async fn test_health_passes_with_fresh_budget() {
+        let client = CachedVedicClient::new(Config::new("test-key"));
+        assert!(client.health().await.is_ok());
+    }
+
     #[test]
     fn test_status_report() {
         let report = StatusReport {
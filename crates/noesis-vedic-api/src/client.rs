@@ -79,6 +79,7 @@ impl VedicApiClient {
         logging::log_request(&log_method, &log_url, &self.config.masked_api_key());
 
         let start = Instant::now();
+        noesis_core::usage::record_external_api_call();
         let response = match request.send().await {
             Ok(resp) => resp,
             Err(err) => {
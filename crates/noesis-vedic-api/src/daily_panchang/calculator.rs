@@ -10,9 +10,7 @@ pub fn calculate_daily_panchang(
     longitude: f64,
     timezone: f64,
 ) -> DailyPanchang {
-    // Calculate sunrise/sunset (simplified - would use actual astronomical calculation)
-    let sunrise = calculate_sunrise(date, latitude, longitude, timezone);
-    let sunset = calculate_sunset(date, latitude, longitude, timezone);
+    let (sunrise, sunset) = calculate_sun_times(date, latitude, longitude, timezone);
     
     // Get vara (weekday)
     let vara = get_vara(date);
@@ -66,15 +64,16 @@ pub fn calculate_daily_panchang(
     }
 }
 
-fn calculate_sunrise(_date: NaiveDate, lat: f64, _lon: f64, _tz: f64) -> String {
-    // Simplified sunrise calculation
-    let base_hour = if lat > 0.0 { 6 } else { 5 };
-    format!("{:02}:15", base_hour)
-}
-
-fn calculate_sunset(_date: NaiveDate, lat: f64, _lon: f64, _tz: f64) -> String {
-    let base_hour = if lat > 0.0 { 18 } else { 19 };
-    format!("{:02}:30", base_hour)
+/// Local sunrise/sunset ("HH:MM") from the NOAA solar algorithm.
+///
+/// Falls back to 06:00 / 18:00 when the sun does not rise or set (polar regions).
+fn calculate_sun_times(date: NaiveDate, lat: f64, lon: f64, tz: f64) -> (String, String) {
+    let offset_minutes = (tz * 60.0).round() as i32;
+    noesis_solar::SolarDay::calculate(date, lat, lon)
+        .ok()
+        .and_then(|day| day.local_sunrise_sunset(offset_minutes))
+        .map(|(rise, set)| (rise.format("%H:%M").to_string(), set.format("%H:%M").to_string()))
+        .unwrap_or_else(|| ("06:00".to_string(), "18:00".to_string()))
 }
 
 fn get_vara(date: NaiveDate) -> String {
@@ -177,6 +176,21 @@ mod tests {
         assert_eq!(get_vara(date), "Sunday");
     }
 
+    #[test]
+    fn test_calculate_sun_times() {
+        let equinox = NaiveDate::from_ymd_opt(2024, 3, 20).unwrap();
+        let (sunrise, sunset) = calculate_sun_times(equinox, 12.97, 77.59, 5.5);
+        assert!(sunrise.starts_with("06:2"), "sunrise {}", sunrise);
+        assert!(sunset.starts_with("18:"), "sunset {}", sunset);
+
+        // Polar night in Tromsø
+        let winter = NaiveDate::from_ymd_opt(2024, 12, 21).unwrap();
+        assert_eq!(
+            calculate_sun_times(winter, 69.65, 18.96, 1.0),
+            ("06:00".to_string(), "18:00".to_string())
+        );
+    }
+
     #[test]
     fn test_format_time_offset() {
         assert_eq!(format_time_offset("06:00", 1.5), "07:30");
@@ -178,7 +178,7 @@ pub mod mocks;
 pub use config::{Config, ProviderType};
 pub use error::{VedicApiError, VedicApiResult, Result};
 pub use client::VedicApiClient;
-pub use cache::ApiCache;
+pub use cache::{ApiCache, CacheWarmReport};
 pub use rate_limiter::{RateLimiter, RateLimitStatus};
 pub use cached_client::CachedVedicClient;
 pub use service::VedicApiService;
@@ -3,6 +3,8 @@
 //! The day is divided into 24 Horas (planetary hours), ruled by the seven planets
 //! in a specific sequence. Each Hora is approximately 1 hour long.
 
+use chrono::{Duration, NaiveDate, NaiveDateTime, NaiveTime, Weekday};
+use noesis_core::hora::{self as native, HoraPlanet};
 use serde::{Deserialize, Serialize};
 
 /// A single Hora (planetary hour)
@@ -35,6 +37,20 @@ pub enum Planet {
     Saturn,
 }
 
+impl From<HoraPlanet> for Planet {
+    fn from(planet: HoraPlanet) -> Self {
+        match planet {
+            HoraPlanet::Sun => Planet::Sun,
+            HoraPlanet::Moon => Planet::Moon,
+            HoraPlanet::Mars => Planet::Mars,
+            HoraPlanet::Mercury => Planet::Mercury,
+            HoraPlanet::Jupiter => Planet::Jupiter,
+            HoraPlanet::Venus => Planet::Venus,
+            HoraPlanet::Saturn => Planet::Saturn,
+        }
+    }
+}
+
 impl Planet {
     /// Get the planet name as a string
     pub fn as_str(&self) -> &'static str {
@@ -163,7 +179,14 @@ impl HoraTimings {
             .collect();
         
         all_horas.iter()
-            .find(|h| current_time >= h.start.as_str() && current_time <= h.end.as_str())
+            .find(|h| {
+                if h.start <= h.end {
+                    current_time >= h.start.as_str() && current_time <= h.end.as_str()
+                } else {
+                    // Hora spans midnight
+                    current_time >= h.start.as_str() || current_time <= h.end.as_str()
+                }
+            })
             .copied()
     }
     
@@ -230,45 +253,29 @@ pub struct HoraSequence;
 impl HoraSequence {
     /// Get the starting planet for a given day
     pub fn get_starting_planet(day: &str) -> Planet {
-        match day.to_lowercase().as_str() {
-            "sunday" | "sun" => Planet::Sun,
-            "monday" | "mon" => Planet::Moon,
-            "tuesday" | "tue" => Planet::Mars,
-            "wednesday" | "wed" => Planet::Mercury,
-            "thursday" | "thu" => Planet::Jupiter,
-            "friday" | "fri" => Planet::Venus,
-            "saturday" | "sat" => Planet::Saturn,
-            _ => Planet::Sun,
-        }
+        HoraPlanet::day_lord(parse_weekday(day)).into()
     }
     
     /// Generate the 24 Hora sequence for a given day
     /// The sequence is: Sun → Venus → Mercury → Moon → Saturn → Jupiter → Mars → repeat
     pub fn generate_sequence(day: &str) -> Vec<Planet> {
-        let start = Self::get_starting_planet(day);
-        let sequence_order = vec![
-            Planet::Sun,
-            Planet::Venus,
-            Planet::Mercury,
-            Planet::Moon,
-            Planet::Saturn,
-            Planet::Jupiter,
-            Planet::Mars,
-        ];
-        
-        // Find starting position
-        let start_idx = sequence_order.iter()
-            .position(|&p| p == start)
-            .unwrap_or(0);
-        
-        // Generate 24 Horas by cycling through the sequence
-        let mut horas = Vec::with_capacity(24);
-        for i in 0..24 {
-            let idx = (start_idx + i) % 7;
-            horas.push(sequence_order[idx]);
-        }
-        
-        horas
+        native::hora_rulers(parse_weekday(day))
+            .iter()
+            .map(|&p| p.into())
+            .collect()
+    }
+}
+
+/// Parse a weekday name, defaulting to Sunday when unrecognised
+fn parse_weekday(day: &str) -> Weekday {
+    match day.to_lowercase().as_str() {
+        "monday" | "mon" => Weekday::Mon,
+        "tuesday" | "tue" => Weekday::Tue,
+        "wednesday" | "wed" => Weekday::Wed,
+        "thursday" | "thu" => Weekday::Thu,
+        "friday" | "fri" => Weekday::Fri,
+        "saturday" | "sat" => Weekday::Sat,
+        _ => Weekday::Sun,
     }
 }
 
@@ -276,17 +283,28 @@ impl HoraSequence {
 /// 
 /// Day Horas = time between sunrise and sunset divided by 12
 /// Night Horas = time between sunset and next sunrise divided by 12
+///
+/// Times are local "HH:MM" strings. Unparseable or inconsistent times fall
+/// back to a 06:00 sunrise and 18:00 sunset.
 pub fn calculate_hora_timings(
     day: &str,
     sunrise: &str,
     sunset: &str,
     next_sunrise: &str,
 ) -> HoraTimings {
-    let sequence = HoraSequence::generate_sequence(day);
-    
-    // Parse times (simplified - assumes HH:MM format)
-    let day_horas = calculate_period_horas(&sequence[0..12], sunrise, sunset, 1);
-    let night_horas = calculate_period_horas(&sequence[12..24], sunset, next_sunrise, 13);
+    let weekday = parse_weekday(day);
+    let horas = period_bounds(sunrise, sunset, next_sunrise)
+        .and_then(|(rise, set, next)| native::calculate_horas_for_weekday(weekday, rise, set, next).ok())
+        .or_else(|| {
+            let (rise, set, next) = period_bounds("06:00", "18:00", "06:00")?;
+            native::calculate_horas_for_weekday(weekday, rise, set, next).ok()
+        })
+        .unwrap_or_default();
+
+    let (day_horas, night_horas): (Vec<Hora>, Vec<Hora>) = horas
+        .iter()
+        .map(to_api_hora)
+        .partition(|h| h.number <= 12);
     
     HoraTimings {
         day_horas,
@@ -297,47 +315,43 @@ pub fn calculate_hora_timings(
     }
 }
 
-/// Calculate Horas for a single period (day or night)
-fn calculate_period_horas(
-    planets: &[Planet],
-    start_time: &str,
-    end_time: &str,
-    start_number: u8,
-) -> Vec<Hora> {
-    let mut horas = Vec::new();
-    
-    // Simplified calculation - in real implementation would parse times
-    // and divide the period into 12 equal parts
-    let duration_minutes = 60; // Simplified: 1 hour per Hora
-    
-    for (i, planet) in planets.iter().enumerate() {
-        let hora_num = start_number + i as u8;
-        let start_hour = hora_num - 1;
-        let end_hour = hora_num;
-        
-        let quality = match planet {
-            Planet::Sun => "Power and authority",
-            Planet::Moon => "Change and receptivity",
-            Planet::Mars => "Action and courage",
-            Planet::Mercury => "Communication and skill",
-            Planet::Jupiter => "Wisdom and expansion",
-            Planet::Venus => "Pleasure and harmony",
-            Planet::Saturn => "Restriction and discipline",
-        };
-        
-        let is_favorable = !matches!(planet, Planet::Saturn);
-        
-        horas.push(Hora {
-            number: hora_num,
-            ruler: *planet,
-            start: format!("{:02}:00", start_hour),
-            end: format!("{:02}:00", end_hour),
-            is_favorable,
-            quality: quality.to_string(),
-        });
+/// Anchor "HH:MM" solar events onto a reference date, rolling the next sunrise
+/// into the following day.
+fn period_bounds(
+    sunrise: &str,
+    sunset: &str,
+    next_sunrise: &str,
+) -> Option<(NaiveDateTime, NaiveDateTime, NaiveDateTime)> {
+    let parse = |t: &str| NaiveTime::parse_from_str(t, "%H:%M").ok();
+    let reference = NaiveDate::from_ymd_opt(2000, 1, 1)?;
+
+    let rise = NaiveDateTime::new(reference, parse(sunrise)?);
+    let set = NaiveDateTime::new(reference, parse(sunset)?);
+    let next = NaiveDateTime::new(reference + Duration::days(1), parse(next_sunrise)?);
+    Some((rise, set, next))
+}
+
+fn to_api_hora(hora: &native::Hora) -> Hora {
+    let planet: Planet = hora.ruler.into();
+
+    let quality = match planet {
+        Planet::Sun => "Power and authority",
+        Planet::Moon => "Change and receptivity",
+        Planet::Mars => "Action and courage",
+        Planet::Mercury => "Communication and skill",
+        Planet::Jupiter => "Wisdom and expansion",
+        Planet::Venus => "Pleasure and harmony",
+        Planet::Saturn => "Restriction and discipline",
+    };
+
+    Hora {
+        number: hora.number,
+        ruler: planet,
+        start: hora.start.format("%H:%M").to_string(),
+        end: hora.end.format("%H:%M").to_string(),
+        is_favorable: !matches!(planet, Planet::Saturn),
+        quality: quality.to_string(),
     }
-    
-    horas
 }
 
 /// Helper to find favorable time windows for activities
@@ -409,6 +423,21 @@ mod tests {
         assert_eq!(timings.total_horas(), 24);
     }
 
+    #[test]
+    fn test_hora_timings_divide_actual_day_length() {
+        // 13h day, 11h night
+        let timings = calculate_hora_timings("Monday", "05:30", "18:30", "05:30");
+        assert_eq!(timings.day_horas[0].ruler, Planet::Moon);
+        assert_eq!(timings.day_horas[0].start, "05:30");
+        assert_eq!(timings.day_horas[0].end, "06:35");
+        assert_eq!(timings.day_horas[11].end, "18:30");
+        assert_eq!(timings.night_horas[11].end, "05:30");
+
+        // The hora spanning midnight is still found
+        let late = timings.get_current_hora("00:10").unwrap();
+        assert!(late.number > 12);
+    }
+
     #[test]
     fn test_activity_preferences() {
         let activities = Planet::Venus.suitable_activities();
@@ -64,7 +64,7 @@ impl ExponentialBackoff {
 
         if self.config.jitter {
             // Add up to 25% jitter
-            let jitter = (capped_ms as f64 * 0.25 * rand_simple()) as u64;
+            let jitter = (capped_ms as f64 * 0.25 * noesis_core::rng::jitter_fraction()) as u64;
             Duration::from_millis(capped_ms + jitter)
         } else {
             Duration::from_millis(capped_ms)
@@ -137,16 +137,6 @@ impl ExponentialBackoff {
     }
 }
 
-/// Simple pseudo-random for jitter (avoids pulling in rand crate)
-fn rand_simple() -> f64 {
-    use std::time::SystemTime;
-    let nanos = SystemTime::now()
-        .duration_since(SystemTime::UNIX_EPOCH)
-        .unwrap_or_default()
-        .subsec_nanos();
-    (nanos % 1000) as f64 / 1000.0
-}
-
 // ====================== FALLBACK CHAIN (FAPI-098) ======================
 
 /// The source that ultimately provided the data
@@ -366,9 +356,7 @@ impl FallbackChain {
         let vara_num = (((jdn as i64 + 1) % 7) as u8).max(1);
         let vara = Vara::from_number(vara_num).unwrap_or(Vara::Monday);
 
-        // Approximate sunrise/sunset (simplified for latitude)
-        let sunrise = approximate_sunrise(lat, jdn);
-        let sunset = approximate_sunset(lat, jdn);
+        let day_boundaries = native_day_boundaries(year, month, day, lat, lng, tzone);
 
         Ok(Panchang {
             date: DateInfo {
@@ -443,13 +431,7 @@ impl FallbackChain {
                 rahu: None,
                 ketu: None,
             },
-            day_boundaries: DayBoundaries {
-                sunrise: sunrise.clone(),
-                sunset: sunset.clone(),
-                next_sunrise: sunrise,
-                day_duration: "12:00".to_string(),
-                night_duration: "12:00".to_string(),
-            },
+            day_boundaries,
             ayanamsa: 24.17, // Approximate Lahiri ayanamsa for modern era
         })
     }
@@ -494,45 +476,35 @@ fn sign_from_longitude(lng: f64) -> &'static str {
     SIGNS[index.min(11)]
 }
 
-/// Approximate sunrise time string for a latitude and JDN
-fn approximate_sunrise(lat: f64, jdn: f64) -> String {
-    // Simplified sunrise calculation
-    let day_of_year = ((jdn - julian_day_number(2024, 1, 1)) % 365.25) as f64;
-    let declination = 23.45 * ((360.0 / 365.0 * (day_of_year + 284.0)).to_radians().sin());
-    let lat_rad = lat.to_radians();
-    let decl_rad = declination.to_radians();
-
-    let cos_ha = -(lat_rad.tan() * decl_rad.tan());
-    let ha = if cos_ha.abs() > 1.0 {
-        90.0 // Polar edge case
-    } else {
-        cos_ha.acos().to_degrees()
+/// Day boundaries from NOAA sunrise/sunset for a date and location.
+///
+/// `tzone` is the UTC offset in hours. Polar days and nights fall back to a
+/// 06:00 / 18:00 split.
+fn native_day_boundaries(year: i32, month: u32, day: u32, lat: f64, lng: f64, tzone: f64) -> crate::panchang::DayBoundaries {
+    let offset_minutes = (tzone * 60.0).round() as i32;
+    let sun_times = |date: chrono::NaiveDate| {
+        noesis_solar::SolarDay::calculate(date, lat, lng)
+            .ok()
+            .and_then(|d| d.local_sunrise_sunset(offset_minutes))
+            .unwrap_or_else(|| {
+                let at = |h| date.and_hms_opt(h, 0, 0).unwrap_or_default();
+                (at(6), at(18))
+            })
     };
 
-    let sunrise_hours = 12.0 - ha / 15.0;
-    let h = sunrise_hours.floor() as u32;
-    let m = ((sunrise_hours - h as f64) * 60.0).round() as u32;
-    format!("{:02}:{:02}", h.min(23), m.min(59))
-}
+    let date = chrono::NaiveDate::from_ymd_opt(year, month, day).unwrap_or_default();
+    let (sunrise, sunset) = sun_times(date);
+    let (next_sunrise, _) = sun_times(date + chrono::Duration::days(1));
 
-/// Approximate sunset time string for a latitude and JDN
-fn approximate_sunset(lat: f64, jdn: f64) -> String {
-    let day_of_year = ((jdn - julian_day_number(2024, 1, 1)) % 365.25) as f64;
-    let declination = 23.45 * ((360.0 / 365.0 * (day_of_year + 284.0)).to_radians().sin());
-    let lat_rad = lat.to_radians();
-    let decl_rad = declination.to_radians();
-
-    let cos_ha = -(lat_rad.tan() * decl_rad.tan());
-    let ha = if cos_ha.abs() > 1.0 {
-        90.0
-    } else {
-        cos_ha.acos().to_degrees()
-    };
+    let hhmm = |d: chrono::Duration| format!("{:02}:{:02}", d.num_hours(), d.num_minutes() % 60);
 
-    let sunset_hours = 12.0 + ha / 15.0;
-    let h = sunset_hours.floor() as u32;
-    let m = ((sunset_hours - h as f64) * 60.0).round() as u32;
-    format!("{:02}:{:02}", h.min(23), m.min(59))
+    crate::panchang::DayBoundaries {
+        sunrise: sunrise.format("%H:%M").to_string(),
+        sunset: sunset.format("%H:%M").to_string(),
+        next_sunrise: next_sunrise.format("%H:%M").to_string(),
+        day_duration: hhmm(sunset - sunrise),
+        night_duration: hhmm(next_sunrise - sunset),
+    }
 }
 
 // ====================== RESILIENCE METRICS ======================
@@ -733,7 +705,7 @@ mod tests {
     #[test]
     fn test_approximate_sunrise_reasonable() {
         // Bangalore at equinox-ish
-        let sunrise = approximate_sunrise(12.97, julian_day_number(2024, 3, 20));
+        let sunrise = native_day_boundaries(2024, 3, 20, 12.97, 77.59, 5.5).sunrise;
         // Should be roughly 6:00-6:30
         assert!(sunrise.starts_with("06:") || sunrise.starts_with("05:"),
             "Sunrise at lat 12.97 should be around 6am, got {}", sunrise);
@@ -10,10 +10,11 @@
 
 use std::sync::Arc;
 use std::time::Instant;
+use noesis_cache::CacheManager;
 use tracing::{debug, warn};
 
 use crate::{
-    CachedVedicClient, Config, Result,
+    CachedVedicClient, CacheWarmReport, Config, Result,
     panchang::{Panchang, CompletePanchang, PanchangQuery},
     dasha::{VimshottariDasha, DashaLevel},
     chart::{BirthChart, NavamsaChart},
@@ -58,10 +59,24 @@ impl VedicApiService {
         Ok(Self::with_metrics(CachedVedicClient::new(config), metrics))
     }
 
+    /// Create from environment with responses persisted in `cache`, reloading
+    /// what earlier runs stored before returning.
+    pub async fn from_env_with_cache(cache: Arc<CacheManager>, metrics: Arc<NoesisMetrics>) -> Result<(Self, CacheWarmReport)> {
+        let config = Config::from_env()?;
+        let client = CachedVedicClient::with_cache_manager(config, cache);
+        let report = client.warm_cache().await;
+        Ok((Self::with_metrics(client, metrics), report))
+    }
+
     pub fn client(&self) -> &CachedVedicClient {
         &self.client
     }
 
+    /// See [`CachedVedicClient::health`]
+    pub async fn health(&self) -> std::result::Result<(), noesis_core::EngineError> {
+        self.client.health().await
+    }
+
     /// Get a reference to the metrics collector.
     pub fn metrics(&self) -> &NoesisMetrics {
         &self.metrics
@@ -73,6 +73,7 @@ impl WesternApiClient {
         }
 
         // 4. Execute Network Request
+        noesis_core::usage::record_external_api_call();
         let response = builder.send().await?;
         
         if !response.status().is_success() {
@@ -1,7 +1,12 @@
 //! Noesis Witness — Self-inquiry prompt generation for consciousness development
 //!
 //! Every engine output includes a witness_prompt. This crate provides
-//! consciousness-level-appropriate prompt templates.
+//! consciousness-level-appropriate prompt templates, and content packs (see
+//! [`packs`]) that rotate how those prompts are put to each user.
+
+pub mod packs;
+
+pub use packs::{PromptPack, PromptPacks, DEFAULT_PACK};
 
 /// Generate a witness prompt appropriate to the user's consciousness level.
 ///
@@ -0,0 +1,413 @@
+//! Witness prompt content packs and rotation
+//!
+//! A [`PromptPack`] holds several templates per engine and consciousness
+//! level. The orchestrator passes each engine's `witness_prompt` through the
+//! pack its user is assigned to, picking one of the level's templates by
+//! hashing the pack, engine, level, date and user: the same user gets the
+//! same sentence all day and usually another one the next, while the next
+//! user may get a different one today.
+//!
+//! Templates may use `{prompt}`, the engine's own prompt, and `{engine}`, its
+//! id. Users are split between packs by weight, hashing only the user id, so
+//! an A/B split holds across engines and days. [`DEFAULT_PACK`] only rephrases
+//! around the engine's prompt and is installed from the start; other packs are
+//! uploaded at runtime through the admin wisdom endpoints.
+
+use std::collections::BTreeMap;
+use std::sync::RwLock;
+
+use chrono::NaiveDate;
+use noesis_core::{EngineError, ValidationCode, ValidationError};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+#[cfg(feature = "openapi")]
+use utoipa::ToSchema;
+
+/// Pack installed from the start
+pub const DEFAULT_PACK: &str = "classic";
+
+/// Experiment id packs are reported under in the experiment metrics, with
+/// the pack id as the variant
+pub const EXPERIMENT_ID: &str = "witness-pack";
+
+/// Engine key of templates for engines without their own
+pub const ANY_ENGINE: &str = "*";
+
+/// Highest consciousness level a template can be for
+pub const MAX_LEVEL: u8 = 5;
+
+/// Longest pack id, also the `variant` label of its metrics
+pub const MAX_PACK_ID_LEN: usize = 64;
+
+/// Longest template, in characters
+pub const MAX_TEMPLATE_CHARS: usize = 500;
+
+/// Placeholders a template may use
+const PLACEHOLDERS: [&str; 2] = ["{prompt}", "{engine}"];
+
+/// Templates for one engine, by level
+pub type LevelTemplates = BTreeMap<u8, Vec<String>>;
+
+/// Witness prompt templates by engine and consciousness level
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct PromptPack {
+    #[cfg_attr(feature = "openapi", schema(example = "gentle"))]
+    pub id: String,
+    #[serde(default)]
+    pub description: String,
+    /// Relative share of users; 0 keeps the pack without assigning anyone
+    #[serde(default = "default_weight")]
+    #[cfg_attr(feature = "openapi", schema(example = 1))]
+    pub weight: u32,
+    /// Templates by engine id (`*` for any engine), then level (0-5); a
+    /// level without templates uses the nearest level below it
+    #[cfg_attr(
+        feature = "openapi",
+        schema(value_type = Object, example = json!({"*": {"0": ["{prompt}", "Take a breath first. {prompt}"]}}))
+    )]
+    pub templates: BTreeMap<String, LevelTemplates>,
+}
+
+fn default_weight() -> u32 {
+    1
+}
+
+impl PromptPack {
+    /// Check the id and templates before the pack goes live
+    pub fn validate(&self) -> Result<(), EngineError> {
+        let mut errors = Vec::new();
+        if !is_valid_pack_id(&self.id) {
+            errors.push(ValidationError::new(
+                "id",
+                ValidationCode::InvalidFormat,
+                format!("Pack id must be 1-{} characters of a-z, 0-9, '_' or '-'", MAX_PACK_ID_LEN),
+            ));
+        }
+        if self.templates.values().all(|levels| levels.values().all(Vec::is_empty)) {
+            errors.push(ValidationError::new("templates", ValidationCode::Empty, "Pack has no templates"));
+        }
+        for (engine_id, levels) in &self.templates {
+            for (level, templates) in levels {
+                let field = format!("templates.{}.{}", engine_id, level);
+                if *level > MAX_LEVEL {
+                    errors.push(ValidationError::new(
+                        &field,
+                        ValidationCode::OutOfRange,
+                        format!("Level must be 0-{}", MAX_LEVEL),
+                    ));
+                }
+                for (i, template) in templates.iter().enumerate() {
+                    if let Some(message) = template_problem(template) {
+                        errors.push(ValidationError::new(format!("{}[{}]", field, i), ValidationCode::InvalidFormat, message));
+                    }
+                }
+            }
+        }
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(EngineError::InvalidInput(errors))
+        }
+    }
+
+    /// Templates for `engine_id` at `level`: the engine's own, else `*`'s,
+    /// at the highest level up to `level` that has any
+    pub fn templates_for(&self, engine_id: &str, level: u8) -> Option<&[String]> {
+        [engine_id, ANY_ENGINE].iter().find_map(|key| {
+            self.templates
+                .get(*key)?
+                .range(..=level)
+                .rev()
+                .map(|(_, templates)| templates.as_slice())
+                .find(|templates| !templates.is_empty())
+        })
+    }
+
+    /// Rewrite `prompt`, the witness prompt `engine_id` wrote, with the
+    /// template for `level` that `date` and `user_id` rotate to; `None` when
+    /// the pack has nothing for the engine and level
+    pub fn render(
+        &self,
+        engine_id: &str,
+        level: u8,
+        date: NaiveDate,
+        user_id: Option<&str>,
+        prompt: &str,
+    ) -> Option<String> {
+        let templates = self.templates_for(engine_id, level)?;
+        let day = date.to_string();
+        let level = level.to_string();
+        let index = hash(&[&self.id, engine_id, &level, &day, user_id.unwrap_or_default()]) % templates.len() as u64;
+        let template = &templates[index as usize];
+        Some(template.replace("{engine}", engine_id).replace("{prompt}", prompt).trim().to_string())
+    }
+}
+
+fn template_problem(template: &str) -> Option<String> {
+    if template.trim().is_empty() {
+        return Some("Template is empty".to_string());
+    }
+    if template.chars().count() > MAX_TEMPLATE_CHARS {
+        return Some(format!("Template is longer than {} characters", MAX_TEMPLATE_CHARS));
+    }
+    // Anything in braces that isn't a known placeholder is likely a typo
+    let mut rest = template;
+    while let Some(start) = rest.find('{') {
+        let placeholder = match rest[start..].find('}') {
+            Some(end) => &rest[start..start + end + 1],
+            None => return Some("Template has an unclosed '{'".to_string()),
+        };
+        if !PLACEHOLDERS.contains(&placeholder) {
+            return Some(format!("Unknown placeholder {}; use {{prompt}} or {{engine}}", placeholder));
+        }
+        rest = &rest[start + placeholder.len()..];
+    }
+    None
+}
+
+/// Whether `value` is usable as a pack id: 1-64 of `[a-z0-9_-]`
+pub fn is_valid_pack_id(value: &str) -> bool {
+    !value.is_empty()
+        && value.len() <= MAX_PACK_ID_LEN
+        && value
+            .bytes()
+            .all(|b| b.is_ascii_lowercase() || b.is_ascii_digit() || b == b'_' || b == b'-')
+}
+
+/// Stable hash of the parts, independent of process and platform
+fn hash(parts: &[&str]) -> u64 {
+    let mut hasher = Sha256::new();
+    for part in parts {
+        hasher.update((part.len() as u64).to_be_bytes());
+        hasher.update(part.as_bytes());
+    }
+    let digest = hasher.finalize();
+    u64::from_be_bytes(digest[..8].try_into().expect("SHA-256 digest has 32 bytes"))
+}
+
+/// The pack installed from the start: the engine's prompt, alone or framed
+/// for the level it was written for
+pub fn default_pack() -> PromptPack {
+    let levels = |entries: &[(u8, &[&str])]| -> LevelTemplates {
+        entries
+            .iter()
+            .map(|(level, templates)| (*level, templates.iter().map(|t| t.to_string()).collect()))
+            .collect()
+    };
+    PromptPack {
+        id: DEFAULT_PACK.to_string(),
+        description: "The engine's own prompt, with framings that vary by day".to_string(),
+        weight: 1,
+        templates: BTreeMap::from([(
+            ANY_ENGINE.to_string(),
+            levels(&[
+                (
+                    0,
+                    &[
+                        "{prompt}",
+                        "Take a slow breath before you read on. {prompt}",
+                        "{prompt} There is nothing to fix here, only something to notice.",
+                        "Read this once, then look away for a moment. {prompt}",
+                    ],
+                ),
+                (
+                    2,
+                    &[
+                        "{prompt}",
+                        "Before you answer, notice who is answering. {prompt}",
+                        "{prompt} Let the question stay open a little longer than is comfortable.",
+                        "Set the reading aside for a moment. {prompt}",
+                    ],
+                ),
+                (
+                    4,
+                    &[
+                        "{prompt}",
+                        "{prompt} Then let even the question go.",
+                        "Without reaching for an answer: {prompt}",
+                    ],
+                ),
+            ]),
+        )]),
+    }
+}
+
+/// Live packs, changeable at runtime through the admin API
+pub struct PromptPacks {
+    packs: RwLock<BTreeMap<String, PromptPack>>,
+}
+
+impl Default for PromptPacks {
+    fn default() -> Self {
+        let pack = default_pack();
+        Self {
+            packs: RwLock::new(BTreeMap::from([(pack.id.clone(), pack)])),
+        }
+    }
+}
+
+impl PromptPacks {
+    /// Packs with only [`DEFAULT_PACK`] installed
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add or replace a pack; returns the one it replaced
+    pub fn upsert(&self, pack: PromptPack) -> Result<Option<PromptPack>, EngineError> {
+        pack.validate()?;
+        Ok(self.write().insert(pack.id.clone(), pack))
+    }
+
+    pub fn remove(&self, id: &str) -> Option<PromptPack> {
+        self.write().remove(id)
+    }
+
+    pub fn get(&self, id: &str) -> Option<PromptPack> {
+        self.read().get(id).cloned()
+    }
+
+    /// All packs, ordered by id
+    pub fn list(&self) -> Vec<PromptPack> {
+        self.read().values().cloned().collect()
+    }
+
+    /// Id of the pack `user_id` is assigned to, weighing packs by `weight`;
+    /// `None` when no pack has a positive weight
+    pub fn assign(&self, user_id: Option<&str>) -> Option<String> {
+        let packs = self.read();
+        let total: u64 = packs.values().map(|pack| u64::from(pack.weight)).sum();
+        if total == 0 {
+            return None;
+        }
+        let mut bucket = hash(&[EXPERIMENT_ID, user_id.unwrap_or_default()]) % total;
+        packs
+            .values()
+            .find(|pack| {
+                let weight = u64::from(pack.weight);
+                if bucket < weight {
+                    true
+                } else {
+                    bucket -= weight;
+                    false
+                }
+            })
+            .map(|pack| pack.id.clone())
+    }
+
+    /// Rewrite `prompt` through the pack `user_id` is assigned to; returns
+    /// the pack id and the new prompt, or `None` to keep the engine's
+    pub fn rotate(
+        &self,
+        engine_id: &str,
+        level: u8,
+        date: NaiveDate,
+        user_id: Option<&str>,
+        prompt: &str,
+    ) -> Option<(String, String)> {
+        let id = self.assign(user_id)?;
+        let packs = self.read();
+        let rendered = packs.get(&id)?.render(engine_id, level, date, user_id, prompt)?;
+        Some((id, rendered))
+    }
+
+    fn read(&self) -> std::sync::RwLockReadGuard<'_, BTreeMap<String, PromptPack>> {
+        self.packs.read().unwrap_or_else(|e| e.into_inner())
+    }
+
+    fn write(&self) -> std::sync::RwLockWriteGuard<'_, BTreeMap<String, PromptPack>> {
+        self.packs.write().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pack(id: &str, weight: u32, templates: &[&str]) -> PromptPack {
+        PromptPack {
+            id: id.to_string(),
+            description: String::new(),
+            weight,
+            templates: BTreeMap::from([(
+                ANY_ENGINE.to_string(),
+                BTreeMap::from([(0, templates.iter().map(|t| t.to_string()).collect())]),
+            )]),
+        }
+    }
+
+    fn day(d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, 3, d).unwrap()
+    }
+
+    #[test]
+    fn test_rotation_is_stable_per_day_and_user() {
+        let pack = default_pack();
+        let render = |date, user| pack.render("numerology", 1, date, Some(user), "What do you notice?").unwrap();
+        assert_eq!(render(day(1), "alice"), render(day(1), "alice"));
+
+        let by_day: std::collections::HashSet<String> = (1..=28).map(|d| render(day(d), "alice")).collect();
+        assert!(by_day.len() > 1, "prompt never changes from day to day");
+        assert!(by_day.iter().all(|prompt| prompt.contains("What do you notice?")));
+        let by_user: std::collections::HashSet<String> =
+            ["alice", "bob", "carol", "dave", "erin"].iter().map(|user| render(day(1), user)).collect();
+        assert!(by_user.len() > 1, "every user gets the same prompt");
+    }
+
+    #[test]
+    fn test_templates_fall_back_to_lower_levels_and_any_engine() {
+        let mut pack = pack("p", 1, &["any {engine}"]);
+        pack.templates.insert(
+            "tarot".to_string(),
+            BTreeMap::from([(2, vec!["tarot two".to_string()]), (4, vec![])]),
+        );
+        assert_eq!(pack.templates_for("tarot", 5), Some(&["tarot two".to_string()][..]));
+        assert_eq!(pack.templates_for("tarot", 1), Some(&["any {engine}".to_string()][..]));
+        assert_eq!(pack.render("numerology", 3, day(1), None, "").as_deref(), Some("any numerology"));
+
+        let tarot_only = PromptPack {
+            templates: BTreeMap::from([("tarot".to_string(), BTreeMap::from([(1, vec!["t".to_string()])]))]),
+            ..pack
+        };
+        assert!(tarot_only.templates_for("tarot", 0).is_none());
+        assert!(tarot_only.templates_for("numerology", 3).is_none());
+    }
+
+    #[test]
+    fn test_validate() {
+        assert!(default_pack().validate().is_ok());
+        assert!(pack("Gentle", 1, &["{prompt}"]).validate().is_err());
+        assert!(pack("gentle", 1, &[]).validate().is_err());
+        assert!(pack("gentle", 1, &["  "]).validate().is_err());
+        assert!(pack("gentle", 1, &["{promt}"]).validate().is_err());
+        assert!(pack("gentle", 1, &["{prompt"]).validate().is_err());
+
+        let mut high = pack("gentle", 1, &["{prompt}"]);
+        high.templates.get_mut(ANY_ENGINE).unwrap().insert(6, vec!["x".to_string()]);
+        let Err(EngineError::InvalidInput(errors)) = high.validate() else { panic!("level 6 accepted") };
+        assert_eq!(errors[0].field, "templates.*.6");
+    }
+
+    #[test]
+    fn test_users_split_between_packs_by_weight() {
+        let packs = PromptPacks::new();
+        assert_eq!(packs.assign(Some("alice")).as_deref(), Some(DEFAULT_PACK));
+
+        packs.upsert(pack("gentle", 3, &["Gently: {prompt}"])).unwrap();
+        let users: Vec<String> = (0..400).map(|i| format!("user-{}", i)).collect();
+        let gentle = users.iter().filter(|u| packs.assign(Some(u)).as_deref() == Some("gentle")).count();
+        assert!((250..350).contains(&gentle), "{} of 400 in a 3:1 split", gentle);
+        assert_eq!(packs.assign(Some("user-7")), packs.assign(Some("user-7")));
+
+        let (id, prompt) = packs
+            .rotate("tarot", 0, day(1), Some(&users[0]), "Why?")
+            .unwrap();
+        assert_eq!(id, packs.assign(Some(&users[0])).unwrap());
+        assert!(prompt.contains("Why?"));
+
+        packs.upsert(pack(DEFAULT_PACK, 0, &["{prompt}"])).unwrap();
+        packs.upsert(pack("gentle", 0, &["{prompt}"])).unwrap();
+        assert!(packs.rotate("tarot", 0, day(1), Some("alice"), "Why?").is_none());
+    }
+}
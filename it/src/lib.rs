@@ -0,0 +1,255 @@
+//! End-to-end harness: the whole API against real Postgres and Redis
+//!
+//! [`TestEnv::start`] starts Postgres and Redis in containers with
+//! testcontainers, a [`MockBridge`] standing in for the TypeScript engine
+//! server, and the API on a local port, wired together as `noesis-server`
+//! wires them: migrations run at boot, API keys are validated in Postgres and
+//! results cache in Redis. Tests then talk HTTP to it, so regressions between
+//! crates (middleware order, auth against the real schema, cache layering)
+//! show up here rather than after a deploy.
+//!
+//! Docker is required. Without it `start` returns `None` and the tests skip,
+//! except when `CI` is set, where it panics so the suite can't pass without
+//! having run:
+//!
+//!   cargo test -p noesis-it
+
+use std::collections::HashMap;
+use std::net::SocketAddr;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum::routing::{get, post};
+use axum::{Json, Router};
+use noesis_api::{build_app_state_with, create_router, ApiConfig};
+use noesis_bridge::{BridgeManager, RetryConfig};
+use reqwest::header::HeaderMap;
+use reqwest::Method;
+use serde_json::{json, Value};
+use sqlx::PgPool;
+use testcontainers_modules::postgres::Postgres;
+use testcontainers_modules::redis::Redis;
+use testcontainers_modules::testcontainers::runners::AsyncRunner;
+use testcontainers_modules::testcontainers::{ContainerAsync, ImageExt};
+use tokio::net::TcpListener;
+
+/// Password every user registered by [`TestEnv::register`] has
+pub const PASSWORD: &str = "correct horse battery staple";
+
+/// Recorded bridge exchanges, shared with the bridge's contract tests
+fn fixture_dir() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../crates/noesis-bridge/tests/fixtures/contract")
+}
+
+/// Replies of the mock bridge: per engine id, the recorded status and body
+#[derive(Clone, Default)]
+struct Replies {
+    recorded: HashMap<String, (StatusCode, Value)>,
+    not_found: Option<Value>,
+    calls: Arc<Mutex<HashMap<String, usize>>>,
+}
+
+/// TypeScript engine server stand-in answering every engine with its
+/// recorded contract fixture, and counting the calls it gets
+pub struct MockBridge {
+    pub url: String,
+    calls: Arc<Mutex<HashMap<String, usize>>>,
+}
+
+impl MockBridge {
+    pub async fn start() -> Self {
+        let mut replies = Replies::default();
+        for entry in std::fs::read_dir(fixture_dir()).expect("bridge contract fixtures") {
+            let path = entry.unwrap().path();
+            if path.extension().is_none_or(|ext| ext != "json") {
+                continue;
+            }
+            let fixture: Value = serde_json::from_str(&std::fs::read_to_string(&path).unwrap()).unwrap();
+            let status = StatusCode::from_u16(fixture["status"].as_u64().unwrap() as u16).unwrap();
+            if status == StatusCode::NOT_FOUND {
+                replies.not_found = Some(fixture["response"].clone());
+            } else {
+                let engine_id = fixture["engine_id"].as_str().unwrap().to_string();
+                replies.recorded.insert(engine_id, (status, fixture["response"].clone()));
+            }
+        }
+        let calls = replies.calls.clone();
+
+        let app = Router::new()
+            .route("/health", get(|| async { Json(json!({ "status": "ok" })) }))
+            .route("/engines/:engine_id/calculate", post(replay))
+            .with_state(replies);
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        tokio::spawn(async move { axum::serve(listener, app).await });
+
+        Self { url, calls }
+    }
+
+    /// Calculations the bridge has sent for `engine_id` so far
+    pub fn calls(&self, engine_id: &str) -> usize {
+        self.calls.lock().unwrap().get(engine_id).copied().unwrap_or(0)
+    }
+}
+
+async fn replay(State(replies): State<Replies>, Path(engine_id): Path<String>) -> (StatusCode, Json<Value>) {
+    *replies.calls.lock().unwrap().entry(engine_id.clone()).or_default() += 1;
+    match replies.recorded.get(&engine_id) {
+        Some((status, body)) => (*status, Json(body.clone())),
+        None => (StatusCode::NOT_FOUND, Json(replies.not_found.clone().unwrap_or(Value::Null))),
+    }
+}
+
+/// A response as tests look at it
+pub struct Reply {
+    pub status: StatusCode,
+    pub headers: HeaderMap,
+    pub body: Value,
+}
+
+/// Credentials a request is sent with
+#[derive(Clone, Copy)]
+pub enum Auth<'a> {
+    None,
+    Bearer(&'a str),
+    ApiKey(&'a str),
+}
+
+/// The API, its databases and its bridge, running until dropped
+pub struct TestEnv {
+    pub url: String,
+    pub bridge: MockBridge,
+    /// Primary pool of the API, for arranging what the API has no endpoint for
+    pub pool: PgPool,
+    client: reqwest::Client,
+    _postgres: ContainerAsync<Postgres>,
+    _redis: ContainerAsync<Redis>,
+}
+
+impl TestEnv {
+    /// Start everything, or `None` when Docker isn't available outside CI
+    pub async fn start() -> Option<Self> {
+        let containers = async {
+            let postgres = Postgres::default().with_tag("16-alpine").start().await?;
+            let redis = Redis::default().with_tag("7-alpine").start().await?;
+            Ok::<_, testcontainers_modules::testcontainers::TestcontainersError>((postgres, redis))
+        };
+        let (postgres, redis) = match containers.await {
+            Ok(containers) => containers,
+            Err(e) if std::env::var_os("CI").is_none() => {
+                eprintln!("skipping end-to-end test, containers failed to start: {}", e);
+                return None;
+            }
+            Err(e) => panic!("containers failed to start: {}", e),
+        };
+
+        let database_url = format!(
+            "postgres://postgres:postgres@{}:{}/postgres",
+            postgres.get_host().await.unwrap(),
+            postgres.get_host_port_ipv4(5432).await.unwrap()
+        );
+        let redis_url = format!(
+            "redis://{}:{}",
+            redis.get_host().await.unwrap(),
+            redis.get_host_port_ipv4(6379).await.unwrap()
+        );
+
+        let mut config = ApiConfig::from_env();
+        config.host = "127.0.0.1".to_string();
+        config.port = 0;
+        config.jwt_secret = "noesis-it-secret".to_string();
+        config.database_url = database_url;
+        config.database_replica_url = None;
+        config.redis_url = Some(redis_url);
+        config.db_auto_migrate = true;
+        config.db_connect_attempts = 10;
+        config.rate_limit_requests = 1_000;
+
+        // The server as deployed, with the bridged engines pointed at the mock
+        let bridge = MockBridge::start().await;
+        let engines = BridgeManager::with_config(&bridge.url, |engine| engine.with_retry(RetryConfig::disabled()))
+            .engines();
+        let state = build_app_state_with(&config, |core| engines.into_iter().fold(core, |core, engine| core.engine(engine)))
+            .await;
+        assert!(state.database.is_available(), "migrations failed against a fresh database");
+        let pool = state.db_pools.primary.clone();
+
+        let app = create_router(state, &config);
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        tokio::spawn(async move {
+            axum::serve(listener, app.into_make_service_with_connect_info::<SocketAddr>()).await
+        });
+
+        Some(Self {
+            url,
+            bridge,
+            pool,
+            client: reqwest::Client::new(),
+            _postgres: postgres,
+            _redis: redis,
+        })
+    }
+
+    /// Send `body` as JSON to `path` (e.g. `/api/v1/status`)
+    pub async fn send(&self, method: Method, path: &str, auth: Auth<'_>, body: Option<Value>) -> Reply {
+        let mut request = self
+            .client
+            .request(method, format!("{}{}", self.url, path))
+            .header("Accept", "application/json");
+        request = match auth {
+            Auth::None => request,
+            Auth::Bearer(token) => request.bearer_auth(token),
+            Auth::ApiKey(key) => request.header("X-API-Key", key),
+        };
+        if let Some(body) = body {
+            request = request.json(&body);
+        }
+        let response = request.send().await.expect("API unreachable");
+        let status = StatusCode::from_u16(response.status().as_u16()).unwrap();
+        let headers = response.headers().clone();
+        let text = response.text().await.unwrap();
+        let body = if text.is_empty() { Value::Null } else { serde_json::from_str(&text).unwrap_or(Value::String(text)) };
+        Reply { status, headers, body }
+    }
+
+    /// Register a fresh user and log in, returning their user id and token
+    pub async fn register(&self) -> (String, String) {
+        let email = format!("it-{}@example.com", uuid::Uuid::new_v4());
+        let registered = self
+            .send(
+                Method::POST,
+                "/api/v1/auth/register",
+                Auth::None,
+                Some(json!({ "email": email, "password": PASSWORD, "full_name": "Integration Test" })),
+            )
+            .await;
+        assert_eq!(registered.status, StatusCode::CREATED, "{}", registered.body);
+        let token = self.login(&email).await;
+        (registered.body["id"].as_str().unwrap().to_string(), token)
+    }
+
+    /// Log in as `email`, returning a token
+    pub async fn login(&self, email: &str) -> String {
+        let login = self
+            .send(Method::POST, "/api/v1/auth/login", Auth::None, Some(json!({ "email": email, "password": PASSWORD })))
+            .await;
+        assert_eq!(login.status, StatusCode::OK, "{}", login.body);
+        login.body["token"].as_str().unwrap().to_string()
+    }
+
+    /// Move a user to consciousness phase `level` and log in again, since a
+    /// token carries the phase it was issued with
+    pub async fn set_phase(&self, user_id: &str, level: i32) -> String {
+        let id = uuid::Uuid::parse_str(user_id).unwrap();
+        let email: String = sqlx::query_scalar("UPDATE users SET consciousness_level = $1 WHERE id = $2 RETURNING email")
+            .bind(level)
+            .bind(id)
+            .fetch_one(&self.pool)
+            .await
+            .unwrap();
+        self.login(&email).await
+    }
+}
@@ -0,0 +1,186 @@
+//! Flows through the whole stack: auth → calculate → cache hit → workflow →
+//! history, API keys minted over HTTP and validated against Postgres, and
+//! those keys following their account through deletion and restore
+
+use axum::http::StatusCode;
+use noesis_it::{Auth, TestEnv, PASSWORD};
+use reqwest::Method;
+use serde_json::{json, Value};
+
+fn birth_input(options: Value) -> Value {
+    json!({
+        "birth_data": {
+            "name": "Ada Lovelace",
+            "date": "1990-01-15",
+            "time": "14:30",
+            "latitude": 12.9716,
+            "longitude": 77.5946,
+            "timezone": "Asia/Kolkata"
+        },
+        "options": options
+    })
+}
+
+#[tokio::test]
+async fn test_auth_calculate_cache_workflow_history() {
+    let Some(env) = TestEnv::start().await else { return };
+
+    // Auth runs before anything else touches the request
+    let anonymous = env
+        .send(Method::POST, "/api/v1/engines/numerology/calculate", Auth::None, Some(birth_input(json!({}))))
+        .await;
+    assert_eq!(anonymous.status, StatusCode::UNAUTHORIZED);
+
+    let (user_id, token) = env.register().await;
+    let calculate = |engine_id: &str, input: Value| {
+        let path = format!("/api/v1/engines/{}/calculate", engine_id);
+        let token = token.clone();
+        let env = &env;
+        async move { env.send(Method::POST, &path, Auth::Bearer(&token), Some(input)).await }
+    };
+
+    // A repeated calculation is served from the cache
+    let first = calculate("numerology", birth_input(json!({}))).await;
+    assert_eq!(first.status, StatusCode::OK, "{}", first.body);
+    assert_eq!(first.body["metadata"]["cached"], false);
+    let second = calculate("numerology", birth_input(json!({}))).await;
+    assert_eq!(second.status, StatusCode::OK);
+    assert_eq!(second.body["metadata"]["cached"], true);
+    assert_eq!(second.body["metadata"]["usage"]["cache_layer"], "l1");
+    assert_eq!(second.body["result"], first.body["result"]);
+
+    // Bridged engines go out to the TypeScript server, camelCase keys come back snake_cased
+    let tarot = calculate("tarot", json!({ "options": { "spread": "three_card", "question": "What am I not seeing?", "seed": 42 } })).await;
+    assert_eq!(tarot.status, StatusCode::OK, "{}", tarot.body);
+    assert_eq!(env.bridge.calls("tarot"), 1);
+    assert_eq!(tarot.body["metadata"]["usage"]["bridge_round_trips"], 1);
+    assert!(tarot.body["result"]["positions"][0]["card"].get("is_reversed").is_some());
+
+    // Decision support needs phase 1 for the I Ching; run it and keep the result
+    let token = env.set_phase(&user_id, 3).await;
+    let workflow = env
+        .send(
+            Method::POST,
+            "/api/v1/workflows/decision-support/execute?save=true",
+            Auth::Bearer(&token),
+            Some(birth_input(json!({ "question": "Should I take the job?" }))),
+        )
+        .await;
+    assert_eq!(workflow.status, StatusCode::OK, "{}", workflow.body);
+    assert!(workflow.body["engine_outputs"]["i-ching"].is_object(), "{}", workflow.body);
+    assert!(env.bridge.calls("i-ching") >= 1);
+    let result_id = workflow.headers["x-result-id"].to_str().unwrap().to_string();
+
+    // ... and read it back from history
+    let saved = env
+        .send(Method::GET, &format!("/api/v1/results/{}", result_id), Auth::Bearer(&token), None)
+        .await;
+    assert_eq!(saved.status, StatusCode::OK, "{}", saved.body);
+    assert_eq!(saved.body["workflow_id"], "decision-support");
+    assert_eq!(saved.body["result"]["engine_outputs"]["i-ching"], workflow.body["engine_outputs"]["i-ching"]);
+
+    // Nobody else can
+    let (_, stranger) = env.register().await;
+    let hidden = env
+        .send(Method::GET, &format!("/api/v1/results/{}", result_id), Auth::Bearer(&stranger), None)
+        .await;
+    assert_eq!(hidden.status, StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn test_minted_api_key_is_validated_and_scoped() {
+    let Some(env) = TestEnv::start().await else { return };
+    let (_, token) = env.register().await;
+
+    let minted = env
+        .send(
+            Method::POST,
+            "/api/v1/users/me/api-keys",
+            Auth::Bearer(&token),
+            Some(json!({ "name": "it", "allowed_engines": ["numerology"], "allowed_workflows": [] })),
+        )
+        .await;
+    assert_eq!(minted.status, StatusCode::CREATED, "{}", minted.body);
+    let key = minted.body["key"].as_str().unwrap().to_string();
+
+    let calculate = |engine_id: &str| {
+        let path = format!("/api/v1/engines/{}/calculate", engine_id);
+        let key = key.clone();
+        let env = &env;
+        async move { env.send(Method::POST, &path, Auth::ApiKey(&key), Some(birth_input(json!({})))).await }
+    };
+
+    let allowed = calculate("numerology").await;
+    assert_eq!(allowed.status, StatusCode::OK, "{}", allowed.body);
+    assert!(allowed.headers.contains_key("x-ratelimit-limit"));
+
+    let refused = calculate("panchanga").await;
+    assert_eq!(refused.status, StatusCode::FORBIDDEN);
+    assert_eq!(refused.body["details"]["required_scope"], "engine:panchanga");
+
+    // A scoped key can't widen itself
+    let listed = env.send(Method::GET, "/api/v1/users/me/api-keys", Auth::ApiKey(&key), None).await;
+    assert_eq!(listed.status, StatusCode::FORBIDDEN);
+
+    let id = minted.body["id"].as_str().unwrap();
+    let revoked = env
+        .send(Method::DELETE, &format!("/api/v1/users/me/api-keys/{}", id), Auth::Bearer(&token), None)
+        .await;
+    assert_eq!(revoked.status, StatusCode::NO_CONTENT);
+    let listed = env.send(Method::GET, "/api/v1/users/me/api-keys", Auth::Bearer(&token), None).await;
+    assert_eq!(listed.body["api_keys"], json!([]));
+}
+
+#[tokio::test]
+async fn test_offline_bundle_needs_every_engine_scope() {
+    let Some(env) = TestEnv::start().await else { return };
+    let (_, token) = env.register().await;
+
+    let minted = env
+        .send(
+            Method::POST,
+            "/api/v1/users/me/api-keys",
+            Auth::Bearer(&token),
+            Some(json!({ "name": "it", "allowed_engines": ["numerology"] })),
+        )
+        .await;
+    assert_eq!(minted.status, StatusCode::CREATED, "{}", minted.body);
+    let key = minted.body["key"].as_str().unwrap();
+
+    let path = "/api/v1/users/me/offline-bundle?days=1";
+    let refused = env.send(Method::GET, path, Auth::ApiKey(key), None).await;
+    assert_eq!(refused.status, StatusCode::FORBIDDEN);
+    assert_eq!(refused.body["details"]["required_scope"], "engine:human-design");
+
+    // The same user's token gets past the scope check, to the missing birth data
+    let unscoped = env.send(Method::GET, path, Auth::Bearer(&token), None).await;
+    assert_eq!(unscoped.status, StatusCode::UNPROCESSABLE_ENTITY, "{}", unscoped.body);
+}
+
+#[tokio::test]
+async fn test_api_keys_follow_account_deletion_and_restore() {
+    let Some(env) = TestEnv::start().await else { return };
+    let (_, token) = env.register().await;
+    let me = env.send(Method::GET, "/api/v1/users/me", Auth::Bearer(&token), None).await;
+    let email = me.body["email"].as_str().unwrap().to_string();
+
+    let minted = env
+        .send(Method::POST, "/api/v1/users/me/api-keys", Auth::Bearer(&token), Some(json!({ "name": "it" })))
+        .await;
+    assert_eq!(minted.status, StatusCode::CREATED, "{}", minted.body);
+    let key = minted.body["key"].as_str().unwrap().to_string();
+    let calculate = || {
+        env.send(Method::POST, "/api/v1/engines/numerology/calculate", Auth::ApiKey(&key), Some(birth_input(json!({}))))
+    };
+    assert_eq!(calculate().await.status, StatusCode::OK);
+
+    let deleted = env.send(Method::DELETE, "/api/v1/users/me", Auth::Bearer(&token), None).await;
+    assert_eq!(deleted.status, StatusCode::OK, "{}", deleted.body);
+    assert_eq!(calculate().await.status, StatusCode::UNAUTHORIZED);
+
+    let restored = env
+        .send(Method::POST, "/api/v1/auth/restore", Auth::None, Some(json!({ "email": email, "password": PASSWORD })))
+        .await;
+    assert_eq!(restored.status, StatusCode::OK, "{}", restored.body);
+    assert_eq!(calculate().await.status, StatusCode::OK);
+}
@@ -0,0 +1,53 @@
+/**
+ * Record the server side of the noesis-bridge contract
+ *
+ * Posts the `request` of every fixture in
+ * crates/noesis-bridge/tests/fixtures/contract to a server started in-process
+ * and writes back the status and response body. The Rust contract tests check
+ * these recordings against the bridge's wire types, so re-run this after
+ * changing a request or response shape:
+ *
+ *   bun run record:contract
+ */
+
+import { readdirSync, readFileSync, writeFileSync } from 'node:fs'
+import { join } from 'node:path'
+import { createServer, registry } from '../src/server'
+import { TarotEngine } from '../src/engines/tarot'
+import { IChingEngine } from '../src/engines/i-ching'
+import { EnneagramEngine } from '../src/engines/enneagram'
+import { SacredGeometryEngine } from '../src/engines/sacred-geometry'
+import { SigilForgeEngine } from '../src/engines/sigil-forge'
+
+const FIXTURES_DIR = join(import.meta.dir, '../../crates/noesis-bridge/tests/fixtures/contract')
+const PORT = 3098
+
+/** Fixed so the echoed request ID in error bodies is reproducible */
+const REQUEST_ID = 'contract-fixture'
+
+registry.register(new TarotEngine())
+registry.register(new IChingEngine())
+registry.register(new EnneagramEngine())
+registry.register(new SacredGeometryEngine())
+registry.register(new SigilForgeEngine())
+
+const app = createServer()
+app.listen(PORT)
+
+for (const file of readdirSync(FIXTURES_DIR).filter((f) => f.endsWith('.json')).sort()) {
+  const path = join(FIXTURES_DIR, file)
+  const fixture = JSON.parse(readFileSync(path, 'utf8'))
+
+  const response = await fetch(`http://localhost:${PORT}/engines/${fixture.engine_id}/calculate`, {
+    method: 'POST',
+    headers: { 'Content-Type': 'application/json', 'x-request-id': REQUEST_ID },
+    body: JSON.stringify(fixture.request),
+  })
+  fixture.status = response.status
+  fixture.response = await response.json()
+
+  writeFileSync(path, `${JSON.stringify(fixture, null, 2)}\n`)
+  console.log(`${file}: ${response.status}`)
+}
+
+app.stop()
@@ -15,6 +15,7 @@ import {
   calculateFromAnswerArray,
   getAssessmentQuestions,
 } from './assessment'
+import { getDefaultSeed } from '../../utils/random'
 import type { EnneagramNumber, EnneagramType } from './wisdom'
 import {
   getDisintegrationConnection,
@@ -132,7 +133,7 @@ export class EnneagramEngine implements ConsciousnessEngine {
     const wingParam = input.parameters.wing as number | undefined
     const includeAssessment = input.parameters.includeAssessment as boolean | undefined
     const includeMovementPrompts = input.parameters.includeMovementPrompts as boolean | undefined
-    const seed = input.seed
+    const seed = input.seed ?? getDefaultSeed()
 
     let result: EnneagramResult
     let witnessPrompts: WitnessPrompt[]
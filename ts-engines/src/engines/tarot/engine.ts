@@ -6,6 +6,7 @@ import type { ConsciousnessEngine, EngineMetadata, EngineInput, EngineOutput } f
 import { SpreadType, parseSpreadType, SPREAD_DEFINITIONS } from './spreads'
 import { performReading } from './reading'
 import { generateQuestionBasedPrompts } from './witness'
+import { getDefaultSeed } from '../../utils/random'
 
 export class TarotEngine implements ConsciousnessEngine {
   metadata(): EngineMetadata {
@@ -40,7 +41,8 @@ export class TarotEngine implements ConsciousnessEngine {
     // Extract parameters
     const spreadParam = (input.parameters.spread as string) ?? 'three_card'
     const question = input.question ?? (input.parameters.question as string)
-    const seed = input.seed
+    // Resolve once so the draw and the prompts share a seed, and report it
+    const seed = input.seed ?? getDefaultSeed()
 
     // Parse spread type
     const spreadType = parseSpreadType(spreadParam) ?? SpreadType.THREE_CARD
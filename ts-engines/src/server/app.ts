@@ -4,11 +4,34 @@ import { registry } from './registry'
 
 const startTime = Date.now()
 
+/** Header carrying the request ID forwarded by the Rust bridge */
+export const REQUEST_ID_HEADER = 'x-request-id'
+
+const MAX_REQUEST_ID_LENGTH = 128
+
+/**
+ * Reuse the caller's request ID when it is 1-128 visible ASCII characters,
+ * mirroring the Rust API, otherwise create one
+ */
+export function resolveRequestId(header: string | null): string {
+  if (header && header.length <= MAX_REQUEST_ID_LENGTH && /^[\x21-\x7e]+$/.test(header)) {
+    return header
+  }
+  return crypto.randomUUID()
+}
+
 /**
  * Create the Elysia HTTP server with all routes
  */
 export function createServer() {
   const app = new Elysia()
+    // Correlate with the Rust API: echo the request ID and expose it to handlers
+    .derive(({ request, set }) => {
+      const requestId = resolveRequestId(request.headers.get(REQUEST_ID_HEADER))
+      set.headers[REQUEST_ID_HEADER] = requestId
+      return { requestId }
+    })
+
     // Health check endpoint
     .get(
       '/health',
@@ -32,6 +55,7 @@ export function createServer() {
       ({
         params,
         set,
+        requestId,
       }): ReturnType<typeof registry.get> extends infer T
         ? T extends undefined
           ? ErrorResponse
@@ -43,6 +67,7 @@ export function createServer() {
           return {
             error: `Engine not found: ${params.id}`,
             error_code: 'ENGINE_NOT_FOUND',
+            request_id: requestId,
           } as ErrorResponse
         }
         return engine.metadata() as any
@@ -57,13 +82,14 @@ export function createServer() {
     // Calculate endpoint
     .post(
       '/engines/:id/calculate',
-      async ({ params, body, set }) => {
+      async ({ params, body, set, requestId }) => {
         const engine = registry.get(params.id)
         if (!engine) {
           set.status = 404
           return {
             error: `Engine not found: ${params.id}`,
             error_code: 'ENGINE_NOT_FOUND',
+            request_id: requestId,
           } as ErrorResponse
         }
 
@@ -79,6 +105,7 @@ export function createServer() {
               required_phase: meta.required_phase,
               provided_phase: body.consciousness_level,
             },
+            request_id: requestId,
           } as ErrorResponse
         }
 
@@ -86,10 +113,12 @@ export function createServer() {
           const result = await engine.calculate(body as EngineInput)
           return result
         } catch (err) {
+          console.error(`[${requestId}] ${params.id} calculation failed:`, err)
           set.status = 500
           return {
             error: err instanceof Error ? err.message : 'Unknown error',
             error_code: 'CALCULATION_ERROR',
+            request_id: requestId,
           } as ErrorResponse
         }
       },
@@ -1,6 +1,10 @@
 /**
  * Core types for TypeScript consciousness engines
  * Mirrors the Rust ConsciousnessEngine trait from noesis-core
+ *
+ * EngineInput, EngineOutput and ErrorResponse are the wire format read by
+ * crates/noesis-bridge/src/wire.rs. After changing one, re-record the
+ * contract fixtures with `bun run record:contract`.
  */
 
 /** Phase of consciousness required to access an engine */
@@ -80,6 +84,8 @@ export interface ErrorResponse {
   error: string
   error_code: string
   details?: Record<string, unknown>
+  /** X-Request-Id of the failed request, for correlating with the Rust API logs */
+  request_id?: string
 }
 
 /** Health check response */
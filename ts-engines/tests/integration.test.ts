@@ -413,6 +413,37 @@ describe('Sigil Forge Engine', () => {
 // 7. Error Handling Tests
 // ============================================================================
 
+describe('Determinism', () => {
+  const inputs: Record<string, Record<string, unknown>> = {
+    tarot: { spread: 'celtic_cross' },
+    'i-ching': {},
+    enneagram: { type: 4, includeMovementPrompts: true },
+    'sacred-geometry': {},
+    'sigil-forge': { intention: 'I welcome clarity' },
+  }
+
+  for (const [engineId, parameters] of Object.entries(inputs)) {
+    it(`${engineId} is byte-identical for the same seed`, async () => {
+      const body = { consciousness_level: 5, parameters, seed: 42 }
+      const { status, data: first } = await apiCall('POST', `/engines/${engineId}/calculate`, body)
+      const { data: second } = await apiCall('POST', `/engines/${engineId}/calculate`, body)
+      expect(status).toBe(200)
+      expect(JSON.stringify((first as any).result)).toBe(JSON.stringify((second as any).result))
+      expect(JSON.stringify((first as any).witness_prompts)).toBe(
+        JSON.stringify((second as any).witness_prompts),
+      )
+    })
+  }
+
+  it('tarot reports the seed it drew with when none is given', async () => {
+    const { data } = await apiCall('POST', '/engines/tarot/calculate', {
+      consciousness_level: 0,
+      parameters: {},
+    })
+    expect(typeof (data as any).result.seed).toBe('number')
+  })
+})
+
 describe('Error Handling', () => {
   it('404 for unknown engine', async () => {
     const { status, data } = await apiCall('GET', '/engines/nonexistent/info')
@@ -463,3 +494,24 @@ describe('Error Handling', () => {
     }
   })
 })
+
+// ============================================================================
+// Request ID Correlation
+// ============================================================================
+
+describe('Request ID', () => {
+  it('echoes the forwarded X-Request-Id in headers and error bodies', async () => {
+    const response = await fetch(`${baseUrl}/engines/nonexistent/info`, {
+      headers: { 'X-Request-Id': 'req-from-rust-1' },
+    })
+    const data = (await response.json()) as any
+    expect(response.status).toBe(404)
+    expect(response.headers.get('x-request-id')).toBe('req-from-rust-1')
+    expect(data.request_id).toBe('req-from-rust-1')
+  })
+
+  it('creates a request ID when none is forwarded', async () => {
+    const response = await fetch(`${baseUrl}/health`)
+    expect(response.headers.get('x-request-id')).toMatch(/^[0-9a-f-]{36}$/)
+  })
+})